@@ -5,19 +5,26 @@ use std::path::Path;
 use crate::environment::is_interactive;
 
 use changeset_core::BumpType;
-use changeset_operations::operations::{AddInput, AddOperation, AddResult};
-use changeset_operations::providers::{FileSystemChangesetIO, FileSystemProjectProvider};
-use changeset_operations::traits::ProjectProvider;
-use changeset_project::ProjectKind;
-
-use super::AddArgs;
+use changeset_operations::operations::{
+    AddInput, AddOperation, AddResult, DependencyUpdateOperation,
+};
+use changeset_operations::providers::{
+    BumpInferenceChain, ConventionalCommitsInference, FileHeuristicsInference,
+    FileSystemChangesetIO, Git2Provider,
+};
+use changeset_operations::traits::{GitProvider, ProjectProvider};
+use changeset_project::{ChangesetNaming, ProjectKind, RootChangesetConfig};
+use serde::Deserialize;
+
+use super::{AddArgs, InferBumpArg};
+use crate::config_overrides::{self, project_provider};
 use crate::error::{CliError, Result};
 use crate::interaction::{NonInteractiveProvider, TerminalInteractionProvider};
 
 pub(super) fn run(args: AddArgs, start_path: &Path) -> Result<()> {
     validate_package_bump_args(&args.package_bumps)?;
 
-    let project_provider = FileSystemProjectProvider::new();
+    let project_provider = project_provider();
     let project = project_provider.discover_project(start_path)?;
 
     let is_single_package = project.kind == ProjectKind::SinglePackage && args.packages.is_empty();
@@ -27,13 +34,44 @@ pub(super) fn run(args: AddArgs, start_path: &Path) -> Result<()> {
         }
     }
 
-    let changeset_writer = FileSystemChangesetIO::new(&project.root);
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let changeset_writer = changeset_writer(&project.root, &root_config);
+
+    if args.stdin_json {
+        return run_batch(start_path, project_provider, changeset_writer);
+    }
 
-    let input = build_input(&args)?;
+    let mut input = build_input(&args)?;
+    if args.deps_update {
+        let updates = DependencyUpdateOperation::new(
+            config_overrides::project_provider(),
+            Git2Provider::new(),
+        )
+        .execute(start_path, &args.base)?;
+        if updates.updates.is_empty() {
+            println!("No dependency updates since {}.", args.base);
+            return Ok(());
+        }
+        input.packages = updates.packages();
+        input.bump = input.bump.or(Some(BumpType::Patch));
+        input.description = input.description.or_else(|| Some(updates.summary()));
+    }
 
-    let result = if is_interactive() {
+    let result = if is_interactive() && !args.deps_update {
         let interaction_provider = TerminalInteractionProvider::new(args.editor);
         let operation = AddOperation::new(project_provider, changeset_writer, interaction_provider);
+        #[cfg_attr(not(feature = "api-diff"), allow(unused_mut))]
+        let mut sources = args.infer_bump.clone();
+        #[cfg(feature = "api-diff")]
+        if args.suggest_bump && !sources.contains(&InferBumpArg::Api) {
+            sources.push(InferBumpArg::Api);
+        }
+        let inference = bump_inference_chain(&sources, &args.base);
+        let operation = if inference.is_empty() {
+            operation
+        } else {
+            operation.with_bump_inference(Box::new(inference))
+        };
         operation.execute(start_path, input)?
     } else {
         let interaction_provider = NonInteractiveProvider;
@@ -62,6 +100,94 @@ pub(super) fn run(args: AddArgs, start_path: &Path) -> Result<()> {
     }
 }
 
+/// Writes new changesets named as the `changeset-names` setting asks.
+pub(super) fn changeset_writer(
+    project_root: &Path,
+    root_config: &RootChangesetConfig,
+) -> FileSystemChangesetIO {
+    let writer = FileSystemChangesetIO::new(project_root);
+    if root_config.changeset_naming() == ChangesetNaming::Branch {
+        // Detached checkouts have no branch to name the file after.
+        if let Ok(branch) = Git2Provider::new().current_branch(project_root) {
+            return writer.with_branch_names(&branch);
+        }
+    }
+    writer
+}
+
+/// One changeset in the `--stdin-json` document.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct BatchEntry {
+    #[serde(default)]
+    packages: Vec<String>,
+    bump: Option<BumpType>,
+    /// Bump type per package, taking precedence over `bump`.
+    #[serde(default)]
+    releases: HashMap<String, BumpType>,
+    category: Option<String>,
+    scope: Option<String>,
+    advisory: Option<String>,
+    owner: Option<String>,
+    summary: String,
+}
+
+impl From<BatchEntry> for AddInput {
+    fn from(entry: BatchEntry) -> Self {
+        Self {
+            packages: entry.packages,
+            bump: entry.bump,
+            package_bumps: entry.releases,
+            category: entry.category,
+            scope: entry.scope,
+            advisory: entry.advisory,
+            description: Some(entry.summary),
+            owner: entry.owner,
+            owned_by: None,
+        }
+    }
+}
+
+fn run_batch(
+    start_path: &Path,
+    project_provider: impl ProjectProvider,
+    changeset_writer: FileSystemChangesetIO,
+) -> Result<()> {
+    let inputs = parse_batch(std::io::stdin())?;
+    let operation = AddOperation::new(project_provider, changeset_writer, NonInteractiveProvider);
+
+    for result in operation.execute_batch(start_path, inputs)? {
+        if let AddResult::Created { file_path, .. } = result {
+            println!("Created changeset: {}", file_path.display());
+        }
+    }
+    Ok(())
+}
+
+fn parse_batch(reader: impl std::io::Read) -> Result<Vec<AddInput>> {
+    let entries: Vec<BatchEntry> = serde_json::from_reader(reader)
+        .map_err(|source| CliError::ChangesetBatchParse { source })?;
+    Ok(entries.into_iter().map(AddInput::from).collect())
+}
+
+/// Combines the requested inference sources, in order, into one provider.
+pub(super) fn bump_inference_chain(sources: &[InferBumpArg], base: &str) -> BumpInferenceChain {
+    sources
+        .iter()
+        .fold(BumpInferenceChain::new(), |chain, source| match source {
+            InferBumpArg::Commits => {
+                chain.with(ConventionalCommitsInference::new(Git2Provider::new(), base))
+            }
+            InferBumpArg::Files => {
+                chain.with(FileHeuristicsInference::new(Git2Provider::new(), base))
+            }
+            #[cfg(feature = "api-diff")]
+            InferBumpArg::Api => chain.with(
+                changeset_operations::providers::RustdocBumpInference::new(base),
+            ),
+        })
+}
+
 fn build_input(args: &AddArgs) -> Result<AddInput> {
     let package_bumps = parse_package_bumps(&args.package_bumps)?;
 
@@ -75,8 +201,12 @@ fn build_input(args: &AddArgs) -> Result<AddInput> {
         packages: args.packages.clone(),
         bump: args.bump,
         package_bumps,
-        category: args.category,
+        category: args.category.clone(),
+        scope: args.scope.clone(),
+        advisory: args.advisory.clone(),
         description,
+        owner: args.owner.clone(),
+        owned_by: args.owned_by.clone(),
     })
 }
 
@@ -98,7 +228,7 @@ fn parse_package_bumps(package_bumps: &[String]) -> Result<HashMap<String, BumpT
     Ok(map)
 }
 
-fn parse_package_bump(input: &str) -> Result<(String, BumpType)> {
+pub(super) fn parse_package_bump(input: &str) -> Result<(String, BumpType)> {
     let Some((name, bump_str)) = input.split_once(':') else {
         return Err(CliError::InvalidPackageBumpFormat {
             input: input.to_string(),
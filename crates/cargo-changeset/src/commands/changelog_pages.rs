@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use changeset_operations::operations::{ChangelogPagesInput, ChangelogPagesOperation};
+use changeset_operations::providers::FileSystemTextFileIO;
+
+use super::ChangelogPagesArgs;
+use crate::config_overrides::project_provider;
+use crate::error::Result;
+
+pub(crate) fn run(args: ChangelogPagesArgs, start_path: &Path) -> Result<()> {
+    let operation = ChangelogPagesOperation::new(project_provider(), FileSystemTextFileIO::new());
+    let output = operation.execute(
+        start_path,
+        &ChangelogPagesInput {
+            out_dir: args.out,
+            front_matter: args.front_matter.into(),
+        },
+    )?;
+
+    if output.pages.is_empty() {
+        println!("No releases in the changelogs yet");
+    }
+    for page in &output.pages {
+        println!("Wrote {}", page.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use changeset_operations::operations::{
+    COMPENSATION_JOURNAL_FILENAME, CompensateOperation, CompensationJournal,
+};
+use changeset_operations::providers::{
+    FileSystemChangelogWriter, FileSystemManifestWriter, FileSystemReleaseStateIO, Git2Provider,
+    changeset_io_for,
+};
+use changeset_operations::traits::ProjectProvider;
+
+use super::CompensateArgs;
+use crate::config_overrides::project_provider;
+use crate::error::{CliError, Result};
+
+pub(crate) fn run(args: CompensateArgs, start_path: &Path) -> Result<()> {
+    let path = match args.journal {
+        Some(path) => path,
+        None => default_journal_path(start_path)?,
+    };
+    let journal = load_journal(&path)?;
+
+    if args.dry_run {
+        print_journal(&journal);
+        return Ok(());
+    }
+
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(&journal.project_root)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let operation = CompensateOperation::new(
+        Git2Provider::new(),
+        FileSystemManifestWriter::new(),
+        changeset_io_for(&journal.project_root, &root_config),
+        FileSystemReleaseStateIO::new(),
+        FileSystemChangelogWriter::new(),
+    );
+    let remaining = operation.execute(&journal);
+
+    if remaining.is_empty() {
+        fs::remove_file(&path).map_err(|source| CliError::JournalWrite {
+            path: path.clone(),
+            source,
+        })?;
+        println!("All compensations applied; removed {}", path.display());
+        return Ok(());
+    }
+
+    save_journal(&path, &remaining)?;
+    print_journal(&remaining);
+    Err(CliError::CompensationIncomplete {
+        count: remaining.entries.iter().map(|e| e.actions.len()).sum(),
+        path,
+    })
+}
+
+fn default_journal_path(start_path: &Path) -> Result<PathBuf> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    Ok(project
+        .root
+        .join(root_config.changeset_dir())
+        .join(COMPENSATION_JOURNAL_FILENAME))
+}
+
+pub(super) fn load_journal(path: &Path) -> Result<CompensationJournal> {
+    let content = fs::read_to_string(path).map_err(|source| CliError::JournalRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_str(&content).map_err(|source| CliError::JournalParse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn save_journal(path: &Path, journal: &CompensationJournal) -> Result<()> {
+    serde_json::to_string_pretty(journal)
+        .map_err(std::io::Error::other)
+        .and_then(|content| fs::write(path, content))
+        .map_err(|source| CliError::JournalWrite {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+fn print_journal(journal: &CompensationJournal) {
+    if journal.is_empty() {
+        println!("Nothing to compensate.");
+        return;
+    }
+
+    for entry in &journal.entries {
+        println!("{} - {}", entry.step, entry.description);
+        for action in &entry.actions {
+            println!("  - {}", action.describe());
+        }
+        println!("  last error: {}", entry.error);
+    }
+}
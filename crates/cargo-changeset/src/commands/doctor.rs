@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use changeset_operations::operations::{DoctorInput, DoctorOperation};
+use changeset_operations::providers::{FileSystemReleaseStateIO, Git2Provider};
+use changeset_project::CURRENT_CONFIG_VERSION;
+
+use crate::commands::DoctorArgs;
+use crate::config_overrides::project_provider;
+use crate::error::{CliError, Result};
+use crate::output::format_version_drift;
+
+pub(crate) fn run(args: DoctorArgs, start_path: &Path) -> Result<()> {
+    let operation = DoctorOperation::new(
+        project_provider(),
+        Git2Provider::new(),
+        FileSystemReleaseStateIO::new(),
+    );
+    let output = operation.execute(
+        start_path,
+        &DoctorInput {
+            migrate: args.migrate,
+        },
+    )?;
+
+    for migrated in &output.migrated_state_files {
+        println!(
+            "Migrated {} from config_version {} to {CURRENT_CONFIG_VERSION}",
+            migrated.file, migrated.version
+        );
+    }
+    for outdated in &output.outdated_state_files {
+        println!(
+            "note: {} uses config_version {} (current: {CURRENT_CONFIG_VERSION}); \
+             run `cargo changeset doctor --migrate` to upgrade it",
+            outdated.file, outdated.version
+        );
+    }
+
+    if output.is_healthy() {
+        println!("No problems found.");
+        return Ok(());
+    }
+
+    eprintln!("Manifest versions differ from latest tags:");
+    for drift in &output.version_drift {
+        eprint!("{}", format_version_drift(drift));
+    }
+
+    Err(CliError::VersionDrift {
+        count: output.version_drift.len(),
+    })
+}
@@ -0,0 +1,18 @@
+use changeset_operations::{ERROR_CODES, ErrorCode};
+
+use super::ExplainArgs;
+use crate::error::{CliError, Result};
+
+pub(crate) fn run(args: &ExplainArgs) -> Result<()> {
+    let Some(code) = &args.code else {
+        for entry in ERROR_CODES {
+            println!("{}  {}", entry.code, entry.name);
+        }
+        return Ok(());
+    };
+
+    let entry =
+        ErrorCode::lookup(code).ok_or_else(|| CliError::UnknownErrorCode { code: code.clone() })?;
+    println!("{} {}\n\n{}", entry.code, entry.name, entry.explanation);
+    Ok(())
+}
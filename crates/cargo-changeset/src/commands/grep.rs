@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use changeset_operations::operations::{ChangesetOrigin, GrepInput, GrepOperation};
+use changeset_operations::providers::{Git2Provider, changeset_io_for};
+use changeset_operations::traits::ProjectProvider;
+
+use super::GrepArgs;
+use crate::config_overrides::project_provider;
+use crate::error::Result;
+
+pub(crate) fn run(args: GrepArgs, start_path: &Path) -> Result<()> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let changeset_reader = changeset_io_for(&project.root, &root_config);
+
+    let operation = GrepOperation::new(project_provider, changeset_reader, Git2Provider::new());
+    let output = operation.execute(
+        start_path,
+        &GrepInput {
+            pattern: args.pattern,
+            ignore_case: args.ignore_case,
+            packages: args.packages,
+            categories: args.categories,
+            since: args.since,
+            until: args.until,
+            pending_only: args.pending,
+        },
+    )?;
+
+    if output.matches.is_empty() {
+        println!("No matching changesets.");
+        return Ok(());
+    }
+    for found in &output.matches {
+        let origin = match found.origin {
+            ChangesetOrigin::Pending => "pending",
+            ChangesetOrigin::Consumed => "pre-released",
+            ChangesetOrigin::Released => "released",
+        };
+        let path = found
+            .path
+            .strip_prefix(&project.root)
+            .unwrap_or(&found.path);
+        println!("{} ({origin})", path.display());
+        for line in &found.lines {
+            println!("  {line}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use changeset_operations::operations::{
+    HookInstallInput, HookInstallOperation, VerifyInput, VerifyOperation,
+};
+use changeset_operations::providers::{FileSystemChangesetIO, Git2Provider};
+use changeset_operations::traits::ProjectProvider;
+
+use super::{HookArgs, HookCommand, HookInstallArgs, HookRunArgs};
+use crate::config_overrides::project_provider;
+use crate::error::Result;
+use crate::output::OutputFormat;
+
+pub(crate) fn run(args: HookArgs, start_path: &Path) -> Result<()> {
+    match args.command {
+        HookCommand::Install(install_args) => run_install(install_args, start_path),
+        HookCommand::Run(run_args) => run_hook(run_args, start_path),
+    }
+}
+
+fn run_install(args: HookInstallArgs, start_path: &Path) -> Result<()> {
+    let hooks = if args.hooks.is_empty() && !args.merge_driver {
+        vec![super::HookKindArg::PreCommit.into()]
+    } else {
+        args.hooks.into_iter().map(Into::into).collect()
+    };
+
+    let operation = HookInstallOperation::new(Git2Provider::new());
+    let output = operation.execute(
+        start_path,
+        &HookInstallInput {
+            hooks,
+            force: args.force,
+            merge_driver: args.merge_driver,
+        },
+    )?;
+
+    for path in &output.installed {
+        println!("Installed hook: {}", path.display());
+    }
+    if let Some(path) = &output.merge_driver {
+        println!("Registered merge driver in: {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn run_hook(args: HookRunArgs, start_path: &Path) -> Result<()> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+
+    let git_provider = Git2Provider::new();
+    let changeset_reader = FileSystemChangesetIO::new(&project.root);
+
+    let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+    let input = VerifyInput {
+        base: String::from("HEAD"),
+        head: None,
+        allow_deleted_changesets: args.allow_deleted_changesets,
+        staged: true,
+        require_advisory: false,
+    };
+
+    let outcome = operation.execute(start_path, &input)?;
+
+    super::verify::report_outcome(outcome, args.quiet, OutputFormat::Text)
+}
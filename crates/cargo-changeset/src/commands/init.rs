@@ -2,22 +2,25 @@ use std::path::Path;
 
 use changeset_manifest::InitConfig;
 use changeset_operations::operations::{
-    InitInput, InitOperation, InitPlan, build_config_from_input,
+    InitInput, InitOperation, InitPlan, ProjectAnalysis, analyze_project, build_config_from_input,
+    build_init_plan,
 };
-use changeset_operations::providers::{FileSystemManifestWriter, FileSystemProjectProvider};
+use changeset_operations::providers::{FileSystemManifestWriter, Git2Provider};
 use changeset_operations::traits::{
-    ChangelogSettingsInput, GitSettingsInput, ProjectContext, ProjectProvider, VersionSettingsInput,
+    ChangelogSettingsInput, GitSettingsInput, InitSuggestions, ManifestWriter, ProjectContext,
+    ProjectProvider, VersionSettingsInput,
 };
 use changeset_project::ProjectKind;
 
 use crate::commands::InitArgs;
+use crate::config_overrides::project_provider;
 use crate::error::Result;
 use crate::interaction::{
     TerminalInitInteractionProvider, confirm_proceed, is_terminal_interactive,
 };
 
 pub(crate) fn run(args: InitArgs, start_path: &Path) -> Result<()> {
-    let project_provider = FileSystemProjectProvider::new();
+    let project_provider = project_provider();
     let manifest_writer = FileSystemManifestWriter::new();
     let interaction_provider = TerminalInitInteractionProvider::new();
 
@@ -26,43 +29,43 @@ pub(crate) fn run(args: InitArgs, start_path: &Path) -> Result<()> {
 
     let context = ProjectContext {
         is_single_package: project.kind == ProjectKind::SinglePackage,
+        suggestions: InitSuggestions::default(),
     };
 
     let is_interactive = !args.no_interactive && is_terminal_interactive();
 
-    let input = if args.defaults {
-        build_init_input(&args, context)
-    } else if is_interactive {
-        build_init_input_interactive(&args, &interaction_provider, context)?
-    } else {
-        build_init_input(&args, context)
-    };
-
-    let config = build_config_from_input(&input, context);
-
-    let changeset_dir_path = root_config.changeset_dir();
-    let full_changeset_dir = project.root.join(changeset_dir_path);
-    let dir_exists = full_changeset_dir.exists();
-    let gitkeep_exists = full_changeset_dir.join(".gitkeep").exists();
-
     let metadata_section = match project.kind {
         ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => {
             changeset_manifest::MetadataSection::Workspace
         }
         ProjectKind::SinglePackage => changeset_manifest::MetadataSection::Package,
     };
+    let existing =
+        manifest_writer.read_metadata(&project.root.join("Cargo.toml"), metadata_section)?;
 
-    let plan = InitPlan {
-        changeset_dir: full_changeset_dir,
-        dir_exists,
-        gitkeep_exists,
-        metadata_section,
-        config,
+    let config = if !existing.config.is_empty() {
+        // Already configured: only explicitly passed options override the manifest.
+        build_requested_config(&args)
+    } else {
+        let input = if args.defaults {
+            build_init_input(&args, context)
+        } else if is_interactive {
+            let analysis = analyze_project(&project, &Git2Provider::new());
+            print_analysis(&analysis);
+            build_init_input_interactive(&args, &interaction_provider, analysis.context())?
+        } else {
+            build_init_input(&args, context)
+        };
+        build_config_from_input(&input, context)
     };
 
+    let plan = build_init_plan(&project, &root_config, config, existing, args.upgrade)?;
+
     print_summary(&plan);
 
-    let skip_confirmation = args.defaults || args.no_interactive || !is_terminal_interactive();
+    let nothing_to_write = plan.changes.is_empty() && !plan.upgrade;
+    let skip_confirmation =
+        nothing_to_write || args.defaults || args.no_interactive || !is_terminal_interactive();
     if !skip_confirmation && !confirm_proceed("Proceed with initialization?")? {
         println!("Aborted.");
         return Ok(());
@@ -91,6 +94,10 @@ pub(crate) fn run(args: InitArgs, start_path: &Path) -> Result<()> {
         println!("Created .gitkeep file");
     }
 
+    for deprecated in &output.upgraded_keys {
+        println!("Renamed {} to {}", deprecated.key, deprecated.replacement);
+    }
+
     if output.wrote_config {
         if let Some(section) = output.config_location {
             println!("Wrote configuration to {section} in Cargo.toml");
@@ -164,9 +171,57 @@ fn build_init_input_interactive(
         git_config,
         changelog_config,
         version_config,
+        upgrade: args.upgrade,
     })
 }
 
+fn print_analysis(analysis: &ProjectAnalysis) {
+    let suggestions = analysis.suggestions();
+
+    println!();
+    println!("=== Project Analysis ===");
+    println!();
+
+    if analysis.is_single_package {
+        println!("Layout: single package");
+    } else {
+        println!("Layout: workspace with {} packages", analysis.package_count);
+    }
+
+    match (&analysis.tags, suggestions.tag_format) {
+        (Some(tags), Some(tag_format)) => println!(
+            "Tags: detected {} tag(s) like {} -> suggest {tag_format}",
+            tags.count, tags.example
+        ),
+        _ => println!("Tags: no release tags yet"),
+    }
+
+    match (&analysis.changelog, suggestions.changelog) {
+        (Some(changelog), Some(location)) => {
+            let found = if changelog.root {
+                "CHANGELOG.md at the project root".to_string()
+            } else {
+                format!("CHANGELOG.md in {}", changelog.packages.join(", "))
+            };
+            println!("Changelog: found {found} -> suggest {location}");
+            if changelog.keep_a_changelog {
+                println!(
+                    "  written in the Keep a Changelog style; set changelog-format = \"keep-a-changelog\" to keep it"
+                );
+            }
+        }
+        _ => println!("Changelog: none yet"),
+    }
+
+    match (&analysis.remote_host, suggestions.comparison_links) {
+        (Some(host), Some(links)) => {
+            println!("Remote: {host} -> suggest comparison links {links}");
+        }
+        (Some(host), None) => println!("Remote: {host}"),
+        (None, _) => println!("Remote: none -> suggest comparison links disabled"),
+    }
+}
+
 fn print_summary(plan: &InitPlan) {
     println!();
     println!("=== Initialization Summary ===");
@@ -188,42 +243,61 @@ fn print_summary(plan: &InitPlan) {
         println!("  - .gitkeep file will be created");
     }
 
-    if !plan.config.is_empty() {
+    if plan.upgrade && !plan.existing.deprecated_keys.is_empty() {
+        println!();
+        println!("Deprecated options to be renamed:");
+        for deprecated in &plan.existing.deprecated_keys {
+            println!("  {} -> {}", deprecated.key, deprecated.replacement);
+        }
+    }
+
+    if plan.existing.config.is_empty() {
+        if plan.config.is_empty() {
+            println!();
+            println!("No configuration will be written (using defaults).");
+        } else {
+            println!();
+            println!("Configuration to be written to {}:", plan.metadata_section);
+            print_config_summary(&plan.config);
+        }
+    } else if plan.changes.is_empty() {
         println!();
-        println!("Configuration to be written to {}:", plan.metadata_section);
-        print_config_summary(&plan.config);
+        println!("Configuration is up to date.");
     } else {
         println!();
-        println!("No configuration will be written (using defaults).");
+        println!("Configuration changes for {}:", plan.metadata_section);
+        for change in &plan.changes {
+            match change.current {
+                Some(ref current) => {
+                    println!("  {}: {current} -> {}", change.key, change.proposed);
+                }
+                None => println!("  {}: (unset) -> {}", change.key, change.proposed),
+            }
+        }
     }
 
     println!();
 }
 
 fn print_config_summary(config: &InitConfig) {
-    if let Some(commit) = config.commit {
-        println!("  commit = {commit}");
-    }
-    if let Some(tags) = config.tags {
-        println!("  tags = {tags}");
-    }
-    if let Some(keep_changesets) = config.keep_changesets {
-        println!("  keep_changesets = {keep_changesets}");
-    }
-    if let Some(ref tag_format) = config.tag_format {
-        println!("  tag_format = \"{}\"", tag_format.as_str());
-    }
-    if let Some(ref changelog) = config.changelog {
-        println!("  changelog = \"{}\"", changelog.as_str());
+    for (key, value) in config.entries() {
+        println!("  {key} = {value}");
     }
-    if let Some(ref comparison_links) = config.comparison_links {
-        println!("  comparison_links = \"{}\"", comparison_links.as_str());
+}
+
+fn build_requested_config(args: &InitArgs) -> InitConfig {
+    if args.defaults {
+        return InitConfig::default();
     }
-    if let Some(ref zero_version_behavior) = config.zero_version_behavior {
-        println!(
-            "  zero_version_behavior = \"{}\"",
-            zero_version_behavior.as_str()
-        );
+
+    InitConfig {
+        commit: args.commit,
+        tags: args.tags,
+        keep_changesets: args.keep_changesets,
+        tag_format: args.tag_format.map(Into::into),
+        changelog: args.changelog.map(Into::into),
+        comparison_links: args.comparison_links.map(Into::into),
+        zero_version_behavior: args.zero_version_behavior.map(Into::into),
     }
 }
 
@@ -270,5 +344,6 @@ fn build_init_input(args: &InitArgs, context: ProjectContext) -> InitInput {
         git_config,
         changelog_config,
         version_config,
+        upgrade: args.upgrade,
     }
 }
@@ -1,26 +1,55 @@
 use std::path::Path;
 
+use crate::config_overrides::project_provider;
 use crate::environment::is_interactive;
 
 use changeset_core::PrereleaseSpec;
-use changeset_operations::providers::{FileSystemProjectProvider, FileSystemReleaseStateIO};
+use changeset_operations::operations::ManageOperation;
+use changeset_operations::providers::FileSystemReleaseStateIO;
 use changeset_operations::traits::{ProjectProvider, ReleaseStateIO};
-use changeset_project::{CargoProject, GraduationState, PrereleaseState};
+use changeset_project::{CargoProject, GraduationState, PrereleaseState, StateFile};
 use changeset_version::{is_prerelease, is_zero_version};
 use dialoguer::{Input, Select};
 
 use super::{ManageArgs, ManageCommand, ManageGraduationArgs, ManagePrereleaseArgs};
 use crate::error::{CliError, Result};
+use crate::interaction::TerminalManageInteractionProvider;
 
 pub(crate) fn run(args: ManageArgs, start_path: &Path) -> Result<()> {
     match args.command {
-        ManageCommand::Prerelease(prerelease_args) => run_prerelease(prerelease_args, start_path),
-        ManageCommand::Graduation(graduation_args) => run_graduation(graduation_args, start_path),
+        Some(ManageCommand::Prerelease(prerelease_args)) => {
+            run_prerelease(prerelease_args, start_path)
+        }
+        Some(ManageCommand::Graduation(graduation_args)) => {
+            run_graduation(graduation_args, start_path)
+        }
+        None => run_editor(start_path),
     }
 }
 
+fn run_editor(start_path: &Path) -> Result<()> {
+    if !is_interactive() {
+        return Err(CliError::NotATty);
+    }
+
+    let operation = ManageOperation::new(
+        project_provider(),
+        FileSystemReleaseStateIO::new(),
+        TerminalManageInteractionProvider::new(),
+    );
+    let output = operation.execute(start_path)?;
+
+    if output.saved {
+        println!("Saved release state files");
+    } else {
+        println!("Discarded changes");
+    }
+
+    Ok(())
+}
+
 fn run_prerelease(args: ManagePrereleaseArgs, start_path: &Path) -> Result<()> {
-    let project_provider = FileSystemProjectProvider::new();
+    let project_provider = project_provider();
     let project = project_provider.discover_project(start_path)?;
     let (root_config, _) = project_provider.load_configs(&project)?;
     let changeset_dir = project.root.join(root_config.changeset_dir());
@@ -89,7 +118,10 @@ fn run_prerelease(args: ManagePrereleaseArgs, start_path: &Path) -> Result<()> {
     }
 
     if args.list {
-        print_prerelease_state(&prerelease_state);
+        print_prerelease_state(
+            &prerelease_state,
+            &state_file_label(&project, &changeset_dir, StateFile::Prerelease),
+        );
     }
 
     Ok(())
@@ -108,7 +140,10 @@ fn run_prerelease_interactive(
 
     loop {
         println!();
-        print_prerelease_state(prerelease_state);
+        print_prerelease_state(
+            prerelease_state,
+            &state_file_label(project, changeset_dir, StateFile::Prerelease),
+        );
         println!();
 
         let options = [
@@ -285,7 +320,7 @@ fn interactive_graduate_from_prerelease(
 }
 
 fn run_graduation(args: ManageGraduationArgs, start_path: &Path) -> Result<()> {
-    let project_provider = FileSystemProjectProvider::new();
+    let project_provider = project_provider();
     let project = project_provider.discover_project(start_path)?;
     let (root_config, _) = project_provider.load_configs(&project)?;
     let changeset_dir = project.root.join(root_config.changeset_dir());
@@ -325,7 +360,10 @@ fn run_graduation(args: ManageGraduationArgs, start_path: &Path) -> Result<()> {
     }
 
     if args.list {
-        print_graduation_state(&state);
+        print_graduation_state(
+            &state,
+            &state_file_label(&project, &changeset_dir, StateFile::Graduation),
+        );
     }
 
     Ok(())
@@ -343,7 +381,10 @@ fn run_graduation_interactive(
 
     loop {
         println!();
-        print_graduation_state(state);
+        print_graduation_state(
+            state,
+            &state_file_label(project, changeset_dir, StateFile::Graduation),
+        );
         println!();
 
         let options = [
@@ -428,7 +469,7 @@ fn interactive_remove_graduation(
         return Ok(());
     }
 
-    let mut items: Vec<String> = state.iter().map(str::to_string).collect();
+    let mut items: Vec<String> = state.crates().map(str::to_string).collect();
     items.sort();
 
     let selection = Select::new()
@@ -473,8 +514,9 @@ fn parse_prerelease_entry(input: &str) -> Result<(String, String)> {
 
 fn validate_prerelease_tag(tag: &str) -> Result<()> {
     tag.parse::<PrereleaseSpec>()
-        .map_err(|_| CliError::InvalidPrereleaseTag {
+        .map_err(|source| CliError::InvalidPrereleaseTag {
             tag: tag.to_string(),
+            source,
         })?;
     Ok(())
 }
@@ -514,13 +556,23 @@ fn validate_can_graduate(project: &CargoProject, name: &str) -> Result<()> {
     Ok(())
 }
 
-fn print_prerelease_state(state: &PrereleaseState) {
+/// The state file's path relative to the project root, for headings.
+fn state_file_label(project: &CargoProject, changeset_dir: &Path, file: StateFile) -> String {
+    changeset_dir
+        .strip_prefix(&project.root)
+        .unwrap_or(changeset_dir)
+        .join(file.file_name())
+        .display()
+        .to_string()
+}
+
+fn print_prerelease_state(state: &PrereleaseState, label: &str) {
     if state.is_empty() {
         println!("(No packages in pre-release mode)");
         return;
     }
 
-    println!("Pre-release configuration (.changeset/pre-release.toml):");
+    println!("Pre-release configuration ({label}):");
     let mut items: Vec<_> = state.iter().collect();
     items.sort_by(|a, b| a.0.cmp(b.0));
     for (crate_name, tag) in items {
@@ -528,18 +580,21 @@ fn print_prerelease_state(state: &PrereleaseState) {
     }
 }
 
-fn print_graduation_state(state: &GraduationState) {
+fn print_graduation_state(state: &GraduationState, label: &str) {
     if state.is_empty() {
         println!("(No packages queued for graduation)");
         return;
     }
 
-    println!("Graduation queue (.changeset/graduation.toml):");
+    println!("Graduation queue ({label}):");
     let mut items: Vec<_> = state.iter().collect();
     items.sort();
     for crate_name in items {
         println!("  - {crate_name}");
     }
+    for (crate_name, policy) in state.policies() {
+        println!("  - {crate_name} ({policy})");
+    }
 }
 
 #[cfg(test)]
@@ -693,6 +748,8 @@ mod tests {
                         path: PathBuf::from(format!("/mock/project/crates/{name}")),
                     })
                     .collect(),
+                missing_members: Vec::new(),
+                package_manifests: std::collections::BTreeMap::new(),
             }
         }
 
@@ -749,6 +806,8 @@ mod tests {
                         path: PathBuf::from(format!("/mock/project/crates/{name}")),
                     })
                     .collect(),
+                missing_members: Vec::new(),
+                package_manifests: std::collections::BTreeMap::new(),
             }
         }
 
@@ -0,0 +1,26 @@
+use changeset_operations::operations::{MergeStateInput, MergeStateOperation};
+use changeset_operations::providers::FileSystemTextFileIO;
+
+use super::MergeStateArgs;
+use crate::error::{CliError, Result};
+
+pub(crate) fn run(args: MergeStateArgs) -> Result<()> {
+    let operation = MergeStateOperation::new(FileSystemTextFileIO::new());
+    let output = operation.execute(&MergeStateInput {
+        base: args.base,
+        ours: args.ours,
+        theirs: args.theirs,
+    })?;
+
+    if output.conflicts.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!("Conflicting changes to {} (kept ours):", output.file);
+    for conflict in &output.conflicts {
+        eprintln!("  - {conflict}");
+    }
+    Err(CliError::MergeStateConflicts {
+        count: output.conflicts.len(),
+    })
+}
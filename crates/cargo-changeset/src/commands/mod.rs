@@ -1,26 +1,54 @@
 mod add;
+mod changelog_pages;
+mod compensate;
+mod doctor;
+mod explain;
+mod grep;
+mod hook;
 mod init;
 mod manage;
+mod merge_state;
+mod multi;
+mod new_crate;
+mod next_version;
+mod notify;
+mod publish;
 mod release;
+mod release_manifest;
+mod serve;
 mod status;
 mod verify;
 
-use std::path::Path;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 
-use changeset_core::{BumpType, ChangeCategory};
+use changeset_changelog::PageFrontMatter;
+use changeset_core::BumpType;
 use changeset_manifest::{ChangelogLocation, ComparisonLinks, TagFormat, ZeroVersionBehavior};
+use changeset_operations::operations::{
+    DEFAULT_CHANGELOG_PAGES_DIR, DirtyTreeAction, HookKind, ReleaseStep, SmokeCheck,
+};
 use clap::{Args, Subcommand, ValueEnum};
 
 use crate::error::Result;
+use crate::output::OutputFormat;
 
 #[derive(Subcommand)]
 pub(crate) enum Commands {
     /// Add a new changeset
     Add(AddArgs),
+    /// Scaffold a workspace member from the new-crate template
+    NewCrate(NewCrateArgs),
     /// Verify changeset coverage for changed packages
     Verify(VerifyArgs),
     /// Show pending changesets and projected version bumps
-    Status,
+    Status(StatusArgs),
+    /// Print the version a package would be released as with the pending changesets
+    NextVersion(NextVersionArgs),
+    /// Search pending and released changesets by summary and metadata
+    Grep(GrepArgs),
+    /// Check the repository for inconsistencies such as version drift
+    Doctor(DoctorArgs),
     /// Calculate version bumps and prepare releases based on pending changesets
     #[command(
         verbatim_doc_comment,
@@ -40,10 +68,87 @@ Per-package configuration can also be set via:
 Use 'cargo changeset manage' to configure these files."
     )]
     Release(ReleaseArgs),
+    /// Publish workspace packages to crates.io, dependencies first
+    Publish(PublishArgs),
+    /// Send the release notification for a release manifest written by `release --manifest`
+    Notify(NotifyArgs),
+    /// Write a docs page per package and release from the CHANGELOG.json files
+    ChangelogPages(ChangelogPagesArgs),
+    /// Retry the compensations left over from a partially rolled back release
+    Compensate(CompensateArgs),
     /// Initialize changeset directory in the project
     Init(InitArgs),
     /// Manage release configuration files
     Manage(ManageArgs),
+    /// Install or run git hooks that check changeset coverage
+    Hook(HookArgs),
+    /// Merge two versions of pre-release.toml or graduation.toml (git merge driver)
+    MergeState(MergeStateArgs),
+    /// Run status, verify, or release across the repositories in changeset-workspaces.toml
+    Multi(MultiArgs),
+    /// Serve pending changesets, projected versions, and past releases as read-only JSON over HTTP
+    Serve(ServeArgs),
+    /// Print extended guidance for an error code such as CS0030
+    Explain(ExplainArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct ExplainArgs {
+    /// Error code to explain, e.g. CS0030; lists all codes when omitted
+    pub code: Option<String>,
+}
+
+#[derive(Args)]
+pub(crate) struct StatusArgs {
+    /// Show what shipped between this tag or revision and HEAD instead: released versions,
+    /// consumed changesets and changelog sections per package
+    #[arg(long, value_name = "TAG")]
+    pub since: Option<String>,
+}
+
+#[derive(Args)]
+pub(crate) struct NextVersionArgs {
+    /// Package to print the next version of (required in workspaces)
+    #[arg(long, short = 'p')]
+    pub package: Option<String>,
+}
+
+#[derive(Args)]
+pub(crate) struct GrepArgs {
+    /// Regular expression matched against each summary line and metadata line
+    /// such as "crate-a: patch" or "category: fixed"
+    pub pattern: String,
+
+    /// Match case-insensitively
+    #[arg(long, short = 'i')]
+    pub ignore_case: bool,
+
+    /// Only changesets releasing this package, given by name, path, or glob (repeatable)
+    #[arg(long = "package", short = 'p', value_name = "PACKAGE")]
+    pub packages: Vec<String>,
+
+    /// Only changesets with this category id (repeatable)
+    #[arg(long = "category", short = 'c', value_name = "CATEGORY")]
+    pub categories: Vec<String>,
+
+    /// Only changesets committed on or after this day
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub since: Option<chrono::NaiveDate>,
+
+    /// Only changesets committed on or before this day
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub until: Option<chrono::NaiveDate>,
+
+    /// Leave out changesets deleted by past releases
+    #[arg(long)]
+    pub pending: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct DoctorArgs {
+    /// Rewrite state files in .changeset stored in an older format
+    #[arg(long)]
+    pub migrate: bool,
 }
 
 #[derive(Args)]
@@ -56,6 +161,10 @@ pub(crate) struct InitArgs {
     #[arg(long)]
     pub no_interactive: bool,
 
+    /// Rename deprecated option names in an existing configuration
+    #[arg(long)]
+    pub upgrade: bool,
+
     /// Create git commits on release (default: true)
     #[arg(long)]
     pub commit: Option<bool>,
@@ -149,8 +258,9 @@ impl From<ZeroVersionBehaviorArg> for ZeroVersionBehavior {
 
 #[derive(Args)]
 pub(crate) struct AddArgs {
-    /// Package(s) to include in the changeset (skips interactive selection)
-    #[arg(long = "package", short = 'p', value_name = "NAME")]
+    /// Package(s) to include in the changeset (skips interactive selection). Takes a
+    /// name, a path relative to the project root, or a glob like "changeset-*"
+    #[arg(long = "package", short = 'p', value_name = "PACKAGE")]
     pub packages: Vec<String>,
 
     /// Bump type for all packages (major, minor, patch)
@@ -161,9 +271,25 @@ pub(crate) struct AddArgs {
     #[arg(long = "package-bump", value_name = "NAME:TYPE")]
     pub package_bumps: Vec<String>,
 
-    /// Change category (defaults to "changed")
-    #[arg(long, short = 'c', value_enum, default_value = "changed")]
-    pub category: ChangeCategory,
+    /// Change category id from the configured categories (defaults to "changed")
+    #[arg(long, short = 'c')]
+    pub category: Option<String>,
+
+    /// Component the change affects, e.g. "parser" or "cli"
+    #[arg(long, short = 's')]
+    pub scope: Option<String>,
+
+    /// Security advisory the change resolves, e.g. "RUSTSEC-2024-0001" or "CVE-2024-12345"
+    #[arg(long, value_name = "ID")]
+    pub advisory: Option<String>,
+
+    /// Write to the changeset directory of this owner from the `changeset-dirs` table
+    #[arg(long, value_name = "OWNER")]
+    pub owner: Option<String>,
+
+    /// Only offer packages owned by OWNER, e.g. "@org/web", per the `owners` table or CODEOWNERS
+    #[arg(long, value_name = "OWNER")]
+    pub owned_by: Option<String>,
 
     /// Description (use "-" to read from stdin)
     #[arg(long, short = 'm')]
@@ -172,9 +298,60 @@ pub(crate) struct AddArgs {
     /// Open external editor ($EDITOR) for description input
     #[arg(long)]
     pub editor: bool,
+
+    /// Preselect a bump type from a public API diff against --base (needs a nightly toolchain);
+    /// shorthand for `--infer-bump api`
+    #[cfg(feature = "api-diff")]
+    #[arg(long)]
+    pub suggest_bump: bool,
+
+    /// Preselect a bump type inferred from changes since --base; sources earlier in the
+    /// list take precedence
+    #[arg(long, value_name = "SOURCES", value_delimiter = ',')]
+    pub infer_bump: Vec<InferBumpArg>,
+
+    /// Create a changeset for the packages whose dependencies changed since --base, as in
+    /// Dependabot or Renovate pull requests
+    #[arg(long, conflicts_with_all = ["packages", "owned_by"])]
+    pub deps_update: bool,
+
+    /// Read a JSON array of changesets from stdin and create them all, or none if any is
+    /// invalid
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "packages", "bump", "package_bumps", "category", "scope", "advisory", "owner",
+            "owned_by", "message", "editor", "infer_bump", "deps_update",
+        ]
+    )]
+    pub stdin_json: bool,
+
+    /// Base revision to diff the public API or dependencies against
+    #[arg(long, default_value = "main")]
+    pub base: String,
 }
 
 #[derive(Args)]
+pub(crate) struct NewCrateArgs {
+    /// Name of the new crate
+    pub name: String,
+
+    /// Create a binary crate (src/main.rs) instead of a library
+    #[arg(long)]
+    pub bin: bool,
+
+    /// Also add an "Initial release" changeset for the crate with this bump type
+    #[arg(
+        long,
+        value_enum,
+        value_name = "BUMP",
+        num_args = 0..=1,
+        default_missing_value = "minor"
+    )]
+    pub initial_release: Option<BumpType>,
+}
+
+#[derive(Args, Clone)]
 pub(crate) struct VerifyArgs {
     /// Base branch to compare against
     #[arg(long, default_value = "main")]
@@ -191,14 +368,58 @@ pub(crate) struct VerifyArgs {
     /// Allow deleted changeset files (not recommended)
     #[arg(long, short = 'd')]
     pub allow_deleted_changesets: bool,
+
+    /// Pull request label; with "security", a security changeset naming its advisory is
+    /// required. Labels of the pull request a GitHub Actions workflow runs for are read
+    /// automatically
+    #[arg(long = "label", value_name = "NAME")]
+    pub labels: Vec<String>,
+
+    /// Check that the next changelog renders the same way every time instead of
+    /// checking changeset coverage
+    #[arg(long)]
+    pub changelog: bool,
+
+    /// With --changelog, compare the rendered changelog with this committed snapshot
+    #[arg(long, value_name = "PATH", requires = "changelog")]
+    pub snapshot: Option<PathBuf>,
+
+    /// Write the rendered changelog to the --snapshot file instead of comparing
+    #[arg(long, requires = "snapshot")]
+    pub update_snapshot: bool,
+
+    /// Check that existing changelogs follow the Keep a Changelog structure instead
+    /// of checking changeset coverage
+    #[arg(long, conflicts_with = "changelog")]
+    pub changelog_format: bool,
+
+    /// Report covered packages whose changesets declare a smaller bump than these
+    /// sources infer since --base, in order of precedence; does not fail verification
+    #[arg(long, value_name = "SOURCES", value_delimiter = ',')]
+    pub infer_bump: Vec<InferBumpArg>,
 }
 
-#[derive(Args)]
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum InferBumpArg {
+    /// Conventional Commits messages of the commits touching the package
+    Commits,
+    /// Patch when only docs, tests, benches, examples or Markdown files changed
+    Files,
+    /// Public API diff from rustdoc JSON (needs a nightly toolchain)
+    #[cfg(feature = "api-diff")]
+    Api,
+}
+
+#[derive(Args, Clone)]
 pub(crate) struct ReleaseArgs {
     /// Preview changes without modifying any files
     #[arg(long)]
     pub dry_run: bool,
 
+    /// With --dry-run, show a unified diff of every file the release would change
+    #[arg(long, requires = "dry_run")]
+    pub diff: bool,
+
     /// Convert inherited versions (version.workspace = true) to explicit versions
     #[arg(long)]
     pub convert: bool,
@@ -233,12 +454,190 @@ pub(crate) struct ReleaseArgs {
     /// Can be specified multiple times.
     #[arg(long, value_name = "CRATE", num_args = 0..=1, default_missing_value = "")]
     pub graduate: Vec<String>,
+
+    /// Bump a crate without a changeset file. Format: "crate:type" where type is
+    /// major, minor, or patch. Records a "Forced <type> bump" changelog entry.
+    /// Can be specified multiple times.
+    #[arg(long, value_name = "CRATE:TYPE")]
+    pub force_bump: Vec<String>,
+
+    /// Release every crate when no changesets are pending, recording a
+    /// "Maintenance release" changelog entry. Bumps by TYPE, patch by default.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "TYPE",
+        num_args = 0..=1,
+        default_missing_value = "patch"
+    )]
+    pub allow_empty_release: Option<BumpType>,
+
+    /// Release the versions already in Cargo.toml: write changelogs, delete
+    /// changesets and create tags without editing any manifest
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "convert", "prerelease", "force", "graduate", "force_bump", "allow_empty_release",
+        ]
+    )]
+    pub tag_only: bool,
+
+    /// Release only the changesets of these packages and leave the others pending.
+    /// Takes a name, a path relative to the project root, or a glob like "changeset-*"
+    #[arg(
+        long = "package",
+        short = 'p',
+        value_name = "PACKAGE",
+        conflicts_with = "allow_empty_release"
+    )]
+    pub packages: Vec<String>,
+
+    /// Release every package on this channel (stable, beta, nightly or one from
+    /// the channels table) instead of the channel configured for it
+    #[arg(long, value_name = "CHANNEL", conflicts_with_all = ["prerelease", "tag_only"])]
+    pub channel: Option<String>,
+
+    /// Rewrite versions in files outside the manifests. Format: "glob=pattern",
+    /// where pattern is a regex containing {version} and optionally {package}.
+    /// Can be specified multiple times; applied after configured version stamps.
+    #[arg(long, value_name = "GLOB=PATTERN")]
+    pub stamp: Vec<String>,
+
+    /// Write a markdown release summary (versions, tags, commit, changelog excerpts).
+    /// Defaults to .changeset/RELEASE_SUMMARY.md when no path is given.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "")]
+    pub summary: Option<String>,
+
+    /// Write a JSON release manifest (packages, versions, tags, commit) for `publish` and
+    /// `notify` in later jobs. Defaults to .changeset/release-manifest.json when no path is given.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "")]
+    pub manifest: Option<String>,
+
+    /// Print how long each release step took
+    #[arg(long)]
+    pub timings: bool,
+
+    /// Show which changesets contributed to each package's bump and which one decided it
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Publish summaries of confidential changesets that are still under embargo
+    #[arg(long)]
+    pub include_confidential: bool,
+
+    /// Allow releasing from a branch other than the configured release branch
+    #[arg(long)]
+    pub allow_branch: bool,
+
+    /// Allow releasing when the local branch is behind its remote
+    #[arg(long)]
+    pub allow_stale: bool,
+
+    /// What to do with uncommitted changes; prompts when interactive and unset
+    #[arg(long, value_enum, value_name = "ACTION")]
+    pub on_dirty: Option<OnDirtyArg>,
+
+    /// Fold new changesets into the release commit at HEAD and move its tags;
+    /// refused once the commit has been pushed
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "dry_run", "convert", "no_commit", "no_tags", "prerelease", "force",
+            "graduate", "force_bump", "allow_empty_release", "stamp", "summary", "manifest", "on_dirty",
+            "unsafe_step_control", "tag_only", "packages", "channel",
+        ]
+    )]
+    pub amend: bool,
+
+    /// Enable --skip-step and --only-until; may leave the repository half-released
+    #[arg(long, hide_short_help = true)]
+    pub unsafe_step_control: bool,
+
+    /// Skip a release step (repeatable)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STEP",
+        requires = "unsafe_step_control",
+        hide_short_help = true
+    )]
+    pub skip_step: Vec<ReleaseStepArg>,
+
+    /// Stop the release after this step
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STEP",
+        requires = "unsafe_step_control",
+        hide_short_help = true
+    )]
+    pub only_until: Option<ReleaseStepArg>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum OnDirtyArg {
+    Abort,
+    Stash,
+    Include,
+}
+
+impl From<OnDirtyArg> for DirtyTreeAction {
+    fn from(arg: OnDirtyArg) -> Self {
+        match arg {
+            OnDirtyArg::Abort => Self::Abort,
+            OnDirtyArg::Stash => Self::Stash,
+            OnDirtyArg::Include => Self::Include,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum ReleaseStepArg {
+    RestoreChangelogs,
+    PreChecks,
+    WriteManifests,
+    UpdateDependencies,
+    StampFiles,
+    RemoveWorkspaceVersion,
+    MarkConsumed,
+    ClearConsumed,
+    DeleteChangesets,
+    StageFiles,
+    CreateCommit,
+    CreateTags,
+    UpdateState,
+}
+
+impl From<ReleaseStepArg> for ReleaseStep {
+    fn from(arg: ReleaseStepArg) -> Self {
+        match arg {
+            ReleaseStepArg::RestoreChangelogs => Self::RestoreChangelogs,
+            ReleaseStepArg::PreChecks => Self::PreChecks,
+            ReleaseStepArg::WriteManifests => Self::WriteManifests,
+            ReleaseStepArg::UpdateDependencies => Self::UpdateDependencies,
+            ReleaseStepArg::StampFiles => Self::StampFiles,
+            ReleaseStepArg::RemoveWorkspaceVersion => Self::RemoveWorkspaceVersion,
+            ReleaseStepArg::MarkConsumed => Self::MarkConsumed,
+            ReleaseStepArg::ClearConsumed => Self::ClearConsumed,
+            ReleaseStepArg::DeleteChangesets => Self::DeleteChangesets,
+            ReleaseStepArg::StageFiles => Self::StageFiles,
+            ReleaseStepArg::CreateCommit => Self::CreateCommit,
+            ReleaseStepArg::CreateTags => Self::CreateTags,
+            ReleaseStepArg::UpdateState => Self::UpdateState,
+        }
+    }
 }
 
 #[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
 pub(crate) struct ManageArgs {
     #[command(subcommand)]
-    pub command: ManageCommand,
+    pub command: Option<ManageCommand>,
+
+    /// Edit pre-release.toml and graduation.toml in a menu, previewing the
+    /// result before saving
+    #[arg(long, short)]
+    pub interactive: bool,
 }
 
 #[derive(Subcommand)]
@@ -286,21 +685,280 @@ pub(crate) struct ManageGraduationArgs {
     pub list: bool,
 }
 
+#[derive(Args)]
+pub(crate) struct PublishArgs {
+    /// Run `cargo publish --dry-run` for every package instead of uploading
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Require a crates.io trusted publishing token, even if CARGO_REGISTRY_TOKEN is set
+    #[arg(long)]
+    pub trusted_publishing: bool,
+
+    /// Publish only the packages in a release manifest written by `release --manifest`.
+    /// Defaults to .changeset/release-manifest.json when no path is given.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "")]
+    pub manifest: Option<String>,
+
+    /// Skip packages whose current version is already on crates.io, to finish an interrupted run
+    #[arg(long)]
+    pub only_missing: bool,
+
+    /// After publishing, wait up to SECONDS for every new version to appear in the crates.io index
+    #[arg(long, value_name = "SECONDS")]
+    pub verify_timeout: Option<u64>,
+
+    /// Install or depend on each published version once it is in the index
+    #[arg(long, value_enum, requires = "verify_timeout")]
+    pub smoke_check: Option<SmokeCheckArg>,
+
+    /// Append the verification results to a release summary.
+    /// Defaults to .changeset/RELEASE_SUMMARY.md when no path is given.
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = "",
+        requires = "verify_timeout"
+    )]
+    pub summary: Option<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum SmokeCheckArg {
+    /// `cargo install` the published version
+    Install,
+    /// `cargo add` the published version to a scratch library and build it
+    Add,
+}
+
+impl From<SmokeCheckArg> for SmokeCheck {
+    fn from(arg: SmokeCheckArg) -> Self {
+        match arg {
+            SmokeCheckArg::Install => Self::Install,
+            SmokeCheckArg::Add => Self::Add,
+        }
+    }
+}
+
+#[derive(Args)]
+pub(crate) struct NotifyArgs {
+    /// Release manifest to announce (defaults to .changeset/release-manifest.json)
+    #[arg(long)]
+    pub manifest: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub(crate) struct ChangelogPagesArgs {
+    /// Directory to write the <package>/<version>.md pages to, relative to the project root
+    #[arg(long, default_value = DEFAULT_CHANGELOG_PAGES_DIR)]
+    pub out: PathBuf,
+
+    /// Front matter at the top of each page
+    #[arg(long, value_name = "STYLE", default_value = "yaml")]
+    pub front_matter: FrontMatterArg,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum FrontMatterArg {
+    /// `---` delimited YAML
+    Yaml,
+    /// `+++` delimited TOML with the package and version under `[extra]`, for Zola
+    Toml,
+    /// A title heading instead of front matter, for mdBook
+    None,
+}
+
+impl From<FrontMatterArg> for PageFrontMatter {
+    fn from(arg: FrontMatterArg) -> Self {
+        match arg {
+            FrontMatterArg::Yaml => Self::Yaml,
+            FrontMatterArg::Toml => Self::Toml,
+            FrontMatterArg::None => Self::None,
+        }
+    }
+}
+
+#[derive(Args)]
+pub(crate) struct CompensateArgs {
+    /// Compensation journal to replay (defaults to .changeset/release-journal.json)
+    #[arg(long)]
+    pub journal: Option<PathBuf>,
+
+    /// List what each remaining compensation would undo without applying it
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct ServeArgs {
+    /// Address to listen on, e.g. 127.0.0.1:8080
+    #[arg(long, value_name = "ADDR")]
+    pub http: SocketAddr,
+}
+
+#[derive(Args)]
+pub(crate) struct MultiArgs {
+    /// Orchestration file (defaults to the nearest changeset-workspaces.toml)
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Stop at the first repository that fails
+    #[arg(long)]
+    pub fail_fast: bool,
+
+    #[command(subcommand)]
+    pub command: MultiCommand,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum MultiCommand {
+    /// Show pending changesets in every repository
+    Status,
+    /// Verify changeset coverage in every repository
+    Verify(VerifyArgs),
+    /// Release every repository
+    Release(ReleaseArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct HookArgs {
+    #[command(subcommand)]
+    pub command: HookCommand,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum HookCommand {
+    /// Write hook scripts into the repository's hooks directory
+    Install(HookInstallArgs),
+
+    /// Check staged changes for changeset coverage (invoked by installed hooks)
+    Run(HookRunArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct HookInstallArgs {
+    /// Hook(s) to install (defaults to pre-commit unless --merge-driver is given)
+    #[arg(value_enum)]
+    pub hooks: Vec<HookKindArg>,
+
+    /// Overwrite existing hook scripts
+    #[arg(long, short = 'f')]
+    pub force: bool,
+
+    /// Register `merge-state` as the git merge driver for pre-release.toml and
+    /// graduation.toml in the local git config and .gitattributes
+    #[arg(long)]
+    pub merge_driver: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct MergeStateArgs {
+    /// Common ancestor version (%O); missing or empty merges as a plain union
+    #[arg(long)]
+    pub base: Option<PathBuf>,
+
+    /// Current branch's version (%A), overwritten with the merge result
+    #[arg(long)]
+    pub ours: PathBuf,
+
+    /// Version being merged in (%B)
+    #[arg(long)]
+    pub theirs: PathBuf,
+}
+
+#[derive(Args)]
+pub(crate) struct HookRunArgs {
+    /// Hook being run
+    #[arg(value_enum)]
+    pub hook: HookKindArg,
+
+    /// Arguments passed by git to the hook (ignored)
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, hide = true)]
+    pub hook_args: Vec<String>,
+
+    /// Suppress all output (exit code only)
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// Allow deleted changeset files (not recommended)
+    #[arg(long, short = 'd')]
+    pub allow_deleted_changesets: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum HookKindArg {
+    PreCommit,
+    CommitMsg,
+}
+
+impl From<HookKindArg> for HookKind {
+    fn from(arg: HookKindArg) -> Self {
+        match arg {
+            HookKindArg::PreCommit => Self::PreCommit,
+            HookKindArg::CommitMsg => Self::CommitMsg,
+        }
+    }
+}
+
 pub(crate) struct ExecuteResult {
     pub quiet: bool,
 }
 
 impl Commands {
-    pub(crate) fn execute(self, start_path: &Path) -> (Result<()>, ExecuteResult) {
+    pub(crate) fn execute(
+        self,
+        start_path: &Path,
+        offline: bool,
+        output: OutputFormat,
+    ) -> (Result<()>, ExecuteResult) {
         match self {
             Self::Add(args) => (add::run(args, start_path), ExecuteResult { quiet: false }),
             Self::Verify(args) => {
                 let quiet = args.quiet;
-                (verify::run(args, start_path), ExecuteResult { quiet })
+                (
+                    verify::run(args, start_path, offline, output),
+                    ExecuteResult { quiet },
+                )
+            }
+            Self::Status(args) => {
+                let result = match args.since {
+                    Some(since) => status::run_since(&since, start_path),
+                    None => status::run(start_path),
+                };
+                (result, ExecuteResult { quiet: false })
             }
-            Self::Status => (status::run(start_path), ExecuteResult { quiet: false }),
+            Self::NextVersion(args) => (
+                next_version::run(args, start_path, output),
+                ExecuteResult { quiet: false },
+            ),
+            Self::Grep(args) => (grep::run(args, start_path), ExecuteResult { quiet: false }),
+            Self::Doctor(args) => (
+                doctor::run(args, start_path),
+                ExecuteResult { quiet: false },
+            ),
             Self::Release(args) => (
-                release::run(args, start_path),
+                release::run(args, start_path, offline),
+                ExecuteResult { quiet: false },
+            ),
+            Self::Publish(args) => (
+                publish::run(args, start_path, offline),
+                ExecuteResult { quiet: false },
+            ),
+            Self::Notify(args) => (
+                notify::run(args, start_path, offline),
+                ExecuteResult { quiet: false },
+            ),
+            Self::ChangelogPages(args) => (
+                changelog_pages::run(args, start_path),
+                ExecuteResult { quiet: false },
+            ),
+            Self::Compensate(args) => (
+                compensate::run(args, start_path),
+                ExecuteResult { quiet: false },
+            ),
+            Self::NewCrate(args) => (
+                new_crate::run(args, start_path),
                 ExecuteResult { quiet: false },
             ),
             Self::Init(args) => (init::run(args, start_path), ExecuteResult { quiet: false }),
@@ -308,6 +966,20 @@ impl Commands {
                 manage::run(args, start_path),
                 ExecuteResult { quiet: false },
             ),
+            Self::Hook(args) => {
+                let quiet = matches!(&args.command, HookCommand::Run(run) if run.quiet);
+                (hook::run(args, start_path), ExecuteResult { quiet })
+            }
+            Self::MergeState(args) => (merge_state::run(args), ExecuteResult { quiet: false }),
+            Self::Multi(args) => {
+                let quiet = matches!(&args.command, MultiCommand::Verify(verify) if verify.quiet);
+                (
+                    multi::run(args, start_path, offline),
+                    ExecuteResult { quiet },
+                )
+            }
+            Self::Serve(args) => (serve::run(args, start_path), ExecuteResult { quiet: false }),
+            Self::Explain(args) => (explain::run(&args), ExecuteResult { quiet: false }),
         }
     }
 }
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use changeset_project::{MultiRepoConfig, RepositoryEntry, find_multi_repo_config};
+
+use super::{MultiArgs, MultiCommand};
+use crate::error::{CliError, Result};
+use crate::output::OutputFormat;
+
+pub(crate) fn run(args: MultiArgs, start_path: &Path, offline: bool) -> Result<()> {
+    let config_path = match args.config {
+        Some(path) => path,
+        None => {
+            find_multi_repo_config(start_path).ok_or_else(|| CliError::MultiRepoConfigNotFound {
+                start_dir: start_path.to_path_buf(),
+            })?
+        }
+    };
+    let config = MultiRepoConfig::load(&config_path)?;
+    let quiet = matches!(&args.command, MultiCommand::Verify(verify) if verify.quiet);
+
+    let mut failed = Vec::new();
+    let mut completed = 0;
+    for repository in config.repositories() {
+        completed += 1;
+        if !quiet {
+            println!("==> {} ({})", repository.name, repository.path.display());
+        }
+
+        if let Err(error) = run_in(&args.command, repository, offline) {
+            if !quiet {
+                crate::print_error(&error);
+            }
+            failed.push(repository.name.clone());
+            if args.fail_fast {
+                break;
+            }
+        }
+
+        if !quiet {
+            println!();
+        }
+    }
+
+    if !quiet {
+        println!("Summary:");
+        for (index, repository) in config.repositories().iter().enumerate() {
+            let state = if index >= completed {
+                "skipped"
+            } else if failed.contains(&repository.name) {
+                "failed"
+            } else {
+                "ok"
+            };
+            println!("  {state:<7} {}", repository.name);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::MultiRepoFailed { failed })
+    }
+}
+
+fn run_in(command: &MultiCommand, repository: &RepositoryEntry, offline: bool) -> Result<()> {
+    match command {
+        MultiCommand::Status => super::status::run(&repository.path),
+        MultiCommand::Verify(args) => {
+            super::verify::run(args.clone(), &repository.path, offline, OutputFormat::Text)
+        }
+        MultiCommand::Release(args) => super::release::run(args.clone(), &repository.path, offline),
+    }
+}
@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use changeset_operations::operations::{NewCrateInput, NewCrateOperation};
+use changeset_operations::providers::{FileSystemManifestWriter, FileSystemTextFileIO};
+use changeset_operations::traits::ProjectProvider;
+
+use super::NewCrateArgs;
+use super::add::changeset_writer;
+use crate::config_overrides::project_provider;
+use crate::error::Result;
+
+pub(super) fn run(args: NewCrateArgs, start_path: &Path) -> Result<()> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+
+    let operation = NewCrateOperation::new(
+        project_provider,
+        FileSystemManifestWriter::new(),
+        FileSystemTextFileIO::new(),
+        changeset_writer(&project.root, &root_config),
+    );
+    let output = operation.execute(
+        start_path,
+        &NewCrateInput {
+            name: args.name,
+            binary: args.bin,
+            initial_release: args.initial_release,
+        },
+    )?;
+
+    println!("Created crate: {}", output.crate_dir.display());
+    if output.added_member {
+        println!("Added it to the workspace members");
+    }
+    if let Some(changeset) = &output.changeset {
+        println!("Created changeset: {}", changeset.display());
+    }
+    Ok(())
+}
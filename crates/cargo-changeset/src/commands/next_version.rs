@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use changeset_operations::operations::{NextVersionOperation, NextVersionOutput};
+use changeset_operations::providers::changeset_io_for;
+use changeset_operations::traits::ProjectProvider;
+use serde_json::json;
+
+use super::NextVersionArgs;
+use crate::config_overrides::project_provider;
+use crate::error::Result;
+use crate::output::OutputFormat;
+
+pub(crate) fn run(args: NextVersionArgs, start_path: &Path, output: OutputFormat) -> Result<()> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let changeset_reader = changeset_io_for(&project.root, &root_config);
+
+    let operation = NextVersionOperation::new(project_provider, changeset_reader);
+    let next = operation.execute(start_path, args.package.as_deref())?;
+
+    match output {
+        OutputFormat::Json => println!("{}", render_json(&next)),
+        OutputFormat::Text => println!("{}", next.next_version),
+    }
+    Ok(())
+}
+
+fn render_json(output: &NextVersionOutput) -> serde_json::Value {
+    json!({
+        "package": output.package,
+        "currentVersion": output.current_version.to_string(),
+        "nextVersion": output.next_version.to_string(),
+        "bump": output.bump_type,
+    })
+}
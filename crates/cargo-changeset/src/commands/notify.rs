@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use changeset_operations::OperationError;
+use changeset_operations::operations::{NotifyOperation, NotifyOutcome, RELEASE_MANIFEST_FILENAME};
+use changeset_operations::providers::HttpNotifier;
+
+use super::NotifyArgs;
+use super::release_manifest::{changeset_file, load_manifest};
+use crate::config_overrides::project_provider;
+use crate::error::Result;
+
+pub(crate) fn run(args: NotifyArgs, start_path: &Path, offline: bool) -> Result<()> {
+    if offline {
+        return Err(OperationError::OfflineNetworkRequired {
+            step: "the release notification",
+        }
+        .into());
+    }
+
+    let path = match args.manifest {
+        Some(path) => path,
+        None => changeset_file(start_path, RELEASE_MANIFEST_FILENAME)?,
+    };
+    let manifest = load_manifest(&path)?;
+
+    let operation = NotifyOperation::new(project_provider(), HttpNotifier::new());
+    match operation.execute(start_path, &manifest)? {
+        NotifyOutcome::Sent => println!("Release notification sent."),
+        NotifyOutcome::NotConfigured => println!("No release notification webhook is configured."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use changeset_operations::OperationError;
+use changeset_operations::operations::{
+    PublishInput, PublishOperation, PublishOutput, RELEASE_SUMMARY_FILENAME, VerifyPublished,
+    render_publish_verification,
+};
+use changeset_operations::providers::{
+    CargoPublisher, RegistryCredentials, ShellCommandRunner, SparseIndex,
+};
+
+use super::release_manifest::{load_manifest, resolve_changeset_file, resolve_manifest_path};
+use crate::commands::PublishArgs;
+use crate::config_overrides::project_provider;
+use crate::error::{CliError, Result};
+
+/// Pause between queries of the crates.io index while waiting for new versions.
+const INDEX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+pub(crate) fn run(args: PublishArgs, start_path: &Path, offline: bool) -> Result<()> {
+    if offline {
+        return Err(OperationError::OfflineNetworkRequired {
+            step: "publishing to crates.io",
+        }
+        .into());
+    }
+
+    let manifest = resolve_manifest_path(args.manifest.as_deref(), start_path)?
+        .map(|path| load_manifest(&path))
+        .transpose()?;
+
+    let credentials = RegistryCredentials::resolve(args.trusted_publishing)?;
+    if matches!(credentials, RegistryCredentials::TrustedPublishing { .. }) {
+        println!("Authenticated with crates.io trusted publishing.");
+    }
+
+    let operation = PublishOperation::new(project_provider(), CargoPublisher::new())
+        .with_registry_index(Box::new(SparseIndex::new()))
+        .with_command_runner(Box::new(ShellCommandRunner::new()));
+    let result = operation.execute(
+        start_path,
+        &PublishInput {
+            dry_run: args.dry_run,
+            token: credentials.token().map(str::to_string),
+            only_missing: args.only_missing,
+            manifest,
+            verify: args.verify_timeout.map(|seconds| VerifyPublished {
+                timeout: Duration::from_secs(seconds),
+                poll_interval: INDEX_POLL_INTERVAL,
+                smoke_check: args.smoke_check.map(Into::into),
+            }),
+        },
+    );
+
+    if let Err(e) = credentials.release() {
+        eprintln!("warning: {e}");
+    }
+    let output = result?;
+
+    let verb = if output.dry_run {
+        "Would publish"
+    } else {
+        "Published"
+    };
+    for package in &output.already_published {
+        println!("Already published {} {}", package.name, package.version);
+    }
+    for package in &output.held_back {
+        println!(
+            "Held back {} {} (release channel does not publish)",
+            package.name, package.version
+        );
+    }
+    for package in &output.published {
+        println!("{verb} {} {}", package.name, package.version);
+    }
+    if output.published.is_empty()
+        && output.already_published.is_empty()
+        && output.held_back.is_empty()
+    {
+        println!("No publishable packages.");
+    }
+
+    report_verifications(&output);
+    if !output.verifications.is_empty()
+        && let Some(path) = resolve_changeset_file(
+            args.summary.as_deref(),
+            start_path,
+            RELEASE_SUMMARY_FILENAME,
+        )?
+    {
+        append_summary(&path, &output)?;
+        println!("Publish verification written to {}", path.display());
+    }
+
+    let unverified = output.unverified().count();
+    if unverified > 0 {
+        return Err(CliError::PublishUnverified { count: unverified });
+    }
+
+    Ok(())
+}
+
+fn report_verifications(output: &PublishOutput) {
+    for verification in &output.verifications {
+        let package = &verification.package;
+        if !verification.visible {
+            println!(
+                "{} {} did not appear in the crates.io index",
+                package.name, package.version
+            );
+            continue;
+        }
+        match &verification.smoke_check {
+            Some(check) if !check.success => {
+                println!(
+                    "{} {} failed its smoke check ({})",
+                    package.name,
+                    package.version,
+                    check.status()
+                );
+                let captured = check.captured();
+                if !captured.is_empty() {
+                    println!("{captured}");
+                }
+            }
+            _ => println!("Verified {} {}", package.name, package.version),
+        }
+    }
+}
+
+/// Appends to the summary `release --summary` wrote, or starts a new one.
+fn append_summary(path: &Path, output: &PublishOutput) -> Result<()> {
+    let summary_write = |source| CliError::SummaryWrite {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(summary_write)?;
+    let separator = if file.metadata().map_err(summary_write)?.len() > 0 {
+        "\n"
+    } else {
+        ""
+    };
+    write!(file, "{separator}{}", render_publish_verification(output)).map_err(summary_write)
+}
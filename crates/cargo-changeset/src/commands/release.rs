@@ -1,21 +1,33 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
-use changeset_core::PrereleaseSpec;
+use changeset_core::{BumpType, PrereleaseSpec};
 use changeset_operations::OperationError;
 use changeset_operations::operations::{
-    GitOperationResult, PackageReleaseConfig, ReleaseInput, ReleaseOperation, ReleaseOutcome,
-    ReleaseOutput,
+    AmendInput, AmendOutcome, AmendReleaseOperation, BumpSource, COMPENSATION_JOURNAL_FILENAME,
+    DirtyTreeAction, FileDiff, GitOperationResult, NotifyOperation, NotifyOutcome,
+    PackageReleaseConfig, PrereleaseSource, RELEASE_SUMMARY_FILENAME, ReleaseInput,
+    ReleaseManifest, ReleaseOperation, ReleaseOutcome, ReleaseOutput, StepControl, StepTiming,
+    ZeroVersionTransform, render_release_summary,
 };
 use changeset_operations::providers::{
-    FileSystemChangelogWriter, FileSystemChangesetIO, FileSystemManifestWriter,
-    FileSystemProjectProvider, FileSystemReleaseStateIO, Git2Provider,
+    FileSystemChangelogWriter, FileSystemManifestWriter, FileSystemProjectProvider,
+    FileSystemReleaseStateIO, Git2Provider, HttpNotifier, changeset_io_for,
 };
-use changeset_operations::traits::ProjectProvider;
+use changeset_operations::traits::{ChangesetIO, ProjectProvider};
+use changeset_project::{OfflineBehavior, RootChangesetConfig, VersionStamp};
 use changeset_version::is_prerelease;
 
 use super::ReleaseArgs;
-use crate::error::Result;
+use super::add::parse_package_bump;
+use super::compensate::load_journal;
+use super::release_manifest::{resolve_changeset_file, resolve_manifest_path, save_manifest};
+use crate::config_overrides::project_provider;
+use crate::error::{CliError, Result};
+use crate::interaction::{is_terminal_interactive, select_dirty_tree_action};
 
 /// Parsed prerelease specification from CLI
 #[derive(Debug, Clone)]
@@ -35,15 +47,37 @@ struct ParsedGraduateArgs {
     all: bool,
 }
 
-pub(crate) fn run(args: ReleaseArgs, start_path: &Path) -> Result<()> {
-    let project_provider = FileSystemProjectProvider::new();
+pub(crate) fn run(args: ReleaseArgs, start_path: &Path, offline: bool) -> Result<()> {
+    let project_provider = project_provider();
     let project = project_provider.discover_project(start_path)?;
-    let changeset_io = FileSystemChangesetIO::new(&project.root);
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let changeset_io = changeset_io_for(&project.root, &root_config);
+    if args.amend {
+        return amend(&args, start_path, project_provider, changeset_io);
+    }
     let manifest_writer = FileSystemManifestWriter::new();
     let changelog_writer = FileSystemChangelogWriter::new();
-    let git_provider = Git2Provider::new();
     let release_state_io = FileSystemReleaseStateIO::new();
 
+    let summary_path = resolve_changeset_file(
+        args.summary.as_deref(),
+        start_path,
+        RELEASE_SUMMARY_FILENAME,
+    )?;
+    let manifest_path = resolve_manifest_path(args.manifest.as_deref(), start_path)?;
+    let git_provider = if offline {
+        if root_config.offline_behavior() == OfflineBehavior::Error && !args.dry_run {
+            check_offline_after_release(&root_config)?;
+        }
+        Git2Provider::new().with_offline(root_config.offline_behavior())
+    } else {
+        Git2Provider::new()
+    };
+    let journal_path = project
+        .root
+        .join(root_config.changeset_dir())
+        .join(COMPENSATION_JOURNAL_FILENAME);
+
     let parsed_prerelease = parse_prerelease_args(&args.prerelease, &project)?;
     let parsed_graduate = parse_graduate_args(&args.graduate);
 
@@ -62,8 +96,35 @@ pub(crate) fn run(args: ReleaseArgs, start_path: &Path) -> Result<()> {
             .or_insert_with(PackageReleaseConfig::default)
             .graduate_zero = true;
     }
+    for input in &args.force_bump {
+        let (pkg, bump) = parse_package_bump(input)?;
+        per_package_config
+            .entry(pkg)
+            .or_insert_with(PackageReleaseConfig::default)
+            .forced_bump = Some(bump);
+    }
 
-    let operation = ReleaseOperation::new(
+    let mut step_control = StepControl::default();
+    for step in &args.skip_step {
+        step_control = step_control.with_skip((*step).into());
+    }
+    if let Some(step) = args.only_until {
+        step_control = step_control.with_only_until(step.into());
+    }
+    if step_control != StepControl::default() {
+        eprintln!(
+            "warning: release steps are restricted; the repository may be left half-released"
+        );
+    }
+
+    // A journal left by a failed release records the steps whose effects could
+    // not be undone; retrying resumes them instead of repeating them.
+    let previous_journal = if journal_path.exists() && !args.dry_run {
+        Some(load_journal(&journal_path)?)
+    } else {
+        None
+    };
+    let mut operation = ReleaseOperation::new(
         project_provider,
         changeset_io,
         manifest_writer,
@@ -71,7 +132,10 @@ pub(crate) fn run(args: ReleaseArgs, start_path: &Path) -> Result<()> {
         git_provider,
         release_state_io,
     );
-    let input = ReleaseInput {
+    if let Some(journal) = &previous_journal {
+        operation = operation.with_completed_steps(journal.completed_steps());
+    }
+    let mut input = ReleaseInput {
         dry_run: args.dry_run,
         convert_inherited: args.convert,
         no_commit: args.no_commit,
@@ -81,14 +145,206 @@ pub(crate) fn run(args: ReleaseArgs, start_path: &Path) -> Result<()> {
         per_package_config,
         global_prerelease: parsed_prerelease.and_then(|p| p.global),
         graduate_all: parsed_graduate.all,
+        allow_branch: args.allow_branch,
+        allow_stale: args.allow_stale,
+        include_confidential: args.include_confidential,
+        diff: args.diff,
+        step_control,
+        stamps: parse_stamp_args(&args.stamp)?,
+        on_dirty: args.on_dirty.map(Into::into).unwrap_or_default(),
+        empty_release_bump: args.allow_empty_release,
+        tag_only: args.tag_only,
+        packages: args.packages.clone(),
+        channel: args.channel.clone(),
     };
-    let outcome = operation.execute(start_path, &input)?;
+    let outcome = match operation.execute(start_path, &input) {
+        // The working tree is checked before anything is written, so asking and retrying is safe.
+        Err(OperationError::DirtyWorkingTree { files })
+            if args.on_dirty.is_none() && is_terminal_interactive() =>
+        {
+            input.on_dirty = select_dirty_tree_action(&files)?;
+            if input.on_dirty == DirtyTreeAction::Abort {
+                return Err(OperationError::DirtyWorkingTree { files }.into());
+            }
+            operation.execute(start_path, &input)
+        }
+        result => result,
+    }
+    .map_err(|e| save_compensation_journal(e, &journal_path))?;
+
+    if previous_journal.is_some_and(|journal| !journal.completed.is_empty()) {
+        // Replaying the old journal now would undo the resumed release.
+        fs::remove_file(&journal_path).map_err(|source| CliError::JournalWrite {
+            path: journal_path.clone(),
+            source,
+        })?;
+    }
 
     print_outcome(&outcome);
 
+    if let (ReleaseOutcome::DryRun(output) | ReleaseOutcome::Executed(output), true) =
+        (&outcome, args.explain)
+    {
+        print_provenance(output);
+    }
+
+    if let (ReleaseOutcome::Executed(output), true) = (&outcome, args.timings) {
+        print_step_timings(&output.step_timings);
+    }
+
+    if let (ReleaseOutcome::Executed(output), Some(path)) = (&outcome, &summary_path) {
+        fs::write(path, render_release_summary(output)).map_err(|source| {
+            CliError::SummaryWrite {
+                path: path.clone(),
+                source,
+            }
+        })?;
+        println!("Release summary written to {}", path.display());
+    }
+
+    if let (ReleaseOutcome::Executed(output), Some(path)) = (&outcome, &manifest_path) {
+        save_manifest(path, &ReleaseManifest::from_output(output))?;
+        println!("Release manifest written to {}", path.display());
+    }
+
+    if let ReleaseOutcome::Executed(output) = &outcome {
+        if !offline {
+            send_notification(start_path, output);
+            #[cfg(feature = "otlp")]
+            export_trace(output);
+        } else if root_config.notification_config().webhook_url().is_some() {
+            println!("Release notification skipped (--offline).");
+        }
+    }
+
     Ok(())
 }
 
+fn amend(
+    args: &ReleaseArgs,
+    start_path: &Path,
+    project_provider: FileSystemProjectProvider,
+    changeset_io: Arc<dyn ChangesetIO>,
+) -> Result<()> {
+    let operation = AmendReleaseOperation::new(
+        project_provider,
+        changeset_io,
+        FileSystemChangelogWriter::new(),
+        Git2Provider::new(),
+    );
+    let input = AmendInput {
+        keep_changesets: args.keep_changesets,
+        include_confidential: args.include_confidential,
+    };
+
+    match operation.execute(start_path, &input)? {
+        AmendOutcome::NoChangesets => println!("No new changesets to add to the release."),
+        AmendOutcome::Amended(output) => {
+            println!("Amended release:");
+            for (name, version) in &output.releases {
+                println!("  {name} {version}");
+            }
+            println!(
+                "\nAdded {} changeset(s) to {} changelog(s)",
+                output.changesets_consumed.len(),
+                output.changelogs_updated.len()
+            );
+            println!(
+                "\nCommit amended: {}",
+                &output.commit.sha[..7.min(output.commit.sha.len())]
+            );
+            if !output.tags_moved.is_empty() {
+                println!("\nTags moved:");
+                for tag in &output.tags_moved {
+                    println!("  - {}", tag.name);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Fails before anything is released when a step that runs after the release
+/// would need the network.
+fn check_offline_after_release(root_config: &RootChangesetConfig) -> Result<()> {
+    if root_config.notification_config().webhook_url().is_some() {
+        return Err(OperationError::OfflineNetworkRequired {
+            step: "the release notification",
+        }
+        .into());
+    }
+    #[cfg(feature = "otlp")]
+    if changeset_operations::operations::otlp_traces_endpoint().is_some() {
+        return Err(OperationError::OfflineNetworkRequired {
+            step: "the OTLP trace export",
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Notification failures are reported as warnings because the release has already completed.
+fn send_notification(start_path: &Path, output: &ReleaseOutput) {
+    let operation = NotifyOperation::new(project_provider(), HttpNotifier::new());
+
+    match operation.execute(start_path, &ReleaseManifest::from_output(output)) {
+        Ok(NotifyOutcome::Sent) => println!("Release notification sent."),
+        Ok(NotifyOutcome::NotConfigured) => {}
+        Err(e) => print_warning(&e),
+    }
+}
+
+/// Exports step timings when an OTLP endpoint is configured; failures are warnings.
+#[cfg(feature = "otlp")]
+fn export_trace(output: &ReleaseOutput) {
+    use changeset_operations::operations::{otlp_traces_endpoint, render_release_trace};
+    use changeset_operations::providers::OtlpHttpExporter;
+
+    let (Some(endpoint), Some(trace)) = (otlp_traces_endpoint(), render_release_trace(output))
+    else {
+        return;
+    };
+
+    if let Err(e) = OtlpHttpExporter::new().export(&endpoint, &trace) {
+        print_warning(&e);
+    }
+}
+
+fn print_warning(error: &OperationError) {
+    eprintln!("warning: {error}");
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        eprintln!("caused by: {cause}");
+        source = std::error::Error::source(cause);
+    }
+}
+
+/// Writes the journal of a partially failed rollback next to the changesets.
+///
+/// The journal is dropped from the error when it cannot be written so that no
+/// replay is suggested for it.
+fn save_compensation_journal(mut error: OperationError, path: &Path) -> OperationError {
+    let OperationError::SagaCompensationFailed { journal, .. } = &mut error else {
+        return error;
+    };
+    let Some(entries) = journal.as_ref() else {
+        return error;
+    };
+
+    let written = serde_json::to_string_pretty(entries)
+        .map_err(|e| e.to_string())
+        .and_then(|content| fs::write(path, content).map_err(|e| e.to_string()));
+    if let Err(e) = written {
+        eprintln!(
+            "warning: failed to write compensation journal to {}: {e}",
+            path.display()
+        );
+        *journal = None;
+    }
+    error
+}
+
 fn parse_prerelease_args(
     args: &[String],
     project: &changeset_project::CargoProject,
@@ -137,7 +393,21 @@ fn parse_prerelease_args(
 
 fn parse_prerelease_spec(s: &str) -> Result<PrereleaseSpec> {
     s.parse()
-        .map_err(|_| crate::error::CliError::InvalidPrereleaseTag { tag: s.to_string() })
+        .map_err(|source| crate::error::CliError::InvalidPrereleaseTag {
+            tag: s.to_string(),
+            source,
+        })
+}
+
+fn parse_stamp_args(args: &[String]) -> Result<Vec<VersionStamp>> {
+    args.iter()
+        .map(|arg| match arg.split_once('=') {
+            Some((files, pattern)) if !files.is_empty() && !pattern.is_empty() => {
+                Ok(VersionStamp::new(files, pattern))
+            }
+            _ => Err(CliError::InvalidStampFormat { input: arg.clone() }),
+        })
+        .collect()
 }
 
 fn parse_graduate_args(args: &[String]) -> ParsedGraduateArgs {
@@ -158,11 +428,14 @@ fn parse_graduate_args(args: &[String]) -> ParsedGraduateArgs {
 fn print_outcome(outcome: &ReleaseOutcome) {
     match outcome {
         ReleaseOutcome::NoChangesets => {
-            println!("No pending changesets to release.");
+            println!(
+                "No pending changesets to release. Use --allow-empty-release for a maintenance release."
+            );
         }
         ReleaseOutcome::DryRun(output) => {
             println!("Dry run - no changes will be made.\n");
             print_release_output(output);
+            print_file_diffs(&output.file_diffs);
         }
         ReleaseOutcome::Executed(output) => {
             print_release_output(output);
@@ -171,6 +444,12 @@ fn print_outcome(outcome: &ReleaseOutcome) {
     }
 }
 
+fn print_file_diffs(file_diffs: &[FileDiff]) {
+    for file_diff in file_diffs {
+        print!("\n{}", file_diff.unified());
+    }
+}
+
 fn print_release_output(output: &ReleaseOutput) {
     if output.planned_releases.is_empty() {
         println!("No packages to release.");
@@ -209,11 +488,124 @@ fn print_release_output(output: &ReleaseOutput) {
             "\nConsumed {} changeset file(s)",
             output.changesets_consumed.len()
         );
+        print_changeset_owners(output);
     }
 }
 
+fn print_changeset_owners(output: &ReleaseOutput) {
+    if output.changeset_owners.is_empty() {
+        return;
+    }
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for path in &output.changesets_consumed {
+        let owner = output
+            .changeset_owners
+            .get(path)
+            .map_or("primary directory", String::as_str);
+        *counts.entry(owner).or_default() += 1;
+    }
+    for (owner, count) in counts {
+        println!("  {owner}: {count}");
+    }
+}
+
+fn print_provenance(output: &ReleaseOutput) {
+    if output.provenance.is_empty() {
+        return;
+    }
+
+    println!("\nBump explanation:");
+    for provenance in &output.provenance {
+        let winner = provenance.winner();
+        match winner {
+            Some(w) => println!("  {} ({})", provenance.package, bump_name(w.bump_type)),
+            None => println!("  {}", provenance.package),
+        }
+        for contribution in &provenance.contributions {
+            let source = match contribution.source {
+                BumpSource::Changeset(index) => output.changesets_consumed.get(index).map_or_else(
+                    || format!("changeset #{index}"),
+                    |p| p.display().to_string(),
+                ),
+                BumpSource::Forced => "--force-bump".to_string(),
+            };
+            let marker = if winner == Some(contribution) {
+                "  <- decides bump"
+            } else {
+                ""
+            };
+            println!(
+                "    {:<5}  {source}{marker}",
+                bump_name(contribution.bump_type)
+            );
+        }
+        if let Some(transform) = &provenance.zero_version {
+            let description = match transform {
+                ZeroVersionTransform::Demoted { from, to } => {
+                    format!("{} demoted to {}", bump_name(*from), bump_name(*to))
+                }
+                ZeroVersionTransform::PromotedToStable => "major promoted to 1.0.0".to_string(),
+                ZeroVersionTransform::Graduated => "graduated to 1.0.0".to_string(),
+            };
+            println!("    0.x: {description}");
+        }
+        if let Some(prerelease) = &provenance.prerelease {
+            let source = match prerelease.source {
+                PrereleaseSource::Cli => "--prerelease",
+                PrereleaseSource::CliGlobal => "--prerelease (all packages)",
+                PrereleaseSource::Toml => "pre-release.toml",
+                PrereleaseSource::Channel => "release channel",
+            };
+            println!("    prerelease: {} (from {source})", prerelease.tag);
+        }
+    }
+}
+
+fn bump_name(bump: BumpType) -> &'static str {
+    match bump {
+        BumpType::Major => "major",
+        BumpType::Minor => "minor",
+        BumpType::Patch => "patch",
+    }
+}
+
+fn print_step_timings(timings: &[StepTiming]) {
+    let width = timings
+        .iter()
+        .map(|t| t.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("total".len());
+
+    println!("\nTimings:");
+    for timing in timings {
+        println!(
+            "  {:<width$}  {}",
+            timing.name,
+            format_duration(timing.duration)
+        );
+    }
+    let total: Duration = timings.iter().map(|t| t.duration).sum();
+    println!("  {:<width$}  {}", "total", format_duration(total));
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:>9.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
 fn print_git_result(git_result: &GitOperationResult) {
-    if let Some(commit) = &git_result.commit {
+    if !git_result.release_commits.is_empty() {
+        println!("\nCommits created:");
+        for release_commit in &git_result.release_commits {
+            let sha = &release_commit.commit.sha;
+            println!(
+                "  - {} ({})",
+                &sha[..7.min(sha.len())],
+                release_commit.packages.join(", ")
+            );
+        }
+    } else if let Some(commit) = &git_result.commit {
         println!(
             "\nCommit created: {}",
             &commit.sha[..7.min(commit.sha.len())]
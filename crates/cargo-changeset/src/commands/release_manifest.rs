@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use changeset_operations::operations::{RELEASE_MANIFEST_FILENAME, ReleaseManifest};
+use changeset_operations::traits::ProjectProvider;
+
+use crate::config_overrides::project_provider;
+use crate::error::{CliError, Result};
+
+/// Resolves a `--flag [PATH]` argument: `None` when the flag is absent, the
+/// given path, or `file_name` in the changeset directory when no path is given.
+pub(super) fn resolve_changeset_file(
+    arg: Option<&str>,
+    start_path: &Path,
+    file_name: &str,
+) -> Result<Option<PathBuf>> {
+    let Some(path) = arg else {
+        return Ok(None);
+    };
+
+    if !path.is_empty() {
+        return Ok(Some(PathBuf::from(path)));
+    }
+
+    changeset_file(start_path, file_name).map(Some)
+}
+
+/// `file_name` in the project's changeset directory.
+pub(super) fn changeset_file(start_path: &Path, file_name: &str) -> Result<PathBuf> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    Ok(project
+        .root
+        .join(root_config.changeset_dir())
+        .join(file_name))
+}
+
+pub(super) fn resolve_manifest_path(
+    arg: Option<&str>,
+    start_path: &Path,
+) -> Result<Option<PathBuf>> {
+    resolve_changeset_file(arg, start_path, RELEASE_MANIFEST_FILENAME)
+}
+
+pub(super) fn load_manifest(path: &Path) -> Result<ReleaseManifest> {
+    let content = fs::read_to_string(path).map_err(|source| CliError::ReleaseManifestRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_str(&content).map_err(|source| CliError::ReleaseManifestParse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub(super) fn save_manifest(path: &Path, manifest: &ReleaseManifest) -> Result<()> {
+    serde_json::to_string_pretty(manifest)
+        .map_err(std::io::Error::other)
+        .and_then(|content| fs::write(path, content + "\n"))
+        .map_err(|source| CliError::ReleaseManifestWrite {
+            path: path.to_path_buf(),
+            source,
+        })
+}
@@ -0,0 +1,256 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+use changeset_operations::operations::{
+    CONFIDENTIAL_PLACEHOLDER, StatusOperation, StatusOutput, tagged_releases,
+};
+use changeset_operations::providers::{
+    FileSystemManifestWriter, FileSystemReleaseStateIO, Git2Provider, changeset_io_for,
+};
+use changeset_operations::traits::{GitProvider, ProjectProvider};
+use chrono::Local;
+use serde_json::{Value, json};
+
+use super::ServeArgs;
+use crate::config_overrides::project_provider;
+use crate::error::{CliError, Result};
+
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest request line or header line accepted, in bytes.
+const MAX_REQUEST_LINE: u64 = 8 * 1024;
+/// Most bytes of a rejected request read before closing the connection.
+const MAX_DISCARDED: u64 = 64 * 1024;
+
+pub(crate) fn run(args: ServeArgs, start_path: &Path) -> Result<()> {
+    let project_root = project_provider().discover_project(start_path)?.root;
+
+    let listener = TcpListener::bind(args.http).map_err(|source| CliError::ServeBind {
+        addr: args.http,
+        source,
+    })?;
+    println!("Serving release data on http://{}", listener.local_addr()?);
+    std::io::stdout().flush()?;
+
+    for stream in listener.incoming() {
+        let result = stream.and_then(|stream| handle_connection(stream, &project_root));
+        if let Err(e) = result {
+            eprintln!("warning: failed to handle request: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream, project_root: &Path) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+
+    let Some(request_line) = read_request(&mut reader)? else {
+        let body = json!({ "error": "request line or header too long" });
+        respond(&stream, 431, &body, true)?;
+        // Read the rest of the request, so that closing the connection does
+        // not reset it before the client has read the response.
+        stream.shutdown(Shutdown::Write)?;
+        let _ = std::io::copy(&mut reader.take(MAX_DISCARDED), &mut std::io::sink());
+        return Ok(());
+    };
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split_once('?').map_or(target, |(path, _)| path);
+
+    let (status, body) = if method == "GET" || method == "HEAD" {
+        route(path, project_root)
+    } else {
+        (405, json!({ "error": "method not allowed" }))
+    };
+
+    respond(&stream, status, &body, method != "HEAD")
+}
+
+/// Reads the request line and skips the headers. Returns `None` when a line
+/// is longer than [`MAX_REQUEST_LINE`].
+fn read_request(reader: &mut impl BufRead) -> std::io::Result<Option<String>> {
+    let mut request_line = String::new();
+    if !read_limited_line(reader, &mut request_line)? {
+        return Ok(None);
+    }
+    let mut header = String::new();
+    loop {
+        header.clear();
+        if !read_limited_line(reader, &mut header)? {
+            return Ok(None);
+        }
+        if header.trim().is_empty() {
+            return Ok(Some(request_line));
+        }
+    }
+}
+
+/// Reads one line of at most [`MAX_REQUEST_LINE`] bytes, returning whether it
+/// fit.
+fn read_limited_line(reader: &mut impl BufRead, line: &mut String) -> std::io::Result<bool> {
+    let read = reader.by_ref().take(MAX_REQUEST_LINE).read_line(line)?;
+    Ok(read < MAX_REQUEST_LINE as usize || line.ends_with('\n'))
+}
+
+fn respond(
+    mut writer: &TcpStream,
+    status: u16,
+    body: &Value,
+    with_body: bool,
+) -> std::io::Result<()> {
+    let body = body.to_string();
+    let reason = match status {
+        200 => "OK",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    };
+    write!(
+        writer,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    if with_body {
+        writer.write_all(body.as_bytes())?;
+    }
+    writer.flush()
+}
+
+fn route(path: &str, project_root: &Path) -> (u16, Value) {
+    let result = match path.trim_end_matches('/') {
+        "" => Ok(json!({
+            "endpoints": ["/summary", "/changesets", "/versions", "/releases"],
+        })),
+        "/summary" => load_status(project_root).map(|status| summary_json(&status)),
+        "/changesets" => {
+            load_status(project_root).map(|status| changesets_json(&status, project_root))
+        }
+        "/versions" => load_status(project_root).map(|status| versions_json(&status)),
+        "/releases" => releases_json(project_root),
+        _ => return (404, json!({ "error": format!("no endpoint at '{path}'") })),
+    };
+
+    match result {
+        Ok(body) => (200, body),
+        Err(e) => (500, json!({ "error": error_chain(&e) })),
+    }
+}
+
+fn load_status(project_root: &Path) -> Result<StatusOutput> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(project_root)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let operation = StatusOperation::new(
+        project_provider,
+        changeset_io_for(&project.root, &root_config),
+        FileSystemManifestWriter::new(),
+        Git2Provider::new(),
+    )
+    .with_release_state_io(Box::new(FileSystemReleaseStateIO::new()));
+    Ok(operation.execute(project_root)?)
+}
+
+fn changesets_json(status: &StatusOutput, project_root: &Path) -> Value {
+    let today = Local::now().date_naive();
+
+    status
+        .changeset_files
+        .iter()
+        .zip(&status.changesets)
+        .map(|(path, changeset)| {
+            let summary = if changeset.is_embargoed(today) {
+                CONFIDENTIAL_PLACEHOLDER
+            } else {
+                changeset.summary.as_str()
+            };
+            json!({
+                "file": path.strip_prefix(project_root).unwrap_or(path).display().to_string(),
+                "summary": summary,
+                "category": changeset.category.id(),
+                "scope": changeset.scope,
+                "releases": changeset
+                    .releases
+                    .iter()
+                    .map(|release| json!({ "package": release.name, "bump": release.bump_type }))
+                    .collect::<Vec<_>>(),
+                "consumedForPrerelease": changeset.consumed_for_prerelease,
+                "owner": status.changeset_owners.get(path),
+            })
+        })
+        .collect()
+}
+
+fn summary_json(status: &StatusOutput) -> Value {
+    let summary = &status.summary;
+    json!({
+        "pendingChangesets": summary.pending_changesets,
+        "packagesToRelease": summary.packages_to_release,
+        "bumps": {
+            "major": summary.major,
+            "minor": summary.minor,
+            "patch": summary.patch,
+        },
+        "unchangedPackages": summary.unchanged_packages,
+        "prereleaseQueue": summary
+            .prerelease_queue
+            .iter()
+            .map(|(package, tag)| json!({ "package": package, "tag": tag }))
+            .collect::<Vec<_>>(),
+        "graduationQueue": summary.graduation_queue,
+    })
+}
+
+fn versions_json(status: &StatusOutput) -> Value {
+    status
+        .projected_releases
+        .iter()
+        .map(|release| {
+            json!({
+                "package": release.name,
+                "currentVersion": release.current_version.to_string(),
+                "newVersion": release.new_version.to_string(),
+                "bump": release.bump_type,
+            })
+        })
+        .collect()
+}
+
+fn releases_json(project_root: &Path) -> Result<Value> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(project_root)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let tags = Git2Provider::new().list_tags(&project.root)?;
+
+    Ok(tagged_releases(
+        &project,
+        root_config.git_config(),
+        root_config.channels(),
+        &tags,
+    )
+    .into_iter()
+    .map(|release| {
+        json!({
+            "package": release.package,
+            "version": release.version.to_string(),
+            "tag": release.tag,
+        })
+    })
+    .collect())
+}
+
+fn error_chain(error: &CliError) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
@@ -1,21 +1,32 @@
 use std::path::Path;
 
-use changeset_operations::operations::StatusOperation;
+use changeset_operations::operations::{RetrospectiveOperation, StatusOperation};
 use changeset_operations::providers::{
-    FileSystemChangesetIO, FileSystemManifestWriter, FileSystemProjectProvider,
+    FileSystemManifestWriter, FileSystemReleaseStateIO, FileSystemTextFileIO, Git2Provider,
+    changeset_io_for,
 };
 use changeset_operations::traits::ProjectProvider;
 
+use crate::config_overrides::project_provider;
 use crate::error::Result;
 use crate::output::{PlainTextStatusFormatter, StatusFormatter};
 
 pub(crate) fn run(start_path: &Path) -> Result<()> {
-    let project_provider = FileSystemProjectProvider::new();
+    let project_provider = project_provider();
     let project = project_provider.discover_project(start_path)?;
-    let changeset_reader = FileSystemChangesetIO::new(&project.root);
+    let (root_config, _) = project_provider.load_configs(&project)?;
+    let changeset_reader = changeset_io_for(&project.root, &root_config);
     let inherited_checker = FileSystemManifestWriter::new();
 
-    let operation = StatusOperation::new(project_provider, changeset_reader, inherited_checker);
+    let git_provider = Git2Provider::new();
+
+    let operation = StatusOperation::new(
+        project_provider,
+        changeset_reader,
+        inherited_checker,
+        git_provider,
+    )
+    .with_release_state_io(Box::new(FileSystemReleaseStateIO::new()));
     let output = operation.execute(start_path)?;
 
     let formatter = PlainTextStatusFormatter;
@@ -23,3 +34,18 @@ pub(crate) fn run(start_path: &Path) -> Result<()> {
 
     Ok(())
 }
+
+/// Shows what shipped between `since` and HEAD.
+pub(crate) fn run_since(since: &str, start_path: &Path) -> Result<()> {
+    let operation = RetrospectiveOperation::new(
+        project_provider(),
+        Git2Provider::new(),
+        FileSystemTextFileIO::new(),
+    );
+    let output = operation.execute(start_path, since)?;
+
+    let formatter = PlainTextStatusFormatter;
+    print!("{}", formatter.format_retrospective(since, &output));
+
+    Ok(())
+}
@@ -1,37 +1,307 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-use changeset_operations::operations::{VerifyInput, VerifyOperation, VerifyOutcome};
+use changeset_changelog::{ChangelogLocation, validate_keep_a_changelog};
+use changeset_core::unified_diff;
+use changeset_operations::OperationError;
+use changeset_operations::operations::{
+    DirtyTreeAction, ReleaseInput, ReleaseOperation, ReleaseOutcome, StepControl, VerifyInput,
+    VerifyOperation, VerifyOutcome, render_changelog_preview,
+};
 use changeset_operations::providers::{
-    FileSystemChangesetIO, FileSystemProjectProvider, Git2Provider,
+    FileSystemChangelogWriter, FileSystemChangesetIO, FileSystemManifestWriter,
+    FileSystemReleaseStateIO, Git2Provider, GitHubChangedFilesProvider, github_token_provider,
 };
-use changeset_operations::traits::ProjectProvider;
+use changeset_operations::traits::{ProjectProvider, TokenProvider};
+use changeset_operations::verification::{MissingChangeset, VerificationResult};
+use changeset_project::{CargoProject, OfflineBehavior};
+use serde::Serialize;
 
 use super::VerifyArgs;
+use super::add::bump_inference_chain;
+use crate::config_overrides::project_provider;
 use crate::error::{CliError, Result};
-use crate::output::{OutputFormatter, PlainTextFormatter};
+use crate::output::{OutputFormat, OutputFormatter, PlainTextFormatter};
+
+/// Pull requests with this label need a security changeset that names its advisory.
+const SECURITY_LABEL: &str = "security";
+
+pub(crate) fn run(
+    args: VerifyArgs,
+    start_path: &Path,
+    offline: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    if args.changelog {
+        return verify_changelog(&args, start_path);
+    }
+    if args.changelog_format {
+        return verify_changelog_format(args.quiet, start_path);
+    }
 
-pub(crate) fn run(args: VerifyArgs, start_path: &Path) -> Result<()> {
-    let project_provider = FileSystemProjectProvider::new();
+    let project_provider = project_provider();
     let project = project_provider.discover_project(start_path)?;
+    if !args.quiet {
+        warn_missing_members(&project);
+    }
+
+    let offline_behavior = if offline {
+        Some(
+            project_provider
+                .load_configs(&project)?
+                .0
+                .offline_behavior(),
+        )
+    } else {
+        None
+    };
 
     let git_provider = Git2Provider::new();
     let changeset_reader = FileSystemChangesetIO::new(&project.root);
 
-    let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+    let mut operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+    // In a pull request workflow, ask GitHub for the PR's files so shallow clones work.
+    // An explicit --head means the user wants a local diff.
+    let github = args
+        .head
+        .is_none()
+        .then(|| GitHubChangedFilesProvider::from_env(&project.root))
+        .flatten();
+    let require_advisory = args
+        .labels
+        .iter()
+        .chain(github.iter().flat_map(|github| github.labels()))
+        .any(|label| label.eq_ignore_ascii_case(SECURITY_LABEL));
+
+    if let Some(github) = github {
+        match offline_behavior {
+            None => {
+                let github = match github_token_provider().token()? {
+                    Some(token) => github.with_token(token),
+                    None => github,
+                };
+                operation = operation.with_changed_files_provider(Box::new(github));
+            }
+            Some(OfflineBehavior::Skip) => {}
+            Some(OfflineBehavior::Error) => {
+                return Err(OperationError::OfflineNetworkRequired {
+                    step: "fetching the pull request's files from GitHub",
+                }
+                .into());
+            }
+        }
+    }
+
+    let inference = bump_inference_chain(&args.infer_bump, &args.base);
+    if !inference.is_empty() {
+        operation = operation.with_bump_inference(Box::new(inference));
+    }
 
     let input = VerifyInput {
         base: args.base,
         head: args.head,
         allow_deleted_changesets: args.allow_deleted_changesets,
+        staged: false,
+        require_advisory,
     };
 
     let outcome = operation.execute(start_path, &input)?;
 
+    report_outcome(outcome, args.quiet, output)
+}
+
+/// Members absent from a sparse checkout are skipped; changes under them need no changeset here.
+fn warn_missing_members(project: &CargoProject) {
+    for member in &project.missing_members {
+        let display = member.strip_prefix(&project.root).unwrap_or(member);
+        eprintln!(
+            "warning: workspace member '{}' is not checked out; skipping",
+            display.display()
+        );
+    }
+}
+
+/// Renders the next changelog twice and optionally checks it against a snapshot.
+fn verify_changelog(args: &VerifyArgs, start_path: &Path) -> Result<()> {
+    let first = render_next_changelog(start_path)?;
+    let second = render_next_changelog(start_path)?;
+    if first != second {
+        if !args.quiet {
+            eprint!(
+                "{}",
+                unified_diff(&first, &second, "first run", "second run")
+            );
+        }
+        return Err(CliError::ChangelogNondeterministic);
+    }
+
+    if let Some(path) = &args.snapshot {
+        let snapshot_error = |source| CliError::ChangelogSnapshotIo {
+            path: path.clone(),
+            source,
+        };
+        if args.update_snapshot {
+            fs::write(path, &first).map_err(snapshot_error)?;
+            if !args.quiet {
+                println!("Updated changelog snapshot {}", path.display());
+            }
+            return Ok(());
+        }
+
+        let expected = fs::read_to_string(path).map_err(snapshot_error)?;
+        if expected != first {
+            if !args.quiet {
+                eprint!(
+                    "{}",
+                    unified_diff(&expected, &first, "snapshot", "rendered")
+                );
+            }
+            return Err(CliError::ChangelogSnapshotMismatch { path: path.clone() });
+        }
+    }
+
+    if !args.quiet {
+        println!("Changelog renders deterministically");
+    }
+    Ok(())
+}
+
+/// Validates every existing changelog against the Keep a Changelog structure.
+fn verify_changelog_format(quiet: bool, start_path: &Path) -> Result<()> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let (root_config, _) = project_provider.load_configs(&project)?;
+
+    let paths: Vec<PathBuf> = match root_config.changelog_config().changelog {
+        ChangelogLocation::Root => vec![project.root.join("CHANGELOG.md")],
+        ChangelogLocation::PerPackage => project
+            .packages
+            .iter()
+            .map(|package| package.path.join("CHANGELOG.md"))
+            .collect(),
+    };
+
+    let mut checked = 0;
+    let mut violation_count = 0;
+    for path in paths.iter().filter(|path| path.exists()) {
+        let content = fs::read_to_string(path).map_err(|source| CliError::ChangelogRead {
+            path: path.clone(),
+            source,
+        })?;
+        let violations = validate_keep_a_changelog(&content);
+        if !quiet {
+            let display = path.strip_prefix(&project.root).unwrap_or(path).display();
+            for violation in &violations {
+                eprintln!("{display}:{}: {}", violation.line, violation.message);
+            }
+        }
+        checked += 1;
+        violation_count += violations.len();
+    }
+
+    if violation_count > 0 {
+        return Err(CliError::ChangelogFormatViolations {
+            count: violation_count,
+        });
+    }
+    if !quiet {
+        println!("{checked} changelog(s) follow Keep a Changelog");
+    }
+    Ok(())
+}
+
+/// Renders the changelog entries the next release would add without touching any file.
+fn render_next_changelog(start_path: &Path) -> Result<String> {
+    let project_provider = project_provider();
+    let project = project_provider.discover_project(start_path)?;
+    let operation = ReleaseOperation::new(
+        project_provider,
+        FileSystemChangesetIO::new(&project.root),
+        FileSystemManifestWriter::new(),
+        FileSystemChangelogWriter::new(),
+        Git2Provider::new(),
+        FileSystemReleaseStateIO::new(),
+    );
+    let input = ReleaseInput {
+        dry_run: true,
+        convert_inherited: true,
+        no_commit: true,
+        no_tags: true,
+        keep_changesets: true,
+        force: false,
+        diff: true,
+        per_package_config: HashMap::new(),
+        global_prerelease: None,
+        graduate_all: false,
+        allow_branch: true,
+        allow_stale: true,
+        include_confidential: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::default(),
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
+    };
+
+    match operation.execute(start_path, &input)? {
+        ReleaseOutcome::DryRun(output) | ReleaseOutcome::Executed(output) => {
+            Ok(render_changelog_preview(&output))
+        }
+        ReleaseOutcome::NoChangesets => Ok(String::new()),
+    }
+}
+
+pub(super) fn report_outcome(
+    outcome: VerifyOutcome,
+    quiet: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    if output == OutputFormat::Json {
+        if !quiet {
+            print_json(&outcome)?;
+        }
+        return check_outcome(outcome, true);
+    }
+    check_outcome(outcome, quiet)
+}
+
+/// JSON form of a verification result, listing the uncovered packages with
+/// their changed files as `missing`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VerifyReport<'a> {
+    #[serde(flatten)]
+    result: &'a VerificationResult,
+    missing: Vec<MissingChangeset>,
+}
+
+/// Prints the verification result, empty when no package was affected.
+fn print_json(outcome: &VerifyOutcome) -> Result<()> {
+    let empty = VerificationResult::default();
+    let result = match outcome {
+        VerifyOutcome::Success(result) | VerifyOutcome::Failed(result) => result,
+        VerifyOutcome::NoChanges | VerifyOutcome::NoPackagesAffected { .. } => &empty,
+    };
+    let report = VerifyReport {
+        result,
+        missing: result.missing_changesets(),
+    };
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|source| CliError::VerifyOutputSerialize { source })?;
+    println!("{json}");
+    Ok(())
+}
+
+fn check_outcome(outcome: VerifyOutcome, quiet: bool) -> Result<()> {
     let formatter = PlainTextFormatter;
 
     match outcome {
         VerifyOutcome::NoChanges => {
-            if !args.quiet {
+            if !quiet {
                 println!("No files changed");
             }
             Ok(())
@@ -40,7 +310,7 @@ pub(crate) fn run(args: VerifyArgs, start_path: &Path) -> Result<()> {
             project_file_count,
             ignored_file_count,
         } => {
-            if !args.quiet {
+            if !quiet {
                 println!("No packages affected by changes");
                 if project_file_count > 0 {
                     println!("  {project_file_count} project-level file(s) changed");
@@ -52,23 +322,41 @@ pub(crate) fn run(args: VerifyArgs, start_path: &Path) -> Result<()> {
             Ok(())
         }
         VerifyOutcome::Success(result) => {
-            if !args.quiet {
+            if !quiet {
                 print!("{}", formatter.format_success(&result));
             }
             Ok(())
         }
         VerifyOutcome::Failed(result) => {
-            if !args.quiet {
+            if !quiet {
                 eprint!("{}", formatter.format_failure(&result));
             }
             if !result.deleted_changesets.is_empty() {
                 Err(CliError::ChangesetDeleted {
                     paths: result.deleted_changesets,
                 })
-            } else {
+            } else if !result.consumed_changeset_edits.is_empty() {
+                Err(CliError::ConsumedChangesetEdited {
+                    paths: result.consumed_changeset_edits,
+                })
+            } else if result.name_clashes() > 0 {
+                Err(CliError::DuplicateChangesets {
+                    count: result.name_clashes(),
+                })
+            } else if !result.uncovered_packages.is_empty() {
                 Err(CliError::VerificationFailed {
                     uncovered_count: result.uncovered_packages.len(),
                 })
+            } else if result.missing_advisory {
+                Err(CliError::AdvisoryMissing)
+            } else if !result.lint_failures.is_empty() {
+                Err(CliError::SummaryLintFailed {
+                    count: result.lint_failures.len(),
+                })
+            } else {
+                Err(CliError::ScopeCheckFailed {
+                    count: result.scope_failures.len(),
+                })
             }
         }
     }
@@ -0,0 +1,22 @@
+//! Config overrides given with the global `--config KEY=VALUE` flag.
+//!
+//! They are set once at startup and applied by every project provider the
+//! commands create, so each command sees the overridden configuration.
+
+use std::sync::OnceLock;
+
+use changeset_operations::providers::FileSystemProjectProvider;
+use changeset_project::ConfigOverride;
+
+static CONFIG_OVERRIDES: OnceLock<Vec<ConfigOverride>> = OnceLock::new();
+
+/// Sets the overrides for this process. Only the first call has an effect.
+pub(crate) fn set_config_overrides(overrides: Vec<ConfigOverride>) {
+    let _ = CONFIG_OVERRIDES.set(overrides);
+}
+
+/// A file system project provider that applies the `--config` overrides.
+pub(crate) fn project_provider() -> FileSystemProjectProvider {
+    FileSystemProjectProvider::new()
+        .with_config_overrides(CONFIG_OVERRIDES.get().cloned().unwrap_or_default())
+}
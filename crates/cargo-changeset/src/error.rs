@@ -1,5 +1,7 @@
+use std::net::SocketAddr;
 use std::path::PathBuf;
 
+use changeset_operations::{ERROR_CODES, ErrorCode};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -45,12 +47,143 @@ pub enum CliError {
     )]
     ChangesetDeleted { paths: Vec<PathBuf> },
 
+    #[error("{} changeset(s) already consumed by a pre-release were edited", paths.len())]
+    ConsumedChangesetEdited { paths: Vec<PathBuf> },
+
+    #[error("{count} changeset summary(ies) failed lint")]
+    SummaryLintFailed { count: usize },
+
+    #[error("{count} changeset(s) have a missing or disallowed scope")]
+    ScopeCheckFailed { count: usize },
+
+    #[error("{count} changeset(s) share a file name with another pending changeset")]
+    DuplicateChangesets { count: usize },
+
+    #[error("security pull requests need a changeset with category 'security' and an advisory")]
+    AdvisoryMissing,
+
+    #[error("{count} release state entry(ies) were changed differently on both sides")]
+    MergeStateConflicts { count: usize },
+
+    #[error("{count} package(s) have versions that differ from their latest tag")]
+    VersionDrift { count: usize },
+
+    #[error("failed to write release summary to '{path}'")]
+    SummaryWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("changelog rendered differently on consecutive runs")]
+    ChangelogNondeterministic,
+
+    #[error("rendered changelog does not match snapshot '{path}'")]
+    ChangelogSnapshotMismatch { path: PathBuf },
+
+    #[error("failed to access changelog snapshot '{path}'")]
+    ChangelogSnapshotIo {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{count} Keep a Changelog violation(s) found")]
+    ChangelogFormatViolations { count: usize },
+
+    #[error("failed to read changelog '{path}'")]
+    ChangelogRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to read compensation journal '{path}'")]
+    JournalRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse compensation journal '{path}'")]
+    JournalParse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to write compensation journal '{path}'")]
+    JournalWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to read release manifest '{path}'")]
+    ReleaseManifestRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to serialize the verification result")]
+    VerifyOutputSerialize {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to parse changesets from stdin")]
+    ChangesetBatchParse {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to parse release manifest '{path}'")]
+    ReleaseManifestParse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to write release manifest '{path}'")]
+    ReleaseManifestWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{count} compensation(s) still failing; see '{}'", path.display())]
+    CompensationIncomplete { count: usize, path: PathBuf },
+
+    #[error("{count} published package(s) failed verification")]
+    PublishUnverified { count: usize },
+
+    #[error("no changeset-workspaces.toml found in '{start_dir}' or its parents")]
+    MultiRepoConfigNotFound { start_dir: PathBuf },
+
+    #[error("{} repository(ies) failed: {}", failed.len(), failed.join(", "))]
+    MultiRepoFailed { failed: Vec<String> },
+
+    #[error("failed to listen on '{addr}'")]
+    ServeBind {
+        addr: SocketAddr,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("invalid prerelease tag '{tag}'")]
-    InvalidPrereleaseTag { tag: String },
+    InvalidPrereleaseTag {
+        tag: String,
+        #[source]
+        source: changeset_core::PrereleaseSpecParseError,
+    },
 
     #[error("invalid pre-release format '{input}' (expected 'crate:tag')")]
     InvalidPrereleaseFormat { input: String },
 
+    #[error("invalid version stamp '{input}' (expected 'glob=pattern')")]
+    InvalidStampFormat { input: String },
+
     #[error("package '{name}' not found in workspace")]
     PackageNotFound { name: String },
 
@@ -59,16 +192,56 @@ pub enum CliError {
 
     #[error("cannot graduate package '{package}' with stable version '{version}' (>= 1.0.0)")]
     CannotGraduateStable { package: String, version: String },
+
+    #[error("unknown error code '{code}'; run `cargo changeset explain` to list all codes")]
+    UnknownErrorCode { code: String },
 }
 
 pub type Result<T> = std::result::Result<T, CliError>;
 
+impl CliError {
+    /// The stable code of operation errors and failed verifications.
+    #[must_use]
+    pub(crate) fn code(&self) -> Option<&'static ErrorCode> {
+        let index = match self {
+            Self::Operation(error) => return Some(error.code()),
+            Self::VerificationFailed { .. } => 86,
+            Self::ChangesetDeleted { .. } => 87,
+            Self::ConsumedChangesetEdited { .. } => 88,
+            Self::DuplicateChangesets { .. } => 89,
+            Self::AdvisoryMissing => 90,
+            Self::SummaryLintFailed { .. } => 91,
+            Self::ScopeCheckFailed { .. } => 92,
+            _ => return None,
+        };
+        Some(&ERROR_CODES[index])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
     use super::CliError;
 
+    #[test]
+    fn verification_failures_have_codes() {
+        let errors = [
+            CliError::VerificationFailed { uncovered_count: 1 },
+            CliError::ChangesetDeleted { paths: Vec::new() },
+            CliError::ConsumedChangesetEdited { paths: Vec::new() },
+            CliError::DuplicateChangesets { count: 1 },
+            CliError::AdvisoryMissing,
+            CliError::SummaryLintFailed { count: 1 },
+            CliError::ScopeCheckFailed { count: 1 },
+        ];
+
+        for error in &errors {
+            let code = error.code().expect("verification failures have a code");
+            assert!(format!("{error:?}").starts_with(code.name), "{error:?}");
+        }
+    }
+
     #[test]
     fn io_error_converts_via_from() {
         let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "test");
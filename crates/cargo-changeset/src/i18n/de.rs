@@ -0,0 +1,380 @@
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    ("caused-by", "verursacht durch: {cause}"),
+    (
+        "explain-hint",
+        "Weitere Informationen zu diesem Fehler liefert `cargo changeset explain {code}`.",
+    ),
+    (
+        "interactive.ci",
+        "Eingabe erforderlich, aber die Ausführung erfolgt in einer CI-Umgebung (erkannt über ${env_var})",
+    ),
+    (
+        "interactive.disabled",
+        "interaktiver Modus über CARGO_CHANGESET_NO_TTY deaktiviert",
+    ),
+    (
+        "interactive.no-terminal",
+        "der interaktive Modus benötigt ein Terminal",
+    ),
+    (
+        "interactive.hint",
+        "Um diesen Befehl nicht-interaktiv zu verwenden, gib Folgendes an:",
+    ),
+    (
+        "interactive.hint-package",
+        "  --package <PACKAGE>    Einzubeziehende Pakete",
+    ),
+    (
+        "interactive.hint-bump",
+        "  --bump <TYPE>          Art der Versionserhöhung: major, minor oder patch",
+    ),
+    (
+        "interactive.hint-message",
+        "  -m <MESSAGE>           Beschreibung der Änderung",
+    ),
+    ("interactive.example", "Beispiel:"),
+    (
+        "interactive.example-command",
+        "  cargo changeset add --package my-crate --bump minor -m \"Neue Funktion\"",
+    ),
+    (
+        "saga.failed-at-step",
+        "Fehler: Release im Schritt '{step}' fehlgeschlagen",
+    ),
+    (
+        "saga.rollback-completed",
+        "Rollback erfolgreich abgeschlossen.",
+    ),
+    (
+        "saga.workspace-restored",
+        "Dein Arbeitsbereich wurde in den ursprünglichen Zustand zurückversetzt.",
+    ),
+    (
+        "saga.rollback-partial",
+        "Rollback teilweise fehlgeschlagen ({count} Kompensation(en) fehlgeschlagen):",
+    ),
+    ("saga.compensation-error", "Fehler: {error}"),
+    (
+        "saga.inconsistent",
+        "WARNUNG: Dein Arbeitsbereich ist möglicherweise in einem inkonsistenten Zustand.",
+    ),
+    (
+        "saga.retry-compensate",
+        "Führe `cargo changeset compensate` aus, um die fehlgeschlagenen Kompensationen zu wiederholen.",
+    ),
+    (
+        "saga.manual-cleanup",
+        "Möglicherweise ist manuelles Aufräumen erforderlich.",
+    ),
+    ("op.project", "Projektfehler"),
+    ("op.cancelled", "Vorgang vom Benutzer abgebrochen"),
+    (
+        "op.empty-project",
+        "keine Pakete im Projekt unter '{path}' gefunden",
+    ),
+    (
+        "op.unknown-package",
+        "unbekanntes Paket '{name}'{hint} (verfügbar: {available})",
+    ),
+    ("op.did-you-mean", "; meintest du {names}?"),
+    (
+        "op.package-required",
+        "in einem Workspace ist ein Paket erforderlich; verwende --package <name> (verfügbar: {available})",
+    ),
+    (
+        "op.unknown-category",
+        "unbekannte Änderungskategorie '{category}' (verfügbar: {available})",
+    ),
+    (
+        "op.unknown-scope",
+        "unbekannter Scope '{scope}' (erlaubt: {allowed})",
+    ),
+    (
+        "op.unknown-changeset-owner",
+        "kein Changeset-Verzeichnis gehört '{owner}' (Besitzer: {available})",
+    ),
+    (
+        "op.no-owned-packages",
+        "'{owner}' besitzt laut `owners`-Tabelle und CODEOWNERS keine Pakete",
+    ),
+    (
+        "op.missing-scope",
+        "ein Scope ist erforderlich; verwende --scope <scope>",
+    ),
+    (
+        "op.missing-bump-type",
+        "Art der Versionserhöhung für Paket '{package}' fehlt (verwende --bump oder --package-bump)",
+    ),
+    (
+        "op.missing-description",
+        "Beschreibung fehlt (verwende -m oder gib sie interaktiv ein)",
+    ),
+    (
+        "op.empty-description",
+        "die Beschreibung darf nicht leer sein",
+    ),
+    ("op.no-packages-selected", "keine Pakete ausgewählt"),
+    (
+        "op.comparison-links-required",
+        "Vergleichslinks sind aktiviert, aber keine Repository-URL ist verfügbar",
+    ),
+    (
+        "op.dirty-working-tree",
+        "das Arbeitsverzeichnis enthält nicht committete Änderungen; committe oder stashe sie, oder verwende --on-dirty oder --no-commit",
+    ),
+    (
+        "op.stash-restore-failed",
+        "die für das Release gestashten Änderungen konnten nicht wiederhergestellt werden; stelle sie mit `git stash pop` wieder her",
+    ),
+    (
+        "op.not-on-release-branch",
+        "Releases müssen vom Branch '{expected}' erstellt werden, der aktuelle Branch ist aber '{current}'; verwende --allow-branch, um dies zu übergehen",
+    ),
+    (
+        "op.branch-behind-remote",
+        "der lokale Branch liegt hinter seinem Remote zurück; hole die neuesten Änderungen oder verwende --allow-stale, um dies zu übergehen",
+    ),
+    (
+        "op.offline-network-required",
+        "{step} benötigt Netzwerkzugriff, aber --offline ist gesetzt; setze offline-behavior = \"skip\", um den Schritt zu überspringen",
+    ),
+    (
+        "op.no-release-to-amend",
+        "HEAD hat keine Release-Tags; es gibt kein Release zum Ergänzen",
+    ),
+    (
+        "op.release-already-pushed",
+        "der Release-Commit oder seine Tags liegen bereits auf dem Remote; ihn zu ändern würde veröffentlichte Historie umschreiben",
+    ),
+    (
+        "op.prerelease-tag-required",
+        "die aktuelle Version ist stabil; gib ein Pre-Release-Tag an: --prerelease <tag>",
+    ),
+    (
+        "op.no-changesets-without-force",
+        "keine Changesets gefunden; verwende --force, um ohne Changesets zu releasen",
+    ),
+    (
+        "op.hook-exists",
+        "Git-Hook existiert bereits unter '{path}' (verwende --force zum Überschreiben)",
+    ),
+    ("op.validation-failed", "Release-Validierung fehlgeschlagen"),
+    (
+        "op.version-calculation",
+        "Versionsberechnung fehlgeschlagen",
+    ),
+    (
+        "op.changeset-file-read",
+        "Changeset-Datei '{path}' konnte nicht gelesen werden",
+    ),
+    (
+        "op.changeset-parse",
+        "Changeset-Datei '{path}' konnte nicht geparst werden",
+    ),
+    (
+        "op.changeset-file-write",
+        "Changeset-Datei konnte nicht geschrieben werden",
+    ),
+    (
+        "op.changeset-list",
+        "Changeset-Dateien in '{path}' konnten nicht aufgelistet werden",
+    ),
+    (
+        "op.changeset-category-not-configured",
+        "Changeset '{path}' verwendet die unbekannte Kategorie '{category}' (verfügbar: {available})",
+    ),
+    (
+        "op.summary-lint",
+        "die Changeset-Zusammenfassung hat die Prüfung nicht bestanden: {issues}",
+    ),
+    (
+        "op.interaction-required",
+        "Eingabe erforderlich, aber es wurde keine Eingabe geliefert",
+    ),
+    ("op.io", "E/A-Fehler"),
+    (
+        "op.inherited-versions-require-convert",
+        "Pakete mit geerbten Versionen erfordern --convert: {packages}",
+    ),
+    (
+        "op.deprecated-config-keys",
+        "die Konfiguration verwendet veraltete Optionsnamen: {keys}; führe `cargo changeset init --upgrade` aus, um sie umzubenennen",
+    ),
+    (
+        "op.amend-package-not-released",
+        "Changeset '{path}' betrifft '{package}', das nicht Teil des Releases an HEAD ist",
+    ),
+    (
+        "op.amend-bump-exceeds-release",
+        "die nachgereichten Changesets erfordern Version {required} von '{package}', höher als die veröffentlichte {version}; veröffentliche sie stattdessen als neue Version",
+    ),
+    (
+        "op.release-section-not-found",
+        "das Changelog '{path}' hat keinen Abschnitt für Version {version}",
+    ),
+    (
+        "op.hook-write",
+        "Git-Hook '{path}' konnte nicht geschrieben werden",
+    ),
+    (
+        "op.notification-failed",
+        "die Release-Benachrichtigung konnte nicht gesendet werden",
+    ),
+    (
+        "op.github-api-request",
+        "die Dateien des Pull Requests konnten nicht von GitHub abgerufen werden",
+    ),
+    (
+        "op.github-api-response",
+        "die Dateien des Pull Requests von GitHub konnten nicht geparst werden",
+    ),
+    (
+        "op.oidc-token-request",
+        "es konnte kein OIDC-Token von GitHub Actions angefordert werden",
+    ),
+    (
+        "op.oidc-token-response",
+        "das OIDC-Token von GitHub Actions konnte nicht geparst werden",
+    ),
+    (
+        "op.no-registry-credentials",
+        "CARGO_REGISTRY_TOKEN ist nicht gesetzt und der Job kann kein OIDC-Token für Trusted Publishing anfordern",
+    ),
+    (
+        "op.trusted-publishing-unavailable",
+        "Trusted Publishing funktioniert nur in GitHub Actions",
+    ),
+    (
+        "op.trusted-publishing-exchange",
+        "crates.io hat den Tokenaustausch für Trusted Publishing abgelehnt",
+    ),
+    (
+        "op.trusted-publishing-response",
+        "das Trusted-Publishing-Token von crates.io konnte nicht geparst werden",
+    ),
+    (
+        "op.trusted-publishing-revoke",
+        "das Trusted-Publishing-Token konnte nicht widerrufen werden",
+    ),
+    (
+        "op.publish-failed",
+        "das Veröffentlichen von '{package}' ist fehlgeschlagen ({status})\n{output}",
+    ),
+    (
+        "op.registry-index-request",
+        "der Registry-Index für '{package}' konnte nicht abgefragt werden",
+    ),
+    (
+        "op.release-manifest-mismatch",
+        "das Release-Manifest führt {package} {manifest_version}, der Workspace hat aber Version {workspace_version}",
+    ),
+    (
+        "op.release-manifest-package-missing",
+        "das Release-Manifest führt {package} {manifest_version}, der Workspace enthält dieses Paket aber nicht",
+    ),
+    (
+        "op.json-changelog-not-configured",
+        "Changelog-Seiten werden aus CHANGELOG.json erstellt, das nicht aktiviert ist",
+    ),
+    (
+        "op.not-a-workspace",
+        "'{path}' ist ein einzelnes Paket; neue Crates können nur einem Workspace hinzugefügt werden",
+    ),
+    (
+        "op.invalid-crate-name",
+        "ungültiger Crate-Name '{name}': {reason}",
+    ),
+    (
+        "op.crate-exists",
+        "Crate '{name}' kann nicht erstellt werden: '{path}' existiert bereits",
+    ),
+    (
+        "op.version-not-bumped",
+        "'{package}' hat Version {version}, die nicht über dem letzten Tag {tagged} liegt; erhöhe die Version in Cargo.toml vor einem reinen Tag-Release",
+    ),
+    (
+        "op.changeset-outside-selection",
+        "Changeset '{path}' veröffentlicht auch '{package}', das nicht ausgewählt ist; füge es zu --package hinzu oder veröffentliche alle Pakete",
+    ),
+    (
+        "op.unknown-channel",
+        "unbekannter Release-Kanal '{name}' für '{package}' (verfügbar: {available})",
+    ),
+    (
+        "op.invalid-search-pattern",
+        "ungültiges Suchmuster '{pattern}': {reason}",
+    ),
+    (
+        "op.keyring-access",
+        "das Token '{service}' konnte nicht aus dem Schlüsselbund des Betriebssystems gelesen werden",
+    ),
+    (
+        "op.trace-export-failed",
+        "der Release-Trace konnte nicht exportiert werden",
+    ),
+    (
+        "op.api-diff",
+        "der Vergleich der öffentlichen API für '{package}' ist fehlgeschlagen: {reason}",
+    ),
+    (
+        "op.command-spawn",
+        "der Befehl '{command}' konnte nicht gestartet werden",
+    ),
+    (
+        "op.pre-check-failed",
+        "die Prüfung vor dem Release '{command}' ist fehlgeschlagen ({status})\n{output}",
+    ),
+    (
+        "op.stamp-glob",
+        "ungültiges Glob-Muster für Versionsstempel '{pattern}'",
+    ),
+    (
+        "op.stamp-pattern",
+        "ungültiges Muster für Versionsstempel '{pattern}': {reason}",
+    ),
+    ("op.text-file-read", "'{path}' konnte nicht gelesen werden"),
+    (
+        "op.text-file-write",
+        "'{path}' konnte nicht geschrieben werden",
+    ),
+    (
+        "op.invalid-changeset-path",
+        "ungültiger Changeset-Pfad '{path}': {reason}",
+    ),
+    (
+        "op.preview-read",
+        "'{path}' konnte für den Release-Diff nicht gelesen werden",
+    ),
+    (
+        "op.release-state-read",
+        "die Release-Statusdatei '{path}' konnte nicht gelesen werden",
+    ),
+    (
+        "op.release-state-write",
+        "die Release-Statusdatei '{path}' konnte nicht geschrieben werden",
+    ),
+    (
+        "op.release-state-parse",
+        "die Release-Statusdatei '{path}' konnte nicht geparst werden",
+    ),
+    (
+        "op.release-state-serialize",
+        "der Release-Status für '{path}' konnte nicht serialisiert werden",
+    ),
+    (
+        "op.version-parse",
+        "Version '{version}' konnte bei {context} nicht geparst werden",
+    ),
+    (
+        "op.tag-deletion-failed",
+        "{count} Tag(s) konnten bei der Kompensation nicht gelöscht werden: {tags}",
+    ),
+    (
+        "op.saga-failed",
+        "die Release-Saga ist im Schritt '{step}' fehlgeschlagen",
+    ),
+    (
+        "op.saga-compensation-failed",
+        "die Release-Saga ist im Schritt '{step}' fehlgeschlagen und {count} Kompensation(en) sind ebenfalls fehlgeschlagen",
+    ),
+];
@@ -0,0 +1,347 @@
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    ("caused-by", "caused by: {cause}"),
+    (
+        "explain-hint",
+        "For more information about this error, run `cargo changeset explain {code}`.",
+    ),
+    (
+        "interactive.ci",
+        "interactive input required but running in CI environment (detected via ${env_var})",
+    ),
+    (
+        "interactive.disabled",
+        "interactive mode disabled via CARGO_CHANGESET_NO_TTY",
+    ),
+    (
+        "interactive.no-terminal",
+        "interactive mode requires a terminal",
+    ),
+    (
+        "interactive.hint",
+        "To use this command non-interactively, provide:",
+    ),
+    (
+        "interactive.hint-package",
+        "  --package <PACKAGE>    Specify package(s) to include",
+    ),
+    (
+        "interactive.hint-bump",
+        "  --bump <TYPE>          Bump type: major, minor, or patch",
+    ),
+    (
+        "interactive.hint-message",
+        "  -m <MESSAGE>           Change description",
+    ),
+    ("interactive.example", "Example:"),
+    (
+        "interactive.example-command",
+        "  cargo changeset add --package my-crate --bump minor -m \"Added feature\"",
+    ),
+    (
+        "saga.failed-at-step",
+        "Error: Release failed at step '{step}'",
+    ),
+    (
+        "saga.rollback-completed",
+        "Rollback completed successfully.",
+    ),
+    (
+        "saga.workspace-restored",
+        "Your workspace has been restored to its original state.",
+    ),
+    (
+        "saga.rollback-partial",
+        "Rollback partially failed ({count} compensation(s) failed):",
+    ),
+    ("saga.compensation-error", "Error: {error}"),
+    (
+        "saga.inconsistent",
+        "WARNING: Your workspace may be in an inconsistent state.",
+    ),
+    (
+        "saga.retry-compensate",
+        "Run `cargo changeset compensate` to retry the failed compensations.",
+    ),
+    ("saga.manual-cleanup", "Manual cleanup may be required."),
+    ("op.project", "project error"),
+    ("op.cancelled", "operation cancelled by user"),
+    (
+        "op.empty-project",
+        "no packages found in project at '{path}'",
+    ),
+    (
+        "op.unknown-package",
+        "unknown package '{name}'{hint} (available: {available})",
+    ),
+    ("op.did-you-mean", "; did you mean {names}?"),
+    (
+        "op.package-required",
+        "a package is required in a workspace; use --package <name> (available: {available})",
+    ),
+    (
+        "op.unknown-category",
+        "unknown change category '{category}' (available: {available})",
+    ),
+    (
+        "op.unknown-scope",
+        "unknown scope '{scope}' (allowed: {allowed})",
+    ),
+    (
+        "op.unknown-changeset-owner",
+        "no changeset directory is owned by '{owner}' (owners: {available})",
+    ),
+    (
+        "op.no-owned-packages",
+        "'{owner}' owns no packages in the `owners` table or CODEOWNERS",
+    ),
+    (
+        "op.missing-scope",
+        "a scope is required; use --scope <scope>",
+    ),
+    (
+        "op.missing-bump-type",
+        "missing bump type for package '{package}' (use --bump or --package-bump)",
+    ),
+    (
+        "op.missing-description",
+        "missing description (use -m or provide interactively)",
+    ),
+    ("op.empty-description", "description cannot be empty"),
+    ("op.no-packages-selected", "no packages selected"),
+    (
+        "op.comparison-links-required",
+        "comparison links enabled but no repository URL available",
+    ),
+    (
+        "op.dirty-working-tree",
+        "working tree has uncommitted changes; commit or stash them, or use --on-dirty or --no-commit",
+    ),
+    (
+        "op.stash-restore-failed",
+        "failed to restore the changes stashed for the release; recover them with `git stash pop`",
+    ),
+    (
+        "op.not-on-release-branch",
+        "releases must be made from branch '{expected}' but current branch is '{current}'; use --allow-branch to override",
+    ),
+    (
+        "op.branch-behind-remote",
+        "local branch is behind its remote; pull the latest changes or use --allow-stale to override",
+    ),
+    (
+        "op.offline-network-required",
+        "{step} needs network access but --offline is set; set offline-behavior = \"skip\" to skip it",
+    ),
+    (
+        "op.no-release-to-amend",
+        "HEAD has no release tags; there is no release to amend",
+    ),
+    (
+        "op.release-already-pushed",
+        "the release commit or its tags are already on the remote; amending it would rewrite published history",
+    ),
+    (
+        "op.prerelease-tag-required",
+        "current version is stable; please specify a pre-release tag: --prerelease <tag>",
+    ),
+    (
+        "op.no-changesets-without-force",
+        "no changesets found; use --force to release without changesets",
+    ),
+    (
+        "op.hook-exists",
+        "git hook already exists at '{path}' (use --force to overwrite)",
+    ),
+    ("op.validation-failed", "release validation failed"),
+    ("op.version-calculation", "version calculation failed"),
+    (
+        "op.changeset-file-read",
+        "failed to read changeset file '{path}'",
+    ),
+    (
+        "op.changeset-parse",
+        "failed to parse changeset file '{path}'",
+    ),
+    ("op.changeset-file-write", "failed to write changeset file"),
+    (
+        "op.changeset-list",
+        "failed to list changeset files in '{path}'",
+    ),
+    (
+        "op.changeset-category-not-configured",
+        "changeset '{path}' uses unknown category '{category}' (available: {available})",
+    ),
+    ("op.summary-lint", "changeset summary failed lint: {issues}"),
+    (
+        "op.interaction-required",
+        "interaction required but provider returned None",
+    ),
+    ("op.io", "IO error"),
+    (
+        "op.inherited-versions-require-convert",
+        "packages with inherited versions require --convert flag: {packages}",
+    ),
+    (
+        "op.deprecated-config-keys",
+        "configuration uses deprecated option names: {keys}; run `cargo changeset init --upgrade` to rename them",
+    ),
+    (
+        "op.amend-package-not-released",
+        "changeset '{path}' affects '{package}', which is not part of the release at HEAD",
+    ),
+    (
+        "op.amend-bump-exceeds-release",
+        "the late changesets call for version {required} of '{package}', above the released {version}; release them as a new version instead",
+    ),
+    (
+        "op.release-section-not-found",
+        "changelog '{path}' has no section for version {version}",
+    ),
+    ("op.hook-write", "failed to write git hook '{path}'"),
+    (
+        "op.notification-failed",
+        "failed to send release notification",
+    ),
+    (
+        "op.github-api-request",
+        "failed to fetch pull request files from GitHub",
+    ),
+    (
+        "op.github-api-response",
+        "failed to parse pull request files from GitHub",
+    ),
+    (
+        "op.oidc-token-request",
+        "failed to request an OIDC token from GitHub Actions",
+    ),
+    (
+        "op.oidc-token-response",
+        "failed to parse the OIDC token from GitHub Actions",
+    ),
+    (
+        "op.no-registry-credentials",
+        "no CARGO_REGISTRY_TOKEN is set and the job cannot request an OIDC token for trusted publishing",
+    ),
+    (
+        "op.trusted-publishing-unavailable",
+        "trusted publishing only works in GitHub Actions",
+    ),
+    (
+        "op.trusted-publishing-exchange",
+        "crates.io rejected the trusted publishing token exchange",
+    ),
+    (
+        "op.trusted-publishing-response",
+        "failed to parse the trusted publishing token from crates.io",
+    ),
+    (
+        "op.trusted-publishing-revoke",
+        "failed to revoke the trusted publishing token",
+    ),
+    (
+        "op.publish-failed",
+        "publishing '{package}' failed ({status})\n{output}",
+    ),
+    (
+        "op.registry-index-request",
+        "failed to query the registry index for '{package}'",
+    ),
+    (
+        "op.release-manifest-mismatch",
+        "the release manifest lists {package} {manifest_version}, but the workspace has version {workspace_version}",
+    ),
+    (
+        "op.release-manifest-package-missing",
+        "the release manifest lists {package} {manifest_version}, but the workspace has no such package",
+    ),
+    (
+        "op.json-changelog-not-configured",
+        "changelog pages are built from CHANGELOG.json, which is not enabled",
+    ),
+    (
+        "op.not-a-workspace",
+        "'{path}' is a single package; new crates can only be added to a workspace",
+    ),
+    (
+        "op.invalid-crate-name",
+        "invalid crate name '{name}': {reason}",
+    ),
+    (
+        "op.crate-exists",
+        "cannot create crate '{name}': '{path}' already exists",
+    ),
+    (
+        "op.version-not-bumped",
+        "'{package}' is at {version}, which is not above its latest tag {tagged}; bump its version in Cargo.toml before a tag-only release",
+    ),
+    (
+        "op.changeset-outside-selection",
+        "changeset '{path}' also releases '{package}', which is not selected; add it to --package or release all packages",
+    ),
+    (
+        "op.unknown-channel",
+        "unknown release channel '{name}' for '{package}' (available: {available})",
+    ),
+    (
+        "op.invalid-search-pattern",
+        "invalid search pattern '{pattern}': {reason}",
+    ),
+    (
+        "op.keyring-access",
+        "failed to read the '{service}' token from the OS keyring",
+    ),
+    ("op.trace-export-failed", "failed to export release trace"),
+    (
+        "op.api-diff",
+        "public API diff for '{package}' failed: {reason}",
+    ),
+    ("op.command-spawn", "failed to start command '{command}'"),
+    (
+        "op.pre-check-failed",
+        "pre-release check '{command}' failed ({status})\n{output}",
+    ),
+    ("op.stamp-glob", "invalid version stamp glob '{pattern}'"),
+    (
+        "op.stamp-pattern",
+        "invalid version stamp pattern '{pattern}': {reason}",
+    ),
+    ("op.text-file-read", "failed to read '{path}'"),
+    ("op.text-file-write", "failed to write '{path}'"),
+    (
+        "op.invalid-changeset-path",
+        "invalid changeset path '{path}': {reason}",
+    ),
+    (
+        "op.preview-read",
+        "failed to read '{path}' for the release diff",
+    ),
+    (
+        "op.release-state-read",
+        "failed to read release state file '{path}'",
+    ),
+    (
+        "op.release-state-write",
+        "failed to write release state file '{path}'",
+    ),
+    (
+        "op.release-state-parse",
+        "failed to parse release state file '{path}'",
+    ),
+    (
+        "op.release-state-serialize",
+        "failed to serialize release state for '{path}'",
+    ),
+    (
+        "op.version-parse",
+        "failed to parse version '{version}' during {context}",
+    ),
+    (
+        "op.tag-deletion-failed",
+        "failed to delete {count} tag(s) during compensation: {tags}",
+    ),
+    ("op.saga-failed", "release saga failed at step '{step}'"),
+    (
+        "op.saga-compensation-failed",
+        "release saga failed at step '{step}' and {count} compensation(s) also failed",
+    ),
+];
@@ -0,0 +1,189 @@
+//! Message catalog for user-facing CLI output.
+//!
+//! Messages are looked up by key in the catalog of the active locale and fall
+//! back to English when a translation is missing. Placeholders are written as
+//! `{name}` and filled in by the [`tr!`](crate::tr) macro.
+
+mod de;
+mod en;
+mod operation;
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+pub(crate) use operation::operation_message;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Locale {
+    En,
+    De,
+}
+
+impl Locale {
+    /// Parses a locale tag such as `de`, `de-AT`, or `de_DE.UTF-8`.
+    ///
+    /// Only the language is considered; unsupported languages yield `None`.
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "en" | "c" | "posix" => Some(Self::En),
+            "de" => Some(Self::De),
+            _ => None,
+        }
+    }
+
+    /// Picks the locale from `--locale`, then `LC_ALL`, `LC_MESSAGES`, and
+    /// `LANG`, using the first one that is set and non-empty.
+    pub(crate) fn detect(explicit: Option<&str>) -> Self {
+        explicit
+            .map(str::to_string)
+            .or_else(|| {
+                ["LC_ALL", "LC_MESSAGES", "LANG"]
+                    .iter()
+                    .find_map(|var| std::env::var(var).ok().filter(|value| !value.is_empty()))
+            })
+            .and_then(|tag| Self::from_tag(&tag))
+            .unwrap_or(Self::En)
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::En => en::MESSAGES,
+            Self::De => de::MESSAGES,
+        }
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Sets the locale for the rest of the process; later calls are ignored.
+pub(crate) fn set_locale(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+pub(crate) fn locale() -> Locale {
+    LOCALE.get().copied().unwrap_or(Locale::En)
+}
+
+fn lookup(locale: Locale, key: &str) -> Option<&'static str> {
+    locale
+        .catalog()
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, message)| *message)
+}
+
+/// Returns the message for `key` in `locale` with its placeholders filled in.
+pub(crate) fn message_in(locale: Locale, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let template = lookup(locale, key)
+        .or_else(|| lookup(Locale::En, key))
+        .unwrap_or(key);
+
+    let mut message = template.to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    message
+}
+
+/// Returns the message for `key` in the active locale.
+pub(crate) fn message(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    message_in(locale(), key, args)
+}
+
+/// Looks up a message in the active locale, filling in named placeholders.
+///
+/// ```ignore
+/// tr!("saga.failed-at-step", step = step)
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($key:literal) => {
+        $crate::i18n::message($key, &[])
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::message(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn placeholders(template: &str) -> BTreeSet<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn parses_locale_tags() {
+        assert_eq!(Locale::from_tag("de_DE.UTF-8"), Some(Locale::De));
+        assert_eq!(Locale::from_tag("de-AT"), Some(Locale::De));
+        assert_eq!(Locale::from_tag("en_US.UTF-8"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("C"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("fr_FR"), None);
+    }
+
+    #[test]
+    fn explicit_locale_wins() {
+        assert_eq!(Locale::detect(Some("de")), Locale::De);
+    }
+
+    #[test]
+    fn fills_placeholders() {
+        let message = message_in(
+            Locale::De,
+            "saga.failed-at-step",
+            &[("step", &"create-tags")],
+        );
+
+        assert_eq!(
+            message,
+            "Fehler: Release im Schritt 'create-tags' fehlgeschlagen"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_key_for_unknown_messages() {
+        assert_eq!(message_in(Locale::De, "no.such.key", &[]), "no.such.key");
+    }
+
+    #[test]
+    fn catalogs_have_no_duplicate_keys() {
+        for catalog in [en::MESSAGES, de::MESSAGES] {
+            let keys: BTreeSet<_> = catalog.iter().map(|(key, _)| key).collect();
+            assert_eq!(keys.len(), catalog.len());
+        }
+    }
+
+    #[test]
+    fn translations_match_english_keys_and_placeholders() {
+        for (key, translated) in de::MESSAGES {
+            let english = lookup(Locale::En, key)
+                .unwrap_or_else(|| panic!("'{key}' is translated but has no English message"));
+            assert_eq!(placeholders(english), placeholders(translated), "{key}");
+        }
+    }
+
+    #[test]
+    fn every_english_message_is_translated() {
+        for (key, _) in en::MESSAGES {
+            assert!(
+                lookup(Locale::De, key).is_some(),
+                "'{key}' has no German message"
+            );
+        }
+    }
+}
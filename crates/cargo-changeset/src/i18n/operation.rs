@@ -0,0 +1,293 @@
+use changeset_operations::OperationError;
+
+use crate::tr;
+
+/// Returns the message for an operation error in the active locale.
+///
+/// Errors wrapping another crate's error keep that error's message.
+pub(crate) fn operation_message(error: &OperationError) -> String {
+    match error {
+        OperationError::Project(_) => tr!("op.project"),
+        OperationError::Cancelled => tr!("op.cancelled"),
+        OperationError::EmptyProject(path) => tr!("op.empty-project", path = path.display()),
+        OperationError::UnknownPackage {
+            name,
+            available,
+            suggestions,
+        } => {
+            let hint = if suggestions.is_empty() {
+                String::new()
+            } else {
+                let names: Vec<String> = suggestions.iter().map(|s| format!("'{s}'")).collect();
+                tr!("op.did-you-mean", names = names.join(", "))
+            };
+            tr!(
+                "op.unknown-package",
+                name = name,
+                hint = hint,
+                available = available
+            )
+        }
+        OperationError::PackageRequired { available } => {
+            tr!("op.package-required", available = available)
+        }
+        OperationError::UnknownCategory {
+            category,
+            available,
+        } => tr!(
+            "op.unknown-category",
+            category = category,
+            available = available
+        ),
+        OperationError::UnknownScope { scope, allowed } => {
+            tr!("op.unknown-scope", scope = scope, allowed = allowed)
+        }
+        OperationError::UnknownChangesetOwner { owner, available } => {
+            tr!(
+                "op.unknown-changeset-owner",
+                owner = owner,
+                available = available
+            )
+        }
+        OperationError::NoOwnedPackages { owner } => {
+            tr!("op.no-owned-packages", owner = owner)
+        }
+        OperationError::MissingScope => tr!("op.missing-scope"),
+        OperationError::MissingBumpType { package_name } => {
+            tr!("op.missing-bump-type", package = package_name)
+        }
+        OperationError::MissingDescription => tr!("op.missing-description"),
+        OperationError::EmptyDescription => tr!("op.empty-description"),
+        OperationError::NoPackagesSelected => tr!("op.no-packages-selected"),
+        OperationError::ComparisonLinksRequired => tr!("op.comparison-links-required"),
+        OperationError::DirtyWorkingTree { .. } => tr!("op.dirty-working-tree"),
+        OperationError::StashRestoreFailed { .. } => tr!("op.stash-restore-failed"),
+        OperationError::NotOnReleaseBranch { current, expected } => tr!(
+            "op.not-on-release-branch",
+            current = current,
+            expected = expected
+        ),
+        OperationError::BranchBehindRemote => tr!("op.branch-behind-remote"),
+        OperationError::OfflineNetworkRequired { step } => {
+            tr!("op.offline-network-required", step = step)
+        }
+        OperationError::NoReleaseToAmend => tr!("op.no-release-to-amend"),
+        OperationError::ReleaseAlreadyPushed => tr!("op.release-already-pushed"),
+        OperationError::PrereleaseTagRequired => tr!("op.prerelease-tag-required"),
+        OperationError::NoChangesetsWithoutForce => tr!("op.no-changesets-without-force"),
+        OperationError::HookExists { path } => tr!("op.hook-exists", path = path.display()),
+        OperationError::ValidationFailed(_) => tr!("op.validation-failed"),
+        OperationError::VersionCalculation(_) => tr!("op.version-calculation"),
+        OperationError::ChangesetFileRead { path, .. } => {
+            tr!("op.changeset-file-read", path = path.display())
+        }
+        OperationError::ChangesetParse { path, .. } => {
+            tr!("op.changeset-parse", path = path.display())
+        }
+        OperationError::ChangesetFileWrite(_) => tr!("op.changeset-file-write"),
+        OperationError::ChangesetList { path, .. } => {
+            tr!("op.changeset-list", path = path.display())
+        }
+        OperationError::ChangesetCategoryNotConfigured {
+            path,
+            category,
+            available,
+        } => tr!(
+            "op.changeset-category-not-configured",
+            path = path.display(),
+            category = category,
+            available = available
+        ),
+        OperationError::SummaryLint { issues } => {
+            let issues: Vec<String> = issues.iter().map(ToString::to_string).collect();
+            tr!("op.summary-lint", issues = issues.join("; "))
+        }
+        OperationError::InteractionRequired => tr!("op.interaction-required"),
+        OperationError::Io(_) => tr!("op.io"),
+        OperationError::InheritedVersionsRequireConvert { packages } => tr!(
+            "op.inherited-versions-require-convert",
+            packages = packages.join(", ")
+        ),
+        OperationError::DeprecatedConfigKeys { keys } => {
+            tr!("op.deprecated-config-keys", keys = keys.join(", "))
+        }
+        OperationError::AmendPackageNotReleased { package, path } => tr!(
+            "op.amend-package-not-released",
+            path = path.display(),
+            package = package
+        ),
+        OperationError::AmendBumpExceedsRelease {
+            package,
+            version,
+            required,
+        } => tr!(
+            "op.amend-bump-exceeds-release",
+            package = package,
+            version = version,
+            required = required
+        ),
+        OperationError::ReleaseSectionNotFound { path, version } => tr!(
+            "op.release-section-not-found",
+            path = path.display(),
+            version = version
+        ),
+        OperationError::HookWrite { path, .. } => tr!("op.hook-write", path = path.display()),
+        OperationError::NotificationFailed { .. } => tr!("op.notification-failed"),
+        OperationError::GitHubApiRequest { .. } => tr!("op.github-api-request"),
+        OperationError::GitHubApiResponse { .. } => tr!("op.github-api-response"),
+        OperationError::OidcTokenRequest { .. } => tr!("op.oidc-token-request"),
+        OperationError::OidcTokenResponse { .. } => tr!("op.oidc-token-response"),
+        OperationError::NoRegistryCredentials => tr!("op.no-registry-credentials"),
+        OperationError::TrustedPublishingUnavailable => tr!("op.trusted-publishing-unavailable"),
+        OperationError::TrustedPublishingExchange { .. } => tr!("op.trusted-publishing-exchange"),
+        OperationError::TrustedPublishingResponse { .. } => tr!("op.trusted-publishing-response"),
+        OperationError::TrustedPublishingRevoke { .. } => tr!("op.trusted-publishing-revoke"),
+        OperationError::PublishFailed {
+            package,
+            status,
+            output,
+        } => tr!(
+            "op.publish-failed",
+            package = package,
+            status = status,
+            output = output
+        ),
+        OperationError::RegistryIndexRequest { package, .. } => {
+            tr!("op.registry-index-request", package = package)
+        }
+        OperationError::ReleaseManifestMismatch {
+            package,
+            manifest_version,
+            workspace_version: Some(workspace_version),
+        } => tr!(
+            "op.release-manifest-mismatch",
+            package = package,
+            manifest_version = manifest_version,
+            workspace_version = workspace_version
+        ),
+        OperationError::ReleaseManifestMismatch {
+            package,
+            manifest_version,
+            workspace_version: None,
+        } => tr!(
+            "op.release-manifest-package-missing",
+            package = package,
+            manifest_version = manifest_version
+        ),
+        OperationError::JsonChangelogNotConfigured => tr!("op.json-changelog-not-configured"),
+        OperationError::NotAWorkspace(path) => tr!("op.not-a-workspace", path = path.display()),
+        OperationError::InvalidCrateName { name, reason } => {
+            tr!("op.invalid-crate-name", name = name, reason = reason)
+        }
+        OperationError::CrateExists { name, path } => {
+            tr!("op.crate-exists", name = name, path = path.display())
+        }
+        OperationError::VersionNotBumped {
+            package,
+            version,
+            tagged,
+        } => tr!(
+            "op.version-not-bumped",
+            package = package,
+            version = version,
+            tagged = tagged
+        ),
+        OperationError::ChangesetOutsideSelection { path, package } => tr!(
+            "op.changeset-outside-selection",
+            path = path.display(),
+            package = package
+        ),
+        OperationError::UnknownChannel {
+            name,
+            package,
+            available,
+        } => tr!(
+            "op.unknown-channel",
+            name = name,
+            package = package,
+            available = available
+        ),
+        OperationError::InvalidSearchPattern { pattern, reason } => {
+            tr!(
+                "op.invalid-search-pattern",
+                pattern = pattern,
+                reason = reason
+            )
+        }
+        #[cfg(feature = "keyring")]
+        OperationError::KeyringAccess { service, .. } => {
+            tr!("op.keyring-access", service = service)
+        }
+        #[cfg(feature = "otlp")]
+        OperationError::TraceExportFailed { .. } => tr!("op.trace-export-failed"),
+        #[cfg(feature = "api-diff")]
+        OperationError::ApiDiff { package, reason } => {
+            tr!("op.api-diff", package = package, reason = reason)
+        }
+        OperationError::CommandSpawn { command, .. } => {
+            tr!("op.command-spawn", command = command)
+        }
+        OperationError::PreCheckFailed {
+            command,
+            status,
+            output,
+        } => tr!(
+            "op.pre-check-failed",
+            command = command,
+            status = status,
+            output = output
+        ),
+        OperationError::StampGlob { pattern, .. } => tr!("op.stamp-glob", pattern = pattern),
+        OperationError::StampPattern { pattern, reason } => {
+            tr!("op.stamp-pattern", pattern = pattern, reason = reason)
+        }
+        OperationError::TextFileRead { path, .. } => {
+            tr!("op.text-file-read", path = path.display())
+        }
+        OperationError::TextFileWrite { path, .. } => {
+            tr!("op.text-file-write", path = path.display())
+        }
+        OperationError::InvalidChangesetPath { path, reason } => tr!(
+            "op.invalid-changeset-path",
+            path = path.display(),
+            reason = reason
+        ),
+        OperationError::PreviewRead { path, .. } => tr!("op.preview-read", path = path.display()),
+        OperationError::ReleaseStateRead { path, .. } => {
+            tr!("op.release-state-read", path = path.display())
+        }
+        OperationError::ReleaseStateWrite { path, .. } => {
+            tr!("op.release-state-write", path = path.display())
+        }
+        OperationError::ReleaseStateParse { path, .. } => {
+            tr!("op.release-state-parse", path = path.display())
+        }
+        OperationError::ReleaseStateSerialize { path, .. } => {
+            tr!("op.release-state-serialize", path = path.display())
+        }
+        OperationError::VersionParse { version, context } => {
+            tr!("op.version-parse", version = version, context = context)
+        }
+        OperationError::TagDeletionFailed { failed_tags } => tr!(
+            "op.tag-deletion-failed",
+            count = failed_tags.len(),
+            tags = failed_tags.join(", ")
+        ),
+        OperationError::SagaFailed { step, .. } => tr!("op.saga-failed", step = step),
+        OperationError::SagaCompensationFailed {
+            step,
+            compensation_failures,
+            ..
+        } => tr!(
+            "op.saga-compensation-failed",
+            step = step,
+            count = compensation_failures.len()
+        ),
+        // Messages of the wrapped crates are not part of the catalog.
+        OperationError::Core(e) => e.to_string(),
+        OperationError::Git(e) => e.to_string(),
+        OperationError::Parse(e) => e.to_string(),
+        OperationError::Manifest(e) => e.to_string(),
+        OperationError::Changelog(e) => e.to_string(),
+    }
+}
@@ -1,18 +1,22 @@
 use std::fs;
 use std::io::Write as _;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::environment::is_interactive;
 
-use changeset_core::{BumpType, ChangeCategory, PackageInfo};
+use changeset_core::{BumpType, CategorySet, PackageInfo};
 use changeset_manifest::{ChangelogLocation, ComparisonLinks, TagFormat, ZeroVersionBehavior};
 use changeset_operations::Result;
+use changeset_operations::operations::DirtyTreeAction;
 use changeset_operations::traits::{
     BumpSelection, CategorySelection, ChangelogSettingsInput, DescriptionInput, GitSettingsInput,
-    InitInteractionProvider, InteractionProvider, PackageSelection, ProjectContext,
+    InitInteractionProvider, InteractionProvider, ManageAction, ManageInteractionProvider,
+    ManageRejection, ManageView, PackageSelection, ProjectContext, ScopeSelection,
     VersionSettingsInput,
 };
-use dialoguer::{Confirm, MultiSelect, Select};
+use changeset_project::StateFile;
+use dialoguer::{Confirm, Input, MultiSelect, Select};
 
 use crate::error::CliError;
 
@@ -55,57 +59,113 @@ impl InteractionProvider for TerminalInteractionProvider {
         }
     }
 
-    fn select_bump_type(&self, package_name: &str) -> Result<BumpSelection> {
+    fn select_bump_type(
+        &self,
+        package_name: &str,
+        suggested: Option<BumpType>,
+    ) -> Result<BumpSelection> {
         let items = [
             "patch - Bug fixes (backwards compatible)",
             "minor - New features (backwards compatible)",
             "major - Breaking changes",
         ];
+        let bumps = [BumpType::Patch, BumpType::Minor, BumpType::Major];
+
+        let suggested_index =
+            suggested.and_then(|bump| bumps.iter().position(|candidate| *candidate == bump));
+        let prompt = match suggested_index {
+            Some(index) => {
+                let name = ["patch", "minor", "major"][index];
+                format!("Select bump type for '{package_name}' (suggested: {name})")
+            }
+            None => format!("Select bump type for '{package_name}'"),
+        };
 
         let selection = Select::new()
-            .with_prompt(format!("Select bump type for '{package_name}'"))
+            .with_prompt(prompt)
             .items(items)
-            .default(0)
+            .default(suggested_index.unwrap_or_default())
             .interact_opt()
             .map_err(|e| match e {
                 dialoguer::Error::IO(io_err) => cli_to_operation_error(CliError::Io(io_err)),
             })?;
 
-        match selection {
-            Some(0) => Ok(BumpSelection::Selected(BumpType::Patch)),
-            Some(1) => Ok(BumpSelection::Selected(BumpType::Minor)),
-            Some(2) => Ok(BumpSelection::Selected(BumpType::Major)),
-            _ => Ok(BumpSelection::Cancelled),
+        match selection.and_then(|index| bumps.get(index)) {
+            Some(bump) => Ok(BumpSelection::Selected(*bump)),
+            None => Ok(BumpSelection::Cancelled),
         }
     }
 
-    fn select_category(&self) -> Result<CategorySelection> {
-        let items = [
-            "changed - General changes (default)",
-            "added - New features",
-            "fixed - Bug fixes",
-            "deprecated - Deprecated features",
-            "removed - Removed features",
-            "security - Security fixes",
-        ];
+    fn select_category(&self, categories: &CategorySet) -> Result<CategorySelection> {
+        let definitions: Vec<_> = categories.iter().collect();
+        let default_category = categories.default_category();
+        let default_index = definitions
+            .iter()
+            .position(|d| d.category == default_category)
+            .unwrap_or_default();
+        let items: Vec<String> = definitions
+            .iter()
+            .enumerate()
+            .map(|(index, d)| {
+                let suffix = if index == default_index {
+                    " (default)"
+                } else {
+                    ""
+                };
+                format!("{} - {}{suffix}", d.category.id(), d.label)
+            })
+            .collect();
 
         let selection = Select::new()
             .with_prompt("Select change category")
             .items(items)
-            .default(0)
+            .default(default_index)
             .interact_opt()
             .map_err(|e| match e {
                 dialoguer::Error::IO(io_err) => cli_to_operation_error(CliError::Io(io_err)),
             })?;
 
+        match selection.and_then(|index| definitions.get(index)) {
+            Some(definition) => Ok(CategorySelection::Selected(definition.category.clone())),
+            None => Ok(CategorySelection::Cancelled),
+        }
+    }
+
+    fn select_scope(&self, allowed: &[String], required: bool) -> Result<ScopeSelection> {
+        let to_operation_error = |e| match e {
+            dialoguer::Error::IO(io_err) => cli_to_operation_error(CliError::Io(io_err)),
+        };
+
+        if allowed.is_empty() {
+            let scope: String = Input::new()
+                .with_prompt("Scope")
+                .allow_empty(!required)
+                .interact_text()
+                .map_err(to_operation_error)?;
+            let scope = scope.trim();
+            return Ok(ScopeSelection::Selected(
+                (!scope.is_empty()).then(|| scope.to_string()),
+            ));
+        }
+
+        let mut items: Vec<&str> = allowed.iter().map(String::as_str).collect();
+        if !required {
+            items.insert(0, "(none)");
+        }
+
+        let selection = Select::new()
+            .with_prompt("Select scope")
+            .items(&items)
+            .default(0)
+            .interact_opt()
+            .map_err(to_operation_error)?;
+
         match selection {
-            Some(0) => Ok(CategorySelection::Selected(ChangeCategory::Changed)),
-            Some(1) => Ok(CategorySelection::Selected(ChangeCategory::Added)),
-            Some(2) => Ok(CategorySelection::Selected(ChangeCategory::Fixed)),
-            Some(3) => Ok(CategorySelection::Selected(ChangeCategory::Deprecated)),
-            Some(4) => Ok(CategorySelection::Selected(ChangeCategory::Removed)),
-            Some(5) => Ok(CategorySelection::Selected(ChangeCategory::Security)),
-            _ => Ok(CategorySelection::Cancelled),
+            Some(0) if !required => Ok(ScopeSelection::Selected(None)),
+            Some(index) => Ok(ScopeSelection::Selected(
+                items.get(index).map(ToString::to_string),
+            )),
+            None => Ok(ScopeSelection::Cancelled),
         }
     }
 
@@ -124,7 +184,15 @@ fn cli_to_operation_error(e: CliError) -> changeset_operations::OperationError {
     match e {
         CliError::Io(io) => OperationError::Io(io),
         CliError::NotATty => OperationError::InteractionRequired,
-        CliError::EditorFailed { source } => OperationError::Io(source),
+        CliError::EditorFailed { source }
+        | CliError::SummaryWrite { source, .. }
+        | CliError::JournalRead { source, .. }
+        | CliError::ChangelogSnapshotIo { source, .. }
+        | CliError::ChangelogRead { source, .. }
+        | CliError::JournalWrite { source, .. }
+        | CliError::ReleaseManifestRead { source, .. }
+        | CliError::ReleaseManifestWrite { source, .. }
+        | CliError::ServeBind { source, .. } => OperationError::Io(source),
         CliError::Core(e) => OperationError::Core(e),
         CliError::Git(e) => OperationError::Git(e),
         CliError::Project(e) => OperationError::Project(e),
@@ -135,10 +203,30 @@ fn cli_to_operation_error(e: CliError) -> changeset_operations::OperationError {
         | CliError::InvalidPrereleaseTag { .. }
         | CliError::VerificationFailed { .. }
         | CliError::ChangesetDeleted { .. }
+        | CliError::ConsumedChangesetEdited { .. }
+        | CliError::SummaryLintFailed { .. }
+        | CliError::ScopeCheckFailed { .. }
+        | CliError::DuplicateChangesets { .. }
+        | CliError::AdvisoryMissing
+        | CliError::VersionDrift { .. }
+        | CliError::MergeStateConflicts { .. }
+        | CliError::MultiRepoConfigNotFound { .. }
+        | CliError::MultiRepoFailed { .. }
+        | CliError::JournalParse { .. }
+        | CliError::ReleaseManifestParse { .. }
+        | CliError::ChangesetBatchParse { .. }
+        | CliError::VerifyOutputSerialize { .. }
+        | CliError::CompensationIncomplete { .. }
+        | CliError::PublishUnverified { .. }
+        | CliError::ChangelogNondeterministic
+        | CliError::ChangelogSnapshotMismatch { .. }
+        | CliError::ChangelogFormatViolations { .. }
         | CliError::InvalidPrereleaseFormat { .. }
+        | CliError::InvalidStampFormat { .. }
         | CliError::PackageNotFound { .. }
         | CliError::CannotGraduatePrerelease { .. }
-        | CliError::CannotGraduateStable { .. } => OperationError::Cancelled,
+        | CliError::CannotGraduateStable { .. }
+        | CliError::UnknownErrorCode { .. } => OperationError::Cancelled,
     }
 }
 
@@ -213,14 +301,25 @@ impl InteractionProvider for NonInteractiveProvider {
         Err(changeset_operations::OperationError::InteractionRequired)
     }
 
-    fn select_bump_type(&self, package_name: &str) -> Result<BumpSelection> {
+    fn select_bump_type(
+        &self,
+        package_name: &str,
+        _suggested: Option<BumpType>,
+    ) -> Result<BumpSelection> {
         Err(changeset_operations::OperationError::MissingBumpType {
             package_name: package_name.to_string(),
         })
     }
 
-    fn select_category(&self) -> Result<CategorySelection> {
-        Ok(CategorySelection::Selected(ChangeCategory::default()))
+    fn select_category(&self, categories: &CategorySet) -> Result<CategorySelection> {
+        Ok(CategorySelection::Selected(categories.default_category()))
+    }
+
+    fn select_scope(&self, _allowed: &[String], required: bool) -> Result<ScopeSelection> {
+        if required {
+            return Err(changeset_operations::OperationError::MissingScope);
+        }
+        Ok(ScopeSelection::Selected(None))
     }
 
     fn get_description(&self) -> Result<DescriptionInput> {
@@ -258,7 +357,7 @@ impl InitInteractionProvider for TerminalInitInteractionProvider {
         let commit = select_bool("Create git commits on release?", true)?;
         let tags = select_bool("Create git tags on release?", true)?;
         let keep_changesets = select_bool("Keep changeset files after release?", false)?;
-        let tag_format = select_tag_format(context.is_single_package)?;
+        let tag_format = select_tag_format(context)?;
 
         Ok(Some(GitSettingsInput {
             commit,
@@ -291,9 +390,9 @@ impl InitInteractionProvider for TerminalInitInteractionProvider {
         let changelog = if context.is_single_package {
             ChangelogLocation::Root
         } else {
-            select_changelog_location()?
+            select_changelog_location(context.suggestions.changelog)?
         };
-        let comparison_links = select_comparison_links()?;
+        let comparison_links = select_comparison_links(context.suggestions.comparison_links)?;
 
         Ok(Some(ChangelogSettingsInput {
             changelog,
@@ -326,6 +425,122 @@ impl InitInteractionProvider for TerminalInitInteractionProvider {
     }
 }
 
+/// Menu-driven editor for the release state files.
+pub struct TerminalManageInteractionProvider;
+
+impl TerminalManageInteractionProvider {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ManageInteractionProvider for TerminalManageInteractionProvider {
+    fn next_action(&self, view: &ManageView<'_>) -> Result<ManageAction> {
+        if !is_interactive() {
+            return Err(cli_to_operation_error(CliError::NotATty));
+        }
+
+        println!();
+        let state_file = |file: StateFile| view.changeset_dir.join(file.file_name());
+        print_state_preview(&state_file(StateFile::Prerelease), view.prerelease_toml);
+        print_state_preview(&state_file(StateFile::Graduation), view.graduation_toml);
+        println!();
+
+        let options = [
+            "Add crate to pre-release",
+            "Remove crate from pre-release",
+            "Add crate to graduation queue",
+            "Remove crate from graduation queue",
+            "Save and exit",
+            "Exit without saving",
+        ];
+
+        loop {
+            let selection = Select::new()
+                .with_prompt("What would you like to do?")
+                .items(options)
+                .default(0)
+                .interact_opt()
+                .map_err(dialoguer_to_operation_error)?;
+
+            let action = match selection {
+                Some(0) => match select_package(view.packages.iter().map(|p| p.name.as_str()))? {
+                    Some(package) => {
+                        let tag: String = Input::new()
+                            .with_prompt("Enter pre-release tag (e.g., alpha, beta, rc)")
+                            .interact_text()
+                            .map_err(dialoguer_to_operation_error)?;
+                        Some(ManageAction::AddPrerelease { package, tag })
+                    }
+                    None => None,
+                },
+                Some(1) => select_package(view.prerelease.iter().map(|(name, _)| name))?
+                    .map(|package| ManageAction::RemovePrerelease { package }),
+                Some(2) => select_package(view.packages.iter().map(|p| p.name.as_str()))?
+                    .map(|package| ManageAction::AddGraduation { package }),
+                Some(3) => select_package(view.graduation.crates())?
+                    .map(|package| ManageAction::RemoveGraduation { package }),
+                Some(4) => Some(ManageAction::Save),
+                _ => {
+                    if !view.has_unsaved_changes || select_bool("Discard unsaved changes?", false)?
+                    {
+                        Some(ManageAction::Discard)
+                    } else {
+                        None
+                    }
+                }
+            };
+
+            if let Some(action) = action {
+                return Ok(action);
+            }
+        }
+    }
+
+    fn report_rejection(&self, rejection: &ManageRejection) -> Result<()> {
+        eprintln!("error: {rejection}");
+        Ok(())
+    }
+}
+
+fn print_state_preview(file: &Path, content: Option<&str>) {
+    let file = file.display();
+    match content {
+        Some(content) => {
+            println!("{file}:");
+            for line in content.lines() {
+                println!("  {line}");
+            }
+        }
+        None => println!("{file}: (not present)"),
+    }
+}
+
+/// Asks for one of `names`; `None` when there is nothing to pick or the user cancels.
+fn select_package<'a>(names: impl Iterator<Item = &'a str>) -> Result<Option<String>> {
+    let mut items: Vec<&str> = names.collect();
+    items.sort_unstable();
+    if items.is_empty() {
+        println!("No crates to choose from.");
+        return Ok(None);
+    }
+
+    let selection = Select::new()
+        .with_prompt("Select a crate")
+        .items(&items)
+        .interact_opt()
+        .map_err(dialoguer_to_operation_error)?;
+
+    Ok(selection.map(|index| items[index].to_string()))
+}
+
+fn dialoguer_to_operation_error(e: dialoguer::Error) -> changeset_operations::OperationError {
+    match e {
+        dialoguer::Error::IO(io) => cli_to_operation_error(CliError::Io(io)),
+    }
+}
+
 fn select_bool(prompt: &str, default: bool) -> Result<bool> {
     Confirm::new()
         .with_prompt(prompt)
@@ -336,91 +551,107 @@ fn select_bool(prompt: &str, default: bool) -> Result<bool> {
         })
 }
 
-fn select_tag_format(is_single_package: bool) -> Result<TagFormat> {
-    let (items, default_idx) = if is_single_package {
-        (
-            [
-                "version-only - Tags like v1.0.0 (default)",
-                "crate-prefixed - Tags like crate-name@1.0.0",
-            ],
-            0,
-        )
-    } else {
+fn select_tag_format(context: ProjectContext) -> Result<TagFormat> {
+    let default = context
+        .suggestions
+        .tag_format
+        .unwrap_or(if context.is_single_package {
+            TagFormat::VersionOnly
+        } else {
+            TagFormat::CratePrefixed
+        });
+    let options = [
+        (TagFormat::VersionOnly, "version-only - Tags like v1.0.0"),
         (
-            [
-                "version-only - Tags like v1.0.0",
-                "crate-prefixed - Tags like crate-name@1.0.0 (default)",
-            ],
-            1,
-        )
-    };
+            TagFormat::CratePrefixed,
+            "crate-prefixed - Tags like crate-name@1.0.0",
+        ),
+    ];
 
-    let selection = Select::new()
-        .with_prompt("Select tag format")
-        .items(items)
-        .default(default_idx)
-        .interact_opt()
-        .map_err(|e| match e {
-            dialoguer::Error::IO(io) => cli_to_operation_error(CliError::Io(io)),
-        })?;
+    select_option(
+        "Select tag format",
+        &options,
+        default,
+        context.suggestions.tag_format.is_some(),
+    )
+}
 
-    match selection {
-        Some(0) => Ok(TagFormat::VersionOnly),
-        Some(1) => Ok(TagFormat::CratePrefixed),
-        _ => {
-            if is_single_package {
-                Ok(TagFormat::VersionOnly)
+/// Shows a selection of `options`, marking `default` as the default or, when
+/// it was detected from the repository, as the suggestion.
+fn select_option<T: Copy + PartialEq>(
+    prompt: &str,
+    options: &[(T, &str)],
+    default: T,
+    suggested: bool,
+) -> Result<T> {
+    let marker = if suggested { "suggested" } else { "default" };
+    let items: Vec<String> = options
+        .iter()
+        .map(|(value, label)| {
+            if *value == default {
+                format!("{label} ({marker})")
             } else {
-                Ok(TagFormat::CratePrefixed)
+                (*label).to_string()
             }
-        }
-    }
-}
-
-fn select_changelog_location() -> Result<ChangelogLocation> {
-    let items = [
-        "root - Single CHANGELOG.md at project root (default)",
-        "per-package - CHANGELOG.md in each package directory",
-    ];
+        })
+        .collect();
+    let default_idx = options
+        .iter()
+        .position(|(value, _)| *value == default)
+        .unwrap_or_default();
 
     let selection = Select::new()
-        .with_prompt("Select changelog location")
-        .items(items)
-        .default(0)
+        .with_prompt(prompt)
+        .items(&items)
+        .default(default_idx)
         .interact_opt()
-        .map_err(|e| match e {
-            dialoguer::Error::IO(io) => cli_to_operation_error(CliError::Io(io)),
-        })?;
+        .map_err(dialoguer_to_operation_error)?;
 
-    match selection {
-        Some(0) => Ok(ChangelogLocation::Root),
-        Some(1) => Ok(ChangelogLocation::PerPackage),
-        _ => Ok(ChangelogLocation::default()),
-    }
+    Ok(selection.map_or(default, |idx| options[idx].0))
 }
 
-fn select_comparison_links() -> Result<ComparisonLinks> {
-    let items = [
-        "auto - Generate links if git remote detected (default)",
-        "enabled - Always generate comparison links",
-        "disabled - Never generate comparison links",
+fn select_changelog_location(suggested: Option<ChangelogLocation>) -> Result<ChangelogLocation> {
+    let options = [
+        (
+            ChangelogLocation::Root,
+            "root - Single CHANGELOG.md at project root",
+        ),
+        (
+            ChangelogLocation::PerPackage,
+            "per-package - CHANGELOG.md in each package directory",
+        ),
     ];
 
-    let selection = Select::new()
-        .with_prompt("Select comparison links mode")
-        .items(items)
-        .default(0)
-        .interact_opt()
-        .map_err(|e| match e {
-            dialoguer::Error::IO(io) => cli_to_operation_error(CliError::Io(io)),
-        })?;
+    select_option(
+        "Select changelog location",
+        &options,
+        suggested.unwrap_or_default(),
+        suggested.is_some(),
+    )
+}
 
-    match selection {
-        Some(0) => Ok(ComparisonLinks::Auto),
-        Some(1) => Ok(ComparisonLinks::Enabled),
-        Some(2) => Ok(ComparisonLinks::Disabled),
-        _ => Ok(ComparisonLinks::default()),
-    }
+fn select_comparison_links(suggested: Option<ComparisonLinks>) -> Result<ComparisonLinks> {
+    let options = [
+        (
+            ComparisonLinks::Auto,
+            "auto - Generate links if git remote detected",
+        ),
+        (
+            ComparisonLinks::Enabled,
+            "enabled - Always generate comparison links",
+        ),
+        (
+            ComparisonLinks::Disabled,
+            "disabled - Never generate comparison links",
+        ),
+    ];
+
+    select_option(
+        "Select comparison links mode",
+        &options,
+        suggested.unwrap_or_default(),
+        suggested.is_some(),
+    )
 }
 
 fn select_zero_version_behavior() -> Result<ZeroVersionBehavior> {
@@ -468,6 +699,48 @@ pub fn confirm_proceed(prompt: &str) -> crate::error::Result<bool> {
     Ok(confirmed == Some(true))
 }
 
+/// Lists the uncommitted files blocking a release and asks how to proceed.
+///
+/// Cancelling the prompt aborts the release.
+///
+/// # Errors
+///
+/// Returns an error if stdin is not interactive or the prompt fails.
+pub fn select_dirty_tree_action(files: &[PathBuf]) -> crate::error::Result<DirtyTreeAction> {
+    if !is_interactive() {
+        return Err(CliError::NotATty);
+    }
+
+    eprintln!("The working tree has uncommitted changes:");
+    for file in files {
+        eprintln!("  {}", file.display());
+    }
+
+    let items = [
+        "stash - set them aside and restore them after the release",
+        "include - add them to the release commit",
+        "abort - cancel the release",
+    ];
+    let actions = [
+        DirtyTreeAction::Stash,
+        DirtyTreeAction::Include,
+        DirtyTreeAction::Abort,
+    ];
+
+    let selection = Select::new()
+        .with_prompt("How should the release handle them?")
+        .items(items)
+        .default(0)
+        .interact_opt()
+        .map_err(|e| match e {
+            dialoguer::Error::IO(io) => CliError::Io(io),
+        })?;
+
+    Ok(selection
+        .and_then(|index| actions.get(index).copied())
+        .unwrap_or(DirtyTreeAction::Abort))
+}
+
 /// Checks if the environment allows interactive prompts.
 ///
 /// Returns `true` when:
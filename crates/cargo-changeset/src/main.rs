@@ -1,16 +1,20 @@
 mod commands;
+mod config_overrides;
 mod environment;
 mod error;
+mod i18n;
 mod interaction;
 mod output;
 
 use std::path::PathBuf;
 use std::process::ExitCode;
 
+use changeset_project::ConfigOverride;
 use clap::Parser;
 
 use crate::commands::Commands;
 use crate::error::CliError;
+use crate::output::OutputFormat;
 
 #[derive(Parser)]
 #[command(name = "cargo")]
@@ -27,6 +31,31 @@ struct ChangesetCli {
     #[arg(long = "path", short = 'C', global = true)]
     path: Option<PathBuf>,
 
+    /// Never touch the network: skip or reject remote checks, API calls, and notifications
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Language for messages, e.g. `de` or `en`; defaults to LC_ALL, LC_MESSAGES, or LANG
+    #[arg(long, global = true, value_name = "LOCALE")]
+    locale: Option<String>,
+
+    /// Output format; `json` prints errors as a JSON object on stderr, and the
+    /// results of `verify` and `next-version` on stdout
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "FORMAT",
+        default_value = "text"
+    )]
+    output: OutputFormat,
+
+    /// Override a config key for this run, e.g. `tags=false`; repeatable.
+    /// Keys are those of `[workspace.metadata.changeset]`, dotted keys address
+    /// table entries such as `display-names.my-crate`
+    #[arg(long = "config", global = true, value_name = "KEY=VALUE")]
+    config: Vec<ConfigOverride>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -38,19 +67,22 @@ fn main() -> ExitCode {
         Err(_) => ChangesetCli::parse(),
     };
 
+    i18n::set_locale(i18n::Locale::detect(cli.locale.as_deref()));
+    config_overrides::set_config_overrides(cli.config);
+
     let start_path = match resolve_start_path(cli.path) {
         Ok(path) => path,
         Err(e) => {
-            print_error(&e);
+            report_error(&e, cli.output);
             return ExitCode::FAILURE;
         }
     };
 
-    let (result, exec_result) = cli.command.execute(&start_path);
+    let (result, exec_result) = cli.command.execute(&start_path, cli.offline, cli.output);
 
     if let Err(e) = result {
         if !exec_result.quiet {
-            print_error(&e);
+            report_error(&e, cli.output);
         }
         return ExitCode::FAILURE;
     }
@@ -64,17 +96,31 @@ fn resolve_start_path(path: Option<PathBuf>) -> Result<PathBuf, CliError> {
     }
 }
 
-fn print_error(error: &CliError) {
+fn report_error(error: &CliError, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_error(error),
+        OutputFormat::Json => eprintln!("{}", output::error_json(error)),
+    }
+}
+
+pub(crate) fn print_error(error: &CliError) {
     if let CliError::Operation(op_err) = error {
         print_operation_error(op_err);
+    } else if let Some(code) = error.code() {
+        eprintln!("error[{}]: {error}", code.code);
+        print_causes(error);
+        eprintln!("{}", tr!("explain-hint", code = code.code));
     } else {
         eprintln!("error: {error}");
+        print_causes(error);
+    }
+}
 
-        let mut source = std::error::Error::source(error);
-        while let Some(cause) = source {
-            eprintln!("caused by: {cause}");
-            source = std::error::Error::source(cause);
-        }
+fn print_causes(error: &dyn std::error::Error) {
+    let mut source = error.source();
+    while let Some(cause) = source {
+        eprintln!("{}", tr!("caused-by", cause = cause));
+        source = cause.source();
     }
 }
 
@@ -83,57 +129,33 @@ fn print_operation_error(error: &changeset_operations::OperationError) {
 
     use crate::environment::NonInteractiveReason;
 
+    let code = error.code().code;
     match error {
         OperationError::InteractionRequired => match crate::environment::non_interactive_reason() {
             Some(NonInteractiveReason::CiDetected { env_var }) => {
                 eprintln!(
-                    "error: interactive input required but running in CI environment \
-                         (detected via ${env_var})"
+                    "error[{code}]: {}",
+                    tr!("interactive.ci", env_var = env_var)
                 );
                 eprintln!();
-                eprintln!("To use this command non-interactively, provide:");
-                eprintln!("  --package <PACKAGE>    Specify package(s) to include");
-                eprintln!("  --bump <TYPE>          Bump type: major, minor, or patch");
-                eprintln!("  -m <MESSAGE>           Change description");
+                eprintln!("{}", tr!("interactive.hint"));
+                eprintln!("{}", tr!("interactive.hint-package"));
+                eprintln!("{}", tr!("interactive.hint-bump"));
+                eprintln!("{}", tr!("interactive.hint-message"));
                 eprintln!();
-                eprintln!("Example:");
-                eprintln!(
-                    "  cargo changeset add --package my-crate --bump minor -m \"Added feature\""
-                );
+                eprintln!("{}", tr!("interactive.example"));
+                eprintln!("{}", tr!("interactive.example-command"));
             }
             Some(NonInteractiveReason::ExplicitDisable) => {
-                eprintln!("error: interactive mode disabled via CARGO_CHANGESET_NO_TTY");
+                eprintln!("error[{code}]: {}", tr!("interactive.disabled"));
             }
             Some(NonInteractiveReason::NoTerminal) | None => {
-                eprintln!("error: interactive mode requires a terminal");
+                eprintln!("error[{code}]: {}", tr!("interactive.no-terminal"));
             }
         },
-        OperationError::MissingBumpType { package_name } => {
-            eprintln!(
-                "error: missing bump type for package '{package_name}' (use --bump or --package-bump)"
-            );
-        }
-        OperationError::MissingDescription => {
-            eprintln!("error: missing description (use -m or provide interactively)");
-        }
-        OperationError::EmptyDescription => {
-            eprintln!("error: description cannot be empty");
-        }
-        OperationError::EmptyProject(path) => {
-            eprintln!(
-                "error: no packages found in project at '{}'",
-                path.display()
-            );
-        }
-        OperationError::UnknownPackage { name, available } => {
-            eprintln!("error: unknown package '{name}' (available: {available})");
-        }
         OperationError::Project(e) => {
-            eprintln!("error: project error");
-            eprintln!("caused by: {e}");
-        }
-        OperationError::Cancelled => {
-            eprintln!("error: operation cancelled by user");
+            eprintln!("error[{code}]: {}", i18n::operation_message(error));
+            eprintln!("{}", tr!("caused-by", cause = e));
         }
         OperationError::SagaFailed { step, source } => {
             print_saga_failed(step, source.as_ref());
@@ -142,34 +164,52 @@ fn print_operation_error(error: &changeset_operations::OperationError) {
             step,
             source,
             compensation_failures,
+            journal,
         } => {
-            print_saga_compensation_failed(step, source.as_ref(), compensation_failures);
+            print_saga_compensation_failed(
+                step,
+                source.as_ref(),
+                compensation_failures,
+                journal.is_some(),
+            );
         }
         _ => {
-            eprintln!("error: {error}");
-            let mut source = std::error::Error::source(error);
-            while let Some(cause) = source {
-                eprintln!("caused by: {cause}");
-                source = std::error::Error::source(cause);
-            }
+            eprintln!("error[{code}]: {}", i18n::operation_message(error));
+            print_causes(error);
         }
     }
+
+    let explained = match error {
+        OperationError::Cancelled => return,
+        OperationError::SagaFailed { source, .. }
+        | OperationError::SagaCompensationFailed { source, .. } => source.code().code,
+        _ => code,
+    };
+    eprintln!("{}", tr!("explain-hint", code = explained));
 }
 
-fn print_saga_failed(step: &str, source: &changeset_operations::OperationError) {
+fn print_saga_step_error(step: &str, source: &changeset_operations::OperationError) {
     eprintln!();
-    eprintln!("Error: Release failed at step '{step}'");
-    eprintln!("  -> {source}");
+    eprintln!("{}", tr!("saga.failed-at-step", step = step));
+    eprintln!(
+        "  -> error[{}]: {}",
+        source.code().code,
+        i18n::operation_message(source)
+    );
 
     let mut error_source = std::error::Error::source(source);
     while let Some(cause) = error_source {
         eprintln!("  -> {cause}");
         error_source = std::error::Error::source(cause);
     }
+}
+
+fn print_saga_failed(step: &str, source: &changeset_operations::OperationError) {
+    print_saga_step_error(step, source);
 
     eprintln!();
-    eprintln!("Rollback completed successfully.");
-    eprintln!("Your workspace has been restored to its original state.");
+    eprintln!("{}", tr!("saga.rollback-completed"));
+    eprintln!("{}", tr!("saga.workspace-restored"));
     eprintln!();
 }
 
@@ -177,31 +217,34 @@ fn print_saga_compensation_failed(
     step: &str,
     source: &changeset_operations::OperationError,
     compensation_failures: &[changeset_operations::CompensationFailure],
+    journaled: bool,
 ) {
-    eprintln!();
-    eprintln!("Error: Release failed at step '{step}'");
-    eprintln!("  -> {source}");
-
-    let mut error_source = std::error::Error::source(source);
-    while let Some(cause) = error_source {
-        eprintln!("  -> {cause}");
-        error_source = std::error::Error::source(cause);
-    }
+    print_saga_step_error(step, source);
 
     eprintln!();
     eprintln!(
-        "Rollback partially failed ({} compensation(s) failed):",
-        compensation_failures.len()
+        "{}",
+        tr!("saga.rollback-partial", count = compensation_failures.len())
     );
     eprintln!();
 
     for failure in compensation_failures {
         eprintln!("  x {} - {}", failure.step, failure.description);
-        eprintln!("    Error: {}", failure.error);
+        eprintln!(
+            "    {}",
+            tr!(
+                "saga.compensation-error",
+                error = i18n::operation_message(&failure.error)
+            )
+        );
     }
 
     eprintln!();
-    eprintln!("WARNING: Your workspace may be in an inconsistent state.");
-    eprintln!("Manual cleanup may be required.");
+    eprintln!("{}", tr!("saga.inconsistent"));
+    if journaled {
+        eprintln!("{}", tr!("saga.retry-compensate"));
+    } else {
+        eprintln!("{}", tr!("saga.manual-cleanup"));
+    }
     eprintln!();
 }
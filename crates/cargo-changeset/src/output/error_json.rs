@@ -0,0 +1,138 @@
+use changeset_operations::OperationError;
+use serde_json::{Value, json};
+
+use crate::error::CliError;
+
+/// Renders an error as the JSON object printed on stderr with `--output json`.
+///
+/// Saga failures report the code, message, and files of the step error that
+/// caused them, together with the failed step and how the rollback went.
+pub(crate) fn error_json(error: &CliError) -> Value {
+    let CliError::Operation(op_err) = error else {
+        let code = error.code();
+        return json!({
+            "code": code.map(|code| code.code),
+            "name": code.map(|code| code.name),
+            "message": error.to_string(),
+            "causes": causes(error),
+            "step": null,
+            "compensation": null,
+            "files": [],
+        });
+    };
+
+    let (cause, step, compensation) = match op_err {
+        OperationError::SagaFailed { step, source } => (
+            source.as_ref(),
+            Some(step),
+            json!({ "status": "completed", "failures": [] }),
+        ),
+        OperationError::SagaCompensationFailed {
+            step,
+            source,
+            compensation_failures,
+            journal,
+        } => (
+            source.as_ref(),
+            Some(step),
+            json!({
+                "status": "partial",
+                "failures": compensation_failures
+                    .iter()
+                    .map(|failure| json!({
+                        "step": failure.step,
+                        "description": failure.description,
+                        "error": failure.error.to_string(),
+                    }))
+                    .collect::<Vec<_>>(),
+                "journaled": journal.is_some(),
+            }),
+        ),
+        _ => (op_err, None, Value::Null),
+    };
+
+    let code = cause.code();
+    json!({
+        "code": code.code,
+        "name": code.name,
+        "message": cause.to_string(),
+        "causes": causes(cause),
+        "step": step,
+        "compensation": compensation,
+        "files": cause
+            .affected_files()
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn causes(error: &dyn std::error::Error) -> Vec<String> {
+    let mut causes = Vec::new();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    causes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use changeset_operations::CompensationFailure;
+
+    use super::*;
+
+    #[test]
+    fn operation_error_includes_code_and_files() {
+        let error = CliError::Operation(OperationError::DirtyWorkingTree {
+            files: vec![PathBuf::from("src/lib.rs")],
+        });
+
+        let value = error_json(&error);
+
+        assert_eq!(value["code"], "CS0030");
+        assert_eq!(value["name"], "DirtyWorkingTree");
+        assert_eq!(value["files"], json!(["src/lib.rs"]));
+        assert_eq!(value["step"], Value::Null);
+        assert_eq!(value["compensation"], Value::Null);
+    }
+
+    #[test]
+    fn verification_failure_includes_code() {
+        let value = error_json(&CliError::VerificationFailed { uncovered_count: 2 });
+
+        assert_eq!(value["code"], "CS0087");
+        assert_eq!(value["name"], "VerificationFailed");
+    }
+
+    #[test]
+    fn partial_rollback_lists_failed_compensations() {
+        let error = CliError::Operation(OperationError::SagaCompensationFailed {
+            step: "create-tags".to_string(),
+            source: Box::new(OperationError::BranchBehindRemote),
+            compensation_failures: vec![CompensationFailure {
+                step: "create-commit".to_string(),
+                description: "reset release commit".to_string(),
+                error: Box::new(OperationError::Cancelled),
+            }],
+            journal: None,
+        });
+
+        let value = error_json(&error);
+
+        assert_eq!(
+            value["code"],
+            OperationError::BranchBehindRemote.code().code
+        );
+        assert_eq!(value["step"], "create-tags");
+        assert_eq!(value["compensation"]["status"], "partial");
+        assert_eq!(
+            value["compensation"]["failures"][0]["step"],
+            "create-commit"
+        );
+        assert_eq!(value["compensation"]["journaled"], false);
+    }
+}
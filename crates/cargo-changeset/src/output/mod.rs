@@ -1,7 +1,17 @@
+mod error_json;
 mod formatter;
 mod plain;
 mod status;
 
+pub(crate) use error_json::error_json;
 pub(crate) use formatter::OutputFormatter;
 pub(crate) use plain::PlainTextFormatter;
-pub(crate) use status::{PlainTextStatusFormatter, StatusFormatter};
+pub(crate) use status::{PlainTextStatusFormatter, StatusFormatter, format_version_drift};
+
+/// Format of errors and command results, selected with the global `--output` flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use changeset_operations::verification::VerificationResult;
+use changeset_operations::verification::rules::DuplicateKind;
 
 use super::OutputFormatter;
 
@@ -12,10 +13,18 @@ impl PlainTextFormatter {
         for pkg in &result.affected_packages {
             let status = if result.covered_packages.contains(&pkg.name) {
                 "✓"
+            } else if result.exempt_packages.contains(pkg) {
+                "-"
             } else {
                 "✗"
             };
-            output.push_str(&format!("  {status} {}\n", pkg.name));
+            match result.transitive_packages.get(&pkg.name) {
+                Some(dependency) => output.push_str(&format!(
+                    "  {status} {} (depends on {dependency})\n",
+                    pkg.name
+                )),
+                None => output.push_str(&format!("  {status} {}\n", pkg.name)),
+            }
         }
     }
 
@@ -42,6 +51,47 @@ impl PlainTextFormatter {
         Self::format_file_list(output, "Project-level files", &result.project_files);
         Self::format_file_list(output, "Ignored files", &result.ignored_files);
         Self::format_covered_packages(output, result);
+        Self::format_exempt_packages(output, result);
+        Self::format_understated_bumps(output, result);
+        Self::format_duplicate_changesets(output, result);
+    }
+
+    fn format_exempt_packages(output: &mut String, result: &VerificationResult) {
+        if !result.exempt_packages.is_empty() {
+            output.push_str("\nNo changeset required (require-changeset):\n");
+            for pkg in &result.exempt_packages {
+                output.push_str(&format!("  {}\n", pkg.name));
+            }
+        }
+    }
+
+    fn format_duplicate_changesets(output: &mut String, result: &VerificationResult) {
+        if !result.duplicate_changesets.is_empty() {
+            output.push_str("\nDuplicate changesets:\n");
+            for duplicate in &result.duplicate_changesets {
+                let reason = match duplicate.kind {
+                    DuplicateKind::SameName => "same file name as",
+                    DuplicateKind::SameContent => "same content as",
+                };
+                output.push_str(&format!(
+                    "  {}: {reason} {}\n",
+                    duplicate.path.display(),
+                    duplicate.duplicate_of.display()
+                ));
+            }
+        }
+    }
+
+    fn format_understated_bumps(output: &mut String, result: &VerificationResult) {
+        if !result.understated_bumps.is_empty() {
+            output.push_str("\nBumps that may be too small:\n");
+            for bump in &result.understated_bumps {
+                output.push_str(&format!(
+                    "  {}: changesets declare {:?}, {} suggest {:?}\n",
+                    bump.package, bump.declared, bump.provider, bump.inferred
+                ));
+            }
+        }
     }
 }
 
@@ -57,6 +107,13 @@ impl OutputFormatter for PlainTextFormatter {
         let mut output = String::new();
         Self::format_common_sections(&mut output, result);
 
+        if !result.consumed_changeset_edits.is_empty() {
+            output.push_str("Consumed changesets must not be edited:\n");
+            for path in &result.consumed_changeset_edits {
+                output.push_str(&format!("  {}\n", path.display()));
+            }
+        }
+
         if !result.uncovered_packages.is_empty() {
             output.push_str("Packages without changeset coverage:\n");
             for pkg in &result.uncovered_packages {
@@ -64,6 +121,36 @@ impl OutputFormatter for PlainTextFormatter {
             }
         }
 
+        if !result.uncovered_owners.is_empty() {
+            output.push_str("Owners missing changesets:\n");
+            for (owner, packages) in &result.uncovered_owners {
+                output.push_str(&format!("  {owner}: {}\n", packages.join(", ")));
+            }
+        }
+
+        if !result.lint_failures.is_empty() {
+            output.push_str("Changeset summaries failing lint:\n");
+            for (path, issues) in &result.lint_failures {
+                output.push_str(&format!("  {}\n", path.display()));
+                for issue in issues {
+                    output.push_str(&format!("    {issue}\n"));
+                }
+            }
+        }
+
+        if !result.scope_failures.is_empty() {
+            output.push_str("Changesets with invalid scopes:\n");
+            for (path, issue) in &result.scope_failures {
+                output.push_str(&format!("  {}: {issue}\n", path.display()));
+            }
+        }
+
+        if result.missing_advisory {
+            output.push_str(
+                "Security pull requests need a changeset with category 'security' and an advisory\n",
+            );
+        }
+
         output
     }
 }
@@ -1,7 +1,11 @@
-use changeset_operations::operations::StatusOutput;
+use changeset_operations::operations::{
+    DriftKind, RetrospectiveOutput, ShippedPackage, StatusOutput, VersionDrift,
+};
+use chrono::{DateTime, Utc};
 
 pub(crate) trait StatusFormatter {
     fn format_status(&self, output: &StatusOutput) -> String;
+    fn format_retrospective(&self, since: &str, output: &RetrospectiveOutput) -> String;
 }
 
 pub(crate) struct PlainTextStatusFormatter;
@@ -12,9 +16,22 @@ impl PlainTextStatusFormatter {
             "Pending changesets: {}\n",
             status.changeset_files.len()
         ));
+        let now = Utc::now();
         for file in &status.changeset_files {
             if let Some(name) = file.file_name() {
-                output.push_str(&format!("  {}\n", name.to_string_lossy()));
+                let owner = status
+                    .changeset_owners
+                    .get(file)
+                    .map(|owner| format!(" [{owner}]"))
+                    .unwrap_or_default();
+                match status.changeset_ages.get(file) {
+                    Some(age) => output.push_str(&format!(
+                        "  {}{owner} ({})\n",
+                        name.to_string_lossy(),
+                        format_added(age.added, now)
+                    )),
+                    None => output.push_str(&format!("  {}{owner}\n", name.to_string_lossy())),
+                }
             }
         }
     }
@@ -32,7 +49,7 @@ impl PlainTextStatusFormatter {
 
             output.push_str(&format!(
                 "  {}: {} -> {} ({:?}){}\n",
-                release.name,
+                status.display_name(&release.name),
                 release.current_version,
                 release.new_version,
                 release.bump_type,
@@ -64,7 +81,11 @@ impl PlainTextStatusFormatter {
         output.push('\n');
         output.push_str("Packages without changesets:\n");
         for pkg in &status.unchanged_packages {
-            output.push_str(&format!("  {} ({})\n", pkg.name, pkg.version));
+            output.push_str(&format!(
+                "  {} ({})\n",
+                status.display_name(&pkg.name),
+                pkg.version
+            ));
         }
     }
 
@@ -81,12 +102,33 @@ impl PlainTextStatusFormatter {
     }
 
     fn format_summary(output: &mut String, status: &StatusOutput) {
-        output.push('\n');
+        let summary = &status.summary;
         output.push_str(&format!(
-            "Summary: {} changeset(s), {} package(s) affected\n",
-            status.changesets.len(),
-            status.projected_releases.len()
+            "Summary: {} changeset(s), {} package(s) to release ({} major, {} minor, {} patch), {} unchanged\n",
+            summary.pending_changesets,
+            summary.packages_to_release,
+            summary.major,
+            summary.minor,
+            summary.patch,
+            summary.unchanged_packages
         ));
+        if !summary.prerelease_queue.is_empty() {
+            let queued: Vec<_> = summary
+                .prerelease_queue
+                .iter()
+                .map(|(name, tag)| format!("{} ({tag})", status.display_name(name)))
+                .collect();
+            output.push_str(&format!("Pre-release queue: {}\n", queued.join(", ")));
+        }
+        if !summary.graduation_queue.is_empty() {
+            let queued: Vec<_> = summary
+                .graduation_queue
+                .iter()
+                .map(|name| status.display_name(name))
+                .collect();
+            output.push_str(&format!("Graduation queue: {}\n", queued.join(", ")));
+        }
+        output.push('\n');
     }
 
     fn format_inherited_versions_warning(output: &mut String, status: &StatusOutput) {
@@ -102,6 +144,45 @@ impl PlainTextStatusFormatter {
         output.push_str("  Release will require --convert flag\n");
     }
 
+    fn format_version_drift_warning(output: &mut String, status: &StatusOutput) {
+        if status.version_drift.is_empty() {
+            return;
+        }
+
+        output.push('\n');
+        output.push_str("Warning: Manifest versions differ from latest tags:\n");
+        for drift in &status.version_drift {
+            output.push_str(&format_version_drift(drift));
+        }
+    }
+
+    fn format_stale_changesets_warning(output: &mut String, status: &StatusOutput) {
+        let stale = status.stale_changesets();
+        if stale.is_empty() {
+            return;
+        }
+
+        output.push('\n');
+        output.push_str(&format!(
+            "Warning: Changesets pending through {}+ releases:\n",
+            status.stale_changeset_releases
+        ));
+        for (path, changeset, age) in stale {
+            let packages: Vec<_> = changeset
+                .releases
+                .iter()
+                .map(|release| status.display_name(&release.name))
+                .collect();
+            output.push_str(&format!(
+                "  {}: {} ({} release(s))\n",
+                path.file_name().unwrap_or_default().to_string_lossy(),
+                packages.join(", "),
+                age.releases_since
+            ));
+        }
+        output.push_str("  Check that these packages are still being released\n");
+    }
+
     fn format_consumed_prerelease_changesets(output: &mut String, status: &StatusOutput) {
         const MAX_DISPLAYED: usize = 10;
 
@@ -135,9 +216,75 @@ impl PlainTextStatusFormatter {
     }
 }
 
+fn format_added(added: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    match (now - added).num_days() {
+        ..=0 => "added today".to_string(),
+        1 => "added 1 day ago".to_string(),
+        days => format!("added {days} days ago"),
+    }
+}
+
+fn format_shipped_package(output: &mut String, package: &ShippedPackage) {
+    match &package.previous_version {
+        Some(previous) => output.push_str(&format!(
+            "\n{}: {previous} -> {}\n",
+            package.name, package.current_version
+        )),
+        None => output.push_str(&format!(
+            "\n{}: {} (new)\n",
+            package.name, package.current_version
+        )),
+    }
+    let released: Vec<String> = package
+        .released_versions
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    output.push_str(&format!("  Released: {}\n", released.join(", ")));
+
+    if !package.changesets.is_empty() {
+        output.push_str("  Changesets:\n");
+        for changeset in &package.changesets {
+            let bump = changeset
+                .releases
+                .iter()
+                .find(|release| release.name == package.name)
+                .map(|release| format!(" ({:?})", release.bump_type))
+                .unwrap_or_default();
+            output.push_str(&format!("    - {}{bump}\n", changeset.summary));
+        }
+    }
+    format_changelog_sections(output, &package.changelog_sections, "  ");
+}
+
+fn format_changelog_sections(output: &mut String, sections: &[String], indent: &str) {
+    for section in sections {
+        output.push('\n');
+        for line in section.lines() {
+            if line.is_empty() {
+                output.push('\n');
+            } else {
+                output.push_str(&format!("{indent}{line}\n"));
+            }
+        }
+    }
+}
+
+pub(crate) fn format_version_drift(drift: &VersionDrift) -> String {
+    let direction = match drift.kind {
+        DriftKind::ManifestAhead => "manifest ahead of tag",
+        DriftKind::TagAhead => "tag ahead of manifest",
+    };
+    format!(
+        "  {}: Cargo.toml {} vs tag {} ({direction})\n",
+        drift.package, drift.manifest_version, drift.tag_version
+    )
+}
+
 impl StatusFormatter for PlainTextStatusFormatter {
     fn format_status(&self, status: &StatusOutput) -> String {
         let mut output = String::new();
+        Self::format_summary(&mut output, status);
 
         if status.changesets.is_empty() && status.consumed_prerelease_changesets.is_empty() {
             output.push_str("No pending changesets.\n");
@@ -150,11 +297,28 @@ impl StatusFormatter for PlainTextStatusFormatter {
             Self::format_projected_releases(&mut output, status);
             Self::format_unchanged_packages(&mut output, status);
             Self::format_unknown_packages(&mut output, status);
-            Self::format_summary(&mut output, status);
         }
 
         Self::format_inherited_versions_warning(&mut output, status);
+        Self::format_version_drift_warning(&mut output, status);
+        Self::format_stale_changesets_warning(&mut output, status);
+
+        output
+    }
 
+    fn format_retrospective(&self, since: &str, retrospective: &RetrospectiveOutput) -> String {
+        if retrospective.packages.is_empty() {
+            return format!("Nothing shipped since {since}.\n");
+        }
+
+        let mut output = format!(
+            "Shipped since {since}: {} package(s)\n",
+            retrospective.packages.len()
+        );
+        for package in &retrospective.packages {
+            format_shipped_package(&mut output, package);
+        }
+        format_changelog_sections(&mut output, &retrospective.root_changelog_sections, "");
         output
     }
 }
@@ -163,12 +327,14 @@ impl StatusFormatter for PlainTextStatusFormatter {
 mod tests {
     use super::*;
     use changeset_core::{BumpType, ChangeCategory, Changeset, PackageInfo, PackageRelease};
-    use changeset_operations::operations::PackageVersion;
+    use changeset_operations::operations::{PackageVersion, StatusSummary};
     use indexmap::IndexMap;
+    use std::collections::HashMap;
     use std::path::PathBuf;
 
     fn empty_status() -> StatusOutput {
         StatusOutput {
+            summary: StatusSummary::default(),
             changesets: Vec::new(),
             changeset_files: Vec::new(),
             projected_releases: Vec::new(),
@@ -177,6 +343,11 @@ mod tests {
             packages_with_inherited_versions: Vec::new(),
             unknown_packages: Vec::new(),
             consumed_prerelease_changesets: Vec::new(),
+            version_drift: Vec::new(),
+            display_names: HashMap::new(),
+            changeset_ages: HashMap::new(),
+            changeset_owners: HashMap::new(),
+            stale_changeset_releases: 3,
         }
     }
 
@@ -217,8 +388,12 @@ mod tests {
                 })
                 .collect(),
             category,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         }
     }
 
@@ -229,7 +404,39 @@ mod tests {
 
         let result = formatter.format_status(&status);
 
-        assert_eq!(result, "No pending changesets.\n");
+        assert_eq!(
+            result,
+            "Summary: 0 changeset(s), 0 package(s) to release (0 major, 0 minor, 0 patch), 0 unchanged\n\
+             \n\
+             No pending changesets.\n"
+        );
+    }
+
+    #[test]
+    fn format_uses_display_names() {
+        let formatter = PlainTextStatusFormatter;
+        let mut status = empty_status();
+        status.changesets = vec![make_changeset(
+            &[("crate-internal-name", BumpType::Minor)],
+            ChangeCategory::Added,
+            "Add widgets",
+        )];
+        status.changeset_files = vec![PathBuf::from(".changeset/changesets/widgets.md")];
+        status.projected_releases = vec![make_package_version(
+            "crate-internal-name",
+            "1.0.0",
+            "1.1.0",
+            BumpType::Minor,
+        )];
+        status.unchanged_packages = vec![make_package_info("other-crate", "2.0.0")];
+        status.display_names =
+            HashMap::from([("crate-internal-name".to_string(), "Widget SDK".to_string())]);
+
+        let result = formatter.format_status(&status);
+
+        assert!(result.contains("  Widget SDK: 1.0.0 -> 1.1.0 (Minor)"));
+        assert!(result.contains("  other-crate (2.0.0)"));
+        assert!(!result.contains("crate-internal-name"));
     }
 
     #[test]
@@ -268,13 +475,22 @@ mod tests {
             map
         };
 
+        status.summary = StatusSummary {
+            pending_changesets: 1,
+            packages_to_release: 1,
+            patch: 1,
+            ..StatusSummary::default()
+        };
+
         let result = formatter.format_status(&status);
 
         assert!(result.contains("Pending changesets: 1"));
         assert!(result.contains("fix-bug.md"));
         assert!(result.contains("Projected releases:"));
         assert!(result.contains("my-crate: 1.0.0 -> 1.0.1 (Patch)"));
-        assert!(result.contains("Summary: 1 changeset(s), 1 package(s) affected"));
+        assert!(result.starts_with(
+            "Summary: 1 changeset(s), 1 package(s) to release (0 major, 0 minor, 1 patch), 0 unchanged\n"
+        ));
     }
 
     #[test]
@@ -431,12 +647,22 @@ mod tests {
             map
         };
 
+        status.summary = StatusSummary {
+            pending_changesets: 2,
+            packages_to_release: 2,
+            minor: 1,
+            patch: 1,
+            ..StatusSummary::default()
+        };
+
         let result = formatter.format_status(&status);
 
         assert!(result.contains("Pending changesets: 2"));
         assert!(result.contains("crate-a: 1.0.0 -> 1.0.1 (Patch)"));
         assert!(result.contains("crate-b: 2.0.0 -> 2.1.0 (Minor)"));
-        assert!(result.contains("Summary: 2 changeset(s), 2 package(s) affected"));
+        assert!(result.contains(
+            "Summary: 2 changeset(s), 2 package(s) to release (0 major, 1 minor, 1 patch)"
+        ));
     }
 
     #[test]
@@ -487,12 +713,16 @@ mod tests {
             map
         };
         status.unknown_packages = vec!["unknown-crate".to_string()];
+        status.summary = StatusSummary {
+            pending_changesets: 1,
+            ..StatusSummary::default()
+        };
 
         let result = formatter.format_status(&status);
 
         assert!(result.contains("Pending changesets: 1"));
         assert!(result.contains("Warning: Unknown packages in changesets:"));
-        assert!(result.contains("Summary: 1 changeset(s), 0 package(s) affected"));
+        assert!(result.contains("Summary: 1 changeset(s), 0 package(s) to release"));
     }
 
     #[test]
@@ -687,4 +917,120 @@ mod tests {
             "should not show truncation for small lists"
         );
     }
+
+    #[test]
+    fn format_version_drift_warning() {
+        let formatter = PlainTextStatusFormatter;
+        let mut status = empty_status();
+        status.version_drift = vec![VersionDrift {
+            package: "crate-a".to_string(),
+            manifest_version: "1.1.0".parse().expect("valid version"),
+            tag_version: "1.0.0".parse().expect("valid version"),
+            kind: DriftKind::ManifestAhead,
+        }];
+
+        let result = formatter.format_status(&status);
+
+        assert!(result.contains("Warning: Manifest versions differ from latest tags:"));
+        assert!(result.contains("crate-a: Cargo.toml 1.1.0 vs tag 1.0.0 (manifest ahead of tag)"));
+    }
+
+    #[test]
+    fn format_changeset_ages_and_stale_warning() {
+        use changeset_operations::operations::ChangesetAge;
+
+        let formatter = PlainTextStatusFormatter;
+        let mut status = empty_status();
+        let fresh = PathBuf::from(".changeset/changesets/fresh.md");
+        let old = PathBuf::from(".changeset/changesets/old.md");
+        status.changesets = vec![
+            make_changeset(
+                &[("crate-a", BumpType::Patch)],
+                ChangeCategory::Fixed,
+                "Fix",
+            ),
+            make_changeset(
+                &[("crate-b", BumpType::Minor)],
+                ChangeCategory::Added,
+                "Add",
+            ),
+        ];
+        status.changeset_files = vec![fresh.clone(), old.clone()];
+        status.changeset_ages.insert(
+            fresh,
+            ChangesetAge {
+                added: Utc::now(),
+                releases_since: 0,
+            },
+        );
+        status.changeset_ages.insert(
+            old,
+            ChangesetAge {
+                added: Utc::now() - chrono::Duration::days(40),
+                releases_since: 4,
+            },
+        );
+
+        let result = formatter.format_status(&status);
+
+        assert!(result.contains("  fresh.md (added today)"));
+        assert!(result.contains("  old.md (added 40 days ago)"));
+        assert!(result.contains("Warning: Changesets pending through 3+ releases:"));
+        assert!(result.contains("  old.md: crate-b (4 release(s))"));
+        assert!(!result.contains("fresh.md: crate-a"));
+
+        status.stale_changeset_releases = 0;
+        let result = formatter.format_status(&status);
+        assert!(!result.contains("Warning: Changesets pending"));
+    }
+
+    #[test]
+    fn format_changeset_owners() {
+        let formatter = PlainTextStatusFormatter;
+        let mut status = empty_status();
+        let team = PathBuf::from("teams/web/changesets/team.md");
+        status.changesets = vec![
+            make_changeset(
+                &[("crate-a", BumpType::Patch)],
+                ChangeCategory::Fixed,
+                "Fix",
+            ),
+            make_changeset(
+                &[("crate-b", BumpType::Minor)],
+                ChangeCategory::Added,
+                "Add",
+            ),
+        ];
+        status.changeset_files = vec![PathBuf::from(".changeset/changesets/fix.md"), team.clone()];
+        status.changeset_owners.insert(team, "web".to_string());
+
+        let result = formatter.format_status(&status);
+
+        assert!(result.contains("  fix.md\n"));
+        assert!(result.contains("  team.md [web]\n"));
+    }
+
+    #[test]
+    fn format_summary_lists_release_queues() {
+        let formatter = PlainTextStatusFormatter;
+        let mut status = empty_status();
+        status
+            .display_names
+            .insert("crate-b".to_string(), "Crate B".to_string());
+        status.summary = StatusSummary {
+            unchanged_packages: 2,
+            prerelease_queue: vec![("crate-a".to_string(), "alpha".to_string())],
+            graduation_queue: vec!["crate-b".to_string()],
+            ..StatusSummary::default()
+        };
+
+        let result = formatter.format_status(&status);
+
+        assert!(result.starts_with(
+            "Summary: 0 changeset(s), 0 package(s) to release (0 major, 0 minor, 0 patch), 2 unchanged\n\
+             Pre-release queue: crate-a (alpha)\n\
+             Graduation queue: Crate B\n\n"
+        ));
+        assert!(result.contains("No pending changesets."));
+    }
 }
@@ -2,6 +2,7 @@ use std::fs;
 use std::process::Command;
 use std::time::Duration;
 
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::contains;
 use tempfile::TempDir;
 
@@ -188,6 +189,93 @@ mod non_interactive {
         assert!(content.contains("Fixed a bug"), "should contain message");
     }
 
+    #[test]
+    fn add_rejects_summary_failing_lint() {
+        let workspace = create_single_crate_workspace();
+        let manifest_path = workspace.path().join("Cargo.toml");
+        let manifest = fs::read_to_string(&manifest_path).expect("read Cargo.toml");
+        fs::write(
+            &manifest_path,
+            format!(
+                "{manifest}\n[package.metadata.changeset]\nlint-summaries = true\nlint-denied-words = [\"teh\"]\n"
+            ),
+        )
+        .expect("write Cargo.toml");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .arg("add")
+            .arg("--bump")
+            .arg("patch")
+            .arg("-m")
+            .arg("Fix teh `parse() bug")
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("unbalanced backticks"))
+            .stderr(contains("denied word 'teh'"));
+
+        assert!(!workspace.path().join(".changeset/changesets").exists());
+    }
+
+    #[test]
+    fn add_records_scope_and_enforces_required_scopes() {
+        let workspace = create_single_crate_workspace();
+        let manifest_path = workspace.path().join("Cargo.toml");
+        let manifest = fs::read_to_string(&manifest_path).expect("read Cargo.toml");
+        fs::write(
+            &manifest_path,
+            format!(
+                "{manifest}\n[package.metadata.changeset]\nscopes = [\"parser\", \"cli\"]\nrequire-scope = true\n"
+            ),
+        )
+        .expect("write Cargo.toml");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--bump", "patch", "-m", "Handle empty input"])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("a scope is required"));
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args([
+                "add",
+                "-b",
+                "patch",
+                "-s",
+                "docs",
+                "-m",
+                "Handle empty input",
+            ])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("unknown scope 'docs' (allowed: parser, cli)"));
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args([
+                "add",
+                "-b",
+                "patch",
+                "--scope",
+                "parser",
+                "-m",
+                "Handle empty input",
+            ])
+            .current_dir(workspace.path())
+            .assert()
+            .success();
+
+        let changeset_dir = workspace.path().join(".changeset/changesets");
+        let entry = fs::read_dir(&changeset_dir)
+            .expect("read changeset dir")
+            .next()
+            .expect("changeset file exists")
+            .expect("read dir entry");
+        let content = fs::read_to_string(entry.path()).expect("read changeset");
+        assert!(content.contains("scope: parser\n"));
+    }
+
     #[test]
     fn add_outside_workspace_fails() {
         let dir = TempDir::new().expect("failed to create temp dir");
@@ -399,6 +487,29 @@ mod non_interactive {
         assert!(content.contains("category: fixed"));
     }
 
+    #[test]
+    fn add_with_advisory_flag() {
+        let workspace = create_single_crate_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--bump", "patch", "-c", "security"])
+            .args(["--advisory", "RUSTSEC-2024-0001", "-m", "Fix overflow"])
+            .current_dir(workspace.path())
+            .assert()
+            .success();
+
+        let changeset_dir = workspace.path().join(".changeset/changesets");
+        let files: Vec<_> = fs::read_dir(&changeset_dir)
+            .expect("read dir")
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+            .collect();
+
+        let content = fs::read_to_string(files[0].path()).expect("read file");
+        assert!(content.contains("category: security"));
+        assert!(content.contains("advisory: RUSTSEC-2024-0001"));
+    }
+
     #[test]
     fn add_with_package_flag_case_sensitivity() {
         let workspace = create_virtual_workspace();
@@ -436,6 +547,39 @@ mod non_interactive {
             .stderr(contains("crate_one"));
     }
 
+    #[test]
+    fn add_selects_packages_by_path_and_glob() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "-p", "crates/b", "--bump", "patch", "-m", "By path"])
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("crate-b"))
+            .stdout(contains("crate-a").not());
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "-p", "crate-*", "--bump", "patch", "-m", "By glob"])
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("crate-a"))
+            .stdout(contains("crate-b"));
+    }
+
+    #[test]
+    fn add_with_misspelled_package_suggests_near_misses() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "-p", "crate-c", "--bump", "patch", "-m", "test"])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("did you mean 'crate-a', 'crate-b'?"));
+    }
+
     #[test]
     fn add_generates_unique_filenames() {
         let workspace = create_single_crate_workspace();
@@ -481,6 +625,65 @@ mod non_interactive {
 }
 
 #[cfg(not(windows))]
+mod deps_update {
+    use super::*;
+
+    fn git(dir: &TempDir, args: &[&str]) {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir.path())
+            .output()
+            .expect("failed to run git");
+    }
+
+    fn committed_virtual_workspace() -> TempDir {
+        let workspace = create_virtual_workspace();
+        fs::write(
+            workspace.path().join("crates/b/Cargo.toml"),
+            "[package]\nname = \"crate-b\"\nversion = \"0.2.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nlog = \"0.4.20\"\n",
+        )
+        .expect("failed to write crate-b Cargo.toml");
+        git(&workspace, &["init", "--initial-branch=main"]);
+        git(&workspace, &["config", "user.email", "test@example.com"]);
+        git(&workspace, &["config", "user.name", "Test"]);
+        git(&workspace, &["add", "-A"]);
+        git(&workspace, &["commit", "-m", "Initial commit"]);
+        workspace
+    }
+
+    #[test]
+    fn creates_changeset_for_updated_dependencies() {
+        let workspace = committed_virtual_workspace();
+        let manifest = workspace.path().join("crates/b/Cargo.toml");
+        let content = fs::read_to_string(&manifest).expect("failed to read Cargo.toml");
+        fs::write(&manifest, content.replace("0.4.20", "0.4.21"))
+            .expect("failed to write Cargo.toml");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--deps-update", "--category", "security"])
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("Summary: Update dependencies: log"))
+            .stdout(contains("Category: Security"))
+            .stdout(contains("crate-b: Patch"))
+            .stdout(contains("crate-a").not());
+    }
+
+    #[test]
+    fn reports_when_nothing_changed() {
+        let workspace = committed_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--deps-update"])
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("No dependency updates since main."));
+    }
+}
+
 mod interactive {
     use std::os::unix::fs::PermissionsExt;
 
@@ -889,3 +1092,80 @@ mod ci_detection {
             .stderr(contains("CARGO_CHANGESET_NO_TTY"));
     }
 }
+
+mod stdin_json {
+    use super::*;
+
+    fn changeset_files(workspace: &TempDir) -> Vec<String> {
+        let changeset_dir = workspace.path().join(".changeset/changesets");
+        let Ok(entries) = fs::read_dir(&changeset_dir) else {
+            return Vec::new();
+        };
+        entries
+            .filter_map(|e| e.ok())
+            .map(|e| fs::read_to_string(e.path()).expect("read changeset"))
+            .collect()
+    }
+
+    #[test]
+    fn creates_every_changeset_from_stdin() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--stdin-json"])
+            .write_stdin(
+                r#"[
+                    {"releases": {"crate-a": "minor"}, "category": "added", "summary": "Add parser"},
+                    {"packages": ["crate-a", "crate-b"], "bump": "patch", "summary": "Fix docs"}
+                ]"#,
+            )
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("Created changeset:").count(2));
+
+        let files = changeset_files(&workspace);
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|content| content.contains("Add parser")
+            && content.contains("crate-a: minor")
+            && content.contains("category: added")));
+        assert!(
+            files
+                .iter()
+                .any(|content| content.contains("Fix docs") && content.contains("crate-b: patch"))
+        );
+    }
+
+    #[test]
+    fn writes_nothing_when_one_changeset_is_invalid() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--stdin-json"])
+            .write_stdin(
+                r#"[
+                    {"releases": {"crate-a": "minor"}, "summary": "Add parser"},
+                    {"releases": {"crate-c": "patch"}, "summary": "Unknown crate"}
+                ]"#,
+            )
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("crate-c"));
+
+        assert!(changeset_files(&workspace).is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["add", "--stdin-json"])
+            .write_stdin(r#"[{"releases": {"crate-a": "minor"}, "title": "Add parser"}]"#)
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("failed to parse changesets from stdin"));
+    }
+}
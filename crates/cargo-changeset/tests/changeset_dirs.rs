@@ -0,0 +1,195 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"[workspace]
+members = ["crates/*"]
+resolver = "2"
+
+[workspace.metadata.changeset]
+changeset-dir = "docs/changes"
+changeset-dirs = { web = "teams/web/changes" }
+"#,
+    )
+    .expect("write workspace Cargo.toml");
+    for name in ["crate-a", "crate-b"] {
+        fs::create_dir_all(dir.path().join(format!("crates/{name}/src")))
+            .expect("create crate dir");
+        fs::write(
+            dir.path().join(format!("crates/{name}/Cargo.toml")),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(dir.path().join(format!("crates/{name}/src/lib.rs")), "").expect("write lib.rs");
+    }
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn write_changeset(dir: &TempDir, changeset_dir: &str, name: &str, package: &str) {
+    let changesets = dir.path().join(changeset_dir).join("changesets");
+    fs::create_dir_all(&changesets).expect("create changesets dir");
+    fs::write(
+        changesets.join(format!("{name}.md")),
+        format!("---\n\"{package}\": patch\n---\n\nFix {name}\n"),
+    )
+    .expect("write changeset");
+}
+
+fn changesets_in(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir.join("changesets"))
+        .map(|entries| {
+            entries
+                .map(|entry| entry.expect("read entry").file_name())
+                .map(|name| name.to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn cmd(dir: &TempDir) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset");
+    cmd.current_dir(dir.path());
+    cmd
+}
+
+#[test]
+fn add_writes_to_configured_changeset_dir() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["add", "-p", "crate-a", "-b", "patch", "-m", "Fix a bug"])
+        .assert()
+        .success()
+        .stdout(contains("docs/changes/changesets/"));
+
+    assert_eq!(changesets_in(&dir.path().join("docs/changes")).len(), 1);
+    assert!(!dir.path().join(".changeset").exists());
+}
+
+#[test]
+fn add_with_owner_writes_to_owned_dir() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["add", "-p", "crate-a", "-b", "patch", "-m", "Fix a bug"])
+        .args(["--owner", "web"])
+        .assert()
+        .success()
+        .stdout(contains("teams/web/changes/changesets/"));
+
+    assert_eq!(
+        changesets_in(&dir.path().join("teams/web/changes")).len(),
+        1
+    );
+    assert!(changesets_in(&dir.path().join("docs/changes")).is_empty());
+}
+
+#[test]
+fn add_with_unknown_owner_fails() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["add", "-p", "crate-a", "-b", "patch", "-m", "Fix a bug"])
+        .args(["--owner", "mobile"])
+        .assert()
+        .failure()
+        .stderr(contains("error[CS0065]"))
+        .stderr(contains("owners: web"));
+}
+
+#[test]
+fn status_merges_changeset_dirs_and_shows_owners() {
+    let dir = create_workspace();
+    write_changeset(&dir, "docs/changes", "core", "crate-a");
+    write_changeset(&dir, "teams/web/changes", "button", "crate-b");
+
+    cmd(&dir)
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(contains("Pending changesets: 2"))
+        .stdout(contains("  core.md\n"))
+        .stdout(contains("  button.md [web]"))
+        .stdout(contains("crate-a: 1.0.0 -> 1.0.1"))
+        .stdout(contains("crate-b: 1.0.0 -> 1.0.1"));
+}
+
+#[test]
+fn release_consumes_changesets_from_every_dir() {
+    let dir = create_workspace();
+    write_changeset(&dir, "docs/changes", "core", "crate-a");
+    write_changeset(&dir, "teams/web/changes", "button", "crate-b");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Add changesets"]);
+
+    cmd(&dir)
+        .arg("release")
+        .assert()
+        .success()
+        .stdout(contains("Consumed 2 changeset file(s)"))
+        .stdout(contains("  primary directory: 1"))
+        .stdout(contains("  web: 1"));
+
+    assert!(changesets_in(&dir.path().join("docs/changes")).is_empty());
+    assert!(changesets_in(&dir.path().join("teams/web/changes")).is_empty());
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(changelog.contains("Fix core"));
+    assert!(changelog.contains("Fix button"));
+}
+
+#[test]
+fn add_names_changesets_after_the_branch() {
+    let dir = create_workspace();
+    git(&dir, &["checkout", "-b", "feature/login"]);
+
+    cmd(&dir)
+        .args(["--config", "changeset-names=branch"])
+        .args(["add", "-p", "crate-a", "-b", "patch", "-m", "Fix a bug"])
+        .assert()
+        .success()
+        .stdout(contains("docs/changes/changesets/feature-login-"));
+}
+
+#[test]
+fn verify_rejects_same_changeset_name_in_two_dirs() {
+    let dir = create_workspace();
+    git(&dir, &["checkout", "-b", "feature"]);
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "// fix").expect("write lib.rs");
+    write_changeset(&dir, "docs/changes", "fix", "crate-a");
+    write_changeset(&dir, "teams/web/changes", "fix", "crate-b");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Fix"]);
+
+    cmd(&dir)
+        .args(["verify", "--base", "main"])
+        .assert()
+        .failure()
+        .stderr(contains("Duplicate changesets:"))
+        .stderr(contains(
+            "docs/changes/changesets/fix.md: same file name as teams/web/changes/changesets/fix.md",
+        ));
+}
@@ -0,0 +1,164 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_half_released_project() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.1\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Release my-crate 1.0.1"]);
+    git(&dir, &["tag", "v1.0.1"]);
+
+    let journal = serde_json::json!({
+        "projectRoot": dir.path(),
+        "entries": [
+            {
+                "step": "create-tags",
+                "description": "delete the created tags",
+                "actions": [{ "action": "delete-tag", "name": "v1.0.1" }],
+                "error": "tag is locked"
+            },
+            {
+                "step": "write-manifests",
+                "description": "restore original package versions in Cargo.toml files",
+                "actions": [{
+                    "action": "write-version",
+                    "manifest": dir.path().join("Cargo.toml"),
+                    "version": "1.0.0"
+                }],
+                "error": "permission denied"
+            }
+        ]
+    });
+    fs::write(
+        dir.path().join(".changeset/release-journal.json"),
+        journal.to_string(),
+    )
+    .expect("write journal");
+
+    dir
+}
+
+#[test]
+fn dry_run_lists_compensations_without_applying_them() {
+    let dir = create_half_released_project();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["compensate", "--dry-run"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("create-tags - delete the created tags"))
+        .stdout(contains("delete tag v1.0.1"))
+        .stdout(contains("set version 1.0.0 in"));
+
+    assert_eq!(git(&dir, &["tag", "--list"]).trim(), "v1.0.1");
+    assert!(dir.path().join(".changeset/release-journal.json").exists());
+}
+
+#[test]
+fn applies_compensations_and_removes_journal() {
+    let dir = create_half_released_project();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["compensate", "--journal", ".changeset/release-journal.json"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("All compensations applied"));
+
+    assert!(git(&dir, &["tag", "--list"]).trim().is_empty());
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.0\""));
+    assert!(!dir.path().join(".changeset/release-journal.json").exists());
+}
+
+#[test]
+fn missing_journal_is_reported() {
+    let dir = create_half_released_project();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["compensate", "--journal", "missing.json"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("failed to read compensation journal"));
+}
+
+#[test]
+fn retried_release_resumes_tags_left_in_place() {
+    let dir = TempDir::new().expect("create temp dir");
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+    git(&dir, &["tag", "v1.0.1"]);
+
+    let journal = serde_json::json!({
+        "projectRoot": dir.path(),
+        "entries": [{
+            "step": "create-tags",
+            "description": "delete the created tags",
+            "actions": [{ "action": "delete-tag", "name": "v1.0.1" }],
+            "error": "tag is locked"
+        }],
+        "completed": ["create-tags:my-crate@1.0.1"]
+    });
+    let journal_path = dir.path().join(".changeset/release-journal.json");
+    fs::write(&journal_path, journal.to_string()).expect("write journal");
+    fs::write(
+        dir.path().join(".git/info/exclude"),
+        ".changeset/release-journal.json\n",
+    )
+    .expect("write exclude");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("1.0.0 -> 1.0.1"));
+
+    assert_eq!(git(&dir, &["tag", "--list"]).trim(), "v1.0.1");
+    assert!(!journal_path.exists());
+}
@@ -0,0 +1,96 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::prelude::*;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n\n\
+         [workspace.metadata.changeset]\ntags = true\n",
+    )
+    .expect("write workspace Cargo.toml");
+    fs::create_dir_all(dir.path().join("crates/crate-a/src")).expect("create crate-a dir");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        "[package]\nname = \"crate-a\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/change.md"),
+        "---\n\"crate-a\": patch\n---\n\nFixed a bug\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+    dir
+}
+
+#[test]
+fn config_flags_override_manifest_settings() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "release",
+            "--config",
+            "tags=false",
+            "--config",
+            "changelog=per-package",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert!(git(&dir, &["tag", "--list"]).trim().is_empty());
+    assert!(dir.path().join("crates/crate-a/CHANGELOG.md").is_file());
+    assert!(!dir.path().join("CHANGELOG.md").exists());
+}
+
+#[test]
+fn unknown_config_key_is_rejected() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["--config", "git.tags=false", "status"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("unknown config key 'git'"));
+}
+
+#[test]
+fn config_value_of_the_wrong_type_is_rejected() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["--config", "tags=maybe", "status"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("--config <KEY=VALUE>"))
+        .stderr(contains("invalid value for 'tags'"))
+        .stderr(contains("expected a boolean"))
+        .stderr(contains("Cargo.toml").not());
+}
@@ -0,0 +1,139 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const CHANGESET: &str = "---\n\"my-crate\": patch\n---\n\nFix a bug\n";
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_package_with_consumed_state_file() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n[package.metadata.changeset]\nconsumed-state = \"file\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(dir.path().join(".changeset/changesets/fix.md"), CHANGESET).expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn prerelease_records_consumption_outside_changeset_files() {
+    let dir = create_package_with_consumed_state_file();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--prerelease", "alpha"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changeset = fs::read_to_string(dir.path().join(".changeset/changesets/fix.md"))
+        .expect("read changeset");
+    assert_eq!(changeset, CHANGESET);
+    let state = fs::read_to_string(dir.path().join(".changeset/consumed.toml"))
+        .expect("read consumed.toml");
+    assert!(state.contains("\"1.0.1-alpha.1\""));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("status")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("fix.md (consumed for 1.0.1-alpha.1)"));
+}
+
+#[test]
+fn graduation_removes_consumed_state_file() {
+    let dir = create_package_with_consumed_state_file();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--prerelease", "alpha"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Record consumed changesets"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("1.0.1-alpha.1 -> 1.0.1"));
+
+    assert!(!dir.path().join(".changeset/consumed.toml").exists());
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(changelog.contains("## [1.0.1]"));
+}
+
+#[test]
+fn consumed_state_file_is_committed_with_each_release() {
+    let dir = create_package_with_consumed_state_file();
+    let git_status = || {
+        let output = Command::new("git")
+            .args(["status", "--porcelain"])
+            .current_dir(dir.path())
+            .output()
+            .expect("failed to run git");
+        String::from_utf8(output.stdout).expect("git status is UTF-8")
+    };
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--prerelease", "alpha"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+    assert_eq!(git_status(), "");
+
+    fs::write(
+        dir.path().join(".changeset/changesets/typo.md"),
+        CHANGESET.replace("bug", "typo"),
+    )
+    .expect("write changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Add changeset"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--prerelease", "alpha"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("1.0.1-alpha.1 -> 1.0.1-alpha.2"));
+    assert_eq!(git_status(), "");
+    let state = fs::read_to_string(dir.path().join(".changeset/consumed.toml"))
+        .expect("read consumed.toml");
+    assert!(state.contains("\"1.0.1-alpha.2\""));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("1.0.1-alpha.2 -> 1.0.1"));
+    assert_eq!(git_status(), "");
+    assert!(!dir.path().join(".changeset/consumed.toml").exists());
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(changelog.contains("Fix a bug"));
+    assert!(changelog.contains("Fix a typo"));
+}
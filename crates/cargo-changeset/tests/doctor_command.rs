@@ -0,0 +1,132 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_tagged_workspace(tag: &str) -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::create_dir_all(dir.path().join("crates/crate-a/src"))
+        .expect("failed to create crate a dir");
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/*"]
+resolver = "2"
+"#,
+    )
+    .expect("failed to write workspace Cargo.toml");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        r#"
+[package]
+name = "crate-a"
+version = "0.2.0"
+edition = "2021"
+"#,
+    )
+    .expect("failed to write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "")
+        .expect("failed to write crate-a lib.rs");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+    git(&dir, &["tag", tag]);
+
+    dir
+}
+
+#[test]
+fn doctor_passes_when_versions_match_tags() {
+    let dir = create_tagged_workspace("crate-a@v0.2.0");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("doctor")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("No problems found"));
+}
+
+#[test]
+fn doctor_fails_on_version_drift() {
+    let dir = create_tagged_workspace("crate-a@v0.1.0");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("doctor")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains(
+            "crate-a: Cargo.toml 0.2.0 vs tag 0.1.0 (manifest ahead of tag)",
+        ));
+}
+
+#[test]
+fn status_warns_about_version_drift() {
+    let dir = create_tagged_workspace("crate-a@v0.3.0");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("status")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains(
+            "Warning: Manifest versions differ from latest tags:",
+        ))
+        .stdout(contains("tag ahead of manifest"));
+}
+
+#[test]
+fn doctor_migrates_outdated_state_files_on_request() {
+    let dir = create_tagged_workspace("crate-a@v0.2.0");
+    let changeset_dir = dir.path().join(".changeset");
+    fs::create_dir_all(&changeset_dir).expect("failed to create changeset dir");
+    fs::write(
+        changeset_dir.join("pre-release.toml"),
+        "crate-a = \"alpha\"\n",
+    )
+    .expect("failed to write pre-release.toml");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("doctor")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("pre-release.toml uses config_version 1"));
+
+    let content = fs::read_to_string(changeset_dir.join("pre-release.toml"))
+        .expect("failed to read pre-release.toml");
+    assert_eq!(
+        content, "crate-a = \"alpha\"\n",
+        "doctor must not rewrite without --migrate"
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["doctor", "--migrate"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains(
+            "Migrated pre-release.toml from config_version 1 to 2",
+        ));
+
+    let content = fs::read_to_string(changeset_dir.join("pre-release.toml"))
+        .expect("failed to read pre-release.toml");
+    assert!(content.contains("config_version = 2"));
+    assert!(content.contains("[packages]"));
+}
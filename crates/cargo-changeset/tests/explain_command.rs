@@ -0,0 +1,61 @@
+use std::fs;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+#[test]
+fn explains_error_code() {
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["explain", "CS0030"])
+        .assert()
+        .success()
+        .stdout(contains("CS0030 DirtyWorkingTree"))
+        .stdout(contains("--on-dirty"));
+}
+
+#[test]
+fn lists_all_codes_without_argument() {
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["explain"])
+        .assert()
+        .success()
+        .stdout(contains("CS0001  Core"))
+        .stdout(contains("CS0064  SagaCompensationFailed"));
+}
+
+#[test]
+fn rejects_unknown_code() {
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["explain", "CS9999"])
+        .assert()
+        .failure()
+        .stderr(contains("unknown error code 'CS9999'"));
+}
+
+#[test]
+fn operation_errors_print_their_code() {
+    let dir = TempDir::new().expect("create temp dir");
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+    for name in ["crate-a", "crate-b"] {
+        fs::create_dir_all(dir.path().join(format!("crates/{name}/src")))
+            .expect("create crate dir");
+        fs::write(
+            dir.path().join(format!("crates/{name}/Cargo.toml")),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(dir.path().join(format!("crates/{name}/src/lib.rs")), "").expect("write lib.rs");
+    }
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["next-version"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("error[CS0015]: a package is required"))
+        .stderr(contains("run `cargo changeset explain CS0015`"));
+}
@@ -0,0 +1,118 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    assert!(output.status.success(), "git {args:?} failed");
+}
+
+fn write_changeset(dir: &TempDir, name: &str, package: &str, summary: &str) {
+    fs::write(
+        dir.path().join(".changeset/changesets").join(name),
+        format!("---\n\"{package}\": patch\ncategory: fixed\n---\n\n{summary}\n"),
+    )
+    .expect("write changeset");
+}
+
+/// A workspace with one released and one pending changeset.
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write Cargo.toml");
+    for name in ["crate-a", "crate-b"] {
+        let path = dir.path().join("crates").join(name);
+        fs::create_dir_all(path.join("src")).expect("create src dir");
+        fs::write(
+            path.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+        )
+        .expect("write Cargo.toml");
+        fs::write(path.join("src/lib.rs"), "").expect("write lib.rs");
+    }
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    write_changeset(&dir, "old.md", "crate-b", "Fix panic on empty input");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Add changeset"]);
+
+    fs::remove_file(dir.path().join(".changeset/changesets/old.md")).expect("remove changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Release"]);
+
+    write_changeset(&dir, "new.md", "crate-a", "Fix panic in parser");
+    dir
+}
+
+#[test]
+fn grep_lists_pending_and_released_changesets() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["grep", "PANIC", "-i"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains(".changeset/changesets/new.md (pending)"))
+        .stdout(contains("  Fix panic in parser"))
+        .stdout(contains(".changeset/changesets/old.md (released)"))
+        .stdout(contains("  Fix panic on empty input"));
+}
+
+#[test]
+fn grep_filters_by_package_and_history() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["grep", "panic", "--package", "crate-b"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("old.md (released)").and(contains("new.md").not()));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["grep", "crate-b: patch", "--pending"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("No matching changesets."));
+}
+
+#[test]
+fn grep_filters_by_date() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["grep", "panic", "--until", "2000-01-01"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("No matching changesets."));
+}
+
+#[test]
+fn grep_rejects_invalid_pattern() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["grep", "fix("])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("invalid search pattern 'fix('"));
+}
@@ -0,0 +1,218 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_workspace_with_git() -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::create_dir_all(dir.path().join("crates/crate-a/src"))
+        .expect("failed to create crate a dir");
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/*"]
+resolver = "2"
+"#,
+    )
+    .expect("failed to write workspace Cargo.toml");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        r#"
+[package]
+name = "crate-a"
+version = "0.1.0"
+edition = "2021"
+"#,
+    )
+    .expect("failed to write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "")
+        .expect("failed to write crate-a lib.rs");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn add_changeset(dir: &TempDir, package_name: &str) {
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("failed to create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/change.md"),
+        format!(
+            r#"---
+"{package_name}": patch
+---
+
+Test changeset for {package_name}.
+"#
+        ),
+    )
+    .expect("failed to write changeset");
+}
+
+#[test]
+fn hook_install_writes_pre_commit_script() {
+    let workspace = create_workspace_with_git();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["hook", "install"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains("pre-commit"));
+
+    let script = fs::read_to_string(workspace.path().join(".git/hooks/pre-commit"))
+        .expect("pre-commit hook should be written");
+    assert!(script.contains("cargo changeset hook run pre-commit"));
+}
+
+#[test]
+fn hook_install_fails_when_hook_exists() {
+    let workspace = create_workspace_with_git();
+    fs::create_dir_all(workspace.path().join(".git/hooks")).expect("failed to create hooks dir");
+    fs::write(
+        workspace.path().join(".git/hooks/pre-commit"),
+        "#!/bin/sh\n",
+    )
+    .expect("failed to write existing hook");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["hook", "install", "pre-commit"])
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("already exists"));
+}
+
+#[test]
+fn hook_run_fails_when_staged_change_uncovered() {
+    let workspace = create_workspace_with_git();
+    fs::write(
+        workspace.path().join("crates/crate-a/src/lib.rs"),
+        "// changed",
+    )
+    .expect("failed to modify lib.rs");
+    git(&workspace, &["add", "-A"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["hook", "run", "pre-commit"])
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("crate-a"));
+}
+
+#[test]
+fn hook_run_succeeds_when_staged_change_covered() {
+    let workspace = create_workspace_with_git();
+    fs::write(
+        workspace.path().join("crates/crate-a/src/lib.rs"),
+        "// changed",
+    )
+    .expect("failed to modify lib.rs");
+    add_changeset(&workspace, "crate-a");
+    git(&workspace, &["add", "-A"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["hook", "run", "commit-msg", ".git/COMMIT_EDITMSG"])
+        .current_dir(workspace.path())
+        .assert()
+        .success();
+}
+
+#[test]
+fn hook_run_ignores_unstaged_changes() {
+    let workspace = create_workspace_with_git();
+    fs::write(
+        workspace.path().join("crates/crate-a/src/lib.rs"),
+        "// changed",
+    )
+    .expect("failed to modify lib.rs");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["hook", "run", "pre-commit"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains("No files changed"));
+}
+
+#[test]
+fn hook_install_merge_driver_registers_state_files() {
+    let workspace = create_workspace_with_git();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["hook", "install", "--merge-driver"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains(".gitattributes"));
+
+    let attributes = fs::read_to_string(workspace.path().join(".gitattributes"))
+        .expect(".gitattributes should be written");
+    assert!(attributes.contains("pre-release.toml merge=changeset-state"));
+    assert!(attributes.contains("graduation.toml merge=changeset-state"));
+    assert!(!workspace.path().join(".git/hooks/pre-commit").exists());
+
+    let driver = Command::new("git")
+        .args(["config", "merge.changeset-state.driver"])
+        .current_dir(workspace.path())
+        .output()
+        .expect("failed to run git");
+    assert!(String::from_utf8_lossy(&driver.stdout).contains("cargo changeset merge-state"));
+}
+
+#[test]
+fn merge_state_fails_on_conflicting_tags() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    fs::write(
+        dir.path().join("base"),
+        "config_version = 2\n\n[packages]\ncrate-a = \"alpha\"\n",
+    )
+    .expect("failed to write base");
+    fs::write(
+        dir.path().join("ours"),
+        "config_version = 2\n\n[packages]\ncrate-a = \"beta\"\n",
+    )
+    .expect("failed to write ours");
+    fs::write(
+        dir.path().join("theirs"),
+        "config_version = 2\n\n[packages]\ncrate-a = \"rc\"\ncrate-b = \"alpha\"\n",
+    )
+    .expect("failed to write theirs");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "merge-state",
+            "--base",
+            "base",
+            "--ours",
+            "ours",
+            "--theirs",
+            "theirs",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("pre-release tag of crate-a"));
+
+    let merged = fs::read_to_string(dir.path().join("ours")).expect("failed to read ours");
+    assert!(merged.contains("crate-a = \"beta\""));
+    assert!(merged.contains("crate-b = \"alpha\""));
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_package_with_changeset() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn stderr_json(output: &std::process::Output) -> serde_json::Value {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    serde_json::from_str(stderr.trim()).unwrap_or_else(|e| panic!("{e}: {stderr}"))
+}
+
+#[test]
+fn json_output_serializes_errors_on_stderr() {
+    let dir = create_package_with_changeset();
+    fs::write(dir.path().join("src/lib.rs"), "pub fn wip() {}\n").expect("modify lib.rs");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--output", "json"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+
+    let error = stderr_json(&output);
+    assert_eq!(error["code"], "CS0030");
+    assert_eq!(error["name"], "DirtyWorkingTree");
+    assert_eq!(error["files"], serde_json::json!(["src/lib.rs"]));
+    assert_eq!(error["step"], serde_json::Value::Null);
+}
+
+#[test]
+fn text_output_is_the_default() {
+    let dir = create_package_with_changeset();
+    fs::write(dir.path().join("src/lib.rs"), "pub fn wip() {}\n").expect("modify lib.rs");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("error[CS0030]"), "{stderr}");
+}
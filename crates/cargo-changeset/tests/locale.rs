@@ -0,0 +1,82 @@
+use std::fs;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+    for name in ["crate-a", "crate-b"] {
+        fs::create_dir_all(dir.path().join(format!("crates/{name}/src")))
+            .expect("create crate dir");
+        fs::write(
+            dir.path().join(format!("crates/{name}/Cargo.toml")),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(dir.path().join(format!("crates/{name}/src/lib.rs")), "").expect("write lib.rs");
+    }
+    dir
+}
+
+fn next_version(dir: &TempDir) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset");
+    cmd.args(["next-version"])
+        .current_dir(dir.path())
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env_remove("LANG");
+    cmd
+}
+
+#[test]
+fn locale_flag_selects_german_messages() {
+    let dir = create_workspace();
+
+    next_version(&dir)
+        .args(["--locale", "de"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "error[CS0015]: in einem Workspace ist ein Paket erforderlich",
+        ))
+        .stderr(contains("Weitere Informationen zu diesem Fehler"));
+}
+
+#[test]
+fn lang_selects_german_messages() {
+    let dir = create_workspace();
+
+    next_version(&dir)
+        .env("LANG", "de_DE.UTF-8")
+        .assert()
+        .failure()
+        .stderr(contains("in einem Workspace ist ein Paket erforderlich"));
+}
+
+#[test]
+fn locale_flag_overrides_lang() {
+    let dir = create_workspace();
+
+    next_version(&dir)
+        .env("LANG", "de_DE.UTF-8")
+        .args(["--locale", "en"])
+        .assert()
+        .failure()
+        .stderr(contains("a package is required in a workspace"));
+}
+
+#[test]
+fn unsupported_locale_falls_back_to_english() {
+    let dir = create_workspace();
+
+    next_version(&dir)
+        .env("LANG", "fr_FR.UTF-8")
+        .assert()
+        .failure()
+        .stderr(contains("a package is required in a workspace"));
+}
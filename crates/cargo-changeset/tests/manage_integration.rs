@@ -373,6 +373,27 @@ mod manage_prerelease {
             .stderr(contains("invalid prerelease tag"));
     }
 
+    #[test]
+    fn add_with_leading_zero_tag_explains_rule() {
+        let workspace = create_virtual_workspace();
+        fs::create_dir_all(workspace.path().join(".changeset")).expect("create changeset dir");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["manage", "pre-release", "--add", "crate-a:007"])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("invalid prerelease tag '007'"))
+            .stderr(contains("must not have leading zeros"));
+
+        assert!(
+            !workspace
+                .path()
+                .join(".changeset/pre-release.toml")
+                .exists()
+        );
+    }
+
     #[test]
     fn graduate_prerelease_version_fails() {
         let workspace = create_workspace_with_prerelease_version();
@@ -681,4 +702,88 @@ mod manage_graduation {
             .stderr(contains("cannot graduate"))
             .stderr(contains("stable"));
     }
+
+    #[test]
+    fn list_shows_graduation_policies() {
+        let workspace = create_virtual_workspace();
+        fs::create_dir_all(workspace.path().join(".changeset")).expect("create changeset dir");
+        fs::write(
+            workspace.path().join(".changeset/graduation.toml"),
+            "config_version = 2\ngraduation = []\n\n[policies.crate-a]\ngraduate_after = \"2025-01-01\"\n\n[policies.crate-b]\ngraduate_on = \"major\"\n",
+        )
+        .expect("write graduation.toml");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["manage", "graduation", "--list"])
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("crate-a (on or after 2025-01-01)"))
+            .stdout(contains("crate-b (on a major release)"));
+    }
+
+    #[test]
+    fn remove_drops_graduation_policy() {
+        let workspace = create_virtual_workspace();
+        fs::create_dir_all(workspace.path().join(".changeset")).expect("create changeset dir");
+        let graduation_path = workspace.path().join(".changeset/graduation.toml");
+        fs::write(
+            &graduation_path,
+            "config_version = 2\n\n[policies.crate-a]\ngraduate_on = \"major\"\n",
+        )
+        .expect("write graduation.toml");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["manage", "graduation", "--remove", "crate-a"])
+            .current_dir(workspace.path())
+            .assert()
+            .success()
+            .stdout(contains("Removed crate-a from graduation queue"));
+
+        assert!(
+            !graduation_path.exists(),
+            "graduation.toml should be deleted once the queue is empty"
+        );
+    }
+}
+
+mod manage_interactive {
+    use super::*;
+
+    #[test]
+    fn interactive_in_non_tty_fails() {
+        let workspace = create_virtual_workspace();
+        fs::create_dir_all(workspace.path().join(".changeset")).expect("create changeset dir");
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["manage", "--interactive"])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("interactive mode requires a terminal"));
+    }
+
+    #[test]
+    fn interactive_conflicts_with_subcommand() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["manage", "--interactive", "graduation", "--list"])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("cannot be used with"));
+    }
+
+    #[test]
+    fn manage_without_arguments_shows_help() {
+        let workspace = create_virtual_workspace();
+
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .args(["manage"])
+            .current_dir(workspace.path())
+            .assert()
+            .failure()
+            .stderr(contains("--interactive"));
+    }
 }
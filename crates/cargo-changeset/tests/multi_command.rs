@@ -0,0 +1,102 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_repository(root: &Path, name: &str) {
+    let dir = root.join(name);
+    fs::create_dir_all(dir.join("src")).expect("create src dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+    )
+    .expect("write Cargo.toml");
+    fs::write(dir.join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.join(".changeset/changesets")).expect("create .changeset dir");
+    fs::write(dir.join(".changeset/changesets/.gitkeep"), "").expect("write .gitkeep");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+}
+
+fn create_setup() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+    create_repository(dir.path(), "core");
+    create_repository(dir.path(), "utils");
+    fs::write(
+        dir.path().join("changeset-workspaces.toml"),
+        "[[repository]]\npath = \"core\"\n\n[[repository]]\npath = \"utils\"\nname = \"shared-utils\"\n",
+    )
+    .expect("write changeset-workspaces.toml");
+    dir
+}
+
+#[test]
+fn status_runs_in_every_repository() {
+    let dir = create_setup();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["multi", "status"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("==> core ("))
+        .stdout(contains("==> shared-utils ("))
+        .stdout(contains("  ok      core\n"))
+        .stdout(contains("  ok      shared-utils\n"));
+}
+
+#[test]
+fn verify_failure_in_one_repository_fails_the_run() {
+    let dir = create_setup();
+    let core = dir.path().join("core");
+    git(&core, &["checkout", "-b", "feature"]);
+    fs::write(core.join("src/lib.rs"), "pub fn added() {}\n").expect("write lib.rs");
+    git(&core, &["add", "-A"]);
+    git(&core, &["commit", "-m", "Change core"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["multi", "verify"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stdout(contains("  failed  core\n"))
+        .stdout(contains("  ok      shared-utils\n"))
+        .stderr(contains("1 repository(ies) failed: core"));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["multi", "--fail-fast", "verify"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stdout(contains("  skipped shared-utils\n"))
+        .stdout(contains("==> shared-utils").not());
+}
+
+#[test]
+fn missing_config_is_reported() {
+    let dir = TempDir::new().expect("create temp dir");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["multi", "status"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("no changeset-workspaces.toml found"));
+}
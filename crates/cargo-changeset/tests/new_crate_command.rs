@@ -0,0 +1,91 @@
+use std::fs;
+
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn create_workspace(extra_config: &str) -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    fs::create_dir_all(dir.path().join("crates/a/src")).expect("failed to create crate dir");
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!("[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n{extra_config}"),
+    )
+    .expect("failed to write workspace Cargo.toml");
+    fs::write(
+        dir.path().join("crates/a/Cargo.toml"),
+        "[package]\nname = \"crate-a\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    )
+    .expect("failed to write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/a/src/lib.rs"), "").expect("failed to write lib.rs");
+    dir
+}
+
+#[test]
+fn scaffolds_crate_covered_by_member_glob() {
+    let workspace = create_workspace("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["new-crate", "parser", "--initial-release"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains("Created crate:"))
+        .stdout(contains("Added it to the workspace members").not());
+
+    let manifest = fs::read_to_string(workspace.path().join("crates/parser/Cargo.toml"))
+        .expect("read new manifest");
+    assert!(manifest.contains("name = \"parser\"\nversion = \"0.1.0\""));
+    assert!(workspace.path().join("crates/parser/src/lib.rs").exists());
+    let root = fs::read_to_string(workspace.path().join("Cargo.toml")).expect("read root");
+    assert!(root.contains("members = [\"crates/*\"]"));
+
+    let changesets: Vec<_> = fs::read_dir(workspace.path().join(".changeset/changesets"))
+        .expect("read changesets")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| fs::read_to_string(entry.path()).expect("read changeset"))
+        .collect();
+    assert_eq!(changesets.len(), 1);
+    assert!(changesets[0].contains("parser: minor"));
+    assert!(changesets[0].contains("Initial release"));
+}
+
+#[test]
+fn registers_crate_outside_member_globs_with_template_metadata() {
+    let workspace = create_workspace(
+        "\n[workspace.metadata.changeset.new-crate]\npath = \"tools/{name}\"\n\
+         package-metadata = { require-changeset = \"always\" }\n",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["new-crate", "xtask", "--bin"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains("Added it to the workspace members"));
+
+    let root = fs::read_to_string(workspace.path().join("Cargo.toml")).expect("read root");
+    assert!(root.contains("members = [\"crates/*\", \"tools/xtask\"]"));
+    let manifest = fs::read_to_string(workspace.path().join("tools/xtask/Cargo.toml"))
+        .expect("read new manifest");
+    assert!(manifest.contains("[package.metadata.changeset]\nrequire-changeset = \"always\"\n"));
+    assert!(workspace.path().join("tools/xtask/src/main.rs").exists());
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("status")
+        .current_dir(workspace.path())
+        .assert()
+        .success();
+}
+
+#[test]
+fn refuses_existing_package_name() {
+    let workspace = create_workspace("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["new-crate", "crate-a"])
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("CS0081"));
+}
@@ -0,0 +1,77 @@
+use std::fs;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn create_workspace_with_changeset() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+
+    for (name, version) in [("crate-a", "1.0.0"), ("crate-b", "2.0.0")] {
+        fs::create_dir_all(dir.path().join(format!("crates/{name}/src")))
+            .expect("create crate dir");
+        fs::write(
+            dir.path().join(format!("crates/{name}/Cargo.toml")),
+            format!("[package]\nname = \"{name}\"\nversion = \"{version}\"\nedition = \"2021\"\n"),
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(dir.path().join(format!("crates/{name}/src/lib.rs")), "").expect("write lib.rs");
+    }
+
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/feature.md"),
+        "---\n\"crate-a\": minor\n---\n\nAdd a feature\n",
+    )
+    .expect("write changeset");
+
+    dir
+}
+
+#[test]
+fn prints_next_version_in_plain_form() {
+    let dir = create_workspace_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["next-version", "--package", "crate-a"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout("1.1.0\n");
+}
+
+#[test]
+fn prints_next_version_as_json() {
+    let dir = create_workspace_with_changeset();
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["next-version", "--package", "crate-b", "--output", "json"])
+        .current_dir(dir.path())
+        .output()
+        .expect("run next-version");
+
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout is JSON");
+    assert_eq!(value["package"], "crate-b");
+    assert_eq!(value["currentVersion"], "2.0.0");
+    assert_eq!(value["nextVersion"], "2.0.0");
+    assert!(value["bump"].is_null());
+}
+
+#[test]
+fn workspace_requires_package() {
+    let dir = create_workspace_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("next-version")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("--package"));
+}
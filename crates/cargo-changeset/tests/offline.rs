@@ -0,0 +1,126 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::prelude::*;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// A package with a changeset whose branch tracks a remote that cannot be reached.
+fn create_package_with_unreachable_upstream(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+    let missing = dir.path().join("missing-remote.git");
+    git(
+        dir.path(),
+        &["remote", "add", "origin", &missing.to_string_lossy()],
+    );
+    git(dir.path(), &["config", "branch.main.remote", "origin"]);
+    git(
+        dir.path(),
+        &["config", "branch.main.merge", "refs/heads/main"],
+    );
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n[package.metadata.changeset]\n{metadata}"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_fails_when_upstream_is_unreachable() {
+    let dir = create_package_with_unreachable_upstream("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("failed to fetch from remote 'origin'"));
+}
+
+#[test]
+fn offline_release_skips_remote_check() {
+    let dir = create_package_with_unreachable_upstream("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["--offline", "release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert!(git(dir.path(), &["tag", "--list"]).contains("v1.0.1"));
+}
+
+#[test]
+fn offline_release_skips_notification() {
+    let dir =
+        create_package_with_unreachable_upstream("webhook-url = \"http://127.0.0.1:9/hook\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--offline"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Release notification skipped"))
+        .stderr(contains("warning").not());
+}
+
+#[test]
+fn offline_error_behavior_rejects_network_steps() {
+    let dir = create_package_with_unreachable_upstream("offline-behavior = \"error\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["--offline", "release"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("needs network access"));
+
+    assert!(dir.path().join(".changeset/changesets/fix.md").exists());
+}
+
+#[test]
+fn offline_error_behavior_rejects_configured_notification() {
+    let dir = create_package_with_unreachable_upstream(
+        "offline-behavior = \"error\"\nwebhook-url = \"http://127.0.0.1:9/hook\"\n",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["--offline", "release", "--allow-stale"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("the release notification needs network access"));
+
+    assert!(dir.path().join(".changeset/changesets/fix.md").exists());
+}
@@ -0,0 +1,134 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::prelude::*;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"[workspace]
+members = ["crates/*"]
+resolver = "2"
+
+[workspace.metadata.changeset.owners]
+crate-c = ["@org/cli"]
+"#,
+    )
+    .expect("write workspace Cargo.toml");
+    for name in ["crate-a", "crate-b", "crate-c"] {
+        fs::create_dir_all(dir.path().join(format!("crates/{name}/src")))
+            .expect("create crate dir");
+        fs::write(
+            dir.path().join(format!("crates/{name}/Cargo.toml")),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(dir.path().join(format!("crates/{name}/src/lib.rs")), "").expect("write lib.rs");
+    }
+    fs::create_dir_all(dir.path().join(".github")).expect("create .github dir");
+    fs::write(
+        dir.path().join(".github/CODEOWNERS"),
+        "/crates/crate-a/ @org/web\n/crates/crate-b/ @org/core\n",
+    )
+    .expect("write CODEOWNERS");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn cmd(dir: &TempDir) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset");
+    cmd.current_dir(dir.path());
+    cmd
+}
+
+#[test]
+fn verify_reports_owners_missing_changesets() {
+    let dir = create_workspace();
+    git(&dir, &["checkout", "-b", "feature"]);
+    for name in ["crate-a", "crate-b", "crate-c"] {
+        fs::write(
+            dir.path().join(format!("crates/{name}/src/lib.rs")),
+            "pub fn f() {}\n",
+        )
+        .expect("write lib.rs");
+    }
+    fs::create_dir_all(dir.path().join(".changeset/changesets")).expect("create changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/core.md"),
+        "---\n\"crate-b\": patch\n---\n\nFix core\n",
+    )
+    .expect("write changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Change crates"]);
+
+    cmd(&dir)
+        .args(["verify", "--base", "main"])
+        .assert()
+        .failure()
+        .stderr(contains("Owners missing changesets:"))
+        .stderr(contains("  @org/cli: crate-c"))
+        .stderr(contains("  @org/web: crate-a"))
+        .stderr(contains("@org/core").not());
+}
+
+#[test]
+fn add_owned_by_selects_the_only_owned_package() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["add", "--owned-by", "@org/web", "-b", "patch", "-m", "Fix"])
+        .assert()
+        .success()
+        .stdout(contains("  - crate-a: Patch"));
+}
+
+#[test]
+fn add_owned_by_rejects_packages_of_other_owners() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["add", "--owned-by", "@org/web", "-p", "crate-b"])
+        .args(["-b", "patch", "-m", "Fix"])
+        .assert()
+        .failure()
+        .stderr(contains("unknown package 'crate-b'"))
+        .stderr(contains("crate-a"));
+}
+
+#[test]
+fn add_owned_by_unknown_owner_fails() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args([
+            "add",
+            "--owned-by",
+            "@org/mobile",
+            "-b",
+            "patch",
+            "-m",
+            "Fix",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("error[CS0066]"));
+}
@@ -0,0 +1,167 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const ACTIONS_VARS: [&str; 3] = [
+    "GITHUB_ACTIONS",
+    "ACTIONS_ID_TOKEN_REQUEST_URL",
+    "ACTIONS_ID_TOKEN_REQUEST_TOKEN",
+];
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+    for (name, extra) in [
+        ("app", "[dependencies]\nlib = { path = \"../lib\" }\n"),
+        ("lib", ""),
+        ("xtask", "publish = false\n"),
+    ] {
+        let crate_dir = dir.path().join("crates").join(name);
+        fs::create_dir_all(crate_dir.join("src")).expect("create crate dir");
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n{extra}"
+            ),
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(crate_dir.join("src/lib.rs"), "").expect("write lib.rs");
+    }
+    dir
+}
+
+/// Writes a stand-in for cargo that logs its arguments and registry token.
+fn fake_cargo(dir: &Path) -> (PathBuf, PathBuf) {
+    let log = dir.join("cargo.log");
+    let script = dir.join("fake-cargo");
+    fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\necho \"$* token=$CARGO_REGISTRY_TOKEN\" >> '{}'\n",
+            log.display()
+        ),
+    )
+    .expect("write fake cargo");
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).expect("make executable");
+    (script, log)
+}
+
+fn cmd(dir: &TempDir) -> assert_cmd::Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset");
+    cmd.current_dir(dir.path())
+        .env_remove("CARGO_REGISTRY_TOKEN");
+    for var in ACTIONS_VARS {
+        cmd.env_remove(var);
+    }
+    cmd
+}
+
+#[test]
+fn publishes_packages_in_dependency_order() {
+    let dir = create_workspace();
+    let bin = TempDir::new().expect("create temp dir");
+    let (cargo, log) = fake_cargo(bin.path());
+
+    cmd(&dir)
+        .arg("publish")
+        .env("CARGO", &cargo)
+        .env("CARGO_REGISTRY_TOKEN", "secret")
+        .assert()
+        .success()
+        .stdout(contains("Published lib 1.0.0\nPublished app 1.0.0\n"));
+
+    let log = fs::read_to_string(log).expect("read cargo log");
+    assert_eq!(
+        log,
+        "publish --package lib token=secret\npublish --package app token=secret\n"
+    );
+}
+
+#[test]
+fn actions_job_without_credentials_fails() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .arg("publish")
+        .env("GITHUB_ACTIONS", "true")
+        .assert()
+        .failure()
+        .stderr(contains("error[CS0070]"))
+        .stderr(contains("no CARGO_REGISTRY_TOKEN is set"));
+}
+
+#[test]
+fn trusted_publishing_outside_actions_fails() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["publish", "--trusted-publishing"])
+        .assert()
+        .failure()
+        .stderr(contains("error[CS0071]"));
+}
+
+#[test]
+fn dry_run_skips_verification() {
+    let dir = create_workspace();
+    let bin = TempDir::new().expect("create temp dir");
+    let (cargo, _) = fake_cargo(bin.path());
+
+    cmd(&dir)
+        .args(["publish", "--dry-run", "--verify-timeout", "1", "--summary"])
+        .env("CARGO", &cargo)
+        .env("CARGO_REGISTRY_TOKEN", "secret")
+        .assert()
+        .success()
+        .stdout(contains("Would publish app 1.0.0"))
+        .stdout(contains("Verified").not());
+
+    assert!(!dir.path().join(".changeset/RELEASE_SUMMARY.md").exists());
+}
+
+#[test]
+fn smoke_check_requires_verify_timeout() {
+    let dir = create_workspace();
+
+    cmd(&dir)
+        .args(["publish", "--smoke-check", "install"])
+        .assert()
+        .failure()
+        .stderr(contains("--verify-timeout"));
+}
+
+#[test]
+fn publishes_packages_from_release_manifest() {
+    let dir = create_workspace();
+    let bin = TempDir::new().expect("create temp dir");
+    let (cargo, log) = fake_cargo(bin.path());
+    let manifest = dir.path().join("release-manifest.json");
+    fs::write(
+        &manifest,
+        r#"{"packages":[{"name":"app","previousVersion":"0.9.0","version":"1.0.0","bump":"minor"}],"tags":[],"commit":null}"#,
+    )
+    .expect("write release manifest");
+
+    cmd(&dir)
+        .arg("publish")
+        .arg("--manifest")
+        .arg(&manifest)
+        .env("CARGO", &cargo)
+        .env("CARGO_REGISTRY_TOKEN", "secret")
+        .assert()
+        .success()
+        .stdout(contains("Published app 1.0.0"));
+
+    let log = fs::read_to_string(log).expect("read cargo log");
+    assert_eq!(log, "publish --package app token=secret\n");
+}
@@ -0,0 +1,158 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// A package released as 1.0.1, with a changeset written after the release.
+fn create_released_package() -> TempDir {
+    create_released_package_with("", "patch")
+}
+
+/// A package released from 1.0.0 to 1.0.1 with `metadata` appended to its
+/// manifest, and a late changeset calling for a `late_bump` bump.
+fn create_released_package_with(metadata: &str, late_bump: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n{metadata}"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+    git(dir.path(), &["tag", "v1.0.0"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    fs::write(
+        dir.path().join(".changeset/changesets/late.md"),
+        format!("---\n\"my-crate\": {late_bump}\n---\n\nFix another bug\n"),
+    )
+    .expect("write late changeset");
+
+    dir
+}
+
+fn rev_parse(dir: &Path, rev: &str) -> String {
+    git(dir, &["rev-parse", rev]).trim().to_string()
+}
+
+#[test]
+fn amend_folds_late_changeset_into_release() {
+    let dir = create_released_package();
+    let parent = rev_parse(dir.path(), "HEAD~1");
+    let message = git(dir.path(), &["log", "-1", "--format=%B"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--amend"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Commit amended"))
+        .stdout(contains("v1.0.1"));
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains("Fix a bug\n- **my-crate**: Fix another bug\n"));
+    assert_eq!(changelog.matches("## [1.0.1]").count(), 1);
+
+    assert_eq!(rev_parse(dir.path(), "HEAD~1"), parent);
+    assert_eq!(git(dir.path(), &["log", "-1", "--format=%B"]), message);
+    assert_eq!(
+        rev_parse(dir.path(), "v1.0.1^{commit}"),
+        rev_parse(dir.path(), "HEAD")
+    );
+    assert!(!dir.path().join(".changeset/changesets/late.md").exists());
+    assert!(git(dir.path(), &["status", "--porcelain"]).is_empty());
+}
+
+#[test]
+fn amend_refuses_pushed_release() {
+    let dir = create_released_package();
+    let remote = TempDir::new().expect("create remote dir");
+    git(remote.path(), &["init", "--bare"]);
+    let remote_path = remote.path().to_string_lossy().into_owned();
+    git(dir.path(), &["remote", "add", "origin", &remote_path]);
+    git(dir.path(), &["push", "origin", "main"]);
+    let head = rev_parse(dir.path(), "HEAD");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--amend"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("already on the remote"));
+
+    assert_eq!(rev_parse(dir.path(), "HEAD"), head);
+    assert!(dir.path().join(".changeset/changesets/late.md").exists());
+}
+
+#[test]
+fn amend_refuses_late_changeset_above_released_bump() {
+    let dir = create_released_package_with("", "minor");
+    let head = rev_parse(dir.path(), "HEAD");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--amend"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains(
+            "version 1.1.0 of 'my-crate', above the released 1.0.1",
+        ));
+
+    assert_eq!(rev_parse(dir.path(), "HEAD"), head);
+    assert!(dir.path().join(".changeset/changesets/late.md").exists());
+}
+
+#[test]
+fn amend_rewrites_changelog_in_tag_message() {
+    let dir = create_released_package_with(
+        "\n[package.metadata.changeset]\ntag-body = \"changelog\"\n",
+        "patch",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--amend"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let message = git(
+        dir.path(),
+        &["tag", "--list", "--format=%(contents)", "v1.0.1"],
+    );
+    assert!(message.starts_with("Release my-crate v1.0.1\n\n"));
+    assert!(message.contains("Fix a bug"));
+    assert!(message.contains("Fix another bug"));
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const WORKSPACE_MANIFEST: &str = r#"[workspace]
+members = ["crates/*"]
+resolver = "2"
+
+[workspace.metadata.changeset]
+categories = [
+    { id = "perf", label = "Performance improvements", section = "Performance" },
+    { id = "fixed" },
+]
+"#;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_workspace(changeset: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(dir.path().join("Cargo.toml"), WORKSPACE_MANIFEST)
+        .expect("write workspace Cargo.toml");
+    fs::create_dir_all(dir.path().join("crates/crate-a/src")).expect("create crate-a dir");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        "[package]\nname = \"crate-a\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/change.md"),
+        changeset,
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn custom_category_gets_its_own_changelog_section() {
+    let dir = create_workspace("---\ncategory: perf\n\"crate-a\": patch\n---\n\nFaster startup\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run", "--diff"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("+### Performance\n"))
+        .stdout(contains("+- **crate-a**: Faster startup\n"));
+}
+
+#[test]
+fn release_rejects_unconfigured_category() {
+    let dir = create_workspace("---\ncategory: added\n\"crate-a\": minor\n---\n\nNew API\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("unknown category 'added'"))
+        .stderr(contains("perf, fixed"));
+}
+
+#[test]
+fn add_accepts_configured_category_id() {
+    let dir = create_workspace("---\ncategory: fixed\n\"crate-a\": patch\n---\n\nFix\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "add",
+            "--package",
+            "crate-a",
+            "--bump",
+            "patch",
+            "--category",
+            "perf",
+            "-m",
+            "Cache lookups",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "add", "-p", "crate-a", "-b", "patch", "-c", "docs", "-m", "Docs",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("unknown change category 'docs'"));
+}
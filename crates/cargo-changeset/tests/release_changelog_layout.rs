@@ -0,0 +1,199 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_workspace(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n\n\
+             [workspace.metadata.changeset]\n{metadata}"
+        ),
+    )
+    .expect("write workspace Cargo.toml");
+    fs::create_dir_all(dir.path().join("crates/crate-a/src")).expect("create crate-a dir");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        "[package]\nname = \"crate-a\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/change.md"),
+        "---\n\"crate-a\": patch\n---\n\nFixed a bug\n",
+    )
+    .expect("write changeset");
+
+    dir
+}
+
+fn commit_and_release(dir: &TempDir) {
+    git(dir, &["add", "-A"]);
+    git(dir, &["commit", "-m", "Initial commit"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+}
+
+#[test]
+fn new_changelog_starts_with_configured_preamble() {
+    let dir = create_workspace(
+        "changelog = \"per-package\"\n\
+         changelog-preamble = \"# {package}\\n\\nAll releases of {package}.\\n\"\n",
+    );
+
+    commit_and_release(&dir);
+
+    let content =
+        fs::read_to_string(dir.path().join("crates/crate-a/CHANGELOG.md")).expect("read changelog");
+    assert!(
+        content.starts_with("# crate-a\n\nAll releases of crate-a.\n\n## [1.0.1] - ",),
+        "unexpected changelog:\n{content}"
+    );
+}
+
+#[test]
+fn custom_header_and_footer_survive_release() {
+    let dir = create_workspace("");
+    let header = "# Changelog\n\nSee the website for older releases.\n\n";
+    let footer = "\n---\n\nMaintained by the crate-a team.\n";
+    fs::write(
+        dir.path().join("CHANGELOG.md"),
+        format!("{header}## [1.0.0] - 2025-01-01\n### Added\n\n- Initial release\n{footer}"),
+    )
+    .expect("write changelog");
+
+    commit_and_release(&dir);
+
+    let content = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(
+        content.starts_with(&format!("{header}## [1.0.1] - ")),
+        "unexpected changelog:\n{content}"
+    );
+    assert!(
+        content.ends_with(&format!("- Initial release\n{footer}")),
+        "unexpected changelog:\n{content}"
+    );
+}
+
+#[test]
+fn releases_beyond_the_limit_are_archived_and_committed() {
+    let dir = create_workspace("changelog-max-releases = 2\n");
+    fs::write(
+        dir.path().join("CHANGELOG.md"),
+        "# Changelog\n\n## [1.0.0] - 2025-02-01\n\n- Second\n\n## [0.9.0] - 2024-06-01\n\n- First\n",
+    )
+    .expect("write changelog");
+
+    commit_and_release(&dir);
+
+    let content = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(
+        content.contains("## [1.0.0]"),
+        "unexpected changelog:\n{content}"
+    );
+    assert!(
+        !content.contains("## [0.9.0]"),
+        "unexpected changelog:\n{content}"
+    );
+    assert!(
+        content.contains("[CHANGELOG-2024.md](CHANGELOG-2024.md)"),
+        "unexpected changelog:\n{content}"
+    );
+    let archive = fs::read_to_string(dir.path().join("CHANGELOG-2024.md")).expect("read archive");
+    assert!(
+        archive.contains("## [0.9.0] - 2024-06-01"),
+        "unexpected archive:\n{archive}"
+    );
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    assert!(
+        status.stdout.is_empty(),
+        "release left files uncommitted: {}",
+        String::from_utf8_lossy(&status.stdout)
+    );
+}
+
+#[test]
+fn json_output_is_written_and_committed_next_to_markdown() {
+    let dir = create_workspace("changelog-output = [\"markdown\", \"json\"]\n");
+
+    commit_and_release(&dir);
+
+    let content = fs::read_to_string(dir.path().join("CHANGELOG.json")).expect("read changelog");
+    let json: serde_json::Value = serde_json::from_str(&content).expect("valid JSON");
+    assert_eq!(json["releases"][0]["version"], "1.0.1");
+    assert_eq!(
+        json["releases"][0]["entries"][0]["description"],
+        "Fixed a bug"
+    );
+    assert_eq!(json["releases"][0]["entries"][0]["package"], "crate-a");
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    assert!(
+        status.stdout.is_empty(),
+        "release left files uncommitted: {}",
+        String::from_utf8_lossy(&status.stdout)
+    );
+}
+
+#[test]
+fn changelog_pages_are_written_per_package_and_version() {
+    let dir = create_workspace("changelog-output = [\"markdown\", \"json\"]\n");
+    commit_and_release(&dir);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["changelog-pages", "--front-matter", "toml"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let page = fs::read_to_string(dir.path().join("docs/changelog/crate-a/1.0.1.md"))
+        .expect("read changelog page");
+    assert!(page.starts_with("+++\ntitle = \"crate-a 1.0.1\"\n"));
+    assert!(page.contains("[extra]\npackage = \"crate-a\"\nversion = \"1.0.1\"\n+++\n"));
+    assert!(page.contains("## Changed\n\n- Fixed a bug\n"));
+}
+
+#[test]
+fn changelog_pages_require_json_output() {
+    let dir = create_workspace("");
+    commit_and_release(&dir);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["changelog-pages"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "CHANGELOG.json, which is not enabled",
+        ));
+}
@@ -0,0 +1,121 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_package(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n{metadata}"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/feature.md"),
+        "---\n\"my-crate\": minor\n---\n\nAdded a feature\n",
+    )
+    .expect("write changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn manifest_version(dir: &TempDir) -> String {
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    manifest
+        .lines()
+        .find_map(|line| line.strip_prefix("version = "))
+        .expect("version line")
+        .trim_matches('"')
+        .to_string()
+}
+
+#[test]
+fn channel_flag_releases_a_namespaced_prerelease() {
+    let dir = create_package("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--channel", "nightly"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(manifest_version(&dir), "1.1.0-nightly.1");
+    assert_eq!(
+        git(&dir, &["tag", "--list"]).trim(),
+        "nightly/v1.1.0-nightly.1"
+    );
+}
+
+#[test]
+fn nightly_comparison_link_points_at_namespaced_tag() {
+    let dir = create_package("");
+    git(
+        &dir,
+        &["remote", "add", "origin", "https://github.com/owner/repo"],
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--channel", "nightly"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains(
+        "[1.1.0-nightly.1]: https://github.com/owner/repo/compare/v1.0.0...nightly/v1.1.0-nightly.1"
+    ));
+}
+
+#[test]
+fn package_channel_comes_from_metadata() {
+    let dir = create_package(
+        "\n[package.metadata.changeset]\nchannel = \"canary\"\n\n\
+         [package.metadata.changeset.channels.canary]\nprerelease = \"canary\"\n",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(manifest_version(&dir), "1.1.0-canary.1");
+    assert!(git(&dir, &["tag", "--list"]).contains("v1.1.0-canary.1"));
+}
+
+#[test]
+fn unknown_channel_fails() {
+    let dir = create_package("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--channel", "edge"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("unknown release channel 'edge'"));
+
+    assert_eq!(manifest_version(&dir), "1.0.0");
+}
@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_package(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n\
+             [package.metadata.changeset]\n{metadata}"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_commit_uses_configured_author_and_committer() {
+    let dir = create_package(
+        "commit-author = \"release-bot <bot@company.com>\"\n\
+         commit-committer = \"github-actions[bot] <41898282+github-actions[bot]@users.noreply.github.com>\"\n",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(
+        git(dir.path(), &["log", "-1", "--format=%an <%ae>%n%cn <%ce>"]),
+        "release-bot <bot@company.com>\n\
+         github-actions[bot] <41898282+github-actions[bot]@users.noreply.github.com>\n"
+    );
+}
+
+#[test]
+fn release_commit_defaults_to_git_user() {
+    let dir = create_package("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(
+        git(dir.path(), &["log", "-1", "--format=%an <%ae>%n%cn <%ce>"]),
+        "Test <test@example.com>\nTest <test@example.com>\n"
+    );
+}
+
+#[test]
+fn release_rejects_malformed_commit_author() {
+    let dir = create_package("commit-author = \"release-bot\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .failure();
+
+    assert_eq!(git(dir.path(), &["log", "--format=%s"]), "Initial commit\n");
+}
@@ -0,0 +1,127 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn write_crate(dir: &Path, name: &str) {
+    let crate_dir = dir.join("crates").join(name);
+    fs::create_dir_all(crate_dir.join("src")).expect("create crate dir");
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+    )
+    .expect("write crate Cargo.toml");
+    fs::write(crate_dir.join("src/lib.rs"), "").expect("write lib.rs");
+}
+
+fn create_workspace(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n\n\
+             [workspace.metadata.changeset]\nchangelog = \"per-package\"\n{metadata}"
+        ),
+    )
+    .expect("write workspace Cargo.toml");
+    write_crate(dir.path(), "crate-a");
+    write_crate(dir.path(), "crate-b");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/change.md"),
+        "---\n\"crate-a\": patch\n\"crate-b\": minor\n---\n\nImproved both crates\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn rev_parse(dir: &Path, rev: &str) -> String {
+    git(dir, &["rev-parse", rev]).trim().to_string()
+}
+
+fn changed_files(dir: &Path, rev: &str) -> Vec<String> {
+    git(dir, &["show", "--name-only", "--format=", rev])
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn per_package_strategy_commits_and_tags_each_crate() {
+    let dir = create_workspace("commit-strategy = \"per-package\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Commits created:"));
+
+    assert_eq!(
+        git(dir.path(), &["log", "--format=%s", "-3"]),
+        "crate-b@v1.1.0\ncrate-a@v1.0.1\nInitial commit\n"
+    );
+    assert_eq!(
+        rev_parse(dir.path(), "crate-a@v1.0.1^{commit}"),
+        rev_parse(dir.path(), "HEAD~1")
+    );
+    assert_eq!(
+        rev_parse(dir.path(), "crate-b@v1.1.0^{commit}"),
+        rev_parse(dir.path(), "HEAD")
+    );
+    assert_eq!(
+        changed_files(dir.path(), "HEAD~1"),
+        vec![
+            ".changeset/changesets/change.md",
+            "crates/crate-a/CHANGELOG.md",
+            "crates/crate-a/Cargo.toml"
+        ]
+    );
+    assert_eq!(
+        changed_files(dir.path(), "HEAD"),
+        vec!["crates/crate-b/CHANGELOG.md", "crates/crate-b/Cargo.toml"]
+    );
+    assert!(git(dir.path(), &["status", "--porcelain"]).is_empty());
+}
+
+#[test]
+fn single_strategy_is_the_default() {
+    let dir = create_workspace("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Commit created:"));
+
+    assert_eq!(
+        git(dir.path(), &["log", "--format=%s", "-2"]),
+        "crate-a@v1.0.1, crate-b@v1.1.0\nInitial commit\n"
+    );
+    assert_eq!(
+        rev_parse(dir.path(), "crate-a@v1.0.1^{commit}"),
+        rev_parse(dir.path(), "crate-b@v1.1.0^{commit}")
+    );
+}
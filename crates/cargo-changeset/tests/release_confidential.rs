@@ -0,0 +1,73 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_project_with_confidential_changeset() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"[package]
+name = "my-crate"
+version = "1.0.0"
+edition = "2021"
+"#,
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\ncategory: security\nconfidential: true\n\"my-crate\": patch\n---\n\nFix overflow in header parsing\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_redacts_embargoed_changeset() {
+    let dir = create_project_with_confidential_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(changelog.contains("details will be published after the embargo"));
+    assert!(!changelog.contains("Fix overflow in header parsing"));
+}
+
+#[test]
+fn include_confidential_publishes_summary() {
+    let dir = create_project_with_confidential_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--include-confidential"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(changelog.contains("Fix overflow in header parsing"));
+}
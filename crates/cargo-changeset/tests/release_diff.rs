@@ -0,0 +1,107 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const CRATE_B_MANIFEST: &str = r#"[package]
+name = "crate-b"
+version = "2.0.0"
+edition = "2021"
+
+[dependencies]
+crate-a = { path = "../crate-a", version = "1.0.0" }
+"#;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+    fs::create_dir_all(dir.path().join("crates/crate-a/src")).expect("create crate-a dir");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        "[package]\nname = \"crate-a\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join("crates/crate-b/src")).expect("create crate-b dir");
+    fs::write(
+        dir.path().join("crates/crate-b/Cargo.toml"),
+        CRATE_B_MANIFEST,
+    )
+    .expect("write crate-b Cargo.toml");
+    fs::write(dir.path().join("crates/crate-b/src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/feature.md"),
+        "---\n\"crate-a\": minor\n---\n\nAdd a feature\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn dry_run_diff_shows_every_file_change() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run", "--diff"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains(
+            "--- a/crates/crate-a/Cargo.toml\n+++ b/crates/crate-a/Cargo.toml\n",
+        ))
+        .stdout(contains("-version = \"1.0.0\"\n+version = \"1.1.0\"\n"))
+        .stdout(contains(
+            "--- a/crates/crate-b/Cargo.toml\n+++ b/crates/crate-b/Cargo.toml\n",
+        ))
+        .stdout(contains(
+            "-crate-a = { path = \"../crate-a\", version = \"1.0.0\" }\n\
+             +crate-a = { path = \"../crate-a\", version = \"1.1.0\" }\n",
+        ))
+        .stdout(contains("--- /dev/null\n+++ b/CHANGELOG.md\n"))
+        .stdout(contains("+- **crate-a**: Add a feature\n"))
+        .stdout(contains(
+            "--- a/.changeset/changesets/feature.md\n+++ /dev/null\n",
+        ));
+
+    let manifest =
+        fs::read_to_string(dir.path().join("crates/crate-b/Cargo.toml")).expect("read manifest");
+    assert_eq!(manifest, CRATE_B_MANIFEST);
+    assert!(!dir.path().join("CHANGELOG.md").exists());
+    assert!(dir.path().join(".changeset/changesets/feature.md").exists());
+}
+
+#[test]
+fn diff_requires_dry_run() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--diff"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("--dry-run"));
+}
@@ -0,0 +1,128 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// A released-ready package with a modified tracked file and an untracked file.
+fn create_dirty_package() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    fs::write(dir.path().join("src/lib.rs"), "// work in progress\n").expect("modify lib.rs");
+    fs::write(dir.path().join("notes.txt"), "todo\n").expect("write notes.txt");
+
+    dir
+}
+
+fn committed_files(dir: &Path) -> String {
+    git(dir, &["show", "--name-only", "--format=", "HEAD"])
+}
+
+#[test]
+fn dirty_tree_aborts_without_on_dirty() {
+    let dir = create_dirty_package();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .env("CARGO_CHANGESET_NO_TTY", "1")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("uncommitted changes"))
+        .stderr(contains("--on-dirty"));
+
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.0\""));
+}
+
+#[test]
+fn on_dirty_stash_restores_changes_after_release() {
+    let dir = create_dirty_package();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--on-dirty", "stash"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let committed = committed_files(dir.path());
+    assert!(committed.contains("Cargo.toml"));
+    assert!(!committed.contains("src/lib.rs"));
+    assert!(!committed.contains("notes.txt"));
+
+    let lib = fs::read_to_string(dir.path().join("src/lib.rs")).expect("read lib.rs");
+    assert_eq!(lib, "// work in progress\n");
+    assert!(dir.path().join("notes.txt").exists());
+    assert!(git(dir.path(), &["stash", "list"]).is_empty());
+}
+
+#[test]
+fn on_dirty_stash_restores_changes_when_release_fails() {
+    let dir = create_dirty_package();
+    let manifest_path = dir.path().join("Cargo.toml");
+    let mut manifest = fs::read_to_string(&manifest_path).expect("read Cargo.toml");
+    manifest.push_str("\n[package.metadata.changeset]\nrelease-pre-checks = [\"exit 1\"]\n");
+    fs::write(&manifest_path, manifest).expect("write Cargo.toml");
+    git(dir.path(), &["commit", "-am", "Add failing pre-check"]);
+    fs::write(dir.path().join("src/lib.rs"), "// work in progress\n").expect("modify lib.rs");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--on-dirty", "stash"])
+        .current_dir(dir.path())
+        .assert()
+        .failure();
+
+    let lib = fs::read_to_string(dir.path().join("src/lib.rs")).expect("read lib.rs");
+    assert_eq!(lib, "// work in progress\n");
+    assert!(dir.path().join("notes.txt").exists());
+    assert!(git(dir.path(), &["stash", "list"]).is_empty());
+}
+
+#[test]
+fn on_dirty_include_adds_changes_to_release_commit() {
+    let dir = create_dirty_package();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--on-dirty", "include"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let committed = committed_files(dir.path());
+    assert!(committed.contains("Cargo.toml"));
+    assert!(committed.contains("src/lib.rs"));
+    assert!(committed.contains("notes.txt"));
+    assert!(git(dir.path(), &["status", "--porcelain"]).is_empty());
+}
@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_package_without_changesets() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(dir.path().join(".changeset/changesets/.gitkeep"), "").expect("write .gitkeep");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_without_changesets_suggests_allow_empty_release() {
+    let dir = create_package_without_changesets();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("--allow-empty-release"));
+
+    assert_eq!(git(dir.path(), &["tag"]), "");
+}
+
+#[test]
+fn allow_empty_release_cuts_patch_maintenance_release() {
+    let dir = create_package_without_changesets();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--allow-empty-release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(git(dir.path(), &["tag"]), "v1.0.1\n");
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains("Maintenance release"), "{changelog}");
+}
+
+#[test]
+fn allow_empty_release_uses_given_bump() {
+    let dir = create_package_without_changesets();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--allow-empty-release=minor"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(git(dir.path(), &["tag"]), "v1.1.0\n");
+}
@@ -0,0 +1,111 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_single_package(version: &str, changesets: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!("[package]\nname = \"my-crate\"\nversion = \"{version}\"\nedition = \"2021\"\n"),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    for (name, bump) in changesets {
+        fs::write(
+            dir.path().join(format!(".changeset/changesets/{name}.md")),
+            format!("---\n\"my-crate\": {bump}\n---\n\nChange {name}\n"),
+        )
+        .expect("write changeset");
+    }
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn create_single_package_with_changesets() -> TempDir {
+    create_single_package("1.0.0", &[("fix", "patch"), ("feature", "minor")])
+}
+
+#[test]
+fn dry_run_explain_shows_contributing_changesets() {
+    let dir = create_single_package_with_changesets();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run", "--explain"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Bump explanation:"))
+        .stdout(contains("my-crate (minor)"))
+        .stdout(contains("patch  .changeset/changesets/fix.md\n"))
+        .stdout(contains(
+            "minor  .changeset/changesets/feature.md  <- decides bump",
+        ));
+}
+
+#[test]
+fn dry_run_without_explain_omits_explanation() {
+    let dir = create_single_package_with_changesets();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Bump explanation:").not());
+}
+
+#[test]
+fn invalid_prerelease_tag_is_rejected_before_planning() {
+    let dir = create_single_package("1.0.0", &[("fix", "patch")]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "release",
+            "--dry-run",
+            "--prerelease",
+            "my-crate:feature/foo",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("invalid prerelease tag 'feature/foo'"))
+        .stderr(contains("only ASCII letters, digits and '-' are allowed"));
+}
+
+#[test]
+fn explain_shows_zero_version_and_prerelease_sources() {
+    let dir = create_single_package("0.3.0", &[("breaking", "major")]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run", "--explain", "--prerelease", "alpha"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("my-crate (major)"))
+        .stdout(contains("0.x: major demoted to minor"))
+        .stdout(contains(
+            "prerelease: alpha (from --prerelease (all packages))",
+        ));
+}
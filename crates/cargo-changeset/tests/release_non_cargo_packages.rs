@@ -0,0 +1,74 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+const PACKAGE_JSON: &str =
+    "{\n  \"name\": \"web\",\n  \"version\": \"1.0.0\",\n  \"private\": true\n}\n";
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n\n\
+         [workspace.metadata.changeset]\nextra-packages = [\"web\"]\nchangelog = \"per-package\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+    let crate_dir = dir.path().join("crates/core");
+    fs::create_dir_all(crate_dir.join("src")).expect("create crate dir");
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        "[package]\nname = \"core\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::write(crate_dir.join("src/lib.rs"), "").expect("write lib.rs");
+
+    fs::create_dir_all(dir.path().join("web")).expect("create web dir");
+    fs::write(dir.path().join("web/package.json"), PACKAGE_JSON).expect("write package.json");
+
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/web.md"),
+        "---\n\"web\": minor\n\"core\": patch\n---\n\nShare the API client\n",
+    )
+    .expect("write changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_bumps_package_json_next_to_crates() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let package_json = fs::read_to_string(dir.path().join("web/package.json")).expect("read");
+    assert_eq!(package_json, PACKAGE_JSON.replace("1.0.0", "1.1.0"));
+    let manifest = fs::read_to_string(dir.path().join("crates/core/Cargo.toml")).expect("read");
+    assert!(manifest.contains("version = \"1.0.1\""));
+
+    let changelog = fs::read_to_string(dir.path().join("web/CHANGELOG.md")).expect("read");
+    assert!(changelog.contains("Share the API client"));
+    assert!(git(&dir, &["tag", "--list"]).contains("web@v1.1.0"));
+}
@@ -0,0 +1,146 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::process::Command;
+use std::thread;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_project_with_webhook(webhook_url: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "my-crate"
+version = "1.0.0"
+edition = "2021"
+
+[package.metadata.changeset]
+webhook-url = "{webhook_url}"
+webhook-format = "slack"
+webhook-template = "Shipped {{releases}}"
+"#
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+/// Accepts a single HTTP request, answers with 200 and returns the request body.
+fn serve_once(listener: TcpListener) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("accept connection");
+        let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("read header line");
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().expect("valid content length");
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).expect("read body");
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .expect("write response");
+
+        String::from_utf8(body).expect("utf-8 body")
+    })
+}
+
+#[test]
+fn release_posts_notification_to_webhook() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind listener");
+    let url = format!("http://{}/hook", listener.local_addr().expect("local addr"));
+    let server = serve_once(listener);
+    let dir = create_project_with_webhook(&url);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Release notification sent."));
+
+    let body = server.join().expect("server thread");
+    assert_eq!(body, r#"{"text":"Shipped my-crate 1.0.1"}"#);
+}
+
+#[test]
+fn notification_failure_is_a_warning() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind listener");
+    let url = format!("http://{}/hook", listener.local_addr().expect("local addr"));
+    drop(listener);
+    let dir = create_project_with_webhook(&url);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Release complete."))
+        .stderr(contains("warning: failed to send release notification"));
+
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.1\""));
+}
+
+#[test]
+fn notify_command_announces_a_release_manifest() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind listener");
+    let url = format!("http://{}/hook", listener.local_addr().expect("local addr"));
+    let server = serve_once(listener);
+    let dir = create_project_with_webhook(&url);
+    fs::write(
+        dir.path().join(".changeset/release-manifest.json"),
+        r#"{"packages":[{"name":"my-crate","previousVersion":"1.0.0","version":"1.0.1","bump":"patch"}],"tags":["v1.0.1"],"commit":"abc1234"}"#,
+    )
+    .expect("write release manifest");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("notify")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Release notification sent."));
+
+    let body = server.join().expect("server thread");
+    assert_eq!(body, r#"{"text":"Shipped my-crate 1.0.1"}"#);
+}
@@ -0,0 +1,117 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn write_changeset(dir: &TempDir, name: &str, packages: &[&str]) {
+    let releases: String = packages
+        .iter()
+        .map(|package| format!("\"{package}\": patch\n"))
+        .collect();
+    fs::write(
+        dir.path().join(format!(".changeset/changesets/{name}.md")),
+        format!("---\n{releases}---\n\nChange in {}\n", packages.join(", ")),
+    )
+    .expect("write changeset");
+}
+
+fn create_workspace() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+    )
+    .expect("write workspace Cargo.toml");
+    for name in ["changeset-core", "changeset-git", "cli"] {
+        let crate_dir = dir.path().join("crates").join(name);
+        fs::create_dir_all(crate_dir.join("src")).expect("create crate dir");
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\nedition = \"2021\"\n"),
+        )
+        .expect("write Cargo.toml");
+        fs::write(crate_dir.join("src/lib.rs"), "").expect("write lib.rs");
+    }
+
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    write_changeset(&dir, "core", &["changeset-core"]);
+    write_changeset(&dir, "git", &["changeset-git"]);
+    write_changeset(&dir, "cli", &["cli"]);
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+fn version(dir: &TempDir, name: &str) -> String {
+    fs::read_to_string(dir.path().join("crates").join(name).join("Cargo.toml"))
+        .expect("read Cargo.toml")
+        .lines()
+        .find_map(|line| line.strip_prefix("version = "))
+        .expect("version line")
+        .trim_matches('"')
+        .to_string()
+}
+
+#[test]
+fn release_by_glob_leaves_other_changesets_pending() {
+    let dir = create_workspace();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--package", "changeset-*"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(version(&dir, "changeset-core"), "1.0.1");
+    assert_eq!(version(&dir, "changeset-git"), "1.0.1");
+    assert_eq!(version(&dir, "cli"), "1.0.0");
+    assert!(!dir.path().join(".changeset/changesets/core.md").exists());
+    assert!(dir.path().join(".changeset/changesets/cli.md").exists());
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "-p", "crates/cli"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(version(&dir, "cli"), "1.0.1");
+}
+
+#[test]
+fn release_rejects_changesets_spanning_unselected_packages() {
+    let dir = create_workspace();
+    write_changeset(&dir, "shared", &["changeset-core", "cli"]);
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Add shared changeset"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "-p", "changeset-core"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("also releases 'cli'"));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run", "-p", "changeset-cor"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("did you mean 'changeset-core'?"));
+}
@@ -0,0 +1,94 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_package_with_pre_checks(pre_checks: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n\
+             [package.metadata.changeset]\nrelease-pre-checks = {pre_checks}\n"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn failing_pre_check_blocks_release() {
+    let dir = create_package_with_pre_checks(r#"["echo checking {package}; exit 3"]"#);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("pre-release check"))
+        .stderr(contains("exit code 3"))
+        .stderr(contains("checking my-crate"));
+
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.0\""));
+    assert!(!dir.path().join("CHANGELOG.md").exists());
+    assert!(dir.path().join(".changeset/changesets/fix.md").exists());
+    assert!(git(&dir, &["status", "--porcelain"]).trim().is_empty());
+}
+
+#[test]
+fn passing_pre_checks_allow_release() {
+    let dir = create_package_with_pre_checks(r#"["true", "test {package} = my-crate"]"#);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.1\""));
+}
+
+#[test]
+fn pre_checks_run_before_changelogs_are_written() {
+    let dir = create_package_with_pre_checks(r#"["test ! -e CHANGELOG.md"]"#);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert!(dir.path().join("CHANGELOG.md").exists());
+}
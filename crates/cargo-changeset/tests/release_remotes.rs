@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// A package with a changeset, cloned from a fork and with the main repository
+/// added as `upstream`.
+fn create_fork_checkout(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+    git(
+        dir.path(),
+        &["remote", "add", "origin", "https://github.com/fork/repo"],
+    );
+    git(
+        dir.path(),
+        &["remote", "add", "upstream", "https://github.com/owner/repo"],
+    );
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n[package.metadata.changeset]\n{metadata}"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn comparison_links_use_configured_remote() {
+    let dir = create_fork_checkout("remote = \"upstream\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains("[1.0.1]: https://github.com/owner/repo/compare/v1.0.0...v1.0.1"));
+}
+
+#[test]
+fn comparison_links_fall_back_to_origin() {
+    let dir = create_fork_checkout("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains("https://github.com/fork/repo/compare/v1.0.0...v1.0.1"));
+}
+
+#[test]
+fn missing_configured_remote_fails_release() {
+    let dir = create_fork_checkout("remote = \"company\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("company"));
+
+    assert!(dir.path().join(".changeset/changesets/fix.md").exists());
+}
+
+#[test]
+fn comparison_links_use_crate_prefixed_tags() {
+    let dir = create_fork_checkout("tag-format = \"crate-prefixed\"\n");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains(
+        "[1.0.1]: https://github.com/fork/repo/compare/my-crate@v1.0.0...my-crate@v1.0.1"
+    ));
+}
+
+#[test]
+fn comparison_links_template_receives_release_tags() {
+    let dir = create_fork_checkout(
+        "tag-format = \"crate-prefixed\"\n\
+         comparison-links-template = \"https://git.example.com/{repository}/diff/{base}..{target}\"\n",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(changelog.contains(
+        "[1.0.1]: https://git.example.com/fork/repo/diff/my-crate@v1.0.0..my-crate@v1.0.1"
+    ));
+}
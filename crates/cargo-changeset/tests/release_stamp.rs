@@ -0,0 +1,157 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_package(metadata: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n\
+             [package.metadata.changeset]\n{metadata}"
+        ),
+    )
+    .expect("write Cargo.toml");
+    fs::write(
+        dir.path().join("README.md"),
+        "```toml\n[dependencies]\nmy-crate = \"1.0.0\"\n```\n",
+    )
+    .expect("write README.md");
+    fs::create_dir_all(dir.path().join("charts/app")).expect("create chart dir");
+    fs::write(
+        dir.path().join("charts/app/Chart.yaml"),
+        "version: 0.3.0\nappVersion: 1.0.0\n",
+    )
+    .expect("write Chart.yaml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/feature.md"),
+        "---\n\"my-crate\": minor\n---\n\nAdd a feature\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn configured_stamps_are_written_and_committed() {
+    let dir = create_package(
+        "[[package.metadata.changeset.version-stamps]]\n\
+         files = \"*.md\"\n\
+         pattern = '{package} = \"{version}\"'\n",
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let readme = fs::read_to_string(dir.path().join("README.md")).expect("read README.md");
+    assert!(readme.contains("my-crate = \"1.1.0\""));
+    assert!(git(&dir, &["status", "--porcelain"]).trim().is_empty());
+    assert!(git(&dir, &["show", "--name-only", "--format=", "HEAD"]).contains("README.md"));
+}
+
+#[test]
+fn readme_badges_are_updated_when_enabled() {
+    let dir = create_package("readme-badges = true\n");
+    fs::write(
+        dir.path().join("README.md"),
+        "[![docs](https://img.shields.io/docsrs/my-crate/1.0.0)](https://docs.rs/my-crate/1.0.0)\n",
+    )
+    .expect("write README.md");
+    git(&dir, &["commit", "-am", "Add badges"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let readme = fs::read_to_string(dir.path().join("README.md")).expect("read README.md");
+    assert_eq!(
+        readme,
+        "[![docs](https://img.shields.io/docsrs/my-crate/1.1.0)](https://docs.rs/my-crate/1.1.0)\n"
+    );
+    assert!(git(&dir, &["show", "--name-only", "--format=", "HEAD"]).contains("README.md"));
+}
+
+#[test]
+fn stamp_flag_rewrites_matching_files() {
+    let dir = create_package("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "release",
+            "--no-commit",
+            "--stamp",
+            "charts/*/Chart.yaml=appVersion: {version}",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let chart =
+        fs::read_to_string(dir.path().join("charts/app/Chart.yaml")).expect("read Chart.yaml");
+    assert_eq!(chart, "version: 0.3.0\nappVersion: 1.1.0\n");
+    let readme = fs::read_to_string(dir.path().join("README.md")).expect("read README.md");
+    assert!(readme.contains("my-crate = \"1.0.0\""));
+}
+
+#[test]
+fn dry_run_diff_shows_stamped_files() {
+    let dir = create_package("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "release",
+            "--dry-run",
+            "--diff",
+            "--stamp",
+            "README.md=my-crate = \"{version}\"",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("+my-crate = \"1.1.0\""));
+
+    let readme = fs::read_to_string(dir.path().join("README.md")).expect("read README.md");
+    assert!(readme.contains("my-crate = \"1.0.0\""));
+}
+
+#[test]
+fn malformed_stamp_is_rejected() {
+    let dir = create_package("");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--stamp", "README.md"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("expected 'glob=pattern'"));
+}
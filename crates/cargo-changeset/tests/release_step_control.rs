@@ -0,0 +1,97 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_single_package_with_changeset() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn skip_step_requires_unsafe_step_control() {
+    let dir = create_single_package_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--skip-step", "create-tags"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("--unsafe-step-control"));
+}
+
+#[test]
+fn skipped_step_does_not_run() {
+    let dir = create_single_package_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "release",
+            "--unsafe-step-control",
+            "--skip-step",
+            "create-tags",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert!(git(&dir, &["tag", "--list"]).trim().is_empty());
+    assert!(git(&dir, &["log", "-1", "--format=%s"]).contains("1.0.1"));
+}
+
+#[test]
+fn only_until_stops_after_step() {
+    let dir = create_single_package_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args([
+            "release",
+            "--unsafe-step-control",
+            "--only-until",
+            "write-manifests",
+        ])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let manifest = fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.1\""));
+    assert!(dir.path().join(".changeset/changesets/fix.md").exists());
+    assert_eq!(
+        git(&dir, &["log", "-1", "--format=%s"]).trim(),
+        "Initial commit"
+    );
+}
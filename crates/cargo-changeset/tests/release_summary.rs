@@ -0,0 +1,117 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_single_package_with_changeset() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"[package]
+name = "my-crate"
+version = "1.0.0"
+edition = "2021"
+"#,
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_writes_summary_to_default_location() {
+    let dir = create_single_package_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--summary"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Release summary written to"));
+
+    let summary = fs::read_to_string(dir.path().join(".changeset/RELEASE_SUMMARY.md"))
+        .expect("read release summary");
+    assert!(summary.contains("| my-crate | 1.0.0 | 1.0.1 | Patch |"));
+    assert!(summary.contains("- `v1.0.1`"));
+    assert!(summary.contains("## Commit"));
+    assert!(summary.contains("Fix a bug"));
+}
+
+#[test]
+fn release_writes_summary_to_custom_path() {
+    let dir = create_single_package_with_changeset();
+    let output_dir = TempDir::new().expect("create output dir");
+    let summary_path = output_dir.path().join("summary.md");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("release")
+        .arg("--summary")
+        .arg(&summary_path)
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert!(summary_path.exists());
+}
+
+#[test]
+fn dry_run_does_not_write_summary() {
+    let dir = create_single_package_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--dry-run", "--summary"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert!(!dir.path().join(".changeset/RELEASE_SUMMARY.md").exists());
+}
+
+#[test]
+fn release_writes_manifest_to_default_location() {
+    let dir = create_single_package_with_changeset();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--manifest"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Release manifest written to"));
+
+    let manifest: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(dir.path().join(".changeset/release-manifest.json"))
+            .expect("read release manifest"),
+    )
+    .expect("valid json");
+    assert_eq!(manifest["packages"][0]["name"], "my-crate");
+    assert_eq!(manifest["packages"][0]["previousVersion"], "1.0.0");
+    assert_eq!(manifest["packages"][0]["version"], "1.0.1");
+    assert_eq!(manifest["tags"][0], "v1.0.1");
+    assert!(manifest["commit"].is_string());
+}
@@ -0,0 +1,80 @@
+use std::fs;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+const MANIFEST: &str = "[package]\nname = \"my-crate\"\nversion = \"1.1.0\"\nedition = \"2021\"\n";
+
+fn create_package(tag: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(dir.path().join("Cargo.toml"), MANIFEST).expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+    git(&dir, &["tag", tag]);
+
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFixed a bug\n",
+    )
+    .expect("write changeset");
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Add changeset"]);
+
+    dir
+}
+
+#[test]
+fn tag_only_tags_the_manifest_version() {
+    let dir = create_package("v1.0.0");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--tag-only"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("Cargo.toml")).expect("read Cargo.toml"),
+        MANIFEST
+    );
+    let changelog = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(
+        changelog.contains("## [1.1.0]"),
+        "unexpected changelog:\n{changelog}"
+    );
+    assert!(!dir.path().join(".changeset/changesets/fix.md").exists());
+    assert!(git(&dir, &["tag", "--list"]).contains("v1.1.0"));
+}
+
+#[test]
+fn tag_only_fails_when_version_is_already_tagged() {
+    let dir = create_package("v1.1.0");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--tag-only"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("bump its version"));
+
+    assert!(dir.path().join(".changeset/changesets/fix.md").exists());
+}
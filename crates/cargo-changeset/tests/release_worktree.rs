@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn create_single_package_with_changeset() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(dir.path(), &["init", "--initial-branch=main"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\n\"my-crate\": patch\n---\n\nFix a bug\n",
+    )
+    .expect("write changeset");
+
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-m", "Initial commit"]);
+
+    dir
+}
+
+#[test]
+fn release_in_linked_worktree() {
+    let dir = create_single_package_with_changeset();
+    let parent = TempDir::new().expect("create temp dir");
+    let worktree = parent.path().join("release");
+    git(
+        dir.path(),
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "release",
+            worktree.to_str().expect("utf-8 path"),
+        ],
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(&worktree)
+        .assert()
+        .success();
+
+    let manifest = fs::read_to_string(worktree.join("Cargo.toml")).expect("read Cargo.toml");
+    assert!(manifest.contains("version = \"1.0.1\""));
+    assert!(git(&worktree, &["status", "--porcelain"]).is_empty());
+    assert_eq!(git(dir.path(), &["tag", "--list"]).trim(), "v1.0.1");
+    assert!(git(dir.path(), &["log", "-1", "--format=%s", "release"]).contains("1.0.1"));
+    assert!(git(dir.path(), &["status", "--porcelain"]).is_empty());
+}
+
+#[test]
+fn release_in_bare_repository_fails_with_clear_error() {
+    let dir = TempDir::new().expect("create temp dir");
+    git(dir.path(), &["init", "--bare"]);
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(contains("is a bare git repository"));
+}
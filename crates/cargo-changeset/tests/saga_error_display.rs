@@ -181,7 +181,7 @@ fn release_saga_failure_shows_failed_step_and_rollback_message() {
         .assert()
         .failure()
         .stderr(contains("Error: Release failed at step"))
-        .stderr(contains("create_tags"))
+        .stderr(contains("create-tags"))
         .stderr(contains("Rollback completed successfully"))
         .stderr(contains("restored to its original state"));
 }
@@ -199,7 +199,7 @@ fn release_saga_failure_message_includes_step_name() {
         .current_dir(workspace.path())
         .assert()
         .failure()
-        .stderr(contains("'create_tags'"));
+        .stderr(contains("'create-tags'"));
 }
 
 #[test]
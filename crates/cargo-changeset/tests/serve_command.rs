@@ -0,0 +1,147 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::process::{Child, Command, Stdio};
+
+use tempfile::TempDir;
+
+fn git(dir: &TempDir, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run git");
+}
+
+fn create_project() -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    git(&dir, &["init", "--initial-branch=main"]);
+    git(&dir, &["config", "user.email", "test@example.com"]);
+    git(&dir, &["config", "user.name", "Test"]);
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write Cargo.toml");
+    fs::create_dir_all(dir.path().join("src")).expect("create src dir");
+    fs::write(dir.path().join("src/lib.rs"), "").expect("write lib.rs");
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/feature.md"),
+        "---\ncategory: added\n\"my-crate\": minor\n---\n\nAdd streaming parser\n",
+    )
+    .expect("write changeset");
+    fs::write(
+        dir.path().join(".changeset/changesets/fix.md"),
+        "---\ncategory: security\nconfidential: true\n\"my-crate\": patch\n---\n\nFix overflow in header parsing\n",
+    )
+    .expect("write changeset");
+
+    git(&dir, &["add", "-A"]);
+    git(&dir, &["commit", "-m", "Initial commit"]);
+    git(&dir, &["tag", "v1.0.0"]);
+
+    dir
+}
+
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start(dir: &TempDir) -> Self {
+        let mut child = Command::new(assert_cmd::cargo::cargo_bin!("cargo-changeset"))
+            .args(["serve", "--http", "127.0.0.1:0"])
+            .current_dir(dir.path())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("spawn server");
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().expect("server stdout"))
+            .read_line(&mut line)
+            .expect("read server address");
+        let addr = line
+            .trim()
+            .rsplit_once("http://")
+            .expect("server prints its address")
+            .1
+            .to_string();
+
+        Self { child, addr }
+    }
+
+    fn get(&self, path: &str) -> String {
+        let mut stream = TcpStream::connect(&self.addr).expect("connect to server");
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").expect("send request");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("read response");
+        response
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn serves_changesets_versions_and_releases() {
+    let dir = create_project();
+    let server = Server::start(&dir);
+
+    let changesets = server.get("/changesets");
+    assert!(changesets.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(changesets.contains("Content-Type: application/json"));
+    assert!(changesets.contains(r#""summary":"Add streaming parser""#));
+    assert!(changesets.contains(r#""bump":"minor""#));
+    assert!(!changesets.contains("Fix overflow in header parsing"));
+
+    let summary = server.get("/summary");
+    assert!(summary.contains(r#""pendingChangesets":2"#));
+    assert!(summary.contains(r#""packagesToRelease":1"#));
+    assert!(summary.contains(r#""minor":1"#));
+
+    let versions = server.get("/versions");
+    assert!(versions.contains(r#""currentVersion":"1.0.0""#));
+    assert!(versions.contains(r#""newVersion":"1.1.0""#));
+
+    let releases = server.get("/releases/");
+    assert!(releases.contains(r#""package":"my-crate""#));
+    assert!(releases.contains(r#""tag":"v1.0.0""#));
+}
+
+#[test]
+fn unknown_endpoint_returns_not_found() {
+    let dir = create_project();
+    let server = Server::start(&dir);
+
+    let response = server.get("/deploy");
+
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    assert!(response.contains("no endpoint at '/deploy'"));
+}
+
+#[test]
+fn overlong_request_line_is_rejected() {
+    let dir = create_project();
+    let server = Server::start(&dir);
+
+    let mut stream = TcpStream::connect(&server.addr).expect("connect to server");
+    let path = "a".repeat(16 * 1024);
+    write!(stream, "GET /{path} HTTP/1.1\r\nHost: localhost\r\n\r\n").expect("send request");
+    stream.shutdown(Shutdown::Write).expect("finish request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("read response");
+
+    assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large\r\n"));
+
+    let response = server.get("/summary");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+}
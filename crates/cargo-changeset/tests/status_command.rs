@@ -1,4 +1,6 @@
 use std::fs;
+use std::path::Path;
+use std::process::Command;
 
 use predicates::str::contains;
 use tempfile::TempDir;
@@ -149,7 +151,7 @@ fn status_shows_single_changeset() {
         .stdout(contains("fix-bug.md"))
         .stdout(contains("Projected releases:"))
         .stdout(contains("my-crate: 1.0.0 -> 1.0.1 (Patch)"))
-        .stdout(contains("Summary: 1 changeset(s), 1 package(s) affected"));
+        .stdout(contains("Summary: 1 changeset(s), 1 package(s) to release"));
 }
 
 #[test]
@@ -256,5 +258,138 @@ fn status_multiple_packages_multiple_bumps() {
             "crate-a: 1.0.0 -> 1.1.0 (Minor) (from: Patch, Minor)",
         ))
         .stdout(contains("crate-b: 2.0.0 -> 3.0.0 (Major)"))
-        .stdout(contains("Summary: 3 changeset(s), 2 package(s) affected"));
+        .stdout(contains("Summary: 3 changeset(s), 2 package(s) to release"));
+}
+
+fn git_at(dir: &Path, date: &str, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_DATE", date)
+        .env("GIT_COMMITTER_DATE", date)
+        .output()
+        .expect("failed to run git");
+    assert!(output.status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn status_shows_changeset_age_and_stale_warning() {
+    let dir = create_single_package_project();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"my-crate\"\nversion = \"1.0.0\"\nedition = \"2021\"\n\n[package.metadata.changeset]\nstale-changeset-releases = 1\n",
+    )
+    .expect("write Cargo.toml");
+    fs::write(
+        dir.path().join(".changeset/changesets/old.md"),
+        "---\n\"my-crate\": patch\n---\n\nOld fix\n",
+    )
+    .expect("write changeset");
+
+    let date = "2024-01-01T12:00:00Z";
+    git_at(dir.path(), date, &["init", "--initial-branch=main"]);
+    git_at(
+        dir.path(),
+        date,
+        &["config", "user.email", "test@example.com"],
+    );
+    git_at(dir.path(), date, &["config", "user.name", "Test"]);
+    git_at(dir.path(), date, &["add", "-A"]);
+    git_at(dir.path(), date, &["commit", "-m", "Add changeset"]);
+    let later = "2024-02-01T12:00:00Z";
+    git_at(
+        dir.path(),
+        later,
+        &["commit", "--allow-empty", "-m", "Release"],
+    );
+    git_at(dir.path(), later, &["tag", "v1.0.0"]);
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("status")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("old.md (added "))
+        .stdout(contains("days ago)"))
+        .stdout(contains("Warning: Changesets pending through 1+ releases:"))
+        .stdout(contains("old.md: my-crate (1 release(s))"));
+}
+
+#[test]
+fn status_summary_lists_prerelease_queue() {
+    let dir = create_workspace_project();
+    fs::write(
+        dir.path().join(".changeset/changesets/feature.md"),
+        "---\n\"crate-a\": minor\n---\n\nAdd a feature\n",
+    )
+    .expect("write changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["manage", "pre-release", "--add", "crate-b:beta"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("status")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with(
+            "Summary: 1 changeset(s), 1 package(s) to release (0 major, 1 minor, 0 patch), 1 unchanged\n\
+             Pre-release queue: crate-b (beta)\n",
+        ));
+}
+
+#[test]
+fn status_since_lists_what_shipped() {
+    let dir = create_workspace_project();
+    let date = "2024-01-01T12:00:00Z";
+    git_at(dir.path(), date, &["init", "--initial-branch=main"]);
+    git_at(
+        dir.path(),
+        date,
+        &["config", "user.email", "test@example.com"],
+    );
+    git_at(dir.path(), date, &["config", "user.name", "Test"]);
+    git_at(dir.path(), date, &["add", "-A"]);
+    git_at(dir.path(), date, &["commit", "-m", "Initial"]);
+    git_at(dir.path(), date, &["tag", "crate-a@v1.0.0"]);
+
+    write_changeset(&dir, "parser.md", "crate-a", "minor", "Add parser");
+    git_at(dir.path(), date, &["add", "-A"]);
+    git_at(dir.path(), date, &["commit", "-m", "Add changeset"]);
+
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        "[package]\nname = \"crate-a\"\nversion = \"1.1.0\"\nedition = \"2021\"\n",
+    )
+    .expect("write crate-a Cargo.toml");
+    fs::remove_file(dir.path().join(".changeset/changesets/parser.md")).expect("remove changeset");
+    fs::write(
+        dir.path().join("CHANGELOG.md"),
+        "# Changelog\n\n## [1.1.0] - 2024-01-02\n\n### Changed\n\n- Add parser\n",
+    )
+    .expect("write CHANGELOG.md");
+    git_at(dir.path(), date, &["add", "-A"]);
+    git_at(dir.path(), date, &["commit", "-m", "Release"]);
+    git_at(dir.path(), date, &["tag", "crate-a@v1.1.0"]);
+
+    cargo_changeset_status!()
+        .args(["status", "--since", "crate-a@v1.0.0"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Shipped since crate-a@v1.0.0: 1 package(s)"))
+        .stdout(contains("crate-a: 1.0.0 -> 1.1.0"))
+        .stdout(contains("Released: 1.1.0"))
+        .stdout(contains("- Add parser (Minor)"))
+        .stdout(contains("## [1.1.0] - 2024-01-02"));
+
+    cargo_changeset_status!()
+        .args(["status", "--since", "crate-a@v1.1.0"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Nothing shipped since crate-a@v1.1.0."));
 }
@@ -1,6 +1,7 @@
 use std::fs;
 use std::process::Command;
 
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::contains;
 use tempfile::TempDir;
 
@@ -242,6 +243,82 @@ fn verify_exit_code_1_when_package_uncovered() {
         .stderr(contains("without changeset coverage"));
 }
 
+#[test]
+fn verify_fails_when_summary_fails_lint() {
+    let workspace = create_virtual_workspace_with_git();
+    create_branch(&workspace, "feature");
+
+    let manifest_path = workspace.path().join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).expect("failed to read Cargo.toml");
+    fs::write(
+        &manifest_path,
+        format!("{manifest}\n[workspace.metadata.changeset]\nlint-summaries = true\n"),
+    )
+    .expect("failed to write Cargo.toml");
+
+    fs::write(
+        workspace.path().join("crates/crate-a/src/lib.rs"),
+        "// changed",
+    )
+    .expect("failed to modify lib.rs");
+
+    fs::create_dir_all(workspace.path().join(".changeset/changesets"))
+        .expect("failed to create .changeset/changesets dir");
+    fs::write(
+        workspace.path().join(".changeset/changesets/bare-url.md"),
+        "---\n\"crate-a\": patch\n---\n\nFix crash, see https://example.com/issues/1\n",
+    )
+    .expect("failed to write changeset");
+    git_add_and_commit(&workspace, "Add changes with changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("verify")
+        .arg("--base")
+        .arg("main")
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("Changeset summaries failing lint"))
+        .stderr(contains("bare URL 'https://example.com/issues/1'"));
+}
+
+#[test]
+fn verify_security_label_requires_changeset_with_advisory() {
+    let workspace = create_virtual_workspace_with_git();
+    create_branch(&workspace, "feature");
+
+    fs::write(
+        workspace.path().join("crates/crate-a/src/lib.rs"),
+        "// changed",
+    )
+    .expect("failed to modify lib.rs");
+    add_changeset(&workspace, "crate-a");
+    git_add_and_commit(&workspace, "Fix overflow");
+
+    let verify = || {
+        let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset");
+        cmd.args(["verify", "--base", "main", "--label", "security"])
+            .current_dir(workspace.path());
+        cmd
+    };
+
+    verify()
+        .assert()
+        .failure()
+        .stderr(contains("category 'security' and an advisory"));
+
+    fs::write(
+        workspace
+            .path()
+            .join(".changeset/changesets/crate-a-changeset.md"),
+        "---\ncategory: security\nadvisory: RUSTSEC-2024-0001\n\"crate-a\": patch\n---\n\nFix overflow.\n",
+    )
+    .expect("failed to write changeset");
+    git_add_and_commit(&workspace, "Link advisory");
+
+    verify().assert().success();
+}
+
 #[test]
 fn verify_exit_code_0_when_only_changeset_directory_changes() {
     let workspace = create_virtual_workspace_with_git();
@@ -366,6 +443,177 @@ ignored-files = ["benches/**"]
         .success();
 }
 
+#[test]
+fn verify_library_only_skips_binary_only_packages() {
+    let dir = create_virtual_workspace_with_git();
+
+    fs::create_dir_all(dir.path().join("crates/my-cli/src")).expect("failed to create cli dir");
+    fs::write(
+        dir.path().join("crates/my-cli/Cargo.toml"),
+        r#"
+[package]
+name = "my-cli"
+version = "0.1.0"
+edition = "2021"
+
+[package.metadata.changeset]
+require-changeset = "library-only"
+"#,
+    )
+    .expect("failed to write my-cli Cargo.toml");
+    fs::write(dir.path().join("crates/my-cli/src/main.rs"), "fn main() {}")
+        .expect("failed to write main.rs");
+    git_add_and_commit(&dir, "Add cli");
+    create_branch(&dir, "feature");
+
+    fs::write(
+        dir.path().join("crates/my-cli/src/main.rs"),
+        "fn main() { println!(); }",
+    )
+    .expect("failed to update main.rs");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "// changed")
+        .expect("failed to update lib.rs");
+    git_add_and_commit(&dir, "Change cli and library");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main"])
+        .current_dir(dir.path())
+        .assert()
+        .code(1)
+        .stderr(
+            contains("1 package(s) have changes without changeset coverage")
+                .and(contains("- my-cli")),
+        );
+
+    add_changeset(&dir, "crate-a");
+    git_add_and_commit(&dir, "Add changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains(
+            "No changeset required (require-changeset):\n  my-cli",
+        ));
+}
+
+#[test]
+fn verify_transitive_coverage_flags_dependents() {
+    let dir = create_virtual_workspace_with_git();
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/*"]
+resolver = "2"
+
+[workspace.metadata.changeset]
+transitive-coverage-depth = 1
+"#,
+    )
+    .expect("failed to write workspace Cargo.toml");
+    fs::write(
+        dir.path().join("crates/crate-b/Cargo.toml"),
+        r#"
+[package]
+name = "crate-b"
+version = "0.2.0"
+edition = "2021"
+
+[dependencies]
+crate-a = { path = "../crate-a" }
+"#,
+    )
+    .expect("failed to write crate-b Cargo.toml");
+    git_add_and_commit(&dir, "Depend on crate-a");
+    create_branch(&dir, "feature");
+
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "// changed")
+        .expect("failed to update lib.rs");
+    add_changeset(&dir, "crate-a");
+    git_add_and_commit(&dir, "Change crate-a");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main"])
+        .current_dir(dir.path())
+        .assert()
+        .code(1)
+        .stderr(contains("✗ crate-b (depends on crate-a)"));
+
+    add_changeset_with_name(&dir, "crate-b", "facade");
+    git_add_and_commit(&dir, "Add facade changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+}
+
+#[test]
+fn verify_infer_bump_reports_understated_bumps() {
+    let dir = create_virtual_workspace_with_git();
+    create_branch(&dir, "feature");
+
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "// changed")
+        .expect("failed to update lib.rs");
+    add_changeset(&dir, "crate-a");
+    git_add_and_commit(&dir, "feat: add parser");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main", "--infer-bump", "commits,files"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains(
+            "crate-a: changesets declare Patch, commits suggest Minor",
+        ));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(contains("Bumps that may be too small").not());
+}
+
+#[test]
+fn verify_json_lists_missing_packages_with_their_files() {
+    let dir = create_virtual_workspace_with_git();
+    create_branch(&dir, "feature");
+
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "// changed")
+        .expect("failed to update lib.rs");
+    git_add_and_commit(&dir, "feat: add parser");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main", "--output", "json"])
+        .args(["--infer-bump", "commits"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).expect("stderr is JSON");
+    assert_eq!(error["code"], "CS0087");
+    assert_eq!(error["name"], "VerificationFailed");
+
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout is JSON");
+    assert_eq!(
+        value["missing"],
+        serde_json::json!([{
+            "package": "crate-a",
+            "changedFiles": ["crates/crate-a/src/lib.rs"],
+            "suggestedBump": "minor",
+        }])
+    );
+    assert_eq!(value["deleted"], serde_json::json!([]));
+    assert_eq!(value["affectedPackages"], serde_json::json!(["crate-a"]));
+}
+
 #[test]
 fn verify_exit_code_1_for_nonexistent_base_branch() {
     let workspace = create_virtual_workspace_with_git();
@@ -693,6 +941,87 @@ fn verify_deleted_changeset_with_allow_flag_passes() {
         .success();
 }
 
+fn write_consumed_changeset(dir: &TempDir, summary: &str) {
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("failed to create .changeset/changesets dir");
+    fs::write(
+        dir.path().join(".changeset/changesets/shipped.md"),
+        format!(
+            "---\nconsumedForPrerelease: 1.0.1-alpha.1\n\"crate-a\": patch\n---\n\n{summary}\n"
+        ),
+    )
+    .expect("failed to write changeset");
+}
+
+#[test]
+fn verify_fails_when_consumed_changeset_is_edited() {
+    let workspace = create_virtual_workspace_with_git();
+
+    write_consumed_changeset(&workspace, "Fix a bug.");
+    git_add_and_commit(&workspace, "Pre-release");
+
+    create_branch(&workspace, "feature");
+    write_consumed_changeset(&workspace, "Fix a different bug.");
+    git_add_and_commit(&workspace, "Edit shipped changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("verify")
+        .arg("--base")
+        .arg("main")
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("Consumed changesets must not be edited"))
+        .stderr(contains(".changeset/changesets/shipped.md"));
+}
+
+#[test]
+fn verify_fails_when_consumed_marker_is_removed() {
+    let workspace = create_virtual_workspace_with_git();
+
+    write_consumed_changeset(&workspace, "Fix a bug.");
+    git_add_and_commit(&workspace, "Pre-release");
+
+    create_branch(&workspace, "feature");
+    add_changeset_with_name(&workspace, "crate-a", "shipped");
+    git_add_and_commit(&workspace, "Resurrect shipped changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("verify")
+        .arg("--base")
+        .arg("main")
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("already consumed by a pre-release were edited"));
+}
+
+#[test]
+fn verify_allows_editing_pending_changeset() {
+    let workspace = create_virtual_workspace_with_git();
+
+    add_changeset(&workspace, "crate-a");
+    git_add_and_commit(&workspace, "Add changeset");
+
+    create_branch(&workspace, "feature");
+    fs::write(
+        workspace
+            .path()
+            .join(".changeset/changesets/crate-a-changeset.md"),
+        "---\n\"crate-a\": minor\n---\n\nReworded.\n",
+    )
+    .expect("failed to edit changeset");
+    git_add_and_commit(&workspace, "Edit changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .arg("verify")
+        .arg("--base")
+        .arg("main")
+        .current_dir(workspace.path())
+        .assert()
+        .success();
+}
+
 #[test]
 fn verify_multiple_commits_on_feature_branch_all_covered() {
     let workspace = create_workspace_with_three_crates();
@@ -1279,3 +1608,212 @@ fn verify_deleted_and_added_changeset_with_allow_flag() {
         .assert()
         .success();
 }
+
+#[test]
+fn verify_caches_file_mapping_outside_version_control() {
+    let workspace = create_virtual_workspace_with_git();
+    create_branch(&workspace, "feature");
+
+    fs::write(
+        workspace.path().join("crates/crate-a/src/lib.rs"),
+        "// changed",
+    )
+    .expect("failed to modify lib.rs");
+
+    add_changeset(&workspace, "crate-a");
+    git_add_and_commit(&workspace, "Add changes with changeset");
+
+    for _ in 0..2 {
+        assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+            .arg("verify")
+            .arg("--base")
+            .arg("main")
+            .current_dir(workspace.path())
+            .assert()
+            .success();
+    }
+
+    assert!(
+        workspace
+            .path()
+            .join(".changeset/.cache/file-map.json")
+            .exists()
+    );
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(workspace.path())
+        .output()
+        .expect("failed to run git status");
+    assert!(status.stdout.is_empty());
+}
+
+#[test]
+fn verify_changelog_matches_updated_snapshot() {
+    let workspace = create_virtual_workspace_with_git();
+    add_changeset_with_name(&workspace, "crate-b", "b-fix");
+    add_changeset_with_name(&workspace, "crate-a", "a-fix");
+    git_add_and_commit(&workspace, "Add changesets");
+    let snapshot = workspace.path().join("changelog.snap");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--changelog", "--update-snapshot", "--snapshot"])
+        .arg(&snapshot)
+        .current_dir(workspace.path())
+        .assert()
+        .success();
+
+    let rendered = fs::read_to_string(&snapshot).expect("read snapshot");
+    assert!(rendered.contains("Test changeset for crate-a."));
+    assert!(rendered.contains("Test changeset for crate-b."));
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--changelog", "--snapshot"])
+        .arg(&snapshot)
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains("Changelog renders deterministically"));
+
+    assert!(
+        workspace
+            .path()
+            .join(".changeset/changesets/a-fix.md")
+            .exists()
+    );
+}
+
+#[test]
+fn verify_changelog_fails_on_stale_snapshot() {
+    let workspace = create_virtual_workspace_with_git();
+    add_changeset(&workspace, "crate-a");
+    git_add_and_commit(&workspace, "Add changeset");
+    let snapshot = workspace.path().join("changelog.snap");
+    fs::write(
+        &snapshot,
+        "## Changelog: crate-a 0.1.1\n\n### Fixed\n\n- Old\n",
+    )
+    .expect("write snapshot");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--changelog", "--snapshot"])
+        .arg(&snapshot)
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("-- Old"))
+        .stderr(contains("does not match snapshot"));
+}
+
+#[test]
+fn verify_snapshot_requires_changelog_flag() {
+    let workspace = create_virtual_workspace_with_git();
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--snapshot", "changelog.snap"])
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains("--changelog"));
+}
+
+#[test]
+fn verify_changelog_format_reports_violations() {
+    let workspace = create_virtual_workspace_with_git();
+    fs::write(
+        workspace.path().join("CHANGELOG.md"),
+        "# Changelog\n\n## [0.1.0] - 2025-01-01\n\n### Fixes\n\n- Bug fix\n",
+    )
+    .expect("write changelog");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--changelog-format"])
+        .current_dir(workspace.path())
+        .assert()
+        .failure()
+        .stderr(contains(
+            "CHANGELOG.md:3: expected '## [Unreleased]' as the first section",
+        ))
+        .stderr(contains("CHANGELOG.md:5: unknown change type 'Fixes'"))
+        .stderr(contains("Keep a Changelog violation(s) found"));
+}
+
+#[test]
+fn keep_a_changelog_release_passes_format_check() {
+    let workspace = create_virtual_workspace_with_git();
+    let manifest = workspace.path().join("Cargo.toml");
+    let mut content = fs::read_to_string(&manifest).expect("read Cargo.toml");
+    content.push_str("\n[workspace.metadata.changeset]\nchangelog-format = \"keep-a-changelog\"\n");
+    fs::write(&manifest, content).expect("write Cargo.toml");
+    Command::new("git")
+        .args(["remote", "add", "origin", "https://github.com/owner/repo"])
+        .current_dir(workspace.path())
+        .output()
+        .expect("failed to add remote");
+    add_changeset(&workspace, "crate-a");
+    git_add_and_commit(&workspace, "Add changeset");
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["release", "--allow-stale"])
+        .current_dir(workspace.path())
+        .assert()
+        .success();
+
+    let changelog =
+        fs::read_to_string(workspace.path().join("CHANGELOG.md")).expect("read changelog");
+    assert!(changelog.contains("## [Unreleased]\n\n## [0.1.1]"));
+    assert!(
+        changelog
+            .contains("[Unreleased]: https://github.com/owner/repo/compare/crate-a@v0.1.1...HEAD")
+    );
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--changelog-format"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stdout(contains("1 changelog(s) follow Keep a Changelog"));
+}
+
+#[test]
+fn verify_skips_members_outside_sparse_checkout() {
+    let workspace = create_virtual_workspace_with_git();
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/crate-a\", \"crates/crate-b\"]\nresolver = \"2\"\n",
+    )
+    .expect("failed to write workspace Cargo.toml");
+    git_add_and_commit(&workspace, "List members explicitly");
+    create_branch(&workspace, "feature");
+
+    fs::write(workspace.path().join("crates/crate-a/src/lib.rs"), "// a").expect("modify a");
+    fs::write(workspace.path().join("crates/crate-b/src/lib.rs"), "// b").expect("modify b");
+    add_changeset(&workspace, "crate-a");
+    git_add_and_commit(&workspace, "Change both crates");
+
+    let output = Command::new("git")
+        .args([
+            "sparse-checkout",
+            "set",
+            "--cone",
+            "crates/crate-a",
+            ".changeset",
+        ])
+        .current_dir(workspace.path())
+        .output()
+        .expect("failed to run git sparse-checkout");
+    assert!(
+        output.status.success(),
+        "sparse-checkout failed: {output:?}"
+    );
+    assert!(!workspace.path().join("crates/crate-b").exists());
+
+    assert_cmd::cargo::cargo_bin_cmd!("cargo-changeset")
+        .args(["verify", "--base", "main"])
+        .current_dir(workspace.path())
+        .assert()
+        .success()
+        .stderr(contains(
+            "warning: workspace member 'crates/crate-b' is not checked out; skipping",
+        ));
+}
@@ -1,15 +1,25 @@
 use std::path::Path;
 
+use changeset_core::{LineEnding, atomic_write};
+use chrono::{Datelike, NaiveDate};
+use indexmap::IndexMap;
+use semver::Version;
+
+use crate::config::{ArchivePolicy, ChangelogFormat};
 use crate::entry::VersionRelease;
 use crate::error::ChangelogError;
 use crate::forge::RepositoryInfo;
-use crate::format::{format_version_release, new_changelog};
+use crate::format::{format_entries_by, format_version_release, new_changelog};
 
 const HEADER_END_MARKER: &str = "and this project adheres to [Semantic Versioning]";
+pub(crate) const UNRELEASED_HEADING: &str = "## [Unreleased]";
+const ARCHIVE_LINKS_PREFIX: &str = "Older releases: ";
 
 #[derive(Debug, Clone)]
 pub struct Changelog {
+    /// Content with `\n` line breaks; converted back to `line_ending` on write.
     content: String,
+    line_ending: LineEnding,
 }
 
 impl Default for Changelog {
@@ -23,9 +33,33 @@ impl Changelog {
     pub fn new() -> Self {
         Self {
             content: new_changelog(),
+            line_ending: LineEnding::Lf,
         }
     }
 
+    /// Creates a changelog that starts with `preamble` instead of the default header.
+    #[must_use]
+    pub fn from_preamble(preamble: &str) -> Self {
+        let mut content = LineEnding::normalize(preamble);
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        Self {
+            line_ending: LineEnding::detect(preamble),
+            content,
+        }
+    }
+
+    /// Creates the changelog file `release` is the first entry of, using the
+    /// release's preamble when it has one.
+    #[must_use]
+    pub fn for_release(release: &VersionRelease) -> Self {
+        release
+            .preamble
+            .as_deref()
+            .map_or_else(Self::new, Self::from_preamble)
+    }
+
     /// # Errors
     ///
     /// Returns `ChangelogError::Read` if the file cannot be read.
@@ -35,7 +69,16 @@ impl Changelog {
             source,
         })?;
 
-        Ok(Self { content })
+        Ok(Self::parse(&content))
+    }
+
+    /// Creates a changelog from existing file content.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        Self {
+            line_ending: LineEnding::detect(content),
+            content: LineEnding::normalize(content),
+        }
     }
 
     /// # Errors
@@ -59,19 +102,330 @@ impl Changelog {
         &self.content
     }
 
+    /// Line ending used when writing the changelog back to disk.
+    #[must_use]
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
     pub fn add_release(
         &mut self,
         release: &VersionRelease,
         repo_info: Option<&RepositoryInfo>,
         previous_version: Option<&str>,
     ) {
-        let formatted = format_version_release(release);
+        let keep_a_changelog = release.format == ChangelogFormat::KeepAChangelog;
+        if keep_a_changelog && !self.has_unreleased_section() {
+            let insertion_point = self.find_insertion_point();
+            self.insert_section(insertion_point, &format!("{UNRELEASED_HEADING}\n"));
+        }
+
+        let insertion_point = if keep_a_changelog {
+            self.find_release_insertion_point()
+        } else {
+            self.find_insertion_point()
+        };
+        self.insert_section(insertion_point, &format_version_release(release));
+
+        if let Some(repo) = repo_info {
+            let base_tag = previous_version.map_or("HEAD".to_string(), |v| release.previous_tag(v));
+            let target_tag = release.tag(&release.version);
+            let comparison_url = repo.comparison_url(&base_tag, &target_tag);
+            let link_line = format!("[{}]: {}", release.version, comparison_url);
+
+            if keep_a_changelog {
+                let unreleased_line =
+                    format!("[Unreleased]: {}", repo.comparison_url(&target_tag, "HEAD"));
+                self.insert_leading_links(&unreleased_line, &link_line);
+            } else if !self.content.contains(&link_line) {
+                self.insert_links(&link_line);
+            }
+        }
+    }
+
+    /// Section of the release of `version`, from its heading to the next
+    /// section, without trailing blank lines.
+    #[must_use]
+    pub fn release_section(&self, version: &Version) -> Option<&str> {
+        let layout = self.layout();
+        let heading = format!("## [{version}]");
+        let index = layout
+            .headings
+            .iter()
+            .position(|&position| self.content[position..].starts_with(&heading))?;
+        let start = layout.headings[index];
+        let end = layout
+            .headings
+            .get(index + 1)
+            .copied()
+            .unwrap_or(layout.sections_end);
+        Some(self.content[start..end].trim_end())
+    }
+
+    /// Adds the entries of `release` to the existing section for its version,
+    /// below the entries of matching subsections or in new subsections at its end.
+    ///
+    /// Returns `false` and leaves the changelog unchanged when there is no
+    /// section for the version.
+    pub fn extend_release(&mut self, release: &VersionRelease) -> bool {
+        let layout = self.layout();
+        let heading = format!("## [{}]", release.version);
+        let Some(index) = layout
+            .headings
+            .iter()
+            .position(|&position| self.content[position..].starts_with(&heading))
+        else {
+            return false;
+        };
+        let start = layout.headings[index];
+        let end = layout
+            .headings
+            .get(index + 1)
+            .copied()
+            .unwrap_or(layout.sections_end);
+
+        let body_end = start + self.content[start..end].trim_end().len();
+        let mut body = self.content[start..body_end].to_string();
+        let entries = format_entries_by(&release.entries, &release.categories, |a, b| {
+            release.entry_order.compare(&release.categories, a, b)
+        });
+        for (subheading, items) in subsections(&entries) {
+            append_to_subsection(&mut body, subheading, &items);
+        }
+        self.content.replace_range(start..body_end, &body);
+        true
+    }
+
+    /// File content with the original line endings, as [`Self::write_to_file`] writes it.
+    #[must_use]
+    pub fn render(&self) -> String {
+        self.line_ending.apply(&self.content)
+    }
+
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::Write` if the file cannot be written.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), ChangelogError> {
+        atomic_write(path, self.render()).map_err(|source| ChangelogError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Moves the release sections `policy` archives out of the changelog,
+    /// together with their link definitions, and links the archive files from
+    /// the footer. Releases without a date in their heading are never moved.
+    ///
+    /// Returns the moved sections grouped by archive file, newest first.
+    pub fn take_archived_releases(&mut self, policy: &ArchivePolicy) -> Vec<ArchivedReleases> {
+        let layout = self.layout();
+        let releases: Vec<(usize, usize)> = layout
+            .headings
+            .iter()
+            .enumerate()
+            .filter(|&(_, &start)| !self.content[start..].starts_with(UNRELEASED_HEADING))
+            .map(|(index, &start)| {
+                let end = layout
+                    .headings
+                    .get(index + 1)
+                    .copied()
+                    .unwrap_or(layout.sections_end);
+                (start, end)
+            })
+            .collect();
+        let heading = |start: usize| self.content[start..].lines().next().unwrap_or_default();
+        let newest_year = releases
+            .iter()
+            .find_map(|&(start, _)| release_year(heading(start)));
+
+        let mut moved = Vec::new();
+        for (index, &(start, end)) in releases.iter().enumerate() {
+            let Some(year) = release_year(heading(start)) else {
+                continue;
+            };
+            let archived = match policy.max_releases {
+                Some(max) => index >= max,
+                None => newest_year.is_some_and(|newest| year < newest),
+            };
+            if archived {
+                moved.push((start, end, year));
+            }
+        }
+        if moved.is_empty() {
+            return Vec::new();
+        }
+
+        let mut archives: IndexMap<String, ArchivedReleases> = IndexMap::new();
+        let mut versions = Vec::new();
+        for &(start, end, year) in &moved {
+            let file_name = policy.archive_file(year);
+            let archive = archives
+                .entry(file_name.clone())
+                .or_insert_with(|| ArchivedReleases {
+                    file_name,
+                    sections: Vec::new(),
+                    links: Vec::new(),
+                    line_ending: self.line_ending,
+                });
+            archive
+                .sections
+                .push(format!("{}\n", self.content[start..end].trim_end()));
+            if let Some(label) = heading(start)
+                .strip_prefix("## [")
+                .and_then(|rest| rest.split_once(']'))
+            {
+                versions.push((label.0.to_string(), archive.file_name.clone()));
+            }
+        }
+
+        for &(start, end, _) in moved.iter().rev() {
+            self.content.replace_range(start..end, "");
+        }
+        self.take_version_links(&versions, &mut archives);
+        if self.layout().sections_end == self.content.len() {
+            self.content.truncate(self.content.trim_end().len());
+            self.content.push('\n');
+        }
+        self.link_archives(archives.keys());
+
+        archives.into_values().collect()
+    }
+
+    /// Moves the link definitions of `versions` into their archives.
+    fn take_version_links(
+        &mut self,
+        versions: &[(String, String)],
+        archives: &mut IndexMap<String, ArchivedReleases>,
+    ) {
+        let mut kept = String::with_capacity(self.content.len());
+        for line in self.content.split_inclusive('\n') {
+            let archive = version_link_label(line.trim_end()).and_then(|label| {
+                versions
+                    .iter()
+                    .find(|(version, _)| version == label)
+                    .and_then(|(_, file_name)| archives.get_mut(file_name))
+            });
+            match archive {
+                Some(archive) => archive.links.push(line.trim_end().to_string()),
+                None => kept.push_str(line),
+            }
+        }
+        self.content = kept;
+    }
+
+    /// Lists the archive files on the `Older releases` line of the footer,
+    /// newest first, adding the line after a thematic break when missing.
+    fn link_archives<'a>(&mut self, file_names: impl Iterator<Item = &'a String>) {
+        let existing = self
+            .content
+            .lines()
+            .find(|line| line.starts_with(ARCHIVE_LINKS_PREFIX))
+            .map(str::to_string);
+        let mut links: Vec<String> = file_names
+            .map(|file_name| format!("[{file_name}]({file_name})"))
+            .filter(|link| existing.as_ref().is_none_or(|line| !line.contains(link)))
+            .collect();
+        if links.is_empty() {
+            return;
+        }
+
+        if let Some(line) = existing {
+            links.push(line[ARCHIVE_LINKS_PREFIX.len()..].to_string());
+            let updated = format!("{ARCHIVE_LINKS_PREFIX}{}", links.join(", "));
+            self.content = self.content.replacen(&line, &updated, 1);
+        } else {
+            let footer_start = self.layout().sections_end;
+            let block = format!("---\n\n{ARCHIVE_LINKS_PREFIX}{}\n", links.join(", "));
+            self.insert_section(footer_start, &block);
+        }
+    }
+
+    /// Start of the first section, or where it goes when there is none.
+    fn find_insertion_point(&self) -> usize {
+        self.layout().sections_start
+    }
+
+    /// Position of the first release section, skipping the `Unreleased` section.
+    ///
+    /// Without any release, this is where the footer starts.
+    fn find_release_insertion_point(&self) -> usize {
+        let layout = self.layout();
+        layout
+            .headings
+            .iter()
+            .find(|&&position| !self.content[position..].starts_with(UNRELEASED_HEADING))
+            .copied()
+            .unwrap_or(layout.sections_end)
+    }
+
+    /// Splits the content into header, sections and footer, skipping fenced
+    /// code blocks.
+    ///
+    /// The footer starts at the first link definition, thematic break, HTML
+    /// comment or non-section heading after the last section heading. Without
+    /// sections, only link definitions, thematic breaks and HTML comments after
+    /// the standard header end it, since the header may have its own headings.
+    fn layout(&self) -> Layout {
+        let mut lines = Vec::new();
+        let mut in_fence = false;
+        let mut position = 0;
+        for line in self.content.split_inclusive('\n') {
+            let text = line.trim_end();
+            if is_fence(text) {
+                in_fence = !in_fence;
+            } else if !in_fence {
+                lines.push((position, text));
+            }
+            position += line.len();
+        }
+
+        let headings: Vec<usize> = lines
+            .iter()
+            .filter(|(_, text)| text.starts_with("## ["))
+            .map(|&(position, _)| position)
+            .collect();
 
-        let insertion_point = self.find_insertion_point();
+        if let Some(&last) = headings.last() {
+            let sections_end = lines
+                .iter()
+                .filter(|&&(position, _)| position > last)
+                .find(|(_, text)| starts_footer(text))
+                .map_or(self.content.len(), |&(position, _)| position);
+            return Layout {
+                sections_start: headings[0],
+                sections_end,
+                headings,
+            };
+        }
 
-        let mut new_content = String::with_capacity(self.content.len() + formatted.len() + 100);
+        let header_end = self.content.find(HEADER_END_MARKER).and_then(|marker| {
+            self.content[marker..]
+                .find('\n')
+                .map(|newline| marker + newline + 1)
+        });
+        let footer_start = lines
+            .iter()
+            .filter(|&&(position, _)| position >= header_end.unwrap_or(0))
+            .find(|(_, text)| is_footer_line(text))
+            .map_or(self.content.len(), |&(position, _)| position);
+        Layout {
+            sections_start: header_end.unwrap_or(footer_start),
+            sections_end: footer_start,
+            headings,
+        }
+    }
 
-        new_content.push_str(&self.content[..insertion_point]);
+    fn has_unreleased_section(&self) -> bool {
+        self.content
+            .lines()
+            .any(|line| line.trim_end() == UNRELEASED_HEADING)
+    }
+
+    /// Inserts `section` at `position`, separated from its neighbours by blank lines.
+    fn insert_section(&mut self, position: usize, section: &str) {
+        let mut new_content = String::with_capacity(self.content.len() + section.len() + 4);
+
+        new_content.push_str(&self.content[..position]);
         if !new_content.ends_with("\n\n") {
             if new_content.ends_with('\n') {
                 new_content.push('\n');
@@ -79,58 +433,223 @@ impl Changelog {
                 new_content.push_str("\n\n");
             }
         }
-        new_content.push_str(&formatted);
+        new_content.push_str(section);
 
-        if insertion_point < self.content.len() {
-            let rest = &self.content[insertion_point..];
+        if position < self.content.len() {
+            let rest = &self.content[position..];
             if !rest.starts_with('\n') {
                 new_content.push('\n');
             }
             new_content.push_str(rest);
         }
 
-        if let Some(repo) = repo_info {
-            let base_tag = previous_version.map_or("HEAD".to_string(), |v| format!("v{v}"));
-            let target_tag = format!("v{}", release.version);
-            let comparison_url = repo.comparison_url(&base_tag, &target_tag);
+        self.content = new_content;
+    }
 
-            let link_line = format!("[{}]: {}", release.version, comparison_url);
-            if !new_content.contains(&link_line) {
-                if !new_content.ends_with('\n') {
-                    new_content.push('\n');
+    /// Adds link definitions after the last one in the footer, or at the
+    /// start of the footer when it has none, after a blank line.
+    fn insert_links(&mut self, links: &str) {
+        let footer_start = self.layout().sections_end;
+        let mut position = footer_start;
+        let mut last_link_end = None;
+        for line in self.content[footer_start..].split_inclusive('\n') {
+            position += line.len();
+            if is_link_definition(line.trim_end()) {
+                last_link_end = Some(position);
+            }
+        }
+
+        match last_link_end {
+            Some(end) if end < self.content.len() => {
+                self.content.insert_str(end, &format!("\n{links}\n"));
+            }
+            None if footer_start < self.content.len() => {
+                self.insert_section(footer_start, &format!("{links}\n"));
+            }
+            _ => {
+                if !self.content.ends_with('\n') {
+                    self.content.push('\n');
                 }
-                new_content.push('\n');
-                new_content.push_str(&link_line);
-                new_content.push('\n');
+                self.content.push('\n');
+                self.content.push_str(links);
+                self.content.push('\n');
             }
         }
+    }
 
-        self.content = new_content;
+    /// Replaces the `[Unreleased]` link and puts it, followed by `link_line`,
+    /// above the existing version links.
+    fn insert_leading_links(&mut self, unreleased_line: &str, link_line: &str) {
+        let mut lines: Vec<&str> = self
+            .content
+            .lines()
+            .filter(|line| !line.starts_with("[Unreleased]: ") && *line != link_line)
+            .collect();
+        let first_link = lines
+            .iter()
+            .position(|line| version_link_label(line).is_some());
+
+        let no_existing_links = first_link.is_none();
+        if let Some(index) = first_link {
+            lines.splice(index..index, [unreleased_line, link_line]);
+        }
+        let mut content = lines.join("\n");
+        content.push('\n');
+        self.content = content;
+        if no_existing_links {
+            self.insert_links(&format!("{unreleased_line}\n{link_line}"));
+        }
     }
+}
 
-    /// # Errors
-    ///
-    /// Returns `ChangelogError::Write` if the file cannot be written.
-    pub fn write_to_file(&self, path: &Path) -> Result<(), ChangelogError> {
-        std::fs::write(path, &self.content).map_err(|source| ChangelogError::Write {
-            path: path.to_path_buf(),
-            source,
-        })
+/// Release sections moved out of a changelog into one archive file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedReleases {
+    /// Archive file path relative to the changelog.
+    pub file_name: String,
+    /// Sections, newest first.
+    sections: Vec<String>,
+    /// Link definitions of the sections.
+    links: Vec<String>,
+    line_ending: LineEnding,
+}
+
+impl ArchivedReleases {
+    /// Number of release sections moved to the archive.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sections.len()
     }
 
-    fn find_insertion_point(&self) -> usize {
-        if let Some(first_version_pos) = self.content.find("\n## [") {
-            return first_version_pos + 1;
-        }
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
 
-        if let Some(header_end) = self.content.find(HEADER_END_MARKER) {
-            if let Some(newline_after) = self.content[header_end..].find('\n') {
-                return header_end + newline_after + 1;
+    /// Content of the archive file with the releases added above the ones it
+    /// already holds, which are older. `existing` is the current content of
+    /// the archive, or `None` to start a new one.
+    #[must_use]
+    pub fn merge_into(&self, existing: Option<&str>) -> String {
+        let mut archive = existing.map_or_else(
+            || Changelog {
+                content: "# Changelog\n".to_string(),
+                line_ending: self.line_ending,
+            },
+            Changelog::parse,
+        );
+        let position = archive.find_insertion_point();
+        archive.insert_section(position, &self.sections.join("\n"));
+        if !self.links.is_empty() {
+            let links = self.links.join("\n");
+            if archive
+                .content
+                .lines()
+                .last()
+                .is_some_and(is_link_definition)
+            {
+                archive.content.push_str(&links);
+                archive.content.push('\n');
+            } else {
+                archive.insert_links(&links);
             }
         }
+        archive.render()
+    }
+}
 
-        self.content.len()
+/// Year of the date in a release heading such as `## [1.2.0] - 2025-01-31`.
+fn release_year(heading: &str) -> Option<i32> {
+    let (_, rest) = heading.split_once("] - ")?;
+    let date = NaiveDate::parse_from_str(rest.get(..10)?, "%Y-%m-%d").ok()?;
+    Some(date.year())
+}
+
+/// Byte offsets splitting a changelog into header, sections and footer.
+struct Layout {
+    sections_start: usize,
+    sections_end: usize,
+    /// Start of every section heading, in file order.
+    headings: Vec<usize>,
+}
+
+/// Splits rendered entries into their `###` headings and list items.
+fn subsections(entries: &str) -> Vec<(&str, Vec<&str>)> {
+    let mut subsections: Vec<(&str, Vec<&str>)> = Vec::new();
+    for line in entries.lines() {
+        if line.starts_with("### ") {
+            subsections.push((line, Vec::new()));
+        } else if line.starts_with("- ")
+            && let Some((_, items)) = subsections.last_mut()
+        {
+            items.push(line);
+        }
     }
+    subsections
+}
+
+/// Appends `items` after the last line of the `subheading` subsection of a
+/// release section, or adds the subsection at the end when it is missing.
+fn append_to_subsection(section: &mut String, subheading: &str, items: &[&str]) {
+    let mut position = 0;
+    let mut heading_end = None;
+    for line in section.split_inclusive('\n') {
+        position += line.len();
+        if line.trim_end() == subheading {
+            heading_end = Some(position);
+            break;
+        }
+    }
+
+    let mut lines = String::new();
+    for item in items {
+        lines.push('\n');
+        lines.push_str(item);
+    }
+    if let Some(heading_end) = heading_end {
+        let subsection_end = section[heading_end..]
+            .find("\n### ")
+            .map_or(section.len(), |offset| heading_end + offset);
+        let insert_at = heading_end + section[heading_end..subsection_end].trim_end().len();
+        section.insert_str(insert_at, &lines);
+    } else {
+        section.push_str("\n\n");
+        section.push_str(subheading);
+        section.push('\n');
+        section.push_str(&lines);
+    }
+}
+
+fn is_link_definition(line: &str) -> bool {
+    line.starts_with('[') && line.contains("]: ")
+}
+
+pub(crate) fn is_fence(line: &str) -> bool {
+    line.starts_with("```") || line.starts_with("~~~")
+}
+
+/// Whether `line`, found after the last section heading, starts the footer.
+pub(crate) fn starts_footer(line: &str) -> bool {
+    is_footer_line(line) || is_non_section_heading(line)
+}
+
+fn is_footer_line(line: &str) -> bool {
+    let thematic_break = line.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| line.chars().all(|c| c == m));
+    is_link_definition(line) || thematic_break || line.starts_with("<!--")
+}
+
+fn is_non_section_heading(line: &str) -> bool {
+    (line.starts_with("# ") || line.starts_with("## ")) && !line.starts_with("## [")
+}
+
+/// Label of a link definition for a changelog section, such as `1.2.0` in
+/// `[1.2.0]: https://...`.
+pub(crate) fn version_link_label(line: &str) -> Option<&str> {
+    let (label, _) = line.strip_prefix('[')?.split_once("]: ")?;
+    (label == "Unreleased" || Version::parse(label).is_ok()).then_some(label)
 }
 
 #[cfg(test)]
@@ -194,6 +713,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn comparison_link_uses_release_tag_prefix() {
+        let mut changelog = Changelog::new();
+        let release = VersionRelease::new(
+            Version::new(1, 1, 0),
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+        )
+        .with_tag_prefix("crate-a@");
+        let repo_info =
+            RepositoryInfo::from_url("https://github.com/owner/repo").expect("valid url");
+
+        changelog.add_release(&release, Some(&repo_info), Some("1.0.0"));
+
+        assert!(changelog.content().contains(
+            "[1.1.0]: https://github.com/owner/repo/compare/crate-a@v1.0.0...crate-a@v1.1.0"
+        ));
+    }
+
+    #[test]
+    fn comparison_link_starts_at_previous_tag_prefix() {
+        let mut changelog = Changelog::new();
+        let release = VersionRelease::new(
+            Version::parse("1.1.0-nightly.1").expect("valid version"),
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+        )
+        .with_tag_prefix("nightly/crate-a@")
+        .with_previous_tag_prefix("crate-a@");
+        let repo_info =
+            RepositoryInfo::from_url("https://github.com/owner/repo").expect("valid url");
+
+        changelog.add_release(&release, Some(&repo_info), Some("1.0.0"));
+
+        assert!(changelog.content().contains(
+            "[1.1.0-nightly.1]: https://github.com/owner/repo/compare/crate-a@v1.0.0...nightly/crate-a@v1.1.0-nightly.1"
+        ));
+    }
+
     #[test]
     fn multiple_releases_maintain_order() {
         let mut changelog = Changelog::new();
@@ -255,6 +813,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn keep_a_changelog_releases_follow_unreleased_section() {
+        let mut changelog = Changelog::new();
+        let repo_info =
+            RepositoryInfo::from_url("https://github.com/owner/repo").expect("valid url");
+
+        for (version, previous, date) in [
+            (Version::new(1, 0, 0), None, (2025, 1, 1)),
+            (Version::new(1, 1, 0), Some("1.0.0"), (2025, 2, 1)),
+        ] {
+            let release = VersionRelease::new(
+                version,
+                NaiveDate::from_ymd_opt(date.0, date.1, date.2).expect("valid date"),
+                vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+            )
+            .with_format(ChangelogFormat::KeepAChangelog);
+            changelog.add_release(&release, Some(&repo_info), previous);
+        }
+
+        let content = changelog.content();
+        assert!(content.contains(
+            "## [Unreleased]\n\n## [1.1.0] - 2025-02-01\n### Fixed\n\n- Bug fix\n\n## [1.0.0]"
+        ));
+        assert!(content.ends_with(
+            "\n\n[Unreleased]: https://github.com/owner/repo/compare/v1.1.0...HEAD\n\
+             [1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0\n\
+             [1.0.0]: https://github.com/owner/repo/compare/HEAD...v1.0.0\n"
+        ));
+        assert!(crate::validate_keep_a_changelog(content).is_empty());
+    }
+
+    #[test]
+    fn keep_a_changelog_keeps_unreleased_section_in_existing_file() {
+        let mut changelog = Changelog::parse(
+            "# Changelog\n\n## [Unreleased]\n\n### Added\n\n- Pending\n\n## [1.0.0] - 2025-01-01\n\n### Added\n\n- First\n",
+        );
+        let release = VersionRelease::new(
+            Version::new(1, 1, 0),
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+        )
+        .with_format(ChangelogFormat::KeepAChangelog);
+
+        changelog.add_release(&release, None, Some("1.0.0"));
+
+        assert_eq!(
+            changelog.content(),
+            "# Changelog\n\n## [Unreleased]\n\n### Added\n\n- Pending\n\n\
+             ## [1.1.0] - 2025-02-01\n### Fixed\n\n- Bug fix\n\n\
+             ## [1.0.0] - 2025-01-01\n\n### Added\n\n- First\n"
+        );
+    }
+
     #[test]
     fn from_file_reads_content() {
         let temp_dir = tempfile::tempdir().expect("create temp dir");
@@ -300,6 +911,40 @@ mod tests {
         assert_eq!(changelog.content(), content);
     }
 
+    #[test]
+    fn preserves_crlf_line_endings() {
+        let temp_dir = tempfile::tempdir().expect("create temp dir");
+        let file_path = temp_dir.path().join("CHANGELOG.md");
+        let existing = Changelog::new()
+            .content()
+            .replace('\n', "\r\n")
+            .replace(
+                "and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\r\n",
+                "and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\r\n\r\n## [1.0.0] - 2025-01-01\r\n\r\n### Added\r\n\r\n- First\r\n",
+            );
+        std::fs::write(&file_path, &existing).expect("write file");
+
+        let mut changelog = Changelog::from_file(&file_path).expect("read file");
+        assert_eq!(changelog.line_ending(), LineEnding::CrLf);
+
+        let release = VersionRelease::new(
+            Version::new(1, 1, 0),
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Second")],
+        );
+        changelog.add_release(&release, None, None);
+        changelog.write_to_file(&file_path).expect("write file");
+
+        let written = std::fs::read_to_string(&file_path).expect("read file");
+        assert!(written.contains("## [1.1.0] - 2025-02-01\r\n"));
+        assert!(written.contains("- Second\r\n\r\n## [1.0.0]"));
+        assert_eq!(
+            written.matches('\n').count(),
+            written.matches("\r\n").count()
+        );
+        assert!(written.starts_with(&existing[..existing.find("## [1.0.0]").expect("section")]));
+    }
+
     #[test]
     fn from_file_validated_rejects_invalid_changelog() {
         let temp_dir = tempfile::tempdir().expect("create temp dir");
@@ -314,4 +959,284 @@ mod tests {
             Err(ChangelogError::InvalidChangelogFormat { .. })
         ));
     }
+
+    fn fixed_release(version: Version) -> VersionRelease {
+        VersionRelease::new(
+            version,
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+        )
+    }
+
+    #[test]
+    fn custom_header_and_footer_are_preserved() {
+        let header = "# Release Notes\n\n![badge](https://example.com/badge.svg)\n\n## Support\n\nAsk on the forum.\n\n";
+        let footer = "\n---\n\n<!-- generated by cargo-changeset -->\n";
+        let mut changelog = Changelog::parse(&format!(
+            "{header}## [1.0.0] - 2025-01-01\n### Added\n\n- Feature\n\n[1.0.0]: https://example.com/1.0.0\n{footer}"
+        ));
+        let repo_info =
+            RepositoryInfo::from_url("https://github.com/owner/repo").expect("valid url");
+
+        changelog.add_release(
+            &fixed_release(Version::new(1, 1, 0)),
+            Some(&repo_info),
+            Some("1.0.0"),
+        );
+
+        let content = changelog.content();
+        assert!(content.starts_with(&format!("{header}## [1.1.0] - 2025-02-01\n")));
+        assert!(content.ends_with(&format!(
+            "[1.0.0]: https://example.com/1.0.0\n\n\
+             [1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0\n{footer}"
+        )));
+    }
+
+    #[test]
+    fn first_release_goes_before_footer() {
+        let mut changelog = Changelog::from_preamble("# my-crate\n\n<!-- footer -->");
+        let repo_info =
+            RepositoryInfo::from_url("https://github.com/owner/repo").expect("valid url");
+
+        changelog.add_release(
+            &fixed_release(Version::new(1, 0, 0)),
+            Some(&repo_info),
+            None,
+        );
+
+        assert_eq!(
+            changelog.content(),
+            "# my-crate\n\n## [1.0.0] - 2025-02-01\n### Fixed\n\n- Bug fix\n\n\
+             [1.0.0]: https://github.com/owner/repo/compare/HEAD...v1.0.0\n\n<!-- footer -->\n"
+        );
+    }
+
+    #[test]
+    fn release_section_ends_at_next_section() {
+        let changelog = Changelog::parse(
+            "# Changelog\n\n## [1.1.0] - 2025-02-01\n\n### Added\n\n- New API\n\n\
+             ## [1.0.0] - 2025-01-01\n\n### Fixed\n\n- Bug\n\n[1.1.0]: https://example.com\n",
+        );
+
+        assert_eq!(
+            changelog.release_section(&Version::new(1, 1, 0)),
+            Some("## [1.1.0] - 2025-02-01\n\n### Added\n\n- New API")
+        );
+        assert_eq!(
+            changelog.release_section(&Version::new(1, 0, 0)),
+            Some("## [1.0.0] - 2025-01-01\n\n### Fixed\n\n- Bug")
+        );
+        assert_eq!(changelog.release_section(&Version::new(0, 9, 0)), None);
+    }
+
+    #[test]
+    fn headings_in_code_blocks_are_not_sections() {
+        let header = "# Changelog\n\n```md\n## [0.0.1] - 2020-01-01\n```\n";
+        let mut changelog = Changelog::parse(header);
+
+        changelog.add_release(&fixed_release(Version::new(1, 0, 0)), None, None);
+
+        assert!(
+            changelog
+                .content()
+                .starts_with(&format!("{header}\n## [1.0.0] - 2025-02-01\n"))
+        );
+    }
+
+    #[test]
+    fn for_release_uses_preamble() {
+        let release = fixed_release(Version::new(1, 0, 0)).with_preamble("# my-crate\n");
+        assert_eq!(Changelog::for_release(&release).content(), "# my-crate\n");
+        assert_eq!(
+            Changelog::for_release(&fixed_release(Version::new(1, 0, 0))).content(),
+            Changelog::new().content()
+        );
+    }
+
+    fn release_on(version: Version, entries: Vec<ChangelogEntry>) -> VersionRelease {
+        VersionRelease::new(
+            version,
+            NaiveDate::from_ymd_opt(2025, 3, 1).expect("valid date"),
+            entries,
+        )
+    }
+
+    #[test]
+    fn extend_release_appends_to_matching_subsection() {
+        let mut changelog = Changelog::new();
+        changelog.add_release(
+            &release_on(
+                Version::new(1, 0, 0),
+                vec![ChangelogEntry::new(ChangeCategory::Added, "Old feature")],
+            ),
+            None,
+            None,
+        );
+        changelog.add_release(
+            &release_on(
+                Version::new(1, 1, 0),
+                vec![
+                    ChangelogEntry::new(ChangeCategory::Added, "New feature"),
+                    ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix"),
+                ],
+            ),
+            None,
+            Some("1.0.0"),
+        );
+
+        let extended = changelog.extend_release(&release_on(
+            Version::new(1, 1, 0),
+            vec![ChangelogEntry::new(ChangeCategory::Added, "Late feature")],
+        ));
+
+        assert!(extended);
+        let content = changelog.content();
+        assert!(content.contains(
+            "## [1.1.0] - 2025-03-01\n### Added\n\n- New feature\n- Late feature\n\n### Fixed\n\n- Bug fix\n"
+        ));
+        assert_eq!(content.matches("Late feature").count(), 1);
+        assert!(content.contains("## [1.0.0] - 2025-03-01\n### Added\n\n- Old feature\n"));
+    }
+
+    #[test]
+    fn extend_release_adds_missing_subsection() {
+        let mut changelog = Changelog::new();
+        changelog.add_release(
+            &release_on(
+                Version::new(1, 1, 0),
+                vec![ChangelogEntry::new(ChangeCategory::Added, "New feature")],
+            ),
+            None,
+            None,
+        );
+        changelog.add_release(
+            &release_on(
+                Version::new(1, 2, 0),
+                vec![ChangelogEntry::new(ChangeCategory::Added, "Newest feature")],
+            ),
+            None,
+            None,
+        );
+
+        let extended = changelog.extend_release(&release_on(
+            Version::new(1, 1, 0),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Late fix")],
+        ));
+
+        assert!(extended);
+        assert!(changelog.content().ends_with(
+            "## [1.1.0] - 2025-03-01\n### Added\n\n- New feature\n\n### Fixed\n\n- Late fix\n"
+        ));
+    }
+
+    #[test]
+    fn extend_release_without_section_leaves_changelog_unchanged() {
+        let mut changelog = Changelog::new();
+        changelog.add_release(
+            &release_on(
+                Version::new(1, 1, 0),
+                vec![ChangelogEntry::new(ChangeCategory::Added, "New feature")],
+            ),
+            None,
+            None,
+        );
+        let before = changelog.content().to_string();
+
+        let extended = changelog.extend_release(&release_on(
+            Version::new(1, 1, 1),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Late fix")],
+        ));
+
+        assert!(!extended);
+        assert_eq!(changelog.content(), before);
+    }
+
+    const ROTATION_HISTORY: &str = "# Changelog\n\n## [1.2.0] - 2026-03-01\n\n- Three\n\n## [1.1.0] - 2026-01-10\n\n- Two\n\n## [1.0.0] - 2025-06-01\n\n- One\n\n## [0.1.0] - 2024-02-01\n\n- Zero\n\n[1.2.0]: https://example.com/1.2.0\n[1.1.0]: https://example.com/1.1.0\n[1.0.0]: https://example.com/1.0.0\n[0.1.0]: https://example.com/0.1.0\n";
+
+    fn policy(max_releases: Option<usize>) -> ArchivePolicy {
+        ArchivePolicy {
+            max_releases,
+            archive: ArchivePolicy::DEFAULT_ARCHIVE.to_string(),
+        }
+    }
+
+    #[test]
+    fn archives_releases_beyond_the_limit() {
+        let mut changelog = Changelog::parse(ROTATION_HISTORY);
+
+        let archives = changelog.take_archived_releases(&policy(Some(2)));
+
+        assert_eq!(
+            changelog.content(),
+            "# Changelog\n\n## [1.2.0] - 2026-03-01\n\n- Three\n\n## [1.1.0] - 2026-01-10\n\n- Two\n\n---\n\nOlder releases: [CHANGELOG-2025.md](CHANGELOG-2025.md), [CHANGELOG-2024.md](CHANGELOG-2024.md)\n\n[1.2.0]: https://example.com/1.2.0\n[1.1.0]: https://example.com/1.1.0\n"
+        );
+        let names: Vec<_> = archives.iter().map(|a| a.file_name.as_str()).collect();
+        assert_eq!(names, ["CHANGELOG-2025.md", "CHANGELOG-2024.md"]);
+        assert_eq!(
+            archives[0].merge_into(None),
+            "# Changelog\n\n## [1.0.0] - 2025-06-01\n\n- One\n\n[1.0.0]: https://example.com/1.0.0\n"
+        );
+    }
+
+    #[test]
+    fn archives_releases_of_earlier_years_without_a_limit() {
+        let mut changelog = Changelog::parse(ROTATION_HISTORY);
+
+        let archives = changelog.take_archived_releases(&policy(None));
+
+        assert_eq!(archives.len(), 2);
+        assert!(changelog.content().contains("## [1.1.0] - 2026-01-10"));
+        assert!(!changelog.content().contains("## [1.0.0]"));
+    }
+
+    #[test]
+    fn archived_releases_go_above_older_ones() {
+        let policy = ArchivePolicy {
+            max_releases: Some(3),
+            archive: "HISTORY.md".to_string(),
+        };
+        let mut changelog = Changelog::parse(ROTATION_HISTORY);
+        let archive = changelog.take_archived_releases(&policy)[0].merge_into(None);
+        changelog.add_release(&fixed_release(Version::new(1, 3, 0)), None, None);
+
+        let archived = changelog.take_archived_releases(&policy);
+
+        let archive = archived[0].merge_into(Some(&archive));
+        assert!(archive.find("## [1.0.0]") < archive.find("## [0.1.0]"));
+        assert!(
+            archive.ends_with(
+                "[0.1.0]: https://example.com/0.1.0\n[1.0.0]: https://example.com/1.0.0\n"
+            )
+        );
+        assert_eq!(changelog.content().matches("Older releases: ").count(), 1);
+        assert!(
+            changelog
+                .content()
+                .contains("Older releases: [HISTORY.md](HISTORY.md)\n")
+        );
+    }
+
+    #[test]
+    fn new_archives_are_listed_first() {
+        let mut changelog = Changelog::parse(ROTATION_HISTORY);
+        changelog.take_archived_releases(&policy(Some(3)));
+
+        changelog.take_archived_releases(&policy(Some(2)));
+
+        assert!(changelog.content().contains(
+            "Older releases: [CHANGELOG-2025.md](CHANGELOG-2025.md), [CHANGELOG-2024.md](CHANGELOG-2024.md)\n"
+        ));
+    }
+
+    #[test]
+    fn nothing_is_archived_within_the_limit() {
+        let mut changelog = Changelog::parse(ROTATION_HISTORY);
+
+        assert!(
+            changelog
+                .take_archived_releases(&policy(Some(4)))
+                .is_empty()
+        );
+        assert_eq!(changelog.content(), ROTATION_HISTORY);
+    }
 }
@@ -1,5 +1,10 @@
+use std::cmp::Ordering;
+
+use changeset_core::CategorySet;
 use serde::Deserialize;
 
+use crate::entry::ChangelogEntry;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ChangelogLocation {
@@ -17,6 +22,81 @@ pub enum ComparisonLinksSetting {
     Disabled,
 }
 
+/// Structure the changelog files follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangelogFormat {
+    /// Release sections below the header, newest first.
+    #[default]
+    Standard,
+    /// Strict [Keep a Changelog](https://keepachangelog.com/en/1.1.0/): an
+    /// `Unreleased` section above the releases and a link definition for
+    /// every section.
+    KeepAChangelog,
+}
+
+/// A file format a changelog is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangelogOutput {
+    /// `CHANGELOG.md`, always written.
+    Markdown,
+    /// `CHANGELOG.json` next to each `CHANGELOG.md`, with the same releases
+    /// as structured data.
+    Json,
+}
+
+/// How entries are ordered within a changelog section.
+///
+/// Every order falls back to the changeset file name, so equal keys still
+/// render the same way on every run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EntryOrder {
+    /// By category order, then package, then changeset file name.
+    #[default]
+    Category,
+    /// By package, then category order, then changeset file name.
+    Package,
+    /// By changeset modification time, oldest first, then file name.
+    Timestamp,
+    /// In the order the changeset files were read.
+    File,
+}
+
+impl EntryOrder {
+    /// Compares two entries; `categories` decides the rank of each category.
+    #[must_use]
+    pub fn compare(
+        self,
+        categories: &CategorySet,
+        a: &ChangelogEntry,
+        b: &ChangelogEntry,
+    ) -> Ordering {
+        let rank = |entry: &ChangelogEntry| {
+            categories
+                .iter()
+                .position(|definition| definition.category == entry.category)
+                .unwrap_or(usize::MAX)
+        };
+        let by_file = || a.file_name.cmp(&b.file_name);
+
+        match self {
+            Self::Category => rank(a)
+                .cmp(&rank(b))
+                .then_with(|| a.package.cmp(&b.package))
+                .then_with(by_file),
+            Self::Package => a
+                .package
+                .cmp(&b.package)
+                .then_with(|| rank(a).cmp(&rank(b)))
+                .then_with(by_file),
+            Self::Timestamp => a.timestamp.cmp(&b.timestamp).then_with(by_file),
+            Self::File => Ordering::Equal,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct ChangelogConfig {
@@ -25,6 +105,63 @@ pub struct ChangelogConfig {
     #[serde(default)]
     pub comparison_links: ComparisonLinksSetting,
     pub comparison_links_template: Option<String>,
+    #[serde(default)]
+    pub entry_order: EntryOrder,
+    #[serde(default)]
+    pub format: ChangelogFormat,
+    /// Header for newly created changelog files; `{package}` is replaced by
+    /// the name the changelog belongs to.
+    pub preamble: Option<String>,
+    /// Formats to write besides Markdown, which is always written.
+    #[serde(default)]
+    pub output: Vec<ChangelogOutput>,
+    /// Release sections kept in each changelog before older ones are archived.
+    pub max_releases: Option<usize>,
+    /// Archive file for older release sections, relative to the changelog.
+    pub archive: Option<String>,
+}
+
+impl ChangelogConfig {
+    /// Whether a `CHANGELOG.json` is maintained next to each `CHANGELOG.md`.
+    #[must_use]
+    pub fn writes_json(&self) -> bool {
+        self.output.contains(&ChangelogOutput::Json)
+    }
+
+    /// How older release sections are moved out of the changelogs, if at all.
+    #[must_use]
+    pub fn archive_policy(&self) -> Option<ArchivePolicy> {
+        if self.max_releases.is_none() && self.archive.is_none() {
+            return None;
+        }
+        Some(ArchivePolicy {
+            max_releases: self.max_releases,
+            archive: self
+                .archive
+                .clone()
+                .unwrap_or_else(|| ArchivePolicy::DEFAULT_ARCHIVE.to_string()),
+        })
+    }
+}
+
+/// Which release sections are moved from a changelog into archive files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivePolicy {
+    /// Release sections kept in the changelog. Without a limit, only the
+    /// releases from the year of the latest one are kept.
+    pub max_releases: Option<usize>,
+    /// Archive file name relative to the changelog; `{year}` is replaced by
+    /// the year of each archived release.
+    pub archive: String,
+}
+
+impl ArchivePolicy {
+    pub const DEFAULT_ARCHIVE: &str = "CHANGELOG-{year}.md";
+
+    #[must_use]
+    pub fn archive_file(&self, year: i32) -> String {
+        self.archive.replace("{year}", &year.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -37,6 +174,10 @@ mod tests {
         assert_eq!(config.changelog, ChangelogLocation::Root);
         assert_eq!(config.comparison_links, ComparisonLinksSetting::Auto);
         assert!(config.comparison_links_template.is_none());
+        assert_eq!(config.entry_order, EntryOrder::Category);
+        assert_eq!(config.format, ChangelogFormat::Standard);
+        assert!(config.preamble.is_none());
+        assert!(!config.writes_json());
     }
 
     #[test]
@@ -45,6 +186,9 @@ mod tests {
             changelog = "per-package"
             comparison-links = "enabled"
             comparison-links-template = "https://example.com/{repository}/compare/{base}...{target}"
+            entry-order = "timestamp"
+            format = "keep-a-changelog"
+            output = ["markdown", "json"]
         "#;
 
         let config: ChangelogConfig = toml::from_str(toml).expect("should deserialize");
@@ -54,6 +198,9 @@ mod tests {
             config.comparison_links_template.as_deref(),
             Some("https://example.com/{repository}/compare/{base}...{target}")
         );
+        assert_eq!(config.entry_order, EntryOrder::Timestamp);
+        assert_eq!(config.format, ChangelogFormat::KeepAChangelog);
+        assert!(config.writes_json());
     }
 
     #[test]
@@ -1,13 +1,25 @@
+use std::time::SystemTime;
+
 use chrono::NaiveDate;
 use semver::Version;
 
-use changeset_core::ChangeCategory;
+use changeset_core::{CategorySet, ChangeCategory};
+
+use crate::config::{ChangelogFormat, EntryOrder};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChangelogEntry {
     pub category: ChangeCategory,
     pub description: String,
     pub package: Option<String>,
+    /// Component the change affects, rendered as a prefix of the description.
+    pub scope: Option<String>,
+    /// Security advisory id, rendered as a link after the description.
+    pub advisory: Option<String>,
+    /// Name of the changeset file the entry was generated from.
+    pub file_name: Option<String>,
+    /// When the changeset file was last modified.
+    pub timestamp: Option<SystemTime>,
 }
 
 impl ChangelogEntry {
@@ -17,6 +29,10 @@ impl ChangelogEntry {
             category,
             description: description.into(),
             package: None,
+            scope: None,
+            advisory: None,
+            file_name: None,
+            timestamp: None,
         }
     }
 
@@ -25,6 +41,30 @@ impl ChangelogEntry {
         self.package = Some(package.into());
         self
     }
+
+    #[must_use]
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_advisory(mut self, advisory: impl Into<String>) -> Self {
+        self.advisory = Some(advisory.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,6 +72,25 @@ pub struct VersionRelease {
     pub version: Version,
     pub date: NaiveDate,
     pub entries: Vec<ChangelogEntry>,
+    /// Categories that decide the section headings and their order.
+    pub categories: CategorySet,
+    /// Order of the entries within each section.
+    pub entry_order: EntryOrder,
+    /// Structure of the changelog the release is added to.
+    pub format: ChangelogFormat,
+    /// Header used when the release creates a new changelog file.
+    pub preamble: Option<String>,
+    /// Also add the release to the `CHANGELOG.json` next to the Markdown file.
+    pub json: bool,
+    /// Versions of the packages released together, for releases that cover
+    /// several packages in one changelog.
+    pub package_versions: Vec<(String, Version)>,
+    /// Prefix of the git tags releases in this changelog are tagged with, such
+    /// as `crate-a@`; comparison links point at `{tag_prefix}v{version}`.
+    pub tag_prefix: String,
+    /// Tag prefix of the previous version when it differs from `tag_prefix`,
+    /// such as a stable release followed by one tagged `nightly/crate-a@`.
+    pub previous_tag_prefix: Option<String>,
 }
 
 impl VersionRelease {
@@ -41,8 +100,80 @@ impl VersionRelease {
             version,
             date,
             entries,
+            categories: CategorySet::builtin(),
+            entry_order: EntryOrder::default(),
+            format: ChangelogFormat::default(),
+            preamble: None,
+            json: false,
+            package_versions: Vec::new(),
+            tag_prefix: String::new(),
+            previous_tag_prefix: None,
         }
     }
+
+    #[must_use]
+    pub fn with_categories(mut self, categories: CategorySet) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    #[must_use]
+    pub fn with_entry_order(mut self, entry_order: EntryOrder) -> Self {
+        self.entry_order = entry_order;
+        self
+    }
+
+    #[must_use]
+    pub fn with_format(mut self, format: ChangelogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub fn with_json(mut self, json: bool) -> Self {
+        self.json = json;
+        self
+    }
+
+    #[must_use]
+    pub fn with_package_versions(mut self, package_versions: Vec<(String, Version)>) -> Self {
+        self.package_versions = package_versions;
+        self
+    }
+
+    #[must_use]
+    pub fn with_preamble(mut self, preamble: impl Into<String>) -> Self {
+        self.preamble = Some(preamble.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_tag_prefix(mut self, tag_prefix: impl Into<String>) -> Self {
+        self.tag_prefix = tag_prefix.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_previous_tag_prefix(mut self, tag_prefix: impl Into<String>) -> Self {
+        self.previous_tag_prefix = Some(tag_prefix.into());
+        self
+    }
+
+    /// Git tag of `version` in the tag format of this changelog.
+    #[must_use]
+    pub fn tag(&self, version: impl std::fmt::Display) -> String {
+        format!("{}v{version}", self.tag_prefix)
+    }
+
+    /// Git tag of the previous version `version`.
+    #[must_use]
+    pub fn previous_tag(&self, version: impl std::fmt::Display) -> String {
+        let prefix = self
+            .previous_tag_prefix
+            .as_ref()
+            .unwrap_or(&self.tag_prefix);
+        format!("{prefix}v{version}")
+    }
 }
 
 #[cfg(test)]
@@ -31,6 +31,13 @@ pub enum ChangelogError {
     #[error("invalid repository path in URL '{url}': expected owner/repo format")]
     InvalidRepositoryPath { url: String },
 
+    #[error("invalid JSON changelog at '{path}'")]
+    InvalidJson {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
     #[error("invalid changelog format at '{path}': missing required header")]
     InvalidChangelogFormat { path: PathBuf },
 
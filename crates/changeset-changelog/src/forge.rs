@@ -17,6 +17,9 @@ pub struct RepositoryInfo {
     pub owner: String,
     pub repo: String,
     pub base_url: Url,
+    /// URL template used instead of the forge's compare URL; see
+    /// [`expand_comparison_template`].
+    pub comparison_template: Option<String>,
 }
 
 impl RepositoryInfo {
@@ -49,11 +52,22 @@ impl RepositoryInfo {
             owner,
             repo,
             base_url,
+            comparison_template: None,
         })
     }
 
+    #[must_use]
+    pub fn with_comparison_template(mut self, template: Option<String>) -> Self {
+        self.comparison_template = template;
+        self
+    }
+
     #[must_use]
     pub fn comparison_url(&self, base_tag: &str, target_tag: &str) -> String {
+        if let Some(template) = &self.comparison_template {
+            let repository = format!("{}/{}", self.owner, self.repo);
+            return expand_comparison_template(template, &repository, base_tag, target_tag);
+        }
         match self.forge {
             Forge::GitHub | Forge::Gitea => format!(
                 "{}{}/{}/compare/{}...{}",
@@ -223,6 +237,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn comparison_template_overrides_forge_url() {
+        let info = RepositoryInfo::from_url("https://github.com/owner/repo")
+            .expect("should parse")
+            .with_comparison_template(Some(
+                "https://mirror.example.com/{repository}/diff/{base}..{target}".to_string(),
+            ));
+        let url = info.comparison_url("crate-a@v1.0.0", "crate-a@v1.1.0");
+        assert_eq!(
+            url,
+            "https://mirror.example.com/owner/repo/diff/crate-a@v1.0.0..crate-a@v1.1.0"
+        );
+    }
+
     #[test]
     fn error_invalid_url() {
         let result = RepositoryInfo::from_url("not-a-valid-url");
@@ -1,10 +1,11 @@
-use std::collections::BTreeMap;
+use std::cmp::Ordering;
 use std::fmt::Write;
 
 use chrono::NaiveDate;
 use semver::Version;
 
-use changeset_core::ChangeCategory;
+use changeset_core::CategorySet;
+use indexmap::IndexMap;
 
 use crate::entry::{ChangelogEntry, VersionRelease};
 use crate::forge::RepositoryInfo;
@@ -22,51 +23,104 @@ pub fn new_changelog() -> String {
     CHANGELOG_HEADER.to_string()
 }
 
+/// Formats entries grouped under the section headings of `categories`.
+///
+/// Categories that share a section are merged under one heading. Entries whose
+/// category is not in the set follow the configured sections, headed by the
+/// category name.
 #[must_use]
-pub fn format_entries(entries: &[ChangelogEntry]) -> String {
+pub fn format_entries(entries: &[ChangelogEntry], categories: &CategorySet) -> String {
+    format_entries_by(entries, categories, |_, _| Ordering::Equal)
+}
+
+/// Like [`format_entries`], but orders the entries of each section with `compare`.
+///
+/// The sort is stable, so entries that compare equal keep their relative order.
+#[must_use]
+pub fn format_entries_by(
+    entries: &[ChangelogEntry],
+    categories: &CategorySet,
+    compare: impl Fn(&ChangelogEntry, &ChangelogEntry) -> Ordering,
+) -> String {
     if entries.is_empty() {
         return String::new();
     }
 
-    let mut by_category: BTreeMap<ChangeCategory, Vec<&ChangelogEntry>> = BTreeMap::new();
-
+    let mut sections: IndexMap<String, Vec<&ChangelogEntry>> = IndexMap::new();
+
+    for definition in categories.iter() {
+        let matching = entries
+            .iter()
+            .filter(|entry| entry.category == definition.category);
+        for entry in matching {
+            sections
+                .entry(definition.section.clone())
+                .or_default()
+                .push(entry);
+        }
+    }
     for entry in entries {
-        by_category.entry(entry.category).or_default().push(entry);
+        if !categories.contains(&entry.category) {
+            sections
+                .entry(entry.category.to_string())
+                .or_default()
+                .push(entry);
+        }
     }
 
     let mut output = String::new();
 
-    let category_order = [
-        ChangeCategory::Added,
-        ChangeCategory::Changed,
-        ChangeCategory::Deprecated,
-        ChangeCategory::Removed,
-        ChangeCategory::Fixed,
-        ChangeCategory::Security,
-    ];
-
-    for category in category_order {
-        if let Some(category_entries) = by_category.get(&category) {
-            output.push_str("\n### ");
-            output.push_str(&category.to_string());
-            output.push('\n');
-
-            for entry in category_entries {
-                output.push_str("\n- ");
-                if let Some(ref package) = entry.package {
-                    output.push_str("**");
-                    output.push_str(package);
-                    output.push_str("**: ");
-                }
-                output.push_str(&entry.description);
+    for (section, mut section_entries) in sections {
+        section_entries.sort_by(|a, b| compare(a, b));
+        output.push_str("\n### ");
+        output.push_str(&section);
+        output.push('\n');
+
+        for entry in section_entries {
+            output.push_str("\n- ");
+            if let Some(ref package) = entry.package {
+                output.push_str("**");
+                output.push_str(package);
+                output.push_str("**: ");
+            }
+            if let Some(ref scope) = entry.scope {
+                output.push_str(scope);
+                output.push_str(": ");
+            }
+            output.push_str(&entry.description);
+            if let Some(ref advisory) = entry.advisory {
+                output.push_str(" (");
+                output.push_str(&format_advisory(advisory));
+                output.push(')');
             }
-            output.push('\n');
         }
+        output.push('\n');
     }
 
     output
 }
 
+/// The page of a `RUSTSEC-`, `CVE-` or `GHSA-` advisory id, or `None` for other ids.
+#[must_use]
+pub fn advisory_url(advisory: &str) -> Option<String> {
+    if advisory.starts_with("RUSTSEC-") {
+        Some(format!("https://rustsec.org/advisories/{advisory}.html"))
+    } else if advisory.starts_with("CVE-") {
+        Some(format!("https://www.cve.org/CVERecord?id={advisory}"))
+    } else if advisory.starts_with("GHSA-") {
+        Some(format!("https://github.com/advisories/{advisory}"))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn format_advisory(advisory: &str) -> String {
+    advisory_url(advisory).map_or_else(
+        || advisory.to_string(),
+        |url| format!("[{advisory}]({url})"),
+    )
+}
+
 #[must_use]
 pub fn format_version_header(version: &Version, date: NaiveDate) -> String {
     format!("## [{version}] - {date}")
@@ -75,7 +129,11 @@ pub fn format_version_header(version: &Version, date: NaiveDate) -> String {
 #[must_use]
 pub fn format_version_release(release: &VersionRelease) -> String {
     let mut output = format_version_header(&release.version, release.date);
-    output.push_str(&format_entries(&release.entries));
+    output.push_str(&format_entries_by(
+        &release.entries,
+        &release.categories,
+        |a, b| release.entry_order.compare(&release.categories, a, b),
+    ));
     output
 }
 
@@ -98,7 +156,12 @@ pub fn format_comparison_links(
 
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use changeset_core::{CategoryDefinition, ChangeCategory};
+
     use super::*;
+    use crate::config::EntryOrder;
 
     #[test]
     fn new_changelog_has_proper_header() {
@@ -111,14 +174,14 @@ mod tests {
     #[test]
     fn format_empty_entries() {
         let entries: Vec<ChangelogEntry> = vec![];
-        let formatted = format_entries(&entries);
+        let formatted = format_entries(&entries, &CategorySet::builtin());
         assert!(formatted.is_empty());
     }
 
     #[test]
     fn format_single_entry() {
         let entries = vec![ChangelogEntry::new(ChangeCategory::Added, "New feature")];
-        let formatted = format_entries(&entries);
+        let formatted = format_entries(&entries, &CategorySet::builtin());
         assert!(formatted.contains("### Added"));
         assert!(formatted.contains("- New feature"));
     }
@@ -130,7 +193,7 @@ mod tests {
             ChangelogEntry::new(ChangeCategory::Added, "New feature"),
             ChangelogEntry::new(ChangeCategory::Fixed, "Another bug fix"),
         ];
-        let formatted = format_entries(&entries);
+        let formatted = format_entries(&entries, &CategorySet::builtin());
 
         assert!(formatted.contains("### Added"));
         assert!(formatted.contains("### Fixed"));
@@ -147,10 +210,42 @@ mod tests {
     fn format_entry_with_package() {
         let entries =
             vec![ChangelogEntry::new(ChangeCategory::Changed, "Updated API").with_package("core")];
-        let formatted = format_entries(&entries);
+        let formatted = format_entries(&entries, &CategorySet::builtin());
         assert!(formatted.contains("- **core**: Updated API"));
     }
 
+    #[test]
+    fn format_entry_with_scope() {
+        let entries = vec![
+            ChangelogEntry::new(ChangeCategory::Fixed, "Handle empty input")
+                .with_package("core")
+                .with_scope("parser"),
+            ChangelogEntry::new(ChangeCategory::Fixed, "Quote paths").with_scope("cli"),
+        ];
+        let formatted = format_entries(&entries, &CategorySet::builtin());
+        assert!(formatted.contains("- **core**: parser: Handle empty input"));
+        assert!(formatted.contains("- cli: Quote paths"));
+    }
+
+    #[test]
+    fn format_entry_with_advisory() {
+        let entries = vec![
+            ChangelogEntry::new(ChangeCategory::Security, "Fix overflow")
+                .with_advisory("RUSTSEC-2024-0001"),
+            ChangelogEntry::new(ChangeCategory::Security, "Escape input")
+                .with_advisory("CVE-2024-12345"),
+            ChangelogEntry::new(ChangeCategory::Security, "Reject paths").with_advisory("OSV-1"),
+        ];
+        let formatted = format_entries(&entries, &CategorySet::builtin());
+        assert!(formatted.contains(
+            "- Fix overflow ([RUSTSEC-2024-0001](https://rustsec.org/advisories/RUSTSEC-2024-0001.html))"
+        ));
+        assert!(formatted.contains(
+            "- Escape input ([CVE-2024-12345](https://www.cve.org/CVERecord?id=CVE-2024-12345))"
+        ));
+        assert!(formatted.contains("- Reject paths (OSV-1)"));
+    }
+
     #[test]
     fn format_version_header_correct() {
         let version = Version::new(1, 2, 3);
@@ -186,7 +281,7 @@ mod tests {
             ChangelogEntry::new(ChangeCategory::Added, "New feature"),
         ];
 
-        let formatted = format_entries(&entries);
+        let formatted = format_entries(&entries, &CategorySet::builtin());
 
         let positions: Vec<usize> = [
             "### Added",
@@ -207,4 +302,130 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn configured_categories_drive_sections() {
+        let categories = CategorySet::new(vec![
+            CategoryDefinition {
+                category: ChangeCategory::from_id("perf"),
+                label: "Performance improvements".to_string(),
+                section: "Performance".to_string(),
+                order: 10,
+            },
+            CategoryDefinition {
+                category: ChangeCategory::Fixed,
+                label: "Bug fixes".to_string(),
+                section: "Fixes".to_string(),
+                order: 20,
+            },
+            CategoryDefinition {
+                category: ChangeCategory::Security,
+                label: "Security fixes".to_string(),
+                section: "Fixes".to_string(),
+                order: 30,
+            },
+        ]);
+        let entries = vec![
+            ChangelogEntry::new(ChangeCategory::Security, "Patch CVE"),
+            ChangelogEntry::new(ChangeCategory::Added, "Legacy entry"),
+            ChangelogEntry::new(ChangeCategory::Fixed, "Fix crash"),
+            ChangelogEntry::new(ChangeCategory::from_id("perf"), "Faster parsing"),
+        ];
+
+        let formatted = format_entries(&entries, &categories);
+
+        assert_eq!(
+            formatted,
+            "\n### Performance\n\n- Faster parsing\n\
+             \n### Fixes\n\n- Fix crash\n- Patch CVE\n\
+             \n### Added\n\n- Legacy entry\n"
+        );
+    }
+
+    fn ordered_entries() -> Vec<ChangelogEntry> {
+        let at = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        vec![
+            ChangelogEntry::new(ChangeCategory::Fixed, "Fix in core")
+                .with_package("core")
+                .with_file_name("b.md")
+                .with_timestamp(at(10)),
+            ChangelogEntry::new(ChangeCategory::Fixed, "Fix in cli")
+                .with_package("cli")
+                .with_file_name("c.md")
+                .with_timestamp(at(30)),
+            ChangelogEntry::new(ChangeCategory::Fixed, "Other fix in core")
+                .with_package("core")
+                .with_file_name("a.md")
+                .with_timestamp(at(20)),
+        ]
+    }
+
+    fn descriptions(formatted: &str) -> Vec<&str> {
+        formatted
+            .lines()
+            .filter_map(|line| line.strip_prefix("- "))
+            .collect()
+    }
+
+    #[test]
+    fn format_entries_preserves_given_order() {
+        let formatted = format_entries(&ordered_entries(), &CategorySet::builtin());
+
+        assert_eq!(
+            descriptions(&formatted),
+            vec![
+                "**core**: Fix in core",
+                "**cli**: Fix in cli",
+                "**core**: Other fix in core"
+            ]
+        );
+    }
+
+    #[test]
+    fn format_entries_by_package_order() {
+        let categories = CategorySet::builtin();
+        let formatted = format_entries_by(&ordered_entries(), &categories, |a, b| {
+            EntryOrder::Package.compare(&categories, a, b)
+        });
+
+        assert_eq!(
+            descriptions(&formatted),
+            vec![
+                "**cli**: Fix in cli",
+                "**core**: Other fix in core",
+                "**core**: Fix in core"
+            ]
+        );
+    }
+
+    #[test]
+    fn format_entries_by_timestamp_order() {
+        let categories = CategorySet::builtin();
+        let formatted = format_entries_by(&ordered_entries(), &categories, |a, b| {
+            EntryOrder::Timestamp.compare(&categories, a, b)
+        });
+
+        assert_eq!(
+            descriptions(&formatted),
+            vec![
+                "**core**: Fix in core",
+                "**core**: Other fix in core",
+                "**cli**: Fix in cli"
+            ]
+        );
+    }
+
+    #[test]
+    fn format_version_release_applies_entry_order() {
+        let release = VersionRelease::new(
+            Version::new(1, 0, 0),
+            NaiveDate::from_ymd_opt(2025, 1, 15).expect("valid date"),
+            ordered_entries(),
+        )
+        .with_entry_order(EntryOrder::File);
+
+        let formatted = format_version_release(&release);
+
+        assert_eq!(descriptions(&formatted)[0], "**core**: Fix in core");
+    }
 }
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use changeset_core::atomic_write;
+use serde::{Deserialize, Serialize};
+
+use crate::entry::{ChangelogEntry, VersionRelease};
+use crate::error::ChangelogError;
+
+/// The JSON changelog kept next to `markdown_path`, e.g. `CHANGELOG.json`
+/// for `CHANGELOG.md`.
+#[must_use]
+pub fn json_changelog_path(markdown_path: &Path) -> PathBuf {
+    markdown_path.with_extension("json")
+}
+
+/// Machine-readable counterpart of a Markdown changelog, for docs sites and
+/// dashboards that read the releases without parsing Markdown.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonChangelog {
+    /// Releases, newest first.
+    pub releases: Vec<JsonRelease>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonRelease {
+    pub version: String,
+    /// Release date as `YYYY-MM-DD`.
+    pub date: String,
+    /// Version of each package released, for a changelog shared by several
+    /// packages.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub packages: BTreeMap<String, String>,
+    pub entries: Vec<JsonEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonEntry {
+    /// Category id, e.g. `fixed`.
+    pub category: String,
+    /// Section heading the entry is listed under in the Markdown changelog.
+    pub section: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advisory: Option<String>,
+}
+
+impl JsonChangelog {
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::Read` if the file cannot be read, or
+    /// `ChangelogError::InvalidJson` if it is not a JSON changelog.
+    pub fn from_file(path: &Path) -> Result<Self, ChangelogError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ChangelogError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&content).map_err(|source| ChangelogError::InvalidJson {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if `content` is not a JSON changelog.
+    pub fn parse(content: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(content)
+    }
+
+    /// Adds `release` as the newest release.
+    pub fn add_release(&mut self, release: &VersionRelease) {
+        self.releases.insert(
+            0,
+            JsonRelease {
+                version: release.version.to_string(),
+                date: release.date.to_string(),
+                packages: release
+                    .package_versions
+                    .iter()
+                    .map(|(name, version)| (name.clone(), version.to_string()))
+                    .collect(),
+                entries: json_entries(release),
+            },
+        );
+    }
+
+    /// Adds the entries of `release` to the existing release of its version.
+    ///
+    /// Returns `false` and leaves the changelog unchanged when there is no
+    /// release for the version.
+    pub fn extend_release(&mut self, release: &VersionRelease) -> bool {
+        let version = release.version.to_string();
+        let Some(existing) = self
+            .releases
+            .iter_mut()
+            .find(|existing| existing.version == version)
+        else {
+            return false;
+        };
+        existing.entries.extend(json_entries(release));
+        true
+    }
+
+    /// Pretty-printed file content with a trailing newline.
+    ///
+    /// # Panics
+    ///
+    /// Never; every field serializes to JSON.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut content = serde_json::to_string_pretty(self).expect("changelog serializes to JSON");
+        content.push('\n');
+        content
+    }
+
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::Write` if the file cannot be written.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), ChangelogError> {
+        atomic_write(path, self.render()).map_err(|source| ChangelogError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// Entries of `release` in the order the Markdown changelog lists them.
+fn json_entries(release: &VersionRelease) -> Vec<JsonEntry> {
+    let mut entries: Vec<&ChangelogEntry> = release.entries.iter().collect();
+    entries.sort_by_key(|entry| {
+        release
+            .categories
+            .iter()
+            .position(|definition| definition.category == entry.category)
+            .unwrap_or(usize::MAX)
+    });
+    entries
+        .into_iter()
+        .map(|entry| JsonEntry {
+            category: entry.category.id().to_string(),
+            section: release.categories.get(&entry.category).map_or_else(
+                || entry.category.to_string(),
+                |definition| definition.section.clone(),
+            ),
+            description: entry.description.clone(),
+            package: entry.package.clone(),
+            scope: entry.scope.clone(),
+            advisory: entry.advisory.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use semver::Version;
+
+    use changeset_core::ChangeCategory;
+
+    use super::*;
+
+    fn release(version: Version, entries: Vec<ChangelogEntry>) -> VersionRelease {
+        VersionRelease::new(
+            version,
+            NaiveDate::from_ymd_opt(2025, 1, 15).expect("valid date"),
+            entries,
+        )
+    }
+
+    #[test]
+    fn releases_are_added_newest_first_and_round_trip() {
+        let mut changelog = JsonChangelog::default();
+        changelog.add_release(&release(
+            Version::new(1, 0, 0),
+            vec![ChangelogEntry::new(
+                ChangeCategory::Added,
+                "Initial release",
+            )],
+        ));
+        changelog.add_release(&release(
+            Version::new(1, 0, 1),
+            vec![
+                ChangelogEntry::new(ChangeCategory::Security, "Fix overflow")
+                    .with_advisory("RUSTSEC-2024-0001"),
+                ChangelogEntry::new(ChangeCategory::Fixed, "Handle empty input")
+                    .with_package("core")
+                    .with_scope("parser"),
+            ],
+        ));
+
+        let rendered = changelog.render();
+
+        assert_eq!(changelog.releases[0].version, "1.0.1");
+        assert_eq!(changelog.releases[0].date, "2025-01-15");
+        assert_eq!(changelog.releases[0].entries[0].category, "fixed");
+        assert_eq!(changelog.releases[0].entries[1].section, "Security");
+        assert!(rendered.contains(r#""advisory": "RUSTSEC-2024-0001""#));
+        assert!(!rendered.contains(r#""scope": null"#));
+        assert_eq!(
+            JsonChangelog::parse(&rendered).expect("should parse"),
+            changelog
+        );
+    }
+
+    #[test]
+    fn extend_release_appends_to_existing_version() {
+        let mut changelog = JsonChangelog::default();
+        changelog.add_release(&release(
+            Version::new(1, 0, 0),
+            vec![ChangelogEntry::new(
+                ChangeCategory::Added,
+                "Initial release",
+            )],
+        ));
+
+        let late = release(
+            Version::new(1, 0, 0),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Late fix")],
+        );
+        assert!(changelog.extend_release(&late));
+        assert_eq!(changelog.releases[0].entries.len(), 2);
+
+        let missing = release(Version::new(2, 0, 0), Vec::new());
+        assert!(!changelog.extend_release(&missing));
+    }
+
+    #[test]
+    fn package_versions_are_listed_only_when_present() {
+        let mut changelog = JsonChangelog::default();
+        changelog.add_release(&release(Version::new(1, 0, 0), Vec::new()));
+        assert!(!changelog.render().contains("packages"));
+
+        changelog.add_release(
+            &release(Version::new(2, 0, 0), Vec::new()).with_package_versions(vec![
+                ("crate-b".to_string(), Version::new(2, 0, 0)),
+                ("crate-a".to_string(), Version::new(1, 4, 0)),
+            ]),
+        );
+
+        let packages = &changelog.releases[0].packages;
+        assert_eq!(packages["crate-a"], "1.4.0");
+        assert_eq!(packages["crate-b"], "2.0.0");
+    }
+
+    #[test]
+    fn json_path_sits_next_to_markdown() {
+        assert_eq!(
+            json_changelog_path(Path::new("crates/a/CHANGELOG.md")),
+            Path::new("crates/a/CHANGELOG.json")
+        );
+    }
+}
@@ -4,15 +4,26 @@ mod entry;
 mod error;
 mod forge;
 mod format;
+mod json;
+mod pages;
+mod stream;
+mod validate;
 
-pub use changelog::Changelog;
-pub use config::{ChangelogConfig, ChangelogLocation, ComparisonLinksSetting};
+pub use changelog::{ArchivedReleases, Changelog};
+pub use config::{
+    ArchivePolicy, ChangelogConfig, ChangelogFormat, ChangelogLocation, ChangelogOutput,
+    ComparisonLinksSetting, EntryOrder,
+};
 pub use entry::{ChangelogEntry, VersionRelease};
 pub use error::ChangelogError;
 pub use forge::{Forge, RepositoryInfo, expand_comparison_template};
 pub use format::{
-    format_comparison_links, format_entries, format_version_header, format_version_release,
-    new_changelog,
+    advisory_url, format_comparison_links, format_entries, format_entries_by,
+    format_version_header, format_version_release, new_changelog,
 };
+pub use json::{JsonChangelog, JsonEntry, JsonRelease, json_changelog_path};
+pub use pages::{ChangelogPage, PageFrontMatter};
+pub use stream::StreamedChangelog;
+pub use validate::{FormatViolation, validate_keep_a_changelog};
 
 pub type Result<T> = std::result::Result<T, ChangelogError>;
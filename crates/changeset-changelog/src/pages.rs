@@ -0,0 +1,180 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+
+use crate::format::format_advisory;
+use crate::json::JsonEntry;
+
+/// Front matter at the top of a changelog page, for the static site
+/// generator that builds the docs site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageFrontMatter {
+    /// `---` delimited YAML, read by Jekyll, Hugo and most other generators.
+    #[default]
+    Yaml,
+    /// `+++` delimited TOML, as Zola expects, with the package and version
+    /// under `[extra]`.
+    Toml,
+    /// No front matter; the page starts with a title heading, as mdBook
+    /// renders pages verbatim.
+    None,
+}
+
+/// The changes of one package in one release, rendered as its own page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogPage {
+    pub package: String,
+    pub version: String,
+    /// Release date as `YYYY-MM-DD`.
+    pub date: String,
+    /// Entries in changelog order, without a package label.
+    pub entries: Vec<JsonEntry>,
+}
+
+impl ChangelogPage {
+    /// Location of the page below the docs directory: `<package>/<version>.md`.
+    #[must_use]
+    pub fn relative_path(&self) -> PathBuf {
+        PathBuf::from(&self.package).join(format!("{}.md", self.version))
+    }
+
+    #[must_use]
+    pub fn render(&self, front_matter: PageFrontMatter) -> String {
+        let title = format!("{} {}", self.package, self.version);
+        let mut output = String::new();
+
+        match front_matter {
+            PageFrontMatter::Yaml => {
+                let _ = write!(
+                    output,
+                    "---\ntitle: {}\ndate: {}\npackage: {}\nversion: {}\n---\n",
+                    quote(&title),
+                    self.date,
+                    quote(&self.package),
+                    quote(&self.version),
+                );
+            }
+            PageFrontMatter::Toml => {
+                let _ = write!(
+                    output,
+                    "+++\ntitle = {}\ndate = {}\n\n[extra]\npackage = {}\nversion = {}\n+++\n",
+                    quote(&title),
+                    self.date,
+                    quote(&self.package),
+                    quote(&self.version),
+                );
+            }
+            PageFrontMatter::None => {
+                let _ = write!(output, "# {title}\n\nReleased on {}.\n", self.date);
+            }
+        }
+
+        let mut sections: IndexMap<&str, Vec<&JsonEntry>> = IndexMap::new();
+        for entry in &self.entries {
+            sections.entry(&entry.section).or_default().push(entry);
+        }
+
+        for (section, entries) in sections {
+            let _ = write!(output, "\n## {section}\n\n");
+            for entry in entries {
+                output.push_str("- ");
+                if let Some(ref scope) = entry.scope {
+                    output.push_str(scope);
+                    output.push_str(": ");
+                }
+                output.push_str(&entry.description);
+                if let Some(ref advisory) = entry.advisory {
+                    let _ = write!(output, " ({})", format_advisory(advisory));
+                }
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+}
+
+/// A double-quoted string, valid in both YAML and TOML.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(category: &str, section: &str, description: &str) -> JsonEntry {
+        JsonEntry {
+            category: category.to_string(),
+            section: section.to_string(),
+            description: description.to_string(),
+            package: None,
+            scope: None,
+            advisory: None,
+        }
+    }
+
+    fn page() -> ChangelogPage {
+        ChangelogPage {
+            package: "crate-a".to_string(),
+            version: "1.2.0".to_string(),
+            date: "2025-01-15".to_string(),
+            entries: vec![
+                entry("added", "Added", "New API"),
+                JsonEntry {
+                    scope: Some("parser".to_string()),
+                    ..entry("fixed", "Fixed", "Handle empty input")
+                },
+                JsonEntry {
+                    advisory: Some("RUSTSEC-2024-0001".to_string()),
+                    ..entry("security", "Security", "Fix overflow")
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn page_lives_under_package_directory() {
+        assert_eq!(
+            page().relative_path(),
+            PathBuf::from("crate-a").join("1.2.0.md")
+        );
+    }
+
+    #[test]
+    fn yaml_page_groups_entries_by_section() {
+        let rendered = page().render(PageFrontMatter::Yaml);
+
+        assert_eq!(
+            rendered,
+            "---\n\
+             title: \"crate-a 1.2.0\"\n\
+             date: 2025-01-15\n\
+             package: \"crate-a\"\n\
+             version: \"1.2.0\"\n\
+             ---\n\
+             \n## Added\n\n- New API\n\
+             \n## Fixed\n\n- parser: Handle empty input\n\
+             \n## Security\n\n\
+             - Fix overflow ([RUSTSEC-2024-0001](https://rustsec.org/advisories/RUSTSEC-2024-0001.html))\n"
+        );
+    }
+
+    #[test]
+    fn toml_page_keeps_package_under_extra() {
+        let rendered = page().render(PageFrontMatter::Toml);
+
+        assert!(rendered.starts_with(
+            "+++\ntitle = \"crate-a 1.2.0\"\ndate = 2025-01-15\n\n[extra]\npackage = \"crate-a\"\n"
+        ));
+        assert!(rendered.contains("version = \"1.2.0\"\n+++\n"));
+    }
+
+    #[test]
+    fn page_without_front_matter_starts_with_title() {
+        let rendered = page().render(PageFrontMatter::None);
+
+        assert!(rendered.starts_with("# crate-a 1.2.0\n\nReleased on 2025-01-15.\n\n## Added\n"));
+    }
+}
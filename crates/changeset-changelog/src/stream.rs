@@ -0,0 +1,293 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use changeset_core::AtomicWriteOptions;
+
+use crate::changelog::{Changelog, UNRELEASED_HEADING, is_fence, starts_footer};
+use crate::entry::VersionRelease;
+use crate::error::ChangelogError;
+use crate::forge::RepositoryInfo;
+
+/// Stands in for the release sections between the head and the footer.
+const PLACEHOLDER: &str = "\u{1}unchanged release sections\u{1}\n";
+
+/// A changelog file that new releases are added to without reading its
+/// release history into memory.
+///
+/// Only the head, up to and including the heading of the latest release, and
+/// the footer after the last section are loaded. New sections and comparison
+/// links only ever go there, so the sections in between are copied from the
+/// old file to the new one as they are.
+#[derive(Debug)]
+pub struct StreamedChangelog {
+    path: PathBuf,
+    /// Head and footer around [`PLACEHOLDER`].
+    window: Changelog,
+    original: Changelog,
+    /// Byte range of the copied sections in the file as it was read.
+    sections: Range<u64>,
+}
+
+impl StreamedChangelog {
+    /// Returns `None` when the file has no release section to stream past; it
+    /// is then fully read already and small enough for [`Changelog`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::Read` if the file cannot be read or its head
+    /// or footer is not valid UTF-8.
+    pub fn open(path: &Path) -> Result<Option<Self>, ChangelogError> {
+        let read_error = |source| ChangelogError::Read {
+            path: path.to_path_buf(),
+            source,
+        };
+        let mut reader = BufReader::new(File::open(path).map_err(read_error)?);
+
+        let Some(head) = read_head(&mut reader).map_err(read_error)? else {
+            return Ok(None);
+        };
+        let sections_start = head.len() as u64;
+        let footer_start = find_footer(&mut reader, sections_start).map_err(read_error)?;
+
+        let mut footer = String::new();
+        reader
+            .seek(SeekFrom::Start(footer_start))
+            .and_then(|_| reader.read_to_string(&mut footer))
+            .map_err(read_error)?;
+
+        let window = Changelog::parse(&format!("{head}{PLACEHOLDER}{footer}"));
+        Ok(Some(Self {
+            path: path.to_path_buf(),
+            original: window.clone(),
+            window,
+            sections: sections_start..footer_start,
+        }))
+    }
+
+    /// Adds `release` like [`Changelog::add_release`].
+    pub fn add_release(
+        &mut self,
+        release: &VersionRelease,
+        repo_info: Option<&RepositoryInfo>,
+        previous_version: Option<&str>,
+    ) {
+        self.window
+            .add_release(release, repo_info, previous_version);
+    }
+
+    /// Writes the new head and footer around the sections of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::Write` if the file cannot be written.
+    pub fn write(&self) -> Result<(), ChangelogError> {
+        self.rewrite(self.sections.clone(), &self.window)
+    }
+
+    /// Puts back the head and footer the file had when it was opened, after
+    /// [`Self::write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::Write` if the file cannot be written.
+    pub fn restore(&self) -> Result<(), ChangelogError> {
+        let (head, _) = self.split(&self.window)?;
+        let start = head.len() as u64;
+        let end = start + (self.sections.end - self.sections.start);
+        self.rewrite(start..end, &self.original)
+    }
+
+    /// Replaces the file with `window`, filling the placeholder with the
+    /// bytes of the file in `sections`.
+    fn rewrite(&self, sections: Range<u64>, window: &Changelog) -> Result<(), ChangelogError> {
+        let (head, footer) = self.split(window)?;
+        AtomicWriteOptions::new()
+            .write_with(&self.path, |file| {
+                file.write_all(head.as_bytes())?;
+                let mut source = File::open(&self.path)?;
+                source.seek(SeekFrom::Start(sections.start))?;
+                let length = sections.end - sections.start;
+                if io::copy(&mut source.take(length), file)? != length {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+                }
+                file.write_all(footer.as_bytes())
+            })
+            .map_err(|source| ChangelogError::Write {
+                path: self.path.clone(),
+                source,
+            })
+    }
+
+    /// Head and footer of `window` with its line endings.
+    fn split(&self, window: &Changelog) -> Result<(String, String), ChangelogError> {
+        let rendered = window.render();
+        let placeholder = window.line_ending().apply(PLACEHOLDER);
+        let (head, footer) =
+            rendered
+                .split_once(&placeholder)
+                .ok_or_else(|| ChangelogError::Write {
+                    path: self.path.clone(),
+                    source: io::Error::other("lost track of the release sections"),
+                })?;
+        Ok((head.to_string(), footer.to_string()))
+    }
+}
+
+/// Reads up to and including the heading of the first release, the place new
+/// releases are inserted at. `None` when there is no such heading.
+fn read_head(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut head = String::new();
+    let mut in_fence = false;
+    loop {
+        let start = head.len();
+        if reader.read_line(&mut head)? == 0 {
+            return Ok(None);
+        }
+        let text = head[start..].trim_end();
+        if is_fence(text) {
+            in_fence = !in_fence;
+        } else if !in_fence && text.starts_with("## [") && !text.starts_with(UNRELEASED_HEADING) {
+            return Ok(head.ends_with('\n').then_some(head));
+        }
+    }
+}
+
+/// Offset of the first footer line after the last section heading, or of
+/// the end of the file when there is no footer.
+fn find_footer(reader: &mut impl BufRead, mut position: u64) -> io::Result<u64> {
+    let mut footer_start = None;
+    let mut in_fence = false;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let length = reader.read_until(b'\n', &mut line)?;
+        if length == 0 {
+            return Ok(footer_start.unwrap_or(position));
+        }
+        let text = String::from_utf8_lossy(&line);
+        let text = text.trim_end();
+        if is_fence(text) {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            if text.starts_with("## [") {
+                footer_start = None;
+            } else if footer_start.is_none() && starts_footer(text) {
+                footer_start = Some(position);
+            }
+        }
+        position += length as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ChangelogFormat;
+    use crate::entry::ChangelogEntry;
+    use changeset_core::ChangeCategory;
+    use chrono::NaiveDate;
+    use std::fs;
+
+    fn release(version: &str, format: ChangelogFormat) -> VersionRelease {
+        VersionRelease {
+            format,
+            ..VersionRelease::new(
+                version.parse().expect("valid version"),
+                NaiveDate::from_ymd_opt(2026, 3, 1).expect("valid date"),
+                vec![ChangelogEntry::new(
+                    ChangeCategory::Fixed,
+                    format!("Change in {version}"),
+                )],
+            )
+        }
+    }
+
+    fn repo() -> RepositoryInfo {
+        RepositoryInfo::from_url("https://github.com/owner/repo").expect("valid url")
+    }
+
+    /// Asserts that streaming `release` into `content` gives the same file as
+    /// rewriting it in memory.
+    fn assert_same_as_full_rewrite(content: &str, release: &VersionRelease, linked: bool) {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("CHANGELOG.md");
+        fs::write(&path, content).expect("seed");
+        let repo = repo();
+        let repo_info = linked.then_some(&repo);
+
+        let mut expected = Changelog::parse(content);
+        expected.add_release(release, repo_info, Some("1.1.0"));
+
+        let mut streamed = StreamedChangelog::open(&path)
+            .expect("open")
+            .expect("changelog has a release");
+        streamed.add_release(release, repo_info, Some("1.1.0"));
+        streamed.write().expect("write");
+
+        assert_eq!(fs::read_to_string(&path).expect("read"), expected.render());
+    }
+
+    const HISTORY: &str = "# Changelog\n\nIntro.\n\n## [1.1.0] - 2026-02-01\n\n### Fixed\n\n- Two\n\n```md\n## [0.0.1]\n[x]: y\n```\n\n## [1.0.0] - 2026-01-01\n\n### Added\n\n- One\n";
+
+    #[test]
+    fn matches_full_rewrite_without_links() {
+        assert_same_as_full_rewrite(HISTORY, &release("1.2.0", ChangelogFormat::Standard), false);
+    }
+
+    #[test]
+    fn matches_full_rewrite_with_footer_links() {
+        let content =
+            format!("{HISTORY}\n[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0\n");
+        assert_same_as_full_rewrite(&content, &release("1.2.0", ChangelogFormat::Standard), true);
+    }
+
+    #[test]
+    fn matches_full_rewrite_for_keep_a_changelog() {
+        let content = HISTORY.replace("Intro.\n\n", "Intro.\n\n## [Unreleased]\n\n")
+            + "\n[Unreleased]: https://github.com/owner/repo/compare/v1.1.0...HEAD\n"
+            + "[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0\n";
+        assert_same_as_full_rewrite(
+            &content,
+            &release("1.2.0", ChangelogFormat::KeepAChangelog),
+            true,
+        );
+    }
+
+    #[test]
+    fn matches_full_rewrite_with_crlf() {
+        let content = HISTORY.replace('\n', "\r\n");
+        assert_same_as_full_rewrite(&content, &release("1.2.0", ChangelogFormat::Standard), true);
+    }
+
+    #[test]
+    fn changelog_without_releases_is_not_streamed() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("CHANGELOG.md");
+        fs::write(&path, "# Changelog\n\n## [Unreleased]\n").expect("seed");
+
+        assert!(StreamedChangelog::open(&path).expect("open").is_none());
+    }
+
+    #[test]
+    fn restore_puts_back_the_original_file() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("CHANGELOG.md");
+        fs::write(&path, HISTORY).expect("seed");
+
+        let mut streamed = StreamedChangelog::open(&path)
+            .expect("open")
+            .expect("changelog has a release");
+        let repo = repo();
+        streamed.add_release(
+            &release("1.2.0", ChangelogFormat::Standard),
+            Some(&repo),
+            Some("1.1.0"),
+        );
+        streamed.write().expect("write");
+        streamed.restore().expect("restore");
+
+        assert_eq!(fs::read_to_string(&path).expect("read"), HISTORY);
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use chrono::NaiveDate;
+use semver::Version;
+
+use crate::changelog::{UNRELEASED_HEADING, version_link_label};
+
+/// Section headings allowed within a release by Keep a Changelog.
+const CHANGE_TYPES: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+/// A place where a changelog departs from the Keep a Changelog structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatViolation {
+    /// 1-based line number the violation was found on.
+    pub line: usize,
+    pub message: String,
+}
+
+impl FormatViolation {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FormatViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Checks `content` against the Keep a Changelog 1.1.0 structure.
+///
+/// Requires the `# Changelog` title, a preamble referencing Keep a Changelog
+/// and Semantic Versioning, an `Unreleased` section before any release,
+/// release headings of the form `## [1.2.0] - 2025-01-31` in descending
+/// version order, the standard change types as subsections, and a link
+/// definition for every section. Returns an empty list for a compliant file.
+#[must_use]
+pub fn validate_keep_a_changelog(content: &str) -> Vec<FormatViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<(usize, &str)> = content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim_end()))
+        .collect();
+
+    if lines.first().map(|(_, line)| *line) != Some("# Changelog") {
+        violations.push(FormatViolation::new(
+            1,
+            "expected '# Changelog' as the first line",
+        ));
+    }
+
+    check_preamble(&lines, &mut violations);
+
+    let mut sections: Vec<(usize, String)> = Vec::new();
+    let mut defined_links: Vec<(usize, String)> = Vec::new();
+    let mut previous_version: Option<Version> = None;
+
+    for &(number, line) in &lines {
+        if let Some(label) = version_link_label(line) {
+            defined_links.push((number, label.to_string()));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            if sections.is_empty() && line != UNRELEASED_HEADING {
+                violations.push(FormatViolation::new(
+                    number,
+                    format!("expected '{UNRELEASED_HEADING}' as the first section"),
+                ));
+            }
+            if line == UNRELEASED_HEADING {
+                if !sections.is_empty() {
+                    violations.push(FormatViolation::new(
+                        number,
+                        "'Unreleased' must be the first section and appear only once",
+                    ));
+                }
+                sections.push((number, "Unreleased".to_string()));
+                continue;
+            }
+            match parse_release_heading(heading) {
+                Some((version, _)) => {
+                    if let Some(previous) = &previous_version
+                        && version >= *previous
+                    {
+                        violations.push(FormatViolation::new(
+                            number,
+                            format!("version {version} is not lower than the preceding {previous}"),
+                        ));
+                    }
+                    sections.push((number, version.to_string()));
+                    previous_version = Some(version);
+                }
+                None => violations.push(FormatViolation::new(
+                    number,
+                    format!("expected '## [<version>] - <YYYY-MM-DD>', found '{line}'"),
+                )),
+            }
+        } else if let Some(change_type) = line.strip_prefix("### ") {
+            if !CHANGE_TYPES.contains(&change_type) {
+                violations.push(FormatViolation::new(
+                    number,
+                    format!(
+                        "unknown change type '{change_type}' (expected one of: {})",
+                        CHANGE_TYPES.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    if sections.is_empty() {
+        violations.push(FormatViolation::new(
+            lines.len().max(1),
+            format!("missing '{UNRELEASED_HEADING}' section"),
+        ));
+    }
+
+    check_link_definitions(&sections, &defined_links, &mut violations);
+
+    violations.sort_by_key(|violation| violation.line);
+    violations
+}
+
+/// Checks that the text before the first section links to both specifications.
+fn check_preamble(lines: &[(usize, &str)], violations: &mut Vec<FormatViolation>) {
+    let first_section = lines
+        .iter()
+        .position(|(_, line)| line.starts_with("## "))
+        .unwrap_or(lines.len());
+    let preamble: Vec<&str> = lines[..first_section].iter().map(|(_, l)| *l).collect();
+    let preamble = preamble.join("\n");
+    for (name, url) in [
+        ("Keep a Changelog", "https://keepachangelog.com"),
+        ("Semantic Versioning", "https://semver.org"),
+    ] {
+        if !preamble.contains(&format!("[{name}]({url}")) {
+            violations.push(FormatViolation::new(
+                1,
+                format!("preamble does not link to {name} ({url})"),
+            ));
+        }
+    }
+}
+
+/// Checks that every section has a link definition and vice versa.
+fn check_link_definitions(
+    sections: &[(usize, String)],
+    defined_links: &[(usize, String)],
+    violations: &mut Vec<FormatViolation>,
+) {
+    let defined: HashSet<&str> = defined_links.iter().map(|(_, l)| l.as_str()).collect();
+    let headed: HashSet<&str> = sections.iter().map(|(_, l)| l.as_str()).collect();
+    for (number, label) in sections {
+        if !defined.contains(label.as_str()) {
+            violations.push(FormatViolation::new(
+                *number,
+                format!("missing link definition for [{label}]"),
+            ));
+        }
+    }
+    for (number, label) in defined_links {
+        if !headed.contains(label.as_str()) {
+            violations.push(FormatViolation::new(
+                *number,
+                format!("link definition [{label}] has no matching section"),
+            ));
+        }
+    }
+}
+
+/// Parses `[1.2.0] - 2025-01-31`, optionally followed by ` [YANKED]`.
+fn parse_release_heading(heading: &str) -> Option<(Version, NaiveDate)> {
+    let (version, rest) = heading.strip_prefix('[')?.split_once("] - ")?;
+    let date = rest.strip_suffix(" [YANKED]").unwrap_or(rest);
+    Some((
+        Version::parse(version).ok()?,
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPLIANT: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+
+## [Unreleased]
+
+## [1.1.0] - 2025-02-01
+
+### Fixed
+
+- Bug fix
+
+## [1.0.0] - 2025-01-01
+
+### Added
+
+- First release
+
+[Unreleased]: https://github.com/owner/repo/compare/v1.1.0...HEAD
+[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0
+[1.0.0]: https://github.com/owner/repo/compare/HEAD...v1.0.0
+";
+
+    fn messages(content: &str) -> Vec<String> {
+        validate_keep_a_changelog(content)
+            .into_iter()
+            .map(|violation| violation.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn accepts_compliant_changelog() {
+        assert!(messages(COMPLIANT).is_empty());
+    }
+
+    #[test]
+    fn reports_missing_unreleased_section() {
+        let content = COMPLIANT.replace("## [Unreleased]\n\n", "").replace(
+            "[Unreleased]: https://github.com/owner/repo/compare/v1.1.0...HEAD\n",
+            "",
+        );
+
+        assert_eq!(
+            messages(&content),
+            ["line 8: expected '## [Unreleased]' as the first section"]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_headings_and_change_types() {
+        let content = COMPLIANT
+            .replace("## [1.0.0] - 2025-01-01", "## 1.0.0 (2025-01-01)")
+            .replace("### Fixed", "### Bug Fixes");
+
+        assert_eq!(
+            messages(&content),
+            [
+                "line 12: unknown change type 'Bug Fixes' (expected one of: Added, Changed, Deprecated, Removed, Fixed, Security)",
+                "line 16: expected '## [<version>] - <YYYY-MM-DD>', found '## 1.0.0 (2025-01-01)'",
+                "line 24: link definition [1.0.0] has no matching section",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_unordered_versions_and_missing_links() {
+        let content = COMPLIANT
+            .replace("## [1.0.0] - 2025-01-01", "## [1.2.0] - 2025-03-01")
+            .replace(
+                "[1.1.0]: https://github.com/owner/repo/compare/v1.0.0...v1.1.0\n",
+                "",
+            );
+
+        assert_eq!(
+            messages(&content),
+            [
+                "line 10: missing link definition for [1.1.0]",
+                "line 16: version 1.2.0 is not lower than the preceding 1.1.0",
+                "line 16: missing link definition for [1.2.0]",
+                "line 23: link definition [1.0.0] has no matching section",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_preamble_links() {
+        let content = COMPLIANT.replace(
+            "The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\n",
+            "",
+        );
+
+        assert_eq!(
+            messages(&content),
+            ["line 1: preamble does not link to Keep a Changelog (https://keepachangelog.com)"]
+        );
+    }
+}
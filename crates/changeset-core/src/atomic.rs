@@ -0,0 +1,225 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Writes `contents` to `path` through a temporary file and an atomic rename,
+/// flushing data to disk before the rename.
+///
+/// Readers (and a crash mid-write) observe either the old or the new file,
+/// never a truncated one.
+///
+/// # Errors
+///
+/// Returns an error if the temporary file cannot be written or renamed.
+pub fn atomic_write(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    AtomicWriteOptions::new().write(path, contents)
+}
+
+/// Configuration for [`atomic_write`]-style writes.
+#[derive(Debug, Clone, Copy)]
+pub struct AtomicWriteOptions {
+    fsync: bool,
+}
+
+impl Default for AtomicWriteOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AtomicWriteOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { fsync: true }
+    }
+
+    /// Whether to fsync the file and its directory. Enabled by default; disabling
+    /// keeps the atomic rename but trades durability on power loss for speed.
+    #[must_use]
+    pub fn fsync(mut self, fsync: bool) -> Self {
+        self.fsync = fsync;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the temporary file cannot be written or renamed.
+    pub fn write(&self, path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+        self.write_with(path, |file| file.write_all(contents.as_ref()))
+    }
+
+    /// Like [`Self::write`], but lets `write` stream the contents into the
+    /// temporary file. `path` keeps its old contents until `write` succeeds,
+    /// so it can be read while writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `write` fails or the temporary file cannot be
+    /// created or renamed.
+    pub fn write_with(
+        &self,
+        path: &Path,
+        write: impl FnOnce(&mut File) -> io::Result<()>,
+    ) -> io::Result<()> {
+        let temp_path = temp_path_for(path);
+
+        let result = self
+            .write_temp(&temp_path, path, write)
+            .and_then(|()| fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result?;
+
+        if self.fsync {
+            sync_parent_dir(path)?;
+        }
+        Ok(())
+    }
+
+    fn write_temp(
+        &self,
+        temp_path: &Path,
+        target: &Path,
+        write: impl FnOnce(&mut File) -> io::Result<()>,
+    ) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(temp_path)?;
+        write(&mut file)?;
+
+        if let Ok(metadata) = fs::metadata(target) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        if self.fsync {
+            file.sync_all()?;
+        }
+        Ok(())
+    }
+}
+
+fn temp_path_for(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map_or_else(|| "file".into(), |name| name.to_string_lossy());
+    let counter = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".{file_name}.{}.{counter}.tmp", std::process::id()))
+}
+
+/// Persists the rename itself; directories cannot be opened for syncing on Windows.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => File::open(parent)?.sync_all(),
+        _ => File::open(".")?.sync_all(),
+    }
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+fn sync_parent_dir(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_files(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .expect("read dir")
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "tmp"))
+            .collect()
+    }
+
+    #[test]
+    fn creates_new_file() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+
+        atomic_write(&path, "[package]\n").expect("write");
+
+        assert_eq!(fs::read_to_string(&path).expect("read"), "[package]\n");
+        assert!(temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn replaces_existing_file() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("CHANGELOG.md");
+        fs::write(&path, "old content that is longer").expect("seed");
+
+        AtomicWriteOptions::new()
+            .fsync(false)
+            .write(&path, "new")
+            .expect("write");
+
+        assert_eq!(fs::read_to_string(&path).expect("read"), "new");
+        assert!(temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn failed_write_leaves_no_temp_file() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let target = dir.path().join("occupied");
+        fs::create_dir(&target).expect("create dir");
+        fs::write(target.join("inner"), "x").expect("populate dir");
+
+        assert!(atomic_write(&target, "content").is_err());
+        assert!(temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn streamed_write_can_read_the_old_file() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("CHANGELOG.md");
+        fs::write(&path, "old").expect("seed");
+
+        AtomicWriteOptions::new()
+            .fsync(false)
+            .write_with(&path, |file| {
+                file.write_all(b"new ")?;
+                io::copy(&mut File::open(&path)?, file).map(|_| ())
+            })
+            .expect("write");
+
+        assert_eq!(fs::read_to_string(&path).expect("read"), "new old");
+    }
+
+    #[test]
+    fn failed_streamed_write_keeps_the_old_file() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("CHANGELOG.md");
+        fs::write(&path, "old").expect("seed");
+
+        let result = AtomicWriteOptions::new().write_with(&path, |file| {
+            file.write_all(b"partial")?;
+            Err(io::Error::other("injected failure"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).expect("read"), "old");
+        assert!(temp_files(dir.path()).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn preserves_permissions_of_existing_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("script.sh");
+        fs::write(&path, "old").expect("seed");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).expect("chmod");
+
+        atomic_write(&path, "new").expect("write");
+
+        let mode = fs::metadata(&path).expect("metadata").permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+    }
+}
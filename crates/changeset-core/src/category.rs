@@ -0,0 +1,162 @@
+use crate::types::ChangeCategory;
+
+/// How a change category is presented in prompts and changelogs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryDefinition {
+    pub category: ChangeCategory,
+    /// Description shown when selecting a category interactively.
+    pub label: String,
+    /// Changelog heading that entries of this category are grouped under.
+    pub section: String,
+    /// Position of the category in changelogs and prompts; lower comes first.
+    pub order: i64,
+}
+
+/// The change categories a project accepts, sorted by their order.
+///
+/// Without configuration this is the built-in Keep a Changelog set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategorySet {
+    definitions: Vec<CategoryDefinition>,
+}
+
+impl Default for CategorySet {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl CategorySet {
+    #[must_use]
+    pub fn builtin() -> Self {
+        let labels = [
+            "New features",
+            "General changes",
+            "Deprecated features",
+            "Removed features",
+            "Bug fixes",
+            "Security fixes",
+        ];
+
+        Self::new(
+            ChangeCategory::BUILTIN
+                .into_iter()
+                .zip(labels)
+                .zip(1..)
+                .map(|((category, label), position)| CategoryDefinition {
+                    section: category.to_string(),
+                    label: label.to_string(),
+                    order: position * 10,
+                    category,
+                })
+                .collect(),
+        )
+    }
+
+    /// Creates a set from definitions; definitions with equal order keep their relative position.
+    #[must_use]
+    pub fn new(mut definitions: Vec<CategoryDefinition>) -> Self {
+        definitions.sort_by_key(|definition| definition.order);
+        Self { definitions }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CategoryDefinition> {
+        self.definitions.iter()
+    }
+
+    #[must_use]
+    pub fn get(&self, category: &ChangeCategory) -> Option<&CategoryDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.category == *category)
+    }
+
+    #[must_use]
+    pub fn contains(&self, category: &ChangeCategory) -> bool {
+        self.get(category).is_some()
+    }
+
+    /// Looks up a category by id, ignoring ASCII case.
+    #[must_use]
+    pub fn resolve(&self, id: &str) -> Option<ChangeCategory> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.category.id().eq_ignore_ascii_case(id))
+            .map(|definition| definition.category.clone())
+    }
+
+    /// `changed` when the set contains it, otherwise the first category.
+    #[must_use]
+    pub fn default_category(&self) -> ChangeCategory {
+        if self.contains(&ChangeCategory::Changed) {
+            return ChangeCategory::Changed;
+        }
+        self.definitions
+            .first()
+            .map_or(ChangeCategory::Changed, |definition| {
+                definition.category.clone()
+            })
+    }
+
+    /// Comma-separated category ids, for error messages.
+    #[must_use]
+    pub fn ids(&self) -> String {
+        self.definitions
+            .iter()
+            .map(|definition| definition.category.id())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(id: &str, section: &str, order: i64) -> CategoryDefinition {
+        CategoryDefinition {
+            category: ChangeCategory::from_id(id),
+            label: id.to_string(),
+            section: section.to_string(),
+            order,
+        }
+    }
+
+    #[test]
+    fn builtin_set_follows_keep_a_changelog_order() {
+        let set = CategorySet::builtin();
+
+        let ids: Vec<_> = set.iter().map(|d| d.category.id()).collect();
+        assert_eq!(
+            ids,
+            [
+                "added",
+                "changed",
+                "deprecated",
+                "removed",
+                "fixed",
+                "security"
+            ]
+        );
+        assert_eq!(set.default_category(), ChangeCategory::Changed);
+    }
+
+    #[test]
+    fn custom_set_is_sorted_and_resolves_ids() {
+        let set = CategorySet::new(vec![
+            definition("perf", "Performance", 20),
+            definition("fixed", "Bug Fixes", 10),
+        ]);
+
+        let ids: Vec<_> = set.iter().map(|d| d.category.id()).collect();
+        assert_eq!(ids, ["fixed", "perf"]);
+        assert_eq!(
+            set.resolve("PERF"),
+            Some(ChangeCategory::Custom("perf".to_string()))
+        );
+        assert_eq!(set.resolve("fixed"), Some(ChangeCategory::Fixed));
+        assert_eq!(set.resolve("added"), None);
+        assert_eq!(set.default_category(), ChangeCategory::Fixed);
+        assert_eq!(set.ids(), "fixed, perf");
+    }
+}
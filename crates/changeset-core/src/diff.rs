@@ -0,0 +1,188 @@
+/// Lines of unchanged context shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Renders a line-based unified diff between two texts.
+///
+/// Returns an empty string when both texts have the same lines. A missing file
+/// is represented by an empty text and a `/dev/null` label.
+#[must_use]
+pub fn unified_diff(before: &str, after: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = before.lines().collect();
+    let new_lines: Vec<&str> = after.lines().collect();
+    let ops = line_ops(&old_lines, &new_lines);
+
+    let changes: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != Op::Equal)
+        .map(|(index, _)| index)
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let mut output = format!("--- {old_label}\n+++ {new_label}\n");
+    for (first, last) in group_changes(&changes) {
+        let start = first.saturating_sub(CONTEXT_LINES);
+        let end = (last + CONTEXT_LINES + 1).min(ops.len());
+        push_hunk(&mut output, &ops, start, end);
+    }
+    output
+}
+
+/// Groups change indices whose hunks would overlap or touch.
+fn group_changes(changes: &[usize]) -> Vec<(usize, usize)> {
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for &index in changes {
+        match groups.last_mut() {
+            Some((_, last)) if index - *last <= 2 * CONTEXT_LINES + 1 => *last = index,
+            _ => groups.push((index, index)),
+        }
+    }
+    groups
+}
+
+fn push_hunk(output: &mut String, ops: &[(Op, &str)], start: usize, end: usize) {
+    let old_before = ops[..start]
+        .iter()
+        .filter(|(op, _)| *op != Op::Insert)
+        .count();
+    let new_before = ops[..start]
+        .iter()
+        .filter(|(op, _)| *op != Op::Delete)
+        .count();
+    let hunk = &ops[start..end];
+    let old_count = hunk.iter().filter(|(op, _)| *op != Op::Insert).count();
+    let new_count = hunk.iter().filter(|(op, _)| *op != Op::Delete).count();
+
+    output.push_str(&format!(
+        "@@ -{},{old_count} +{},{new_count} @@\n",
+        hunk_start(old_before, old_count),
+        hunk_start(new_before, new_count),
+    ));
+    for (op, line) in hunk {
+        let prefix = match op {
+            Op::Equal => ' ',
+            Op::Delete => '-',
+            Op::Insert => '+',
+        };
+        output.push(prefix);
+        output.push_str(line);
+        output.push('\n');
+    }
+}
+
+/// Empty ranges point at the line before them, as in `diff -u`.
+fn hunk_start(lines_before: usize, count: usize) -> usize {
+    if count == 0 {
+        lines_before
+    } else {
+        lines_before + 1
+    }
+}
+
+/// Line edit script based on the longest common subsequence.
+///
+/// The common prefix and suffix are stripped first, so the quadratic part only
+/// covers the changed region.
+fn line_ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let width = new_mid.len() + 1;
+    let mut lcs = vec![0_usize; (old_mid.len() + 1) * width];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<(Op, &str)> = old[..prefix].iter().map(|l| (Op::Equal, *l)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() || j < new_mid.len() {
+        if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+            ops.push((Op::Equal, old_mid[i]));
+            i += 1;
+            j += 1;
+        } else if i < old_mid.len()
+            && (j == new_mid.len() || lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            ops.push((Op::Delete, old_mid[i]));
+            i += 1;
+        } else {
+            ops.push((Op::Insert, new_mid[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old[old.len() - suffix..].iter().map(|l| (Op::Equal, *l)));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_texts_have_no_diff() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "a/x", "b/x"), "");
+    }
+
+    #[test]
+    fn replaced_line_is_shown_with_context() {
+        let before = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let after = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+
+        assert_eq!(
+            unified_diff(before, after, "a/x", "b/x"),
+            "--- a/x\n+++ b/x\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn distant_changes_get_separate_hunks() {
+        let before: String = (1..=20).map(|n| format!("{n}\n")).collect();
+        let after: String = (1..=20)
+            .map(|n| match n {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                n => format!("{n}\n"),
+            })
+            .collect();
+
+        let diff = unified_diff(&before, &after, "a/x", "b/x");
+
+        assert_eq!(diff.matches("@@ -").count(), 2);
+        assert!(diff.contains("@@ -1,5 +1,5 @@\n 1\n-2\n+two\n"));
+        assert!(diff.contains("@@ -16,5 +16,5 @@\n"));
+    }
+
+    #[test]
+    fn created_and_deleted_files() {
+        assert_eq!(
+            unified_diff("", "a\nb\n", "/dev/null", "b/x"),
+            "--- /dev/null\n+++ b/x\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+        assert_eq!(
+            unified_diff("a\n", "", "a/x", "/dev/null"),
+            "--- a/x\n+++ /dev/null\n@@ -1,1 +0,0 @@\n-a\n"
+        );
+    }
+}
@@ -18,8 +18,14 @@ pub enum PrereleaseSpecParseError {
     #[error("prerelease identifier cannot be empty")]
     Empty,
 
-    #[error("prerelease identifier '{0}' contains invalid character '{1}'")]
+    #[error(
+        "prerelease identifier '{0}' contains invalid character '{1}' \
+         (only ASCII letters, digits and '-' are allowed)"
+    )]
     InvalidCharacter(String, char),
+
+    #[error("numeric prerelease identifier '{0}' must not have leading zeros")]
+    LeadingZero(String),
 }
 
 pub type Result<T> = std::result::Result<T, ChangesetError>;
@@ -1,5 +1,13 @@
+mod atomic;
+mod category;
+mod diff;
 pub mod error;
+mod line_ending;
 pub mod types;
 
+pub use atomic::{AtomicWriteOptions, atomic_write};
+pub use category::{CategoryDefinition, CategorySet};
+pub use diff::unified_diff;
 pub use error::*;
+pub use line_ending::LineEnding;
 pub use types::*;
@@ -0,0 +1,64 @@
+/// Line ending style of a text file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Detects the dominant line ending of `content`.
+    ///
+    /// Files with mixed endings resolve to whichever style is used by most lines,
+    /// and files without any line breaks resolve to [`LineEnding::Lf`].
+    #[must_use]
+    pub fn detect(content: &str) -> Self {
+        let total = content.matches('\n').count();
+        let crlf = content.matches("\r\n").count();
+        if crlf * 2 > total {
+            Self::CrLf
+        } else {
+            Self::Lf
+        }
+    }
+
+    /// Converts all line breaks in `content` to `\n`.
+    #[must_use]
+    pub fn normalize(content: &str) -> String {
+        content.replace("\r\n", "\n")
+    }
+
+    /// Converts all line breaks in `content` to this line ending.
+    #[must_use]
+    pub fn apply(self, content: &str) -> String {
+        let normalized = Self::normalize(content);
+        match self {
+            Self::Lf => normalized,
+            Self::CrLf => normalized.replace('\n', "\r\n"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_lf_and_crlf() {
+        assert_eq!(LineEnding::detect("a\nb\n"), LineEnding::Lf);
+        assert_eq!(LineEnding::detect("a\r\nb\r\n"), LineEnding::CrLf);
+        assert_eq!(LineEnding::detect("no line breaks"), LineEnding::Lf);
+    }
+
+    #[test]
+    fn mixed_endings_resolve_to_majority() {
+        assert_eq!(LineEnding::detect("a\r\nb\r\nc\n"), LineEnding::CrLf);
+        assert_eq!(LineEnding::detect("a\r\nb\nc\n"), LineEnding::Lf);
+    }
+
+    #[test]
+    fn apply_converts_every_line_break() {
+        assert_eq!(LineEnding::CrLf.apply("a\nb\r\nc\n"), "a\r\nb\r\nc\r\n");
+        assert_eq!(LineEnding::Lf.apply("a\r\nb\nc\r\n"), "a\nb\nc\n");
+    }
+}
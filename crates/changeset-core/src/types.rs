@@ -1,6 +1,7 @@
 use std::fmt;
 use std::str::FromStr;
 
+use chrono::NaiveDate;
 use clap::ValueEnum;
 use semver::Version;
 use serde::{Deserialize, Serialize};
@@ -50,20 +51,7 @@ mod tests {
     }
 }
 
-#[derive(
-    Debug,
-    Clone,
-    Copy,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
-    Serialize,
-    Deserialize,
-    Default,
-    ValueEnum,
-)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum ChangeCategory {
     Added,
@@ -73,6 +61,44 @@ pub enum ChangeCategory {
     Removed,
     Fixed,
     Security,
+    /// A project-defined category, identified by its configured id.
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl ChangeCategory {
+    /// The Keep a Changelog categories, in changelog order.
+    pub const BUILTIN: [Self; 6] = [
+        Self::Added,
+        Self::Changed,
+        Self::Deprecated,
+        Self::Removed,
+        Self::Fixed,
+        Self::Security,
+    ];
+
+    /// Maps an id to a built-in category, or to a custom one if it is not built in.
+    #[must_use]
+    pub fn from_id(id: &str) -> Self {
+        Self::BUILTIN
+            .into_iter()
+            .find(|category| category.id() == id)
+            .unwrap_or_else(|| Self::Custom(id.to_string()))
+    }
+
+    /// Identifier used in changeset front matter and configuration.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Added => "added",
+            Self::Changed => "changed",
+            Self::Deprecated => "deprecated",
+            Self::Removed => "removed",
+            Self::Fixed => "fixed",
+            Self::Security => "security",
+            Self::Custom(id) => id,
+        }
+    }
 }
 
 impl fmt::Display for ChangeCategory {
@@ -84,6 +110,7 @@ impl fmt::Display for ChangeCategory {
             Self::Removed => "Removed",
             Self::Fixed => "Fixed",
             Self::Security => "Security",
+            Self::Custom(id) => id,
         };
         write!(f, "{s}")
     }
@@ -106,12 +133,26 @@ pub struct PackageRelease {
 /// in a prerelease. When set, it contains the prerelease version string (e.g., "1.0.1-alpha.1").
 /// Consumed changesets are excluded from subsequent prereleases but are aggregated into
 /// the changelog when graduating to a stable release.
+///
+/// # Confidential Changes
+///
+/// Changesets marked `confidential` (e.g. security fixes under coordinated disclosure)
+/// keep their summary out of the changelog while embargoed. The embargo lasts until
+/// the optional `embargo` date, or indefinitely when no date is set.
+///
+/// # Security Advisories
+///
+/// The `advisory` field links a change to a published advisory such as a RustSec
+/// or CVE id, which the changelog renders as a link next to the entry.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Changeset {
     pub summary: String,
     pub releases: Vec<PackageRelease>,
     #[serde(default)]
     pub category: ChangeCategory,
+    /// Component of the package the change affects, e.g. `parser` or `cli`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
     /// Version string of the prerelease that consumed this changeset, if any.
     /// Set during prerelease creation, cleared during graduation to stable.
     #[serde(
@@ -122,6 +163,22 @@ pub struct Changeset {
     pub consumed_for_prerelease: Option<String>,
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub graduate: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub confidential: bool,
+    /// First day on which a confidential summary may be published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embargo: Option<NaiveDate>,
+    /// Security advisory the change resolves, e.g. `RUSTSEC-2024-0001` or a CVE id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advisory: Option<String>,
+}
+
+impl Changeset {
+    /// Returns whether the summary must be withheld from output on `today`.
+    #[must_use]
+    pub fn is_embargoed(&self, today: NaiveDate) -> bool {
+        self.confidential && self.embargo.is_none_or(|date| today < date)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -169,6 +226,10 @@ impl FromStr for PrereleaseSpec {
             return Err(Self::Err::InvalidCharacter(s.to_string(), invalid_char));
         }
 
+        if s.len() > 1 && s.starts_with('0') && s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Self::Err::LeadingZero(s.to_string()));
+        }
+
         Ok(match s.to_lowercase().as_str() {
             "alpha" => Self::Alpha,
             "beta" => Self::Beta,
@@ -285,6 +346,16 @@ mod prerelease_spec_tests {
         );
     }
 
+    #[test]
+    fn from_str_rejects_numeric_identifier_with_leading_zero() {
+        assert_eq!(
+            "01".parse::<PrereleaseSpec>().unwrap_err(),
+            crate::error::PrereleaseSpecParseError::LeadingZero("01".to_string())
+        );
+        assert!("0".parse::<PrereleaseSpec>().is_ok());
+        assert!("01a".parse::<PrereleaseSpec>().is_ok());
+    }
+
     #[test]
     fn from_str_accepts_valid_semver_identifiers() {
         assert!("alpha".parse::<PrereleaseSpec>().is_ok());
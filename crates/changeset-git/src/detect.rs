@@ -0,0 +1,153 @@
+/// How existing release tags name their versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagStyle {
+    /// Tags are just the version, like `v1.2.3` or `1.2.3`.
+    VersionOnly,
+    /// Tags put a package name in front of the version, like `foo@v1.2.3` or
+    /// `foo-v1.2.3`.
+    PackagePrefixed,
+}
+
+/// The tag style most existing release tags follow, with an example tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagConvention {
+    pub style: TagStyle,
+    pub example: String,
+    /// How many tags follow the style.
+    pub count: usize,
+}
+
+/// Classifies a tag name, or returns `None` if it does not end in a version.
+fn classify_tag(tag: &str) -> Option<TagStyle> {
+    let start = tag
+        .char_indices()
+        .find(|&(i, c)| c.is_ascii_digit() && is_version(&tag[i..]))
+        .map(|(i, _)| i)?;
+    let prefix = tag[..start].strip_suffix('v').unwrap_or(&tag[..start]);
+
+    if prefix.is_empty() {
+        return Some(TagStyle::VersionOnly);
+    }
+    let name = prefix.strip_suffix(['@', '-', '/', '_'])?;
+    (!name.is_empty()).then_some(TagStyle::PackagePrefixed)
+}
+
+fn is_version(value: &str) -> bool {
+    let core = value.split(['-', '+']).next().unwrap_or_default();
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Detects the tag style the majority of `tags` follow.
+///
+/// Tags that do not end in a `major.minor.patch` version are ignored. Returns
+/// `None` when no tag looks like a release tag. Ties favour package-prefixed
+/// tags, which stay unambiguous in workspaces.
+#[must_use]
+pub fn detect_tag_convention(tags: &[String]) -> Option<TagConvention> {
+    let mut version_only: Vec<&String> = Vec::new();
+    let mut prefixed: Vec<&String> = Vec::new();
+    for tag in tags {
+        match classify_tag(tag) {
+            Some(TagStyle::VersionOnly) => version_only.push(tag),
+            Some(TagStyle::PackagePrefixed) => prefixed.push(tag),
+            None => {}
+        }
+    }
+
+    let (style, matching) = if prefixed.len() >= version_only.len() {
+        (TagStyle::PackagePrefixed, prefixed)
+    } else {
+        (TagStyle::VersionOnly, version_only)
+    };
+    let example = matching.last()?;
+
+    Some(TagConvention {
+        style,
+        example: (*example).clone(),
+        count: matching.len(),
+    })
+}
+
+/// Extracts the host name from a remote URL.
+///
+/// Handles URLs with a scheme (`https://github.com/o/r.git`,
+/// `ssh://git@host:22/o/r`) as well as scp-like addresses
+/// (`git@github.com:o/r.git`). Returns `None` for local paths.
+#[must_use]
+pub fn remote_host(url: &str) -> Option<String> {
+    let authority = if let Some((_, rest)) = url.split_once("://") {
+        rest.split('/').next()?
+    } else {
+        let (authority, _) = url.split_once(':')?;
+        // Paths such as `./repo:x` or `C:\repo` are local, not scp-like.
+        if authority.contains(['/', '\\']) || authority.len() == 1 {
+            return None;
+        }
+        authority
+    };
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = host.split(':').next().unwrap_or(host);
+
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn detects_package_prefixed_tags() {
+        let convention = detect_tag_convention(&tags(&["foo-v1.2.2", "foo-v1.2.3", "bar@v0.1.0"]))
+            .expect("convention");
+
+        assert_eq!(convention.style, TagStyle::PackagePrefixed);
+        assert_eq!(convention.example, "bar@v0.1.0");
+        assert_eq!(convention.count, 3);
+    }
+
+    #[test]
+    fn detects_version_only_tags() {
+        let convention =
+            detect_tag_convention(&tags(&["v1.0.0", "1.1.0", "v2.0.0-rc.1"])).expect("convention");
+
+        assert_eq!(convention.style, TagStyle::VersionOnly);
+        assert_eq!(convention.count, 3);
+    }
+
+    #[test]
+    fn ignores_tags_without_versions() {
+        assert_eq!(
+            detect_tag_convention(&tags(&["nightly", "release-2", "v1.2"])),
+            None
+        );
+    }
+
+    #[test]
+    fn extracts_remote_hosts() {
+        assert_eq!(
+            remote_host("https://github.com/owner/repo.git").as_deref(),
+            Some("github.com")
+        );
+        assert_eq!(
+            remote_host("git@gitlab.example.com:group/repo.git").as_deref(),
+            Some("gitlab.example.com")
+        );
+        assert_eq!(
+            remote_host("ssh://git@Bitbucket.org:22/owner/repo").as_deref(),
+            Some("bitbucket.org")
+        );
+        assert_eq!(remote_host("/srv/git/repo.git"), None);
+        assert_eq!(remote_host("../repo"), None);
+        assert_eq!(remote_host("C:\\repos\\project"), None);
+    }
+}
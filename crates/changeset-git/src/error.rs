@@ -9,6 +9,9 @@ pub enum GitError {
     #[error("not a git repository: '{path}'")]
     NotARepository { path: PathBuf },
 
+    #[error("'{path}' is a bare git repository; run from a checkout or linked worktree")]
+    BareRepository { path: PathBuf },
+
     #[error("failed to resolve reference '{refspec}'")]
     RefNotFound {
         refspec: String,
@@ -39,6 +42,25 @@ pub enum GitError {
     #[error("diff delta has no file path")]
     MissingDeltaPath,
 
+    #[error("git remote '{name}' does not exist")]
+    RemoteNotFound { name: String },
+
+    #[error(
+        "authentication to remote '{remote}' failed; check ssh-agent, your git credential helper, or CARGO_CHANGESET_GIT_TOKEN"
+    )]
+    AuthenticationFailed {
+        remote: String,
+        #[source]
+        source: git2::Error,
+    },
+
+    #[error("failed to fetch from remote '{remote}'; check the network connection and remote URL")]
+    FetchFailed {
+        remote: String,
+        #[source]
+        source: git2::Error,
+    },
+
     #[error("HEAD has no parent commit")]
     NoParentCommit {
         #[source]
@@ -1,10 +1,12 @@
+mod detect;
 mod error;
 mod repository;
 mod types;
 
+pub use detect::{TagConvention, TagStyle, detect_tag_convention, remote_host};
 pub use error::GitError;
 pub use repository::Repository;
-pub use types::{CommitInfo, FileChange, FileStatus, TagInfo};
+pub use types::{CommitIdentity, CommitInfo, FileChange, FileStatus, Identity, TagInfo};
 
 use std::path::Path;
 
@@ -24,6 +26,16 @@ pub fn current_branch(path: &Path) -> Result<String> {
     Repository::open(path)?.current_branch()
 }
 
+/// Returns the hex object id git assigns to a blob with `content`, as printed by
+/// `git hash-object`.
+///
+/// # Errors
+///
+/// Returns an error if the object id cannot be computed.
+pub fn blob_hash(content: &[u8]) -> Result<String> {
+    Ok(git2::Oid::hash_object(git2::ObjectType::Blob, content)?.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,6 +54,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn blob_hash_matches_git_hash_object() -> anyhow::Result<()> {
+        assert_eq!(
+            blob_hash(b"hello\n")?,
+            "ce013625030ba8dba906f756967f9e9ca394464a"
+        );
+        Ok(())
+    }
+
     #[test]
     fn current_branch_via_public_fn() -> anyhow::Result<()> {
         let (dir, _repo) = setup_test_repo()?;
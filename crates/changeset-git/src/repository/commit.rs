@@ -1,4 +1,6 @@
-use crate::{CommitInfo, GitError, Result};
+use std::path::{Path, PathBuf};
+
+use crate::{CommitIdentity, CommitInfo, GitError, Identity, Result};
 
 use super::Repository;
 
@@ -23,11 +25,164 @@ impl Repository {
         Ok(())
     }
 
+    /// Returns the SHA of the commit at HEAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD cannot be resolved.
+    pub fn head_sha(&self) -> Result<String> {
+        Ok(self.inner.head()?.peel_to_commit()?.id().to_string())
+    }
+
+    /// Performs a soft reset to the commit `sha`, keeping changes staged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sha` does not name a commit or the reset fails.
+    pub fn reset_soft_to(&self, sha: &str) -> Result<()> {
+        let commit = self.inner.find_commit(self.resolve_commit(sha)?)?;
+        self.inner
+            .reset(commit.as_object(), git2::ResetType::Soft, None)?;
+        Ok(())
+    }
+
+    /// Replaces HEAD with a commit of the current index, keeping its message,
+    /// author and parents. `committer` defaults to the repository's user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD cannot be resolved or the commit cannot be created.
+    pub fn amend_head(&self, committer: Option<&Identity>) -> Result<CommitInfo> {
+        let head_commit = self.inner.head()?.peel_to_commit()?;
+        let mut index = self.inner.index()?;
+        let tree = self.inner.find_tree(index.write_tree()?)?;
+        let committer = self.signature(committer)?;
+
+        let commit_oid = head_commit.amend(
+            Some("HEAD"),
+            None,
+            Some(&committer),
+            None,
+            None,
+            Some(&tree),
+        )?;
+
+        Ok(CommitInfo {
+            sha: commit_oid.to_string(),
+            message: head_commit.message().unwrap_or_default().to_string(),
+        })
+    }
+
+    /// Messages of the commits reachable from `head` but not from `base`,
+    /// newest first. With `path`, only commits that changed something under it
+    /// are listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GitError::RefNotFound`] if either reference cannot be resolved,
+    /// or an error if the history cannot be walked.
+    pub fn commit_messages(
+        &self,
+        base: &str,
+        head: &str,
+        path: Option<&Path>,
+    ) -> Result<Vec<String>> {
+        let pathspec = path
+            .map(|path| self.to_relative_path(path))
+            .filter(|relative| !relative.as_os_str().is_empty());
+
+        let mut messages = Vec::new();
+        for commit in self.range_commits(Some(base), head)? {
+            if let Some(pathspec) = &pathspec {
+                if self.commit_diff(&commit, pathspec)?.deltas().len() == 0 {
+                    continue;
+                }
+            }
+            messages.push(commit.message().unwrap_or_default().to_string());
+        }
+        Ok(messages)
+    }
+
+    /// Files under `path` that the commits reachable from `head` but not from
+    /// `base` deleted, with their content before the deletion, newest first.
+    /// Without `base`, the whole history of `head` is searched. Paths are
+    /// relative to the repository root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GitError::RefNotFound`] if either reference cannot be resolved,
+    /// or an error if the history cannot be walked.
+    pub fn deleted_files(
+        &self,
+        base: Option<&str>,
+        head: &str,
+        path: &Path,
+    ) -> Result<Vec<(PathBuf, String)>> {
+        let pathspec = self.to_relative_path(path);
+
+        let mut deleted = Vec::new();
+        for commit in self.range_commits(base, head)? {
+            let diff = self.commit_diff(&commit, &pathspec)?;
+            for delta in diff.deltas() {
+                if delta.status() != git2::Delta::Deleted {
+                    continue;
+                }
+                let old_file = delta.old_file();
+                let Some(file_path) = old_file.path() else {
+                    continue;
+                };
+                let blob = self.inner.find_blob(old_file.id())?;
+                deleted.push((
+                    file_path.to_path_buf(),
+                    String::from_utf8_lossy(blob.content()).into_owned(),
+                ));
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Commits reachable from `head` but not from `base`, newest first.
+    fn range_commits(&self, base: Option<&str>, head: &str) -> Result<Vec<git2::Commit<'_>>> {
+        let mut walk = self.inner.revwalk()?;
+        walk.push(self.resolve_commit(head)?)?;
+        if let Some(base) = base {
+            walk.hide(self.resolve_commit(base)?)?;
+        }
+        walk.map(|oid| Ok(self.inner.find_commit(oid?)?)).collect()
+    }
+
+    /// Changes `commit` made under `pathspec`, against its first parent.
+    fn commit_diff(&self, commit: &git2::Commit<'_>, pathspec: &Path) -> Result<git2::Diff<'_>> {
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let mut options = git2::DiffOptions::new();
+        if !pathspec.as_os_str().is_empty() {
+            options.pathspec(pathspec);
+        }
+        Ok(self
+            .inner
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut options))?)
+    }
+
     /// # Errors
     ///
     /// Returns an error if the commit cannot be created.
     pub fn commit(&self, message: &str) -> Result<CommitInfo> {
-        let sig = self.inner.signature()?;
+        self.commit_as(message, &CommitIdentity::default())
+    }
+
+    /// Commits the index with the given author and committer instead of the
+    /// repository's user where they are set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit cannot be created.
+    pub fn commit_as(&self, message: &str, identity: &CommitIdentity) -> Result<CommitInfo> {
+        let author = self.signature(identity.author.as_ref())?;
+        let committer = self.signature(identity.committer.as_ref())?;
         let mut index = self.inner.index()?;
         let tree_id = index.write_tree()?;
         let tree = self.inner.find_tree(tree_id)?;
@@ -36,9 +191,9 @@ impl Repository {
 
         let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
 
-        let commit_oid = self
-            .inner
-            .commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)?;
+        let commit_oid =
+            self.inner
+                .commit(Some("HEAD"), &author, &committer, message, &tree, &parents)?;
 
         let sha = commit_oid.to_string();
 
@@ -47,14 +202,73 @@ impl Repository {
             message: message.to_string(),
         })
     }
+
+    fn signature(&self, identity: Option<&Identity>) -> Result<git2::Signature<'static>> {
+        match identity {
+            Some(identity) => Ok(git2::Signature::now(&identity.name, &identity.email)?),
+            None => Ok(self.inner.signature()?),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::tests::setup_test_repo;
-    use crate::GitError;
+    use crate::{CommitIdentity, GitError, Identity};
     use std::fs;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn commit_messages_lists_range_filtered_by_path() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let base = repo.inner.head()?.peel_to_commit()?.id().to_string();
+
+        fs::create_dir_all(dir.path().join("crates/a"))?;
+        fs::write(dir.path().join("crates/a/lib.rs"), "a")?;
+        repo.stage_files(&[Path::new("crates/a/lib.rs")])?;
+        repo.commit("feat: add a")?;
+        fs::write(dir.path().join("README.md"), "docs")?;
+        repo.stage_files(&[Path::new("README.md")])?;
+        repo.commit("docs: readme")?;
+
+        assert_eq!(
+            repo.commit_messages(&base, "HEAD", None)?,
+            ["docs: readme", "feat: add a"]
+        );
+        assert_eq!(
+            repo.commit_messages(&base, "HEAD", Some(&dir.path().join("crates/a")))?,
+            ["feat: add a"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deleted_files_returns_content_before_deletion() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let base = repo.inner.head()?.peel_to_commit()?.id().to_string();
+
+        fs::create_dir_all(dir.path().join(".changeset"))?;
+        fs::write(dir.path().join(".changeset/fix.md"), "fix")?;
+        fs::write(dir.path().join("notes.md"), "notes")?;
+        repo.stage_files(&[Path::new(".changeset/fix.md"), Path::new("notes.md")])?;
+        repo.commit("Add changeset")?;
+        fs::remove_file(dir.path().join(".changeset/fix.md"))?;
+        fs::remove_file(dir.path().join("notes.md"))?;
+        repo.stage_files(&[Path::new(".changeset/fix.md"), Path::new("notes.md")])?;
+        repo.commit("Release")?;
+
+        assert_eq!(
+            repo.deleted_files(Some(&base), "HEAD", &dir.path().join(".changeset"))?,
+            [(PathBuf::from(".changeset/fix.md"), "fix".to_string())]
+        );
+        assert_eq!(
+            repo.deleted_files(None, "HEAD", Path::new(".changeset"))?,
+            [(PathBuf::from(".changeset/fix.md"), "fix".to_string())]
+        );
+
+        Ok(())
+    }
 
     #[test]
     fn create_commit() -> anyhow::Result<()> {
@@ -91,6 +305,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn commit_as_records_configured_identity() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[Path::new("file.txt")])?;
+
+        let bot = Identity {
+            name: "github-actions[bot]".to_string(),
+            email: "41898282+github-actions[bot]@users.noreply.github.com".to_string(),
+        };
+        repo.commit_as(
+            "Release",
+            &CommitIdentity {
+                author: Some(bot.clone()),
+                committer: None,
+            },
+        )?;
+
+        let head = repo.inner.head()?.peel_to_commit()?;
+        assert_eq!(head.author().name(), Some(bot.name.as_str()));
+        assert_eq!(head.author().email(), Some(bot.email.as_str()));
+        let user = repo.inner.signature()?;
+        assert_eq!(head.committer().name(), user.name());
+        assert_eq!(head.committer().email(), user.email());
+
+        Ok(())
+    }
+
     #[test]
     fn reset_to_parent_undoes_last_commit() -> anyhow::Result<()> {
         let (dir, repo) = setup_test_repo()?;
@@ -124,4 +366,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn reset_soft_to_restores_replaced_commit() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[Path::new("file.txt")])?;
+        let original = repo.commit("Release commit")?;
+        assert_eq!(repo.head_sha()?, original.sha);
+
+        fs::write(dir.path().join("late.txt"), "late")?;
+        repo.stage_files(&[Path::new("late.txt")])?;
+        repo.amend_head(None)?;
+        repo.reset_soft_to(&original.sha)?;
+
+        assert_eq!(repo.head_sha()?, original.sha);
+        assert!(
+            repo.staged_files()?
+                .iter()
+                .any(|change| change.path == Path::new("late.txt"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn amend_head_replaces_commit_and_keeps_message() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[Path::new("file.txt")])?;
+        let original = repo.commit("Release commit")?;
+        let parent = repo.inner.head()?.peel_to_commit()?.parent_id(0)?;
+
+        fs::write(dir.path().join("late.txt"), "late")?;
+        repo.stage_files(&[Path::new("late.txt")])?;
+        let amended = repo.amend_head(None)?;
+
+        assert_ne!(amended.sha, original.sha);
+        assert_eq!(amended.message, "Release commit");
+        let head = repo.inner.head()?.peel_to_commit()?;
+        assert_eq!(head.id().to_string(), amended.sha);
+        assert_eq!(head.parent_id(0)?, parent);
+        assert!(head.tree()?.get_name("late.txt").is_some());
+
+        Ok(())
+    }
 }
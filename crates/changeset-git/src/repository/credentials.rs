@@ -0,0 +1,394 @@
+use std::process::Command;
+
+use git2::{Cred, CredentialType, ErrorClass, ErrorCode, RemoteCallbacks};
+
+use crate::{GitError, Result};
+
+use super::Repository;
+
+/// Environment variable holding a token for HTTPS remotes on any host.
+const GIT_TOKEN_ENV: &str = "CARGO_CHANGESET_GIT_TOKEN";
+
+/// Fallback token variable, as set by GitHub Actions; only offered to GitHub.
+const GITHUB_TOKEN_ENV: &str = "GITHUB_TOKEN";
+
+/// URL of the GitHub server a workflow runs on, as set by GitHub Actions.
+const GITHUB_SERVER_URL_ENV: &str = "GITHUB_SERVER_URL";
+
+/// Credentials offered to a remote, each at most once so that libgit2 does
+/// not retry a rejected one forever.
+#[derive(Default)]
+struct CredentialAttempts {
+    ssh_agent: bool,
+    token: bool,
+    helper: bool,
+    default: bool,
+}
+
+impl CredentialAttempts {
+    /// Picks the next credential to offer, in order: ssh-agent, a token from
+    /// the environment, the configured git credential helper, then the
+    /// platform default (e.g. Kerberos).
+    fn next(
+        &mut self,
+        config: &git2::Config,
+        token: Option<&str>,
+        url: &str,
+        username: Option<&str>,
+        allowed: CredentialType,
+    ) -> std::result::Result<Cred, git2::Error> {
+        if allowed.contains(CredentialType::USERNAME) {
+            return Cred::username(username.unwrap_or("git"));
+        }
+        if allowed.contains(CredentialType::SSH_KEY) && !self.ssh_agent {
+            self.ssh_agent = true;
+            return Cred::ssh_key_from_agent(username.unwrap_or("git"));
+        }
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            if let Some(token) = token
+                && !self.token
+            {
+                self.token = true;
+                return Cred::userpass_plaintext(username.unwrap_or("x-access-token"), token);
+            }
+            if !self.helper {
+                self.helper = true;
+                if let Ok(cred) = Cred::credential_helper(config, url, username) {
+                    return Ok(cred);
+                }
+            }
+        }
+        if allowed.contains(CredentialType::DEFAULT) && !self.default {
+            self.default = true;
+            return Cred::default();
+        }
+        Err(git2::Error::new(
+            ErrorCode::Auth,
+            ErrorClass::Callback,
+            format!("no accepted credentials for '{url}'"),
+        ))
+    }
+}
+
+/// Token for the HTTPS remote at `url`: [`GIT_TOKEN_ENV`] for any host, then
+/// `GITHUB_TOKEN` when the remote is on `github.com` or the GitHub server of
+/// `GITHUB_SERVER_URL`, so that a GitHub token never reaches another host.
+fn token_for_url(url: &str, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let var = |name| env(name).filter(|value: &String| !value.is_empty());
+    var(GIT_TOKEN_ENV).or_else(|| {
+        let host = url_host(url)?;
+        let is_github = host.eq_ignore_ascii_case("github.com")
+            || var(GITHUB_SERVER_URL_ENV).is_some_and(|server| {
+                url_host(&server).is_some_and(|h| h.eq_ignore_ascii_case(host))
+            });
+        is_github.then(|| var(GITHUB_TOKEN_ENV)).flatten()
+    })
+}
+
+/// Host of an `http(s)://` URL, without user info or port.
+fn url_host(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = host_port.split(':').next()?;
+    (!host.is_empty()).then_some(host)
+}
+
+/// Whether `url` is reached over SSH, in URL or scp-like `user@host:path` form.
+fn is_ssh_url(url: &str) -> bool {
+    if let Some((scheme, _)) = url.split_once("://") {
+        return scheme == "ssh" || scheme == "git+ssh";
+    }
+    url.split_once(':')
+        .is_some_and(|(host, _)| host.len() > 1 && !host.contains('/'))
+}
+
+/// Whether a failure reported by libgit2 or the git CLI was an authentication failure.
+fn is_auth_failure(error: &git2::Error) -> bool {
+    let message = error.message().to_ascii_lowercase();
+    error.code() == ErrorCode::Auth
+        || message.contains("authentication")
+        || message.contains("permission denied")
+        || message.contains("could not read username")
+}
+
+/// Name of the tag a remote reference points at, without the `^{}` suffix of
+/// a peeled annotated tag.
+fn remote_tag_name(reference: &str) -> Option<&str> {
+    reference
+        .strip_prefix("refs/tags/")
+        .map(|name| name.trim_end_matches("^{}"))
+}
+
+/// Maps a failed fetch to an authentication or a network error.
+pub(crate) fn remote_error(remote: &str, source: git2::Error) -> GitError {
+    if is_auth_failure(&source) {
+        GitError::AuthenticationFailed {
+            remote: remote.to_string(),
+            source,
+        }
+    } else {
+        GitError::FetchFailed {
+            remote: remote.to_string(),
+            source,
+        }
+    }
+}
+
+impl Repository {
+    /// Fetches `refspecs` from the remote named `remote_name`.
+    ///
+    /// Credentials come from ssh-agent, [`GIT_TOKEN_ENV`] (or `GITHUB_TOKEN`
+    /// for GitHub remotes) for HTTPS, the configured git credential helper, or the platform
+    /// default. When `GIT_SSH_COMMAND` is set, SSH remotes are fetched with the
+    /// git CLI, since libgit2 cannot run a custom SSH command.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GitError::AuthenticationFailed`] if the remote rejects every
+    /// credential, or [`GitError::FetchFailed`] if it cannot be reached.
+    pub(crate) fn fetch(&self, remote_name: &str, refspecs: &[&str]) -> Result<()> {
+        let mut remote = self.inner.find_remote(remote_name)?;
+        let url = remote.url().unwrap_or_default().to_string();
+
+        if is_ssh_url(&url) && std::env::var_os("GIT_SSH_COMMAND").is_some() {
+            return self
+                .run_remote_cli(remote_name, &["fetch", remote_name], refspecs)
+                .map(drop);
+        }
+
+        let mut options = git2::FetchOptions::new();
+        options.remote_callbacks(self.credential_callbacks()?);
+
+        remote
+            .fetch(refspecs, Some(&mut options), None)
+            .map_err(|source| remote_error(remote_name, source))
+    }
+
+    /// Lists the tags that exist on the remote named `remote_name`, asking the
+    /// remote itself rather than reading local references. Connects like
+    /// [`Self::fetch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GitError::AuthenticationFailed`] if the remote rejects every
+    /// credential, or [`GitError::FetchFailed`] if it cannot be reached.
+    pub(crate) fn remote_tags(&self, remote_name: &str) -> Result<Vec<String>> {
+        let mut remote = self.inner.find_remote(remote_name)?;
+        let url = remote.url().unwrap_or_default().to_string();
+
+        let mut tags: Vec<String> =
+            if is_ssh_url(&url) && std::env::var_os("GIT_SSH_COMMAND").is_some() {
+                self.run_remote_cli(remote_name, &["ls-remote", "--tags", remote_name], &[])?
+                    .lines()
+                    .filter_map(|line| line.split_once('\t'))
+                    .filter_map(|(_, reference)| remote_tag_name(reference))
+                    .map(String::from)
+                    .collect()
+            } else {
+                let connection = remote
+                    .connect_auth(
+                        git2::Direction::Fetch,
+                        Some(self.credential_callbacks()?),
+                        None,
+                    )
+                    .map_err(|source| remote_error(remote_name, source))?;
+                connection
+                    .list()
+                    .map_err(|source| remote_error(remote_name, source))?
+                    .iter()
+                    .filter_map(|head| remote_tag_name(head.name()))
+                    .map(String::from)
+                    .collect()
+            };
+        tags.sort();
+        tags.dedup();
+        Ok(tags)
+    }
+
+    /// Offers the credentials described on [`Self::fetch`] to a remote.
+    fn credential_callbacks(&self) -> Result<RemoteCallbacks<'static>> {
+        let config = self.inner.config()?;
+        let mut attempts = CredentialAttempts::default();
+        let mut callbacks = RemoteCallbacks::new();
+        callbacks.credentials(move |url, username, allowed| {
+            let token = token_for_url(url, |name| std::env::var(name).ok());
+            attempts.next(&config, token.as_deref(), url, username, allowed)
+        });
+        Ok(callbacks)
+    }
+
+    /// Runs a git CLI command that talks to `remote_name` and returns its stdout.
+    fn run_remote_cli(
+        &self,
+        remote_name: &str,
+        args: &[&str],
+        refspecs: &[&str],
+    ) -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .args(refspecs)
+            .current_dir(self.root())
+            .output()
+            .map_err(|e| {
+                remote_error(
+                    remote_name,
+                    git2::Error::new(ErrorCode::GenericError, ErrorClass::Os, e.to_string()),
+                )
+            })?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Err(remote_error(
+            remote_name,
+            git2::Error::new(ErrorCode::GenericError, ErrorClass::Ssh, stderr),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::tests::setup_test_repo;
+
+    #[test]
+    fn detects_ssh_urls() {
+        assert!(is_ssh_url("git@github.com:owner/repo.git"));
+        assert!(is_ssh_url("ssh://git@github.com/owner/repo.git"));
+        assert!(!is_ssh_url("https://github.com/owner/repo.git"));
+        assert!(!is_ssh_url("/srv/git/repo.git"));
+        assert!(!is_ssh_url("file:///srv/git/repo.git"));
+    }
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        }
+    }
+
+    #[test]
+    fn github_token_is_only_offered_to_github() {
+        let vars = [("GITHUB_TOKEN", "gh-secret")];
+
+        assert_eq!(
+            token_for_url("https://github.com/owner/repo.git", env(&vars)).as_deref(),
+            Some("gh-secret")
+        );
+        assert_eq!(
+            token_for_url("https://gitlab.com/owner/repo.git", env(&vars)),
+            None
+        );
+        assert_eq!(
+            token_for_url("https://github.com.evil.example/owner/repo.git", env(&vars)),
+            None
+        );
+    }
+
+    #[test]
+    fn github_token_is_offered_to_the_configured_github_server() {
+        let vars = [
+            ("GITHUB_TOKEN", "gh-secret"),
+            ("GITHUB_SERVER_URL", "https://github.example.com"),
+        ];
+
+        assert_eq!(
+            token_for_url(
+                "https://x-access-token@github.example.com:443/o/r.git",
+                env(&vars)
+            )
+            .as_deref(),
+            Some("gh-secret")
+        );
+        assert_eq!(
+            token_for_url("https://gitea.example.com/o/r.git", env(&vars)),
+            None
+        );
+    }
+
+    #[test]
+    fn git_token_is_offered_to_any_host() {
+        let vars = [
+            ("CARGO_CHANGESET_GIT_TOKEN", "any-secret"),
+            ("GITHUB_TOKEN", "gh-secret"),
+        ];
+
+        assert_eq!(
+            token_for_url("https://gitlab.com/owner/repo.git", env(&vars)).as_deref(),
+            Some("any-secret")
+        );
+    }
+
+    #[test]
+    fn auth_failures_are_reported_separately() {
+        let auth = git2::Error::new(ErrorCode::Auth, ErrorClass::Http, "too many redirects");
+        let denied = git2::Error::new(
+            ErrorCode::GenericError,
+            ErrorClass::Ssh,
+            "git@github.com: Permission denied (publickey).",
+        );
+        let network = git2::Error::new(
+            ErrorCode::GenericError,
+            ErrorClass::Net,
+            "failed to resolve address for github.com",
+        );
+
+        assert!(matches!(
+            remote_error("origin", auth),
+            GitError::AuthenticationFailed { .. }
+        ));
+        assert!(matches!(
+            remote_error("origin", denied),
+            GitError::AuthenticationFailed { .. }
+        ));
+        assert!(matches!(
+            remote_error("origin", network),
+            GitError::FetchFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn credentials_are_offered_once_each() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        let config = repo.inner.config()?;
+        let mut attempts = CredentialAttempts::default();
+        let url = "https://example.com/owner/repo.git";
+        let allowed = CredentialType::USER_PASS_PLAINTEXT;
+
+        assert!(
+            attempts
+                .next(&config, Some("secret"), url, None, allowed)
+                .is_ok()
+        );
+
+        let mut exhausted = false;
+        for _ in 0..3 {
+            if let Err(error) = attempts.next(&config, Some("secret"), url, None, allowed) {
+                assert_eq!(error.code(), ErrorCode::Auth);
+                exhausted = true;
+                break;
+            }
+        }
+        assert!(exhausted);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_from_unreachable_remote_is_a_network_error() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let missing = dir.path().join("missing-remote.git");
+        repo.inner.remote("origin", &missing.to_string_lossy())?;
+
+        let result = repo.fetch("origin", &["main"]);
+
+        assert!(matches!(result, Err(GitError::FetchFailed { .. })));
+
+        Ok(())
+    }
+}
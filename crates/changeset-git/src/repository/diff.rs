@@ -13,51 +13,33 @@ impl Repository {
 
         let base_tree = base.map(|refspec| self.resolve_tree(refspec)).transpose()?;
 
-        let mut diff = self
+        let diff = self
             .inner
             .diff_tree_to_tree(base_tree.as_ref(), Some(&head_tree), None)?;
 
-        let mut find_opts = git2::DiffFindOptions::new();
-        find_opts.renames(true);
-        find_opts.copies(true);
-        find_opts.copies_from_unmodified(true);
-        diff.find_similar(Some(&mut find_opts))?;
-
-        let mut changes = Vec::new();
-
-        for delta in diff.deltas() {
-            let status = match delta.status() {
-                git2::Delta::Added => FileStatus::Added,
-                git2::Delta::Deleted => FileStatus::Deleted,
-                git2::Delta::Modified => FileStatus::Modified,
-                git2::Delta::Renamed => FileStatus::Renamed,
-                git2::Delta::Copied => FileStatus::Copied,
-                git2::Delta::Typechange => FileStatus::Typechange,
-                git2::Delta::Unmodified
-                | git2::Delta::Ignored
-                | git2::Delta::Untracked
-                | git2::Delta::Unreadable
-                | git2::Delta::Conflicted => continue,
-            };
-
-            let path = delta
-                .new_file()
-                .path()
-                .or_else(|| delta.old_file().path())
-                .map(PathBuf::from)
-                .ok_or(GitError::MissingDeltaPath)?;
-
-            let mut change = FileChange::new(path, status);
-
-            if status == FileStatus::Renamed || status == FileStatus::Copied {
-                let old_path = delta.old_file().path().ok_or(GitError::MissingDeltaPath)?;
-                change = change.with_old_path(old_path.to_path_buf());
-            }
-
-            changes.push(change);
-        }
+        collect_changes(diff)
+    }
+
+    /// Returns the files staged in the index relative to HEAD.
+    ///
+    /// When HEAD is unborn, every staged file is reported as added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be read or the diff fails.
+    pub fn staged_files(&self) -> Result<Vec<FileChange>> {
+        let head_tree = match self.inner.head() {
+            Ok(head) => Some(head.peel_to_tree()?),
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => None,
+            Err(e) => return Err(e.into()),
+        };
+        let index = self.inner.index()?;
+
+        let diff = self
+            .inner
+            .diff_tree_to_index(head_tree.as_ref(), Some(&index), None)?;
 
-        Ok(changes)
+        collect_changes(diff)
     }
 
     /// # Errors
@@ -67,7 +49,7 @@ impl Repository {
         self.changed_files(Some(base), "HEAD")
     }
 
-    fn resolve_tree(&self, refspec: &str) -> Result<git2::Tree<'_>> {
+    pub(super) fn resolve_tree(&self, refspec: &str) -> Result<git2::Tree<'_>> {
         let obj = self
             .inner
             .revparse_single(refspec)
@@ -83,6 +65,18 @@ impl Repository {
         })
     }
 
+    pub(super) fn resolve_commit(&self, refspec: &str) -> Result<git2::Oid> {
+        let obj = self
+            .inner
+            .revparse_single(refspec)
+            .or_else(|original_err| self.try_remote_tracking_ref(refspec).ok_or(original_err))
+            .map_err(|source| GitError::RefNotFound {
+                refspec: refspec.to_string(),
+                source,
+            })?;
+        Ok(obj.peel_to_commit()?.id())
+    }
+
     fn try_remote_tracking_ref(&self, refspec: &str) -> Option<git2::Object<'_>> {
         if !refspec.starts_with("refs/") && refspec.contains('/') {
             self.inner
@@ -94,6 +88,50 @@ impl Repository {
     }
 }
 
+fn collect_changes(mut diff: git2::Diff<'_>) -> Result<Vec<FileChange>> {
+    let mut find_opts = git2::DiffFindOptions::new();
+    find_opts.renames(true);
+    find_opts.copies(true);
+    find_opts.copies_from_unmodified(true);
+    diff.find_similar(Some(&mut find_opts))?;
+
+    let mut changes = Vec::new();
+
+    for delta in diff.deltas() {
+        let status = match delta.status() {
+            git2::Delta::Added => FileStatus::Added,
+            git2::Delta::Deleted => FileStatus::Deleted,
+            git2::Delta::Modified => FileStatus::Modified,
+            git2::Delta::Renamed => FileStatus::Renamed,
+            git2::Delta::Copied => FileStatus::Copied,
+            git2::Delta::Typechange => FileStatus::Typechange,
+            git2::Delta::Unmodified
+            | git2::Delta::Ignored
+            | git2::Delta::Untracked
+            | git2::Delta::Unreadable
+            | git2::Delta::Conflicted => continue,
+        };
+
+        let path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(PathBuf::from)
+            .ok_or(GitError::MissingDeltaPath)?;
+
+        let mut change = FileChange::new(path, status);
+
+        if status == FileStatus::Renamed || status == FileStatus::Copied {
+            let old_path = delta.old_file().path().ok_or(GitError::MissingDeltaPath)?;
+            change = change.with_old_path(old_path.to_path_buf());
+        }
+
+        changes.push(change);
+    }
+
+    Ok(changes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::tests::setup_test_repo;
@@ -372,6 +410,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn staged_files_reports_only_index_changes() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        fs::write(dir.path().join("staged.txt"), "staged")?;
+        fs::write(dir.path().join("unstaged.txt"), "unstaged")?;
+
+        let mut index = repo.inner.index()?;
+        index.add_path(std::path::Path::new("staged.txt"))?;
+        index.write()?;
+
+        let changes = repo.staged_files()?;
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].status, FileStatus::Added);
+        assert_eq!(changes[0].path, PathBuf::from("staged.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn staged_files_empty_when_nothing_staged() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        fs::write(dir.path().join("untracked.txt"), "content")?;
+
+        assert!(repo.staged_files()?.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn blob_ref_returns_not_a_tree_error() -> anyhow::Result<()> {
         let (_dir, repo) = setup_test_repo()?;
@@ -38,6 +38,54 @@ impl Repository {
         }
         Ok(())
     }
+
+    /// Returns the commit time, in seconds since the Unix epoch, of the most
+    /// recent commit reachable from HEAD that added `path`.
+    ///
+    /// Returns `None` if the file has never been committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD or the history cannot be read.
+    pub fn file_added_time(&self, path: &Path) -> Result<Option<i64>> {
+        let relative_path = self.to_relative_path(path);
+        let mut revwalk = self.inner.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+
+        for oid in revwalk {
+            let commit = self.inner.find_commit(oid?)?;
+            if commit.tree()?.get_path(&relative_path).is_err() {
+                continue;
+            }
+            let in_parent = match commit.parents().next() {
+                Some(parent) => parent.tree()?.get_path(&relative_path).is_ok(),
+                None => false,
+            };
+            if !in_parent {
+                return Ok(Some(commit.time().seconds()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the contents of `path` as of `refspec`.
+    ///
+    /// Returns `None` if the file does not exist at that revision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GitError::RefNotFound`] if the reference cannot be resolved,
+    /// or an error if the blob cannot be read.
+    pub fn file_at_ref(&self, refspec: &str, path: &Path) -> Result<Option<String>> {
+        let relative_path = self.to_relative_path(path);
+        let tree = self.resolve_tree(refspec)?;
+        let Ok(entry) = tree.get_path(&relative_path) else {
+            return Ok(None);
+        };
+        let blob = entry.to_object(&self.inner)?.peel_to_blob()?;
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
 }
 
 #[cfg(test)]
@@ -47,6 +95,73 @@ mod tests {
     use std::fs;
     use std::path::Path;
 
+    fn commit_all(repo: &super::Repository, message: &str) -> anyhow::Result<i64> {
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let mut index = repo.inner.index()?;
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree = repo.inner.find_tree(index.write_tree()?)?;
+        let parent = repo.inner.head()?.peel_to_commit()?;
+        let oid = repo
+            .inner
+            .commit(Some("HEAD"), &sig, &sig, message, &tree, &[&parent])?;
+        Ok(repo.inner.find_commit(oid)?.time().seconds())
+    }
+
+    #[test]
+    fn file_added_time_is_time_of_adding_commit() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        fs::write(dir.path().join("fix.md"), "first")?;
+        let added = commit_all(&repo, "Add changeset")?;
+        fs::write(dir.path().join("fix.md"), "edited")?;
+        commit_all(&repo, "Edit changeset")?;
+
+        assert_eq!(repo.file_added_time(Path::new("fix.md"))?, Some(added));
+        assert_eq!(
+            repo.file_added_time(&dir.path().join("fix.md"))?,
+            Some(added)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_added_time_is_none_for_uncommitted_file() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        fs::write(dir.path().join("new.md"), "content")?;
+
+        assert_eq!(repo.file_added_time(Path::new("new.md"))?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_at_ref_reads_committed_contents() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        fs::write(dir.path().join("fix.md"), "first")?;
+        commit_all(&repo, "Add changeset")?;
+        fs::write(dir.path().join("fix.md"), "edited")?;
+        commit_all(&repo, "Edit changeset")?;
+
+        assert_eq!(
+            repo.file_at_ref("HEAD~1", Path::new("fix.md"))?.as_deref(),
+            Some("first")
+        );
+        assert_eq!(
+            repo.file_at_ref("HEAD", Path::new("fix.md"))?.as_deref(),
+            Some("edited")
+        );
+        assert_eq!(repo.file_at_ref("HEAD~2", Path::new("fix.md"))?, None);
+        assert!(matches!(
+            repo.file_at_ref("no-such-ref", Path::new("fix.md")),
+            Err(GitError::RefNotFound { .. })
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn delete_single_file() -> anyhow::Result<()> {
         let (dir, repo) = setup_test_repo()?;
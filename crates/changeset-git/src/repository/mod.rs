@@ -1,12 +1,14 @@
 mod commit;
+mod credentials;
 mod diff;
 mod files;
 mod remote;
 mod staging;
+mod stash;
 mod status;
 mod tag;
 
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use crate::{GitError, Result};
 
@@ -16,16 +18,19 @@ pub struct Repository {
 }
 
 impl Repository {
+    /// Opens the repository containing `path`, which may be a linked worktree.
+    ///
     /// # Errors
     ///
-    /// Returns [`GitError::NotARepository`] if the path is not inside a git repository.
+    /// Returns [`GitError::NotARepository`] if the path is not inside a git repository,
+    /// or [`GitError::BareRepository`] if the repository has no working tree.
     pub fn open(path: &Path) -> Result<Self> {
         let inner = git2::Repository::discover(path).map_err(|_| GitError::NotARepository {
             path: path.to_path_buf(),
         })?;
 
-        let root = inner.workdir().ok_or_else(|| GitError::NotARepository {
-            path: path.to_path_buf(),
+        let root = inner.workdir().ok_or_else(|| GitError::BareRepository {
+            path: inner.path().to_path_buf(),
         })?;
 
         // Use dunce to get a path without the \\?\ prefix on Windows
@@ -39,17 +44,81 @@ impl Repository {
         &self.root
     }
 
+    /// Git directory of this checkout; `.git/worktrees/<name>` for a linked worktree.
+    #[must_use]
+    pub fn git_dir(&self) -> &Path {
+        self.inner.path()
+    }
+
+    /// Git directory shared by all worktrees, holding refs, config and hooks.
+    #[must_use]
+    pub fn common_dir(&self) -> &Path {
+        self.inner.commondir()
+    }
+
+    #[must_use]
+    pub fn is_worktree(&self) -> bool {
+        self.inner.is_worktree()
+    }
+
+    /// Returns the directory git reads hooks from, honoring `core.hooksPath`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository configuration cannot be read.
+    pub fn hooks_dir(&self) -> Result<PathBuf> {
+        let config = self.inner.config()?;
+        match config.get_path("core.hooksPath") {
+            Ok(path) if path.is_absolute() => Ok(path),
+            Ok(path) => Ok(self.root.join(path)),
+            Err(_) => Ok(self.common_dir().join("hooks")),
+        }
+    }
+
+    /// Sets `key` to `value` in the repository's local configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be opened or written.
+    pub fn set_config(&self, key: &str, value: &str) -> Result<()> {
+        let mut config = self.inner.config()?.open_level(git2::ConfigLevel::Local)?;
+        config.set_str(key, value)?;
+        Ok(())
+    }
+
+    /// Converts `path` into a clean path relative to the repository root.
+    ///
+    /// Removes `\\?\` prefixes and `.`/`..` components so the result is accepted
+    /// by the git index on every platform.
     pub(crate) fn to_relative_path(&self, path: &Path) -> PathBuf {
+        let path = normalize_components(dunce::simplified(path));
         if path.is_absolute() {
-            // Use dunce to normalize the path (removes \\?\ prefix on Windows)
-            let normalized = dunce::simplified(path);
-            normalized
-                .strip_prefix(&self.root)
-                .map_or_else(|_| path.to_path_buf(), Path::to_path_buf)
+            path.strip_prefix(&self.root)
+                .map_or_else(|_| path.clone(), Path::to_path_buf)
         } else {
-            path.to_path_buf()
+            path
+        }
+    }
+}
+
+/// Lexically resolves `.` and `..` components without touching the filesystem.
+fn normalize_components(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            other => normalized.push(other),
         }
     }
+    normalized
 }
 
 #[cfg(test)]
@@ -57,6 +126,18 @@ pub(crate) mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn normalize_components_resolves_dots() {
+        assert_eq!(
+            normalize_components(Path::new("./a/./b/../c")),
+            PathBuf::from("a/c")
+        );
+        assert_eq!(
+            normalize_components(Path::new("../../a")),
+            PathBuf::from("../../a")
+        );
+    }
+
     pub(crate) fn setup_test_repo() -> anyhow::Result<(TempDir, Repository)> {
         let dir = TempDir::new()?;
         let repo = git2::Repository::init(dir.path())?;
@@ -83,10 +164,120 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[test]
+    fn hooks_dir_defaults_to_git_dir() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        let hooks_dir = repo.hooks_dir()?;
+
+        assert_eq!(
+            hooks_dir.canonicalize()?,
+            dir.path().join(".git/hooks").canonicalize()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hooks_dir_honors_core_hooks_path() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.inner
+            .config()?
+            .set_str("core.hooksPath", "scripts/hooks")?;
+
+        let hooks_dir = repo.hooks_dir()?;
+
+        assert_eq!(hooks_dir, repo.root().join("scripts/hooks"));
+        Ok(())
+    }
+
+    #[test]
+    fn set_config_writes_local_configuration() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+
+        repo.set_config("merge.example.driver", "true %A")?;
+
+        let config = repo.inner.config()?;
+        assert_eq!(config.get_string("merge.example.driver")?, "true %A");
+        Ok(())
+    }
+
     #[test]
     fn open_nonexistent_repository() {
         let dir = TempDir::new().expect("failed to create temp dir");
         let result = Repository::open(dir.path());
         assert!(matches!(result, Err(GitError::NotARepository { .. })));
     }
+
+    #[test]
+    fn open_bare_repository_fails() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        git2::Repository::init_bare(dir.path())?;
+
+        let result = Repository::open(dir.path());
+
+        assert!(matches!(result, Err(GitError::BareRepository { .. })));
+        Ok(())
+    }
+
+    /// Adds a linked worktree of `repo` on a new branch named `name`.
+    fn add_worktree(repo: &Repository, name: &str) -> anyhow::Result<(TempDir, Repository)> {
+        let parent = TempDir::new()?;
+        let path = parent.path().join(name);
+        repo.inner.worktree(name, &path, None)?;
+        let worktree = Repository::open(&path)?;
+        Ok((parent, worktree))
+    }
+
+    #[test]
+    fn open_linked_worktree() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let (parent, worktree) = add_worktree(&repo, "feature")?;
+
+        assert!(worktree.is_worktree());
+        assert!(!repo.is_worktree());
+        assert_eq!(
+            worktree.root().canonicalize()?,
+            parent.path().join("feature").canonicalize()?
+        );
+        assert_eq!(
+            worktree.common_dir().canonicalize()?,
+            dir.path().join(".git").canonicalize()?
+        );
+        assert_eq!(
+            worktree.git_dir().canonicalize()?,
+            dir.path().join(".git/worktrees/feature").canonicalize()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hooks_dir_in_worktree_uses_common_dir() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let (_parent, worktree) = add_worktree(&repo, "feature")?;
+
+        assert_eq!(
+            worktree.hooks_dir()?.canonicalize()?,
+            dir.path().join(".git/hooks").canonicalize()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stage_and_commit_in_worktree() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        let (_parent, worktree) = add_worktree(&repo, "feature")?;
+
+        std::fs::write(worktree.root().join("file.txt"), "content")?;
+        assert!(!worktree.is_working_tree_clean()?);
+        assert!(repo.is_working_tree_clean()?);
+
+        worktree.stage_files(&[&worktree.root().join("file.txt")])?;
+        worktree.commit("Add file")?;
+
+        assert!(worktree.is_working_tree_clean()?);
+        assert_eq!(worktree.current_branch()?, "feature");
+        let head = repo.inner.find_branch("feature", git2::BranchType::Local)?;
+        assert_eq!(head.get().peel_to_commit()?.message(), Some("Add file"));
+        Ok(())
+    }
 }
@@ -1,28 +1,155 @@
-use crate::{Repository, Result};
+use crate::{GitError, Repository, Result};
 
 impl Repository {
+    /// Returns the URL of the remote chosen by [`Self::remote_name`].
+    ///
     /// # Errors
     ///
-    /// Returns an error if the remote lookup fails.
-    pub fn remote_url(&self) -> Result<Option<String>> {
-        let Ok(remote) = self.inner.find_remote("origin") else {
+    /// Returns an error if the remote lookup fails or `preferred` names a
+    /// remote that does not exist.
+    pub fn remote_url(&self, preferred: Option<&str>) -> Result<Option<String>> {
+        let Some(name) = self.remote_name(preferred)? else {
             return Ok(None);
         };
+        let remote = self.inner.find_remote(&name)?;
 
         Ok(remote.url().map(String::from))
     }
+
+    /// Picks the remote the repository publishes to.
+    ///
+    /// Uses `preferred` when given. Otherwise falls back to the upstream
+    /// remote of the current branch, then `origin`, then `upstream`, then the
+    /// first configured remote. Returns `Ok(None)` when there are no remotes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GitError::RemoteNotFound`] if `preferred` names a remote that
+    /// does not exist, or an error if the remotes cannot be listed.
+    pub fn remote_name(&self, preferred: Option<&str>) -> Result<Option<String>> {
+        let remotes = self.inner.remotes()?;
+        let names: Vec<&str> = remotes.iter().flatten().collect();
+
+        if let Some(preferred) = preferred {
+            if !names.contains(&preferred) {
+                return Err(GitError::RemoteNotFound {
+                    name: preferred.to_string(),
+                });
+            }
+            return Ok(Some(preferred.to_string()));
+        }
+
+        let upstream = self.current_branch().ok().and_then(|branch| {
+            let buf = self
+                .inner
+                .branch_upstream_remote(&format!("refs/heads/{branch}"))
+                .ok()?;
+            buf.as_str().map(String::from)
+        });
+
+        Ok(upstream
+            .filter(|name| names.contains(&name.as_str()))
+            .or_else(|| {
+                ["origin", "upstream"]
+                    .into_iter()
+                    .find(|name| names.contains(name))
+                    .map(String::from)
+            })
+            .or_else(|| names.first().map(|name| (*name).to_string())))
+    }
+
+    /// Whether the current branch tracks a remote branch, i.e. whether
+    /// [`Self::is_up_to_date`] needs to fetch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD is detached.
+    pub fn has_upstream(&self) -> Result<bool> {
+        let local_ref = format!("refs/heads/{}", self.current_branch()?);
+        Ok(self.inner.branch_upstream_remote(&local_ref).is_ok())
+    }
+
+    /// Fetches the upstream of the current branch and reports whether the local
+    /// branch contains every commit of its upstream.
+    ///
+    /// Returns `Ok(true)` when the current branch has no upstream configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD is detached, the fetch fails, or the upstream
+    /// reference cannot be resolved after fetching.
+    pub fn is_up_to_date(&self) -> Result<bool> {
+        let branch_name = self.current_branch()?;
+        let local_ref = format!("refs/heads/{branch_name}");
+
+        let Ok(remote_name) = self.inner.branch_upstream_remote(&local_ref) else {
+            return Ok(true);
+        };
+        let remote_name = remote_name.as_str().unwrap_or_default().to_string();
+        let merge_ref = self.inner.branch_upstream_merge(&local_ref)?;
+        let merge_ref = merge_ref.as_str().unwrap_or_default().to_string();
+
+        self.fetch(&remote_name, &[merge_ref.as_str()])?;
+
+        let local = self.inner.refname_to_id(&local_ref)?;
+        let upstream = self
+            .inner
+            .find_branch(&branch_name, git2::BranchType::Local)?
+            .upstream()?
+            .get()
+            .peel_to_commit()?
+            .id();
+
+        let (_, behind) = self.inner.graph_ahead_behind(local, upstream)?;
+        Ok(behind == 0)
+    }
+
+    /// Reports whether the HEAD commit or any of `tags` has reached a remote.
+    ///
+    /// The branches are checked against the remote-tracking branches, which
+    /// only reflect the remote as of the last fetch: a push made from another
+    /// clone since then goes unnoticed. The tags are looked up on the remote
+    /// chosen by [`Self::remote_name`] itself, so nothing is checked over the
+    /// network when `tags` is empty or no remote is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD or the remote-tracking branches cannot be
+    /// resolved, or the remote cannot be reached to list its tags.
+    pub fn is_head_pushed(&self, tags: &[String]) -> Result<bool> {
+        let head = self.inner.head()?.peel_to_commit()?.id();
+        for branch in self.inner.branches(Some(git2::BranchType::Remote))? {
+            let (branch, _) = branch?;
+            let Some(target) = branch.get().target() else {
+                continue;
+            };
+            if target == head || self.inner.graph_descendant_of(target, head)? {
+                return Ok(true);
+            }
+        }
+
+        if tags.is_empty() {
+            return Ok(false);
+        }
+        let Some(remote_name) = self.remote_name(None)? else {
+            return Ok(false);
+        };
+        let remote_tags = self.remote_tags(&remote_name)?;
+        Ok(tags.iter().any(|tag| remote_tags.contains(tag)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::repository::tests::setup_test_repo;
+    use tempfile::TempDir;
 
     #[test]
     fn remote_url_returns_none_when_no_remote() -> anyhow::Result<()> {
         let (_dir, repo) = setup_test_repo()?;
 
-        let url = repo.remote_url()?;
+        let url = repo.remote_url(None)?;
 
         assert!(url.is_none());
 
@@ -37,10 +164,182 @@ mod tests {
             .remote("origin", "https://github.com/owner/repo")?;
 
         let repository = Repository::open(dir.path())?;
-        let url = repository.remote_url()?;
+        let url = repository.remote_url(None)?;
+
+        assert_eq!(url.as_deref(), Some("https://github.com/owner/repo"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_url_uses_preferred_remote() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.inner
+            .remote("origin", "https://github.com/fork/repo")?;
+        repo.inner
+            .remote("upstream", "https://github.com/owner/repo")?;
+
+        let url = repo.remote_url(Some("upstream"))?;
 
         assert_eq!(url.as_deref(), Some("https://github.com/owner/repo"));
 
         Ok(())
     }
+
+    #[test]
+    fn remote_url_fails_for_unknown_preferred_remote() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.inner
+            .remote("origin", "https://github.com/owner/repo")?;
+
+        let result = repo.remote_url(Some("upstream"));
+
+        assert!(matches!(result, Err(GitError::RemoteNotFound { name }) if name == "upstream"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_name_falls_back_to_origin_then_upstream_then_first() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.inner.remote("mirror", "https://example.com/mirror")?;
+        assert_eq!(repo.remote_name(None)?.as_deref(), Some("mirror"));
+
+        repo.inner
+            .remote("upstream", "https://github.com/owner/repo")?;
+        assert_eq!(repo.remote_name(None)?.as_deref(), Some("upstream"));
+
+        repo.inner
+            .remote("origin", "https://github.com/fork/repo")?;
+        assert_eq!(repo.remote_name(None)?.as_deref(), Some("origin"));
+
+        Ok(())
+    }
+
+    fn setup_with_upstream() -> anyhow::Result<(TempDir, TempDir, Repository, String)> {
+        let (dir, repo) = setup_test_repo()?;
+        let remote_dir = TempDir::new()?;
+        git2::Repository::init_bare(remote_dir.path())?;
+
+        let branch = repo.current_branch()?;
+        let remote_url = remote_dir.path().to_string_lossy().to_string();
+        {
+            let mut remote = repo.inner.remote("origin", &remote_url)?;
+            let refspec = format!("refs/heads/{branch}:refs/heads/{branch}");
+            remote.push(&[refspec.as_str()], None)?;
+            remote.fetch(&[branch.as_str()], None, None)?;
+        }
+        repo.inner
+            .find_branch(&branch, git2::BranchType::Local)?
+            .set_upstream(Some(&format!("origin/{branch}")))?;
+
+        Ok((dir, remote_dir, repo, branch))
+    }
+
+    #[test]
+    fn has_upstream_only_when_tracking() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        assert!(!repo.has_upstream()?);
+
+        let (_dir, _remote_dir, repo, _branch) = setup_with_upstream()?;
+        assert!(repo.has_upstream()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_up_to_date_without_upstream() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+
+        assert!(repo.is_up_to_date()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_up_to_date_when_in_sync_with_upstream() -> anyhow::Result<()> {
+        let (_dir, _remote_dir, repo, _branch) = setup_with_upstream()?;
+
+        assert!(repo.is_up_to_date()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_up_to_date_false_when_behind_upstream() -> anyhow::Result<()> {
+        let (_dir, remote_dir, repo, branch) = setup_with_upstream()?;
+
+        let remote_repo = git2::Repository::open_bare(remote_dir.path())?;
+        let parent = remote_repo
+            .find_reference(&format!("refs/heads/{branch}"))?
+            .peel_to_commit()?;
+        let sig = git2::Signature::now("Other", "other@example.com")?;
+        remote_repo.commit(
+            Some(&format!("refs/heads/{branch}")),
+            &sig,
+            &sig,
+            "Remote commit",
+            &parent.tree()?,
+            &[&parent],
+        )?;
+
+        assert!(!repo.is_up_to_date()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_head_pushed_false_without_remote() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+
+        assert!(!repo.is_head_pushed(&["v1.0.0".to_string()])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_head_pushed_tracks_local_commits() -> anyhow::Result<()> {
+        let (dir, _remote_dir, repo, _branch) = setup_with_upstream()?;
+        assert!(repo.is_head_pushed(&[])?);
+
+        std::fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[std::path::Path::new("file.txt")])?;
+        repo.commit("Local commit")?;
+
+        assert!(!repo.is_head_pushed(&[])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_head_pushed_when_tag_is_on_remote() -> anyhow::Result<()> {
+        let (dir, _remote_dir, repo, _branch) = setup_with_upstream()?;
+        std::fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[std::path::Path::new("file.txt")])?;
+        repo.commit("Release commit")?;
+        repo.create_tag("v1.0.0", "Release 1.0.0")?;
+        let tags = ["v1.0.0".to_string()];
+        assert!(!repo.is_head_pushed(&tags)?);
+
+        repo.inner
+            .find_remote("origin")?
+            .push(&["refs/tags/v1.0.0:refs/tags/v1.0.0"], None)?;
+
+        assert!(repo.is_head_pushed(&tags)?);
+        assert!(!repo.is_head_pushed(&["v2.0.0".to_string()])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_name_prefers_upstream_of_current_branch() -> anyhow::Result<()> {
+        let (_dir, _remote_dir, repo, _branch) = setup_with_upstream()?;
+        repo.inner.remote_rename("origin", "company")?;
+        repo.inner
+            .remote("origin", "https://github.com/fork/repo")?;
+
+        assert_eq!(repo.remote_name(None)?.as_deref(), Some("company"));
+
+        Ok(())
+    }
 }
@@ -36,6 +36,16 @@ impl Repository {
 
         Ok(())
     }
+
+    /// Returns whether `path` is matched by the repository's ignore rules,
+    /// whether or not it is tracked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ignore rules cannot be read.
+    pub fn is_path_ignored(&self, path: &Path) -> Result<bool> {
+        Ok(self.inner.is_path_ignored(self.to_relative_path(path))?)
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +84,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn stage_normalizes_dot_components() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        fs::create_dir_all(dir.path().join("sub"))?;
+        fs::write(dir.path().join("file1.txt"), "content1")?;
+        fs::write(dir.path().join("sub/file2.txt"), "content2")?;
+
+        let absolute = dir.path().join("sub").join(".").join("file2.txt");
+        repo.stage_files(&[Path::new("./sub/../file1.txt"), &absolute])?;
+
+        let index = repo.inner.index()?;
+        assert!(index.get_path(Path::new("file1.txt"), 0).is_some());
+        assert!(index.get_path(Path::new("sub/file2.txt"), 0).is_some());
+
+        Ok(())
+    }
+
     #[test]
     fn stage_all_files() -> anyhow::Result<()> {
         let (dir, repo) = setup_test_repo()?;
@@ -113,4 +141,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn ignored_paths_follow_gitignore() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+
+        fs::write(dir.path().join(".gitignore"), "target/\n*.generated.rs\n")?;
+
+        assert!(repo.is_path_ignored(Path::new("crates/a/target/debug/out"))?);
+        assert!(repo.is_path_ignored(&dir.path().join("src/schema.generated.rs"))?);
+        assert!(!repo.is_path_ignored(Path::new("src/lib.rs"))?);
+
+        Ok(())
+    }
 }
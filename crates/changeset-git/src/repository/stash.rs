@@ -0,0 +1,73 @@
+use crate::Result;
+
+use super::Repository;
+
+impl Repository {
+    /// Stashes all local changes, including untracked files, and cleans the
+    /// working tree.
+    ///
+    /// Returns `false` when there was nothing to stash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no signature is configured or the stash cannot be saved.
+    pub fn stash_push(&mut self, message: &str) -> Result<bool> {
+        let sig = self.inner.signature()?;
+        match self.inner.stash_save2(
+            &sig,
+            Some(message),
+            Some(git2::StashFlags::INCLUDE_UNTRACKED),
+        ) {
+            Ok(_) => Ok(true),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Applies the most recent stash, restoring its index, and drops it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no stash or it conflicts with the working tree.
+    pub fn stash_pop(&mut self) -> Result<()> {
+        let mut options = git2::StashApplyOptions::new();
+        options.reinstantiate_index();
+        self.inner.stash_pop(0, Some(&mut options))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::super::tests::setup_test_repo;
+
+    #[test]
+    fn stash_push_and_pop_round_trip() -> anyhow::Result<()> {
+        let (dir, mut repo) = setup_test_repo()?;
+        fs::write(dir.path().join("staged.txt"), "staged")?;
+        repo.stage_files(&[Path::new("staged.txt")])?;
+        fs::write(dir.path().join("untracked.txt"), "new")?;
+
+        assert!(repo.stash_push("release")?);
+        assert!(repo.is_working_tree_clean()?);
+        assert!(!dir.path().join("untracked.txt").exists());
+
+        repo.stash_pop()?;
+        assert_eq!(fs::read_to_string(dir.path().join("staged.txt"))?, "staged");
+        let staged = repo.staged_files()?;
+        assert_eq!(staged.len(), 1);
+        assert_eq!(staged[0].path, Path::new("staged.txt"));
+        assert_eq!(fs::read_to_string(dir.path().join("untracked.txt"))?, "new");
+        Ok(())
+    }
+
+    #[test]
+    fn stash_push_on_clean_tree_stashes_nothing() -> anyhow::Result<()> {
+        let (_dir, mut repo) = setup_test_repo()?;
+        assert!(!repo.stash_push("release")?);
+        Ok(())
+    }
+}
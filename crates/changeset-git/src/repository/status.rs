@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{GitError, Result};
 
 use super::Repository;
@@ -22,13 +24,44 @@ impl Repository {
     ///
     /// Returns an error if the git status operation fails.
     pub fn is_working_tree_clean(&self) -> Result<bool> {
-        let statuses = self.inner.statuses(Some(
+        self.has_no_changes(false)
+    }
+
+    /// Like [`Self::is_working_tree_clean`], but ignores submodules whose
+    /// checked-out commit or content differs from the recorded one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the git status operation fails.
+    pub fn is_working_tree_clean_ignoring_submodules(&self) -> Result<bool> {
+        self.has_no_changes(true)
+    }
+
+    /// Paths, relative to the repository root, of every modified, staged or
+    /// untracked file, in the order git reports them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the git status operation fails.
+    pub fn dirty_files(&self, exclude_submodules: bool) -> Result<Vec<PathBuf>> {
+        let statuses = self.statuses(exclude_submodules)?;
+        Ok(statuses
+            .iter()
+            .filter_map(|entry| entry.path().map(PathBuf::from))
+            .collect())
+    }
+
+    fn has_no_changes(&self, exclude_submodules: bool) -> Result<bool> {
+        Ok(self.statuses(exclude_submodules)?.is_empty())
+    }
+
+    fn statuses(&self, exclude_submodules: bool) -> Result<git2::Statuses<'_>> {
+        Ok(self.inner.statuses(Some(
             git2::StatusOptions::new()
                 .include_untracked(true)
-                .recurse_untracked_dirs(true),
-        ))?;
-
-        Ok(statuses.is_empty())
+                .recurse_untracked_dirs(true)
+                .exclude_submodules(exclude_submodules),
+        ))?)
     }
 
     /// # Errors
@@ -48,6 +81,9 @@ mod tests {
     use super::super::tests::setup_test_repo;
     use crate::GitError;
     use std::fs;
+    use std::path::Path;
+
+    use super::super::Repository;
 
     #[test]
     fn current_branch_on_main() -> anyhow::Result<()> {
@@ -72,6 +108,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dirty_files_lists_modified_and_untracked() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        fs::write(dir.path().join("new_file.txt"), "content")?;
+        fs::create_dir_all(dir.path().join("nested"))?;
+        fs::write(dir.path().join("nested/other.txt"), "content")?;
+
+        let mut files = repo.dirty_files(false)?;
+        files.sort();
+
+        assert_eq!(
+            files,
+            vec![
+                Path::new("nested/other.txt").to_path_buf(),
+                Path::new("new_file.txt").to_path_buf(),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Adds a submodule at `sub` pointing to a fresh repository with one commit.
+    fn add_submodule(repo: &Repository) -> anyhow::Result<git2::Repository> {
+        let upstream_dir = tempfile::TempDir::new()?;
+        let upstream = upstream_dir.path();
+        let upstream_repo = git2::Repository::init(upstream)?;
+        fs::write(upstream.join("lib.txt"), "v1")?;
+        commit_all(&upstream_repo, "Initial")?;
+
+        let url = upstream.to_str().expect("utf-8 path");
+        let mut submodule = repo.inner.submodule(url, Path::new("sub"), true)?;
+        let sub_repo = submodule.clone(None)?;
+        submodule.add_finalize()?;
+        commit_all(&repo.inner, "Add submodule")?;
+        Ok(sub_repo)
+    }
+
+    fn commit_all(repo: &git2::Repository, message: &str) -> anyhow::Result<()> {
+        let mut index = repo.index()?;
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let parents = repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_commit().ok())
+            .into_iter()
+            .collect::<Vec<_>>();
+        let parents: Vec<&git2::Commit<'_>> = parents.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)?;
+        Ok(())
+    }
+
+    #[test]
+    fn submodule_changes_can_be_ignored() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let sub_repo = add_submodule(&repo)?;
+        assert!(repo.is_working_tree_clean()?);
+
+        fs::write(dir.path().join("sub/lib.txt"), "v2")?;
+        commit_all(&sub_repo, "Update")?;
+
+        assert!(!repo.is_working_tree_clean()?);
+        assert!(repo.is_working_tree_clean_ignoring_submodules()?);
+
+        fs::write(dir.path().join("new_file.txt"), "content")?;
+        assert!(!repo.is_working_tree_clean_ignoring_submodules()?);
+        Ok(())
+    }
+
     #[test]
     fn require_clean_fails_on_dirty() -> anyhow::Result<()> {
         let (dir, repo) = setup_test_repo()?;
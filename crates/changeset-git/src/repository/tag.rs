@@ -22,15 +22,115 @@ impl Repository {
         }
     }
 
+    /// Returns the names of all tags in the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag list cannot be read.
+    pub fn list_tags(&self) -> Result<Vec<String>> {
+        let names = self.inner.tag_names(None)?;
+        Ok(names.iter().flatten().map(String::from).collect())
+    }
+
     /// # Errors
     ///
     /// Returns an error if the tag cannot be created or already exists.
     pub fn create_tag(&self, name: &str, message: &str) -> Result<TagInfo> {
         let head = self.inner.head()?.peel_to_commit()?;
+        self.tag_commit(name, message, &head)
+    }
+
+    /// Creates an annotated tag pointing at the commit `target_sha` instead of HEAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `target_sha` is not a commit, or the tag cannot be
+    /// created or already exists.
+    pub fn create_tag_at(&self, name: &str, message: &str, target_sha: &str) -> Result<TagInfo> {
+        let target = self.inner.find_commit(git2::Oid::from_str(target_sha)?)?;
+        self.tag_commit(name, message, &target)
+    }
+
+    fn tag_commit(&self, name: &str, message: &str, target: &git2::Commit<'_>) -> Result<TagInfo> {
         let sig = self.inner.signature()?;
 
         self.inner
-            .tag(name, head.as_object(), &sig, message, false)?;
+            .tag(name, target.as_object(), &sig, message, false)?;
+
+        Ok(TagInfo {
+            name: name.to_string(),
+            target_sha: target.id().to_string(),
+        })
+    }
+
+    /// Returns the names of the tags pointing at the HEAD commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HEAD or the tags cannot be resolved.
+    pub fn tags_at_head(&self) -> Result<Vec<String>> {
+        let head = self.inner.head()?.peel_to_commit()?.id();
+        let mut tags = Vec::new();
+        for name in self.list_tags()? {
+            let target = self
+                .inner
+                .find_reference(&format!("refs/tags/{name}"))?
+                .peel_to_commit()?
+                .id();
+            if target == head {
+                tags.push(name);
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Returns the commit times, in seconds since the Unix epoch, of the
+    /// distinct commits that tags point at.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a tag cannot be resolved to a commit.
+    pub fn tagged_commit_times(&self) -> Result<Vec<i64>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut times = Vec::new();
+        for name in self.list_tags()? {
+            let commit = self
+                .inner
+                .find_reference(&format!("refs/tags/{name}"))?
+                .peel_to_commit()?;
+            if seen.insert(commit.id()) {
+                times.push(commit.time().seconds());
+            }
+        }
+        Ok(times)
+    }
+
+    /// Points an existing tag at HEAD, annotated with `message` when given and
+    /// keeping the message of an annotated tag otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag does not exist or cannot be replaced.
+    pub fn move_tag(&self, name: &str, message: Option<&str>) -> Result<TagInfo> {
+        let reference = self.inner.find_reference(&format!("refs/tags/{name}"))?;
+        let message = message.map(str::to_string).or_else(|| {
+            reference
+                .peel_to_tag()
+                .ok()
+                .map(|tag| tag.message().unwrap_or_default().to_string())
+        });
+        let head = self.inner.head()?.peel_to_commit()?;
+
+        match message {
+            Some(message) => {
+                let sig = self.inner.signature()?;
+                self.inner
+                    .tag(name, head.as_object(), &sig, &message, true)?;
+            }
+            None => {
+                self.inner.tag_lightweight(name, head.as_object(), true)?;
+            }
+        }
 
         Ok(TagInfo {
             name: name.to_string(),
@@ -42,6 +142,8 @@ impl Repository {
 #[cfg(test)]
 mod tests {
     use super::super::tests::setup_test_repo;
+    use std::fs;
+    use std::path::Path;
 
     #[test]
     fn create_annotated_tag() -> anyhow::Result<()> {
@@ -74,6 +176,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn create_tag_at_targets_given_commit() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        let first = repo.inner.head()?.peel_to_commit()?.id().to_string();
+        fs::write(dir.path().join("second.txt"), "second")?;
+        repo.stage_files(&[Path::new("second.txt")])?;
+        repo.commit("Second commit")?;
+
+        let tag_info = repo.create_tag_at("v1.0.0", "Release 1.0.0", &first)?;
+
+        assert_eq!(tag_info.target_sha, first);
+        let tagged = repo
+            .inner
+            .find_reference("refs/tags/v1.0.0")?
+            .peel_to_commit()?
+            .id()
+            .to_string();
+        assert_eq!(tagged, first);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tagged_commit_times_counts_each_commit_once() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.create_tag("a@v1.0.0", "Release a 1.0.0")?;
+        repo.create_tag("b@v1.0.0", "Release b 1.0.0")?;
+
+        let head_time = repo.inner.head()?.peel_to_commit()?.time().seconds();
+
+        assert_eq!(repo.tagged_commit_times()?, vec![head_time]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_tags_returns_all_tag_names() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.create_tag("v1.0.0", "Release 1.0.0")?;
+        repo.create_tag("my-crate@v0.2.0", "Release my-crate 0.2.0")?;
+
+        let mut tags = repo.list_tags()?;
+        tags.sort();
+
+        assert_eq!(tags, vec!["my-crate@v0.2.0", "v1.0.0"]);
+
+        Ok(())
+    }
+
     #[test]
     fn duplicate_tag_fails() -> anyhow::Result<()> {
         let (_dir, repo) = setup_test_repo()?;
@@ -111,4 +262,55 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn tags_at_head_skips_older_commits() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        repo.create_tag("v1.0.0", "Release 1.0.0")?;
+        fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[Path::new("file.txt")])?;
+        repo.commit("Release 1.1.0")?;
+        repo.create_tag("v1.1.0", "Release 1.1.0")?;
+
+        assert_eq!(repo.tags_at_head()?, vec!["v1.1.0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_tag_points_at_head_and_keeps_message() -> anyhow::Result<()> {
+        let (dir, repo) = setup_test_repo()?;
+        repo.create_tag("v1.0.0", "Release 1.0.0")?;
+        fs::write(dir.path().join("file.txt"), "content")?;
+        repo.stage_files(&[Path::new("file.txt")])?;
+        let commit = repo.commit("Amended release")?;
+
+        let moved = repo.move_tag("v1.0.0", None)?;
+
+        assert_eq!(moved.target_sha, commit.sha);
+        let tag = repo
+            .inner
+            .find_reference("refs/tags/v1.0.0")?
+            .peel_to_tag()?;
+        assert_eq!(tag.target_id().to_string(), commit.sha);
+        assert_eq!(tag.message(), Some("Release 1.0.0"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_tag_replaces_message_when_given() -> anyhow::Result<()> {
+        let (_dir, repo) = setup_test_repo()?;
+        repo.create_tag("v1.0.0", "Release 1.0.0")?;
+
+        repo.move_tag("v1.0.0", Some("Release 1.0.0\n\n- Late fix\n"))?;
+
+        let tag = repo
+            .inner
+            .find_reference("refs/tags/v1.0.0")?
+            .peel_to_tag()?;
+        assert_eq!(tag.message(), Some("Release 1.0.0\n\n- Late fix\n"));
+
+        Ok(())
+    }
 }
@@ -45,3 +45,18 @@ pub struct CommitInfo {
     pub sha: String,
     pub message: String,
 }
+
+/// A name and email recorded on a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+/// Author and committer of a commit; `None` falls back to the repository's
+/// `user.name` and `user.email`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitIdentity {
+    pub author: Option<Identity>,
+    pub committer: Option<Identity>,
+}
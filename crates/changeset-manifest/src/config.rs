@@ -4,6 +4,15 @@ pub enum MetadataSection {
     Package,
 }
 
+impl MetadataSection {
+    pub(crate) const fn root_key(self) -> &'static str {
+        match self {
+            Self::Workspace => "workspace",
+            Self::Package => "package",
+        }
+    }
+}
+
 impl std::fmt::Display for MetadataSection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -22,6 +31,12 @@ pub enum TagFormat {
 }
 
 impl TagFormat {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        [Self::VersionOnly, Self::CratePrefixed]
+            .into_iter()
+            .find(|variant| variant.as_str() == value)
+    }
+
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -46,6 +61,12 @@ pub enum ChangelogLocation {
 }
 
 impl ChangelogLocation {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        [Self::Root, Self::PerPackage]
+            .into_iter()
+            .find(|variant| variant.as_str() == value)
+    }
+
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -71,6 +92,12 @@ pub enum ComparisonLinks {
 }
 
 impl ComparisonLinks {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        [Self::Auto, Self::Enabled, Self::Disabled]
+            .into_iter()
+            .find(|variant| variant.as_str() == value)
+    }
+
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -96,6 +123,12 @@ pub enum ZeroVersionBehavior {
 }
 
 impl ZeroVersionBehavior {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        [Self::EffectiveMinor, Self::AutoPromoteOnMajor]
+            .into_iter()
+            .find(|variant| variant.as_str() == value)
+    }
+
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -133,4 +166,57 @@ impl InitConfig {
             && self.comparison_links.is_none()
             && self.zero_version_behavior.is_none()
     }
+
+    /// The set options as manifest keys and TOML-formatted values, in the order
+    /// they are written.
+    #[must_use]
+    pub fn entries(&self) -> Vec<(&'static str, String)> {
+        let quoted = |value: &str| format!("\"{value}\"");
+        [
+            ("commit", self.commit.map(|v| v.to_string())),
+            ("tags", self.tags.map(|v| v.to_string())),
+            (
+                "keep-changesets",
+                self.keep_changesets.map(|v| v.to_string()),
+            ),
+            ("tag-format", self.tag_format.map(|v| quoted(v.as_str()))),
+            ("changelog", self.changelog.map(|v| quoted(v.as_str()))),
+            (
+                "comparison-links",
+                self.comparison_links.map(|v| quoted(v.as_str())),
+            ),
+            (
+                "zero-version-behavior",
+                self.zero_version_behavior.map(|v| quoted(v.as_str())),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|value| (key, value)))
+        .collect()
+    }
+}
+
+/// Option names written by earlier versions of `init`, paired with their
+/// current spelling. The old spellings are not read by the configuration
+/// parser; `init --upgrade` renames them.
+pub const DEPRECATED_KEYS: &[(&str, &str)] = &[
+    ("keep_changesets", "keep-changesets"),
+    ("tag_format", "tag-format"),
+    ("comparison_links", "comparison-links"),
+    ("zero_version_behavior", "zero-version-behavior"),
+];
+
+/// A deprecated option name found in a manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecatedKey {
+    pub key: &'static str,
+    pub replacement: &'static str,
+}
+
+/// The init-managed options currently present in a manifest's metadata section.
+#[derive(Debug, Clone, Default)]
+pub struct ExistingMetadata {
+    /// Current values, reading deprecated spellings where the current one is absent.
+    pub config: InitConfig,
+    pub deprecated_keys: Vec<DeprecatedKey>,
 }
@@ -4,14 +4,17 @@ mod reader;
 mod writer;
 
 pub use config::{
-    ChangelogLocation, ComparisonLinks, InitConfig, MetadataSection, TagFormat, ZeroVersionBehavior,
+    ChangelogLocation, ComparisonLinks, DEPRECATED_KEYS, DeprecatedKey, ExistingMetadata,
+    InitConfig, MetadataSection, TagFormat, ZeroVersionBehavior,
 };
 pub use error::ManifestError;
 pub use reader::{
-    has_inherited_version, has_workspace_package_version, read_document, read_version,
-    read_workspace_version,
+    has_inherited_version, has_workspace_package_version, parse_document, read_document,
+    read_metadata_section, read_version, read_workspace_version,
 };
 pub use writer::{
-    remove_workspace_version, update_dependency_version, verify_version, write_metadata_section,
+    add_workspace_member, remove_workspace_version, render_dependency_version,
+    render_package_manifest, render_version, render_without_workspace_version,
+    update_dependency_version, upgrade_metadata_section, verify_version, write_metadata_section,
     write_version, write_workspace_version,
 };
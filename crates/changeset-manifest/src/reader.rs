@@ -1,8 +1,12 @@
 use std::path::Path;
 
 use semver::Version;
-use toml_edit::DocumentMut;
+use toml_edit::{DocumentMut, Item};
 
+use crate::config::{
+    ChangelogLocation, ComparisonLinks, DEPRECATED_KEYS, DeprecatedKey, ExistingMetadata,
+    InitConfig, MetadataSection, TagFormat, ZeroVersionBehavior,
+};
 use crate::error::ManifestError;
 
 /// # Errors
@@ -15,6 +19,15 @@ pub fn read_document(path: &Path) -> Result<DocumentMut, ManifestError> {
         source,
     })?;
 
+    parse_document(path, &content)
+}
+
+/// Parses manifest content that was read from `path`; the path is only used in errors.
+///
+/// # Errors
+///
+/// Returns `ManifestError::Parse` if the TOML is malformed.
+pub fn parse_document(path: &Path, content: &str) -> Result<DocumentMut, ManifestError> {
     content
         .parse::<DocumentMut>()
         .map_err(|source| ManifestError::Parse {
@@ -150,6 +163,64 @@ pub fn read_workspace_version(path: &Path) -> Result<Version, ManifestError> {
     })
 }
 
+/// Reads the init-managed options from the changeset metadata section.
+///
+/// Values under deprecated option names are used when the current name is
+/// absent, and every deprecated name found is reported. Values that are not
+/// valid for their option are treated as unset.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or parsed.
+pub fn read_metadata_section(
+    path: &Path,
+    section: MetadataSection,
+) -> Result<ExistingMetadata, ManifestError> {
+    let doc = read_document(path)?;
+
+    let Some(table) = doc
+        .get(section.root_key())
+        .and_then(|root| root.get("metadata"))
+        .and_then(|metadata| metadata.get("changeset"))
+        .and_then(Item::as_table_like)
+    else {
+        return Ok(ExistingMetadata::default());
+    };
+
+    let deprecated_keys: Vec<DeprecatedKey> = DEPRECATED_KEYS
+        .iter()
+        .filter(|(key, _)| table.contains_key(key))
+        .map(|&(key, replacement)| DeprecatedKey { key, replacement })
+        .collect();
+
+    let get = |key: &str| {
+        table.get(key).or_else(|| {
+            DEPRECATED_KEYS
+                .iter()
+                .find(|(_, replacement)| *replacement == key)
+                .and_then(|(deprecated, _)| table.get(deprecated))
+        })
+    };
+    let get_bool = |key: &str| get(key).and_then(Item::as_bool);
+    let get_str = |key: &str| get(key).and_then(Item::as_str);
+
+    let config = InitConfig {
+        commit: get_bool("commit"),
+        tags: get_bool("tags"),
+        keep_changesets: get_bool("keep-changesets"),
+        tag_format: get_str("tag-format").and_then(TagFormat::parse),
+        changelog: get_str("changelog").and_then(ChangelogLocation::parse),
+        comparison_links: get_str("comparison-links").and_then(ComparisonLinks::parse),
+        zero_version_behavior: get_str("zero-version-behavior")
+            .and_then(ZeroVersionBehavior::parse),
+    };
+
+    Ok(ExistingMetadata {
+        config,
+        deprecated_keys,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +300,64 @@ edition = "2021"
 
         assert!(!has_workspace_package_version(&path).expect("check workspace version"));
     }
+
+    #[test]
+    fn read_metadata_section_falls_back_to_deprecated_keys() {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+commit = false
+tag_format = "crate-prefixed"
+comparison-links = "enabled"
+comparison_links = "disabled"
+changelog = "bogus"
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let existing =
+            read_metadata_section(&path, MetadataSection::Workspace).expect("read metadata");
+
+        assert_eq!(existing.config.commit, Some(false));
+        assert_eq!(existing.config.tag_format, Some(TagFormat::CratePrefixed));
+        assert_eq!(
+            existing.config.comparison_links,
+            Some(ComparisonLinks::Enabled)
+        );
+        assert_eq!(existing.config.changelog, None);
+        assert_eq!(
+            existing.deprecated_keys,
+            vec![
+                DeprecatedKey {
+                    key: "tag_format",
+                    replacement: "tag-format"
+                },
+                DeprecatedKey {
+                    key: "comparison_links",
+                    replacement: "comparison-links"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn read_metadata_section_missing_section_is_empty() {
+        let toml = r#"
+[package]
+name = "test-crate"
+version = "1.0.0"
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let existing =
+            read_metadata_section(&path, MetadataSection::Package).expect("read metadata");
+
+        assert!(existing.config.is_empty());
+        assert!(existing.deprecated_keys.is_empty());
+    }
 }
@@ -1,11 +1,12 @@
 use std::path::Path;
 
+use changeset_core::atomic_write;
 use semver::Version;
-use toml_edit::{Item, Table, value};
+use toml_edit::{Array, DocumentMut, Item, Table, value};
 
-use crate::config::{InitConfig, MetadataSection};
+use crate::config::{DEPRECATED_KEYS, DeprecatedKey, InitConfig, MetadataSection};
 use crate::error::ManifestError;
-use crate::reader::{read_document, read_version};
+use crate::reader::{parse_document, read_document, read_version};
 
 const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
 
@@ -14,7 +15,30 @@ const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "dev-dependencies", "bui
 /// Returns an error if the manifest cannot be read, parsed, or written.
 pub fn write_version(path: &Path, version: &Version) -> Result<(), ManifestError> {
     let mut doc = read_document(path)?;
+    set_package_version(&mut doc, path, version)?;
+    write_document(path, &doc)
+}
 
+/// Returns `content` with the package version set, as [`write_version`] would write it.
+///
+/// # Errors
+///
+/// Returns an error if the content cannot be parsed or has no `[package]` table.
+pub fn render_version(
+    path: &Path,
+    content: &str,
+    version: &Version,
+) -> Result<String, ManifestError> {
+    let mut doc = parse_document(path, content)?;
+    set_package_version(&mut doc, path, version)?;
+    Ok(doc.to_string())
+}
+
+fn set_package_version(
+    doc: &mut DocumentMut,
+    path: &Path,
+    version: &Version,
+) -> Result<(), ManifestError> {
     let package = doc
         .get_mut("package")
         .ok_or_else(|| ManifestError::MissingField {
@@ -30,11 +54,7 @@ pub fn write_version(path: &Path, version: &Version) -> Result<(), ManifestError
         })?;
 
     package_table.insert("version", value(version.to_string()));
-
-    std::fs::write(path, doc.to_string()).map_err(|source| ManifestError::Write {
-        path: path.to_path_buf(),
-        source,
-    })
+    Ok(())
 }
 
 /// # Errors
@@ -42,26 +62,37 @@ pub fn write_version(path: &Path, version: &Version) -> Result<(), ManifestError
 /// Returns an error if the manifest cannot be read, parsed, or written.
 pub fn remove_workspace_version(path: &Path) -> Result<(), ManifestError> {
     let mut doc = read_document(path)?;
+    if unset_workspace_version(&mut doc) {
+        write_document(path, &doc)?;
+    }
+    Ok(())
+}
 
-    let Some(workspace) = doc.get_mut("workspace") else {
-        return Ok(());
-    };
-
-    let Some(workspace_table) = workspace.as_table_like_mut() else {
-        return Ok(());
-    };
-
-    let Some(package) = workspace_table.get_mut("package") else {
-        return Ok(());
-    };
-
-    let Some(package_table) = package.as_table_like_mut() else {
-        return Ok(());
-    };
+/// Returns `content` without the workspace package version, as
+/// [`remove_workspace_version`] would write it.
+///
+/// # Errors
+///
+/// Returns an error if the content cannot be parsed.
+pub fn render_without_workspace_version(
+    path: &Path,
+    content: &str,
+) -> Result<String, ManifestError> {
+    let mut doc = parse_document(path, content)?;
+    unset_workspace_version(&mut doc);
+    Ok(doc.to_string())
+}
 
-    package_table.remove("version");
+fn unset_workspace_version(doc: &mut DocumentMut) -> bool {
+    doc.get_mut("workspace")
+        .and_then(Item::as_table_like_mut)
+        .and_then(|workspace| workspace.get_mut("package"))
+        .and_then(Item::as_table_like_mut)
+        .is_some_and(|package| package.remove("version").is_some())
+}
 
-    std::fs::write(path, doc.to_string()).map_err(|source| ManifestError::Write {
+fn write_document(path: &Path, doc: &DocumentMut) -> Result<(), ManifestError> {
+    atomic_write(path, doc.to_string()).map_err(|source| ManifestError::Write {
         path: path.to_path_buf(),
         source,
     })
@@ -103,7 +134,7 @@ pub fn write_workspace_version(path: &Path, version: &Version) -> Result<(), Man
 
     package_table.insert("version", value(version.to_string()));
 
-    std::fs::write(path, doc.to_string()).map_err(|source| ManifestError::Write {
+    atomic_write(path, doc.to_string()).map_err(|source| ManifestError::Write {
         path: path.to_path_buf(),
         source,
     })
@@ -142,49 +173,16 @@ pub fn write_metadata_section(
     }
 
     let mut doc = read_document(path)?;
-
-    let root_key = match section {
-        MetadataSection::Workspace => "workspace",
-        MetadataSection::Package => "package",
-    };
-
-    let root = doc
-        .entry(root_key)
-        .or_insert_with(|| Item::Table(Table::new()));
-
-    let root_table = root
-        .as_table_mut()
-        .ok_or_else(|| ManifestError::InvalidSectionType {
-            path: path.to_path_buf(),
-            section: root_key.to_string(),
-        })?;
-
-    let metadata = root_table
-        .entry("metadata")
-        .or_insert_with(|| Item::Table(Table::new()));
-
-    let metadata_table =
-        metadata
-            .as_table_mut()
-            .ok_or_else(|| ManifestError::InvalidSectionType {
-                path: path.to_path_buf(),
-                section: format!("{root_key}.metadata"),
-            })?;
-
-    let changeset = metadata_table
-        .entry("changeset")
-        .or_insert_with(|| Item::Table(Table::new()));
-
-    let changeset_table =
-        changeset
-            .as_table_mut()
-            .ok_or_else(|| ManifestError::InvalidSectionType {
-                path: path.to_path_buf(),
-                section: format!("{root_key}.metadata.changeset"),
-            })?;
+    let changeset_table = metadata_table(&mut doc, path, section)?;
 
     changeset_table.set_implicit(true);
 
+    for (key, _) in config.entries() {
+        if let Some((deprecated, _)) = DEPRECATED_KEYS.iter().find(|(_, current)| *current == key) {
+            changeset_table.remove(deprecated);
+        }
+    }
+
     if let Some(commit) = config.commit {
         changeset_table.insert("commit", value(commit));
     }
@@ -194,11 +192,11 @@ pub fn write_metadata_section(
     }
 
     if let Some(keep_changesets) = config.keep_changesets {
-        changeset_table.insert("keep_changesets", value(keep_changesets));
+        changeset_table.insert("keep-changesets", value(keep_changesets));
     }
 
     if let Some(tag_format) = config.tag_format {
-        changeset_table.insert("tag_format", value(tag_format.as_str()));
+        changeset_table.insert("tag-format", value(tag_format.as_str()));
     }
 
     if let Some(changelog) = config.changelog {
@@ -206,27 +204,193 @@ pub fn write_metadata_section(
     }
 
     if let Some(comparison_links) = config.comparison_links {
-        changeset_table.insert("comparison_links", value(comparison_links.as_str()));
+        changeset_table.insert("comparison-links", value(comparison_links.as_str()));
     }
 
     if let Some(zero_version_behavior) = config.zero_version_behavior {
         changeset_table.insert(
-            "zero_version_behavior",
+            "zero-version-behavior",
             value(zero_version_behavior.as_str()),
         );
     }
 
-    std::fs::write(path, doc.to_string()).map_err(|source| ManifestError::Write {
+    atomic_write(path, doc.to_string()).map_err(|source| ManifestError::Write {
         path: path.to_path_buf(),
         source,
     })
 }
 
+/// Renders the manifest of a new package, with `changeset_metadata`, the TOML
+/// body of its `[package.metadata.changeset]` table, left out when empty.
+/// `path` is only used in errors.
+///
+/// # Errors
+///
+/// Returns an error if `changeset_metadata` is not valid TOML.
+pub fn render_package_manifest(
+    path: &Path,
+    name: &str,
+    version: &Version,
+    edition: &str,
+    changeset_metadata: &str,
+) -> Result<String, ManifestError> {
+    let mut package = Table::new();
+    package.insert("name", value(name));
+    package.insert("version", value(version.to_string()));
+    package.insert("edition", value(edition));
+
+    let changeset = parse_document(path, changeset_metadata)?;
+    if !changeset.is_empty() {
+        let mut metadata = Table::new();
+        metadata.set_implicit(true);
+        metadata.insert("changeset", Item::Table(changeset.as_table().clone()));
+        package.insert("metadata", Item::Table(metadata));
+    }
+
+    let mut doc = DocumentMut::new();
+    doc.insert("package", Item::Table(package));
+    doc.insert("dependencies", Item::Table(Table::new()));
+    Ok(doc.to_string())
+}
+
+/// Appends `member` to `workspace.members`, creating the list if needed.
+///
+/// Returns `false` and leaves the file untouched if the list already contains
+/// `member`.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read, parsed, or written, or if
+/// it has no `[workspace]` table.
+pub fn add_workspace_member(path: &Path, member: &str) -> Result<bool, ManifestError> {
+    let mut doc = read_document(path)?;
+    let workspace = doc
+        .get_mut("workspace")
+        .ok_or_else(|| ManifestError::MissingSection {
+            path: path.to_path_buf(),
+            section: "workspace".to_string(),
+        })?
+        .as_table_like_mut()
+        .ok_or_else(|| ManifestError::InvalidSectionType {
+            path: path.to_path_buf(),
+            section: "workspace".to_string(),
+        })?;
+
+    let members = workspace
+        .entry("members")
+        .or_insert_with(|| value(Array::new()))
+        .as_array_mut()
+        .ok_or_else(|| ManifestError::InvalidSectionType {
+            path: path.to_path_buf(),
+            section: "workspace.members".to_string(),
+        })?;
+
+    if members.iter().any(|entry| entry.as_str() == Some(member)) {
+        return Ok(false);
+    }
+    members.push(member);
+
+    write_document(path, &doc)?;
+    Ok(true)
+}
+
+/// Renames deprecated option names in the changeset metadata section to their
+/// current spelling, keeping their values. When both spellings are present the
+/// current one wins and the deprecated key is dropped.
+///
+/// Returns the renamed keys; the file is left untouched when there are none.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read, parsed, or written.
+pub fn upgrade_metadata_section(
+    path: &Path,
+    section: MetadataSection,
+) -> Result<Vec<DeprecatedKey>, ManifestError> {
+    let mut doc = read_document(path)?;
+    let has_section = doc
+        .get(section.root_key())
+        .and_then(|root| root.get("metadata"))
+        .and_then(|metadata| metadata.get("changeset"))
+        .is_some();
+    if !has_section {
+        return Ok(Vec::new());
+    }
+
+    let changeset_table = metadata_table(&mut doc, path, section)?;
+
+    let mut renamed = Vec::new();
+    for &(key, replacement) in DEPRECATED_KEYS {
+        let Some(item) = changeset_table.remove(key) else {
+            continue;
+        };
+        if !changeset_table.contains_key(replacement) {
+            changeset_table.insert(replacement, item);
+        }
+        renamed.push(DeprecatedKey { key, replacement });
+    }
+
+    if renamed.is_empty() {
+        return Ok(renamed);
+    }
+
+    atomic_write(path, doc.to_string()).map_err(|source| ManifestError::Write {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    Ok(renamed)
+}
+
+fn metadata_table<'a>(
+    doc: &'a mut DocumentMut,
+    path: &Path,
+    section: MetadataSection,
+) -> Result<&'a mut Table, ManifestError> {
+    let root_key = section.root_key();
+
+    let root = doc
+        .entry(root_key)
+        .or_insert_with(|| Item::Table(Table::new()));
+
+    let root_table = root
+        .as_table_mut()
+        .ok_or_else(|| ManifestError::InvalidSectionType {
+            path: path.to_path_buf(),
+            section: root_key.to_string(),
+        })?;
+
+    let metadata = root_table
+        .entry("metadata")
+        .or_insert_with(|| Item::Table(Table::new()));
+
+    let metadata_table =
+        metadata
+            .as_table_mut()
+            .ok_or_else(|| ManifestError::InvalidSectionType {
+                path: path.to_path_buf(),
+                section: format!("{root_key}.metadata"),
+            })?;
+
+    let changeset = metadata_table
+        .entry("changeset")
+        .or_insert_with(|| Item::Table(Table::new()));
+
+    changeset
+        .as_table_mut()
+        .ok_or_else(|| ManifestError::InvalidSectionType {
+            path: path.to_path_buf(),
+            section: format!("{root_key}.metadata.changeset"),
+        })
+}
+
 /// Updates the version of a dependency in all relevant sections of a Cargo.toml.
 ///
 /// Checks `[workspace.dependencies]`, `[dependencies]`, `[dev-dependencies]`,
-/// and `[build-dependencies]`. Only updates table-form entries that have an
-/// explicit `version` key and do NOT have `workspace = true`.
+/// and `[build-dependencies]`, including their `[target.'cfg(...)'.*]`
+/// variants. Only updates table-form entries that have an explicit `version`
+/// key and do NOT have `workspace = true`. With `skip_optional`, entries marked
+/// `optional = true` are left unchanged.
 ///
 /// # Errors
 ///
@@ -235,13 +399,47 @@ pub fn update_dependency_version(
     path: &Path,
     dependency_name: &str,
     new_version: &Version,
+    skip_optional: bool,
 ) -> Result<bool, ManifestError> {
     let mut doc = read_document(path)?;
+    let changed = set_dependency_version(&mut doc, dependency_name, new_version, skip_optional);
+
+    if changed {
+        write_document(path, &doc)?;
+    }
+
+    Ok(changed)
+}
+
+/// Returns `content` with the dependency requirement rewritten, as
+/// [`update_dependency_version`] would write it, or `None` if nothing changes.
+///
+/// # Errors
+///
+/// Returns an error if the content cannot be parsed.
+pub fn render_dependency_version(
+    path: &Path,
+    content: &str,
+    dependency_name: &str,
+    new_version: &Version,
+    skip_optional: bool,
+) -> Result<Option<String>, ManifestError> {
+    let mut doc = parse_document(path, content)?;
+    let changed = set_dependency_version(&mut doc, dependency_name, new_version, skip_optional);
+    Ok(changed.then(|| doc.to_string()))
+}
+
+fn set_dependency_version(
+    doc: &mut DocumentMut,
+    dependency_name: &str,
+    new_version: &Version,
+    skip_optional: bool,
+) -> bool {
     let mut changed = false;
 
     if let Some(workspace) = doc.get_mut("workspace") {
         if let Some(deps) = workspace.get_mut("dependencies") {
-            if update_dep_entry(deps, dependency_name, new_version) {
+            if update_dep_entry(deps, dependency_name, new_version, skip_optional) {
                 changed = true;
             }
         }
@@ -249,23 +447,33 @@ pub fn update_dependency_version(
 
     for section in &DEPENDENCY_SECTIONS {
         if let Some(deps) = doc.get_mut(section) {
-            if update_dep_entry(deps, dependency_name, new_version) {
+            if update_dep_entry(deps, dependency_name, new_version, skip_optional) {
                 changed = true;
             }
         }
     }
 
-    if changed {
-        std::fs::write(path, doc.to_string()).map_err(|source| ManifestError::Write {
-            path: path.to_path_buf(),
-            source,
-        })?;
+    if let Some(targets) = doc.get_mut("target").and_then(Item::as_table_like_mut) {
+        for (_, target) in targets.iter_mut() {
+            for section in &DEPENDENCY_SECTIONS {
+                if let Some(deps) = target.get_mut(section) {
+                    if update_dep_entry(deps, dependency_name, new_version, skip_optional) {
+                        changed = true;
+                    }
+                }
+            }
+        }
     }
 
-    Ok(changed)
+    changed
 }
 
-fn update_dep_entry(deps: &mut Item, dep_name: &str, new_version: &Version) -> bool {
+fn update_dep_entry(
+    deps: &mut Item,
+    dep_name: &str,
+    new_version: &Version,
+    skip_optional: bool,
+) -> bool {
     let Some(entry) = deps.get_mut(dep_name) else {
         return false;
     };
@@ -279,6 +487,14 @@ fn update_dep_entry(deps: &mut Item, dep_name: &str, new_version: &Version) -> b
             return false;
         }
 
+        let is_optional = table
+            .get("optional")
+            .and_then(toml_edit::Item::as_bool)
+            .unwrap_or(false);
+        if skip_optional && is_optional {
+            return false;
+        }
+
         if table.get("version").is_some() {
             table.insert("version", value(new_version.to_string()));
             return true;
@@ -529,6 +745,75 @@ tags = false
         assert!(!content.contains("tags = false"));
     }
 
+    #[test]
+    fn write_metadata_replaces_deprecated_spelling() {
+        let toml = r#"
+[package]
+name = "test-crate"
+version = "1.0.0"
+
+[package.metadata.changeset]
+tag_format = "version-only"
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let config = InitConfig {
+            tag_format: Some(crate::TagFormat::CratePrefixed),
+            ..Default::default()
+        };
+
+        write_metadata_section(&path, MetadataSection::Package, &config).expect("write metadata");
+
+        let content = std::fs::read_to_string(&path).expect("read file");
+        assert!(content.contains(r#"tag-format = "crate-prefixed""#));
+        assert!(!content.contains("tag_format"));
+    }
+
+    #[test]
+    fn upgrade_metadata_renames_deprecated_keys() {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+keep_changesets = true
+tag-format = "crate-prefixed"
+tag_format = "version-only"
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let renamed =
+            upgrade_metadata_section(&path, MetadataSection::Workspace).expect("upgrade metadata");
+
+        assert_eq!(
+            renamed.iter().map(|key| key.key).collect::<Vec<_>>(),
+            vec!["keep_changesets", "tag_format"]
+        );
+        let content = std::fs::read_to_string(&path).expect("read file");
+        assert!(content.contains("keep-changesets = true"));
+        assert!(content.contains(r#"tag-format = "crate-prefixed""#));
+        assert!(!content.contains("keep_changesets"));
+        assert!(!content.contains("tag_format"));
+    }
+
+    #[test]
+    fn upgrade_metadata_without_section_leaves_file_untouched() {
+        let toml = "[package]\nname = \"test-crate\"\nversion = \"1.0.0\"\n";
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let renamed =
+            upgrade_metadata_section(&path, MetadataSection::Package).expect("upgrade metadata");
+
+        assert!(renamed.is_empty());
+        assert_eq!(std::fs::read_to_string(&path).expect("read file"), toml);
+    }
+
     #[test]
     fn write_metadata_creates_nested_hierarchy() {
         let toml = r#"
@@ -607,11 +892,11 @@ members = ["crates/*"]
         let content = std::fs::read_to_string(&path).expect("read file");
         assert!(content.contains("commit = true"));
         assert!(content.contains("tags = true"));
-        assert!(content.contains("keep_changesets = false"));
-        assert!(content.contains(r#"tag_format = "crate-prefixed""#));
+        assert!(content.contains("keep-changesets = false"));
+        assert!(content.contains(r#"tag-format = "crate-prefixed""#));
         assert!(content.contains(r#"changelog = "per-package""#));
-        assert!(content.contains(r#"comparison_links = "enabled""#));
-        assert!(content.contains(r#"zero_version_behavior = "auto-promote-on-major""#));
+        assert!(content.contains(r#"comparison-links = "enabled""#));
+        assert!(content.contains(r#"zero-version-behavior = "auto-promote-on-major""#));
     }
 
     #[test]
@@ -669,10 +954,10 @@ members = ["crates/*"]
         write_metadata_section(&path, MetadataSection::Workspace, &config).expect("write metadata");
 
         let content = std::fs::read_to_string(&path).expect("read file");
-        assert!(content.contains(r#"tag_format = "version-only""#));
+        assert!(content.contains(r#"tag-format = "version-only""#));
         assert!(content.contains(r#"changelog = "root""#));
-        assert!(content.contains(r#"comparison_links = "auto""#));
-        assert!(content.contains(r#"zero_version_behavior = "effective-minor""#));
+        assert!(content.contains(r#"comparison-links = "auto""#));
+        assert!(content.contains(r#"zero-version-behavior = "effective-minor""#));
     }
 
     #[test]
@@ -707,8 +992,8 @@ my-crate = { path = "crates/my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -730,8 +1015,8 @@ my-crate = { path = "../my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -752,8 +1037,8 @@ my-crate = { path = "../my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -774,8 +1059,8 @@ my-crate = { path = "../my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -796,8 +1081,8 @@ my-crate = { workspace = true }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(!result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -819,8 +1104,8 @@ my-crate = { path = "../my-crate" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(!result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -841,8 +1126,8 @@ some-other = "1.0.0"
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(!result);
     }
 
@@ -860,7 +1145,8 @@ my-crate = { path = "crates/my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
 
         let content = std::fs::read_to_string(&path).expect("read file");
         assert!(content.contains("# Root manifest"));
@@ -881,8 +1167,8 @@ my-crate = "1.0.0"
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(!result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -906,8 +1192,8 @@ my-crate = { path = "../my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let result =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(result);
 
         let content = std::fs::read_to_string(&path).expect("read file");
@@ -925,12 +1211,174 @@ my-crate = { path = "crates/my-crate", version = "1.0.0" }
         let path = dir.path().join("Cargo.toml");
         std::fs::write(&path, toml).expect("write test file");
 
-        let changed =
-            update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0)).expect("update");
+        let changed = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
         assert!(changed);
 
-        let not_changed = update_dependency_version(&path, "nonexistent", &Version::new(2, 0, 0))
-            .expect("update");
+        let not_changed =
+            update_dependency_version(&path, "nonexistent", &Version::new(2, 0, 0), false)
+                .expect("update");
         assert!(!not_changed);
     }
+
+    #[test]
+    fn update_dep_version_updates_target_specific_deps() {
+        let toml = r#"
+[package]
+name = "other-crate"
+version = "0.1.0"
+
+[target.'cfg(unix)'.dependencies]
+my-crate = { path = "../my-crate", version = "1.0.0" }
+
+[target.x86_64-pc-windows-msvc.build-dependencies]
+my-crate = { path = "../my-crate", version = "1.0.0" }
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
+        assert!(result);
+
+        let content = std::fs::read_to_string(&path).expect("read file");
+        assert_eq!(content.matches(r#"version = "2.0.0""#).count(), 2);
+        assert!(content.contains("[target.'cfg(unix)'.dependencies]"));
+    }
+
+    #[test]
+    fn update_dep_version_skip_optional_leaves_optional_deps() {
+        let toml = r#"
+[package]
+name = "other-crate"
+version = "0.1.0"
+
+[dependencies]
+my-crate = { path = "../my-crate", version = "1.0.0", optional = true }
+
+[dev-dependencies]
+my-crate = { path = "../my-crate", version = "1.0.0" }
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let result = update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), true)
+            .expect("update");
+        assert!(result);
+
+        let content = std::fs::read_to_string(&path).expect("read file");
+        assert!(content.contains(r#"version = "1.0.0", optional = true"#));
+        assert_eq!(content.matches(r#"version = "2.0.0""#).count(), 1);
+
+        update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
+        let content = std::fs::read_to_string(&path).expect("read file");
+        assert!(content.contains(r#"version = "2.0.0", optional = true"#));
+    }
+
+    #[test]
+    fn render_functions_match_file_writers() {
+        let toml = r#"
+[package]
+name = "other-crate"
+version = "1.0.0"
+
+[dependencies]
+my-crate = { path = "../my-crate", version = "1.0.0" }
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let rendered = render_version(&path, toml, &Version::new(1, 1, 0)).expect("render");
+        let rendered =
+            render_dependency_version(&path, &rendered, "my-crate", &Version::new(2, 0, 0), false)
+                .expect("render")
+                .expect("dependency changed");
+        assert!(
+            render_dependency_version(&path, &rendered, "missing", &Version::new(2, 0, 0), false)
+                .expect("render")
+                .is_none()
+        );
+
+        write_version(&path, &Version::new(1, 1, 0)).expect("write version");
+        update_dependency_version(&path, "my-crate", &Version::new(2, 0, 0), false)
+            .expect("update");
+        let written = std::fs::read_to_string(&path).expect("read file");
+
+        assert_eq!(rendered, written);
+    }
+
+    #[test]
+    fn add_workspace_member_appends_to_members() {
+        let toml = r#"[workspace]
+members = ["crates/a"]
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        assert!(add_workspace_member(&path, "crates/b").expect("add member"));
+        assert!(!add_workspace_member(&path, "crates/b").expect("add member again"));
+
+        let content = std::fs::read_to_string(&path).expect("read file");
+        assert_eq!(
+            content,
+            "[workspace]\nmembers = [\"crates/a\", \"crates/b\"]\n"
+        );
+    }
+
+    #[test]
+    fn add_workspace_member_requires_workspace() {
+        let toml = r#"[package]
+name = "test-crate"
+version = "1.0.0"
+"#;
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, toml).expect("write test file");
+
+        let result = add_workspace_member(&path, "crates/b");
+
+        assert!(matches!(result, Err(ManifestError::MissingSection { .. })));
+    }
+
+    #[test]
+    fn render_package_manifest_includes_changeset_metadata() {
+        let content = render_package_manifest(
+            Path::new("crates/parser/Cargo.toml"),
+            "parser",
+            &Version::new(0, 1, 0),
+            "2024",
+            "require-changeset = \"always\"\ntags = false\n",
+        )
+        .expect("render manifest");
+
+        assert_eq!(
+            content,
+            "[package]\nname = \"parser\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n\
+             [package.metadata.changeset]\nrequire-changeset = \"always\"\ntags = false\n\n\
+             [dependencies]\n"
+        );
+    }
+
+    #[test]
+    fn render_package_manifest_without_metadata() {
+        let content = render_package_manifest(
+            Path::new("crates/parser/Cargo.toml"),
+            "parser",
+            &Version::new(0, 1, 0),
+            "2024",
+            "",
+        )
+        .expect("render manifest");
+
+        assert_eq!(
+            content,
+            "[package]\nname = \"parser\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n\
+             [dependencies]\n"
+        );
+    }
 }
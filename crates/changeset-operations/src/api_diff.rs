@@ -0,0 +1,260 @@
+//! Public API comparison based on rustdoc's JSON output.
+
+use std::collections::BTreeMap;
+
+use changeset_core::BumpType;
+use serde_json::{Map, Value};
+
+/// Keys holding item ids, which differ between two rustdoc runs.
+const ID_KEYS: [&str; 7] = [
+    "id",
+    "impls",
+    "items",
+    "variants",
+    "fields",
+    "blanket_impls",
+    "implementations",
+];
+
+/// Public items of a crate keyed by path, each with a normalized signature.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiSurface {
+    items: BTreeMap<String, String>,
+}
+
+impl ApiSurface {
+    /// Collects public items, enum variants, trait items, public fields, and
+    /// public inherent methods from a rustdoc JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not valid JSON.
+    pub fn from_rustdoc_json(json: &str) -> Result<Self, serde_json::Error> {
+        let document: Value = serde_json::from_str(json)?;
+        let empty = Map::new();
+        let index = document
+            .get("index")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let paths = document
+            .get("paths")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+
+        let mut items = BTreeMap::new();
+        for (id, summary) in paths {
+            if summary.get("crate_id").and_then(Value::as_u64) != Some(0) {
+                continue;
+            }
+            let Some(item) = index.get(id).filter(|item| is_public(item)) else {
+                continue;
+            };
+            let Some(segments) = summary.get("path").and_then(Value::as_array) else {
+                continue;
+            };
+            let path = segments
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join("::");
+
+            for (name, member) in members(item, index) {
+                items.insert(format!("{path}::{name}"), signature(member));
+            }
+            items.insert(path, signature(item));
+        }
+
+        Ok(Self { items })
+    }
+}
+
+/// Major if an item was removed or changed, minor if items were only added,
+/// patch otherwise.
+#[must_use]
+pub fn suggest_bump(before: &ApiSurface, after: &ApiSurface) -> BumpType {
+    let removed_or_changed = before
+        .items
+        .iter()
+        .any(|(path, signature)| after.items.get(path) != Some(signature));
+    if removed_or_changed {
+        return BumpType::Major;
+    }
+
+    let added = after
+        .items
+        .keys()
+        .any(|path| !before.items.contains_key(path));
+    if added {
+        BumpType::Minor
+    } else {
+        BumpType::Patch
+    }
+}
+
+fn is_public(item: &Value) -> bool {
+    item.get("visibility").and_then(Value::as_str) == Some("public")
+}
+
+fn id_key(id: &Value) -> Option<String> {
+    match id {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+fn resolve<'a>(
+    ids: Option<&'a Value>,
+    index: &'a Map<String, Value>,
+) -> impl Iterator<Item = &'a Value> {
+    ids.and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(id_key)
+        .filter_map(|id| index.get(&id))
+}
+
+/// Named members reachable through an item's public API.
+fn members<'a>(item: &'a Value, index: &'a Map<String, Value>) -> Vec<(&'a str, &'a Value)> {
+    let Some((kind, inner)) = item
+        .get("inner")
+        .and_then(Value::as_object)
+        .and_then(|inner| inner.iter().next())
+    else {
+        return Vec::new();
+    };
+
+    let mut members: Vec<&Value> = match kind.as_str() {
+        "enum" => resolve(inner.get("variants"), index).collect(),
+        "trait" => resolve(inner.get("items"), index).collect(),
+        "struct" => resolve(inner.pointer("/kind/plain/fields"), index)
+            .filter(|field| is_public(field))
+            .collect(),
+        "union" => resolve(inner.get("fields"), index)
+            .filter(|field| is_public(field))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let inherent_impls = resolve(inner.get("impls"), index)
+        .filter_map(|implementation| implementation.pointer("/inner/impl"))
+        .filter(|implementation| implementation.get("trait").is_none_or(Value::is_null));
+    for implementation in inherent_impls {
+        members.extend(resolve(implementation.get("items"), index).filter(|m| is_public(m)));
+    }
+
+    members
+        .into_iter()
+        .filter_map(|member| Some((member.get("name")?.as_str()?, member)))
+        .collect()
+}
+
+fn signature(item: &Value) -> String {
+    let mut inner = item.get("inner").cloned().unwrap_or(Value::Null);
+    strip_ids(&mut inner);
+    inner.to_string()
+}
+
+fn strip_ids(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|key, _| !ID_KEYS.contains(&key.as_str()));
+            map.values_mut().for_each(strip_ids);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_ids),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn function(id: u64, name: &str, output: &str) -> Value {
+        json!({
+            "id": id,
+            "crate_id": 0,
+            "name": name,
+            "visibility": "public",
+            "inner": { "function": { "sig": { "output": { "primitive": output } } } }
+        })
+    }
+
+    fn document(items: &[Value]) -> String {
+        let mut index = Map::new();
+        let mut paths = Map::new();
+        for item in items {
+            let id = item["id"].to_string();
+            let name = item["name"].as_str().unwrap_or_default();
+            paths.insert(
+                id.clone(),
+                json!({ "crate_id": 0, "path": ["my_crate", name], "kind": "function" }),
+            );
+            index.insert(id, item.clone());
+        }
+        json!({ "index": index, "paths": paths }).to_string()
+    }
+
+    fn surface(items: &[Value]) -> ApiSurface {
+        ApiSurface::from_rustdoc_json(&document(items)).expect("valid rustdoc JSON")
+    }
+
+    #[test]
+    fn suggests_bump_from_surface_changes() {
+        let base = surface(&[function(1, "parse", "u8")]);
+
+        assert_eq!(
+            suggest_bump(&base, &surface(&[function(7, "parse", "u8")])),
+            BumpType::Patch
+        );
+        assert_eq!(
+            suggest_bump(
+                &base,
+                &surface(&[function(1, "parse", "u8"), function(2, "render", "u8")])
+            ),
+            BumpType::Minor
+        );
+        assert_eq!(
+            suggest_bump(&base, &surface(&[function(1, "parse", "u16")])),
+            BumpType::Major
+        );
+        assert_eq!(suggest_bump(&base, &ApiSurface::default()), BumpType::Major);
+    }
+
+    #[test]
+    fn collects_public_methods_and_skips_private_items() {
+        let json = json!({
+            "index": {
+                "1": {
+                    "id": 1, "name": "Parser", "visibility": "public",
+                    "inner": { "struct": { "kind": { "plain": { "fields": [] } }, "impls": [2, 5] } }
+                },
+                "2": {
+                    "id": 2, "visibility": "default",
+                    "inner": { "impl": { "trait": null, "items": [3, 4] } }
+                },
+                "3": function(3, "new", "u8"),
+                "4": { "id": 4, "name": "helper", "visibility": "default", "inner": {} },
+                "5": {
+                    "id": 5, "visibility": "default",
+                    "inner": { "impl": { "trait": { "path": "Clone" }, "items": [6] } }
+                },
+                "6": function(6, "clone", "u8"),
+                "7": { "id": 7, "name": "internal", "visibility": "crate", "inner": {} }
+            },
+            "paths": {
+                "1": { "crate_id": 0, "path": ["my_crate", "Parser"], "kind": "struct" },
+                "7": { "crate_id": 0, "path": ["my_crate", "internal"], "kind": "function" },
+                "9": { "crate_id": 3, "path": ["std", "string", "String"], "kind": "struct" }
+            }
+        })
+        .to_string();
+
+        let surface = ApiSurface::from_rustdoc_json(&json).expect("valid rustdoc JSON");
+
+        let paths: Vec<_> = surface.items.keys().map(String::as_str).collect();
+        assert_eq!(paths, ["my_crate::Parser", "my_crate::Parser::new"]);
+    }
+}
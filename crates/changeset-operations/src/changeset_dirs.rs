@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+
+use changeset_project::RootChangesetConfig;
+
+use crate::Result;
+use crate::traits::ChangesetReader;
+
+/// Every configured changeset directory joined to the project root, primary first.
+pub(crate) fn changeset_dirs(project_root: &Path, config: &RootChangesetConfig) -> Vec<PathBuf> {
+    config
+        .changeset_dirs()
+        .map(|dir| project_root.join(dir))
+        .collect()
+}
+
+/// Lists pending changesets across `dirs`, keeping each file once.
+pub(crate) fn list_changesets<R: ChangesetReader + ?Sized>(
+    reader: &R,
+    dirs: &[PathBuf],
+) -> Result<Vec<PathBuf>> {
+    merge(dirs, |dir| reader.list_changesets(dir))
+}
+
+/// Lists changesets consumed for a pre-release across `dirs`, keeping each file once.
+pub(crate) fn list_consumed_changesets<R: ChangesetReader + ?Sized>(
+    reader: &R,
+    dirs: &[PathBuf],
+) -> Result<Vec<PathBuf>> {
+    merge(dirs, |dir| reader.list_consumed_changesets(dir))
+}
+
+fn merge(
+    dirs: &[PathBuf],
+    mut list: impl FnMut(&Path) -> Result<Vec<PathBuf>>,
+) -> Result<Vec<PathBuf>> {
+    let mut merged: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        for path in list(dir)? {
+            if !merged.contains(&path) {
+                merged.push(path);
+            }
+        }
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::providers::FileSystemChangesetIO;
+
+    const CHANGESET: &str = "---\n\"crate-a\": patch\n---\n\nFix a bug\n";
+
+    #[test]
+    fn merges_changesets_from_every_directory() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        for changesets in ["docs/changes/changesets", "teams/web/changesets"] {
+            fs::create_dir_all(dir.path().join(changesets))?;
+            fs::write(dir.path().join(changesets).join("fix.md"), CHANGESET)?;
+        }
+        let config = RootChangesetConfig::default()
+            .with_changeset_dir("docs/changes")
+            .with_owned_changeset_dir("web", "teams/web");
+        let io = FileSystemChangesetIO::new(dir.path());
+
+        let files = list_changesets(&io, &changeset_dirs(dir.path(), &config))?;
+
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("docs/changes/changesets/fix.md"),
+                PathBuf::from("teams/web/changesets/fix.md"),
+            ]
+        );
+
+        Ok(())
+    }
+}
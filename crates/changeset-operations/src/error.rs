@@ -1,8 +1,11 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use changeset_saga::SagaError;
+use semver::Version;
 use thiserror::Error;
 
+use crate::lint::LintIssue;
+
 /// Details about a failed compensation during saga rollback.
 #[derive(Debug)]
 pub struct CompensationFailure {
@@ -67,8 +70,38 @@ pub enum OperationError {
     #[error("no packages found in project at '{0}'")]
     EmptyProject(PathBuf),
 
-    #[error("unknown package '{name}' (available: {available})")]
-    UnknownPackage { name: String, available: String },
+    #[error("unknown package '{name}'{} (available: {available})", did_you_mean(.suggestions))]
+    UnknownPackage {
+        name: String,
+        available: String,
+        /// Package names close to `name`, closest first.
+        suggestions: Vec<String>,
+    },
+
+    #[error("a package is required in a workspace; use --package <name> (available: {available})")]
+    PackageRequired { available: String },
+
+    #[error("unknown change category '{category}' (available: {available})")]
+    UnknownCategory { category: String, available: String },
+
+    #[error("changeset '{path}' uses unknown category '{category}' (available: {available})")]
+    ChangesetCategoryNotConfigured {
+        path: PathBuf,
+        category: String,
+        available: String,
+    },
+
+    #[error("no changeset directory is owned by '{owner}' (owners: {available})")]
+    UnknownChangesetOwner { owner: String, available: String },
+
+    #[error("'{owner}' owns no packages in the `owners` table or CODEOWNERS")]
+    NoOwnedPackages { owner: String },
+
+    #[error("unknown scope '{scope}' (allowed: {allowed})")]
+    UnknownScope { scope: String, allowed: String },
+
+    #[error("a scope is required; use --scope <scope>")]
+    MissingScope,
 
     #[error("missing bump type for package '{package_name}'")]
     MissingBumpType { package_name: String },
@@ -79,6 +112,9 @@ pub enum OperationError {
     #[error("description cannot be empty")]
     EmptyDescription,
 
+    #[error("changeset summary failed lint: {}", join_issues(issues))]
+    SummaryLint { issues: Vec<LintIssue> },
+
     #[error("no packages selected")]
     NoPackagesSelected,
 
@@ -91,11 +127,65 @@ pub enum OperationError {
     #[error("packages with inherited versions require --convert flag: {}", packages.join(", "))]
     InheritedVersionsRequireConvert { packages: Vec<String> },
 
+    #[error(
+        "configuration uses deprecated option names: {}; run `cargo changeset init --upgrade` to rename them",
+        keys.join(", ")
+    )]
+    DeprecatedConfigKeys { keys: Vec<String> },
+
     #[error("comparison links enabled but no repository URL available")]
     ComparisonLinksRequired,
 
-    #[error("working tree has uncommitted changes; commit or stash them, or use --no-commit")]
-    DirtyWorkingTree,
+    #[error(
+        "working tree has uncommitted changes; commit or stash them, or use --on-dirty or --no-commit"
+    )]
+    DirtyWorkingTree { files: Vec<PathBuf> },
+
+    #[error(
+        "failed to restore the changes stashed for the release; recover them with `git stash pop`"
+    )]
+    StashRestoreFailed {
+        #[source]
+        source: Box<OperationError>,
+    },
+
+    #[error(
+        "releases must be made from branch '{expected}' but current branch is '{current}'; use --allow-branch to override"
+    )]
+    NotOnReleaseBranch { current: String, expected: String },
+
+    #[error(
+        "local branch is behind its remote; pull the latest changes or use --allow-stale to override"
+    )]
+    BranchBehindRemote,
+
+    #[error(
+        "{step} needs network access but --offline is set; set offline-behavior = \"skip\" to skip it"
+    )]
+    OfflineNetworkRequired { step: &'static str },
+
+    #[error("HEAD has no release tags; there is no release to amend")]
+    NoReleaseToAmend,
+
+    #[error(
+        "the release commit or its tags are already on the remote; amending it would rewrite published history"
+    )]
+    ReleaseAlreadyPushed,
+
+    #[error("changeset '{}' affects '{package}', which is not part of the release at HEAD", path.display())]
+    AmendPackageNotReleased { package: String, path: PathBuf },
+
+    #[error(
+        "the late changesets call for version {required} of '{package}', above the released {version}; release them as a new version instead"
+    )]
+    AmendBumpExceedsRelease {
+        package: String,
+        version: Version,
+        required: Version,
+    },
+
+    #[error("changelog '{}' has no section for version {version}", path.display())]
+    ReleaseSectionNotFound { path: PathBuf, version: Version },
 
     #[error("current version is stable; please specify a pre-release tag: --prerelease <tag>")]
     PrereleaseTagRequired,
@@ -103,9 +193,202 @@ pub enum OperationError {
     #[error("no changesets found; use --force to release without changesets")]
     NoChangesetsWithoutForce,
 
+    #[error("git hook already exists at '{path}' (use --force to overwrite)")]
+    HookExists { path: PathBuf },
+
+    #[error("failed to write git hook '{path}'")]
+    HookWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to send release notification")]
+    NotificationFailed {
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error("failed to fetch pull request files from GitHub")]
+    GitHubApiRequest {
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error("failed to parse pull request files from GitHub")]
+    GitHubApiResponse {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to request an OIDC token from GitHub Actions")]
+    OidcTokenRequest {
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error("failed to parse the OIDC token from GitHub Actions")]
+    OidcTokenResponse {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error(
+        "no CARGO_REGISTRY_TOKEN is set and the job cannot request an OIDC token for trusted publishing"
+    )]
+    NoRegistryCredentials,
+
+    #[error("trusted publishing only works in GitHub Actions")]
+    TrustedPublishingUnavailable,
+
+    #[error("crates.io rejected the trusted publishing token exchange")]
+    TrustedPublishingExchange {
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error("failed to parse the trusted publishing token from crates.io")]
+    TrustedPublishingResponse {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to revoke the trusted publishing token")]
+    TrustedPublishingRevoke {
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error("publishing '{package}' failed ({status})\n{output}")]
+    PublishFailed {
+        package: String,
+        status: String,
+        output: String,
+    },
+
+    #[error("failed to query the registry index for '{package}'")]
+    RegistryIndexRequest {
+        package: String,
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[error(
+        "the release manifest lists {package} {manifest_version}, but the workspace has {}",
+        workspace_version.as_deref().map_or_else(|| "no such package".to_string(), |version| format!("version {version}"))
+    )]
+    ReleaseManifestMismatch {
+        package: String,
+        manifest_version: String,
+        workspace_version: Option<String>,
+    },
+
+    #[error("changelog pages are built from CHANGELOG.json, which is not enabled")]
+    JsonChangelogNotConfigured,
+
+    #[error("'{}' is a single package; new crates can only be added to a workspace", .0.display())]
+    NotAWorkspace(PathBuf),
+
+    #[error("invalid crate name '{name}': {reason}")]
+    InvalidCrateName { name: String, reason: &'static str },
+
+    #[error("cannot create crate '{name}': '{}' already exists", path.display())]
+    CrateExists { name: String, path: PathBuf },
+
+    #[error(
+        "'{package}' is at {version}, which is not above its latest tag {tagged}; \
+         bump its version in Cargo.toml before a tag-only release"
+    )]
+    VersionNotBumped {
+        package: String,
+        version: Version,
+        tagged: Version,
+    },
+
+    #[error(
+        "changeset '{}' also releases '{package}', which is not selected; \
+         add it to --package or release all packages",
+        path.display()
+    )]
+    ChangesetOutsideSelection { path: PathBuf, package: String },
+
+    #[error("unknown release channel '{name}' for '{package}' (available: {available})")]
+    UnknownChannel {
+        name: String,
+        package: String,
+        available: String,
+    },
+
+    #[error("invalid search pattern '{pattern}': {reason}")]
+    InvalidSearchPattern { pattern: String, reason: String },
+
+    #[cfg(feature = "keyring")]
+    #[error("failed to read the '{service}' token from the OS keyring")]
+    KeyringAccess {
+        service: String,
+        #[source]
+        source: keyring::Error,
+    },
+
+    #[cfg(feature = "otlp")]
+    #[error("failed to export release trace")]
+    TraceExportFailed {
+        #[source]
+        source: Box<ureq::Error>,
+    },
+
+    #[cfg(feature = "api-diff")]
+    #[error("public API diff for '{package}' failed: {reason}")]
+    ApiDiff { package: String, reason: String },
+
+    #[error("failed to start command '{command}'")]
+    CommandSpawn {
+        command: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("pre-release check '{command}' failed ({status})\n{output}")]
+    PreCheckFailed {
+        command: String,
+        status: String,
+        output: String,
+    },
+
+    #[error("invalid version stamp glob '{pattern}'")]
+    StampGlob {
+        pattern: String,
+        #[source]
+        source: globset::Error,
+    },
+
+    #[error("invalid version stamp pattern '{pattern}': {reason}")]
+    StampPattern { pattern: String, reason: String },
+
+    #[error("failed to read '{path}'")]
+    TextFileRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to write '{path}'")]
+    TextFileWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("invalid changeset path '{path}': {reason}")]
     InvalidChangesetPath { path: PathBuf, reason: &'static str },
 
+    #[error("failed to read '{path}' for the release diff")]
+    PreviewRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("failed to read release state file '{path}'")]
     ReleaseStateRead {
         path: PathBuf,
@@ -157,11 +440,49 @@ pub enum OperationError {
         step: String,
         source: Box<OperationError>,
         compensation_failures: Vec<CompensationFailure>,
+        /// The compensations that could not be applied, for replaying later.
+        journal: Option<Box<crate::operations::CompensationJournal>>,
     },
 }
 
 pub type Result<T> = std::result::Result<T, OperationError>;
 
+impl OperationError {
+    /// Files the error is about, such as the changeset that failed to parse
+    /// or the uncommitted files blocking a release.
+    ///
+    /// Saga failures report the files of the step error that caused them.
+    #[must_use]
+    pub fn affected_files(&self) -> Vec<&Path> {
+        match self {
+            Self::ChangesetFileRead { path, .. }
+            | Self::ChangesetParse { path, .. }
+            | Self::ChangesetList { path, .. }
+            | Self::ChangesetCategoryNotConfigured { path, .. }
+            | Self::AmendPackageNotReleased { path, .. }
+            | Self::ReleaseSectionNotFound { path, .. }
+            | Self::HookExists { path }
+            | Self::HookWrite { path, .. }
+            | Self::TextFileRead { path, .. }
+            | Self::TextFileWrite { path, .. }
+            | Self::InvalidChangesetPath { path, .. }
+            | Self::PreviewRead { path, .. }
+            | Self::ReleaseStateRead { path, .. }
+            | Self::ReleaseStateWrite { path, .. }
+            | Self::ReleaseStateParse { path, .. }
+            | Self::ReleaseStateSerialize { path, .. }
+            | Self::CrateExists { path, .. }
+            | Self::NotAWorkspace(path)
+            | Self::EmptyProject(path) => vec![path.as_path()],
+            Self::DirtyWorkingTree { files } => files.iter().map(PathBuf::as_path).collect(),
+            Self::StashRestoreFailed { source }
+            | Self::SagaFailed { source, .. }
+            | Self::SagaCompensationFailed { source, .. } => source.affected_files(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 impl From<SagaError<OperationError>> for OperationError {
     fn from(err: SagaError<OperationError>) -> Self {
         match err {
@@ -186,6 +507,7 @@ impl From<SagaError<OperationError>> for OperationError {
                     step: failed_step,
                     source: Box::new(step_error),
                     compensation_failures,
+                    journal: None,
                 }
             }
             _ => Self::SagaFailed {
@@ -196,6 +518,23 @@ impl From<SagaError<OperationError>> for OperationError {
     }
 }
 
+/// `; did you mean 'a', 'b'?` for non-empty `names`.
+fn did_you_mean(names: &[String]) -> String {
+    if names.is_empty() {
+        return String::new();
+    }
+    let names: Vec<String> = names.iter().map(|name| format!("'{name}'")).collect();
+    format!("; did you mean {}?", names.join(", "))
+}
+
+fn join_issues(issues: &[LintIssue]) -> String {
+    issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +553,7 @@ mod tests {
         let err = OperationError::UnknownPackage {
             name: "missing".to_string(),
             available: "foo, bar".to_string(),
+            suggestions: Vec::new(),
         };
 
         let msg = err.to_string();
@@ -222,6 +562,18 @@ mod tests {
         assert!(msg.contains("foo, bar"));
     }
 
+    #[test]
+    fn saga_failure_reports_files_of_step_error() {
+        let err = OperationError::SagaFailed {
+            step: "create-commit".to_string(),
+            source: Box::new(OperationError::DirtyWorkingTree {
+                files: vec![PathBuf::from("src/lib.rs")],
+            }),
+        };
+
+        assert_eq!(err.affected_files(), vec![Path::new("src/lib.rs")]);
+    }
+
     #[test]
     fn cancelled_error_message() {
         let err = OperationError::Cancelled;
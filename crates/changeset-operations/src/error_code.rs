@@ -0,0 +1,778 @@
+use crate::OperationError;
+
+/// A stable identifier for a class of [`OperationError`], or of failed
+/// verification reported by the CLI, with extended guidance.
+///
+/// Codes are never reused or renumbered, so they can be searched for and
+/// matched by tooling across releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    /// The code, e.g. `CS0030`.
+    pub code: &'static str,
+    /// The name of the error variant.
+    pub name: &'static str,
+    /// What causes the error and how to resolve it.
+    pub explanation: &'static str,
+}
+
+/// Every error code, ordered by code.
+pub const ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode {
+        code: "CS0001",
+        name: "Core",
+        explanation: "A changeset or package violates a core invariant, such as an invalid \
+package name or bump type. The message names the offending value; fix it in the changeset \
+or manifest and run the command again.",
+    },
+    ErrorCode {
+        code: "CS0002",
+        name: "Git",
+        explanation: "A git operation failed. The cause lists the underlying git error. Check \
+that the command runs inside a git checkout (not a bare repository), that HEAD is on a \
+branch, and that remotes and credentials are configured.",
+    },
+    ErrorCode {
+        code: "CS0003",
+        name: "Project",
+        explanation: "The Cargo project or its changeset configuration could not be loaded. \
+Check that Cargo.toml parses, that workspace members exist, and that the \
+[workspace.metadata.changeset] or [package.metadata.changeset] table only uses valid values.",
+    },
+    ErrorCode {
+        code: "CS0004",
+        name: "Parse",
+        explanation: "A changeset could not be parsed. Changesets start with a front matter \
+block between `---` lines mapping package names to bump types, followed by the summary.",
+    },
+    ErrorCode {
+        code: "CS0005",
+        name: "Manifest",
+        explanation: "A Cargo.toml could not be read or updated while writing versions. Check \
+that the manifest is valid TOML, that its version is a plain string or inherited from the \
+workspace, and that the file is writable.",
+    },
+    ErrorCode {
+        code: "CS0006",
+        name: "Changelog",
+        explanation: "A changelog could not be read or written. Check that the changelog file \
+is writable and, with the Keep a Changelog format, that its structure is valid \
+(`cargo changeset verify --changelog-format` lists violations).",
+    },
+    ErrorCode {
+        code: "CS0007",
+        name: "VersionCalculation",
+        explanation: "A new version could not be computed, for example because a pre-release \
+tag is not a valid semver identifier or a version overflowed. Check the current versions and \
+the --prerelease and --graduate arguments.",
+    },
+    ErrorCode {
+        code: "CS0008",
+        name: "ChangesetFileRead",
+        explanation: "A changeset file exists but could not be read. Check its permissions and \
+that it is valid UTF-8.",
+    },
+    ErrorCode {
+        code: "CS0009",
+        name: "ChangesetParse",
+        explanation: "A changeset file has invalid front matter. The message names the file; \
+open it and check that every line between the `---` markers reads `\"package\": bump`.",
+    },
+    ErrorCode {
+        code: "CS0010",
+        name: "ChangesetFileWrite",
+        explanation: "A changeset file could not be written. Check that the changeset \
+directory exists and is writable.",
+    },
+    ErrorCode {
+        code: "CS0011",
+        name: "ChangesetList",
+        explanation: "The changeset directory could not be listed. Run `cargo changeset init` \
+to create it, or check the `changeset-dir` setting.",
+    },
+    ErrorCode {
+        code: "CS0012",
+        name: "Cancelled",
+        explanation: "The operation was cancelled at a prompt. Nothing was changed.",
+    },
+    ErrorCode {
+        code: "CS0013",
+        name: "EmptyProject",
+        explanation: "The project has no packages. Check the `members` of the workspace, or \
+that the sparse checkout includes at least one member.",
+    },
+    ErrorCode {
+        code: "CS0014",
+        name: "UnknownPackage",
+        explanation: "A package given on the command line or in a changeset is not part of the \
+project. The message lists the available packages; check the spelling or whether the crate \
+was renamed.",
+    },
+    ErrorCode {
+        code: "CS0015",
+        name: "PackageRequired",
+        explanation: "The command needs to know which package to act on in a workspace. Pass \
+--package with one of the listed packages.",
+    },
+    ErrorCode {
+        code: "CS0016",
+        name: "UnknownCategory",
+        explanation: "The change category is not configured. Use one of the listed categories \
+or add it to the `categories` setting.",
+    },
+    ErrorCode {
+        code: "CS0017",
+        name: "ChangesetCategoryNotConfigured",
+        explanation: "A pending changeset uses a category that is no longer configured. Edit \
+the changeset to use one of the listed categories, or add the category back to the \
+configuration.",
+    },
+    ErrorCode {
+        code: "CS0018",
+        name: "UnknownScope",
+        explanation: "The scope is not in the configured list of allowed scopes. Use one of \
+the listed scopes or extend the `scopes` setting.",
+    },
+    ErrorCode {
+        code: "CS0019",
+        name: "MissingScope",
+        explanation: "The project requires every changeset to have a scope. Pass --scope, or \
+set `require-scope = false`.",
+    },
+    ErrorCode {
+        code: "CS0020",
+        name: "MissingBumpType",
+        explanation: "A package was selected without a bump type while running \
+non-interactively. Pass --bump for all packages or --package-bump package:type per package.",
+    },
+    ErrorCode {
+        code: "CS0021",
+        name: "MissingDescription",
+        explanation: "No changeset description was given while running non-interactively. \
+Pass -m with the summary, or pipe it on stdin.",
+    },
+    ErrorCode {
+        code: "CS0022",
+        name: "EmptyDescription",
+        explanation: "The changeset description is empty or only whitespace. Describe the \
+change in at least one word.",
+    },
+    ErrorCode {
+        code: "CS0023",
+        name: "SummaryLint",
+        explanation: "The changeset summary breaks one of the configured lint rules. The \
+message lists each issue; reword the summary or adjust `lint-summaries` and \
+`lint-denied-words`.",
+    },
+    ErrorCode {
+        code: "CS0024",
+        name: "NoPackagesSelected",
+        explanation: "No package was selected for the changeset. Select at least one package, \
+or pass --package.",
+    },
+    ErrorCode {
+        code: "CS0025",
+        name: "InteractionRequired",
+        explanation: "The command needs input it can only ask for interactively, but no \
+terminal is available (for example in CI). Pass the missing values as flags; the error output \
+lists them.",
+    },
+    ErrorCode {
+        code: "CS0026",
+        name: "Io",
+        explanation: "A file system operation failed. The cause names the underlying error, \
+such as a missing file or denied permission.",
+    },
+    ErrorCode {
+        code: "CS0027",
+        name: "InheritedVersionsRequireConvert",
+        explanation: "The listed packages inherit their version from the workspace \
+(`version.workspace = true`) but are released separately. Pass --convert to give each \
+package its own version in its manifest.",
+    },
+    ErrorCode {
+        code: "CS0028",
+        name: "DeprecatedConfigKeys",
+        explanation: "The configuration uses option names that were renamed. Run \
+`cargo changeset init --upgrade` to rewrite them.",
+    },
+    ErrorCode {
+        code: "CS0029",
+        name: "ComparisonLinksRequired",
+        explanation: "Changelog comparison links are enabled but no repository URL is known. \
+Set `repository` in Cargo.toml, add a git remote, or set `comparison-links = \"auto\"`.",
+    },
+    ErrorCode {
+        code: "CS0030",
+        name: "DirtyWorkingTree",
+        explanation: "A committing release needs a clean working tree so that only release \
+changes are committed. Commit or stash your changes, pass --on-dirty stash or \
+--on-dirty include, or release with --no-commit.",
+    },
+    ErrorCode {
+        code: "CS0031",
+        name: "StashRestoreFailed",
+        explanation: "Changes stashed before the release could not be re-applied afterwards. \
+They are still in the stash; run `git stash pop` and resolve any conflicts.",
+    },
+    ErrorCode {
+        code: "CS0032",
+        name: "NotOnReleaseBranch",
+        explanation: "Releases are restricted to the branch set in `release-branch`. Switch \
+to that branch, or pass --allow-branch.",
+    },
+    ErrorCode {
+        code: "CS0033",
+        name: "BranchBehindRemote",
+        explanation: "The remote branch has commits that are not in the local branch, so the \
+release would be based on stale history. Pull the latest changes, or pass --allow-stale.",
+    },
+    ErrorCode {
+        code: "CS0034",
+        name: "OfflineNetworkRequired",
+        explanation: "A release step needs the network but --offline is set with \
+`offline-behavior = \"error\"`. Run without --offline, or set \
+`offline-behavior = \"skip\"` to skip network steps.",
+    },
+    ErrorCode {
+        code: "CS0035",
+        name: "NoReleaseToAmend",
+        explanation: "`release --amend` found no release tags on HEAD. Amend only works \
+directly after a release; otherwise run a normal release.",
+    },
+    ErrorCode {
+        code: "CS0036",
+        name: "ReleaseAlreadyPushed",
+        explanation: "The release commit is already on a remote branch, or one of its tags is on \
+the remote, and amending it would rewrite published history. Run a normal release for the late changesets instead.",
+    },
+    ErrorCode {
+        code: "CS0037",
+        name: "AmendPackageNotReleased",
+        explanation: "A late changeset affects a package that the release at HEAD did not \
+include, so it cannot be folded into that release. Release it separately.",
+    },
+    ErrorCode {
+        code: "CS0038",
+        name: "ReleaseSectionNotFound",
+        explanation: "`release --amend` could not find the changelog section of the released \
+version. Check that the changelog was not edited since the release.",
+    },
+    ErrorCode {
+        code: "CS0039",
+        name: "PrereleaseTagRequired",
+        explanation: "The current version is stable, so the pre-release tag cannot be reused. \
+Pass one explicitly, e.g. --prerelease beta.",
+    },
+    ErrorCode {
+        code: "CS0040",
+        name: "NoChangesetsWithoutForce",
+        explanation: "A pre-release was requested but there are no changesets. Pass --force \
+to increment the pre-release number anyway.",
+    },
+    ErrorCode {
+        code: "CS0041",
+        name: "HookExists",
+        explanation: "A git hook already exists at the target path. Pass --force to replace \
+it, or add the changeset check to the existing hook by hand.",
+    },
+    ErrorCode {
+        code: "CS0042",
+        name: "HookWrite",
+        explanation: "The git hook could not be written. Check that the hooks directory \
+exists and is writable.",
+    },
+    ErrorCode {
+        code: "CS0043",
+        name: "NotificationFailed",
+        explanation: "The release webhook could not be delivered. The release itself \
+succeeded. Check `webhook-url` and the network connection.",
+    },
+    ErrorCode {
+        code: "CS0044",
+        name: "GitHubApiRequest",
+        explanation: "The pull request files could not be fetched from GitHub. Check the \
+network connection and that GITHUB_TOKEN grants read access to the repository.",
+    },
+    ErrorCode {
+        code: "CS0045",
+        name: "GitHubApiResponse",
+        explanation: "GitHub answered with a response that is not a list of pull request \
+files. Retry; if it persists, fall back to the git diff with --base.",
+    },
+    ErrorCode {
+        code: "CS0046",
+        name: "TraceExportFailed",
+        explanation: "The release trace could not be sent to the OTLP endpoint. The release \
+itself succeeded. Check the endpoint configuration.",
+    },
+    ErrorCode {
+        code: "CS0047",
+        name: "ApiDiff",
+        explanation: "The public API of a package could not be compared with its last \
+release. The message gives the reason; check that rustdoc JSON can be built for the package.",
+    },
+    ErrorCode {
+        code: "CS0048",
+        name: "CommandSpawn",
+        explanation: "A configured command could not be started. Check that the program is \
+installed and on PATH.",
+    },
+    ErrorCode {
+        code: "CS0049",
+        name: "PreCheckFailed",
+        explanation: "A command in `release-pre-checks` exited unsuccessfully, so nothing was \
+released. The output of the command is shown; fix the reported problem and release again.",
+    },
+    ErrorCode {
+        code: "CS0050",
+        name: "StampGlob",
+        explanation: "The file glob of a version stamp is invalid. Fix the glob in \
+`version-stamps` or the --stamp argument.",
+    },
+    ErrorCode {
+        code: "CS0051",
+        name: "StampPattern",
+        explanation: "The pattern of a version stamp is invalid. It must be a regular \
+expression containing `{version}` exactly once, and optionally `{package}`.",
+    },
+    ErrorCode {
+        code: "CS0052",
+        name: "TextFileRead",
+        explanation: "A file could not be read. Check that it exists and is readable.",
+    },
+    ErrorCode {
+        code: "CS0053",
+        name: "TextFileWrite",
+        explanation: "A file could not be written. Check that its directory exists and is \
+writable.",
+    },
+    ErrorCode {
+        code: "CS0054",
+        name: "InvalidChangesetPath",
+        explanation: "A changeset path has no file name. Pass the path of a changeset file \
+inside the changeset directory.",
+    },
+    ErrorCode {
+        code: "CS0055",
+        name: "PreviewRead",
+        explanation: "A file could not be read to build the --diff preview. Check that it \
+exists and is readable.",
+    },
+    ErrorCode {
+        code: "CS0056",
+        name: "ReleaseStateRead",
+        explanation: "A release state file (pre-release.toml, graduation.toml or \
+consumed.toml) could not be read. Check its permissions.",
+    },
+    ErrorCode {
+        code: "CS0057",
+        name: "ReleaseStateWrite",
+        explanation: "A release state file could not be written. Check that the changeset \
+directory is writable.",
+    },
+    ErrorCode {
+        code: "CS0058",
+        name: "ReleaseStateParse",
+        explanation: "A release state file is not valid TOML or has an unexpected layout. \
+Fix it by hand or with `cargo changeset manage`.",
+    },
+    ErrorCode {
+        code: "CS0059",
+        name: "ReleaseStateSerialize",
+        explanation: "Release state could not be serialized. This is a bug; please report \
+it with the state file attached.",
+    },
+    ErrorCode {
+        code: "CS0060",
+        name: "ValidationFailed",
+        explanation: "The release arguments conflict with each other or with the saved \
+release state. Each problem is listed; for example, a package cannot be both graduated and \
+pre-released.",
+    },
+    ErrorCode {
+        code: "CS0061",
+        name: "VersionParse",
+        explanation: "A stored version is not valid semver. Check the version in the \
+manifest or release state file named in the message.",
+    },
+    ErrorCode {
+        code: "CS0062",
+        name: "TagDeletionFailed",
+        explanation: "Tags created by a failed release could not be removed during rollback. \
+Delete them with `git tag -d <tag>`.",
+    },
+    ErrorCode {
+        code: "CS0063",
+        name: "SagaFailed",
+        explanation: "A release step failed and every change made so far was rolled back. \
+The cause names the failing step and its error; fix it and release again.",
+    },
+    ErrorCode {
+        code: "CS0064",
+        name: "SagaCompensationFailed",
+        explanation: "A release step failed and rolling back some earlier steps failed too, \
+so the workspace may be half-released. Run `cargo changeset compensate` to retry the \
+failed rollbacks, or undo the listed changes by hand.",
+    },
+    ErrorCode {
+        code: "CS0065",
+        name: "UnknownChangesetOwner",
+        explanation: "No changeset directory in the `changeset-dirs` table belongs to the \
+owner passed with --owner. Use one of the listed owners, or add a directory for the owner.",
+    },
+    ErrorCode {
+        code: "CS0066",
+        name: "NoOwnedPackages",
+        explanation: "No package is owned by the owner passed with --owned-by. Owners come \
+from the `owners` table, or from CODEOWNERS for packages the table does not list. Check the \
+spelling, including the leading `@` of user and team handles.",
+    },
+    ErrorCode {
+        code: "CS0067",
+        name: "OidcTokenRequest",
+        explanation: "GitHub Actions did not issue an OIDC token. Grant the job the \
+`id-token: write` permission so ACTIONS_ID_TOKEN_REQUEST_URL and \
+ACTIONS_ID_TOKEN_REQUEST_TOKEN are set.",
+    },
+    ErrorCode {
+        code: "CS0068",
+        name: "OidcTokenResponse",
+        explanation: "GitHub Actions answered the OIDC token request with a response that \
+holds no token. Retry the job; if it persists, check the GitHub status page.",
+    },
+    ErrorCode {
+        code: "CS0069",
+        name: "KeyringAccess",
+        explanation: "A token is stored in the OS keyring but could not be read. Unlock the \
+keyring, or allow cargo-changeset to access the entry, and run the command again.",
+    },
+    ErrorCode {
+        code: "CS0070",
+        name: "NoRegistryCredentials",
+        explanation: "`publish` runs in GitHub Actions without a way to authenticate to \
+crates.io. Either set CARGO_REGISTRY_TOKEN from a secret, or use trusted publishing: add the \
+repository and workflow as a trusted publisher in the crate's crates.io settings and grant \
+the job `permissions: id-token: write`.",
+    },
+    ErrorCode {
+        code: "CS0071",
+        name: "TrustedPublishingUnavailable",
+        explanation: "--trusted-publishing was passed outside GitHub Actions, where no OIDC \
+token can be requested. Publish from the configured workflow, or drop the flag and \
+authenticate with CARGO_REGISTRY_TOKEN or `cargo login`.",
+    },
+    ErrorCode {
+        code: "CS0072",
+        name: "TrustedPublishingExchange",
+        explanation: "crates.io did not issue a publish token for the job's OIDC token. Check \
+that a trusted publisher is configured for every crate being published, and that its \
+repository, workflow file, and environment match the running job.",
+    },
+    ErrorCode {
+        code: "CS0073",
+        name: "TrustedPublishingResponse",
+        explanation: "crates.io answered the trusted publishing exchange with a response that \
+holds no token. Retry the job; if it persists, check the crates.io status page.",
+    },
+    ErrorCode {
+        code: "CS0074",
+        name: "TrustedPublishingRevoke",
+        explanation: "The trusted publishing token could not be revoked after publishing. The \
+packages were published, and the token expires on its own within 30 minutes.",
+    },
+    ErrorCode {
+        code: "CS0075",
+        name: "PublishFailed",
+        explanation: "`cargo publish` failed for a package, so it and the packages after it \
+were not published. The output of cargo is shown; fix the reported problem and publish \
+again.",
+    },
+    ErrorCode {
+        code: "CS0076",
+        name: "RegistryIndexRequest",
+        explanation: "The registry's sparse index could not be queried while checking that \
+published versions are visible. The packages were published; check network access to \
+index.crates.io (or HTTPS_PROXY) and verify the versions by hand.",
+    },
+    ErrorCode {
+        code: "CS0077",
+        name: "ReleaseManifestMismatch",
+        explanation: "`publish --manifest` found a package whose version in the workspace differs \
+from the one the release manifest records, so the checkout is not the released commit. Check \
+out the commit or tag listed in the manifest before publishing.",
+    },
+    ErrorCode {
+        code: "CS0078",
+        name: "JsonChangelogNotConfigured",
+        explanation: "Changelog pages for a docs site are generated from the structured \
+`CHANGELOG.json` files, which are only kept when `changelog-output` includes `json`. Add it to \
+`[workspace.metadata.changeset]` (or `[package.metadata.changeset]`) and release once to create \
+the files.",
+    },
+    ErrorCode {
+        code: "CS0079",
+        name: "NotAWorkspace",
+        explanation: "`new-crate` adds a member to a Cargo workspace, but the project is a single \
+package without a [workspace] table. Add `[workspace]` to the root Cargo.toml, or create the \
+crate with `cargo new` in a separate project.",
+    },
+    ErrorCode {
+        code: "CS0080",
+        name: "InvalidCrateName",
+        explanation: "The name given to `new-crate` is not a valid package name. Names must be \
+non-empty, use only ASCII letters, digits, `-` and `_`, and must not start with a digit.",
+    },
+    ErrorCode {
+        code: "CS0081",
+        name: "CrateExists",
+        explanation: "`new-crate` does not overwrite existing files. The workspace already has a \
+package with that name, or the directory the `new-crate` template points to already exists. \
+Pick another name or remove the directory.",
+    },
+    ErrorCode {
+        code: "CS0082",
+        name: "VersionNotBumped",
+        explanation: "`release --tag-only` releases the versions already in the manifests, so \
+every package with changesets needs a version above its latest tag. Bump the version in the \
+package's Cargo.toml, or run `release` without `--tag-only` to let it bump the version.",
+    },
+    ErrorCode {
+        code: "CS0083",
+        name: "ChangesetOutsideSelection",
+        explanation: "`release --package` releases only the changesets of the selected packages \
+and leaves the others pending. A changeset that releases a selected and an unselected package \
+cannot be split, so the release stops. Add the other package to `--package`, or release \
+without `--package`.",
+    },
+    ErrorCode {
+        code: "CS0084",
+        name: "UnknownChannel",
+        explanation: "A package is released on a channel that is neither built in (`stable`, \
+`beta`, `nightly`) nor defined in the `channels` table. Check the `channel` key in the \
+package's `[package.metadata.changeset]` and the value of `release --channel`, or add the \
+channel to `channels`.",
+    },
+    ErrorCode {
+        code: "CS0085",
+        name: "InvalidSearchPattern",
+        explanation: "The pattern given to `cargo changeset grep` is not a valid regular \
+expression. Escape characters such as `(`, `[` or `*` with a backslash to match them literally.",
+    },
+    ErrorCode {
+        code: "CS0086",
+        name: "AmendBumpExceedsRelease",
+        explanation: "`release --amend` never changes a version, but a late changeset calls for a \
+larger bump than the release at HEAD made, e.g. a minor change folded into a patch release. \
+Commit the release as it is and run a normal release for the late changesets.",
+    },
+    ErrorCode {
+        code: "CS0087",
+        name: "VerificationFailed",
+        explanation: "`cargo changeset verify` found packages changed since the base branch \
+that no changeset covers. Run `cargo changeset add` for each listed package, or list files \
+that never need a changeset under `ignored-files`.",
+    },
+    ErrorCode {
+        code: "CS0088",
+        name: "ChangesetDeleted",
+        explanation: "Changeset files that exist on the base branch were deleted in this \
+branch. Changesets are only removed by a release; restore the files, or pass \
+--allow-deleted-changesets when the deletion is intended.",
+    },
+    ErrorCode {
+        code: "CS0089",
+        name: "ConsumedChangesetEdited",
+        explanation: "A changeset already consumed by a pre-release was edited. Its changelog \
+entry has shipped, so the edit would not appear in it; add a new changeset for the change \
+instead and revert the edit.",
+    },
+    ErrorCode {
+        code: "CS0090",
+        name: "DuplicateChangesets",
+        explanation: "Two pending changesets share a file name, for example in the changeset \
+directories of different packages. Rename one of them so that each file name is unique.",
+    },
+    ErrorCode {
+        code: "CS0091",
+        name: "AdvisoryMissing",
+        explanation: "The pull request is labelled as a security fix, but no changeset has \
+category `security` with an advisory. Add an `advisory` line with the advisory ID to the \
+changeset of the fix.",
+    },
+    ErrorCode {
+        code: "CS0092",
+        name: "SummaryLintFailed",
+        explanation: "Changeset summaries failed lint: an unclosed code fence, unbalanced \
+backticks, a bare URL or a denied word. The listed changesets name the issue; fix their \
+summaries.",
+    },
+    ErrorCode {
+        code: "CS0093",
+        name: "ScopeCheckFailed",
+        explanation: "Changesets lack a scope where the configuration requires one, or use a \
+scope that is not in the configured list. Add or correct the `scope` line of the listed \
+changesets.",
+    },
+];
+
+impl ErrorCode {
+    /// Looks up a code case-insensitively; `CS12` and `cs0012` both find `CS0012`.
+    #[must_use]
+    pub fn lookup(code: &str) -> Option<&'static Self> {
+        let digits = code
+            .get(..2)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("CS"))
+            .map_or(code, |_| &code[2..]);
+        let number: u16 = digits.parse().ok()?;
+        ERROR_CODES
+            .iter()
+            .find(|entry| entry.code[2..].parse() == Ok(number))
+    }
+}
+
+impl OperationError {
+    /// Returns the stable code for this error.
+    #[must_use]
+    pub fn code(&self) -> &'static ErrorCode {
+        let index = match self {
+            Self::Core(_) => 0,
+            Self::Git(_) => 1,
+            Self::Project(_) => 2,
+            Self::Parse(_) => 3,
+            Self::Manifest(_) => 4,
+            Self::Changelog(_) => 5,
+            Self::VersionCalculation(_) => 6,
+            Self::ChangesetFileRead { .. } => 7,
+            Self::ChangesetParse { .. } => 8,
+            Self::ChangesetFileWrite(_) => 9,
+            Self::ChangesetList { .. } => 10,
+            Self::Cancelled => 11,
+            Self::EmptyProject(_) => 12,
+            Self::UnknownPackage { .. } => 13,
+            Self::PackageRequired { .. } => 14,
+            Self::UnknownCategory { .. } => 15,
+            Self::ChangesetCategoryNotConfigured { .. } => 16,
+            Self::UnknownScope { .. } => 17,
+            Self::MissingScope => 18,
+            Self::MissingBumpType { .. } => 19,
+            Self::MissingDescription => 20,
+            Self::EmptyDescription => 21,
+            Self::SummaryLint { .. } => 22,
+            Self::NoPackagesSelected => 23,
+            Self::InteractionRequired => 24,
+            Self::Io(_) => 25,
+            Self::InheritedVersionsRequireConvert { .. } => 26,
+            Self::DeprecatedConfigKeys { .. } => 27,
+            Self::ComparisonLinksRequired => 28,
+            Self::DirtyWorkingTree { .. } => 29,
+            Self::StashRestoreFailed { .. } => 30,
+            Self::NotOnReleaseBranch { .. } => 31,
+            Self::BranchBehindRemote => 32,
+            Self::OfflineNetworkRequired { .. } => 33,
+            Self::NoReleaseToAmend => 34,
+            Self::ReleaseAlreadyPushed => 35,
+            Self::AmendPackageNotReleased { .. } => 36,
+            Self::ReleaseSectionNotFound { .. } => 37,
+            Self::PrereleaseTagRequired => 38,
+            Self::NoChangesetsWithoutForce => 39,
+            Self::HookExists { .. } => 40,
+            Self::HookWrite { .. } => 41,
+            Self::NotificationFailed { .. } => 42,
+            Self::GitHubApiRequest { .. } => 43,
+            Self::GitHubApiResponse { .. } => 44,
+            #[cfg(feature = "otlp")]
+            Self::TraceExportFailed { .. } => 45,
+            #[cfg(feature = "api-diff")]
+            Self::ApiDiff { .. } => 46,
+            Self::CommandSpawn { .. } => 47,
+            Self::PreCheckFailed { .. } => 48,
+            Self::StampGlob { .. } => 49,
+            Self::StampPattern { .. } => 50,
+            Self::TextFileRead { .. } => 51,
+            Self::TextFileWrite { .. } => 52,
+            Self::InvalidChangesetPath { .. } => 53,
+            Self::PreviewRead { .. } => 54,
+            Self::ReleaseStateRead { .. } => 55,
+            Self::ReleaseStateWrite { .. } => 56,
+            Self::ReleaseStateParse { .. } => 57,
+            Self::ReleaseStateSerialize { .. } => 58,
+            Self::ValidationFailed(_) => 59,
+            Self::VersionParse { .. } => 60,
+            Self::TagDeletionFailed { .. } => 61,
+            Self::SagaFailed { .. } => 62,
+            Self::SagaCompensationFailed { .. } => 63,
+            Self::UnknownChangesetOwner { .. } => 64,
+            Self::NoOwnedPackages { .. } => 65,
+            Self::OidcTokenRequest { .. } => 66,
+            Self::OidcTokenResponse { .. } => 67,
+            #[cfg(feature = "keyring")]
+            Self::KeyringAccess { .. } => 68,
+            Self::NoRegistryCredentials => 69,
+            Self::TrustedPublishingUnavailable => 70,
+            Self::TrustedPublishingExchange { .. } => 71,
+            Self::TrustedPublishingResponse { .. } => 72,
+            Self::TrustedPublishingRevoke { .. } => 73,
+            Self::PublishFailed { .. } => 74,
+            Self::RegistryIndexRequest { .. } => 75,
+            Self::ReleaseManifestMismatch { .. } => 76,
+            Self::JsonChangelogNotConfigured => 77,
+            Self::NotAWorkspace(_) => 78,
+            Self::InvalidCrateName { .. } => 79,
+            Self::CrateExists { .. } => 80,
+            Self::VersionNotBumped { .. } => 81,
+            Self::ChangesetOutsideSelection { .. } => 82,
+            Self::UnknownChannel { .. } => 83,
+            Self::InvalidSearchPattern { .. } => 84,
+            Self::AmendBumpExceedsRelease { .. } => 85,
+        };
+        &ERROR_CODES[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn codes_are_sequential_and_unique() {
+        for (entry, number) in ERROR_CODES.iter().zip(1..) {
+            assert_eq!(entry.code, format!("CS{number:04}"));
+        }
+    }
+
+    #[test]
+    fn code_matches_variant_name() {
+        let errors = [
+            OperationError::Cancelled,
+            OperationError::DirtyWorkingTree { files: Vec::new() },
+            OperationError::BranchBehindRemote,
+            OperationError::SagaFailed {
+                step: "create-commit".to_string(),
+                source: Box::new(OperationError::Cancelled),
+            },
+            OperationError::EmptyProject(PathBuf::from("/project")),
+        ];
+
+        for error in &errors {
+            assert!(
+                format!("{error:?}").starts_with(error.code().name),
+                "{error:?} has code {}",
+                error.code().code
+            );
+        }
+    }
+
+    #[test]
+    fn lookup_accepts_short_and_lowercase_codes() {
+        let expected = Some(&ERROR_CODES[29]);
+
+        assert_eq!(ErrorCode::lookup("CS0030"), expected);
+        assert_eq!(ErrorCode::lookup("cs0030"), expected);
+        assert_eq!(ErrorCode::lookup("CS30"), expected);
+        assert_eq!(ErrorCode::lookup("30"), expected);
+        assert_eq!(ErrorCode::lookup("CS9999"), None);
+        assert_eq!(ErrorCode::lookup("dirty"), None);
+    }
+}
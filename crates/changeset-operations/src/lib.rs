@@ -1,7 +1,14 @@
+#[cfg(feature = "api-diff")]
+pub mod api_diff;
+pub(crate) mod changeset_dirs;
 mod error;
+mod error_code;
+pub mod lint;
 pub mod operations;
+pub(crate) mod package_selection;
 pub(crate) mod planner;
 pub mod providers;
+pub(crate) mod tags;
 pub mod traits;
 pub(crate) mod types;
 pub mod verification;
@@ -10,3 +17,4 @@ pub mod verification;
 pub mod mocks;
 
 pub use error::{CompensationFailure, OperationError, Result};
+pub use error_code::{ERROR_CODES, ErrorCode};
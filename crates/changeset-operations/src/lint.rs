@@ -0,0 +1,234 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+
+const URL_SCHEMES: [&str; 2] = ["https://", "http://"];
+
+/// A problem found in a changeset summary that would render badly in the changelog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum LintIssue {
+    UnclosedCodeFence,
+    UnbalancedBackticks,
+    BareUrl { url: String },
+    DeniedWord { word: String },
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedCodeFence => write!(f, "unclosed code fence"),
+            Self::UnbalancedBackticks => write!(f, "unbalanced backticks"),
+            Self::BareUrl { url } => write!(f, "bare URL '{url}' (wrap it in <...> or a link)"),
+            Self::DeniedWord { word } => write!(f, "denied word '{word}'"),
+        }
+    }
+}
+
+/// Checks a summary for broken markdown and denied words.
+///
+/// Code fences and code spans are excluded from the URL and word checks.
+/// Denied words are matched case-insensitively against whole words.
+#[must_use]
+pub fn lint_summary(summary: &str, denied_words: &[String]) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    let (prose, fence_closed) = strip_code_fences(summary);
+    if !fence_closed {
+        issues.push(LintIssue::UnclosedCodeFence);
+    }
+
+    let prose = strip_code_spans(&prose).unwrap_or_else(|| {
+        issues.push(LintIssue::UnbalancedBackticks);
+        prose.replace('`', " ")
+    });
+
+    issues.extend(
+        find_bare_urls(&prose)
+            .into_iter()
+            .map(|url| LintIssue::BareUrl { url }),
+    );
+    issues.extend(
+        find_denied_words(&prose, denied_words)
+            .into_iter()
+            .map(|word| LintIssue::DeniedWord { word }),
+    );
+
+    issues
+}
+
+/// Drops fenced code blocks, returning the remaining text and whether every fence was closed.
+fn strip_code_fences(text: &str) -> (String, bool) {
+    let mut prose = String::with_capacity(text.len());
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if !in_fence {
+            prose.push_str(line);
+            prose.push('\n');
+        }
+    }
+
+    (prose, !in_fence)
+}
+
+/// Replaces code spans with a space; `None` if a backtick run is never closed
+/// by a run of the same length.
+fn strip_code_spans(text: &str) -> Option<String> {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('`') {
+        output.push_str(&rest[..start]);
+        let run = backtick_run(&rest[start..]);
+        let after = &rest[start + run..];
+        let close = find_closing_run(after, run)?;
+        output.push(' ');
+        rest = &after[close + run..];
+    }
+    output.push_str(rest);
+
+    Some(output)
+}
+
+fn backtick_run(text: &str) -> usize {
+    text.bytes().take_while(|&b| b == b'`').count()
+}
+
+fn find_closing_run(text: &str, len: usize) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(pos) = text[offset..].find('`') {
+        let at = offset + pos;
+        let run = backtick_run(&text[at..]);
+        if run == len {
+            return Some(at);
+        }
+        offset = at + run;
+    }
+    None
+}
+
+fn find_bare_urls(text: &str) -> Vec<String> {
+    let mut urls = Vec::new();
+
+    for (start, _) in text.match_indices("http") {
+        let candidate = &text[start..];
+        if !URL_SCHEMES
+            .iter()
+            .any(|scheme| candidate.starts_with(scheme))
+        {
+            continue;
+        }
+        let before = &text[..start];
+        if before.ends_with('<') || before.ends_with("](") || before.ends_with('[') {
+            continue;
+        }
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || matches!(c, ')' | ']' | '>'))
+            .unwrap_or(candidate.len());
+        urls.push(candidate[..end].to_string());
+    }
+
+    urls
+}
+
+fn find_denied_words(text: &str, denied_words: &[String]) -> Vec<String> {
+    if denied_words.is_empty() {
+        return Vec::new();
+    }
+
+    let denied: HashSet<String> = denied_words.iter().map(|w| w.to_lowercase()).collect();
+    let mut seen = HashSet::new();
+
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|word| word.trim_matches('\''))
+        .filter(|word| !word.is_empty())
+        .filter_map(|word| {
+            let lower = word.to_lowercase();
+            (denied.contains(&lower) && seen.insert(lower)).then(|| word.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn clean_summary_has_no_issues() {
+        let summary = "Fix `parse()` for ``a ` b`` inputs, see <https://example.com> and \
+                       [the docs](https://docs.rs/crate).";
+
+        assert!(lint_summary(summary, &[]).is_empty());
+    }
+
+    #[test]
+    fn detects_unbalanced_backticks() {
+        assert_eq!(
+            lint_summary("Fix `parse() handling", &[]),
+            vec![LintIssue::UnbalancedBackticks]
+        );
+        assert_eq!(
+            lint_summary("Fix ``parse()` handling", &[]),
+            vec![LintIssue::UnbalancedBackticks]
+        );
+    }
+
+    #[test]
+    fn detects_unclosed_code_fence() {
+        let summary = "Example:\n\n```rust\nlet x = 1;\n";
+
+        assert_eq!(
+            lint_summary(summary, &[]),
+            vec![LintIssue::UnclosedCodeFence]
+        );
+    }
+
+    #[test]
+    fn ignores_code_fences_and_spans() {
+        let summary = "Example:\n\n```\ncurl https://example.com `\n```\n\nUse `https://x.dev`.";
+
+        assert!(lint_summary(summary, &words(&["curl"])).is_empty());
+    }
+
+    #[test]
+    fn detects_bare_urls() {
+        assert_eq!(
+            lint_summary(
+                "See https://example.com/issue/1 for details (http://a.b).",
+                &[]
+            ),
+            vec![
+                LintIssue::BareUrl {
+                    url: "https://example.com/issue/1".to_string()
+                },
+                LintIssue::BareUrl {
+                    url: "http://a.b".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_denied_words_once_case_insensitively() {
+        assert_eq!(
+            lint_summary("Teh parser no longer drops teh header", &words(&["teh"])),
+            vec![LintIssue::DeniedWord {
+                word: "Teh".to_string()
+            }]
+        );
+        assert!(lint_summary("Tehran support", &words(&["teh"])).is_empty());
+    }
+}
@@ -1,34 +1,39 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use changeset_core::{BumpType, ChangeCategory, Changeset, PackageInfo, PackageRelease};
+use changeset_core::{
+    BumpType, CategorySet, ChangeCategory, Changeset, PackageInfo, PackageRelease,
+};
+use changeset_project::{
+    CHANGESETS_SUBDIR, CargoProject, OwnedChangesetDir, RootChangesetConfig, ScopeConfig,
+    package_owners,
+};
 use indexmap::IndexSet;
 
 use crate::Result;
 use crate::error::OperationError;
+use crate::lint::lint_summary;
+use crate::package_selection;
 use crate::traits::{
-    BumpSelection, CategorySelection, ChangesetWriter, DescriptionInput, InteractionProvider,
-    PackageSelection, ProjectProvider,
+    BumpInferenceProvider, BumpSelection, CategorySelection, ChangesetWriter, DescriptionInput,
+    InteractionProvider, PackageSelection, ProjectProvider, ScopeSelection,
 };
 
+#[derive(Default)]
 pub struct AddInput {
     pub packages: Vec<String>,
     pub bump: Option<BumpType>,
     pub package_bumps: HashMap<String, BumpType>,
-    pub category: ChangeCategory,
+    /// Category id from the command line, resolved against the configured categories.
+    pub category: Option<String>,
+    pub scope: Option<String>,
+    /// Security advisory the change resolves, e.g. `RUSTSEC-2024-0001`.
+    pub advisory: Option<String>,
     pub description: Option<String>,
-}
-
-impl Default for AddInput {
-    fn default() -> Self {
-        Self {
-            packages: Vec::new(),
-            bump: None,
-            package_bumps: HashMap::new(),
-            category: ChangeCategory::Changed,
-            description: None,
-        }
-    }
+    /// Owner of the changeset directory to write to instead of the primary one.
+    pub owner: Option<String>,
+    /// Only offer packages this owner owns, per the `owners` table or `CODEOWNERS`.
+    pub owned_by: Option<String>,
 }
 
 #[derive(Debug)]
@@ -41,10 +46,21 @@ pub enum AddResult {
     NoPackages,
 }
 
+/// A validated changeset that is ready to be written, or the outcome that
+/// ended the input early.
+enum Prepared {
+    Ready {
+        changeset: Changeset,
+        changeset_dir: PathBuf,
+    },
+    Done(AddResult),
+}
+
 pub struct AddOperation<P, W, I> {
     project_provider: P,
     changeset_writer: W,
     interaction_provider: I,
+    bump_inference: Option<Box<dyn BumpInferenceProvider>>,
 }
 
 impl<P, W, I> AddOperation<P, W, I>
@@ -58,36 +74,132 @@ where
             project_provider,
             changeset_writer,
             interaction_provider,
+            bump_inference: None,
         }
     }
 
+    /// Preselects the inferred bump type when prompting for one.
+    #[must_use]
+    pub fn with_bump_inference(mut self, inference: Box<dyn BumpInferenceProvider>) -> Self {
+        self.bump_inference = Some(inference);
+        self
+    }
+
     /// # Errors
     ///
     /// Returns an error if the project cannot be discovered, has no packages, or
     /// if the changeset cannot be written.
     pub fn execute(&self, start_path: &Path, input: AddInput) -> Result<AddResult> {
+        let (project, root_config) = self.load_project(start_path)?;
+
+        match self.prepare(&project, &root_config, input)? {
+            Prepared::Ready {
+                changeset,
+                changeset_dir,
+            } => self.write(changeset, &changeset_dir),
+            Prepared::Done(result) => Ok(result),
+        }
+    }
+
+    /// Creates one changeset per input. Every input is validated before the
+    /// first file is written, and the files already written are removed again
+    /// if a later one fails, so either all changesets are created or none.
+    ///
+    /// If an input is cancelled, nothing is written and its result is
+    /// returned on its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be discovered, has no packages,
+    /// if any input is invalid, or if a changeset cannot be written.
+    pub fn execute_batch(
+        &self,
+        start_path: &Path,
+        inputs: Vec<AddInput>,
+    ) -> Result<Vec<AddResult>> {
+        let (project, root_config) = self.load_project(start_path)?;
+
+        let mut prepared = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            match self.prepare(&project, &root_config, input)? {
+                Prepared::Ready {
+                    changeset,
+                    changeset_dir,
+                } => prepared.push((changeset, changeset_dir)),
+                Prepared::Done(result) => return Ok(vec![result]),
+            }
+        }
+
+        let mut results = Vec::with_capacity(prepared.len());
+        for (changeset, changeset_dir) in prepared {
+            match self.write(changeset, &changeset_dir) {
+                Ok(result) => results.push(result),
+                Err(error) => {
+                    for result in &results {
+                        if let AddResult::Created { file_path, .. } = result {
+                            // Best effort: the write error is the one to report.
+                            let _ = self.changeset_writer.delete_changeset(file_path);
+                        }
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn load_project(&self, start_path: &Path) -> Result<(CargoProject, RootChangesetConfig)> {
         let project = self.project_provider.discover_project(start_path)?;
 
         if project.packages.is_empty() {
             return Err(OperationError::EmptyProject(project.root));
         }
 
-        let packages = match self.select_packages(&project.packages, &input)? {
-            Some(packages) if packages.is_empty() => return Ok(AddResult::NoPackages),
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        Ok((project, root_config))
+    }
+
+    fn prepare(
+        &self,
+        project: &CargoProject,
+        root_config: &RootChangesetConfig,
+        input: AddInput,
+    ) -> Result<Prepared> {
+        let available = match input.owned_by.as_deref() {
+            Some(owner) => packages_owned_by(project, root_config, owner)?,
+            None => project.packages.clone(),
+        };
+
+        let packages = match self.select_packages(&project.root, &available, &input)? {
+            Some(packages) if packages.is_empty() => {
+                return Ok(Prepared::Done(AddResult::NoPackages));
+            }
             Some(packages) => packages,
-            None => return Ok(AddResult::Cancelled),
+            None => return Ok(Prepared::Done(AddResult::Cancelled)),
         };
 
-        let Some(releases) = self.collect_releases(&packages, &input)? else {
-            return Ok(AddResult::Cancelled);
+        let Some(releases) = self.collect_releases(&project.root, &packages, &input)? else {
+            return Ok(Prepared::Done(AddResult::Cancelled));
         };
 
-        let Some(category) = self.select_category(&input)? else {
-            return Ok(AddResult::Cancelled);
+        let owned_dir = match input.owner.as_deref() {
+            Some(owner) => Some(find_owned_dir(root_config, owner)?),
+            None => None,
+        };
+
+        let Some(category) = self.select_category(&input, root_config.categories())? else {
+            return Ok(Prepared::Done(AddResult::Cancelled));
+        };
+
+        let ScopeSelection::Selected(scope) =
+            self.select_scope(&input, root_config.scope_config())?
+        else {
+            return Ok(Prepared::Done(AddResult::Cancelled));
         };
 
         let Some(description) = self.get_description(&input)? else {
-            return Ok(AddResult::Cancelled);
+            return Ok(Prepared::Done(AddResult::Cancelled));
         };
 
         let description = description.trim();
@@ -95,23 +207,46 @@ where
             return Err(OperationError::EmptyDescription);
         }
 
+        let lint_config = root_config.lint_config();
+        if lint_config.enabled() {
+            let issues = lint_summary(description, lint_config.denied_words());
+            if !issues.is_empty() {
+                return Err(OperationError::SummaryLint { issues });
+            }
+        }
+
         let changeset = Changeset {
             summary: description.to_string(),
             releases,
             category,
+            scope,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: input.advisory,
         };
 
-        let (root_config, _) = self.project_provider.load_configs(&project)?;
-        let changeset_dir = self
-            .project_provider
-            .ensure_changeset_dir(&project, &root_config)?;
+        let changeset_dir = match owned_dir {
+            Some(dir) => self
+                .project_provider
+                .ensure_owned_changeset_dir(project, dir)?,
+            None => self
+                .project_provider
+                .ensure_changeset_dir(project, root_config)?,
+        };
+
+        Ok(Prepared::Ready {
+            changeset,
+            changeset_dir,
+        })
+    }
 
+    fn write(&self, changeset: Changeset, changeset_dir: &Path) -> Result<AddResult> {
         let filename = self
             .changeset_writer
-            .write_changeset(&changeset_dir, &changeset)?;
-        let file_path = changeset_dir.join(&filename);
+            .write_changeset(changeset_dir, &changeset)?;
+        let file_path = changeset_dir.join(CHANGESETS_SUBDIR).join(&filename);
 
         Ok(AddResult::Created {
             changeset,
@@ -121,13 +256,15 @@ where
 
     fn select_packages(
         &self,
+        project_root: &Path,
         available: &[PackageInfo],
         input: &AddInput,
     ) -> Result<Option<Vec<PackageInfo>>> {
         let explicit_packages = collect_explicit_packages(input);
 
         if !explicit_packages.is_empty() {
-            let packages = resolve_explicit_packages(available, &explicit_packages)?;
+            let packages =
+                package_selection::select_packages(project_root, available, &explicit_packages)?;
             return Ok(Some(packages));
         }
 
@@ -143,6 +280,7 @@ where
 
     fn collect_releases(
         &self,
+        project_root: &Path,
         packages: &[PackageInfo],
         input: &AddInput,
     ) -> Result<Option<Vec<PackageRelease>>> {
@@ -154,7 +292,14 @@ where
             } else if let Some(bump) = input.bump {
                 bump
             } else {
-                match self.interaction_provider.select_bump_type(&package.name)? {
+                let suggested = match &self.bump_inference {
+                    Some(inference) => inference.infer_bump(project_root, package)?,
+                    None => None,
+                };
+                match self
+                    .interaction_provider
+                    .select_bump_type(&package.name, suggested)?
+                {
                     BumpSelection::Selected(bump) => bump,
                     BumpSelection::Cancelled => return Ok(None),
                 }
@@ -169,21 +314,56 @@ where
         Ok(Some(releases))
     }
 
-    fn select_category(&self, input: &AddInput) -> Result<Option<ChangeCategory>> {
-        let has_explicit_input = input.description.is_some()
-            || !input.packages.is_empty()
-            || !input.package_bumps.is_empty();
+    fn select_category(
+        &self,
+        input: &AddInput,
+        categories: &CategorySet,
+    ) -> Result<Option<ChangeCategory>> {
+        if let Some(id) = &input.category {
+            return categories.resolve(id).map(Some).ok_or_else(|| {
+                OperationError::UnknownCategory {
+                    category: id.clone(),
+                    available: categories.ids(),
+                }
+            });
+        }
 
-        if input.category != ChangeCategory::default() || has_explicit_input {
-            return Ok(Some(input.category));
+        if has_explicit_input(input) {
+            return Ok(Some(categories.default_category()));
         }
 
-        match self.interaction_provider.select_category()? {
+        match self.interaction_provider.select_category(categories)? {
             CategorySelection::Selected(category) => Ok(Some(category)),
             CategorySelection::Cancelled => Ok(None),
         }
     }
 
+    fn select_scope(&self, input: &AddInput, scope_config: &ScopeConfig) -> Result<ScopeSelection> {
+        if let Some(scope) = input.scope.as_deref().map(str::trim) {
+            if scope.is_empty() || !scope_config.is_allowed(scope) {
+                return Err(OperationError::UnknownScope {
+                    scope: scope.to_string(),
+                    allowed: scope_config.scopes().join(", "),
+                });
+            }
+            return Ok(ScopeSelection::Selected(Some(scope.to_string())));
+        }
+
+        if scope_config.scopes().is_empty() && !scope_config.required() {
+            return Ok(ScopeSelection::Selected(None));
+        }
+
+        if has_explicit_input(input) {
+            if scope_config.required() {
+                return Err(OperationError::MissingScope);
+            }
+            return Ok(ScopeSelection::Selected(None));
+        }
+
+        self.interaction_provider
+            .select_scope(scope_config.scopes(), scope_config.required())
+    }
+
     fn get_description(&self, input: &AddInput) -> Result<Option<String>> {
         if let Some(description) = &input.description {
             return Ok(Some(description.clone()));
@@ -196,6 +376,11 @@ where
     }
 }
 
+/// Whether any flag was passed that signals a non-interactive invocation.
+fn has_explicit_input(input: &AddInput) -> bool {
+    input.description.is_some() || !input.packages.is_empty() || !input.package_bumps.is_empty()
+}
+
 fn collect_explicit_packages(input: &AddInput) -> Vec<String> {
     let mut packages: IndexSet<String> = input.packages.iter().cloned().collect();
 
@@ -206,29 +391,45 @@ fn collect_explicit_packages(input: &AddInput) -> Vec<String> {
     packages.into_iter().collect()
 }
 
-fn resolve_explicit_packages(
-    packages: &[PackageInfo],
-    package_names: &[String],
+fn packages_owned_by(
+    project: &CargoProject,
+    root_config: &RootChangesetConfig,
+    owner: &str,
 ) -> Result<Vec<PackageInfo>> {
-    let unique_names: IndexSet<&String> = package_names.iter().collect();
-    let mut selected = Vec::with_capacity(unique_names.len());
+    let ownership = package_owners(project, root_config);
+    let packages: Vec<PackageInfo> = project
+        .packages
+        .iter()
+        .filter(|package| {
+            ownership
+                .get(&package.name)
+                .is_some_and(|owners| owners.iter().any(|o| o == owner))
+        })
+        .cloned()
+        .collect();
+    if packages.is_empty() {
+        return Err(OperationError::NoOwnedPackages {
+            owner: owner.to_string(),
+        });
+    }
+    Ok(packages)
+}
 
-    for name in unique_names {
-        let package = packages.iter().find(|p| p.name == *name).ok_or_else(|| {
-            let available = packages
+fn find_owned_dir<'a>(
+    root_config: &'a RootChangesetConfig,
+    owner: &str,
+) -> Result<&'a OwnedChangesetDir> {
+    let dirs = root_config.owned_changeset_dirs();
+    dirs.iter().find(|dir| dir.owner() == owner).ok_or_else(|| {
+        OperationError::UnknownChangesetOwner {
+            owner: owner.to_string(),
+            available: dirs
                 .iter()
-                .map(|p| p.name.as_str())
+                .map(OwnedChangesetDir::owner)
                 .collect::<Vec<_>>()
-                .join(", ");
-            OperationError::UnknownPackage {
-                name: name.clone(),
-                available,
-            }
-        })?;
-        selected.push(package.clone());
-    }
-
-    Ok(selected)
+                .join(", "),
+        }
+    })
 }
 
 #[cfg(test)]
@@ -303,6 +504,8 @@ mod operation_tests {
     use crate::mocks::{
         MockChangesetWriter, MockInteractionProvider, MockProjectProvider, make_package,
     };
+    use changeset_core::CategoryDefinition;
+    use changeset_project::{LintConfig, RootChangesetConfig, ScopeConfig};
 
     #[test]
     fn creates_changeset_for_single_package_project() {
@@ -395,7 +598,9 @@ mod operation_tests {
         let interaction = MockInteractionProvider {
             package_selection: crate::traits::PackageSelection::Selected(packages),
             bump_selections: std::sync::Mutex::new(vec![]),
+            suggested_bumps: std::sync::Mutex::new(vec![]),
             category_selection: crate::traits::CategorySelection::Selected(ChangeCategory::Changed),
+            scope_selection: crate::traits::ScopeSelection::Selected(None),
             description: crate::traits::DescriptionInput::Provided("test".to_string()),
         };
 
@@ -452,6 +657,55 @@ mod operation_tests {
         assert!(matches!(err, crate::OperationError::EmptyDescription));
     }
 
+    #[test]
+    fn rejects_summary_failing_lint_when_enabled() {
+        let lint_config = LintConfig::default()
+            .with_enabled(true)
+            .with_denied_words(vec!["teh".to_string()]);
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(RootChangesetConfig::default().with_lint_config(lint_config));
+        let writer = MockChangesetWriter::new();
+        let interaction = MockInteractionProvider::all_cancelled();
+
+        let operation = AddOperation::new(project_provider, writer, interaction);
+
+        let input = AddInput {
+            packages: vec!["my-crate".to_string()],
+            bump: Some(BumpType::Patch),
+            description: Some("Fix `teh parser".to_string()),
+            ..Default::default()
+        };
+
+        let err = operation
+            .execute(Path::new("/any"), input)
+            .expect_err("AddOperation should reject a summary failing lint");
+
+        let crate::OperationError::SummaryLint { issues } = err else {
+            panic!("expected SummaryLint error, got {err:?}");
+        };
+        assert_eq!(issues.len(), 2);
+    }
+
+    #[test]
+    fn skips_lint_when_disabled() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let writer = MockChangesetWriter::new();
+        let interaction = MockInteractionProvider::all_cancelled();
+
+        let operation = AddOperation::new(project_provider, writer, interaction);
+
+        let input = AddInput {
+            packages: vec!["my-crate".to_string()],
+            bump: Some(BumpType::Patch),
+            description: Some("See https://example.com".to_string()),
+            ..Default::default()
+        };
+
+        let result = operation.execute(Path::new("/any"), input);
+
+        assert!(matches!(result, Ok(AddResult::Created { .. })));
+    }
+
     #[test]
     fn uses_interactive_selection_for_workspace_without_explicit_packages() {
         let packages = vec![
@@ -523,7 +777,7 @@ mod operation_tests {
         let input = AddInput {
             packages: vec!["my-crate".to_string()],
             bump: Some(BumpType::Minor),
-            category: ChangeCategory::Fixed,
+            category: Some("fixed".to_string()),
             description: Some("Bug fix".to_string()),
             ..Default::default()
         };
@@ -540,6 +794,186 @@ mod operation_tests {
         }
     }
 
+    #[test]
+    fn resolves_configured_custom_category() {
+        let categories = CategorySet::new(vec![CategoryDefinition {
+            category: ChangeCategory::Custom("perf".to_string()),
+            label: "Performance".to_string(),
+            section: "Performance".to_string(),
+            order: 10,
+        }]);
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(RootChangesetConfig::default().with_categories(categories));
+        let operation = AddOperation::new(
+            project_provider,
+            MockChangesetWriter::new(),
+            MockInteractionProvider::all_cancelled(),
+        );
+
+        let input = AddInput {
+            packages: vec!["my-crate".to_string()],
+            bump: Some(BumpType::Patch),
+            category: Some("Perf".to_string()),
+            description: Some("Faster startup".to_string()),
+            ..Default::default()
+        };
+
+        match operation.execute(Path::new("/any"), input) {
+            Ok(AddResult::Created { changeset, .. }) => {
+                assert_eq!(
+                    changeset.category,
+                    ChangeCategory::Custom("perf".to_string())
+                );
+            }
+            other => panic!("Expected AddResult::Created, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn records_allowed_scope() {
+        let scope_config = ScopeConfig::default().with_scopes(vec!["parser".to_string()]);
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(RootChangesetConfig::default().with_scope_config(scope_config));
+        let operation = AddOperation::new(
+            project_provider,
+            MockChangesetWriter::new(),
+            MockInteractionProvider::all_cancelled(),
+        );
+
+        let input = AddInput {
+            packages: vec!["my-crate".to_string()],
+            bump: Some(BumpType::Patch),
+            scope: Some("parser".to_string()),
+            description: Some("Handle empty input".to_string()),
+            ..Default::default()
+        };
+
+        match operation.execute(Path::new("/any"), input) {
+            Ok(AddResult::Created { changeset, .. }) => {
+                assert_eq!(changeset.scope.as_deref(), Some("parser"));
+            }
+            other => panic!("Expected AddResult::Created, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_disallowed_and_missing_scopes() {
+        let scope_config = ScopeConfig::default()
+            .with_scopes(vec!["parser".to_string()])
+            .with_required(true);
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(RootChangesetConfig::default().with_scope_config(scope_config));
+        let operation = AddOperation::new(
+            project_provider,
+            MockChangesetWriter::new(),
+            MockInteractionProvider::all_cancelled(),
+        );
+        let input = |scope: Option<&str>| AddInput {
+            packages: vec!["my-crate".to_string()],
+            bump: Some(BumpType::Patch),
+            scope: scope.map(ToString::to_string),
+            description: Some("Handle empty input".to_string()),
+            ..Default::default()
+        };
+
+        let err = operation
+            .execute(Path::new("/any"), input(Some("docs")))
+            .expect_err("disallowed scope should fail");
+        assert!(matches!(err, OperationError::UnknownScope { ref scope, .. } if scope == "docs"));
+
+        let err = operation
+            .execute(Path::new("/any"), input(None))
+            .expect_err("missing required scope should fail");
+        assert!(matches!(err, OperationError::MissingScope));
+    }
+
+    #[test]
+    fn prompts_for_scope_when_interactive() {
+        let packages = vec![make_package("my-crate", "1.0.0")];
+        let scope_config = ScopeConfig::default().with_scopes(vec!["cli".to_string()]);
+        let project_provider = MockProjectProvider::workspace(vec![("my-crate", "1.0.0")])
+            .with_root_config(RootChangesetConfig::default().with_scope_config(scope_config));
+        let interaction =
+            MockInteractionProvider::with_selections(packages, BumpType::Patch, "Quote paths")
+                .with_scope("cli");
+        let operation =
+            AddOperation::new(project_provider, MockChangesetWriter::new(), interaction);
+
+        match operation.execute(Path::new("/any"), AddInput::default()) {
+            Ok(AddResult::Created { changeset, .. }) => {
+                assert_eq!(changeset.scope.as_deref(), Some("cli"));
+            }
+            other => panic!("Expected AddResult::Created, got {other:?}"),
+        }
+    }
+
+    struct FixedSuggester(BumpType);
+
+    impl BumpInferenceProvider for FixedSuggester {
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+
+        fn infer_bump(
+            &self,
+            _project_root: &Path,
+            _package: &PackageInfo,
+        ) -> Result<Option<BumpType>> {
+            Ok(Some(self.0))
+        }
+    }
+
+    #[test]
+    fn passes_suggested_bump_to_prompt() {
+        let packages = vec![make_package("my-crate", "1.0.0")];
+        let project_provider = MockProjectProvider::workspace(vec![("my-crate", "1.0.0")]);
+        let interaction =
+            MockInteractionProvider::with_selections(packages, BumpType::Minor, "Add parser");
+        let operation =
+            AddOperation::new(project_provider, MockChangesetWriter::new(), interaction)
+                .with_bump_inference(Box::new(FixedSuggester(BumpType::Minor)));
+
+        let result = operation
+            .execute(Path::new("/any"), AddInput::default())
+            .expect("AddOperation failed with bump inference");
+
+        assert!(matches!(result, AddResult::Created { .. }));
+        assert_eq!(
+            *operation
+                .interaction_provider
+                .suggested_bumps
+                .lock()
+                .expect("lock poisoned"),
+            vec![Some(BumpType::Minor)]
+        );
+    }
+
+    #[test]
+    fn rejects_unconfigured_category() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let operation = AddOperation::new(
+            project_provider,
+            MockChangesetWriter::new(),
+            MockInteractionProvider::all_cancelled(),
+        );
+
+        let input = AddInput {
+            packages: vec!["my-crate".to_string()],
+            bump: Some(BumpType::Patch),
+            category: Some("perf".to_string()),
+            description: Some("Faster startup".to_string()),
+            ..Default::default()
+        };
+
+        let err = operation
+            .execute(Path::new("/any"), input)
+            .expect_err("unknown category should fail");
+        assert!(matches!(
+            err,
+            OperationError::UnknownCategory { ref category, .. } if category == "perf"
+        ));
+    }
+
     #[test]
     fn creates_changeset_file_in_project() {
         let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
@@ -566,4 +1000,69 @@ mod operation_tests {
             _ => panic!("Expected AddResult::Created"),
         }
     }
+
+    fn batch_input(package: &str, description: &str) -> AddInput {
+        AddInput {
+            packages: vec![package.to_string()],
+            bump: Some(BumpType::Minor),
+            description: Some(description.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn batch_creates_every_changeset() {
+        let project_provider =
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")]);
+        let writer = std::sync::Arc::new(MockChangesetWriter::new());
+        let interaction = MockInteractionProvider::all_cancelled();
+        let operation = AddOperation::new(project_provider, writer.clone(), interaction);
+
+        let results = operation
+            .execute_batch(
+                Path::new("/any"),
+                vec![
+                    batch_input("crate-a", "Add feature"),
+                    batch_input("crate-b", "Add another feature"),
+                ],
+            )
+            .expect("batch should succeed");
+
+        assert_eq!(results.len(), 2);
+        assert!(
+            results
+                .iter()
+                .all(|result| matches!(result, AddResult::Created { .. }))
+        );
+        let summaries: Vec<_> = writer
+            .written_changesets()
+            .into_iter()
+            .map(|(_, changeset)| changeset.summary)
+            .collect();
+        assert_eq!(summaries, ["Add feature", "Add another feature"]);
+    }
+
+    #[test]
+    fn batch_writes_nothing_when_an_input_is_invalid() {
+        let project_provider =
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")]);
+        let writer = std::sync::Arc::new(MockChangesetWriter::new());
+        let interaction = MockInteractionProvider::all_cancelled();
+        let operation = AddOperation::new(project_provider, writer.clone(), interaction);
+
+        let err = operation
+            .execute_batch(
+                Path::new("/any"),
+                vec![
+                    batch_input("crate-a", "Add feature"),
+                    batch_input("crate-c", "Unknown package"),
+                ],
+            )
+            .expect_err("batch with an unknown package should fail");
+
+        assert!(
+            matches!(err, OperationError::UnknownPackage { ref name, .. } if name == "crate-c")
+        );
+        assert!(writer.written_changesets().is_empty());
+    }
 }
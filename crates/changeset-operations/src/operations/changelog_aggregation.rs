@@ -1,24 +1,100 @@
 use std::collections::HashMap;
+use std::time::SystemTime;
 
-use changeset_changelog::{ChangelogEntry, VersionRelease};
-use changeset_core::Changeset;
+use changeset_changelog::{
+    ChangelogConfig, ChangelogEntry, ChangelogFormat, EntryOrder, VersionRelease,
+};
+use changeset_core::{CategorySet, Changeset};
 use chrono::NaiveDate;
 use semver::Version;
 
+/// Text that replaces the summary of an embargoed confidential changeset.
+pub const CONFIDENTIAL_PLACEHOLDER: &str =
+    "Security fix; details will be published after the embargo.";
+
+/// Collects changelog entries per package.
+///
+/// Released entries are sorted by the configured [`EntryOrder`], which always
+/// falls back to the changeset file name, so the rendered changelog does not
+/// depend on the order in which changeset files were read.
 pub(crate) struct ChangesetAggregator {
     entries_by_package: HashMap<String, Vec<ChangelogEntry>>,
+    redact_embargoed_on: Option<NaiveDate>,
+    display_names: HashMap<String, String>,
+    categories: CategorySet,
+    entry_order: EntryOrder,
+    format: ChangelogFormat,
+    json: bool,
 }
 
 impl ChangesetAggregator {
     pub(crate) fn new() -> Self {
         Self {
             entries_by_package: HashMap::new(),
+            redact_embargoed_on: None,
+            display_names: HashMap::new(),
+            categories: CategorySet::builtin(),
+            entry_order: EntryOrder::default(),
+            format: ChangelogFormat::default(),
+            json: false,
         }
     }
 
-    pub(crate) fn add_changeset(&mut self, changeset: &Changeset) {
+    /// Labels root changelog entries with these names instead of crate names.
+    pub(crate) fn with_display_names(mut self, display_names: HashMap<String, String>) -> Self {
+        self.display_names = display_names;
+        self
+    }
+
+    /// Groups changelog entries by these categories instead of the built-in ones.
+    pub(crate) fn with_categories(mut self, categories: CategorySet) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Applies the entry order, format and outputs of `config`.
+    pub(crate) fn with_changelog_config(mut self, config: &ChangelogConfig) -> Self {
+        self.entry_order = config.entry_order;
+        self.format = config.format;
+        self.json = config.writes_json();
+        self
+    }
+
+    /// Replaces summaries of changesets still embargoed on `today` with a placeholder.
+    pub(crate) fn redact_embargoed(mut self, today: NaiveDate) -> Self {
+        self.redact_embargoed_on = Some(today);
+        self
+    }
+
+    /// Adds the entries of `changeset`, read from the file named `file_name`
+    /// that was last modified at `timestamp`.
+    pub(crate) fn add_changeset(
+        &mut self,
+        file_name: &str,
+        timestamp: Option<SystemTime>,
+        changeset: &Changeset,
+    ) {
+        let redacted = self
+            .redact_embargoed_on
+            .is_some_and(|today| changeset.is_embargoed(today));
+        let description = if redacted {
+            CONFIDENTIAL_PLACEHOLDER
+        } else {
+            &changeset.summary
+        };
+
         for release in &changeset.releases {
-            let entry = ChangelogEntry::new(changeset.category, &changeset.summary);
+            let mut entry = ChangelogEntry::new(changeset.category.clone(), description)
+                .with_file_name(file_name);
+            if let Some(scope) = &changeset.scope {
+                entry = entry.with_scope(scope);
+            }
+            if let Some(advisory) = changeset.advisory.as_ref().filter(|_| !redacted) {
+                entry = entry.with_advisory(advisory);
+            }
+            if let Some(timestamp) = timestamp {
+                entry = entry.with_timestamp(timestamp);
+            }
             self.entries_by_package
                 .entry(release.name.clone())
                 .or_default()
@@ -37,7 +113,7 @@ impl ChangesetAggregator {
             return None;
         }
 
-        Some(VersionRelease::new(version.clone(), date, entries.clone()))
+        Some(self.release(version, date, entries.clone()))
     }
 
     pub(crate) fn build_root_release(
@@ -51,7 +127,8 @@ impl ChangesetAggregator {
         for (package_name, _) in packages {
             if let Some(entries) = self.entries_by_package.get(package_name) {
                 for entry in entries {
-                    let prefixed_entry = entry.clone().with_package(package_name);
+                    let label = self.display_names.get(package_name).unwrap_or(package_name);
+                    let prefixed_entry = entry.clone().with_package(label);
                     all_entries.push(prefixed_entry);
                 }
             }
@@ -61,7 +138,24 @@ impl ChangesetAggregator {
             return None;
         }
 
-        Some(VersionRelease::new(version.clone(), date, all_entries))
+        Some(
+            self.release(version, date, all_entries)
+                .with_package_versions(packages.to_vec()),
+        )
+    }
+
+    fn release(
+        &self,
+        version: &Version,
+        date: NaiveDate,
+        mut entries: Vec<ChangelogEntry>,
+    ) -> VersionRelease {
+        entries.sort_by(|a, b| self.entry_order.compare(&self.categories, a, b));
+        VersionRelease::new(version.clone(), date, entries)
+            .with_categories(self.categories.clone())
+            .with_entry_order(self.entry_order)
+            .with_format(self.format)
+            .with_json(self.json)
     }
 }
 
@@ -82,8 +176,12 @@ mod tests {
                 })
                 .collect(),
             category,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         }
     }
 
@@ -106,7 +204,7 @@ mod tests {
         let mut aggregator = ChangesetAggregator::new();
         let changeset = make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fixed a bug");
 
-        aggregator.add_changeset(&changeset);
+        aggregator.add_changeset("changeset.md", None, &changeset);
 
         let release = aggregator
             .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
@@ -118,20 +216,35 @@ mod tests {
         assert!(release.entries[0].package.is_none());
     }
 
+    #[test]
+    fn scope_is_carried_into_entry() {
+        let mut aggregator = ChangesetAggregator::new();
+        let mut changeset = make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fixed a bug");
+        changeset.scope = Some("parser".to_string());
+
+        aggregator.add_changeset("changeset.md", None, &changeset);
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
+            .expect("release should exist");
+
+        assert_eq!(release.entries[0].scope.as_deref(), Some("parser"));
+    }
+
     #[test]
     fn multiple_changesets_same_package() {
         let mut aggregator = ChangesetAggregator::new();
 
-        aggregator.add_changeset(&make_changeset(
-            &["my-crate"],
-            ChangeCategory::Fixed,
-            "Fix 1",
-        ));
-        aggregator.add_changeset(&make_changeset(
-            &["my-crate"],
-            ChangeCategory::Added,
-            "Feature 1",
-        ));
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fix 1"),
+        );
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Added, "Feature 1"),
+        );
 
         let release = aggregator
             .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
@@ -149,7 +262,7 @@ mod tests {
             "Updated both",
         );
 
-        aggregator.add_changeset(&changeset);
+        aggregator.add_changeset("changeset.md", None, &changeset);
 
         let release_a = aggregator
             .build_package_release("crate-a", &Version::new(1, 0, 0), test_date())
@@ -168,11 +281,11 @@ mod tests {
     fn categories_preserved() {
         let mut aggregator = ChangesetAggregator::new();
 
-        aggregator.add_changeset(&make_changeset(
-            &["my-crate"],
-            ChangeCategory::Security,
-            "Security fix",
-        ));
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Security, "Security fix"),
+        );
 
         let release = aggregator
             .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
@@ -185,16 +298,16 @@ mod tests {
     fn build_root_release_prefixes_packages() {
         let mut aggregator = ChangesetAggregator::new();
 
-        aggregator.add_changeset(&make_changeset(
-            &["crate-a"],
-            ChangeCategory::Added,
-            "Feature A",
-        ));
-        aggregator.add_changeset(&make_changeset(
-            &["crate-b"],
-            ChangeCategory::Fixed,
-            "Fix B",
-        ));
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_changeset(&["crate-a"], ChangeCategory::Added, "Feature A"),
+        );
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_changeset(&["crate-b"], ChangeCategory::Fixed, "Fix B"),
+        );
 
         let packages = vec![
             ("crate-a".to_string(), Version::new(1, 1, 0)),
@@ -218,6 +331,28 @@ mod tests {
 
         assert!(has_crate_a, "Should have crate-a entry");
         assert!(has_crate_b, "Should have crate-b entry");
+        assert_eq!(release.package_versions, packages);
+    }
+
+    #[test]
+    fn build_root_release_uses_display_names() {
+        let mut aggregator = ChangesetAggregator::new().with_display_names(HashMap::from([(
+            "crate-internal-name".to_string(),
+            "Widget SDK".to_string(),
+        )]));
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_changeset(&["crate-internal-name"], ChangeCategory::Added, "Feature A"),
+        );
+
+        let packages = vec![("crate-internal-name".to_string(), Version::new(1, 1, 0))];
+
+        let release = aggregator
+            .build_root_release(&Version::new(1, 1, 0), test_date(), &packages)
+            .expect("release should exist");
+
+        assert_eq!(release.entries[0].package.as_deref(), Some("Widget SDK"));
     }
 
     #[test]
@@ -229,4 +364,190 @@ mod tests {
 
         assert!(release.is_none());
     }
+
+    fn make_confidential(embargo: Option<NaiveDate>) -> Changeset {
+        Changeset {
+            confidential: true,
+            embargo,
+            advisory: Some("RUSTSEC-2024-0001".to_string()),
+            ..make_changeset(&["my-crate"], ChangeCategory::Security, "Fix CVE details")
+        }
+    }
+
+    #[test]
+    fn embargoed_summary_is_redacted() {
+        let mut aggregator = ChangesetAggregator::new().redact_embargoed(test_date());
+        aggregator.add_changeset("changeset.md", None, &make_confidential(None));
+        aggregator.add_changeset(
+            "changeset.md",
+            None,
+            &make_confidential(NaiveDate::from_ymd_opt(2025, 2, 1)),
+        );
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 1), test_date())
+            .expect("release should exist");
+
+        assert_eq!(release.entries.len(), 2);
+        for entry in &release.entries {
+            assert_eq!(entry.description, CONFIDENTIAL_PLACEHOLDER);
+            assert_eq!(entry.category, ChangeCategory::Security);
+            assert_eq!(entry.advisory, None);
+        }
+    }
+
+    #[test]
+    fn summary_is_published_once_embargo_ends() {
+        let mut aggregator = ChangesetAggregator::new().redact_embargoed(test_date());
+        aggregator.add_changeset("changeset.md", None, &make_confidential(Some(test_date())));
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 1), test_date())
+            .expect("release should exist");
+
+        assert_eq!(release.entries[0].description, "Fix CVE details");
+        assert_eq!(
+            release.entries[0].advisory.as_deref(),
+            Some("RUSTSEC-2024-0001")
+        );
+    }
+
+    #[test]
+    fn confidential_summary_kept_without_redaction() {
+        let mut aggregator = ChangesetAggregator::new();
+        aggregator.add_changeset("changeset.md", None, &make_confidential(None));
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 1), test_date())
+            .expect("release should exist");
+
+        assert_eq!(release.entries[0].description, "Fix CVE details");
+    }
+
+    #[test]
+    fn package_entries_sorted_by_category_then_file_name() {
+        let mut aggregator = ChangesetAggregator::new();
+        aggregator.add_changeset(
+            "b.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fix B"),
+        );
+        aggregator.add_changeset(
+            "c.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Added, "Feature C"),
+        );
+        aggregator.add_changeset(
+            "a.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fix A"),
+        );
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
+            .expect("release should exist");
+
+        let descriptions: Vec<_> = release
+            .entries
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["Feature C", "Fix A", "Fix B"]);
+    }
+
+    #[test]
+    fn root_entries_sorted_by_category_then_package_then_file_name() {
+        let mut aggregator = ChangesetAggregator::new();
+        aggregator.add_changeset(
+            "a.md",
+            None,
+            &make_changeset(&["crate-b"], ChangeCategory::Fixed, "Fix B"),
+        );
+        aggregator.add_changeset(
+            "b.md",
+            None,
+            &make_changeset(&["crate-a"], ChangeCategory::Fixed, "Fix A2"),
+        );
+        aggregator.add_changeset(
+            "a.md",
+            None,
+            &make_changeset(&["crate-a"], ChangeCategory::Fixed, "Fix A1"),
+        );
+        aggregator.add_changeset(
+            "z.md",
+            None,
+            &make_changeset(&["crate-b"], ChangeCategory::Added, "Feature B"),
+        );
+
+        let packages = vec![
+            ("crate-b".to_string(), Version::new(1, 1, 0)),
+            ("crate-a".to_string(), Version::new(1, 0, 1)),
+        ];
+        let release = aggregator
+            .build_root_release(&Version::new(1, 1, 0), test_date(), &packages)
+            .expect("release should exist");
+
+        let descriptions: Vec<_> = release
+            .entries
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["Feature B", "Fix A1", "Fix A2", "Fix B"]);
+    }
+
+    #[test]
+    fn entries_sorted_by_configured_order() {
+        let at = |secs| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+        let mut aggregator = ChangesetAggregator::new().with_changelog_config(&ChangelogConfig {
+            entry_order: EntryOrder::Timestamp,
+            ..ChangelogConfig::default()
+        });
+        aggregator.add_changeset(
+            "a.md",
+            Some(at(20)),
+            &make_changeset(&["my-crate"], ChangeCategory::Fixed, "Later fix"),
+        );
+        aggregator.add_changeset(
+            "b.md",
+            Some(at(10)),
+            &make_changeset(&["my-crate"], ChangeCategory::Added, "Earlier feature"),
+        );
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
+            .expect("release should exist");
+
+        let descriptions: Vec<_> = release
+            .entries
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["Earlier feature", "Later fix"]);
+        assert_eq!(release.entry_order, EntryOrder::Timestamp);
+        assert_eq!(release.entries[0].file_name.as_deref(), Some("b.md"));
+    }
+
+    #[test]
+    fn file_order_preserves_insertion_order() {
+        let mut aggregator = ChangesetAggregator::new().with_changelog_config(&ChangelogConfig {
+            entry_order: EntryOrder::File,
+            ..ChangelogConfig::default()
+        });
+        aggregator.add_changeset(
+            "b.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fix B"),
+        );
+        aggregator.add_changeset(
+            "a.md",
+            None,
+            &make_changeset(&["my-crate"], ChangeCategory::Fixed, "Fix A"),
+        );
+
+        let release = aggregator
+            .build_package_release("my-crate", &Version::new(1, 0, 0), test_date())
+            .expect("release should exist");
+
+        assert_eq!(release.entries[0].description, "Fix B");
+    }
 }
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use changeset_changelog::{
+    ChangelogLocation, ChangelogPage, JsonChangelog, JsonEntry, PageFrontMatter,
+};
+use changeset_project::CargoProject;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{ProjectProvider, TextFileIO};
+
+/// Default location of the page tree, relative to the project root.
+pub const DEFAULT_CHANGELOG_PAGES_DIR: &str = "docs/changelog";
+
+#[derive(Debug, Clone)]
+pub struct ChangelogPagesInput {
+    /// Directory the `<package>/<version>.md` pages are written to; relative
+    /// paths are resolved against the project root.
+    pub out_dir: PathBuf,
+    pub front_matter: PageFrontMatter,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogPagesOutput {
+    /// Pages written, in release order per changelog.
+    pub pages: Vec<PathBuf>,
+}
+
+/// Regenerates one docs page per package and release from the `CHANGELOG.json`
+/// files, so a docs site built by mdBook, Zola or similar stays in sync with
+/// the releases.
+pub struct ChangelogPagesOperation<P, T> {
+    project_provider: P,
+    text_file_io: T,
+}
+
+impl<P, T> ChangelogPagesOperation<P, T>
+where
+    P: ProjectProvider,
+    T: TextFileIO,
+{
+    pub fn new(project_provider: P, text_file_io: T) -> Self {
+        Self {
+            project_provider,
+            text_file_io,
+        }
+    }
+
+    /// Writes a page for every release of every package.
+    ///
+    /// Existing pages are overwritten; pages of versions no longer in the
+    /// changelogs are left in place. Changelogs that do not exist yet are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::JsonChangelogNotConfigured` if `CHANGELOG.json`
+    /// is not kept, or an error if the project cannot be discovered or a
+    /// changelog cannot be read or a page cannot be written.
+    pub fn execute(
+        &self,
+        start_path: &Path,
+        input: &ChangelogPagesInput,
+    ) -> Result<ChangelogPagesOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let changelog_config = root_config.changelog_config();
+        if !changelog_config.writes_json() {
+            return Err(OperationError::JsonChangelogNotConfigured);
+        }
+
+        let pages = match changelog_config.changelog {
+            ChangelogLocation::Root => self
+                .read_changelog(&project.root.join("CHANGELOG.json"))?
+                .map(|changelog| root_pages(&changelog, root_config.display_names()))
+                .unwrap_or_default(),
+            ChangelogLocation::PerPackage => self.package_pages(&project)?,
+        };
+
+        let out_dir = project.root.join(&input.out_dir);
+        let mut written = Vec::with_capacity(pages.len());
+        for page in pages {
+            let path = out_dir.join(page.relative_path());
+            self.text_file_io
+                .write_file(&path, &page.render(input.front_matter))?;
+            written.push(path);
+        }
+
+        Ok(ChangelogPagesOutput { pages: written })
+    }
+
+    fn package_pages(&self, project: &CargoProject) -> Result<Vec<ChangelogPage>> {
+        let mut pages = Vec::new();
+        for package in &project.packages {
+            let Some(changelog) = self.read_changelog(&package.path.join("CHANGELOG.json"))? else {
+                continue;
+            };
+            pages.extend(changelog.releases.into_iter().map(|release| ChangelogPage {
+                package: package.name.clone(),
+                version: release.version,
+                date: release.date,
+                entries: release.entries,
+            }));
+        }
+        Ok(pages)
+    }
+
+    fn read_changelog(&self, path: &Path) -> Result<Option<JsonChangelog>> {
+        let content = match self.text_file_io.read_file(path) {
+            Ok(content) => content,
+            Err(OperationError::TextFileRead { source, .. })
+                if source.kind() == ErrorKind::NotFound =>
+            {
+                return Ok(None);
+            }
+            Err(err) => return Err(err),
+        };
+        JsonChangelog::parse(&content).map(Some).map_err(|source| {
+            changeset_changelog::ChangelogError::InvalidJson {
+                path: path.to_path_buf(),
+                source,
+            }
+            .into()
+        })
+    }
+}
+
+/// Splits each release of a shared changelog into the pages of the packages
+/// it released, using the package versions the release recorded.
+fn root_pages(
+    changelog: &JsonChangelog,
+    display_names: &HashMap<String, String>,
+) -> Vec<ChangelogPage> {
+    let mut pages = Vec::new();
+    for release in &changelog.releases {
+        for (package, version) in &release.packages {
+            let label = display_names.get(package).unwrap_or(package);
+            let entries: Vec<JsonEntry> = release
+                .entries
+                .iter()
+                .filter(|entry| entry.package.as_ref() == Some(label))
+                .map(|entry| JsonEntry {
+                    package: None,
+                    ..entry.clone()
+                })
+                .collect();
+            if entries.is_empty() {
+                continue;
+            }
+            pages.push(ChangelogPage {
+                package: package.clone(),
+                version: version.clone(),
+                date: release.date.clone(),
+                entries,
+            });
+        }
+    }
+    pages
+}
+
+#[cfg(test)]
+mod tests {
+    use changeset_changelog::ChangelogConfig;
+    use changeset_changelog::ChangelogOutput;
+    use changeset_project::RootChangesetConfig;
+
+    use super::*;
+    use crate::providers::memory::{InMemoryProjectProvider, InMemoryTextFileIO};
+
+    const ROOT_CHANGELOG: &str = r#"{
+  "releases": [
+    {
+      "version": "2.0.0",
+      "date": "2025-02-01",
+      "packages": { "crate-a": "1.1.0", "crate-b": "2.0.0" },
+      "entries": [
+        { "category": "added", "section": "Added", "description": "New API", "package": "Crate A" },
+        { "category": "fixed", "section": "Fixed", "description": "Fix B", "package": "crate-b" }
+      ]
+    }
+  ]
+}
+"#;
+
+    fn json_config(changelog: ChangelogLocation) -> RootChangesetConfig {
+        RootChangesetConfig::default().with_changelog_config(ChangelogConfig {
+            changelog,
+            output: vec![ChangelogOutput::Markdown, ChangelogOutput::Json],
+            ..ChangelogConfig::default()
+        })
+    }
+
+    fn input() -> ChangelogPagesInput {
+        ChangelogPagesInput {
+            out_dir: PathBuf::from(DEFAULT_CHANGELOG_PAGES_DIR),
+            front_matter: PageFrontMatter::None,
+        }
+    }
+
+    #[test]
+    fn shared_changelog_is_split_per_package() -> anyhow::Result<()> {
+        let root = PathBuf::from("/mock/workspace");
+        let io = InMemoryTextFileIO::new().with_file(root.join("CHANGELOG.json"), ROOT_CHANGELOG);
+        let provider =
+            InMemoryProjectProvider::workspace(vec![("crate-a", "1.1.0"), ("crate-b", "2.0.0")])
+                .with_root_config(
+                    json_config(ChangelogLocation::Root).with_display_name("crate-a", "Crate A"),
+                );
+        let operation = ChangelogPagesOperation::new(provider, io);
+
+        let output = operation.execute(&root, &input())?;
+
+        let pages_dir = root.join(DEFAULT_CHANGELOG_PAGES_DIR);
+        assert_eq!(
+            output.pages,
+            [
+                pages_dir.join("crate-a/1.1.0.md"),
+                pages_dir.join("crate-b/2.0.0.md")
+            ]
+        );
+        let page_a = operation
+            .text_file_io
+            .file(&output.pages[0])
+            .expect("page written");
+        assert!(page_a.starts_with("# crate-a 1.1.0\n"));
+        assert!(page_a.contains("- New API\n"));
+        assert!(!page_a.contains("Fix B"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn per_package_changelogs_get_a_page_per_release() -> anyhow::Result<()> {
+        let root = PathBuf::from("/mock/workspace");
+        let io = InMemoryTextFileIO::new().with_file(
+            root.join("crates/crate-a/CHANGELOG.json"),
+            r#"{"releases": [
+                {"version": "1.1.0", "date": "2025-02-01", "entries": []},
+                {"version": "1.0.0", "date": "2025-01-01", "entries": []}
+            ]}"#,
+        );
+        let provider =
+            InMemoryProjectProvider::workspace(vec![("crate-a", "1.1.0"), ("crate-b", "2.0.0")])
+                .with_root_config(json_config(ChangelogLocation::PerPackage));
+        let operation = ChangelogPagesOperation::new(provider, io);
+
+        let output = operation.execute(&root, &input())?;
+
+        let pages_dir = root.join(DEFAULT_CHANGELOG_PAGES_DIR);
+        assert_eq!(
+            output.pages,
+            [
+                pages_dir.join("crate-a/1.1.0.md"),
+                pages_dir.join("crate-a/1.0.0.md")
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn requires_json_changelog() {
+        let operation = ChangelogPagesOperation::new(
+            InMemoryProjectProvider::single_package("solo", "1.0.0"),
+            InMemoryTextFileIO::new(),
+        );
+
+        let err = operation
+            .execute(Path::new("/any"), &input())
+            .expect_err("json output not configured");
+
+        assert!(matches!(err, OperationError::JsonChangelogNotConfigured));
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+
+use changeset_project::{
+    changed_keys, dependency_specs, is_workspace_inherited, locked_versions,
+    workspace_dependency_specs,
+};
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{GitProvider, ProjectProvider};
+
+/// The external dependencies of one package that changed since the base revision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageDependencyUpdate {
+    pub package: String,
+    /// Sorted dependency names.
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct DependencyUpdateOutput {
+    /// Packages with updated dependencies, in workspace order.
+    pub updates: Vec<PackageDependencyUpdate>,
+}
+
+impl DependencyUpdateOutput {
+    #[must_use]
+    pub fn packages(&self) -> Vec<String> {
+        self.updates
+            .iter()
+            .map(|update| update.package.clone())
+            .collect()
+    }
+
+    /// Every updated dependency once, sorted.
+    #[must_use]
+    pub fn dependencies(&self) -> Vec<String> {
+        self.updates
+            .iter()
+            .flat_map(|update| update.dependencies.iter().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// The standard changeset summary, e.g. `Update dependencies: log, serde`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!("Update dependencies: {}", self.dependencies().join(", "))
+    }
+}
+
+/// Finds the packages whose external dependencies changed since a base
+/// revision, as bots like Dependabot or Renovate change them.
+pub struct DependencyUpdateOperation<P, G> {
+    project_provider: P,
+    git_provider: G,
+}
+
+impl<P, G> DependencyUpdateOperation<P, G>
+where
+    P: ProjectProvider,
+    G: GitProvider,
+{
+    pub fn new(project_provider: P, git_provider: G) -> Self {
+        Self {
+            project_provider,
+            git_provider,
+        }
+    }
+
+    /// Compares each package's dependency tables, the root
+    /// `[workspace.dependencies]` table and `Cargo.lock` between `base` and
+    /// the working tree.
+    ///
+    /// A dependency counts as updated for a package when its spec in the
+    /// package manifest changed, when it inherits a workspace dependency whose
+    /// spec changed, or when its locked version changed. Workspace members and
+    /// dev-dependencies are left out, since neither affects the package's users.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be loaded, a file cannot be read
+    /// at `base` or in the working tree, or a manifest cannot be parsed.
+    pub fn execute(&self, start_path: &Path, base: &str) -> Result<DependencyUpdateOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let root = &project.root;
+        let members: HashSet<&str> = project
+            .packages
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect();
+
+        let root_manifest = root.join("Cargo.toml");
+        let workspace_changes = changed_keys(
+            &self.read_at(root, base, &root_manifest, workspace_dependency_specs)?,
+            &read_current(&root_manifest, workspace_dependency_specs)?,
+        );
+        let lockfile = root.join("Cargo.lock");
+        let lock_changes = changed_keys(
+            &self.read_at(root, base, &lockfile, locked_versions)?,
+            &read_current(&lockfile, locked_versions)?,
+        );
+
+        let mut updates = Vec::new();
+        for package in &project.cargo_packages() {
+            let manifest = package.path.join("Cargo.toml");
+            let base_specs = self.read_at(root, base, &manifest, dependency_specs)?;
+            let current_specs = read_current(&manifest, dependency_specs)?;
+
+            let mut dependencies = changed_keys(&base_specs, &current_specs);
+            dependencies.extend(
+                current_specs
+                    .iter()
+                    .filter(|(name, spec)| {
+                        (is_workspace_inherited(spec) && workspace_changes.contains(*name))
+                            || lock_changes.contains(*name)
+                    })
+                    .map(|(name, _)| name.clone()),
+            );
+            dependencies.retain(|name| !members.contains(name.as_str()));
+
+            if !dependencies.is_empty() {
+                updates.push(PackageDependencyUpdate {
+                    package: package.name.clone(),
+                    dependencies: dependencies.into_iter().collect(),
+                });
+            }
+        }
+
+        Ok(DependencyUpdateOutput { updates })
+    }
+
+    /// Parses `path` as of `base`; a file missing there parses as empty.
+    fn read_at<V>(
+        &self,
+        root: &Path,
+        base: &str,
+        path: &Path,
+        parse: impl Fn(&Path, &str) -> changeset_project::Result<BTreeMap<String, V>>,
+    ) -> Result<BTreeMap<String, V>> {
+        match self.git_provider.file_at_ref(root, base, path)? {
+            Some(content) => Ok(parse(path, &content)?),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+}
+
+/// Parses `path` from the working tree; a missing file parses as empty.
+fn read_current<V>(
+    path: &Path,
+    parse: impl Fn(&Path, &str) -> changeset_project::Result<BTreeMap<String, V>>,
+) -> Result<BTreeMap<String, V>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(parse(path, &content)?),
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(source) => Err(OperationError::TextFileRead {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use changeset_core::PackageInfo;
+    use changeset_project::{CargoProject, ProjectKind};
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::mocks::{MockGitProvider, MockProjectProvider};
+
+    const BASE_LOCK: &str = "[[package]]\nname = \"log\"\nversion = \"0.4.20\"\n\n\
+                             [[package]]\nname = \"rand\"\nversion = \"0.8.5\"\n";
+
+    fn package(root: &Path, name: &str) -> PackageInfo {
+        PackageInfo {
+            name: name.to_string(),
+            version: "1.0.0".parse().expect("valid version"),
+            path: root.join("crates").join(name),
+        }
+    }
+
+    fn write(path: &Path, content: &str) -> anyhow::Result<()> {
+        fs::create_dir_all(path.parent().unwrap_or(path))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    #[test]
+    fn collects_manifest_workspace_and_lockfile_updates() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let root = dir.path();
+        let app_manifest = "[package]\nname = \"app\"\n[dependencies]\n\
+                            lib = { path = \"../lib\" }\nserde = { workspace = true }\n";
+        let lib_base = "[package]\nname = \"lib\"\n[dependencies]\nlog = \"0.4\"\nrand = \"0.8\"\n";
+        let lib_current = "[package]\nname = \"lib\"\n[dependencies]\nlog = \"0.4\"\nrand = \"0.8\"\n\
+             [dev-dependencies]\ninsta = \"1\"\n";
+        let tool_manifest = "[package]\nname = \"tool\"\n[dependencies]\nrand = \"0.8\"\n";
+        write(
+            &root.join("Cargo.toml"),
+            "[workspace]\n[workspace.dependencies]\nserde = \"1.0.200\"\n",
+        )?;
+        write(
+            &root.join("Cargo.lock"),
+            &BASE_LOCK.replace("0.4.20", "0.4.21"),
+        )?;
+        write(&root.join("crates/app/Cargo.toml"), app_manifest)?;
+        write(&root.join("crates/lib/Cargo.toml"), lib_current)?;
+        write(&root.join("crates/tool/Cargo.toml"), tool_manifest)?;
+
+        let git = MockGitProvider::new()
+            .with_file_at_ref(
+                "main",
+                root.join("Cargo.toml"),
+                "[workspace]\n[workspace.dependencies]\nserde = \"1.0.100\"\n",
+            )
+            .with_file_at_ref("main", root.join("Cargo.lock"), BASE_LOCK)
+            .with_file_at_ref("main", root.join("crates/app/Cargo.toml"), app_manifest)
+            .with_file_at_ref("main", root.join("crates/lib/Cargo.toml"), lib_base)
+            .with_file_at_ref("main", root.join("crates/tool/Cargo.toml"), tool_manifest);
+        let project = CargoProject {
+            root: root.to_path_buf(),
+            kind: ProjectKind::VirtualWorkspace,
+            packages: vec![
+                package(root, "app"),
+                package(root, "lib"),
+                package(root, "tool"),
+            ],
+            missing_members: Vec::<PathBuf>::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        };
+        let operation = DependencyUpdateOperation::new(MockProjectProvider::new(project), git);
+
+        let output = operation.execute(root, "main")?;
+
+        assert_eq!(
+            output.updates,
+            [
+                PackageDependencyUpdate {
+                    package: "app".to_string(),
+                    dependencies: vec!["serde".to_string()],
+                },
+                PackageDependencyUpdate {
+                    package: "lib".to_string(),
+                    dependencies: vec!["log".to_string()],
+                },
+            ]
+        );
+        assert_eq!(output.summary(), "Update dependencies: log, serde");
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use crate::Result;
+use crate::operations::drift::{VersionDrift, detect_version_drift};
+use crate::traits::{GitProvider, OutdatedStateFile, ProjectProvider, ReleaseStateIO};
+
+#[derive(Debug, Default)]
+pub struct DoctorInput {
+    /// Rewrite state files stored in an older layout.
+    pub migrate: bool,
+}
+
+#[derive(Debug)]
+pub struct DoctorOutput {
+    /// Packages whose manifest version differs from their latest release tag.
+    pub version_drift: Vec<VersionDrift>,
+    /// State files still stored in an older layout. They remain readable.
+    pub outdated_state_files: Vec<OutdatedStateFile>,
+    /// State files rewritten in the current layout.
+    pub migrated_state_files: Vec<OutdatedStateFile>,
+}
+
+impl DoctorOutput {
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.version_drift.is_empty()
+    }
+}
+
+pub struct DoctorOperation<P, G, S> {
+    project_provider: P,
+    git_provider: G,
+    release_state_io: S,
+}
+
+impl<P, G, S> DoctorOperation<P, G, S>
+where
+    P: ProjectProvider,
+    G: GitProvider,
+    S: ReleaseStateIO,
+{
+    pub fn new(project_provider: P, git_provider: G, release_state_io: S) -> Self {
+        Self {
+            project_provider,
+            git_provider,
+            release_state_io,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be discovered, its configuration
+    /// cannot be loaded, the repository tags cannot be listed, or a state file
+    /// cannot be read or migrated.
+    pub fn execute(&self, start_path: &Path, input: &DoctorInput) -> Result<DoctorOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+
+        let tags = self.git_provider.list_tags(&project.root)?;
+        let version_drift = detect_version_drift(
+            &project,
+            root_config.git_config(),
+            root_config.channels(),
+            &tags,
+        );
+
+        let changeset_dir = project.root.join(root_config.changeset_dir());
+        let migrated_state_files = if input.migrate {
+            self.release_state_io.migrate_state_files(&changeset_dir)?
+        } else {
+            Vec::new()
+        };
+        let outdated_state_files = self.release_state_io.outdated_state_files(&changeset_dir)?;
+
+        Ok(DoctorOutput {
+            version_drift,
+            outdated_state_files,
+            migrated_state_files,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{MockGitProvider, MockProjectProvider, MockReleaseStateIO};
+
+    #[test]
+    fn healthy_when_versions_match_tags() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let git_provider = MockGitProvider::new().with_existing_tags(&["v0.9.0", "v1.0.0"]);
+
+        let output =
+            DoctorOperation::new(project_provider, git_provider, MockReleaseStateIO::new())
+                .execute(Path::new("/any"), &DoctorInput::default())
+                .expect("DoctorOperation failed");
+
+        assert!(output.is_healthy());
+    }
+
+    #[test]
+    fn reports_drift_when_tag_is_ahead() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let git_provider = MockGitProvider::new().with_existing_tags(&["v1.2.0"]);
+
+        let output =
+            DoctorOperation::new(project_provider, git_provider, MockReleaseStateIO::new())
+                .execute(Path::new("/any"), &DoctorInput::default())
+                .expect("DoctorOperation failed");
+
+        assert!(!output.is_healthy());
+        assert_eq!(
+            output.version_drift[0].manifest_version.to_string(),
+            "1.0.0"
+        );
+    }
+}
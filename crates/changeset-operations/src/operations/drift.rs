@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+
+use changeset_core::PackageInfo;
+use changeset_project::{CargoProject, GitConfig, ReleaseChannel};
+use semver::Version;
+
+use crate::tags::{TagNaming, uses_crate_prefix};
+
+/// Direction of a mismatch between a manifest version and the latest tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftKind {
+    /// `Cargo.toml` declares a version newer than the latest tag.
+    ManifestAhead,
+    /// The latest tag is newer than the version declared in `Cargo.toml`.
+    TagAhead,
+}
+
+/// A package whose manifest version does not match its latest release tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDrift {
+    pub package: String,
+    pub manifest_version: Version,
+    pub tag_version: Version,
+    pub kind: DriftKind,
+}
+
+/// Compares each package's manifest version with its latest release tag.
+///
+/// Tags in the namespace of one of `channels` count as release tags. Packages
+/// without any matching tag are skipped, as are all packages when tagging is
+/// disabled in the configuration.
+#[must_use]
+pub fn detect_version_drift(
+    project: &CargoProject,
+    git_config: &GitConfig,
+    channels: &[ReleaseChannel],
+    tags: &[String],
+) -> Vec<VersionDrift> {
+    if !git_config.tags() {
+        return Vec::new();
+    }
+
+    let naming = TagNaming::new(uses_crate_prefix(&project.kind, git_config.tag_format()))
+        .with_channels(channels);
+
+    project
+        .packages
+        .iter()
+        .filter_map(|package| package_drift(package, tags, &naming))
+        .collect()
+}
+
+fn package_drift(
+    package: &PackageInfo,
+    tags: &[String],
+    naming: &TagNaming,
+) -> Option<VersionDrift> {
+    let tag_version = naming.latest_version(tags, &package.name)?;
+
+    let kind = match package.version.cmp(&tag_version) {
+        Ordering::Equal => return None,
+        Ordering::Greater => DriftKind::ManifestAhead,
+        Ordering::Less => DriftKind::TagAhead,
+    };
+
+    Some(VersionDrift {
+        package: package.name.clone(),
+        manifest_version: package.version.clone(),
+        tag_version,
+        kind,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use changeset_project::ProjectKind;
+    use std::path::PathBuf;
+
+    fn workspace(packages: &[(&str, &str)]) -> CargoProject {
+        CargoProject {
+            root: PathBuf::from("/workspace"),
+            kind: ProjectKind::VirtualWorkspace,
+            packages: packages
+                .iter()
+                .map(|(name, version)| PackageInfo {
+                    name: (*name).to_string(),
+                    version: version.parse().expect("valid version"),
+                    path: PathBuf::from("/workspace/crates").join(name),
+                })
+                .collect(),
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        }
+    }
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|t| (*t).to_string()).collect()
+    }
+
+    #[test]
+    fn reports_no_drift_when_versions_match() {
+        let project = workspace(&[("crate-a", "1.0.0")]);
+
+        let drift = detect_version_drift(
+            &project,
+            &GitConfig::default(),
+            &[],
+            &tags(&["crate-a@v1.0.0"]),
+        );
+
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn reports_manifest_ahead_of_tag() {
+        let project = workspace(&[("crate-a", "1.1.0")]);
+
+        let drift = detect_version_drift(
+            &project,
+            &GitConfig::default(),
+            &[],
+            &tags(&["crate-a@v0.9.0", "crate-a@v1.0.0"]),
+        );
+
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].kind, DriftKind::ManifestAhead);
+        assert_eq!(drift[0].tag_version, Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn matches_tags_in_channel_namespace() {
+        let project = workspace(&[("crate-a", "1.1.0-nightly.1")]);
+
+        let drift = detect_version_drift(
+            &project,
+            &GitConfig::default(),
+            &ReleaseChannel::builtin(),
+            &tags(&["crate-a@v1.0.0", "nightly/crate-a@v1.1.0-nightly.1"]),
+        );
+
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn reports_tag_ahead_of_manifest() {
+        let project = workspace(&[("crate-a", "1.0.0"), ("crate-b", "2.0.0")]);
+
+        let drift = detect_version_drift(
+            &project,
+            &GitConfig::default(),
+            &[],
+            &tags(&["crate-a@v1.0.1", "crate-b@v2.0.0"]),
+        );
+
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].package, "crate-a");
+        assert_eq!(drift[0].kind, DriftKind::TagAhead);
+    }
+
+    #[test]
+    fn skips_packages_without_tags() {
+        let project = workspace(&[("crate-a", "1.0.0")]);
+
+        let drift = detect_version_drift(&project, &GitConfig::default(), &[], &tags(&["v0.1.0"]));
+
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn single_package_uses_version_only_tags() {
+        let project = CargoProject {
+            root: PathBuf::from("/project"),
+            kind: ProjectKind::SinglePackage,
+            packages: vec![PackageInfo {
+                name: "my-crate".to_string(),
+                version: "0.3.0".parse().expect("valid version"),
+                path: PathBuf::from("/project"),
+            }],
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        };
+
+        let drift = detect_version_drift(&project, &GitConfig::default(), &[], &tags(&["v0.2.0"]));
+
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].kind, DriftKind::ManifestAhead);
+    }
+}
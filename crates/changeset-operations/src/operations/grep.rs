@@ -0,0 +1,403 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use changeset_core::{BumpType, Changeset};
+use chrono::{Local, NaiveDate};
+use regex::{Regex, RegexBuilder};
+
+use crate::Result;
+use crate::changeset_dirs;
+use crate::error::OperationError;
+use crate::package_selection::select_packages;
+use crate::traits::{ChangesetReader, GitProvider, ProjectProvider};
+
+/// Where a matching changeset was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangesetOrigin {
+    /// Waiting for the next release.
+    Pending,
+    /// Included in a pre-release and waiting for the stable release.
+    Consumed,
+    /// Deleted by a past release; read from the git history.
+    Released,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GrepInput {
+    /// Regular expression matched against each line of the summary and the
+    /// metadata.
+    pub pattern: String,
+    pub ignore_case: bool,
+    /// Package names, paths or globs; a changeset must release one of them.
+    pub packages: Vec<String>,
+    /// Category ids; a changeset must have one of them.
+    pub categories: Vec<String>,
+    /// Only changesets added on or after this day.
+    pub since: Option<NaiveDate>,
+    /// Only changesets added on or before this day.
+    pub until: Option<NaiveDate>,
+    /// Leave out changesets deleted by past releases.
+    pub pending_only: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GrepMatch {
+    pub path: PathBuf,
+    pub origin: ChangesetOrigin,
+    pub changeset: Changeset,
+    /// Summary and metadata lines the pattern matched, metadata as
+    /// `key: value`.
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct GrepOutput {
+    /// Changesets on disk in listing order, then released ones, most recently
+    /// released first.
+    pub matches: Vec<GrepMatch>,
+}
+
+/// Searches pending changesets and those that past releases deleted.
+pub struct GrepOperation<P, R, G> {
+    project_provider: P,
+    changeset_reader: R,
+    git_provider: G,
+}
+
+impl<P, R, G> GrepOperation<P, R, G>
+where
+    P: ProjectProvider,
+    R: ChangesetReader,
+    G: GitProvider,
+{
+    pub fn new(project_provider: P, changeset_reader: R, git_provider: G) -> Self {
+        Self {
+            project_provider,
+            changeset_reader,
+            git_provider,
+        }
+    }
+
+    /// Lists the changesets with a summary or metadata line matching
+    /// [`GrepInput::pattern`] that pass the package, category and date
+    /// filters.
+    ///
+    /// A changeset's date is the day it was committed; changesets not
+    /// committed yet count as added today.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be discovered, the pattern is
+    /// not a valid regular expression, a package selector matches no package,
+    /// or the changesets or the history cannot be read.
+    pub fn execute(&self, start_path: &Path, input: &GrepInput) -> Result<GrepOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let regex = RegexBuilder::new(&input.pattern)
+            .case_insensitive(input.ignore_case)
+            .build()
+            .map_err(|e| OperationError::InvalidSearchPattern {
+                pattern: input.pattern.clone(),
+                reason: e.to_string(),
+            })?;
+        let packages: HashSet<String> =
+            select_packages(&project.root, &project.packages, &input.packages)?
+                .into_iter()
+                .map(|package| package.name)
+                .collect();
+        let filter = Filter {
+            regex,
+            packages,
+            categories: &input.categories,
+        };
+
+        let dirs = changeset_dirs::changeset_dirs(&project.root, &root_config);
+        let mut candidates = Vec::new();
+        for (origin, paths) in [
+            (
+                ChangesetOrigin::Pending,
+                changeset_dirs::list_changesets(&self.changeset_reader, &dirs)?,
+            ),
+            (
+                ChangesetOrigin::Consumed,
+                changeset_dirs::list_consumed_changesets(&self.changeset_reader, &dirs)?,
+            ),
+        ] {
+            for path in paths {
+                let changeset = self.changeset_reader.read_changeset(&path)?;
+                candidates.push((path, origin, changeset));
+            }
+        }
+        if !input.pending_only {
+            let repository_root = self.git_provider.repository_root(&project.root)?;
+            let mut seen = HashSet::new();
+            for dir in root_config.changeset_dirs() {
+                for (path, content) in
+                    self.git_provider
+                        .deleted_file_contents(&project.root, None, "HEAD", dir)?
+                {
+                    if path.extension().is_none_or(|ext| ext != "md") || !seen.insert(path.clone())
+                    {
+                        continue;
+                    }
+                    if let Ok(changeset) = changeset_parse::parse_changeset(&content) {
+                        candidates.push((
+                            repository_root.join(path),
+                            ChangesetOrigin::Released,
+                            changeset,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut matches = Vec::new();
+        for (path, origin, changeset) in candidates {
+            let Some(lines) = filter.matching_lines(&changeset) else {
+                continue;
+            };
+            if (input.since.is_some() || input.until.is_some())
+                && !self.added_within(&project.root, &path, input.since, input.until)?
+            {
+                continue;
+            }
+            matches.push(GrepMatch {
+                path,
+                origin,
+                changeset,
+                lines,
+            });
+        }
+        Ok(GrepOutput { matches })
+    }
+
+    fn added_within(
+        &self,
+        project_root: &Path,
+        path: &Path,
+        since: Option<NaiveDate>,
+        until: Option<NaiveDate>,
+    ) -> Result<bool> {
+        let added = self
+            .git_provider
+            .file_added_date(project_root, path)?
+            .map_or_else(|| Local::now().date_naive(), |date| date.date_naive());
+        Ok(since.is_none_or(|since| added >= since) && until.is_none_or(|until| added <= until))
+    }
+}
+
+struct Filter<'a> {
+    regex: Regex,
+    /// Empty when no package filter was given.
+    packages: HashSet<String>,
+    categories: &'a [String],
+}
+
+impl Filter<'_> {
+    /// The lines matching the pattern, or `None` if the changeset is filtered
+    /// out or no line matches.
+    fn matching_lines(&self, changeset: &Changeset) -> Option<Vec<String>> {
+        if !self.packages.is_empty()
+            && !changeset
+                .releases
+                .iter()
+                .any(|release| self.packages.contains(&release.name))
+        {
+            return None;
+        }
+        if !self.categories.is_empty()
+            && !self
+                .categories
+                .iter()
+                .any(|category| category == changeset.category.id())
+        {
+            return None;
+        }
+
+        let lines: Vec<String> = searchable_lines(changeset)
+            .into_iter()
+            .filter(|line| self.regex.is_match(line))
+            .collect();
+        (!lines.is_empty()).then_some(lines)
+    }
+}
+
+fn searchable_lines(changeset: &Changeset) -> Vec<String> {
+    let mut lines: Vec<String> = changeset
+        .releases
+        .iter()
+        .map(|release| {
+            let bump = match release.bump_type {
+                BumpType::Patch => "patch",
+                BumpType::Minor => "minor",
+                BumpType::Major => "major",
+            };
+            format!("{}: {bump}", release.name)
+        })
+        .collect();
+    lines.push(format!("category: {}", changeset.category.id()));
+    if let Some(scope) = &changeset.scope {
+        lines.push(format!("scope: {scope}"));
+    }
+    if let Some(advisory) = &changeset.advisory {
+        lines.push(format!("advisory: {advisory}"));
+    }
+    lines.extend(
+        changeset
+            .summary
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use changeset_core::{ChangeCategory, PackageRelease};
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::mocks::{MockChangesetReader, MockGitProvider, MockProjectProvider};
+
+    const RELEASED: &str = "---\n\"crate-b\": patch\n---\n\nFix panic on empty input\n";
+
+    fn changeset(package: &str, category: ChangeCategory, summary: &str) -> Changeset {
+        Changeset {
+            summary: summary.to_string(),
+            releases: vec![PackageRelease {
+                name: package.to_string(),
+                bump_type: BumpType::Patch,
+            }],
+            category,
+            scope: None,
+            consumed_for_prerelease: None,
+            graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
+        }
+    }
+
+    fn operation(
+        git_provider: MockGitProvider,
+    ) -> GrepOperation<MockProjectProvider, MockChangesetReader, MockGitProvider> {
+        let project_provider =
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "1.0.0")]);
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(
+                PathBuf::from("/mock/workspace/.changeset/changesets/panic.md"),
+                changeset("crate-a", ChangeCategory::Fixed, "Fix panic in parser"),
+            )
+            .with_changeset(
+                PathBuf::from("/mock/workspace/.changeset/changesets/docs.md"),
+                changeset("crate-a", ChangeCategory::Changed, "Improve docs"),
+            );
+        GrepOperation::new(
+            project_provider,
+            changeset_reader,
+            git_provider
+                .with_root(PathBuf::from("/mock/workspace"))
+                .with_deleted_file(".changeset/changesets/old.md", RELEASED),
+        )
+    }
+
+    fn grep(pattern: &str) -> GrepInput {
+        GrepInput {
+            pattern: pattern.to_string(),
+            ..GrepInput::default()
+        }
+    }
+
+    #[test]
+    fn finds_pending_and_released_changesets() {
+        let output = operation(MockGitProvider::new())
+            .execute(Path::new("/any"), &grep("panic"))
+            .expect("grep failed");
+
+        let found: Vec<_> = output
+            .matches
+            .iter()
+            .map(|found| (found.path.clone(), found.origin))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    PathBuf::from("/mock/workspace/.changeset/changesets/panic.md"),
+                    ChangesetOrigin::Pending
+                ),
+                (
+                    PathBuf::from("/mock/workspace/.changeset/changesets/old.md"),
+                    ChangesetOrigin::Released
+                ),
+            ]
+        );
+        assert_eq!(output.matches[0].lines, ["Fix panic in parser"]);
+    }
+
+    #[test]
+    fn filters_by_package_category_and_metadata() {
+        let operation = operation(MockGitProvider::new());
+
+        let by_package = operation
+            .execute(
+                Path::new("/any"),
+                &GrepInput {
+                    packages: vec!["crate-b".to_string()],
+                    ignore_case: true,
+                    ..grep("PANIC")
+                },
+            )
+            .expect("grep failed");
+        let by_category = operation
+            .execute(
+                Path::new("/any"),
+                &GrepInput {
+                    categories: vec!["changed".to_string()],
+                    pending_only: true,
+                    ..grep("crate-a: patch")
+                },
+            )
+            .expect("grep failed");
+
+        assert_eq!(by_package.matches.len(), 1);
+        assert_eq!(by_package.matches[0].origin, ChangesetOrigin::Released);
+        assert_eq!(by_category.matches.len(), 1);
+        assert_eq!(by_category.matches[0].changeset.summary, "Improve docs");
+    }
+
+    #[test]
+    fn filters_by_date_added() {
+        let date = |day| {
+            Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0)
+                .single()
+                .expect("valid date")
+        };
+        let git_provider = MockGitProvider::new()
+            .with_file_added_date("/mock/workspace/.changeset/changesets/old.md", date(5));
+
+        let output = operation(git_provider)
+            .execute(
+                Path::new("/any"),
+                &GrepInput {
+                    until: NaiveDate::from_ymd_opt(2024, 1, 10),
+                    ..grep("panic")
+                },
+            )
+            .expect("grep failed");
+
+        assert_eq!(output.matches.len(), 1);
+        assert_eq!(output.matches[0].origin, ChangesetOrigin::Released);
+    }
+
+    #[test]
+    fn rejects_invalid_pattern() {
+        let result = operation(MockGitProvider::new()).execute(Path::new("/any"), &grep("fix("));
+
+        assert!(matches!(
+            result,
+            Err(OperationError::InvalidSearchPattern { .. })
+        ));
+    }
+}
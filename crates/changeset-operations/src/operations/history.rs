@@ -0,0 +1,148 @@
+use changeset_project::{CargoProject, GitConfig, ReleaseChannel};
+use semver::Version;
+
+use crate::tags::{TagNaming, uses_crate_prefix};
+
+/// A past release of a package, recovered from its release tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedRelease {
+    pub package: String,
+    pub version: Version,
+    pub tag: String,
+}
+
+/// Lists the releases recorded as tags, newest version first.
+///
+/// Only tags belonging to a package of `project` are included, plain or in the
+/// namespace of one of `channels`. Returns nothing
+/// when tagging is disabled in the configuration.
+#[must_use]
+pub fn tagged_releases(
+    project: &CargoProject,
+    git_config: &GitConfig,
+    channels: &[ReleaseChannel],
+    tags: &[String],
+) -> Vec<TaggedRelease> {
+    if !git_config.tags() {
+        return Vec::new();
+    }
+
+    let naming = TagNaming::new(uses_crate_prefix(&project.kind, git_config.tag_format()))
+        .with_channels(channels);
+    let single_package = match project.packages.as_slice() {
+        [package] => Some(package.name.as_str()),
+        _ => None,
+    };
+
+    let mut releases: Vec<_> = tags
+        .iter()
+        .filter_map(|tag| {
+            let (package, version) = naming.parse(tag)?;
+            let package = package.or(single_package)?;
+            project
+                .packages
+                .iter()
+                .any(|p| p.name == package)
+                .then(|| TaggedRelease {
+                    package: package.to_string(),
+                    version,
+                    tag: tag.clone(),
+                })
+        })
+        .collect();
+    releases.sort_by(|a, b| {
+        b.version
+            .cmp(&a.version)
+            .then_with(|| a.package.cmp(&b.package))
+    });
+    releases
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use changeset_core::PackageInfo;
+    use changeset_project::ProjectKind;
+
+    use super::*;
+
+    fn project(kind: ProjectKind, names: &[&str]) -> CargoProject {
+        CargoProject {
+            root: PathBuf::from("/workspace"),
+            kind,
+            packages: names
+                .iter()
+                .map(|name| PackageInfo {
+                    name: (*name).to_string(),
+                    version: Version::new(1, 0, 0),
+                    path: PathBuf::from("/workspace/crates").join(name),
+                })
+                .collect(),
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        }
+    }
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|t| (*t).to_string()).collect()
+    }
+
+    #[test]
+    fn lists_workspace_releases_newest_first() {
+        let project = project(ProjectKind::VirtualWorkspace, &["crate-a", "crate-b"]);
+
+        let releases = tagged_releases(
+            &project,
+            &GitConfig::default(),
+            &[],
+            &tags(&[
+                "crate-a@v1.0.0",
+                "crate-b@v1.2.0",
+                "crate-a@v1.1.0",
+                "other@v9.0.0",
+                "nightly",
+            ]),
+        );
+
+        let listed: Vec<_> = releases.iter().map(|r| r.tag.as_str()).collect();
+        assert_eq!(
+            listed,
+            ["crate-b@v1.2.0", "crate-a@v1.1.0", "crate-a@v1.0.0"]
+        );
+    }
+
+    #[test]
+    fn lists_releases_tagged_in_channel_namespace() {
+        let project = project(ProjectKind::VirtualWorkspace, &["crate-a"]);
+
+        let releases = tagged_releases(
+            &project,
+            &GitConfig::default(),
+            &ReleaseChannel::builtin(),
+            &tags(&["crate-a@v1.0.0", "nightly/crate-a@v1.1.0-nightly.1"]),
+        );
+
+        let listed: Vec<_> = releases.iter().map(|r| r.tag.as_str()).collect();
+        assert_eq!(
+            listed,
+            ["nightly/crate-a@v1.1.0-nightly.1", "crate-a@v1.0.0"]
+        );
+    }
+
+    #[test]
+    fn attributes_unprefixed_tags_to_single_package() {
+        let project = project(ProjectKind::SinglePackage, &["solo"]);
+
+        let releases = tagged_releases(&project, &GitConfig::default(), &[], &tags(&["v0.2.0"]));
+
+        assert_eq!(
+            releases,
+            [TaggedRelease {
+                package: "solo".to_string(),
+                version: Version::new(0, 2, 0),
+                tag: "v0.2.0".to_string(),
+            }]
+        );
+    }
+}
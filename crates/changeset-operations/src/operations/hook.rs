@@ -0,0 +1,296 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::GitProvider;
+
+/// Git hooks that `cargo changeset` can install and run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookKind {
+    PreCommit,
+    CommitMsg,
+}
+
+impl HookKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PreCommit => "pre-commit",
+            Self::CommitMsg => "commit-msg",
+        }
+    }
+
+    /// Returns the shell script installed for this hook.
+    #[must_use]
+    pub fn script(self) -> String {
+        format!(
+            "#!/bin/sh\n\
+             # Installed by cargo-changeset. Checks staged changes for changeset coverage.\n\
+             exec cargo changeset hook run {} \"$@\"\n",
+            self.as_str()
+        )
+    }
+}
+
+impl std::fmt::Display for HookKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Name of the git merge driver that runs `cargo changeset merge-state`.
+const MERGE_DRIVER_NAME: &str = "changeset-state";
+
+/// State files the merge driver is registered for in `.gitattributes`.
+const MERGE_DRIVER_FILES: [&str; 2] = ["pre-release.toml", "graduation.toml"];
+
+pub struct HookInstallInput {
+    pub hooks: Vec<HookKind>,
+    /// Overwrite existing hook scripts.
+    pub force: bool,
+    /// Register `merge-state` as the merge driver for the release state files.
+    pub merge_driver: bool,
+}
+
+#[derive(Debug)]
+pub struct HookInstallOutput {
+    pub installed: Vec<PathBuf>,
+    /// `.gitattributes` file the merge driver was registered in.
+    pub merge_driver: Option<PathBuf>,
+}
+
+pub struct HookInstallOperation<G> {
+    git_provider: G,
+}
+
+impl<G> HookInstallOperation<G>
+where
+    G: GitProvider,
+{
+    pub fn new(git_provider: G) -> Self {
+        Self { git_provider }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the hooks directory cannot be determined, a hook
+    /// already exists and `force` is not set, or a script cannot be written.
+    pub fn execute(
+        &self,
+        start_path: &Path,
+        input: &HookInstallInput,
+    ) -> Result<HookInstallOutput> {
+        let hooks_dir = self.git_provider.hooks_dir(start_path)?;
+
+        let targets: Vec<(HookKind, PathBuf)> = input
+            .hooks
+            .iter()
+            .map(|hook| (*hook, hooks_dir.join(hook.as_str())))
+            .collect();
+
+        if !input.force {
+            if let Some((_, path)) = targets.iter().find(|(_, path)| path.exists()) {
+                return Err(OperationError::HookExists { path: path.clone() });
+            }
+        }
+
+        fs::create_dir_all(&hooks_dir).map_err(|source| OperationError::HookWrite {
+            path: hooks_dir.clone(),
+            source,
+        })?;
+
+        let mut installed = Vec::with_capacity(targets.len());
+        for (hook, path) in targets {
+            write_hook_script(&path, &hook.script())?;
+            installed.push(path);
+        }
+
+        let merge_driver = if input.merge_driver {
+            Some(self.install_merge_driver(start_path)?)
+        } else {
+            None
+        };
+
+        Ok(HookInstallOutput {
+            installed,
+            merge_driver,
+        })
+    }
+
+    /// Defines the driver in the local git config and maps the state files to
+    /// it in the repository's `.gitattributes`, which is meant to be committed
+    /// so every clone that installs the driver picks it up.
+    fn install_merge_driver(&self, start_path: &Path) -> Result<PathBuf> {
+        self.git_provider.set_config(
+            start_path,
+            &format!("merge.{MERGE_DRIVER_NAME}.name"),
+            "cargo-changeset release state merge",
+        )?;
+        self.git_provider.set_config(
+            start_path,
+            &format!("merge.{MERGE_DRIVER_NAME}.driver"),
+            "cargo changeset merge-state --base %O --ours %A --theirs %B",
+        )?;
+
+        let path = self
+            .git_provider
+            .repository_root(start_path)?
+            .join(".gitattributes");
+        let existing = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(OperationError::HookWrite { path, source }),
+        };
+
+        let mut content = existing.clone();
+        for file in MERGE_DRIVER_FILES {
+            let line = format!("{file} merge={MERGE_DRIVER_NAME}");
+            if content.lines().any(|existing| existing.trim() == line) {
+                continue;
+            }
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push_str(&line);
+            content.push('\n');
+        }
+
+        if content != existing {
+            fs::write(&path, content).map_err(|source| OperationError::HookWrite {
+                path: path.clone(),
+                source,
+            })?;
+        }
+        Ok(path)
+    }
+}
+
+fn write_hook_script(path: &Path, content: &str) -> Result<()> {
+    let to_error = |source| OperationError::HookWrite {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    fs::write(path, content).map_err(to_error)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755)).map_err(to_error)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::MockGitProvider;
+    use tempfile::TempDir;
+
+    #[test]
+    fn installs_requested_hooks() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let hooks_dir = dir.path().join("hooks");
+        let git_provider = MockGitProvider::new().with_hooks_dir(hooks_dir.clone());
+        let operation = HookInstallOperation::new(git_provider);
+
+        let output = operation.execute(
+            dir.path(),
+            &HookInstallInput {
+                hooks: vec![HookKind::PreCommit, HookKind::CommitMsg],
+                force: false,
+                merge_driver: false,
+            },
+        )?;
+
+        assert_eq!(output.installed.len(), 2);
+        let pre_commit = fs::read_to_string(hooks_dir.join("pre-commit"))?;
+        assert!(pre_commit.contains("cargo changeset hook run pre-commit"));
+        let commit_msg = fs::read_to_string(hooks_dir.join("commit-msg"))?;
+        assert!(commit_msg.contains("cargo changeset hook run commit-msg"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_to_overwrite_existing_hook() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("pre-commit"), "#!/bin/sh\necho custom\n")?;
+        let git_provider = MockGitProvider::new().with_hooks_dir(dir.path().to_path_buf());
+        let operation = HookInstallOperation::new(git_provider);
+
+        let result = operation.execute(
+            dir.path(),
+            &HookInstallInput {
+                hooks: vec![HookKind::PreCommit],
+                force: false,
+                merge_driver: false,
+            },
+        );
+
+        assert!(matches!(result, Err(OperationError::HookExists { .. })));
+        let content = fs::read_to_string(dir.path().join("pre-commit"))?;
+        assert!(content.contains("echo custom"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn force_overwrites_existing_hook() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("pre-commit"), "#!/bin/sh\necho custom\n")?;
+        let git_provider = MockGitProvider::new().with_hooks_dir(dir.path().to_path_buf());
+        let operation = HookInstallOperation::new(git_provider);
+
+        operation.execute(
+            dir.path(),
+            &HookInstallInput {
+                hooks: vec![HookKind::PreCommit],
+                force: true,
+                merge_driver: false,
+            },
+        )?;
+
+        let content = fs::read_to_string(dir.path().join("pre-commit"))?;
+        assert!(content.contains("cargo changeset hook run pre-commit"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn registers_merge_driver_once() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join(".gitattributes"), "*.rs text")?;
+        let git_provider = std::sync::Arc::new(
+            MockGitProvider::new()
+                .with_hooks_dir(dir.path().join("hooks"))
+                .with_root(dir.path().to_path_buf()),
+        );
+        let operation = HookInstallOperation::new(std::sync::Arc::clone(&git_provider));
+        let input = HookInstallInput {
+            hooks: Vec::new(),
+            force: false,
+            merge_driver: true,
+        };
+
+        operation.execute(dir.path(), &input)?;
+        let output = operation.execute(dir.path(), &input)?;
+
+        assert_eq!(output.merge_driver, Some(dir.path().join(".gitattributes")));
+        let attributes = fs::read_to_string(dir.path().join(".gitattributes"))?;
+        assert_eq!(
+            attributes,
+            "*.rs text\n\
+             pre-release.toml merge=changeset-state\n\
+             graduation.toml merge=changeset-state\n"
+        );
+        assert!(git_provider.config_values().contains(&(
+            "merge.changeset-state.driver".to_string(),
+            "cargo changeset merge-state --base %O --ours %A --theirs %B".to_string()
+        )));
+
+        Ok(())
+    }
+}
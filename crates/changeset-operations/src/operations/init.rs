@@ -1,13 +1,20 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use changeset_manifest::{InitConfig, MetadataSection};
-use changeset_project::{CargoProject, ProjectKind, RootChangesetConfig};
+use changeset_git::{TagConvention, TagStyle, detect_tag_convention, remote_host};
+use changeset_manifest::{
+    ChangelogLocation, ComparisonLinks, DeprecatedKey, ExistingMetadata, InitConfig,
+    MetadataSection, TagFormat,
+};
+use changeset_project::{
+    CargoProject, ChangelogLayout, ProjectKind, RootChangesetConfig, detect_changelog_layout,
+};
 
 use crate::Result;
+use crate::error::OperationError;
 use crate::traits::{
-    ChangelogSettingsInput, GitSettingsInput, InitInteractionProvider, ManifestWriter,
-    ProjectContext, ProjectProvider, VersionSettingsInput,
+    ChangelogSettingsInput, GitProvider, GitSettingsInput, InitInteractionProvider,
+    InitSuggestions, ManifestWriter, ProjectContext, ProjectProvider, VersionSettingsInput,
 };
 
 /// Input for the init operation.
@@ -15,10 +22,16 @@ use crate::traits::{
 /// Configuration sources have the following precedence (highest to lowest):
 /// 1. `defaults: true` - Uses all default values, ignores other fields
 /// 2. Explicit `git_config`, `changelog_config`, `version_config` fields
-/// 3. Interactive prompts via `InitInteractionProvider` (only if no explicit config)
+/// 3. Interactive prompts via `InitInteractionProvider` (only if no explicit config
+///    and the manifest has no changeset configuration yet)
+///
+/// When the manifest is already configured, unspecified options keep their
+/// current values and options missing from the manifest are added with defaults.
 #[derive(Debug, Default)]
 pub struct InitInput {
     pub defaults: bool,
+    /// Rename deprecated option names in the existing configuration.
+    pub upgrade: bool,
     pub git_config: Option<GitSettingsInput>,
     pub changelog_config: Option<ChangelogSettingsInput>,
     pub version_config: Option<VersionSettingsInput>,
@@ -31,7 +44,21 @@ pub struct InitPlan {
     pub dir_exists: bool,
     pub gitkeep_exists: bool,
     pub metadata_section: MetadataSection,
+    /// Options to write: only those that are missing or differ from the manifest.
     pub config: InitConfig,
+    /// The configuration found in the manifest before init runs.
+    pub existing: ExistingMetadata,
+    pub changes: Vec<ConfigChange>,
+    pub upgrade: bool,
+}
+
+/// A single option that init will add or change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub key: &'static str,
+    /// The current TOML value, or `None` if the option is not set.
+    pub current: Option<String>,
+    pub proposed: String,
 }
 
 #[derive(Debug)]
@@ -42,6 +69,81 @@ pub struct InitOutput {
     pub created_gitkeep: bool,
     pub wrote_config: bool,
     pub config_location: Option<MetadataSection>,
+    pub upgraded_keys: Vec<DeprecatedKey>,
+}
+
+/// What `init` found in an existing repository, used to propose settings
+/// that match it.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectAnalysis {
+    pub is_single_package: bool,
+    pub package_count: usize,
+    /// The style most existing release tags follow.
+    pub tags: Option<TagConvention>,
+    /// Existing changelog files.
+    pub changelog: Option<ChangelogLayout>,
+    /// Host of the remote the repository publishes to.
+    pub remote_host: Option<String>,
+}
+
+impl ProjectAnalysis {
+    /// Turns the findings into suggested defaults for the init prompts.
+    #[must_use]
+    pub fn suggestions(&self) -> InitSuggestions {
+        InitSuggestions {
+            tag_format: self.tags.as_ref().map(|tags| match tags.style {
+                TagStyle::VersionOnly => TagFormat::VersionOnly,
+                TagStyle::PackagePrefixed => TagFormat::CratePrefixed,
+            }),
+            changelog: self.changelog.as_ref().map(|changelog| {
+                if changelog.root || self.is_single_package {
+                    ChangelogLocation::Root
+                } else {
+                    ChangelogLocation::PerPackage
+                }
+            }),
+            comparison_links: match self.remote_host.as_deref() {
+                None => Some(ComparisonLinks::Disabled),
+                Some(host) if is_known_forge(host) => Some(ComparisonLinks::Enabled),
+                Some(_) => None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn context(&self) -> ProjectContext {
+        ProjectContext {
+            is_single_package: self.is_single_package,
+            suggestions: self.suggestions(),
+        }
+    }
+}
+
+/// Inspects the workspace layout, release tags, changelogs, and remote of a
+/// project.
+///
+/// Git failures, such as a project that is not a repository yet, leave the
+/// corresponding findings empty.
+pub fn analyze_project<G: GitProvider>(project: &CargoProject, git: &G) -> ProjectAnalysis {
+    let tags = git.list_tags(&project.root).unwrap_or_default();
+    let remote_url = git.remote_url(&project.root, None).ok().flatten();
+
+    ProjectAnalysis {
+        is_single_package: project.kind == ProjectKind::SinglePackage,
+        package_count: project.packages.len(),
+        tags: detect_tag_convention(&tags),
+        changelog: detect_changelog_layout(project),
+        remote_host: remote_url.as_deref().and_then(remote_host),
+    }
+}
+
+/// Whether comparison links can be generated for the host without extra
+/// configuration.
+fn is_known_forge(host: &str) -> bool {
+    ["github.com", "gitlab.com", "bitbucket.org", "codeberg.org"].contains(&host)
+        || host.starts_with("gitlab.")
+        || host.ends_with(".sr.ht")
+        || host == "git.sr.ht"
 }
 
 pub struct InitOperation<P, M = (), I = ()> {
@@ -97,18 +199,28 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if the project cannot be discovered or configuration
-    /// cannot be built (e.g., interactive prompts fail).
+    /// Returns an error if the project cannot be discovered, the existing
+    /// configuration cannot be read, it uses deprecated option names and
+    /// `upgrade` is not set, or configuration cannot be built (e.g.,
+    /// interactive prompts fail).
     pub fn prepare(&self, start_path: &Path, input: &InitInput) -> Result<InitPlan> {
         let project = self.project_provider.discover_project(start_path)?;
         let (root_config, _) = self.project_provider.load_configs(&project)?;
 
         let context = ProjectContext {
             is_single_package: project.kind == ProjectKind::SinglePackage,
+            suggestions: InitSuggestions::default(),
+        };
+        let existing = match self.manifest_writer {
+            Some(ref writer) => writer.read_metadata(
+                &project.root.join("Cargo.toml"),
+                metadata_section_for(&project),
+            )?,
+            None => ExistingMetadata::default(),
         };
-        let config = self.build_config(input, context)?;
+        let config = self.build_config(input, context, existing.config.is_empty())?;
 
-        Ok(build_init_plan(&project, &root_config, config))
+        build_init_plan(&project, &root_config, config, existing, input.upgrade)
     }
 
     /// Executes the init operation using a pre-built plan.
@@ -130,11 +242,16 @@ where
             fs::write(&gitkeep_path, "")?;
         }
 
+        let mut upgraded_keys = Vec::new();
         let wrote_config = if let Some(ref writer) = self.manifest_writer {
+            let manifest_path = project.root.join("Cargo.toml");
+            if plan.upgrade && !plan.existing.deprecated_keys.is_empty() {
+                upgraded_keys = writer.upgrade_metadata(&manifest_path, plan.metadata_section)?;
+            }
+
             if plan.config.is_empty() {
                 false
             } else {
-                let manifest_path = project.root.join("Cargo.toml");
                 writer.write_metadata(&manifest_path, plan.metadata_section, &plan.config)?;
                 true
             }
@@ -152,6 +269,7 @@ where
             } else {
                 None
             },
+            upgraded_keys,
         })
     }
 
@@ -166,7 +284,12 @@ where
         self.execute_plan(start_path, &plan)
     }
 
-    fn build_config(&self, input: &InitInput, context: ProjectContext) -> Result<InitConfig> {
+    fn build_config(
+        &self,
+        input: &InitInput,
+        context: ProjectContext,
+        allow_prompts: bool,
+    ) -> Result<InitConfig> {
         if input.defaults {
             return Ok(build_default_config(context));
         }
@@ -189,7 +312,7 @@ where
             config.zero_version_behavior = Some(version.zero_version_behavior);
         }
 
-        if config.is_empty() {
+        if config.is_empty() && allow_prompts {
             if let Some(ref provider) = self.interaction_provider {
                 if let Some(git) = provider.configure_git_settings(context)? {
                     config.commit = Some(git.commit);
@@ -213,30 +336,142 @@ where
     }
 }
 
-/// Builds an `InitPlan` from project information and configuration.
-fn build_init_plan(
+/// Builds an `InitPlan` from project information, the requested configuration,
+/// and the configuration already present in the manifest.
+///
+/// On an already configured project, requested options override existing
+/// values and options missing from the manifest are filled with defaults. Only
+/// options that differ from the manifest end up in the plan's `config`.
+///
+/// # Errors
+///
+/// Returns `OperationError::DeprecatedConfigKeys` if the existing configuration
+/// uses deprecated option names and `upgrade` is not set.
+pub fn build_init_plan(
     project: &CargoProject,
     root_config: &RootChangesetConfig,
-    config: InitConfig,
-) -> InitPlan {
+    requested: InitConfig,
+    existing: ExistingMetadata,
+    upgrade: bool,
+) -> Result<InitPlan> {
+    if !upgrade && !existing.deprecated_keys.is_empty() {
+        return Err(OperationError::DeprecatedConfigKeys {
+            keys: existing
+                .deprecated_keys
+                .iter()
+                .map(|deprecated| deprecated.key.to_string())
+                .collect(),
+        });
+    }
+
     let changeset_dir_path = root_config.changeset_dir();
     let full_changeset_dir = project.root.join(changeset_dir_path);
     let dir_exists = full_changeset_dir.exists();
     let gitkeep_exists = full_changeset_dir.join(".gitkeep").exists();
 
-    let metadata_section = match project.kind {
-        ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => {
-            MetadataSection::Workspace
-        }
-        ProjectKind::SinglePackage => MetadataSection::Package,
+    let proposed = if existing.config.is_empty() {
+        requested
+    } else {
+        let context = ProjectContext {
+            is_single_package: project.kind == ProjectKind::SinglePackage,
+            suggestions: InitSuggestions::default(),
+        };
+        merge_config(&requested, &existing.config, &build_default_config(context))
     };
 
-    InitPlan {
+    Ok(InitPlan {
         changeset_dir: full_changeset_dir,
         dir_exists,
         gitkeep_exists,
-        metadata_section,
-        config,
+        metadata_section: metadata_section_for(project),
+        config: changed_options(&existing.config, &proposed),
+        changes: diff_config(&existing.config, &proposed),
+        existing,
+        upgrade,
+    })
+}
+
+fn metadata_section_for(project: &CargoProject) -> MetadataSection {
+    match project.kind {
+        ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => {
+            MetadataSection::Workspace
+        }
+        ProjectKind::SinglePackage => MetadataSection::Package,
+    }
+}
+
+/// Layers configuration sources, taking each option from the first of
+/// `requested`, `existing`, and `defaults` that sets it.
+#[must_use]
+pub fn merge_config(
+    requested: &InitConfig,
+    existing: &InitConfig,
+    defaults: &InitConfig,
+) -> InitConfig {
+    InitConfig {
+        commit: requested.commit.or(existing.commit).or(defaults.commit),
+        tags: requested.tags.or(existing.tags).or(defaults.tags),
+        keep_changesets: requested
+            .keep_changesets
+            .or(existing.keep_changesets)
+            .or(defaults.keep_changesets),
+        tag_format: requested
+            .tag_format
+            .or(existing.tag_format)
+            .or(defaults.tag_format),
+        changelog: requested
+            .changelog
+            .or(existing.changelog)
+            .or(defaults.changelog),
+        comparison_links: requested
+            .comparison_links
+            .or(existing.comparison_links)
+            .or(defaults.comparison_links),
+        zero_version_behavior: requested
+            .zero_version_behavior
+            .or(existing.zero_version_behavior)
+            .or(defaults.zero_version_behavior),
+    }
+}
+
+/// Lists the options in `proposed` that are missing from or differ in `current`.
+#[must_use]
+pub fn diff_config(current: &InitConfig, proposed: &InitConfig) -> Vec<ConfigChange> {
+    let current = current.entries();
+
+    proposed
+        .entries()
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let existing = current
+                .iter()
+                .find(|(current_key, _)| *current_key == key)
+                .map(|(_, current_value)| current_value.clone());
+            (existing.as_ref() != Some(&value)).then_some(ConfigChange {
+                key,
+                current: existing,
+                proposed: value,
+            })
+        })
+        .collect()
+}
+
+fn changed_options(current: &InitConfig, proposed: &InitConfig) -> InitConfig {
+    fn changed<T: Copy + PartialEq>(current: Option<T>, proposed: Option<T>) -> Option<T> {
+        proposed.filter(|value| current != Some(*value))
+    }
+
+    InitConfig {
+        commit: changed(current.commit, proposed.commit),
+        tags: changed(current.tags, proposed.tags),
+        keep_changesets: changed(current.keep_changesets, proposed.keep_changesets),
+        tag_format: changed(current.tag_format, proposed.tag_format),
+        changelog: changed(current.changelog, proposed.changelog),
+        comparison_links: changed(current.comparison_links, proposed.comparison_links),
+        zero_version_behavior: changed(
+            current.zero_version_behavior,
+            proposed.zero_version_behavior,
+        ),
     }
 }
 
@@ -305,11 +540,13 @@ where
         let project = self.project_provider.discover_project(start_path)?;
         let (root_config, _) = self.project_provider.load_configs(&project)?;
 
-        Ok(build_init_plan(
+        build_init_plan(
             &project,
             &root_config,
             InitConfig::default(),
-        ))
+            ExistingMetadata::default(),
+            false,
+        )
     }
 
     /// Executes the simple init operation using a pre-built plan.
@@ -336,6 +573,7 @@ where
             created_gitkeep: !plan.gitkeep_exists,
             wrote_config: false,
             config_location: None,
+            upgraded_keys: Vec::new(),
         })
     }
 
@@ -507,6 +745,7 @@ mod tests {
             version_config: Some(VersionSettingsInput {
                 zero_version_behavior: ZeroVersionBehavior::AutoPromoteOnMajor,
             }),
+            upgrade: false,
         };
 
         let result = operation
@@ -555,6 +794,7 @@ mod tests {
             }),
             changelog_config: None,
             version_config: None,
+            upgrade: false,
         };
 
         let result = operation
@@ -734,4 +974,187 @@ mod tests {
         let (_, section, _) = &written[0];
         assert_eq!(*section, MetadataSection::Package);
     }
+
+    fn existing_git_config() -> ExistingMetadata {
+        ExistingMetadata {
+            config: InitConfig {
+                commit: Some(false),
+                tags: Some(true),
+                ..Default::default()
+            },
+            deprecated_keys: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn rerun_keeps_existing_values_and_adds_missing_defaults() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let changeset_dir = dir.path().join(".changeset");
+        std::fs::create_dir_all(&changeset_dir).expect("create changeset dir");
+
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_changeset_dir(changeset_dir.clone());
+        let manifest_writer =
+            Arc::new(MockManifestWriter::new().with_existing_metadata(existing_git_config()));
+        let interaction_provider = Arc::new(MockInitInteractionProvider::new());
+
+        let operation = InitOperation::new(project_provider)
+            .with_manifest_writer(Arc::clone(&manifest_writer))
+            .with_interaction_provider(Arc::clone(&interaction_provider));
+
+        let plan = operation
+            .prepare(Path::new("/any"), &InitInput::default())
+            .expect("prepare failed");
+
+        assert_eq!(plan.config.commit, None, "unchanged keys are not rewritten");
+        assert_eq!(plan.config.tags, None);
+        assert_eq!(plan.config.keep_changesets, Some(false));
+        assert_eq!(plan.config.tag_format, Some(TagFormat::VersionOnly));
+        assert!(plan.changes.iter().all(|change| change.current.is_none()));
+        assert_eq!(plan.changes.len(), 5);
+    }
+
+    #[test]
+    fn rerun_reports_changed_values() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let changeset_dir = dir.path().join(".changeset");
+        std::fs::create_dir_all(&changeset_dir).expect("create changeset dir");
+
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_changeset_dir(changeset_dir.clone());
+        let manifest_writer =
+            Arc::new(MockManifestWriter::new().with_existing_metadata(existing_git_config()));
+
+        let operation =
+            InitOperation::new(project_provider).with_manifest_writer(Arc::clone(&manifest_writer));
+        let operation = operation.with_interaction_provider(MockInitInteractionProvider::new());
+
+        let input = InitInput {
+            git_config: Some(GitSettingsInput {
+                commit: true,
+                tags: true,
+                keep_changesets: false,
+                tag_format: TagFormat::VersionOnly,
+            }),
+            ..Default::default()
+        };
+        let plan = operation
+            .prepare(Path::new("/any"), &input)
+            .expect("prepare failed");
+
+        assert!(plan.changes.contains(&ConfigChange {
+            key: "commit",
+            current: Some("false".to_string()),
+            proposed: "true".to_string(),
+        }));
+        assert!(!plan.changes.iter().any(|change| change.key == "tags"));
+    }
+
+    #[test]
+    fn deprecated_keys_require_upgrade() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let changeset_dir = dir.path().join(".changeset");
+        std::fs::create_dir_all(&changeset_dir).expect("create changeset dir");
+
+        let existing = ExistingMetadata {
+            config: InitConfig {
+                tag_format: Some(TagFormat::CratePrefixed),
+                ..Default::default()
+            },
+            deprecated_keys: vec![DeprecatedKey {
+                key: "tag_format",
+                replacement: "tag-format",
+            }],
+        };
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_changeset_dir(changeset_dir.clone());
+        let manifest_writer = Arc::new(MockManifestWriter::new().with_existing_metadata(existing));
+
+        let operation = InitOperation::new(project_provider)
+            .with_manifest_writer(Arc::clone(&manifest_writer))
+            .with_interaction_provider(MockInitInteractionProvider::new());
+
+        let err = operation
+            .prepare(Path::new("/any"), &InitInput::default())
+            .expect_err("deprecated keys should be rejected");
+        assert!(
+            matches!(err, OperationError::DeprecatedConfigKeys { ref keys } if keys == &["tag_format"])
+        );
+
+        let input = InitInput {
+            upgrade: true,
+            ..Default::default()
+        };
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("upgrade failed");
+
+        assert_eq!(result.upgraded_keys.len(), 1);
+        assert_eq!(manifest_writer.upgraded_metadata().len(), 1);
+        let written = manifest_writer.written_metadata();
+        let (_, _, config) = &written[0];
+        assert_eq!(config.tag_format, None, "upgraded value is kept as is");
+    }
+
+    fn workspace_project(root: &Path) -> CargoProject {
+        CargoProject {
+            root: root.to_path_buf(),
+            kind: ProjectKind::VirtualWorkspace,
+            packages: ["foo", "bar"]
+                .into_iter()
+                .map(|name| changeset_core::PackageInfo {
+                    name: name.to_string(),
+                    version: semver::Version::new(1, 2, 3),
+                    path: root.join("crates").join(name),
+                })
+                .collect(),
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn analysis_suggests_settings_matching_the_repository() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let project = workspace_project(dir.path());
+        std::fs::create_dir_all(dir.path().join("crates/foo")).expect("create package dir");
+        std::fs::write(dir.path().join("crates/foo/CHANGELOG.md"), "# Changelog\n")
+            .expect("write changelog");
+        let git = crate::mocks::MockGitProvider::new()
+            .with_existing_tags(&["foo-v1.2.2", "foo-v1.2.3", "nightly"])
+            .with_remote_url("git@github.com:owner/repo.git");
+
+        let analysis = analyze_project(&project, &git);
+
+        assert_eq!(analysis.package_count, 2);
+        assert_eq!(
+            analysis.tags.as_ref().map(|tags| tags.example.as_str()),
+            Some("foo-v1.2.3")
+        );
+        assert_eq!(analysis.remote_host.as_deref(), Some("github.com"));
+        assert_eq!(
+            analysis.suggestions(),
+            InitSuggestions {
+                tag_format: Some(TagFormat::CratePrefixed),
+                changelog: Some(ChangelogLocation::PerPackage),
+                comparison_links: Some(ComparisonLinks::Enabled),
+            }
+        );
+    }
+
+    #[test]
+    fn analysis_of_empty_repository_only_disables_comparison_links() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let project = workspace_project(dir.path());
+
+        let analysis = analyze_project(&project, &crate::mocks::MockGitProvider::new());
+
+        assert_eq!(
+            analysis.suggestions(),
+            InitSuggestions {
+                comparison_links: Some(ComparisonLinks::Disabled),
+                ..Default::default()
+            }
+        );
+    }
 }
@@ -0,0 +1,350 @@
+use std::path::Path;
+
+use changeset_core::{PackageInfo, PrereleaseSpec};
+use changeset_project::{GraduationState, PrereleaseState, StateFile, serialize_state};
+use changeset_version::{is_prerelease, is_zero_version};
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{
+    ManageAction, ManageInteractionProvider, ManageRejection, ManageView, ProjectProvider,
+    ReleaseStateIO,
+};
+
+/// The state files as the editing session left them.
+#[derive(Debug, Clone)]
+pub struct ManageOutput {
+    pub prerelease: PrereleaseState,
+    pub graduation: GraduationState,
+    /// `false` when the session was discarded and nothing was written.
+    pub saved: bool,
+}
+
+/// Menu-driven editor for `pre-release.toml` and `graduation.toml`.
+///
+/// Edits are validated as they are made and kept in memory; the files are
+/// only written when the user saves.
+pub struct ManageOperation<P, S, I> {
+    project_provider: P,
+    release_state_io: S,
+    interaction_provider: I,
+}
+
+impl<P, S, I> ManageOperation<P, S, I>
+where
+    P: ProjectProvider,
+    S: ReleaseStateIO,
+    I: ManageInteractionProvider,
+{
+    pub fn new(project_provider: P, release_state_io: S, interaction_provider: I) -> Self {
+        Self {
+            project_provider,
+            release_state_io,
+            interaction_provider,
+        }
+    }
+
+    /// Runs the editor until the user saves or discards their edits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be discovered, the state files
+    /// cannot be read or written, or the interaction fails.
+    pub fn execute(&self, start_path: &Path) -> Result<ManageOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let changeset_dir = project.root.join(root_config.changeset_dir());
+
+        let original_prerelease = self
+            .release_state_io
+            .load_prerelease_state(&changeset_dir)?
+            .unwrap_or_default();
+        let original_graduation = self
+            .release_state_io
+            .load_graduation_state(&changeset_dir)?
+            .unwrap_or_default();
+        let mut prerelease = original_prerelease.clone();
+        let mut graduation = original_graduation.clone();
+
+        loop {
+            let prerelease_toml = preview(
+                &changeset_dir,
+                StateFile::Prerelease,
+                &prerelease,
+                prerelease.is_blank(),
+            )?;
+            let graduation_toml = preview(
+                &changeset_dir,
+                StateFile::Graduation,
+                &graduation,
+                graduation.is_empty(),
+            )?;
+            let view = ManageView {
+                packages: &project.packages,
+                changeset_dir: root_config.changeset_dir(),
+                prerelease: &prerelease,
+                graduation: &graduation,
+                prerelease_toml: prerelease_toml.as_deref(),
+                graduation_toml: graduation_toml.as_deref(),
+                has_unsaved_changes: prerelease != original_prerelease
+                    || graduation != original_graduation,
+            };
+
+            let outcome = match self.interaction_provider.next_action(&view)? {
+                ManageAction::Save => {
+                    if prerelease != original_prerelease {
+                        self.release_state_io
+                            .save_prerelease_state(&changeset_dir, &prerelease)?;
+                    }
+                    if graduation != original_graduation {
+                        self.release_state_io
+                            .save_graduation_state(&changeset_dir, &graduation)?;
+                    }
+                    return Ok(ManageOutput {
+                        prerelease,
+                        graduation,
+                        saved: true,
+                    });
+                }
+                ManageAction::Discard => {
+                    return Ok(ManageOutput {
+                        prerelease: original_prerelease,
+                        graduation: original_graduation,
+                        saved: false,
+                    });
+                }
+                action => apply(action, &project.packages, &mut prerelease, &mut graduation),
+            };
+
+            if let Err(rejection) = outcome {
+                self.interaction_provider.report_rejection(&rejection)?;
+            }
+        }
+    }
+}
+
+fn apply(
+    action: ManageAction,
+    packages: &[PackageInfo],
+    prerelease: &mut PrereleaseState,
+    graduation: &mut GraduationState,
+) -> std::result::Result<(), ManageRejection> {
+    match action {
+        ManageAction::AddPrerelease { package, tag } => {
+            find_package(packages, &package)?;
+            tag.parse::<PrereleaseSpec>()
+                .map_err(|e| ManageRejection::InvalidPrereleaseTag {
+                    tag: tag.clone(),
+                    reason: e.to_string(),
+                })?;
+            prerelease.insert(package, tag);
+        }
+        ManageAction::RemovePrerelease { package } => {
+            if prerelease.remove(&package).is_none() {
+                return Err(ManageRejection::NotListed {
+                    name: package,
+                    file: StateFile::Prerelease.file_name(),
+                });
+            }
+        }
+        ManageAction::AddGraduation { package } => {
+            let info = find_package(packages, &package)?;
+            if is_prerelease(&info.version) {
+                return Err(ManageRejection::CannotGraduatePrerelease {
+                    package,
+                    version: info.version.to_string(),
+                });
+            }
+            if !is_zero_version(&info.version) {
+                return Err(ManageRejection::CannotGraduateStable {
+                    package,
+                    version: info.version.to_string(),
+                });
+            }
+            graduation.add(package);
+        }
+        ManageAction::RemoveGraduation { package } => {
+            if !graduation.remove(&package) {
+                return Err(ManageRejection::NotListed {
+                    name: package,
+                    file: StateFile::Graduation.file_name(),
+                });
+            }
+        }
+        ManageAction::Save | ManageAction::Discard => {}
+    }
+    Ok(())
+}
+
+fn find_package<'a>(
+    packages: &'a [PackageInfo],
+    name: &str,
+) -> std::result::Result<&'a PackageInfo, ManageRejection> {
+    packages
+        .iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| ManageRejection::PackageNotFound {
+            name: name.to_string(),
+        })
+}
+
+/// Renders a state file the way saving writes it; `None` when saving removes it.
+fn preview<T: serde::Serialize>(
+    changeset_dir: &Path,
+    file: StateFile,
+    state: &T,
+    removes_file: bool,
+) -> Result<Option<String>> {
+    if removes_file {
+        return Ok(None);
+    }
+    serialize_state(state)
+        .map(Some)
+        .map_err(|source| OperationError::ReleaseStateSerialize {
+            path: changeset_dir.join(file.file_name()),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::mocks::{MockManageInteractionProvider, MockProjectProvider, MockReleaseStateIO};
+
+    fn workspace() -> MockProjectProvider {
+        MockProjectProvider::workspace(vec![("crate-a", "0.3.0"), ("crate-b", "1.2.0")])
+    }
+
+    #[test]
+    fn save_writes_edited_state() -> anyhow::Result<()> {
+        let release_state_io = Arc::new(MockReleaseStateIO::new());
+        let interaction = Arc::new(MockManageInteractionProvider::new(vec![
+            ManageAction::AddPrerelease {
+                package: "crate-b".to_string(),
+                tag: "beta".to_string(),
+            },
+            ManageAction::AddGraduation {
+                package: "crate-a".to_string(),
+            },
+            ManageAction::Save,
+        ]));
+        let operation = ManageOperation::new(
+            workspace(),
+            Arc::clone(&release_state_io),
+            Arc::clone(&interaction),
+        );
+
+        let output = operation.execute(Path::new("/any"))?;
+
+        assert!(output.saved);
+        let prerelease = release_state_io
+            .get_prerelease_state()
+            .expect("pre-release state saved");
+        assert_eq!(prerelease.get("crate-b"), Some("beta"));
+        let graduation = release_state_io
+            .get_graduation_state()
+            .expect("graduation state saved");
+        assert!(graduation.contains("crate-a"));
+        Ok(())
+    }
+
+    #[test]
+    fn discard_writes_nothing() -> anyhow::Result<()> {
+        let release_state_io = Arc::new(MockReleaseStateIO::new());
+        let interaction = Arc::new(MockManageInteractionProvider::new(vec![
+            ManageAction::AddGraduation {
+                package: "crate-a".to_string(),
+            },
+            ManageAction::Discard,
+        ]));
+        let operation = ManageOperation::new(
+            workspace(),
+            Arc::clone(&release_state_io),
+            Arc::clone(&interaction),
+        );
+
+        let output = operation.execute(Path::new("/any"))?;
+
+        assert!(!output.saved);
+        assert!(output.graduation.is_empty());
+        assert!(release_state_io.get_graduation_state().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_edits_are_reported_and_skipped() -> anyhow::Result<()> {
+        let release_state_io = Arc::new(MockReleaseStateIO::new());
+        let interaction = Arc::new(MockManageInteractionProvider::new(vec![
+            ManageAction::AddPrerelease {
+                package: "crate-a".to_string(),
+                tag: "alpha.01".to_string(),
+            },
+            ManageAction::AddGraduation {
+                package: "crate-b".to_string(),
+            },
+            ManageAction::AddPrerelease {
+                package: "missing".to_string(),
+                tag: "alpha".to_string(),
+            },
+            ManageAction::RemoveGraduation {
+                package: "crate-a".to_string(),
+            },
+            ManageAction::Save,
+        ]));
+        let operation = ManageOperation::new(
+            workspace(),
+            Arc::clone(&release_state_io),
+            Arc::clone(&interaction),
+        );
+
+        let output = operation.execute(Path::new("/any"))?;
+
+        let rejections = interaction.rejections();
+        assert_eq!(rejections.len(), 4);
+        assert!(matches!(
+            rejections[0],
+            ManageRejection::InvalidPrereleaseTag { .. }
+        ));
+        assert!(matches!(
+            rejections[1],
+            ManageRejection::CannotGraduateStable { .. }
+        ));
+        assert!(matches!(
+            rejections[2],
+            ManageRejection::PackageNotFound { .. }
+        ));
+        assert!(matches!(rejections[3], ManageRejection::NotListed { .. }));
+        assert!(output.prerelease.is_empty());
+        assert!(output.graduation.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn view_previews_resulting_toml() -> anyhow::Result<()> {
+        let release_state_io = Arc::new(MockReleaseStateIO::new());
+        let interaction = Arc::new(MockManageInteractionProvider::new(vec![
+            ManageAction::AddPrerelease {
+                package: "crate-a".to_string(),
+                tag: "alpha".to_string(),
+            },
+            ManageAction::Discard,
+        ]));
+        let operation = ManageOperation::new(
+            workspace(),
+            Arc::clone(&release_state_io),
+            Arc::clone(&interaction),
+        );
+
+        operation.execute(Path::new("/any"))?;
+
+        let previews = interaction.prerelease_previews();
+        assert_eq!(previews[0], None);
+        let after_add = previews[1].as_deref().expect("preview after adding");
+        assert!(after_add.contains("config_version = 2"));
+        assert!(after_add.contains("crate-a = \"alpha\""));
+        assert_eq!(interaction.unsaved_changes(), vec![false, true]);
+        Ok(())
+    }
+}
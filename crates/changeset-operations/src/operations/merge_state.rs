@@ -0,0 +1,202 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use changeset_project::{
+    GraduationState, PrereleaseState, StateFile, StateMerge, migrate_state, serialize_state,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::TextFileIO;
+
+/// The three versions of a release state file git hands a merge driver.
+pub struct MergeStateInput {
+    /// Common ancestor; a missing or empty file merges as a plain union.
+    pub base: Option<PathBuf>,
+    /// Current branch's version, overwritten with the merge result.
+    pub ours: PathBuf,
+    /// Version being merged in.
+    pub theirs: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeStateOutput {
+    pub file: StateFile,
+    /// Entries both sides changed in different ways; `ours` was kept for them.
+    pub conflicts: Vec<String>,
+}
+
+/// Semantic merge of `pre-release.toml` or `graduation.toml`, usable as a git
+/// merge driver: entries added or removed on either side are kept that way,
+/// and only entries both sides changed differently are reported as conflicts.
+pub struct MergeStateOperation<T> {
+    text_file_io: T,
+}
+
+impl<T: TextFileIO> MergeStateOperation<T> {
+    pub fn new(text_file_io: T) -> Self {
+        Self { text_file_io }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or parsed, uses a newer
+    /// layout than this release supports, or the result cannot be written.
+    pub fn execute(&self, input: &MergeStateInput) -> Result<MergeStateOutput> {
+        let base = match &input.base {
+            Some(path) => self.read_table(path)?,
+            None => toml::Table::new(),
+        };
+        let ours = self.read_table(&input.ours)?;
+        let theirs = self.read_table(&input.theirs)?;
+
+        let file = detect_state_file([&base, &ours, &theirs]);
+        let conflicts = match file {
+            StateFile::Prerelease => self.merge_and_write(
+                file,
+                [base, ours, theirs],
+                &input.ours,
+                PrereleaseState::merge,
+            )?,
+            StateFile::Graduation => self.merge_and_write(
+                file,
+                [base, ours, theirs],
+                &input.ours,
+                GraduationState::merge,
+            )?,
+        };
+
+        Ok(MergeStateOutput { file, conflicts })
+    }
+
+    fn merge_and_write<S: DeserializeOwned + Serialize>(
+        &self,
+        file: StateFile,
+        [base, ours, theirs]: [toml::Table; 3],
+        output: &Path,
+        merge: fn(&S, &S, &S) -> StateMerge<S>,
+    ) -> Result<Vec<String>> {
+        let base: S = migrate_state(file, base)?.state;
+        let ours: S = migrate_state(file, ours)?.state;
+        let theirs: S = migrate_state(file, theirs)?.state;
+
+        let merged = merge(&base, &ours, &theirs);
+        let content = serialize_state(&merged.state).map_err(|source| {
+            OperationError::ReleaseStateSerialize {
+                path: output.to_path_buf(),
+                source,
+            }
+        })?;
+        self.text_file_io.write_file(output, &content)?;
+        Ok(merged.conflicts)
+    }
+
+    fn read_table(&self, path: &Path) -> Result<toml::Table> {
+        let content = match self.text_file_io.read_file(path) {
+            Ok(content) => content,
+            Err(OperationError::TextFileRead { source, .. })
+                if source.kind() == ErrorKind::NotFound =>
+            {
+                return Ok(toml::Table::new());
+            }
+            Err(err) => return Err(err),
+        };
+        toml::from_str(&content).map_err(|source| OperationError::ReleaseStateParse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// Tells the state files apart by their keys, since git passes the three
+/// versions as temporary files with unrelated names.
+fn detect_state_file(tables: [&toml::Table; 3]) -> StateFile {
+    let is_graduation = tables
+        .iter()
+        .any(|table| table.contains_key("graduation") || table.contains_key("policies"));
+    if is_graduation {
+        StateFile::Graduation
+    } else {
+        StateFile::Prerelease
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::providers::memory::InMemoryTextFileIO;
+
+    fn input() -> MergeStateInput {
+        MergeStateInput {
+            base: Some(PathBuf::from("/tmp/base")),
+            ours: PathBuf::from("/tmp/ours"),
+            theirs: PathBuf::from("/tmp/theirs"),
+        }
+    }
+
+    #[test]
+    fn merges_prerelease_state_into_ours() -> anyhow::Result<()> {
+        let io = Arc::new(
+            InMemoryTextFileIO::new()
+                .with_file(
+                    PathBuf::from("/tmp/base"),
+                    "config_version = 2\n\n[packages]\ncrate-a = \"alpha\"\n",
+                )
+                .with_file(
+                    PathBuf::from("/tmp/ours"),
+                    "config_version = 2\n\n[packages]\ncrate-a = \"alpha\"\ncrate-b = \"beta\"\n",
+                )
+                .with_file(
+                    PathBuf::from("/tmp/theirs"),
+                    "config_version = 2\n\n[packages]\ncrate-c = \"rc\"\n",
+                ),
+        );
+        let operation = MergeStateOperation::new(Arc::clone(&io));
+
+        let output = operation.execute(&input())?;
+
+        assert_eq!(output.file, StateFile::Prerelease);
+        assert!(output.conflicts.is_empty());
+        let merged: toml::Table = io
+            .file(Path::new("/tmp/ours"))
+            .unwrap_or_default()
+            .parse()?;
+        let packages = merged.get("packages").and_then(toml::Value::as_table);
+        assert_eq!(
+            packages.map(|packages| packages.keys().cloned().collect::<Vec<_>>()),
+            Some(vec!["crate-b".to_string(), "crate-c".to_string()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reports_conflicting_graduation_policies() -> anyhow::Result<()> {
+        let io = Arc::new(
+            InMemoryTextFileIO::new()
+                .with_file(
+                    PathBuf::from("/tmp/ours"),
+                    "config_version = 2\ngraduation = []\n\n\
+                     [policies.crate-a]\ngraduate_on = \"major\"\n",
+                )
+                .with_file(
+                    PathBuf::from("/tmp/theirs"),
+                    "config_version = 2\ngraduation = [\"crate-b\"]\n\n\
+                     [policies.crate-a]\ngraduate_on = \"minor\"\n",
+                ),
+        );
+        let operation = MergeStateOperation::new(Arc::clone(&io));
+
+        let output = operation.execute(&input())?;
+
+        assert_eq!(output.file, StateFile::Graduation);
+        assert_eq!(output.conflicts, ["graduation policy of crate-a"]);
+        let merged = io.file(Path::new("/tmp/ours")).unwrap_or_default();
+        assert!(merged.contains("graduation = [\"crate-b\"]"));
+        assert!(merged.contains("graduate_on = \"major\""));
+        Ok(())
+    }
+}
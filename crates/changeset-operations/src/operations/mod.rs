@@ -1,22 +1,69 @@
 mod add;
 mod changelog_aggregation;
+mod changelog_pages;
+mod dependency_update;
+mod doctor;
+mod drift;
+mod grep;
+mod history;
+mod hook;
 mod init;
+mod manage;
+mod merge_state;
+mod new_crate;
+mod next_version;
+mod notify;
+mod publish;
 pub mod release;
+mod retrospective;
 mod status;
+#[cfg(feature = "otlp")]
+mod telemetry;
 mod verify;
 
-pub use crate::planner::{ReleasePlan, VersionPlanner};
+pub use crate::planner::{PlanConfig, ReleasePlan, VersionPlanner, plan_from_parts};
 pub use add::{AddInput, AddOperation, AddResult};
+pub use changelog_aggregation::CONFIDENTIAL_PLACEHOLDER;
+pub use changelog_pages::{
+    ChangelogPagesInput, ChangelogPagesOperation, ChangelogPagesOutput, DEFAULT_CHANGELOG_PAGES_DIR,
+};
+pub use dependency_update::{
+    DependencyUpdateOperation, DependencyUpdateOutput, PackageDependencyUpdate,
+};
+pub use doctor::{DoctorInput, DoctorOperation, DoctorOutput};
+pub use drift::{DriftKind, VersionDrift, detect_version_drift};
+pub use grep::{ChangesetOrigin, GrepInput, GrepMatch, GrepOperation, GrepOutput};
+pub use history::{TaggedRelease, tagged_releases};
+pub use hook::{HookInstallInput, HookInstallOperation, HookInstallOutput, HookKind};
 pub use init::{
-    InitInput, InitOperation, InitOutput, InitPlan, build_config_from_input, build_default_config,
+    ConfigChange, InitInput, InitOperation, InitOutput, InitPlan, ProjectAnalysis, analyze_project,
+    build_config_from_input, build_default_config, build_init_plan, diff_config, merge_config,
+};
+pub use manage::{ManageOperation, ManageOutput};
+pub use merge_state::{MergeStateInput, MergeStateOperation, MergeStateOutput};
+pub use new_crate::{NewCrateInput, NewCrateOperation, NewCrateOutput};
+pub use next_version::{NextVersionOperation, NextVersionOutput};
+pub use notify::{NotifyOperation, NotifyOutcome, render_message};
+pub use publish::{
+    PackageVerification, PublishInput, PublishOperation, PublishOutput, SmokeCheck,
+    VerifyPublished, render_publish_verification,
 };
 pub use release::{
-    ChangelogUpdate, CommitResult, GitOperationResult, PackageVersion, ReleaseInput,
-    ReleaseOperation, ReleaseOutcome, ReleaseOutput, ReleaseSagaContext, TagResult,
+    AmendInput, AmendOutcome, AmendOutput, AmendReleaseOperation, BumpContribution, BumpSource,
+    COMPENSATION_JOURNAL_FILENAME, ChangelogUpdate, CommitResult, CompensateOperation,
+    CompensationAction, CompensationJournal, DirtyTreeAction, DynReleaseOperation, FileDiff,
+    GitOperationResult, JournalEntry, PackageProvenance, PackageVersion, PrereleaseProvenance,
+    PrereleaseSource, RELEASE_MANIFEST_FILENAME, RELEASE_SUMMARY_FILENAME, ReleaseCommit,
+    ReleaseInput, ReleaseManifest, ReleaseOperation, ReleaseOutcome, ReleaseOutput,
+    ReleaseSagaContext, ReleaseStep, ReleasedPackage, StepControl, StepTiming, TagResult,
+    ZeroVersionTransform, render_changelog_preview, render_release_summary,
 };
 pub use release::{
     PackageReleaseConfig, ReleaseCliInput, ReleaseValidator, ValidatedReleaseConfig,
     ValidationError, ValidationErrors,
 };
-pub use status::{StatusOperation, StatusOutput};
+pub use retrospective::{RetrospectiveOperation, RetrospectiveOutput, ShippedPackage};
+pub use status::{ChangesetAge, StatusOperation, StatusOutput, StatusSummary};
+#[cfg(feature = "otlp")]
+pub use telemetry::{OTLP_SERVICE_NAME, otlp_traces_endpoint, render_release_trace};
 pub use verify::{VerifyInput, VerifyOperation, VerifyOutcome};
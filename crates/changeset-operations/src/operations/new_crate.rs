@@ -0,0 +1,426 @@
+use std::path::{Component, Path, PathBuf};
+
+use changeset_core::{BumpType, Changeset, PackageRelease};
+use changeset_project::{CHANGESETS_SUBDIR, NewCrateTemplate, ProjectKind};
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{ChangesetWriter, ManifestWriter, ProjectProvider, TextFileIO};
+
+/// Summary of the changeset written for a new crate's first release.
+const INITIAL_RELEASE_SUMMARY: &str = "Initial release";
+
+#[derive(Debug, Default)]
+pub struct NewCrateInput {
+    pub name: String,
+    /// Scaffold `src/main.rs` instead of `src/lib.rs`.
+    pub binary: bool,
+    /// Also write an "Initial release" changeset with this bump.
+    pub initial_release: Option<BumpType>,
+}
+
+#[derive(Debug)]
+pub struct NewCrateOutput {
+    pub crate_dir: PathBuf,
+    /// Whether the crate was added to `workspace.members`; `false` when an
+    /// existing entry, such as `crates/*`, already covers it.
+    pub added_member: bool,
+    pub changeset: Option<PathBuf>,
+}
+
+/// Scaffolds a workspace member from the `new-crate` template, so that new
+/// crates start out with the workspace's changeset settings.
+pub struct NewCrateOperation<P, M, T, W> {
+    project_provider: P,
+    manifest_writer: M,
+    text_file_io: T,
+    changeset_writer: W,
+}
+
+impl<P, M, T, W> NewCrateOperation<P, M, T, W>
+where
+    P: ProjectProvider,
+    M: ManifestWriter,
+    T: TextFileIO,
+    W: ChangesetWriter,
+{
+    pub fn new(
+        project_provider: P,
+        manifest_writer: M,
+        text_file_io: T,
+        changeset_writer: W,
+    ) -> Self {
+        Self {
+            project_provider,
+            manifest_writer,
+            text_file_io,
+            changeset_writer,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the project is not a workspace, the name is invalid
+    /// or taken, a file of the crate already exists, or the crate, manifest
+    /// or changeset cannot be written.
+    pub fn execute(&self, start_path: &Path, input: &NewCrateInput) -> Result<NewCrateOutput> {
+        validate_crate_name(&input.name)?;
+
+        let project = self.project_provider.discover_project(start_path)?;
+        if project.kind == ProjectKind::SinglePackage {
+            return Err(OperationError::NotAWorkspace(project.root));
+        }
+
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let template = root_config.new_crate_template();
+        let relative_dir = template.path(&input.name);
+        let crate_dir = project.root.join(&relative_dir);
+
+        if let Some(existing) = project.packages.iter().find(|p| p.name == input.name) {
+            return Err(OperationError::CrateExists {
+                name: input.name.clone(),
+                path: existing.path.clone(),
+            });
+        }
+
+        let source_file = if input.binary {
+            (
+                "src/main.rs",
+                "fn main() {\n    println!(\"Hello, world!\");\n}\n",
+            )
+        } else {
+            ("src/lib.rs", "")
+        };
+        let files = [
+            (
+                "Cargo.toml",
+                render_manifest(&crate_dir.join("Cargo.toml"), &input.name, template)?,
+            ),
+            (source_file.0, source_file.1.to_string()),
+        ];
+        for (file, _) in &files {
+            let path = crate_dir.join(file);
+            if self.text_file_io.read_file(&path).is_ok() {
+                return Err(OperationError::CrateExists {
+                    name: input.name.clone(),
+                    path,
+                });
+            }
+        }
+        for (file, content) in &files {
+            self.text_file_io
+                .write_file(&crate_dir.join(file), content)?;
+        }
+
+        // Discovery expands the member globs the way Cargo does, so the crate
+        // is only listed explicitly when no existing entry picks it up.
+        let covered = self
+            .project_provider
+            .discover_project(&project.root)?
+            .packages
+            .iter()
+            .any(|p| p.name == input.name);
+        let added_member = !covered
+            && self.manifest_writer.add_workspace_member(
+                &project.root.join("Cargo.toml"),
+                &member_path(&relative_dir),
+            )?;
+
+        let changeset = match input.initial_release {
+            Some(bump_type) => {
+                let changeset = Changeset {
+                    summary: INITIAL_RELEASE_SUMMARY.to_string(),
+                    releases: vec![PackageRelease {
+                        name: input.name.clone(),
+                        bump_type,
+                    }],
+                    category: root_config
+                        .categories()
+                        .resolve("added")
+                        .unwrap_or_else(|| root_config.categories().default_category()),
+                    scope: None,
+                    consumed_for_prerelease: None,
+                    graduate: false,
+                    confidential: false,
+                    embargo: None,
+                    advisory: None,
+                };
+                let changeset_dir = self
+                    .project_provider
+                    .ensure_changeset_dir(&project, &root_config)?;
+                let filename = self
+                    .changeset_writer
+                    .write_changeset(&changeset_dir, &changeset)?;
+                Some(changeset_dir.join(CHANGESETS_SUBDIR).join(filename))
+            }
+            None => None,
+        };
+
+        Ok(NewCrateOutput {
+            crate_dir,
+            added_member,
+            changeset,
+        })
+    }
+}
+
+/// Checks `name` against Cargo's rules for package names.
+fn validate_crate_name(name: &str) -> Result<()> {
+    let invalid = |reason| OperationError::InvalidCrateName {
+        name: name.to_string(),
+        reason,
+    };
+
+    let Some(first) = name.chars().next() else {
+        return Err(invalid("the name is empty"));
+    };
+    if first.is_ascii_digit() {
+        return Err(invalid("the name must not start with a digit"));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(invalid(
+            "only ASCII letters, digits, `-` and `_` are allowed",
+        ));
+    }
+    Ok(())
+}
+
+fn render_manifest(path: &Path, name: &str, template: &NewCrateTemplate) -> Result<String> {
+    let metadata = toml::to_string(template.package_metadata()).map_err(|err| {
+        OperationError::TextFileWrite {
+            path: path.to_path_buf(),
+            source: std::io::Error::other(err),
+        }
+    })?;
+    Ok(changeset_manifest::render_package_manifest(
+        path,
+        name,
+        template.version(),
+        template.edition(),
+        &metadata,
+    )?)
+}
+
+/// `path` as a `workspace.members` entry, with `/` separators on every
+/// platform.
+fn member_path(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use changeset_core::ChangeCategory;
+    use changeset_project::RootChangesetConfig;
+
+    use super::*;
+    use crate::mocks::{MockChangesetWriter, MockManifestWriter, MockProjectProvider};
+    use crate::providers::memory::InMemoryTextFileIO;
+
+    struct Fixture {
+        manifest_writer: Arc<MockManifestWriter>,
+        files: Arc<InMemoryTextFileIO>,
+        changesets: Arc<MockChangesetWriter>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                manifest_writer: Arc::new(MockManifestWriter::new()),
+                files: Arc::new(InMemoryTextFileIO::new()),
+                changesets: Arc::new(MockChangesetWriter::new()),
+            }
+        }
+
+        fn run(
+            &self,
+            project_provider: MockProjectProvider,
+            input: &NewCrateInput,
+        ) -> Result<NewCrateOutput> {
+            NewCrateOperation::new(
+                project_provider,
+                Arc::clone(&self.manifest_writer),
+                Arc::clone(&self.files),
+                Arc::clone(&self.changesets),
+            )
+            .execute(Path::new("/any"), input)
+        }
+    }
+
+    fn input(name: &str) -> NewCrateInput {
+        NewCrateInput {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn scaffolds_library_and_registers_member() -> anyhow::Result<()> {
+        let fixture = Fixture::new();
+        let project_provider = MockProjectProvider::workspace(vec![("crate-a", "1.0.0")]);
+
+        let output = fixture.run(project_provider, &input("parser"))?;
+
+        let crate_dir = PathBuf::from("/mock/workspace/crates/parser");
+        assert_eq!(output.crate_dir, crate_dir);
+        assert!(output.added_member);
+        assert!(output.changeset.is_none());
+        let manifest = fixture
+            .files
+            .file(&crate_dir.join("Cargo.toml"))
+            .expect("manifest written");
+        assert!(manifest.starts_with(
+            "[package]\nname = \"parser\"\nversion = \"0.1.0\"\nedition = \"2024\"\n"
+        ));
+        assert_eq!(
+            fixture.files.file(&crate_dir.join("src/lib.rs")),
+            Some(String::new())
+        );
+        assert_eq!(
+            fixture.manifest_writer.added_workspace_members(),
+            [(
+                PathBuf::from("/mock/workspace/Cargo.toml"),
+                "crates/parser".to_string()
+            )]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn applies_template_settings() -> anyhow::Result<()> {
+        let fixture = Fixture::new();
+        let mut metadata = toml::Table::new();
+        metadata.insert("tags".to_string(), toml::Value::Boolean(false));
+        let template = NewCrateTemplate::default()
+            .with_path("libs/{name}")
+            .with_package_metadata(metadata);
+        let project_provider = MockProjectProvider::workspace(vec![("crate-a", "1.0.0")])
+            .with_root_config(RootChangesetConfig::default().with_new_crate_template(template));
+
+        let output = fixture.run(
+            project_provider,
+            &NewCrateInput {
+                binary: true,
+                ..input("tool")
+            },
+        )?;
+
+        assert_eq!(output.crate_dir, PathBuf::from("/mock/workspace/libs/tool"));
+        let manifest = fixture
+            .files
+            .file(&output.crate_dir.join("Cargo.toml"))
+            .expect("manifest written");
+        assert!(manifest.contains("[package.metadata.changeset]\ntags = false\n"));
+        assert!(
+            fixture
+                .files
+                .file(&output.crate_dir.join("src/main.rs"))
+                .is_some()
+        );
+        assert!(
+            fixture
+                .files
+                .file(&output.crate_dir.join("src/lib.rs"))
+                .is_none()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn writes_initial_release_changeset() -> anyhow::Result<()> {
+        let fixture = Fixture::new();
+        let project_provider = MockProjectProvider::workspace(vec![("crate-a", "1.0.0")]);
+
+        let output = fixture.run(
+            project_provider,
+            &NewCrateInput {
+                initial_release: Some(BumpType::Minor),
+                ..input("parser")
+            },
+        )?;
+
+        assert!(output.changeset.is_some());
+        let written = fixture.changesets.written_changesets();
+        assert_eq!(written.len(), 1);
+        let changeset = &written[0].1;
+        assert_eq!(changeset.summary, "Initial release");
+        assert_eq!(changeset.category, ChangeCategory::Added);
+        assert_eq!(changeset.releases[0].name, "parser");
+        assert_eq!(changeset.releases[0].bump_type, BumpType::Minor);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_existing_package() {
+        let fixture = Fixture::new();
+        let project_provider = MockProjectProvider::workspace(vec![("crate-a", "1.0.0")]);
+
+        let err = fixture
+            .run(project_provider, &input("crate-a"))
+            .expect_err("existing package should be rejected");
+
+        assert!(matches!(err, OperationError::CrateExists { ref name, .. } if name == "crate-a"));
+        assert!(fixture.manifest_writer.added_workspace_members().is_empty());
+    }
+
+    #[test]
+    fn does_not_overwrite_existing_files() {
+        let path = PathBuf::from("/mock/workspace/crates/parser/src/lib.rs");
+        let fixture = Fixture {
+            files: Arc::new(InMemoryTextFileIO::new().with_file(path.clone(), "pub fn parse() {}")),
+            ..Fixture::new()
+        };
+        let project_provider = MockProjectProvider::workspace(vec![("crate-a", "1.0.0")]);
+
+        let err = fixture
+            .run(project_provider, &input("parser"))
+            .expect_err("existing files should be kept");
+
+        assert!(matches!(err, OperationError::CrateExists { path: ref p, .. } if *p == path));
+        assert_eq!(
+            fixture.files.file(&path).as_deref(),
+            Some("pub fn parse() {}")
+        );
+    }
+
+    #[test]
+    fn rejects_single_package_project() {
+        let fixture = Fixture::new();
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+
+        let err = fixture
+            .run(project_provider, &input("parser"))
+            .expect_err("single package should be rejected");
+
+        assert!(matches!(err, OperationError::NotAWorkspace(_)));
+    }
+
+    #[test]
+    fn validates_crate_names() {
+        for name in ["", "1parser", "my crate", "parser!"] {
+            assert!(
+                matches!(
+                    validate_crate_name(name),
+                    Err(OperationError::InvalidCrateName { .. })
+                ),
+                "{name:?} should be rejected"
+            );
+        }
+        for name in ["parser", "my-crate", "my_crate2"] {
+            assert!(
+                validate_crate_name(name).is_ok(),
+                "{name:?} should be valid"
+            );
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::path::Path;
+
+use changeset_core::{BumpType, PackageInfo};
+use semver::Version;
+
+use crate::Result;
+use crate::changeset_dirs;
+use crate::error::OperationError;
+use crate::package_selection;
+use crate::planner::{PlanConfig, plan_from_parts};
+use crate::traits::{ChangesetReader, ProjectProvider};
+
+/// The version a package would be released as with the pending changesets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextVersionOutput {
+    pub package: String,
+    pub current_version: Version,
+    /// Equal to `current_version` when no pending changeset affects the package.
+    pub next_version: Version,
+    /// `None` when no pending changeset affects the package.
+    pub bump_type: Option<BumpType>,
+}
+
+pub struct NextVersionOperation<P, R> {
+    project_provider: P,
+    changeset_reader: R,
+}
+
+impl<P, R> NextVersionOperation<P, R>
+where
+    P: ProjectProvider,
+    R: ChangesetReader,
+{
+    pub fn new(project_provider: P, changeset_reader: R) -> Self {
+        Self {
+            project_provider,
+            changeset_reader,
+        }
+    }
+
+    /// Computes the next version of `package`, or of the only package when `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be discovered, changeset files
+    /// cannot be read, the package is unknown, or no package was given in a
+    /// project with several packages.
+    pub fn execute(&self, start_path: &Path, package: Option<&str>) -> Result<NextVersionOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let package = select_package(&project.packages, package)?;
+
+        let changeset_dirs = changeset_dirs::changeset_dirs(&project.root, &root_config);
+        let changesets = changeset_dirs::list_changesets(&self.changeset_reader, &changeset_dirs)?
+            .iter()
+            .map(|path| self.changeset_reader.read_changeset(path))
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = plan_from_parts(
+            &project.packages,
+            &changesets,
+            &PlanConfig::from(&root_config),
+        )?;
+        let release = plan.releases.into_iter().find(|r| r.name == package.name);
+
+        Ok(NextVersionOutput {
+            package: package.name.clone(),
+            current_version: package.version.clone(),
+            next_version: release
+                .as_ref()
+                .map_or_else(|| package.version.clone(), |r| r.new_version.clone()),
+            bump_type: release.map(|r| r.bump_type),
+        })
+    }
+}
+
+fn select_package<'a>(packages: &'a [PackageInfo], name: Option<&str>) -> Result<&'a PackageInfo> {
+    let available = || {
+        packages
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match (name, packages) {
+        (Some(name), _) => packages
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| package_selection::unknown_package(name, packages)),
+        (None, [package]) => Ok(package),
+        (None, _) => Err(OperationError::PackageRequired {
+            available: available(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::mocks::{MockChangesetReader, MockProjectProvider, make_changeset};
+
+    #[test]
+    fn single_package_needs_no_name() -> anyhow::Result<()> {
+        let operation = NextVersionOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.2.3"),
+            MockChangesetReader::new().with_changeset(
+                PathBuf::from(".changeset/changesets/feature.md"),
+                make_changeset("my-crate", BumpType::Minor, "Add a feature"),
+            ),
+        );
+
+        let output = operation.execute(Path::new("/any"), None)?;
+
+        assert_eq!(output.package, "my-crate");
+        assert_eq!(output.current_version, Version::new(1, 2, 3));
+        assert_eq!(output.next_version, Version::new(1, 3, 0));
+        assert_eq!(output.bump_type, Some(BumpType::Minor));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unaffected_package_keeps_its_version() -> anyhow::Result<()> {
+        let operation = NextVersionOperation::new(
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")]),
+            MockChangesetReader::new().with_changeset(
+                PathBuf::from(".changeset/changesets/fix.md"),
+                make_changeset("crate-a", BumpType::Patch, "Fix"),
+            ),
+        );
+
+        let output = operation.execute(Path::new("/any"), Some("crate-b"))?;
+
+        assert_eq!(output.next_version, Version::new(2, 0, 0));
+        assert!(output.bump_type.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn workspace_requires_package_name() {
+        let operation = NextVersionOperation::new(
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")]),
+            MockChangesetReader::new(),
+        );
+
+        let err = operation
+            .execute(Path::new("/any"), None)
+            .expect_err("workspace without --package");
+
+        assert!(matches!(
+            err,
+            OperationError::PackageRequired { ref available } if available == "crate-a, crate-b"
+        ));
+    }
+
+    #[test]
+    fn unknown_package_is_rejected() {
+        let operation = NextVersionOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.0.0"),
+            MockChangesetReader::new(),
+        );
+
+        let err = operation
+            .execute(Path::new("/any"), Some("other"))
+            .expect_err("unknown package");
+
+        assert!(matches!(err, OperationError::UnknownPackage { .. }));
+    }
+}
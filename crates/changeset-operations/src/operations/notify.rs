@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use changeset_project::{NotificationConfig, WebhookFormat};
+use serde_json::json;
+
+use crate::Result;
+use crate::operations::release::ReleaseManifest;
+use crate::traits::{Notifier, ProjectProvider};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NotifyOutcome {
+    /// No webhook URL is configured.
+    NotConfigured,
+    /// The notification was delivered.
+    Sent,
+}
+
+pub struct NotifyOperation<P, N> {
+    project_provider: P,
+    notifier: N,
+}
+
+impl<P, N> NotifyOperation<P, N>
+where
+    P: ProjectProvider,
+    N: Notifier,
+{
+    pub fn new(project_provider: P, notifier: N) -> Self {
+        Self {
+            project_provider,
+            notifier,
+        }
+    }
+
+    /// Posts a release notification to the configured webhook.
+    ///
+    /// Runs after the release has completed, possibly in a later job that
+    /// only has the release manifest, so callers should treat failures as
+    /// warnings rather than undoing the release.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project configuration cannot be loaded or the
+    /// webhook request fails.
+    pub fn execute(&self, start_path: &Path, manifest: &ReleaseManifest) -> Result<NotifyOutcome> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let config = root_config.notification_config();
+
+        let Some(url) = config.webhook_url() else {
+            return Ok(NotifyOutcome::NotConfigured);
+        };
+
+        let body = build_payload(config, manifest);
+        self.notifier.post_json(url, &body)?;
+
+        Ok(NotifyOutcome::Sent)
+    }
+}
+
+/// Renders the configured message template for a release.
+///
+/// Supported placeholders: `{releases}`, `{tags}`, `{commit}` and `{changelog}`.
+#[must_use]
+pub fn render_message(template: &str, manifest: &ReleaseManifest) -> String {
+    template
+        .replace("{releases}", &releases_text(manifest))
+        .replace("{tags}", &manifest.tags.join(", "))
+        .replace("{commit}", manifest.commit.as_deref().unwrap_or_default())
+        .replace("{changelog}", &manifest.changelog.join("\n\n"))
+}
+
+fn build_payload(config: &NotificationConfig, manifest: &ReleaseManifest) -> String {
+    let message = render_message(config.message_template(), manifest);
+
+    let payload = match config.webhook_format() {
+        WebhookFormat::Slack => json!({ "text": message }),
+        WebhookFormat::Discord => json!({ "content": message }),
+        WebhookFormat::Generic => json!({
+            "message": message,
+            "releases": manifest
+                .packages
+                .iter()
+                .map(|p| json!({
+                    "name": p.name,
+                    "previous_version": p.previous_version,
+                    "version": p.version,
+                }))
+                .collect::<Vec<_>>(),
+            "tags": manifest.tags,
+            "commit": manifest.commit,
+            "changelog": manifest.changelog.join("\n\n"),
+        }),
+    };
+
+    payload.to_string()
+}
+
+fn releases_text(manifest: &ReleaseManifest) -> String {
+    manifest
+        .packages
+        .iter()
+        .map(|p| format!("{} {}", p.name, p.version))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::mocks::{MockNotifier, MockProjectProvider};
+    use crate::operations::release::{GitOperationResult, ReleaseOutput, TagResult};
+    use crate::types::PackageVersion;
+    use changeset_core::BumpType;
+    use changeset_project::RootChangesetConfig;
+
+    fn make_manifest() -> ReleaseManifest {
+        ReleaseManifest::from_output(&ReleaseOutput {
+            planned_releases: vec![PackageVersion {
+                name: "my-crate".to_string(),
+                current_version: "1.0.0".parse().expect("valid version"),
+                new_version: "1.0.1".parse().expect("valid version"),
+                bump_type: BumpType::Patch,
+            }],
+            unchanged_packages: Vec::new(),
+            changesets_consumed: Vec::new(),
+            changeset_owners: HashMap::new(),
+            changelog_updates: Vec::new(),
+            git_result: Some(GitOperationResult {
+                commit: None,
+                release_commits: Vec::new(),
+                tags_created: vec![TagResult {
+                    name: "v1.0.1".to_string(),
+                    target_sha: "abc1234".to_string(),
+                }],
+                changesets_deleted: Vec::new(),
+            }),
+            step_timings: Vec::new(),
+            provenance: Vec::new(),
+            file_diffs: Vec::new(),
+        })
+    }
+
+    fn provider_with(config: NotificationConfig) -> MockProjectProvider {
+        MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(RootChangesetConfig::default().with_notification_config(config))
+    }
+
+    #[test]
+    fn skips_when_no_webhook_configured() {
+        let operation = NotifyOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.0.0"),
+            MockNotifier::new(),
+        );
+
+        let outcome = operation
+            .execute(Path::new("/any"), &make_manifest())
+            .expect("notify failed");
+
+        assert_eq!(outcome, NotifyOutcome::NotConfigured);
+    }
+
+    #[test]
+    fn posts_slack_payload_with_rendered_template() {
+        let config = NotificationConfig::default()
+            .with_webhook("https://hooks.example.com/slack", WebhookFormat::Slack)
+            .with_message_template("Shipped {releases} ({tags})");
+        let notifier = std::sync::Arc::new(MockNotifier::new());
+        let operation = NotifyOperation::new(provider_with(config), notifier.clone());
+
+        let outcome = operation
+            .execute(Path::new("/any"), &make_manifest())
+            .expect("notify failed");
+
+        assert_eq!(outcome, NotifyOutcome::Sent);
+        let posted = notifier.posted();
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].0, "https://hooks.example.com/slack");
+        assert_eq!(posted[0].1, r#"{"text":"Shipped my-crate 1.0.1 (v1.0.1)"}"#);
+    }
+
+    #[test]
+    fn generic_payload_includes_release_data() {
+        let config = NotificationConfig::default()
+            .with_webhook("https://hooks.example.com", WebhookFormat::Generic);
+
+        let body = build_payload(&config, &make_manifest());
+        let value: serde_json::Value = serde_json::from_str(&body).expect("valid json");
+
+        assert_eq!(value["message"], "Released my-crate 1.0.1");
+        assert_eq!(value["releases"][0]["previous_version"], "1.0.0");
+        assert_eq!(value["tags"][0], "v1.0.1");
+        assert!(value["commit"].is_null());
+    }
+
+    #[test]
+    fn propagates_notifier_errors() {
+        let config = NotificationConfig::default()
+            .with_webhook("https://hooks.example.com", WebhookFormat::Discord);
+        let operation = NotifyOperation::new(provider_with(config), MockNotifier::failing());
+
+        let result = operation.execute(Path::new("/any"), &make_manifest());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,568 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use changeset_core::PackageInfo;
+use changeset_project::{ReleaseChannel, publish_order};
+use tracing::{info, warn};
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::operations::release::ReleaseManifest;
+use crate::traits::{CommandOutput, CommandRunner, ProjectProvider, Publisher, RegistryIndex};
+
+#[derive(Debug, Default)]
+pub struct PublishInput {
+    /// Run `cargo publish --dry-run` instead of uploading.
+    pub dry_run: bool,
+    /// Registry token to publish with; `None` lets cargo find its own.
+    pub token: Option<String>,
+    /// Skip packages whose current version the registry index already lists,
+    /// to finish a publish run that stopped partway.
+    pub only_missing: bool,
+    /// Check that the published versions reach the registry index.
+    pub verify: Option<VerifyPublished>,
+    /// Publish only the packages a release recorded, after checking that the
+    /// workspace holds the recorded versions.
+    pub manifest: Option<ReleaseManifest>,
+}
+
+/// How to check published versions once every package is uploaded.
+#[derive(Debug, Clone)]
+pub struct VerifyPublished {
+    /// How long to wait for all versions to appear in the index.
+    pub timeout: Duration,
+    /// Pause between index queries.
+    pub poll_interval: Duration,
+    pub smoke_check: Option<SmokeCheck>,
+}
+
+/// A command run against each published version once it is in the index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmokeCheck {
+    /// `cargo install` the exact version.
+    Install,
+    /// `cargo add` the exact version to a new library and build it.
+    Add,
+}
+
+impl SmokeCheck {
+    fn command(self, package: &PackageInfo) -> String {
+        let name = &package.name;
+        let version = &package.version;
+        match self {
+            Self::Install => format!("cargo install {name} --version ={version} --root ."),
+            Self::Add => format!(
+                "cargo init --lib --name publish-smoke-check --vcs none \
+                 && cargo add {name}@={version} && cargo check"
+            ),
+        }
+    }
+}
+
+/// The outcome of checking one published package.
+#[derive(Debug, Clone)]
+pub struct PackageVerification {
+    pub package: PackageInfo,
+    /// Whether the version appeared in the index before the timeout.
+    pub visible: bool,
+    /// The smoke check's output; `None` if none was requested or the version
+    /// never appeared.
+    pub smoke_check: Option<CommandOutput>,
+}
+
+impl PackageVerification {
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.visible
+            && self
+                .smoke_check
+                .as_ref()
+                .is_none_or(|output| output.success)
+    }
+}
+
+#[derive(Debug)]
+pub struct PublishOutput {
+    /// Packages published, in the order they were uploaded.
+    pub published: Vec<PackageInfo>,
+    /// Packages left out by [`PublishInput::only_missing`] because their
+    /// version is already on the registry.
+    pub already_published: Vec<PackageInfo>,
+    /// Packages whose version belongs to a release channel that does not
+    /// publish, such as `nightly`.
+    pub held_back: Vec<PackageInfo>,
+    pub dry_run: bool,
+    /// One entry per published package when verification was requested.
+    pub verifications: Vec<PackageVerification>,
+}
+
+impl PublishOutput {
+    /// Verifications that did not pass.
+    pub fn unverified(&self) -> impl Iterator<Item = &PackageVerification> {
+        self.verifications
+            .iter()
+            .filter(|verification| !verification.passed())
+    }
+}
+
+pub struct PublishOperation<P, U> {
+    project_provider: P,
+    publisher: U,
+    registry_index: Option<Box<dyn RegistryIndex>>,
+    command_runner: Option<Box<dyn CommandRunner>>,
+}
+
+impl<P, U> PublishOperation<P, U>
+where
+    P: ProjectProvider,
+    U: Publisher,
+{
+    pub fn new(project_provider: P, publisher: U) -> Self {
+        Self {
+            project_provider,
+            publisher,
+            registry_index: None,
+            command_runner: None,
+        }
+    }
+
+    /// Queries `index` for published versions when [`PublishInput::verify`]
+    /// or [`PublishInput::only_missing`] is set; without an index, neither
+    /// has any effect.
+    #[must_use]
+    pub fn with_registry_index(mut self, index: Box<dyn RegistryIndex>) -> Self {
+        self.registry_index = Some(index);
+        self
+    }
+
+    /// Runs smoke checks through `runner`.
+    #[must_use]
+    pub fn with_command_runner(mut self, runner: Box<dyn CommandRunner>) -> Self {
+        self.command_runner = Some(runner);
+        self
+    }
+
+    /// Publishes every publishable workspace package, dependencies first.
+    ///
+    /// With [`PublishInput::manifest`], only the packages the release recorded
+    /// are published. With [`PublishInput::only_missing`], packages whose
+    /// version is already on the registry are skipped. Either way the rest
+    /// keep their order. Packages whose version belongs to a release channel
+    /// with publishing turned off are held back.
+    ///
+    /// Stops at the first package that fails, since the packages after it may
+    /// depend on it. Packages that never show up in the index, or fail their
+    /// smoke check, are reported in [`PublishOutput::verifications`] rather
+    /// than as an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be loaded, the workspace versions
+    /// differ from the release manifest, a package fails to publish, or the
+    /// registry index cannot be queried.
+    pub fn execute(&self, start_path: &Path, input: &PublishInput) -> Result<PublishOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let mut packages = publish_order(&project)?;
+        if let Some(manifest) = &input.manifest {
+            check_manifest_versions(manifest, &project.packages)?;
+            packages.retain(|package| manifest.version_of(&package.name).is_some());
+        }
+        let (packages, held_back): (Vec<_>, Vec<_>) = packages.into_iter().partition(|package| {
+            root_config
+                .channel_of_version(&package.version)
+                .is_none_or(ReleaseChannel::publish)
+        });
+
+        let mut published = Vec::with_capacity(packages.len());
+        let mut already_published = Vec::new();
+        for package in packages {
+            if input.only_missing
+                && let Some(index) = &self.registry_index
+                && index.has_version(&package.name, &package.version)?
+            {
+                info!(package = %package.name, version = %package.version, "skipping version already in the registry index");
+                already_published.push(package);
+                continue;
+            }
+            let output = self.publisher.publish(
+                &project.root,
+                &package.name,
+                input.token.as_deref(),
+                input.dry_run,
+            )?;
+            if !output.success {
+                return Err(OperationError::PublishFailed {
+                    package: package.name,
+                    status: output.status(),
+                    output: output.captured(),
+                });
+            }
+            published.push(package);
+        }
+
+        let verifications = match (&input.verify, &self.registry_index) {
+            (Some(verify), Some(index)) if !input.dry_run => {
+                self.verify_published(index.as_ref(), &published, verify)?
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(PublishOutput {
+            published,
+            already_published,
+            held_back,
+            dry_run: input.dry_run,
+            verifications,
+        })
+    }
+
+    fn verify_published(
+        &self,
+        index: &dyn RegistryIndex,
+        published: &[PackageInfo],
+        verify: &VerifyPublished,
+    ) -> Result<Vec<PackageVerification>> {
+        let deadline = Instant::now() + verify.timeout;
+        let mut visible = vec![false; published.len()];
+        loop {
+            for (package, visible) in published.iter().zip(visible.iter_mut()) {
+                if !*visible && index.has_version(&package.name, &package.version)? {
+                    info!(package = %package.name, version = %package.version, "published version is in the registry index");
+                    *visible = true;
+                }
+            }
+            if visible.iter().all(|visible| *visible) || Instant::now() >= deadline {
+                break;
+            }
+            std::thread::sleep(verify.poll_interval);
+        }
+
+        let mut verifications = Vec::with_capacity(published.len());
+        for (package, visible) in published.iter().zip(visible) {
+            if !visible {
+                warn!(package = %package.name, version = %package.version, timeout = ?verify.timeout, "published version never appeared in the registry index");
+            }
+            let smoke_check = match (verify.smoke_check, &self.command_runner) {
+                (Some(check), Some(runner)) if visible => {
+                    Some(run_smoke_check(runner.as_ref(), check, package)?)
+                }
+                _ => None,
+            };
+            verifications.push(PackageVerification {
+                package: package.clone(),
+                visible,
+                smoke_check,
+            });
+        }
+
+        Ok(verifications)
+    }
+}
+
+fn check_manifest_versions(manifest: &ReleaseManifest, packages: &[PackageInfo]) -> Result<()> {
+    for released in &manifest.packages {
+        let workspace_version = packages
+            .iter()
+            .find(|package| package.name == released.name)
+            .map(|package| package.version.to_string());
+        if workspace_version.as_deref() != Some(released.version.as_str()) {
+            return Err(OperationError::ReleaseManifestMismatch {
+                package: released.name.clone(),
+                manifest_version: released.version.clone(),
+                workspace_version,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn run_smoke_check(
+    runner: &dyn CommandRunner,
+    check: SmokeCheck,
+    package: &PackageInfo,
+) -> Result<CommandOutput> {
+    // A scratch directory keeps the workspace's own config and lockfile out
+    // of the check, so it sees what a downstream user would.
+    let scratch = tempfile::TempDir::new()?;
+    let output = runner.run(scratch.path(), &check.command(package))?;
+    if output.success {
+        info!(package = %package.name, version = %package.version, check = ?check, "smoke check passed");
+    } else {
+        warn!(package = %package.name, version = %package.version, check = ?check, status = %output.status(), "smoke check failed");
+    }
+    Ok(output)
+}
+
+/// Renders a markdown section listing each verified package, for appending to
+/// the release summary.
+#[must_use]
+pub fn render_publish_verification(output: &PublishOutput) -> String {
+    let mut section = String::from("## Publish Verification\n\n");
+    section.push_str("| Package | Version | In index | Smoke check |\n");
+    section.push_str("| --- | --- | --- | --- |\n");
+    for verification in &output.verifications {
+        let smoke_check = match &verification.smoke_check {
+            None => "-".to_string(),
+            Some(check) if check.success => "passed".to_string(),
+            Some(check) => format!("failed ({})", check.status()),
+        };
+        let _ = writeln!(
+            section,
+            "| {} | {} | {} | {smoke_check} |",
+            verification.package.name,
+            verification.package.version,
+            if verification.visible { "yes" } else { "no" },
+        );
+    }
+    section
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::mocks::{MockCommandRunner, MockPublisher, MockRegistryIndex};
+    use crate::providers::FileSystemProjectProvider;
+
+    fn workspace() -> anyhow::Result<TempDir> {
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+        )?;
+        for (name, extra) in [
+            ("app", "[dependencies]\nlib = { path = \"../lib\" }\n"),
+            ("lib", ""),
+        ] {
+            let path = dir.path().join("crates").join(name);
+            fs::create_dir_all(path.join("src"))?;
+            fs::write(
+                path.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n{extra}"),
+            )?;
+            fs::write(path.join("src/lib.rs"), "")?;
+        }
+        Ok(dir)
+    }
+
+    #[test]
+    fn publishes_dependencies_first_with_token() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let publisher = Arc::new(MockPublisher::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), Arc::clone(&publisher));
+
+        let output = operation.execute(
+            dir.path(),
+            &PublishInput {
+                dry_run: false,
+                token: Some("secret".to_string()),
+                ..PublishInput::default()
+            },
+        )?;
+
+        let names: Vec<&str> = output.published.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["lib", "app"]);
+        assert_eq!(
+            publisher.published(),
+            vec![
+                ("lib".to_string(), Some("secret".to_string())),
+                ("app".to_string(), Some("secret".to_string())),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn holds_back_versions_of_unpublished_channels() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let lib_manifest = dir.path().join("crates/lib/Cargo.toml");
+        let manifest = fs::read_to_string(&lib_manifest)?;
+        fs::write(&lib_manifest, manifest.replace("1.0.0", "1.1.0-nightly.1"))?;
+        let publisher = Arc::new(MockPublisher::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), Arc::clone(&publisher));
+
+        let output = operation.execute(dir.path(), &PublishInput::default())?;
+
+        let held_back: Vec<&str> = output.held_back.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(held_back, ["lib"]);
+        assert_eq!(publisher.published(), vec![("app".to_string(), None)]);
+        Ok(())
+    }
+
+    #[test]
+    fn stops_at_first_failure() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let publisher = Arc::new(
+            MockPublisher::new().with_failure("lib", "error: crate lib@1.0.0 already exists"),
+        );
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), Arc::clone(&publisher));
+
+        let result = operation.execute(dir.path(), &PublishInput::default());
+
+        assert!(matches!(
+            result,
+            Err(OperationError::PublishFailed { ref package, ref output, .. })
+                if package == "lib" && output.contains("already exists")
+        ));
+        assert_eq!(publisher.published().len(), 1);
+        Ok(())
+    }
+
+    fn verify(smoke_check: Option<SmokeCheck>) -> PublishInput {
+        PublishInput {
+            verify: Some(VerifyPublished {
+                timeout: Duration::from_millis(50),
+                poll_interval: Duration::ZERO,
+                smoke_check,
+            }),
+            ..PublishInput::default()
+        }
+    }
+
+    #[test]
+    fn polls_until_versions_appear_and_runs_smoke_checks() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let index = Arc::new(MockRegistryIndex::new().with_delay("app", 2));
+        let runner = Arc::new(MockCommandRunner::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), MockPublisher::new())
+                .with_registry_index(Box::new(Arc::clone(&index)))
+                .with_command_runner(Box::new(Arc::clone(&runner)));
+
+        let output = operation.execute(dir.path(), &verify(Some(SmokeCheck::Install)))?;
+
+        assert_eq!(index.queries(), ["lib", "app", "app", "app"]);
+        assert!(output.verifications.iter().all(PackageVerification::passed));
+        let commands: Vec<String> = runner
+            .runs()
+            .into_iter()
+            .map(|(_, command)| command)
+            .collect();
+        assert_eq!(
+            commands,
+            [
+                "cargo install lib --version =1.0.0 --root .",
+                "cargo install app --version =1.0.0 --root .",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reports_versions_missing_after_timeout() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let runner = Arc::new(MockCommandRunner::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), MockPublisher::new())
+                .with_registry_index(Box::new(MockRegistryIndex::new().with_missing("app")))
+                .with_command_runner(Box::new(Arc::clone(&runner)));
+
+        let output = operation.execute(dir.path(), &verify(Some(SmokeCheck::Add)))?;
+
+        let unverified: Vec<&str> = output
+            .unverified()
+            .map(|verification| verification.package.name.as_str())
+            .collect();
+        assert_eq!(unverified, ["app"]);
+        assert_eq!(runner.runs().len(), 1);
+        assert!(render_publish_verification(&output).contains("| app | 1.0.0 | no | - |"));
+        Ok(())
+    }
+
+    #[test]
+    fn only_missing_skips_versions_already_on_the_registry() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let publisher = Arc::new(MockPublisher::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), Arc::clone(&publisher))
+                .with_registry_index(Box::new(MockRegistryIndex::new().with_missing("app")));
+
+        let output = operation.execute(
+            dir.path(),
+            &PublishInput {
+                only_missing: true,
+                ..PublishInput::default()
+            },
+        )?;
+
+        let skipped: Vec<&str> = output
+            .already_published
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect();
+        assert_eq!(skipped, ["lib"]);
+        assert_eq!(publisher.published(), vec![("app".to_string(), None)]);
+        Ok(())
+    }
+
+    fn manifest(packages: &[(&str, &str)]) -> ReleaseManifest {
+        ReleaseManifest {
+            packages: packages
+                .iter()
+                .map(|(name, version)| crate::operations::ReleasedPackage {
+                    name: (*name).to_string(),
+                    previous_version: "0.9.0".to_string(),
+                    version: (*version).to_string(),
+                    bump: changeset_core::BumpType::Minor,
+                    provenance: None,
+                })
+                .collect(),
+            tags: Vec::new(),
+            commit: None,
+            changelog: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn publishes_only_packages_in_the_release_manifest() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let publisher = Arc::new(MockPublisher::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), Arc::clone(&publisher));
+
+        let output = operation.execute(
+            dir.path(),
+            &PublishInput {
+                manifest: Some(manifest(&[("app", "1.0.0")])),
+                ..PublishInput::default()
+            },
+        )?;
+
+        assert_eq!(output.published.len(), 1);
+        assert_eq!(publisher.published(), vec![("app".to_string(), None)]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_a_manifest_for_another_checkout() -> anyhow::Result<()> {
+        let dir = workspace()?;
+        let publisher = Arc::new(MockPublisher::new());
+        let operation =
+            PublishOperation::new(FileSystemProjectProvider::new(), Arc::clone(&publisher));
+
+        let result = operation.execute(
+            dir.path(),
+            &PublishInput {
+                manifest: Some(manifest(&[("lib", "1.1.0")])),
+                ..PublishInput::default()
+            },
+        );
+
+        assert!(matches!(
+            result,
+            Err(OperationError::ReleaseManifestMismatch { ref workspace_version, .. })
+                if workspace_version.as_deref() == Some("1.0.0")
+        ));
+        assert!(publisher.published().is_empty());
+        Ok(())
+    }
+}
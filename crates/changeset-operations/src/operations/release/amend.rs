@@ -0,0 +1,905 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use changeset_changelog::{Changelog, ChangelogLocation, VersionRelease, json_changelog_path};
+use changeset_core::{Changeset, PackageInfo, ZeroVersionBehavior};
+use changeset_git::{CommitInfo, Identity};
+use changeset_project::TagBody;
+use chrono::Local;
+use semver::{Prerelease, Version};
+
+use super::operation::{CommitResult, TagResult};
+use super::saga_steps::release_commit_identity;
+use crate::Result;
+use crate::changeset_dirs;
+use crate::error::OperationError;
+use crate::operations::changelog_aggregation::ChangesetAggregator;
+use crate::providers::FileSystemTextFileIO;
+use crate::tags::{TagNaming, uses_crate_prefix};
+use crate::traits::{
+    ChangelogWriter, ChangesetReader, ChangesetWriter, GitProvider, ProjectProvider, TextFileIO,
+};
+
+pub struct AmendInput {
+    /// Commit the late changesets instead of deleting them.
+    pub keep_changesets: bool,
+    /// Publish summaries of confidential changesets that are still embargoed.
+    pub include_confidential: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct AmendOutput {
+    /// Packages of the amended release with their released versions.
+    pub releases: Vec<(String, Version)>,
+    pub changesets_consumed: Vec<PathBuf>,
+    pub changelogs_updated: Vec<PathBuf>,
+    pub commit: CommitResult,
+    pub tags_moved: Vec<TagResult>,
+}
+
+#[derive(Debug)]
+pub enum AmendOutcome {
+    Amended(AmendOutput),
+    NoChangesets,
+}
+
+/// A changelog extended by the amend, with its content beforehand.
+struct ChangelogBackup {
+    path: PathBuf,
+    original_content: Option<String>,
+}
+
+/// Folds changesets written after a release into that release.
+///
+/// The release is the commit at HEAD, recognised by its release tags. New
+/// changesets are added to the existing changelog sections without changing
+/// any version, the commit is amended and its tags are moved to the new commit.
+/// A release whose commit or tags already reached a remote is never amended.
+pub struct AmendReleaseOperation<P, RW, C, G> {
+    project_provider: P,
+    changeset_io: RW,
+    changelog_writer: C,
+    git_provider: G,
+    text_file_io: Arc<dyn TextFileIO>,
+}
+
+impl<P, RW, C, G> AmendReleaseOperation<P, RW, C, G>
+where
+    P: ProjectProvider,
+    RW: ChangesetReader + ChangesetWriter,
+    C: ChangelogWriter,
+    G: GitProvider,
+{
+    pub fn new(
+        project_provider: P,
+        changeset_io: RW,
+        changelog_writer: C,
+        git_provider: G,
+    ) -> Self {
+        Self {
+            project_provider,
+            changeset_io,
+            changelog_writer,
+            git_provider,
+            text_file_io: Arc::new(FileSystemTextFileIO::new()),
+        }
+    }
+
+    /// Replaces the file access used to back up the changelogs before they
+    /// are extended.
+    #[must_use]
+    pub fn with_text_file_io(mut self, text_file_io: Arc<dyn TextFileIO>) -> Self {
+        self.text_file_io = text_file_io;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if HEAD is not an unpushed release commit, files other
+    /// than new changesets are uncommitted, a changeset affects a package the
+    /// release does not include, or updating the changelogs or git fails.
+    pub fn execute(&self, start_path: &Path, input: &AmendInput) -> Result<AmendOutcome> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let git_config = root_config.git_config();
+        let changeset_dirs = changeset_dirs::changeset_dirs(&project.root, &root_config);
+
+        let late_changesets = self.find_late_changesets(
+            &project.root,
+            &changeset_dirs,
+            git_config.ignore_submodule_changes(),
+        )?;
+        if late_changesets.is_empty() {
+            return Ok(AmendOutcome::NoChangesets);
+        }
+
+        let naming = TagNaming::new(uses_crate_prefix(&project.kind, git_config.tag_format()))
+            .with_channels(root_config.channels());
+        let head_tags = self.git_provider.tags_at_head(&project.root)?;
+        let released_tags: Vec<(&PackageInfo, &String)> = project
+            .packages
+            .iter()
+            .filter_map(|package| {
+                head_tags
+                    .iter()
+                    .find(|head_tag| naming.is_tag_of(head_tag, &package.name, &package.version))
+                    .map(|head_tag| (package, head_tag))
+            })
+            .collect();
+        let released: Vec<&PackageInfo> =
+            released_tags.iter().map(|(package, _)| *package).collect();
+        if released.is_empty() {
+            return Err(OperationError::NoReleaseToAmend);
+        }
+        if self
+            .git_provider
+            .is_head_pushed(&project.root, &head_tags)?
+        {
+            return Err(OperationError::ReleaseAlreadyPushed);
+        }
+
+        let mut aggregator = ChangesetAggregator::new()
+            .with_display_names(root_config.display_names().clone())
+            .with_categories(root_config.categories().clone())
+            .with_changelog_config(root_config.changelog_config());
+        if !input.include_confidential {
+            aggregator = aggregator.redact_embargoed(Local::now().date_naive());
+        }
+        let changesets = self.read_late_changesets(&late_changesets, &released, &mut aggregator)?;
+        check_late_bumps(
+            &released,
+            &changesets,
+            &self.git_provider.list_tags(&project.root)?,
+            &naming,
+            root_config.zero_version_behavior(),
+        )?;
+
+        let location = root_config.changelog_config().changelog;
+        let extensions = changelog_extensions(&project.root, location, &aggregator, &released);
+        let keep_changesets = input.keep_changesets || git_config.keep_changesets();
+        let backups =
+            self.apply_changes(&project.root, &extensions, &changesets, keep_changesets)?;
+
+        let tags = if git_config.tag_body() == TagBody::Changelog {
+            self.changelog_tag_messages(
+                &project.root,
+                location,
+                &extensions,
+                &released_tags,
+                &backups,
+            )
+        } else {
+            MovedTags::keeping_messages(released_tags.len())
+        };
+        let committer = release_commit_identity(git_config).committer;
+        let (commit, tags_moved) =
+            match self.amend_with_tags(&project.root, committer.as_ref(), &released_tags, &tags) {
+                Ok(amended) => amended,
+                Err(error) => {
+                    self.restore(&project.root, &backups, &changesets, keep_changesets);
+                    return Err(error);
+                }
+            };
+
+        Ok(AmendOutcome::Amended(AmendOutput {
+            releases: released
+                .iter()
+                .map(|package| (package.name.clone(), package.version.clone()))
+                .collect(),
+            changesets_consumed: late_changesets,
+            changelogs_updated: extensions.into_iter().map(|(path, _)| path).collect(),
+            commit: CommitResult {
+                sha: commit.sha,
+                message: commit.message,
+            },
+            tags_moved,
+        }))
+    }
+
+    /// Reads the late changesets and adds them to `aggregator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::AmendPackageNotReleased` if a changeset
+    /// affects a package the release does not include.
+    fn read_late_changesets(
+        &self,
+        late_changesets: &[PathBuf],
+        released: &[&PackageInfo],
+        aggregator: &mut ChangesetAggregator,
+    ) -> Result<Vec<(PathBuf, Changeset)>> {
+        let mut changesets = Vec::new();
+        for path in late_changesets {
+            let changeset = self.changeset_io.read_changeset(path)?;
+            if let Some(release) = changeset
+                .releases
+                .iter()
+                .find(|release| !released.iter().any(|p| p.name == release.name))
+            {
+                return Err(OperationError::AmendPackageNotReleased {
+                    package: release.name.clone(),
+                    path: path.clone(),
+                });
+            }
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            aggregator.add_changeset(
+                &file_name,
+                self.changeset_io.changeset_timestamp(path),
+                &changeset,
+            );
+            changesets.push((path.clone(), changeset));
+        }
+        Ok(changesets)
+    }
+
+    /// Tag annotations embedding the extended changelog sections, and the
+    /// ones the tags had before for a rollback.
+    fn changelog_tag_messages(
+        &self,
+        project_root: &Path,
+        location: ChangelogLocation,
+        extensions: &[(PathBuf, VersionRelease)],
+        released_tags: &[(&PackageInfo, &String)],
+        backups: &[ChangelogBackup],
+    ) -> MovedTags {
+        let amended = tag_messages(project_root, location, extensions, released_tags, |path| {
+            self.text_file_io.read_file(path).ok()
+        });
+        let original = tag_messages(project_root, location, extensions, released_tags, |path| {
+            backups
+                .iter()
+                .find(|backup| backup.path == path)
+                .and_then(|backup| backup.original_content.clone())
+        });
+        MovedTags { amended, original }
+    }
+
+    /// Amends HEAD with the staged changes and moves the release tags to the
+    /// new commit. If a tag cannot be moved, HEAD and the tags already moved go
+    /// back to the original commit.
+    fn amend_with_tags(
+        &self,
+        project_root: &Path,
+        committer: Option<&Identity>,
+        released_tags: &[(&PackageInfo, &String)],
+        messages: &MovedTags,
+    ) -> Result<(CommitInfo, Vec<TagResult>)> {
+        let original_sha = self.git_provider.head_sha(project_root)?;
+        let commit = self.git_provider.amend_commit(project_root, committer)?;
+
+        let mut tags_moved = Vec::new();
+        if let Err(error) = self.move_tags(
+            project_root,
+            released_tags,
+            &messages.amended,
+            &mut tags_moved,
+        ) {
+            self.undo_amend(project_root, &original_sha, &tags_moved, &messages.original);
+            return Err(error);
+        }
+        Ok((commit, tags_moved))
+    }
+
+    /// Points the tag of each released package at the amended commit,
+    /// collecting the moved tags in `tags_moved`.
+    fn move_tags(
+        &self,
+        project_root: &Path,
+        released_tags: &[(&PackageInfo, &String)],
+        messages: &[Option<String>],
+        tags_moved: &mut Vec<TagResult>,
+    ) -> Result<()> {
+        for ((_, name), message) in released_tags.iter().zip(messages) {
+            let tag = self
+                .git_provider
+                .move_tag(project_root, name, message.as_deref())?;
+            tags_moved.push(TagResult {
+                name: tag.name,
+                target_sha: tag.target_sha,
+            });
+        }
+        Ok(())
+    }
+
+    /// Puts HEAD back on the commit the amend replaced, keeping the changes
+    /// staged, and points the tags already moved at it again with their
+    /// original messages.
+    ///
+    /// Failures are ignored so that the error that caused the rollback is reported.
+    fn undo_amend(
+        &self,
+        project_root: &Path,
+        original_sha: &str,
+        tags_moved: &[TagResult],
+        messages: &[Option<String>],
+    ) {
+        if self
+            .git_provider
+            .reset_soft_to(project_root, original_sha)
+            .is_err()
+        {
+            return;
+        }
+        for (tag, message) in tags_moved.iter().zip(messages) {
+            let _ = self
+                .git_provider
+                .move_tag(project_root, &tag.name, message.as_deref());
+        }
+    }
+
+    /// Changesets in any changeset directory that are not committed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::DirtyWorkingTree` if any other file has
+    /// uncommitted changes.
+    fn find_late_changesets(
+        &self,
+        project_root: &Path,
+        changeset_dirs: &[PathBuf],
+        ignore_submodules: bool,
+    ) -> Result<Vec<PathBuf>> {
+        let dirty_files = self
+            .git_provider
+            .dirty_files(project_root, ignore_submodules)?;
+        let changeset_files = changeset_dirs::list_changesets(&self.changeset_io, changeset_dirs)?;
+
+        let (changesets, others): (Vec<_>, Vec<_>) = dirty_files
+            .into_iter()
+            .partition(|dirty| changeset_files.iter().any(|path| path.ends_with(dirty)));
+        if !others.is_empty() {
+            return Err(OperationError::DirtyWorkingTree { files: others });
+        }
+
+        Ok(changeset_files
+            .into_iter()
+            .filter(|path| changesets.iter().any(|dirty| path.ends_with(dirty)))
+            .collect())
+    }
+
+    /// Extends the changelogs and removes or keeps the changesets, staging all
+    /// of it for the amended commit. Restores the changelogs if a step fails.
+    fn apply_changes(
+        &self,
+        project_root: &Path,
+        extensions: &[(PathBuf, VersionRelease)],
+        changesets: &[(PathBuf, Changeset)],
+        keep_changesets: bool,
+    ) -> Result<Vec<ChangelogBackup>> {
+        let changelog_files = self.changelog_files(extensions);
+        let mut backups = Vec::new();
+        for path in &changelog_files {
+            let original_content = if self.changelog_writer.changelog_exists(path) {
+                Some(self.text_file_io.read_file(path)?)
+            } else {
+                None
+            };
+            backups.push(ChangelogBackup {
+                path: path.clone(),
+                original_content,
+            });
+        }
+
+        let result = self.write_changes(
+            project_root,
+            extensions,
+            &changelog_files,
+            changesets,
+            keep_changesets,
+        );
+
+        match result {
+            Ok(()) => Ok(backups),
+            Err(error) => {
+                self.restore(project_root, &backups, changesets, keep_changesets);
+                Err(error)
+            }
+        }
+    }
+
+    /// The changelogs the extensions rewrite, including existing JSON changelogs.
+    fn changelog_files(&self, extensions: &[(PathBuf, VersionRelease)]) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        for (path, release) in extensions {
+            files.push(path.clone());
+            let json_path = json_changelog_path(path);
+            if release.json && self.changelog_writer.changelog_exists(&json_path) {
+                files.push(json_path);
+            }
+        }
+        files
+    }
+
+    fn write_changes(
+        &self,
+        project_root: &Path,
+        extensions: &[(PathBuf, VersionRelease)],
+        changelog_files: &[PathBuf],
+        changesets: &[(PathBuf, Changeset)],
+        keep_changesets: bool,
+    ) -> Result<()> {
+        for (path, release) in extensions {
+            self.changelog_writer.extend_release(path, release)?;
+        }
+        let changeset_paths: Vec<&Path> =
+            changesets.iter().map(|(path, _)| path.as_path()).collect();
+        if keep_changesets {
+            self.git_provider
+                .stage_files(project_root, &changeset_paths)?;
+        } else {
+            self.git_provider
+                .delete_files(project_root, &changeset_paths)?;
+        }
+        let changelog_paths: Vec<&Path> = changelog_files.iter().map(PathBuf::as_path).collect();
+        self.git_provider
+            .stage_files(project_root, &changelog_paths)
+    }
+
+    /// Puts the changelogs and changesets back as they were before the amend.
+    ///
+    /// Failures are ignored so that the error that caused the rollback is reported.
+    fn restore(
+        &self,
+        project_root: &Path,
+        backups: &[ChangelogBackup],
+        changesets: &[(PathBuf, Changeset)],
+        keep_changesets: bool,
+    ) {
+        for backup in backups {
+            if let Some(content) = &backup.original_content {
+                let _ = self
+                    .changelog_writer
+                    .restore_changelog(&backup.path, content);
+            }
+        }
+        let changelog_paths: Vec<&Path> = backups.iter().map(|b| b.path.as_path()).collect();
+        let _ = self
+            .git_provider
+            .stage_files(project_root, &changelog_paths);
+        if !keep_changesets {
+            for (path, changeset) in changesets {
+                if !path.exists() {
+                    let _ = self.changeset_io.restore_changeset(path, changeset);
+                }
+            }
+        }
+    }
+}
+
+/// The annotations of the moved tags, one per released tag; `None` keeps a
+/// tag's message.
+struct MovedTags {
+    amended: Vec<Option<String>>,
+    original: Vec<Option<String>>,
+}
+
+impl MovedTags {
+    fn keeping_messages(count: usize) -> Self {
+        Self {
+            amended: vec![None; count],
+            original: vec![None; count],
+        }
+    }
+}
+
+/// Fails when the late changesets of a package call for a larger version
+/// than its release made, since amending never changes a version.
+///
+/// The release is compared with the package's previous tagged version, so
+/// pre-releases of the released version do not count. Packages released for
+/// the first time have nothing to compare with.
+fn check_late_bumps(
+    released: &[&PackageInfo],
+    changesets: &[(PathBuf, Changeset)],
+    tags: &[String],
+    naming: &TagNaming,
+    zero_behavior: ZeroVersionBehavior,
+) -> Result<()> {
+    for package in released {
+        let Some(bump) = changesets
+            .iter()
+            .flat_map(|(_, changeset)| &changeset.releases)
+            .filter(|release| release.name == package.name)
+            .map(|release| release.bump_type)
+            .max()
+        else {
+            continue;
+        };
+        let Some(previous) = naming.previous_version(tags, &package.name, &package.version) else {
+            continue;
+        };
+        let required = changeset_version::calculate_new_version_with_zero_behavior(
+            &without_prerelease(&previous),
+            Some(bump),
+            None,
+            zero_behavior,
+            false,
+        )?;
+        if required > without_prerelease(&package.version) {
+            return Err(OperationError::AmendBumpExceedsRelease {
+                package: package.name.clone(),
+                version: package.version.clone(),
+                required,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn without_prerelease(version: &Version) -> Version {
+    let mut version = version.clone();
+    version.pre = Prerelease::EMPTY;
+    version
+}
+
+/// The changelog the release of `package` is written to.
+fn changelog_path(
+    project_root: &Path,
+    location: ChangelogLocation,
+    package: &PackageInfo,
+) -> PathBuf {
+    match location {
+        ChangelogLocation::Root => project_root.join("CHANGELOG.md"),
+        ChangelogLocation::PerPackage => package.path.join("CHANGELOG.md"),
+    }
+}
+
+/// Annotations for the released tags embedding their changelog sections as
+/// `read` returns the changelogs, like the tags `release` creates. Tags whose
+/// changelog was not extended keep their message.
+fn tag_messages(
+    project_root: &Path,
+    location: ChangelogLocation,
+    extensions: &[(PathBuf, VersionRelease)],
+    released_tags: &[(&PackageInfo, &String)],
+    read: impl Fn(&Path) -> Option<String>,
+) -> Vec<Option<String>> {
+    released_tags
+        .iter()
+        .map(|(package, _)| {
+            let path = changelog_path(project_root, location, package);
+            let (_, release) = extensions.iter().find(|(extended, _)| *extended == path)?;
+            let changelog = Changelog::parse(&read(&path)?);
+            let title = format!("Release {} v{}", package.name, package.version);
+            let body = changelog
+                .release_section(&release.version)?
+                .split_once('\n')
+                .map(|(_, body)| body.trim())
+                .unwrap_or_default();
+            Some(if body.is_empty() {
+                title
+            } else {
+                format!("{title}\n\n{body}\n")
+            })
+        })
+        .collect()
+}
+
+/// The changelog sections of the released packages to extend with the late entries.
+fn changelog_extensions(
+    project_root: &Path,
+    location: ChangelogLocation,
+    aggregator: &ChangesetAggregator,
+    released: &[&PackageInfo],
+) -> Vec<(PathBuf, VersionRelease)> {
+    let today = Local::now().date_naive();
+    match location {
+        ChangelogLocation::Root => {
+            let Some(version) = released.iter().map(|p| &p.version).max() else {
+                return Vec::new();
+            };
+            let packages: Vec<_> = released
+                .iter()
+                .map(|p| (p.name.clone(), p.version.clone()))
+                .collect();
+            aggregator
+                .build_root_release(version, today, &packages)
+                .map(|release| (project_root.join("CHANGELOG.md"), release))
+                .into_iter()
+                .collect()
+        }
+        ChangelogLocation::PerPackage => released
+            .iter()
+            .filter_map(|package| {
+                aggregator
+                    .build_package_release(&package.name, &package.version, today)
+                    .map(|release| (changelog_path(project_root, location, package), release))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{
+        MockChangelogWriter, MockChangesetReader, MockGitProvider, MockProjectProvider,
+        make_changeset,
+    };
+    use crate::providers::memory::InMemoryTextFileIO;
+    use changeset_core::BumpType;
+
+    const LATE_CHANGESET: &str = "/mock/project/.changeset/changesets/late.md";
+
+    fn input() -> AmendInput {
+        AmendInput {
+            keep_changesets: false,
+            include_confidential: false,
+        }
+    }
+
+    fn make_operation(
+        git_provider: MockGitProvider,
+    ) -> AmendReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockChangelogWriter,
+        MockGitProvider,
+    > {
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Late fix");
+        AmendReleaseOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.0.1"),
+            MockChangesetReader::new().with_changeset(PathBuf::from(LATE_CHANGESET), changeset),
+            MockChangelogWriter::new(),
+            git_provider,
+        )
+    }
+
+    fn released_head() -> MockGitProvider {
+        MockGitProvider::new()
+            .with_head_tags(&["v1.0.1"])
+            .with_dirty_files(vec![PathBuf::from(".changeset/changesets/late.md")])
+    }
+
+    #[test]
+    fn folds_late_changeset_into_release() {
+        let operation = make_operation(released_head());
+
+        let outcome = operation
+            .execute(Path::new("/any"), &input())
+            .expect("amend failed");
+
+        let AmendOutcome::Amended(output) = outcome else {
+            panic!("expected amended release");
+        };
+        assert_eq!(
+            output.releases,
+            vec![("my-crate".to_string(), Version::new(1, 0, 1))]
+        );
+        let written = operation.changelog_writer.written_releases();
+        assert_eq!(written.len(), 1);
+        assert_eq!(written[0].1.version, Version::new(1, 0, 1));
+        assert_eq!(written[0].1.entries[0].description, "Late fix");
+
+        let git = &operation.git_provider;
+        assert_eq!(git.deleted_files(), vec![PathBuf::from(LATE_CHANGESET)]);
+        assert!(
+            git.staged_files()
+                .contains(&PathBuf::from("/mock/project/CHANGELOG.md"))
+        );
+        assert_eq!(git.amend_count(), 1);
+        assert_eq!(git.tags_moved(), vec!["v1.0.1".to_string()]);
+    }
+
+    #[test]
+    fn moves_tag_under_channel_namespace() {
+        let git_provider = released_head().with_head_tags(&["nightly/v1.0.1"]);
+        let operation = make_operation(git_provider);
+
+        let outcome = operation
+            .execute(Path::new("/any"), &input())
+            .expect("amend failed");
+
+        assert!(matches!(outcome, AmendOutcome::Amended(_)));
+        assert_eq!(
+            operation.git_provider.tags_moved(),
+            vec!["nightly/v1.0.1".to_string()]
+        );
+    }
+
+    #[test]
+    fn returns_no_changesets_without_late_changesets() {
+        let operation = make_operation(MockGitProvider::new().with_head_tags(&["v1.0.1"]));
+
+        let outcome = operation
+            .execute(Path::new("/any"), &input())
+            .expect("amend failed");
+
+        assert!(matches!(outcome, AmendOutcome::NoChangesets));
+        assert_eq!(operation.git_provider.amend_count(), 0);
+    }
+
+    #[test]
+    fn errors_when_head_is_not_a_release() {
+        let git_provider = released_head().with_head_tags(&["v1.0.0"]);
+        let operation = make_operation(git_provider);
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        assert!(matches!(result, Err(OperationError::NoReleaseToAmend)));
+    }
+
+    #[test]
+    fn errors_when_release_is_pushed() {
+        let operation = make_operation(released_head().with_head_pushed(true));
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        assert!(matches!(result, Err(OperationError::ReleaseAlreadyPushed)));
+        assert_eq!(operation.git_provider.amend_count(), 0);
+    }
+
+    #[test]
+    fn errors_when_release_tag_is_pushed() {
+        let operation = make_operation(released_head().with_remote_tags(&["v1.0.1"]));
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        assert!(matches!(result, Err(OperationError::ReleaseAlreadyPushed)));
+        assert_eq!(operation.git_provider.amend_count(), 0);
+    }
+
+    #[test]
+    fn errors_on_other_uncommitted_changes() {
+        let git_provider = released_head().with_dirty_files(vec![
+            PathBuf::from(".changeset/changesets/late.md"),
+            PathBuf::from("src/lib.rs"),
+        ]);
+        let operation = make_operation(git_provider);
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        let Err(OperationError::DirtyWorkingTree { files }) = result else {
+            panic!("expected dirty working tree error, got {result:?}");
+        };
+        assert_eq!(files, vec![PathBuf::from("src/lib.rs")]);
+    }
+
+    #[test]
+    fn keeps_changesets_in_amended_commit() {
+        let operation = make_operation(released_head());
+
+        operation
+            .execute(
+                Path::new("/any"),
+                &AmendInput {
+                    keep_changesets: true,
+                    ..input()
+                },
+            )
+            .expect("amend failed");
+
+        let git = &operation.git_provider;
+        assert!(git.deleted_files().is_empty());
+        assert!(git.staged_files().contains(&PathBuf::from(LATE_CHANGESET)));
+    }
+
+    #[test]
+    fn failed_amend_keeps_tags() {
+        let git_provider = released_head();
+        git_provider.set_fail_on_amend(true);
+        let operation = make_operation(git_provider);
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        assert!(result.is_err());
+        assert!(operation.git_provider.tags_moved().is_empty());
+    }
+
+    fn make_operation_with_late_bump(
+        git_provider: MockGitProvider,
+        bump: BumpType,
+    ) -> AmendReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockChangelogWriter,
+        MockGitProvider,
+    > {
+        let changeset = make_changeset("my-crate", bump, "Late feature");
+        AmendReleaseOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.0.1"),
+            MockChangesetReader::new().with_changeset(PathBuf::from(LATE_CHANGESET), changeset),
+            MockChangelogWriter::new(),
+            git_provider,
+        )
+    }
+
+    #[test]
+    fn errors_when_late_bump_exceeds_release() {
+        let git_provider = released_head().with_existing_tags(&["v1.0.0", "v1.0.1"]);
+        let operation = make_operation_with_late_bump(git_provider, BumpType::Minor);
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        let Err(OperationError::AmendBumpExceedsRelease {
+            package, required, ..
+        }) = result
+        else {
+            panic!("expected bump error, got {result:?}");
+        };
+        assert_eq!(package, "my-crate");
+        assert_eq!(required, Version::new(1, 1, 0));
+        assert_eq!(operation.git_provider.amend_count(), 0);
+    }
+
+    #[test]
+    fn compares_late_bump_with_release_before_its_prereleases() {
+        let git_provider =
+            released_head().with_existing_tags(&["v1.0.0", "v1.0.1-alpha.1", "v1.0.1"]);
+        let operation = make_operation_with_late_bump(git_provider, BumpType::Patch);
+
+        let outcome = operation
+            .execute(Path::new("/any"), &input())
+            .expect("amend failed");
+
+        assert!(matches!(outcome, AmendOutcome::Amended(_)));
+    }
+
+    #[test]
+    fn keeps_tag_messages_without_changelog_tag_body() {
+        let operation = make_operation(released_head());
+
+        operation
+            .execute(Path::new("/any"), &input())
+            .expect("amend failed");
+
+        assert_eq!(operation.git_provider.moved_tag_messages(), vec![None]);
+    }
+
+    #[test]
+    fn failed_tag_move_restores_commit_and_tags() -> anyhow::Result<()> {
+        let changeset = make_changeset("crate-a", BumpType::Patch, "Late fix");
+        let late = PathBuf::from("/mock/workspace/.changeset/changesets/late.md");
+        let git_provider = MockGitProvider::new()
+            .with_head_tags(&["crate-a@v1.0.1", "crate-b@v1.0.1"])
+            .with_dirty_files(vec![PathBuf::from(".changeset/changesets/late.md")]);
+        git_provider.set_fail_on_move_tag_nth(1);
+        let original_sha = git_provider.head_sha(Path::new("/any"))?;
+        let operation = AmendReleaseOperation::new(
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.1"), ("crate-b", "1.0.1")]),
+            MockChangesetReader::new().with_changeset(late, changeset),
+            MockChangelogWriter::new(),
+            git_provider,
+        );
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        assert!(result.is_err());
+        let git = &operation.git_provider;
+        assert_eq!(git.amend_count(), 1);
+        assert_eq!(git.head_sha(Path::new("/any"))?, original_sha);
+        // The tag moved to the amended commit is moved back to the original one.
+        assert_eq!(
+            git.tags_moved(),
+            vec!["crate-a@v1.0.1".to_string(), "crate-a@v1.0.1".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn backs_up_changelogs_through_text_file_io() {
+        let changelog = PathBuf::from("/mock/project/CHANGELOG.md");
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Late fix");
+        let git_provider = released_head();
+        git_provider.set_fail_on_amend(true);
+        let operation = AmendReleaseOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.0.1"),
+            MockChangesetReader::new().with_changeset(PathBuf::from(LATE_CHANGESET), changeset),
+            MockChangelogWriter::new().with_existing_changelog(changelog.clone()),
+            git_provider,
+        )
+        .with_text_file_io(Arc::new(InMemoryTextFileIO::new()));
+
+        let result = operation.execute(Path::new("/any"), &input());
+
+        let Err(OperationError::TextFileRead { path, .. }) = result else {
+            panic!("expected changelog read error, got {result:?}");
+        };
+        assert_eq!(path, changelog);
+        assert_eq!(operation.git_provider.amend_count(), 0);
+    }
+}
@@ -0,0 +1,487 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use changeset_core::Changeset;
+use changeset_project::{GraduationState, PrereleaseState};
+use changeset_saga::{IdempotencyKeys, SagaError};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use super::context::ReleaseSagaContext;
+use crate::OperationError;
+use crate::traits::{
+    ChangelogWriter, ChangesetReader, ChangesetWriter, GitProvider, ManifestWriter, ReleaseStateIO,
+};
+
+/// File name of the compensation journal inside the changeset directory.
+pub const COMPENSATION_JOURNAL_FILENAME: &str = "release-journal.json";
+
+/// A single undo operation performed while rolling back a release.
+///
+/// Versions are kept as strings so that the journal round-trips through JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    tag = "action",
+    rename_all = "kebab-case",
+    rename_all_fields = "camelCase"
+)]
+pub enum CompensationAction {
+    WriteVersion {
+        manifest: PathBuf,
+        version: String,
+    },
+    UpdateDependencyVersion {
+        manifest: PathBuf,
+        dependency: String,
+        version: String,
+        skip_optional: bool,
+    },
+    WriteWorkspaceVersion {
+        manifest: PathBuf,
+        version: String,
+    },
+    ClearConsumed {
+        changeset_dir: PathBuf,
+        paths: Vec<PathBuf>,
+    },
+    MarkConsumed {
+        changeset_dir: PathBuf,
+        path: PathBuf,
+        version: String,
+    },
+    RestoreChangeset {
+        path: PathBuf,
+        changeset: Changeset,
+    },
+    ResetToParent,
+    DeleteTag {
+        name: String,
+    },
+    SavePrereleaseState {
+        changeset_dir: PathBuf,
+        state: PrereleaseState,
+    },
+    SaveGraduationState {
+        changeset_dir: PathBuf,
+        state: GraduationState,
+    },
+    RestoreChangelog {
+        path: PathBuf,
+        content: String,
+    },
+    DeleteChangelog {
+        path: PathBuf,
+    },
+    RestoreFile {
+        path: PathBuf,
+        content: String,
+    },
+    PopStash,
+}
+
+impl CompensationAction {
+    /// Describes what applying this action would undo.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::WriteVersion { manifest, version } => {
+                format!("set version {version} in {}", manifest.display())
+            }
+            Self::UpdateDependencyVersion {
+                manifest,
+                dependency,
+                version,
+                ..
+            } => format!(
+                "set dependency '{dependency}' to {version} in {}",
+                manifest.display()
+            ),
+            Self::WriteWorkspaceVersion { manifest, version } => {
+                format!("set workspace version {version} in {}", manifest.display())
+            }
+            Self::ClearConsumed { paths, .. } => {
+                format!("clear consumed status of {} changeset(s)", paths.len())
+            }
+            Self::MarkConsumed { path, version, .. } => {
+                format!("mark {} as consumed by {version}", path.display())
+            }
+            Self::RestoreChangeset { path, .. } => {
+                format!("restore changeset {}", path.display())
+            }
+            Self::ResetToParent => "reset the release commit to its parent".to_string(),
+            Self::DeleteTag { name } => format!("delete tag {name}"),
+            Self::SavePrereleaseState { .. } => "restore the prerelease state file".to_string(),
+            Self::SaveGraduationState { .. } => "restore the graduation state file".to_string(),
+            Self::RestoreChangelog { path, .. } => {
+                format!("restore changelog {}", path.display())
+            }
+            Self::DeleteChangelog { path } => format!("delete changelog {}", path.display()),
+            Self::RestoreFile { path, .. } => format!("restore {}", path.display()),
+            Self::PopStash => "restore the stashed working tree changes".to_string(),
+        }
+    }
+
+    fn apply<G, M, RW, S, C>(&self, ctx: &ReleaseSagaContext<G, M, RW, S, C>) -> crate::Result<()>
+    where
+        G: GitProvider,
+        M: ManifestWriter,
+        RW: ChangesetReader + ChangesetWriter,
+        S: ReleaseStateIO,
+        C: ChangelogWriter,
+    {
+        match self {
+            Self::WriteVersion { manifest, version } => ctx
+                .manifest_writer()
+                .write_version(manifest, &parse_version(version)?),
+            Self::UpdateDependencyVersion {
+                manifest,
+                dependency,
+                version,
+                skip_optional,
+            } => ctx
+                .manifest_writer()
+                .update_dependency_version(
+                    manifest,
+                    dependency,
+                    &parse_version(version)?,
+                    *skip_optional,
+                )
+                .map(|_| ()),
+            Self::WriteWorkspaceVersion { manifest, version } => ctx
+                .manifest_writer()
+                .write_workspace_version(manifest, &parse_version(version)?),
+            Self::ClearConsumed {
+                changeset_dir,
+                paths,
+            } => {
+                let paths: Vec<&Path> = paths.iter().map(PathBuf::as_path).collect();
+                ctx.changeset_rw()
+                    .clear_consumed_for_prerelease(changeset_dir, &paths)
+            }
+            Self::MarkConsumed {
+                changeset_dir,
+                path,
+                version,
+            } => ctx.changeset_rw().mark_consumed_for_prerelease(
+                changeset_dir,
+                &[path.as_path()],
+                &parse_version(version)?,
+            ),
+            Self::RestoreChangeset { path, changeset } => {
+                ctx.changeset_rw().restore_changeset(path, changeset)
+            }
+            Self::ResetToParent => ctx.git_provider().reset_to_parent(ctx.project_root()),
+            Self::DeleteTag { name } => ctx
+                .git_provider()
+                .delete_tag(ctx.project_root(), name)
+                .map(|_| ()),
+            Self::SavePrereleaseState {
+                changeset_dir,
+                state,
+            } => ctx
+                .release_state_io()
+                .save_prerelease_state(changeset_dir, state),
+            Self::SaveGraduationState {
+                changeset_dir,
+                state,
+            } => ctx
+                .release_state_io()
+                .save_graduation_state(changeset_dir, state),
+            Self::RestoreChangelog { path, content } => {
+                ctx.changelog_writer().restore_changelog(path, content)
+            }
+            Self::DeleteChangelog { path } => ctx.changelog_writer().delete_changelog(path),
+            Self::RestoreFile { path, content } => ctx.text_file_io().write_file(path, content),
+            Self::PopStash => ctx.git_provider().stash_pop(ctx.project_root()),
+        }
+    }
+}
+
+fn parse_version(version: &str) -> crate::Result<Version> {
+    version.parse().map_err(|_| OperationError::VersionParse {
+        version: version.to_string(),
+        context: "compensation".to_string(),
+    })
+}
+
+/// Compensation actions of one saga step that could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    pub step: String,
+    pub description: String,
+    pub actions: Vec<CompensationAction>,
+    /// The first error reported while applying `actions`.
+    pub error: String,
+}
+
+/// The compensations left over after a release rollback partially failed.
+///
+/// Entries are in the order they should be replayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompensationJournal {
+    pub project_root: PathBuf,
+    pub entries: Vec<JournalEntry>,
+    /// Idempotency keys of the release steps whose effects are still in place,
+    /// such as `create-tags:my-crate@1.0.1`. A retried release resumes these steps
+    /// instead of repeating them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completed: Vec<String>,
+}
+
+impl CompensationJournal {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The recorded keys, for [`ReleaseOperation::with_completed_steps`](super::ReleaseOperation::with_completed_steps).
+    #[must_use]
+    pub fn completed_steps(&self) -> IdempotencyKeys {
+        self.completed.iter().cloned().collect()
+    }
+
+    /// Drops the keys of steps that no longer have compensations left.
+    fn retain_uncompensated_keys(&mut self, keys: &[String]) {
+        self.completed = keys
+            .iter()
+            .filter(|key| {
+                let step = key.split_once(':').map_or(key.as_str(), |(step, _)| step);
+                self.entries.iter().any(|entry| entry.step == step)
+            })
+            .cloned()
+            .collect();
+    }
+}
+
+impl<G, M, RW, S, C> ReleaseSagaContext<G, M, RW, S, C>
+where
+    G: GitProvider,
+    M: ManifestWriter,
+    RW: ChangesetReader + ChangesetWriter,
+    S: ReleaseStateIO,
+    C: ChangelogWriter,
+{
+    /// Applies every action, recording the ones that fail in the journal.
+    ///
+    /// All actions are attempted even after a failure so that as much as
+    /// possible is undone. Failed tag deletions are reported together.
+    pub(crate) fn compensate(
+        &self,
+        step: &str,
+        description: String,
+        actions: Vec<CompensationAction>,
+    ) -> crate::Result<()> {
+        let mut failed = Vec::new();
+        let mut errors = Vec::new();
+        for action in actions {
+            if let Err(e) = action.apply(self) {
+                failed.push(action);
+                errors.push(e);
+            }
+        }
+
+        let Some(first_error) = errors.into_iter().next() else {
+            return Ok(());
+        };
+
+        let failed_tags: Vec<_> = failed
+            .iter()
+            .filter_map(|action| match action {
+                CompensationAction::DeleteTag { name } => Some(name.clone()),
+                _ => None,
+            })
+            .collect();
+        let error = if failed_tags.len() == failed.len() {
+            OperationError::TagDeletionFailed { failed_tags }
+        } else {
+            first_error
+        };
+
+        self.journal().push(JournalEntry {
+            step: step.to_string(),
+            description,
+            actions: failed,
+            error: error.to_string(),
+        });
+        Err(error)
+    }
+
+    /// Takes the compensations recorded as failed so far.
+    pub(crate) fn take_journal(&self) -> CompensationJournal {
+        CompensationJournal {
+            project_root: self.project_root().to_path_buf(),
+            entries: std::mem::take(&mut *self.journal()),
+            completed: Vec::new(),
+        }
+    }
+
+    /// Converts a failed saga into an error carrying the compensations left to
+    /// replay and the keys of the steps they belong to.
+    pub(crate) fn saga_error(
+        &self,
+        error: SagaError<OperationError>,
+        completed: &IdempotencyKeys,
+    ) -> OperationError {
+        let mut error = OperationError::from(error);
+        if let OperationError::SagaCompensationFailed { journal, .. } = &mut error {
+            let mut taken = self.take_journal();
+            taken.completed = completed.iter().map(str::to_string).collect();
+            *journal = Some(Box::new(taken));
+        }
+        error
+    }
+}
+
+/// Replays the compensations recorded in a [`CompensationJournal`].
+pub struct CompensateOperation<G, M, RW, S, C> {
+    git_provider: Arc<G>,
+    manifest_writer: Arc<M>,
+    changeset_rw: Arc<RW>,
+    release_state_io: Arc<S>,
+    changelog_writer: Arc<C>,
+}
+
+impl<G, M, RW, S, C> CompensateOperation<G, M, RW, S, C>
+where
+    G: GitProvider,
+    M: ManifestWriter,
+    RW: ChangesetReader + ChangesetWriter,
+    S: ReleaseStateIO,
+    C: ChangelogWriter,
+{
+    pub fn new(
+        git_provider: G,
+        manifest_writer: M,
+        changeset_rw: RW,
+        release_state_io: S,
+        changelog_writer: C,
+    ) -> Self {
+        Self {
+            git_provider: Arc::new(git_provider),
+            manifest_writer: Arc::new(manifest_writer),
+            changeset_rw: Arc::new(changeset_rw),
+            release_state_io: Arc::new(release_state_io),
+            changelog_writer: Arc::new(changelog_writer),
+        }
+    }
+
+    /// Applies every journaled action and returns the ones that still fail.
+    #[must_use]
+    pub fn execute(&self, journal: &CompensationJournal) -> CompensationJournal {
+        let ctx = ReleaseSagaContext::new(
+            journal.project_root.clone(),
+            Arc::clone(&self.git_provider),
+            Arc::clone(&self.manifest_writer),
+            Arc::clone(&self.changeset_rw),
+            Arc::clone(&self.release_state_io),
+            Arc::clone(&self.changelog_writer),
+        );
+
+        for entry in &journal.entries {
+            let _ = ctx.compensate(
+                &entry.step,
+                entry.description.clone(),
+                entry.actions.clone(),
+            );
+        }
+        let mut remaining = ctx.take_journal();
+        remaining.retain_uncompensated_keys(&journal.completed);
+        remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{
+        MockChangelogWriter, MockChangesetReader, MockGitProvider, MockManifestWriter,
+        MockReleaseStateIO,
+    };
+
+    fn operation(
+        git: MockGitProvider,
+    ) -> CompensateOperation<
+        MockGitProvider,
+        MockManifestWriter,
+        MockChangesetReader,
+        MockReleaseStateIO,
+        MockChangelogWriter,
+    > {
+        CompensateOperation::new(
+            git,
+            MockManifestWriter::new(),
+            MockChangesetReader::new(),
+            MockReleaseStateIO::new(),
+            MockChangelogWriter::new(),
+        )
+    }
+
+    fn journal(actions: Vec<CompensationAction>) -> CompensationJournal {
+        CompensationJournal {
+            project_root: PathBuf::from("/repo"),
+            entries: vec![JournalEntry {
+                step: "create-tags".to_string(),
+                description: "delete the created tags".to_string(),
+                actions,
+                error: "tag is locked".to_string(),
+            }],
+            completed: vec!["create-tags:my-crate@1.0.1".to_string()],
+        }
+    }
+
+    #[test]
+    fn journal_round_trips_through_json() -> anyhow::Result<()> {
+        let journal = journal(vec![
+            CompensationAction::WriteVersion {
+                manifest: PathBuf::from("/repo/Cargo.toml"),
+                version: "1.0.0".to_string(),
+            },
+            CompensationAction::ResetToParent,
+            CompensationAction::DeleteTag {
+                name: "v1.0.1".to_string(),
+            },
+        ]);
+
+        let json = serde_json::to_string(&journal)?;
+        assert!(json.contains(r#""action":"write-version""#));
+        assert_eq!(serde_json::from_str::<CompensationJournal>(&json)?, journal);
+        Ok(())
+    }
+
+    #[test]
+    fn replay_keeps_only_failing_actions() {
+        let journal = journal(vec![
+            CompensationAction::DeleteTag {
+                name: "v1.0.1".to_string(),
+            },
+            CompensationAction::WriteVersion {
+                manifest: PathBuf::from("/repo/Cargo.toml"),
+                version: "not-a-version".to_string(),
+            },
+        ]);
+
+        let remaining = operation(MockGitProvider::new()).execute(&journal);
+
+        assert_eq!(remaining.entries.len(), 1);
+        assert_eq!(
+            remaining.entries[0].actions,
+            [CompensationAction::WriteVersion {
+                manifest: PathBuf::from("/repo/Cargo.toml"),
+                version: "not-a-version".to_string(),
+            }]
+        );
+        assert!(remaining.entries[0].error.contains("not-a-version"));
+        assert_eq!(remaining.completed, ["create-tags:my-crate@1.0.1"]);
+    }
+
+    #[test]
+    fn replay_of_successful_actions_empties_journal() {
+        let remaining = operation(MockGitProvider::new())
+            .execute(&journal(vec![CompensationAction::ResetToParent]));
+
+        assert!(remaining.is_empty());
+        assert!(remaining.completed.is_empty());
+    }
+}
@@ -1,8 +1,12 @@
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard};
 
+use super::compensation::JournalEntry;
+
+use crate::providers::FileSystemTextFileIO;
 use crate::traits::{
     ChangelogWriter, ChangesetReader, ChangesetWriter, GitProvider, ManifestWriter, ReleaseStateIO,
+    TextFileIO,
 };
 
 pub struct ReleaseSagaContext<G, M, RW, S, C> {
@@ -12,6 +16,8 @@ pub struct ReleaseSagaContext<G, M, RW, S, C> {
     changeset_rw: Arc<RW>,
     release_state_io: Arc<S>,
     changelog_writer: Arc<C>,
+    text_file_io: Arc<dyn TextFileIO>,
+    journal: Arc<Mutex<Vec<JournalEntry>>>,
 }
 
 impl<G, M, RW, S, C> Clone for ReleaseSagaContext<G, M, RW, S, C> {
@@ -23,6 +29,8 @@ impl<G, M, RW, S, C> Clone for ReleaseSagaContext<G, M, RW, S, C> {
             changeset_rw: Arc::clone(&self.changeset_rw),
             release_state_io: Arc::clone(&self.release_state_io),
             changelog_writer: Arc::clone(&self.changelog_writer),
+            text_file_io: Arc::clone(&self.text_file_io),
+            journal: Arc::clone(&self.journal),
         }
     }
 }
@@ -50,9 +58,17 @@ where
             changeset_rw,
             release_state_io,
             changelog_writer,
+            text_file_io: Arc::new(FileSystemTextFileIO::new()),
+            journal: Arc::default(),
         }
     }
 
+    #[must_use]
+    pub fn with_text_file_io(mut self, text_file_io: Arc<dyn TextFileIO>) -> Self {
+        self.text_file_io = text_file_io;
+        self
+    }
+
     #[must_use]
     pub fn project_root(&self) -> &Path {
         &self.project_root
@@ -82,4 +98,13 @@ where
     pub fn changelog_writer(&self) -> &C {
         &self.changelog_writer
     }
+
+    #[must_use]
+    pub fn text_file_io(&self) -> &dyn TextFileIO {
+        self.text_file_io.as_ref()
+    }
+
+    pub(super) fn journal(&self) -> MutexGuard<'_, Vec<JournalEntry>> {
+        self.journal.lock().expect("lock poisoned")
+    }
 }
@@ -1,16 +1,37 @@
+mod amend;
+mod compensation;
 mod context;
 mod operation;
+mod pre_checks;
+mod preview;
+mod release_manifest;
 mod saga_data;
 mod saga_steps;
+mod stamp;
+mod step_control;
 pub mod steps;
+mod summary;
 mod validator;
 
-pub use crate::types::{PackageReleaseConfig, PackageVersion};
+pub use crate::types::{
+    BumpContribution, BumpSource, PackageProvenance, PackageReleaseConfig, PackageVersion,
+    PrereleaseProvenance, PrereleaseSource, ZeroVersionTransform,
+};
+pub use amend::{AmendInput, AmendOutcome, AmendOutput, AmendReleaseOperation};
+pub use compensation::{
+    COMPENSATION_JOURNAL_FILENAME, CompensateOperation, CompensationAction, CompensationJournal,
+    JournalEntry,
+};
 pub use context::ReleaseSagaContext;
 pub use operation::{
-    ChangelogUpdate, CommitResult, GitOperationResult, ReleaseInput, ReleaseOperation,
-    ReleaseOutcome, ReleaseOutput, TagResult,
+    ChangelogUpdate, CommitResult, DirtyTreeAction, DynReleaseOperation, GitOperationResult,
+    ReleaseCommit, ReleaseInput, ReleaseOperation, ReleaseOutcome, ReleaseOutput, StepTiming,
+    TagResult,
 };
+pub use preview::FileDiff;
+pub use release_manifest::{RELEASE_MANIFEST_FILENAME, ReleaseManifest, ReleasedPackage};
+pub use step_control::{ReleaseStep, StepControl};
+pub use summary::{RELEASE_SUMMARY_FILENAME, render_changelog_preview, render_release_summary};
 pub use validator::{
     ReleaseCliInput, ReleaseValidator, ValidatedReleaseConfig, ValidationError, ValidationErrors,
 };
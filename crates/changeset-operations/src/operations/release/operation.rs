@@ -1,32 +1,50 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
-use changeset_changelog::{ChangelogLocation, ComparisonLinksSetting, RepositoryInfo};
-use changeset_core::{PackageInfo, PrereleaseSpec};
-use changeset_project::{GraduationState, ProjectKind, TagFormat};
-use changeset_saga::SagaBuilder;
-use chrono::Local;
+use changeset_changelog::{
+    ChangelogLocation, ComparisonLinksSetting, RepositoryInfo, VersionRelease, format_entries,
+    json_changelog_path,
+};
+use changeset_core::{
+    BumpType, CategorySet, ChangeCategory, Changeset, PackageInfo, PackageRelease, PrereleaseSpec,
+};
+use changeset_project::{
+    GraduationState, PackageAdapters, PackageChangesetConfig, ReleaseChannel, VersionStamp,
+};
+use changeset_saga::{IdempotencyKeys, SagaAuditLog, SagaBuilder};
+use chrono::{Local, NaiveDate};
 use indexmap::IndexMap;
 use semver::Version;
+use tracing::{debug, warn};
 
 use super::context::ReleaseSagaContext;
+use super::pre_checks::run_pre_checks;
+use super::preview::{FileDiff, PreviewFiles};
 use super::saga_data::{ReleaseSagaData, SagaReleaseOptions};
 use super::saga_steps::{
     ClearChangesetsConsumedStep, CreateCommitStep, CreateTagsStep, DeleteChangesetFilesStep,
-    MarkChangesetsConsumedStep, RemoveWorkspaceVersionStep, RestoreChangelogsStep, StageFilesStep,
-    UpdateDependencyVersionsStep, UpdateReleaseStateStep, WriteManifestVersionsStep,
+    MarkChangesetsConsumedStep, RemoveWorkspaceVersionStep, RestoreChangelogsStep,
+    RestoreStashStep, StageFilesStep, StampFilesStep, UpdateDependencyVersionsStep,
+    UpdateReleaseStateStep, WriteManifestVersionsStep, release_commit_identity,
 };
+use super::stamp::{plan_stamps, release_stamps};
+use super::step_control::{Controlled, ReleaseStep, StepControl};
 use super::validator::{ReleaseCliInput, ReleaseValidator};
 use crate::Result;
+use crate::changeset_dirs;
 use crate::error::OperationError;
 use crate::operations::changelog_aggregation::ChangesetAggregator;
-use crate::planner::VersionPlanner;
+use crate::package_selection;
+use crate::planner::{self, VersionPlanner};
+use crate::providers::{FileSystemTextFileIO, ShellCommandRunner};
+use crate::tags::{TagNaming, uses_crate_prefix};
 use crate::traits::{
-    ChangelogWriter, ChangesetReader, ChangesetWriter, GitProvider, ManifestWriter,
-    ProjectProvider, ReleaseStateIO,
+    ChangelogWriter, ChangesetIO, ChangesetReader, ChangesetWriter, CommandRunner, GitProvider,
+    ManifestWriter, PlannedChangelogWrite, ProjectProvider, ReleaseStateIO, TextFileIO,
 };
-use crate::types::{PackageReleaseConfig, PackageVersion};
+use crate::types::{PackageProvenance, PackageReleaseConfig, PackageVersion, PrereleaseSource};
 
 pub struct ReleaseInput {
     pub dry_run: bool,
@@ -35,12 +53,60 @@ pub struct ReleaseInput {
     pub no_tags: bool,
     pub keep_changesets: bool,
     pub force: bool,
+    /// With `dry_run`, render the exact content changes of every file the release writes.
+    pub diff: bool,
     /// Per-package release configuration from CLI (merged with TOML state at execution).
     pub per_package_config: HashMap<String, PackageReleaseConfig>,
     /// Global prerelease tag (applies to all packages without specific config).
     pub global_prerelease: Option<PrereleaseSpec>,
     /// Whether `--graduate` was passed without specific crates (single-package mode).
     pub graduate_all: bool,
+    /// Skip the configured release branch check.
+    pub allow_branch: bool,
+    /// Skip the check that the local branch is not behind its remote.
+    pub allow_stale: bool,
+    /// Publish summaries of confidential changesets that are still embargoed.
+    pub include_confidential: bool,
+    /// Restricts which release steps run; for diagnosing failed releases.
+    pub step_control: StepControl,
+    /// Version stamps from the CLI, applied after the configured ones.
+    pub stamps: Vec<VersionStamp>,
+    /// What to do with uncommitted changes when the release commits.
+    pub on_dirty: DirtyTreeAction,
+    /// Bump every package by this much when there are no changesets, recording a
+    /// maintenance release instead of returning [`ReleaseOutcome::NoChangesets`].
+    pub empty_release_bump: Option<BumpType>,
+    /// Release the versions already in the manifests: write changelogs, delete
+    /// changesets and tag without editing any manifest.
+    pub tag_only: bool,
+    /// Release only the changesets of these packages, given by name, path or
+    /// glob; the others stay pending. Empty releases every package.
+    pub packages: Vec<String>,
+    /// Release every package on this channel instead of the one configured
+    /// for it.
+    pub channel: Option<String>,
+}
+
+/// How a committing release treats a working tree with uncommitted changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DirtyTreeAction {
+    /// Fail with [`OperationError::DirtyWorkingTree`].
+    #[default]
+    Abort,
+    /// Stash the changes and restore them once the release finishes or rolls back.
+    Stash,
+    /// Add the changes to the release commit.
+    Include,
+}
+
+/// Message of the stash holding local changes during a release.
+const RELEASE_STASH_MESSAGE: &str = "cargo-changeset: changes stashed during release";
+
+/// The state of the working tree a committing release starts from.
+enum WorkingTree {
+    Clean,
+    Stashed { root: PathBuf },
+    Included(Vec<PathBuf>),
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +115,12 @@ pub struct ChangelogUpdate {
     pub package: Option<String>,
     pub version: Version,
     pub created: bool,
+    /// Rendered changelog entries added for this release.
+    pub excerpt: String,
+    /// The `CHANGELOG.json` updated along with the Markdown changelog, if any.
+    pub json_path: Option<PathBuf>,
+    /// Archive files older release sections were moved to.
+    pub archive_paths: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +129,13 @@ pub struct CommitResult {
     pub message: String,
 }
 
+/// One commit of a per-package release and the packages it releases.
+#[derive(Debug, Clone)]
+pub struct ReleaseCommit {
+    pub packages: Vec<String>,
+    pub commit: CommitResult,
+}
+
 #[derive(Debug, Clone)]
 pub struct TagResult {
     pub name: String,
@@ -66,17 +145,36 @@ pub struct TagResult {
 #[derive(Debug, Clone, Default)]
 pub struct GitOperationResult {
     pub commit: Option<CommitResult>,
+    /// Commits of a per-package release, oldest first; `commit` is the last
+    /// of them. Empty when the release is a single commit.
+    pub release_commits: Vec<ReleaseCommit>,
     pub tags_created: Vec<TagResult>,
     pub changesets_deleted: Vec<PathBuf>,
 }
 
+/// Execution time of a single release saga step.
+#[derive(Debug, Clone)]
+pub struct StepTiming {
+    pub name: String,
+    pub started_at: SystemTime,
+    pub duration: Duration,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReleaseOutput {
     pub planned_releases: Vec<PackageVersion>,
     pub unchanged_packages: Vec<String>,
     pub changesets_consumed: Vec<PathBuf>,
+    /// Owners of consumed changesets from owned changeset directories, keyed by path.
+    pub changeset_owners: HashMap<PathBuf, String>,
     pub changelog_updates: Vec<ChangelogUpdate>,
     pub git_result: Option<GitOperationResult>,
+    /// Per-step execution times; empty for dry runs.
+    pub step_timings: Vec<StepTiming>,
+    /// What decided each planned release; empty for prerelease graduation.
+    pub provenance: Vec<PackageProvenance>,
+    /// Content changes of every file the release writes; only filled for dry runs with `diff`.
+    pub file_diffs: Vec<FileDiff>,
 }
 
 #[derive(Debug)]
@@ -90,12 +188,16 @@ struct GitOptions {
     should_commit: bool,
     should_create_tags: bool,
     should_delete_changesets: bool,
+    /// Packages released without a tag, from their `tags` setting.
+    untagged_packages: Vec<String>,
 }
 
 struct ReleaseContext {
     project: changeset_project::CargoProject,
     root_config: changeset_project::RootChangesetConfig,
     changeset_dir: PathBuf,
+    /// The primary changeset directory followed by the owned ones.
+    changeset_dirs: Vec<PathBuf>,
     changeset_files: Vec<PathBuf>,
     prerelease_state: Option<changeset_project::PrereleaseState>,
     graduation_state: Option<GraduationState>,
@@ -105,20 +207,140 @@ struct ReleaseContext {
     is_prerelease_release: bool,
     git_options: GitOptions,
     inherited_packages: Vec<String>,
+    include_confidential: bool,
+    step_control: StepControl,
+    version_stamps: Vec<VersionStamp>,
+    /// Date graduation policies are evaluated against.
+    today: NaiveDate,
+    /// No changesets were pending, so every package gets a maintenance bump.
+    is_maintenance_release: bool,
+    tag_only: bool,
+    /// Namespaces of the tags of packages released on a namespaced channel.
+    tag_namespaces: IndexMap<String, String>,
     early_return: Option<Result<ReleaseOutcome>>,
 }
 
+impl ReleaseContext {
+    fn tag_naming(&self) -> TagNaming {
+        TagNaming::new(uses_crate_prefix(
+            &self.project.kind,
+            self.root_config.git_config().tag_format(),
+        ))
+        .with_channels(self.root_config.channels())
+    }
+
+    /// Points the comparison links of `changelog_release` at the tag of
+    /// `release` in its channel's namespace, starting from the tag of the
+    /// previous version in the namespace of the channel it was released on.
+    fn set_tag_prefixes(&self, changelog_release: &mut VersionRelease, release: &PackageVersion) {
+        let naming = self.tag_naming();
+        let namespace = self
+            .tag_namespaces
+            .get(&release.name)
+            .map_or("", String::as_str);
+        let previous_namespace = self
+            .root_config
+            .channel_of_version(&release.current_version)
+            .map_or("", ReleaseChannel::tag_namespace);
+        changelog_release.tag_prefix = naming.prefix(namespace, &release.name);
+        changelog_release.previous_tag_prefix =
+            Some(naming.prefix(previous_namespace, &release.name));
+    }
+}
+
 struct ReleasePlan {
     output: ReleaseOutput,
     planned_releases: Vec<PackageVersion>,
     package_lookup: IndexMap<String, PackageInfo>,
     changelog_backups: Vec<super::steps::ChangelogFileState>,
+    stamped_files: Vec<super::steps::StampedFile>,
+}
+
+/// The steps the release runs; a tag-only release never edits a manifest.
+fn release_step_control(input: &ReleaseInput) -> StepControl {
+    if !input.tag_only {
+        return input.step_control.clone();
+    }
+    input
+        .step_control
+        .clone()
+        .with_skip(ReleaseStep::WriteManifests)
+        .with_skip(ReleaseStep::UpdateDependencies)
+        .with_skip(ReleaseStep::RemoveWorkspaceVersion)
+}
+
+/// Header for a changelog created for `name`, from the configured template.
+fn render_preamble(
+    changelog_config: &changeset_changelog::ChangelogConfig,
+    name: &str,
+) -> Option<String> {
+    changelog_config
+        .preamble
+        .as_ref()
+        .map(|template| template.replace("{package}", name))
+}
+
+/// Changelog entry recorded for packages bumped by an empty release.
+const MAINTENANCE_RELEASE_SUMMARY: &str = "Maintenance release";
+
+/// Synthesizes changelog entries for bumps requested without a changeset file.
+fn changeset_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn forced_bump_changesets(
+    per_package_config: &HashMap<String, PackageReleaseConfig>,
+) -> Vec<Changeset> {
+    let mut forced: Vec<_> = per_package_config
+        .iter()
+        .filter_map(|(name, config)| config.forced_bump.map(|bump| (name, bump)))
+        .collect();
+    forced.sort_by(|a, b| a.0.cmp(b.0));
+
+    forced
+        .into_iter()
+        .map(|(name, bump)| {
+            let bump_name = match bump {
+                BumpType::Major => "major",
+                BumpType::Minor => "minor",
+                BumpType::Patch => "patch",
+            };
+            Changeset {
+                summary: format!("Forced {bump_name} bump"),
+                releases: vec![PackageRelease {
+                    name: name.clone(),
+                    bump_type: bump,
+                }],
+                category: ChangeCategory::default(),
+                scope: None,
+                consumed_for_prerelease: None,
+                graduate: false,
+                confidential: false,
+                embargo: None,
+                advisory: None,
+            }
+        })
+        .collect()
 }
 
-fn find_previous_tag(planned_releases: &[PackageVersion]) -> Option<String> {
-    let first_release = planned_releases.first()?;
-    let previous_version = &first_release.current_version;
-    Some(previous_version.to_string())
+/// Rejects pending changesets whose category is not configured for the project.
+fn validate_categories(
+    changesets: &[Changeset],
+    paths: &[PathBuf],
+    categories: &CategorySet,
+) -> Result<()> {
+    for (changeset, path) in changesets.iter().zip(paths) {
+        if !categories.contains(&changeset.category) {
+            return Err(OperationError::ChangesetCategoryNotConfigured {
+                path: path.clone(),
+                category: changeset.category.id().to_string(),
+                available: categories.ids(),
+            });
+        }
+    }
+    Ok(())
 }
 
 fn is_any_prerelease_configured(
@@ -128,6 +350,23 @@ fn is_any_prerelease_configured(
     input.global_prerelease.is_some() || per_package_config.values().any(|c| c.prerelease.is_some())
 }
 
+/// Packages whose pre-release tag was given on the command line.
+fn cli_prerelease_packages(
+    per_package_config: &HashMap<String, PackageReleaseConfig>,
+) -> Vec<String> {
+    per_package_config
+        .iter()
+        .filter(|(_, config)| {
+            config.prerelease.is_some()
+                && matches!(
+                    config.prerelease_source,
+                    None | Some(PrereleaseSource::Cli | PrereleaseSource::CliGlobal)
+                )
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
 fn is_prerelease_graduation(
     packages: &[PackageInfo],
     per_package_config: &HashMap<String, PackageReleaseConfig>,
@@ -154,6 +393,45 @@ fn is_zero_graduation(
         .any(|p| changeset_version::is_zero_version(&p.version))
 }
 
+/// Marks crates whose graduation policy in `graduation.toml` is satisfied as graduating.
+///
+/// `bumps` holds each crate's changeset bumps; forced bumps come from the config.
+fn apply_graduation_policies(
+    per_package_config: &mut HashMap<String, PackageReleaseConfig>,
+    graduation_state: Option<&GraduationState>,
+    bumps: &IndexMap<String, Vec<BumpType>>,
+    today: NaiveDate,
+) {
+    let Some(state) = graduation_state else {
+        return;
+    };
+    for (name, policy) in state.policies() {
+        let changeset_bump = bumps
+            .get(name)
+            .and_then(|b| changeset_version::max_bump_type(b));
+        let forced_bump = per_package_config.get(name).and_then(|c| c.forced_bump);
+        if policy.is_satisfied(today, changeset_bump.max(forced_bump)) {
+            per_package_config
+                .entry(name.to_string())
+                .or_default()
+                .graduate_zero = true;
+        }
+    }
+}
+
+/// A [`ReleaseOperation`] whose providers are trait objects.
+///
+/// Useful when providers are chosen at runtime, e.g. from configuration; any
+/// provider can be passed as `Arc::new(provider)`.
+pub type DynReleaseOperation = ReleaseOperation<
+    Arc<dyn ProjectProvider>,
+    Arc<dyn ChangesetIO>,
+    Arc<dyn ManifestWriter>,
+    Arc<dyn ChangelogWriter>,
+    Arc<dyn GitProvider>,
+    Arc<dyn ReleaseStateIO>,
+>;
+
 pub struct ReleaseOperation<P, RW, M, C, G, S> {
     project_provider: P,
     changeset_io: Arc<RW>,
@@ -161,6 +439,10 @@ pub struct ReleaseOperation<P, RW, M, C, G, S> {
     changelog_writer: C,
     git_provider: Arc<G>,
     release_state_io: Arc<S>,
+    command_runner: Arc<dyn CommandRunner>,
+    text_file_io: Arc<dyn TextFileIO>,
+    package_adapters: PackageAdapters,
+    completed_steps: IdempotencyKeys,
 }
 
 #[cfg(test)]
@@ -198,9 +480,44 @@ where
             changelog_writer,
             git_provider: Arc::new(git_provider),
             release_state_io: Arc::new(release_state_io),
+            command_runner: Arc::new(ShellCommandRunner::new()),
+            text_file_io: Arc::new(FileSystemTextFileIO::new()),
+            package_adapters: PackageAdapters::default(),
+            completed_steps: IdempotencyKeys::new(),
         }
     }
 
+    /// Replaces the runner used for the configured pre-release checks.
+    #[must_use]
+    pub fn with_command_runner(mut self, command_runner: Arc<dyn CommandRunner>) -> Self {
+        self.command_runner = command_runner;
+        self
+    }
+
+    /// Replaces the file access used for version stamps.
+    #[must_use]
+    pub fn with_text_file_io(mut self, text_file_io: Arc<dyn TextFileIO>) -> Self {
+        self.text_file_io = text_file_io;
+        self
+    }
+
+    /// Replaces the adapters used to preview version writes to manifests other
+    /// than `Cargo.toml` on `--dry-run --diff`.
+    #[must_use]
+    pub fn with_package_adapters(mut self, package_adapters: PackageAdapters) -> Self {
+        self.package_adapters = package_adapters;
+        self
+    }
+
+    /// Resumes the release steps whose effects an earlier, failed release left
+    /// in place, as recorded in its [`CompensationJournal`](super::CompensationJournal),
+    /// instead of repeating them.
+    #[must_use]
+    pub fn with_completed_steps(mut self, completed_steps: IdempotencyKeys) -> Self {
+        self.completed_steps = completed_steps;
+        self
+    }
+
     fn find_packages_with_inherited_versions(
         &self,
         packages: &[PackageInfo],
@@ -209,9 +526,23 @@ where
             .find_packages_with_inherited_versions(packages)
     }
 
-    fn detect_repository_info(&self, project_root: &Path) -> Option<RepositoryInfo> {
-        let url = self.git_provider.remote_url(project_root).ok()??;
-        RepositoryInfo::from_url(&url).ok()
+    /// Repository info from the configured remote, or from the one picked by
+    /// fallback when none is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured remote cannot be read.
+    fn detect_repository_info(
+        &self,
+        project_root: &Path,
+        remote: Option<&str>,
+    ) -> Result<Option<RepositoryInfo>> {
+        let url = match self.git_provider.remote_url(project_root, remote) {
+            Ok(url) => url,
+            Err(_) if remote.is_none() => None,
+            Err(e) => return Err(e),
+        };
+        Ok(url.and_then(|url| RepositoryInfo::from_url(&url).ok()))
     }
 
     fn capture_changelog_state(
@@ -222,64 +553,54 @@ where
         package_lookup: &IndexMap<String, PackageInfo>,
     ) -> Result<Vec<super::steps::ChangelogFileState>> {
         use super::steps::ChangelogFileState;
-        let mut backups = Vec::new();
 
-        match changelog_config.changelog {
-            ChangelogLocation::Root => {
-                let changelog_path = project_root.join("CHANGELOG.md");
-                let max_version = planned_releases
-                    .iter()
-                    .map(|r| &r.new_version)
-                    .max()
-                    .cloned();
-
-                if let Some(version) = max_version {
-                    let file_existed = self.changelog_writer.changelog_exists(&changelog_path);
-                    let original_content = if file_existed {
-                        Some(std::fs::read_to_string(&changelog_path).map_err(|e| {
-                            OperationError::ChangesetFileRead {
-                                path: changelog_path.clone(),
-                                source: e,
-                            }
-                        })?)
-                    } else {
-                        None
-                    };
-
-                    backups.push(ChangelogFileState {
-                        path: changelog_path,
-                        version,
-                        package: None,
-                        original_content,
-                        file_existed,
-                    });
-                }
+        let targets: Vec<(PathBuf, Version, Option<String>)> = match changelog_config.changelog {
+            ChangelogLocation::Root => planned_releases
+                .iter()
+                .map(|r| &r.new_version)
+                .max()
+                .map(|version| (project_root.join("CHANGELOG.md"), version.clone(), None))
+                .into_iter()
+                .collect(),
+            ChangelogLocation::PerPackage => planned_releases
+                .iter()
+                .filter_map(|release| {
+                    let pkg = package_lookup.get(&release.name)?;
+                    Some((
+                        pkg.path.join("CHANGELOG.md"),
+                        release.new_version.clone(),
+                        Some(release.name.clone()),
+                    ))
+                })
+                .collect(),
+        };
+
+        let mut backups = Vec::new();
+        for (changelog_path, version, package) in targets {
+            let mut paths = vec![changelog_path];
+            if changelog_config.writes_json() {
+                paths.push(json_changelog_path(&paths[0]));
             }
-            ChangelogLocation::PerPackage => {
-                for release in planned_releases {
-                    if let Some(pkg) = package_lookup.get(&release.name) {
-                        let changelog_path = pkg.path.join("CHANGELOG.md");
-                        let file_existed = self.changelog_writer.changelog_exists(&changelog_path);
-                        let original_content = if file_existed {
-                            Some(std::fs::read_to_string(&changelog_path).map_err(|e| {
-                                OperationError::ChangesetFileRead {
-                                    path: changelog_path.clone(),
-                                    source: e,
-                                }
-                            })?)
-                        } else {
-                            None
-                        };
-
-                        backups.push(ChangelogFileState {
-                            path: changelog_path,
-                            version: release.new_version.clone(),
-                            package: Some(release.name.clone()),
-                            original_content,
-                            file_existed,
-                        });
-                    }
-                }
+            for path in paths {
+                let file_existed = self.changelog_writer.changelog_exists(&path);
+                let original_content = if file_existed {
+                    Some(std::fs::read_to_string(&path).map_err(|e| {
+                        OperationError::ChangesetFileRead {
+                            path: path.clone(),
+                            source: e,
+                        }
+                    })?)
+                } else {
+                    None
+                };
+
+                backups.push(ChangelogFileState {
+                    path,
+                    version: version.clone(),
+                    package: package.clone(),
+                    original_content,
+                    file_existed,
+                });
             }
         }
 
@@ -288,47 +609,57 @@ where
 
     fn generate_changelog_updates(
         &self,
-        project_root: &Path,
-        changelog_config: &changeset_changelog::ChangelogConfig,
+        changelog_writer: &dyn ChangelogWriter,
+        context: &ReleaseContext,
         aggregator: &ChangesetAggregator,
         planned_releases: &[PackageVersion],
         package_lookup: &IndexMap<String, PackageInfo>,
     ) -> Result<Vec<ChangelogUpdate>> {
+        let project_root = context.project.root.as_path();
         let today = Local::now().date_naive();
-        let repo_info = self.resolve_repo_info(project_root, changelog_config)?;
-        let mut changelog_updates = Vec::new();
+        let changelog_config = context.root_config.changelog_config();
+        let repo_info = self.resolve_repo_info(project_root, &context.root_config)?;
+        let mut writes = Vec::new();
+        let mut planned_updates = Vec::new();
 
         match changelog_config.changelog {
             ChangelogLocation::Root => {
-                let changelog_path = project_root.join("CHANGELOG.md");
-                let max_version = planned_releases
-                    .iter()
-                    .map(|r| &r.new_version)
-                    .max()
-                    .cloned();
-
-                if let Some(version) = max_version {
+                // The root section is headed by the highest new version, so its
+                // comparison link spans the tags of the package released at it.
+                let newest = planned_releases.iter().max_by(|a, b| {
+                    a.new_version
+                        .cmp(&b.new_version)
+                        .then_with(|| b.name.cmp(&a.name))
+                });
+
+                if let Some(newest) = newest {
+                    let version = newest.new_version.clone();
                     let packages: Vec<_> = planned_releases
                         .iter()
                         .map(|r| (r.name.clone(), r.new_version.clone()))
                         .collect();
 
-                    if let Some(release) = aggregator.build_root_release(&version, today, &packages)
+                    if let Some(mut release) =
+                        aggregator.build_root_release(&version, today, &packages)
                     {
-                        let previous_tag = find_previous_tag(planned_releases);
-
-                        let result = self.changelog_writer.write_release(
-                            &changelog_path,
-                            &release,
-                            repo_info.as_ref(),
-                            previous_tag.as_deref(),
-                        )?;
-
-                        changelog_updates.push(ChangelogUpdate {
-                            path: result.path,
-                            package: None,
+                        let root_name = project_root
+                            .file_name()
+                            .map(|name| name.to_string_lossy().into_owned())
+                            .unwrap_or_default();
+                        release.preamble = render_preamble(changelog_config, &root_name);
+                        context.set_tag_prefixes(&mut release, newest);
+                        planned_updates.push((
+                            None,
                             version,
-                            created: result.created,
+                            format_entries(&release.entries, &release.categories)
+                                .trim()
+                                .to_string(),
+                        ));
+                        writes.push(PlannedChangelogWrite {
+                            path: project_root.join("CHANGELOG.md"),
+                            release,
+                            repo_info: repo_info.clone(),
+                            previous_version: Some(newest.current_version.to_string()),
                         });
                     }
                 }
@@ -336,27 +667,29 @@ where
             ChangelogLocation::PerPackage => {
                 for release in planned_releases {
                     if let Some(pkg) = package_lookup.get(&release.name) {
-                        let changelog_path = pkg.path.join("CHANGELOG.md");
-
-                        if let Some(version_release) = aggregator.build_package_release(
+                        if let Some(mut version_release) = aggregator.build_package_release(
                             &release.name,
                             &release.new_version,
                             today,
                         ) {
-                            let previous_version = release.current_version.to_string();
-
-                            let result = self.changelog_writer.write_release(
-                                &changelog_path,
-                                &version_release,
-                                repo_info.as_ref(),
-                                Some(&previous_version),
-                            )?;
-
-                            changelog_updates.push(ChangelogUpdate {
-                                path: result.path,
-                                package: Some(release.name.clone()),
-                                version: release.new_version.clone(),
-                                created: result.created,
+                            version_release.preamble =
+                                render_preamble(changelog_config, &release.name);
+                            context.set_tag_prefixes(&mut version_release, release);
+                            planned_updates.push((
+                                Some(release.name.clone()),
+                                release.new_version.clone(),
+                                format_entries(
+                                    &version_release.entries,
+                                    &version_release.categories,
+                                )
+                                .trim()
+                                .to_string(),
+                            ));
+                            writes.push(PlannedChangelogWrite {
+                                path: pkg.path.join("CHANGELOG.md"),
+                                release: version_release,
+                                repo_info: repo_info.clone(),
+                                previous_version: Some(release.current_version.to_string()),
                             });
                         }
                     }
@@ -364,44 +697,221 @@ where
             }
         }
 
-        Ok(changelog_updates)
+        let results = changelog_writer.write_releases(&writes)?;
+
+        Ok(results
+            .into_iter()
+            .zip(planned_updates)
+            .map(|(result, (package, version, excerpt))| ChangelogUpdate {
+                json_path: changelog_config
+                    .writes_json()
+                    .then(|| json_changelog_path(&result.path)),
+                path: result.path,
+                package,
+                version,
+                created: result.created,
+                excerpt,
+                archive_paths: Vec::new(),
+            })
+            .collect())
+    }
+
+    /// Writes the release changelogs and rotates them, returning the updates
+    /// and the state of every file written beforehand.
+    fn write_changelogs(
+        &self,
+        context: &ReleaseContext,
+        aggregator: &ChangesetAggregator,
+        planned_releases: &[PackageVersion],
+        package_lookup: &IndexMap<String, PackageInfo>,
+    ) -> Result<(Vec<ChangelogUpdate>, Vec<super::steps::ChangelogFileState>)> {
+        let mut backups = self.capture_changelog_state(
+            &context.project.root,
+            context.root_config.changelog_config(),
+            planned_releases,
+            package_lookup,
+        )?;
+        let mut updates = self.generate_changelog_updates(
+            &self.changelog_writer,
+            context,
+            aggregator,
+            planned_releases,
+            package_lookup,
+        )?;
+        if let Err(e) = Self::rotate_changelogs(
+            &self.changelog_writer,
+            context.root_config.changelog_config(),
+            &mut updates,
+            &mut backups,
+        ) {
+            self.restore_changelog_backups(&backups);
+            return Err(e);
+        }
+        Ok((updates, backups))
+    }
+
+    /// Moves older release sections of the updated changelogs into archive
+    /// files, recording each archive's prior state in `backups`.
+    fn rotate_changelogs(
+        changelog_writer: &dyn ChangelogWriter,
+        changelog_config: &changeset_changelog::ChangelogConfig,
+        updates: &mut [ChangelogUpdate],
+        backups: &mut Vec<super::steps::ChangelogFileState>,
+    ) -> Result<()> {
+        let Some(policy) = changelog_config.archive_policy() else {
+            return Ok(());
+        };
+        for update in updates {
+            for archive in changelog_writer.rotate_changelog(&update.path, &policy)? {
+                if backups.iter().all(|backup| backup.path != archive.path) {
+                    backups.push(super::steps::ChangelogFileState {
+                        path: archive.path.clone(),
+                        version: update.version.clone(),
+                        package: update.package.clone(),
+                        file_existed: archive.original_content.is_some(),
+                        original_content: archive.original_content,
+                    });
+                }
+                update.archive_paths.push(archive.path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Best-effort restore of changelogs written before the release saga
+    /// started.
+    fn restore_changelog_backups(&self, backups: &[super::steps::ChangelogFileState]) {
+        for backup in backups.iter().rev() {
+            let result = match &backup.original_content {
+                Some(content) => self
+                    .changelog_writer
+                    .restore_changelog(&backup.path, content),
+                None => self.changelog_writer.delete_changelog(&backup.path),
+            };
+            if let Err(e) = result {
+                warn!(path = %backup.path.display(), error = %e, "failed to restore changelog");
+            }
+        }
     }
 
     fn resolve_repo_info(
         &self,
         project_root: &Path,
-        changelog_config: &changeset_changelog::ChangelogConfig,
+        root_config: &changeset_project::RootChangesetConfig,
     ) -> Result<Option<RepositoryInfo>> {
-        match changelog_config.comparison_links {
-            ComparisonLinksSetting::Disabled => Ok(None),
-            ComparisonLinksSetting::Auto => Ok(self.detect_repository_info(project_root)),
+        let remote = root_config.git_config().remote();
+        let changelog_config = root_config.changelog_config();
+        let repo_info = match changelog_config.comparison_links {
+            ComparisonLinksSetting::Disabled => None,
+            ComparisonLinksSetting::Auto => self.detect_repository_info(project_root, remote)?,
             ComparisonLinksSetting::Enabled => {
-                let repo_info = self.detect_repository_info(project_root);
+                let repo_info = self.detect_repository_info(project_root, remote)?;
                 if repo_info.is_none() {
                     return Err(OperationError::ComparisonLinksRequired);
                 }
-                Ok(repo_info)
+                repo_info
             }
-        }
+        };
+        Ok(repo_info.map(|info| {
+            info.with_comparison_template(changelog_config.comparison_links_template.clone())
+        }))
     }
 
-    /// Validates that the working tree is clean when committing is enabled.
+    /// Checks the working tree before a committing release and applies
+    /// `on_dirty` when it has uncommitted changes, optionally disregarding
+    /// submodules. Runs before anything is read so stashed changes cannot leak
+    /// into the release.
     ///
     /// # Errors
     ///
     /// Returns `OperationError::DirtyWorkingTree` if the working tree has uncommitted
-    /// changes and committing is enabled.
-    fn validate_working_tree(
+    /// changes, committing is enabled, and `on_dirty` is [`DirtyTreeAction::Abort`].
+    fn prepare_working_tree(&self, start_path: &Path, input: &ReleaseInput) -> Result<WorkingTree> {
+        if input.dry_run || input.no_commit {
+            return Ok(WorkingTree::Clean);
+        }
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let git_config = root_config.git_config();
+        let ignore_submodules = git_config.ignore_submodule_changes();
+        if !git_config.commit()
+            || self
+                .git_provider
+                .is_working_tree_clean(&project.root, ignore_submodules)?
+        {
+            return Ok(WorkingTree::Clean);
+        }
+
+        match input.on_dirty {
+            DirtyTreeAction::Abort => Err(OperationError::DirtyWorkingTree {
+                files: self
+                    .git_provider
+                    .dirty_files(&project.root, ignore_submodules)?,
+            }),
+            DirtyTreeAction::Stash => {
+                if self
+                    .git_provider
+                    .stash_push(&project.root, RELEASE_STASH_MESSAGE)?
+                {
+                    Ok(WorkingTree::Stashed { root: project.root })
+                } else {
+                    Ok(WorkingTree::Clean)
+                }
+            }
+            DirtyTreeAction::Include => Ok(WorkingTree::Included(
+                self.git_provider
+                    .dirty_files(&project.root, ignore_submodules)?,
+            )),
+        }
+    }
+
+    /// Validates that the current branch matches the configured release branch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::NotOnReleaseBranch` if a release branch is configured,
+    /// the current branch differs, and neither `allow_branch` nor `dry_run` is set.
+    fn validate_release_branch(
+        &self,
+        project_root: &Path,
+        release_branch: Option<&str>,
+        allow_branch: bool,
+        dry_run: bool,
+    ) -> Result<()> {
+        let Some(expected) = release_branch else {
+            return Ok(());
+        };
+        if allow_branch || dry_run {
+            return Ok(());
+        }
+        let current = self.git_provider.current_branch(project_root)?;
+        if current != expected {
+            return Err(OperationError::NotOnReleaseBranch {
+                current,
+                expected: expected.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Validates that the local branch is not behind its upstream when committing is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::BranchBehindRemote` if the upstream has commits
+    /// that are missing locally and `allow_stale` is not set.
+    fn validate_up_to_date(
         &self,
         project_root: &Path,
         should_commit: bool,
+        allow_stale: bool,
         dry_run: bool,
     ) -> Result<()> {
-        if should_commit && !dry_run {
-            let is_clean = self.git_provider.is_working_tree_clean(project_root)?;
-            if !is_clean {
-                return Err(OperationError::DirtyWorkingTree);
-            }
+        if !should_commit || allow_stale || dry_run {
+            return Ok(());
+        }
+        if !self.git_provider.is_up_to_date(project_root)? {
+            return Err(OperationError::BranchBehindRemote);
         }
         Ok(())
     }
@@ -426,29 +936,87 @@ where
         Ok(inherited_packages)
     }
 
-    /// Loads changesets from the changeset directory and populates the aggregator.
+    /// Lists the pending changesets of the selected packages, or all without a
+    /// selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::UnknownPackage` for a selector that matches no
+    /// package, and `OperationError::ChangesetOutsideSelection` for a selected
+    /// changeset that also releases a package outside the selection.
+    fn list_selected_changesets(
+        &self,
+        project: &changeset_project::CargoProject,
+        changeset_dirs: &[PathBuf],
+        selectors: &[String],
+    ) -> Result<Vec<PathBuf>> {
+        let changeset_files = changeset_dirs::list_changesets(&self.changeset_io, changeset_dirs)?;
+        if selectors.is_empty() {
+            return Ok(changeset_files);
+        }
+        let selected: HashSet<String> =
+            package_selection::select_packages(&project.root, &project.packages, selectors)?
+                .into_iter()
+                .map(|package| package.name)
+                .collect();
+
+        let mut kept = Vec::new();
+        for path in changeset_files {
+            let changeset = self.changeset_io.read_changeset(&path)?;
+            let (inside, outside): (Vec<_>, Vec<_>) = changeset
+                .releases
+                .iter()
+                .partition(|release| selected.contains(&release.name));
+            match (inside.is_empty(), outside.first()) {
+                (true, _) => {}
+                (false, None) => kept.push(path),
+                (false, Some(release)) => {
+                    return Err(OperationError::ChangesetOutsideSelection {
+                        path,
+                        package: release.name.clone(),
+                    });
+                }
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Loads changesets from the changeset directories and populates the aggregator.
     ///
     /// # Errors
     ///
     /// Returns an error if changeset files cannot be read or parsed.
     fn load_changesets(
         &self,
-        changeset_dir: &Path,
+        changeset_dirs: &[PathBuf],
         changeset_files: &[PathBuf],
+        include_confidential: bool,
     ) -> Result<(Vec<changeset_core::Changeset>, ChangesetAggregator)> {
         let mut changesets = Vec::new();
         let mut aggregator = ChangesetAggregator::new();
+        if !include_confidential {
+            aggregator = aggregator.redact_embargoed(Local::now().date_naive());
+        }
 
         for path in changeset_files {
             let changeset = self.changeset_io.read_changeset(path)?;
-            aggregator.add_changeset(&changeset);
+            aggregator.add_changeset(
+                &changeset_file_name(path),
+                self.changeset_io.changeset_timestamp(path),
+                &changeset,
+            );
             changesets.push(changeset);
         }
 
-        let consumed_paths = self.changeset_io.list_consumed_changesets(changeset_dir)?;
+        let consumed_paths =
+            changeset_dirs::list_consumed_changesets(&self.changeset_io, changeset_dirs)?;
         for path in &consumed_paths {
             let changeset = self.changeset_io.read_changeset(path)?;
-            aggregator.add_changeset(&changeset);
+            aggregator.add_changeset(
+                &changeset_file_name(path),
+                self.changeset_io.changeset_timestamp(path),
+                &changeset,
+            );
         }
 
         Ok((changesets, aggregator))
@@ -473,19 +1041,46 @@ where
     /// Returns an error if the project cannot be discovered, changeset files
     /// cannot be read, or manifest updates fail.
     pub fn execute(&self, start_path: &Path, input: &ReleaseInput) -> Result<ReleaseOutcome> {
+        let working_tree = self.prepare_working_tree(start_path, input)?;
+        let outcome = self.execute_from(start_path, input, &working_tree);
+
+        // A failed saga restores the stash itself while compensating.
+        let rolled_back = matches!(
+            outcome,
+            Err(OperationError::SagaFailed { .. } | OperationError::SagaCompensationFailed { .. })
+        );
+        if let WorkingTree::Stashed { root } = &working_tree
+            && !rolled_back
+        {
+            self.git_provider.stash_pop(root).map_err(|source| {
+                OperationError::StashRestoreFailed {
+                    source: Box::new(source),
+                }
+            })?;
+        }
+
+        outcome
+    }
+
+    fn execute_from(
+        &self,
+        start_path: &Path,
+        input: &ReleaseInput,
+        working_tree: &WorkingTree,
+    ) -> Result<ReleaseOutcome> {
         let context = self.prepare_release_context(start_path, input)?;
 
         if let Some(early_return) = context.early_return {
             return early_return;
         }
 
-        let plan = self.plan_release(&context, input.dry_run)?;
+        let plan = self.plan_release(&context, input)?;
 
         if input.dry_run {
             return Ok(ReleaseOutcome::DryRun(plan.output));
         }
 
-        self.execute_release(&context, plan)
+        self.execute_release(&context, plan, working_tree)
     }
 
     fn prepare_release_context(
@@ -494,10 +1089,19 @@ where
         input: &ReleaseInput,
     ) -> Result<ReleaseContext> {
         let project = self.project_provider.discover_project(start_path)?;
-        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let (root_config, package_configs) = self.project_provider.load_configs(&project)?;
+
+        self.validate_release_branch(
+            &project.root,
+            root_config.git_config().release_branch(),
+            input.allow_branch,
+            input.dry_run,
+        )?;
 
         let changeset_dir = project.root.join(root_config.changeset_dir());
-        let changeset_files = self.changeset_io.list_changesets(&changeset_dir)?;
+        let changeset_dirs = changeset_dirs::changeset_dirs(&project.root, &root_config);
+        let changeset_files =
+            self.list_selected_changesets(&project, &changeset_dirs, &input.packages)?;
 
         let prerelease_state = self
             .release_state_io
@@ -506,9 +1110,9 @@ where
             .release_state_io
             .load_graduation_state(&changeset_dir)?;
 
-        let cli_input = Self::build_cli_input(input);
+        let channels = package_channels(&project, &root_config, &package_configs, input)?;
         let validated_config = ReleaseValidator::validate(
-            &cli_input,
+            &Self::build_cli_input(input, &channels),
             prerelease_state.as_ref(),
             graduation_state.as_ref(),
             &project.packages,
@@ -516,7 +1120,23 @@ where
         )
         .map_err(OperationError::ValidationFailed)?;
 
-        let per_package_config = validated_config.per_package;
+        let today = Local::now().date_naive();
+        let mut per_package_config = validated_config.per_package;
+        // Changesets are not parsed yet, so only policies that need no bump apply here;
+        // planning evaluates the rest.
+        apply_graduation_policies(
+            &mut per_package_config,
+            graduation_state.as_ref(),
+            &IndexMap::new(),
+            today,
+        );
+
+        let is_maintenance_release = Self::apply_empty_release_bump(
+            &mut per_package_config,
+            &project.packages,
+            &changeset_files,
+            input.empty_release_bump,
+        );
 
         let is_prerelease_graduation =
             is_prerelease_graduation(&project.packages, &per_package_config);
@@ -527,21 +1147,35 @@ where
             Self::check_early_return(&changeset_files, is_graduating, input, &per_package_config);
 
         let git_config = root_config.git_config();
+        let untagged_packages = untagged_packages(&project, &root_config, &package_configs);
         let git_options = GitOptions {
             should_commit: !input.no_commit && git_config.commit(),
-            should_create_tags: !input.no_tags && git_config.tags(),
+            should_create_tags: !input.no_tags && untagged_packages.len() < project.packages.len(),
             should_delete_changesets: !input.keep_changesets && !git_config.keep_changesets(),
+            untagged_packages,
         };
         let is_prerelease_release = is_any_prerelease_configured(input, &per_package_config);
 
-        self.validate_working_tree(&project.root, git_options.should_commit, input.dry_run)?;
-        let inherited_packages =
-            self.check_inherited_versions(&project.packages, input.convert_inherited)?;
+        self.validate_up_to_date(
+            &project.root,
+            git_options.should_commit,
+            input.allow_stale,
+            input.dry_run,
+        )?;
+        let inherited_packages = if input.tag_only {
+            Vec::new()
+        } else {
+            self.check_inherited_versions(&project.cargo_packages(), input.convert_inherited)?
+        };
+
+        let version_stamps =
+            release_stamps(&project, &root_config, &package_configs, &input.stamps);
 
         Ok(ReleaseContext {
             project,
             root_config,
             changeset_dir,
+            changeset_dirs,
             changeset_files,
             prerelease_state,
             graduation_state,
@@ -551,17 +1185,54 @@ where
             is_prerelease_release,
             git_options,
             inherited_packages,
+            include_confidential: input.include_confidential,
+            step_control: release_step_control(input),
+            version_stamps,
+            today,
+            is_maintenance_release,
+            tag_only: input.tag_only,
+            tag_namespaces: channels
+                .into_iter()
+                .filter(|(_, channel)| !channel.tag_namespace().is_empty())
+                .map(|(name, channel)| (name, channel.tag_namespace().to_string()))
+                .collect(),
             early_return,
         })
     }
 
+    /// Forces `bump` on every package when no changesets or forced bumps are
+    /// pending; returns whether the release became a maintenance release.
+    fn apply_empty_release_bump(
+        per_package_config: &mut HashMap<String, PackageReleaseConfig>,
+        packages: &[PackageInfo],
+        changeset_files: &[PathBuf],
+        bump: Option<BumpType>,
+    ) -> bool {
+        let Some(bump) = bump else {
+            return false;
+        };
+        if !changeset_files.is_empty()
+            || per_package_config.values().any(|c| c.forced_bump.is_some())
+        {
+            return false;
+        }
+        for package in packages {
+            per_package_config
+                .entry(package.name.clone())
+                .or_default()
+                .forced_bump = Some(bump);
+        }
+        true
+    }
+
     fn check_early_return(
         changeset_files: &[PathBuf],
         is_graduating: bool,
         input: &ReleaseInput,
         per_package_config: &HashMap<String, PackageReleaseConfig>,
     ) -> Option<Result<ReleaseOutcome>> {
-        if changeset_files.is_empty() && !is_graduating {
+        let has_forced_bumps = per_package_config.values().any(|c| c.forced_bump.is_some());
+        if changeset_files.is_empty() && !is_graduating && !has_forced_bumps {
             if is_any_prerelease_configured(input, per_package_config) && !input.force {
                 return Some(Err(OperationError::NoChangesetsWithoutForce));
             }
@@ -570,21 +1241,100 @@ where
         None
     }
 
-    fn plan_release(&self, context: &ReleaseContext, dry_run: bool) -> Result<ReleasePlan> {
-        let (changesets, aggregator) =
-            self.load_changesets(&context.changeset_dir, &context.changeset_files)?;
-
-        let planned_releases = if context.is_prerelease_graduation {
-            VersionPlanner::plan_graduation(&context.project.packages)?.releases
+    fn plan_versions(
+        context: &ReleaseContext,
+        changesets: &[Changeset],
+    ) -> Result<planner::ReleasePlan> {
+        let mut version_plan = if context.is_prerelease_graduation {
+            VersionPlanner::plan_graduation(&context.project.packages)?
         } else {
+            let mut per_package_config = context.per_package_config.clone();
+            apply_graduation_policies(
+                &mut per_package_config,
+                context.graduation_state.as_ref(),
+                &VersionPlanner::aggregate_bumps(changesets),
+                context.today,
+            );
             VersionPlanner::plan_releases_per_package(
-                &changesets,
+                changesets,
                 &context.project.packages,
-                &context.per_package_config,
+                &per_package_config,
                 context.root_config.zero_version_behavior(),
             )?
-            .releases
         };
+        for provenance in &version_plan.provenance {
+            debug!(
+                package = %provenance.package,
+                provenance = %serde_json::to_string(provenance).unwrap_or_default(),
+                "planned release"
+            );
+        }
+        if let Some(state) = &context.prerelease_state {
+            VersionPlanner::continue_prerelease_numbers(&mut version_plan.releases, state)?;
+        }
+        Ok(version_plan)
+    }
+
+    /// Releases the versions already in the manifests instead of the planned
+    /// ones, each compared against the latest tag of its package.
+    fn keep_manifest_versions(
+        &self,
+        context: &ReleaseContext,
+        releases: &mut [PackageVersion],
+    ) -> Result<()> {
+        let tags = self.git_provider.list_tags(&context.project.root)?;
+        let naming = context.tag_naming();
+        for release in releases {
+            let Some(package) = context
+                .project
+                .packages
+                .iter()
+                .find(|package| package.name == release.name)
+            else {
+                continue;
+            };
+            let tagged = naming.latest_version(&tags, &release.name);
+            if let Some(tagged) = tagged.as_ref().filter(|tagged| **tagged >= package.version) {
+                return Err(OperationError::VersionNotBumped {
+                    package: release.name.clone(),
+                    version: package.version.clone(),
+                    tagged: tagged.clone(),
+                });
+            }
+            release.current_version = tagged.unwrap_or_else(|| package.version.clone());
+            release.new_version = package.version.clone();
+        }
+        Ok(())
+    }
+
+    fn plan_release(&self, context: &ReleaseContext, input: &ReleaseInput) -> Result<ReleasePlan> {
+        let (changesets, mut aggregator) = self.load_changesets(
+            &context.changeset_dirs,
+            &context.changeset_files,
+            context.include_confidential,
+        )?;
+        validate_categories(
+            &changesets,
+            &context.changeset_files,
+            context.root_config.categories(),
+        )?;
+        for mut changeset in forced_bump_changesets(&context.per_package_config) {
+            if context.is_maintenance_release {
+                changeset.summary = MAINTENANCE_RELEASE_SUMMARY.to_string();
+            }
+            // Forced bumps have no file; they sort before file-backed entries.
+            aggregator.add_changeset("", None, &changeset);
+        }
+        let aggregator = aggregator
+            .with_display_names(context.root_config.display_names().clone())
+            .with_categories(context.root_config.categories().clone())
+            .with_changelog_config(context.root_config.changelog_config());
+
+        let mut version_plan = Self::plan_versions(context, &changesets)?;
+        if context.tag_only {
+            self.keep_manifest_versions(context, &mut version_plan.releases)?;
+        }
+        let planned_releases = version_plan.releases;
 
         let package_lookup: IndexMap<_, _> = context
             .project
@@ -596,31 +1346,61 @@ where
         let unchanged_packages =
             Self::collect_unchanged_packages(&context.project.packages, &planned_releases);
 
-        let (changelog_updates, changelog_backups) = if dry_run {
-            (Vec::new(), Vec::new())
-        } else {
-            let backups = self.capture_changelog_state(
-                &context.project.root,
-                context.root_config.changelog_config(),
+        let stamped_files = plan_stamps(
+            self.text_file_io.as_ref(),
+            &context.project.root,
+            &context.version_stamps,
+            &planned_releases,
+        )?;
+
+        let mut file_diffs = Vec::new();
+        let (changelog_updates, changelog_backups) = if input.dry_run && input.diff {
+            let preview = PreviewFiles::with_adapters(self.package_adapters.clone());
+            let mut updates = self.generate_changelog_updates(
+                &preview,
+                context,
+                &aggregator,
                 &planned_releases,
                 &package_lookup,
             )?;
-            let updates = self.generate_changelog_updates(
-                &context.project.root,
+            Self::rotate_changelogs(
+                &preview,
                 context.root_config.changelog_config(),
-                &aggregator,
-                &planned_releases,
-                &package_lookup,
+                &mut updates,
+                &mut Vec::new(),
             )?;
-            (updates, backups)
+            Self::preview_file_changes(&preview, context, &planned_releases, &package_lookup)?;
+            for file in &stamped_files {
+                preview.stamp(&file.path, &file.stamped_content)?;
+            }
+            file_diffs = preview.into_diffs(&context.project.root);
+            (updates, Vec::new())
+        } else if input.dry_run {
+            (Vec::new(), Vec::new())
+        } else {
+            self.run_pre_checks(context, &planned_releases)?;
+            self.write_changelogs(context, &aggregator, &planned_releases, &package_lookup)?
         };
 
         let output = ReleaseOutput {
             planned_releases: planned_releases.clone(),
             unchanged_packages,
             changesets_consumed: context.changeset_files.clone(),
+            changeset_owners: context
+                .changeset_files
+                .iter()
+                .filter_map(|path| {
+                    let owner = context
+                        .root_config
+                        .changeset_owner(&context.project.root, path)?;
+                    Some((path.clone(), owner.to_string()))
+                })
+                .collect(),
             changelog_updates,
             git_result: None,
+            step_timings: Vec::new(),
+            provenance: version_plan.provenance,
+            file_diffs,
         };
 
         Ok(ReleasePlan {
@@ -628,45 +1408,152 @@ where
             planned_releases,
             package_lookup,
             changelog_backups,
+            stamped_files,
         })
     }
 
-    fn execute_release(
+    /// Runs the configured pre-release checks; they run before the first
+    /// changelog is written, so a failing check leaves every file untouched.
+    fn run_pre_checks(
         &self,
         context: &ReleaseContext,
-        plan: ReleasePlan,
-    ) -> Result<ReleaseOutcome> {
-        let package_paths: IndexMap<String, PathBuf> = plan
-            .package_lookup
-            .iter()
-            .map(|(name, info)| (name.clone(), info.path.clone()))
-            .collect();
-
-        let saga_data = ReleaseSagaData::new(
-            context.changeset_dir.clone(),
-            context.project.root.join("Cargo.toml"),
-            plan.planned_releases.clone(),
-            package_paths,
-            plan.output.changelog_updates.clone(),
-            context.changeset_files.clone(),
+        planned_releases: &[PackageVersion],
+    ) -> Result<()> {
+        if !context.step_control.runs(ReleaseStep::PreChecks) {
+            return Ok(());
+        }
+        run_pre_checks(
+            self.command_runner.as_ref(),
+            &context.project.root,
+            context.root_config.release_pre_checks(),
+            planned_releases,
         )
-        .with_options(SagaReleaseOptions {
-            is_prerelease_release: context.is_prerelease_release,
-            is_graduating: context.is_graduating,
-            is_prerelease_graduation: context.is_prerelease_graduation,
-            should_commit: context.git_options.should_commit,
-            should_create_tags: context.git_options.should_create_tags,
-            should_delete_changesets: context.git_options.should_delete_changesets,
-        })
-        .with_inherited_packages(context.inherited_packages.clone())
-        .with_prerelease_state(context.prerelease_state.as_ref())
-        .with_graduation_state(context.graduation_state.as_ref())
-        .with_changelog_backups(plan.changelog_backups);
-
-        let result = self.execute_release_saga(context, saga_data)?;
+    }
 
-        Ok(ReleaseOutcome::Executed(ReleaseOutput {
+    /// Applies the manifest edits and changeset deletions of the release saga to
+    /// in-memory copies, in the same order the saga steps run.
+    fn preview_file_changes(
+        preview: &PreviewFiles,
+        context: &ReleaseContext,
+        planned_releases: &[PackageVersion],
+        package_lookup: &IndexMap<String, PackageInfo>,
+    ) -> Result<()> {
+        let project = &context.project;
+        let root_manifest = project.root.join("Cargo.toml");
+        let mut manifest_paths: Vec<_> = package_lookup
+            .values()
+            .filter(|pkg| project.is_cargo_package(pkg))
+            .map(|pkg| pkg.path.join("Cargo.toml"))
+            .collect();
+        manifest_paths.push(root_manifest.clone());
+
+        // A tag-only release keeps the manifests as they are.
+        if !context.tag_only {
+            for release in planned_releases {
+                if let Some(pkg) = package_lookup.get(&release.name) {
+                    preview.write_version(&project.manifest_path(pkg), &release.new_version)?;
+                }
+            }
+
+            let skip_optional = context.root_config.ignore_optional_dependencies();
+            for release in planned_releases {
+                for manifest_path in &manifest_paths {
+                    preview.update_dependency_version(
+                        manifest_path,
+                        &release.name,
+                        &release.new_version,
+                        skip_optional,
+                    )?;
+                }
+            }
+        }
+
+        if !context.inherited_packages.is_empty() {
+            preview.remove_workspace_version(&root_manifest)?;
+        }
+
+        let deletes_changesets = context.git_options.should_delete_changesets
+            && !context.is_prerelease_release
+            && !context.is_prerelease_graduation;
+        if deletes_changesets {
+            for path in &context.changeset_files {
+                preview.delete(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Snapshots the file the consumed marks are kept in, so that a rollback
+    /// can put it back.
+    fn consumed_state_file(&self) -> Result<Option<super::steps::ConsumedStateFile>> {
+        let Some(path) = self.changeset_io.consumed_state_file() else {
+            return Ok(None);
+        };
+        let original_content = if path.exists() {
+            Some(self.text_file_io.read_file(&path)?)
+        } else {
+            None
+        };
+        Ok(Some(super::steps::ConsumedStateFile {
+            path,
+            original_content,
+        }))
+    }
+
+    fn execute_release(
+        &self,
+        context: &ReleaseContext,
+        plan: ReleasePlan,
+        working_tree: &WorkingTree,
+    ) -> Result<ReleaseOutcome> {
+        let package_manifests: IndexMap<String, PathBuf> = plan
+            .package_lookup
+            .iter()
+            .map(|(name, info)| (name.clone(), context.project.manifest_path(info)))
+            .collect();
+
+        let saga_data = ReleaseSagaData::new(
+            context.changeset_dir.clone(),
+            context.project.root.join("Cargo.toml"),
+            plan.planned_releases.clone(),
+            package_manifests,
+            plan.output.changelog_updates.clone(),
+            context.changeset_files.clone(),
+        )
+        .with_options(SagaReleaseOptions {
+            is_prerelease_release: context.is_prerelease_release,
+            is_graduating: context.is_graduating,
+            is_prerelease_graduation: context.is_prerelease_graduation,
+            should_commit: context.git_options.should_commit,
+            should_create_tags: context.git_options.should_create_tags,
+            should_delete_changesets: context.git_options.should_delete_changesets,
+        })
+        .with_changeset_dirs(context.changeset_dirs.clone())
+        .with_inherited_packages(context.inherited_packages.clone())
+        .with_untagged_packages(context.git_options.untagged_packages.clone())
+        .with_tag_namespaces(context.tag_namespaces.clone())
+        .with_skip_optional_dependencies(context.root_config.ignore_optional_dependencies())
+        .with_prerelease_state(
+            context.prerelease_state.as_ref(),
+            &cli_prerelease_packages(&context.per_package_config),
+        )
+        .with_graduation_state(context.graduation_state.as_ref())
+        .with_changelog_backups(plan.changelog_backups)
+        .with_stamped_files(plan.stamped_files)
+        .with_commit_strategy(context.root_config.git_config().commit_strategy())
+        .with_changes_stashed(matches!(working_tree, WorkingTree::Stashed { .. }))
+        .with_included_files(match working_tree {
+            WorkingTree::Included(files) => files.clone(),
+            WorkingTree::Clean | WorkingTree::Stashed { .. } => Vec::new(),
+        })
+        .with_consumed_state_file(self.consumed_state_file()?);
+
+        let (result, step_timings) = self.execute_release_saga(context, saga_data)?;
+
+        Ok(ReleaseOutcome::Executed(ReleaseOutput {
             git_result: Some(result.into_git_result()),
+            step_timings,
             ..plan.output
         }))
     }
@@ -676,16 +1563,14 @@ where
         &self,
         context: &ReleaseContext,
         saga_data: ReleaseSagaData,
-    ) -> Result<ReleaseSagaData> {
+    ) -> Result<(ReleaseSagaData, Vec<StepTiming>)> {
         let git_config = context.root_config.git_config();
-        let use_crate_prefix = match &context.project.kind {
-            ProjectKind::SinglePackage => git_config.tag_format() == TagFormat::CratePrefixed,
-            ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => true,
-        };
+        let use_crate_prefix = uses_crate_prefix(&context.project.kind, git_config.tag_format());
 
         type RestoreChangelogs<G, M, RW, S, CW> = RestoreChangelogsStep<G, M, RW, S, CW>;
         type WriteManifests<G, M, RW, S, CW> = WriteManifestVersionsStep<G, M, RW, S, CW>;
         type UpdateDeps<G, M, RW, S, CW> = UpdateDependencyVersionsStep<G, M, RW, S, CW>;
+        type StampFiles<G, M, RW, S, CW> = StampFilesStep<G, M, RW, S, CW>;
         type RemoveWorkspace<G, M, RW, S, CW> = RemoveWorkspaceVersionStep<G, M, RW, S, CW>;
         type MarkConsumed<G, M, RW, S, CW> = MarkChangesetsConsumedStep<G, M, RW, S, CW>;
         type ClearConsumed<G, M, RW, S, CW> = ClearChangesetsConsumedStep<G, M, RW, S, CW>;
@@ -695,28 +1580,88 @@ where
         type Tags<G, M, RW, S, CW> = CreateTagsStep<G, M, RW, S, CW>;
         type UpdateState<G, M, RW, S, CW> = UpdateReleaseStateStep<G, M, RW, S, CW>;
 
+        let control = &context.step_control;
+        // No step runs with a timeout: each one writes files or talks to git,
+        // and an abandoned call could still land after the rollback.
         let saga = SagaBuilder::new()
-            .first_step(RestoreChangelogs::<G, M, RW, S, C>::new())
-            .then(WriteManifests::<G, M, RW, S, C>::new())
-            .then(UpdateDeps::<G, M, RW, S, C>::new())
-            .then(RemoveWorkspace::<G, M, RW, S, C>::new())
-            .then(MarkConsumed::<G, M, RW, S, C>::new())
-            .then(ClearConsumed::<G, M, RW, S, C>::new())
-            .then(DeleteChangesets::<G, M, RW, S, C>::new())
-            .then(Stage::<G, M, RW, S, C>::new())
-            .then(Commit::<G, M, RW, S, C>::new(
-                git_config.commit_title_template().to_string(),
-                git_config.changes_in_body(),
+            .first_step(RestoreStashStep::<G, M, RW, S, C>::new())
+            .then(Controlled::new(
+                RestoreChangelogs::<G, M, RW, S, C>::new(),
+                ReleaseStep::RestoreChangelogs,
+                control,
+            ))
+            .then(Controlled::new(
+                WriteManifests::<G, M, RW, S, C>::new(),
+                ReleaseStep::WriteManifests,
+                control,
+            ))
+            .then(Controlled::new(
+                UpdateDeps::<G, M, RW, S, C>::new(),
+                ReleaseStep::UpdateDependencies,
+                control,
+            ))
+            .then(Controlled::new(
+                StampFiles::<G, M, RW, S, C>::new(),
+                ReleaseStep::StampFiles,
+                control,
+            ))
+            .then(Controlled::new(
+                RemoveWorkspace::<G, M, RW, S, C>::new(),
+                ReleaseStep::RemoveWorkspaceVersion,
+                control,
+            ))
+            .then(Controlled::new(
+                MarkConsumed::<G, M, RW, S, C>::new(),
+                ReleaseStep::MarkConsumed,
+                control,
+            ))
+            .then(Controlled::new(
+                ClearConsumed::<G, M, RW, S, C>::new(),
+                ReleaseStep::ClearConsumed,
+                control,
+            ))
+            .then(Controlled::new(
+                DeleteChangesets::<G, M, RW, S, C>::new(),
+                ReleaseStep::DeleteChangesets,
+                control,
+            ))
+            .then(Controlled::new(
+                UpdateState::<G, M, RW, S, C>::new(),
+                ReleaseStep::UpdateState,
+                control,
+            ))
+            .then(Controlled::new(
+                Stage::<G, M, RW, S, C>::new(),
+                ReleaseStep::StageFiles,
+                control,
             ))
-            .then(Tags::<G, M, RW, S, C>::new(
-                git_config.tag_format(),
-                use_crate_prefix,
+            .then(Controlled::new(
+                Commit::<G, M, RW, S, C>::new(
+                    git_config.commit_title_template().to_string(),
+                    git_config.changes_in_body(),
+                )
+                .with_body(git_config)
+                .with_display_names(context.root_config.display_names().clone())
+                .with_identity(release_commit_identity(git_config)),
+                ReleaseStep::CreateCommit,
+                control,
+            ))
+            .then(Controlled::new(
+                Tags::<G, M, RW, S, C>::new(git_config.tag_format(), use_crate_prefix)
+                    .with_tag_body(git_config.tag_body()),
+                ReleaseStep::CreateTags,
+                control,
             ))
-            .then(UpdateState::<G, M, RW, S, C>::new())
             .build();
 
         let saga_context = self.create_saga_context(&context.project.root);
-        saga.execute(&saga_context, saga_data).map_err(Into::into)
+        let wall_start = SystemTime::now();
+        let clock_start = Instant::now();
+        let mut completed = self.completed_steps.clone();
+        let (result, audit_log) = saga.execute_resumable(&saga_context, saga_data, &mut completed);
+        let data = result.map_err(|e| saga_context.saga_error(e, &completed))?;
+
+        Ok((data, step_timings(&audit_log, wall_start, clock_start)))
     }
 
     fn create_saga_context(&self, project_root: &Path) -> ReleaseSagaContext<G, M, RW, S, C> {
@@ -728,10 +1673,21 @@ where
             Arc::clone(&self.release_state_io),
             Arc::new(self.changelog_writer.clone()),
         )
+        .with_text_file_io(Arc::clone(&self.text_file_io))
     }
 
-    fn build_cli_input(input: &ReleaseInput) -> ReleaseCliInput {
+    fn build_cli_input(
+        input: &ReleaseInput,
+        channels: &IndexMap<String, ReleaseChannel>,
+    ) -> ReleaseCliInput {
         ReleaseCliInput {
+            channel_prerelease: channels
+                .iter()
+                .filter_map(|(name, channel)| {
+                    let spec = channel.prerelease()?.parse().ok()?;
+                    Some((name.clone(), spec))
+                })
+                .collect(),
             cli_prerelease: input
                 .per_package_config
                 .iter()
@@ -750,10 +1706,96 @@ where
                 .map(|(name, _)| name.clone())
                 .collect(),
             graduate_all: input.graduate_all,
+            cli_forced_bumps: input
+                .per_package_config
+                .iter()
+                .filter_map(|(name, config)| config.forced_bump.map(|bump| (name.clone(), bump)))
+                .collect(),
         }
     }
 }
 
+/// Converts the saga audit log into wall-clock step timings.
+fn step_timings(
+    audit_log: &SagaAuditLog,
+    wall_start: SystemTime,
+    clock_start: Instant,
+) -> Vec<StepTiming> {
+    audit_log
+        .records()
+        .iter()
+        .filter_map(|record| {
+            let duration = record.duration?;
+            let offset = record.started_at.saturating_duration_since(clock_start);
+            Some(StepTiming {
+                name: record.name.clone(),
+                started_at: wall_start + offset,
+                duration,
+            })
+        })
+        .collect()
+}
+
+/// The channel each package is released on: `--channel`, else the package's
+/// `channel` setting, else `stable`.
+///
+/// # Errors
+///
+/// Returns `OperationError::UnknownChannel` for a channel that is not defined.
+fn package_channels(
+    project: &changeset_project::CargoProject,
+    root_config: &changeset_project::RootChangesetConfig,
+    package_configs: &HashMap<String, PackageChangesetConfig>,
+    input: &ReleaseInput,
+) -> Result<IndexMap<String, ReleaseChannel>> {
+    let mut channels = IndexMap::new();
+    for package in &project.packages {
+        let name = input
+            .channel
+            .as_deref()
+            .or_else(|| {
+                package_configs
+                    .get(&package.name)
+                    .and_then(PackageChangesetConfig::channel)
+            })
+            .unwrap_or(ReleaseChannel::STABLE);
+        let channel = root_config
+            .channel(name)
+            .ok_or_else(|| OperationError::UnknownChannel {
+                name: name.to_string(),
+                package: package.name.clone(),
+                available: root_config
+                    .channels()
+                    .iter()
+                    .map(ReleaseChannel::name)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            })?;
+        channels.insert(package.name.clone(), channel.clone());
+    }
+    Ok(channels)
+}
+
+/// Packages whose `tags` setting, or the root one, turns tagging off.
+fn untagged_packages(
+    project: &changeset_project::CargoProject,
+    root_config: &changeset_project::RootChangesetConfig,
+    package_configs: &HashMap<String, PackageChangesetConfig>,
+) -> Vec<String> {
+    let tags = root_config.git_config().tags();
+    project
+        .packages
+        .iter()
+        .filter(|package| {
+            !package_configs
+                .get(&package.name)
+                .and_then(PackageChangesetConfig::tags)
+                .unwrap_or(tags)
+        })
+        .map(|package| package.name.clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -774,6 +1816,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         }
     }
 
@@ -812,6 +1865,192 @@ mod tests {
         assert!(matches!(result, ReleaseOutcome::NoChangesets));
     }
 
+    #[test]
+    fn forced_bump_releases_without_changesets() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let operation = make_operation(
+            project_provider,
+            MockChangesetReader::new(),
+            MockManifestWriter::new(),
+        );
+        let mut per_package_config = HashMap::new();
+        per_package_config.insert(
+            "my-crate".to_string(),
+            PackageReleaseConfig {
+                forced_bump: Some(BumpType::Minor),
+                ..Default::default()
+            },
+        );
+        let input = ReleaseInput {
+            per_package_config,
+            ..default_input()
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed");
+
+        let ReleaseOutcome::DryRun(output) = result else {
+            panic!("expected DryRun outcome");
+        };
+        assert_eq!(output.planned_releases.len(), 1);
+        assert_eq!(output.planned_releases[0].new_version.to_string(), "1.1.0");
+    }
+
+    #[test]
+    fn empty_release_bumps_every_package() {
+        let project_provider =
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "0.3.1")]);
+        let operation = make_operation(
+            project_provider,
+            MockChangesetReader::new(),
+            MockManifestWriter::new(),
+        );
+        let input = ReleaseInput {
+            empty_release_bump: Some(BumpType::Patch),
+            ..default_input()
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed");
+
+        let ReleaseOutcome::DryRun(output) = result else {
+            panic!("expected DryRun outcome");
+        };
+        let versions: Vec<_> = output
+            .planned_releases
+            .iter()
+            .map(|release| (release.name.as_str(), release.new_version.to_string()))
+            .collect();
+        assert_eq!(
+            versions,
+            vec![
+                ("crate-a", "1.0.1".to_string()),
+                ("crate-b", "0.3.2".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_release_bump_is_ignored_with_pending_changesets() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset = make_changeset("my-crate", BumpType::Minor, "Add feature");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/feature.md"), changeset);
+        let operation = make_operation(
+            project_provider,
+            changeset_reader,
+            MockManifestWriter::new(),
+        );
+        let input = ReleaseInput {
+            empty_release_bump: Some(BumpType::Major),
+            ..default_input()
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed");
+
+        let ReleaseOutcome::DryRun(output) = result else {
+            panic!("expected DryRun outcome");
+        };
+        assert_eq!(output.planned_releases[0].new_version.to_string(), "1.1.0");
+    }
+
+    fn tag_only_operation(
+        tags: &[&str],
+    ) -> ReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockManifestWriter,
+        MockChangelogWriter,
+        MockGitProvider,
+        MockReleaseStateIO,
+    > {
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix bug");
+        ReleaseOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.2.0"),
+            MockChangesetReader::new()
+                .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset),
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            MockGitProvider::new().with_existing_tags(tags),
+            MockReleaseStateIO::new(),
+        )
+    }
+
+    #[test]
+    fn tag_only_releases_the_manifest_version() {
+        let operation = tag_only_operation(&["v1.0.0", "v1.1.0"]);
+        let input = ReleaseInput {
+            tag_only: true,
+            ..default_input()
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed");
+
+        let ReleaseOutcome::DryRun(output) = result else {
+            panic!("expected DryRun outcome");
+        };
+        assert_eq!(
+            output.planned_releases[0].current_version.to_string(),
+            "1.1.0"
+        );
+        assert_eq!(output.planned_releases[0].new_version.to_string(), "1.2.0");
+    }
+
+    #[test]
+    fn tag_only_requires_a_version_above_the_latest_tag() {
+        let operation = tag_only_operation(&["v1.2.0"]);
+        let input = ReleaseInput {
+            tag_only: true,
+            ..default_input()
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(matches!(
+            result,
+            Err(OperationError::VersionNotBumped { ref package, .. }) if package == "my-crate"
+        ));
+    }
+
+    #[test]
+    fn tag_only_skips_manifest_steps() {
+        let input = ReleaseInput {
+            tag_only: true,
+            ..default_input()
+        };
+
+        let control = release_step_control(&input);
+
+        assert!(!control.runs(ReleaseStep::WriteManifests));
+        assert!(!control.runs(ReleaseStep::UpdateDependencies));
+        assert!(control.runs(ReleaseStep::DeleteChangesets));
+        assert!(control.runs(ReleaseStep::CreateTags));
+    }
+
+    #[test]
+    fn forced_bump_changesets_have_synthesized_summary() {
+        let mut per_package_config = HashMap::new();
+        per_package_config.insert(
+            "my-crate".to_string(),
+            PackageReleaseConfig {
+                forced_bump: Some(BumpType::Major),
+                ..Default::default()
+            },
+        );
+
+        let changesets = forced_bump_changesets(&per_package_config);
+
+        assert_eq!(changesets.len(), 1);
+        assert_eq!(changesets[0].summary, "Forced major bump");
+        assert_eq!(changesets[0].releases[0].name, "my-crate");
+    }
+
     #[test]
     fn calculates_single_patch_bump() {
         let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
@@ -867,6 +2106,18 @@ mod tests {
         let release = &output.planned_releases[0];
         assert_eq!(release.new_version.to_string(), "1.3.0");
         assert_eq!(release.bump_type, BumpType::Minor);
+
+        assert_eq!(output.provenance.len(), 1);
+        let winner = output.provenance[0]
+            .winner()
+            .expect("explanation should have a winner");
+        let crate::types::BumpSource::Changeset(index) = winner.source else {
+            panic!("expected the winning bump to come from a changeset");
+        };
+        assert_eq!(
+            output.changesets_consumed[index],
+            PathBuf::from(".changeset/changesets/feature.md")
+        );
     }
 
     #[test]
@@ -917,6 +2168,79 @@ mod tests {
         assert_eq!(crate_b.new_version.to_string(), "3.0.0");
     }
 
+    fn selection_operation(
+        changesets: Vec<(PathBuf, changeset_core::Changeset)>,
+    ) -> ReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockManifestWriter,
+        MockChangelogWriter,
+        MockGitProvider,
+        MockReleaseStateIO,
+    > {
+        make_operation(
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")]),
+            MockChangesetReader::new().with_changesets(changesets),
+            MockManifestWriter::new(),
+        )
+    }
+
+    #[test]
+    fn package_selection_releases_only_selected_changesets() {
+        let operation = selection_operation(vec![
+            (
+                PathBuf::from(".changeset/changesets/feature-a.md"),
+                make_changeset("crate-a", BumpType::Minor, "Add feature to A"),
+            ),
+            (
+                PathBuf::from(".changeset/changesets/breaking-b.md"),
+                make_changeset("crate-b", BumpType::Major, "Breaking change in B"),
+            ),
+        ]);
+        let input = ReleaseInput {
+            packages: vec!["crates/crate-a".to_string()],
+            ..default_input()
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed");
+
+        let ReleaseOutcome::DryRun(output) = result else {
+            panic!("expected DryRun outcome");
+        };
+        let released: Vec<_> = output.planned_releases.iter().map(|r| &r.name).collect();
+        assert_eq!(released, ["crate-a"]);
+        assert_eq!(
+            output.changesets_consumed,
+            [PathBuf::from(".changeset/changesets/feature-a.md")]
+        );
+    }
+
+    #[test]
+    fn package_selection_rejects_changesets_spanning_unselected_packages() {
+        let mut changeset = make_changeset("crate-a", BumpType::Minor, "Shared change");
+        changeset.releases.push(changeset_core::PackageRelease {
+            name: "crate-b".to_string(),
+            bump_type: BumpType::Patch,
+        });
+        let operation = selection_operation(vec![(
+            PathBuf::from(".changeset/changesets/shared.md"),
+            changeset,
+        )]);
+        let input = ReleaseInput {
+            packages: vec!["crate-a".to_string()],
+            ..default_input()
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(matches!(
+            result,
+            Err(OperationError::ChangesetOutsideSelection { ref package, .. }) if package == "crate-b"
+        ));
+    }
+
     #[test]
     fn identifies_unchanged_packages() {
         let project_provider = MockProjectProvider::workspace(vec![
@@ -990,13 +2314,75 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
             .execute(Path::new("/any"), &input)
             .expect("execute failed");
 
-        assert!(matches!(result, ReleaseOutcome::Executed(_)));
+        let ReleaseOutcome::Executed(output) = result else {
+            panic!("expected Executed outcome");
+        };
+        let names: Vec<&str> = output
+            .step_timings
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert!(names.contains(&"write-manifests"));
+        assert!(names.contains(&"update-state"));
+    }
+
+    #[test]
+    fn dry_run_has_no_step_timings() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset);
+        let manifest_writer = MockManifestWriter::new();
+
+        let operation = make_operation(project_provider, changeset_reader, manifest_writer);
+        let input = ReleaseInput {
+            dry_run: true,
+            convert_inherited: false,
+            no_commit: true,
+            no_tags: true,
+            keep_changesets: true,
+            force: false,
+            per_package_config: HashMap::new(),
+            global_prerelease: None,
+            graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed");
+
+        let ReleaseOutcome::DryRun(output) = result else {
+            panic!("expected DryRun outcome");
+        };
+        assert!(output.step_timings.is_empty());
     }
 
     #[test]
@@ -1027,6 +2413,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1065,6 +2462,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -1095,6 +2503,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -1133,6 +2552,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(_) = operation
@@ -1175,11 +2605,118 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
 
-        assert!(matches!(result, Err(OperationError::DirtyWorkingTree)));
+        assert!(matches!(
+            result,
+            Err(OperationError::DirtyWorkingTree { .. })
+        ));
+    }
+
+    fn dirty_tree_operation(
+        git_provider: MockGitProvider,
+    ) -> ReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockManifestWriter,
+        MockChangelogWriter,
+        MockGitProvider,
+        MockReleaseStateIO,
+    > {
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix");
+        ReleaseOperation::new(
+            MockProjectProvider::single_package("my-crate", "1.0.0"),
+            MockChangesetReader::new()
+                .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset),
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            git_provider,
+            MockReleaseStateIO::new(),
+        )
+    }
+
+    fn dirty_tree_input(on_dirty: DirtyTreeAction) -> ReleaseInput {
+        ReleaseInput {
+            dry_run: false,
+            no_commit: false,
+            on_dirty,
+            ..default_input()
+        }
+    }
+
+    #[test]
+    fn dirty_working_tree_error_lists_files() {
+        let git_provider =
+            MockGitProvider::new().with_dirty_files(vec![PathBuf::from("notes.txt")]);
+        let operation = dirty_tree_operation(git_provider);
+
+        let result =
+            operation.execute(Path::new("/any"), &dirty_tree_input(DirtyTreeAction::Abort));
+
+        let Err(OperationError::DirtyWorkingTree { files }) = result else {
+            panic!("expected dirty working tree error, got {result:?}");
+        };
+        assert_eq!(files, vec![PathBuf::from("notes.txt")]);
+    }
+
+    #[test]
+    fn stashes_dirty_changes_and_restores_them_after_release() {
+        let git_provider =
+            MockGitProvider::new().with_dirty_files(vec![PathBuf::from("notes.txt")]);
+        let operation = dirty_tree_operation(git_provider);
+
+        let result =
+            operation.execute(Path::new("/any"), &dirty_tree_input(DirtyTreeAction::Stash));
+
+        assert!(matches!(result, Ok(ReleaseOutcome::Executed(_))));
+        let git = operation.git_provider();
+        assert_eq!(git.stashes(), vec![RELEASE_STASH_MESSAGE.to_string()]);
+        assert_eq!(git.stash_pop_count(), 1);
+        assert!(!git.staged_files().contains(&PathBuf::from("notes.txt")));
+    }
+
+    #[test]
+    fn failed_release_restores_stash_once() {
+        let git_provider =
+            MockGitProvider::new().with_dirty_files(vec![PathBuf::from("notes.txt")]);
+        git_provider.set_fail_on_commit(true);
+        let operation = dirty_tree_operation(git_provider);
+
+        let result =
+            operation.execute(Path::new("/any"), &dirty_tree_input(DirtyTreeAction::Stash));
+
+        assert!(matches!(result, Err(OperationError::SagaFailed { .. })));
+        assert_eq!(operation.git_provider().stash_pop_count(), 1);
+    }
+
+    #[test]
+    fn includes_dirty_changes_in_release_commit() {
+        let git_provider =
+            MockGitProvider::new().with_dirty_files(vec![PathBuf::from("notes.txt")]);
+        let operation = dirty_tree_operation(git_provider);
+
+        let result = operation.execute(
+            Path::new("/any"),
+            &dirty_tree_input(DirtyTreeAction::Include),
+        );
+
+        assert!(matches!(result, Ok(ReleaseOutcome::Executed(_))));
+        let git = operation.git_provider();
+        assert!(git.staged_files().contains(&PathBuf::from("notes.txt")));
+        assert!(git.stashes().is_empty());
     }
 
     #[test]
@@ -1201,14 +2738,195 @@ mod tests {
         );
         let input = ReleaseInput {
             dry_run: false,
-            convert_inherited: false,
-            no_commit: true,
-            no_tags: true,
-            keep_changesets: true,
-            force: false,
-            per_package_config: HashMap::new(),
-            global_prerelease: None,
-            graduate_all: false,
+            convert_inherited: false,
+            no_commit: true,
+            no_tags: true,
+            keep_changesets: true,
+            force: false,
+            per_package_config: HashMap::new(),
+            global_prerelease: None,
+            graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn allows_dirty_tree_in_dry_run() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset);
+        let manifest_writer = MockManifestWriter::new();
+        let git_provider = MockGitProvider::new().is_clean(false);
+
+        let operation = ReleaseOperation::new(
+            project_provider,
+            changeset_reader,
+            manifest_writer,
+            MockChangelogWriter::new(),
+            git_provider,
+            MockReleaseStateIO::new(),
+        );
+        let input = ReleaseInput {
+            dry_run: true,
+            convert_inherited: false,
+            no_commit: false,
+            no_tags: false,
+            keep_changesets: false,
+            force: false,
+            per_package_config: HashMap::new(),
+            global_prerelease: None,
+            graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(result.is_ok());
+    }
+
+    fn make_stale_operation() -> ReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockManifestWriter,
+        MockChangelogWriter,
+        MockGitProvider,
+        MockReleaseStateIO,
+    > {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset);
+
+        ReleaseOperation::new(
+            project_provider,
+            changeset_reader,
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            MockGitProvider::new().with_up_to_date(false),
+            MockReleaseStateIO::new(),
+        )
+    }
+
+    #[test]
+    fn errors_when_branch_behind_remote() {
+        let operation = make_stale_operation();
+        let input = ReleaseInput {
+            dry_run: false,
+            no_commit: false,
+            ..default_input()
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(matches!(result, Err(OperationError::BranchBehindRemote)));
+    }
+
+    #[test]
+    fn allow_stale_overrides_remote_check() {
+        let operation = make_stale_operation();
+        let input = ReleaseInput {
+            dry_run: false,
+            no_commit: false,
+            allow_stale: true,
+            ..default_input()
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn no_commit_skips_remote_check() {
+        let operation = make_stale_operation();
+        let input = ReleaseInput {
+            dry_run: false,
+            ..default_input()
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(result.is_ok());
+    }
+
+    fn make_branch_guarded_operation(
+        current_branch: &str,
+    ) -> ReleaseOperation<
+        MockProjectProvider,
+        MockChangesetReader,
+        MockManifestWriter,
+        MockChangelogWriter,
+        MockGitProvider,
+        MockReleaseStateIO,
+    > {
+        use changeset_project::{GitConfig, RootChangesetConfig};
+
+        let config = RootChangesetConfig::default()
+            .with_git_config(GitConfig::default().with_release_branch("main"));
+        let project_provider =
+            MockProjectProvider::single_package("my-crate", "1.0.0").with_root_config(config);
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset);
+
+        ReleaseOperation::new(
+            project_provider,
+            changeset_reader,
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            MockGitProvider::new().with_branch(current_branch),
+            MockReleaseStateIO::new(),
+        )
+    }
+
+    #[test]
+    fn errors_when_not_on_release_branch() {
+        let operation = make_branch_guarded_operation("feature/foo");
+        let input = ReleaseInput {
+            dry_run: false,
+            ..default_input()
+        };
+
+        let result = operation.execute(Path::new("/any"), &input);
+
+        assert!(matches!(
+            result,
+            Err(OperationError::NotOnReleaseBranch { ref current, ref expected })
+                if current == "feature/foo" && expected == "main"
+        ));
+    }
+
+    #[test]
+    fn allows_release_on_release_branch() {
+        let operation = make_branch_guarded_operation("main");
+        let input = ReleaseInput {
+            dry_run: false,
+            ..default_input()
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -1217,32 +2935,12 @@ mod tests {
     }
 
     #[test]
-    fn allows_dirty_tree_in_dry_run() {
-        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
-        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix");
-        let changeset_reader = MockChangesetReader::new()
-            .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset);
-        let manifest_writer = MockManifestWriter::new();
-        let git_provider = MockGitProvider::new().is_clean(false);
-
-        let operation = ReleaseOperation::new(
-            project_provider,
-            changeset_reader,
-            manifest_writer,
-            MockChangelogWriter::new(),
-            git_provider,
-            MockReleaseStateIO::new(),
-        );
+    fn allow_branch_overrides_release_branch_check() {
+        let operation = make_branch_guarded_operation("feature/foo");
         let input = ReleaseInput {
-            dry_run: true,
-            convert_inherited: false,
-            no_commit: false,
-            no_tags: false,
-            keep_changesets: false,
-            force: false,
-            per_package_config: HashMap::new(),
-            global_prerelease: None,
-            graduate_all: false,
+            dry_run: false,
+            allow_branch: true,
+            ..default_input()
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -1250,6 +2948,15 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn dry_run_skips_release_branch_check() {
+        let operation = make_branch_guarded_operation("feature/foo");
+
+        let result = operation.execute(Path::new("/any"), &default_input());
+
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn commit_message_uses_template() {
         use std::sync::Arc;
@@ -1279,6 +2986,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1327,6 +3045,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1344,6 +3073,76 @@ mod tests {
         assert!(tag_names.contains(&&"crate-b@v2.0.1".to_string()));
     }
 
+    #[test]
+    fn package_can_opt_out_of_tags() {
+        use std::sync::Arc;
+
+        let project_provider =
+            MockProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")])
+                .with_package_config(
+                    "crate-b",
+                    PackageChangesetConfig::default().with_tags(false),
+                );
+        let changeset_reader = MockChangesetReader::new().with_changesets(vec![
+            (
+                PathBuf::from(".changeset/changesets/fix-a.md"),
+                make_changeset("crate-a", BumpType::Patch, "Fix A"),
+            ),
+            (
+                PathBuf::from(".changeset/changesets/fix-b.md"),
+                make_changeset("crate-b", BumpType::Patch, "Fix B"),
+            ),
+        ]);
+        let git_provider = Arc::new(MockGitProvider::new());
+
+        let operation = ReleaseOperation::new(
+            project_provider,
+            changeset_reader,
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            Arc::clone(&git_provider),
+            MockReleaseStateIO::new(),
+        );
+        let input = ReleaseInput {
+            dry_run: false,
+            convert_inherited: false,
+            no_commit: false,
+            no_tags: false,
+            keep_changesets: true,
+            force: false,
+            per_package_config: HashMap::new(),
+            global_prerelease: None,
+            graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
+        };
+
+        let ReleaseOutcome::Executed(output) = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed")
+        else {
+            panic!("expected Executed outcome");
+        };
+
+        let git_result = output.git_result.expect("should have git result");
+        let tag_names: Vec<_> = git_result.tags_created.iter().map(|t| &t.name).collect();
+        assert_eq!(tag_names, vec!["crate-a@v1.0.1"]);
+        assert_eq!(
+            output.planned_releases.len(),
+            2,
+            "crate-b is still released"
+        );
+    }
+
     #[test]
     fn no_tags_skips_tag_creation() {
         use std::sync::Arc;
@@ -1373,6 +3172,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1416,6 +3226,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1462,6 +3283,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1508,6 +3340,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1556,6 +3399,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let _ = operation
@@ -1606,6 +3460,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -1657,6 +3522,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: Some(PrereleaseSpec::Alpha),
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
@@ -1693,6 +3569,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: Some(PrereleaseSpec::Alpha),
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -1720,6 +3607,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: Some(PrereleaseSpec::Alpha),
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
@@ -1764,6 +3662,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
@@ -1815,6 +3724,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
@@ -1873,6 +3793,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
@@ -1930,6 +3861,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: Some(PrereleaseSpec::Beta),
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation
@@ -1978,6 +3920,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: true,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2044,6 +3997,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2106,6 +4070,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2157,6 +4132,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: Some(PrereleaseSpec::Beta),
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2208,6 +4194,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2231,6 +4228,98 @@ mod tests {
         );
     }
 
+    fn release_with_graduation_policy(
+        bump: Option<BumpType>,
+        policy: changeset_project::GraduationPolicy,
+    ) -> (ReleaseOutput, Arc<MockReleaseStateIO>) {
+        let project_provider = MockProjectProvider::single_package("my-crate", "0.5.0");
+        let mut changeset_reader = MockChangesetReader::new();
+        if let Some(bump) = bump {
+            changeset_reader = changeset_reader.with_changeset(
+                PathBuf::from(".changeset/changesets/change.md"),
+                make_changeset("my-crate", bump, "Change"),
+            );
+        }
+
+        let mut graduation_state = GraduationState::new();
+        graduation_state.set_policy("my-crate".to_string(), policy);
+        let release_state_io =
+            Arc::new(MockReleaseStateIO::new().with_graduation_state(graduation_state));
+
+        let operation = ReleaseOperation::new(
+            project_provider,
+            changeset_reader,
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            MockGitProvider::new(),
+            Arc::clone(&release_state_io),
+        );
+        let input = ReleaseInput {
+            dry_run: false,
+            ..default_input()
+        };
+
+        let ReleaseOutcome::Executed(output) = operation
+            .execute(Path::new("/any"), &input)
+            .expect("release should succeed")
+        else {
+            panic!("expected Executed outcome");
+        };
+        (output, release_state_io)
+    }
+
+    #[test]
+    fn graduation_policy_on_major_graduates_with_breaking_change() {
+        let policy = changeset_project::GraduationPolicy::new().with_graduate_on(BumpType::Major);
+
+        let (output, release_state_io) =
+            release_with_graduation_policy(Some(BumpType::Major), policy);
+
+        assert_eq!(output.planned_releases[0].new_version.to_string(), "1.0.0");
+        assert!(
+            release_state_io
+                .get_graduation_state()
+                .is_none_or(|state| !state.contains("my-crate")),
+            "graduated package should be removed from graduation state"
+        );
+    }
+
+    #[test]
+    fn graduation_policy_on_major_waits_for_breaking_change() {
+        let policy = changeset_project::GraduationPolicy::new().with_graduate_on(BumpType::Major);
+
+        let (output, release_state_io) =
+            release_with_graduation_policy(Some(BumpType::Minor), policy);
+
+        assert_eq!(output.planned_releases[0].new_version.major, 0);
+        assert!(
+            release_state_io
+                .get_graduation_state()
+                .is_some_and(|state| state.policy("my-crate").is_some()),
+            "policy should stay queued until it is satisfied"
+        );
+    }
+
+    #[test]
+    fn graduation_policy_after_past_date_graduates_without_changesets() {
+        let policy = changeset_project::GraduationPolicy::new()
+            .with_graduate_after(NaiveDate::from_ymd_opt(2000, 1, 1).expect("valid date"));
+
+        let (output, _) = release_with_graduation_policy(None, policy);
+
+        assert_eq!(output.planned_releases[0].new_version.to_string(), "1.0.0");
+    }
+
+    #[test]
+    fn graduation_policy_before_date_is_not_applied() {
+        let policy = changeset_project::GraduationPolicy::new()
+            .with_graduate_after(NaiveDate::from_ymd_opt(9999, 1, 1).expect("valid date"));
+
+        let (output, _) = release_with_graduation_policy(Some(BumpType::Major), policy);
+
+        assert_eq!(output.planned_releases[0].new_version.major, 0);
+    }
+
     #[test]
     fn graduate_all_flag_graduates_zero_versions() {
         let project_provider = MockProjectProvider::single_package("my-crate", "0.5.0");
@@ -2250,6 +4339,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: true,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2267,6 +4367,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cli_prerelease_tag_is_recorded_in_state() {
+        use changeset_project::PrereleaseState;
+        use std::sync::Arc;
+
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset_path = PathBuf::from(".changeset/changesets/feature.md");
+        let changeset = make_changeset("my-crate", BumpType::Minor, "Add feature");
+        let changeset_reader =
+            Arc::new(MockChangesetReader::new().with_changeset(changeset_path, changeset));
+        let release_state_io =
+            Arc::new(MockReleaseStateIO::new().with_prerelease_state(PrereleaseState::new()));
+
+        let operation = ReleaseOperation::new(
+            project_provider,
+            Arc::clone(&changeset_reader),
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            MockGitProvider::new(),
+            Arc::clone(&release_state_io),
+        );
+        let input = ReleaseInput {
+            dry_run: false,
+            convert_inherited: false,
+            no_commit: true,
+            no_tags: true,
+            keep_changesets: true,
+            force: false,
+            per_package_config: HashMap::new(),
+            global_prerelease: Some(PrereleaseSpec::Beta),
+            graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
+        };
+
+        operation
+            .execute(Path::new("/any"), &input)
+            .expect("release should succeed");
+
+        let state = release_state_io
+            .get_prerelease_state()
+            .expect("pre-release state should be saved");
+        assert_eq!(state.get("my-crate"), Some("beta"));
+        assert_eq!(state.number("my-crate"), Some(1));
+    }
+
     #[test]
     fn prerelease_state_saved_after_normal_release() {
         use changeset_project::PrereleaseState;
@@ -2302,6 +4457,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2327,6 +4493,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prerelease_numbers_continue_per_package_from_state() {
+        use changeset_project::PrereleaseState;
+        use std::sync::Arc;
+
+        let project_provider = MockProjectProvider::workspace(vec![
+            ("crate-a", "1.0.1-alpha.1"),
+            ("crate-b", "2.0.0-alpha.3"),
+        ]);
+        let changeset_reader = Arc::new(
+            MockChangesetReader::new()
+                .with_changeset(
+                    PathBuf::from(".changeset/changesets/fix-a.md"),
+                    make_changeset("crate-a", BumpType::Patch, "Fix A"),
+                )
+                .with_changeset(
+                    PathBuf::from(".changeset/changesets/fix-b.md"),
+                    make_changeset("crate-b", BumpType::Patch, "Fix B"),
+                ),
+        );
+
+        let mut prerelease_state = PrereleaseState::new();
+        prerelease_state.insert("crate-a".to_string(), "alpha".to_string());
+        prerelease_state.insert("crate-b".to_string(), "alpha".to_string());
+        prerelease_state.set_number("crate-a".to_string(), 4);
+        prerelease_state.set_number("crate-b".to_string(), 3);
+        let release_state_io =
+            Arc::new(MockReleaseStateIO::new().with_prerelease_state(prerelease_state));
+
+        let operation = ReleaseOperation::new(
+            project_provider,
+            Arc::clone(&changeset_reader),
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            MockGitProvider::new(),
+            Arc::clone(&release_state_io),
+        );
+        let input = ReleaseInput {
+            dry_run: false,
+            convert_inherited: false,
+            no_commit: true,
+            no_tags: true,
+            keep_changesets: true,
+            force: false,
+            per_package_config: HashMap::new(),
+            global_prerelease: None,
+            graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
+        };
+
+        let ReleaseOutcome::Executed(output) = operation
+            .execute(Path::new("/any"), &input)
+            .expect("release should succeed")
+        else {
+            panic!("expected Executed outcome");
+        };
+
+        let version_of = |name: &str| {
+            output
+                .planned_releases
+                .iter()
+                .find(|r| r.name == name)
+                .map(|r| r.new_version.to_string())
+        };
+        assert_eq!(version_of("crate-a").as_deref(), Some("1.0.1-alpha.5"));
+        assert_eq!(version_of("crate-b").as_deref(), Some("2.0.0-alpha.4"));
+
+        let updated_state = release_state_io
+            .get_prerelease_state()
+            .expect("prerelease state should remain");
+        assert_eq!(updated_state.number("crate-a"), Some(5));
+        assert_eq!(updated_state.number("crate-b"), Some(4));
+    }
+
     #[test]
     fn prerelease_graduation_removes_package_from_state_if_present() {
         use std::sync::Arc;
@@ -2361,6 +4611,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let ReleaseOutcome::Executed(output) = operation
@@ -2420,6 +4681,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -2478,6 +4750,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
@@ -2487,6 +4770,44 @@ mod tests {
         assert_eq!(tags.len(), 2, "should create tags for both packages");
     }
 
+    #[test]
+    fn completed_tag_step_is_resumed_instead_of_repeated() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset = make_changeset("my-crate", BumpType::Patch, "Fix a bug");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/fix.md"), changeset);
+        let git_provider = MockGitProvider::new();
+        git_provider.set_fail_on_create_tag(true);
+
+        let operation = ReleaseOperation::new(
+            project_provider,
+            changeset_reader,
+            MockManifestWriter::new(),
+            MockChangelogWriter::new(),
+            git_provider,
+            MockReleaseStateIO::new(),
+        )
+        .with_completed_steps(["create-tags:my-crate@1.0.1"].into_iter().collect());
+        let input = ReleaseInput {
+            dry_run: false,
+            no_commit: false,
+            no_tags: false,
+            ..default_input()
+        };
+
+        let ReleaseOutcome::Executed(output) = operation
+            .execute(Path::new("/any"), &input)
+            .expect("execute failed")
+        else {
+            panic!("expected Executed outcome");
+        };
+
+        let git_result = output.git_result.expect("should have git result");
+        assert_eq!(git_result.tags_created.len(), 1);
+        assert_eq!(git_result.tags_created[0].name, "v1.0.1");
+        assert!(operation.git_provider().tags_created().is_empty());
+    }
+
     #[test]
     fn saga_rollback_resets_commit_when_tag_creation_fails() {
         let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
@@ -2515,6 +4836,17 @@ mod tests {
             per_package_config: HashMap::new(),
             global_prerelease: None,
             graduate_all: false,
+            allow_branch: false,
+            allow_stale: false,
+            include_confidential: false,
+            diff: false,
+            step_control: StepControl::default(),
+            stamps: Vec::new(),
+            on_dirty: DirtyTreeAction::Abort,
+            empty_release_bump: None,
+            tag_only: false,
+            packages: Vec::new(),
+            channel: None,
         };
 
         let result = operation.execute(Path::new("/any"), &input);
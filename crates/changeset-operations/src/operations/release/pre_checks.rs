@@ -0,0 +1,140 @@
+//! Runs the configured pre-release checks before the release writes any file.
+
+use std::path::Path;
+
+use tracing::debug;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{CommandOutput, CommandRunner};
+use crate::types::PackageVersion;
+
+/// Placeholder in a pre-check command that is replaced by each released package.
+const PACKAGE_PLACEHOLDER: &str = "{package}";
+
+/// Runs `commands` in order from `project_root`; the first failing one aborts
+/// the release with its captured output.
+pub(super) fn run_pre_checks(
+    command_runner: &dyn CommandRunner,
+    project_root: &Path,
+    commands: &[String],
+    planned_releases: &[PackageVersion],
+) -> Result<()> {
+    for command in expanded_commands(commands, planned_releases) {
+        debug!(%command, "running pre-release check");
+        let output = command_runner.run(project_root, &command)?;
+        if !output.success {
+            return Err(pre_check_failure(command, &output));
+        }
+    }
+    Ok(())
+}
+
+/// Expands commands with a `{package}` placeholder once per released package.
+fn expanded_commands(commands: &[String], planned_releases: &[PackageVersion]) -> Vec<String> {
+    commands
+        .iter()
+        .flat_map(|command| {
+            if command.contains(PACKAGE_PLACEHOLDER) {
+                planned_releases
+                    .iter()
+                    .map(|release| command.replace(PACKAGE_PLACEHOLDER, &release.name))
+                    .collect()
+            } else {
+                vec![command.clone()]
+            }
+        })
+        .collect()
+}
+
+fn pre_check_failure(command: String, output: &CommandOutput) -> OperationError {
+    OperationError::PreCheckFailed {
+        command,
+        status: output.status(),
+        output: output.captured(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use changeset_core::BumpType;
+
+    use super::*;
+    use crate::mocks::MockCommandRunner;
+
+    fn releases() -> Vec<PackageVersion> {
+        vec![PackageVersion {
+            name: "pkg-a".to_string(),
+            current_version: "1.0.0".parse().expect("valid version"),
+            new_version: "1.0.1".parse().expect("valid version"),
+            bump_type: BumpType::Patch,
+        }]
+    }
+
+    fn commands() -> Vec<String> {
+        vec![
+            "cargo check --workspace".to_string(),
+            "cargo test -p {package}".to_string(),
+        ]
+    }
+
+    #[test]
+    fn expands_package_placeholder() -> anyhow::Result<()> {
+        let runner = MockCommandRunner::new();
+
+        run_pre_checks(
+            &runner,
+            Path::new("/mock/project"),
+            &commands(),
+            &releases(),
+        )?;
+
+        let commands: Vec<_> = runner.runs().into_iter().map(|(_, c)| c).collect();
+        assert_eq!(
+            commands,
+            vec!["cargo check --workspace", "cargo test -p pkg-a"]
+        );
+        assert!(
+            runner
+                .runs()
+                .iter()
+                .all(|(dir, _)| dir == &PathBuf::from("/mock/project"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn stops_at_first_failure_with_output() {
+        let runner = MockCommandRunner::new().with_failure(
+            "cargo check --workspace",
+            CommandOutput {
+                code: Some(101),
+                stderr: "error[E0425]: cannot find value `x`\n".to_string(),
+                ..CommandOutput::default()
+            },
+        );
+
+        let result = run_pre_checks(
+            &runner,
+            Path::new("/mock/project"),
+            &commands(),
+            &releases(),
+        );
+
+        let Err(OperationError::PreCheckFailed {
+            command,
+            status,
+            output,
+        }) = result
+        else {
+            panic!("expected PreCheckFailed, got {result:?}");
+        };
+        assert_eq!(command, "cargo check --workspace");
+        assert_eq!(status, "exit code 101");
+        assert_eq!(output, "error[E0425]: cannot find value `x`");
+        assert_eq!(runner.runs().len(), 1);
+    }
+}
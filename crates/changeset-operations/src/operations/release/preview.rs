@@ -0,0 +1,380 @@
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use changeset_changelog::{
+    ArchivePolicy, Changelog, ChangelogError, JsonChangelog, RepositoryInfo, VersionRelease,
+    json_changelog_path,
+};
+use changeset_core::unified_diff;
+use changeset_project::PackageAdapters;
+use indexmap::IndexMap;
+use semver::Version;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::non_cargo_adapter;
+use crate::traits::{
+    ChangelogArchive, ChangelogWriteResult, ChangelogWriter, PlannedChangelogWrite,
+};
+
+/// A file a release would create, modify, or delete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// Path relative to the project root.
+    pub path: PathBuf,
+    /// Content before the release; `None` if the file would be created.
+    pub before: Option<String>,
+    /// Content after the release; `None` if the file would be deleted.
+    pub after: Option<String>,
+}
+
+impl FileDiff {
+    /// Renders the change as a unified diff with `a/` and `b/` path labels.
+    #[must_use]
+    pub fn unified(&self) -> String {
+        let path = self.path.display();
+        let old_label = self
+            .before
+            .as_ref()
+            .map_or_else(|| "/dev/null".to_string(), |_| format!("a/{path}"));
+        let new_label = self
+            .after
+            .as_ref()
+            .map_or_else(|| "/dev/null".to_string(), |_| format!("b/{path}"));
+
+        unified_diff(
+            self.before.as_deref().unwrap_or_default(),
+            self.after.as_deref().unwrap_or_default(),
+            &old_label,
+            &new_label,
+        )
+    }
+}
+
+#[derive(Debug)]
+struct PreviewFile {
+    before: Option<String>,
+    after: Option<String>,
+}
+
+/// In-memory copies of the files a release writes.
+///
+/// Files are read from disk the first time they are touched; every later edit
+/// applies to the in-memory copy, so nothing on disk changes.
+#[derive(Debug, Default)]
+pub(super) struct PreviewFiles {
+    files: Mutex<IndexMap<PathBuf, PreviewFile>>,
+    adapters: PackageAdapters,
+}
+
+impl PreviewFiles {
+    /// Previews writes to manifests other than `Cargo.toml` with `adapters`.
+    pub(super) fn with_adapters(adapters: PackageAdapters) -> Self {
+        Self {
+            adapters,
+            ..Self::default()
+        }
+    }
+
+    fn update(
+        &self,
+        path: &Path,
+        edit: impl FnOnce(Option<&str>) -> Result<Option<String>>,
+    ) -> Result<()> {
+        let mut files = self.files.lock().expect("lock poisoned");
+
+        if !files.contains_key(path) {
+            let before = match std::fs::read_to_string(path) {
+                Ok(content) => Some(content),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(source) => {
+                    return Err(OperationError::PreviewRead {
+                        path: path.to_path_buf(),
+                        source,
+                    });
+                }
+            };
+            files.insert(
+                path.to_path_buf(),
+                PreviewFile {
+                    after: before.clone(),
+                    before,
+                },
+            );
+        }
+
+        if let Some(file) = files.get_mut(path) {
+            file.after = edit(file.after.as_deref())?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn write_version(&self, manifest_path: &Path, version: &Version) -> Result<()> {
+        self.update(manifest_path, |content| {
+            let content = content.unwrap_or_default();
+            if let Some(adapter) = non_cargo_adapter(&self.adapters, manifest_path) {
+                return Ok(Some(adapter.write_version(
+                    manifest_path,
+                    content,
+                    version,
+                )?));
+            }
+            Ok(Some(changeset_manifest::render_version(
+                manifest_path,
+                content,
+                version,
+            )?))
+        })
+    }
+
+    pub(super) fn update_dependency_version(
+        &self,
+        manifest_path: &Path,
+        dependency_name: &str,
+        version: &Version,
+        skip_optional: bool,
+    ) -> Result<()> {
+        self.update(manifest_path, |content| {
+            let Some(content) = content else {
+                return Ok(None);
+            };
+            let updated = changeset_manifest::render_dependency_version(
+                manifest_path,
+                content,
+                dependency_name,
+                version,
+                skip_optional,
+            )?;
+            Ok(Some(updated.unwrap_or_else(|| content.to_string())))
+        })
+    }
+
+    pub(super) fn remove_workspace_version(&self, manifest_path: &Path) -> Result<()> {
+        self.update(manifest_path, |content| {
+            let content = content.unwrap_or_default();
+            Ok(Some(changeset_manifest::render_without_workspace_version(
+                manifest_path,
+                content,
+            )?))
+        })
+    }
+
+    pub(super) fn stamp(&self, path: &Path, content: &str) -> Result<()> {
+        self.update(path, |_| Ok(Some(content.to_string())))
+    }
+
+    pub(super) fn delete(&self, path: &Path) -> Result<()> {
+        self.update(path, |_| Ok(None))
+    }
+
+    /// Files whose content would change, with paths relative to `project_root`.
+    pub(super) fn into_diffs(self, project_root: &Path) -> Vec<FileDiff> {
+        self.files
+            .into_inner()
+            .expect("lock poisoned")
+            .into_iter()
+            .filter(|(_, file)| file.before != file.after)
+            .map(|(path, file)| FileDiff {
+                path: path
+                    .strip_prefix(project_root)
+                    .map_or_else(|_| path.clone(), Path::to_path_buf),
+                before: file.before,
+                after: file.after,
+            })
+            .collect()
+    }
+}
+
+impl ChangelogWriter for PreviewFiles {
+    fn write_release(
+        &self,
+        changelog_path: &Path,
+        release: &VersionRelease,
+        repo_info: Option<&RepositoryInfo>,
+        previous_version: Option<&str>,
+    ) -> Result<ChangelogWriteResult> {
+        let mut created = false;
+        self.update(changelog_path, |content| {
+            created = content.is_none();
+            let mut changelog =
+                content.map_or_else(|| Changelog::for_release(release), Changelog::parse);
+            changelog.add_release(release, repo_info, previous_version);
+            Ok(Some(changelog.render()))
+        })?;
+
+        if release.json {
+            let json_path = json_changelog_path(changelog_path);
+            self.update(&json_path, |content| {
+                let mut changelog = match content {
+                    Some(content) => parse_json(&json_path, content)?,
+                    None => JsonChangelog::default(),
+                };
+                changelog.add_release(release);
+                Ok(Some(changelog.render()))
+            })?;
+        }
+
+        Ok(ChangelogWriteResult {
+            path: changelog_path.to_path_buf(),
+            created,
+        })
+    }
+
+    fn write_releases(
+        &self,
+        writes: &[PlannedChangelogWrite],
+    ) -> Result<Vec<ChangelogWriteResult>> {
+        writes
+            .iter()
+            .map(|write| {
+                self.write_release(
+                    &write.path,
+                    &write.release,
+                    write.repo_info.as_ref(),
+                    write.previous_version.as_deref(),
+                )
+            })
+            .collect()
+    }
+
+    fn extend_release(&self, changelog_path: &Path, release: &VersionRelease) -> Result<()> {
+        self.update(changelog_path, |content| {
+            let mut changelog = Changelog::parse(content.unwrap_or_default());
+            if !changelog.extend_release(release) {
+                return Err(OperationError::ReleaseSectionNotFound {
+                    path: changelog_path.to_path_buf(),
+                    version: release.version.clone(),
+                });
+            }
+            Ok(Some(changelog.render()))
+        })?;
+
+        if release.json {
+            let json_path = json_changelog_path(changelog_path);
+            self.update(&json_path, |content| {
+                let Some(content) = content else {
+                    return Ok(None);
+                };
+                let mut changelog = parse_json(&json_path, content)?;
+                if !changelog.extend_release(release) {
+                    return Err(OperationError::ReleaseSectionNotFound {
+                        path: json_path.clone(),
+                        version: release.version.clone(),
+                    });
+                }
+                Ok(Some(changelog.render()))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn rotate_changelog(
+        &self,
+        changelog_path: &Path,
+        policy: &ArchivePolicy,
+    ) -> Result<Vec<ChangelogArchive>> {
+        let mut moved = Vec::new();
+        self.update(changelog_path, |content| {
+            let Some(content) = content else {
+                return Ok(None);
+            };
+            let mut changelog = Changelog::parse(content);
+            moved = changelog.take_archived_releases(policy);
+            Ok(Some(changelog.render()))
+        })?;
+
+        let directory = changelog_path.parent().unwrap_or(Path::new(""));
+        let mut archives = Vec::with_capacity(moved.len());
+        for releases in &moved {
+            let path = directory.join(&releases.file_name);
+            let mut original_content = None;
+            self.update(&path, |content| {
+                original_content = content.map(str::to_string);
+                Ok(Some(releases.merge_into(content)))
+            })?;
+            archives.push(ChangelogArchive {
+                path,
+                original_content,
+            });
+        }
+        Ok(archives)
+    }
+
+    fn changelog_exists(&self, path: &Path) -> bool {
+        let files = self.files.lock().expect("lock poisoned");
+        files
+            .get(path)
+            .map_or_else(|| path.exists(), |file| file.after.is_some())
+    }
+
+    fn restore_changelog(&self, path: &Path, content: &str) -> Result<()> {
+        self.update(path, |_| Ok(Some(content.to_string())))
+    }
+
+    fn delete_changelog(&self, path: &Path) -> Result<()> {
+        self.delete(path)
+    }
+}
+
+fn parse_json(path: &Path, content: &str) -> Result<JsonChangelog> {
+    JsonChangelog::parse(content).map_err(|source| {
+        ChangelogError::InvalidJson {
+            path: path.to_path_buf(),
+            source,
+        }
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn edits_stay_in_memory() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let manifest = dir.path().join("Cargo.toml");
+        let original = "[package]\nname = \"a\"\nversion = \"1.0.0\"\n";
+        std::fs::write(&manifest, original)?;
+        let changeset = dir.path().join(".changeset/changesets/fix.md");
+
+        let preview = PreviewFiles::default();
+        preview.write_version(&manifest, &Version::new(1, 1, 0))?;
+        preview.update_dependency_version(&manifest, "a", &Version::new(1, 1, 0), false)?;
+        preview.delete(&changeset)?;
+
+        assert_eq!(std::fs::read_to_string(&manifest)?, original);
+
+        let diffs = preview.into_diffs(dir.path());
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, PathBuf::from("Cargo.toml"));
+        assert_eq!(diffs[0].before.as_deref(), Some(original));
+        assert!(
+            diffs[0]
+                .unified()
+                .contains("--- a/Cargo.toml\n+++ b/Cargo.toml\n")
+        );
+        assert!(
+            diffs[0]
+                .unified()
+                .contains("-version = \"1.0.0\"\n+version = \"1.1.0\"\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn deleted_file_diffs_against_dev_null() {
+        let diff = FileDiff {
+            path: PathBuf::from(".changeset/changesets/fix.md"),
+            before: Some("---\na: patch\n---\n\nFix\n".to_string()),
+            after: None,
+        };
+
+        let rendered = diff.unified();
+
+        assert!(rendered.starts_with("--- a/.changeset/changesets/fix.md\n+++ /dev/null\n"));
+        assert!(rendered.contains("@@ -1,5 +0,0 @@\n"));
+    }
+}
@@ -0,0 +1,123 @@
+use changeset_core::BumpType;
+use serde::{Deserialize, Serialize};
+
+use super::operation::ReleaseOutput;
+use crate::types::PackageProvenance;
+
+/// Default file name for the release manifest, relative to the changeset directory.
+pub const RELEASE_MANIFEST_FILENAME: &str = "release-manifest.json";
+
+/// What a release produced, written by `release --manifest` so that
+/// publishing and notifications can run in a later CI job without
+/// recomputing the release.
+///
+/// Versions are kept as strings so that the manifest round-trips through JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseManifest {
+    pub packages: Vec<ReleasedPackage>,
+    pub tags: Vec<String>,
+    /// SHA of the release commit, or the last one of a per-package release.
+    pub commit: Option<String>,
+    /// Changelog entries added by the release, one per changelog.
+    #[serde(default)]
+    pub changelog: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasedPackage {
+    pub name: String,
+    pub previous_version: String,
+    pub version: String,
+    pub bump: BumpType,
+    /// What decided the new version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<PackageProvenance>,
+}
+
+impl ReleaseManifest {
+    #[must_use]
+    pub fn from_output(output: &ReleaseOutput) -> Self {
+        let git_result = output.git_result.as_ref();
+        Self {
+            packages: output
+                .planned_releases
+                .iter()
+                .map(|release| ReleasedPackage {
+                    name: release.name.clone(),
+                    previous_version: release.current_version.to_string(),
+                    version: release.new_version.to_string(),
+                    bump: release.bump_type,
+                    provenance: output
+                        .provenance
+                        .iter()
+                        .find(|provenance| provenance.package == release.name)
+                        .cloned(),
+                })
+                .collect(),
+            tags: git_result
+                .iter()
+                .flat_map(|git| git.tags_created.iter().map(|tag| tag.name.clone()))
+                .collect(),
+            commit: git_result
+                .and_then(|git| git.commit.as_ref())
+                .map(|commit| commit.sha.clone()),
+            changelog: output
+                .changelog_updates
+                .iter()
+                .filter(|update| !update.excerpt.is_empty())
+                .map(|update| update.excerpt.clone())
+                .collect(),
+        }
+    }
+
+    /// The released version of `package`, if the release includes it.
+    #[must_use]
+    pub fn version_of(&self, package: &str) -> Option<&str> {
+        self.packages
+            .iter()
+            .find(|released| released.name == package)
+            .map(|released| released.version.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BumpContribution, BumpSource};
+
+    #[test]
+    fn round_trips_through_json() -> anyhow::Result<()> {
+        let manifest = ReleaseManifest {
+            packages: vec![ReleasedPackage {
+                name: "my-crate".to_string(),
+                previous_version: "1.0.0".to_string(),
+                version: "1.1.0".to_string(),
+                bump: BumpType::Minor,
+                provenance: Some(PackageProvenance {
+                    package: "my-crate".to_string(),
+                    contributions: vec![BumpContribution {
+                        source: BumpSource::Changeset(0),
+                        bump_type: BumpType::Minor,
+                    }],
+                    zero_version: None,
+                    prerelease: None,
+                }),
+            }],
+            tags: vec!["v1.1.0".to_string()],
+            commit: Some("abc1234".to_string()),
+            changelog: vec!["### Added\n\n- Things".to_string()],
+        };
+
+        let json = serde_json::to_string(&manifest)?;
+
+        assert!(json.contains(r#""previousVersion":"1.0.0""#));
+        assert!(json.contains(r#""bump":"minor""#));
+        assert!(json.contains(r#""provenance":{"package":"my-crate","contributions""#));
+        assert!(json.contains(r#""bumpType":"minor""#));
+        assert_eq!(serde_json::from_str::<ReleaseManifest>(&json)?, manifest);
+        assert_eq!(manifest.version_of("my-crate"), Some("1.1.0"));
+        Ok(())
+    }
+}
@@ -1,13 +1,15 @@
 use std::path::PathBuf;
 
-use changeset_project::{GraduationState, PrereleaseState};
+use changeset_project::{CommitStrategy, GraduationState, PrereleaseState};
 use indexmap::IndexMap;
 use semver::Version;
 
 use super::steps::{
-    ChangelogFileState, ChangesetFileState, GraduationStateUpdate, PrereleaseStateUpdate,
+    ChangelogFileState, ChangesetFileState, ConsumedStateFile, GraduationStateUpdate,
+    PrereleaseStateUpdate, StampedFile,
 };
-use super::{ChangelogUpdate, CommitResult, GitOperationResult, TagResult};
+use super::{ChangelogUpdate, CommitResult, GitOperationResult, ReleaseCommit, TagResult};
+use crate::tags::TagNaming;
 use crate::types::PackageVersion;
 
 #[derive(Debug, Clone, Default)]
@@ -23,11 +25,17 @@ pub struct SagaReleaseOptions {
 #[derive(Debug, Clone, Default)]
 pub struct ReleaseSagaData {
     pub changeset_dir: PathBuf,
+    /// Directories whose consumed changesets are cleared on graduation; the
+    /// primary changeset directory followed by the owned ones.
+    pub changeset_dirs: Vec<PathBuf>,
     pub root_manifest_path: PathBuf,
     pub inherited_packages: Vec<String>,
+    /// Leave requirements on optional dependencies untouched when rewriting versions.
+    pub skip_optional_dependencies: bool,
 
     pub planned_releases: Vec<PackageVersion>,
-    pub package_paths: IndexMap<String, PathBuf>,
+    /// Manifest holding the version of each package, by package name.
+    pub package_manifests: IndexMap<String, PathBuf>,
     pub changelog_updates: Vec<ChangelogUpdate>,
 
     pub is_prerelease_release: bool,
@@ -36,6 +44,10 @@ pub struct ReleaseSagaData {
     pub should_commit: bool,
     pub should_create_tags: bool,
     pub should_delete_changesets: bool,
+    /// Packages that get no tag even when tags are created.
+    pub untagged_packages: Vec<String>,
+    /// Put in front of the tags of packages released on a namespaced channel.
+    pub tag_namespaces: IndexMap<String, String>,
 
     pub prerelease_state_update: Option<PrereleaseStateUpdate>,
     pub graduation_state_update: Option<GraduationStateUpdate>,
@@ -47,10 +59,14 @@ pub struct ReleaseSagaData {
     pub workspace_version_removed: bool,
     pub original_workspace_version: Option<Version>,
 
+    pub stamped_files: Vec<StampedFile>,
+
     pub staged_files: Vec<PathBuf>,
     pub files_were_staged: bool,
 
+    pub commit_strategy: CommitStrategy,
     pub commit_result: Option<CommitResult>,
+    pub release_commits: Vec<ReleaseCommit>,
 
     pub tags_created: Vec<TagResult>,
 
@@ -58,9 +74,16 @@ pub struct ReleaseSagaData {
     pub changesets_consumed: bool,
     pub consumed_cleared: bool,
     pub consumed_files_cleared: Vec<ChangesetFileState>,
+    /// Written by the consumed-state storage instead of the changeset files.
+    pub consumed_state_file: Option<ConsumedStateFile>,
 
     pub changelog_backups: Vec<ChangelogFileState>,
     pub changelogs_written: bool,
+
+    /// Local changes were stashed before the release and are restored on rollback.
+    pub changes_stashed: bool,
+    /// Dirty files, relative to the repository root, added to the release commit.
+    pub included_files: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -84,7 +107,7 @@ impl ReleaseSagaData {
         changeset_dir: PathBuf,
         root_manifest_path: PathBuf,
         planned_releases: Vec<PackageVersion>,
-        package_paths: IndexMap<String, PathBuf>,
+        package_manifests: IndexMap<String, PathBuf>,
         changelog_updates: Vec<ChangelogUpdate>,
         changeset_files: Vec<PathBuf>,
     ) -> Self {
@@ -98,10 +121,11 @@ impl ReleaseSagaData {
             .collect();
 
         Self {
+            changeset_dirs: vec![changeset_dir.clone()],
             changeset_dir,
             root_manifest_path,
             planned_releases,
-            package_paths,
+            package_manifests,
             changelog_updates,
             changeset_files: changeset_file_states,
             ..Default::default()
@@ -118,26 +142,67 @@ impl ReleaseSagaData {
         self
     }
 
+    pub fn with_changeset_dirs(mut self, changeset_dirs: Vec<PathBuf>) -> Self {
+        self.changeset_dirs = changeset_dirs;
+        self
+    }
+
     pub fn with_inherited_packages(mut self, inherited_packages: Vec<String>) -> Self {
         self.inherited_packages = inherited_packages;
         self
     }
 
-    pub fn with_prerelease_state(mut self, current_state: Option<&PrereleaseState>) -> Self {
-        if let Some(state) = current_state {
-            let mut new_state = state.clone();
-            for release in &self.planned_releases {
-                let was_prerelease = changeset_version::is_prerelease(&release.current_version);
-                let is_now_stable = !changeset_version::is_prerelease(&release.new_version);
-                if was_prerelease && is_now_stable {
-                    let _ = new_state.remove(&release.name);
-                }
+    pub fn with_untagged_packages(mut self, untagged_packages: Vec<String>) -> Self {
+        self.untagged_packages = untagged_packages;
+        self
+    }
+
+    pub fn with_tag_namespaces(mut self, tag_namespaces: IndexMap<String, String>) -> Self {
+        self.tag_namespaces = tag_namespaces;
+        self
+    }
+
+    pub fn with_skip_optional_dependencies(mut self, skip_optional_dependencies: bool) -> Self {
+        self.skip_optional_dependencies = skip_optional_dependencies;
+        self
+    }
+
+    /// Records the planned pre-release numbers in `pre-release.toml`, and the
+    /// tags of `cli_packages`, whose pre-release tag was given on the command
+    /// line, so that the next release continues on them. Packages graduating
+    /// to a stable version are removed. Without a `pre-release.toml` nothing
+    /// is recorded, so the next plain release graduates.
+    pub fn with_prerelease_state(
+        mut self,
+        current_state: Option<&PrereleaseState>,
+        cli_packages: &[String],
+    ) -> Self {
+        let Some(current_state) = current_state else {
+            return self;
+        };
+
+        let mut new_state = current_state.clone();
+        for release in &self.planned_releases {
+            let was_prerelease = changeset_version::is_prerelease(&release.current_version);
+            let is_now_stable = !changeset_version::is_prerelease(&release.new_version);
+            if was_prerelease && is_now_stable {
+                let _ = new_state.remove(&release.name);
+                continue;
+            }
+            if cli_packages.contains(&release.name)
+                && let Some(tag) = changeset_version::extract_prerelease_tag(&release.new_version)
+            {
+                new_state.insert(release.name.clone(), tag);
+            }
+            if let Some(number) = changeset_version::extract_prerelease_number(&release.new_version)
+            {
+                new_state.set_number(release.name.clone(), number);
             }
-            self.prerelease_state_update = Some(PrereleaseStateUpdate {
-                original: Some(state.clone()),
-                new_state,
-            });
         }
+        self.prerelease_state_update = Some(PrereleaseStateUpdate {
+            original: Some(current_state.clone()),
+            new_state,
+        });
         self
     }
 
@@ -157,15 +222,60 @@ impl ReleaseSagaData {
         self
     }
 
+    /// Planned releases that get a tag.
+    pub(super) fn tagged_releases(&self) -> impl Iterator<Item = &PackageVersion> {
+        self.planned_releases
+            .iter()
+            .filter(|release| !self.untagged_packages.contains(&release.name))
+    }
+
+    /// The tag of `release`, in its channel's namespace.
+    pub(super) fn tag_name(&self, release: &PackageVersion, use_crate_prefix: bool) -> String {
+        let namespace = self
+            .tag_namespaces
+            .get(&release.name)
+            .map_or("", String::as_str);
+        TagNaming::new(use_crate_prefix).format(namespace, &release.name, &release.new_version)
+    }
+
     pub fn with_changelog_backups(mut self, backups: Vec<ChangelogFileState>) -> Self {
         self.changelogs_written = !backups.is_empty();
         self.changelog_backups = backups;
         self
     }
 
+    pub fn with_stamped_files(mut self, stamped_files: Vec<StampedFile>) -> Self {
+        self.stamped_files = stamped_files;
+        self
+    }
+
+    pub fn with_commit_strategy(mut self, commit_strategy: CommitStrategy) -> Self {
+        self.commit_strategy = commit_strategy;
+        self
+    }
+
+    pub fn with_changes_stashed(mut self, changes_stashed: bool) -> Self {
+        self.changes_stashed = changes_stashed;
+        self
+    }
+
+    pub fn with_included_files(mut self, included_files: Vec<PathBuf>) -> Self {
+        self.included_files = included_files;
+        self
+    }
+
+    pub fn with_consumed_state_file(
+        mut self,
+        consumed_state_file: Option<ConsumedStateFile>,
+    ) -> Self {
+        self.consumed_state_file = consumed_state_file;
+        self
+    }
+
     pub fn into_git_result(self) -> GitOperationResult {
         GitOperationResult {
             commit: self.commit_result,
+            release_commits: self.release_commits,
             tags_created: self.tags_created,
             changesets_deleted: self.changesets_deleted,
         }
@@ -1,17 +1,27 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use changeset_project::TagFormat;
+use changeset_core::BumpType;
+use changeset_git::{CommitIdentity, Identity};
+use changeset_project::{
+    CommitBody, CommitStrategy, GitConfig, GitIdentity, PrereleaseState, StateFile, TagBody,
+    TagFormat,
+};
 use changeset_saga::SagaStep;
 use tracing::debug;
 
+use super::compensation::CompensationAction;
 use super::context::ReleaseSagaContext;
 use super::saga_data::{DependencyUpdate, ManifestUpdate, ReleaseSagaData};
-use super::{CommitResult, TagResult};
+use super::step_control::ReleaseStep;
+use super::{ChangelogUpdate, CommitResult, ReleaseCommit, TagResult};
 use crate::OperationError;
+use crate::changeset_dirs;
 use crate::traits::{
     ChangelogWriter, ChangesetReader, ChangesetWriter, GitProvider, ManifestWriter, ReleaseStateIO,
 };
+use crate::types::PackageVersion;
 
 pub struct WriteManifestVersionsStep<G, M, RW, S, C> {
     _marker: PhantomData<(G, M, RW, S, C)>,
@@ -46,7 +56,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "write_manifest_versions"
+        ReleaseStep::WriteManifests.id()
     }
 
     fn execute(
@@ -57,8 +67,7 @@ where
         let mut manifest_updates = Vec::new();
 
         for release in &input.planned_releases {
-            if let Some(pkg_path) = input.package_paths.get(&release.name) {
-                let manifest_path = pkg_path.join("Cargo.toml");
+            if let Some(manifest_path) = input.package_manifests.get(&release.name).cloned() {
                 ctx.manifest_writer()
                     .write_version(&manifest_path, &release.new_version)?;
                 ctx.manifest_writer()
@@ -90,21 +99,36 @@ where
             count = input.manifest_updates.len(),
             "rolling back manifest version updates"
         );
-        for release in &input.planned_releases {
-            if let Some(pkg_path) = input.package_paths.get(&release.name) {
-                let manifest_path = pkg_path.join("Cargo.toml");
-                ctx.manifest_writer()
-                    .write_version(&manifest_path, &release.current_version)?;
-            }
-        }
-        Ok(())
+        let actions = input
+            .planned_releases
+            .iter()
+            .filter_map(|release| {
+                let manifest = input.package_manifests.get(&release.name)?;
+                Some(CompensationAction::WriteVersion {
+                    manifest: manifest.clone(),
+                    version: release.current_version.to_string(),
+                })
+            })
+            .collect();
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
-        "restore original package versions in Cargo.toml files".to_string()
+        "restore original package versions in manifests".to_string()
     }
 }
 
+/// The package manifests that are `Cargo.toml` files; only those take part in
+/// dependency requirement updates.
+fn cargo_manifests(input: &ReleaseSagaData) -> Vec<PathBuf> {
+    input
+        .package_manifests
+        .values()
+        .filter(|path| path.file_name().is_some_and(|name| name == "Cargo.toml"))
+        .cloned()
+        .collect()
+}
+
 pub struct UpdateDependencyVersionsStep<G, M, RW, S, C> {
     _marker: PhantomData<(G, M, RW, S, C)>,
 }
@@ -138,7 +162,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "update_dependency_versions"
+        ReleaseStep::UpdateDependencies.id()
     }
 
     fn execute(
@@ -148,11 +172,7 @@ where
     ) -> Result<Self::Output, Self::Error> {
         let mut dependency_updates = Vec::new();
 
-        let mut manifest_paths: Vec<_> = input
-            .package_paths
-            .values()
-            .map(|p| p.join("Cargo.toml"))
-            .collect();
+        let mut manifest_paths = cargo_manifests(&input);
         manifest_paths.push(input.root_manifest_path.clone());
 
         for release in &input.planned_releases {
@@ -161,6 +181,7 @@ where
                     manifest_path,
                     &release.name,
                     &release.new_version,
+                    input.skip_optional_dependencies,
                 )?;
 
                 if updated {
@@ -191,23 +212,21 @@ where
             count = input.dependency_updates.len(),
             "rolling back dependency version updates"
         );
-        let mut manifest_paths: Vec<_> = input
-            .package_paths
-            .values()
-            .map(|p| p.join("Cargo.toml"))
-            .collect();
+        let mut manifest_paths = cargo_manifests(&input);
         manifest_paths.push(input.root_manifest_path.clone());
 
+        let mut actions = Vec::new();
         for release in &input.planned_releases {
             for manifest_path in &manifest_paths {
-                ctx.manifest_writer().update_dependency_version(
-                    manifest_path,
-                    &release.name,
-                    &release.current_version,
-                )?;
+                actions.push(CompensationAction::UpdateDependencyVersion {
+                    manifest: manifest_path.clone(),
+                    dependency: release.name.clone(),
+                    version: release.current_version.to_string(),
+                    skip_optional: input.skip_optional_dependencies,
+                });
             }
         }
-        Ok(())
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
@@ -215,6 +234,77 @@ where
     }
 }
 
+/// Writes the version stamps planned before the saga started.
+pub struct StampFilesStep<G, M, RW, S, C> {
+    _marker: PhantomData<(G, M, RW, S, C)>,
+}
+
+impl<G, M, RW, S, C> StampFilesStep<G, M, RW, S, C> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<G, M, RW, S, C> Default for StampFilesStep<G, M, RW, S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G, M, RW, S, C> SagaStep for StampFilesStep<G, M, RW, S, C>
+where
+    G: GitProvider + Send + Sync,
+    M: ManifestWriter + Send + Sync,
+    RW: ChangesetReader + ChangesetWriter + Send + Sync,
+    S: ReleaseStateIO + Send + Sync,
+    C: ChangelogWriter + Send + Sync,
+{
+    type Input = ReleaseSagaData;
+    type Output = ReleaseSagaData;
+    type Context = ReleaseSagaContext<G, M, RW, S, C>;
+    type Error = OperationError;
+
+    fn name(&self) -> &'static str {
+        ReleaseStep::StampFiles.id()
+    }
+
+    fn execute(
+        &self,
+        ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        for file in &input.stamped_files {
+            debug!(path = %file.path.display(), "stamping version");
+            ctx.text_file_io()
+                .write_file(&file.path, &file.stamped_content)?;
+        }
+        Ok(input)
+    }
+
+    fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        debug!(
+            count = input.stamped_files.len(),
+            "restoring version-stamped files"
+        );
+        let actions = input
+            .stamped_files
+            .iter()
+            .map(|file| CompensationAction::RestoreFile {
+                path: file.path.clone(),
+                content: file.original_content.clone(),
+            })
+            .collect();
+        ctx.compensate(self.name(), self.compensation_description(), actions)
+    }
+
+    fn compensation_description(&self) -> String {
+        "restore files rewritten by version stamps".to_string()
+    }
+}
+
 pub struct RemoveWorkspaceVersionStep<G, M, RW, S, C> {
     _marker: PhantomData<(G, M, RW, S, C)>,
 }
@@ -248,7 +338,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "remove_workspace_version"
+        ReleaseStep::RemoveWorkspaceVersion.id()
     }
 
     fn execute(
@@ -268,16 +358,22 @@ where
     }
 
     fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        let mut actions = Vec::new();
         if !input.inherited_packages.is_empty() {
-            if let Some(version) = &input.original_workspace_version {
-                ctx.manifest_writer()
-                    .write_workspace_version(&input.root_manifest_path, version)?;
-            } else if let Some(release) = input.planned_releases.first() {
-                ctx.manifest_writer()
-                    .write_workspace_version(&input.root_manifest_path, &release.current_version)?;
+            let version = input.original_workspace_version.as_ref().or_else(|| {
+                input
+                    .planned_releases
+                    .first()
+                    .map(|release| &release.current_version)
+            });
+            if let Some(version) = version {
+                actions.push(CompensationAction::WriteWorkspaceVersion {
+                    manifest: input.root_manifest_path.clone(),
+                    version: version.to_string(),
+                });
             }
         }
-        Ok(())
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
@@ -318,7 +414,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "mark_changesets_consumed"
+        ReleaseStep::MarkConsumed.id()
     }
 
     fn execute(
@@ -348,20 +444,24 @@ where
         // Check the same conditions as execute() to determine if we would have marked
         // changesets as consumed. We cannot rely on input.changesets_consumed because
         // compensate receives the original input, not the modified output.
+        let mut actions = Vec::new();
         if input.is_prerelease_release && !input.changeset_files.is_empty() {
-            let files_to_clear: Vec<&Path> = input
+            let files_to_clear: Vec<_> = input
                 .changeset_files
                 .iter()
                 .filter(|f| f.original_consumed_status.is_none())
-                .map(|f| f.path.as_path())
+                .map(|f| f.path.clone())
                 .collect();
 
             if !files_to_clear.is_empty() {
-                ctx.changeset_rw()
-                    .clear_consumed_for_prerelease(&input.changeset_dir, &files_to_clear)?;
+                actions.push(CompensationAction::ClearConsumed {
+                    changeset_dir: input.changeset_dir.clone(),
+                    paths: files_to_clear,
+                });
             }
+            actions.extend(restore_consumed_state_file(&input));
         }
-        Ok(())
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
@@ -402,7 +502,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "clear_changesets_consumed"
+        ReleaseStep::ClearConsumed.id()
     }
 
     fn execute(
@@ -411,9 +511,10 @@ where
         mut input: Self::Input,
     ) -> Result<Self::Output, Self::Error> {
         if input.is_graduating {
-            let consumed_paths = ctx
-                .changeset_rw()
-                .list_consumed_changesets(&input.changeset_dir)?;
+            let consumed_paths = changeset_dirs::list_consumed_changesets(
+                ctx.changeset_rw(),
+                &input.changeset_dirs,
+            )?;
 
             if !consumed_paths.is_empty() {
                 let mut consumed_files = Vec::new();
@@ -438,23 +539,22 @@ where
     }
 
     fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
-        for file_state in &input.consumed_files_cleared {
-            if let Some(original_version) = &file_state.original_consumed_status {
-                let version: semver::Version =
-                    original_version
-                        .parse()
-                        .map_err(|_| OperationError::VersionParse {
-                            version: original_version.clone(),
-                            context: "compensation restore consumed status".to_string(),
-                        })?;
-                ctx.changeset_rw().mark_consumed_for_prerelease(
-                    &input.changeset_dir,
-                    &[file_state.path.as_path()],
-                    &version,
-                )?;
-            }
+        let mut actions: Vec<_> = input
+            .consumed_files_cleared
+            .iter()
+            .filter_map(|file_state| {
+                let version = file_state.original_consumed_status.as_ref()?;
+                Some(CompensationAction::MarkConsumed {
+                    changeset_dir: input.changeset_dir.clone(),
+                    path: file_state.path.clone(),
+                    version: version.clone(),
+                })
+            })
+            .collect();
+        if input.is_graduating {
+            actions.extend(restore_consumed_state_file(&input));
         }
-        Ok(())
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
@@ -462,6 +562,16 @@ where
     }
 }
 
+/// Puts back the consumed-state file as it was before the release. A file
+/// the release created is already removed once its last mark is cleared.
+fn restore_consumed_state_file(input: &ReleaseSagaData) -> Option<CompensationAction> {
+    let file = input.consumed_state_file.as_ref()?;
+    Some(CompensationAction::RestoreFile {
+        path: file.path.clone(),
+        content: file.original_content.clone()?,
+    })
+}
+
 pub struct DeleteChangesetFilesStep<G, M, RW, S, C> {
     _marker: PhantomData<(G, M, RW, S, C)>,
 }
@@ -495,7 +605,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "delete_changeset_files"
+        ReleaseStep::DeleteChangesets.id()
     }
 
     fn execute(
@@ -529,13 +639,18 @@ where
     }
 
     fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
-        for file_state in &input.changeset_files {
-            if let Some(changeset) = &file_state.backup {
-                ctx.changeset_rw()
-                    .restore_changeset(&file_state.path, changeset)?;
-            }
-        }
-        Ok(())
+        let actions = input
+            .changeset_files
+            .iter()
+            .filter_map(|file_state| {
+                let changeset = file_state.backup.as_ref()?;
+                Some(CompensationAction::RestoreChangeset {
+                    path: file_state.path.clone(),
+                    changeset: changeset.clone(),
+                })
+            })
+            .collect();
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
@@ -576,7 +691,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "stage_files"
+        ReleaseStep::StageFiles.id()
     }
 
     fn execute(
@@ -584,34 +699,12 @@ where
         ctx: &Self::Context,
         mut input: Self::Input,
     ) -> Result<Self::Output, Self::Error> {
-        if !input.should_commit {
+        // Per-package commits stage their own files one commit at a time.
+        if !input.should_commit || input.commit_strategy == CommitStrategy::PerPackage {
             return Ok(input);
         }
 
-        let mut files = Vec::new();
-
-        for update in &input.manifest_updates {
-            files.push(update.manifest_path.clone());
-        }
-
-        if input.workspace_version_removed {
-            files.push(input.root_manifest_path.clone());
-        }
-
-        for update in &input.changelog_updates {
-            files.push(update.path.clone());
-        }
-
-        for update in &input.dependency_updates {
-            files.push(update.manifest_path.clone());
-        }
-
-        if !input.changesets_deleted.is_empty() {
-            files.extend(input.changesets_deleted.iter().cloned());
-        }
-
-        files.sort();
-        files.dedup();
+        let files = collect_files_to_stage(ctx, release_files(&input))?;
 
         if !files.is_empty() {
             let paths_refs: Vec<&Path> = files.iter().map(AsRef::as_ref).collect();
@@ -633,9 +726,173 @@ where
     }
 }
 
+/// Every file the release changed, sorted and without duplicates.
+fn release_files(input: &ReleaseSagaData) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for update in &input.manifest_updates {
+        files.push(update.manifest_path.clone());
+    }
+
+    if input.workspace_version_removed {
+        files.push(input.root_manifest_path.clone());
+    }
+
+    for update in &input.changelog_updates {
+        files.push(update.path.clone());
+        files.extend(update.json_path.iter().cloned());
+        files.extend(update.archive_paths.iter().cloned());
+    }
+
+    for update in &input.dependency_updates {
+        files.push(update.manifest_path.clone());
+    }
+
+    for file in &input.stamped_files {
+        files.push(file.path.clone());
+    }
+
+    files.extend(input.included_files.iter().cloned());
+
+    if input.changesets_consumed {
+        files.extend(input.changeset_files.iter().map(|file| file.path.clone()));
+    }
+    files.extend(
+        input
+            .consumed_files_cleared
+            .iter()
+            .map(|file| file.path.clone()),
+    );
+    if input.changesets_consumed || input.consumed_cleared {
+        files.extend(
+            input
+                .consumed_state_file
+                .iter()
+                .map(|file| file.path.clone()),
+        );
+    }
+
+    if input.prerelease_state_update.is_some() {
+        files.push(input.changeset_dir.join(StateFile::Prerelease.file_name()));
+    }
+    if input.graduation_state_update.is_some() {
+        files.push(input.changeset_dir.join(StateFile::Graduation.file_name()));
+    }
+
+    if !input.changesets_deleted.is_empty() {
+        files.extend(input.changesets_deleted.iter().cloned());
+    }
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Releases recorded by one per-package commit and the files it stages.
+/// Drops the files git ignores, so generated artifacts written next to the
+/// release files are never committed.
+fn collect_files_to_stage<G, M, RW, S, C>(
+    ctx: &ReleaseSagaContext<G, M, RW, S, C>,
+    files: Vec<PathBuf>,
+) -> Result<Vec<PathBuf>, OperationError>
+where
+    G: GitProvider + Send + Sync,
+    M: ManifestWriter + Send + Sync,
+    RW: ChangesetReader + ChangesetWriter + Send + Sync,
+    S: ReleaseStateIO + Send + Sync,
+    C: ChangelogWriter + Send + Sync,
+{
+    if files.is_empty() {
+        return Ok(files);
+    }
+    let ignored = ctx
+        .git_provider()
+        .ignored_paths(ctx.project_root(), &files)?;
+    Ok(files
+        .into_iter()
+        .filter(|file| !ignored.contains(file))
+        .collect())
+}
+
+struct CommitGroup {
+    releases: Vec<PackageVersion>,
+    files: Vec<PathBuf>,
+}
+
+/// Splits the release files into one commit per released package, in release order.
+///
+/// A package's commit holds its manifest and its own changelog. Files shared
+/// between packages (the root changelog and manifest, dependency requirement
+/// updates, stamped files and deleted changesets) go into the first commit,
+/// together with packages that changed no file of their own; deleted
+/// changesets are already staged by the time the first commit is made.
+fn per_package_commits(input: &ReleaseSagaData) -> Vec<CommitGroup> {
+    let mut remaining = release_files(input);
+    let mut groups: Vec<CommitGroup> = Vec::new();
+    let mut without_files = Vec::new();
+
+    for release in &input.planned_releases {
+        let manifest = input.package_manifests.get(&release.name);
+        let owns = |file: &PathBuf| {
+            manifest == Some(file)
+                || input.changelog_updates.iter().any(|update| {
+                    update.package.as_deref() == Some(release.name.as_str())
+                        && (&update.path == file
+                            || update.json_path.as_ref() == Some(file)
+                            || update.archive_paths.contains(file))
+                })
+        };
+        let (files, rest): (Vec<_>, Vec<_>) = remaining.into_iter().partition(owns);
+        remaining = rest;
+
+        if files.is_empty() {
+            without_files.push(release.clone());
+        } else {
+            groups.push(CommitGroup {
+                releases: vec![release.clone()],
+                files,
+            });
+        }
+    }
+
+    if remaining.is_empty() && without_files.is_empty() {
+        return groups;
+    }
+    match groups.first_mut() {
+        Some(first) => {
+            first.releases.extend(without_files);
+            first.files.extend(remaining);
+            first.files.sort();
+        }
+        None if !remaining.is_empty() => groups.push(CommitGroup {
+            releases: without_files,
+            files: remaining,
+        }),
+        None => {}
+    }
+    groups
+}
+
+/// The author and committer configured for release commits.
+pub(super) fn release_commit_identity(git_config: &GitConfig) -> CommitIdentity {
+    let identity = |configured: &GitIdentity| Identity {
+        name: configured.name().to_string(),
+        email: configured.email().to_string(),
+    };
+    CommitIdentity {
+        author: git_config.commit_author().map(identity),
+        committer: git_config.commit_committer().map(identity),
+    }
+}
+
 pub struct CreateCommitStep<G, M, RW, S, C> {
     commit_title_template: String,
     include_changes_in_body: bool,
+    body: CommitBody,
+    body_max_length: Option<usize>,
+    body_truncation: String,
+    display_names: HashMap<String, String>,
+    identity: CommitIdentity,
     _marker: PhantomData<(G, M, RW, S, C)>,
 }
 
@@ -645,14 +902,52 @@ impl<G, M, RW, S, C> CreateCommitStep<G, M, RW, S, C> {
         Self {
             commit_title_template,
             include_changes_in_body,
+            body: CommitBody::default(),
+            body_max_length: None,
+            body_truncation: String::new(),
+            display_names: HashMap::new(),
+            identity: CommitIdentity::default(),
             _marker: PhantomData,
         }
     }
 
-    fn build_commit_message(&self, planned_releases: &[crate::types::PackageVersion]) -> String {
+    /// Records `identity` as author and committer instead of the user's git config.
+    #[must_use]
+    pub fn with_identity(mut self, identity: CommitIdentity) -> Self {
+        self.identity = identity;
+        self
+    }
+
+    /// Lays out the body and cuts it to length as configured in `git_config`.
+    #[must_use]
+    pub fn with_body(mut self, git_config: &GitConfig) -> Self {
+        self.body = git_config.commit_body();
+        self.body_max_length = git_config.commit_body_max_length();
+        self.body_truncation = git_config.commit_body_truncation().to_string();
+        self
+    }
+
+    /// Names packages in the commit message by display name instead of crate name.
+    #[must_use]
+    pub fn with_display_names(mut self, display_names: HashMap<String, String>) -> Self {
+        self.display_names = display_names;
+        self
+    }
+
+    fn display_name<'a>(&'a self, package: &'a str) -> &'a str {
+        self.display_names
+            .get(package)
+            .map_or(package, String::as_str)
+    }
+
+    fn build_commit_message(
+        &self,
+        planned_releases: &[PackageVersion],
+        changelog_updates: &[ChangelogUpdate],
+    ) -> String {
         let version_list: Vec<String> = planned_releases
             .iter()
-            .map(|r| format!("{}@v{}", r.name, r.new_version))
+            .map(|r| format!("{}@v{}", self.display_name(&r.name), r.new_version))
             .collect();
         let new_version = version_list.join(", ");
 
@@ -664,13 +959,115 @@ impl<G, M, RW, S, C> CreateCommitStep<G, M, RW, S, C> {
             return title;
         }
 
-        let body: Vec<String> = planned_releases
+        let body = match self.body {
+            CommitBody::List => planned_releases
+                .iter()
+                .map(|r| self.release_line(r))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            CommitBody::Grouped => self.grouped_body(planned_releases, changelog_updates),
+        };
+        let body = truncate_body(&body, self.body_max_length, &self.body_truncation);
+
+        format!("{title}\n\n{body}")
+    }
+
+    fn release_line(&self, release: &PackageVersion) -> String {
+        format!(
+            "- {} {} -> {}",
+            self.display_name(&release.name),
+            release.current_version,
+            release.new_version
+        )
+    }
+
+    /// Releases under a heading per bump type, largest first, each followed by
+    /// its indented changelog entries. A single root changelog section is
+    /// added once after the groups.
+    fn grouped_body(
+        &self,
+        planned_releases: &[PackageVersion],
+        changelog_updates: &[ChangelogUpdate],
+    ) -> String {
+        let mut sections = Vec::new();
+        for (heading, bump) in [
+            ("Major", BumpType::Major),
+            ("Minor", BumpType::Minor),
+            ("Patch", BumpType::Patch),
+        ] {
+            let mut section = format!("{heading}:");
+            let mut releases = planned_releases
+                .iter()
+                .filter(|release| release.bump_type == bump)
+                .peekable();
+            if releases.peek().is_none() {
+                continue;
+            }
+            for release in releases {
+                section.push('\n');
+                section.push_str(&self.release_line(release));
+                if let Some(excerpt) = package_excerpt(changelog_updates, release) {
+                    for line in excerpt.lines() {
+                        section.push('\n');
+                        if !line.is_empty() {
+                            section.push_str("  ");
+                            section.push_str(line);
+                        }
+                    }
+                }
+            }
+            sections.push(section);
+        }
+
+        if let Some(root) = changelog_updates
             .iter()
-            .map(|r| format!("- {} {} -> {}", r.name, r.current_version, r.new_version))
-            .collect();
+            .find(|update| update.package.is_none() && !update.excerpt.is_empty())
+        {
+            sections.push(root.excerpt.clone());
+        }
+        sections.join("\n\n")
+    }
+}
+
+/// Changelog entries written for `release` in its own package changelog.
+fn package_excerpt<'a>(
+    updates: &'a [ChangelogUpdate],
+    release: &PackageVersion,
+) -> Option<&'a str> {
+    updates
+        .iter()
+        .find(|update| {
+            update.package.as_deref() == Some(release.name.as_str())
+                && update.version == release.new_version
+        })
+        .map(|update| update.excerpt.as_str())
+        .filter(|excerpt| !excerpt.is_empty())
+}
+
+/// Cuts `body` after the last whole line that fits in `max_length` characters
+/// together with a final `truncation` line.
+fn truncate_body(body: &str, max_length: Option<usize>, truncation: &str) -> String {
+    let Some(max_length) = max_length else {
+        return body.to_string();
+    };
+    if body.chars().count() <= max_length {
+        return body.to_string();
+    }
 
-        format!("{}\n\n{}", title, body.join("\n"))
+    let budget = max_length.saturating_sub(truncation.chars().count() + 1);
+    let mut kept = String::new();
+    let mut length = 0;
+    for line in body.lines() {
+        let line_length = line.chars().count() + 1;
+        if length + line_length > budget {
+            break;
+        }
+        kept.push_str(line);
+        kept.push('\n');
+        length += line_length;
     }
+    kept.push_str(truncation);
+    kept
 }
 
 impl<G, M, RW, S, C> SagaStep for CreateCommitStep<G, M, RW, S, C>
@@ -687,7 +1084,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "create_commit"
+        ReleaseStep::CreateCommit.id()
     }
 
     fn execute(
@@ -695,12 +1092,20 @@ where
         ctx: &Self::Context,
         mut input: Self::Input,
     ) -> Result<Self::Output, Self::Error> {
-        if !input.should_commit || !input.files_were_staged {
+        if !input.should_commit {
+            return Ok(input);
+        }
+        if input.commit_strategy == CommitStrategy::PerPackage {
+            return self.commit_per_package(ctx, input);
+        }
+        if !input.files_were_staged {
             return Ok(input);
         }
 
-        let message = self.build_commit_message(&input.planned_releases);
-        let commit_info = ctx.git_provider().commit(ctx.project_root(), &message)?;
+        let message = self.build_commit_message(&input.planned_releases, &input.changelog_updates);
+        let commit_info =
+            ctx.git_provider()
+                .commit(ctx.project_root(), &message, &self.identity)?;
 
         input.commit_result = Some(CommitResult {
             sha: commit_info.sha,
@@ -711,20 +1116,130 @@ where
     }
 
     fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        let mut actions = Vec::new();
         if input.should_commit {
-            ctx.git_provider().reset_to_parent(ctx.project_root())?;
+            let commits = match input.commit_strategy {
+                CommitStrategy::Single => 1,
+                CommitStrategy::PerPackage => per_package_commits(&input).len(),
+            };
+            actions.extend((0..commits).map(|_| CompensationAction::ResetToParent));
         }
-        Ok(())
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
         "reset to parent commit".to_string()
     }
+
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        input
+            .should_commit
+            .then(|| release_key(ReleaseStep::CreateCommit, &input.planned_releases))
+    }
+
+    /// Takes the release commit left in place by an earlier run from `HEAD`.
+    fn resume(
+        &self,
+        ctx: &Self::Context,
+        mut input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        input.commit_result = Some(CommitResult {
+            sha: ctx.git_provider().head_sha(ctx.project_root())?,
+            message: self.build_commit_message(&input.planned_releases, &input.changelog_updates),
+        });
+        Ok(input)
+    }
+}
+
+/// Idempotency key of `step` releasing `releases`, e.g.
+/// `create-commit:my-crate@1.0.1`.
+fn release_key<'a>(
+    step: ReleaseStep,
+    releases: impl IntoIterator<Item = &'a PackageVersion>,
+) -> String {
+    let releases: Vec<String> = releases
+        .into_iter()
+        .map(|release| format!("{}@{}", release.name, release.new_version))
+        .collect();
+    format!("{}:{}", step.id(), releases.join(","))
+}
+
+impl<G, M, RW, S, C> CreateCommitStep<G, M, RW, S, C>
+where
+    G: GitProvider + Send + Sync,
+    M: ManifestWriter + Send + Sync,
+    RW: ChangesetReader + ChangesetWriter + Send + Sync,
+    S: ReleaseStateIO + Send + Sync,
+    C: ChangelogWriter + Send + Sync,
+{
+    /// Stages and commits each package on its own. If a commit fails, the
+    /// commits already created are reset before the error is returned.
+    fn commit_per_package(
+        &self,
+        ctx: &ReleaseSagaContext<G, M, RW, S, C>,
+        mut input: ReleaseSagaData,
+    ) -> Result<ReleaseSagaData, OperationError> {
+        let mut groups = per_package_commits(&input);
+        for group in &mut groups {
+            group.files = collect_files_to_stage(ctx, std::mem::take(&mut group.files))?;
+        }
+        let mut commits = Vec::with_capacity(groups.len());
+
+        for group in &groups {
+            match self.commit_group(ctx, group, &input.changelog_updates) {
+                Ok(commit) => commits.push(commit),
+                Err(e) => {
+                    for _ in &commits {
+                        let _ = ctx.git_provider().reset_to_parent(ctx.project_root());
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        let mut staged_files: Vec<PathBuf> =
+            groups.into_iter().flat_map(|group| group.files).collect();
+        staged_files.sort();
+        input.files_were_staged = !staged_files.is_empty();
+        input.staged_files = staged_files;
+        input.commit_result = commits.last().map(|last| last.commit.clone());
+        input.release_commits = commits;
+
+        Ok(input)
+    }
+
+    fn commit_group(
+        &self,
+        ctx: &ReleaseSagaContext<G, M, RW, S, C>,
+        group: &CommitGroup,
+        changelog_updates: &[ChangelogUpdate],
+    ) -> Result<ReleaseCommit, OperationError> {
+        let paths: Vec<&Path> = group.files.iter().map(AsRef::as_ref).collect();
+        ctx.git_provider().stage_files(ctx.project_root(), &paths)?;
+
+        let message = self.build_commit_message(&group.releases, changelog_updates);
+        let commit_info =
+            ctx.git_provider()
+                .commit(ctx.project_root(), &message, &self.identity)?;
+
+        Ok(ReleaseCommit {
+            packages: group
+                .releases
+                .iter()
+                .map(|release| release.name.clone())
+                .collect(),
+            commit: CommitResult {
+                sha: commit_info.sha,
+                message: commit_info.message,
+            },
+        })
+    }
 }
 
 pub struct CreateTagsStep<G, M, RW, S, C> {
     tag_format: TagFormat,
     use_crate_prefix: bool,
+    tag_body: TagBody,
     _marker: PhantomData<(G, M, RW, S, C)>,
 }
 
@@ -734,9 +1249,40 @@ impl<G, M, RW, S, C> CreateTagsStep<G, M, RW, S, C> {
         Self {
             tag_format,
             use_crate_prefix,
+            tag_body: TagBody::default(),
             _marker: PhantomData,
         }
     }
+
+    #[must_use]
+    pub fn with_tag_body(mut self, tag_body: TagBody) -> Self {
+        self.tag_body = tag_body;
+        self
+    }
+
+    /// Annotation for the tag of `release`, followed by its changelog section
+    /// when configured.
+    ///
+    /// With a single root changelog, the section covers every released package.
+    fn tag_message(&self, input: &ReleaseSagaData, release: &PackageVersion) -> String {
+        let title = format!("Release {} v{}", release.name, release.new_version);
+        if self.tag_body != TagBody::Changelog {
+            return title;
+        }
+
+        let updates = &input.changelog_updates;
+        let excerpt = package_excerpt(updates, release).or_else(|| {
+            updates
+                .iter()
+                .find(|update| update.package.is_none())
+                .map(|update| update.excerpt.as_str())
+                .filter(|excerpt| !excerpt.is_empty())
+        });
+        match excerpt {
+            Some(excerpt) => format!("{title}\n\n{excerpt}\n"),
+            None => title,
+        }
+    }
 }
 
 impl<G, M, RW, S, C> SagaStep for CreateTagsStep<G, M, RW, S, C>
@@ -753,7 +1299,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "create_tags"
+        ReleaseStep::CreateTags.id()
     }
 
     fn execute(
@@ -770,19 +1316,28 @@ where
         let mut tags = Vec::new();
         let mut created_tag_names: Vec<String> = Vec::new();
 
-        for release in &input.planned_releases {
-            let tag_name = if use_prefix {
-                format!("{}@v{}", release.name, release.new_version)
-            } else {
-                format!("v{}", release.new_version)
-            };
+        for release in input.tagged_releases() {
+            let tag_name = input.tag_name(release, use_prefix);
 
-            let tag_message = format!("Release {} v{}", release.name, release.new_version);
+            let tag_message = self.tag_message(&input, release);
+
+            let release_commit = input
+                .release_commits
+                .iter()
+                .find(|commit| commit.packages.contains(&release.name));
+            let created = match release_commit {
+                Some(target) => ctx.git_provider().create_tag_at(
+                    ctx.project_root(),
+                    &tag_name,
+                    &tag_message,
+                    &target.commit.sha,
+                ),
+                None => ctx
+                    .git_provider()
+                    .create_tag(ctx.project_root(), &tag_name, &tag_message),
+            };
 
-            match ctx
-                .git_provider()
-                .create_tag(ctx.project_root(), &tag_name, &tag_message)
-            {
+            match created {
                 Ok(tag_info) => {
                     created_tag_names.push(tag_name);
                     tags.push(TagResult {
@@ -812,32 +1367,44 @@ where
 
         let use_prefix = self.use_crate_prefix || self.tag_format == TagFormat::CratePrefixed;
 
-        let mut failed_tags = Vec::new();
-        for release in &input.planned_releases {
-            let tag_name = if use_prefix {
-                format!("{}@v{}", release.name, release.new_version)
-            } else {
-                format!("v{}", release.new_version)
-            };
-            if ctx
-                .git_provider()
-                .delete_tag(ctx.project_root(), &tag_name)
-                .is_err()
-            {
-                failed_tags.push(tag_name);
-            }
-        }
-
-        if failed_tags.is_empty() {
-            Ok(())
-        } else {
-            Err(OperationError::TagDeletionFailed { failed_tags })
-        }
-    }
+        let actions = input
+            .tagged_releases()
+            .map(|release| CompensationAction::DeleteTag {
+                name: input.tag_name(release, use_prefix),
+            })
+            .collect();
+        ctx.compensate(self.name(), self.compensation_description(), actions)
+    }
 
     fn compensation_description(&self) -> String {
         "delete the created tags".to_string()
     }
+
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        input
+            .should_create_tags
+            .then(|| release_key(ReleaseStep::CreateTags, input.tagged_releases()))
+    }
+
+    /// Reports the tags left in place by an earlier run as created at the release commit.
+    fn resume(
+        &self,
+        _ctx: &Self::Context,
+        mut input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        let Some(commit) = &input.commit_result else {
+            return Ok(input);
+        };
+        let use_prefix = self.use_crate_prefix || self.tag_format == TagFormat::CratePrefixed;
+        input.tags_created = input
+            .tagged_releases()
+            .map(|release| TagResult {
+                name: input.tag_name(release, use_prefix),
+                target_sha: commit.sha.clone(),
+            })
+            .collect();
+        Ok(input)
+    }
 }
 
 pub struct UpdateReleaseStateStep<G, M, RW, S, C> {
@@ -873,7 +1440,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "update_release_state"
+        ReleaseStep::UpdateState.id()
     }
 
     fn execute(
@@ -895,26 +1462,44 @@ where
     }
 
     fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        let mut actions = Vec::new();
         if let Some(update) = &input.prerelease_state_update {
-            if let Some(original) = &update.original {
-                ctx.release_state_io()
-                    .save_prerelease_state(&input.changeset_dir, original)?;
-            }
+            // Saving a blank state removes a file the release created.
+            actions.push(CompensationAction::SavePrereleaseState {
+                changeset_dir: input.changeset_dir.clone(),
+                state: update.original.clone().unwrap_or_else(PrereleaseState::new),
+            });
         }
 
         if let Some(update) = &input.graduation_state_update {
             if let Some(original) = &update.original {
-                ctx.release_state_io()
-                    .save_graduation_state(&input.changeset_dir, original)?;
+                actions.push(CompensationAction::SaveGraduationState {
+                    changeset_dir: input.changeset_dir.clone(),
+                    state: original.clone(),
+                });
             }
         }
 
-        Ok(())
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
         "restore original release state files".to_string()
     }
+
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        (input.prerelease_state_update.is_some() || input.graduation_state_update.is_some())
+            .then(|| release_key(ReleaseStep::UpdateState, &input.planned_releases))
+    }
+
+    /// The state files written by an earlier run are already in place.
+    fn resume(
+        &self,
+        _ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(input)
+    }
 }
 
 pub struct RestoreChangelogsStep<G, M, RW, S, C> {
@@ -950,7 +1535,7 @@ where
     type Error = OperationError;
 
     fn name(&self) -> &'static str {
-        "restore_changelogs"
+        ReleaseStep::RestoreChangelogs.id()
     }
 
     fn execute(
@@ -962,17 +1547,24 @@ where
     }
 
     fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
-        for backup in &input.changelog_backups {
-            if backup.file_existed {
-                if let Some(content) = &backup.original_content {
-                    ctx.changelog_writer()
-                        .restore_changelog(&backup.path, content)?;
+        let actions = input
+            .changelog_backups
+            .iter()
+            .filter_map(|backup| {
+                if !backup.file_existed {
+                    return Some(CompensationAction::DeleteChangelog {
+                        path: backup.path.clone(),
+                    });
                 }
-            } else {
-                ctx.changelog_writer().delete_changelog(&backup.path)?;
-            }
-        }
-        Ok(())
+                backup.original_content.as_ref().map(|content| {
+                    CompensationAction::RestoreChangelog {
+                        path: backup.path.clone(),
+                        content: content.clone(),
+                    }
+                })
+            })
+            .collect();
+        ctx.compensate(self.name(), self.compensation_description(), actions)
     }
 
     fn compensation_description(&self) -> String {
@@ -980,6 +1572,70 @@ where
     }
 }
 
+/// Restores changes stashed before the release when the release rolls back.
+///
+/// Runs first so that its compensation runs last, once every other step has
+/// put the working tree back to the stashed state.
+pub struct RestoreStashStep<G, M, RW, S, C> {
+    _marker: PhantomData<(G, M, RW, S, C)>,
+}
+
+impl<G, M, RW, S, C> RestoreStashStep<G, M, RW, S, C> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<G, M, RW, S, C> Default for RestoreStashStep<G, M, RW, S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G, M, RW, S, C> SagaStep for RestoreStashStep<G, M, RW, S, C>
+where
+    G: GitProvider + Send + Sync,
+    M: ManifestWriter + Send + Sync,
+    RW: ChangesetReader + ChangesetWriter + Send + Sync,
+    S: ReleaseStateIO + Send + Sync,
+    C: ChangelogWriter + Send + Sync,
+{
+    type Input = ReleaseSagaData;
+    type Output = ReleaseSagaData;
+    type Context = ReleaseSagaContext<G, M, RW, S, C>;
+    type Error = OperationError;
+
+    fn name(&self) -> &'static str {
+        "restore-stash"
+    }
+
+    fn execute(
+        &self,
+        _ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(input)
+    }
+
+    fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        if !input.changes_stashed {
+            return Ok(());
+        }
+        ctx.compensate(
+            self.name(),
+            self.compensation_description(),
+            vec![CompensationAction::PopStash],
+        )
+    }
+
+    fn compensation_description(&self) -> String {
+        "restore stashed working tree changes".to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -994,7 +1650,10 @@ mod tests {
         MockChangelogWriter, MockChangesetReader, MockGitProvider, MockManifestWriter,
         MockReleaseStateIO,
     };
+    use crate::operations::release::ChangelogUpdate;
     use crate::operations::release::saga_data::SagaReleaseOptions;
+    use crate::operations::release::steps::StampedFile;
+    use crate::providers::memory::InMemoryTextFileIO;
     use crate::types::PackageVersion;
 
     type TestContext = ReleaseSagaContext<
@@ -1031,17 +1690,17 @@ mod tests {
     }
 
     fn make_test_data() -> ReleaseSagaData {
-        let mut package_paths = IndexMap::new();
-        package_paths.insert(
+        let mut package_manifests = IndexMap::new();
+        package_manifests.insert(
             "pkg-a".to_string(),
-            PathBuf::from("/mock/project/crates/pkg-a"),
+            PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml"),
         );
 
         ReleaseSagaData::new(
             PathBuf::from("/mock/project/.changeset"),
             PathBuf::from("/mock/project/Cargo.toml"),
             vec![make_test_release("pkg-a", "1.0.0", "1.0.1")],
-            package_paths,
+            package_manifests,
             Vec::new(),
             Vec::new(),
         )
@@ -1232,51 +1891,430 @@ mod tests {
     }
 
     #[test]
-    fn stage_files_deduplicates_manifest_and_dependency_updates() -> anyhow::Result<()> {
+    fn stage_files_deduplicates_manifest_and_dependency_updates() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: StageFilesStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = StageFilesStep::new();
+        let mut input = make_test_data();
+        let shared_path = PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml");
+        input.manifest_updates.push(ManifestUpdate {
+            manifest_path: shared_path.clone(),
+            old_version: "1.0.0".parse()?,
+            new_version: "1.0.1".parse()?,
+            written: true,
+        });
+        input.dependency_updates.push(DependencyUpdate {
+            manifest_path: shared_path.clone(),
+            dependency_name: "pkg-a".to_string(),
+            old_version: "1.0.0".parse()?,
+            new_version: "1.0.1".parse()?,
+        });
+
+        let result = SagaStep::execute(&step, &ctx, input)?;
+
+        let count = result
+            .staged_files
+            .iter()
+            .filter(|p| **p == shared_path)
+            .count();
+        assert_eq!(count, 1, "duplicate paths should be deduplicated");
+
+        Ok(())
+    }
+
+    #[test]
+    fn stage_files_stages_manifest_and_changelog_files() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: StageFilesStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = StageFilesStep::new();
+        let mut input = make_test_data();
+        input.manifest_updates.push(ManifestUpdate {
+            manifest_path: PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml"),
+            old_version: "1.0.0".parse()?,
+            new_version: "1.0.1".parse()?,
+            written: true,
+        });
+
+        let result = SagaStep::execute(&step, &ctx, input)?;
+
+        assert!(result.files_were_staged);
+        assert!(!result.staged_files.is_empty());
+        assert!(!git_provider.staged_files().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn stage_files_skips_ignored_files() -> anyhow::Result<()> {
+        let git_provider = Arc::new(
+            MockGitProvider::new()
+                .with_ignored_paths(vec![PathBuf::from("/mock/project/crates/pkg-a/target")]),
+        );
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: StageFilesStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = StageFilesStep::new();
+        let manifest = PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml");
+        let mut input = make_test_data();
+        input.included_files = vec![
+            manifest.clone(),
+            PathBuf::from("/mock/project/crates/pkg-a/target/package.json"),
+        ];
+
+        let result = SagaStep::execute(&step, &ctx, input)?;
+
+        assert_eq!(result.staged_files, vec![manifest.clone()]);
+        assert_eq!(git_provider.staged_files(), vec![manifest]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_commit_creates_commit_when_files_staged() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: CreateCommitStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateCommitStep::new("Release {new-version}".to_string(), false);
+        let mut input = make_test_data();
+        input.files_were_staged = true;
+
+        let result = SagaStep::execute(&step, &ctx, input)?;
+
+        assert!(result.commit_result.is_some());
+        assert_eq!(git_provider.commits().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_commit_uses_display_names() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: CreateCommitStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateCommitStep::new("Release {new-version}".to_string(), true).with_display_names(
+            HashMap::from([("pkg-a".to_string(), "Widget SDK".to_string())]),
+        );
+        let mut input = make_test_data();
+        input.files_were_staged = true;
+
+        SagaStep::execute(&step, &ctx, input)?;
+
+        assert_eq!(
+            git_provider.commits(),
+            vec!["Release Widget SDK@v1.0.1\n\n- Widget SDK 1.0.0 -> 1.0.1".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_commit_records_configured_identity() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+        let author = GitIdentity::parse("release-bot <bot@company.com>")?;
+        let identity = release_commit_identity(&GitConfig::default().with_commit_author(author));
+
+        let step: CreateCommitStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateCommitStep::new("Release {new-version}".to_string(), false)
+            .with_identity(identity.clone());
+        let mut input = make_test_data();
+        input.files_were_staged = true;
+
+        SagaStep::execute(&step, &ctx, input)?;
+
+        let bot = Some(Identity {
+            name: "release-bot".to_string(),
+            email: "bot@company.com".to_string(),
+        });
+        assert_eq!(identity.author, bot);
+        assert_eq!(identity.committer, bot);
+        assert_eq!(git_provider.commit_identities(), vec![identity]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_commit_compensate_resets_to_parent() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: CreateCommitStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateCommitStep::new("Release {new-version}".to_string(), false);
+        let mut input = make_test_data();
+        input.commit_result = Some(CommitResult {
+            sha: "abc123".to_string(),
+            message: "Release".to_string(),
+        });
+
+        SagaStep::compensate(&step, &ctx, input)?;
+
+        assert_eq!(git_provider.reset_count(), 1);
+
+        Ok(())
+    }
+
+    type TestCommitStep = CreateCommitStep<
+        MockGitProvider,
+        MockManifestWriter,
+        MockChangesetReader,
+        MockReleaseStateIO,
+        MockChangelogWriter,
+    >;
+
+    fn package_update(package: &str, version: &str, excerpt: &str) -> ChangelogUpdate {
+        ChangelogUpdate {
+            path: PathBuf::from(format!("/mock/project/crates/{package}/CHANGELOG.md")),
+            package: Some(package.to_string()),
+            version: version.parse().expect("valid version"),
+            created: false,
+            excerpt: excerpt.to_string(),
+            json_path: None,
+            archive_paths: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn grouped_commit_body_lists_releases_by_bump_type() {
+        let step = TestCommitStep::new("Release".to_string(), true)
+            .with_body(&GitConfig::default().with_commit_body(CommitBody::Grouped));
+        let mut major = make_test_release("pkg-b", "1.2.0", "2.0.0");
+        major.bump_type = BumpType::Major;
+        let releases = vec![make_test_release("pkg-a", "1.0.0", "1.0.1"), major];
+        let updates = vec![
+            package_update("pkg-a", "1.0.1", "### Fixed\n\n- Bug fix"),
+            package_update("pkg-b", "2.0.0", "### Removed\n\n- Old API"),
+        ];
+
+        let message = step.build_commit_message(&releases, &updates);
+
+        assert_eq!(
+            message,
+            "Release\n\n\
+             Major:\n\
+             - pkg-b 1.2.0 -> 2.0.0\n  ### Removed\n\n  - Old API\n\n\
+             Patch:\n\
+             - pkg-a 1.0.0 -> 1.0.1\n  ### Fixed\n\n  - Bug fix"
+        );
+    }
+
+    #[test]
+    fn commit_body_is_cut_at_max_length() {
+        let step = TestCommitStep::new("Release".to_string(), true).with_body(
+            &GitConfig::default()
+                .with_commit_body(CommitBody::Grouped)
+                .with_commit_body_max_length(46),
+        );
+        let releases = vec![make_test_release("pkg-a", "1.0.0", "1.0.1")];
+        let updates = vec![package_update(
+            "pkg-a",
+            "1.0.1",
+            "### Fixed\n\n- First fix\n- Second fix",
+        )];
+
+        let message = step.build_commit_message(&releases, &updates);
+
+        assert_eq!(
+            message,
+            "Release\n\nPatch:\n- pkg-a 1.0.0 -> 1.0.1\n  ### Fixed\n..."
+        );
+    }
+
+    fn make_per_package_data() -> ReleaseSagaData {
+        let mut input = make_test_data();
+        input.commit_strategy = CommitStrategy::PerPackage;
+        input
+            .planned_releases
+            .push(make_test_release("pkg-b", "2.0.0", "2.0.1"));
+        input.package_manifests.insert(
+            "pkg-b".to_string(),
+            PathBuf::from("/mock/project/crates/pkg-b/Cargo.toml"),
+        );
+        for (name, old, new) in [("pkg-a", "1.0.0", "1.0.1"), ("pkg-b", "2.0.0", "2.0.1")] {
+            input.manifest_updates.push(ManifestUpdate {
+                manifest_path: PathBuf::from(format!("/mock/project/crates/{name}/Cargo.toml")),
+                old_version: old.parse().expect("valid version"),
+                new_version: new.parse().expect("valid version"),
+                written: true,
+            });
+        }
+        input.changelog_updates.push(ChangelogUpdate {
+            path: PathBuf::from("/mock/project/crates/pkg-a/CHANGELOG.md"),
+            package: Some("pkg-a".to_string()),
+            version: semver::Version::new(1, 0, 1),
+            created: false,
+            excerpt: String::new(),
+            json_path: Some(PathBuf::from("/mock/project/crates/pkg-a/CHANGELOG.json")),
+            archive_paths: Vec::new(),
+        });
+        input.changesets_deleted = vec![PathBuf::from("/mock/project/.changeset/fix.md")];
+        input
+    }
+
+    #[test]
+    fn stage_files_leaves_per_package_staging_to_commits() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: StageFilesStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = StageFilesStep::new();
+
+        let result = SagaStep::execute(&step, &ctx, make_per_package_data())?;
+
+        assert!(!result.files_were_staged);
+        assert!(git_provider.staged_files().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_commit_per_package_commits_each_package() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+        let step = TestCommitStep::new("Release {new-version}".to_string(), false);
+
+        let result = SagaStep::execute(&step, &ctx, make_per_package_data())?;
+
+        assert_eq!(
+            git_provider.commits(),
+            vec![
+                "Release pkg-a@v1.0.1".to_string(),
+                "Release pkg-b@v2.0.1".to_string()
+            ]
+        );
+        assert_eq!(result.release_commits.len(), 2);
+        assert_eq!(result.release_commits[0].packages, vec!["pkg-a"]);
+        assert_eq!(result.release_commits[1].packages, vec!["pkg-b"]);
+        assert_eq!(
+            result.commit_result.map(|commit| commit.sha),
+            Some(result.release_commits[1].commit.sha.clone())
+        );
+        assert_eq!(
+            git_provider.staged_files(),
+            vec![
+                PathBuf::from("/mock/project/.changeset/fix.md"),
+                PathBuf::from("/mock/project/crates/pkg-a/CHANGELOG.json"),
+                PathBuf::from("/mock/project/crates/pkg-a/CHANGELOG.md"),
+                PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml"),
+                PathBuf::from("/mock/project/crates/pkg-b/Cargo.toml"),
+            ],
+            "shared files belong to the first commit"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_commit_per_package_resets_created_commits_on_failure() {
         let git_provider = Arc::new(MockGitProvider::new());
+        git_provider.set_fail_on_commit_nth(1);
         let ctx = make_test_context(
             Arc::clone(&git_provider),
             Arc::new(MockManifestWriter::new()),
             Arc::new(MockChangesetReader::new()),
             Arc::new(MockReleaseStateIO::new()),
         );
+        let step = TestCommitStep::new("Release {new-version}".to_string(), false);
 
-        let step: StageFilesStep<
-            MockGitProvider,
-            MockManifestWriter,
-            MockChangesetReader,
-            MockReleaseStateIO,
-            MockChangelogWriter,
-        > = StageFilesStep::new();
-        let mut input = make_test_data();
-        let shared_path = PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml");
-        input.manifest_updates.push(ManifestUpdate {
-            manifest_path: shared_path.clone(),
-            old_version: "1.0.0".parse()?,
-            new_version: "1.0.1".parse()?,
-            written: true,
-        });
-        input.dependency_updates.push(DependencyUpdate {
-            manifest_path: shared_path.clone(),
-            dependency_name: "pkg-a".to_string(),
-            old_version: "1.0.0".parse()?,
-            new_version: "1.0.1".parse()?,
-        });
-
-        let result = SagaStep::execute(&step, &ctx, input)?;
-
-        let count = result
-            .staged_files
-            .iter()
-            .filter(|p| **p == shared_path)
-            .count();
-        assert_eq!(count, 1, "duplicate paths should be deduplicated");
+        let result = SagaStep::execute(&step, &ctx, make_per_package_data());
 
-        Ok(())
+        assert!(result.is_err());
+        assert_eq!(git_provider.commits().len(), 1);
+        assert_eq!(git_provider.reset_count(), 1);
     }
 
     #[test]
-    fn stage_files_stages_manifest_and_changelog_files() -> anyhow::Result<()> {
+    fn create_commit_per_package_compensate_resets_each_commit() -> anyhow::Result<()> {
         let git_provider = Arc::new(MockGitProvider::new());
         let ctx = make_test_context(
             Arc::clone(&git_provider),
@@ -1284,33 +2322,17 @@ mod tests {
             Arc::new(MockChangesetReader::new()),
             Arc::new(MockReleaseStateIO::new()),
         );
+        let step = TestCommitStep::new("Release {new-version}".to_string(), false);
 
-        let step: StageFilesStep<
-            MockGitProvider,
-            MockManifestWriter,
-            MockChangesetReader,
-            MockReleaseStateIO,
-            MockChangelogWriter,
-        > = StageFilesStep::new();
-        let mut input = make_test_data();
-        input.manifest_updates.push(ManifestUpdate {
-            manifest_path: PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml"),
-            old_version: "1.0.0".parse()?,
-            new_version: "1.0.1".parse()?,
-            written: true,
-        });
-
-        let result = SagaStep::execute(&step, &ctx, input)?;
+        SagaStep::compensate(&step, &ctx, make_per_package_data())?;
 
-        assert!(result.files_were_staged);
-        assert!(!result.staged_files.is_empty());
-        assert!(!git_provider.staged_files().is_empty());
+        assert_eq!(git_provider.reset_count(), 2);
 
         Ok(())
     }
 
     #[test]
-    fn create_commit_creates_commit_when_files_staged() -> anyhow::Result<()> {
+    fn create_tags_per_package_target_package_commits() -> anyhow::Result<()> {
         let git_provider = Arc::new(MockGitProvider::new());
         let ctx = make_test_context(
             Arc::clone(&git_provider),
@@ -1318,27 +2340,46 @@ mod tests {
             Arc::new(MockChangesetReader::new()),
             Arc::new(MockReleaseStateIO::new()),
         );
-
-        let step: CreateCommitStep<
+        let commit_step = TestCommitStep::new("Release {new-version}".to_string(), false);
+        let tags_step: CreateTagsStep<
             MockGitProvider,
             MockManifestWriter,
             MockChangesetReader,
             MockReleaseStateIO,
             MockChangelogWriter,
-        > = CreateCommitStep::new("Release {new-version}".to_string(), false);
-        let mut input = make_test_data();
-        input.files_were_staged = true;
+        > = CreateTagsStep::new(TagFormat::CratePrefixed, true);
 
-        let result = SagaStep::execute(&step, &ctx, input)?;
+        let committed = SagaStep::execute(&commit_step, &ctx, make_per_package_data())?;
+        let result = SagaStep::execute(&tags_step, &ctx, committed)?;
 
-        assert!(result.commit_result.is_some());
-        assert_eq!(git_provider.commits().len(), 1);
+        let targets: Vec<_> = result
+            .tags_created
+            .iter()
+            .map(|tag| (tag.name.as_str(), tag.target_sha.as_str()))
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                (
+                    "pkg-a@v1.0.1",
+                    result.release_commits[0].commit.sha.as_str()
+                ),
+                (
+                    "pkg-b@v2.0.1",
+                    result.release_commits[1].commit.sha.as_str()
+                ),
+            ]
+        );
+        assert_ne!(
+            result.release_commits[0].commit.sha,
+            result.release_commits[1].commit.sha
+        );
 
         Ok(())
     }
 
     #[test]
-    fn create_commit_compensate_resets_to_parent() -> anyhow::Result<()> {
+    fn create_tags_creates_tags_when_commit_exists() -> anyhow::Result<()> {
         let git_provider = Arc::new(MockGitProvider::new());
         let ctx = make_test_context(
             Arc::clone(&git_provider),
@@ -1347,28 +2388,29 @@ mod tests {
             Arc::new(MockReleaseStateIO::new()),
         );
 
-        let step: CreateCommitStep<
+        let step: CreateTagsStep<
             MockGitProvider,
             MockManifestWriter,
             MockChangesetReader,
             MockReleaseStateIO,
             MockChangelogWriter,
-        > = CreateCommitStep::new("Release {new-version}".to_string(), false);
+        > = CreateTagsStep::new(TagFormat::VersionOnly, false);
         let mut input = make_test_data();
         input.commit_result = Some(CommitResult {
             sha: "abc123".to_string(),
             message: "Release".to_string(),
         });
 
-        SagaStep::compensate(&step, &ctx, input)?;
+        let result = SagaStep::execute(&step, &ctx, input)?;
 
-        assert_eq!(git_provider.reset_count(), 1);
+        assert_eq!(result.tags_created.len(), 1);
+        assert_eq!(git_provider.tags_created().len(), 1);
 
         Ok(())
     }
 
     #[test]
-    fn create_tags_creates_tags_when_commit_exists() -> anyhow::Result<()> {
+    fn create_tags_resume_reports_existing_tags_without_creating_them() -> anyhow::Result<()> {
         let git_provider = Arc::new(MockGitProvider::new());
         let ctx = make_test_context(
             Arc::clone(&git_provider),
@@ -1390,14 +2432,53 @@ mod tests {
             message: "Release".to_string(),
         });
 
-        let result = SagaStep::execute(&step, &ctx, input)?;
+        assert_eq!(
+            step.idempotency_key(&input).as_deref(),
+            Some("create-tags:pkg-a@1.0.1")
+        );
+        let result = SagaStep::resume(&step, &ctx, input)?;
 
         assert_eq!(result.tags_created.len(), 1);
-        assert_eq!(git_provider.tags_created().len(), 1);
+        assert_eq!(result.tags_created[0].name, "v1.0.1");
+        assert_eq!(result.tags_created[0].target_sha, "abc123");
+        assert!(git_provider.tags_created().is_empty());
 
         Ok(())
     }
 
+    #[test]
+    fn release_steps_have_no_idempotency_key_when_they_do_nothing() {
+        let mut input = make_test_data();
+        input.should_commit = false;
+        input.should_create_tags = false;
+
+        let commit: CreateCommitStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateCommitStep::new("Release {new-version}".to_string(), false);
+        let tags: CreateTagsStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateTagsStep::new(TagFormat::VersionOnly, false);
+        let state: UpdateReleaseStateStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = UpdateReleaseStateStep::new();
+
+        assert_eq!(commit.idempotency_key(&input), None);
+        assert_eq!(tags.idempotency_key(&input), None);
+        assert_eq!(state.idempotency_key(&input), None);
+    }
+
     #[test]
     fn create_tags_compensate_deletes_tags() -> anyhow::Result<()> {
         let git_provider = Arc::new(MockGitProvider::new());
@@ -1449,14 +2530,14 @@ mod tests {
             MockChangelogWriter,
         > = CreateTagsStep::new(TagFormat::CratePrefixed, true);
 
-        let mut package_paths = IndexMap::new();
-        package_paths.insert(
+        let mut package_manifests = IndexMap::new();
+        package_manifests.insert(
             "pkg-a".to_string(),
-            PathBuf::from("/mock/project/crates/pkg-a"),
+            PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml"),
         );
-        package_paths.insert(
+        package_manifests.insert(
             "pkg-b".to_string(),
-            PathBuf::from("/mock/project/crates/pkg-b"),
+            PathBuf::from("/mock/project/crates/pkg-b/Cargo.toml"),
         );
 
         let mut input = ReleaseSagaData::new(
@@ -1466,7 +2547,7 @@ mod tests {
                 make_test_release("pkg-a", "1.0.0", "1.0.1"),
                 make_test_release("pkg-b", "2.0.0", "2.0.1"),
             ],
-            package_paths,
+            package_manifests,
             Vec::new(),
             Vec::new(),
         )
@@ -1503,6 +2584,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_tags_skips_untagged_packages() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: CreateTagsStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateTagsStep::new(TagFormat::CratePrefixed, true);
+
+        let mut input = ReleaseSagaData::new(
+            PathBuf::from("/mock/project/.changeset"),
+            PathBuf::from("/mock/project/Cargo.toml"),
+            vec![
+                make_test_release("pkg-a", "1.0.0", "1.0.1"),
+                make_test_release("internal", "0.3.0", "0.3.1"),
+            ],
+            IndexMap::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .with_options(SagaReleaseOptions {
+            should_commit: true,
+            should_create_tags: true,
+            ..SagaReleaseOptions::default()
+        })
+        .with_untagged_packages(vec!["internal".to_string()]);
+        input.commit_result = Some(CommitResult {
+            sha: "abc123".to_string(),
+            message: "Release".to_string(),
+        });
+
+        let result = SagaStep::execute(&step, &ctx, input)?;
+        assert_eq!(result.tags_created.len(), 1);
+        assert_eq!(result.tags_created[0].name, "pkg-a@v1.0.1");
+
+        SagaStep::compensate(&step, &ctx, result)?;
+        assert_eq!(
+            git_provider.deleted_tags(),
+            vec!["pkg-a@v1.0.1".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_tags_embeds_changelog_section() -> anyhow::Result<()> {
+        let git_provider = Arc::new(MockGitProvider::new());
+        let ctx = make_test_context(
+            Arc::clone(&git_provider),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        );
+
+        let step: CreateTagsStep<
+            MockGitProvider,
+            MockManifestWriter,
+            MockChangesetReader,
+            MockReleaseStateIO,
+            MockChangelogWriter,
+        > = CreateTagsStep::new(TagFormat::VersionOnly, false).with_tag_body(TagBody::Changelog);
+        let mut input = make_test_data();
+        input.changelog_updates = vec![ChangelogUpdate {
+            path: PathBuf::from("/mock/project/CHANGELOG.md"),
+            package: Some("pkg-a".to_string()),
+            version: semver::Version::new(1, 0, 1),
+            created: false,
+            excerpt: "### Fixed\n\n- Bug fix".to_string(),
+            json_path: None,
+            archive_paths: Vec::new(),
+        }];
+        input.commit_result = Some(CommitResult {
+            sha: "abc123".to_string(),
+            message: "Release".to_string(),
+        });
+
+        SagaStep::execute(&step, &ctx, input)?;
+
+        let (_, message) = &git_provider.tags_created()[0];
+        assert_eq!(message, "Release pkg-a v1.0.1\n\n### Fixed\n\n- Bug fix\n");
+
+        Ok(())
+    }
+
     #[test]
     fn create_tags_partial_failure_deletes_multiple_tags_when_third_fails() {
         let git_provider = Arc::new(MockGitProvider::new());
@@ -1523,18 +2697,18 @@ mod tests {
             MockChangelogWriter,
         > = CreateTagsStep::new(TagFormat::CratePrefixed, true);
 
-        let mut package_paths = IndexMap::new();
-        package_paths.insert(
+        let mut package_manifests = IndexMap::new();
+        package_manifests.insert(
             "pkg-a".to_string(),
-            PathBuf::from("/mock/project/crates/pkg-a"),
+            PathBuf::from("/mock/project/crates/pkg-a/Cargo.toml"),
         );
-        package_paths.insert(
+        package_manifests.insert(
             "pkg-b".to_string(),
-            PathBuf::from("/mock/project/crates/pkg-b"),
+            PathBuf::from("/mock/project/crates/pkg-b/Cargo.toml"),
         );
-        package_paths.insert(
+        package_manifests.insert(
             "pkg-c".to_string(),
-            PathBuf::from("/mock/project/crates/pkg-c"),
+            PathBuf::from("/mock/project/crates/pkg-c/Cargo.toml"),
         );
 
         let mut input = ReleaseSagaData::new(
@@ -1545,7 +2719,7 @@ mod tests {
                 make_test_release("pkg-b", "2.0.0", "2.0.1"),
                 make_test_release("pkg-c", "3.0.0", "3.0.1"),
             ],
-            package_paths,
+            package_manifests,
             Vec::new(),
             Vec::new(),
         )
@@ -1587,6 +2761,41 @@ mod tests {
         );
     }
 
+    type TestStampFilesStep = StampFilesStep<
+        MockGitProvider,
+        MockManifestWriter,
+        MockChangesetReader,
+        MockReleaseStateIO,
+        MockChangelogWriter,
+    >;
+
+    #[test]
+    fn stamp_files_writes_and_restores_stamped_content() -> anyhow::Result<()> {
+        let readme = PathBuf::from("/mock/project/README.md");
+        let io = Arc::new(InMemoryTextFileIO::new().with_file(readme.clone(), "pkg-a = \"1.0.0\""));
+        let ctx = make_test_context(
+            Arc::new(MockGitProvider::new()),
+            Arc::new(MockManifestWriter::new()),
+            Arc::new(MockChangesetReader::new()),
+            Arc::new(MockReleaseStateIO::new()),
+        )
+        .with_text_file_io(Arc::clone(&io) as Arc<dyn crate::traits::TextFileIO>);
+        let data = make_test_data().with_stamped_files(vec![StampedFile {
+            path: readme.clone(),
+            original_content: "pkg-a = \"1.0.0\"".to_string(),
+            stamped_content: "pkg-a = \"1.0.1\"".to_string(),
+        }]);
+        let step = TestStampFilesStep::new();
+
+        SagaStep::execute(&step, &ctx, data.clone())?;
+        assert_eq!(io.file(&readme).as_deref(), Some("pkg-a = \"1.0.1\""));
+
+        SagaStep::compensate(&step, &ctx, data)?;
+        assert_eq!(io.file(&readme).as_deref(), Some("pkg-a = \"1.0.0\""));
+
+        Ok(())
+    }
+
     #[allow(clippy::items_after_statements)]
     mod rollback_integration {
         use changeset_core::{ChangeCategory, Changeset, PackageRelease};
@@ -1604,8 +2813,12 @@ mod tests {
                     bump_type: BumpType::Patch,
                 }],
                 category: ChangeCategory::Fixed,
+                scope: None,
                 consumed_for_prerelease: None,
                 graduate: false,
+                confidential: false,
+                embargo: None,
+                advisory: None,
             }
         }
 
@@ -2016,5 +3229,78 @@ mod tests {
                 "consumed status should be cleared after rollback (was not consumed before)"
             );
         }
+
+        #[test]
+        fn consumed_state_file_is_staged_and_restored_on_failure() {
+            use crate::operations::release::steps::ConsumedStateFile;
+
+            let git_provider = Arc::new(MockGitProvider::new());
+            let changeset_path = PathBuf::from("/mock/project/.changeset/changesets/fix.md");
+            let state_path = PathBuf::from("/mock/project/.changeset/consumed.toml");
+            let original = "[consumed]\nabc = \"1.0.1-alpha.1\"\n";
+            let changeset_rw = Arc::new(
+                MockChangesetReader::new()
+                    .with_changeset(changeset_path.clone(), make_test_changeset("pkg-a")),
+            );
+            let text_file_io = Arc::new(InMemoryTextFileIO::new());
+
+            git_provider.set_fail_on_commit(true);
+
+            let ctx = make_test_context(
+                Arc::clone(&git_provider),
+                Arc::new(MockManifestWriter::new()),
+                Arc::clone(&changeset_rw),
+                Arc::new(MockReleaseStateIO::new()),
+            )
+            .with_text_file_io(Arc::clone(&text_file_io) as Arc<dyn crate::traits::TextFileIO>);
+
+            type MarkConsumed = MarkChangesetsConsumedStep<
+                MockGitProvider,
+                MockManifestWriter,
+                MockChangesetReader,
+                MockReleaseStateIO,
+                MockChangelogWriter,
+            >;
+            type Stage = StageFilesStep<
+                MockGitProvider,
+                MockManifestWriter,
+                MockChangesetReader,
+                MockReleaseStateIO,
+                MockChangelogWriter,
+            >;
+            type Commit = CreateCommitStep<
+                MockGitProvider,
+                MockManifestWriter,
+                MockChangesetReader,
+                MockReleaseStateIO,
+                MockChangelogWriter,
+            >;
+
+            let saga = SagaBuilder::new()
+                .first_step(MarkConsumed::new())
+                .then(Stage::new())
+                .then(Commit::new("Release {new-version}".to_string(), false))
+                .build();
+
+            let mut input = make_test_data();
+            input.is_prerelease_release = true;
+            input.changeset_files = vec![ChangesetFileState {
+                path: changeset_path.clone(),
+                original_consumed_status: None,
+                backup: None,
+            }];
+            input.consumed_state_file = Some(ConsumedStateFile {
+                path: state_path.clone(),
+                original_content: Some(original.to_string()),
+            });
+
+            let result = saga.execute(&ctx, input);
+            assert!(result.is_err(), "saga should fail on commit");
+
+            let staged = git_provider.staged_files();
+            assert!(staged.contains(&state_path));
+            assert!(staged.contains(&changeset_path));
+            assert_eq!(text_file_io.file(&state_path).as_deref(), Some(original));
+        }
     }
 }
@@ -0,0 +1,400 @@
+//! Plans the version stamps that rewrite versions in files outside the manifests.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use changeset_project::{
+    CargoProject, PackageChangesetConfig, ReadmeBadges, RootChangesetConfig, VersionStamp,
+};
+use indexmap::IndexMap;
+use regex::Regex;
+use semver::Version;
+
+use super::steps::StampedFile;
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::TextFileIO;
+use crate::types::PackageVersion;
+
+const VERSION_PLACEHOLDER: &str = "{version}";
+const PACKAGE_PLACEHOLDER: &str = "{package}";
+
+/// Matches a semantic version, including pre-release and build metadata.
+const VERSION_PATTERN: &str = r"\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?";
+
+/// Versioned docs.rs, crates.io and shields.io URLs of a crate.
+const README_BADGE_PATTERN: &str = r"(?:docs\.rs/|docs\.rs/crate/|crates\.io/crates/|img\.shields\.io/docsrs/|img\.shields\.io/crates/[a-z]+/){package}/{version}";
+
+const README: &str = "README.md";
+
+/// A compiled stamp pattern and the version it writes into every match.
+#[derive(Debug)]
+struct StampRule {
+    regex: Regex,
+    version: Version,
+}
+
+impl StampRule {
+    fn compile(pattern: &str, package: Option<&str>, version: Version) -> Result<Self> {
+        let invalid = |reason: String| OperationError::StampPattern {
+            pattern: pattern.to_string(),
+            reason,
+        };
+
+        if pattern.matches(VERSION_PLACEHOLDER).count() != 1 {
+            return Err(invalid(format!(
+                "must contain exactly one {VERSION_PLACEHOLDER} placeholder"
+            )));
+        }
+
+        let mut expanded = pattern.replace(
+            VERSION_PLACEHOLDER,
+            &format!("(?P<version>{VERSION_PATTERN})"),
+        );
+        if let Some(package) = package {
+            expanded = expanded.replace(PACKAGE_PLACEHOLDER, &regex::escape(package));
+        }
+
+        let regex = Regex::new(&expanded).map_err(|e| invalid(e.to_string()))?;
+        Ok(Self { regex, version })
+    }
+
+    /// Replaces the version captured by every match, leaving the rest untouched.
+    fn apply(&self, content: &str) -> String {
+        let version = self.version.to_string();
+        let mut stamped = String::with_capacity(content.len());
+        let mut last = 0;
+        for captures in self.regex.captures_iter(content) {
+            if let Some(old) = captures.name("version") {
+                stamped.push_str(&content[last..old.start()]);
+                stamped.push_str(&version);
+                last = old.end();
+            }
+        }
+        stamped.push_str(&content[last..]);
+        stamped
+    }
+}
+
+/// Expands a stamp into the rules it applies for this release.
+///
+/// A stamp naming a package writes that package's version, and nothing if the
+/// package is not released. A pattern with `{package}` applies once per
+/// released package. Any other pattern writes the highest released version.
+fn stamp_rules(stamp: &VersionStamp, releases: &[PackageVersion]) -> Result<Vec<StampRule>> {
+    if let Some(package) = stamp.package() {
+        return releases
+            .iter()
+            .filter(|release| release.name == package)
+            .map(|release| {
+                StampRule::compile(
+                    stamp.pattern(),
+                    Some(&release.name),
+                    release.new_version.clone(),
+                )
+            })
+            .collect();
+    }
+
+    if stamp.pattern().contains(PACKAGE_PLACEHOLDER) {
+        return releases
+            .iter()
+            .map(|release| {
+                StampRule::compile(
+                    stamp.pattern(),
+                    Some(&release.name),
+                    release.new_version.clone(),
+                )
+            })
+            .collect();
+    }
+
+    releases
+        .iter()
+        .map(|release| &release.new_version)
+        .max()
+        .map(|version| StampRule::compile(stamp.pattern(), None, version.clone()))
+        .into_iter()
+        .collect()
+}
+
+/// The configured stamps, then `extra` ones for this release, then the stamps
+/// of the `readme-badges` settings.
+pub(super) fn release_stamps(
+    project: &CargoProject,
+    root_config: &RootChangesetConfig,
+    package_configs: &HashMap<String, PackageChangesetConfig>,
+    extra: &[VersionStamp],
+) -> Vec<VersionStamp> {
+    root_config
+        .version_stamps()
+        .iter()
+        .chain(extra)
+        .cloned()
+        .chain(readme_badge_stamps(
+            project,
+            root_config.readme_badges(),
+            package_configs,
+        ))
+        .collect()
+}
+
+/// Expands the `readme-badges` settings into stamps that rewrite each
+/// package's badges and links in its own `README.md` and the root one.
+fn readme_badge_stamps(
+    project: &CargoProject,
+    root_badges: &ReadmeBadges,
+    package_configs: &HashMap<String, PackageChangesetConfig>,
+) -> Vec<VersionStamp> {
+    let mut stamps = Vec::new();
+    for package in &project.packages {
+        let package_badges = package_configs
+            .get(&package.name)
+            .map(PackageChangesetConfig::readme_badges);
+        let enabled = package_badges
+            .and_then(ReadmeBadges::enabled)
+            .or(root_badges.enabled())
+            .unwrap_or(false);
+        if !enabled {
+            continue;
+        }
+
+        let files = match package.path.strip_prefix(&project.root) {
+            Ok(relative) if !relative.as_os_str().is_empty() => {
+                let relative = relative.to_string_lossy().replace('\\', "/");
+                format!("{{{README},{relative}/{README}}}")
+            }
+            _ => README.to_string(),
+        };
+
+        let patterns = std::iter::once(README_BADGE_PATTERN)
+            .chain(root_badges.patterns().iter().map(String::as_str))
+            .chain(
+                package_badges
+                    .map_or(&[][..], ReadmeBadges::patterns)
+                    .iter()
+                    .map(String::as_str),
+            );
+        for pattern in patterns {
+            stamps.push(VersionStamp::new(&files, pattern).with_package(&package.name));
+        }
+    }
+    stamps
+}
+
+/// Computes the new content of every file the stamps change.
+///
+/// Files matched by several stamps receive all of them, in configuration order.
+pub(super) fn plan_stamps(
+    io: &dyn TextFileIO,
+    project_root: &Path,
+    stamps: &[VersionStamp],
+    releases: &[PackageVersion],
+) -> Result<Vec<StampedFile>> {
+    let mut files: IndexMap<PathBuf, (String, String)> = IndexMap::new();
+
+    for stamp in stamps {
+        let rules = stamp_rules(stamp, releases)?;
+        if rules.is_empty() {
+            continue;
+        }
+
+        for path in io.find_files(project_root, stamp.files())? {
+            if !files.contains_key(&path) {
+                let content = io.read_file(&path)?;
+                files.insert(path.clone(), (content.clone(), content));
+            }
+            if let Some((_, stamped)) = files.get_mut(&path) {
+                for rule in &rules {
+                    *stamped = rule.apply(stamped);
+                }
+            }
+        }
+    }
+
+    Ok(files
+        .into_iter()
+        .filter(|(_, (original, stamped))| original != stamped)
+        .map(|(path, (original_content, stamped_content))| StampedFile {
+            path,
+            original_content,
+            stamped_content,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use changeset_core::BumpType;
+
+    use super::*;
+    use crate::providers::memory::InMemoryTextFileIO;
+
+    fn release(name: &str, current: &str, new: &str) -> PackageVersion {
+        PackageVersion {
+            name: name.to_string(),
+            current_version: current.parse().expect("valid version"),
+            new_version: new.parse().expect("valid version"),
+            bump_type: BumpType::Minor,
+        }
+    }
+
+    #[test]
+    fn stamps_version_in_matching_files() -> anyhow::Result<()> {
+        let root = PathBuf::from("/project");
+        let io = InMemoryTextFileIO::new()
+            .with_file(
+                root.join("README.md"),
+                "```toml\n[dependencies]\nmy-crate = \"1.2.0\"\nserde = \"1.0.0\"\n```\n",
+            )
+            .with_file(root.join("docs/guide.md"), "my-crate = \"1.2.0\"\n");
+        let stamps = [VersionStamp::new("*.md", r#"my-crate = "{version}""#)];
+
+        let stamped = plan_stamps(
+            &io,
+            &root,
+            &stamps,
+            &[release("my-crate", "1.2.0", "1.3.0")],
+        )?;
+
+        assert_eq!(stamped.len(), 1);
+        assert_eq!(stamped[0].path, root.join("README.md"));
+        assert_eq!(
+            stamped[0].stamped_content,
+            "```toml\n[dependencies]\nmy-crate = \"1.3.0\"\nserde = \"1.0.0\"\n```\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn package_placeholder_stamps_each_release() -> anyhow::Result<()> {
+        let root = PathBuf::from("/project");
+        let io = InMemoryTextFileIO::new().with_file(
+            root.join("README.md"),
+            "core = \"0.1.0\"\ncli = \"2.0.0-rc.1\"\nother = \"1.0.0\"\n",
+        );
+        let stamps = [VersionStamp::new("README.md", r#"{package} = "{version}""#)];
+        let releases = [
+            release("core", "0.1.0", "0.2.0"),
+            release("cli", "2.0.0-rc.1", "2.0.0"),
+        ];
+
+        let stamped = plan_stamps(&io, &root, &stamps, &releases)?;
+
+        assert_eq!(
+            stamped[0].stamped_content,
+            "core = \"0.2.0\"\ncli = \"2.0.0\"\nother = \"1.0.0\"\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn stamp_for_unreleased_package_is_skipped() -> anyhow::Result<()> {
+        let root = PathBuf::from("/project");
+        let io =
+            InMemoryTextFileIO::new().with_file(root.join("Chart.yaml"), "appVersion: 1.0.0\n");
+        let stamps =
+            [VersionStamp::new("Chart.yaml", "appVersion: {version}").with_package("server")];
+
+        let stamped = plan_stamps(&io, &root, &stamps, &[release("client", "1.0.0", "1.1.0")])?;
+
+        assert!(stamped.is_empty());
+
+        Ok(())
+    }
+
+    fn workspace(root: &Path, packages: &[&str]) -> CargoProject {
+        CargoProject {
+            root: root.to_path_buf(),
+            kind: changeset_project::ProjectKind::VirtualWorkspace,
+            packages: packages
+                .iter()
+                .map(|name| changeset_core::PackageInfo {
+                    name: (*name).to_string(),
+                    version: "1.0.0".parse().expect("valid version"),
+                    path: root.join("crates").join(name),
+                })
+                .collect(),
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn readme_badges_follow_package_overrides() -> anyhow::Result<()> {
+        let root = PathBuf::from("/project");
+        let project = workspace(&root, &["core", "cli"]);
+        let io = InMemoryTextFileIO::new()
+            .with_file(
+                root.join("README.md"),
+                "https://docs.rs/core/1.0.0 https://docs.rs/cli/1.0.0\n",
+            )
+            .with_file(
+                root.join("crates/core/README.md"),
+                "https://img.shields.io/crates/d/core/1.0.0 [core 1.0.0]\n",
+            );
+        let package_configs = HashMap::from([
+            (
+                "core".to_string(),
+                PackageChangesetConfig::default().with_readme_badges(
+                    ReadmeBadges::default().with_patterns(vec![r"\[core {version}\]".to_string()]),
+                ),
+            ),
+            (
+                "cli".to_string(),
+                PackageChangesetConfig::default().with_readme_badges(ReadmeBadges::new(false)),
+            ),
+        ]);
+        let stamps = readme_badge_stamps(&project, &ReadmeBadges::new(true), &package_configs);
+
+        let stamped = plan_stamps(
+            &io,
+            &root,
+            &stamps,
+            &[
+                release("core", "1.0.0", "1.1.0"),
+                release("cli", "1.0.0", "1.1.0"),
+            ],
+        )?;
+
+        assert_eq!(stamped.len(), 2);
+        assert_eq!(
+            stamped[0].stamped_content,
+            "https://docs.rs/core/1.1.0 https://docs.rs/cli/1.0.0\n"
+        );
+        assert_eq!(
+            stamped[1].stamped_content,
+            "https://img.shields.io/crates/d/core/1.1.0 [core 1.1.0]\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn readme_badges_are_off_by_default() {
+        let root = PathBuf::from("/project");
+        let project = workspace(&root, &["core"]);
+
+        let stamps = readme_badge_stamps(&project, &ReadmeBadges::default(), &HashMap::new());
+
+        assert!(stamps.is_empty());
+    }
+
+    #[test]
+    fn rejects_pattern_without_version_placeholder() {
+        let root = PathBuf::from("/project");
+        let io = InMemoryTextFileIO::new().with_file(root.join("README.md"), "");
+        let stamps = [VersionStamp::new("README.md", "my-crate")];
+
+        let result = plan_stamps(
+            &io,
+            &root,
+            &stamps,
+            &[release("my-crate", "1.0.0", "1.1.0")],
+        );
+
+        assert!(matches!(result, Err(OperationError::StampPattern { .. })));
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+use std::str::FromStr;
+
+use changeset_saga::SagaStep;
+
+/// Stable identifiers of the release saga steps, in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReleaseStep {
+    RestoreChangelogs,
+    PreChecks,
+    WriteManifests,
+    UpdateDependencies,
+    StampFiles,
+    RemoveWorkspaceVersion,
+    MarkConsumed,
+    ClearConsumed,
+    DeleteChangesets,
+    UpdateState,
+    StageFiles,
+    CreateCommit,
+    CreateTags,
+}
+
+impl ReleaseStep {
+    pub const ALL: [Self; 13] = [
+        Self::RestoreChangelogs,
+        Self::PreChecks,
+        Self::WriteManifests,
+        Self::UpdateDependencies,
+        Self::StampFiles,
+        Self::RemoveWorkspaceVersion,
+        Self::MarkConsumed,
+        Self::ClearConsumed,
+        Self::DeleteChangesets,
+        Self::UpdateState,
+        Self::StageFiles,
+        Self::CreateCommit,
+        Self::CreateTags,
+    ];
+
+    #[must_use]
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::RestoreChangelogs => "restore-changelogs",
+            Self::PreChecks => "pre-checks",
+            Self::WriteManifests => "write-manifests",
+            Self::UpdateDependencies => "update-dependencies",
+            Self::StampFiles => "stamp-files",
+            Self::RemoveWorkspaceVersion => "remove-workspace-version",
+            Self::MarkConsumed => "mark-consumed",
+            Self::ClearConsumed => "clear-consumed",
+            Self::DeleteChangesets => "delete-changesets",
+            Self::StageFiles => "stage-files",
+            Self::CreateCommit => "create-commit",
+            Self::CreateTags => "create-tags",
+            Self::UpdateState => "update-state",
+        }
+    }
+}
+
+impl fmt::Display for ReleaseStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl FromStr for ReleaseStep {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|step| step.id() == s)
+            .ok_or_else(|| format!("unknown release step '{s}'"))
+    }
+}
+
+/// Restricts which release saga steps run, for diagnosing failed releases.
+///
+/// Disabled steps pass their input through unchanged and have nothing to roll
+/// back, so later steps run against whatever state the skipped step would
+/// have changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StepControl {
+    skip: Vec<ReleaseStep>,
+    only_until: Option<ReleaseStep>,
+}
+
+impl StepControl {
+    #[must_use]
+    pub fn with_skip(mut self, step: ReleaseStep) -> Self {
+        self.skip.push(step);
+        self
+    }
+
+    /// Stops the release after `step`; every later step is skipped.
+    #[must_use]
+    pub fn with_only_until(mut self, step: ReleaseStep) -> Self {
+        self.only_until = Some(step);
+        self
+    }
+
+    #[must_use]
+    pub fn runs(&self, step: ReleaseStep) -> bool {
+        let within_limit = self.only_until.is_none_or(|last| {
+            let position = |s: ReleaseStep| ReleaseStep::ALL.iter().position(|&other| other == s);
+            position(step) <= position(last)
+        });
+        within_limit && !self.skip.contains(&step)
+    }
+}
+
+/// Wraps a saga step so that it can be disabled by a [`StepControl`].
+///
+/// The step is named by its [`ReleaseStep`] id in audit logs, timings and
+/// compensation journals.
+pub(super) struct Controlled<St> {
+    step: St,
+    id: ReleaseStep,
+    enabled: bool,
+}
+
+impl<St> Controlled<St> {
+    pub(super) fn new(step: St, id: ReleaseStep, control: &StepControl) -> Self {
+        Self {
+            step,
+            id,
+            enabled: control.runs(id),
+        }
+    }
+}
+
+impl<St> SagaStep for Controlled<St>
+where
+    St: SagaStep,
+    St::Input: Into<St::Output>,
+{
+    type Input = St::Input;
+    type Output = St::Output;
+    type Context = St::Context;
+    type Error = St::Error;
+
+    fn name(&self) -> &'static str {
+        self.id.id()
+    }
+
+    fn execute(
+        &self,
+        ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        if self.enabled {
+            self.step.execute(ctx, input)
+        } else {
+            tracing::debug!(step = self.id.id(), "skipping disabled release step");
+            Ok(input.into())
+        }
+    }
+
+    fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        if self.enabled {
+            self.step.compensate(ctx, input)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn compensation_description(&self) -> String {
+        self.step.compensation_description()
+    }
+
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        self.enabled
+            .then(|| self.step.idempotency_key(input))
+            .flatten()
+    }
+
+    fn resume(&self, ctx: &Self::Context, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if self.enabled {
+            self.step.resume(ctx, input)
+        } else {
+            Ok(input.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip() {
+        for step in ReleaseStep::ALL {
+            assert_eq!(step.id().parse::<ReleaseStep>(), Ok(step));
+        }
+        assert!("create_tags".parse::<ReleaseStep>().is_err());
+    }
+
+    #[test]
+    fn only_until_and_skip_combine() {
+        let control = StepControl::default()
+            .with_only_until(ReleaseStep::StageFiles)
+            .with_skip(ReleaseStep::DeleteChangesets);
+
+        assert!(control.runs(ReleaseStep::WriteManifests));
+        assert!(control.runs(ReleaseStep::StageFiles));
+        assert!(!control.runs(ReleaseStep::DeleteChangesets));
+        assert!(!control.runs(ReleaseStep::CreateCommit));
+        assert!(!control.runs(ReleaseStep::CreateTags));
+        assert!(StepControl::default().runs(ReleaseStep::UpdateState));
+    }
+}
@@ -20,6 +20,22 @@ pub struct ChangelogFileState {
     pub file_existed: bool,
 }
 
+/// A file rewritten by a version stamp, with its content before the release.
+#[derive(Debug, Clone)]
+pub struct StampedFile {
+    pub path: PathBuf,
+    pub original_content: String,
+    pub stamped_content: String,
+}
+
+/// The file the consumed marks are kept in, with its content before the release.
+#[derive(Debug, Clone)]
+pub struct ConsumedStateFile {
+    pub path: PathBuf,
+    /// `None` when the file did not exist.
+    pub original_content: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PrereleaseStateUpdate {
     pub original: Option<PrereleaseState>,
@@ -0,0 +1,164 @@
+use std::fmt::Write;
+
+use super::operation::ReleaseOutput;
+
+/// Default file name for the release summary, relative to the changeset directory.
+pub const RELEASE_SUMMARY_FILENAME: &str = "RELEASE_SUMMARY.md";
+
+/// Renders a markdown summary of an executed release.
+///
+/// The summary lists the version changes, created tags, the release commit and
+/// the changelog entries, so it can be forwarded to deployment pipelines or
+/// chat notifications.
+#[must_use]
+pub fn render_release_summary(output: &ReleaseOutput) -> String {
+    let mut summary = String::from("# Release Summary\n\n");
+
+    summary.push_str("| Package | Previous | New | Bump |\n");
+    summary.push_str("| --- | --- | --- | --- |\n");
+    for release in &output.planned_releases {
+        let _ = writeln!(
+            summary,
+            "| {} | {} | {} | {:?} |",
+            release.name, release.current_version, release.new_version, release.bump_type
+        );
+    }
+
+    if let Some(git_result) = &output.git_result {
+        if !git_result.tags_created.is_empty() {
+            summary.push_str("\n## Tags\n\n");
+            for tag in &git_result.tags_created {
+                let _ = writeln!(summary, "- `{}`", tag.name);
+            }
+        }
+
+        if git_result.release_commits.is_empty() {
+            if let Some(commit) = &git_result.commit {
+                let subject = commit.message.lines().next().unwrap_or_default();
+                let _ = write!(summary, "\n## Commit\n\n`{}` {subject}\n", commit.sha);
+            }
+        } else {
+            summary.push_str("\n## Commits\n\n");
+            for release_commit in &git_result.release_commits {
+                let commit = &release_commit.commit;
+                let subject = commit.message.lines().next().unwrap_or_default();
+                let _ = writeln!(summary, "- `{}` {subject}", commit.sha);
+            }
+        }
+    }
+
+    for section in changelog_sections(output) {
+        summary.push('\n');
+        summary.push_str(&section);
+    }
+
+    summary
+}
+
+/// Renders only the changelog entries a release adds, one section per changelog.
+///
+/// The text is independent of the release date and of file system state, so it
+/// can be compared across runs or against a committed snapshot.
+#[must_use]
+pub fn render_changelog_preview(output: &ReleaseOutput) -> String {
+    changelog_sections(output).collect::<Vec<_>>().join("\n")
+}
+
+fn changelog_sections(output: &ReleaseOutput) -> impl Iterator<Item = String> + '_ {
+    output
+        .changelog_updates
+        .iter()
+        .filter(|update| !update.excerpt.is_empty())
+        .map(|update| {
+            let heading = update.package.as_deref().map_or_else(
+                || format!("Changelog {}", update.version),
+                |package| format!("Changelog: {package} {}", update.version),
+            );
+            format!("## {heading}\n\n{}\n", update.excerpt)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use std::path::PathBuf;
+
+    use changeset_core::BumpType;
+
+    use super::*;
+    use crate::operations::release::{
+        ChangelogUpdate, CommitResult, GitOperationResult, TagResult,
+    };
+    use crate::types::PackageVersion;
+
+    fn make_output() -> ReleaseOutput {
+        ReleaseOutput {
+            planned_releases: vec![PackageVersion {
+                name: "crate-a".to_string(),
+                current_version: "1.0.0".parse().expect("valid version"),
+                new_version: "1.1.0".parse().expect("valid version"),
+                bump_type: BumpType::Minor,
+            }],
+            unchanged_packages: Vec::new(),
+            changesets_consumed: Vec::new(),
+            changeset_owners: HashMap::new(),
+            changelog_updates: vec![ChangelogUpdate {
+                path: PathBuf::from("crates/crate-a/CHANGELOG.md"),
+                package: Some("crate-a".to_string()),
+                version: "1.1.0".parse().expect("valid version"),
+                created: false,
+                excerpt: "### Added\n\n- New feature".to_string(),
+                json_path: None,
+                archive_paths: Vec::new(),
+            }],
+            git_result: None,
+            step_timings: Vec::new(),
+            provenance: Vec::new(),
+            file_diffs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_version_table_and_changelog() {
+        let summary = render_release_summary(&make_output());
+
+        assert!(summary.starts_with("# Release Summary\n"));
+        assert!(summary.contains("| crate-a | 1.0.0 | 1.1.0 | Minor |"));
+        assert!(summary.contains("## Changelog: crate-a 1.1.0\n\n### Added\n\n- New feature"));
+        assert!(!summary.contains("## Tags"));
+        assert!(!summary.contains("## Commit"));
+    }
+
+    #[test]
+    fn renders_tags_and_commit() {
+        let mut output = make_output();
+        output.git_result = Some(GitOperationResult {
+            commit: Some(CommitResult {
+                sha: "abc1234".to_string(),
+                message: "Release crate-a v1.1.0\n\nDetails".to_string(),
+            }),
+            release_commits: Vec::new(),
+            tags_created: vec![TagResult {
+                name: "crate-a@v1.1.0".to_string(),
+                target_sha: "abc1234".to_string(),
+            }],
+            changesets_deleted: Vec::new(),
+        });
+
+        let summary = render_release_summary(&output);
+
+        assert!(summary.contains("## Tags\n\n- `crate-a@v1.1.0`"));
+        assert!(summary.contains("## Commit\n\n`abc1234` Release crate-a v1.1.0\n"));
+    }
+
+    #[test]
+    fn changelog_preview_contains_only_changelog_sections() {
+        let preview = render_changelog_preview(&make_output());
+
+        assert_eq!(
+            preview,
+            "## Changelog: crate-a 1.1.0\n\n### Added\n\n- New feature\n"
+        );
+    }
+}
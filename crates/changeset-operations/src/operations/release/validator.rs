@@ -1,10 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
-use changeset_core::{PackageInfo, PrereleaseSpec};
+use changeset_core::{BumpType, PackageInfo, PrereleaseSpec};
 use changeset_project::{GraduationState, PrereleaseState, ProjectKind};
 use changeset_version::{is_prerelease, is_zero_version};
 
-use crate::types::PackageReleaseConfig;
+use crate::types::{PackageReleaseConfig, PrereleaseSource};
 
 /// Input from CLI for validation.
 #[derive(Debug, Clone, Default)]
@@ -17,6 +17,11 @@ pub struct ReleaseCliInput {
     pub cli_graduate: HashSet<String>,
     /// Whether --graduate was passed without specific crates
     pub graduate_all: bool,
+    /// Per-package bumps from --force-bump crate:type
+    pub cli_forced_bumps: HashMap<String, BumpType>,
+    /// Prerelease tags of the channels packages are released on; the other
+    /// sources take precedence
+    pub channel_prerelease: HashMap<String, PrereleaseSpec>,
 }
 
 /// A single validation error with actionable tip.
@@ -48,6 +53,12 @@ pub enum ValidationError {
         tag: String,
         reason: String,
     },
+    /// Invalid prerelease tag passed with --prerelease (`package` is `None` for a global tag)
+    InvalidCliPrereleaseTag {
+        package: Option<String>,
+        tag: String,
+        reason: String,
+    },
 }
 
 impl ValidationError {
@@ -84,6 +95,13 @@ impl ValidationError {
                     "Run `cargo changeset manage pre-release --remove {package}` and re-add with a valid tag"
                 )
             }
+            Self::InvalidCliPrereleaseTag { package, .. } => {
+                let prefix = package.as_ref().map_or(String::new(), |p| format!("{p}:"));
+                format!(
+                    "Use ASCII letters, digits and '-' only, without leading zeros in \
+                     numeric tags, e.g. `--prerelease {prefix}alpha`"
+                )
+            }
         }
     }
 }
@@ -134,6 +152,23 @@ impl std::fmt::Display for ValidationError {
                      {reason}"
                 )
             }
+            Self::InvalidCliPrereleaseTag {
+                package: Some(package),
+                tag,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "invalid prerelease tag '{tag}' for package '{package}': {reason}"
+                )
+            }
+            Self::InvalidCliPrereleaseTag {
+                package: None,
+                tag,
+                reason,
+            } => {
+                write!(f, "invalid prerelease tag '{tag}': {reason}")
+            }
         }
     }
 }
@@ -316,6 +351,15 @@ impl ReleaseValidator {
             &mut collector,
         );
 
+        Self::validate_packages_exist(
+            cli_input.cli_forced_bumps.keys().map(String::as_str),
+            &package_names,
+            &available_packages,
+            &mut collector,
+        );
+
+        Self::validate_cli_prerelease_tags(cli_input, &mut collector);
+
         let parsed_cache =
             Self::validate_and_parse_toml_prerelease(prerelease_state, &mut collector);
 
@@ -365,6 +409,29 @@ impl ReleaseValidator {
         }
     }
 
+    /// Re-checks `--prerelease` tags against the semver identifier rules, so a
+    /// `PrereleaseSpec::Custom` built without parsing cannot reach version calculation.
+    fn validate_cli_prerelease_tags(
+        cli_input: &ReleaseCliInput,
+        collector: &mut ValidationErrorCollector,
+    ) {
+        let per_package = cli_input
+            .cli_prerelease
+            .iter()
+            .map(|(pkg, spec)| (Some(pkg), spec));
+        let global = cli_input.global_prerelease.iter().map(|spec| (None, spec));
+
+        for (package, spec) in per_package.chain(global) {
+            if let Err(e) = spec.identifier().parse::<PrereleaseSpec>() {
+                collector.push(ValidationError::InvalidCliPrereleaseTag {
+                    package: package.cloned(),
+                    tag: spec.identifier().to_string(),
+                    reason: e.to_string(),
+                });
+            }
+        }
+    }
+
     fn validate_prerelease_consistency(
         cli_input: &ReleaseCliInput,
         prerelease_state: Option<&PrereleaseState>,
@@ -406,7 +473,7 @@ impl ReleaseValidator {
         }
 
         if let Some(state) = graduation_state {
-            for pkg_name in state.iter() {
+            for pkg_name in state.crates() {
                 if let Some(pkg) = package_lookup.get(pkg_name) {
                     if is_prerelease(&pkg.version) {
                         collector.push(ValidationError::CannotGraduateFromPrerelease {
@@ -437,7 +504,7 @@ impl ReleaseValidator {
         }
 
         if let Some(state) = graduation_state {
-            for pkg_name in state.iter() {
+            for pkg_name in state.crates() {
                 if let Some(pkg) = package_lookup.get(pkg_name) {
                     if !is_zero_version(&pkg.version) && !is_prerelease(&pkg.version) {
                         collector.push(ValidationError::CannotGraduateStableVersion {
@@ -512,26 +579,29 @@ impl ReleaseValidator {
     ) -> ValidatedReleaseConfig {
         let mut per_package = HashMap::new();
 
+        let mut set_prerelease = |pkg: &str, spec: &PrereleaseSpec, source: PrereleaseSource| {
+            let config = per_package
+                .entry(pkg.to_string())
+                .or_insert_with(PackageReleaseConfig::default);
+            config.prerelease = Some(spec.clone());
+            config.prerelease_source = Some(source);
+        };
+
+        for (pkg, spec) in &cli_input.channel_prerelease {
+            set_prerelease(pkg, spec, PrereleaseSource::Channel);
+        }
+
         for (pkg, spec) in &parsed_cache.specs {
-            per_package
-                .entry(pkg.clone())
-                .or_insert_with(PackageReleaseConfig::default)
-                .prerelease = Some(spec.clone());
+            set_prerelease(pkg, spec, PrereleaseSource::Toml);
         }
 
         for (pkg, spec) in &cli_input.cli_prerelease {
-            per_package
-                .entry(pkg.clone())
-                .or_insert_with(PackageReleaseConfig::default)
-                .prerelease = Some(spec.clone());
+            set_prerelease(pkg, spec, PrereleaseSource::Cli);
         }
 
         if let Some(global) = &cli_input.global_prerelease {
             for pkg in packages {
-                per_package
-                    .entry(pkg.name.clone())
-                    .or_insert_with(PackageReleaseConfig::default)
-                    .prerelease = Some(global.clone());
+                set_prerelease(&pkg.name, global, PrereleaseSource::CliGlobal);
             }
         }
 
@@ -551,6 +621,13 @@ impl ReleaseValidator {
                 .graduate_zero = true;
         }
 
+        for (pkg, bump) in &cli_input.cli_forced_bumps {
+            per_package
+                .entry(pkg.clone())
+                .or_insert_with(PackageReleaseConfig::default)
+                .forced_bump = Some(*bump);
+        }
+
         if cli_input.graduate_all {
             for pkg in packages {
                 if is_zero_version(&pkg.version) {
@@ -770,6 +847,29 @@ mod tests {
                 ValidationError::PackageNotFound { .. }
             ));
         }
+
+        #[test]
+        fn unknown_package_in_forced_bump_fails() {
+            let packages = vec![make_package("known", "1.0.0")];
+            let mut cli_input = ReleaseCliInput::default();
+            cli_input
+                .cli_forced_bumps
+                .insert("unknown".to_string(), BumpType::Patch);
+
+            let result = ReleaseValidator::validate(
+                &cli_input,
+                None,
+                None,
+                &packages,
+                &ProjectKind::SinglePackage,
+            );
+
+            let errors = result.expect_err("validation should fail");
+            assert!(matches!(
+                errors.iter().next().expect("at least one error"),
+                ValidationError::PackageNotFound { .. }
+            ));
+        }
     }
 
     mod graduation_with_prerelease {
@@ -835,6 +935,57 @@ mod tests {
         }
     }
 
+    mod cli_prerelease_validation {
+        use super::*;
+
+        #[test]
+        fn custom_tag_with_leading_zero_fails() {
+            let packages = vec![make_package("crate-a", "1.0.0")];
+            let mut cli_input = ReleaseCliInput::default();
+            cli_input.cli_prerelease.insert(
+                "crate-a".to_string(),
+                PrereleaseSpec::Custom("01".to_string()),
+            );
+            cli_input.global_prerelease = Some(PrereleaseSpec::Custom("feature/foo".to_string()));
+
+            let errors = ReleaseValidator::validate(
+                &cli_input,
+                None,
+                None,
+                &packages,
+                &ProjectKind::SinglePackage,
+            )
+            .expect_err("validation should fail");
+
+            assert_eq!(errors.len(), 2);
+            assert!(
+                errors
+                    .iter()
+                    .all(|error| matches!(error, ValidationError::InvalidCliPrereleaseTag { .. }))
+            );
+        }
+
+        #[test]
+        fn custom_tag_following_semver_rules_passes() {
+            let packages = vec![make_package("crate-a", "1.0.0")];
+            let mut cli_input = ReleaseCliInput::default();
+            cli_input.cli_prerelease.insert(
+                "crate-a".to_string(),
+                PrereleaseSpec::Custom("nightly-2".to_string()),
+            );
+
+            let result = ReleaseValidator::validate(
+                &cli_input,
+                None,
+                None,
+                &packages,
+                &ProjectKind::SinglePackage,
+            );
+
+            assert!(result.is_ok());
+        }
+    }
+
     mod toml_prerelease_validation {
         use super::*;
 
@@ -921,6 +1072,7 @@ mod tests {
                 .get("crate-a")
                 .expect("crate-a should have config");
             assert!(matches!(config_a.prerelease, Some(PrereleaseSpec::Beta)));
+            assert_eq!(config_a.prerelease_source, Some(PrereleaseSource::Cli));
             assert!(config_a.graduate_zero);
 
             let config_b = config
@@ -928,8 +1080,33 @@ mod tests {
                 .get("crate-b")
                 .expect("crate-b should have config");
             assert!(matches!(config_b.prerelease, Some(PrereleaseSpec::Alpha)));
+            assert_eq!(config_b.prerelease_source, Some(PrereleaseSource::Toml));
             assert!(!config_b.graduate_zero);
         }
+
+        #[test]
+        fn carries_forced_bumps_into_config() {
+            let packages = vec![make_package("crate-a", "1.0.0")];
+            let mut cli_input = ReleaseCliInput::default();
+            cli_input
+                .cli_forced_bumps
+                .insert("crate-a".to_string(), BumpType::Minor);
+
+            let config = ReleaseValidator::validate(
+                &cli_input,
+                None,
+                None,
+                &packages,
+                &ProjectKind::SinglePackage,
+            )
+            .expect("validation should pass");
+
+            let config_a = config
+                .per_package
+                .get("crate-a")
+                .expect("crate-a should have config");
+            assert_eq!(config_a.forced_bump, Some(BumpType::Minor));
+        }
     }
 
     mod advanced_error_scenarios {
@@ -1281,6 +1458,22 @@ mod tests {
             assert!(display.contains("invalid"));
         }
 
+        #[test]
+        fn invalid_cli_prerelease_tag_display_and_tip() {
+            let error = ValidationError::InvalidCliPrereleaseTag {
+                package: Some("my-crate".to_string()),
+                tag: "feature/foo".to_string(),
+                reason: "contains invalid character '/'".to_string(),
+            };
+
+            assert!(
+                error
+                    .to_string()
+                    .contains("'feature/foo' for package 'my-crate'")
+            );
+            assert!(error.tip().contains("--prerelease my-crate:alpha"));
+        }
+
         #[test]
         fn invalid_prerelease_tag_tip() {
             let error = ValidationError::InvalidPrereleaseTag {
@@ -0,0 +1,324 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use changeset_changelog::{Changelog, ChangelogLocation};
+use changeset_core::{Changeset, PackageInfo};
+use changeset_project::CargoProject;
+use semver::Version;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::operations::history::tagged_releases;
+use crate::traits::{GitProvider, ProjectProvider, TextFileIO};
+
+/// What one package shipped since the retrospective's starting point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShippedPackage {
+    pub name: String,
+    /// Manifest version at the starting point; `None` for packages added since.
+    pub previous_version: Option<Version>,
+    pub current_version: Version,
+    /// Versions released since, oldest first. Taken from the release tags, or
+    /// just the current version when tagging is disabled.
+    pub released_versions: Vec<Version>,
+    /// Changesets that releases since the starting point consumed for the
+    /// package, newest first.
+    pub changesets: Vec<Changeset>,
+    /// Sections of the package's changelog for the released versions, oldest
+    /// first. Empty with a shared changelog.
+    pub changelog_sections: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetrospectiveOutput {
+    /// Packages that shipped at least one version since the starting point.
+    pub packages: Vec<ShippedPackage>,
+    /// Sections of the shared root changelog for the released versions,
+    /// oldest first. Empty with per-package changelogs.
+    pub root_changelog_sections: Vec<String>,
+}
+
+/// Lists what shipped between a past revision, usually a release tag, and HEAD:
+/// the versions each package released, the changesets those releases consumed
+/// and the changelog sections they wrote.
+pub struct RetrospectiveOperation<P, G, T> {
+    project_provider: P,
+    git_provider: G,
+    text_file_io: T,
+}
+
+impl<P, G, T> RetrospectiveOperation<P, G, T>
+where
+    P: ProjectProvider,
+    G: GitProvider,
+    T: TextFileIO,
+{
+    pub fn new(project_provider: P, git_provider: G, text_file_io: T) -> Self {
+        Self {
+            project_provider,
+            git_provider,
+            text_file_io,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be discovered, `since` cannot be
+    /// resolved, the history cannot be read, or a changelog cannot be read.
+    pub fn execute(&self, start_path: &Path, since: &str) -> Result<RetrospectiveOutput> {
+        let project = self.project_provider.discover_project(start_path)?;
+        let (root_config, _) = self.project_provider.load_configs(&project)?;
+        let root = &project.root;
+
+        let tags = self.git_provider.list_tags(root)?;
+        let tagged = tagged_releases(
+            &project,
+            root_config.git_config(),
+            root_config.channels(),
+            &tags,
+        );
+        let workspace_manifest =
+            self.git_provider
+                .file_at_ref(root, since, &root.join("Cargo.toml"))?;
+
+        let mut consumed = Vec::new();
+        for dir in root_config.changeset_dirs() {
+            for (path, content) in
+                self.git_provider
+                    .deleted_file_contents(root, Some(since), "HEAD", dir)?
+            {
+                if path.extension().is_some_and(|ext| ext == "md") {
+                    if let Ok(changeset) = changeset_parse::parse_changeset(&content) {
+                        consumed.push(changeset);
+                    }
+                }
+            }
+        }
+
+        let changelog_location = root_config.changelog_config().changelog;
+        let mut packages = Vec::new();
+        for package in &project.packages {
+            let previous_version = self
+                .git_provider
+                .file_at_ref(root, since, &package.path.join("Cargo.toml"))?
+                .and_then(|manifest| manifest_version(&manifest, workspace_manifest.as_deref()));
+            if previous_version.as_ref() == Some(&package.version) {
+                continue;
+            }
+
+            let mut released_versions: Vec<Version> = tagged
+                .iter()
+                .filter(|release| release.package == package.name)
+                .map(|release| release.version.clone())
+                .filter(|version| {
+                    version <= &package.version
+                        && previous_version
+                            .as_ref()
+                            .is_none_or(|previous| version > previous)
+                })
+                .collect();
+            released_versions.sort();
+            if released_versions.is_empty() {
+                released_versions.push(package.version.clone());
+            }
+
+            let changelog_sections = match changelog_location {
+                ChangelogLocation::Root => Vec::new(),
+                ChangelogLocation::PerPackage => {
+                    self.changelog_sections(&package.path.join("CHANGELOG.md"), &released_versions)?
+                }
+            };
+
+            packages.push(ShippedPackage {
+                name: package.name.clone(),
+                previous_version,
+                current_version: package.version.clone(),
+                released_versions,
+                changesets: consumed_for(&consumed, package),
+                changelog_sections,
+            });
+        }
+
+        let root_changelog_sections = match changelog_location {
+            ChangelogLocation::Root => {
+                let mut versions: Vec<Version> = packages
+                    .iter()
+                    .flat_map(|package| package.released_versions.iter().cloned())
+                    .collect();
+                versions.sort();
+                versions.dedup();
+                self.changelog_sections(&root_changelog_path(&project), &versions)?
+            }
+            ChangelogLocation::PerPackage => Vec::new(),
+        };
+
+        Ok(RetrospectiveOutput {
+            packages,
+            root_changelog_sections,
+        })
+    }
+
+    /// Sections of the changelog at `path` for `versions`, skipping versions
+    /// without a section. A missing changelog has no sections.
+    fn changelog_sections(&self, path: &Path, versions: &[Version]) -> Result<Vec<String>> {
+        let content = match self.text_file_io.read_file(path) {
+            Ok(content) => content,
+            Err(OperationError::TextFileRead { source, .. })
+                if source.kind() == ErrorKind::NotFound =>
+            {
+                return Ok(Vec::new());
+            }
+            Err(err) => return Err(err),
+        };
+        let changelog = Changelog::parse(&content);
+        Ok(versions
+            .iter()
+            .filter_map(|version| changelog.release_section(version))
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+fn root_changelog_path(project: &CargoProject) -> PathBuf {
+    project.root.join("CHANGELOG.md")
+}
+
+fn consumed_for(consumed: &[Changeset], package: &PackageInfo) -> Vec<Changeset> {
+    consumed
+        .iter()
+        .filter(|changeset| {
+            changeset
+                .releases
+                .iter()
+                .any(|release| release.name == package.name)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Version of the package in `manifest`, resolving `version.workspace = true`
+/// against the workspace manifest.
+fn manifest_version(manifest: &str, workspace_manifest: Option<&str>) -> Option<Version> {
+    let manifest: toml::Table = manifest.parse().ok()?;
+    let version = manifest.get("package")?.get("version")?;
+    if let Some(version) = version.as_str() {
+        return version.parse().ok();
+    }
+    if version.get("workspace")?.as_bool()? {
+        let workspace: toml::Table = workspace_manifest?.parse().ok()?;
+        return workspace
+            .get("workspace")?
+            .get("package")?
+            .get("version")?
+            .as_str()?
+            .parse()
+            .ok();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use changeset_changelog::ChangelogConfig;
+    use changeset_project::RootChangesetConfig;
+
+    use super::*;
+    use crate::providers::memory::{
+        InMemoryGitProvider, InMemoryProjectProvider, InMemoryTextFileIO,
+    };
+
+    const ROOT: &str = "/mock/workspace";
+
+    fn manifest(name: &str, version: &str) -> String {
+        format!("[package]\nname = \"{name}\"\nversion = \"{version}\"\n")
+    }
+
+    fn per_package_config() -> RootChangesetConfig {
+        RootChangesetConfig::default().with_changelog_config(ChangelogConfig {
+            changelog: ChangelogLocation::PerPackage,
+            ..ChangelogConfig::default()
+        })
+    }
+
+    #[test]
+    fn lists_released_versions_changesets_and_sections() -> anyhow::Result<()> {
+        let root = PathBuf::from(ROOT);
+        let provider =
+            InMemoryProjectProvider::workspace(vec![("crate-a", "1.2.0"), ("crate-b", "2.0.0")])
+                .with_root_config(per_package_config());
+        let git = InMemoryGitProvider::new()
+            .with_existing_tags(&["crate-a@v1.0.0", "crate-a@v1.1.0", "crate-a@v1.2.0"])
+            .with_file_at_ref(
+                "crate-a@v1.0.0",
+                root.join("crates/crate-a/Cargo.toml"),
+                manifest("crate-a", "1.0.0"),
+            )
+            .with_file_at_ref(
+                "crate-a@v1.0.0",
+                root.join("crates/crate-b/Cargo.toml"),
+                manifest("crate-b", "2.0.0"),
+            )
+            .with_deleted_file(
+                ".changeset/changesets/parser.md",
+                "---\n\"crate-a\": minor\n---\n\nAdd parser\n",
+            );
+        let io = InMemoryTextFileIO::new().with_file(
+            root.join("crates/crate-a/CHANGELOG.md"),
+            "# Changelog\n\n## [1.2.0] - 2025-02-01\n\n### Added\n\n- Add parser\n\n\
+             ## [1.1.0] - 2025-01-15\n\n### Fixed\n\n- Bug\n\n\
+             ## [1.0.0] - 2025-01-01\n\n### Added\n\n- Initial\n",
+        );
+        let operation = RetrospectiveOperation::new(provider, git, io);
+
+        let output = operation.execute(&root, "crate-a@v1.0.0")?;
+
+        assert_eq!(output.packages.len(), 1);
+        let shipped = &output.packages[0];
+        assert_eq!(shipped.name, "crate-a");
+        assert_eq!(shipped.previous_version, Some(Version::new(1, 0, 0)));
+        assert_eq!(
+            shipped.released_versions,
+            [Version::new(1, 1, 0), Version::new(1, 2, 0)]
+        );
+        assert_eq!(shipped.changesets.len(), 1);
+        assert_eq!(shipped.changesets[0].summary, "Add parser");
+        assert_eq!(shipped.changelog_sections.len(), 2);
+        assert!(shipped.changelog_sections[0].starts_with("## [1.1.0]"));
+        assert!(output.root_changelog_sections.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_packages_ship_their_current_version() -> anyhow::Result<()> {
+        let provider = InMemoryProjectProvider::workspace(vec![("crate-a", "0.1.0")]);
+        let operation = RetrospectiveOperation::new(
+            provider,
+            InMemoryGitProvider::new(),
+            InMemoryTextFileIO::new(),
+        );
+
+        let output = operation.execute(Path::new(ROOT), "v0.0.0")?;
+
+        assert_eq!(output.packages.len(), 1);
+        assert_eq!(output.packages[0].previous_version, None);
+        assert_eq!(
+            output.packages[0].released_versions,
+            [Version::new(0, 1, 0)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn inherited_versions_resolve_against_workspace_manifest() {
+        let workspace = "[workspace.package]\nversion = \"3.1.0\"\n";
+        let member = "[package]\nname = \"a\"\nversion.workspace = true\n";
+
+        assert_eq!(
+            manifest_version(member, Some(workspace)),
+            Some(Version::new(3, 1, 0))
+        );
+        assert_eq!(manifest_version(member, None), None);
+    }
+}
@@ -1,14 +1,46 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use changeset_core::{BumpType, Changeset, PackageInfo};
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 
 use crate::Result;
+use crate::changeset_dirs;
+use crate::operations::drift::{VersionDrift, detect_version_drift};
 use crate::planner::VersionPlanner;
-use crate::traits::{ChangesetReader, InheritedVersionChecker, ProjectProvider};
+use crate::traits::{
+    ChangesetReader, GitProvider, InheritedVersionChecker, ProjectProvider, ReleaseStateIO,
+};
 use crate::types::PackageVersion;
 
+/// When a pending changeset was committed and how many releases it has outlived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangesetAge {
+    /// Date of the commit that added the changeset file.
+    pub added: DateTime<Utc>,
+    /// Releases tagged after the changeset was added.
+    pub releases_since: usize,
+}
+
+/// Headline counts for triaging a workspace at a glance.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusSummary {
+    pub pending_changesets: usize,
+    pub packages_to_release: usize,
+    pub major: usize,
+    pub minor: usize,
+    pub patch: usize,
+    pub unchanged_packages: usize,
+    /// Packages configured for a pre-release, with their tag, sorted by name.
+    pub prerelease_queue: Vec<(String, String)>,
+    /// Packages queued to graduate from 0.x to 1.0.0.
+    pub graduation_queue: Vec<String>,
+}
+
 pub struct StatusOutput {
+    /// Counts shown before the details.
+    pub summary: StatusSummary,
     /// All parsed changesets.
     pub changesets: Vec<Changeset>,
     /// Paths to changeset files.
@@ -25,28 +57,86 @@ pub struct StatusOutput {
     pub unknown_packages: Vec<String>,
     /// Changesets consumed for pre-release versions (path, version consumed for).
     pub consumed_prerelease_changesets: Vec<(PathBuf, String)>,
+    /// Packages whose manifest version differs from their latest release tag.
+    pub version_drift: Vec<VersionDrift>,
+    /// Configured display names keyed by crate name.
+    pub display_names: HashMap<String, String>,
+    /// Ages of committed changesets, keyed by path; uncommitted ones are absent.
+    pub changeset_ages: HashMap<PathBuf, ChangesetAge>,
+    /// Owners of changesets from owned changeset directories, keyed by path.
+    pub changeset_owners: HashMap<PathBuf, String>,
+    /// Releases a changeset may stay pending through before it is stale; `0` disables.
+    pub stale_changeset_releases: usize,
+}
+
+impl StatusOutput {
+    /// Returns the configured display name for `package`, or the crate name itself.
+    #[must_use]
+    pub fn display_name<'a>(&'a self, package: &'a str) -> &'a str {
+        self.display_names
+            .get(package)
+            .map_or(package, String::as_str)
+    }
+
+    /// Pending changesets that have stayed unreleased through the configured
+    /// number of releases, in listing order.
+    #[must_use]
+    pub fn stale_changesets(&self) -> Vec<(&Path, &Changeset, ChangesetAge)> {
+        if self.stale_changeset_releases == 0 {
+            return Vec::new();
+        }
+        self.changeset_files
+            .iter()
+            .zip(&self.changesets)
+            .filter_map(|(path, changeset)| {
+                let age = *self.changeset_ages.get(path)?;
+                (age.releases_since >= self.stale_changeset_releases).then_some((
+                    path.as_path(),
+                    changeset,
+                    age,
+                ))
+            })
+            .collect()
+    }
 }
 
-pub struct StatusOperation<P, R, I> {
+pub struct StatusOperation<P, R, I, G> {
     project_provider: P,
     changeset_reader: R,
     inherited_checker: I,
+    git_provider: G,
+    release_state_io: Option<Box<dyn ReleaseStateIO>>,
 }
 
-impl<P, R, I> StatusOperation<P, R, I>
+impl<P, R, I, G> StatusOperation<P, R, I, G>
 where
     P: ProjectProvider,
     R: ChangesetReader,
     I: InheritedVersionChecker,
+    G: GitProvider,
 {
-    pub fn new(project_provider: P, changeset_reader: R, inherited_checker: I) -> Self {
+    pub fn new(
+        project_provider: P,
+        changeset_reader: R,
+        inherited_checker: I,
+        git_provider: G,
+    ) -> Self {
         Self {
             project_provider,
             changeset_reader,
             inherited_checker,
+            git_provider,
+            release_state_io: None,
         }
     }
 
+    /// Reads the pre-release and graduation queues for the summary through `io`.
+    #[must_use]
+    pub fn with_release_state_io(mut self, io: Box<dyn ReleaseStateIO>) -> Self {
+        self.release_state_io = Some(io);
+        self
+    }
+
     /// # Errors
     ///
     /// Returns an error if the project cannot be discovered or if changeset files
@@ -56,7 +146,9 @@ where
         let (root_config, _) = self.project_provider.load_configs(&project)?;
 
         let changeset_dir = project.root.join(root_config.changeset_dir());
-        let changeset_files = self.changeset_reader.list_changesets(&changeset_dir)?;
+        let changeset_dirs = changeset_dirs::changeset_dirs(&project.root, &root_config);
+        let changeset_files =
+            changeset_dirs::list_changesets(&self.changeset_reader, &changeset_dirs)?;
 
         let mut changesets = Vec::new();
         for path in &changeset_files {
@@ -64,9 +156,8 @@ where
             changesets.push(changeset);
         }
 
-        let consumed_changeset_paths = self
-            .changeset_reader
-            .list_consumed_changesets(&changeset_dir)?;
+        let consumed_changeset_paths =
+            changeset_dirs::list_consumed_changesets(&self.changeset_reader, &changeset_dirs)?;
         let consumed_prerelease_changesets =
             Self::collect_consumed_changesets(&self.changeset_reader, &consumed_changeset_paths)?;
 
@@ -84,9 +175,36 @@ where
 
         let packages_with_inherited_versions = self
             .inherited_checker
-            .find_packages_with_inherited_versions(&project.packages)?;
+            .find_packages_with_inherited_versions(&project.cargo_packages())?;
+
+        // Status is informational, so a missing repository simply means no tags to compare.
+        let tags = self
+            .git_provider
+            .list_tags(&project.root)
+            .unwrap_or_default();
+        let version_drift = detect_version_drift(
+            &project,
+            root_config.git_config(),
+            root_config.channels(),
+            &tags,
+        );
+        let changeset_ages = self.collect_changeset_ages(&project.root, &changeset_files);
+        let changeset_owners = changeset_files
+            .iter()
+            .filter_map(|path| {
+                let owner = root_config.changeset_owner(&project.root, path)?;
+                Some((path.clone(), owner.to_string()))
+            })
+            .collect();
+        let summary = self.summarize(
+            &changeset_dir,
+            changesets.len(),
+            &plan.releases,
+            unchanged_packages.len(),
+        )?;
 
         Ok(StatusOutput {
+            summary,
             changesets,
             changeset_files,
             projected_releases: plan.releases,
@@ -95,9 +213,78 @@ where
             packages_with_inherited_versions,
             unknown_packages: plan.unknown_packages,
             consumed_prerelease_changesets,
+            version_drift,
+            display_names: root_config.display_names().clone(),
+            changeset_ages,
+            changeset_owners,
+            stale_changeset_releases: root_config.stale_changeset_releases(),
         })
     }
 
+    fn summarize(
+        &self,
+        changeset_dir: &Path,
+        pending_changesets: usize,
+        releases: &[PackageVersion],
+        unchanged_packages: usize,
+    ) -> Result<StatusSummary> {
+        let count = |bump| releases.iter().filter(|r| r.bump_type == bump).count();
+        let mut summary = StatusSummary {
+            pending_changesets,
+            packages_to_release: releases.len(),
+            major: count(BumpType::Major),
+            minor: count(BumpType::Minor),
+            patch: count(BumpType::Patch),
+            unchanged_packages,
+            ..StatusSummary::default()
+        };
+
+        if let Some(io) = &self.release_state_io {
+            if let Some(state) = io.load_prerelease_state(changeset_dir)? {
+                summary.prerelease_queue = state
+                    .iter()
+                    .map(|(name, tag)| (name.to_string(), tag.to_string()))
+                    .collect();
+                summary.prerelease_queue.sort();
+            }
+            if let Some(state) = io.load_graduation_state(changeset_dir)? {
+                summary.graduation_queue = state.iter().map(String::from).collect();
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Ages are informational too; changesets whose history cannot be read are left out.
+    fn collect_changeset_ages(
+        &self,
+        project_root: &Path,
+        changeset_files: &[PathBuf],
+    ) -> HashMap<PathBuf, ChangesetAge> {
+        let release_dates = self
+            .git_provider
+            .release_dates(project_root)
+            .unwrap_or_default();
+        changeset_files
+            .iter()
+            .filter_map(|path| {
+                let added = self
+                    .git_provider
+                    .file_added_date(project_root, path)
+                    .ok()
+                    .flatten()?;
+                let releases_since = release_dates.iter().filter(|date| **date > added).count();
+                Some((
+                    path.clone(),
+                    ChangesetAge {
+                        added,
+                        releases_since,
+                    },
+                ))
+            })
+            .collect()
+    }
+
     fn collect_consumed_changesets(
         reader: &R,
         paths: &[PathBuf],
@@ -117,8 +304,8 @@ where
 mod tests {
     use super::*;
     use crate::mocks::{
-        FailingInheritedVersionChecker, MockChangesetReader, MockInheritedVersionChecker,
-        MockProjectProvider, make_changeset,
+        FailingInheritedVersionChecker, MockChangesetReader, MockGitProvider,
+        MockInheritedVersionChecker, MockProjectProvider, make_changeset,
     };
     use changeset_core::BumpType;
     use semver::Version;
@@ -127,7 +314,7 @@ mod tests {
     fn make_operation<P, R>(
         project_provider: P,
         changeset_reader: R,
-    ) -> StatusOperation<P, R, MockInheritedVersionChecker>
+    ) -> StatusOperation<P, R, MockInheritedVersionChecker, MockGitProvider>
     where
         P: ProjectProvider,
         R: ChangesetReader,
@@ -136,6 +323,7 @@ mod tests {
             project_provider,
             changeset_reader,
             MockInheritedVersionChecker::new(),
+            MockGitProvider::new(),
         )
     }
 
@@ -160,6 +348,103 @@ mod tests {
         assert!(result.unknown_packages.is_empty());
     }
 
+    #[test]
+    fn counts_releases_since_each_changeset_was_added() {
+        use chrono::TimeZone;
+
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let committed = PathBuf::from(".changeset/changesets/old.md");
+        let uncommitted = PathBuf::from(".changeset/changesets/new.md");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(
+                committed.clone(),
+                make_changeset("my-crate", BumpType::Patch, "Old"),
+            )
+            .with_changeset(
+                uncommitted.clone(),
+                make_changeset("my-crate", BumpType::Patch, "New"),
+            );
+        let date = |day| {
+            Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0)
+                .single()
+                .expect("valid date")
+        };
+        let git_provider = MockGitProvider::new()
+            .with_file_added_date(committed.clone(), date(10))
+            .with_release_dates(vec![date(1), date(15), date(20)]);
+
+        let operation = StatusOperation::new(
+            project_provider,
+            changeset_reader,
+            MockInheritedVersionChecker::new(),
+            git_provider,
+        );
+
+        let result = operation
+            .execute(Path::new("/any"))
+            .expect("StatusOperation failed to collect changeset ages");
+
+        let age = result.changeset_ages[&committed];
+        assert_eq!(age.added, date(10));
+        assert_eq!(age.releases_since, 2);
+        assert!(!result.changeset_ages.contains_key(&uncommitted));
+        assert!(result.stale_changesets().is_empty());
+    }
+
+    #[test]
+    fn summarizes_bumps_and_release_queues() {
+        use changeset_project::{GraduationState, PrereleaseState};
+
+        use crate::mocks::MockReleaseStateIO;
+
+        let project_provider = MockProjectProvider::workspace(vec![
+            ("crate-a", "1.0.0"),
+            ("crate-b", "1.0.0"),
+            ("crate-c", "0.3.0"),
+        ]);
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(
+                PathBuf::from(".changeset/changesets/a.md"),
+                make_changeset("crate-a", BumpType::Minor, "Add feature"),
+            )
+            .with_changeset(
+                PathBuf::from(".changeset/changesets/b.md"),
+                make_changeset("crate-b", BumpType::Patch, "Fix bug"),
+            );
+        let mut prerelease = PrereleaseState::new();
+        prerelease.insert("crate-b".to_string(), "rc".to_string());
+        prerelease.insert("crate-a".to_string(), "alpha".to_string());
+        let mut graduation = GraduationState::new();
+        graduation.add("crate-c".to_string());
+        let release_state_io = MockReleaseStateIO::new()
+            .with_prerelease_state(prerelease)
+            .with_graduation_state(graduation);
+
+        let operation = make_operation(project_provider, changeset_reader)
+            .with_release_state_io(Box::new(release_state_io));
+
+        let result = operation
+            .execute(Path::new("/any"))
+            .expect("StatusOperation failed to summarize");
+
+        assert_eq!(
+            result.summary,
+            StatusSummary {
+                pending_changesets: 2,
+                packages_to_release: 2,
+                major: 0,
+                minor: 1,
+                patch: 1,
+                unchanged_packages: 1,
+                prerelease_queue: vec![
+                    ("crate-a".to_string(), "alpha".to_string()),
+                    ("crate-b".to_string(), "rc".to_string()),
+                ],
+                graduation_queue: vec!["crate-c".to_string()],
+            }
+        );
+    }
+
     #[test]
     fn collects_changesets_and_projected_releases() {
         let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
@@ -249,7 +534,12 @@ mod tests {
         let inherited_checker = MockInheritedVersionChecker::new()
             .with_inherited(vec![PathBuf::from("/mock/project/Cargo.toml")]);
 
-        let operation = StatusOperation::new(project_provider, changeset_reader, inherited_checker);
+        let operation = StatusOperation::new(
+            project_provider,
+            changeset_reader,
+            inherited_checker,
+            MockGitProvider::new(),
+        );
 
         let result = operation
             .execute(Path::new("/any"))
@@ -328,6 +618,7 @@ mod tests {
             project_provider,
             changeset_reader,
             FailingInheritedVersionChecker,
+            MockGitProvider::new(),
         );
 
         let result = operation.execute(Path::new("/any"));
@@ -452,4 +743,27 @@ mod tests {
         assert!(versions.contains(&"1.0.1-alpha.1"));
         assert!(versions.contains(&"1.0.1-alpha.2"));
     }
+
+    #[test]
+    fn reports_version_drift_against_tags() {
+        let project_provider =
+            MockProjectProvider::workspace(vec![("crate-a", "1.1.0"), ("crate-b", "2.0.0")]);
+        let git_provider =
+            MockGitProvider::new().with_existing_tags(&["crate-a@v1.0.0", "crate-b@v2.0.0"]);
+
+        let operation = StatusOperation::new(
+            project_provider,
+            MockChangesetReader::new(),
+            MockInheritedVersionChecker::new(),
+            git_provider,
+        );
+
+        let result = operation
+            .execute(Path::new("/any"))
+            .expect("StatusOperation failed");
+
+        assert_eq!(result.version_drift.len(), 1);
+        assert_eq!(result.version_drift[0].package, "crate-a");
+        assert_eq!(result.version_drift[0].tag_version, Version::new(1, 0, 0));
+    }
 }
@@ -0,0 +1,216 @@
+use std::fmt::Write;
+use std::hash::{BuildHasher, RandomState};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Value, json};
+
+use crate::operations::release::{ReleaseOutput, StepTiming};
+
+/// Service name reported on exported spans.
+pub const OTLP_SERVICE_NAME: &str = "cargo-changeset";
+
+const TRACES_ENDPOINT_VAR: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+const ENDPOINT_VAR: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+const SPAN_KIND_INTERNAL: u8 = 1;
+
+/// Resolves the OTLP/HTTP traces endpoint from the standard OpenTelemetry
+/// environment variables, or `None` when exporting is not configured.
+#[must_use]
+pub fn otlp_traces_endpoint() -> Option<String> {
+    resolve_traces_endpoint(
+        std::env::var(TRACES_ENDPOINT_VAR).ok(),
+        std::env::var(ENDPOINT_VAR).ok(),
+    )
+}
+
+fn resolve_traces_endpoint(traces: Option<String>, base: Option<String>) -> Option<String> {
+    if let Some(traces) = traces.filter(|url| !url.is_empty()) {
+        return Some(traces);
+    }
+    base.filter(|url| !url.is_empty())
+        .map(|url| format!("{}/v1/traces", url.trim_end_matches('/')))
+}
+
+/// Renders an OTLP/JSON trace with a `release` span and one child span per saga step.
+///
+/// Returns `None` when the release has no recorded step timings (e.g. dry runs).
+#[must_use]
+pub fn render_release_trace(output: &ReleaseOutput) -> Option<Value> {
+    let first = output.step_timings.first()?;
+    let start = first.started_at;
+    let end = output
+        .step_timings
+        .iter()
+        .map(|timing| timing.started_at + timing.duration)
+        .max()
+        .unwrap_or(start);
+
+    let trace_id = random_hex_id(2);
+    let root_span_id = random_hex_id(1);
+
+    let releases = output
+        .planned_releases
+        .iter()
+        .map(|release| format!("{}@{}", release.name, release.new_version))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut spans = vec![json!({
+        "traceId": trace_id,
+        "spanId": root_span_id,
+        "name": "release",
+        "kind": SPAN_KIND_INTERNAL,
+        "startTimeUnixNano": unix_nanos(start),
+        "endTimeUnixNano": unix_nanos(end),
+        "attributes": [string_attribute("changeset.releases", &releases)],
+    })];
+    spans.extend(
+        output
+            .step_timings
+            .iter()
+            .map(|timing| step_span(timing, &trace_id, &root_span_id)),
+    );
+
+    Some(json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [string_attribute("service.name", OTLP_SERVICE_NAME)],
+            },
+            "scopeSpans": [{
+                "scope": { "name": OTLP_SERVICE_NAME },
+                "spans": spans,
+            }],
+        }],
+    }))
+}
+
+fn step_span(timing: &StepTiming, trace_id: &str, parent_span_id: &str) -> Value {
+    json!({
+        "traceId": trace_id,
+        "spanId": random_hex_id(1),
+        "parentSpanId": parent_span_id,
+        "name": timing.name,
+        "kind": SPAN_KIND_INTERNAL,
+        "startTimeUnixNano": unix_nanos(timing.started_at),
+        "endTimeUnixNano": unix_nanos(timing.started_at + timing.duration),
+    })
+}
+
+fn string_attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+/// OTLP/JSON encodes 64-bit integers as decimal strings.
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos())
+        .to_string()
+}
+
+/// Produces a random lowercase hex id made of `words` 64-bit values.
+fn random_hex_id(words: usize) -> String {
+    (0..words).fold(String::with_capacity(words * 16), |mut id, _| {
+        let value = RandomState::new().hash_one(SystemTime::now());
+        let _ = write!(id, "{value:016x}");
+        id
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use std::time::Duration;
+
+    use changeset_core::BumpType;
+
+    use super::*;
+    use crate::types::PackageVersion;
+
+    fn make_output(step_timings: Vec<StepTiming>) -> ReleaseOutput {
+        ReleaseOutput {
+            planned_releases: vec![PackageVersion {
+                name: "my-crate".to_string(),
+                current_version: "1.0.0".parse().expect("valid version"),
+                new_version: "1.0.1".parse().expect("valid version"),
+                bump_type: BumpType::Patch,
+            }],
+            unchanged_packages: Vec::new(),
+            changesets_consumed: Vec::new(),
+            changeset_owners: HashMap::new(),
+            changelog_updates: Vec::new(),
+            git_result: None,
+            step_timings,
+            provenance: Vec::new(),
+            file_diffs: Vec::new(),
+        }
+    }
+
+    fn timing(name: &str, offset_ms: u64, duration_ms: u64) -> StepTiming {
+        StepTiming {
+            name: name.to_string(),
+            started_at: UNIX_EPOCH + Duration::from_millis(1_000 + offset_ms),
+            duration: Duration::from_millis(duration_ms),
+        }
+    }
+
+    #[test]
+    fn traces_endpoint_prefers_specific_variable() {
+        assert_eq!(
+            resolve_traces_endpoint(
+                Some("http://collector/traces".to_string()),
+                Some("http://collector".to_string())
+            ),
+            Some("http://collector/traces".to_string())
+        );
+    }
+
+    #[test]
+    fn traces_endpoint_appends_path_to_base() {
+        assert_eq!(
+            resolve_traces_endpoint(None, Some("http://collector:4318/".to_string())),
+            Some("http://collector:4318/v1/traces".to_string())
+        );
+        assert_eq!(resolve_traces_endpoint(None, Some(String::new())), None);
+        assert_eq!(resolve_traces_endpoint(None, None), None);
+    }
+
+    #[test]
+    fn dry_run_output_has_no_trace() {
+        assert!(render_release_trace(&make_output(Vec::new())).is_none());
+    }
+
+    #[test]
+    fn renders_root_and_step_spans() {
+        let output = make_output(vec![
+            timing("write-manifests", 0, 5),
+            timing("create-tags", 5, 20),
+        ]);
+
+        let trace = render_release_trace(&output).expect("trace rendered");
+        let spans = trace["resourceSpans"][0]["scopeSpans"][0]["spans"]
+            .as_array()
+            .expect("spans array");
+
+        assert_eq!(spans.len(), 3);
+        let root = &spans[0];
+        assert_eq!(root["name"], "release");
+        assert_eq!(root["startTimeUnixNano"], "1000000000");
+        assert_eq!(root["endTimeUnixNano"], "1025000000");
+        assert_eq!(
+            root["attributes"][0]["value"]["stringValue"],
+            "my-crate@1.0.1"
+        );
+        assert_eq!(root["traceId"].as_str().map(str::len), Some(32));
+
+        let step = &spans[2];
+        assert_eq!(step["name"], "create-tags");
+        assert_eq!(step["parentSpanId"], root["spanId"]);
+        assert_eq!(step["traceId"], root["traceId"]);
+        assert_eq!(step["endTimeUnixNano"], "1025000000");
+        assert_eq!(
+            trace["resourceSpans"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            OTLP_SERVICE_NAME
+        );
+    }
+}
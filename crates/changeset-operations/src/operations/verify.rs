@@ -1,17 +1,32 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use changeset_git::{FileChange, FileStatus};
-use changeset_project::map_files_to_packages;
+use changeset_project::{
+    CONSUMED_STATE_FILENAME, CargoProject, FileMapping, PackageChangesetConfig, RootChangesetConfig,
+};
 
 use crate::Result;
-use crate::traits::{ChangesetReader, GitProvider, ProjectProvider};
-use crate::verification::rules::{CoverageRule, DeletedChangesetsRule};
-use crate::verification::{VerificationContext, VerificationEngine, VerificationResult};
+use crate::traits::{
+    BumpInferenceProvider, ChangedFilesProvider, ChangesetReader, GitProvider, ProjectProvider,
+};
+use crate::verification::rules::{
+    AdvisoryRule, BumpInferenceRule, ConsumedChangesetsRule, CoverageRule, DeletedChangesetsRule,
+    DuplicateChangesetsRule, OwnershipRule, ScopeRule, SummaryLintRule,
+};
+use crate::verification::{
+    ChangesetChange, VerificationContext, VerificationEngine, VerificationResult,
+};
 
 pub struct VerifyInput {
     pub base: String,
     pub head: Option<String>,
     pub allow_deleted_changesets: bool,
+    /// Check only the files staged in the index instead of diffing `base..head`.
+    pub staged: bool,
+    /// Require a security changeset with an advisory, as for pull requests
+    /// labelled `security`.
+    pub require_advisory: bool,
 }
 
 #[derive(Debug)]
@@ -29,6 +44,8 @@ pub struct VerifyOperation<P, G, R> {
     project_provider: P,
     git_provider: G,
     changeset_reader: R,
+    changed_files_provider: Option<Box<dyn ChangedFilesProvider>>,
+    bump_inference: Option<Box<dyn BumpInferenceProvider>>,
 }
 
 impl<P, G, R> VerifyOperation<P, G, R>
@@ -42,9 +59,27 @@ where
             project_provider,
             git_provider,
             changeset_reader,
+            changed_files_provider: None,
+            bump_inference: None,
         }
     }
 
+    /// Lists `base..head` changes through `provider` instead of the git provider's diff.
+    /// Staged checks always read the local index.
+    #[must_use]
+    pub fn with_changed_files_provider(mut self, provider: Box<dyn ChangedFilesProvider>) -> Self {
+        self.changed_files_provider = Some(provider);
+        self
+    }
+
+    /// Reports covered packages whose changesets declare a smaller bump than
+    /// `inference` suggests, without failing verification.
+    #[must_use]
+    pub fn with_bump_inference(mut self, inference: Box<dyn BumpInferenceProvider>) -> Self {
+        self.bump_inference = Some(inference);
+        self
+    }
+
     /// # Errors
     ///
     /// Returns an error if the project cannot be discovered, git operations fail,
@@ -53,36 +88,39 @@ where
         let project = self.project_provider.discover_project(start_path)?;
         let (root_config, package_configs) = self.project_provider.load_configs(&project)?;
         let changeset_dir = root_config.changeset_dir();
+        let changeset_dirs: Vec<&Path> = root_config.changeset_dirs().collect();
 
-        let head_ref = input.head.as_deref().unwrap_or("HEAD");
-        let changed_files =
-            self.git_provider
-                .changed_files(&project.root, &input.base, head_ref)?;
+        let changed_files = self.changed_files(&project.root, input)?;
 
         let (changeset_changes, code_changes): (Vec<_>, Vec<_>) = changed_files
             .into_iter()
-            .partition(|change| change.path.starts_with(changeset_dir));
+            .partition(|change| in_changeset_dir(&change.path, &changeset_dirs));
 
-        let deleted_changesets = extract_deleted_changesets(&changeset_changes, changeset_dir);
+        let deleted_changesets = extract_deleted_changesets(&changeset_changes, &changeset_dirs);
         let changeset_files = extract_active_changesets(&changeset_changes);
+        let base_ref = if input.staged { "HEAD" } else { &input.base };
+        let changeset_changes = self.classify_changeset_changes(
+            &project.root,
+            changeset_dir,
+            base_ref,
+            changeset_changes,
+        );
 
         let changed_paths: Vec<PathBuf> =
             code_changes.into_iter().map(|change| change.path).collect();
 
         let has_deleted_changesets = !deleted_changesets.is_empty();
+        let has_consumed_edits = changeset_changes
+            .iter()
+            .any(|change| change.consumed_at_base);
         let has_code_changes = !changed_paths.is_empty();
 
-        if !has_code_changes && !has_deleted_changesets {
+        if !has_code_changes && !has_deleted_changesets && !has_consumed_edits {
             return Ok(VerifyOutcome::NoChanges);
         }
 
         let mapping = if has_code_changes {
-            Some(map_files_to_packages(
-                &project,
-                &changed_paths,
-                &root_config,
-                &package_configs,
-            ))
+            Some(self.map_files(&project, changed_paths, &root_config, &package_configs)?)
         } else {
             None
         };
@@ -92,7 +130,7 @@ where
             changeset_project::FileMapping::affected_packages,
         );
 
-        if affected_packages.is_empty() && !has_deleted_changesets {
+        if affected_packages.is_empty() && !has_deleted_changesets && !has_consumed_edits {
             let (project_file_count, ignored_file_count) = mapping
                 .as_ref()
                 .map_or((0, 0), |m| (m.project_files.len(), m.ignored_files.len()));
@@ -102,14 +140,46 @@ where
             });
         }
 
-        let context = build_context(mapping.as_ref(), changeset_files, deleted_changesets);
+        let mut context = build_context(
+            mapping.as_ref(),
+            changeset_files,
+            deleted_changesets,
+            changeset_changes,
+        );
+        let depth = root_config.transitive_coverage_depth();
+        if depth > 0 && !context.affected_packages.is_empty() {
+            add_transitive_packages(&mut context, &project, depth)?;
+        }
 
         let deleted_rule = DeletedChangesetsRule::new(input.allow_deleted_changesets);
-        let coverage_rule = CoverageRule::new(&self.changeset_reader);
+        let coverage_rule = CoverageRule::new(&self.changeset_reader, &package_configs);
+        let owners = changeset_project::package_owners(&project, &root_config);
+        let ownership_rule = OwnershipRule::new(&owners);
+        let lint_config = root_config.lint_config();
+        let lint_rule = SummaryLintRule::new(&self.changeset_reader, lint_config.denied_words());
+        let scope_rule = ScopeRule::new(&self.changeset_reader, root_config.scope_config());
+        let advisory_rule = AdvisoryRule::new(&self.changeset_reader);
+        let duplicates_rule = DuplicateChangesetsRule::new(&self.changeset_reader, &changeset_dirs);
+        let bump_inference_rule = self.bump_inference.as_deref().map(|inference| {
+            BumpInferenceRule::new(&self.changeset_reader, inference, &project.root)
+        });
 
         let mut engine = VerificationEngine::new();
         engine.add_rule(&deleted_rule);
+        engine.add_rule(&ConsumedChangesetsRule);
+        engine.add_rule(&duplicates_rule);
         engine.add_rule(&coverage_rule);
+        engine.add_rule(&ownership_rule);
+        if lint_config.enabled() {
+            engine.add_rule(&lint_rule);
+        }
+        engine.add_rule(&scope_rule);
+        if input.require_advisory {
+            engine.add_rule(&advisory_rule);
+        }
+        if let Some(rule) = &bump_inference_rule {
+            engine.add_rule(rule);
+        }
 
         let result = engine.verify(&context)?;
 
@@ -119,13 +189,110 @@ where
             Ok(VerifyOutcome::Failed(result))
         }
     }
+
+    /// Maps the changed files to packages. Files matched by the repository's
+    /// ignore rules never count towards a package and are reported as ignored.
+    fn map_files(
+        &self,
+        project: &CargoProject,
+        changed_paths: Vec<PathBuf>,
+        root_config: &RootChangesetConfig,
+        package_configs: &HashMap<String, PackageChangesetConfig>,
+    ) -> Result<FileMapping> {
+        let git_ignored = self
+            .git_provider
+            .ignored_paths(&project.root, &changed_paths)?;
+        let tracked: Vec<PathBuf> = changed_paths
+            .into_iter()
+            .filter(|path| !git_ignored.contains(path))
+            .collect();
+
+        let mut mapping =
+            self.project_provider
+                .map_files(project, &tracked, root_config, package_configs);
+        mapping.ignored_files.extend(git_ignored);
+        Ok(mapping)
+    }
+
+    /// Staged changes, or the `base..head` changes from the changed files
+    /// provider when one is set.
+    fn changed_files(&self, project_root: &Path, input: &VerifyInput) -> Result<Vec<FileChange>> {
+        if input.staged {
+            return self.git_provider.staged_changes(project_root);
+        }
+        let head_ref = input.head.as_deref().unwrap_or("HEAD");
+        match &self.changed_files_provider {
+            Some(provider) => provider.changed_files(project_root, &input.base, head_ref),
+            None => self
+                .git_provider
+                .changed_files(project_root, &input.base, head_ref),
+        }
+    }
+
+    /// Looks up whether each edited changeset was already consumed at `base_ref`,
+    /// either through its front matter marker or an entry in `consumed.toml`.
+    ///
+    /// A base version that cannot be read or parsed (for example when the base
+    /// commit is not available locally) is treated as not consumed.
+    fn classify_changeset_changes(
+        &self,
+        project_root: &Path,
+        changeset_dir: &Path,
+        base_ref: &str,
+        changes: Vec<FileChange>,
+    ) -> Vec<ChangesetChange> {
+        let read_at_base = |path: &Path| {
+            self.git_provider
+                .file_at_ref(project_root, base_ref, path)
+                .ok()
+                .flatten()
+        };
+        let consumed_hashes: HashSet<String> =
+            read_at_base(&changeset_dir.join(CONSUMED_STATE_FILENAME))
+                .and_then(|content| content.parse::<toml::Table>().ok())
+                .and_then(|mut table| match table.remove("consumed") {
+                    Some(toml::Value::Table(consumed)) => {
+                        Some(consumed.into_iter().map(|(k, _)| k).collect())
+                    }
+                    _ => None,
+                })
+                .unwrap_or_default();
+
+        changes
+            .into_iter()
+            .filter(|change| is_markdown_file(&change.path))
+            .map(|change| {
+                let consumed_at_base = matches!(
+                    change.status,
+                    FileStatus::Modified | FileStatus::Renamed | FileStatus::Typechange
+                ) && read_at_base(
+                    change.old_path.as_ref().unwrap_or(&change.path),
+                )
+                .is_some_and(|content| {
+                    changeset_git::blob_hash(content.as_bytes())
+                        .is_ok_and(|hash| consumed_hashes.contains(&hash))
+                        || changeset_parse::parse_changeset(&content)
+                            .is_ok_and(|changeset| changeset.consumed_for_prerelease.is_some())
+                });
+                ChangesetChange {
+                    path: change.path,
+                    status: change.status,
+                    consumed_at_base,
+                }
+            })
+            .collect()
+    }
 }
 
 fn is_markdown_file(path: &Path) -> bool {
     path.extension().is_some_and(|ext| ext == "md")
 }
 
-fn extract_deleted_changesets(changes: &[FileChange], changeset_dir: &Path) -> Vec<PathBuf> {
+fn in_changeset_dir(path: &Path, changeset_dirs: &[&Path]) -> bool {
+    changeset_dirs.iter().any(|dir| path.starts_with(dir))
+}
+
+fn extract_deleted_changesets(changes: &[FileChange], changeset_dirs: &[&Path]) -> Vec<PathBuf> {
     changes
         .iter()
         .filter_map(|change| match change.status {
@@ -133,7 +300,7 @@ fn extract_deleted_changesets(changes: &[FileChange], changeset_dir: &Path) -> V
             FileStatus::Renamed => change
                 .old_path
                 .as_ref()
-                .filter(|old| old.starts_with(changeset_dir) && is_markdown_file(old))
+                .filter(|old| in_changeset_dir(old, changeset_dirs) && is_markdown_file(old))
                 .cloned(),
             _ => None,
         })
@@ -161,31 +328,72 @@ fn build_context(
     mapping: Option<&changeset_project::FileMapping>,
     changeset_files: Vec<PathBuf>,
     deleted_changesets: Vec<PathBuf>,
+    changeset_changes: Vec<ChangesetChange>,
 ) -> VerificationContext {
     match mapping {
         Some(m) => VerificationContext {
             affected_packages: m.affected_packages().into_iter().cloned().collect(),
+            transitive_packages: BTreeMap::new(),
+            package_files: m
+                .package_files
+                .iter()
+                .filter(|pf| !pf.files.is_empty())
+                .map(|pf| (pf.package.name.clone(), pf.files.clone()))
+                .collect(),
             changeset_files,
             deleted_changesets,
+            changeset_changes,
             project_files: m.project_files.clone(),
             ignored_files: m.ignored_files.clone(),
         },
         None => VerificationContext {
             affected_packages: Vec::new(),
+            transitive_packages: BTreeMap::new(),
+            package_files: BTreeMap::new(),
             changeset_files,
             deleted_changesets,
+            changeset_changes,
             project_files: Vec::new(),
             ignored_files: Vec::new(),
         },
     }
 }
 
+/// Adds the workspace packages depending on an affected package, up to
+/// `depth` levels, so API changes in shared crates reach their facades.
+fn add_transitive_packages(
+    context: &mut VerificationContext,
+    project: &CargoProject,
+    depth: usize,
+) -> Result<()> {
+    let dependents = changeset_project::workspace_dependents(project)?;
+    let changed: Vec<&str> = context
+        .affected_packages
+        .iter()
+        .map(|package| package.name.as_str())
+        .collect();
+    let transitive = changeset_project::transitive_dependents(&dependents, &changed, depth);
+
+    context.affected_packages.extend(
+        project
+            .packages
+            .iter()
+            .filter(|package| transitive.contains_key(&package.name))
+            .cloned(),
+    );
+    context.transitive_packages = transitive;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::mocks::{MockChangesetReader, MockGitProvider, MockProjectProvider};
+    use crate::verification::MissingChangeset;
+    use crate::verification::rules::ScopeIssue;
     use changeset_core::BumpType;
     use changeset_git::FileStatus;
+    use changeset_project::{LintConfig, RootChangesetConfig, ScopeConfig};
 
     #[test]
     fn returns_no_changes_when_no_files_changed() {
@@ -199,6 +407,8 @@ mod tests {
             base: "main".to_string(),
             head: None,
             allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
         };
 
         let result = operation
@@ -235,6 +445,8 @@ mod tests {
             base: "main".to_string(),
             head: None,
             allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
         };
 
         let result = operation
@@ -250,6 +462,251 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ignored_files_do_not_affect_packages() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+
+        let git_provider = MockGitProvider::new()
+            .with_changed_files(vec![FileChange {
+                path: PathBuf::from("target/generated/bindings.rs"),
+                status: FileStatus::Modified,
+                old_path: None,
+            }])
+            .with_ignored_paths(vec![PathBuf::from("target")]);
+
+        let operation =
+            VerifyOperation::new(project_provider, git_provider, MockChangesetReader::new());
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed with only ignored files changed");
+
+        assert!(
+            matches!(
+                result,
+                VerifyOutcome::NoPackagesAffected {
+                    project_file_count: 0,
+                    ignored_file_count: 1,
+                }
+            ),
+            "expected NoPackagesAffected, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn accepts_changesets_from_owned_changeset_dirs() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(
+                RootChangesetConfig::default().with_owned_changeset_dir("web", "teams/web"),
+            );
+
+        let git_provider = MockGitProvider::new().with_changed_files(vec![
+            FileChange {
+                path: PathBuf::from("teams/web/changesets/test.md"),
+                status: FileStatus::Added,
+                old_path: None,
+            },
+            FileChange {
+                path: PathBuf::from("src/lib.rs"),
+                status: FileStatus::Modified,
+                old_path: None,
+            },
+        ]);
+
+        let changeset = crate::mocks::make_changeset("my-crate", BumpType::Patch, "Fix bug");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from("teams/web/changesets/test.md"), changeset);
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed for a changeset in an owned directory");
+
+        match result {
+            VerifyOutcome::Success(verification_result) => {
+                assert!(verification_result.covered_packages.contains("my-crate"));
+            }
+            other => panic!("Expected VerifyOutcome::Success, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn returns_failed_when_summary_fails_lint() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(
+                RootChangesetConfig::default()
+                    .with_lint_config(LintConfig::default().with_enabled(true)),
+            );
+
+        let git_provider = MockGitProvider::new().with_changed_files(vec![
+            FileChange {
+                path: PathBuf::from(".changeset/changesets/test.md"),
+                status: FileStatus::Added,
+                old_path: None,
+            },
+            FileChange {
+                path: PathBuf::from("src/lib.rs"),
+                status: FileStatus::Modified,
+                old_path: None,
+            },
+        ]);
+
+        let changeset =
+            crate::mocks::make_changeset("my-crate", BumpType::Patch, "Fix `parse() bug");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/test.md"), changeset);
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed unexpectedly when summary fails lint");
+
+        match result {
+            VerifyOutcome::Failed(verification_result) => {
+                assert!(verification_result.uncovered_packages.is_empty());
+                assert_eq!(
+                    verification_result.lint_failures,
+                    vec![(
+                        PathBuf::from(".changeset/changesets/test.md"),
+                        vec![crate::lint::LintIssue::UnbalancedBackticks]
+                    )]
+                );
+            }
+            other => panic!("Expected VerifyOutcome::Failed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn required_advisory_needs_security_changeset_with_advisory() {
+        let verify = |changeset: changeset_core::Changeset| {
+            let git_provider = MockGitProvider::new().with_changed_files(vec![
+                FileChange {
+                    path: PathBuf::from(".changeset/changesets/test.md"),
+                    status: FileStatus::Added,
+                    old_path: None,
+                },
+                FileChange {
+                    path: PathBuf::from("src/lib.rs"),
+                    status: FileStatus::Modified,
+                    old_path: None,
+                },
+            ]);
+            let changeset_reader = MockChangesetReader::new()
+                .with_changeset(PathBuf::from(".changeset/changesets/test.md"), changeset);
+            let operation = VerifyOperation::new(
+                MockProjectProvider::single_package("my-crate", "1.0.0"),
+                git_provider,
+                changeset_reader,
+            );
+            let input = VerifyInput {
+                base: "main".to_string(),
+                head: None,
+                allow_deleted_changesets: false,
+                staged: false,
+                require_advisory: true,
+            };
+            operation
+                .execute(Path::new("/any"), &input)
+                .expect("VerifyOperation failed unexpectedly")
+        };
+        let changeset = crate::mocks::make_changeset("my-crate", BumpType::Patch, "Fix overflow");
+
+        match verify(changeset.clone()) {
+            VerifyOutcome::Failed(result) => assert!(result.missing_advisory),
+            other => panic!("Expected VerifyOutcome::Failed, got {other:?}"),
+        }
+
+        let linked = changeset_core::Changeset {
+            category: changeset_core::ChangeCategory::Security,
+            advisory: Some("RUSTSEC-2024-0001".to_string()),
+            ..changeset
+        };
+        assert!(matches!(verify(linked), VerifyOutcome::Success(_)));
+    }
+
+    #[test]
+    fn returns_failed_when_scope_not_allowed() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0")
+            .with_root_config(
+                RootChangesetConfig::default().with_scope_config(
+                    ScopeConfig::default().with_scopes(vec!["parser".to_string()]),
+                ),
+            );
+
+        let git_provider = MockGitProvider::new().with_changed_files(vec![
+            FileChange {
+                path: PathBuf::from(".changeset/changesets/test.md"),
+                status: FileStatus::Added,
+                old_path: None,
+            },
+            FileChange {
+                path: PathBuf::from("src/lib.rs"),
+                status: FileStatus::Modified,
+                old_path: None,
+            },
+        ]);
+
+        let mut changeset = crate::mocks::make_changeset("my-crate", BumpType::Patch, "Fix");
+        changeset.scope = Some("docs".to_string());
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/test.md"), changeset);
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed unexpectedly when scope is not allowed");
+
+        match result {
+            VerifyOutcome::Failed(verification_result) => {
+                assert_eq!(
+                    verification_result.scope_failures,
+                    vec![(
+                        PathBuf::from(".changeset/changesets/test.md"),
+                        ScopeIssue::NotAllowed {
+                            scope: "docs".to_string()
+                        }
+                    )]
+                );
+            }
+            other => panic!("Expected VerifyOutcome::Failed, got {other:?}"),
+        }
+    }
+
     #[test]
     fn returns_failed_when_package_not_covered() {
         let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
@@ -268,6 +725,8 @@ mod tests {
             base: "main".to_string(),
             head: None,
             allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
         };
 
         let result = operation
@@ -282,6 +741,155 @@ mod tests {
         }
     }
 
+    #[test]
+    fn failed_result_lists_changed_files_of_missing_packages() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let git_provider = MockGitProvider::new().with_changed_files(vec![FileChange {
+            path: PathBuf::from("src/lib.rs"),
+            status: FileStatus::Modified,
+            old_path: None,
+        }]);
+        let operation =
+            VerifyOperation::new(project_provider, git_provider, MockChangesetReader::new());
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let VerifyOutcome::Failed(result) = operation
+            .execute(Path::new("/any"), &input)
+            .expect("verify")
+        else {
+            panic!("uncovered package must fail verification");
+        };
+        assert_eq!(
+            result.missing_changesets(),
+            vec![MissingChangeset {
+                package: "my-crate".to_string(),
+                changed_files: vec![PathBuf::from("src/lib.rs")],
+                suggested_bump: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn staged_mode_uses_staged_files_only() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+
+        let git_provider = MockGitProvider::new()
+            .with_changed_files(vec![FileChange {
+                path: PathBuf::from("src/lib.rs"),
+                status: FileStatus::Modified,
+                old_path: None,
+            }])
+            .with_staged_changes(vec![
+                FileChange {
+                    path: PathBuf::from(".changeset/changesets/test.md"),
+                    status: FileStatus::Added,
+                    old_path: None,
+                },
+                FileChange {
+                    path: PathBuf::from("src/lib.rs"),
+                    status: FileStatus::Modified,
+                    old_path: None,
+                },
+            ]);
+
+        let changeset = crate::mocks::make_changeset("my-crate", BumpType::Patch, "Fix bug");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/test.md"), changeset);
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: true,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed in staged mode");
+
+        assert!(
+            matches!(result, VerifyOutcome::Success(_)),
+            "staged changeset should cover staged changes, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn staged_mode_fails_when_staged_package_not_covered() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+
+        let git_provider = MockGitProvider::new().with_staged_changes(vec![FileChange {
+            path: PathBuf::from("src/lib.rs"),
+            status: FileStatus::Modified,
+            old_path: None,
+        }]);
+
+        let changeset_reader = MockChangesetReader::new();
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: true,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed in staged mode");
+
+        assert!(matches!(result, VerifyOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn changed_files_provider_replaces_git_diff() {
+        let project_provider = MockProjectProvider::workspace(vec![("crate-a", "1.0.0")]);
+
+        let git_provider = MockGitProvider::new().with_changed_files(vec![FileChange {
+            path: PathBuf::from("crates/crate-a/src/lib.rs"),
+            status: FileStatus::Modified,
+            old_path: None,
+        }]);
+        let remote_files = MockGitProvider::new().with_changed_files(vec![FileChange {
+            path: PathBuf::from("README.md"),
+            status: FileStatus::Modified,
+            old_path: None,
+        }]);
+
+        let changeset_reader = MockChangesetReader::new();
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader)
+            .with_changed_files_provider(Box::new(remote_files));
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed");
+
+        assert!(
+            matches!(result, VerifyOutcome::NoPackagesAffected { .. }),
+            "only the provider's files should be checked, got {result:?}"
+        );
+    }
+
     #[test]
     fn extract_deleted_changesets_identifies_deleted_md_files() {
         let changes = vec![
@@ -297,7 +905,7 @@ mod tests {
             },
         ];
 
-        let deleted = extract_deleted_changesets(&changes, Path::new(".changeset"));
+        let deleted = extract_deleted_changesets(&changes, &[Path::new(".changeset")]);
 
         assert_eq!(deleted.len(), 1);
         assert_eq!(deleted[0], PathBuf::from(".changeset/changesets/old.md"));
@@ -330,6 +938,126 @@ mod tests {
         assert!(active.contains(&PathBuf::from(".changeset/changesets/updated.md")));
     }
 
+    const CONSUMED_CHANGESET: &str =
+        "---\nconsumedForPrerelease: 1.0.1-alpha.1\n\"my-crate\": patch\n---\n\nFix bug\n";
+
+    fn verify_changeset_edit(change: FileChange, git_provider: MockGitProvider) -> VerifyOutcome {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let changeset = crate::mocks::make_changeset("my-crate", BumpType::Patch, "Fix bug");
+        let changeset_reader =
+            MockChangesetReader::new().with_changeset(change.path.clone(), changeset);
+        let git_provider = git_provider.with_changed_files(vec![change]);
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader);
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed unexpectedly")
+    }
+
+    #[test]
+    fn fails_when_consumed_changeset_is_modified() {
+        let git_provider = MockGitProvider::new().with_file_at_ref(
+            "main",
+            ".changeset/changesets/shipped.md",
+            CONSUMED_CHANGESET,
+        );
+
+        let outcome = verify_changeset_edit(
+            FileChange {
+                path: PathBuf::from(".changeset/changesets/shipped.md"),
+                status: FileStatus::Modified,
+                old_path: None,
+            },
+            git_provider,
+        );
+
+        match outcome {
+            VerifyOutcome::Failed(result) => assert_eq!(
+                result.consumed_changeset_edits,
+                vec![PathBuf::from(".changeset/changesets/shipped.md")]
+            ),
+            other => panic!("Expected VerifyOutcome::Failed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn fails_when_consumed_changeset_is_renamed() {
+        let git_provider = MockGitProvider::new().with_file_at_ref(
+            "main",
+            ".changeset/changesets/shipped.md",
+            CONSUMED_CHANGESET,
+        );
+
+        let outcome = verify_changeset_edit(
+            FileChange {
+                path: PathBuf::from(".changeset/changesets/renamed.md"),
+                status: FileStatus::Renamed,
+                old_path: Some(PathBuf::from(".changeset/changesets/shipped.md")),
+            },
+            git_provider,
+        );
+
+        match outcome {
+            VerifyOutcome::Failed(result) => assert_eq!(
+                result.consumed_changeset_edits,
+                vec![PathBuf::from(".changeset/changesets/renamed.md")]
+            ),
+            other => panic!("Expected VerifyOutcome::Failed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn fails_when_changeset_consumed_in_state_file_is_modified() {
+        let pending = "---\n\"my-crate\": patch\n---\n\nFix bug\n";
+        let hash = changeset_git::blob_hash(pending.as_bytes()).expect("hash changeset");
+        let git_provider = MockGitProvider::new()
+            .with_file_at_ref("main", ".changeset/changesets/shipped.md", pending)
+            .with_file_at_ref(
+                "main",
+                ".changeset/consumed.toml",
+                format!("[consumed]\n\"{hash}\" = \"1.0.1-alpha.1\"\n"),
+            );
+
+        let outcome = verify_changeset_edit(
+            FileChange {
+                path: PathBuf::from(".changeset/changesets/shipped.md"),
+                status: FileStatus::Modified,
+                old_path: None,
+            },
+            git_provider,
+        );
+
+        assert!(matches!(outcome, VerifyOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn allows_modifying_pending_changeset() {
+        let git_provider = MockGitProvider::new().with_file_at_ref(
+            "main",
+            ".changeset/changesets/pending.md",
+            "---\n\"my-crate\": patch\n---\n\nFix bug\n",
+        );
+
+        let outcome = verify_changeset_edit(
+            FileChange {
+                path: PathBuf::from(".changeset/changesets/pending.md"),
+                status: FileStatus::Modified,
+                old_path: None,
+            },
+            git_provider,
+        );
+
+        assert!(matches!(outcome, VerifyOutcome::NoChanges));
+    }
+
     #[test]
     fn is_markdown_file_recognizes_md_extension() {
         assert!(is_markdown_file(Path::new("test.md")));
@@ -337,4 +1065,51 @@ mod tests {
         assert!(!is_markdown_file(Path::new("test.rs")));
         assert!(!is_markdown_file(Path::new("test")));
     }
+
+    #[test]
+    fn reports_bumps_smaller_than_inferred() {
+        let project_provider = MockProjectProvider::single_package("my-crate", "1.0.0");
+        let git_provider = MockGitProvider::new().with_changed_files(vec![
+            FileChange::new(
+                PathBuf::from(".changeset/changesets/test.md"),
+                FileStatus::Added,
+            ),
+            FileChange::new(PathBuf::from("src/lib.rs"), FileStatus::Modified),
+        ]);
+        let changeset = crate::mocks::make_changeset("my-crate", BumpType::Patch, "Add parser");
+        let changeset_reader = MockChangesetReader::new()
+            .with_changeset(PathBuf::from(".changeset/changesets/test.md"), changeset);
+        let history = MockGitProvider::new()
+            .with_commit("feat: add parser", vec![PathBuf::from("src/lib.rs")]);
+
+        let operation = VerifyOperation::new(project_provider, git_provider, changeset_reader)
+            .with_bump_inference(Box::new(
+                crate::providers::ConventionalCommitsInference::new(history, "main"),
+            ));
+
+        let input = VerifyInput {
+            base: "main".to_string(),
+            head: None,
+            allow_deleted_changesets: false,
+            staged: false,
+            require_advisory: false,
+        };
+
+        let result = operation
+            .execute(Path::new("/any"), &input)
+            .expect("VerifyOperation failed with bump inference");
+
+        let VerifyOutcome::Success(result) = result else {
+            panic!("understated bumps must not fail verification, got {result:?}");
+        };
+        assert_eq!(
+            result.understated_bumps,
+            vec![crate::verification::rules::UnderstatedBump {
+                package: "my-crate".to_string(),
+                declared: BumpType::Patch,
+                inferred: BumpType::Minor,
+                provider: "commits",
+            }]
+        );
+    }
 }
@@ -0,0 +1,240 @@
+use std::path::{Component, Path, PathBuf};
+
+use changeset_core::PackageInfo;
+use globset::GlobBuilder;
+use indexmap::IndexMap;
+
+use crate::Result;
+use crate::error::OperationError;
+
+/// At most this many near-miss names are suggested for an unknown package.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Resolves `--package` values to packages, in the order given, keeping each
+/// package once.
+///
+/// A selector is a package name, the path of a package directory relative to
+/// `root` (or absolute), or a glob such as `changeset-*` or `crates/*` matched
+/// against the package names and relative paths.
+///
+/// # Errors
+///
+/// Returns `OperationError::UnknownPackage`, with near-miss names, for a
+/// selector that matches no package.
+pub(crate) fn select_packages(
+    root: &Path,
+    packages: &[PackageInfo],
+    selectors: &[String],
+) -> Result<Vec<PackageInfo>> {
+    let mut selected: IndexMap<&str, &PackageInfo> = IndexMap::new();
+    for selector in selectors {
+        let matches = matching_packages(root, packages, selector);
+        if matches.is_empty() {
+            return Err(unknown_package(selector, packages));
+        }
+        for package in matches {
+            selected.entry(&package.name).or_insert(package);
+        }
+    }
+    Ok(selected.into_values().cloned().collect())
+}
+
+/// Error for a package name or selector that matches no package.
+pub(crate) fn unknown_package(selector: &str, packages: &[PackageInfo]) -> OperationError {
+    OperationError::UnknownPackage {
+        name: selector.to_string(),
+        available: packages
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        suggestions: near_misses(selector, packages),
+    }
+}
+
+fn matching_packages<'a>(
+    root: &Path,
+    packages: &'a [PackageInfo],
+    selector: &str,
+) -> Vec<&'a PackageInfo> {
+    if let Some(package) = packages.iter().find(|p| p.name == selector) {
+        return vec![package];
+    }
+
+    if is_glob(selector)
+        && let Ok(glob) = GlobBuilder::new(selector.trim_end_matches('/'))
+            .literal_separator(true)
+            .build()
+    {
+        let matcher = glob.compile_matcher();
+        return packages
+            .iter()
+            .filter(|p| {
+                matcher.is_match(&p.name)
+                    || relative_path(root, &p.path).is_some_and(|path| matcher.is_match(path))
+            })
+            .collect();
+    }
+
+    let wanted = normalize(&root.join(selector));
+    packages
+        .iter()
+        .filter(|p| normalize(&p.path) == wanted)
+        .collect()
+}
+
+fn is_glob(selector: &str) -> bool {
+    selector.contains(['*', '?', '[', '{'])
+}
+
+fn relative_path<'a>(root: &Path, path: &'a Path) -> Option<&'a Path> {
+    path.strip_prefix(root)
+        .ok()
+        .filter(|path| !path.as_os_str().is_empty())
+}
+
+/// Drops `.` components and resolves `..` lexically; package paths need not exist.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Package names closest to the last path component of `selector`. Package
+/// directory names count as well, for misspelled paths.
+fn near_misses(selector: &str, packages: &[PackageInfo]) -> Vec<String> {
+    let needle = selector
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(selector)
+        .replace(['*', '?', '[', ']', '{', '}'], "")
+        .trim_matches(['-', '_'])
+        .to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let threshold = (needle.chars().count() / 3).max(1);
+
+    let mut candidates: Vec<(usize, &str)> = packages
+        .iter()
+        .filter_map(|package| {
+            let name = package.name.to_lowercase();
+            let dir = package
+                .path
+                .file_name()
+                .map(|dir| dir.to_string_lossy().to_lowercase());
+            let distance = dir
+                .iter()
+                .fold(edit_distance(&needle, &name), |distance, dir| {
+                    distance.min(edit_distance(&needle, dir))
+                });
+            let related = needle.len() >= 3 && (name.contains(&needle) || needle.contains(&name));
+            (distance <= threshold || related).then_some((distance, package.name.as_str()))
+        })
+        .collect();
+    candidates.sort_unstable();
+    let closest = candidates.first().map_or(0, |(distance, _)| *distance);
+    candidates
+        .into_iter()
+        .take_while(|(distance, _)| *distance == closest)
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Levenshtein distance between `a` and `b`, by characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = Vec::with_capacity(b.len() + 1);
+        current.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, path: &str) -> PackageInfo {
+        PackageInfo {
+            name: name.to_string(),
+            version: "1.0.0".parse().expect("valid version"),
+            path: PathBuf::from("/repo").join(path),
+        }
+    }
+
+    fn workspace() -> Vec<PackageInfo> {
+        vec![
+            package("changeset-core", "crates/core"),
+            package("changeset-git", "crates/git"),
+            package("cargo-changeset", "crates/cli"),
+        ]
+    }
+
+    fn names(selectors: &[&str]) -> Result<Vec<String>> {
+        let selectors: Vec<String> = selectors.iter().map(ToString::to_string).collect();
+        Ok(
+            select_packages(Path::new("/repo"), &workspace(), &selectors)?
+                .into_iter()
+                .map(|p| p.name)
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn selects_by_name_path_and_glob() -> Result<()> {
+        assert_eq!(names(&["cargo-changeset"])?, ["cargo-changeset"]);
+        assert_eq!(names(&["crates/git"])?, ["changeset-git"]);
+        assert_eq!(names(&["./crates/cli/"])?, ["cargo-changeset"]);
+        assert_eq!(names(&["/repo/crates/core"])?, ["changeset-core"]);
+        assert_eq!(
+            names(&["changeset-*"])?,
+            ["changeset-core", "changeset-git"]
+        );
+        assert_eq!(
+            names(&["crates/c*", "changeset-core"])?,
+            ["changeset-core", "cargo-changeset"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_selector_suggests_near_misses() {
+        let Err(OperationError::UnknownPackage { suggestions, .. }) = names(&["changset-git"])
+        else {
+            panic!("misspelled name must not match");
+        };
+        assert_eq!(suggestions, ["changeset-git"]);
+
+        let Err(OperationError::UnknownPackage { suggestions, .. }) = names(&["crates/gi"]) else {
+            panic!("misspelled path must not match");
+        };
+        assert_eq!(suggestions, ["changeset-git"]);
+
+        let Err(OperationError::UnknownPackage { suggestions, .. }) = names(&["xyz"]) else {
+            panic!("unrelated name must not match");
+        };
+        assert!(suggestions.is_empty());
+
+        let Err(OperationError::UnknownPackage { suggestions, .. }) = names(&["core-*"]) else {
+            panic!("glob must not match");
+        };
+        assert_eq!(suggestions, ["changeset-core"]);
+    }
+}
@@ -1,13 +1,19 @@
 use std::collections::{HashMap, HashSet};
 
 use changeset_core::{BumpType, Changeset, PackageInfo, PrereleaseSpec, ZeroVersionBehavior};
+use changeset_project::{PrereleaseState, RootChangesetConfig};
 use changeset_version::{
-    VersionError, calculate_new_version, calculate_new_version_with_zero_behavior, is_zero_version,
-    max_bump_type,
+    VersionError, calculate_new_version, calculate_new_version_with_zero_behavior,
+    extract_prerelease_number, extract_prerelease_tag, is_zero_version, max_bump_type,
+    with_prerelease_number,
 };
 use indexmap::IndexMap;
+use semver::Version;
 
-use crate::types::{PackageReleaseConfig, PackageVersion};
+use crate::types::{
+    BumpContribution, BumpSource, PackageProvenance, PackageReleaseConfig, PackageVersion,
+    PrereleaseProvenance, PrereleaseSource, ZeroVersionTransform,
+};
 
 /// Result of planning version releases from changesets.
 #[derive(Debug, Clone)]
@@ -16,6 +22,48 @@ pub struct ReleasePlan {
     pub releases: Vec<PackageVersion>,
     /// Packages referenced in changesets but not found in workspace.
     pub unknown_packages: Vec<String>,
+    /// What decided each planned release, in the same order as `releases`
+    /// (empty for prerelease graduation).
+    pub provenance: Vec<PackageProvenance>,
+}
+
+/// Settings that influence version planning, independent of where they were loaded from.
+#[derive(Debug, Clone, Default)]
+pub struct PlanConfig {
+    /// How breaking changes bump `0.x` versions.
+    pub zero_version_behavior: ZeroVersionBehavior,
+    /// Prerelease, graduation and forced bump settings per package.
+    pub per_package: HashMap<String, PackageReleaseConfig>,
+}
+
+impl From<&RootChangesetConfig> for PlanConfig {
+    fn from(config: &RootChangesetConfig) -> Self {
+        Self {
+            zero_version_behavior: config.zero_version_behavior(),
+            per_package: HashMap::new(),
+        }
+    }
+}
+
+/// Plans the next versions from in-memory packages and changesets.
+///
+/// Unlike the release operation this reads no files and needs no providers, so
+/// build scripts and xtasks can compute next versions from parsed changesets.
+///
+/// # Errors
+///
+/// Returns `VersionError` if version calculation fails.
+pub fn plan_from_parts(
+    packages: &[PackageInfo],
+    changesets: &[Changeset],
+    config: &PlanConfig,
+) -> Result<ReleasePlan, VersionError> {
+    VersionPlanner::plan_releases_per_package(
+        changesets,
+        packages,
+        &config.per_package,
+        config.zero_version_behavior,
+    )
 }
 
 /// Plans version releases by aggregating changesets and calculating new versions.
@@ -45,13 +93,14 @@ impl VersionPlanner {
         prerelease: Option<&PrereleaseSpec>,
     ) -> Result<ReleasePlan, VersionError> {
         let package_lookup: IndexMap<_, _> = packages.iter().map(|p| (p.name.clone(), p)).collect();
-        let bumps_by_package = Self::aggregate_bumps(changesets);
+        let sources_by_package = Self::aggregate_bump_sources(changesets);
 
         let mut releases = Vec::new();
         let mut unknown_packages = Vec::new();
+        let mut provenance = Vec::new();
 
-        for (name, bumps) in &bumps_by_package {
-            let bump_type = max_bump_type(bumps);
+        for (name, contributions) in &sources_by_package {
+            let bump_type = max_contributed_bump(contributions);
 
             if bump_type.is_none() && prerelease.is_none() {
                 continue;
@@ -66,6 +115,12 @@ impl VersionPlanner {
                     new_version,
                     bump_type: effective_bump,
                 });
+                provenance.push(PackageProvenance {
+                    package: name.clone(),
+                    contributions: contributions.clone(),
+                    zero_version: None,
+                    prerelease: prerelease_provenance(prerelease, PrereleaseSource::CliGlobal),
+                });
             } else {
                 unknown_packages.push(name.clone());
             }
@@ -74,6 +129,7 @@ impl VersionPlanner {
         Ok(ReleasePlan {
             releases,
             unknown_packages,
+            provenance,
         })
     }
 
@@ -100,6 +156,7 @@ impl VersionPlanner {
         Ok(ReleasePlan {
             releases,
             unknown_packages: Vec::new(),
+            provenance: Vec::new(),
         })
     }
 
@@ -115,14 +172,15 @@ impl VersionPlanner {
         zero_behavior: ZeroVersionBehavior,
     ) -> Result<ReleasePlan, VersionError> {
         let package_lookup: IndexMap<_, _> = packages.iter().map(|p| (p.name.clone(), p)).collect();
-        let bumps_by_package = Self::aggregate_bumps(changesets);
+        let sources_by_package = Self::aggregate_bump_sources(changesets);
         let graduates = Self::collect_graduates(changesets);
 
         let mut releases = Vec::new();
         let mut unknown_packages = Vec::new();
+        let mut provenance = Vec::new();
 
-        for (name, bumps) in &bumps_by_package {
-            let bump_type = max_bump_type(bumps);
+        for (name, contributions) in &sources_by_package {
+            let bump_type = max_contributed_bump(contributions);
             let should_graduate = graduates.contains(name);
 
             if bump_type.is_none() && prerelease.is_none() && !should_graduate {
@@ -144,6 +202,17 @@ impl VersionPlanner {
                     new_version,
                     bump_type: effective_bump,
                 });
+                provenance.push(PackageProvenance {
+                    package: name.clone(),
+                    contributions: contributions.clone(),
+                    zero_version: zero_version_transform(
+                        &pkg.version,
+                        bump_type,
+                        zero_behavior,
+                        should_graduate,
+                    ),
+                    prerelease: prerelease_provenance(prerelease, PrereleaseSource::CliGlobal),
+                });
             } else {
                 unknown_packages.push(name.clone());
             }
@@ -152,6 +221,7 @@ impl VersionPlanner {
         Ok(ReleasePlan {
             releases,
             unknown_packages,
+            provenance,
         })
     }
 
@@ -165,6 +235,7 @@ impl VersionPlanner {
         prerelease: Option<&PrereleaseSpec>,
     ) -> Result<ReleasePlan, VersionError> {
         let mut releases = Vec::new();
+        let mut provenance = Vec::new();
 
         for pkg in packages {
             if is_zero_version(&pkg.version) {
@@ -181,12 +252,19 @@ impl VersionPlanner {
                     new_version,
                     bump_type: BumpType::Major,
                 });
+                provenance.push(PackageProvenance {
+                    package: pkg.name.clone(),
+                    contributions: Vec::new(),
+                    zero_version: Some(ZeroVersionTransform::Graduated),
+                    prerelease: prerelease_provenance(prerelease, PrereleaseSource::CliGlobal),
+                });
             }
         }
 
         Ok(ReleasePlan {
             releases,
             unknown_packages: Vec::new(),
+            provenance,
         })
     }
 
@@ -205,14 +283,29 @@ impl VersionPlanner {
         zero_behavior: ZeroVersionBehavior,
     ) -> Result<ReleasePlan, VersionError> {
         let package_lookup: IndexMap<_, _> = packages.iter().map(|p| (p.name.clone(), p)).collect();
-        let bumps_by_package = Self::aggregate_bumps(changesets);
+        let mut sources_by_package = Self::aggregate_bump_sources(changesets);
+        let mut forced: Vec<_> = per_package_config.iter().collect();
+        // Sorted so packages only bumped by force are released in a stable order.
+        forced.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, config) in forced {
+            if let Some(bump) = config.forced_bump {
+                sources_by_package
+                    .entry(name.clone())
+                    .or_default()
+                    .push(BumpContribution {
+                        source: BumpSource::Forced,
+                        bump_type: bump,
+                    });
+            }
+        }
         let changeset_graduates = Self::collect_graduates(changesets);
 
         let mut releases = Vec::new();
         let mut unknown_packages = Vec::new();
+        let mut provenance = Vec::new();
 
-        for (name, bumps) in &bumps_by_package {
-            let bump_type = max_bump_type(bumps);
+        for (name, contributions) in &sources_by_package {
+            let bump_type = max_contributed_bump(contributions);
             let config = per_package_config.get(name);
 
             let prerelease = config.and_then(|c| c.prerelease.as_ref());
@@ -238,13 +331,24 @@ impl VersionPlanner {
                     new_version,
                     bump_type: effective_bump,
                 });
+                provenance.push(PackageProvenance {
+                    package: name.clone(),
+                    contributions: contributions.clone(),
+                    zero_version: zero_version_transform(
+                        &pkg.version,
+                        bump_type,
+                        zero_behavior,
+                        should_graduate,
+                    ),
+                    prerelease: config.and_then(PackageReleaseConfig::prerelease_provenance),
+                });
             } else {
                 unknown_packages.push(name.clone());
             }
         }
 
         for (name, config) in per_package_config {
-            if bumps_by_package.contains_key(name) {
+            if sources_by_package.contains_key(name) {
                 continue;
             }
 
@@ -266,15 +370,66 @@ impl VersionPlanner {
                     new_version,
                     bump_type: BumpType::Major,
                 });
+                provenance.push(PackageProvenance {
+                    package: name.clone(),
+                    contributions: Vec::new(),
+                    zero_version: zero_version_transform(
+                        &pkg.version,
+                        None,
+                        zero_behavior,
+                        config.graduate_zero,
+                    ),
+                    prerelease: config.prerelease_provenance(),
+                });
             }
         }
 
         Ok(ReleasePlan {
             releases,
             unknown_packages,
+            provenance,
         })
     }
 
+    /// Continues each package's pre-release numbering from `pre-release.toml`.
+    ///
+    /// A release that stays on its current pre-release tag gets a number above
+    /// the one last recorded for that package, even when the manifest version
+    /// suggests a lower one. Packages are numbered independently of each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError` if a renumbered version is invalid.
+    pub fn continue_prerelease_numbers(
+        releases: &mut [PackageVersion],
+        state: &PrereleaseState,
+    ) -> Result<(), VersionError> {
+        for release in releases {
+            let Some(recorded) = state.number(&release.name) else {
+                continue;
+            };
+            let Some(planned) = extract_prerelease_number(&release.new_version) else {
+                continue;
+            };
+            let same_base = Version::new(
+                release.new_version.major,
+                release.new_version.minor,
+                release.new_version.patch,
+            ) == Version::new(
+                release.current_version.major,
+                release.current_version.minor,
+                release.current_version.patch,
+            );
+            let same_tag = extract_prerelease_tag(&release.current_version)
+                == extract_prerelease_tag(&release.new_version);
+
+            if same_base && same_tag && planned <= recorded {
+                release.new_version = with_prerelease_number(&release.new_version, recorded + 1)?;
+            }
+        }
+        Ok(())
+    }
+
     fn collect_graduates(changesets: &[Changeset]) -> HashSet<String> {
         changesets
             .iter()
@@ -285,18 +440,35 @@ impl VersionPlanner {
 
     #[must_use]
     pub fn aggregate_bumps(changesets: &[Changeset]) -> IndexMap<String, Vec<BumpType>> {
-        let mut bumps_by_package: IndexMap<String, Vec<BumpType>> = IndexMap::new();
+        Self::aggregate_bump_sources(changesets)
+            .into_iter()
+            .map(|(name, contributions)| {
+                let bumps = contributions.iter().map(|c| c.bump_type).collect();
+                (name, bumps)
+            })
+            .collect()
+    }
 
-        for changeset in changesets {
+    /// Like [`Self::aggregate_bumps`], but keeps which changeset requested each bump.
+    #[must_use]
+    pub fn aggregate_bump_sources(
+        changesets: &[Changeset],
+    ) -> IndexMap<String, Vec<BumpContribution>> {
+        let mut sources_by_package: IndexMap<String, Vec<BumpContribution>> = IndexMap::new();
+
+        for (index, changeset) in changesets.iter().enumerate() {
             for release in &changeset.releases {
-                bumps_by_package
+                sources_by_package
                     .entry(release.name.clone())
                     .or_default()
-                    .push(release.bump_type);
+                    .push(BumpContribution {
+                        source: BumpSource::Changeset(index),
+                        bump_type: release.bump_type,
+                    });
             }
         }
 
-        bumps_by_package
+        sources_by_package
     }
 
     /// Identifies packages that have changesets and those without.
@@ -320,6 +492,55 @@ impl VersionPlanner {
     }
 }
 
+fn prerelease_provenance(
+    spec: Option<&PrereleaseSpec>,
+    source: PrereleaseSource,
+) -> Option<PrereleaseProvenance> {
+    spec.map(|spec| PrereleaseProvenance {
+        tag: spec.to_string(),
+        source,
+    })
+}
+
+/// Mirrors the 0.x handling in `calculate_new_version_with_zero_behavior`.
+fn zero_version_transform(
+    current: &Version,
+    bump_type: Option<BumpType>,
+    zero_behavior: ZeroVersionBehavior,
+    graduate: bool,
+) -> Option<ZeroVersionTransform> {
+    if graduate {
+        return Some(ZeroVersionTransform::Graduated);
+    }
+    if current.major >= 1 {
+        return None;
+    }
+
+    match (zero_behavior, bump_type?) {
+        (ZeroVersionBehavior::EffectiveMinor, BumpType::Major) => {
+            Some(ZeroVersionTransform::Demoted {
+                from: BumpType::Major,
+                to: BumpType::Minor,
+            })
+        }
+        (ZeroVersionBehavior::EffectiveMinor, BumpType::Minor) => {
+            Some(ZeroVersionTransform::Demoted {
+                from: BumpType::Minor,
+                to: BumpType::Patch,
+            })
+        }
+        (ZeroVersionBehavior::AutoPromoteOnMajor, BumpType::Major) => {
+            Some(ZeroVersionTransform::PromotedToStable)
+        }
+        _ => None,
+    }
+}
+
+fn max_contributed_bump(contributions: &[BumpContribution]) -> Option<BumpType> {
+    let bumps: Vec<BumpType> = contributions.iter().map(|c| c.bump_type).collect();
+    max_bump_type(&bumps)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,8 +564,12 @@ mod tests {
                 bump_type: bump,
             }],
             category: ChangeCategory::Changed,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         }
     }
 
@@ -359,8 +584,12 @@ mod tests {
                 })
                 .collect(),
             category: ChangeCategory::Changed,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         }
     }
 
@@ -482,6 +711,87 @@ mod tests {
         assert_eq!(bumps["crate-b"], vec![BumpType::Major]);
     }
 
+    #[test]
+    fn plan_releases_records_winning_changeset() {
+        let packages = vec![make_package("crate-a", "1.0.0")];
+        let changesets = vec![
+            make_changeset("crate-a", BumpType::Patch, "Fix"),
+            make_changeset("crate-a", BumpType::Minor, "Feature"),
+            make_changeset("crate-a", BumpType::Minor, "Another feature"),
+        ];
+
+        let plan = VersionPlanner::plan_releases(&changesets, &packages).expect("plan_releases");
+
+        assert_eq!(plan.provenance.len(), 1);
+        let provenance = &plan.provenance[0];
+        assert_eq!(provenance.package, "crate-a");
+        assert_eq!(provenance.contributions.len(), 3);
+        assert_eq!(
+            provenance.winner(),
+            Some(&BumpContribution {
+                source: BumpSource::Changeset(1),
+                bump_type: BumpType::Minor,
+            })
+        );
+    }
+
+    #[test]
+    fn provenance_serializes_to_json() {
+        let provenance = PackageProvenance {
+            package: "crate-a".to_string(),
+            contributions: vec![
+                BumpContribution {
+                    source: BumpSource::Changeset(0),
+                    bump_type: BumpType::Major,
+                },
+                BumpContribution {
+                    source: BumpSource::Forced,
+                    bump_type: BumpType::Patch,
+                },
+            ],
+            zero_version: Some(ZeroVersionTransform::Demoted {
+                from: BumpType::Major,
+                to: BumpType::Minor,
+            }),
+            prerelease: Some(PrereleaseProvenance {
+                tag: "alpha".to_string(),
+                source: PrereleaseSource::Toml,
+            }),
+        };
+
+        let json = serde_json::to_value(&provenance).expect("serialize provenance");
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "package": "crate-a",
+                "contributions": [
+                    { "source": { "changeset": 0 }, "bumpType": "major" },
+                    { "source": "forced", "bumpType": "patch" },
+                ],
+                "zeroVersion": { "kind": "demoted", "from": "major", "to": "minor" },
+                "prerelease": { "tag": "alpha", "source": "toml" },
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<PackageProvenance>(json).expect("deserialize provenance"),
+            provenance
+        );
+    }
+
+    #[test]
+    fn provenance_enum_values_are_camel_case() {
+        assert_eq!(
+            serde_json::to_value(PrereleaseSource::CliGlobal).expect("serialize source"),
+            "cliGlobal"
+        );
+        assert_eq!(
+            serde_json::to_value(ZeroVersionTransform::PromotedToStable)
+                .expect("serialize transform"),
+            serde_json::json!({ "kind": "promotedToStable" })
+        );
+    }
+
     #[test]
     fn partition_packages_identifies_changed_and_unchanged() {
         let packages = vec![
@@ -628,6 +938,13 @@ mod tests {
             assert_eq!(plan.releases.len(), 1);
             let release = &plan.releases[0];
             assert_eq!(release.new_version, Version::new(0, 2, 0));
+            assert_eq!(
+                plan.provenance[0].zero_version,
+                Some(ZeroVersionTransform::Demoted {
+                    from: BumpType::Major,
+                    to: BumpType::Minor,
+                })
+            );
         }
 
         #[test]
@@ -664,6 +981,10 @@ mod tests {
             assert_eq!(plan.releases.len(), 1);
             let release = &plan.releases[0];
             assert_eq!(release.new_version, Version::new(1, 0, 0));
+            assert_eq!(
+                plan.provenance[0].zero_version,
+                Some(ZeroVersionTransform::PromotedToStable)
+            );
         }
 
         #[test]
@@ -801,8 +1122,12 @@ mod tests {
                     bump_type: bump,
                 }],
                 category: ChangeCategory::Changed,
+                scope: None,
                 consumed_for_prerelease: None,
                 graduate: true,
+                confidential: false,
+                embargo: None,
+                advisory: None,
             }
         }
 
@@ -895,7 +1220,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Alpha),
+                    prerelease_source: Some(PrereleaseSource::Toml),
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -907,6 +1234,19 @@ mod tests {
             )
             .expect("plan_releases_per_package");
 
+            let provenance_a = plan
+                .provenance
+                .iter()
+                .find(|p| p.package == "crate-a")
+                .expect("crate-a should have provenance");
+            assert_eq!(
+                provenance_a.prerelease,
+                Some(PrereleaseProvenance {
+                    tag: "alpha".to_string(),
+                    source: PrereleaseSource::Toml,
+                })
+            );
+
             let release_a = plan
                 .releases
                 .iter()
@@ -925,6 +1265,63 @@ mod tests {
             assert_eq!(release_b.new_version, Version::new(1, 0, 1));
         }
 
+        #[test]
+        fn forced_bump_combines_with_changeset_bumps() {
+            let packages = vec![
+                make_package("crate-a", "1.0.0"),
+                make_package("crate-b", "2.0.0"),
+            ];
+            let changesets = vec![make_changeset("crate-a", BumpType::Patch, "Fix A")];
+
+            let mut config = HashMap::new();
+            config.insert(
+                "crate-a".to_string(),
+                PackageReleaseConfig {
+                    forced_bump: Some(BumpType::Minor),
+                    ..Default::default()
+                },
+            );
+            config.insert(
+                "crate-b".to_string(),
+                PackageReleaseConfig {
+                    forced_bump: Some(BumpType::Patch),
+                    ..Default::default()
+                },
+            );
+
+            let plan = VersionPlanner::plan_releases_per_package(
+                &changesets,
+                &packages,
+                &config,
+                ZeroVersionBehavior::EffectiveMinor,
+            )
+            .expect("plan_releases_per_package");
+
+            let release_a = plan
+                .releases
+                .iter()
+                .find(|r| r.name == "crate-a")
+                .expect("crate-a should be in releases");
+            let release_b = plan
+                .releases
+                .iter()
+                .find(|r| r.name == "crate-b")
+                .expect("crate-b should be in releases");
+
+            assert_eq!(release_a.new_version, Version::new(1, 1, 0));
+            assert_eq!(release_b.new_version, Version::new(2, 0, 1));
+
+            let provenance_a = plan
+                .provenance
+                .iter()
+                .find(|p| p.package == "crate-a")
+                .expect("crate-a should have provenance");
+            assert_eq!(
+                provenance_a.winner().map(|c| c.source),
+                Some(BumpSource::Forced)
+            );
+        }
+
         #[test]
         fn per_package_graduation_applies_to_specific_crate() {
             let packages = vec![
@@ -941,7 +1338,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: None,
+                    prerelease_source: None,
                     graduate_zero: true,
+                    forced_bump: None,
                 },
             );
 
@@ -978,7 +1377,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: None,
+                    prerelease_source: None,
                     graduate_zero: true,
+                    forced_bump: None,
                 },
             );
 
@@ -1004,7 +1405,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Rc),
+                    prerelease_source: None,
                     graduate_zero: true,
+                    forced_bump: None,
                 },
             );
 
@@ -1059,14 +1462,18 @@ mod tests {
                 "alpha-crate".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Alpha),
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
             config.insert(
                 "beta-crate".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Beta),
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -1118,8 +1525,12 @@ mod tests {
                     bump_type: BumpType::Major,
                 }],
                 category: ChangeCategory::Changed,
+                scope: None,
                 consumed_for_prerelease: None,
                 graduate: true,
+                confidential: false,
+                embargo: None,
+                advisory: None,
             }];
 
             let mut config = HashMap::new();
@@ -1127,7 +1538,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Rc),
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -1156,7 +1569,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: None,
+                    prerelease_source: None,
                     graduate_zero: true,
+                    forced_bump: None,
                 },
             );
 
@@ -1200,7 +1615,9 @@ mod tests {
                 "unknown".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Alpha),
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -1225,7 +1642,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Alpha),
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -1312,7 +1731,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: None,
+                    prerelease_source: None,
                     graduate_zero: true,
+                    forced_bump: None,
                 },
             );
 
@@ -1338,7 +1759,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: None,
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -1368,7 +1791,9 @@ mod tests {
                     pkg.name.clone(),
                     PackageReleaseConfig {
                         prerelease: None,
+                        prerelease_source: None,
                         graduate_zero: true,
+                        forced_bump: None,
                     },
                 );
             }
@@ -1402,7 +1827,9 @@ mod tests {
                 "crate-a".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Beta),
+                    prerelease_source: None,
                     graduate_zero: true,
+                    forced_bump: None,
                 },
             );
 
@@ -1512,7 +1939,9 @@ mod tests {
                 "nonexistent".to_string(),
                 PackageReleaseConfig {
                     prerelease: Some(PrereleaseSpec::Alpha),
+                    prerelease_source: None,
                     graduate_zero: false,
+                    forced_bump: None,
                 },
             );
 
@@ -1531,4 +1960,82 @@ mod tests {
             );
         }
     }
+
+    mod continue_prerelease_numbers {
+        use super::*;
+
+        fn release(name: &str, current: &str, new: &str) -> PackageVersion {
+            PackageVersion {
+                name: name.to_string(),
+                current_version: current.parse().expect("valid version"),
+                new_version: new.parse().expect("valid version"),
+                bump_type: BumpType::Patch,
+            }
+        }
+
+        #[test]
+        fn numbers_continue_per_package() {
+            let mut releases = vec![
+                release("crate-a", "1.0.1-alpha.1", "1.0.1-alpha.2"),
+                release("crate-b", "2.0.0-alpha.1", "2.0.0-alpha.2"),
+            ];
+            let mut state = PrereleaseState::new();
+            state.set_number("crate-a".to_string(), 4);
+            state.set_number("crate-b".to_string(), 1);
+
+            VersionPlanner::continue_prerelease_numbers(&mut releases, &state)
+                .expect("renumbering succeeds");
+
+            assert_eq!(
+                releases[0].new_version,
+                "1.0.1-alpha.5".parse::<Version>().expect("valid")
+            );
+            assert_eq!(
+                releases[1].new_version,
+                "2.0.0-alpha.2".parse::<Version>().expect("valid")
+            );
+        }
+
+        #[test]
+        fn new_tag_restarts_numbering() {
+            let mut releases = vec![release("crate-a", "1.0.1-alpha.3", "1.0.1-beta.1")];
+            let mut state = PrereleaseState::new();
+            state.set_number("crate-a".to_string(), 3);
+
+            VersionPlanner::continue_prerelease_numbers(&mut releases, &state)
+                .expect("renumbering succeeds");
+
+            assert_eq!(
+                releases[0].new_version,
+                "1.0.1-beta.1".parse::<Version>().expect("valid")
+            );
+        }
+
+        #[test]
+        fn new_base_version_restarts_numbering() {
+            let mut releases = vec![release("crate-a", "1.0.0", "1.0.1-alpha.1")];
+            let mut state = PrereleaseState::new();
+            state.set_number("crate-a".to_string(), 3);
+
+            VersionPlanner::continue_prerelease_numbers(&mut releases, &state)
+                .expect("renumbering succeeds");
+
+            assert_eq!(
+                releases[0].new_version,
+                "1.0.1-alpha.1".parse::<Version>().expect("valid")
+            );
+        }
+
+        #[test]
+        fn stable_releases_are_untouched() {
+            let mut releases = vec![release("crate-a", "1.0.1-alpha.3", "1.0.1")];
+            let mut state = PrereleaseState::new();
+            state.set_number("crate-a".to_string(), 3);
+
+            VersionPlanner::continue_prerelease_numbers(&mut releases, &state)
+                .expect("renumbering succeeds");
+
+            assert_eq!(releases[0].new_version, Version::new(1, 0, 1));
+        }
+    }
 }
@@ -0,0 +1,297 @@
+use std::path::Path;
+
+use changeset_core::{BumpType, PackageInfo};
+
+use crate::Result;
+use crate::traits::{BumpInferenceProvider, GitProvider};
+
+/// Directories below a package whose changes never affect its users.
+const NON_CODE_DIRS: &[&str] = &["docs", "tests", "benches", "examples"];
+
+/// Infers bump types from the Conventional Commits messages of the commits
+/// that touched a package since a base revision: a `!` after the type or a
+/// `BREAKING CHANGE:` footer is major, `feat` is minor, `fix` and `perf` are
+/// patch. The largest bump over all commits wins.
+pub struct ConventionalCommitsInference<G> {
+    git: G,
+    base: String,
+}
+
+impl<G: GitProvider> ConventionalCommitsInference<G> {
+    #[must_use]
+    pub fn new(git: G, base: impl Into<String>) -> Self {
+        Self {
+            git,
+            base: base.into(),
+        }
+    }
+}
+
+impl<G: GitProvider> BumpInferenceProvider for ConventionalCommitsInference<G> {
+    fn name(&self) -> &'static str {
+        "commits"
+    }
+
+    fn infer_bump(&self, project_root: &Path, package: &PackageInfo) -> Result<Option<BumpType>> {
+        let path = package_dir(project_root, package);
+        let messages = self
+            .git
+            .commit_messages(project_root, &self.base, "HEAD", Some(path))?;
+        Ok(messages
+            .iter()
+            .filter_map(|message| conventional_bump(message))
+            .max())
+    }
+}
+
+/// Bump type a single commit message calls for, if it follows Conventional
+/// Commits and has a releasable type.
+fn conventional_bump(message: &str) -> Option<BumpType> {
+    let header = message.lines().next()?;
+    let (prefix, _) = header.split_once(':')?;
+    let breaking_footer = message
+        .lines()
+        .skip(1)
+        .any(|line| line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:"));
+    let (prefix, bang) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+    let kind = prefix.split_once('(').map_or(prefix, |(kind, _)| kind);
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    if bang || breaking_footer {
+        return Some(BumpType::Major);
+    }
+    match kind.to_ascii_lowercase().as_str() {
+        "feat" => Some(BumpType::Minor),
+        "fix" | "perf" => Some(BumpType::Patch),
+        _ => None,
+    }
+}
+
+/// Infers a patch bump when every file a package changed since a base
+/// revision is documentation, a test, a benchmark or an example. Has no
+/// opinion once source code changed, leaving that to other providers.
+pub struct FileHeuristicsInference<G> {
+    git: G,
+    base: String,
+}
+
+impl<G: GitProvider> FileHeuristicsInference<G> {
+    #[must_use]
+    pub fn new(git: G, base: impl Into<String>) -> Self {
+        Self {
+            git,
+            base: base.into(),
+        }
+    }
+}
+
+impl<G: GitProvider> BumpInferenceProvider for FileHeuristicsInference<G> {
+    fn name(&self) -> &'static str {
+        "files"
+    }
+
+    fn infer_bump(&self, project_root: &Path, package: &PackageInfo) -> Result<Option<BumpType>> {
+        let package_dir = package_dir(project_root, package);
+        let changes = self.git.changed_files(project_root, &self.base, "HEAD")?;
+        let mut files = changes
+            .iter()
+            .filter_map(|change| change.path.strip_prefix(package_dir).ok())
+            .peekable();
+        if files.peek().is_none() {
+            return Ok(None);
+        }
+        Ok(files.all(is_non_code).then_some(BumpType::Patch))
+    }
+}
+
+fn is_non_code(path: &Path) -> bool {
+    let in_non_code_dir = path
+        .components()
+        .next()
+        .and_then(|component| component.as_os_str().to_str())
+        .is_some_and(|dir| NON_CODE_DIRS.contains(&dir));
+    in_non_code_dir || path.extension().is_some_and(|ext| ext == "md")
+}
+
+/// Package directory relative to the project root, as git reports paths.
+fn package_dir<'a>(project_root: &Path, package: &'a PackageInfo) -> &'a Path {
+    package
+        .path
+        .strip_prefix(project_root)
+        .unwrap_or(&package.path)
+}
+
+/// Consults bump inference providers in precedence order; the first one with
+/// an opinion decides.
+#[derive(Default)]
+pub struct BumpInferenceChain {
+    providers: Vec<Box<dyn BumpInferenceProvider>>,
+}
+
+impl BumpInferenceChain {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with(mut self, provider: impl BumpInferenceProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+}
+
+impl BumpInferenceProvider for BumpInferenceChain {
+    fn name(&self) -> &'static str {
+        "chain"
+    }
+
+    fn infer_bump(&self, project_root: &Path, package: &PackageInfo) -> Result<Option<BumpType>> {
+        Ok(self
+            .infer_bump_with_source(project_root, package)?
+            .map(|(bump, _)| bump))
+    }
+
+    fn infer_bump_with_source(
+        &self,
+        project_root: &Path,
+        package: &PackageInfo,
+    ) -> Result<Option<(BumpType, &'static str)>> {
+        for provider in &self.providers {
+            if let Some(inferred) = provider.infer_bump_with_source(project_root, package)? {
+                return Ok(Some(inferred));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use changeset_git::{FileChange, FileStatus};
+
+    use super::*;
+    use crate::mocks::{MockGitProvider, make_package};
+
+    const ROOT: &str = "/mock";
+
+    fn changed(path: &str) -> FileChange {
+        FileChange::new(PathBuf::from(path), FileStatus::Modified)
+    }
+
+    #[test]
+    fn conventional_commit_types_map_to_bumps() {
+        assert_eq!(conventional_bump("feat: add parser"), Some(BumpType::Minor));
+        assert_eq!(
+            conventional_bump("fix(cli): quote paths"),
+            Some(BumpType::Patch)
+        );
+        assert_eq!(
+            conventional_bump("perf: cache lookups"),
+            Some(BumpType::Patch)
+        );
+        assert_eq!(
+            conventional_bump("feat(api)!: drop v1"),
+            Some(BumpType::Major)
+        );
+        assert_eq!(
+            conventional_bump("refactor: split module\n\nBREAKING CHANGE: renamed Foo"),
+            Some(BumpType::Major)
+        );
+        assert_eq!(conventional_bump("chore: bump deps"), None);
+        assert_eq!(conventional_bump("Update README"), None);
+        assert_eq!(conventional_bump("Merge branch 'main': sync"), None);
+    }
+
+    #[test]
+    fn commits_take_the_largest_bump_of_the_package() -> anyhow::Result<()> {
+        let git = MockGitProvider::new()
+            .with_commit(
+                "fix: off by one",
+                vec![PathBuf::from("crates/crate-a/src/lib.rs")],
+            )
+            .with_commit(
+                "feat: new API",
+                vec![PathBuf::from("crates/crate-a/src/api.rs")],
+            )
+            .with_commit(
+                "feat!: rewrite",
+                vec![PathBuf::from("crates/crate-b/src/lib.rs")],
+            );
+        let inference = ConventionalCommitsInference::new(git, "main");
+
+        let package = make_package("crate-a", "1.0.0");
+        assert_eq!(
+            inference.infer_bump(Path::new(ROOT), &package)?,
+            Some(BumpType::Minor)
+        );
+        let untouched = make_package("crate-c", "1.0.0");
+        assert_eq!(inference.infer_bump(Path::new(ROOT), &untouched)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn non_code_changes_suggest_patch() -> anyhow::Result<()> {
+        let git = MockGitProvider::new().with_changed_files(vec![
+            changed("crates/crate-a/README.md"),
+            changed("crates/crate-a/tests/cli.rs"),
+            changed("crates/crate-b/docs/guide.md"),
+            changed("crates/crate-b/src/lib.rs"),
+        ]);
+        let inference = FileHeuristicsInference::new(git, "main");
+
+        let docs_only = make_package("crate-a", "1.0.0");
+        assert_eq!(
+            inference.infer_bump(Path::new(ROOT), &docs_only)?,
+            Some(BumpType::Patch)
+        );
+        let with_code = make_package("crate-b", "1.0.0");
+        assert_eq!(inference.infer_bump(Path::new(ROOT), &with_code)?, None);
+        let unchanged = make_package("crate-c", "1.0.0");
+        assert_eq!(inference.infer_bump(Path::new(ROOT), &unchanged)?, None);
+        Ok(())
+    }
+
+    struct Fixed(Option<BumpType>);
+
+    impl BumpInferenceProvider for Fixed {
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+
+        fn infer_bump(&self, _root: &Path, _package: &PackageInfo) -> Result<Option<BumpType>> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn chain_returns_first_opinion() -> anyhow::Result<()> {
+        let package = make_package("crate-a", "1.0.0");
+        let chain = BumpInferenceChain::new()
+            .with(Fixed(None))
+            .with(Fixed(Some(BumpType::Patch)))
+            .with(Fixed(Some(BumpType::Major)));
+
+        assert_eq!(
+            chain.infer_bump_with_source(Path::new(ROOT), &package)?,
+            Some((BumpType::Patch, "fixed"))
+        );
+        assert_eq!(
+            BumpInferenceChain::new().infer_bump(Path::new(ROOT), &package)?,
+            None
+        );
+        Ok(())
+    }
+}
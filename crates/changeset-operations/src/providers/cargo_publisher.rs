@@ -0,0 +1,59 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{CommandOutput, Publisher};
+
+/// Publishes packages with `cargo publish`.
+pub struct CargoPublisher;
+
+impl CargoPublisher {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CargoPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Publisher for CargoPublisher {
+    fn publish(
+        &self,
+        root: &Path,
+        package: &str,
+        token: Option<&str>,
+        dry_run: bool,
+    ) -> Result<CommandOutput> {
+        // Cargo sets `CARGO` for subcommands; prefer it over whatever `cargo` is on PATH.
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut command = Command::new(cargo);
+        command
+            .args(["publish", "--package", package])
+            .current_dir(root);
+        if dry_run {
+            command.arg("--dry-run");
+        }
+        if let Some(token) = token {
+            command.env("CARGO_REGISTRY_TOKEN", token);
+        }
+
+        let output = command
+            .output()
+            .map_err(|source| OperationError::CommandSpawn {
+                command: format!("cargo publish --package {package}"),
+                source,
+            })?;
+
+        Ok(CommandOutput {
+            success: output.status.success(),
+            code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
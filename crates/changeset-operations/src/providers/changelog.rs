@@ -1,9 +1,68 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use changeset_changelog::{Changelog, RepositoryInfo, VersionRelease};
+use changeset_changelog::{
+    ArchivePolicy, Changelog, ChangelogError, JsonChangelog, RepositoryInfo, StreamedChangelog,
+    VersionRelease, json_changelog_path,
+};
+use changeset_core::atomic_write;
+use indexmap::IndexMap;
+use tracing::warn;
 
 use crate::Result;
-use crate::traits::{ChangelogWriteResult, ChangelogWriter};
+use crate::traits::{
+    ChangelogArchive, ChangelogWriteResult, ChangelogWriter, PlannedChangelogWrite,
+};
+
+/// New content for one changelog file in a batch write.
+struct StagedChangelog {
+    original: Option<String>,
+    content: StagedContent,
+}
+
+enum StagedContent {
+    Markdown(Changelog),
+    /// An existing Markdown changelog whose release sections stay on disk.
+    Streamed(StreamedChangelog),
+    Json(JsonChangelog),
+}
+
+impl StagedChangelog {
+    fn add_release(&mut self, write: &PlannedChangelogWrite) {
+        match &mut self.content {
+            StagedContent::Markdown(changelog) => changelog.add_release(
+                &write.release,
+                write.repo_info.as_ref(),
+                write.previous_version.as_deref(),
+            ),
+            StagedContent::Streamed(changelog) => changelog.add_release(
+                &write.release,
+                write.repo_info.as_ref(),
+                write.previous_version.as_deref(),
+            ),
+            StagedContent::Json(changelog) => changelog.add_release(&write.release),
+        }
+    }
+
+    fn write_to_file(&self, path: &Path) -> std::result::Result<(), ChangelogError> {
+        match &self.content {
+            StagedContent::Markdown(changelog) => changelog.write_to_file(path),
+            StagedContent::Streamed(changelog) => changelog.write(),
+            StagedContent::Json(changelog) => changelog.write_to_file(path),
+        }
+    }
+
+    fn restore(&self, path: &Path) -> std::result::Result<(), ChangelogError> {
+        let result = match (&self.content, &self.original) {
+            (StagedContent::Streamed(changelog), _) => return changelog.restore(),
+            (_, Some(content)) => atomic_write(path, content),
+            (_, None) => std::fs::remove_file(path),
+        };
+        result.map_err(|source| ChangelogError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
 
 #[derive(Clone)]
 pub struct FileSystemChangelogWriter;
@@ -31,14 +90,34 @@ impl ChangelogWriter for FileSystemChangelogWriter {
     ) -> Result<ChangelogWriteResult> {
         let created = !changelog_path.exists();
 
-        let mut changelog = if created {
-            Changelog::new()
+        let streamed = if created {
+            None
         } else {
-            Changelog::from_file(changelog_path)?
+            StreamedChangelog::open(changelog_path)?
         };
+        if let Some(mut changelog) = streamed {
+            changelog.add_release(release, repo_info, previous_version);
+            changelog.write()?;
+        } else {
+            let mut changelog = if created {
+                Changelog::for_release(release)
+            } else {
+                Changelog::from_file(changelog_path)?
+            };
+            changelog.add_release(release, repo_info, previous_version);
+            changelog.write_to_file(changelog_path)?;
+        }
 
-        changelog.add_release(release, repo_info, previous_version);
-        changelog.write_to_file(changelog_path)?;
+        if release.json {
+            let json_path = json_changelog_path(changelog_path);
+            let mut json = if json_path.exists() {
+                JsonChangelog::from_file(&json_path)?
+            } else {
+                JsonChangelog::default()
+            };
+            json.add_release(release);
+            json.write_to_file(&json_path)?;
+        }
 
         Ok(ChangelogWriteResult {
             path: changelog_path.to_path_buf(),
@@ -46,12 +125,130 @@ impl ChangelogWriter for FileSystemChangelogWriter {
         })
     }
 
+    fn write_releases(
+        &self,
+        writes: &[PlannedChangelogWrite],
+    ) -> Result<Vec<ChangelogWriteResult>> {
+        let mut staged: IndexMap<PathBuf, StagedChangelog> = IndexMap::new();
+        let mut results = Vec::with_capacity(writes.len());
+
+        for write in writes {
+            let created = !staged.contains_key(&write.path) && !write.path.exists();
+            if !staged.contains_key(&write.path) {
+                staged.insert(write.path.clone(), stage_changelog(write)?);
+            }
+            if let Some(entry) = staged.get_mut(&write.path) {
+                entry.add_release(write);
+            }
+            if write.release.json {
+                let json_path = json_changelog_path(&write.path);
+                if !staged.contains_key(&json_path) {
+                    staged.insert(json_path.clone(), stage_json_changelog(&json_path)?);
+                }
+                if let Some(entry) = staged.get_mut(&json_path) {
+                    entry.add_release(write);
+                }
+            }
+            results.push(ChangelogWriteResult {
+                path: write.path.clone(),
+                created,
+            });
+        }
+
+        for path in staged.keys() {
+            validate_target(path)?;
+        }
+
+        write_staged(&staged)?;
+        Ok(results)
+    }
+
+    fn extend_release(&self, changelog_path: &Path, release: &VersionRelease) -> Result<()> {
+        let mut changelog = Changelog::from_file(changelog_path)?;
+        if !changelog.extend_release(release) {
+            return Err(crate::OperationError::ReleaseSectionNotFound {
+                path: changelog_path.to_path_buf(),
+                version: release.version.clone(),
+            });
+        }
+        changelog.write_to_file(changelog_path)?;
+
+        let json_path = json_changelog_path(changelog_path);
+        if release.json && json_path.exists() {
+            let mut json = JsonChangelog::from_file(&json_path)?;
+            if !json.extend_release(release) {
+                return Err(crate::OperationError::ReleaseSectionNotFound {
+                    path: json_path,
+                    version: release.version.clone(),
+                });
+            }
+            json.write_to_file(&json_path)?;
+        }
+        Ok(())
+    }
+
+    fn rotate_changelog(
+        &self,
+        changelog_path: &Path,
+        policy: &ArchivePolicy,
+    ) -> Result<Vec<ChangelogArchive>> {
+        if !changelog_path.exists() {
+            return Ok(Vec::new());
+        }
+        let original = read_original(changelog_path)?;
+        let mut changelog = Changelog::parse(&original);
+        let moved = changelog.take_archived_releases(policy);
+        if moved.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let directory = changelog_path.parent().unwrap_or(Path::new(""));
+        let mut staged: IndexMap<PathBuf, StagedChangelog> = IndexMap::new();
+        let mut archives = Vec::with_capacity(moved.len());
+        for releases in &moved {
+            let path = directory.join(&releases.file_name);
+            let existing = if path.exists() {
+                Some(read_original(&path)?)
+            } else {
+                None
+            };
+            let merged = releases.merge_into(existing.as_deref());
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|source| ChangelogError::Write {
+                    path: path.clone(),
+                    source,
+                })?;
+            }
+            archives.push(ChangelogArchive {
+                path: path.clone(),
+                original_content: existing.clone(),
+            });
+            staged.insert(
+                path,
+                StagedChangelog {
+                    original: existing,
+                    content: StagedContent::Markdown(Changelog::parse(&merged)),
+                },
+            );
+        }
+        staged.insert(
+            changelog_path.to_path_buf(),
+            StagedChangelog {
+                original: Some(original),
+                content: StagedContent::Markdown(changelog),
+            },
+        );
+
+        write_staged(&staged)?;
+        Ok(archives)
+    }
+
     fn changelog_exists(&self, path: &Path) -> bool {
         path.exists()
     }
 
     fn restore_changelog(&self, path: &Path, content: &str) -> Result<()> {
-        std::fs::write(path, content).map_err(crate::OperationError::ChangesetFileWrite)
+        atomic_write(path, content).map_err(crate::OperationError::ChangesetFileWrite)
     }
 
     fn delete_changelog(&self, path: &Path) -> Result<()> {
@@ -62,6 +259,94 @@ impl ChangelogWriter for FileSystemChangelogWriter {
     }
 }
 
+fn stage_changelog(write: &PlannedChangelogWrite) -> Result<StagedChangelog> {
+    let path = write.path.as_path();
+    if !path.exists() {
+        return Ok(StagedChangelog {
+            original: None,
+            content: StagedContent::Markdown(Changelog::for_release(&write.release)),
+        });
+    }
+
+    if let Some(changelog) = StreamedChangelog::open(path)? {
+        return Ok(StagedChangelog {
+            original: None,
+            content: StagedContent::Streamed(changelog),
+        });
+    }
+
+    let original = read_original(path)?;
+    Ok(StagedChangelog {
+        content: StagedContent::Markdown(Changelog::parse(&original)),
+        original: Some(original),
+    })
+}
+
+fn stage_json_changelog(path: &Path) -> Result<StagedChangelog> {
+    if !path.exists() {
+        return Ok(StagedChangelog {
+            original: None,
+            content: StagedContent::Json(JsonChangelog::default()),
+        });
+    }
+
+    let original = read_original(path)?;
+    let changelog =
+        JsonChangelog::parse(&original).map_err(|source| ChangelogError::InvalidJson {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    Ok(StagedChangelog {
+        content: StagedContent::Json(changelog),
+        original: Some(original),
+    })
+}
+
+fn read_original(path: &Path) -> std::result::Result<String, ChangelogError> {
+    std::fs::read_to_string(path).map_err(|source| ChangelogError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Rejects targets that cannot be written before any file in the batch is touched.
+fn validate_target(path: &Path) -> Result<()> {
+    let parent_exists = path
+        .parent()
+        .is_none_or(|parent| parent.as_os_str().is_empty() || parent.is_dir());
+    if !parent_exists {
+        return Err(ChangelogError::Write {
+            path: path.to_path_buf(),
+            source: std::io::Error::from(std::io::ErrorKind::NotFound),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Writes every staged file in order, restoring the files already written if
+/// one fails.
+fn write_staged(staged: &IndexMap<PathBuf, StagedChangelog>) -> Result<()> {
+    let mut written: Vec<(&PathBuf, &StagedChangelog)> = Vec::new();
+    for (path, entry) in staged {
+        if let Err(e) = entry.write_to_file(path) {
+            rollback(&written);
+            return Err(e.into());
+        }
+        written.push((path, entry));
+    }
+    Ok(())
+}
+
+/// Best-effort restore of files written earlier in a failed batch.
+fn rollback(written: &[(&PathBuf, &StagedChangelog)]) {
+    for (path, entry) in written.iter().rev() {
+        if let Err(e) = entry.restore(path) {
+            warn!(path = %path.display(), error = %e, "failed to roll back changelog");
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -129,6 +414,147 @@ mod tests {
         Ok(())
     }
 
+    fn planned_write(path: PathBuf, release: VersionRelease) -> PlannedChangelogWrite {
+        PlannedChangelogWrite {
+            path,
+            release,
+            repo_info: None,
+            previous_version: None,
+        }
+    }
+
+    #[test]
+    fn batch_writes_every_changelog() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let existing = dir.path().join("CHANGELOG.md");
+        let created = dir.path().join("NEW.md");
+        let writer = FileSystemChangelogWriter::new();
+        writer.write_release(&existing, &create_test_release(), None, None)?;
+
+        let release = VersionRelease::new(
+            Version::new(1, 1, 0),
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+        );
+        let results = writer.write_releases(&[
+            planned_write(existing.clone(), release.clone()),
+            planned_write(created.clone(), release),
+        ])?;
+
+        assert_eq!(results.len(), 2);
+        assert!(!results[0].created);
+        assert!(results[1].created);
+        let content = std::fs::read_to_string(&existing)?;
+        assert!(content.contains("## [1.1.0] - 2025-02-01"));
+        assert!(content.contains("## [1.0.0] - 2025-01-15"));
+        assert!(std::fs::read_to_string(&created)?.contains("## [1.1.0] - 2025-02-01"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_changelog_is_kept_next_to_markdown() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let markdown = dir.path().join("CHANGELOG.md");
+        let json = dir.path().join("CHANGELOG.json");
+        let writer = FileSystemChangelogWriter::new();
+        writer.write_release(
+            &markdown,
+            &create_test_release().with_json(true),
+            None,
+            None,
+        )?;
+
+        let release = VersionRelease::new(
+            Version::new(1, 1, 0),
+            NaiveDate::from_ymd_opt(2025, 2, 1).expect("valid date"),
+            vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+        )
+        .with_json(true);
+        writer.write_releases(&[planned_write(markdown.clone(), release.clone())])?;
+        writer.extend_release(
+            &markdown,
+            &VersionRelease::new(
+                Version::new(1, 1, 0),
+                release.date,
+                vec![ChangelogEntry::new(ChangeCategory::Fixed, "Late fix")],
+            )
+            .with_json(true),
+        )?;
+
+        let changelog = JsonChangelog::from_file(&json)?;
+        let versions: Vec<_> = changelog
+            .releases
+            .iter()
+            .map(|release| release.version.as_str())
+            .collect();
+        assert_eq!(versions, ["1.1.0", "1.0.0"]);
+        assert_eq!(changelog.releases[0].entries.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn batch_with_unwritable_target_leaves_files_untouched() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let existing = dir.path().join("CHANGELOG.md");
+        let writer = FileSystemChangelogWriter::new();
+        writer.write_release(&existing, &create_test_release(), None, None)?;
+        let before = std::fs::read_to_string(&existing)?;
+
+        let result = writer.write_releases(&[
+            planned_write(existing.clone(), create_test_release()),
+            planned_write(
+                dir.path().join("missing/CHANGELOG.md"),
+                create_test_release(),
+            ),
+        ]);
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&existing)?, before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rotation_moves_old_releases_to_archive() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let changelog_path = dir.path().join("CHANGELOG.md");
+        let writer = FileSystemChangelogWriter::new();
+        for minor in 0..3 {
+            let release = VersionRelease::new(
+                Version::new(1, minor, 0),
+                NaiveDate::from_ymd_opt(2025, 1, 15).expect("valid date"),
+                vec![ChangelogEntry::new(ChangeCategory::Fixed, "Bug fix")],
+            );
+            writer.write_release(&changelog_path, &release, None, None)?;
+        }
+
+        let policy = ArchivePolicy {
+            max_releases: Some(1),
+            archive: "HISTORY.md".to_string(),
+        };
+        let archives = writer.rotate_changelog(&changelog_path, &policy)?;
+
+        let archive_path = dir.path().join("HISTORY.md");
+        assert_eq!(archives.len(), 1);
+        assert_eq!(archives[0].path, archive_path);
+        assert!(archives[0].original_content.is_none());
+        let content = std::fs::read_to_string(&changelog_path)?;
+        assert!(content.contains("## [1.2.0]"));
+        assert!(!content.contains("## [1.1.0]"));
+        assert!(content.contains("[HISTORY.md](HISTORY.md)"));
+        let archive = std::fs::read_to_string(&archive_path)?;
+        assert!(archive.find("## [1.1.0]") < archive.find("## [1.0.0]"));
+        assert!(
+            writer
+                .rotate_changelog(&changelog_path, &policy)?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn changelog_exists_returns_false_when_missing() {
         let dir = TempDir::new().expect("create temp dir");
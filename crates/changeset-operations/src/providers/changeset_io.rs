@@ -1,7 +1,10 @@
+use std::collections::hash_map::RandomState;
 use std::fs;
+use std::hash::BuildHasher;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-use changeset_core::Changeset;
+use changeset_core::{Changeset, LineEnding, atomic_write};
 use changeset_parse::{parse_changeset, serialize_changeset};
 use changeset_project::CHANGESETS_SUBDIR;
 use semver::Version;
@@ -12,8 +15,12 @@ use crate::traits::{ChangesetReader, ChangesetWriter};
 
 const MAX_FILENAME_ATTEMPTS: usize = 100;
 
+/// Longest branch prefix kept in branch-based changeset names.
+const MAX_BRANCH_PREFIX_LEN: usize = 40;
+
 pub struct FileSystemChangesetIO {
     project_root: PathBuf,
+    branch: Option<String>,
 }
 
 impl FileSystemChangesetIO {
@@ -21,8 +28,17 @@ impl FileSystemChangesetIO {
     pub fn new(project_root: &Path) -> Self {
         Self {
             project_root: project_root.to_path_buf(),
+            branch: None,
         }
     }
+
+    /// Names new changesets after `branch` plus a content hash instead of
+    /// random words, so changesets added on concurrent branches cannot collide.
+    #[must_use]
+    pub fn with_branch_names(mut self, branch: &str) -> Self {
+        self.branch = Some(branch.to_string());
+        self
+    }
 }
 
 impl ChangesetReader for FileSystemChangesetIO {
@@ -46,6 +62,12 @@ impl ChangesetReader for FileSystemChangesetIO {
     fn list_consumed_changesets(&self, changeset_dir: &Path) -> Result<Vec<PathBuf>> {
         self.list_changesets_filtered(changeset_dir, true)
     }
+
+    fn changeset_timestamp(&self, relative_path: &Path) -> Option<SystemTime> {
+        fs::metadata(self.project_root.join(relative_path))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
 }
 
 impl FileSystemChangesetIO {
@@ -117,10 +139,16 @@ impl FileSystemChangesetIO {
 }
 
 impl FileSystemChangesetIO {
-    fn resolve_changeset_path(&self, changeset_dir: &Path, path: &Path) -> Result<PathBuf> {
+    pub(super) fn resolve_changeset_path(
+        &self,
+        changeset_dir: &Path,
+        path: &Path,
+    ) -> Result<PathBuf> {
         if path.is_absolute() {
             Ok(path.to_path_buf())
-        } else if path.starts_with(changeset_dir) {
+        } else if path.starts_with(changeset_dir) || has_parent(path) {
+            // Listed changesets are relative to the project root and may live
+            // in any configured changeset directory.
             Ok(self.project_root.join(path))
         } else {
             let full_changeset_dir = self.resolve_base_path(changeset_dir);
@@ -135,6 +163,11 @@ impl FileSystemChangesetIO {
     }
 }
 
+fn has_parent(path: &Path) -> bool {
+    path.parent()
+        .is_some_and(|parent| !parent.as_os_str().is_empty())
+}
+
 fn update_changeset_file<F>(full_path: &Path, updater: F) -> Result<()>
 where
     F: FnOnce(&mut Changeset),
@@ -154,7 +187,9 @@ where
     updater(&mut changeset);
 
     let serialized = serialize_changeset(&changeset)?;
-    fs::write(full_path, serialized).map_err(OperationError::ChangesetFileWrite)?;
+    let line_ending = LineEnding::detect(&content);
+    atomic_write(full_path, line_ending.apply(&serialized))
+        .map_err(OperationError::ChangesetFileWrite)?;
 
     Ok(())
 }
@@ -162,11 +197,14 @@ where
 impl ChangesetWriter for FileSystemChangesetIO {
     fn write_changeset(&self, changeset_dir: &Path, changeset: &Changeset) -> Result<String> {
         let changesets_subdir = changeset_dir.join(CHANGESETS_SUBDIR);
-        let filename = generate_unique_filename(&changesets_subdir);
+        let content = serialize_changeset(changeset)?;
+        let filename = match &self.branch {
+            Some(branch) => generate_branch_filename(&changesets_subdir, branch, &content),
+            None => generate_unique_filename(&changesets_subdir),
+        };
         let file_path = changesets_subdir.join(&filename);
 
-        let content = serialize_changeset(changeset)?;
-        fs::write(&file_path, content).map_err(OperationError::ChangesetFileWrite)?;
+        atomic_write(&file_path, content).map_err(OperationError::ChangesetFileWrite)?;
 
         Ok(filename)
     }
@@ -179,11 +217,21 @@ impl ChangesetWriter for FileSystemChangesetIO {
         };
 
         let content = serialize_changeset(changeset)?;
-        fs::write(&full_path, content).map_err(OperationError::ChangesetFileWrite)?;
+        atomic_write(&full_path, content).map_err(OperationError::ChangesetFileWrite)?;
 
         Ok(())
     }
 
+    fn delete_changeset(&self, path: &Path) -> Result<()> {
+        let full_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.project_root.join(path)
+        };
+
+        fs::remove_file(&full_path).map_err(OperationError::ChangesetFileWrite)
+    }
+
     fn filename_exists(&self, changeset_dir: &Path, filename: &str) -> bool {
         changeset_dir
             .join(CHANGESETS_SUBDIR)
@@ -218,6 +266,38 @@ impl ChangesetWriter for FileSystemChangesetIO {
     }
 }
 
+fn generate_branch_filename(changeset_dir: &Path, branch: &str, content: &str) -> String {
+    let prefix = branch_slug(branch);
+    loop {
+        let hash = RandomState::new().hash_one((content, SystemTime::now()));
+        let filename = format!("{prefix}-{:08x}.md", hash & 0xffff_ffff);
+        if !changeset_dir.join(&filename).exists() {
+            return filename;
+        }
+    }
+}
+
+/// Lowercase `branch` with runs of anything but ASCII letters and digits
+/// replaced by a single dash, e.g. `feature/Login_Form` becomes
+/// `feature-login-form`.
+fn branch_slug(branch: &str) -> String {
+    let mut slug = String::with_capacity(branch.len());
+    for c in branch.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_BRANCH_PREFIX_LEN);
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "changeset".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
 fn generate_unique_filename(changeset_dir: &Path) -> String {
     for _ in 0..MAX_FILENAME_ATTEMPTS {
         if let Some(name) = petname::petname(3, "-") {
@@ -231,7 +311,6 @@ fn generate_unique_filename(changeset_dir: &Path) -> String {
 
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_millis())
-        .unwrap_or(0);
+        .map_or(0, |d| d.as_millis());
     format!("changeset-{timestamp}.md")
 }
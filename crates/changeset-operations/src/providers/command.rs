@@ -0,0 +1,52 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{CommandOutput, CommandRunner};
+
+/// Runs commands with `sh -c`, or `cmd /C` on Windows.
+pub struct ShellCommandRunner;
+
+impl ShellCommandRunner {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ShellCommandRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandRunner for ShellCommandRunner {
+    fn run(&self, dir: &Path, command: &str) -> Result<CommandOutput> {
+        let mut shell = if cfg!(windows) {
+            let mut shell = Command::new("cmd");
+            shell.arg("/C");
+            shell
+        } else {
+            let mut shell = Command::new("sh");
+            shell.arg("-c");
+            shell
+        };
+
+        let output = shell
+            .arg(command)
+            .current_dir(dir)
+            .output()
+            .map_err(|source| OperationError::CommandSpawn {
+                command: command.to_string(),
+                source,
+            })?;
+
+        Ok(CommandOutput {
+            success: output.status.success(),
+            code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use changeset_core::{Changeset, atomic_write};
+use changeset_project::{CONSUMED_STATE_FILENAME, ConsumedStateStorage, RootChangesetConfig};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use super::FileSystemChangesetIO;
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::{ChangesetIO, ChangesetReader, ChangesetWriter};
+
+/// Returns the changeset storage for the project's configured consumed-state storage.
+#[must_use]
+pub fn changeset_io_for(
+    project_root: &Path,
+    root_config: &RootChangesetConfig,
+) -> Arc<dyn ChangesetIO> {
+    match root_config.consumed_state_storage() {
+        ConsumedStateStorage::Frontmatter => Arc::new(FileSystemChangesetIO::new(project_root)),
+        ConsumedStateStorage::File => Arc::new(OutOfBandChangesetIO::new(
+            project_root,
+            root_config.changeset_dir(),
+        )),
+    }
+}
+
+/// Contents of `consumed.toml`: pre-release versions keyed by the git blob hash
+/// of the changeset file they consumed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ConsumedState {
+    #[serde(default)]
+    consumed: BTreeMap<String, String>,
+}
+
+/// Changeset storage that records pre-release consumption in `consumed.toml`
+/// instead of rewriting the changeset files.
+///
+/// Entries are keyed by the git blob hash of the changeset file, so editing a
+/// consumed changeset makes it pending again. Changesets that still carry a
+/// `consumedForPrerelease` front matter field are treated as consumed as well,
+/// which lets a project switch storage between a pre-release and its graduation.
+pub struct OutOfBandChangesetIO {
+    files: FileSystemChangesetIO,
+    project_root: PathBuf,
+    state_path: PathBuf,
+}
+
+impl OutOfBandChangesetIO {
+    #[must_use]
+    pub fn new(project_root: &Path, changeset_dir: &Path) -> Self {
+        Self {
+            files: FileSystemChangesetIO::new(project_root),
+            project_root: project_root.to_path_buf(),
+            state_path: project_root
+                .join(changeset_dir)
+                .join(CONSUMED_STATE_FILENAME),
+        }
+    }
+
+    fn load_state(&self) -> Result<ConsumedState> {
+        let content = match fs::read_to_string(&self.state_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(ConsumedState::default());
+            }
+            Err(source) => {
+                return Err(OperationError::ReleaseStateRead {
+                    path: self.state_path.clone(),
+                    source,
+                });
+            }
+        };
+        toml::from_str(&content).map_err(|source| OperationError::ReleaseStateParse {
+            path: self.state_path.clone(),
+            source,
+        })
+    }
+
+    fn save_state(&self, state: &ConsumedState) -> Result<()> {
+        if state.consumed.is_empty() {
+            return match fs::remove_file(&self.state_path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    Err(OperationError::ReleaseStateWrite {
+                        path: self.state_path.clone(),
+                        source: e,
+                    })
+                }
+                _ => Ok(()),
+            };
+        }
+
+        let content = toml::to_string_pretty(state).map_err(|source| {
+            OperationError::ReleaseStateSerialize {
+                path: self.state_path.clone(),
+                source,
+            }
+        })?;
+        atomic_write(&self.state_path, content).map_err(|source| {
+            OperationError::ReleaseStateWrite {
+                path: self.state_path.clone(),
+                source,
+            }
+        })
+    }
+
+    fn file_hash(full_path: &Path) -> Result<String> {
+        let content = fs::read(full_path).map_err(|source| OperationError::ChangesetFileRead {
+            path: full_path.to_path_buf(),
+            source,
+        })?;
+        Ok(changeset_git::blob_hash(&content)?)
+    }
+
+    fn consumed_version(&self, state: &ConsumedState, path: &Path) -> Result<Option<String>> {
+        let hash = Self::file_hash(&self.project_root.join(path))?;
+        Ok(state.consumed.get(&hash).cloned())
+    }
+
+    fn list_partitioned(&self, changeset_dir: &Path, consumed: bool) -> Result<Vec<PathBuf>> {
+        let state = self.load_state()?;
+        let mut paths = if consumed {
+            self.files.list_consumed_changesets(changeset_dir)?
+        } else {
+            Vec::new()
+        };
+        for path in self.files.list_changesets(changeset_dir)? {
+            if self.consumed_version(&state, &path)?.is_some() == consumed {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+}
+
+impl ChangesetReader for OutOfBandChangesetIO {
+    fn read_changeset(&self, path: &Path) -> Result<Changeset> {
+        let mut changeset = self.files.read_changeset(path)?;
+        if changeset.consumed_for_prerelease.is_none() {
+            changeset.consumed_for_prerelease = self.consumed_version(&self.load_state()?, path)?;
+        }
+        Ok(changeset)
+    }
+
+    fn list_changesets(&self, changeset_dir: &Path) -> Result<Vec<PathBuf>> {
+        self.list_partitioned(changeset_dir, false)
+    }
+
+    fn list_consumed_changesets(&self, changeset_dir: &Path) -> Result<Vec<PathBuf>> {
+        self.list_partitioned(changeset_dir, true)
+    }
+
+    fn changeset_timestamp(&self, path: &Path) -> Option<SystemTime> {
+        self.files.changeset_timestamp(path)
+    }
+}
+
+impl ChangesetWriter for OutOfBandChangesetIO {
+    fn write_changeset(&self, changeset_dir: &Path, changeset: &Changeset) -> Result<String> {
+        self.files.write_changeset(changeset_dir, changeset)
+    }
+
+    fn restore_changeset(&self, path: &Path, changeset: &Changeset) -> Result<()> {
+        self.files.restore_changeset(path, changeset)
+    }
+
+    fn delete_changeset(&self, path: &Path) -> Result<()> {
+        self.files.delete_changeset(path)
+    }
+
+    fn filename_exists(&self, changeset_dir: &Path, filename: &str) -> bool {
+        self.files.filename_exists(changeset_dir, filename)
+    }
+
+    fn mark_consumed_for_prerelease(
+        &self,
+        changeset_dir: &Path,
+        paths: &[&Path],
+        version: &Version,
+    ) -> Result<()> {
+        let mut state = self.load_state()?;
+        for path in paths {
+            let full_path = self.files.resolve_changeset_path(changeset_dir, path)?;
+            state
+                .consumed
+                .insert(Self::file_hash(&full_path)?, version.to_string());
+        }
+        self.save_state(&state)
+    }
+
+    fn clear_consumed_for_prerelease(&self, changeset_dir: &Path, paths: &[&Path]) -> Result<()> {
+        let mut state = self.load_state()?;
+        let mut marked = Vec::new();
+        for path in paths {
+            let full_path = self.files.resolve_changeset_path(changeset_dir, path)?;
+            state.consumed.remove(&Self::file_hash(&full_path)?);
+            if self
+                .files
+                .read_changeset(&full_path)?
+                .consumed_for_prerelease
+                .is_some()
+            {
+                marked.push(*path);
+            }
+        }
+        self.save_state(&state)?;
+        self.files
+            .clear_consumed_for_prerelease(changeset_dir, &marked)
+    }
+
+    fn consumed_state_file(&self) -> Option<PathBuf> {
+        Some(self.state_path.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const CHANGESET: &str = "---\n\"my-crate\": patch\n---\n\nFix a bug\n";
+
+    fn setup() -> (TempDir, OutOfBandChangesetIO) {
+        let dir = TempDir::new().expect("failed to create temp dir");
+        let changesets = dir.path().join(".changeset/changesets");
+        fs::create_dir_all(&changesets).expect("failed to create changeset dir");
+        fs::write(changesets.join("fix.md"), CHANGESET).expect("failed to write changeset");
+        fs::write(
+            changesets.join("other.md"),
+            CHANGESET.replace("bug", "typo"),
+        )
+        .expect("failed to write changeset");
+        let io = OutOfBandChangesetIO::new(dir.path(), Path::new(".changeset"));
+        (dir, io)
+    }
+
+    #[test]
+    fn marking_consumed_leaves_changeset_file_untouched() -> anyhow::Result<()> {
+        let (dir, io) = setup();
+        let fix = Path::new(".changeset/changesets/fix.md");
+
+        io.mark_consumed_for_prerelease(
+            Path::new(".changeset"),
+            &[fix],
+            &Version::parse("1.0.1-alpha.1")?,
+        )?;
+
+        assert_eq!(fs::read_to_string(dir.path().join(fix))?, CHANGESET);
+        assert!(dir.path().join(".changeset/consumed.toml").exists());
+        assert_eq!(
+            io.read_changeset(fix)?.consumed_for_prerelease.as_deref(),
+            Some("1.0.1-alpha.1")
+        );
+        assert_eq!(
+            io.list_consumed_changesets(Path::new(".changeset"))?,
+            vec![fix.to_path_buf()]
+        );
+        assert_eq!(
+            io.list_changesets(Path::new(".changeset"))?,
+            vec![PathBuf::from(".changeset/changesets/other.md")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn clearing_consumed_removes_state_file() -> anyhow::Result<()> {
+        let (dir, io) = setup();
+        let fix = Path::new(".changeset/changesets/fix.md");
+        io.mark_consumed_for_prerelease(
+            Path::new(".changeset"),
+            &[fix],
+            &Version::parse("1.0.1-alpha.1")?,
+        )?;
+
+        io.clear_consumed_for_prerelease(Path::new(".changeset"), &[fix])?;
+
+        assert!(!dir.path().join(".changeset/consumed.toml").exists());
+        assert!(
+            io.list_consumed_changesets(Path::new(".changeset"))?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn editing_consumed_changeset_makes_it_pending() -> anyhow::Result<()> {
+        let (dir, io) = setup();
+        let fix = Path::new(".changeset/changesets/fix.md");
+        io.mark_consumed_for_prerelease(
+            Path::new(".changeset"),
+            &[fix],
+            &Version::parse("1.0.1-alpha.1")?,
+        )?;
+
+        fs::write(dir.path().join(fix), CHANGESET.replace("bug", "crash"))?;
+
+        assert!(
+            io.list_consumed_changesets(Path::new(".changeset"))?
+                .is_empty()
+        );
+        assert_eq!(io.read_changeset(fix)?.consumed_for_prerelease, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn front_matter_markers_still_count_as_consumed() -> anyhow::Result<()> {
+        let (dir, io) = setup();
+        let fix = Path::new(".changeset/changesets/fix.md");
+        fs::write(
+            dir.path().join(fix),
+            format!(
+                "---\nconsumedForPrerelease: 1.0.1-alpha.1\n{}",
+                &CHANGESET[4..]
+            ),
+        )?;
+
+        assert_eq!(
+            io.list_consumed_changesets(Path::new(".changeset"))?,
+            vec![fix.to_path_buf()]
+        );
+
+        io.clear_consumed_for_prerelease(Path::new(".changeset"), &[fix])?;
+
+        assert_eq!(io.read_changeset(fix)?.consumed_for_prerelease, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::{GitHubOidcTokenProvider, HttpClient};
+use crate::traits::TokenProvider;
+
+const CRATES_IO_API_URL: &str = "https://crates.io";
+/// The audience crates.io expects in GitHub Actions OIDC tokens.
+const CRATES_IO_AUDIENCE: &str = "crates.io";
+const TRUSTED_PUBLISHING_PATH: &str = "/api/v1/trusted_publishing/tokens";
+
+/// Exchanges a GitHub Actions OIDC token for a short-lived crates.io publish
+/// token, as set up under a crate's trusted publishers on crates.io.
+pub struct TrustedPublishing {
+    oidc: GitHubOidcTokenProvider,
+    api_url: String,
+    client: HttpClient,
+}
+
+#[derive(Deserialize)]
+struct ExchangeResponse {
+    token: String,
+}
+
+impl TrustedPublishing {
+    /// Builds an exchange when the job may request OIDC tokens.
+    ///
+    /// Returns `None` outside GitHub Actions and in jobs without the
+    /// `id-token: write` permission.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        Some(Self {
+            oidc: GitHubOidcTokenProvider::from_vars(CRATES_IO_AUDIENCE, var)?,
+            api_url: CRATES_IO_API_URL.to_string(),
+            client: HttpClient::new(),
+        })
+    }
+
+    #[must_use]
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = api_url.into();
+        self
+    }
+
+    fn tokens_url(&self) -> String {
+        format!(
+            "{}{TRUSTED_PUBLISHING_PATH}",
+            self.api_url.trim_end_matches('/')
+        )
+    }
+
+    /// Requests a publish token from crates.io.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if GitHub Actions issues no OIDC token, or crates.io
+    /// rejects it, typically because no trusted publisher matches the
+    /// repository and workflow.
+    pub fn exchange(&self) -> Result<String> {
+        let jwt = self.oidc.token()?.unwrap_or_default();
+        let body = self
+            .client
+            .post(&self.tokens_url(), &[], &json!({ "jwt": jwt }).to_string())
+            .map_err(|source| OperationError::TrustedPublishingExchange {
+                source: Box::new(source),
+            })?;
+        let response: ExchangeResponse = serde_json::from_str(&body)
+            .map_err(|source| OperationError::TrustedPublishingResponse { source })?;
+
+        Ok(response.token)
+    }
+
+    /// Revokes a token from [`Self::exchange`] before it expires on its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if crates.io cannot be reached or refuses the request.
+    pub fn revoke(&self, token: &str) -> Result<()> {
+        let authorization = format!("Bearer {token}");
+        self.client
+            .delete(&self.tokens_url(), &[("Authorization", &authorization)])
+            .map_err(|source| OperationError::TrustedPublishingRevoke {
+                source: Box::new(source),
+            })
+    }
+}
+
+impl TokenProvider for TrustedPublishing {
+    fn token(&self) -> Result<Option<String>> {
+        self.exchange().map(Some)
+    }
+}
+
+/// How `publish` authenticates to crates.io.
+pub enum RegistryCredentials {
+    /// `CARGO_REGISTRY_TOKEN`, or the token stored by `cargo login`, which
+    /// cargo reads itself.
+    Cargo,
+    /// A token cargo-changeset found on its own, such as one in the OS keyring.
+    Token(String),
+    /// A short-lived token from trusted publishing, revoked by [`Self::release`].
+    TrustedPublishing {
+        exchange: Box<TrustedPublishing>,
+        token: String,
+    },
+}
+
+impl RegistryCredentials {
+    /// Picks the credentials for this environment.
+    ///
+    /// A `CARGO_REGISTRY_TOKEN` wins unless `trusted_publishing` is set. In
+    /// GitHub Actions jobs allowed to request OIDC tokens, a trusted
+    /// publishing token is exchanged next. Elsewhere, a token in the OS
+    /// keyring (with the `keyring` feature) or cargo's own credentials are
+    /// used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `trusted_publishing` is set outside GitHub Actions,
+    /// if a GitHub Actions job has neither a `CARGO_REGISTRY_TOKEN` nor the
+    /// `id-token: write` permission, or if the token exchange fails.
+    pub fn resolve(trusted_publishing: bool) -> Result<Self> {
+        Self::resolve_from(trusted_publishing, |name| std::env::var(name).ok())
+    }
+
+    fn resolve_from(
+        trusted_publishing: bool,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        let present = |name: &str| var(name).filter(|value| !value.is_empty());
+
+        if !trusted_publishing && present("CARGO_REGISTRY_TOKEN").is_some() {
+            return Ok(Self::Cargo);
+        }
+        if let Some(exchange) = TrustedPublishing::from_vars(&var) {
+            let token = exchange.exchange()?;
+            return Ok(Self::TrustedPublishing {
+                exchange: Box::new(exchange),
+                token,
+            });
+        }
+
+        let in_actions = present("GITHUB_ACTIONS").as_deref() == Some("true");
+        if in_actions {
+            return Err(OperationError::NoRegistryCredentials);
+        }
+        if trusted_publishing {
+            return Err(OperationError::TrustedPublishingUnavailable);
+        }
+
+        #[cfg(feature = "keyring")]
+        if let Some(token) = crate::providers::KeyringTokenProvider::new(
+            crate::providers::token::KEYRING_SERVICE,
+            "crates-io",
+        )
+        .token()?
+        {
+            return Ok(Self::Token(token));
+        }
+
+        Ok(Self::Cargo)
+    }
+
+    /// The token to hand to cargo, or `None` to let cargo find its own.
+    #[must_use]
+    pub fn token(&self) -> Option<&str> {
+        match self {
+            Self::Cargo => None,
+            Self::Token(token) | Self::TrustedPublishing { token, .. } => Some(token),
+        }
+    }
+
+    /// Revokes a trusted publishing token; other credentials are left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a trusted publishing token cannot be revoked.
+    pub fn release(self) -> Result<()> {
+        match self {
+            Self::TrustedPublishing { exchange, token } => exchange.revoke(&token),
+            Self::Cargo | Self::Token(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+        vars.iter()
+            .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn registry_token_takes_precedence() -> anyhow::Result<()> {
+        let env = env(&[
+            ("CARGO_REGISTRY_TOKEN", "secret"),
+            ("ACTIONS_ID_TOKEN_REQUEST_URL", "http://127.0.0.1:9/token"),
+            ("ACTIONS_ID_TOKEN_REQUEST_TOKEN", "request-token"),
+        ]);
+
+        let credentials = RegistryCredentials::resolve_from(false, |name| env.get(name).cloned())?;
+
+        assert!(matches!(credentials, RegistryCredentials::Cargo));
+        assert_eq!(credentials.token(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn actions_job_without_oidc_permission_fails() {
+        let env = env(&[("GITHUB_ACTIONS", "true")]);
+
+        let result = RegistryCredentials::resolve_from(false, |name| env.get(name).cloned());
+
+        assert!(matches!(result, Err(OperationError::NoRegistryCredentials)));
+    }
+
+    #[test]
+    fn trusted_publishing_outside_actions_fails() {
+        let env = env(&[("CARGO_REGISTRY_TOKEN", "secret")]);
+
+        let result = RegistryCredentials::resolve_from(true, |name| env.get(name).cloned());
+
+        assert!(matches!(
+            result,
+            Err(OperationError::TrustedPublishingUnavailable)
+        ));
+    }
+
+    #[test]
+    fn exchanges_oidc_token_for_publish_token() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base = format!("http://{}", listener.local_addr()?);
+        let server = thread::spawn(move || -> std::io::Result<Vec<String>> {
+            let responses = [r#"{"value":"jwt"}"#, r#"{"token":"publish-token"}"#];
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept()?;
+                let mut reader = BufReader::new(stream.try_clone()?);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line)?;
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                    request.push_str(&line);
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body)?;
+                request.push_str(&String::from_utf8_lossy(&body));
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )?;
+            }
+            Ok(requests)
+        });
+        let env = env(&[
+            (
+                "ACTIONS_ID_TOKEN_REQUEST_URL",
+                &format!("{base}/oidc?api-version=2.0"),
+            ),
+            ("ACTIONS_ID_TOKEN_REQUEST_TOKEN", "request-token"),
+        ]);
+
+        let exchange = TrustedPublishing::from_vars(|name| env.get(name).cloned())
+            .ok_or_else(|| anyhow::anyhow!("expected an exchange"))?
+            .with_api_url(&base);
+
+        assert_eq!(exchange.exchange()?, "publish-token");
+        let requests = server
+            .join()
+            .map_err(|_| anyhow::anyhow!("server thread panicked"))??;
+        assert!(requests[0].starts_with("GET /oidc?api-version=2.0&audience=crates.io "));
+        assert!(requests[1].starts_with("POST /api/v1/trusted_publishing/tokens "));
+        assert!(requests[1].ends_with(r#"{"jwt":"jwt"}"#));
+        Ok(())
+    }
+}
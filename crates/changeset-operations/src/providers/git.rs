@@ -1,16 +1,27 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use changeset_git::{CommitInfo, FileChange, Repository, TagInfo};
+use changeset_git::{CommitIdentity, CommitInfo, FileChange, Identity, Repository, TagInfo};
+use changeset_project::OfflineBehavior;
+use chrono::{DateTime, Utc};
 
-use crate::Result;
-use crate::traits::GitProvider;
+use crate::traits::{ChangedFilesProvider, GitProvider};
+use crate::{OperationError, Result};
 
-pub struct Git2Provider;
+pub struct Git2Provider {
+    offline: Option<OfflineBehavior>,
+}
 
 impl Git2Provider {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self { offline: None }
+    }
+
+    /// Never fetches; remote checks are skipped or fail according to `behavior`.
+    #[must_use]
+    pub fn with_offline(mut self, behavior: OfflineBehavior) -> Self {
+        self.offline = Some(behavior);
+        self
     }
 }
 
@@ -20,6 +31,18 @@ impl Default for Git2Provider {
     }
 }
 
+/// The git-diff backend: compares `base` and `head` in the local repository.
+impl ChangedFilesProvider for Git2Provider {
+    fn changed_files(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<FileChange>> {
+        GitProvider::changed_files(self, project_root, base, head)
+    }
+}
+
 impl GitProvider for Git2Provider {
     fn changed_files(
         &self,
@@ -31,9 +54,48 @@ impl GitProvider for Git2Provider {
         Ok(repo.changed_files(Some(base), head)?)
     }
 
-    fn is_working_tree_clean(&self, project_root: &Path) -> Result<bool> {
+    fn staged_changes(&self, project_root: &Path) -> Result<Vec<FileChange>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.staged_files()?)
+    }
+
+    fn hooks_dir(&self, project_root: &Path) -> Result<PathBuf> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.hooks_dir()?)
+    }
+
+    fn repository_root(&self, project_root: &Path) -> Result<PathBuf> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.root().to_path_buf())
+    }
+
+    fn set_config(&self, project_root: &Path, key: &str, value: &str) -> Result<()> {
         let repo = Repository::open(project_root)?;
-        Ok(repo.is_working_tree_clean()?)
+        Ok(repo.set_config(key, value)?)
+    }
+
+    fn is_working_tree_clean(&self, project_root: &Path, ignore_submodules: bool) -> Result<bool> {
+        let repo = Repository::open(project_root)?;
+        if ignore_submodules {
+            Ok(repo.is_working_tree_clean_ignoring_submodules()?)
+        } else {
+            Ok(repo.is_working_tree_clean()?)
+        }
+    }
+
+    fn dirty_files(&self, project_root: &Path, ignore_submodules: bool) -> Result<Vec<PathBuf>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.dirty_files(ignore_submodules)?)
+    }
+
+    fn stash_push(&self, project_root: &Path, message: &str) -> Result<bool> {
+        let mut repo = Repository::open(project_root)?;
+        Ok(repo.stash_push(message)?)
+    }
+
+    fn stash_pop(&self, project_root: &Path) -> Result<()> {
+        let mut repo = Repository::open(project_root)?;
+        Ok(repo.stash_pop()?)
     }
 
     fn current_branch(&self, project_root: &Path) -> Result<String> {
@@ -46,9 +108,25 @@ impl GitProvider for Git2Provider {
         Ok(repo.stage_files(paths)?)
     }
 
-    fn commit(&self, project_root: &Path, message: &str) -> Result<CommitInfo> {
+    fn ignored_paths(&self, project_root: &Path, paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
         let repo = Repository::open(project_root)?;
-        Ok(repo.commit(message)?)
+        let mut ignored = Vec::new();
+        for path in paths {
+            if repo.is_path_ignored(path)? {
+                ignored.push(path.clone());
+            }
+        }
+        Ok(ignored)
+    }
+
+    fn commit(
+        &self,
+        project_root: &Path,
+        message: &str,
+        identity: &CommitIdentity,
+    ) -> Result<CommitInfo> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.commit_as(message, identity)?)
     }
 
     fn create_tag(&self, project_root: &Path, tag_name: &str, message: &str) -> Result<TagInfo> {
@@ -56,9 +134,120 @@ impl GitProvider for Git2Provider {
         Ok(repo.create_tag(tag_name, message)?)
     }
 
-    fn remote_url(&self, project_root: &Path) -> Result<Option<String>> {
+    fn create_tag_at(
+        &self,
+        project_root: &Path,
+        tag_name: &str,
+        message: &str,
+        target_sha: &str,
+    ) -> Result<TagInfo> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.create_tag_at(tag_name, message, target_sha)?)
+    }
+
+    fn is_up_to_date(&self, project_root: &Path) -> Result<bool> {
+        let repo = Repository::open(project_root)?;
+        match self.offline {
+            None => Ok(repo.is_up_to_date()?),
+            Some(OfflineBehavior::Error) if repo.has_upstream()? => {
+                Err(OperationError::OfflineNetworkRequired {
+                    step: "the remote staleness check",
+                })
+            }
+            Some(_) => Ok(true),
+        }
+    }
+
+    fn list_tags(&self, project_root: &Path) -> Result<Vec<String>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.list_tags()?)
+    }
+
+    fn tags_at_head(&self, project_root: &Path) -> Result<Vec<String>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.tags_at_head()?)
+    }
+
+    fn file_added_date(&self, project_root: &Path, path: &Path) -> Result<Option<DateTime<Utc>>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo
+            .file_added_time(path)?
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0)))
+    }
+
+    fn release_dates(&self, project_root: &Path) -> Result<Vec<DateTime<Utc>>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo
+            .tagged_commit_times()?
+            .into_iter()
+            .filter_map(|seconds| DateTime::from_timestamp(seconds, 0))
+            .collect())
+    }
+
+    fn file_at_ref(
+        &self,
+        project_root: &Path,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Option<String>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.file_at_ref(reference, path)?)
+    }
+
+    fn commit_messages(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+        path: Option<&Path>,
+    ) -> Result<Vec<String>> {
         let repo = Repository::open(project_root)?;
-        Ok(repo.remote_url()?)
+        Ok(repo.commit_messages(base, head, path)?)
+    }
+
+    fn deleted_file_contents(
+        &self,
+        project_root: &Path,
+        base: Option<&str>,
+        head: &str,
+        path: &Path,
+    ) -> Result<Vec<(PathBuf, String)>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.deleted_files(base, head, path)?)
+    }
+
+    fn is_head_pushed(&self, project_root: &Path, tags: &[String]) -> Result<bool> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.is_head_pushed(tags)?)
+    }
+
+    fn head_sha(&self, project_root: &Path) -> Result<String> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.head_sha()?)
+    }
+
+    fn amend_commit(
+        &self,
+        project_root: &Path,
+        committer: Option<&Identity>,
+    ) -> Result<CommitInfo> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.amend_head(committer)?)
+    }
+
+    fn move_tag(
+        &self,
+        project_root: &Path,
+        tag_name: &str,
+        message: Option<&str>,
+    ) -> Result<TagInfo> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.move_tag(tag_name, message)?)
+    }
+
+    fn remote_url(&self, project_root: &Path, remote: Option<&str>) -> Result<Option<String>> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.remote_url(remote)?)
     }
 
     fn delete_files(&self, project_root: &Path, paths: &[&Path]) -> Result<()> {
@@ -75,4 +264,9 @@ impl GitProvider for Git2Provider {
         let repo = Repository::open(project_root)?;
         Ok(repo.reset_to_parent()?)
     }
+
+    fn reset_soft_to(&self, project_root: &Path, sha: &str) -> Result<()> {
+        let repo = Repository::open(project_root)?;
+        Ok(repo.reset_soft_to(sha)?)
+    }
 }
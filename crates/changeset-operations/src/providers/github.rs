@@ -0,0 +1,325 @@
+use std::path::{Path, PathBuf};
+
+use changeset_git::{FileChange, FileStatus};
+use serde::Deserialize;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::HttpClient;
+use crate::traits::ChangedFilesProvider;
+
+const DEFAULT_API_URL: &str = "https://api.github.com";
+const FILES_PER_PAGE: usize = 100;
+/// GitHub stops listing pull request files after this many entries.
+const MAX_PULL_REQUEST_FILES: usize = 3000;
+
+/// Lists changed files through the GitHub pull request files API instead of
+/// diffing locally, so shallow CI checkouts work without fetching history.
+///
+/// The file list always describes the pull request as a whole; the `base` and
+/// `head` arguments are ignored.
+pub struct GitHubChangedFilesProvider {
+    api_url: String,
+    repository: String,
+    pull_number: u64,
+    labels: Vec<String>,
+    token: Option<String>,
+    client: HttpClient,
+}
+
+#[derive(Deserialize)]
+struct PullRequestEvent {
+    pull_request: Option<PullRequestRef>,
+}
+
+#[derive(Deserialize)]
+struct PullRequestRef {
+    number: u64,
+    #[serde(default)]
+    labels: Vec<PullRequestLabel>,
+}
+
+#[derive(Deserialize)]
+struct PullRequestLabel {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct PullRequestFile {
+    filename: PathBuf,
+    status: String,
+    previous_filename: Option<PathBuf>,
+}
+
+impl GitHubChangedFilesProvider {
+    #[must_use]
+    pub fn new(repository: impl Into<String>, pull_number: u64) -> Self {
+        Self {
+            api_url: DEFAULT_API_URL.to_string(),
+            repository: repository.into(),
+            pull_number,
+            labels: Vec::new(),
+            token: None,
+            client: HttpClient::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = api_url.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Names of the labels on the pull request when the workflow was triggered.
+    #[must_use]
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    #[must_use]
+    pub fn with_http_client(mut self, client: HttpClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Builds a provider from the GitHub Actions environment when the workflow
+    /// runs for a pull request and `project_root` lies inside its checkout.
+    ///
+    /// Returns `None` outside Actions, for non-PR events, and for projects
+    /// outside `GITHUB_WORKSPACE`, where the PR file list would not apply.
+    /// The provider has no token; pass one from [`github_token_provider`]
+    /// with [`Self::with_token`].
+    ///
+    /// [`github_token_provider`]: crate::providers::github_token_provider
+    #[must_use]
+    pub fn from_env(project_root: &Path) -> Option<Self> {
+        Self::from_vars(project_root, |name| std::env::var(name).ok())
+    }
+
+    fn from_vars(project_root: &Path, var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        if var("GITHUB_ACTIONS").as_deref() != Some("true") {
+            return None;
+        }
+
+        let workspace = PathBuf::from(var("GITHUB_WORKSPACE")?);
+        if !is_within(project_root, &workspace) {
+            return None;
+        }
+
+        let event = std::fs::read(var("GITHUB_EVENT_PATH")?).ok()?;
+        let pull_request = serde_json::from_slice::<PullRequestEvent>(&event)
+            .ok()?
+            .pull_request?;
+
+        let provider = Self::new(var("GITHUB_REPOSITORY")?, pull_request.number).with_labels(
+            pull_request
+                .labels
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
+        );
+        Some(match var("GITHUB_API_URL").filter(|url| !url.is_empty()) {
+            Some(api_url) => provider.with_api_url(api_url),
+            None => provider,
+        })
+    }
+
+    fn fetch_page(&self, page: usize) -> Result<Vec<PullRequestFile>> {
+        let url = format!(
+            "{}/repos/{}/pulls/{}/files?per_page={FILES_PER_PAGE}&page={page}",
+            self.api_url.trim_end_matches('/'),
+            self.repository,
+            self.pull_number
+        );
+
+        let authorization = self.token.as_ref().map(|token| format!("Bearer {token}"));
+        let mut headers = vec![
+            ("Accept", "application/vnd.github+json"),
+            ("User-Agent", "cargo-changeset"),
+        ];
+        if let Some(authorization) = &authorization {
+            headers.push(("Authorization", authorization));
+        }
+
+        let body =
+            self.client
+                .get(&url, &headers)
+                .map_err(|source| OperationError::GitHubApiRequest {
+                    source: Box::new(source),
+                })?;
+
+        serde_json::from_str(&body).map_err(|source| OperationError::GitHubApiResponse { source })
+    }
+}
+
+impl ChangedFilesProvider for GitHubChangedFilesProvider {
+    fn changed_files(
+        &self,
+        _project_root: &Path,
+        _base: &str,
+        _head: &str,
+    ) -> Result<Vec<FileChange>> {
+        let mut changes = Vec::new();
+
+        for page in 1..=MAX_PULL_REQUEST_FILES / FILES_PER_PAGE {
+            let files = self.fetch_page(page)?;
+            let last_page = files.len() < FILES_PER_PAGE;
+            changes.extend(files.into_iter().map(to_file_change));
+            if last_page {
+                break;
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+fn to_file_change(file: PullRequestFile) -> FileChange {
+    let status = match file.status.as_str() {
+        "added" => FileStatus::Added,
+        "removed" => FileStatus::Deleted,
+        "renamed" => FileStatus::Renamed,
+        "copied" => FileStatus::Copied,
+        _ => FileStatus::Modified,
+    };
+
+    FileChange {
+        path: file.filename,
+        status,
+        old_path: file.previous_filename,
+    }
+}
+
+fn is_within(path: &Path, dir: &Path) -> bool {
+    match (path.canonicalize(), dir.canonicalize()) {
+        (Ok(path), Ok(dir)) => path.starts_with(dir),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn actions_env(workspace: &Path, event: &str) -> anyhow::Result<HashMap<String, String>> {
+        let event_path = workspace.join("event.json");
+        fs::write(&event_path, event)?;
+
+        Ok(HashMap::from([
+            ("GITHUB_ACTIONS".to_string(), "true".to_string()),
+            (
+                "GITHUB_WORKSPACE".to_string(),
+                workspace.display().to_string(),
+            ),
+            (
+                "GITHUB_EVENT_PATH".to_string(),
+                event_path.display().to_string(),
+            ),
+            ("GITHUB_REPOSITORY".to_string(), "owner/repo".to_string()),
+        ]))
+    }
+
+    #[test]
+    fn from_vars_reads_pull_request_context() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let env = actions_env(dir.path(), r#"{"pull_request": {"number": 42}}"#)?;
+
+        let provider =
+            GitHubChangedFilesProvider::from_vars(dir.path(), |name| env.get(name).cloned())
+                .ok_or_else(|| anyhow::anyhow!("expected a provider"))?;
+
+        assert_eq!(provider.repository, "owner/repo");
+        assert_eq!(provider.pull_number, 42);
+        assert!(provider.labels().is_empty());
+        assert_eq!(provider.api_url, DEFAULT_API_URL);
+        assert!(provider.token.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn from_vars_reads_pull_request_labels() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let env = actions_env(
+            dir.path(),
+            r#"{"pull_request": {"number": 42, "labels": [{"name": "security"}, {"name": "bug"}]}}"#,
+        )?;
+
+        let provider =
+            GitHubChangedFilesProvider::from_vars(dir.path(), |name| env.get(name).cloned())
+                .ok_or_else(|| anyhow::anyhow!("expected a provider"))?;
+
+        assert_eq!(provider.labels(), ["security", "bug"]);
+        Ok(())
+    }
+
+    #[test]
+    fn from_vars_skips_non_pull_request_events() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let env = actions_env(dir.path(), r#"{"ref": "refs/heads/main"}"#)?;
+
+        assert!(
+            GitHubChangedFilesProvider::from_vars(dir.path(), |name| env.get(name).cloned())
+                .is_none()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_vars_skips_projects_outside_workspace() -> anyhow::Result<()> {
+        let workspace = TempDir::new()?;
+        let elsewhere = TempDir::new()?;
+        let env = actions_env(workspace.path(), r#"{"pull_request": {"number": 42}}"#)?;
+
+        assert!(
+            GitHubChangedFilesProvider::from_vars(elsewhere.path(), |name| env.get(name).cloned())
+                .is_none()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn maps_pull_request_file_statuses() -> anyhow::Result<()> {
+        let files: Vec<PullRequestFile> = serde_json::from_str(
+            r#"[
+                {"filename": "a.rs", "status": "added"},
+                {"filename": "b.rs", "status": "removed"},
+                {"filename": "c.rs", "status": "renamed", "previous_filename": "old.rs"},
+                {"filename": "d.rs", "status": "changed"}
+            ]"#,
+        )?;
+
+        let changes: Vec<FileChange> = files.into_iter().map(to_file_change).collect();
+
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.status)
+                .collect::<Vec<_>>(),
+            vec![
+                FileStatus::Added,
+                FileStatus::Deleted,
+                FileStatus::Renamed,
+                FileStatus::Modified
+            ]
+        );
+        assert_eq!(changes[2].old_path, Some(PathBuf::from("old.rs")));
+        Ok(())
+    }
+}
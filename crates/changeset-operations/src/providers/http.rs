@@ -0,0 +1,323 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use ureq::http::{HeaderMap, Response, StatusCode};
+use ureq::{Agent, Body, Proxy};
+
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// HTTP client shared by the forge, webhook, and trace exporters.
+///
+/// Retries server errors and rate-limit responses with exponential backoff,
+/// honouring `Retry-After` and GitHub's `X-RateLimit-Reset` headers, and
+/// spaces consecutive requests at least a minimum interval apart. Requests go
+/// through the proxy named by `ALL_PROXY`, `HTTPS_PROXY`, or `HTTP_PROXY`,
+/// except for hosts listed in `NO_PROXY`.
+pub struct HttpClient {
+    agent: Agent,
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl HttpClient {
+    #[must_use]
+    pub fn new() -> Self {
+        let config = Agent::config_builder()
+            .http_status_as_error(false)
+            .proxy(Proxy::try_from_env())
+            .build();
+
+        Self {
+            agent: config.new_agent(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Sets how often a failed request is retried before giving up.
+    #[must_use]
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry, doubled on every further retry
+    /// up to `max`.
+    #[must_use]
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Sets the minimum time between the start of two requests.
+    #[must_use]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Sends a GET request and returns the response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached, or answers with a
+    /// non-success status after all retries.
+    pub fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, ureq::Error> {
+        let mut response = self.send(|agent| {
+            headers
+                .iter()
+                .fold(agent.get(url), |request, (name, value)| {
+                    request.header(*name, *value)
+                })
+                .call()
+        })?;
+        response.body_mut().read_to_string()
+    }
+
+    /// Sends a JSON body in a POST request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached, or answers with a
+    /// non-success status after all retries.
+    pub fn post_json(&self, url: &str, body: &str) -> Result<(), ureq::Error> {
+        self.post(url, &[], body)?;
+        Ok(())
+    }
+
+    /// Sends a JSON body in a POST request and returns the response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached, or answers with a
+    /// non-success status after all retries.
+    pub fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> Result<String, ureq::Error> {
+        let mut response = self.send(|agent| {
+            headers
+                .iter()
+                .fold(
+                    agent.post(url).header("Content-Type", "application/json"),
+                    |request, (name, value)| request.header(*name, *value),
+                )
+                .send(body)
+        })?;
+        response.body_mut().read_to_string()
+    }
+
+    /// Sends a DELETE request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be reached, or answers with a
+    /// non-success status after all retries.
+    pub fn delete(&self, url: &str, headers: &[(&str, &str)]) -> Result<(), ureq::Error> {
+        self.send(|agent| {
+            headers
+                .iter()
+                .fold(agent.delete(url), |request, (name, value)| {
+                    request.header(*name, *value)
+                })
+                .call()
+        })?;
+        Ok(())
+    }
+
+    fn send(
+        &self,
+        request: impl Fn(&Agent) -> Result<Response<Body>, ureq::Error>,
+    ) -> Result<Response<Body>, ureq::Error> {
+        let mut attempt = 0;
+        loop {
+            self.pace();
+            let response = request(&self.agent)?;
+            let status = response.status();
+            if status.is_success() {
+                return Ok(response);
+            }
+            if attempt >= self.max_retries || !is_retryable(status, response.headers()) {
+                return Err(ureq::Error::StatusCode(status.as_u16()));
+            }
+
+            let delay = server_delay(response.headers())
+                .unwrap_or_else(|| self.backoff(attempt))
+                .min(self.max_backoff);
+            tracing::debug!(%status, attempt, ?delay, "retrying HTTP request");
+            thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+    }
+
+    fn pace(&self) {
+        let mut last_request = self
+            .last_request
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(last) = *last_request {
+            let wait = self.min_interval.saturating_sub(last.elapsed());
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Server errors and rate limits are worth retrying. GitHub reports both its
+/// primary and secondary rate limits as `403`, marked by the rate-limit
+/// headers.
+fn is_retryable(status: StatusCode, headers: &HeaderMap) -> bool {
+    status.is_server_error()
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || (status == StatusCode::FORBIDDEN
+            && (headers.contains_key("retry-after")
+                || header(headers, "x-ratelimit-remaining") == Some(0)))
+}
+
+/// How long the server asked us to wait, if it said so.
+fn server_delay(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(seconds) = header(headers, "retry-after") {
+        return Some(Duration::from_secs(seconds));
+    }
+    if header(headers, "x-ratelimit-remaining") == Some(0) {
+        let reset = header(headers, "x-ratelimit-reset")?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        return Some(Duration::from_secs(reset.saturating_sub(now)));
+    }
+    None
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    /// Answers one connection per scripted response, in order, and returns
+    /// how many requests were served.
+    fn serve(responses: &'static [&'static str]) -> (String, thread::JoinHandle<usize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind listener");
+        let url = format!("http://{}/", listener.local_addr().expect("local addr"));
+        let server = thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().expect("accept connection");
+                let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+                let mut line = String::new();
+                while reader.read_line(&mut line).expect("read header line") > 2 {
+                    line.clear();
+                }
+                stream
+                    .write_all(response.as_bytes())
+                    .expect("write response");
+            }
+            responses.len()
+        });
+        (url, server)
+    }
+
+    fn client() -> HttpClient {
+        HttpClient::new()
+            .with_backoff(Duration::ZERO, Duration::ZERO)
+            .with_min_interval(Duration::ZERO)
+    }
+
+    const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok";
+
+    #[test]
+    fn retries_server_errors() {
+        let (url, server) = serve(&[
+            "HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            OK,
+        ]);
+
+        assert_eq!(client().get(&url, &[]).expect("response"), "ok");
+        assert_eq!(server.join().expect("server thread"), 2);
+    }
+
+    #[test]
+    fn retries_secondary_rate_limits() {
+        let (url, server) = serve(&[
+            "HTTP/1.1 403 Forbidden\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            OK,
+        ]);
+
+        assert_eq!(client().get(&url, &[]).expect("response"), "ok");
+        assert_eq!(server.join().expect("server thread"), 2);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let (url, server) = serve(&[
+            "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+
+        let error = client()
+            .with_max_retries(1)
+            .get(&url, &[])
+            .expect_err("request should fail");
+
+        assert!(matches!(error, ureq::Error::StatusCode(503)));
+        assert_eq!(server.join().expect("server thread"), 2);
+    }
+
+    #[test]
+    fn does_not_retry_client_errors() {
+        let (url, server) =
+            serve(&["HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"]);
+
+        let error = client().get(&url, &[]).expect_err("request should fail");
+
+        assert!(matches!(error, ureq::Error::StatusCode(403)));
+        assert_eq!(server.join().expect("server thread"), 1);
+    }
+
+    #[test]
+    fn waits_for_rate_limit_reset() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock after epoch")
+            .as_secs();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "0".parse().expect("header value"));
+        headers.insert(
+            "x-ratelimit-reset",
+            (now + 30).to_string().parse().expect("header value"),
+        );
+
+        assert!(is_retryable(StatusCode::FORBIDDEN, &headers));
+        let delay = server_delay(&headers).expect("delay");
+        assert!(delay <= Duration::from_secs(30) && delay >= Duration::from_secs(29));
+    }
+}
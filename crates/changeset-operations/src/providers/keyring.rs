@@ -0,0 +1,34 @@
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::TokenProvider;
+
+/// Reads a token from the macOS Keychain, the Windows Credential Manager, or
+/// the Linux kernel keyring.
+pub struct KeyringTokenProvider {
+    service: String,
+    user: String,
+}
+
+impl KeyringTokenProvider {
+    #[must_use]
+    pub fn new(service: impl Into<String>, user: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+            user: user.into(),
+        }
+    }
+}
+
+impl TokenProvider for KeyringTokenProvider {
+    fn token(&self) -> Result<Option<String>> {
+        match keyring::Entry::new(&self.service, &self.user).and_then(|entry| entry.get_password())
+        {
+            Ok(token) => Ok(Some(token)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(source) => Err(OperationError::KeyringAccess {
+                service: self.service.clone(),
+                source,
+            }),
+        }
+    }
+}
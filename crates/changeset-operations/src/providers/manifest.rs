@@ -1,18 +1,62 @@
 use std::path::Path;
 
-use changeset_manifest::{InitConfig, MetadataSection};
+use changeset_manifest::{
+    DeprecatedKey, ExistingMetadata, InitConfig, ManifestError, MetadataSection,
+};
+use changeset_project::{PackageAdapter, PackageAdapters};
 use semver::Version;
 
 use crate::Result;
 use crate::traits::{InheritedVersionChecker, ManifestWriter};
 
-pub struct FileSystemManifestWriter;
+pub struct FileSystemManifestWriter {
+    adapters: PackageAdapters,
+}
 
 impl FileSystemManifestWriter {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            adapters: PackageAdapters::default(),
+        }
+    }
+
+    /// Replaces the adapters that write versions to manifests other than
+    /// `Cargo.toml`.
+    #[must_use]
+    pub fn with_adapters(mut self, adapters: PackageAdapters) -> Self {
+        self.adapters = adapters;
+        self
+    }
+
+    fn adapter(&self, manifest_path: &Path) -> Option<&dyn PackageAdapter> {
+        non_cargo_adapter(&self.adapters, manifest_path)
+    }
+}
+
+/// The adapter for a manifest that is not a `Cargo.toml`; those are always
+/// edited in place with `changeset_manifest`.
+pub(crate) fn non_cargo_adapter<'a>(
+    adapters: &'a PackageAdapters,
+    manifest_path: &Path,
+) -> Option<&'a dyn PackageAdapter> {
+    if manifest_path
+        .file_name()
+        .is_some_and(|name| name == "Cargo.toml")
+    {
+        return None;
     }
+    adapters.for_manifest(manifest_path)
+}
+
+fn read(manifest_path: &Path) -> Result<String> {
+    std::fs::read_to_string(manifest_path).map_err(|source| {
+        ManifestError::Read {
+            path: manifest_path.to_path_buf(),
+            source,
+        }
+        .into()
+    })
 }
 
 impl Default for FileSystemManifestWriter {
@@ -29,6 +73,17 @@ impl InheritedVersionChecker for FileSystemManifestWriter {
 
 impl ManifestWriter for FileSystemManifestWriter {
     fn write_version(&self, manifest_path: &Path, new_version: &Version) -> Result<()> {
+        if let Some(adapter) = self.adapter(manifest_path) {
+            let content =
+                adapter.write_version(manifest_path, &read(manifest_path)?, new_version)?;
+            return changeset_core::atomic_write(manifest_path, content).map_err(|source| {
+                ManifestError::Write {
+                    path: manifest_path.to_path_buf(),
+                    source,
+                }
+                .into()
+            });
+        }
         Ok(changeset_manifest::write_version(
             manifest_path,
             new_version,
@@ -55,6 +110,20 @@ impl ManifestWriter for FileSystemManifestWriter {
     }
 
     fn verify_version(&self, manifest_path: &Path, expected: &Version) -> Result<()> {
+        if let Some(adapter) = self.adapter(manifest_path) {
+            let actual = adapter
+                .read_package(manifest_path, &read(manifest_path)?)?
+                .map(|(_, version)| version.to_string());
+            if actual.as_deref() != Some(expected.to_string().as_str()) {
+                return Err(ManifestError::VerificationFailed {
+                    path: manifest_path.to_path_buf(),
+                    expected: expected.to_string(),
+                    actual: actual.unwrap_or_default(),
+                }
+                .into());
+            }
+            return Ok(());
+        }
         Ok(changeset_manifest::verify_version(manifest_path, expected)?)
     }
 
@@ -71,16 +140,47 @@ impl ManifestWriter for FileSystemManifestWriter {
         )?)
     }
 
+    fn read_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<ExistingMetadata> {
+        Ok(changeset_manifest::read_metadata_section(
+            manifest_path,
+            section,
+        )?)
+    }
+
+    fn upgrade_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<Vec<DeprecatedKey>> {
+        Ok(changeset_manifest::upgrade_metadata_section(
+            manifest_path,
+            section,
+        )?)
+    }
+
+    fn add_workspace_member(&self, manifest_path: &Path, member: &str) -> Result<bool> {
+        Ok(changeset_manifest::add_workspace_member(
+            manifest_path,
+            member,
+        )?)
+    }
+
     fn update_dependency_version(
         &self,
         manifest_path: &Path,
         dependency_name: &str,
         new_version: &Version,
+        skip_optional: bool,
     ) -> Result<bool> {
         Ok(changeset_manifest::update_dependency_version(
             manifest_path,
             dependency_name,
             new_version,
+            skip_optional,
         )?)
     }
 }
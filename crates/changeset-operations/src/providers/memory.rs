@@ -0,0 +1,1791 @@
+//! In-memory providers for running operations without touching disk or a real
+//! git repository.
+//!
+//! Useful for hermetic integration tests and for library consumers that want to
+//! preview a release. Every provider records what it was asked to do and
+//! offers builders to seed state and inject failures. Each one also implements its
+//! traits for `Arc<Self>` so the same instance can be shared with an operation
+//! and inspected afterwards.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+
+use changeset_changelog::{ArchivePolicy, RepositoryInfo, VersionRelease};
+use changeset_core::{Changeset, PackageInfo};
+use changeset_git::{CommitIdentity, CommitInfo, FileChange, Identity, TagInfo};
+use changeset_manifest::{DeprecatedKey, ExistingMetadata, InitConfig, MetadataSection};
+use changeset_project::{
+    CHANGESETS_SUBDIR, CargoProject, GraduationState, OwnedChangesetDir, PackageChangesetConfig,
+    PrereleaseState, ProjectKind, RootChangesetConfig,
+};
+use chrono::{DateTime, Utc};
+use semver::Version;
+
+use crate::Result;
+use crate::traits::{
+    ChangedFilesProvider, ChangelogArchive, ChangelogWriteResult, ChangelogWriter, ChangesetReader,
+    ChangesetWriter, GitProvider, InheritedVersionChecker, ManifestWriter, OutdatedStateFile,
+    PlannedChangelogWrite, ProjectProvider, ReleaseStateIO, TextFileIO,
+};
+
+pub struct InMemoryProjectProvider {
+    project: CargoProject,
+    changeset_dir: PathBuf,
+    root_config: RootChangesetConfig,
+    package_configs: HashMap<String, PackageChangesetConfig>,
+}
+
+impl InMemoryProjectProvider {
+    #[must_use]
+    pub fn new(project: CargoProject) -> Self {
+        let changeset_dir = project.root.join(".changeset");
+        Self {
+            project,
+            changeset_dir,
+            root_config: RootChangesetConfig::default(),
+            package_configs: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_changeset_dir(mut self, dir: PathBuf) -> Self {
+        if let Some(parent) = dir.parent() {
+            self.project.root = parent.to_path_buf();
+        }
+        self.changeset_dir = dir;
+        self
+    }
+
+    #[must_use]
+    pub fn with_project_root(mut self, root: PathBuf) -> Self {
+        self.project.root = root;
+        self
+    }
+
+    #[must_use]
+    pub fn with_root_config(mut self, config: RootChangesetConfig) -> Self {
+        self.root_config = config;
+        self
+    }
+
+    #[must_use]
+    pub fn with_package_config(mut self, name: &str, config: PackageChangesetConfig) -> Self {
+        self.package_configs.insert(name.to_string(), config);
+        self
+    }
+
+    /// A single-package project rooted at `/mock/project`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the version string is not valid semver.
+    #[must_use]
+    pub fn single_package(name: &str, version: &str) -> Self {
+        let root = PathBuf::from("/mock/project");
+        let project = CargoProject {
+            root: root.clone(),
+            kind: ProjectKind::SinglePackage,
+            packages: vec![PackageInfo {
+                name: name.to_string(),
+                version: version.parse().expect("valid version"),
+                path: root.clone(),
+            }],
+            missing_members: Vec::new(),
+            package_manifests: BTreeMap::new(),
+        };
+        Self::new(project)
+    }
+
+    /// A virtual workspace rooted at `/mock/workspace` with members under `crates/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any version string is not valid semver.
+    #[must_use]
+    pub fn workspace(packages: Vec<(&str, &str)>) -> Self {
+        let root = PathBuf::from("/mock/workspace");
+        let pkg_infos: Vec<PackageInfo> = packages
+            .into_iter()
+            .map(|(name, version)| PackageInfo {
+                name: name.to_string(),
+                version: version.parse().expect("valid version"),
+                path: root.join("crates").join(name),
+            })
+            .collect();
+
+        let project = CargoProject {
+            root,
+            kind: ProjectKind::VirtualWorkspace,
+            packages: pkg_infos,
+            missing_members: Vec::new(),
+            package_manifests: BTreeMap::new(),
+        };
+        Self::new(project)
+    }
+}
+
+impl ProjectProvider for InMemoryProjectProvider {
+    fn discover_project(&self, _start_path: &Path) -> Result<CargoProject> {
+        Ok(self.project.clone())
+    }
+
+    fn load_configs(
+        &self,
+        _project: &CargoProject,
+    ) -> Result<(RootChangesetConfig, HashMap<String, PackageChangesetConfig>)> {
+        Ok((self.root_config.clone(), self.package_configs.clone()))
+    }
+
+    fn ensure_changeset_dir(
+        &self,
+        _project: &CargoProject,
+        _config: &RootChangesetConfig,
+    ) -> Result<PathBuf> {
+        Ok(self.changeset_dir.clone())
+    }
+
+    fn ensure_owned_changeset_dir(
+        &self,
+        _project: &CargoProject,
+        dir: &OwnedChangesetDir,
+    ) -> Result<PathBuf> {
+        Ok(dir.path().to_path_buf())
+    }
+}
+
+pub struct InMemoryChangesetIO {
+    changesets: Arc<Mutex<HashMap<PathBuf, Changeset>>>,
+    listed_files: Mutex<Vec<PathBuf>>,
+}
+
+impl InMemoryChangesetIO {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            changesets: Arc::new(Mutex::new(HashMap::new())),
+            listed_files: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn with_changeset(self, path: PathBuf, changeset: Changeset) -> Self {
+        self.listed_files
+            .lock()
+            .expect("lock poisoned")
+            .push(path.clone());
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .insert(path, changeset);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn with_changesets(self, changesets: Vec<(PathBuf, Changeset)>) -> Self {
+        {
+            let mut locked = self.changesets.lock().expect("lock poisoned");
+            let mut listed = self.listed_files.lock().expect("lock poisoned");
+            for (path, changeset) in changesets {
+                listed.push(path.clone());
+                locked.insert(path, changeset);
+            }
+        }
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn with_consumed_changeset(
+        self,
+        path: PathBuf,
+        mut changeset: Changeset,
+        version: String,
+    ) -> Self {
+        changeset.consumed_for_prerelease = Some(version);
+        self.listed_files
+            .lock()
+            .expect("lock poisoned")
+            .push(path.clone());
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .insert(path, changeset);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn get_consumed_status(&self, path: &Path) -> Option<String> {
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .get(path)
+            .and_then(|c| c.consumed_for_prerelease.clone())
+    }
+
+    /// Returns a stored changeset by path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn changeset(&self, path: &Path) -> Option<Changeset> {
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .get(path)
+            .cloned()
+    }
+}
+
+impl Default for InMemoryChangesetIO {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChangesetReader for InMemoryChangesetIO {
+    fn read_changeset(&self, path: &Path) -> Result<Changeset> {
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .get(path)
+            .cloned()
+            .ok_or_else(|| crate::OperationError::ChangesetFileRead {
+                path: path.to_path_buf(),
+                source: std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "changeset not stored in memory",
+                ),
+            })
+    }
+
+    fn list_changesets(&self, _changeset_dir: &Path) -> Result<Vec<PathBuf>> {
+        let changesets = self.changesets.lock().expect("lock poisoned");
+        Ok(self
+            .listed_files
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .filter(|p| {
+                changesets
+                    .get(*p)
+                    .is_some_and(|c| c.consumed_for_prerelease.is_none())
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn list_consumed_changesets(&self, _changeset_dir: &Path) -> Result<Vec<PathBuf>> {
+        let changesets = self.changesets.lock().expect("lock poisoned");
+        Ok(self
+            .listed_files
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .filter(|p| {
+                changesets
+                    .get(*p)
+                    .is_some_and(|c| c.consumed_for_prerelease.is_some())
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+impl ChangesetWriter for InMemoryChangesetIO {
+    fn write_changeset(&self, changeset_dir: &Path, changeset: &Changeset) -> Result<String> {
+        let mut listed = self.listed_files.lock().expect("lock poisoned");
+        let filename = format!("changeset-{}.md", listed.len() + 1);
+        let path = changeset_dir.join(CHANGESETS_SUBDIR).join(&filename);
+
+        listed.push(path.clone());
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .insert(path, changeset.clone());
+        Ok(filename)
+    }
+
+    fn restore_changeset(&self, path: &Path, changeset: &Changeset) -> Result<()> {
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .insert(path.to_path_buf(), changeset.clone());
+        Ok(())
+    }
+
+    fn delete_changeset(&self, path: &Path) -> Result<()> {
+        self.listed_files
+            .lock()
+            .expect("lock poisoned")
+            .retain(|listed| listed != path);
+        self.changesets.lock().expect("lock poisoned").remove(path);
+        Ok(())
+    }
+
+    fn filename_exists(&self, changeset_dir: &Path, filename: &str) -> bool {
+        let path = changeset_dir.join(CHANGESETS_SUBDIR).join(filename);
+        self.changesets
+            .lock()
+            .expect("lock poisoned")
+            .contains_key(&path)
+    }
+
+    fn mark_consumed_for_prerelease(
+        &self,
+        _changeset_dir: &Path,
+        paths: &[&Path],
+        version: &Version,
+    ) -> Result<()> {
+        let mut changesets = self.changesets.lock().expect("lock poisoned");
+        for path in paths {
+            if let Some(changeset) = changesets.get_mut(*path) {
+                changeset.consumed_for_prerelease = Some(version.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn clear_consumed_for_prerelease(&self, _changeset_dir: &Path, paths: &[&Path]) -> Result<()> {
+        let mut changesets = self.changesets.lock().expect("lock poisoned");
+        for path in paths {
+            if let Some(changeset) = changesets.get_mut(*path) {
+                changeset.consumed_for_prerelease = None;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct InMemoryGitProvider {
+    changed_files: Vec<FileChange>,
+    staged_changes: Vec<FileChange>,
+    hooks_dir: PathBuf,
+    root: PathBuf,
+    config: Mutex<Vec<(String, String)>>,
+    clean: bool,
+    dirty_files: Vec<PathBuf>,
+    up_to_date: bool,
+    existing_tags: Vec<String>,
+    branch: String,
+    remote_url: Option<String>,
+    remotes: HashMap<String, String>,
+    staged_files: Mutex<Vec<PathBuf>>,
+    commits: Mutex<Vec<String>>,
+    commit_identities: Mutex<Vec<CommitIdentity>>,
+    tags_created: Mutex<Vec<(String, String)>>,
+    deleted_files: Mutex<Vec<PathBuf>>,
+    deleted_tags: Mutex<Vec<String>>,
+    reset_count: Mutex<usize>,
+    fail_on_commit: Mutex<bool>,
+    fail_on_commit_nth: Mutex<Option<usize>>,
+    fail_on_create_tag: Mutex<bool>,
+    fail_on_create_tag_nth: Mutex<Option<usize>>,
+    fail_on_stage_files: Mutex<bool>,
+    stashes: Mutex<Vec<String>>,
+    stash_pops: Mutex<usize>,
+    head_tags: Vec<String>,
+    head_pushed: bool,
+    remote_tags: Vec<String>,
+    file_added_dates: HashMap<PathBuf, DateTime<Utc>>,
+    release_dates: Vec<DateTime<Utc>>,
+    files_at_ref: HashMap<(String, PathBuf), String>,
+    commit_log: Vec<(String, Vec<PathBuf>)>,
+    deleted_history: Vec<(PathBuf, String)>,
+    amend_count: Mutex<usize>,
+    fail_on_amend: Mutex<bool>,
+    tags_moved: Mutex<Vec<String>>,
+    moved_tag_messages: Mutex<Vec<Option<String>>>,
+    fail_on_move_tag_nth: Mutex<Option<usize>>,
+    head_sha: Mutex<String>,
+    ignored_paths: Vec<PathBuf>,
+}
+
+impl InMemoryGitProvider {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            changed_files: Vec::new(),
+            staged_changes: Vec::new(),
+            hooks_dir: PathBuf::from("/mock/project/.git/hooks"),
+            root: PathBuf::from("/mock/project"),
+            config: Mutex::new(Vec::new()),
+            clean: true,
+            dirty_files: Vec::new(),
+            up_to_date: true,
+            existing_tags: Vec::new(),
+            branch: "main".to_string(),
+            remote_url: None,
+            remotes: HashMap::new(),
+            staged_files: Mutex::new(Vec::new()),
+            commits: Mutex::new(Vec::new()),
+            commit_identities: Mutex::new(Vec::new()),
+            tags_created: Mutex::new(Vec::new()),
+            deleted_files: Mutex::new(Vec::new()),
+            deleted_tags: Mutex::new(Vec::new()),
+            reset_count: Mutex::new(0),
+            fail_on_commit: Mutex::new(false),
+            fail_on_commit_nth: Mutex::new(None),
+            fail_on_create_tag: Mutex::new(false),
+            fail_on_create_tag_nth: Mutex::new(None),
+            fail_on_stage_files: Mutex::new(false),
+            stashes: Mutex::new(Vec::new()),
+            stash_pops: Mutex::new(0),
+            head_tags: Vec::new(),
+            head_pushed: false,
+            remote_tags: Vec::new(),
+            file_added_dates: HashMap::new(),
+            release_dates: Vec::new(),
+            files_at_ref: HashMap::new(),
+            commit_log: Vec::new(),
+            deleted_history: Vec::new(),
+            amend_count: Mutex::new(0),
+            fail_on_amend: Mutex::new(false),
+            tags_moved: Mutex::new(Vec::new()),
+            moved_tag_messages: Mutex::new(Vec::new()),
+            fail_on_move_tag_nth: Mutex::new(None),
+            head_sha: Mutex::new("abc123def456".to_string()),
+            ignored_paths: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_changed_files(mut self, files: Vec<FileChange>) -> Self {
+        self.changed_files = files;
+        self
+    }
+
+    #[must_use]
+    pub fn with_staged_changes(mut self, files: Vec<FileChange>) -> Self {
+        self.staged_changes = files;
+        self
+    }
+
+    #[must_use]
+    pub fn with_hooks_dir(mut self, dir: PathBuf) -> Self {
+        self.hooks_dir = dir;
+        self
+    }
+
+    #[must_use]
+    pub fn with_root(mut self, root: PathBuf) -> Self {
+        self.root = root;
+        self
+    }
+
+    #[must_use]
+    pub fn with_branch(mut self, branch: &str) -> Self {
+        self.branch = branch.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn is_clean(mut self, clean: bool) -> Self {
+        self.clean = clean;
+        self
+    }
+
+    /// Marks the working tree dirty with the given files.
+    #[must_use]
+    pub fn with_dirty_files(mut self, files: Vec<PathBuf>) -> Self {
+        self.clean = files.is_empty();
+        self.dirty_files = files;
+        self
+    }
+
+    /// Treats `paths` and everything below them as ignored by git.
+    #[must_use]
+    pub fn with_ignored_paths(mut self, paths: Vec<PathBuf>) -> Self {
+        self.ignored_paths = paths;
+        self
+    }
+
+    #[must_use]
+    pub fn with_up_to_date(mut self, up_to_date: bool) -> Self {
+        self.up_to_date = up_to_date;
+        self
+    }
+
+    #[must_use]
+    pub fn with_existing_tags(mut self, tags: &[&str]) -> Self {
+        self.existing_tags = tags.iter().map(|t| (*t).to_string()).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn with_remote_url(mut self, url: &str) -> Self {
+        self.remote_url = Some(url.to_string());
+        self
+    }
+
+    /// Adds a remote that is only returned when asked for by name.
+    #[must_use]
+    pub fn with_named_remote(mut self, name: &str, url: &str) -> Self {
+        self.remotes.insert(name.to_string(), url.to_string());
+        self
+    }
+
+    /// Sets the tags pointing at HEAD.
+    #[must_use]
+    pub fn with_head_tags(mut self, tags: &[&str]) -> Self {
+        self.head_tags = tags.iter().map(|t| (*t).to_string()).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn with_head_pushed(mut self, pushed: bool) -> Self {
+        self.head_pushed = pushed;
+        self
+    }
+
+    /// Sets the tags that exist on the remote.
+    #[must_use]
+    pub fn with_remote_tags(mut self, tags: &[&str]) -> Self {
+        self.remote_tags = tags.iter().map(|t| (*t).to_string()).collect();
+        self
+    }
+
+    /// Records when `path` was committed; other files count as uncommitted.
+    #[must_use]
+    pub fn with_file_added_date(mut self, path: impl Into<PathBuf>, date: DateTime<Utc>) -> Self {
+        self.file_added_dates.insert(path.into(), date);
+        self
+    }
+
+    #[must_use]
+    pub fn with_release_dates(mut self, dates: Vec<DateTime<Utc>>) -> Self {
+        self.release_dates = dates;
+        self
+    }
+
+    /// Records the contents of `path` at `reference`; other files are reported
+    /// as missing at every revision.
+    #[must_use]
+    pub fn with_file_at_ref(
+        mut self,
+        reference: impl Into<String>,
+        path: impl Into<PathBuf>,
+        content: impl Into<String>,
+    ) -> Self {
+        self.files_at_ref
+            .insert((reference.into(), path.into()), content.into());
+        self
+    }
+
+    /// Adds a file to the ones `deleted_file_contents` lists, newest last. Every
+    /// range contains all added files.
+    #[must_use]
+    pub fn with_deleted_file(
+        mut self,
+        path: impl Into<PathBuf>,
+        content: impl Into<String>,
+    ) -> Self {
+        self.deleted_history.push((path.into(), content.into()));
+        self
+    }
+
+    /// Adds a commit to the history listed by `commit_messages`, newest last.
+    /// Every range contains all added commits.
+    #[must_use]
+    pub fn with_commit(mut self, message: impl Into<String>, paths: Vec<PathBuf>) -> Self {
+        self.commit_log.push((message.into(), paths));
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn staged_files(&self) -> Vec<PathBuf> {
+        self.staged_files.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn commits(&self) -> Vec<String> {
+        self.commits.lock().expect("lock poisoned").clone()
+    }
+
+    /// Identities passed to each commit and amend, in call order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn commit_identities(&self) -> Vec<CommitIdentity> {
+        self.commit_identities
+            .lock()
+            .expect("lock poisoned")
+            .clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn tags_created(&self) -> Vec<(String, String)> {
+        self.tags_created.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn deleted_files(&self) -> Vec<PathBuf> {
+        self.deleted_files.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn deleted_tags(&self) -> Vec<String> {
+        self.deleted_tags.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn reset_count(&self) -> usize {
+        *self.reset_count.lock().expect("lock poisoned")
+    }
+
+    /// Messages of the stashes pushed so far, oldest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn stashes(&self) -> Vec<String> {
+        self.stashes.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn stash_pop_count(&self) -> usize {
+        *self.stash_pops.lock().expect("lock poisoned")
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn amend_count(&self) -> usize {
+        *self.amend_count.lock().expect("lock poisoned")
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn tags_moved(&self) -> Vec<String> {
+        self.tags_moved.lock().expect("lock poisoned").clone()
+    }
+
+    /// The message given to each `move_tag` call, in call order; `None` kept
+    /// the tag's message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn moved_tag_messages(&self) -> Vec<Option<String>> {
+        self.moved_tag_messages
+            .lock()
+            .expect("lock poisoned")
+            .clone()
+    }
+
+    /// Configuration values set so far, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn config_values(&self) -> Vec<(String, String)> {
+        self.config.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_amend(&self, fail: bool) {
+        *self.fail_on_amend.lock().expect("lock poisoned") = fail;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_commit(&self, fail: bool) {
+        *self.fail_on_commit.lock().expect("lock poisoned") = fail;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_create_tag(&self, fail: bool) {
+        *self.fail_on_create_tag.lock().expect("lock poisoned") = fail;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_commit_nth(&self, n: usize) {
+        *self.fail_on_commit_nth.lock().expect("lock poisoned") = Some(n);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_create_tag_nth(&self, n: usize) {
+        *self.fail_on_create_tag_nth.lock().expect("lock poisoned") = Some(n);
+    }
+
+    /// Makes the `n`th call to `move_tag` fail once, counting from zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_move_tag_nth(&self, n: usize) {
+        *self.fail_on_move_tag_nth.lock().expect("lock poisoned") = Some(n);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn set_fail_on_stage_files(&self, fail: bool) {
+        *self.fail_on_stage_files.lock().expect("lock poisoned") = fail;
+    }
+}
+
+impl Default for InMemoryGitProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GitProvider for InMemoryGitProvider {
+    fn changed_files(
+        &self,
+        _project_root: &Path,
+        _base: &str,
+        _head: &str,
+    ) -> Result<Vec<FileChange>> {
+        Ok(self.changed_files.clone())
+    }
+
+    fn staged_changes(&self, _project_root: &Path) -> Result<Vec<FileChange>> {
+        Ok(self.staged_changes.clone())
+    }
+
+    fn hooks_dir(&self, _project_root: &Path) -> Result<PathBuf> {
+        Ok(self.hooks_dir.clone())
+    }
+
+    fn repository_root(&self, _project_root: &Path) -> Result<PathBuf> {
+        Ok(self.root.clone())
+    }
+
+    fn set_config(&self, _project_root: &Path, key: &str, value: &str) -> Result<()> {
+        self.config
+            .lock()
+            .expect("lock poisoned")
+            .push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+
+    fn is_working_tree_clean(
+        &self,
+        _project_root: &Path,
+        _ignore_submodules: bool,
+    ) -> Result<bool> {
+        Ok(self.clean)
+    }
+
+    fn dirty_files(&self, _project_root: &Path, _ignore_submodules: bool) -> Result<Vec<PathBuf>> {
+        Ok(self.dirty_files.clone())
+    }
+
+    fn stash_push(&self, _project_root: &Path, message: &str) -> Result<bool> {
+        if self.clean {
+            return Ok(false);
+        }
+        self.stashes
+            .lock()
+            .expect("lock poisoned")
+            .push(message.to_string());
+        Ok(true)
+    }
+
+    fn stash_pop(&self, _project_root: &Path) -> Result<()> {
+        *self.stash_pops.lock().expect("lock poisoned") += 1;
+        Ok(())
+    }
+
+    fn current_branch(&self, _project_root: &Path) -> Result<String> {
+        Ok(self.branch.clone())
+    }
+
+    fn stage_files(&self, _project_root: &Path, paths: &[&Path]) -> Result<()> {
+        if *self.fail_on_stage_files.lock().expect("lock poisoned") {
+            return Err(crate::OperationError::Io(std::io::Error::other(
+                "injected stage files failure",
+            )));
+        }
+        self.staged_files
+            .lock()
+            .expect("lock poisoned")
+            .extend(paths.iter().map(|p| p.to_path_buf()));
+        Ok(())
+    }
+
+    fn ignored_paths(&self, _project_root: &Path, paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        Ok(paths
+            .iter()
+            .filter(|path| {
+                self.ignored_paths
+                    .iter()
+                    .any(|ignored| path.starts_with(ignored))
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn commit(
+        &self,
+        _project_root: &Path,
+        message: &str,
+        identity: &CommitIdentity,
+    ) -> Result<CommitInfo> {
+        if *self.fail_on_commit.lock().expect("lock poisoned") {
+            return Err(crate::OperationError::Io(std::io::Error::other(
+                "injected commit failure",
+            )));
+        }
+
+        let mut commits = self.commits.lock().expect("lock poisoned");
+        if *self.fail_on_commit_nth.lock().expect("lock poisoned") == Some(commits.len()) {
+            return Err(crate::OperationError::Io(std::io::Error::other(
+                "injected commit failure (nth)",
+            )));
+        }
+        // Later commits get distinct hashes so tags can be told apart.
+        let sha = format!("abc123def{:03x}", 0x456 + commits.len());
+        commits.push(message.to_string());
+        self.commit_identities
+            .lock()
+            .expect("lock poisoned")
+            .push(identity.clone());
+        Ok(CommitInfo {
+            sha,
+            message: message.to_string(),
+        })
+    }
+
+    fn create_tag(&self, project_root: &Path, tag_name: &str, message: &str) -> Result<TagInfo> {
+        self.create_tag_at(project_root, tag_name, message, "abc123def456")
+    }
+
+    fn create_tag_at(
+        &self,
+        _project_root: &Path,
+        tag_name: &str,
+        message: &str,
+        target_sha: &str,
+    ) -> Result<TagInfo> {
+        if *self.fail_on_create_tag.lock().expect("lock poisoned") {
+            return Err(crate::OperationError::Io(std::io::Error::other(
+                "injected create tag failure",
+            )));
+        }
+
+        let current_count = self.tags_created.lock().expect("lock poisoned").len();
+        if let Some(n) = *self.fail_on_create_tag_nth.lock().expect("lock poisoned") {
+            if current_count == n {
+                return Err(crate::OperationError::Io(std::io::Error::other(
+                    "injected create tag failure (nth)",
+                )));
+            }
+        }
+
+        self.tags_created
+            .lock()
+            .expect("lock poisoned")
+            .push((tag_name.to_string(), message.to_string()));
+        Ok(TagInfo {
+            name: tag_name.to_string(),
+            target_sha: target_sha.to_string(),
+        })
+    }
+
+    fn is_up_to_date(&self, _project_root: &Path) -> Result<bool> {
+        Ok(self.up_to_date)
+    }
+
+    fn list_tags(&self, _project_root: &Path) -> Result<Vec<String>> {
+        Ok(self.existing_tags.clone())
+    }
+
+    fn tags_at_head(&self, _project_root: &Path) -> Result<Vec<String>> {
+        Ok(self.head_tags.clone())
+    }
+
+    fn file_added_date(&self, _project_root: &Path, path: &Path) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.file_added_dates.get(path).copied())
+    }
+
+    fn release_dates(&self, _project_root: &Path) -> Result<Vec<DateTime<Utc>>> {
+        Ok(self.release_dates.clone())
+    }
+
+    fn file_at_ref(
+        &self,
+        _project_root: &Path,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Option<String>> {
+        Ok(self
+            .files_at_ref
+            .get(&(reference.to_string(), path.to_path_buf()))
+            .cloned())
+    }
+
+    fn commit_messages(
+        &self,
+        _project_root: &Path,
+        _base: &str,
+        _head: &str,
+        path: Option<&Path>,
+    ) -> Result<Vec<String>> {
+        Ok(self
+            .commit_log
+            .iter()
+            .rev()
+            .filter(|(_, paths)| {
+                path.is_none_or(|path| paths.iter().any(|changed| changed.starts_with(path)))
+            })
+            .map(|(message, _)| message.clone())
+            .collect())
+    }
+
+    fn deleted_file_contents(
+        &self,
+        _project_root: &Path,
+        _base: Option<&str>,
+        _head: &str,
+        path: &Path,
+    ) -> Result<Vec<(PathBuf, String)>> {
+        Ok(self
+            .deleted_history
+            .iter()
+            .rev()
+            .filter(|(deleted, _)| deleted.starts_with(path))
+            .cloned()
+            .collect())
+    }
+
+    fn head_sha(&self, _project_root: &Path) -> Result<String> {
+        Ok(self.head_sha.lock().expect("lock poisoned").clone())
+    }
+
+    fn is_head_pushed(&self, _project_root: &Path, tags: &[String]) -> Result<bool> {
+        Ok(self.head_pushed || tags.iter().any(|tag| self.remote_tags.contains(tag)))
+    }
+
+    fn amend_commit(
+        &self,
+        _project_root: &Path,
+        committer: Option<&Identity>,
+    ) -> Result<CommitInfo> {
+        if *self.fail_on_amend.lock().expect("lock poisoned") {
+            return Err(crate::OperationError::Io(std::io::Error::other(
+                "injected amend failure",
+            )));
+        }
+        self.commit_identities
+            .lock()
+            .expect("lock poisoned")
+            .push(CommitIdentity {
+                author: None,
+                committer: committer.cloned(),
+            });
+        *self.amend_count.lock().expect("lock poisoned") += 1;
+        "fed654cba321".clone_into(&mut self.head_sha.lock().expect("lock poisoned"));
+        Ok(CommitInfo {
+            sha: "fed654cba321".to_string(),
+            message: "amended release".to_string(),
+        })
+    }
+
+    fn move_tag(
+        &self,
+        _project_root: &Path,
+        tag_name: &str,
+        message: Option<&str>,
+    ) -> Result<TagInfo> {
+        let mut tags_moved = self.tags_moved.lock().expect("lock poisoned");
+        let mut fail_nth = self.fail_on_move_tag_nth.lock().expect("lock poisoned");
+        if *fail_nth == Some(tags_moved.len()) {
+            *fail_nth = None;
+            return Err(crate::OperationError::Io(std::io::Error::other(
+                "injected move tag failure (nth)",
+            )));
+        }
+        tags_moved.push(tag_name.to_string());
+        self.moved_tag_messages
+            .lock()
+            .expect("lock poisoned")
+            .push(message.map(str::to_string));
+        Ok(TagInfo {
+            name: tag_name.to_string(),
+            target_sha: self.head_sha.lock().expect("lock poisoned").clone(),
+        })
+    }
+
+    fn remote_url(&self, _project_root: &Path, remote: Option<&str>) -> Result<Option<String>> {
+        match remote {
+            Some(name) => Ok(self.remotes.get(name).cloned()),
+            None => Ok(self.remote_url.clone()),
+        }
+    }
+
+    fn delete_files(&self, _project_root: &Path, paths: &[&Path]) -> Result<()> {
+        self.deleted_files
+            .lock()
+            .expect("lock poisoned")
+            .extend(paths.iter().map(|p| p.to_path_buf()));
+        Ok(())
+    }
+
+    fn delete_tag(&self, _project_root: &Path, tag_name: &str) -> Result<bool> {
+        self.deleted_tags
+            .lock()
+            .expect("lock poisoned")
+            .push(tag_name.to_string());
+        Ok(true)
+    }
+
+    fn reset_to_parent(&self, _project_root: &Path) -> Result<()> {
+        *self.reset_count.lock().expect("lock poisoned") += 1;
+        Ok(())
+    }
+
+    fn reset_soft_to(&self, _project_root: &Path, sha: &str) -> Result<()> {
+        sha.clone_into(&mut self.head_sha.lock().expect("lock poisoned"));
+        Ok(())
+    }
+}
+
+impl ChangedFilesProvider for InMemoryGitProvider {
+    fn changed_files(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<FileChange>> {
+        GitProvider::changed_files(self, project_root, base, head)
+    }
+}
+
+pub struct InMemoryManifestWriter {
+    written_versions: Mutex<Vec<(PathBuf, Version)>>,
+    dependency_version_updates: Mutex<Vec<(PathBuf, String, Version)>>,
+    dependency_update_returns_true: Mutex<bool>,
+    inherited_paths: HashSet<PathBuf>,
+    removed_workspace_version: Mutex<bool>,
+    workspace_version: Mutex<Option<Version>>,
+    written_metadata: Mutex<Vec<(PathBuf, MetadataSection, InitConfig)>>,
+    existing_metadata: ExistingMetadata,
+    upgraded_metadata: Mutex<Vec<(PathBuf, MetadataSection)>>,
+    workspace_members: Mutex<Vec<(PathBuf, String)>>,
+}
+
+impl InMemoryManifestWriter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            written_versions: Mutex::new(Vec::new()),
+            dependency_version_updates: Mutex::new(Vec::new()),
+            dependency_update_returns_true: Mutex::new(false),
+            inherited_paths: HashSet::new(),
+            removed_workspace_version: Mutex::new(false),
+            workspace_version: Mutex::new(None),
+            written_metadata: Mutex::new(Vec::new()),
+            existing_metadata: ExistingMetadata::default(),
+            upgraded_metadata: Mutex::new(Vec::new()),
+            workspace_members: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn with_inherited(mut self, paths: Vec<PathBuf>) -> Self {
+        self.inherited_paths = paths.into_iter().collect();
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn with_dependency_updates_returning_true(self) -> Self {
+        *self
+            .dependency_update_returns_true
+            .lock()
+            .expect("lock poisoned") = true;
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn dependency_version_updates(&self) -> Vec<(PathBuf, String, Version)> {
+        self.dependency_version_updates
+            .lock()
+            .expect("lock poisoned")
+            .clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn with_workspace_version(self, version: Version) -> Self {
+        *self.workspace_version.lock().expect("lock poisoned") = Some(version);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn written_versions(&self) -> Vec<(PathBuf, Version)> {
+        self.written_versions.lock().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn workspace_version_removed(&self) -> bool {
+        *self
+            .removed_workspace_version
+            .lock()
+            .expect("lock poisoned")
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn get_workspace_version(&self) -> Option<Version> {
+        self.workspace_version
+            .lock()
+            .expect("lock poisoned")
+            .clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn written_metadata(&self) -> Vec<(PathBuf, MetadataSection, InitConfig)> {
+        self.written_metadata.lock().expect("lock poisoned").clone()
+    }
+
+    /// Seeds the configuration returned by `read_metadata`.
+    #[must_use]
+    pub fn with_existing_metadata(mut self, existing: ExistingMetadata) -> Self {
+        self.existing_metadata = existing;
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn upgraded_metadata(&self) -> Vec<(PathBuf, MetadataSection)> {
+        self.upgraded_metadata
+            .lock()
+            .expect("lock poisoned")
+            .clone()
+    }
+
+    /// Members added through `add_workspace_member`, with the manifest each
+    /// was added to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn added_workspace_members(&self) -> Vec<(PathBuf, String)> {
+        self.workspace_members
+            .lock()
+            .expect("lock poisoned")
+            .clone()
+    }
+}
+
+impl Default for InMemoryManifestWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InheritedVersionChecker for InMemoryManifestWriter {
+    fn has_inherited_version(&self, manifest_path: &Path) -> Result<bool> {
+        Ok(self.inherited_paths.contains(manifest_path))
+    }
+}
+
+impl ManifestWriter for InMemoryManifestWriter {
+    fn write_version(&self, manifest_path: &Path, new_version: &Version) -> Result<()> {
+        self.written_versions
+            .lock()
+            .expect("lock poisoned")
+            .push((manifest_path.to_path_buf(), new_version.clone()));
+        Ok(())
+    }
+
+    fn remove_workspace_version(&self, _manifest_path: &Path) -> Result<()> {
+        *self
+            .removed_workspace_version
+            .lock()
+            .expect("lock poisoned") = true;
+        *self.workspace_version.lock().expect("lock poisoned") = None;
+        Ok(())
+    }
+
+    fn read_workspace_version(&self, _manifest_path: &Path) -> Result<Option<Version>> {
+        Ok(self
+            .workspace_version
+            .lock()
+            .expect("lock poisoned")
+            .clone())
+    }
+
+    fn write_workspace_version(&self, _manifest_path: &Path, version: &Version) -> Result<()> {
+        *self.workspace_version.lock().expect("lock poisoned") = Some(version.clone());
+        *self
+            .removed_workspace_version
+            .lock()
+            .expect("lock poisoned") = false;
+        Ok(())
+    }
+
+    fn verify_version(&self, _manifest_path: &Path, _expected: &Version) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+        config: &InitConfig,
+    ) -> Result<()> {
+        self.written_metadata.lock().expect("lock poisoned").push((
+            manifest_path.to_path_buf(),
+            section,
+            config.clone(),
+        ));
+        Ok(())
+    }
+
+    fn read_metadata(
+        &self,
+        _manifest_path: &Path,
+        _section: MetadataSection,
+    ) -> Result<ExistingMetadata> {
+        Ok(self.existing_metadata.clone())
+    }
+
+    fn upgrade_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<Vec<DeprecatedKey>> {
+        self.upgraded_metadata
+            .lock()
+            .expect("lock poisoned")
+            .push((manifest_path.to_path_buf(), section));
+        Ok(self.existing_metadata.deprecated_keys.clone())
+    }
+
+    fn add_workspace_member(&self, manifest_path: &Path, member: &str) -> Result<bool> {
+        let mut members = self.workspace_members.lock().expect("lock poisoned");
+        let entry = (manifest_path.to_path_buf(), member.to_string());
+        if members.contains(&entry) {
+            return Ok(false);
+        }
+        members.push(entry);
+        Ok(true)
+    }
+
+    fn update_dependency_version(
+        &self,
+        manifest_path: &Path,
+        dependency_name: &str,
+        new_version: &Version,
+        _skip_optional: bool,
+    ) -> Result<bool> {
+        let returns_true = *self
+            .dependency_update_returns_true
+            .lock()
+            .expect("lock poisoned");
+        if returns_true {
+            self.dependency_version_updates
+                .lock()
+                .expect("lock poisoned")
+                .push((
+                    manifest_path.to_path_buf(),
+                    dependency_name.to_string(),
+                    new_version.clone(),
+                ));
+        }
+        Ok(returns_true)
+    }
+}
+
+pub struct InMemoryChangelogWriter {
+    written: Mutex<Vec<(PathBuf, VersionRelease)>>,
+    existing_changelogs: HashSet<PathBuf>,
+    rotated: Mutex<Vec<(PathBuf, ArchivePolicy)>>,
+}
+
+impl InMemoryChangelogWriter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            written: Mutex::new(Vec::new()),
+            existing_changelogs: HashSet::new(),
+            rotated: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn with_existing_changelog(mut self, path: PathBuf) -> Self {
+        self.existing_changelogs.insert(path);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn written_releases(&self) -> Vec<(PathBuf, VersionRelease)> {
+        self.written.lock().expect("lock poisoned").clone()
+    }
+}
+
+impl Default for InMemoryChangelogWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for InMemoryChangelogWriter {
+    fn clone(&self) -> Self {
+        Self {
+            written: Mutex::new(self.written.lock().expect("lock poisoned").clone()),
+            existing_changelogs: self.existing_changelogs.clone(),
+            rotated: Mutex::new(self.rotated.lock().expect("lock poisoned").clone()),
+        }
+    }
+}
+
+impl ChangelogWriter for InMemoryChangelogWriter {
+    fn write_release(
+        &self,
+        changelog_path: &Path,
+        release: &VersionRelease,
+        _repo_info: Option<&RepositoryInfo>,
+        _previous_version: Option<&str>,
+    ) -> Result<ChangelogWriteResult> {
+        let created = !self.existing_changelogs.contains(changelog_path);
+
+        self.written
+            .lock()
+            .expect("lock poisoned")
+            .push((changelog_path.to_path_buf(), release.clone()));
+
+        Ok(ChangelogWriteResult {
+            path: changelog_path.to_path_buf(),
+            created,
+        })
+    }
+
+    fn write_releases(
+        &self,
+        writes: &[PlannedChangelogWrite],
+    ) -> Result<Vec<ChangelogWriteResult>> {
+        writes
+            .iter()
+            .map(|write| {
+                self.write_release(
+                    &write.path,
+                    &write.release,
+                    write.repo_info.as_ref(),
+                    write.previous_version.as_deref(),
+                )
+            })
+            .collect()
+    }
+
+    fn extend_release(&self, changelog_path: &Path, release: &VersionRelease) -> Result<()> {
+        self.written
+            .lock()
+            .expect("lock poisoned")
+            .push((changelog_path.to_path_buf(), release.clone()));
+        Ok(())
+    }
+
+    fn rotate_changelog(
+        &self,
+        changelog_path: &Path,
+        policy: &ArchivePolicy,
+    ) -> Result<Vec<ChangelogArchive>> {
+        self.rotated
+            .lock()
+            .expect("lock poisoned")
+            .push((changelog_path.to_path_buf(), policy.clone()));
+        Ok(Vec::new())
+    }
+
+    fn changelog_exists(&self, path: &Path) -> bool {
+        self.existing_changelogs.contains(path)
+    }
+
+    fn restore_changelog(&self, _path: &Path, _content: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn delete_changelog(&self, _path: &Path) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub struct InMemoryReleaseStateIO {
+    prerelease_state: RwLock<Option<PrereleaseState>>,
+    graduation_state: RwLock<Option<GraduationState>>,
+}
+
+impl InMemoryReleaseStateIO {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            prerelease_state: RwLock::new(None),
+            graduation_state: RwLock::new(None),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn with_prerelease_state(self, state: PrereleaseState) -> Self {
+        *self.prerelease_state.write().expect("lock poisoned") = Some(state);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn with_graduation_state(self, state: GraduationState) -> Self {
+        *self.graduation_state.write().expect("lock poisoned") = Some(state);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn get_graduation_state(&self) -> Option<GraduationState> {
+        self.graduation_state.read().expect("lock poisoned").clone()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn get_prerelease_state(&self) -> Option<PrereleaseState> {
+        self.prerelease_state.read().expect("lock poisoned").clone()
+    }
+}
+
+impl Default for InMemoryReleaseStateIO {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReleaseStateIO for InMemoryReleaseStateIO {
+    fn load_prerelease_state(&self, _changeset_dir: &Path) -> Result<Option<PrereleaseState>> {
+        Ok(self.prerelease_state.read().expect("lock poisoned").clone())
+    }
+
+    fn save_prerelease_state(&self, _changeset_dir: &Path, state: &PrereleaseState) -> Result<()> {
+        *self.prerelease_state.write().expect("lock poisoned") = if state.is_blank() {
+            None
+        } else {
+            Some(state.clone())
+        };
+        Ok(())
+    }
+
+    fn load_graduation_state(&self, _changeset_dir: &Path) -> Result<Option<GraduationState>> {
+        Ok(self.graduation_state.read().expect("lock poisoned").clone())
+    }
+
+    fn save_graduation_state(&self, _changeset_dir: &Path, state: &GraduationState) -> Result<()> {
+        *self.graduation_state.write().expect("lock poisoned") = if state.is_empty() {
+            None
+        } else {
+            Some(state.clone())
+        };
+        Ok(())
+    }
+
+    fn outdated_state_files(&self, _changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>> {
+        Ok(Vec::new())
+    }
+
+    fn migrate_state_files(&self, _changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>> {
+        Ok(Vec::new())
+    }
+}
+
+pub struct InMemoryTextFileIO {
+    files: RwLock<BTreeMap<PathBuf, String>>,
+}
+
+impl InMemoryTextFileIO {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            files: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn with_file(self, path: PathBuf, content: impl Into<String>) -> Self {
+        self.files
+            .write()
+            .expect("lock poisoned")
+            .insert(path, content.into());
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn file(&self, path: &Path) -> Option<String> {
+        self.files.read().expect("lock poisoned").get(path).cloned()
+    }
+}
+
+impl Default for InMemoryTextFileIO {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextFileIO for InMemoryTextFileIO {
+    fn find_files(&self, root: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+        let matcher = super::text_file::glob_matcher(pattern)?;
+        Ok(self
+            .files
+            .read()
+            .expect("lock poisoned")
+            .keys()
+            .filter(|path| {
+                path.strip_prefix(root)
+                    .is_ok_and(|relative| matcher.is_match(relative))
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn read_file(&self, path: &Path) -> Result<String> {
+        self.file(path)
+            .ok_or_else(|| crate::OperationError::TextFileRead {
+                path: path.to_path_buf(),
+                source: std::io::Error::from(std::io::ErrorKind::NotFound),
+            })
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<()> {
+        self.files
+            .write()
+            .expect("lock poisoned")
+            .insert(path.to_path_buf(), content.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use changeset_core::BumpType;
+
+    use super::*;
+    use crate::mocks::make_changeset;
+
+    fn make_test_changeset(name: &str) -> Changeset {
+        make_changeset(name, BumpType::Patch, &format!("Fix {name}"))
+    }
+
+    #[test]
+    fn list_changesets_filters_consumed() {
+        let changeset_dir = PathBuf::from("/mock/.changeset");
+        let unconsumed_path = changeset_dir.join("unconsumed.md");
+        let consumed_path = changeset_dir.join("consumed.md");
+
+        let reader = InMemoryChangesetIO::new()
+            .with_changeset(unconsumed_path.clone(), make_test_changeset("pkg-a"))
+            .with_consumed_changeset(
+                consumed_path.clone(),
+                make_test_changeset("pkg-b"),
+                "1.0.0-pre.1".to_string(),
+            );
+
+        let listed = reader
+            .list_changesets(&changeset_dir)
+            .expect("list_changesets should succeed");
+
+        assert_eq!(listed.len(), 1);
+        assert!(listed.contains(&unconsumed_path));
+        assert!(!listed.contains(&consumed_path));
+    }
+
+    #[test]
+    fn list_consumed_changesets_returns_consumed() {
+        let changeset_dir = PathBuf::from("/mock/.changeset");
+        let unconsumed_path = changeset_dir.join("unconsumed.md");
+        let consumed_path = changeset_dir.join("consumed.md");
+
+        let reader = InMemoryChangesetIO::new()
+            .with_changeset(unconsumed_path.clone(), make_test_changeset("pkg-a"))
+            .with_consumed_changeset(
+                consumed_path.clone(),
+                make_test_changeset("pkg-b"),
+                "1.0.0-pre.1".to_string(),
+            );
+
+        let consumed = reader
+            .list_consumed_changesets(&changeset_dir)
+            .expect("list_consumed_changesets should succeed");
+
+        assert_eq!(consumed.len(), 1);
+        assert!(consumed.contains(&consumed_path));
+        assert!(!consumed.contains(&unconsumed_path));
+    }
+
+    #[test]
+    fn mark_consumed_updates_state() {
+        let changeset_dir = PathBuf::from("/mock/.changeset");
+        let path = changeset_dir.join("changeset.md");
+
+        let reader =
+            InMemoryChangesetIO::new().with_changeset(path.clone(), make_test_changeset("pkg-a"));
+
+        assert!(reader.get_consumed_status(&path).is_none());
+
+        let version: Version = "2.0.0-pre.1".parse().expect("valid version");
+        reader
+            .mark_consumed_for_prerelease(&changeset_dir, &[path.as_path()], &version)
+            .expect("mark_consumed should succeed");
+
+        assert_eq!(
+            reader.get_consumed_status(&path),
+            Some("2.0.0-pre.1".to_string())
+        );
+
+        let listed = reader
+            .list_changesets(&changeset_dir)
+            .expect("list_changesets should succeed");
+        assert!(listed.is_empty());
+
+        let consumed = reader
+            .list_consumed_changesets(&changeset_dir)
+            .expect("list_consumed_changesets should succeed");
+        assert_eq!(consumed.len(), 1);
+        assert!(consumed.contains(&path));
+    }
+
+    #[test]
+    fn clear_consumed_updates_state() {
+        let changeset_dir = PathBuf::from("/mock/.changeset");
+        let path = changeset_dir.join("changeset.md");
+
+        let reader = InMemoryChangesetIO::new().with_consumed_changeset(
+            path.clone(),
+            make_test_changeset("pkg-a"),
+            "1.0.0-pre.1".to_string(),
+        );
+
+        assert_eq!(
+            reader.get_consumed_status(&path),
+            Some("1.0.0-pre.1".to_string())
+        );
+
+        reader
+            .clear_consumed_for_prerelease(&changeset_dir, &[path.as_path()])
+            .expect("clear_consumed should succeed");
+
+        assert!(reader.get_consumed_status(&path).is_none());
+
+        let consumed = reader
+            .list_consumed_changesets(&changeset_dir)
+            .expect("list_consumed_changesets should succeed");
+        assert!(consumed.is_empty());
+
+        let listed = reader
+            .list_changesets(&changeset_dir)
+            .expect("list_changesets should succeed");
+        assert_eq!(listed.len(), 1);
+        assert!(listed.contains(&path));
+    }
+
+    #[test]
+    fn written_changesets_are_listed() {
+        let changeset_dir = PathBuf::from("/mock/.changeset");
+        let io = InMemoryChangesetIO::new();
+
+        let filename = io
+            .write_changeset(&changeset_dir, &make_test_changeset("pkg-a"))
+            .expect("write_changeset should succeed");
+
+        let path = changeset_dir.join(CHANGESETS_SUBDIR).join(&filename);
+        assert!(io.filename_exists(&changeset_dir, &filename));
+        assert_eq!(
+            io.list_changesets(&changeset_dir)
+                .expect("list_changesets should succeed"),
+            vec![path.clone()]
+        );
+        assert_eq!(
+            io.changeset(&path).map(|c| c.summary),
+            Some("Fix pkg-a".to_string())
+        );
+    }
+}
@@ -1,13 +1,51 @@
+mod bump_inference;
+mod cargo_publisher;
 mod changelog;
 mod changeset_io;
+mod command;
+mod consumed_state;
+mod crates_io;
 mod git;
+mod github;
+mod http;
+#[cfg(feature = "keyring")]
+mod keyring;
 mod manifest;
+pub mod memory;
+mod notifier;
+#[cfg(feature = "otlp")]
+mod otlp;
 mod project;
 mod release_state_io;
+#[cfg(feature = "api-diff")]
+mod rustdoc;
+mod sparse_index;
+mod text_file;
+mod token;
 
+pub use bump_inference::{
+    BumpInferenceChain, ConventionalCommitsInference, FileHeuristicsInference,
+};
+pub use cargo_publisher::CargoPublisher;
 pub use changelog::FileSystemChangelogWriter;
 pub use changeset_io::FileSystemChangesetIO;
+pub use command::ShellCommandRunner;
+pub use consumed_state::{OutOfBandChangesetIO, changeset_io_for};
+pub use crates_io::{RegistryCredentials, TrustedPublishing};
 pub use git::Git2Provider;
+pub use github::GitHubChangedFilesProvider;
+pub use http::HttpClient;
+#[cfg(feature = "keyring")]
+pub use keyring::KeyringTokenProvider;
 pub use manifest::FileSystemManifestWriter;
+pub(crate) use manifest::non_cargo_adapter;
+pub use notifier::HttpNotifier;
+#[cfg(feature = "otlp")]
+pub use otlp::OtlpHttpExporter;
 pub use project::FileSystemProjectProvider;
 pub use release_state_io::FileSystemReleaseStateIO;
+#[cfg(feature = "api-diff")]
+pub use rustdoc::RustdocBumpInference;
+pub use sparse_index::SparseIndex;
+pub use text_file::FileSystemTextFileIO;
+pub use token::{EnvTokenProvider, GitHubOidcTokenProvider, TokenChain, github_token_provider};
@@ -0,0 +1,34 @@
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::HttpClient;
+use crate::traits::Notifier;
+
+pub struct HttpNotifier {
+    client: HttpClient,
+}
+
+impl HttpNotifier {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            client: HttpClient::new(),
+        }
+    }
+}
+
+impl Default for HttpNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Notifier for HttpNotifier {
+    fn post_json(&self, url: &str, body: &str) -> Result<()> {
+        self.client
+            .post_json(url, body)
+            .map_err(|source| OperationError::NotificationFailed {
+                source: Box::new(source),
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use serde_json::Value;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::HttpClient;
+
+/// Sends OTLP/JSON traces to a collector over HTTP.
+pub struct OtlpHttpExporter {
+    client: HttpClient,
+}
+
+impl OtlpHttpExporter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            client: HttpClient::new(),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the collector cannot be reached or rejects the trace.
+    pub fn export(&self, endpoint: &str, trace: &Value) -> Result<()> {
+        self.client
+            .post_json(endpoint, &trace.to_string())
+            .map_err(|source| OperationError::TraceExportFailed {
+                source: Box::new(source),
+            })?;
+        Ok(())
+    }
+}
+
+impl Default for OtlpHttpExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,19 +2,43 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use changeset_project::{
-    CargoProject, PackageChangesetConfig, RootChangesetConfig, discover_project,
-    ensure_changeset_dir, load_changeset_configs,
+    CACHE_SUBDIR, CargoProject, ConfigOverride, FileMapping, MappingCache, OwnedChangesetDir,
+    PackageAdapters, PackageChangesetConfig, RootChangesetConfig, discover_project_with_adapters,
+    ensure_changeset_dir, ensure_owned_changeset_dir, load_changeset_configs_with_overrides,
+    map_files_to_packages, map_files_to_packages_cached,
 };
 
 use crate::Result;
 use crate::traits::ProjectProvider;
 
-pub struct FileSystemProjectProvider;
+pub struct FileSystemProjectProvider {
+    config_overrides: Vec<ConfigOverride>,
+    adapters: PackageAdapters,
+}
 
 impl FileSystemProjectProvider {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            config_overrides: Vec::new(),
+            adapters: PackageAdapters::default(),
+        }
+    }
+
+    /// Applies `overrides` on top of the root changeset configuration every
+    /// time it is loaded.
+    #[must_use]
+    pub fn with_config_overrides(mut self, overrides: Vec<ConfigOverride>) -> Self {
+        self.config_overrides = overrides;
+        self
+    }
+
+    /// Replaces the adapters that read the packages in `extra-packages`
+    /// directories.
+    #[must_use]
+    pub fn with_adapters(mut self, adapters: PackageAdapters) -> Self {
+        self.adapters = adapters;
+        self
     }
 }
 
@@ -26,14 +50,17 @@ impl Default for FileSystemProjectProvider {
 
 impl ProjectProvider for FileSystemProjectProvider {
     fn discover_project(&self, start_path: &Path) -> Result<CargoProject> {
-        Ok(discover_project(start_path)?)
+        Ok(discover_project_with_adapters(start_path, &self.adapters)?)
     }
 
     fn load_configs(
         &self,
         project: &CargoProject,
     ) -> Result<(RootChangesetConfig, HashMap<String, PackageChangesetConfig>)> {
-        Ok(load_changeset_configs(project)?)
+        Ok(load_changeset_configs_with_overrides(
+            project,
+            &self.config_overrides,
+        )?)
     }
 
     fn ensure_changeset_dir(
@@ -43,4 +70,48 @@ impl ProjectProvider for FileSystemProjectProvider {
     ) -> Result<PathBuf> {
         Ok(ensure_changeset_dir(project, config)?)
     }
+
+    fn ensure_owned_changeset_dir(
+        &self,
+        project: &CargoProject,
+        dir: &OwnedChangesetDir,
+    ) -> Result<PathBuf> {
+        Ok(ensure_owned_changeset_dir(project, dir)?)
+    }
+
+    fn map_files(
+        &self,
+        project: &CargoProject,
+        changed_files: &[PathBuf],
+        root_config: &RootChangesetConfig,
+        package_configs: &HashMap<String, PackageChangesetConfig>,
+    ) -> FileMapping {
+        let cache_dir = project
+            .root
+            .join(root_config.changeset_dir())
+            .join(CACHE_SUBDIR);
+
+        let mut cache = match MappingCache::load(project, &cache_dir) {
+            Ok(cache) => cache,
+            Err(err) => {
+                tracing::debug!(error = %err, "file mapping cache unavailable");
+                return map_files_to_packages(project, changed_files, root_config, package_configs);
+            }
+        };
+
+        let mapping = map_files_to_packages_cached(
+            project,
+            changed_files,
+            root_config,
+            package_configs,
+            &mut cache,
+        );
+
+        // The cache only speeds up later runs, so a failed write never fails the caller.
+        if let Err(err) = cache.save(&cache_dir) {
+            tracing::debug!(error = %err, "failed to save file mapping cache");
+        }
+
+        mapping
+    }
 }
@@ -1,14 +1,17 @@
 use std::fs;
 use std::path::Path;
 
-use changeset_project::{GraduationState, PrereleaseState};
+use changeset_core::atomic_write;
+use changeset_project::{
+    GraduationState, Migrated, PrereleaseState, StateFile, migrate_state, serialize_state,
+};
 
 use crate::Result;
 use crate::error::OperationError;
-use crate::traits::ReleaseStateIO;
+use crate::traits::{OutdatedStateFile, ReleaseStateIO};
 
-const PRERELEASE_FILENAME: &str = "pre-release.toml";
-const GRADUATION_FILENAME: &str = "graduation.toml";
+const PRERELEASE_FILENAME: &str = StateFile::Prerelease.file_name();
+const GRADUATION_FILENAME: &str = StateFile::Graduation.file_name();
 
 pub struct FileSystemReleaseStateIO;
 
@@ -27,24 +30,84 @@ impl Default for FileSystemReleaseStateIO {
 
 impl ReleaseStateIO for FileSystemReleaseStateIO {
     fn load_prerelease_state(&self, changeset_dir: &Path) -> Result<Option<PrereleaseState>> {
-        let path = changeset_dir.join(PRERELEASE_FILENAME);
-        load_toml_file(&path)
+        let migrated = load_state_file::<PrereleaseState>(changeset_dir, StateFile::Prerelease)?;
+        Ok(migrated.map(|migrated| migrated.state))
     }
 
     fn save_prerelease_state(&self, changeset_dir: &Path, state: &PrereleaseState) -> Result<()> {
         let path = changeset_dir.join(PRERELEASE_FILENAME);
-        save_toml_file(&path, state, state.is_empty())
+        save_toml_file(&path, state, state.is_blank())
     }
 
     fn load_graduation_state(&self, changeset_dir: &Path) -> Result<Option<GraduationState>> {
-        let path = changeset_dir.join(GRADUATION_FILENAME);
-        load_toml_file(&path)
+        let migrated = load_state_file::<GraduationState>(changeset_dir, StateFile::Graduation)?;
+        Ok(migrated.map(|migrated| migrated.state))
     }
 
     fn save_graduation_state(&self, changeset_dir: &Path, state: &GraduationState) -> Result<()> {
         let path = changeset_dir.join(GRADUATION_FILENAME);
         save_toml_file(&path, state, state.is_empty())
     }
+
+    fn outdated_state_files(&self, changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>> {
+        let mut outdated = Vec::new();
+
+        if let Some(migrated) =
+            load_state_file::<PrereleaseState>(changeset_dir, StateFile::Prerelease)?
+        {
+            outdated.extend(outdated_entry(StateFile::Prerelease, &migrated));
+        }
+        if let Some(migrated) =
+            load_state_file::<GraduationState>(changeset_dir, StateFile::Graduation)?
+        {
+            outdated.extend(outdated_entry(StateFile::Graduation, &migrated));
+        }
+
+        Ok(outdated)
+    }
+
+    fn migrate_state_files(&self, changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>> {
+        let mut migrated_files = Vec::new();
+
+        if let Some(migrated) =
+            load_state_file::<PrereleaseState>(changeset_dir, StateFile::Prerelease)?
+            && let Some(entry) = outdated_entry(StateFile::Prerelease, &migrated)
+        {
+            let path = changeset_dir.join(PRERELEASE_FILENAME);
+            save_toml_file(&path, &migrated.state, false)?;
+            migrated_files.push(entry);
+        }
+        if let Some(migrated) =
+            load_state_file::<GraduationState>(changeset_dir, StateFile::Graduation)?
+            && let Some(entry) = outdated_entry(StateFile::Graduation, &migrated)
+        {
+            let path = changeset_dir.join(GRADUATION_FILENAME);
+            save_toml_file(&path, &migrated.state, false)?;
+            migrated_files.push(entry);
+        }
+
+        Ok(migrated_files)
+    }
+}
+
+fn outdated_entry<T>(file: StateFile, migrated: &Migrated<T>) -> Option<OutdatedStateFile> {
+    migrated.is_outdated().then_some(OutdatedStateFile {
+        file,
+        version: migrated.from_version,
+    })
+}
+
+/// Loads a state file and upgrades it to the current layout in memory.
+fn load_state_file<T: serde::de::DeserializeOwned>(
+    changeset_dir: &Path,
+    file: StateFile,
+) -> Result<Option<Migrated<T>>> {
+    let path = changeset_dir.join(file.file_name());
+    let Some(table) = load_toml_file::<toml::Table>(&path)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(migrate_state(file, table)?))
 }
 
 fn load_toml_file<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Option<T>> {
@@ -84,11 +147,11 @@ fn save_toml_file<T: serde::Serialize>(
     }
 
     let content =
-        toml::to_string_pretty(state).map_err(|source| OperationError::ReleaseStateSerialize {
+        serialize_state(state).map_err(|source| OperationError::ReleaseStateSerialize {
             path: path.to_path_buf(),
             source,
         })?;
-    fs::write(path, content).map_err(|source| OperationError::ReleaseStateWrite {
+    atomic_write(path, content).map_err(|source| OperationError::ReleaseStateWrite {
         path: path.to_path_buf(),
         source,
     })?;
@@ -157,6 +220,25 @@ mod tests {
             assert!(!path.exists());
         }
 
+        #[test]
+        fn save_keeps_numbers_without_tags() {
+            let dir = setup_test_dir();
+            let io = FileSystemReleaseStateIO::new();
+            let mut state = PrereleaseState::new();
+            state.set_number("crate-a".to_string(), 3);
+
+            io.save_prerelease_state(dir.path(), &state)
+                .expect("save should succeed");
+            let loaded = io
+                .load_prerelease_state(dir.path())
+                .expect("load should succeed")
+                .expect("numbers should keep the file");
+
+            assert!(loaded.is_empty());
+            assert_eq!(loaded.number("crate-a"), Some(3));
+            assert_eq!(loaded, state);
+        }
+
         #[test]
         fn save_empty_state_when_file_doesnt_exist_is_noop() {
             let dir = setup_test_dir();
@@ -515,4 +597,72 @@ mod tests {
             assert!(result2.is_ok());
         }
     }
+
+    mod state_migration {
+        use super::*;
+
+        #[test]
+        fn loads_unversioned_prerelease_file_without_rewriting() {
+            let dir = setup_test_dir();
+            let io = FileSystemReleaseStateIO::new();
+            let path = dir.path().join(PRERELEASE_FILENAME);
+            fs::write(&path, "crate-a = \"alpha\"\n").expect("write should succeed");
+
+            let state = io
+                .load_prerelease_state(dir.path())
+                .expect("load should succeed")
+                .expect("should have state");
+
+            assert_eq!(state.get("crate-a"), Some("alpha"));
+            assert_eq!(
+                fs::read_to_string(&path).expect("read file"),
+                "crate-a = \"alpha\"\n"
+            );
+            assert_eq!(
+                io.outdated_state_files(dir.path())
+                    .expect("check should succeed"),
+                vec![OutdatedStateFile {
+                    file: StateFile::Prerelease,
+                    version: 1,
+                }]
+            );
+        }
+
+        #[test]
+        fn migrate_rewrites_outdated_files() {
+            let dir = setup_test_dir();
+            let io = FileSystemReleaseStateIO::new();
+            fs::write(
+                dir.path().join(PRERELEASE_FILENAME),
+                "crate-a = \"alpha\"\n",
+            )
+            .expect("write should succeed");
+            fs::write(
+                dir.path().join(GRADUATION_FILENAME),
+                "graduation = [\"crate-b\"]\n",
+            )
+            .expect("write should succeed");
+
+            let migrated = io
+                .migrate_state_files(dir.path())
+                .expect("migrate should succeed");
+
+            assert_eq!(migrated.len(), 2);
+            let content =
+                fs::read_to_string(dir.path().join(PRERELEASE_FILENAME)).expect("read file");
+            assert!(content.contains("config_version = 2"));
+            assert!(content.contains("[packages]"));
+            assert!(
+                io.outdated_state_files(dir.path())
+                    .expect("check should succeed")
+                    .is_empty()
+            );
+            assert!(
+                io.load_graduation_state(dir.path())
+                    .expect("load should succeed")
+                    .expect("should have state")
+                    .contains("crate-b")
+            );
+        }
+    }
 }
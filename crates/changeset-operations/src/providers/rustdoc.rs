@@ -0,0 +1,189 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+use changeset_core::{BumpType, PackageInfo};
+use tempfile::TempDir;
+use tracing::debug;
+
+use crate::Result;
+use crate::api_diff::{ApiSurface, suggest_bump};
+use crate::error::OperationError;
+use crate::traits::BumpInferenceProvider;
+
+/// Infers bump types by diffing the public API of the working tree against
+/// a base revision, using rustdoc's JSON output.
+///
+/// Requires a nightly toolchain. The base revision is checked out once into a
+/// temporary git worktree, which is removed again when the provider is dropped.
+pub struct RustdocBumpInference {
+    base: String,
+    toolchain: String,
+    scratch: Mutex<Option<Scratch>>,
+}
+
+struct Scratch {
+    repo_root: PathBuf,
+    worktree: PathBuf,
+    target_dir: PathBuf,
+    _dir: TempDir,
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = Command::new("git")
+            .arg("-C")
+            .arg(&self.repo_root)
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.worktree)
+            .output();
+    }
+}
+
+impl RustdocBumpInference {
+    #[must_use]
+    pub fn new(base: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            toolchain: "nightly".to_string(),
+            scratch: Mutex::new(None),
+        }
+    }
+
+    #[must_use]
+    pub fn with_toolchain(mut self, toolchain: impl Into<String>) -> Self {
+        self.toolchain = toolchain.into();
+        self
+    }
+
+    fn prepare(&self, project_root: &Path, package: &str) -> Result<(PathBuf, PathBuf)> {
+        let mut guard = self.scratch.lock().expect("lock poisoned");
+        let scratch = match guard.take() {
+            Some(scratch) => scratch,
+            None => self.checkout_base(project_root, package)?,
+        };
+
+        let relative = project_root
+            .canonicalize()
+            .ok()
+            .and_then(|root| {
+                root.strip_prefix(&scratch.repo_root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .unwrap_or_default();
+        let paths = (scratch.worktree.join(relative), scratch.target_dir.clone());
+        *guard = Some(scratch);
+        Ok(paths)
+    }
+
+    fn checkout_base(&self, project_root: &Path, package: &str) -> Result<Scratch> {
+        let toplevel = run(
+            Command::new("git")
+                .arg("-C")
+                .arg(project_root)
+                .args(["rev-parse", "--show-toplevel"]),
+            package,
+        )?;
+        let repo_root = PathBuf::from(toplevel.trim())
+            .canonicalize()
+            .map_err(|e| api_diff_error(package, e.to_string()))?;
+
+        let dir = TempDir::new().map_err(|e| api_diff_error(package, e.to_string()))?;
+        let worktree = dir.path().join("base");
+        run(
+            Command::new("git")
+                .arg("-C")
+                .arg(&repo_root)
+                .args(["worktree", "add", "--detach"])
+                .arg(&worktree)
+                .arg(&self.base),
+            package,
+        )?;
+
+        Ok(Scratch {
+            repo_root,
+            worktree,
+            target_dir: dir.path().join("target"),
+            _dir: dir,
+        })
+    }
+
+    /// Documents `package` in `root` and returns its API surface, or `None` if
+    /// the package cannot be documented there.
+    fn surface(&self, root: &Path, target_dir: &Path, package: &str) -> Result<Option<ApiSurface>> {
+        let output = Command::new("cargo")
+            .arg(format!("+{}", self.toolchain))
+            .args(["rustdoc", "--quiet", "--lib", "-p", package])
+            .arg("--manifest-path")
+            .arg(root.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(target_dir)
+            .args(["--", "-Z", "unstable-options", "--output-format", "json"])
+            .output()
+            .map_err(|e| api_diff_error(package, e.to_string()))?;
+        if !output.status.success() {
+            debug!(
+                package,
+                root = %root.display(),
+                stderr = %String::from_utf8_lossy(&output.stderr),
+                "rustdoc JSON build failed"
+            );
+            return Ok(None);
+        }
+
+        let json_path = target_dir
+            .join("doc")
+            .join(format!("{}.json", package.replace('-', "_")));
+        let json = std::fs::read_to_string(&json_path)
+            .map_err(|e| api_diff_error(package, format!("{}: {e}", json_path.display())))?;
+        ApiSurface::from_rustdoc_json(&json)
+            .map(Some)
+            .map_err(|e| api_diff_error(package, e.to_string()))
+    }
+}
+
+impl BumpInferenceProvider for RustdocBumpInference {
+    fn name(&self) -> &'static str {
+        "api"
+    }
+
+    fn infer_bump(&self, project_root: &Path, package: &PackageInfo) -> Result<Option<BumpType>> {
+        let (base_root, target_dir) = self.prepare(project_root, &package.name)?;
+
+        let Some(after) = self.surface(project_root, &target_dir, &package.name)? else {
+            return Err(api_diff_error(
+                &package.name,
+                format!(
+                    "rustdoc JSON build failed (is the {} toolchain installed?)",
+                    self.toolchain
+                ),
+            ));
+        };
+        let Some(before) = self.surface(&base_root, &target_dir, &package.name)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(suggest_bump(&before, &after)))
+    }
+}
+
+fn run(command: &mut Command, package: &str) -> Result<String> {
+    let output = command
+        .output()
+        .map_err(|e| api_diff_error(package, e.to_string()))?;
+    if !output.status.success() {
+        return Err(api_diff_error(
+            package,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn api_diff_error(package: &str, reason: String) -> OperationError {
+    OperationError::ApiDiff {
+        package: package.to_string(),
+        reason,
+    }
+}
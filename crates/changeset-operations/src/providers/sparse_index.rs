@@ -0,0 +1,141 @@
+use semver::Version;
+use serde::Deserialize;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::HttpClient;
+use crate::traits::RegistryIndex;
+
+const CRATES_IO_INDEX_URL: &str = "https://index.crates.io";
+
+/// Reads a registry's sparse index, as served for crates.io at
+/// `index.crates.io`.
+pub struct SparseIndex {
+    url: String,
+    client: HttpClient,
+}
+
+#[derive(Deserialize)]
+struct IndexEntry {
+    vers: String,
+}
+
+impl SparseIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            url: CRATES_IO_INDEX_URL.to_string(),
+            client: HttpClient::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+}
+
+impl Default for SparseIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegistryIndex for SparseIndex {
+    fn has_version(&self, package: &str, version: &Version) -> Result<bool> {
+        let url = format!("{}/{}", self.url.trim_end_matches('/'), index_path(package));
+        // The index is served from a CDN; ask for a fresh copy so a version
+        // published moments ago shows up as soon as possible.
+        let body = match self.client.get(&url, &[("Cache-Control", "no-cache")]) {
+            Ok(body) => body,
+            Err(ureq::Error::StatusCode(404)) => return Ok(false),
+            Err(source) => {
+                return Err(OperationError::RegistryIndexRequest {
+                    package: package.to_string(),
+                    source: Box::new(source),
+                });
+            }
+        };
+
+        let version = version.to_string();
+        Ok(body
+            .lines()
+            .filter_map(|line| serde_json::from_str::<IndexEntry>(line).ok())
+            .any(|entry| entry.vers == version))
+    }
+}
+
+/// Path of a package's index file: `1/a`, `2/ab`, `3/a/abc`, or `ab/cd/abcd…`.
+fn index_path(package: &str) -> String {
+    let name = package.to_ascii_lowercase();
+    match name.len() {
+        1 => format!("1/{name}"),
+        2 => format!("2/{name}"),
+        3 => format!("3/{}/{name}", &name[..1]),
+        _ => format!("{}/{}/{name}", &name[..2], &name[2..4]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn index_paths_follow_name_length() {
+        assert_eq!(index_path("a"), "1/a");
+        assert_eq!(index_path("ab"), "2/ab");
+        assert_eq!(index_path("abc"), "3/a/abc");
+        assert_eq!(index_path("Cargo-Changeset"), "ca/rg/cargo-changeset");
+    }
+
+    #[test]
+    fn finds_published_versions_and_treats_missing_packages_as_absent() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base = format!("http://{}", listener.local_addr()?);
+        let server = thread::spawn(move || -> std::io::Result<Vec<String>> {
+            let body = "{\"name\":\"serde\",\"vers\":\"1.0.0\"}\n{\"name\":\"serde\",\"vers\":\"1.0.1\"}\n";
+            let responses = [
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                ),
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            ];
+            let mut request_lines = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept()?;
+                let mut reader = BufReader::new(stream.try_clone()?);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line)?;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line)?;
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                }
+                request_lines.push(request_line.trim_end().to_string());
+                stream.write_all(response.as_bytes())?;
+            }
+            Ok(request_lines)
+        });
+        let index = SparseIndex::new().with_url(&base);
+
+        assert!(index.has_version("serde", &Version::new(1, 0, 1))?);
+        assert!(!index.has_version("missing", &Version::new(1, 0, 0))?);
+        let requests = server
+            .join()
+            .map_err(|_| anyhow::anyhow!("server thread panicked"))??;
+        assert_eq!(
+            requests,
+            ["GET /se/rd/serde HTTP/1.1", "GET /mi/ss/missing HTTP/1.1"]
+        );
+        Ok(())
+    }
+}
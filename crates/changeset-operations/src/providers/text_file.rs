@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::traits::TextFileIO;
+
+/// Directories never searched for stamped files.
+const SKIPPED_DIRS: &[&str] = &[".git", "target"];
+
+pub struct FileSystemTextFileIO;
+
+impl FileSystemTextFileIO {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for FileSystemTextFileIO {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextFileIO for FileSystemTextFileIO {
+    fn find_files(&self, root: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+        let matcher = glob_matcher(pattern)?;
+        let mut files = Vec::new();
+        collect_matches(root, root, &matcher, &mut files)?;
+        files.sort();
+        Ok(files)
+    }
+
+    fn read_file(&self, path: &Path) -> Result<String> {
+        fs::read_to_string(path).map_err(|source| OperationError::TextFileRead {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<()> {
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(path, content))
+            .map_err(|source| OperationError::TextFileWrite {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+}
+
+/// Builds a matcher in which `*` stays within one path component.
+pub(crate) fn glob_matcher(pattern: &str) -> Result<GlobMatcher> {
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map(|glob| glob.compile_matcher())
+        .map_err(|source| OperationError::StampGlob {
+            pattern: pattern.to_string(),
+            source,
+        })
+}
+
+fn collect_matches(
+    root: &Path,
+    dir: &Path,
+    matcher: &GlobMatcher,
+    files: &mut Vec<PathBuf>,
+) -> Result<()> {
+    let entries = fs::read_dir(dir).map_err(|source| OperationError::TextFileRead {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|source| OperationError::TextFileRead {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+        let path = entry.path();
+        if path.is_dir() {
+            let skipped = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| SKIPPED_DIRS.contains(&name));
+            if !skipped {
+                collect_matches(root, &path, matcher, files)?;
+            }
+        } else if path
+            .strip_prefix(root)
+            .is_ok_and(|relative| matcher.is_match(relative))
+        {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_files_matching_glob() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("charts/api"))?;
+        fs::create_dir_all(dir.path().join("target/charts/api"))?;
+        fs::write(dir.path().join("charts/api/Chart.yaml"), "")?;
+        fs::write(dir.path().join("charts/api/values.yaml"), "")?;
+        fs::write(dir.path().join("target/charts/api/Chart.yaml"), "")?;
+        fs::write(dir.path().join("README.md"), "")?;
+
+        let io = FileSystemTextFileIO::new();
+
+        assert_eq!(
+            io.find_files(dir.path(), "charts/*/Chart.yaml")?,
+            [dir.path().join("charts/api/Chart.yaml")]
+        );
+        assert_eq!(
+            io.find_files(dir.path(), "*.md")?,
+            [dir.path().join("README.md")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_glob() {
+        let dir = tempfile::tempdir().expect("tempdir");
+
+        let result = FileSystemTextFileIO::new().find_files(dir.path(), "charts/[");
+
+        assert!(matches!(result, Err(OperationError::StampGlob { .. })));
+    }
+}
@@ -0,0 +1,221 @@
+use serde::Deserialize;
+
+use crate::Result;
+use crate::error::OperationError;
+use crate::providers::HttpClient;
+use crate::traits::TokenProvider;
+
+/// Keyring service under which cargo-changeset looks up its tokens.
+#[cfg(feature = "keyring")]
+pub(crate) const KEYRING_SERVICE: &str = "cargo-changeset";
+
+/// Reads a token from the first of several environment variables that is set
+/// and not empty.
+pub struct EnvTokenProvider {
+    vars: Vec<String>,
+}
+
+impl EnvTokenProvider {
+    #[must_use]
+    pub fn new<I, S>(vars: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            vars: vars.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TokenProvider for EnvTokenProvider {
+    fn token(&self) -> Result<Option<String>> {
+        Ok(self
+            .vars
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|token| !token.is_empty()))
+    }
+}
+
+/// Requests an OIDC ID token for an audience from GitHub Actions.
+///
+/// The token proves which repository and workflow the job runs for, so
+/// services that trust GitHub Actions can hand out short-lived credentials in
+/// exchange instead of relying on long-lived secrets.
+pub struct GitHubOidcTokenProvider {
+    request_url: String,
+    request_token: String,
+    audience: String,
+    client: HttpClient,
+}
+
+#[derive(Deserialize)]
+struct OidcTokenResponse {
+    value: String,
+}
+
+impl GitHubOidcTokenProvider {
+    /// Builds a provider when the job may request OIDC tokens.
+    ///
+    /// Returns `None` outside GitHub Actions and in jobs without the
+    /// `id-token: write` permission.
+    #[must_use]
+    pub fn from_env(audience: impl Into<String>) -> Option<Self> {
+        Self::from_vars(audience, |name| std::env::var(name).ok())
+    }
+
+    pub(crate) fn from_vars(
+        audience: impl Into<String>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Option<Self> {
+        let present = |name| var(name).filter(|value| !value.is_empty());
+        Some(Self {
+            request_url: present("ACTIONS_ID_TOKEN_REQUEST_URL")?,
+            request_token: present("ACTIONS_ID_TOKEN_REQUEST_TOKEN")?,
+            audience: audience.into(),
+            client: HttpClient::new(),
+        })
+    }
+}
+
+impl TokenProvider for GitHubOidcTokenProvider {
+    fn token(&self) -> Result<Option<String>> {
+        let separator = if self.request_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        let url = format!("{}{separator}audience={}", self.request_url, self.audience);
+        let authorization = format!("Bearer {}", self.request_token);
+
+        let body = self
+            .client
+            .get(&url, &[("Authorization", &authorization)])
+            .map_err(|source| OperationError::OidcTokenRequest {
+                source: Box::new(source),
+            })?;
+        let response: OidcTokenResponse = serde_json::from_str(&body)
+            .map_err(|source| OperationError::OidcTokenResponse { source })?;
+
+        Ok(Some(response.value))
+    }
+}
+
+/// Asks several providers in turn and returns the first token found.
+#[derive(Default)]
+pub struct TokenChain {
+    providers: Vec<Box<dyn TokenProvider>>,
+}
+
+impl TokenChain {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+}
+
+impl TokenProvider for TokenChain {
+    fn token(&self) -> Result<Option<String>> {
+        for provider in &self.providers {
+            if let Some(token) = provider.token()? {
+                return Ok(Some(token));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Token sources for the GitHub API: `GITHUB_TOKEN`, then, when built with
+/// the `keyring` feature, the `github` entry of the OS keyring.
+#[must_use]
+pub fn github_token_provider() -> TokenChain {
+    let chain = TokenChain::new().with(EnvTokenProvider::new(["GITHUB_TOKEN"]));
+    #[cfg(feature = "keyring")]
+    let chain = chain.with(crate::providers::KeyringTokenProvider::new(
+        KEYRING_SERVICE,
+        "github",
+    ));
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    struct Fixed(Option<&'static str>);
+
+    impl TokenProvider for Fixed {
+        fn token(&self) -> Result<Option<String>> {
+            Ok(self.0.map(str::to_string))
+        }
+    }
+
+    #[test]
+    fn chain_returns_first_token() -> anyhow::Result<()> {
+        let chain = TokenChain::new()
+            .with(Fixed(None))
+            .with(Fixed(Some("second")))
+            .with(Fixed(Some("third")));
+
+        assert_eq!(chain.token()?.as_deref(), Some("second"));
+        assert_eq!(TokenChain::new().token()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn oidc_provider_requires_actions_token_request_vars() {
+        let env = HashMap::from([("ACTIONS_ID_TOKEN_REQUEST_URL", "https://example.com")]);
+
+        assert!(
+            GitHubOidcTokenProvider::from_vars("crates.io", |name| env
+                .get(name)
+                .map(ToString::to_string))
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn oidc_provider_requests_token_for_audience() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/token?api-version=2.0", listener.local_addr()?);
+        let server = thread::spawn(move || -> std::io::Result<String> {
+            let (mut stream, _) = listener.accept()?;
+            let mut request = String::new();
+            BufReader::new(stream.try_clone()?).read_line(&mut request)?;
+            stream.write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Length: 17\r\nConnection: close\r\n\r\n{\"value\":\"jwt\"}\r\n",
+            )?;
+            Ok(request)
+        });
+        let env = HashMap::from([
+            ("ACTIONS_ID_TOKEN_REQUEST_URL", url),
+            (
+                "ACTIONS_ID_TOKEN_REQUEST_TOKEN",
+                "request-token".to_string(),
+            ),
+        ]);
+
+        let provider =
+            GitHubOidcTokenProvider::from_vars("crates.io", |name| env.get(name).cloned())
+                .ok_or_else(|| anyhow::anyhow!("expected a provider"))?;
+
+        assert_eq!(provider.token()?.as_deref(), Some("jwt"));
+        let request = server
+            .join()
+            .map_err(|_| anyhow::anyhow!("server thread panicked"))??;
+        assert!(request.starts_with("GET /token?api-version=2.0&audience=crates.io "));
+        Ok(())
+    }
+}
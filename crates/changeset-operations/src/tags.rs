@@ -0,0 +1,217 @@
+use changeset_project::{ProjectKind, ReleaseChannel, TagFormat};
+use semver::Version;
+
+/// Returns whether tags include the crate name for the given project layout.
+///
+/// Workspaces always use crate-prefixed tags so that packages cannot collide.
+pub(crate) fn uses_crate_prefix(kind: &ProjectKind, tag_format: TagFormat) -> bool {
+    match kind {
+        ProjectKind::SinglePackage => tag_format == TagFormat::CratePrefixed,
+        ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => true,
+    }
+}
+
+/// Renders and parses release tags: `[namespace]{package}@v{version}`, or
+/// `[namespace]v{version}` without the crate prefix.
+///
+/// The namespace is the tag namespace of the release channel the version was
+/// released on, such as `nightly/`, and is empty for plain tags.
+#[derive(Debug, Clone)]
+pub(crate) struct TagNaming {
+    use_crate_prefix: bool,
+    /// Non-empty namespaces recognised when parsing, longest first.
+    namespaces: Vec<String>,
+}
+
+impl TagNaming {
+    pub(crate) fn new(use_crate_prefix: bool) -> Self {
+        Self {
+            use_crate_prefix,
+            namespaces: Vec::new(),
+        }
+    }
+
+    /// Recognises the tag namespaces of `channels` when parsing.
+    pub(crate) fn with_channels(mut self, channels: &[ReleaseChannel]) -> Self {
+        self.namespaces = channels
+            .iter()
+            .map(ReleaseChannel::tag_namespace)
+            .filter(|namespace| !namespace.is_empty())
+            .map(str::to_string)
+            .collect();
+        self.namespaces
+            .sort_by_key(|namespace| std::cmp::Reverse(namespace.len()));
+        self.namespaces.dedup();
+        self
+    }
+
+    pub(crate) fn format(&self, namespace: &str, package: &str, version: &Version) -> String {
+        format!("{}v{version}", self.prefix(namespace, package))
+    }
+
+    /// Part of a [`format`](Self::format) tag before the `v` of the version.
+    pub(crate) fn prefix(&self, namespace: &str, package: &str) -> String {
+        if self.use_crate_prefix {
+            format!("{namespace}{package}@")
+        } else {
+            namespace.to_string()
+        }
+    }
+
+    /// Parses a tag name produced by [`format`](Self::format) under no
+    /// namespace or one of the recognised namespaces.
+    ///
+    /// Returns the package name (for crate-prefixed tags) and the version, or
+    /// `None` if the tag does not follow the expected format.
+    pub(crate) fn parse<'t>(&self, tag: &'t str) -> Option<(Option<&'t str>, Version)> {
+        self.namespaces
+            .iter()
+            .filter_map(|namespace| tag.strip_prefix(namespace.as_str()))
+            .chain(std::iter::once(tag))
+            .find_map(|tag| self.parse_plain(tag))
+    }
+
+    fn parse_plain<'t>(&self, tag: &'t str) -> Option<(Option<&'t str>, Version)> {
+        if self.use_crate_prefix {
+            let (package, version) = tag.rsplit_once("@v")?;
+            if package.is_empty() {
+                return None;
+            }
+            Some((Some(package), version.parse().ok()?))
+        } else {
+            let version = tag.strip_prefix('v')?;
+            Some((None, version.parse().ok()?))
+        }
+    }
+
+    /// Whether `tag` is the tag of `version` of `package`, in any recognised
+    /// namespace.
+    pub(crate) fn is_tag_of(&self, tag: &str, package: &str, version: &Version) -> bool {
+        self.parse(tag).is_some_and(|(name, tagged)| {
+            name.is_none_or(|name| name == package) && tagged == *version
+        })
+    }
+
+    /// Finds the highest tagged version of a package among the given tag
+    /// names, in any namespace.
+    pub(crate) fn latest_version(&self, tags: &[String], package: &str) -> Option<Version> {
+        tags.iter()
+            .filter_map(|tag| self.parse(tag))
+            .filter(|(name, _)| name.is_none_or(|n| n == package))
+            .map(|(_, version)| version)
+            .max()
+    }
+
+    /// Finds the highest tagged version of a package whose
+    /// `major.minor.patch` is below that of `version`, in any namespace.
+    pub(crate) fn previous_version(
+        &self,
+        tags: &[String],
+        package: &str,
+        version: &Version,
+    ) -> Option<Version> {
+        let base = (version.major, version.minor, version.patch);
+        tags.iter()
+            .filter_map(|tag| self.parse(tag))
+            .filter(|(name, _)| name.is_none_or(|n| n == package))
+            .map(|(_, version)| version)
+            .filter(|tagged| (tagged.major, tagged.minor, tagged.patch) < base)
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_and_parse_roundtrip_with_prefix() {
+        let naming = TagNaming::new(true);
+        let version = Version::new(1, 2, 3);
+        let tag = naming.format("", "my-crate", &version);
+
+        assert_eq!(tag, "my-crate@v1.2.3");
+        assert_eq!(naming.parse(&tag), Some((Some("my-crate"), version)));
+    }
+
+    #[test]
+    fn format_and_parse_roundtrip_without_prefix() {
+        let naming = TagNaming::new(false);
+        let version = Version::new(0, 4, 0);
+        let tag = naming.format("", "my-crate", &version);
+
+        assert_eq!(tag, "v0.4.0");
+        assert_eq!(naming.parse(&tag), Some((None, version)));
+    }
+
+    #[test]
+    fn format_and_parse_roundtrip_with_namespace() {
+        let naming = TagNaming::new(true).with_channels(&ReleaseChannel::builtin());
+        let version: Version = "1.3.0-nightly.1".parse().expect("version");
+        let tag = naming.format("nightly/", "my-crate", &version);
+
+        assert_eq!(tag, "nightly/my-crate@v1.3.0-nightly.1");
+        assert_eq!(
+            naming.parse(&tag),
+            Some((Some("my-crate"), version.clone()))
+        );
+
+        let naming = TagNaming::new(false).with_channels(&ReleaseChannel::builtin());
+        assert_eq!(
+            naming.parse("nightly/v1.3.0-nightly.1"),
+            Some((None, version))
+        );
+    }
+
+    #[test]
+    fn parse_tag_rejects_foreign_formats() {
+        assert!(TagNaming::new(false).parse("release-1").is_none());
+        assert!(TagNaming::new(true).parse("v1.0.0").is_none());
+        assert!(TagNaming::new(true).parse("@v1.0.0").is_none());
+        assert!(TagNaming::new(false).parse("nightly/v1.0.0").is_none());
+    }
+
+    #[test]
+    fn latest_tagged_version_picks_highest_for_package() {
+        let tags = vec![
+            "crate-a@v1.0.0".to_string(),
+            "crate-a@v1.2.0".to_string(),
+            "nightly/crate-a@v1.3.0-nightly.1".to_string(),
+            "crate-b@v3.0.0".to_string(),
+            "unrelated".to_string(),
+        ];
+        let naming = TagNaming::new(true);
+
+        assert_eq!(
+            naming.latest_version(&tags, "crate-a"),
+            Some(Version::new(1, 2, 0))
+        );
+        assert_eq!(naming.latest_version(&tags, "crate-c"), None);
+        assert_eq!(
+            naming
+                .with_channels(&ReleaseChannel::builtin())
+                .latest_version(&tags, "crate-a"),
+            Some("1.3.0-nightly.1".parse().expect("version"))
+        );
+    }
+
+    #[test]
+    fn previous_version_skips_prereleases_of_the_same_version() {
+        let tags = vec![
+            "v1.0.0".to_string(),
+            "v1.0.1-alpha.1".to_string(),
+            "v1.0.1-alpha.2".to_string(),
+            "v1.0.1".to_string(),
+        ];
+        let naming = TagNaming::new(false);
+
+        assert_eq!(
+            naming.previous_version(&tags, "my-crate", &Version::new(1, 0, 1)),
+            Some(Version::new(1, 0, 0))
+        );
+        assert_eq!(
+            naming.previous_version(&tags, "my-crate", &Version::new(1, 0, 0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use changeset_core::{BumpType, PackageInfo};
+
+use crate::Result;
+
+/// Infers the bump type a package's changes call for, e.g. from commit
+/// messages, a public API diff or the kind of files that changed.
+pub trait BumpInferenceProvider: Send + Sync {
+    /// Short identifier shown next to a suggestion, e.g. `commits`.
+    fn name(&self) -> &'static str;
+
+    /// Returns `None` when the provider has no opinion, e.g. for a package
+    /// that does not exist on the base revision.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inference cannot be run.
+    fn infer_bump(&self, project_root: &Path, package: &PackageInfo) -> Result<Option<BumpType>>;
+
+    /// Like [`infer_bump`](Self::infer_bump), with the name of the provider
+    /// that decided. Providers combining others return the inner name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inference cannot be run.
+    fn infer_bump_with_source(
+        &self,
+        project_root: &Path,
+        package: &PackageInfo,
+    ) -> Result<Option<(BumpType, &'static str)>> {
+        Ok(self
+            .infer_bump(project_root, package)?
+            .map(|bump| (bump, self.name())))
+    }
+}
@@ -0,0 +1,14 @@
+use std::path::Path;
+
+use changeset_git::FileChange;
+
+use crate::Result;
+
+/// Source of the files changed between two revisions.
+pub trait ChangedFilesProvider: Send + Sync {
+    /// # Errors
+    ///
+    /// Returns an error if the changed files cannot be determined.
+    fn changed_files(&self, project_root: &Path, base: &str, head: &str)
+    -> Result<Vec<FileChange>>;
+}
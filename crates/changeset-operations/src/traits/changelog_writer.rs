@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use changeset_changelog::{RepositoryInfo, VersionRelease};
+use changeset_changelog::{ArchivePolicy, RepositoryInfo, VersionRelease};
 
 use crate::Result;
 
@@ -10,6 +10,23 @@ pub struct ChangelogWriteResult {
     pub created: bool,
 }
 
+/// A release entry to add to a changelog as part of a batch write.
+#[derive(Debug, Clone)]
+pub struct PlannedChangelogWrite {
+    pub path: PathBuf,
+    pub release: VersionRelease,
+    pub repo_info: Option<RepositoryInfo>,
+    pub previous_version: Option<String>,
+}
+
+/// An archive file written when rotating a changelog.
+#[derive(Debug, Clone)]
+pub struct ChangelogArchive {
+    pub path: PathBuf,
+    /// Content before the rotation; `None` if the archive was created.
+    pub original_content: Option<String>,
+}
+
 pub trait ChangelogWriter: Send + Sync {
     /// # Errors
     ///
@@ -22,6 +39,43 @@ pub trait ChangelogWriter: Send + Sync {
         previous_version: Option<&str>,
     ) -> Result<ChangelogWriteResult>;
 
+    /// Writes several releases as one unit.
+    ///
+    /// New contents for every file are staged and validated before the first
+    /// write; if a later write fails, files already written are restored. Writes
+    /// to the same path are applied in order. Results follow the order of
+    /// `writes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a changelog cannot be read, a target cannot be
+    /// written, or a write fails.
+    fn write_releases(&self, writes: &[PlannedChangelogWrite])
+    -> Result<Vec<ChangelogWriteResult>>;
+
+    /// Adds the entries of `release` to the changelog section of its version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::ReleaseSectionNotFound` if the changelog has no
+    /// section for the version, or an error if it cannot be read or written.
+    fn extend_release(&self, changelog_path: &Path, release: &VersionRelease) -> Result<()>;
+
+    /// Moves the release sections `policy` archives from the changelog at
+    /// `changelog_path` into archive files next to it.
+    ///
+    /// Archives are written before the changelog; if a write fails, files
+    /// already written are restored. Returns the archives that were written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a changelog or archive cannot be read or written.
+    fn rotate_changelog(
+        &self,
+        changelog_path: &Path,
+        policy: &ArchivePolicy,
+    ) -> Result<Vec<ChangelogArchive>>;
+
     fn changelog_exists(&self, path: &Path) -> bool;
 
     /// # Errors
@@ -23,8 +23,12 @@
 //!
 //! 5. **Deletion**: After a stable release, all changeset files (both previously consumed
 //!    and newly processed) are deleted, completing the lifecycle.
+//!
+//! With `consumed-state = "file"` the consumption state is kept in `consumed.toml`
+//! instead of the front matter; see [`OutOfBandChangesetIO`](crate::providers::OutOfBandChangesetIO).
 
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use changeset_core::Changeset;
 use semver::Version;
@@ -49,6 +53,13 @@ pub trait ChangesetReader: Send + Sync {
     ///
     /// Returns an error if the directory cannot be read.
     fn list_consumed_changesets(&self, changeset_dir: &Path) -> Result<Vec<PathBuf>>;
+
+    /// When the changeset at `path` was last modified, if known.
+    ///
+    /// Used to order changelog entries by timestamp.
+    fn changeset_timestamp(&self, _path: &Path) -> Option<SystemTime> {
+        None
+    }
 }
 
 pub trait ChangesetWriter: Send + Sync {
@@ -68,6 +79,14 @@ pub trait ChangesetWriter: Send + Sync {
     /// Returns an error if the changeset cannot be serialized or written.
     fn restore_changeset(&self, path: &Path, changeset: &Changeset) -> Result<()>;
 
+    /// Removes a changeset file written by `write_changeset`, so that a batch
+    /// of new changesets can be rolled back when one of them fails to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be removed.
+    fn delete_changeset(&self, path: &Path) -> Result<()>;
+
     #[must_use]
     fn filename_exists(&self, changeset_dir: &Path, filename: &str) -> bool;
 
@@ -85,4 +104,18 @@ pub trait ChangesetWriter: Send + Sync {
     ///
     /// Returns an error if changesets cannot be read, parsed, or written.
     fn clear_consumed_for_prerelease(&self, changeset_dir: &Path, paths: &[&Path]) -> Result<()>;
+
+    /// The file the consumed marks are written to, when they are kept apart
+    /// from the changeset files.
+    fn consumed_state_file(&self) -> Option<PathBuf> {
+        None
+    }
 }
+
+/// A changeset store that can be both read and written.
+///
+/// Implemented for every type implementing both traits, so that readers and
+/// writers can be combined into a single trait object: `Arc<dyn ChangesetIO>`.
+pub trait ChangesetIO: ChangesetReader + ChangesetWriter {}
+
+impl<T: ChangesetReader + ChangesetWriter + ?Sized> ChangesetIO for T {}
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use crate::Result;
+
+/// Result of running a shell command to completion.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOutput {
+    pub success: bool,
+    /// Exit code, if the process exited normally.
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CommandOutput {
+    /// Describes how the command ended, e.g. `exit code 1`.
+    #[must_use]
+    pub fn status(&self) -> String {
+        self.code.map_or_else(
+            || "terminated by a signal".to_string(),
+            |code| format!("exit code {code}"),
+        )
+    }
+
+    /// The non-empty output streams, stdout first.
+    #[must_use]
+    pub fn captured(&self) -> String {
+        [self.stdout.trim(), self.stderr.trim()]
+            .into_iter()
+            .filter(|stream| !stream.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Runs shell commands such as the configured release pre-checks.
+pub trait CommandRunner: Send + Sync {
+    /// Runs `command` through the platform shell with `dir` as working directory.
+    ///
+    /// A command that runs but exits unsuccessfully is not an error; inspect
+    /// [`CommandOutput::success`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be started.
+    fn run(&self, dir: &Path, command: &str) -> Result<CommandOutput>;
+}
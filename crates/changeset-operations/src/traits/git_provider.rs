@@ -1,6 +1,7 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use changeset_git::{CommitInfo, FileChange, TagInfo};
+use changeset_git::{CommitIdentity, CommitInfo, FileChange, Identity, TagInfo};
+use chrono::{DateTime, Utc};
 
 use crate::Result;
 
@@ -11,10 +12,66 @@ pub trait GitProvider: Send + Sync {
     fn changed_files(&self, project_root: &Path, base: &str, head: &str)
     -> Result<Vec<FileChange>>;
 
+    /// Returns the files staged in the index relative to HEAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or the index cannot be read.
+    fn staged_changes(&self, project_root: &Path) -> Result<Vec<FileChange>>;
+
+    /// Returns the directory git reads hook scripts from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or its configuration
+    /// cannot be read.
+    fn hooks_dir(&self, project_root: &Path) -> Result<PathBuf>;
+
+    /// Returns the working tree root of the repository containing `project_root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened.
+    fn repository_root(&self, project_root: &Path) -> Result<PathBuf>;
+
+    /// Sets `key` to `value` in the repository's local git configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or its configuration
+    /// cannot be written.
+    fn set_config(&self, project_root: &Path, key: &str, value: &str) -> Result<()>;
+
+    /// With `ignore_submodules`, changes inside submodules and moved submodule
+    /// commits do not make the tree dirty.
+    ///
     /// # Errors
     ///
     /// Returns an error if the repository cannot be opened or status check fails.
-    fn is_working_tree_clean(&self, project_root: &Path) -> Result<bool>;
+    fn is_working_tree_clean(&self, project_root: &Path, ignore_submodules: bool) -> Result<bool>;
+
+    /// Lists modified, staged and untracked files relative to the repository root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or status check fails.
+    fn dirty_files(&self, project_root: &Path, ignore_submodules: bool) -> Result<Vec<PathBuf>>;
+
+    /// Stashes all local changes, including untracked files.
+    ///
+    /// Returns `Ok(false)` when there was nothing to stash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or the stash cannot be saved.
+    fn stash_push(&self, project_root: &Path, message: &str) -> Result<bool>;
+
+    /// Restores and drops the most recent stash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no stash or applying it conflicts with the working tree.
+    fn stash_pop(&self, project_root: &Path) -> Result<()>;
 
     /// # Errors
     ///
@@ -26,20 +83,179 @@ pub trait GitProvider: Send + Sync {
     /// Returns an error if staging any of the files fails.
     fn stage_files(&self, project_root: &Path, paths: &[&Path]) -> Result<()>;
 
+    /// Returns the subset of `paths` matched by the repository's ignore rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or its ignore rules
+    /// cannot be read.
+    fn ignored_paths(&self, project_root: &Path, paths: &[PathBuf]) -> Result<Vec<PathBuf>>;
+
+    /// Commits the index; unset parts of `identity` fall back to the user's git config.
+    ///
     /// # Errors
     ///
     /// Returns an error if the commit cannot be created.
-    fn commit(&self, project_root: &Path, message: &str) -> Result<CommitInfo>;
+    fn commit(
+        &self,
+        project_root: &Path,
+        message: &str,
+        identity: &CommitIdentity,
+    ) -> Result<CommitInfo>;
 
     /// # Errors
     ///
     /// Returns an error if the tag cannot be created or already exists.
     fn create_tag(&self, project_root: &Path, tag_name: &str, message: &str) -> Result<TagInfo>;
 
+    /// Creates an annotated tag pointing at the commit `target_sha` instead of HEAD.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the repository cannot be opened.
-    fn remote_url(&self, project_root: &Path) -> Result<Option<String>>;
+    /// Returns an error if the target is not a commit, or the tag cannot be
+    /// created or already exists.
+    fn create_tag_at(
+        &self,
+        project_root: &Path,
+        tag_name: &str,
+        message: &str,
+        target_sha: &str,
+    ) -> Result<TagInfo>;
+
+    /// Fetches the upstream of the current branch and reports whether the local
+    /// branch contains every upstream commit. Returns `Ok(true)` when no upstream
+    /// is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened, HEAD is detached,
+    /// or the fetch fails.
+    fn is_up_to_date(&self, project_root: &Path) -> Result<bool>;
+
+    /// Returns the names of all tags in the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or tags cannot be listed.
+    fn list_tags(&self, project_root: &Path) -> Result<Vec<String>>;
+
+    /// Returns the names of the tags pointing at the HEAD commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or the tags cannot be resolved.
+    fn tags_at_head(&self, project_root: &Path) -> Result<Vec<String>>;
+
+    /// Returns when `path` was last added in the history of HEAD, or `None` if
+    /// it has never been committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or its history cannot be read.
+    fn file_added_date(&self, project_root: &Path, path: &Path) -> Result<Option<DateTime<Utc>>>;
+
+    /// Returns the dates of the distinct commits that tags point at, one per release.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or a tag cannot be resolved.
+    fn release_dates(&self, project_root: &Path) -> Result<Vec<DateTime<Utc>>>;
+
+    /// Returns the contents of `path` as of `reference`, or `None` if the file
+    /// does not exist at that revision.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened, the reference cannot
+    /// be resolved, or the file cannot be read.
+    fn file_at_ref(
+        &self,
+        project_root: &Path,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Option<String>>;
+
+    /// Messages of the commits in `base..head`, newest first. With `path`,
+    /// only commits that changed something under it are listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened, a reference cannot
+    /// be resolved, or the history cannot be walked.
+    fn commit_messages(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+        path: Option<&Path>,
+    ) -> Result<Vec<String>>;
+
+    /// Files under `path` deleted by the commits in `base..head`, with their
+    /// content before the deletion, newest first. Without `base`, the whole
+    /// history of `head` is searched. Paths are relative to the repository
+    /// root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened, a reference cannot
+    /// be resolved, or the history cannot be walked.
+    fn deleted_file_contents(
+        &self,
+        project_root: &Path,
+        base: Option<&str>,
+        head: &str,
+        path: &Path,
+    ) -> Result<Vec<(PathBuf, String)>>;
+
+    /// Reports whether a remote-tracking branch already contains the HEAD
+    /// commit, or any of `tags` exists on the remote.
+    ///
+    /// Remote-tracking branches reflect the remote as of the last fetch, so a
+    /// push from another clone since then is not seen; the tags are listed
+    /// from the remote itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened, the branches cannot
+    /// be read, or the remote cannot be reached to list its tags.
+    fn is_head_pushed(&self, project_root: &Path, tags: &[String]) -> Result<bool>;
+
+    /// Returns the SHA of the commit at HEAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or HEAD cannot be resolved.
+    fn head_sha(&self, project_root: &Path) -> Result<String>;
+
+    /// Replaces HEAD with a commit of the staged changes, keeping its message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or the commit cannot be created.
+    fn amend_commit(&self, project_root: &Path, committer: Option<&Identity>)
+    -> Result<CommitInfo>;
+
+    /// Points an existing tag at HEAD, annotated with `message` when given and
+    /// keeping its message otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag does not exist or cannot be replaced.
+    fn move_tag(
+        &self,
+        project_root: &Path,
+        tag_name: &str,
+        message: Option<&str>,
+    ) -> Result<TagInfo>;
+
+    /// Returns the URL of `remote`, or of the remote picked by fallback when it
+    /// is `None`: the upstream of the current branch, `origin`, `upstream`,
+    /// then the first remote.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or `remote` does not exist.
+    fn remote_url(&self, project_root: &Path, remote: Option<&str>) -> Result<Option<String>>;
 
     /// Deletes files from the filesystem and stages the deletions in git.
     ///
@@ -74,4 +290,11 @@ pub trait GitProvider: Send + Sync {
     /// - HEAD has no parent (initial commit)
     /// - The reset operation fails
     fn reset_to_parent(&self, project_root: &Path) -> Result<()>;
+
+    /// Performs a soft reset to the commit `sha`, keeping changes staged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sha` does not name a commit or the reset fails.
+    fn reset_soft_to(&self, project_root: &Path, sha: &str) -> Result<()>;
 }
@@ -5,6 +5,17 @@ use crate::Result;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ProjectContext {
     pub is_single_package: bool,
+    /// Defaults proposed from what the repository already uses.
+    pub suggestions: InitSuggestions,
+}
+
+/// Settings that match the existing repository, offered as the default
+/// answer in interactive prompts. `None` keeps the built-in default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InitSuggestions {
+    pub tag_format: Option<TagFormat>,
+    pub changelog: Option<ChangelogLocation>,
+    pub comparison_links: Option<ComparisonLinks>,
 }
 
 #[derive(Debug, Clone)]
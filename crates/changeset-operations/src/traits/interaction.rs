@@ -1,4 +1,4 @@
-use changeset_core::{BumpType, ChangeCategory, PackageInfo};
+use changeset_core::{BumpType, CategorySet, ChangeCategory, PackageInfo};
 
 use crate::Result;
 
@@ -20,6 +20,13 @@ pub enum CategorySelection {
     Cancelled,
 }
 
+#[derive(Debug, Clone)]
+pub enum ScopeSelection {
+    /// The chosen scope, or `None` when the user skipped an optional scope.
+    Selected(Option<String>),
+    Cancelled,
+}
+
 #[derive(Debug, Clone)]
 pub enum DescriptionInput {
     Provided(String),
@@ -32,15 +39,30 @@ pub trait InteractionProvider: Send + Sync {
     /// Returns an error if the interaction cannot be completed.
     fn select_packages(&self, available: &[PackageInfo]) -> Result<PackageSelection>;
 
+    /// Asks for a bump type, preselecting `suggested` when there is one.
+    ///
     /// # Errors
     ///
     /// Returns an error if the interaction cannot be completed.
-    fn select_bump_type(&self, package_name: &str) -> Result<BumpSelection>;
+    fn select_bump_type(
+        &self,
+        package_name: &str,
+        suggested: Option<BumpType>,
+    ) -> Result<BumpSelection>;
 
+    /// Offers the configured categories, preselecting the set's default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interaction cannot be completed.
+    fn select_category(&self, categories: &CategorySet) -> Result<CategorySelection>;
+
+    /// Offers the allowed scopes; an empty list means any scope may be typed in.
+    ///
     /// # Errors
     ///
     /// Returns an error if the interaction cannot be completed.
-    fn select_category(&self) -> Result<CategorySelection>;
+    fn select_scope(&self, allowed: &[String], required: bool) -> Result<ScopeSelection>;
 
     /// # Errors
     ///
@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use changeset_core::PackageInfo;
+use changeset_project::{GraduationState, PrereleaseState};
+use thiserror::Error;
+
+use crate::Result;
+
+/// What the state editor shows before asking for the next edit.
+#[derive(Debug, Clone, Copy)]
+pub struct ManageView<'a> {
+    pub packages: &'a [PackageInfo],
+    /// The changeset directory holding the state files, relative to the project root.
+    pub changeset_dir: &'a Path,
+    pub prerelease: &'a PrereleaseState,
+    pub graduation: &'a GraduationState,
+    /// `pre-release.toml` as saving would write it, or `None` if saving removes the file.
+    pub prerelease_toml: Option<&'a str>,
+    /// `graduation.toml` as saving would write it, or `None` if saving removes the file.
+    pub graduation_toml: Option<&'a str>,
+    pub has_unsaved_changes: bool,
+}
+
+/// An edit chosen in the state editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManageAction {
+    AddPrerelease {
+        package: String,
+        tag: String,
+    },
+    RemovePrerelease {
+        package: String,
+    },
+    AddGraduation {
+        package: String,
+    },
+    RemoveGraduation {
+        package: String,
+    },
+    /// Writes the edited state files and ends the session.
+    Save,
+    /// Ends the session without writing anything.
+    Discard,
+}
+
+/// Why the state editor did not apply an edit.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ManageRejection {
+    #[error("package '{name}' not found")]
+    PackageNotFound { name: String },
+
+    #[error("invalid pre-release tag '{tag}': {reason}")]
+    InvalidPrereleaseTag { tag: String, reason: String },
+
+    #[error("cannot graduate package '{package}' with prerelease version '{version}'")]
+    CannotGraduatePrerelease { package: String, version: String },
+
+    #[error("cannot graduate package '{package}' with stable version '{version}' (>= 1.0.0)")]
+    CannotGraduateStable { package: String, version: String },
+
+    #[error("package '{name}' is not in {file}")]
+    NotListed { name: String, file: &'static str },
+}
+
+pub trait ManageInteractionProvider: Send + Sync {
+    /// Shows `view` and asks for the next edit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interaction cannot be completed.
+    fn next_action(&self, view: &ManageView<'_>) -> Result<ManageAction>;
+
+    /// Tells the user why the last edit was not applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interaction cannot be completed.
+    fn report_rejection(&self, rejection: &ManageRejection) -> Result<()>;
+}
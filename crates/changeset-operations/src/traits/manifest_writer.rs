@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use changeset_manifest::{InitConfig, MetadataSection};
+use changeset_manifest::{DeprecatedKey, ExistingMetadata, InitConfig, MetadataSection};
 use semver::Version;
 
 use super::inherited_version_checker::InheritedVersionChecker;
@@ -48,8 +48,40 @@ pub trait ManifestWriter: InheritedVersionChecker + Send + Sync {
         config: &InitConfig,
     ) -> Result<()>;
 
+    /// Reads the changeset configuration currently in the metadata section.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read or parsed.
+    fn read_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<ExistingMetadata>;
+
+    /// Renames deprecated option names in the metadata section, returning the renamed keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read, parsed, or written.
+    fn upgrade_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<Vec<DeprecatedKey>>;
+
+    /// Adds `member` to the `workspace.members` list of a root manifest,
+    /// returning `false` if it was listed already.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read, parsed, or written, or
+    /// has no `[workspace]` table.
+    fn add_workspace_member(&self, manifest_path: &Path, member: &str) -> Result<bool>;
+
     /// Updates the version constraint for a dependency in all relevant sections
-    /// of a Cargo.toml file.
+    /// of a Cargo.toml file, including target-specific ones. With
+    /// `skip_optional`, optional dependencies are left unchanged.
     ///
     /// # Errors
     ///
@@ -59,5 +91,6 @@ pub trait ManifestWriter: InheritedVersionChecker + Send + Sync {
         manifest_path: &Path,
         dependency_name: &str,
         new_version: &Version,
+        skip_optional: bool,
     ) -> Result<bool>;
 }
@@ -1,24 +1,48 @@
+mod bump_inference;
+mod changed_files_provider;
 mod changelog_writer;
 mod changeset_io;
+mod command_runner;
 mod git_provider;
 mod inherited_version_checker;
 mod init_interaction;
 mod interaction;
+mod manage_interaction;
 mod manifest_writer;
+mod notifier;
 mod project_provider;
+mod publisher;
+mod registry_index;
 mod release_state_io;
+mod shared;
+mod text_file_io;
+mod token_provider;
 
-pub use changelog_writer::{ChangelogWriteResult, ChangelogWriter};
-pub use changeset_io::{ChangesetReader, ChangesetWriter};
+pub use bump_inference::BumpInferenceProvider;
+pub use changed_files_provider::ChangedFilesProvider;
+pub use changelog_writer::{
+    ChangelogArchive, ChangelogWriteResult, ChangelogWriter, PlannedChangelogWrite,
+};
+pub use changeset_io::{ChangesetIO, ChangesetReader, ChangesetWriter};
+pub use command_runner::{CommandOutput, CommandRunner};
 pub use git_provider::GitProvider;
 pub use inherited_version_checker::InheritedVersionChecker;
 pub use init_interaction::{
-    ChangelogSettingsInput, GitSettingsInput, InitInteractionProvider, ProjectContext,
-    VersionSettingsInput,
+    ChangelogSettingsInput, GitSettingsInput, InitInteractionProvider, InitSuggestions,
+    ProjectContext, VersionSettingsInput,
 };
 pub use interaction::{
     BumpSelection, CategorySelection, DescriptionInput, InteractionProvider, PackageSelection,
+    ScopeSelection,
+};
+pub use manage_interaction::{
+    ManageAction, ManageInteractionProvider, ManageRejection, ManageView,
 };
 pub use manifest_writer::ManifestWriter;
+pub use notifier::Notifier;
 pub use project_provider::ProjectProvider;
-pub use release_state_io::ReleaseStateIO;
+pub use publisher::Publisher;
+pub use registry_index::RegistryIndex;
+pub use release_state_io::{OutdatedStateFile, ReleaseStateIO};
+pub use text_file_io::TextFileIO;
+pub use token_provider::TokenProvider;
@@ -0,0 +1,10 @@
+use crate::Result;
+
+pub trait Notifier: Send + Sync {
+    /// Posts a JSON payload to a webhook URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent or the endpoint rejects it.
+    fn post_json(&self, url: &str, body: &str) -> Result<()>;
+}
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use changeset_project::{CargoProject, PackageChangesetConfig, RootChangesetConfig};
+use changeset_project::{
+    CargoProject, FileMapping, OwnedChangesetDir, PackageChangesetConfig, RootChangesetConfig,
+    map_files_to_packages,
+};
 
 use crate::Result;
 
@@ -27,4 +30,26 @@ pub trait ProjectProvider: Send + Sync {
         project: &CargoProject,
         config: &RootChangesetConfig,
     ) -> Result<PathBuf>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the owned changeset directory cannot be created.
+    fn ensure_owned_changeset_dir(
+        &self,
+        project: &CargoProject,
+        dir: &OwnedChangesetDir,
+    ) -> Result<PathBuf>;
+
+    /// Assigns changed files to the packages that own them.
+    ///
+    /// Implementations may cache assignments; the default maps every file afresh.
+    fn map_files(
+        &self,
+        project: &CargoProject,
+        changed_files: &[PathBuf],
+        root_config: &RootChangesetConfig,
+        package_configs: &HashMap<String, PackageChangesetConfig>,
+    ) -> FileMapping {
+        map_files_to_packages(project, changed_files, root_config, package_configs)
+    }
 }
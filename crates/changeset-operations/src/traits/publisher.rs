@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use crate::Result;
+use crate::traits::CommandOutput;
+
+/// Uploads packages to a registry.
+pub trait Publisher: Send + Sync {
+    /// Publishes `package` from the workspace at `root`.
+    ///
+    /// Authenticates with `token` when given, and otherwise with whatever
+    /// credentials cargo finds itself. A publish that runs but fails is not an
+    /// error; inspect [`CommandOutput::success`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the publish cannot be started.
+    fn publish(
+        &self,
+        root: &Path,
+        package: &str,
+        token: Option<&str>,
+        dry_run: bool,
+    ) -> Result<CommandOutput>;
+}
@@ -0,0 +1,13 @@
+use semver::Version;
+
+use crate::Result;
+
+/// Looks up the versions a registry serves.
+pub trait RegistryIndex: Send + Sync {
+    /// Whether the index lists `version` of `package`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be queried.
+    fn has_version(&self, package: &str, version: &Version) -> Result<bool>;
+}
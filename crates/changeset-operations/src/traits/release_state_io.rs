@@ -1,14 +1,25 @@
 use std::path::Path;
 
-use changeset_project::{GraduationState, PrereleaseState};
+use changeset_project::{GraduationState, PrereleaseState, StateFile};
 
 use crate::Result;
 
+/// A state file stored in an older layout than the current release writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutdatedStateFile {
+    pub file: StateFile,
+    /// The `config_version` found on disk.
+    pub version: u32,
+}
+
 /// Reads and writes release state configuration files.
 ///
 /// This trait handles persistence of release management state:
 /// - `pre-release.toml`: Maps crate names to prerelease tags
 /// - `graduation.toml`: Lists crates queued for 0.x -> 1.0.0 graduation
+///
+/// Files in an older layout are upgraded in memory on load. They are only
+/// rewritten when saved or explicitly migrated.
 pub trait ReleaseStateIO: Send + Sync {
     /// Loads prerelease state from `.changeset/pre-release.toml`.
     /// Returns `Ok(None)` if the file doesn't exist.
@@ -41,4 +52,19 @@ pub trait ReleaseStateIO: Send + Sync {
     ///
     /// Returns an error if the file cannot be written or deleted.
     fn save_graduation_state(&self, changeset_dir: &Path, state: &GraduationState) -> Result<()>;
+
+    /// Lists state files stored in an older layout, without modifying them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file exists but cannot be read or parsed.
+    fn outdated_state_files(&self, changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>>;
+
+    /// Rewrites state files stored in an older layout in the current layout,
+    /// returning the files that were migrated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read, parsed, or written.
+    fn migrate_state_files(&self, changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>>;
 }
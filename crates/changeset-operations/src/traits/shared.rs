@@ -0,0 +1,502 @@
+//! Forwarding implementations for shared providers.
+//!
+//! Every provider trait is implemented for `Arc<T>`, including `Arc<dyn Trait>`,
+//! so operations can be assembled at runtime from trait objects (see
+//! [`DynReleaseOperation`](crate::operations::DynReleaseOperation)) as well as
+//! from concrete types.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use changeset_changelog::{ArchivePolicy, RepositoryInfo, VersionRelease};
+use changeset_core::{Changeset, PackageInfo};
+use changeset_git::{CommitIdentity, CommitInfo, FileChange, Identity, TagInfo};
+use changeset_manifest::{DeprecatedKey, ExistingMetadata, InitConfig, MetadataSection};
+use changeset_project::{
+    CargoProject, FileMapping, GraduationState, OwnedChangesetDir, PackageChangesetConfig,
+    PrereleaseState, RootChangesetConfig,
+};
+use chrono::{DateTime, Utc};
+use semver::Version;
+
+use super::{
+    ChangedFilesProvider, ChangelogArchive, ChangelogWriteResult, ChangelogWriter, ChangesetReader,
+    ChangesetWriter, CommandOutput, CommandRunner, GitProvider, InheritedVersionChecker,
+    ManageAction, ManageInteractionProvider, ManageRejection, ManageView, ManifestWriter,
+    OutdatedStateFile, PlannedChangelogWrite, ProjectProvider, Publisher, RegistryIndex,
+    ReleaseStateIO, TextFileIO,
+};
+use crate::Result;
+
+impl<T: ProjectProvider + ?Sized> ProjectProvider for Arc<T> {
+    fn discover_project(&self, start_path: &Path) -> Result<CargoProject> {
+        (**self).discover_project(start_path)
+    }
+
+    fn load_configs(
+        &self,
+        project: &CargoProject,
+    ) -> Result<(RootChangesetConfig, HashMap<String, PackageChangesetConfig>)> {
+        (**self).load_configs(project)
+    }
+
+    fn ensure_changeset_dir(
+        &self,
+        project: &CargoProject,
+        config: &RootChangesetConfig,
+    ) -> Result<PathBuf> {
+        (**self).ensure_changeset_dir(project, config)
+    }
+
+    fn ensure_owned_changeset_dir(
+        &self,
+        project: &CargoProject,
+        dir: &OwnedChangesetDir,
+    ) -> Result<PathBuf> {
+        (**self).ensure_owned_changeset_dir(project, dir)
+    }
+
+    fn map_files(
+        &self,
+        project: &CargoProject,
+        changed_files: &[PathBuf],
+        root_config: &RootChangesetConfig,
+        package_configs: &HashMap<String, PackageChangesetConfig>,
+    ) -> FileMapping {
+        (**self).map_files(project, changed_files, root_config, package_configs)
+    }
+}
+
+impl<T: ChangesetReader + ?Sized> ChangesetReader for Arc<T> {
+    fn read_changeset(&self, path: &Path) -> Result<Changeset> {
+        (**self).read_changeset(path)
+    }
+
+    fn list_changesets(&self, changeset_dir: &Path) -> Result<Vec<PathBuf>> {
+        (**self).list_changesets(changeset_dir)
+    }
+
+    fn list_consumed_changesets(&self, changeset_dir: &Path) -> Result<Vec<PathBuf>> {
+        (**self).list_consumed_changesets(changeset_dir)
+    }
+
+    fn changeset_timestamp(&self, path: &Path) -> Option<SystemTime> {
+        (**self).changeset_timestamp(path)
+    }
+}
+
+impl<T: ChangesetWriter + ?Sized> ChangesetWriter for Arc<T> {
+    fn write_changeset(&self, changeset_dir: &Path, changeset: &Changeset) -> Result<String> {
+        (**self).write_changeset(changeset_dir, changeset)
+    }
+
+    fn restore_changeset(&self, path: &Path, changeset: &Changeset) -> Result<()> {
+        (**self).restore_changeset(path, changeset)
+    }
+
+    fn delete_changeset(&self, path: &Path) -> Result<()> {
+        (**self).delete_changeset(path)
+    }
+
+    fn filename_exists(&self, changeset_dir: &Path, filename: &str) -> bool {
+        (**self).filename_exists(changeset_dir, filename)
+    }
+
+    fn mark_consumed_for_prerelease(
+        &self,
+        changeset_dir: &Path,
+        paths: &[&Path],
+        version: &Version,
+    ) -> Result<()> {
+        (**self).mark_consumed_for_prerelease(changeset_dir, paths, version)
+    }
+
+    fn clear_consumed_for_prerelease(&self, changeset_dir: &Path, paths: &[&Path]) -> Result<()> {
+        (**self).clear_consumed_for_prerelease(changeset_dir, paths)
+    }
+
+    fn consumed_state_file(&self) -> Option<PathBuf> {
+        (**self).consumed_state_file()
+    }
+}
+
+impl<T: InheritedVersionChecker + ?Sized> InheritedVersionChecker for Arc<T> {
+    fn has_inherited_version(&self, manifest_path: &Path) -> Result<bool> {
+        (**self).has_inherited_version(manifest_path)
+    }
+
+    fn find_packages_with_inherited_versions(
+        &self,
+        packages: &[PackageInfo],
+    ) -> Result<Vec<String>> {
+        (**self).find_packages_with_inherited_versions(packages)
+    }
+}
+
+impl<T: ManifestWriter + ?Sized> ManifestWriter for Arc<T> {
+    fn write_version(&self, manifest_path: &Path, new_version: &Version) -> Result<()> {
+        (**self).write_version(manifest_path, new_version)
+    }
+
+    fn remove_workspace_version(&self, manifest_path: &Path) -> Result<()> {
+        (**self).remove_workspace_version(manifest_path)
+    }
+
+    fn read_workspace_version(&self, manifest_path: &Path) -> Result<Option<Version>> {
+        (**self).read_workspace_version(manifest_path)
+    }
+
+    fn write_workspace_version(&self, manifest_path: &Path, version: &Version) -> Result<()> {
+        (**self).write_workspace_version(manifest_path, version)
+    }
+
+    fn verify_version(&self, manifest_path: &Path, expected: &Version) -> Result<()> {
+        (**self).verify_version(manifest_path, expected)
+    }
+
+    fn write_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+        config: &InitConfig,
+    ) -> Result<()> {
+        (**self).write_metadata(manifest_path, section, config)
+    }
+
+    fn read_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<ExistingMetadata> {
+        (**self).read_metadata(manifest_path, section)
+    }
+
+    fn upgrade_metadata(
+        &self,
+        manifest_path: &Path,
+        section: MetadataSection,
+    ) -> Result<Vec<DeprecatedKey>> {
+        (**self).upgrade_metadata(manifest_path, section)
+    }
+
+    fn add_workspace_member(&self, manifest_path: &Path, member: &str) -> Result<bool> {
+        (**self).add_workspace_member(manifest_path, member)
+    }
+
+    fn update_dependency_version(
+        &self,
+        manifest_path: &Path,
+        dependency_name: &str,
+        new_version: &Version,
+        skip_optional: bool,
+    ) -> Result<bool> {
+        (**self).update_dependency_version(
+            manifest_path,
+            dependency_name,
+            new_version,
+            skip_optional,
+        )
+    }
+}
+
+impl<T: ChangelogWriter + ?Sized> ChangelogWriter for Arc<T> {
+    fn write_release(
+        &self,
+        changelog_path: &Path,
+        release: &VersionRelease,
+        repo_info: Option<&RepositoryInfo>,
+        previous_version: Option<&str>,
+    ) -> Result<ChangelogWriteResult> {
+        (**self).write_release(changelog_path, release, repo_info, previous_version)
+    }
+
+    fn write_releases(
+        &self,
+        writes: &[PlannedChangelogWrite],
+    ) -> Result<Vec<ChangelogWriteResult>> {
+        (**self).write_releases(writes)
+    }
+
+    fn extend_release(&self, changelog_path: &Path, release: &VersionRelease) -> Result<()> {
+        (**self).extend_release(changelog_path, release)
+    }
+
+    fn rotate_changelog(
+        &self,
+        changelog_path: &Path,
+        policy: &ArchivePolicy,
+    ) -> Result<Vec<ChangelogArchive>> {
+        (**self).rotate_changelog(changelog_path, policy)
+    }
+
+    fn changelog_exists(&self, path: &Path) -> bool {
+        (**self).changelog_exists(path)
+    }
+
+    fn restore_changelog(&self, path: &Path, content: &str) -> Result<()> {
+        (**self).restore_changelog(path, content)
+    }
+
+    fn delete_changelog(&self, path: &Path) -> Result<()> {
+        (**self).delete_changelog(path)
+    }
+}
+
+impl<T: ReleaseStateIO + ?Sized> ReleaseStateIO for Arc<T> {
+    fn load_prerelease_state(&self, changeset_dir: &Path) -> Result<Option<PrereleaseState>> {
+        (**self).load_prerelease_state(changeset_dir)
+    }
+
+    fn save_prerelease_state(&self, changeset_dir: &Path, state: &PrereleaseState) -> Result<()> {
+        (**self).save_prerelease_state(changeset_dir, state)
+    }
+
+    fn load_graduation_state(&self, changeset_dir: &Path) -> Result<Option<GraduationState>> {
+        (**self).load_graduation_state(changeset_dir)
+    }
+
+    fn save_graduation_state(&self, changeset_dir: &Path, state: &GraduationState) -> Result<()> {
+        (**self).save_graduation_state(changeset_dir, state)
+    }
+
+    fn outdated_state_files(&self, changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>> {
+        (**self).outdated_state_files(changeset_dir)
+    }
+
+    fn migrate_state_files(&self, changeset_dir: &Path) -> Result<Vec<OutdatedStateFile>> {
+        (**self).migrate_state_files(changeset_dir)
+    }
+}
+
+impl<T: ManageInteractionProvider + ?Sized> ManageInteractionProvider for Arc<T> {
+    fn next_action(&self, view: &ManageView<'_>) -> Result<ManageAction> {
+        (**self).next_action(view)
+    }
+
+    fn report_rejection(&self, rejection: &ManageRejection) -> Result<()> {
+        (**self).report_rejection(rejection)
+    }
+}
+
+impl<T: ChangedFilesProvider + ?Sized> ChangedFilesProvider for Arc<T> {
+    fn changed_files(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<FileChange>> {
+        (**self).changed_files(project_root, base, head)
+    }
+}
+
+impl<T: CommandRunner + ?Sized> CommandRunner for Arc<T> {
+    fn run(&self, dir: &Path, command: &str) -> Result<CommandOutput> {
+        (**self).run(dir, command)
+    }
+}
+
+impl<T: Publisher + ?Sized> Publisher for Arc<T> {
+    fn publish(
+        &self,
+        root: &Path,
+        package: &str,
+        token: Option<&str>,
+        dry_run: bool,
+    ) -> Result<CommandOutput> {
+        (**self).publish(root, package, token, dry_run)
+    }
+}
+
+impl<T: RegistryIndex + ?Sized> RegistryIndex for Arc<T> {
+    fn has_version(&self, package: &str, version: &Version) -> Result<bool> {
+        (**self).has_version(package, version)
+    }
+}
+
+impl<T: TextFileIO + ?Sized> TextFileIO for Arc<T> {
+    fn find_files(&self, root: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+        (**self).find_files(root, pattern)
+    }
+
+    fn read_file(&self, path: &Path) -> Result<String> {
+        (**self).read_file(path)
+    }
+
+    fn write_file(&self, path: &Path, content: &str) -> Result<()> {
+        (**self).write_file(path, content)
+    }
+}
+
+impl<T: GitProvider + ?Sized> GitProvider for Arc<T> {
+    fn changed_files(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<FileChange>> {
+        (**self).changed_files(project_root, base, head)
+    }
+
+    fn staged_changes(&self, project_root: &Path) -> Result<Vec<FileChange>> {
+        (**self).staged_changes(project_root)
+    }
+
+    fn hooks_dir(&self, project_root: &Path) -> Result<PathBuf> {
+        (**self).hooks_dir(project_root)
+    }
+
+    fn repository_root(&self, project_root: &Path) -> Result<PathBuf> {
+        (**self).repository_root(project_root)
+    }
+
+    fn set_config(&self, project_root: &Path, key: &str, value: &str) -> Result<()> {
+        (**self).set_config(project_root, key, value)
+    }
+
+    fn is_working_tree_clean(&self, project_root: &Path, ignore_submodules: bool) -> Result<bool> {
+        (**self).is_working_tree_clean(project_root, ignore_submodules)
+    }
+
+    fn dirty_files(&self, project_root: &Path, ignore_submodules: bool) -> Result<Vec<PathBuf>> {
+        (**self).dirty_files(project_root, ignore_submodules)
+    }
+
+    fn stash_push(&self, project_root: &Path, message: &str) -> Result<bool> {
+        (**self).stash_push(project_root, message)
+    }
+
+    fn stash_pop(&self, project_root: &Path) -> Result<()> {
+        (**self).stash_pop(project_root)
+    }
+
+    fn current_branch(&self, project_root: &Path) -> Result<String> {
+        (**self).current_branch(project_root)
+    }
+
+    fn stage_files(&self, project_root: &Path, paths: &[&Path]) -> Result<()> {
+        (**self).stage_files(project_root, paths)
+    }
+
+    fn ignored_paths(&self, project_root: &Path, paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        (**self).ignored_paths(project_root, paths)
+    }
+
+    fn commit(
+        &self,
+        project_root: &Path,
+        message: &str,
+        identity: &CommitIdentity,
+    ) -> Result<CommitInfo> {
+        (**self).commit(project_root, message, identity)
+    }
+
+    fn create_tag(&self, project_root: &Path, tag_name: &str, message: &str) -> Result<TagInfo> {
+        (**self).create_tag(project_root, tag_name, message)
+    }
+
+    fn create_tag_at(
+        &self,
+        project_root: &Path,
+        tag_name: &str,
+        message: &str,
+        target_sha: &str,
+    ) -> Result<TagInfo> {
+        (**self).create_tag_at(project_root, tag_name, message, target_sha)
+    }
+
+    fn is_up_to_date(&self, project_root: &Path) -> Result<bool> {
+        (**self).is_up_to_date(project_root)
+    }
+
+    fn list_tags(&self, project_root: &Path) -> Result<Vec<String>> {
+        (**self).list_tags(project_root)
+    }
+
+    fn tags_at_head(&self, project_root: &Path) -> Result<Vec<String>> {
+        (**self).tags_at_head(project_root)
+    }
+
+    fn file_added_date(&self, project_root: &Path, path: &Path) -> Result<Option<DateTime<Utc>>> {
+        (**self).file_added_date(project_root, path)
+    }
+
+    fn release_dates(&self, project_root: &Path) -> Result<Vec<DateTime<Utc>>> {
+        (**self).release_dates(project_root)
+    }
+
+    fn file_at_ref(
+        &self,
+        project_root: &Path,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Option<String>> {
+        (**self).file_at_ref(project_root, reference, path)
+    }
+
+    fn commit_messages(
+        &self,
+        project_root: &Path,
+        base: &str,
+        head: &str,
+        path: Option<&Path>,
+    ) -> Result<Vec<String>> {
+        (**self).commit_messages(project_root, base, head, path)
+    }
+
+    fn deleted_file_contents(
+        &self,
+        project_root: &Path,
+        base: Option<&str>,
+        head: &str,
+        path: &Path,
+    ) -> Result<Vec<(PathBuf, String)>> {
+        (**self).deleted_file_contents(project_root, base, head, path)
+    }
+
+    fn is_head_pushed(&self, project_root: &Path, tags: &[String]) -> Result<bool> {
+        (**self).is_head_pushed(project_root, tags)
+    }
+
+    fn head_sha(&self, project_root: &Path) -> Result<String> {
+        (**self).head_sha(project_root)
+    }
+
+    fn amend_commit(
+        &self,
+        project_root: &Path,
+        committer: Option<&Identity>,
+    ) -> Result<CommitInfo> {
+        (**self).amend_commit(project_root, committer)
+    }
+
+    fn move_tag(
+        &self,
+        project_root: &Path,
+        tag_name: &str,
+        message: Option<&str>,
+    ) -> Result<TagInfo> {
+        (**self).move_tag(project_root, tag_name, message)
+    }
+
+    fn remote_url(&self, project_root: &Path, remote: Option<&str>) -> Result<Option<String>> {
+        (**self).remote_url(project_root, remote)
+    }
+
+    fn delete_files(&self, project_root: &Path, paths: &[&Path]) -> Result<()> {
+        (**self).delete_files(project_root, paths)
+    }
+
+    fn delete_tag(&self, project_root: &Path, tag_name: &str) -> Result<bool> {
+        (**self).delete_tag(project_root, tag_name)
+    }
+
+    fn reset_to_parent(&self, project_root: &Path) -> Result<()> {
+        (**self).reset_to_parent(project_root)
+    }
+
+    fn reset_soft_to(&self, project_root: &Path, sha: &str) -> Result<()> {
+        (**self).reset_soft_to(project_root, sha)
+    }
+}
@@ -0,0 +1,26 @@
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// Reads and rewrites plain text files outside the Cargo manifests, such as
+/// the files stamped with release versions.
+pub trait TextFileIO: Send + Sync {
+    /// Lists the files below `root` whose path relative to `root` matches the
+    /// glob `pattern`, sorted by path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is invalid or a directory cannot be read.
+    fn find_files(&self, root: &Path, pattern: &str) -> Result<Vec<PathBuf>>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read as UTF-8 text.
+    fn read_file(&self, path: &Path) -> Result<String>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the file or its parent directories cannot be
+    /// written.
+    fn write_file(&self, path: &Path, content: &str) -> Result<()>;
+}
@@ -0,0 +1,11 @@
+use crate::Result;
+
+/// A source of credentials for forge and registry APIs.
+pub trait TokenProvider: Send + Sync {
+    /// Returns a token, or `None` if this source has none to offer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source holds a token but it cannot be read.
+    fn token(&self) -> Result<Option<String>>;
+}
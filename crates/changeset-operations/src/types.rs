@@ -1,5 +1,6 @@
 use changeset_core::{BumpType, PrereleaseSpec};
 use semver::Version;
+use serde::{Deserialize, Serialize};
 
 /// Represents a planned version change for a package.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,11 +11,105 @@ pub struct PackageVersion {
     pub bump_type: BumpType,
 }
 
+/// Origin of a bump that contributed to a package's release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BumpSource {
+    /// Position of the changeset among those passed to the planner; in release
+    /// output this indexes `changesets_consumed`.
+    Changeset(usize),
+    /// Requested with `--force-bump` rather than a changeset file.
+    Forced,
+}
+
+/// A single bump requested for a package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BumpContribution {
+    pub source: BumpSource,
+    pub bump_type: BumpType,
+}
+
+/// How the 0.x version policy changed a package's release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum ZeroVersionTransform {
+    /// `effective-minor` shifted the requested bump down one level.
+    Demoted { from: BumpType, to: BumpType },
+    /// `auto-promote-on-major` turned a major bump into 1.0.0.
+    PromotedToStable,
+    /// The package was explicitly graduated to 1.0.0.
+    Graduated,
+}
+
+/// Where a package's prerelease tag was configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PrereleaseSource {
+    /// `--prerelease <crate>:<tag>` on the command line.
+    #[default]
+    Cli,
+    /// `--prerelease <tag>` applied to every package.
+    CliGlobal,
+    /// The package's entry in `pre-release.toml`.
+    Toml,
+    /// The prerelease tag of the channel the package is released on.
+    Channel,
+}
+
+/// The prerelease tag applied to a package and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrereleaseProvenance {
+    pub tag: String,
+    pub source: PrereleaseSource,
+}
+
+/// Everything that decided a package's new version.
+///
+/// Shared by `release --explain`, the release audit trail and machine-readable
+/// output so they cannot drift apart.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageProvenance {
+    pub package: String,
+    /// Every requested bump, in changeset order followed by forced bumps.
+    pub contributions: Vec<BumpContribution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zero_version: Option<ZeroVersionTransform>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<PrereleaseProvenance>,
+}
+
+impl PackageProvenance {
+    /// The contribution that decided the bump: the first one with the highest bump type.
+    #[must_use]
+    pub fn winner(&self) -> Option<&BumpContribution> {
+        let max = self.contributions.iter().map(|c| c.bump_type).max()?;
+        self.contributions.iter().find(|c| c.bump_type == max)
+    }
+}
+
 /// Per-package release configuration from merged CLI + TOML sources.
 #[derive(Debug, Clone, Default)]
 pub struct PackageReleaseConfig {
     /// Prerelease tag for this package (e.g., "alpha", "beta")
     pub prerelease: Option<PrereleaseSpec>,
+    /// Where `prerelease` was configured; `None` is treated as the CLI.
+    pub prerelease_source: Option<PrereleaseSource>,
     /// Whether to graduate this 0.x package to 1.0.0
     pub graduate_zero: bool,
+    /// Bump applied without a changeset file (from `--force-bump`)
+    pub forced_bump: Option<BumpType>,
+}
+
+impl PackageReleaseConfig {
+    /// The configured prerelease tag together with where it came from.
+    #[must_use]
+    pub fn prerelease_provenance(&self) -> Option<PrereleaseProvenance> {
+        self.prerelease.as_ref().map(|spec| PrereleaseProvenance {
+            tag: spec.to_string(),
+            source: self.prerelease_source.unwrap_or_default(),
+        })
+    }
 }
@@ -1,11 +1,29 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use changeset_core::PackageInfo;
+use changeset_git::FileStatus;
 
 pub struct VerificationContext {
     pub affected_packages: Vec<PackageInfo>,
+    /// Affected packages that only depend on a changed package, each with
+    /// that package, under `transitive-coverage-depth`.
+    pub transitive_packages: BTreeMap<String, String>,
+    /// Changed files of each affected package.
+    pub package_files: BTreeMap<String, Vec<PathBuf>>,
     pub changeset_files: Vec<PathBuf>,
     pub deleted_changesets: Vec<PathBuf>,
+    /// Every changed changeset file, classified against the base revision.
+    pub changeset_changes: Vec<ChangesetChange>,
     pub project_files: Vec<PathBuf>,
     pub ignored_files: Vec<PathBuf>,
 }
+
+/// A changeset file touched by the change under verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangesetChange {
+    pub path: PathBuf,
+    pub status: FileStatus,
+    /// Whether the base revision of the file was already consumed by a pre-release.
+    pub consumed_at_base: bool,
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use super::rules::VerificationRule;
 use super::{VerificationContext, VerificationResult};
@@ -24,11 +24,22 @@ impl<'a> VerificationEngine<'a> {
     pub fn verify(&self, context: &VerificationContext) -> Result<VerificationResult> {
         let mut result = VerificationResult {
             affected_packages: context.affected_packages.clone(),
+            transitive_packages: context.transitive_packages.clone(),
             covered_packages: HashSet::new(),
             uncovered_packages: Vec::new(),
+            package_files: context.package_files.clone(),
+            suggested_bumps: BTreeMap::new(),
+            exempt_packages: Vec::new(),
             deleted_changesets: Vec::new(),
             project_files: context.project_files.clone(),
             ignored_files: context.ignored_files.clone(),
+            lint_failures: Vec::new(),
+            scope_failures: Vec::new(),
+            consumed_changeset_edits: Vec::new(),
+            missing_advisory: false,
+            uncovered_owners: BTreeMap::new(),
+            understated_bumps: Vec::new(),
+            duplicate_changesets: Vec::new(),
         };
 
         for rule in &self.rules {
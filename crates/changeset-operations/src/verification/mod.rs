@@ -3,6 +3,6 @@ mod engine;
 mod result;
 pub mod rules;
 
-pub use context::VerificationContext;
+pub use context::{ChangesetChange, VerificationContext};
 pub use engine::VerificationEngine;
-pub use result::VerificationResult;
+pub use result::{MissingChangeset, VerificationResult};
@@ -1,21 +1,120 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::path::PathBuf;
 
-use changeset_core::PackageInfo;
+use changeset_core::{BumpType, PackageInfo};
+use serde::{Serialize, Serializer};
 
-#[derive(Debug)]
+use super::rules::{DuplicateChangeset, DuplicateKind, ScopeIssue, UnderstatedBump};
+use crate::lint::LintIssue;
+
+/// Outcome of the verification rules.
+///
+/// Serializes to JSON for bots and dashboards; packages are listed by name.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VerificationResult {
+    #[serde(serialize_with = "package_names")]
     pub affected_packages: Vec<PackageInfo>,
+    /// Affected packages flagged through a changed dependency, each with that
+    /// dependency.
+    pub transitive_packages: BTreeMap<String, String>,
+    #[serde(serialize_with = "sorted")]
     pub covered_packages: HashSet<String>,
+    /// Listed with their changed files by [`Self::missing_changesets`].
+    #[serde(skip)]
     pub uncovered_packages: Vec<PackageInfo>,
+    /// Changed files of each affected package, relative to the repository root.
+    #[serde(skip)]
+    pub package_files: BTreeMap<String, Vec<PathBuf>>,
+    /// Bumps the configured bump inference suggests for uncovered packages.
+    #[serde(skip)]
+    pub suggested_bumps: BTreeMap<String, BumpType>,
+    /// Changed packages without a changeset that do not need one under their
+    /// `require-changeset` setting.
+    #[serde(serialize_with = "package_names")]
+    pub exempt_packages: Vec<PackageInfo>,
+    #[serde(rename = "deleted")]
     pub deleted_changesets: Vec<PathBuf>,
     pub project_files: Vec<PathBuf>,
     pub ignored_files: Vec<PathBuf>,
+    /// Changeset files whose summaries failed the lint pass.
+    pub lint_failures: Vec<(PathBuf, Vec<LintIssue>)>,
+    /// Changeset files with a missing or disallowed scope.
+    pub scope_failures: Vec<(PathBuf, ScopeIssue)>,
+    /// Changeset files that were already consumed by a pre-release but were edited.
+    pub consumed_changeset_edits: Vec<PathBuf>,
+    /// A security advisory was required, but no security changeset names one.
+    pub missing_advisory: bool,
+    /// Owners of uncovered packages, each with the packages they still need a
+    /// changeset for.
+    pub uncovered_owners: BTreeMap<String, Vec<String>>,
+    /// Covered packages whose changesets declare a smaller bump than bump
+    /// inference suggests. Informational only.
+    pub understated_bumps: Vec<UnderstatedBump>,
+    /// Changesets added by the change that duplicate another pending
+    /// changeset. Only name clashes fail verification; identical content is
+    /// reported but may be intended.
+    pub duplicate_changesets: Vec<DuplicateChangeset>,
+}
+
+/// An affected package that needs a changeset but has none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingChangeset {
+    pub package: String,
+    /// Changed files of the package, relative to the repository root. Empty
+    /// for packages flagged through a changed dependency.
+    pub changed_files: Vec<PathBuf>,
+    /// Bump the configured bump inference suggests, if any.
+    pub suggested_bump: Option<BumpType>,
+}
+
+fn package_names<S: Serializer>(
+    packages: &[PackageInfo],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(packages.iter().map(|package| &package.name))
+}
+
+fn sorted<S: Serializer>(names: &HashSet<String>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(names.iter().collect::<BTreeSet<_>>())
 }
 
 impl VerificationResult {
+    /// Number of duplicate changesets whose file name clashes with another.
+    #[must_use]
+    pub fn name_clashes(&self) -> usize {
+        self.duplicate_changesets
+            .iter()
+            .filter(|duplicate| duplicate.kind == DuplicateKind::SameName)
+            .count()
+    }
+
+    /// The uncovered packages with their changed files and suggested bumps.
+    #[must_use]
+    pub fn missing_changesets(&self) -> Vec<MissingChangeset> {
+        self.uncovered_packages
+            .iter()
+            .map(|package| MissingChangeset {
+                package: package.name.clone(),
+                changed_files: self
+                    .package_files
+                    .get(&package.name)
+                    .cloned()
+                    .unwrap_or_default(),
+                suggested_bump: self.suggested_bumps.get(&package.name).copied(),
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn is_success(&self) -> bool {
-        self.uncovered_packages.is_empty() && self.deleted_changesets.is_empty()
+        self.uncovered_packages.is_empty()
+            && self.deleted_changesets.is_empty()
+            && self.lint_failures.is_empty()
+            && self.scope_failures.is_empty()
+            && self.consumed_changeset_edits.is_empty()
+            && self.name_clashes() == 0
+            && !self.missing_advisory
     }
 }
@@ -0,0 +1,30 @@
+use changeset_core::ChangeCategory;
+
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+use crate::traits::ChangesetReader;
+
+/// Requires at least one added or modified changeset in the security category
+/// that names the advisory it resolves.
+pub struct AdvisoryRule<'a, R: ChangesetReader> {
+    reader: &'a R,
+}
+
+impl<'a, R: ChangesetReader> AdvisoryRule<'a, R> {
+    pub fn new(reader: &'a R) -> Self {
+        Self { reader }
+    }
+}
+
+impl<R: ChangesetReader> VerificationRule for AdvisoryRule<'_, R> {
+    fn check(&self, context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        for path in &context.changeset_files {
+            let changeset = self.reader.read_changeset(path)?;
+            if changeset.category == ChangeCategory::Security && changeset.advisory.is_some() {
+                return Ok(());
+            }
+        }
+        result.missing_advisory = true;
+        Ok(())
+    }
+}
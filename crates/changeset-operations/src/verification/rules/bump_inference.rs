@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use changeset_core::BumpType;
+use serde::Serialize;
+
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+use crate::traits::{BumpInferenceProvider, ChangesetReader};
+
+/// A package whose changesets declare a smaller bump than its changes
+/// suggest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnderstatedBump {
+    pub package: String,
+    /// Largest bump the changesets declare for the package.
+    pub declared: BumpType,
+    pub inferred: BumpType,
+    /// Name of the inference provider that suggested the bump.
+    pub provider: &'static str,
+}
+
+/// Compares the bumps declared for covered packages against a bump inference
+/// provider, and suggests bumps for packages missing a changeset. Findings
+/// are advisory and do not fail verification.
+pub struct BumpInferenceRule<'a, R: ChangesetReader> {
+    reader: &'a R,
+    inference: &'a dyn BumpInferenceProvider,
+    project_root: &'a Path,
+}
+
+impl<'a, R: ChangesetReader> BumpInferenceRule<'a, R> {
+    pub fn new(
+        reader: &'a R,
+        inference: &'a dyn BumpInferenceProvider,
+        project_root: &'a Path,
+    ) -> Self {
+        Self {
+            reader,
+            inference,
+            project_root,
+        }
+    }
+}
+
+impl<R: ChangesetReader> VerificationRule for BumpInferenceRule<'_, R> {
+    fn check(&self, context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        let mut declared: HashMap<String, BumpType> = HashMap::new();
+        for path in &context.changeset_files {
+            let changeset = self.reader.read_changeset(path)?;
+            for release in changeset.releases {
+                let bump = declared.entry(release.name).or_insert(release.bump_type);
+                *bump = (*bump).max(release.bump_type);
+            }
+        }
+
+        for package in &context.affected_packages {
+            let Some(&declared) = declared.get(&package.name) else {
+                continue;
+            };
+            let Some((inferred, provider)) = self
+                .inference
+                .infer_bump_with_source(self.project_root, package)?
+            else {
+                continue;
+            };
+            if inferred > declared {
+                result.understated_bumps.push(UnderstatedBump {
+                    package: package.name.clone(),
+                    declared,
+                    inferred,
+                    provider,
+                });
+            }
+        }
+
+        for package in &result.uncovered_packages {
+            if let Some(bump) = self.inference.infer_bump(self.project_root, package)? {
+                result.suggested_bumps.insert(package.name.clone(), bump);
+            }
+        }
+        Ok(())
+    }
+}
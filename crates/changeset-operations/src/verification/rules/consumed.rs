@@ -0,0 +1,29 @@
+use changeset_git::FileStatus;
+
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+
+/// Rejects edits to changesets that a pre-release has already consumed.
+///
+/// Release tooling owns consumed changesets: it adds the marker and later
+/// deletes the file on graduation. Modifying or renaming one in a change
+/// (including dropping its marker) would resurrect entries that already shipped.
+pub struct ConsumedChangesetsRule;
+
+impl VerificationRule for ConsumedChangesetsRule {
+    fn check(&self, context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        result.consumed_changeset_edits = context
+            .changeset_changes
+            .iter()
+            .filter(|change| {
+                change.consumed_at_base
+                    && matches!(
+                        change.status,
+                        FileStatus::Modified | FileStatus::Renamed | FileStatus::Typechange
+                    )
+            })
+            .map(|change| change.path.clone())
+            .collect();
+        Ok(())
+    }
+}
@@ -1,14 +1,25 @@
+use std::collections::HashMap;
+
+use changeset_project::PackageChangesetConfig;
+
 use super::{VerificationContext, VerificationResult, VerificationRule};
 use crate::Result;
 use crate::traits::ChangesetReader;
 
 pub struct CoverageRule<'a, R: ChangesetReader> {
     reader: &'a R,
+    package_configs: &'a HashMap<String, PackageChangesetConfig>,
 }
 
 impl<'a, R: ChangesetReader> CoverageRule<'a, R> {
-    pub fn new(reader: &'a R) -> Self {
-        Self { reader }
+    pub fn new(
+        reader: &'a R,
+        package_configs: &'a HashMap<String, PackageChangesetConfig>,
+    ) -> Self {
+        Self {
+            reader,
+            package_configs,
+        }
     }
 }
 
@@ -21,12 +32,18 @@ impl<R: ChangesetReader> VerificationRule for CoverageRule<'_, R> {
             }
         }
 
-        result.uncovered_packages = context
+        let (uncovered, exempt): (Vec<_>, Vec<_>) = context
             .affected_packages
             .iter()
             .filter(|pkg| !result.covered_packages.contains(&pkg.name))
             .cloned()
-            .collect();
+            .partition(|pkg| {
+                self.package_configs
+                    .get(&pkg.name)
+                    .is_none_or(PackageChangesetConfig::requires_changeset)
+            });
+        result.uncovered_packages = uncovered;
+        result.exempt_packages = exempt;
 
         Ok(())
     }
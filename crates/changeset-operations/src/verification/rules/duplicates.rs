@@ -0,0 +1,194 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use changeset_core::Changeset;
+use serde::Serialize;
+
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+use crate::traits::ChangesetReader;
+
+/// Why two pending changesets are reported as duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DuplicateKind {
+    /// Files with the same name in different changeset directories.
+    SameName,
+    /// Files with the same releases, category, scope and summary.
+    SameContent,
+}
+
+/// A changeset added by the change that duplicates another pending changeset,
+/// typically because two branches added the same change and were both merged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateChangeset {
+    pub path: PathBuf,
+    pub duplicate_of: PathBuf,
+    pub kind: DuplicateKind,
+}
+
+/// Compares the changesets a change adds or edits against every pending
+/// changeset, reporting files that share a name across changeset directories,
+/// which makes it ambiguous which file a release consumed, and files that
+/// repeat another changeset's content, which releases the same entry twice.
+pub struct DuplicateChangesetsRule<'a, R: ChangesetReader> {
+    reader: &'a R,
+    changeset_dirs: &'a [&'a Path],
+}
+
+impl<'a, R: ChangesetReader> DuplicateChangesetsRule<'a, R> {
+    pub fn new(reader: &'a R, changeset_dirs: &'a [&'a Path]) -> Self {
+        Self {
+            reader,
+            changeset_dirs,
+        }
+    }
+
+    fn pending_changesets(&self) -> Result<Vec<(PathBuf, Changeset)>> {
+        let mut paths = BTreeSet::new();
+        for dir in self.changeset_dirs {
+            paths.extend(self.reader.list_changesets(dir)?);
+        }
+        paths
+            .into_iter()
+            .map(|path| {
+                let changeset = self.reader.read_changeset(&path)?;
+                Ok((path, changeset))
+            })
+            .collect()
+    }
+}
+
+impl<R: ChangesetReader> VerificationRule for DuplicateChangesetsRule<'_, R> {
+    fn check(&self, context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        if context.changeset_files.is_empty() {
+            return Ok(());
+        }
+        let pending = self.pending_changesets()?;
+        let mut reported = BTreeSet::new();
+
+        for path in &context.changeset_files {
+            let Some((_, changeset)) = pending.iter().find(|(pending, _)| pending == path) else {
+                continue;
+            };
+            for (other, other_changeset) in &pending {
+                if other == path {
+                    continue;
+                }
+                let kind = if other.file_name() == path.file_name() {
+                    DuplicateKind::SameName
+                } else if other_changeset == changeset {
+                    DuplicateKind::SameContent
+                } else {
+                    continue;
+                };
+                let pair = if path < other {
+                    (path.clone(), other.clone())
+                } else {
+                    (other.clone(), path.clone())
+                };
+                if reported.insert(pair) {
+                    result.duplicate_changesets.push(DuplicateChangeset {
+                        path: path.clone(),
+                        duplicate_of: other.clone(),
+                        kind,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashSet};
+
+    use changeset_core::BumpType;
+
+    use super::*;
+    use crate::mocks::{MockChangesetReader, make_changeset};
+
+    fn context(changeset_files: &[&str]) -> VerificationContext {
+        VerificationContext {
+            affected_packages: Vec::new(),
+            transitive_packages: BTreeMap::new(),
+            package_files: BTreeMap::new(),
+            changeset_files: changeset_files.iter().map(PathBuf::from).collect(),
+            deleted_changesets: Vec::new(),
+            changeset_changes: Vec::new(),
+            project_files: Vec::new(),
+            ignored_files: Vec::new(),
+        }
+    }
+
+    fn empty_result() -> VerificationResult {
+        VerificationResult {
+            affected_packages: Vec::new(),
+            transitive_packages: BTreeMap::new(),
+            covered_packages: HashSet::new(),
+            uncovered_packages: Vec::new(),
+            package_files: BTreeMap::new(),
+            suggested_bumps: BTreeMap::new(),
+            exempt_packages: Vec::new(),
+            deleted_changesets: Vec::new(),
+            project_files: Vec::new(),
+            ignored_files: Vec::new(),
+            lint_failures: Vec::new(),
+            scope_failures: Vec::new(),
+            consumed_changeset_edits: Vec::new(),
+            missing_advisory: false,
+            uncovered_owners: BTreeMap::new(),
+            understated_bumps: Vec::new(),
+            duplicate_changesets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn reports_same_names_and_same_content() -> anyhow::Result<()> {
+        let fix = make_changeset("crate-a", BumpType::Patch, "Fix parser");
+        let reader = MockChangesetReader::new().with_changesets(vec![
+            (PathBuf::from(".changeset/changesets/fix.md"), fix.clone()),
+            (
+                PathBuf::from("teams/web/changesets/fix.md"),
+                make_changeset("crate-b", BumpType::Minor, "Add page"),
+            ),
+            (PathBuf::from(".changeset/changesets/fix-again.md"), fix),
+            (
+                PathBuf::from(".changeset/changesets/other.md"),
+                make_changeset("crate-a", BumpType::Patch, "Other fix"),
+            ),
+        ]);
+        let dirs = [Path::new(".changeset"), Path::new("teams/web")];
+        let rule = DuplicateChangesetsRule::new(&reader, &dirs);
+        let mut result = empty_result();
+
+        rule.check(
+            &context(&[
+                ".changeset/changesets/fix.md",
+                ".changeset/changesets/fix-again.md",
+            ]),
+            &mut result,
+        )?;
+
+        assert_eq!(
+            result.duplicate_changesets,
+            [
+                DuplicateChangeset {
+                    path: PathBuf::from(".changeset/changesets/fix.md"),
+                    duplicate_of: PathBuf::from(".changeset/changesets/fix-again.md"),
+                    kind: DuplicateKind::SameContent,
+                },
+                DuplicateChangeset {
+                    path: PathBuf::from(".changeset/changesets/fix.md"),
+                    duplicate_of: PathBuf::from("teams/web/changesets/fix.md"),
+                    kind: DuplicateKind::SameName,
+                },
+            ]
+        );
+        assert_eq!(result.name_clashes(), 1);
+        assert!(!result.is_success());
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+use crate::lint::lint_summary;
+use crate::traits::ChangesetReader;
+
+pub struct SummaryLintRule<'a, R: ChangesetReader> {
+    reader: &'a R,
+    denied_words: &'a [String],
+}
+
+impl<'a, R: ChangesetReader> SummaryLintRule<'a, R> {
+    pub fn new(reader: &'a R, denied_words: &'a [String]) -> Self {
+        Self {
+            reader,
+            denied_words,
+        }
+    }
+}
+
+impl<R: ChangesetReader> VerificationRule for SummaryLintRule<'_, R> {
+    fn check(&self, context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        for path in &context.changeset_files {
+            let changeset = self.reader.read_changeset(path)?;
+            let issues = lint_summary(&changeset.summary, self.denied_words);
+            if !issues.is_empty() {
+                result.lint_failures.push((path.clone(), issues));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,22 @@
+mod advisory;
+mod bump_inference;
+mod consumed;
 mod coverage;
 mod deleted;
+mod duplicates;
+mod lint;
+mod ownership;
+mod scope;
 
+pub use advisory::AdvisoryRule;
+pub use bump_inference::{BumpInferenceRule, UnderstatedBump};
+pub use consumed::ConsumedChangesetsRule;
 pub use coverage::CoverageRule;
 pub use deleted::DeletedChangesetsRule;
+pub use duplicates::{DuplicateChangeset, DuplicateChangesetsRule, DuplicateKind};
+pub use lint::SummaryLintRule;
+pub use ownership::OwnershipRule;
+pub use scope::{ScopeIssue, ScopeRule};
 
 use super::{VerificationContext, VerificationResult};
 use crate::Result;
@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+
+/// Groups packages without changeset coverage by their owners, so a review
+/// can tell which team still has to add a changeset.
+///
+/// Runs after [`CoverageRule`](super::CoverageRule); packages without owners
+/// are not listed.
+pub struct OwnershipRule<'a> {
+    owners: &'a BTreeMap<String, Vec<String>>,
+}
+
+impl<'a> OwnershipRule<'a> {
+    #[must_use]
+    pub fn new(owners: &'a BTreeMap<String, Vec<String>>) -> Self {
+        Self { owners }
+    }
+}
+
+impl VerificationRule for OwnershipRule<'_> {
+    fn check(&self, _context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        let mut uncovered: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for package in &result.uncovered_packages {
+            for owner in self.owners.get(&package.name).into_iter().flatten() {
+                uncovered
+                    .entry(owner.clone())
+                    .or_default()
+                    .push(package.name.clone());
+            }
+        }
+        result.uncovered_owners = uncovered;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use changeset_core::PackageInfo;
+    use semver::Version;
+
+    use super::*;
+
+    fn package(name: &str) -> PackageInfo {
+        PackageInfo {
+            name: name.to_string(),
+            version: Version::new(1, 0, 0),
+            path: format!("crates/{name}").into(),
+        }
+    }
+
+    #[test]
+    fn groups_uncovered_packages_by_owner() -> Result<()> {
+        let owners = BTreeMap::from([
+            ("web".to_string(), vec!["@org/web".to_string()]),
+            (
+                "shared".to_string(),
+                vec!["@org/web".to_string(), "@org/core".to_string()],
+            ),
+            ("core".to_string(), vec!["@org/core".to_string()]),
+        ]);
+        let context = VerificationContext {
+            affected_packages: Vec::new(),
+            transitive_packages: BTreeMap::new(),
+            package_files: BTreeMap::new(),
+            changeset_files: Vec::new(),
+            deleted_changesets: Vec::new(),
+            changeset_changes: Vec::new(),
+            project_files: Vec::new(),
+            ignored_files: Vec::new(),
+        };
+        let mut result = VerificationResult {
+            affected_packages: Vec::new(),
+            transitive_packages: BTreeMap::new(),
+            covered_packages: HashSet::from(["core".to_string()]),
+            uncovered_packages: vec![package("web"), package("shared"), package("cli")],
+            package_files: BTreeMap::new(),
+            suggested_bumps: BTreeMap::new(),
+            exempt_packages: Vec::new(),
+            deleted_changesets: Vec::new(),
+            project_files: Vec::new(),
+            ignored_files: Vec::new(),
+            lint_failures: Vec::new(),
+            scope_failures: Vec::new(),
+            consumed_changeset_edits: Vec::new(),
+            missing_advisory: false,
+            uncovered_owners: BTreeMap::new(),
+            understated_bumps: Vec::new(),
+            duplicate_changesets: Vec::new(),
+        };
+
+        OwnershipRule::new(&owners).check(&context, &mut result)?;
+
+        assert_eq!(
+            result.uncovered_owners,
+            BTreeMap::from([
+                ("@org/core".to_string(), vec!["shared".to_string()]),
+                (
+                    "@org/web".to_string(),
+                    vec!["web".to_string(), "shared".to_string()]
+                ),
+            ])
+        );
+
+        Ok(())
+    }
+}
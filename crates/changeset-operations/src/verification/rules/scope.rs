@@ -0,0 +1,62 @@
+use std::fmt;
+
+use changeset_project::ScopeConfig;
+use serde::Serialize;
+
+use super::{VerificationContext, VerificationResult, VerificationRule};
+use crate::Result;
+use crate::traits::ChangesetReader;
+
+/// A changeset whose `scope` does not match the configured scopes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum ScopeIssue {
+    Missing,
+    NotAllowed { scope: String },
+}
+
+impl fmt::Display for ScopeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing scope"),
+            Self::NotAllowed { scope } => write!(f, "scope '{scope}' is not allowed"),
+        }
+    }
+}
+
+pub struct ScopeRule<'a, R: ChangesetReader> {
+    reader: &'a R,
+    scope_config: &'a ScopeConfig,
+}
+
+impl<'a, R: ChangesetReader> ScopeRule<'a, R> {
+    pub fn new(reader: &'a R, scope_config: &'a ScopeConfig) -> Self {
+        Self {
+            reader,
+            scope_config,
+        }
+    }
+}
+
+impl<R: ChangesetReader> VerificationRule for ScopeRule<'_, R> {
+    fn check(&self, context: &VerificationContext, result: &mut VerificationResult) -> Result<()> {
+        for path in &context.changeset_files {
+            let changeset = self.reader.read_changeset(path)?;
+            let issue = match changeset.scope {
+                None if self.scope_config.required() => Some(ScopeIssue::Missing),
+                Some(scope) if !self.scope_config.is_allowed(&scope) => {
+                    Some(ScopeIssue::NotAllowed { scope })
+                }
+                _ => None,
+            };
+            if let Some(issue) = issue {
+                result.scope_failures.push((path.clone(), issue));
+            }
+        }
+        Ok(())
+    }
+}
@@ -243,6 +243,35 @@ Fix a security issue.
     );
 }
 
+#[test]
+fn mark_consumed_preserves_crlf_line_endings() {
+    let dir = create_changeset_dir();
+    fs::write(
+        dir.path().join(".changeset/changesets/windows.md"),
+        "---\r\n\"my-crate\": patch\r\n---\r\n\r\nFix a bug.\r\nWith details.\r\n",
+    )
+    .expect("write changeset file");
+
+    let changeset_io = FileSystemChangesetIO::new(dir.path());
+    let version = Version::parse("1.0.1-alpha.1").expect("parse version");
+    changeset_io
+        .mark_consumed_for_prerelease(
+            Path::new(".changeset"),
+            &[Path::new("windows.md")],
+            &version,
+        )
+        .expect("mark consumed should succeed");
+
+    let content = read_changeset_file(&dir, "windows.md");
+    assert!(content.contains("consumedForPrerelease"));
+    assert!(content.contains("Fix a bug.\r\nWith details.\r\n"));
+    assert_eq!(
+        content.matches('\n').count(),
+        content.matches("\r\n").count(),
+        "every line break should stay CRLF"
+    );
+}
+
 #[test]
 fn mark_multiple_changesets_consumed() {
     let dir = create_changeset_dir();
@@ -333,3 +362,30 @@ fn list_changesets_with_mixed_consumed_status() {
     assert!(consumed_names.contains(&"consumed1.md".to_string()));
     assert!(consumed_names.contains(&"consumed2.md".to_string()));
 }
+
+#[test]
+fn branch_names_combine_branch_slug_and_hash() {
+    let dir = create_changeset_dir();
+    let changeset_io =
+        FileSystemChangesetIO::new(dir.path()).with_branch_names("feature/Login_Form");
+    let changeset =
+        parse_changeset("---\n\"my-crate\": patch\n---\n\nFix login\n").expect("parse changeset");
+    let changeset_dir = dir.path().join(".changeset");
+
+    let first = changeset_io
+        .write_changeset(&changeset_dir, &changeset)
+        .expect("write should succeed");
+    let second = changeset_io
+        .write_changeset(&changeset_dir, &changeset)
+        .expect("write should succeed");
+
+    for name in [&first, &second] {
+        let hash = name
+            .strip_prefix("feature-login-form-")
+            .and_then(|rest| rest.strip_suffix(".md"))
+            .unwrap_or_else(|| panic!("unexpected name {name}"));
+        assert_eq!(hash.len(), 8);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+    assert_ne!(first, second);
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use changeset_core::{BumpType, ChangeCategory, Changeset, PackageRelease};
+use changeset_operations::operations::{
+    DirtyTreeAction, ReleaseInput, ReleaseOperation, ReleaseOutcome, StepControl,
+};
+use changeset_operations::providers::memory::{
+    InMemoryChangelogWriter, InMemoryChangesetIO, InMemoryGitProvider, InMemoryManifestWriter,
+    InMemoryProjectProvider, InMemoryReleaseStateIO,
+};
+use semver::Version;
+
+fn changeset(package: &str, bump: BumpType, summary: &str) -> Changeset {
+    Changeset {
+        summary: summary.to_string(),
+        releases: vec![PackageRelease {
+            name: package.to_string(),
+            bump_type: bump,
+        }],
+        category: ChangeCategory::Fixed,
+        scope: None,
+        consumed_for_prerelease: None,
+        graduate: false,
+        confidential: false,
+        embargo: None,
+        advisory: None,
+    }
+}
+
+fn release_input() -> ReleaseInput {
+    ReleaseInput {
+        dry_run: false,
+        convert_inherited: false,
+        no_commit: false,
+        no_tags: false,
+        keep_changesets: false,
+        force: false,
+        per_package_config: HashMap::new(),
+        global_prerelease: None,
+        graduate_all: false,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
+    }
+}
+
+#[test]
+fn full_release_runs_in_memory() {
+    let changeset_path = PathBuf::from(".changeset/changesets/fix.md");
+    let changeset_io = Arc::new(InMemoryChangesetIO::new().with_changeset(
+        changeset_path.clone(),
+        changeset("crate-a", BumpType::Patch, "Fix a bug"),
+    ));
+    let manifest_writer = Arc::new(InMemoryManifestWriter::new());
+    let changelog_writer = Arc::new(InMemoryChangelogWriter::new());
+    let git_provider = Arc::new(InMemoryGitProvider::new());
+    let release_state_io = Arc::new(InMemoryReleaseStateIO::new());
+
+    let operation = ReleaseOperation::new(
+        InMemoryProjectProvider::workspace(vec![("crate-a", "1.0.0"), ("crate-b", "2.0.0")]),
+        Arc::clone(&changeset_io),
+        Arc::clone(&manifest_writer),
+        Arc::clone(&changelog_writer),
+        Arc::clone(&git_provider),
+        Arc::clone(&release_state_io),
+    );
+
+    let outcome = operation
+        .execute(Path::new("/anywhere"), &release_input())
+        .expect("release should succeed");
+
+    let ReleaseOutcome::Executed(output) = outcome else {
+        panic!("expected Executed outcome");
+    };
+    assert_eq!(output.planned_releases.len(), 1);
+    assert_eq!(output.unchanged_packages, vec!["crate-b".to_string()]);
+
+    assert_eq!(
+        manifest_writer.written_versions(),
+        vec![(
+            PathBuf::from("/mock/workspace/crates/crate-a/Cargo.toml"),
+            Version::new(1, 0, 1)
+        )]
+    );
+    let releases = changelog_writer.written_releases();
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0].1.version, Version::new(1, 0, 1));
+
+    assert_eq!(git_provider.commits().len(), 1);
+    let tags: Vec<String> = git_provider
+        .tags_created()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(tags, vec!["crate-a@v1.0.1".to_string()]);
+    assert_eq!(git_provider.deleted_files(), vec![changeset_path]);
+}
+
+#[test]
+fn failed_tag_rolls_back_in_memory() {
+    let changeset_io = Arc::new(InMemoryChangesetIO::new().with_changeset(
+        PathBuf::from(".changeset/changesets/feature.md"),
+        changeset("my-crate", BumpType::Minor, "Add a feature"),
+    ));
+    let git_provider = Arc::new(InMemoryGitProvider::new());
+    git_provider.set_fail_on_create_tag(true);
+
+    let operation = ReleaseOperation::new(
+        InMemoryProjectProvider::single_package("my-crate", "1.0.0"),
+        Arc::clone(&changeset_io),
+        Arc::new(InMemoryManifestWriter::new()),
+        Arc::new(InMemoryChangelogWriter::new()),
+        Arc::clone(&git_provider),
+        Arc::new(InMemoryReleaseStateIO::new()),
+    );
+
+    let result = operation.execute(Path::new("/anywhere"), &release_input());
+
+    assert!(result.is_err());
+    assert_eq!(git_provider.commits().len(), 1);
+    assert_eq!(git_provider.reset_count(), 1);
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use changeset_core::{BumpType, PackageInfo, PrereleaseSpec, ZeroVersionBehavior};
+use changeset_operations::operations::{PackageReleaseConfig, PlanConfig, plan_from_parts};
+use changeset_parse::parse_changeset;
+use semver::Version;
+
+fn package(name: &str, version: &str) -> PackageInfo {
+    PackageInfo {
+        name: name.to_string(),
+        version: version.parse().expect("valid version"),
+        path: PathBuf::from(format!("crates/{name}")),
+    }
+}
+
+#[test]
+fn plans_next_versions_from_parsed_changesets() -> anyhow::Result<()> {
+    let packages = [package("core", "1.2.3"), package("cli", "0.4.0")];
+    let changesets = [
+        parse_changeset("---\n\"core\": minor\n---\n\nAdd a feature\n")?,
+        parse_changeset("---\n\"core\": patch\n\"cli\": patch\n---\n\nFix a bug\n")?,
+    ];
+
+    let plan = plan_from_parts(&packages, &changesets, &PlanConfig::default())?;
+
+    let versions: HashMap<_, _> = plan
+        .releases
+        .iter()
+        .map(|release| (release.name.as_str(), &release.new_version))
+        .collect();
+    assert_eq!(versions["core"], &Version::new(1, 3, 0));
+    assert_eq!(versions["cli"], &Version::new(0, 4, 1));
+    assert!(plan.unknown_packages.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn applies_per_package_config() -> anyhow::Result<()> {
+    let packages = [package("core", "1.2.3"), package("cli", "0.4.0")];
+    let changesets = [parse_changeset(
+        "---\n\"core\": patch\n\"cli\": major\n---\n\nBreaking fix\n",
+    )?];
+    let config = PlanConfig {
+        zero_version_behavior: ZeroVersionBehavior::default(),
+        per_package: HashMap::from([(
+            "core".to_string(),
+            PackageReleaseConfig {
+                prerelease: Some(PrereleaseSpec::Beta),
+                ..PackageReleaseConfig::default()
+            },
+        )]),
+    };
+
+    let plan = plan_from_parts(&packages, &changesets, &config)?;
+
+    let core = plan
+        .releases
+        .iter()
+        .find(|release| release.name == "core")
+        .expect("core is released");
+    assert_eq!(core.new_version.to_string(), "1.2.4-beta.1");
+    let cli = plan
+        .releases
+        .iter()
+        .find(|release| release.name == "cli")
+        .expect("cli is released");
+    assert_eq!(cli.bump_type, BumpType::Major);
+
+    Ok(())
+}
+
+#[test]
+fn reports_unknown_packages() -> anyhow::Result<()> {
+    let packages = [package("core", "1.0.0")];
+    let changesets = [parse_changeset("---\n\"missing\": patch\n---\n\nFix\n")?];
+
+    let plan = plan_from_parts(&packages, &changesets, &PlanConfig::default())?;
+
+    assert!(plan.releases.is_empty());
+    assert_eq!(plan.unknown_packages, vec!["missing".to_string()]);
+
+    Ok(())
+}
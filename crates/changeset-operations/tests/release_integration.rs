@@ -2,10 +2,13 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::process::Command;
+use std::sync::Arc;
 
+use changeset_core::BumpType;
 use changeset_operations::OperationError;
 use changeset_operations::operations::{
-    ReleaseInput, ReleaseOperation, ReleaseOutcome, StatusOperation,
+    DirtyTreeAction, DynReleaseOperation, PackageReleaseConfig, ReleaseInput, ReleaseOperation,
+    ReleaseOutcome, StatusOperation, StepControl,
 };
 use changeset_operations::providers::{
     FileSystemChangelogWriter, FileSystemChangesetIO, FileSystemManifestWriter,
@@ -176,7 +179,12 @@ fn run_release(
         git_provider,
         release_state_io,
     );
-    let input = ReleaseInput {
+
+    operation.execute(dir.path(), &release_input(dry_run, convert_inherited))
+}
+
+fn release_input(dry_run: bool, convert_inherited: bool) -> ReleaseInput {
+    ReleaseInput {
         dry_run,
         convert_inherited,
         no_commit: true,
@@ -186,9 +194,18 @@ fn run_release(
         per_package_config: HashMap::new(),
         global_prerelease: None,
         graduate_all: false,
-    };
-
-    operation.execute(dir.path(), &input)
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
+    }
 }
 
 #[test]
@@ -210,6 +227,30 @@ fn single_package_version_update() {
     assert_eq!(version, "1.0.1");
 }
 
+#[test]
+fn release_with_trait_object_providers() {
+    let dir = create_single_package_project();
+    write_changeset(&dir, "feature.md", "my-crate", "minor", "Add a feature");
+
+    let operation = DynReleaseOperation::new(
+        Arc::new(FileSystemProjectProvider::new()),
+        Arc::new(FileSystemChangesetIO::new(dir.path())),
+        Arc::new(FileSystemManifestWriter::new()),
+        Arc::new(FileSystemChangelogWriter::new()),
+        Arc::new(Git2Provider::new()),
+        Arc::new(FileSystemReleaseStateIO::new()),
+    );
+    let result = operation
+        .execute(dir.path(), &release_input(false, false))
+        .expect("release should succeed");
+
+    let ReleaseOutcome::Executed(output) = result else {
+        panic!("expected Executed outcome");
+    };
+    assert_eq!(output.planned_releases[0].new_version.to_string(), "1.1.0");
+    assert_eq!(read_version(&dir.path().join("Cargo.toml")), "1.1.0");
+}
+
 #[test]
 fn workspace_with_multiple_packages() {
     let dir = create_workspace_project();
@@ -391,6 +432,59 @@ fn creates_changelog_on_release() {
     assert!(content.contains("Fix a bug"));
 }
 
+#[test]
+fn forced_bump_releases_without_changeset_file() {
+    let dir = create_single_package_project();
+
+    let mut per_package_config = HashMap::new();
+    per_package_config.insert(
+        "my-crate".to_string(),
+        PackageReleaseConfig {
+            forced_bump: Some(BumpType::Minor),
+            ..Default::default()
+        },
+    );
+    let operation = ReleaseOperation::new(
+        FileSystemProjectProvider::new(),
+        FileSystemChangesetIO::new(dir.path()),
+        FileSystemManifestWriter::new(),
+        FileSystemChangelogWriter::new(),
+        Git2Provider::new(),
+        FileSystemReleaseStateIO::new(),
+    );
+    let input = ReleaseInput {
+        dry_run: false,
+        convert_inherited: false,
+        no_commit: true,
+        no_tags: true,
+        keep_changesets: true,
+        force: false,
+        per_package_config,
+        global_prerelease: None,
+        graduate_all: false,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
+    };
+
+    let result = operation
+        .execute(dir.path(), &input)
+        .expect("release should succeed");
+
+    assert!(matches!(result, ReleaseOutcome::Executed(_)));
+    assert_eq!(read_version(&dir.path().join("Cargo.toml")), "1.1.0");
+    let content = fs::read_to_string(dir.path().join("CHANGELOG.md")).expect("read CHANGELOG.md");
+    assert!(content.contains("Forced minor bump"));
+}
+
 #[test]
 fn dry_run_skips_changelog_creation() {
     let dir = create_single_package_project();
@@ -454,8 +548,12 @@ fn status_and_release_calculate_identical_versions() {
     let changeset_reader = FileSystemChangesetIO::new(dir.path());
     let inherited_checker = FileSystemManifestWriter::new();
 
-    let status_operation =
-        StatusOperation::new(project_provider, changeset_reader, inherited_checker);
+    let status_operation = StatusOperation::new(
+        project_provider,
+        changeset_reader,
+        inherited_checker,
+        Git2Provider::new(),
+    );
     let status_output = status_operation
         .execute(dir.path())
         .expect("status should succeed");
@@ -591,6 +689,17 @@ fn run_release_with_git(
         per_package_config: HashMap::new(),
         global_prerelease: None,
         graduate_all: false,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
     };
 
     operation.execute(dir.path(), &input)
@@ -804,7 +913,7 @@ fn release_errors_on_dirty_working_tree() {
     let result = run_release_with_git(&dir, false, false, false);
 
     assert!(
-        matches!(result, Err(OperationError::DirtyWorkingTree)),
+        matches!(result, Err(OperationError::DirtyWorkingTree { .. })),
         "should error on dirty working tree: {result:?}"
     );
 }
@@ -882,6 +991,17 @@ fn run_release_with_prerelease(
         per_package_config: HashMap::new(),
         global_prerelease: prerelease,
         graduate_all: false,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
     };
 
     operation.execute(dir.path(), &input)
@@ -1370,6 +1490,17 @@ fn run_release_with_config(
         per_package_config,
         global_prerelease,
         graduate_all,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
     };
 
     operation.execute(dir.path(), &input)
@@ -1440,7 +1571,9 @@ fn release_fails_on_cli_toml_prerelease_conflict() {
         "crate-a".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: Some(changeset_core::PrereleaseSpec::Beta),
+            prerelease_source: None,
             graduate_zero: false,
+            forced_bump: None,
         },
     );
 
@@ -1620,7 +1753,9 @@ edition = "2021"
         "my-crate".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: None,
+            prerelease_source: None,
             graduate_zero: true,
+            forced_bump: None,
         },
     );
 
@@ -1648,7 +1783,9 @@ fn cannot_graduate_stable_version() {
         "crate-a".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: None,
+            prerelease_source: None,
             graduate_zero: true,
+            forced_bump: None,
         },
     );
 
@@ -1815,7 +1952,9 @@ fn validator_detects_unknown_package_in_prerelease() {
         "nonexistent-crate".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: Some(changeset_core::PrereleaseSpec::Alpha),
+            prerelease_source: None,
             graduate_zero: false,
+            forced_bump: None,
         },
     );
 
@@ -1840,7 +1979,9 @@ fn validator_detects_unknown_package_in_graduate() {
         "nonexistent-crate".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: None,
+            prerelease_source: None,
             graduate_zero: true,
+            forced_bump: None,
         },
     );
 
@@ -1870,7 +2011,9 @@ fn cli_prerelease_matching_toml_succeeds() {
         "crate-a".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: Some(changeset_core::PrereleaseSpec::Alpha),
+            prerelease_source: None,
             graduate_zero: false,
+            forced_bump: None,
         },
     );
 
@@ -2152,14 +2295,18 @@ fn release_fails_with_multiple_conflicting_configs() {
         "crate-a".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: Some(changeset_core::PrereleaseSpec::Beta),
+            prerelease_source: None,
             graduate_zero: false,
+            forced_bump: None,
         },
     );
     per_package_config.insert(
         "crate-b".to_string(),
         changeset_operations::operations::PackageReleaseConfig {
             prerelease: Some(changeset_core::PrereleaseSpec::Alpha),
+            prerelease_source: None,
             graduate_zero: false,
+            forced_bump: None,
         },
     );
 
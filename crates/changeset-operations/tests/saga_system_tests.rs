@@ -10,7 +10,9 @@ use std::path::Path;
 use std::process::Command;
 
 use changeset_operations::OperationError;
-use changeset_operations::operations::{ReleaseInput, ReleaseOperation, ReleaseOutcome};
+use changeset_operations::operations::{
+    DirtyTreeAction, ReleaseInput, ReleaseOperation, ReleaseOutcome, StepControl,
+};
 use changeset_operations::providers::{
     FileSystemChangelogWriter, FileSystemChangesetIO, FileSystemManifestWriter,
     FileSystemProjectProvider, FileSystemReleaseStateIO, Git2Provider,
@@ -216,6 +218,17 @@ fn run_release_with_git(
         per_package_config: HashMap::new(),
         global_prerelease: None,
         graduate_all: false,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
     };
 
     operation.execute(dir.path(), &input)
@@ -504,6 +517,17 @@ fn system_test_no_rollback_needed_for_dry_run() {
         per_package_config: HashMap::new(),
         global_prerelease: None,
         graduate_all: false,
+        allow_branch: false,
+        allow_stale: false,
+        include_confidential: false,
+        diff: false,
+        step_control: StepControl::default(),
+        stamps: Vec::new(),
+        on_dirty: DirtyTreeAction::Abort,
+        empty_release_bump: None,
+        tag_only: false,
+        packages: Vec::new(),
+        channel: None,
     };
 
     let result = operation
@@ -692,6 +716,110 @@ fn system_test_release_updates_root_workspace_dependencies() {
     );
 }
 
+fn create_workspace_with_conditional_deps(changeset_config: &str) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        format!(
+            r#"[workspace]
+members = ["crates/*"]
+resolver = "2"
+
+[workspace.metadata.changeset]
+{changeset_config}
+"#
+        ),
+    )
+    .expect("write workspace Cargo.toml");
+
+    fs::create_dir_all(dir.path().join("crates/crate-a/src")).expect("create crate-a dir");
+    fs::write(
+        dir.path().join("crates/crate-a/Cargo.toml"),
+        r#"[package]
+name = "crate-a"
+version = "1.0.0"
+edition = "2021"
+"#,
+    )
+    .expect("write crate-a Cargo.toml");
+    fs::write(dir.path().join("crates/crate-a/src/lib.rs"), "").expect("write lib.rs");
+
+    fs::create_dir_all(dir.path().join("crates/crate-b/src")).expect("create crate-b dir");
+    fs::write(
+        dir.path().join("crates/crate-b/Cargo.toml"),
+        r#"[package]
+name = "crate-b"
+version = "2.0.0"
+edition = "2021"
+
+[dependencies]
+crate-a = { path = "../crate-a", version = "1.0.0", optional = true }
+
+[target.'cfg(unix)'.dev-dependencies]
+crate-a = { path = "../crate-a", version = "1.0.0" }
+"#,
+    )
+    .expect("write crate-b Cargo.toml");
+    fs::write(dir.path().join("crates/crate-b/src/lib.rs"), "").expect("write lib.rs");
+
+    fs::create_dir_all(dir.path().join(".changeset/changesets"))
+        .expect("create .changeset/changesets dir");
+
+    dir
+}
+
+#[test]
+fn system_test_release_updates_target_specific_and_optional_deps() {
+    let dir = create_workspace_with_conditional_deps("");
+    init_git_repo(&dir);
+    git_add_all(&dir);
+    git_commit(&dir, "Initial commit");
+
+    write_changeset(&dir, "bump-a.md", "crate-a", "minor", "Bump crate-a");
+    git_add_all(&dir);
+    git_commit(&dir, "Add changeset");
+
+    run_release_with_git(&dir, false, false, false).expect("release should succeed");
+
+    let manifest = dir.path().join("crates/crate-b/Cargo.toml");
+    assert_eq!(
+        read_dep_version(&manifest, "crate-a", "dependencies").as_deref(),
+        Some("1.1.0"),
+        "optional dependency should be updated by default"
+    );
+    assert_eq!(
+        read_dep_version(&manifest, "crate-a", "target.cfg(unix).dev-dependencies").as_deref(),
+        Some("1.1.0"),
+        "target-specific dependency should be updated"
+    );
+}
+
+#[test]
+fn system_test_release_can_ignore_optional_deps() {
+    let dir = create_workspace_with_conditional_deps("ignore-optional-dependencies = true");
+    init_git_repo(&dir);
+    git_add_all(&dir);
+    git_commit(&dir, "Initial commit");
+
+    write_changeset(&dir, "bump-a.md", "crate-a", "minor", "Bump crate-a");
+    git_add_all(&dir);
+    git_commit(&dir, "Add changeset");
+
+    run_release_with_git(&dir, false, false, false).expect("release should succeed");
+
+    let manifest = dir.path().join("crates/crate-b/Cargo.toml");
+    assert_eq!(
+        read_dep_version(&manifest, "crate-a", "dependencies").as_deref(),
+        Some("1.0.0"),
+        "optional dependency should be left alone"
+    );
+    assert_eq!(
+        read_dep_version(&manifest, "crate-a", "target.cfg(unix).dev-dependencies").as_deref(),
+        Some("1.1.0")
+    );
+}
+
 #[test]
 fn system_test_release_skips_deps_without_version() {
     let dir = TempDir::new().expect("create temp dir");
@@ -19,6 +19,9 @@ pub enum ValidationError {
 
     #[error("input exceeds maximum size of {max_bytes} bytes")]
     InputTooLarge { max_bytes: usize },
+
+    #[error("embargo date requires the changeset to be marked confidential")]
+    EmbargoWithoutConfidential,
 }
 
 #[derive(Debug, Error)]
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use indexmap::IndexMap;
 use serde::Deserialize;
 use serde_with::{MapPreventDuplicates, serde_as};
@@ -15,10 +16,18 @@ const MAX_INPUT_SIZE: usize = 100 * 1024 * 1024;
 struct FrontMatter {
     #[serde(default)]
     category: ChangeCategory,
+    #[serde(default)]
+    scope: Option<String>,
     #[serde(default, rename = "consumedForPrerelease")]
     consumed_for_prerelease: Option<String>,
     #[serde(default)]
     graduate: bool,
+    #[serde(default)]
+    confidential: bool,
+    #[serde(default)]
+    embargo: Option<NaiveDate>,
+    #[serde(default)]
+    advisory: Option<String>,
     #[serde(flatten)]
     #[serde_as(as = "MapPreventDuplicates<_, _>")]
     releases: IndexMap<String, BumpType>,
@@ -86,6 +95,10 @@ pub fn parse_changeset(content: &str) -> Result<Changeset, FormatError> {
         return Err(ValidationError::NoReleases.into());
     }
 
+    if parsed.embargo.is_some() && !parsed.confidential {
+        return Err(ValidationError::EmbargoWithoutConfidential.into());
+    }
+
     let releases = parsed
         .releases
         .into_iter()
@@ -96,8 +109,12 @@ pub fn parse_changeset(content: &str) -> Result<Changeset, FormatError> {
         summary: body.trim().to_string(),
         releases,
         category: parsed.category,
+        scope: parsed.scope,
         consumed_for_prerelease: parsed.consumed_for_prerelease,
         graduate: parsed.graduate,
+        confidential: parsed.confidential,
+        embargo: parsed.embargo,
+        advisory: parsed.advisory,
     })
 }
 
@@ -419,10 +436,26 @@ Changed behavior.
         assert_eq!(changeset.category, ChangeCategory::Changed);
     }
 
+    #[test]
+    fn parses_custom_category() {
+        let content = r#"---
+category: perf
+"my-crate": patch
+---
+Faster startup.
+"#;
+
+        let changeset = parse_changeset(content).expect("should parse");
+        assert_eq!(
+            changeset.category,
+            ChangeCategory::Custom("perf".to_string())
+        );
+    }
+
     #[test]
     fn error_invalid_category() {
         let content = r#"---
-category: unknown
+category: [perf, fixed]
 "my-crate": patch
 ---
 Some summary.
@@ -494,6 +527,54 @@ Release candidate.
         );
     }
 
+    #[test]
+    fn parses_confidential_with_embargo() {
+        let content = r#"---
+category: security
+confidential: true
+embargo: 2026-11-01
+"my-crate": patch
+---
+Fix buffer overflow in parser.
+"#;
+
+        let changeset = parse_changeset(content).expect("should parse");
+        assert!(changeset.confidential);
+        assert_eq!(
+            changeset.embargo,
+            Some(NaiveDate::from_ymd_opt(2026, 11, 1).expect("valid date"))
+        );
+        assert_eq!(changeset.releases.len(), 1);
+    }
+
+    #[test]
+    fn parses_advisory() {
+        let content = "---\ncategory: security\nadvisory: RUSTSEC-2024-0001\n\"my-crate\": patch\n---\nFix overflow.\n";
+
+        let changeset = parse_changeset(content).expect("should parse");
+        assert_eq!(changeset.advisory.as_deref(), Some("RUSTSEC-2024-0001"));
+    }
+
+    #[test]
+    fn confidential_defaults_to_false() {
+        let content = "---\n\"my-crate\": patch\n---\nSome summary.\n";
+
+        let changeset = parse_changeset(content).expect("should parse");
+        assert!(!changeset.confidential);
+        assert_eq!(changeset.embargo, None);
+    }
+
+    #[test]
+    fn rejects_embargo_without_confidential() {
+        let content = "---\nembargo: 2026-11-01\n\"my-crate\": patch\n---\nSummary.\n";
+
+        let err = parse_changeset(content).expect_err("should fail");
+        assert!(matches!(
+            err,
+            FormatError::Validation(ValidationError::EmbargoWithoutConfidential)
+        ));
+    }
+
     #[test]
     fn graduate_defaults_to_false() {
         let content = r#"---
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use indexmap::IndexMap;
 use serde::Serialize;
 
@@ -9,7 +10,9 @@ use crate::parse::FRONT_MATTER_DELIMITER;
 #[derive(Serialize)]
 struct FrontMatterOutput<'a> {
     #[serde(skip_serializing_if = "is_default_category")]
-    category: ChangeCategory,
+    category: &'a ChangeCategory,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<&'a str>,
     #[serde(
         skip_serializing_if = "Option::is_none",
         rename = "consumedForPrerelease"
@@ -17,12 +20,18 @@ struct FrontMatterOutput<'a> {
     consumed_for_prerelease: Option<&'a str>,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     graduate: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    confidential: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embargo: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    advisory: Option<&'a str>,
     #[serde(flatten)]
     releases: IndexMap<&'a str, BumpType>,
 }
 
-fn is_default_category(category: &ChangeCategory) -> bool {
-    *category == ChangeCategory::default()
+fn is_default_category(category: &&ChangeCategory) -> bool {
+    **category == ChangeCategory::default()
 }
 
 #[must_use = "serialization result should be handled"]
@@ -38,9 +47,13 @@ pub fn serialize_changeset(changeset: &Changeset) -> Result<String, FormatError>
         .collect();
 
     let front_matter = FrontMatterOutput {
-        category: changeset.category,
+        category: &changeset.category,
+        scope: changeset.scope.as_deref(),
         consumed_for_prerelease: changeset.consumed_for_prerelease.as_deref(),
         graduate: changeset.graduate,
+        confidential: changeset.confidential,
+        embargo: changeset.embargo,
+        advisory: changeset.advisory.as_deref(),
         releases: releases_map,
     };
 
@@ -83,8 +96,12 @@ mod tests {
                 },
             ],
             category: ChangeCategory::default(),
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&original).expect("should serialize");
@@ -125,8 +142,12 @@ mod tests {
                 },
             ],
             category: ChangeCategory::default(),
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&original).expect("should serialize");
@@ -143,8 +164,12 @@ mod tests {
             summary: "Some summary".to_string(),
             releases: vec![],
             category: ChangeCategory::default(),
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let err = serialize_changeset(&changeset).expect_err("should fail");
@@ -160,8 +185,12 @@ mod tests {
                 bump_type: BumpType::Patch,
             }],
             category: ChangeCategory::Fixed,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&original).expect("should serialize");
@@ -171,6 +200,30 @@ mod tests {
         assert_eq!(parsed.summary, original.summary);
     }
 
+    #[test]
+    fn roundtrip_with_scope() {
+        let original = Changeset {
+            summary: "Handle empty input".to_string(),
+            releases: vec![PackageRelease {
+                name: "my-crate".to_string(),
+                bump_type: BumpType::Patch,
+            }],
+            category: ChangeCategory::Fixed,
+            scope: Some("parser".to_string()),
+            consumed_for_prerelease: None,
+            graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
+        };
+
+        let serialized = serialize_changeset(&original).expect("should serialize");
+        let parsed = parse_changeset(&serialized).expect("should parse");
+
+        assert!(serialized.contains("scope: parser\n"));
+        assert_eq!(parsed, original);
+    }
+
     #[test]
     fn default_category_not_serialized() {
         let changeset = Changeset {
@@ -180,8 +233,12 @@ mod tests {
                 bump_type: BumpType::Minor,
             }],
             category: ChangeCategory::Changed,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&changeset).expect("should serialize");
@@ -200,8 +257,12 @@ mod tests {
                 bump_type: BumpType::Patch,
             }],
             category: ChangeCategory::Security,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&changeset).expect("should serialize");
@@ -220,8 +281,12 @@ mod tests {
                 bump_type: BumpType::Patch,
             }],
             category: ChangeCategory::Fixed,
+            scope: None,
             consumed_for_prerelease: Some("1.0.1-alpha.1".to_string()),
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&original).expect("should serialize");
@@ -244,8 +309,12 @@ mod tests {
                 bump_type: BumpType::Minor,
             }],
             category: ChangeCategory::Changed,
+            scope: None,
             consumed_for_prerelease: Some("2.0.0-beta.3".to_string()),
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&changeset).expect("should serialize");
@@ -268,8 +337,12 @@ mod tests {
                 bump_type: BumpType::Minor,
             }],
             category: ChangeCategory::Changed,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&changeset).expect("should serialize");
@@ -288,8 +361,12 @@ mod tests {
                 bump_type: BumpType::Minor,
             }],
             category: ChangeCategory::Changed,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&changeset).expect("should serialize");
@@ -299,6 +376,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn confidential_roundtrip() {
+        let original = Changeset {
+            summary: "Fix a vulnerability".to_string(),
+            releases: vec![PackageRelease {
+                name: "my-crate".to_string(),
+                bump_type: BumpType::Patch,
+            }],
+            category: ChangeCategory::Security,
+            scope: None,
+            consumed_for_prerelease: None,
+            graduate: false,
+            confidential: true,
+            embargo: Some(NaiveDate::from_ymd_opt(2026, 11, 1).expect("valid date")),
+            advisory: None,
+        };
+
+        let serialized = serialize_changeset(&original).expect("should serialize");
+        assert!(serialized.contains("confidential: true"));
+
+        let parsed = parse_changeset(&serialized).expect("should parse");
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn advisory_roundtrip() {
+        let original = Changeset {
+            summary: "Fix a vulnerability".to_string(),
+            releases: vec![PackageRelease {
+                name: "my-crate".to_string(),
+                bump_type: BumpType::Patch,
+            }],
+            category: ChangeCategory::Security,
+            scope: None,
+            consumed_for_prerelease: None,
+            graduate: false,
+            confidential: false,
+            embargo: None,
+            advisory: Some("CVE-2024-12345".to_string()),
+        };
+
+        let serialized = serialize_changeset(&original).expect("should serialize");
+        assert!(serialized.contains("advisory: CVE-2024-12345"));
+
+        let parsed = parse_changeset(&serialized).expect("should parse");
+        assert_eq!(parsed, original);
+    }
+
     #[test]
     fn graduate_true_serialized() {
         let changeset = Changeset {
@@ -308,8 +433,12 @@ mod tests {
                 bump_type: BumpType::Major,
             }],
             category: ChangeCategory::Added,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: true,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&changeset).expect("should serialize");
@@ -328,8 +457,12 @@ mod tests {
                 bump_type: BumpType::Major,
             }],
             category: ChangeCategory::Added,
+            scope: None,
             consumed_for_prerelease: None,
             graduate: true,
+            confidential: false,
+            embargo: None,
+            advisory: None,
         };
 
         let serialized = serialize_changeset(&original).expect("should serialize");
@@ -0,0 +1,430 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use semver::Version;
+
+use crate::error::ProjectError;
+
+/// A kind of package that is released alongside the crates, such as an npm
+/// package or a Python project next to them in the repository.
+///
+/// Adapters find the packages in the directories listed under
+/// `extra-packages`, and rewrite their version on release. Changelogs,
+/// changesets and tags work the same for every package.
+pub trait PackageAdapter: Send + Sync {
+    /// Manifest file in the package directory, such as `package.json`.
+    fn manifest_file(&self) -> &'static str;
+
+    /// Name and version of the package `content` describes, or `None` when
+    /// the manifest describes no versioned package.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProjectError::PackageManifest` if the manifest is malformed, and
+    /// `ProjectError::InvalidVersion` if the version is not semver.
+    fn read_package(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+    ) -> Result<Option<(String, Version)>, ProjectError>;
+
+    /// Returns `content` with the package version set to `version`, leaving
+    /// the rest of the file as it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProjectError::PackageManifest` if the manifest has no version
+    /// to replace.
+    fn write_version(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+        version: &Version,
+    ) -> Result<String, ProjectError>;
+}
+
+/// The registered package adapters, tried in order.
+///
+/// The default registers Cargo, npm (`package.json`) and Python
+/// (`pyproject.toml`); adapters added with [`Self::register`] are tried before
+/// them.
+#[derive(Clone)]
+pub struct PackageAdapters {
+    adapters: Vec<Arc<dyn PackageAdapter>>,
+}
+
+impl PackageAdapters {
+    #[must_use]
+    pub fn register(mut self, adapter: Arc<dyn PackageAdapter>) -> Self {
+        self.adapters.insert(0, adapter);
+        self
+    }
+
+    /// The adapter for a manifest, by its file name.
+    #[must_use]
+    pub fn for_manifest(&self, manifest_path: &Path) -> Option<&dyn PackageAdapter> {
+        let file_name = manifest_path.file_name()?;
+        self.adapters
+            .iter()
+            .find(|adapter| file_name == adapter.manifest_file())
+            .map(AsRef::as_ref)
+    }
+
+    /// Reads the package in `dir` with the first adapter whose manifest is
+    /// there, returning it with the manifest path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read or parsed.
+    pub fn read_package(
+        &self,
+        dir: &Path,
+    ) -> Result<Option<(String, Version, PathBuf)>, ProjectError> {
+        for adapter in &self.adapters {
+            let manifest_path = dir.join(adapter.manifest_file());
+            if !manifest_path.is_file() {
+                continue;
+            }
+            let content = std::fs::read_to_string(&manifest_path).map_err(|source| {
+                ProjectError::ManifestRead {
+                    path: manifest_path.clone(),
+                    source,
+                }
+            })?;
+            if let Some((name, version)) = adapter.read_package(&manifest_path, &content)? {
+                return Ok(Some((name, version, manifest_path)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Default for PackageAdapters {
+    fn default() -> Self {
+        Self {
+            adapters: vec![
+                Arc::new(CargoAdapter),
+                Arc::new(NpmAdapter),
+                Arc::new(PythonAdapter),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Debug for PackageAdapters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.adapters.iter().map(|adapter| adapter.manifest_file()))
+            .finish()
+    }
+}
+
+/// Crates outside the workspace members, read from `Cargo.toml`.
+pub struct CargoAdapter;
+
+impl PackageAdapter for CargoAdapter {
+    fn manifest_file(&self) -> &'static str {
+        "Cargo.toml"
+    }
+
+    fn read_package(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+    ) -> Result<Option<(String, Version)>, ProjectError> {
+        read_toml_package(manifest_path, content, &[&["package"]])
+    }
+
+    fn write_version(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+        version: &Version,
+    ) -> Result<String, ProjectError> {
+        write_toml_version(manifest_path, content, &["package"], version)
+    }
+}
+
+/// npm packages, read from the `name` and `version` of `package.json`.
+pub struct NpmAdapter;
+
+impl PackageAdapter for NpmAdapter {
+    fn manifest_file(&self) -> &'static str {
+        "package.json"
+    }
+
+    fn read_package(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+    ) -> Result<Option<(String, Version)>, ProjectError> {
+        let manifest: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| manifest_error(manifest_path, &e))?;
+        let (Some(name), Some(version)) = (
+            manifest.get("name").and_then(serde_json::Value::as_str),
+            manifest.get("version").and_then(serde_json::Value::as_str),
+        ) else {
+            return Ok(None);
+        };
+        Ok(Some((
+            name.to_string(),
+            parse_version(manifest_path, version)?,
+        )))
+    }
+
+    fn write_version(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+        version: &Version,
+    ) -> Result<String, ProjectError> {
+        let wanted = version.to_string();
+        // Nested objects may have a "version" key as well; take the first
+        // string value whose replacement changes the top-level one.
+        for (key, _) in content.match_indices("\"version\"") {
+            let rest = &content[key + "\"version\"".len()..];
+            let Some(value) = rest.trim_start().strip_prefix(':') else {
+                continue;
+            };
+            let Some(value) = value.trim_start().strip_prefix('"') else {
+                continue;
+            };
+            let Some(length) = value.find('"') else {
+                continue;
+            };
+            let start = content.len() - value.len();
+            let updated = format!(
+                "{}{wanted}{}",
+                &content[..start],
+                &content[start + length..]
+            );
+            let is_top_level = serde_json::from_str::<serde_json::Value>(&updated)
+                .is_ok_and(|manifest| manifest["version"] == wanted.as_str());
+            if is_top_level {
+                return Ok(updated);
+            }
+        }
+        Err(ProjectError::PackageManifest {
+            path: manifest_path.to_path_buf(),
+            reason: "no top-level \"version\" string".to_string(),
+        })
+    }
+}
+
+/// Python projects, read from `[project]` or `[tool.poetry]` in
+/// `pyproject.toml`. Projects with a dynamic version have none to bump and
+/// are skipped.
+pub struct PythonAdapter;
+
+const PYTHON_TABLES: [&[&str]; 2] = [&["project"], &["tool", "poetry"]];
+
+impl PackageAdapter for PythonAdapter {
+    fn manifest_file(&self) -> &'static str {
+        "pyproject.toml"
+    }
+
+    fn read_package(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+    ) -> Result<Option<(String, Version)>, ProjectError> {
+        read_toml_package(manifest_path, content, &PYTHON_TABLES)
+    }
+
+    fn write_version(
+        &self,
+        manifest_path: &Path,
+        content: &str,
+        version: &Version,
+    ) -> Result<String, ProjectError> {
+        let table = PYTHON_TABLES
+            .into_iter()
+            .find(|table| toml_table_version(content, table).is_some())
+            .unwrap_or(PYTHON_TABLES[0]);
+        write_toml_version(manifest_path, content, table, version)
+    }
+}
+
+/// Name and version from the first of `tables` that has both.
+fn read_toml_package(
+    manifest_path: &Path,
+    content: &str,
+    tables: &[&[&str]],
+) -> Result<Option<(String, Version)>, ProjectError> {
+    let manifest: toml::Table =
+        toml::from_str(content).map_err(|source| ProjectError::ManifestParse {
+            path: manifest_path.to_path_buf(),
+            source,
+        })?;
+    for path in tables {
+        let Some(table) = path.iter().try_fold(&manifest, |table, key| {
+            table.get(*key).and_then(toml::Value::as_table)
+        }) else {
+            continue;
+        };
+        let name = table.get("name").and_then(toml::Value::as_str);
+        let version = table.get("version").and_then(toml::Value::as_str);
+        if let (Some(name), Some(version)) = (name, version) {
+            return Ok(Some((
+                name.to_string(),
+                parse_version(manifest_path, version)?,
+            )));
+        }
+    }
+    Ok(None)
+}
+
+/// Byte range of the quoted `version` value in the `[table]` section.
+fn toml_table_version(content: &str, table: &[&str]) -> Option<std::ops::Range<usize>> {
+    let header = format!("[{}]", table.join("."));
+    let mut in_table = false;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_table = trimmed == header;
+            continue;
+        }
+        if !in_table {
+            continue;
+        }
+        let Some(value) = trimmed.strip_prefix("version") else {
+            continue;
+        };
+        let Some(value) = value.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let Some(quote) = value.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+            continue;
+        };
+        let length = value[1..].find(quote)?;
+        let value_start = start + line.find(value)? + 1;
+        return Some(value_start..value_start + length);
+    }
+    None
+}
+
+fn write_toml_version(
+    manifest_path: &Path,
+    content: &str,
+    table: &[&str],
+    version: &Version,
+) -> Result<String, ProjectError> {
+    let range =
+        toml_table_version(content, table).ok_or_else(|| ProjectError::PackageManifest {
+            path: manifest_path.to_path_buf(),
+            reason: format!("no version in [{}]", table.join(".")),
+        })?;
+    Ok(format!(
+        "{}{version}{}",
+        &content[..range.start],
+        &content[range.end..]
+    ))
+}
+
+fn parse_version(manifest_path: &Path, version: &str) -> Result<Version, ProjectError> {
+    version
+        .parse()
+        .map_err(|source| ProjectError::InvalidVersion {
+            path: manifest_path.to_path_buf(),
+            version: version.to_string(),
+            source,
+        })
+}
+
+fn manifest_error(manifest_path: &Path, error: &dyn std::fmt::Display) -> ProjectError {
+    ProjectError::PackageManifest {
+        path: manifest_path.to_path_buf(),
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(v: &str) -> Version {
+        v.parse().expect("valid version")
+    }
+
+    #[test]
+    fn npm_adapter_bumps_only_the_top_level_version() -> anyhow::Result<()> {
+        let content = "{\n  \"engines\": { \"version\": \"1.0.0\" },\n  \"name\": \"web\",\n  \"version\": \"1.0.0\"\n}\n";
+        let path = Path::new("package.json");
+
+        assert_eq!(
+            NpmAdapter.read_package(path, content)?,
+            Some(("web".to_string(), version("1.0.0")))
+        );
+        assert_eq!(
+            NpmAdapter.write_version(path, content, &version("1.1.0"))?,
+            content.replace("\"version\": \"1.0.0\"\n", "\"version\": \"1.1.0\"\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn python_adapter_reads_project_and_poetry_tables() -> anyhow::Result<()> {
+        let path = Path::new("pyproject.toml");
+        let project = "[project]\nname = \"tool\"\nversion = \"0.3.0\" # bumped on release\n\n[tool.other]\nversion = \"9\"\n";
+        assert_eq!(
+            PythonAdapter.read_package(path, project)?,
+            Some(("tool".to_string(), version("0.3.0")))
+        );
+        assert_eq!(
+            PythonAdapter.write_version(path, project, &version("0.4.0"))?,
+            project.replace("0.3.0", "0.4.0")
+        );
+
+        let poetry = "[tool.poetry]\nname = 'tool'\nversion = '1.2.3'\n";
+        assert_eq!(
+            PythonAdapter.write_version(path, poetry, &version("2.0.0"))?,
+            poetry.replace("1.2.3", "2.0.0")
+        );
+
+        let dynamic = "[project]\nname = \"tool\"\ndynamic = [\"version\"]\n";
+        assert_eq!(PythonAdapter.read_package(path, dynamic)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn registered_adapters_take_precedence() {
+        struct Custom;
+        impl PackageAdapter for Custom {
+            fn manifest_file(&self) -> &'static str {
+                "package.json"
+            }
+            fn read_package(
+                &self,
+                _: &Path,
+                _: &str,
+            ) -> Result<Option<(String, Version)>, ProjectError> {
+                Ok(None)
+            }
+            fn write_version(
+                &self,
+                _: &Path,
+                content: &str,
+                _: &Version,
+            ) -> Result<String, ProjectError> {
+                Ok(content.to_string())
+            }
+        }
+
+        let adapters = PackageAdapters::default().register(Arc::new(Custom));
+        let adapter = adapters
+            .for_manifest(Path::new("web/package.json"))
+            .expect("adapter for package.json");
+
+        assert_eq!(
+            adapter
+                .write_version(Path::new("package.json"), "{}", &version("1.0.0"))
+                .ok(),
+            Some("{}".to_string())
+        );
+        assert!(adapters.for_manifest(Path::new("setup.py")).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+
+use crate::CargoProject;
+use crate::config::RootChangesetConfig;
+
+/// Where GitHub, GitLab, and Gitea look for a `CODEOWNERS` file, in order.
+const CODEOWNERS_LOCATIONS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+struct OwnerRule {
+    matcher: GlobSet,
+    owners: Vec<String>,
+}
+
+/// Path ownership read from a `CODEOWNERS` file.
+pub struct CodeOwners {
+    rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    /// Parses `CODEOWNERS` content.
+    ///
+    /// Patterns follow gitignore rules: a leading `/` anchors a pattern to the
+    /// repository root, a pattern without a slash matches at any depth, and a
+    /// pattern naming a directory covers everything below it. Lines with
+    /// patterns that cannot be compiled are skipped.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let matcher = compile_pattern(fields.next()?)?;
+                let owners = fields
+                    .take_while(|field| !field.starts_with('#'))
+                    .map(str::to_string)
+                    .collect();
+                Some(OwnerRule { matcher, owners })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Reads the first `CODEOWNERS` file found in the usual locations under
+    /// `root`, or `None` if there is none.
+    #[must_use]
+    pub fn find(root: &Path) -> Option<Self> {
+        CODEOWNERS_LOCATIONS
+            .iter()
+            .find_map(|location| fs::read_to_string(root.join(location)).ok())
+            .map(|content| Self::parse(&content))
+    }
+
+    /// Returns the owners of `path`, relative to the repository root.
+    ///
+    /// The last matching rule wins, so a later rule without owners removes
+    /// ownership set by an earlier one.
+    #[must_use]
+    pub fn owners_of(&self, path: &Path) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matcher.is_match(path))
+            .map_or(&[], |rule| rule.owners.as_slice())
+    }
+}
+
+fn compile_pattern(pattern: &str) -> Option<GlobSet> {
+    let anchored = pattern.starts_with('/');
+    let trimmed = pattern.trim_start_matches('/');
+    let directory = trimmed.ends_with('/');
+    let trimmed = trimmed.trim_end_matches('/');
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let base = if anchored || trimmed.contains('/') {
+        trimmed.to_string()
+    } else {
+        format!("**/{trimmed}")
+    };
+
+    let mut builder = GlobSetBuilder::new();
+    if !directory {
+        builder.add(glob(&base)?);
+    }
+    builder.add(glob(&format!("{base}/**"))?);
+    builder.build().ok()
+}
+
+fn glob(pattern: &str) -> Option<Glob> {
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .ok()
+}
+
+/// Owners of each package, keyed by package name.
+///
+/// Packages listed in the `owners` table use the owners configured there.
+/// Every other package is owned by whoever `CODEOWNERS` assigns its
+/// `Cargo.toml` to. Packages without owners are left out.
+#[must_use]
+pub fn package_owners(
+    project: &CargoProject,
+    config: &RootChangesetConfig,
+) -> BTreeMap<String, Vec<String>> {
+    let codeowners = CodeOwners::find(&project.root);
+
+    project
+        .packages
+        .iter()
+        .filter_map(|package| {
+            let owners = if let Some(owners) = config.package_owners().get(&package.name) {
+                owners.clone()
+            } else {
+                let manifest_path = project.manifest_path(package);
+                let manifest = manifest_path
+                    .strip_prefix(&project.root)
+                    .unwrap_or(&manifest_path);
+                codeowners.as_ref()?.owners_of(manifest).to_vec()
+            };
+            (!owners.is_empty()).then(|| (package.name.clone(), owners))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use changeset_core::PackageInfo;
+    use semver::Version;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::ProjectKind;
+
+    const CODEOWNERS: &str = "\
+# Default owners
+*                 @org/maintainers
+/crates/web/      @org/web @alice
+docs/**/*.md      docs@example.com
+*.lock
+";
+
+    fn owners<'a>(codeowners: &'a CodeOwners, path: &str) -> &'a [String] {
+        codeowners.owners_of(Path::new(path))
+    }
+
+    #[test]
+    fn last_matching_rule_wins() {
+        let codeowners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(owners(&codeowners, "README.md"), ["@org/maintainers"]);
+        assert_eq!(
+            owners(&codeowners, "crates/web/src/lib.rs"),
+            ["@org/web", "@alice"]
+        );
+        assert_eq!(
+            owners(&codeowners, "docs/guide/intro.md"),
+            ["docs@example.com"]
+        );
+        assert!(owners(&codeowners, "Cargo.lock").is_empty());
+    }
+
+    #[test]
+    fn anchored_directory_does_not_match_nested_directories() {
+        let codeowners = CodeOwners::parse("/crates/web/ @org/web\n");
+
+        assert!(owners(&codeowners, "other/crates/web/lib.rs").is_empty());
+        assert!(owners(&codeowners, "crates/web").is_empty());
+    }
+
+    #[test]
+    fn unanchored_names_match_at_any_depth() {
+        let codeowners = CodeOwners::parse("build.rs @org/build # generated\n");
+
+        assert_eq!(owners(&codeowners, "crates/core/build.rs"), ["@org/build"]);
+    }
+
+    #[test]
+    fn config_owners_take_precedence_over_codeowners() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        fs::create_dir_all(dir.path().join(".github"))?;
+        fs::write(
+            dir.path().join(".github/CODEOWNERS"),
+            "/crates/ @org/maintainers\n/crates/web/ @org/web\n",
+        )?;
+        let package = |name: &str| PackageInfo {
+            name: name.to_string(),
+            version: Version::new(1, 0, 0),
+            path: dir.path().join("crates").join(name),
+        };
+        let project = CargoProject {
+            root: dir.path().to_path_buf(),
+            kind: ProjectKind::VirtualWorkspace,
+            packages: vec![package("web"), package("core"), package("cli")],
+            missing_members: Vec::<PathBuf>::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        };
+        let config =
+            RootChangesetConfig::default().with_package_owners("cli", vec!["@org/cli".to_string()]);
+
+        let owners = package_owners(&project, &config);
+
+        assert_eq!(owners["web"], ["@org/web"]);
+        assert_eq!(owners["core"], ["@org/maintainers"]);
+        assert_eq!(owners["cli"], ["@org/cli"]);
+
+        Ok(())
+    }
+}
@@ -1,12 +1,19 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
-use changeset_changelog::{ChangelogConfig, ChangelogLocation, ComparisonLinksSetting};
-use changeset_core::ZeroVersionBehavior;
+use changeset_changelog::ChangelogConfig;
+use changeset_core::{CategoryDefinition, CategorySet, ChangeCategory, ZeroVersionBehavior};
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use semver::Version;
 
 use crate::error::ProjectError;
-use crate::manifest::{ChangesetMetadata, TagFormatValue, read_manifest};
+use crate::manifest::{
+    ChangesetMetadata, ChangesetNamesValue, CommitBodyValue, CommitStrategyValue,
+    ConsumedStateValue, OfflineBehaviorValue, RequireChangesetValue, TagBodyValue, TagFormatValue,
+    WebhookFormatValue, read_manifest, read_manifest_with_overrides,
+};
+use crate::overrides::ConfigOverride;
 use crate::project::{CargoProject, ProjectKind};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -16,6 +23,82 @@ pub enum TagFormat {
     CratePrefixed,
 }
 
+/// What follows the title line in annotated tag messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagBody {
+    /// Only the `Release <package> v<version>` title.
+    #[default]
+    Summary,
+    /// The changelog section written for the released version.
+    Changelog,
+}
+
+/// How the body of release commit messages is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommitBody {
+    /// One `- <package> <old> -> <new>` line per released package.
+    #[default]
+    List,
+    /// Packages under `Major`, `Minor` and `Patch` headings, each followed by
+    /// the changelog entries written for its release.
+    Grouped,
+}
+
+/// How the release changes are split into commits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommitStrategy {
+    /// One commit for the whole release, with every tag pointing at it.
+    #[default]
+    Single,
+    /// One commit per released package, each tagged individually.
+    PerPackage,
+}
+
+/// A `Name <email>` pair recorded on release commits instead of the user's git config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitIdentity {
+    name: String,
+    email: String,
+}
+
+impl GitIdentity {
+    /// Parses `Name <email>`, e.g. `release-bot <bot@company.com>` or a GitHub
+    /// noreply address such as
+    /// `github-actions[bot] <41898282+github-actions[bot]@users.noreply.github.com>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name or email is missing, or the email has no `@`.
+    pub fn parse(value: &str) -> Result<Self, ProjectError> {
+        let invalid = || ProjectError::InvalidGitIdentity {
+            value: value.to_string(),
+        };
+        let (name, rest) = value.trim().split_once('<').ok_or_else(invalid)?;
+        let email = rest.strip_suffix('>').ok_or_else(invalid)?.trim();
+        let name = name.trim();
+        let valid_email = email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty() && !domain.is_empty() && !email.contains(['<', '>', ' '])
+        });
+        if name.is_empty() || !valid_email {
+            return Err(invalid());
+        }
+        Ok(Self {
+            name: name.to_string(),
+            email: email.to_string(),
+        })
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
+
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct GitConfig {
@@ -23,8 +106,18 @@ pub struct GitConfig {
     tags: bool,
     keep_changesets: bool,
     tag_format: TagFormat,
+    tag_body: TagBody,
+    commit_strategy: CommitStrategy,
     commit_title_template: String,
     changes_in_body: bool,
+    commit_body: CommitBody,
+    commit_body_max_length: Option<usize>,
+    commit_body_truncation: String,
+    release_branch: Option<String>,
+    ignore_submodule_changes: bool,
+    remote: Option<String>,
+    commit_author: Option<GitIdentity>,
+    commit_committer: Option<GitIdentity>,
 }
 
 impl Default for GitConfig {
@@ -34,8 +127,18 @@ impl Default for GitConfig {
             tags: true,
             keep_changesets: false,
             tag_format: TagFormat::default(),
+            tag_body: TagBody::default(),
+            commit_strategy: CommitStrategy::default(),
             commit_title_template: String::from("{new-version}"),
             changes_in_body: true,
+            commit_body: CommitBody::default(),
+            commit_body_max_length: None,
+            commit_body_truncation: String::from("..."),
+            release_branch: None,
+            ignore_submodule_changes: false,
+            remote: None,
+            commit_author: None,
+            commit_committer: None,
         }
     }
 }
@@ -61,6 +164,16 @@ impl GitConfig {
         self.tag_format
     }
 
+    #[must_use]
+    pub fn tag_body(&self) -> TagBody {
+        self.tag_body
+    }
+
+    #[must_use]
+    pub fn commit_strategy(&self) -> CommitStrategy {
+        self.commit_strategy
+    }
+
     #[must_use]
     pub fn commit_title_template(&self) -> &str {
         &self.commit_title_template
@@ -71,597 +184,2793 @@ impl GitConfig {
         self.changes_in_body
     }
 
+    #[must_use]
+    pub fn commit_body(&self) -> CommitBody {
+        self.commit_body
+    }
+
+    /// Longest release commit body in characters; `None` leaves it uncut.
+    #[must_use]
+    pub fn commit_body_max_length(&self) -> Option<usize> {
+        self.commit_body_max_length
+    }
+
+    /// Line that ends a release commit body cut at the maximum length.
+    #[must_use]
+    pub fn commit_body_truncation(&self) -> &str {
+        &self.commit_body_truncation
+    }
+
+    #[must_use]
+    pub fn release_branch(&self) -> Option<&str> {
+        self.release_branch.as_deref()
+    }
+
+    /// Whether submodules that moved or have local changes still count as a
+    /// clean working tree.
+    #[must_use]
+    pub fn ignore_submodule_changes(&self) -> bool {
+        self.ignore_submodule_changes
+    }
+
+    /// Name of the git remote used for repository links; `None` picks one automatically.
+    #[must_use]
+    pub fn remote(&self) -> Option<&str> {
+        self.remote.as_deref()
+    }
+
+    /// Author of release commits; `None` uses the user's git config.
+    #[must_use]
+    pub fn commit_author(&self) -> Option<&GitIdentity> {
+        self.commit_author.as_ref()
+    }
+
+    /// Committer of release commits, defaulting to the configured author.
+    #[must_use]
+    pub fn commit_committer(&self) -> Option<&GitIdentity> {
+        self.commit_committer
+            .as_ref()
+            .or(self.commit_author.as_ref())
+    }
+
     #[cfg(any(test, feature = "testing"))]
     #[must_use]
     pub fn with_changes_in_body(mut self, changes_in_body: bool) -> Self {
         self.changes_in_body = changes_in_body;
         self
     }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_commit_body(mut self, commit_body: CommitBody) -> Self {
+        self.commit_body = commit_body;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_commit_body_max_length(mut self, max_length: usize) -> Self {
+        self.commit_body_max_length = Some(max_length);
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_tag_body(mut self, tag_body: TagBody) -> Self {
+        self.tag_body = tag_body;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_commit_strategy(mut self, commit_strategy: CommitStrategy) -> Self {
+        self.commit_strategy = commit_strategy;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_release_branch(mut self, branch: impl Into<String>) -> Self {
+        self.release_branch = Some(branch.into());
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_remote(mut self, remote: impl Into<String>) -> Self {
+        self.remote = Some(remote.into());
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_commit_author(mut self, author: GitIdentity) -> Self {
+        self.commit_author = Some(author);
+        self
+    }
+}
+
+/// Payload shape used when posting release notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebhookFormat {
+    /// JSON object with the rendered message plus structured release data.
+    #[default]
+    Generic,
+    /// Slack incoming webhook (`{"text": ...}`).
+    Slack,
+    /// Discord webhook (`{"content": ...}`).
+    Discord,
+}
+
+/// What `--offline` does with release steps that need the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OfflineBehavior {
+    /// Skip the step and carry on.
+    #[default]
+    Skip,
+    /// Fail before anything is changed.
+    Error,
+}
+
+/// When `verify` asks for a changeset for a changed package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequireChangeset {
+    /// Every change to the package needs a changeset.
+    #[default]
+    Always,
+    /// Only packages with a library target need one; packages that only ship
+    /// binaries or examples do not.
+    LibraryOnly,
+    /// Changes to the package never need a changeset.
+    Never,
+}
+
+/// Where pre-release consumption of changesets is recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConsumedStateStorage {
+    /// A `consumedForPrerelease` field in each changeset's front matter.
+    #[default]
+    Frontmatter,
+    /// A separate `consumed.toml` in the changeset directory, keyed by file hash.
+    File,
+}
+
+/// How `add` names new changeset files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChangesetNaming {
+    /// A random three-word name such as `brave-blue-fox.md`.
+    #[default]
+    Random,
+    /// The current branch followed by a content hash, such as
+    /// `feature-login-3f9a2c1d.md`, so concurrent branches never pick the same name.
+    Branch,
 }
 
 #[derive(Debug, Clone)]
-pub struct RootChangesetConfig {
-    ignored_files: GlobSet,
-    changeset_dir: PathBuf,
-    changelog_config: ChangelogConfig,
-    git_config: GitConfig,
-    zero_version_behavior: ZeroVersionBehavior,
+pub struct NotificationConfig {
+    webhook_url: Option<String>,
+    webhook_format: WebhookFormat,
+    message_template: String,
 }
 
-impl Default for RootChangesetConfig {
+impl Default for NotificationConfig {
     fn default() -> Self {
         Self {
-            ignored_files: GlobSet::empty(),
-            changeset_dir: PathBuf::from(crate::DEFAULT_CHANGESET_DIR),
-            changelog_config: ChangelogConfig::default(),
-            git_config: GitConfig::default(),
-            zero_version_behavior: ZeroVersionBehavior::default(),
+            webhook_url: None,
+            webhook_format: WebhookFormat::default(),
+            message_template: String::from("Released {releases}"),
         }
     }
 }
 
-impl RootChangesetConfig {
+impl NotificationConfig {
     #[must_use]
-    pub fn ignored_files(&self) -> &GlobSet {
-        &self.ignored_files
+    pub fn webhook_url(&self) -> Option<&str> {
+        self.webhook_url.as_deref()
     }
 
     #[must_use]
-    pub fn is_ignored(&self, path: &Path) -> bool {
-        self.ignored_files.is_match(path)
+    pub fn webhook_format(&self) -> WebhookFormat {
+        self.webhook_format
     }
 
     #[must_use]
-    pub fn changeset_dir(&self) -> &Path {
-        &self.changeset_dir
+    pub fn message_template(&self) -> &str {
+        &self.message_template
     }
 
+    #[cfg(any(test, feature = "testing"))]
     #[must_use]
-    pub fn changelog_config(&self) -> &ChangelogConfig {
-        &self.changelog_config
+    pub fn with_webhook(mut self, url: impl Into<String>, format: WebhookFormat) -> Self {
+        self.webhook_url = Some(url.into());
+        self.webhook_format = format;
+        self
     }
 
+    #[cfg(any(test, feature = "testing"))]
     #[must_use]
-    pub fn git_config(&self) -> &GitConfig {
-        &self.git_config
+    pub fn with_message_template(mut self, template: impl Into<String>) -> Self {
+        self.message_template = template.into();
+        self
     }
+}
+
+/// Settings for linting changeset summaries on `add` and `verify`.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    enabled: bool,
+    denied_words: Vec<String>,
+}
 
+impl LintConfig {
     #[must_use]
-    pub fn zero_version_behavior(&self) -> ZeroVersionBehavior {
-        self.zero_version_behavior
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Words rejected in summaries, matched case-insensitively as whole words.
+    #[must_use]
+    pub fn denied_words(&self) -> &[String] {
+        &self.denied_words
     }
 
     #[cfg(any(test, feature = "testing"))]
     #[must_use]
-    pub fn with_git_config(mut self, git_config: GitConfig) -> Self {
-        self.git_config = git_config;
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_denied_words(mut self, words: Vec<String>) -> Self {
+        self.denied_words = words;
         self
     }
 }
 
-#[derive(Debug, Default)]
-pub struct PackageChangesetConfig {
-    ignored_files: GlobSet,
+/// Allowed values for the optional changeset `scope` field.
+#[derive(Debug, Clone, Default)]
+pub struct ScopeConfig {
+    scopes: Vec<String>,
+    required: bool,
 }
 
-impl PackageChangesetConfig {
+impl ScopeConfig {
+    /// Allowed scopes; empty means any scope is accepted.
     #[must_use]
-    pub fn ignored_files(&self) -> &GlobSet {
-        &self.ignored_files
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
     }
 
+    /// Whether every changeset must name a scope.
     #[must_use]
-    pub fn is_ignored(&self, path: &Path) -> bool {
-        self.ignored_files.is_match(path)
+    pub fn required(&self) -> bool {
+        self.required
     }
-}
 
-fn build_glob_set(patterns: &[String]) -> Result<GlobSet, ProjectError> {
-    let mut builder = GlobSetBuilder::new();
-    for pattern in patterns {
-        let glob = Glob::new(pattern).map_err(|source| ProjectError::GlobPattern {
-            pattern: pattern.clone(),
-            source,
-        })?;
-        builder.add(glob);
+    #[must_use]
+    pub fn is_allowed(&self, scope: &str) -> bool {
+        self.scopes.is_empty() || self.scopes.iter().any(|allowed| allowed == scope)
     }
-    builder.build().map_err(|source| ProjectError::GlobPattern {
-        pattern: patterns.join(", "),
-        source,
-    })
-}
 
-fn build_changelog_config(
-    changelog: Option<ChangelogLocation>,
-    comparison_links: Option<ComparisonLinksSetting>,
-    comparison_links_template: Option<String>,
-) -> ChangelogConfig {
-    ChangelogConfig {
-        changelog: changelog.unwrap_or_default(),
-        comparison_links: comparison_links.unwrap_or_default(),
-        comparison_links_template,
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_scopes(mut self, scopes: Vec<String>) -> Self {
+        self.scopes = scopes;
+        self
     }
-}
 
-fn build_git_config(metadata: Option<&ChangesetMetadata>) -> GitConfig {
-    let defaults = GitConfig::default();
-    match metadata {
-        None => defaults,
-        Some(cs) => GitConfig {
-            commit: cs.commit.unwrap_or(defaults.commit),
-            tags: cs.tags.unwrap_or(defaults.tags),
-            keep_changesets: cs.keep_changesets.unwrap_or(defaults.keep_changesets),
-            tag_format: cs.tag_format.map_or(defaults.tag_format, |tf| match tf {
-                TagFormatValue::VersionOnly => TagFormat::VersionOnly,
-                TagFormatValue::CratePrefixed => TagFormat::CratePrefixed,
-            }),
-            commit_title_template: cs
-                .commit_title_template
-                .clone()
-                .unwrap_or(defaults.commit_title_template),
-            changes_in_body: cs.changes_in_body.unwrap_or(defaults.changes_in_body),
-        },
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
     }
 }
 
-/// Parses root configuration from workspace metadata.
-///
-/// # Errors
+/// Rewrites version strings in a file that is not a Cargo manifest.
 ///
-/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
-fn parse_workspace_root_config(project_root: &Path) -> Result<RootChangesetConfig, ProjectError> {
-    let manifest_path = project_root.join("Cargo.toml");
-    let manifest = read_manifest(&manifest_path)?;
-
-    let changeset_metadata = manifest
-        .workspace
-        .and_then(|ws| ws.metadata)
-        .and_then(|meta| meta.changeset);
-
-    let patterns = changeset_metadata
-        .as_ref()
-        .map(|cs| cs.ignored_files.clone())
-        .unwrap_or_default();
-
-    let changeset_dir = changeset_metadata
-        .as_ref()
-        .and_then(|cs| cs.changeset_dir.clone())
-        .unwrap_or_else(|| crate::DEFAULT_CHANGESET_DIR.to_string());
+/// `pattern` is a regular expression in which `{version}` marks the version to
+/// replace and `{package}` matches the name of a released package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionStamp {
+    files: String,
+    pattern: String,
+    package: Option<String>,
+}
 
-    let ignored_files = build_glob_set(&patterns)?;
+impl VersionStamp {
+    #[must_use]
+    pub fn new(files: impl Into<String>, pattern: impl Into<String>) -> Self {
+        Self {
+            files: files.into(),
+            pattern: pattern.into(),
+            package: None,
+        }
+    }
 
-    let changelog_config = build_changelog_config(
-        changeset_metadata.as_ref().and_then(|cs| cs.changelog),
-        changeset_metadata
-            .as_ref()
-            .and_then(|cs| cs.comparison_links),
-        changeset_metadata
-            .as_ref()
-            .and_then(|cs| cs.comparison_links_template.clone()),
-    );
+    /// Stamps the version of `package` instead of inferring it from the pattern.
+    #[must_use]
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
 
-    let git_config = build_git_config(changeset_metadata.as_ref());
+    /// Glob selecting the stamped files, relative to the project root.
+    #[must_use]
+    pub fn files(&self) -> &str {
+        &self.files
+    }
 
-    let zero_version_behavior = changeset_metadata
-        .as_ref()
-        .and_then(|cs| cs.zero_version_behavior)
-        .unwrap_or_default();
+    #[must_use]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
 
-    Ok(RootChangesetConfig {
-        ignored_files,
-        changeset_dir: PathBuf::from(changeset_dir),
-        changelog_config,
-        git_config,
-        zero_version_behavior,
-    })
+    #[must_use]
+    pub fn package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
 }
 
-/// Parses root configuration from package metadata (for single-package projects).
+/// A named release channel such as `stable`, `beta` or `nightly`.
 ///
-/// # Errors
-///
-/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
-fn parse_package_root_config(project_root: &Path) -> Result<RootChangesetConfig, ProjectError> {
-    let manifest_path = project_root.join("Cargo.toml");
-    let manifest = read_manifest(&manifest_path)?;
+/// A channel decides the prerelease tag of the versions released on it, a
+/// namespace put in front of their git tags, and whether `publish` uploads
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseChannel {
+    name: String,
+    prerelease: Option<String>,
+    tag_namespace: String,
+    publish: bool,
+}
 
-    let changeset_metadata = manifest
-        .package
-        .and_then(|pkg| pkg.metadata)
-        .and_then(|meta| meta.changeset);
+impl ReleaseChannel {
+    /// Name of the channel packages are released on unless configured otherwise.
+    pub const STABLE: &'static str = "stable";
 
-    let patterns = changeset_metadata
-        .as_ref()
-        .map(|cs| cs.ignored_files.clone())
-        .unwrap_or_default();
+    /// A channel releasing stable versions under plain tags, published.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            prerelease: None,
+            tag_namespace: String::new(),
+            publish: true,
+        }
+    }
 
-    let changeset_dir = changeset_metadata
-        .as_ref()
-        .and_then(|cs| cs.changeset_dir.clone())
-        .unwrap_or_else(|| crate::DEFAULT_CHANGESET_DIR.to_string());
+    #[must_use]
+    pub fn with_prerelease(mut self, tag: impl Into<String>) -> Self {
+        self.prerelease = Some(tag.into());
+        self
+    }
 
-    let ignored_files = build_glob_set(&patterns)?;
+    #[must_use]
+    pub fn with_tag_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.tag_namespace = namespace.into();
+        self
+    }
 
-    let changelog_config = build_changelog_config(
-        changeset_metadata.as_ref().and_then(|cs| cs.changelog),
-        changeset_metadata
-            .as_ref()
-            .and_then(|cs| cs.comparison_links),
-        changeset_metadata
-            .as_ref()
-            .and_then(|cs| cs.comparison_links_template.clone()),
-    );
+    #[must_use]
+    pub fn with_publish(mut self, publish: bool) -> Self {
+        self.publish = publish;
+        self
+    }
 
-    let git_config = build_git_config(changeset_metadata.as_ref());
+    /// The built-in `stable`, `beta` and `nightly` channels.
+    #[must_use]
+    pub fn builtin() -> Vec<Self> {
+        vec![
+            Self::new(Self::STABLE),
+            Self::new("beta").with_prerelease("beta"),
+            Self::new("nightly")
+                .with_prerelease("nightly")
+                .with_tag_namespace("nightly/")
+                .with_publish(false),
+        ]
+    }
 
-    let zero_version_behavior = changeset_metadata
-        .as_ref()
-        .and_then(|cs| cs.zero_version_behavior)
-        .unwrap_or_default();
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 
-    Ok(RootChangesetConfig {
-        ignored_files,
-        changeset_dir: PathBuf::from(changeset_dir),
-        changelog_config,
-        git_config,
-        zero_version_behavior,
-    })
+    /// Prerelease tag of the versions released on the channel; `None` releases
+    /// stable versions, graduating prereleases.
+    #[must_use]
+    pub fn prerelease(&self) -> Option<&str> {
+        self.prerelease.as_deref()
+    }
+
+    /// Put in front of the git tag of every version released on the channel,
+    /// e.g. `nightly/` for `nightly/my-crate@v1.2.0-nightly.1`.
+    #[must_use]
+    pub fn tag_namespace(&self) -> &str {
+        &self.tag_namespace
+    }
+
+    /// Whether `publish` uploads versions released on the channel.
+    #[must_use]
+    pub fn publish(&self) -> bool {
+        self.publish
+    }
+}
+
+/// Version badges and links in README files, set through `readme-badges`
+/// and `readme-badge-patterns`.
+///
+/// In the root configuration, `enabled` turns the updates on for every
+/// package. A package's own setting overrides it, and its patterns are used
+/// in addition to the root ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadmeBadges {
+    enabled: Option<bool>,
+    patterns: Vec<String>,
+}
+
+impl ReadmeBadges {
+    #[must_use]
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: Some(enabled),
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Adds stamp patterns, with `{package}` and `{version}` placeholders,
+    /// to the built-in badge and link patterns.
+    #[must_use]
+    pub fn with_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.patterns = patterns;
+        self
+    }
+
+    #[must_use]
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    #[must_use]
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+}
+
+/// An additional changeset directory owned by a team or component, set
+/// through the `changeset-dirs` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedChangesetDir {
+    owner: String,
+    path: PathBuf,
+}
+
+impl OwnedChangesetDir {
+    #[must_use]
+    pub fn new(owner: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            owner: owner.into(),
+            path: path.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// The directory, relative to the project root.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// How `new-crate` scaffolds a workspace member, set through the `new-crate`
+/// table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewCrateTemplate {
+    path: String,
+    version: Version,
+    edition: String,
+    package_metadata: toml::Table,
+}
+
+impl Default for NewCrateTemplate {
+    fn default() -> Self {
+        Self {
+            path: "crates/{name}".to_string(),
+            version: Version::new(0, 1, 0),
+            edition: "2024".to_string(),
+            package_metadata: toml::Table::new(),
+        }
+    }
+}
+
+impl NewCrateTemplate {
+    /// Directory of the crate named `name`, relative to the project root.
+    #[must_use]
+    pub fn path(&self, name: &str) -> PathBuf {
+        PathBuf::from(self.path.replace("{name}", name))
+    }
+
+    #[must_use]
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    #[must_use]
+    pub fn edition(&self) -> &str {
+        &self.edition
+    }
+
+    /// Settings written to the new crate's `[package.metadata.changeset]`.
+    #[must_use]
+    pub fn package_metadata(&self) -> &toml::Table {
+        &self.package_metadata
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_package_metadata(mut self, package_metadata: toml::Table) -> Self {
+        self.package_metadata = package_metadata;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RootChangesetConfig {
+    ignored_files: GlobSet,
+    changeset_dir: PathBuf,
+    owned_changeset_dirs: Vec<OwnedChangesetDir>,
+    changelog_config: ChangelogConfig,
+    git_config: GitConfig,
+    zero_version_behavior: ZeroVersionBehavior,
+    notification_config: NotificationConfig,
+    lint_config: LintConfig,
+    display_names: HashMap<String, String>,
+    package_owners: BTreeMap<String, Vec<String>>,
+    ignore_optional_dependencies: bool,
+    categories: CategorySet,
+    scope_config: ScopeConfig,
+    release_pre_checks: Vec<String>,
+    version_stamps: Vec<VersionStamp>,
+    readme_badges: ReadmeBadges,
+    offline_behavior: OfflineBehavior,
+    stale_changeset_releases: usize,
+    consumed_state_storage: ConsumedStateStorage,
+    transitive_coverage_depth: usize,
+    changeset_naming: ChangesetNaming,
+    new_crate_template: NewCrateTemplate,
+    channels: Vec<ReleaseChannel>,
+}
+
+/// Releases a changeset may stay pending through before `status` warns about it.
+const DEFAULT_STALE_CHANGESET_RELEASES: usize = 3;
+
+impl Default for RootChangesetConfig {
+    fn default() -> Self {
+        Self {
+            ignored_files: GlobSet::empty(),
+            changeset_dir: PathBuf::from(crate::DEFAULT_CHANGESET_DIR),
+            owned_changeset_dirs: Vec::new(),
+            changelog_config: ChangelogConfig::default(),
+            git_config: GitConfig::default(),
+            zero_version_behavior: ZeroVersionBehavior::default(),
+            notification_config: NotificationConfig::default(),
+            lint_config: LintConfig::default(),
+            display_names: HashMap::new(),
+            package_owners: BTreeMap::new(),
+            ignore_optional_dependencies: false,
+            categories: CategorySet::builtin(),
+            scope_config: ScopeConfig::default(),
+            release_pre_checks: Vec::new(),
+            version_stamps: Vec::new(),
+            readme_badges: ReadmeBadges::default(),
+            offline_behavior: OfflineBehavior::default(),
+            stale_changeset_releases: DEFAULT_STALE_CHANGESET_RELEASES,
+            consumed_state_storage: ConsumedStateStorage::default(),
+            transitive_coverage_depth: 0,
+            changeset_naming: ChangesetNaming::default(),
+            new_crate_template: NewCrateTemplate::default(),
+            channels: ReleaseChannel::builtin(),
+        }
+    }
 }
 
-/// Parses the root changeset configuration based on project kind.
-///
-/// For single-package projects, reads from `[package.metadata.changeset]`.
-/// For workspaces, reads from `[workspace.metadata.changeset]`.
-///
-/// # Errors
-///
-/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
-pub fn parse_root_config(project: &CargoProject) -> Result<RootChangesetConfig, ProjectError> {
-    match project.kind {
-        ProjectKind::SinglePackage => parse_package_root_config(&project.root),
-        ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => {
-            parse_workspace_root_config(&project.root)
+impl RootChangesetConfig {
+    #[must_use]
+    pub fn ignored_files(&self) -> &GlobSet {
+        &self.ignored_files
+    }
+
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignored_files.is_match(path)
+    }
+
+    /// The primary changeset directory. New changesets and release state
+    /// files are written here.
+    #[must_use]
+    pub fn changeset_dir(&self) -> &Path {
+        &self.changeset_dir
+    }
+
+    /// Additional changeset directories, e.g. one per team, whose changesets
+    /// are merged with the primary directory's when planning a release.
+    #[must_use]
+    pub fn owned_changeset_dirs(&self) -> &[OwnedChangesetDir] {
+        &self.owned_changeset_dirs
+    }
+
+    /// The primary changeset directory followed by the owned ones.
+    pub fn changeset_dirs(&self) -> impl Iterator<Item = &Path> {
+        std::iter::once(self.changeset_dir.as_path()).chain(
+            self.owned_changeset_dirs
+                .iter()
+                .map(OwnedChangesetDir::path),
+        )
+    }
+
+    /// Returns the owner of the directory a changeset file lives in, or
+    /// `None` for changesets in the primary directory.
+    ///
+    /// `changeset` may be relative to the project root or absolute under
+    /// `project_root`.
+    #[must_use]
+    pub fn changeset_owner(&self, project_root: &Path, changeset: &Path) -> Option<&str> {
+        let relative = changeset.strip_prefix(project_root).unwrap_or(changeset);
+        self.owned_changeset_dirs
+            .iter()
+            .find(|dir| relative.starts_with(&dir.path))
+            .map(OwnedChangesetDir::owner)
+    }
+
+    #[must_use]
+    pub fn changelog_config(&self) -> &ChangelogConfig {
+        &self.changelog_config
+    }
+
+    #[must_use]
+    pub fn git_config(&self) -> &GitConfig {
+        &self.git_config
+    }
+
+    #[must_use]
+    pub fn zero_version_behavior(&self) -> ZeroVersionBehavior {
+        self.zero_version_behavior
+    }
+
+    #[must_use]
+    pub fn notification_config(&self) -> &NotificationConfig {
+        &self.notification_config
+    }
+
+    #[must_use]
+    pub fn lint_config(&self) -> &LintConfig {
+        &self.lint_config
+    }
+
+    /// Human-friendly names keyed by crate name, used in changelogs, commit
+    /// messages, and status output. Manifests always keep the crate name.
+    #[must_use]
+    pub fn display_names(&self) -> &HashMap<String, String> {
+        &self.display_names
+    }
+
+    /// Owners of packages from the `owners` table, keyed by package name.
+    /// Packages not listed fall back to `CODEOWNERS`.
+    #[must_use]
+    pub fn package_owners(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.package_owners
+    }
+
+    /// Returns the configured display name for `package`, or the crate name itself.
+    #[must_use]
+    pub fn display_name<'a>(&'a self, package: &'a str) -> &'a str {
+        self.display_names
+            .get(package)
+            .map_or(package, String::as_str)
+    }
+
+    /// Whether release leaves version requirements on optional dependencies unchanged.
+    #[must_use]
+    pub fn ignore_optional_dependencies(&self) -> bool {
+        self.ignore_optional_dependencies
+    }
+
+    /// Change categories accepted in changesets, in changelog order.
+    #[must_use]
+    pub fn categories(&self) -> &CategorySet {
+        &self.categories
+    }
+
+    #[must_use]
+    pub fn scope_config(&self) -> &ScopeConfig {
+        &self.scope_config
+    }
+
+    /// Commands that must succeed before a release changes any manifest.
+    ///
+    /// A `{package}` placeholder makes the command run once per released package.
+    #[must_use]
+    pub fn release_pre_checks(&self) -> &[String] {
+        &self.release_pre_checks
+    }
+
+    /// Files outside the manifests whose version strings are rewritten on release.
+    #[must_use]
+    pub fn version_stamps(&self) -> &[VersionStamp] {
+        &self.version_stamps
+    }
+
+    /// The built-in release channels with the `channels` table applied.
+    #[must_use]
+    pub fn channels(&self) -> &[ReleaseChannel] {
+        &self.channels
+    }
+
+    #[must_use]
+    pub fn channel(&self, name: &str) -> Option<&ReleaseChannel> {
+        self.channels.iter().find(|channel| channel.name == name)
+    }
+
+    /// The channel `version` was released on: the one whose prerelease tag
+    /// starts the version's prerelease, or `stable` for stable versions.
+    /// `None` for a prerelease of no channel.
+    #[must_use]
+    pub fn channel_of_version(&self, version: &Version) -> Option<&ReleaseChannel> {
+        let tag = version.pre.split('.').next().filter(|tag| !tag.is_empty());
+        self.channels
+            .iter()
+            .find(|channel| channel.prerelease.as_deref() == tag)
+    }
+
+    /// Version badges and links rewritten in the README files of released
+    /// packages.
+    #[must_use]
+    pub fn readme_badges(&self) -> &ReadmeBadges {
+        &self.readme_badges
+    }
+
+    /// What `--offline` does with steps that need the network.
+    #[must_use]
+    pub fn offline_behavior(&self) -> OfflineBehavior {
+        self.offline_behavior
+    }
+
+    /// Number of releases a changeset may stay pending through before
+    /// `status` reports it as stale; `0` disables the warning.
+    #[must_use]
+    pub fn stale_changeset_releases(&self) -> usize {
+        self.stale_changeset_releases
+    }
+
+    /// How many levels of workspace dependents `verify` also asks a changeset
+    /// for when a package changes; `0` checks only the changed packages.
+    #[must_use]
+    pub fn transitive_coverage_depth(&self) -> usize {
+        self.transitive_coverage_depth
+    }
+
+    /// Where pre-releases record which changesets they consumed.
+    #[must_use]
+    pub fn consumed_state_storage(&self) -> ConsumedStateStorage {
+        self.consumed_state_storage
+    }
+
+    /// How `add` names new changeset files.
+    #[must_use]
+    pub fn changeset_naming(&self) -> ChangesetNaming {
+        self.changeset_naming
+    }
+
+    #[must_use]
+    pub fn new_crate_template(&self) -> &NewCrateTemplate {
+        &self.new_crate_template
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_changeset_dir(mut self, changeset_dir: impl Into<PathBuf>) -> Self {
+        self.changeset_dir = changeset_dir.into();
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_owned_changeset_dir(
+        mut self,
+        owner: impl Into<String>,
+        path: impl Into<PathBuf>,
+    ) -> Self {
+        self.owned_changeset_dirs
+            .push(OwnedChangesetDir::new(owner, path));
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_changelog_config(mut self, changelog_config: ChangelogConfig) -> Self {
+        self.changelog_config = changelog_config;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_git_config(mut self, git_config: GitConfig) -> Self {
+        self.git_config = git_config;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_notification_config(mut self, notification_config: NotificationConfig) -> Self {
+        self.notification_config = notification_config;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_lint_config(mut self, lint_config: LintConfig) -> Self {
+        self.lint_config = lint_config;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_package_owners(mut self, package: impl Into<String>, owners: Vec<String>) -> Self {
+        self.package_owners.insert(package.into(), owners);
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_display_name(
+        mut self,
+        package: impl Into<String>,
+        display_name: impl Into<String>,
+    ) -> Self {
+        self.display_names
+            .insert(package.into(), display_name.into());
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_ignore_optional_dependencies(mut self, ignore: bool) -> Self {
+        self.ignore_optional_dependencies = ignore;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_categories(mut self, categories: CategorySet) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_scope_config(mut self, scope_config: ScopeConfig) -> Self {
+        self.scope_config = scope_config;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_release_pre_checks(mut self, commands: Vec<String>) -> Self {
+        self.release_pre_checks = commands;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_version_stamps(mut self, stamps: Vec<VersionStamp>) -> Self {
+        self.version_stamps = stamps;
+        self
+    }
+
+    /// Adds `channel`, replacing a channel of the same name.
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_channel(mut self, channel: ReleaseChannel) -> Self {
+        self.channels
+            .retain(|existing| existing.name != channel.name);
+        self.channels.push(channel);
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_readme_badges(mut self, readme_badges: ReadmeBadges) -> Self {
+        self.readme_badges = readme_badges;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_offline_behavior(mut self, behavior: OfflineBehavior) -> Self {
+        self.offline_behavior = behavior;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_stale_changeset_releases(mut self, releases: usize) -> Self {
+        self.stale_changeset_releases = releases;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_transitive_coverage_depth(mut self, depth: usize) -> Self {
+        self.transitive_coverage_depth = depth;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_consumed_state_storage(mut self, storage: ConsumedStateStorage) -> Self {
+        self.consumed_state_storage = storage;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_changeset_naming(mut self, naming: ChangesetNaming) -> Self {
+        self.changeset_naming = naming;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_new_crate_template(mut self, template: NewCrateTemplate) -> Self {
+        self.new_crate_template = template;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PackageChangesetConfig {
+    ignored_files: GlobSet,
+    tags: Option<bool>,
+    require_changeset: RequireChangeset,
+    has_library: bool,
+    readme_badges: ReadmeBadges,
+    channel: Option<String>,
+}
+
+impl PackageChangesetConfig {
+    #[must_use]
+    pub fn ignored_files(&self) -> &GlobSet {
+        &self.ignored_files
+    }
+
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignored_files.is_match(path)
+    }
+
+    /// Overrides the root `tags` setting for this package.
+    #[must_use]
+    pub fn tags(&self) -> Option<bool> {
+        self.tags
+    }
+
+    #[must_use]
+    pub fn require_changeset(&self) -> RequireChangeset {
+        self.require_changeset
+    }
+
+    /// Overrides the root `readme-badges` settings for this package.
+    #[must_use]
+    pub fn readme_badges(&self) -> &ReadmeBadges {
+        &self.readme_badges
+    }
+
+    /// The release channel the package is released on when `release` is not
+    /// given `--channel`; `None` is the stable channel.
+    #[must_use]
+    pub fn channel(&self) -> Option<&str> {
+        self.channel.as_deref()
+    }
+
+    /// Whether the package has a library target, from a `[lib]` section or
+    /// `src/lib.rs`.
+    #[must_use]
+    pub fn has_library(&self) -> bool {
+        self.has_library
+    }
+
+    /// Whether changes to the package need a changeset under its
+    /// `require-changeset` setting.
+    #[must_use]
+    pub fn requires_changeset(&self) -> bool {
+        match self.require_changeset {
+            RequireChangeset::Always => true,
+            RequireChangeset::LibraryOnly => self.has_library,
+            RequireChangeset::Never => false,
+        }
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_tags(mut self, tags: bool) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_readme_badges(mut self, readme_badges: ReadmeBadges) -> Self {
+        self.readme_badges = readme_badges;
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_channel(mut self, channel: impl Into<String>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn with_require_changeset(
+        mut self,
+        require_changeset: RequireChangeset,
+        has_library: bool,
+    ) -> Self {
+        self.require_changeset = require_changeset;
+        self.has_library = has_library;
+        self
+    }
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, ProjectError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|source| ProjectError::GlobPattern {
+            pattern: pattern.clone(),
+            source,
+        })?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|source| ProjectError::GlobPattern {
+        pattern: patterns.join(", "),
+        source,
+    })
+}
+
+fn build_changelog_config(metadata: Option<&ChangesetMetadata>) -> ChangelogConfig {
+    let Some(cs) = metadata else {
+        return ChangelogConfig::default();
+    };
+    ChangelogConfig {
+        changelog: cs.changelog.unwrap_or_default(),
+        comparison_links: cs.comparison_links.unwrap_or_default(),
+        comparison_links_template: cs.comparison_links_template.clone(),
+        entry_order: cs.changelog_entry_order.unwrap_or_default(),
+        format: cs.changelog_format.unwrap_or_default(),
+        preamble: cs.changelog_preamble.clone(),
+        output: cs.changelog_output.clone(),
+        max_releases: cs.changelog_max_releases.map(NonZeroUsize::get),
+        archive: cs.changelog_archive.clone(),
+    }
+}
+
+fn build_git_config(metadata: Option<&ChangesetMetadata>) -> Result<GitConfig, ProjectError> {
+    let defaults = GitConfig::default();
+    match metadata {
+        None => Ok(defaults),
+        Some(cs) => Ok(GitConfig {
+            commit: cs.commit.unwrap_or(defaults.commit),
+            tags: cs.tags.unwrap_or(defaults.tags),
+            keep_changesets: cs.keep_changesets.unwrap_or(defaults.keep_changesets),
+            tag_format: cs.tag_format.map_or(defaults.tag_format, |tf| match tf {
+                TagFormatValue::VersionOnly => TagFormat::VersionOnly,
+                TagFormatValue::CratePrefixed => TagFormat::CratePrefixed,
+            }),
+            tag_body: cs.tag_body.map_or(defaults.tag_body, |tb| match tb {
+                TagBodyValue::Summary => TagBody::Summary,
+                TagBodyValue::Changelog => TagBody::Changelog,
+            }),
+            commit_strategy: cs
+                .commit_strategy
+                .map_or(defaults.commit_strategy, |strategy| match strategy {
+                    CommitStrategyValue::Single => CommitStrategy::Single,
+                    CommitStrategyValue::PerPackage => CommitStrategy::PerPackage,
+                }),
+            commit_title_template: cs
+                .commit_title_template
+                .clone()
+                .unwrap_or(defaults.commit_title_template),
+            changes_in_body: cs.changes_in_body.unwrap_or(defaults.changes_in_body),
+            commit_body: cs
+                .commit_body
+                .map_or(defaults.commit_body, |body| match body {
+                    CommitBodyValue::List => CommitBody::List,
+                    CommitBodyValue::Grouped => CommitBody::Grouped,
+                }),
+            commit_body_max_length: cs
+                .commit_body_max_length
+                .or(defaults.commit_body_max_length),
+            commit_body_truncation: cs
+                .commit_body_truncation
+                .clone()
+                .unwrap_or(defaults.commit_body_truncation),
+            release_branch: cs.release_branch.clone().or(defaults.release_branch),
+            ignore_submodule_changes: cs
+                .ignore_submodule_changes
+                .unwrap_or(defaults.ignore_submodule_changes),
+            remote: cs.remote.clone().or(defaults.remote),
+            commit_author: cs
+                .commit_author
+                .as_deref()
+                .map(GitIdentity::parse)
+                .transpose()?,
+            commit_committer: cs
+                .commit_committer
+                .as_deref()
+                .map(GitIdentity::parse)
+                .transpose()?,
+        }),
+    }
+}
+
+fn build_notification_config(metadata: Option<&ChangesetMetadata>) -> NotificationConfig {
+    let defaults = NotificationConfig::default();
+    match metadata {
+        None => defaults,
+        Some(cs) => NotificationConfig {
+            webhook_url: cs.webhook_url.clone(),
+            webhook_format: cs
+                .webhook_format
+                .map_or(defaults.webhook_format, |wf| match wf {
+                    WebhookFormatValue::Generic => WebhookFormat::Generic,
+                    WebhookFormatValue::Slack => WebhookFormat::Slack,
+                    WebhookFormatValue::Discord => WebhookFormat::Discord,
+                }),
+            message_template: cs
+                .webhook_template
+                .clone()
+                .unwrap_or(defaults.message_template),
+        },
+    }
+}
+
+fn build_owned_changeset_dirs(metadata: Option<&ChangesetMetadata>) -> Vec<OwnedChangesetDir> {
+    metadata
+        .map(|cs| {
+            cs.changeset_dirs
+                .iter()
+                .map(|(owner, path)| OwnedChangesetDir::new(owner, path))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn build_consumed_state_storage(metadata: Option<&ChangesetMetadata>) -> ConsumedStateStorage {
+    metadata
+        .and_then(|cs| cs.consumed_state)
+        .map_or_else(ConsumedStateStorage::default, |value| match value {
+            ConsumedStateValue::Frontmatter => ConsumedStateStorage::Frontmatter,
+            ConsumedStateValue::File => ConsumedStateStorage::File,
+        })
+}
+
+fn build_changeset_naming(metadata: Option<&ChangesetMetadata>) -> ChangesetNaming {
+    metadata
+        .and_then(|cs| cs.changeset_names)
+        .map_or_else(ChangesetNaming::default, |value| match value {
+            ChangesetNamesValue::Random => ChangesetNaming::Random,
+            ChangesetNamesValue::Branch => ChangesetNaming::Branch,
+        })
+}
+
+fn build_offline_behavior(metadata: Option<&ChangesetMetadata>) -> OfflineBehavior {
+    metadata
+        .and_then(|cs| cs.offline_behavior)
+        .map_or_else(OfflineBehavior::default, |value| match value {
+            OfflineBehaviorValue::Skip => OfflineBehavior::Skip,
+            OfflineBehaviorValue::Error => OfflineBehavior::Error,
+        })
+}
+
+/// Resolves the configured category list, or the built-in set when none is configured.
+///
+/// Omitted labels and sections of built-in ids keep their built-in values;
+/// omitted orders follow the list position.
+fn build_categories(metadata: Option<&ChangesetMetadata>) -> Result<CategorySet, ProjectError> {
+    let Some(values) = metadata.and_then(|cs| cs.categories.as_ref()) else {
+        return Ok(CategorySet::builtin());
+    };
+    if values.is_empty() {
+        return Err(ProjectError::InvalidCategory {
+            id: String::new(),
+            reason: "at least one category must be configured",
+        });
+    }
+
+    let builtin = CategorySet::builtin();
+    let mut definitions: Vec<CategoryDefinition> = Vec::with_capacity(values.len());
+    for (value, position) in values.iter().zip(1..) {
+        let id = value.id.trim();
+        if id.is_empty() || id.chars().any(char::is_whitespace) {
+            return Err(ProjectError::InvalidCategory {
+                id: value.id.clone(),
+                reason: "ids must be non-empty and contain no whitespace",
+            });
+        }
+        let category = ChangeCategory::from_id(id);
+        if definitions
+            .iter()
+            .any(|d| d.category.id().eq_ignore_ascii_case(id))
+        {
+            return Err(ProjectError::InvalidCategory {
+                id: id.to_string(),
+                reason: "defined more than once",
+            });
+        }
+
+        let builtin_definition = builtin.get(&category);
+        let label = value
+            .label
+            .clone()
+            .or_else(|| builtin_definition.map(|d| d.label.clone()))
+            .unwrap_or_else(|| id.to_string());
+        let section = value
+            .section
+            .clone()
+            .or_else(|| builtin_definition.map(|d| d.section.clone()))
+            .unwrap_or_else(|| label.clone());
+
+        definitions.push(CategoryDefinition {
+            category,
+            label,
+            section,
+            order: value.order.unwrap_or(position * 10),
+        });
+    }
+
+    Ok(CategorySet::new(definitions))
+}
+
+fn build_lint_config(metadata: Option<&ChangesetMetadata>) -> LintConfig {
+    match metadata {
+        None => LintConfig::default(),
+        Some(cs) => LintConfig {
+            enabled: cs.lint_summaries.unwrap_or(false),
+            denied_words: cs.lint_denied_words.clone(),
+        },
+    }
+}
+
+fn build_scope_config(metadata: Option<&ChangesetMetadata>) -> ScopeConfig {
+    match metadata {
+        None => ScopeConfig::default(),
+        Some(cs) => ScopeConfig {
+            scopes: cs.scopes.clone(),
+            required: cs.require_scope.unwrap_or(false),
+        },
+    }
+}
+
+fn build_version_stamps(metadata: Option<&ChangesetMetadata>) -> Vec<VersionStamp> {
+    metadata
+        .map(|cs| {
+            cs.version_stamps
+                .iter()
+                .map(|stamp| VersionStamp {
+                    files: stamp.files.clone(),
+                    pattern: stamp.pattern.clone(),
+                    package: stamp.package.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn build_readme_badges(metadata: Option<&ChangesetMetadata>) -> ReadmeBadges {
+    metadata
+        .map(|cs| ReadmeBadges {
+            enabled: cs.readme_badges,
+            patterns: cs.readme_badge_patterns.clone(),
+        })
+        .unwrap_or_default()
+}
+
+fn build_new_crate_template(
+    metadata: Option<&ChangesetMetadata>,
+) -> Result<NewCrateTemplate, ProjectError> {
+    let mut template = NewCrateTemplate::default();
+    let Some(value) = metadata.and_then(|cs| cs.new_crate.as_ref()) else {
+        return Ok(template);
+    };
+
+    if let Some(path) = &value.path {
+        template.path.clone_from(path);
+    }
+    if let Some(version) = &value.version {
+        template.version =
+            Version::parse(version).map_err(|source| ProjectError::InvalidTemplateVersion {
+                version: version.clone(),
+                source,
+            })?;
+    }
+    if let Some(edition) = &value.edition {
+        template.edition.clone_from(edition);
+    }
+    template
+        .package_metadata
+        .clone_from(&value.package_metadata);
+    Ok(template)
+}
+
+/// The built-in channels with the `channels` table applied: entries named like
+/// a built-in channel change only the fields they set, others add a channel.
+fn build_channels(
+    metadata: Option<&ChangesetMetadata>,
+) -> Result<Vec<ReleaseChannel>, ProjectError> {
+    let mut channels = ReleaseChannel::builtin();
+    let Some(values) = metadata.map(|cs| &cs.channels) else {
+        return Ok(channels);
+    };
+
+    for (name, value) in values {
+        let invalid = |reason| ProjectError::InvalidChannel {
+            name: name.clone(),
+            reason,
+        };
+        if name.is_empty() || name.chars().any(char::is_whitespace) {
+            return Err(invalid("names must be non-empty and contain no whitespace"));
+        }
+        if !channels.iter().any(|channel| &channel.name == name) {
+            channels.push(ReleaseChannel::new(name.clone()));
+        }
+        let Some(channel) = channels.iter_mut().find(|channel| &channel.name == name) else {
+            continue;
+        };
+        if let Some(prerelease) = &value.prerelease {
+            let valid = !prerelease.is_empty()
+                && !prerelease.chars().all(|c| c.is_ascii_digit())
+                && prerelease
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-');
+            if !valid {
+                return Err(invalid(
+                    "prerelease must be a non-numeric identifier of letters, digits and hyphens",
+                ));
+            }
+            channel.prerelease = Some(prerelease.clone());
+        }
+        if let Some(namespace) = &value.tag_namespace {
+            if namespace.chars().any(|c| c.is_whitespace() || c == '@') {
+                return Err(invalid("tag-namespace cannot contain whitespace or '@'"));
+            }
+            channel.tag_namespace.clone_from(namespace);
+        }
+        if let Some(publish) = value.publish {
+            channel.publish = publish;
+        }
+    }
+
+    // Published versions are matched back to their channel by prerelease tag.
+    let mut prereleases: Vec<&str> = Vec::new();
+    for channel in &channels {
+        let Some(prerelease) = channel.prerelease() else {
+            continue;
+        };
+        if prereleases.contains(&prerelease) {
+            return Err(ProjectError::InvalidChannel {
+                name: channel.name.clone(),
+                reason: "another channel has the same prerelease tag",
+            });
+        }
+        prereleases.push(prerelease);
+    }
+    Ok(channels)
+}
+
+/// Parses root configuration from workspace metadata.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
+fn parse_workspace_root_config(
+    project_root: &Path,
+    overrides: &[ConfigOverride],
+) -> Result<RootChangesetConfig, ProjectError> {
+    let manifest_path = project_root.join("Cargo.toml");
+    let manifest = read_manifest_with_overrides(&manifest_path, "workspace", overrides)?;
+
+    let changeset_metadata = manifest
+        .workspace
+        .and_then(|ws| ws.metadata)
+        .and_then(|meta| meta.changeset);
+
+    let patterns = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.ignored_files.clone())
+        .unwrap_or_default();
+
+    let changeset_dir = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.changeset_dir.clone())
+        .unwrap_or_else(|| crate::DEFAULT_CHANGESET_DIR.to_string());
+    let owned_changeset_dirs = build_owned_changeset_dirs(changeset_metadata.as_ref());
+
+    let ignored_files = build_glob_set(&patterns)?;
+
+    let changelog_config = build_changelog_config(changeset_metadata.as_ref());
+
+    let git_config = build_git_config(changeset_metadata.as_ref())?;
+
+    let zero_version_behavior = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.zero_version_behavior)
+        .unwrap_or_default();
+
+    let notification_config = build_notification_config(changeset_metadata.as_ref());
+    let lint_config = build_lint_config(changeset_metadata.as_ref());
+    let display_names = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.display_names.clone())
+        .unwrap_or_default();
+    let package_owners = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.owners.clone())
+        .unwrap_or_default();
+    let ignore_optional_dependencies = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.ignore_optional_dependencies)
+        .unwrap_or(false);
+    let categories = build_categories(changeset_metadata.as_ref())?;
+    let scope_config = build_scope_config(changeset_metadata.as_ref());
+    let release_pre_checks = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.release_pre_checks.clone())
+        .unwrap_or_default();
+    let version_stamps = build_version_stamps(changeset_metadata.as_ref());
+    let readme_badges = build_readme_badges(changeset_metadata.as_ref());
+    let offline_behavior = build_offline_behavior(changeset_metadata.as_ref());
+    let stale_changeset_releases = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.stale_changeset_releases)
+        .unwrap_or(DEFAULT_STALE_CHANGESET_RELEASES);
+    let consumed_state_storage = build_consumed_state_storage(changeset_metadata.as_ref());
+    let transitive_coverage_depth = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.transitive_coverage_depth)
+        .unwrap_or(0);
+    let changeset_naming = build_changeset_naming(changeset_metadata.as_ref());
+    let new_crate_template = build_new_crate_template(changeset_metadata.as_ref())?;
+    let channels = build_channels(changeset_metadata.as_ref())?;
+
+    Ok(RootChangesetConfig {
+        ignored_files,
+        changeset_dir: PathBuf::from(changeset_dir),
+        owned_changeset_dirs,
+        changelog_config,
+        git_config,
+        zero_version_behavior,
+        notification_config,
+        lint_config,
+        display_names,
+        package_owners,
+        ignore_optional_dependencies,
+        categories,
+        scope_config,
+        release_pre_checks,
+        version_stamps,
+        readme_badges,
+        offline_behavior,
+        stale_changeset_releases,
+        consumed_state_storage,
+        transitive_coverage_depth,
+        changeset_naming,
+        new_crate_template,
+        channels,
+    })
+}
+
+/// Parses root configuration from package metadata (for single-package projects).
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
+fn parse_package_root_config(
+    project_root: &Path,
+    overrides: &[ConfigOverride],
+) -> Result<RootChangesetConfig, ProjectError> {
+    let manifest_path = project_root.join("Cargo.toml");
+    let manifest = read_manifest_with_overrides(&manifest_path, "package", overrides)?;
+
+    let changeset_metadata = manifest
+        .package
+        .and_then(|pkg| pkg.metadata)
+        .and_then(|meta| meta.changeset);
+
+    let patterns = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.ignored_files.clone())
+        .unwrap_or_default();
+
+    let changeset_dir = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.changeset_dir.clone())
+        .unwrap_or_else(|| crate::DEFAULT_CHANGESET_DIR.to_string());
+    let owned_changeset_dirs = build_owned_changeset_dirs(changeset_metadata.as_ref());
+
+    let ignored_files = build_glob_set(&patterns)?;
+
+    let changelog_config = build_changelog_config(changeset_metadata.as_ref());
+
+    let git_config = build_git_config(changeset_metadata.as_ref())?;
+
+    let zero_version_behavior = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.zero_version_behavior)
+        .unwrap_or_default();
+
+    let notification_config = build_notification_config(changeset_metadata.as_ref());
+    let lint_config = build_lint_config(changeset_metadata.as_ref());
+    let display_names = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.display_names.clone())
+        .unwrap_or_default();
+    let package_owners = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.owners.clone())
+        .unwrap_or_default();
+    let ignore_optional_dependencies = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.ignore_optional_dependencies)
+        .unwrap_or(false);
+    let categories = build_categories(changeset_metadata.as_ref())?;
+    let scope_config = build_scope_config(changeset_metadata.as_ref());
+    let release_pre_checks = changeset_metadata
+        .as_ref()
+        .map(|cs| cs.release_pre_checks.clone())
+        .unwrap_or_default();
+    let version_stamps = build_version_stamps(changeset_metadata.as_ref());
+    let readme_badges = build_readme_badges(changeset_metadata.as_ref());
+    let offline_behavior = build_offline_behavior(changeset_metadata.as_ref());
+    let stale_changeset_releases = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.stale_changeset_releases)
+        .unwrap_or(DEFAULT_STALE_CHANGESET_RELEASES);
+    let consumed_state_storage = build_consumed_state_storage(changeset_metadata.as_ref());
+    let transitive_coverage_depth = changeset_metadata
+        .as_ref()
+        .and_then(|cs| cs.transitive_coverage_depth)
+        .unwrap_or(0);
+    let changeset_naming = build_changeset_naming(changeset_metadata.as_ref());
+    let new_crate_template = build_new_crate_template(changeset_metadata.as_ref())?;
+    let channels = build_channels(changeset_metadata.as_ref())?;
+
+    Ok(RootChangesetConfig {
+        ignored_files,
+        changeset_dir: PathBuf::from(changeset_dir),
+        owned_changeset_dirs,
+        changelog_config,
+        git_config,
+        zero_version_behavior,
+        notification_config,
+        lint_config,
+        display_names,
+        package_owners,
+        ignore_optional_dependencies,
+        categories,
+        scope_config,
+        release_pre_checks,
+        version_stamps,
+        readme_badges,
+        offline_behavior,
+        stale_changeset_releases,
+        consumed_state_storage,
+        transitive_coverage_depth,
+        changeset_naming,
+        new_crate_template,
+        channels,
+    })
+}
+
+/// Parses the root changeset configuration based on project kind.
+///
+/// For single-package projects, reads from `[package.metadata.changeset]`.
+/// For workspaces, reads from `[workspace.metadata.changeset]`.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
+pub fn parse_root_config(project: &CargoProject) -> Result<RootChangesetConfig, ProjectError> {
+    parse_root_config_with_overrides(project, &[])
+}
+
+/// Parses the root changeset configuration like [`parse_root_config`], with
+/// `overrides` applied on top of the manifest's changeset metadata.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, if glob patterns
+/// are invalid, or if an override gives a key a value of the wrong type.
+pub fn parse_root_config_with_overrides(
+    project: &CargoProject,
+    overrides: &[ConfigOverride],
+) -> Result<RootChangesetConfig, ProjectError> {
+    match project.kind {
+        ProjectKind::SinglePackage => parse_package_root_config(&project.root, overrides),
+        ProjectKind::VirtualWorkspace | ProjectKind::WorkspaceWithRoot => {
+            parse_workspace_root_config(&project.root, overrides)
+        }
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
+pub fn parse_package_config(package_path: &Path) -> Result<PackageChangesetConfig, ProjectError> {
+    let manifest_path = package_path.join("Cargo.toml");
+    let manifest = read_manifest(&manifest_path)?;
+    let has_library = manifest.lib.is_some() || package_path.join("src/lib.rs").is_file();
+
+    let metadata = manifest
+        .package
+        .and_then(|pkg| pkg.metadata)
+        .and_then(|meta| meta.changeset)
+        .unwrap_or_default();
+
+    let ignored_files = build_glob_set(&metadata.ignored_files)?;
+    let require_changeset =
+        metadata
+            .require_changeset
+            .map_or_else(RequireChangeset::default, |value| match value {
+                RequireChangesetValue::Always => RequireChangeset::Always,
+                RequireChangesetValue::LibraryOnly => RequireChangeset::LibraryOnly,
+                RequireChangesetValue::Never => RequireChangeset::Never,
+            });
+
+    Ok(PackageChangesetConfig {
+        ignored_files,
+        tags: metadata.tags,
+        require_changeset,
+        has_library,
+        readme_badges: build_readme_badges(Some(&metadata)),
+        channel: metadata.channel,
+    })
+}
+
+/// # Errors
+///
+/// Returns an error if any manifest cannot be read or parsed, or if glob patterns are invalid.
+pub fn load_changeset_configs(
+    project: &CargoProject,
+) -> Result<(RootChangesetConfig, HashMap<String, PackageChangesetConfig>), ProjectError> {
+    load_changeset_configs_with_overrides(project, &[])
+}
+
+/// Loads all changeset configuration like [`load_changeset_configs`], with
+/// `overrides` applied to the root configuration. Per-package configuration is
+/// read as written.
+///
+/// # Errors
+///
+/// Returns an error if any manifest cannot be read or parsed, if glob patterns
+/// are invalid, or if an override gives a key a value of the wrong type.
+pub fn load_changeset_configs_with_overrides(
+    project: &CargoProject,
+    overrides: &[ConfigOverride],
+) -> Result<(RootChangesetConfig, HashMap<String, PackageChangesetConfig>), ProjectError> {
+    let root_config = parse_root_config_with_overrides(project, overrides)?;
+
+    let mut package_configs = HashMap::new();
+    for package in &project.packages {
+        // Packages read by other adapters have no `[package.metadata]` table.
+        let config = if project.is_cargo_package(package) {
+            parse_package_config(&package.path)?
+        } else {
+            PackageChangesetConfig::default()
+        };
+        package_configs.insert(package.name.clone(), config);
+    }
+
+    Ok((root_config, package_configs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use changeset_changelog::{
+        ArchivePolicy, ChangelogFormat, ChangelogLocation, ComparisonLinksSetting, EntryOrder,
+    };
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_with_config(toml_content: &str) -> anyhow::Result<TempDir> {
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("Cargo.toml"), toml_content)?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn parse_workspace_root_config_with_ignored_files() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+ignored-files = ["*.md", "docs/**"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert!(config.is_ignored(Path::new("README.md")));
+        assert!(config.is_ignored(Path::new("docs/guide.md")));
+        assert!(!config.is_ignored(Path::new("src/lib.rs")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_workspace_root_config_without_metadata() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert!(!config.is_ignored(Path::new("README.md")));
+        assert!(!config.is_ignored(Path::new("src/lib.rs")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_workspace_root_config_with_custom_changeset_dir() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+changeset-dir = "changes"
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert_eq!(config.changeset_dir(), Path::new("changes"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_workspace_root_config_with_owned_changeset_dirs() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+changeset-dir = "docs/changes"
+changeset-dirs = { web = "teams/web/changes", platform = "teams/platform/changes" }
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert_eq!(
+            config.changeset_dirs().collect::<Vec<_>>(),
+            vec![
+                Path::new("docs/changes"),
+                Path::new("teams/platform/changes"),
+                Path::new("teams/web/changes"),
+            ]
+        );
+        assert_eq!(
+            config.changeset_owner(
+                dir.path(),
+                &dir.path().join("teams/web/changes/changesets/fix.md")
+            ),
+            Some("web")
+        );
+        assert_eq!(
+            config.changeset_owner(dir.path(), Path::new("docs/changes/changesets/fix.md")),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_workspace_root_config_default_changeset_dir() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert_eq!(config.changeset_dir(), Path::new(".changeset"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_package_config_with_ignored_files() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "my-crate"
+version = "0.1.0"
+
+[package.metadata.changeset]
+ignored-files = ["benches/**", "examples/**"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_config(dir.path())?;
+
+        assert!(config.is_ignored(Path::new("benches/bench.rs")));
+        assert!(config.is_ignored(Path::new("examples/demo.rs")));
+        assert!(!config.is_ignored(Path::new("src/lib.rs")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_package_config_tags_override() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "internal-crate"
+version = "0.1.0"
+
+[package.metadata.changeset]
+tags = false
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_config(dir.path())?;
+
+        assert_eq!(config.tags(), Some(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_package_config_require_changeset_library_only() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "my-cli"
+version = "0.1.0"
+
+[package.metadata.changeset]
+require-changeset = "library-only"
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_config(dir.path())?;
+        assert_eq!(config.require_changeset(), RequireChangeset::LibraryOnly);
+        assert!(!config.has_library());
+        assert!(!config.requires_changeset());
+
+        fs::create_dir_all(dir.path().join("src"))?;
+        fs::write(dir.path().join("src/lib.rs"), "")?;
+        let config = parse_package_config(dir.path())?;
+        assert!(config.has_library());
+        assert!(config.requires_changeset());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_package_config_without_metadata() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "my-crate"
+version = "0.1.0"
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_config(dir.path())?;
+
+        assert!(!config.is_ignored(Path::new("benches/bench.rs")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_single_package_root_config() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "my-crate"
+version = "0.1.0"
+
+[package.metadata.changeset]
+ignored-files = ["*.md"]
+changeset-dir = "my-changesets"
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_root_config(dir.path(), &[])?;
+
+        assert!(config.is_ignored(Path::new("README.md")));
+        assert_eq!(config.changeset_dir(), Path::new("my-changesets"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_glob_pattern_returns_error() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+ignored-files = ["[invalid"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let result = parse_workspace_root_config(dir.path(), &[]);
+
+        assert!(result.is_err());
+        let err = result.expect_err("should fail on invalid glob");
+        assert!(matches!(err, ProjectError::GlobPattern { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_ignored_files_list() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+ignored-files = []
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert!(!config.is_ignored(Path::new("anything.txt")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_workspace_changelog_config() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+changelog = "per-package"
+comparison-links = "enabled"
+comparison-links-template = "https://example.com/{repository}/compare/{base}...{target}"
+changelog-entry-order = "package"
+changelog-format = "keep-a-changelog"
+changelog-preamble = "Changelog for {package}.\n"
+changelog-output = ["markdown", "json"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let changelog_config = config.changelog_config();
+
+        assert_eq!(changelog_config.changelog, ChangelogLocation::PerPackage);
+        assert_eq!(
+            changelog_config.comparison_links,
+            ComparisonLinksSetting::Enabled
+        );
+        assert_eq!(
+            changelog_config.comparison_links_template.as_deref(),
+            Some("https://example.com/{repository}/compare/{base}...{target}")
+        );
+        assert_eq!(changelog_config.entry_order, EntryOrder::Package);
+        assert_eq!(changelog_config.format, ChangelogFormat::KeepAChangelog);
+        assert_eq!(
+            changelog_config.preamble.as_deref(),
+            Some("Changelog for {package}.\n")
+        );
+        assert!(changelog_config.writes_json());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_changelog_archive_policy() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+changelog-max-releases = 20
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert_eq!(
+            config.changelog_config().archive_policy(),
+            Some(ArchivePolicy {
+                max_releases: Some(20),
+                archive: "CHANGELOG-{year}.md".to_string(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_changelog_max_releases_is_rejected() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+changelog-max-releases = 0
+"#;
+        let dir = setup_with_config(toml)?;
+
+        assert!(parse_workspace_root_config(dir.path(), &[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_changelog_config_defaults() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let changelog_config = config.changelog_config();
+
+        assert_eq!(changelog_config.changelog, ChangelogLocation::Root);
+        assert_eq!(
+            changelog_config.comparison_links,
+            ComparisonLinksSetting::Auto
+        );
+        assert!(changelog_config.comparison_links_template.is_none());
+        assert_eq!(changelog_config.entry_order, EntryOrder::Category);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_single_package_changelog_config() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "my-crate"
+version = "0.1.0"
+
+[package.metadata.changeset]
+changelog = "root"
+comparison-links = "disabled"
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_root_config(dir.path(), &[])?;
+        let changelog_config = config.changelog_config();
+
+        assert_eq!(changelog_config.changelog, ChangelogLocation::Root);
+        assert_eq!(
+            changelog_config.comparison_links,
+            ComparisonLinksSetting::Disabled
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_git_config_defaults() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let git_config = config.git_config();
+
+        assert!(git_config.commit());
+        assert!(git_config.tags());
+        assert!(!git_config.keep_changesets());
+        assert_eq!(git_config.tag_format(), TagFormat::VersionOnly);
+        assert_eq!(git_config.tag_body(), TagBody::Summary);
+        assert_eq!(git_config.commit_strategy(), CommitStrategy::Single);
+        assert_eq!(git_config.commit_title_template(), "{new-version}");
+        assert!(git_config.changes_in_body());
+        assert!(git_config.release_branch().is_none());
+        assert!(git_config.remote().is_none());
+        assert!(git_config.commit_author().is_none());
+        assert!(git_config.commit_committer().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn overrides_replace_and_extend_manifest_settings() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+tags = true
+"#;
+        let dir = setup_with_config(toml)?;
+        let overrides: Vec<ConfigOverride> = ["tags=false", "display-names.crate-a=Crate A"]
+            .iter()
+            .map(|input| input.parse())
+            .collect::<Result<_, _>>()?;
+
+        let config = parse_workspace_root_config(dir.path(), &overrides)?;
+
+        assert!(!config.git_config().tags());
+        assert_eq!(
+            config.display_names().get("crate-a").map(String::as_str),
+            Some("Crate A")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn override_with_wrong_type_is_rejected_before_parsing() {
+        let result = "tags=sometimes".parse::<ConfigOverride>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_git_config_all_options() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+commit = false
+tags = false
+keep-changesets = true
+tag-format = "crate-prefixed"
+tag-body = "changelog"
+commit-strategy = "per-package"
+commit-title-template = "chore(release): {new-version}"
+changes-in-body = false
+commit-body = "grouped"
+commit-body-max-length = 2000
+commit-body-truncation = "(truncated)"
+release-branch = "main"
+ignore-submodule-changes = true
+remote = "upstream"
+commit-author = "release-bot <bot@company.com>"
+commit-committer = "github-actions[bot] <41898282+github-actions[bot]@users.noreply.github.com>"
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let git_config = config.git_config();
+
+        assert!(!git_config.commit());
+        assert!(!git_config.tags());
+        assert!(git_config.keep_changesets());
+        assert_eq!(git_config.tag_format(), TagFormat::CratePrefixed);
+        assert_eq!(git_config.tag_body(), TagBody::Changelog);
+        assert_eq!(git_config.commit_strategy(), CommitStrategy::PerPackage);
+        assert_eq!(
+            git_config.commit_title_template(),
+            "chore(release): {new-version}"
+        );
+        assert!(!git_config.changes_in_body());
+        assert_eq!(git_config.commit_body(), CommitBody::Grouped);
+        assert_eq!(git_config.commit_body_max_length(), Some(2000));
+        assert_eq!(git_config.commit_body_truncation(), "(truncated)");
+        assert_eq!(git_config.release_branch(), Some("main"));
+        assert!(git_config.ignore_submodule_changes());
+        assert_eq!(git_config.remote(), Some("upstream"));
+        let author = git_config.commit_author().expect("author configured");
+        assert_eq!(author.name(), "release-bot");
+        assert_eq!(author.email(), "bot@company.com");
+        let committer = git_config.commit_committer().expect("committer configured");
+        assert_eq!(committer.name(), "github-actions[bot]");
+        assert_eq!(
+            committer.email(),
+            "41898282+github-actions[bot]@users.noreply.github.com"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_committer_defaults_to_author() -> anyhow::Result<()> {
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+commit-author = "release-bot <bot@company.com>"
+"#,
+        )?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+
+        assert_eq!(
+            config.git_config().commit_committer(),
+            config.git_config().commit_author()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_identity_rejects_malformed_values() {
+        for value in [
+            "release-bot",
+            "<bot@company.com>",
+            "release-bot <>",
+            "release-bot <bot>",
+            "release-bot <bot@company.com",
+            "release-bot <@company.com>",
+        ] {
+            assert!(
+                matches!(
+                    GitIdentity::parse(value),
+                    Err(ProjectError::InvalidGitIdentity { .. })
+                ),
+                "{value} should be rejected"
+            );
         }
     }
-}
 
-/// # Errors
-///
-/// Returns an error if the manifest cannot be read or parsed, or if glob patterns are invalid.
-pub fn parse_package_config(package_path: &Path) -> Result<PackageChangesetConfig, ProjectError> {
-    let manifest_path = package_path.join("Cargo.toml");
-    let manifest = read_manifest(&manifest_path)?;
+    #[test]
+    fn parse_offline_behavior() -> anyhow::Result<()> {
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.offline_behavior(), OfflineBehavior::Skip);
 
-    let patterns = manifest
-        .package
-        .and_then(|pkg| pkg.metadata)
-        .and_then(|meta| meta.changeset)
-        .map(|cs| cs.ignored_files)
-        .unwrap_or_default();
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
 
-    let ignored_files = build_glob_set(&patterns)?;
+[workspace.metadata.changeset]
+offline-behavior = "error"
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.offline_behavior(), OfflineBehavior::Error);
 
-    Ok(PackageChangesetConfig { ignored_files })
-}
+        Ok(())
+    }
 
-/// # Errors
-///
-/// Returns an error if any manifest cannot be read or parsed, or if glob patterns are invalid.
-pub fn load_changeset_configs(
-    project: &CargoProject,
-) -> Result<(RootChangesetConfig, HashMap<String, PackageChangesetConfig>), ProjectError> {
-    let root_config = parse_root_config(project)?;
+    #[test]
+    fn parse_consumed_state_storage() -> anyhow::Result<()> {
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(
+            config.consumed_state_storage(),
+            ConsumedStateStorage::Frontmatter
+        );
 
-    let mut package_configs = HashMap::new();
-    for package in &project.packages {
-        let config = parse_package_config(&package.path)?;
-        package_configs.insert(package.name.clone(), config);
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+consumed-state = "file"
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.consumed_state_storage(), ConsumedStateStorage::File);
+
+        Ok(())
     }
 
-    Ok((root_config, package_configs))
-}
+    #[test]
+    fn parse_changeset_naming() -> anyhow::Result<()> {
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.changeset_naming(), ChangesetNaming::Random);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::TempDir;
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
 
-    fn setup_with_config(toml_content: &str) -> anyhow::Result<TempDir> {
-        let dir = TempDir::new()?;
-        fs::write(dir.path().join("Cargo.toml"), toml_content)?;
-        Ok(dir)
+[workspace.metadata.changeset]
+changeset-names = "branch"
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.changeset_naming(), ChangesetNaming::Branch);
+
+        Ok(())
     }
 
     #[test]
-    fn parse_workspace_root_config_with_ignored_files() -> anyhow::Result<()> {
-        let toml = r#"
+    fn parse_stale_changeset_releases() -> anyhow::Result<()> {
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.stale_changeset_releases(), 3);
+
+        let dir = setup_with_config(
+            r#"
 [workspace]
 members = ["crates/*"]
 
 [workspace.metadata.changeset]
-ignored-files = ["*.md", "docs/**"]
-"#;
-        let dir = setup_with_config(toml)?;
+stale-changeset-releases = 0
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.stale_changeset_releases(), 0);
 
-        let config = parse_workspace_root_config(dir.path())?;
+        Ok(())
+    }
 
-        assert!(config.is_ignored(Path::new("README.md")));
-        assert!(config.is_ignored(Path::new("docs/guide.md")));
-        assert!(!config.is_ignored(Path::new("src/lib.rs")));
+    #[test]
+    fn parse_transitive_coverage_depth() -> anyhow::Result<()> {
+        let dir = setup_with_config(
+            r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+transitive-coverage-depth = 2
+"#,
+        )?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        assert_eq!(config.transitive_coverage_depth(), 2);
+        assert_eq!(
+            RootChangesetConfig::default().transitive_coverage_depth(),
+            0
+        );
 
         Ok(())
     }
 
     #[test]
-    fn parse_workspace_root_config_without_metadata() -> anyhow::Result<()> {
+    fn parse_notification_config_defaults() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let notification_config = config.notification_config();
 
-        assert!(!config.is_ignored(Path::new("README.md")));
-        assert!(!config.is_ignored(Path::new("src/lib.rs")));
+        assert!(notification_config.webhook_url().is_none());
+        assert_eq!(notification_config.webhook_format(), WebhookFormat::Generic);
+        assert_eq!(
+            notification_config.message_template(),
+            "Released {releases}"
+        );
 
         Ok(())
     }
 
     #[test]
-    fn parse_workspace_root_config_with_custom_changeset_dir() -> anyhow::Result<()> {
+    fn parse_notification_config_all_options() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 
 [workspace.metadata.changeset]
-changeset-dir = "changes"
+webhook-url = "https://hooks.slack.com/services/T000/B000/XXX"
+webhook-format = "slack"
+webhook-template = "Shipped {releases}"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let notification_config = config.notification_config();
 
-        assert_eq!(config.changeset_dir(), Path::new("changes"));
+        assert_eq!(
+            notification_config.webhook_url(),
+            Some("https://hooks.slack.com/services/T000/B000/XXX")
+        );
+        assert_eq!(notification_config.webhook_format(), WebhookFormat::Slack);
+        assert_eq!(notification_config.message_template(), "Shipped {releases}");
 
         Ok(())
     }
 
     #[test]
-    fn parse_workspace_root_config_default_changeset_dir() -> anyhow::Result<()> {
+    fn parse_lint_config_defaults() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert_eq!(config.changeset_dir(), Path::new(".changeset"));
+        assert!(!config.lint_config().enabled());
+        assert!(config.lint_config().denied_words().is_empty());
 
         Ok(())
     }
 
     #[test]
-    fn parse_package_config_with_ignored_files() -> anyhow::Result<()> {
+    fn parse_lint_config_all_options() -> anyhow::Result<()> {
         let toml = r#"
 [package]
 name = "my-crate"
-version = "0.1.0"
+version = "1.0.0"
 
 [package.metadata.changeset]
-ignored-files = ["benches/**", "examples/**"]
+lint-summaries = true
+lint-denied-words = ["teh", "recieve"]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_package_config(dir.path())?;
+        let config = parse_package_root_config(dir.path(), &[])?;
 
-        assert!(config.is_ignored(Path::new("benches/bench.rs")));
-        assert!(config.is_ignored(Path::new("examples/demo.rs")));
-        assert!(!config.is_ignored(Path::new("src/lib.rs")));
+        assert!(config.lint_config().enabled());
+        assert_eq!(config.lint_config().denied_words(), ["teh", "recieve"]);
 
         Ok(())
     }
 
     #[test]
-    fn parse_package_config_without_metadata() -> anyhow::Result<()> {
+    fn parse_display_names() -> anyhow::Result<()> {
         let toml = r#"
-[package]
-name = "my-crate"
-version = "0.1.0"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset.display-names]
+crate-internal-name = "Widget SDK"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_package_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert!(!config.is_ignored(Path::new("benches/bench.rs")));
+        assert_eq!(config.display_name("crate-internal-name"), "Widget SDK");
+        assert_eq!(config.display_name("other-crate"), "other-crate");
 
         Ok(())
     }
 
     #[test]
-    fn parse_single_package_root_config() -> anyhow::Result<()> {
+    fn parse_package_owners() -> anyhow::Result<()> {
         let toml = r#"
-[package]
-name = "my-crate"
-version = "0.1.0"
+[workspace]
+members = ["crates/*"]
 
-[package.metadata.changeset]
-ignored-files = ["*.md"]
-changeset-dir = "my-changesets"
+[workspace.metadata.changeset.owners]
+crate-web = ["@org/web", "@alice"]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_package_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert!(config.is_ignored(Path::new("README.md")));
-        assert_eq!(config.changeset_dir(), Path::new("my-changesets"));
+        assert_eq!(
+            config.package_owners().get("crate-web"),
+            Some(&vec!["@org/web".to_string(), "@alice".to_string()])
+        );
 
         Ok(())
     }
 
     #[test]
-    fn invalid_glob_pattern_returns_error() -> anyhow::Result<()> {
+    fn parse_ignore_optional_dependencies() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 
 [workspace.metadata.changeset]
-ignored-files = ["[invalid"]
+ignore-optional-dependencies = true
 "#;
         let dir = setup_with_config(toml)?;
 
-        let result = parse_workspace_root_config(dir.path());
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert!(result.is_err());
-        let err = result.expect_err("should fail on invalid glob");
-        assert!(matches!(err, ProjectError::GlobPattern { .. }));
+        assert!(config.ignore_optional_dependencies());
+        assert!(!RootChangesetConfig::default().ignore_optional_dependencies());
 
         Ok(())
     }
 
     #[test]
-    fn empty_ignored_files_list() -> anyhow::Result<()> {
+    fn parse_configured_categories() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 
 [workspace.metadata.changeset]
-ignored-files = []
+categories = [
+    { id = "fixed" },
+    { id = "perf", label = "Performance improvements", section = "Performance", order = 5 },
+    { id = "docs" },
+]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let definitions: Vec<_> = config.categories().iter().collect();
+
+        assert_eq!(definitions.len(), 3);
+        assert_eq!(definitions[0].category.id(), "perf");
+        assert_eq!(definitions[0].section, "Performance");
+        assert_eq!(definitions[1].category, ChangeCategory::Fixed);
+        assert_eq!(definitions[1].label, "Bug fixes");
+        assert_eq!(definitions[1].section, "Fixed");
+        assert_eq!(definitions[2].label, "docs");
+        assert_eq!(definitions[2].order, 30);
+        assert_eq!(
+            RootChangesetConfig::default().categories(),
+            &CategorySet::builtin()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_scope_config() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset]
+scopes = ["parser", "cli"]
+require-scope = true
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let scope_config = config.scope_config();
 
-        assert!(!config.is_ignored(Path::new("anything.txt")));
+        assert_eq!(scope_config.scopes(), ["parser", "cli"]);
+        assert!(scope_config.required());
+        assert!(scope_config.is_allowed("cli"));
+        assert!(!scope_config.is_allowed("docs"));
+        assert!(ScopeConfig::default().is_allowed("docs"));
 
         Ok(())
     }
 
     #[test]
-    fn parse_workspace_changelog_config() -> anyhow::Result<()> {
+    fn parse_release_pre_checks() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 
 [workspace.metadata.changeset]
-changelog = "per-package"
-comparison-links = "enabled"
-comparison-links-template = "https://example.com/{repository}/compare/{base}...{target}"
+release-pre-checks = ["cargo check --workspace", "cargo test -p {package}"]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
-        let changelog_config = config.changelog_config();
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert_eq!(changelog_config.changelog, ChangelogLocation::PerPackage);
         assert_eq!(
-            changelog_config.comparison_links,
-            ComparisonLinksSetting::Enabled
+            config.release_pre_checks(),
+            ["cargo check --workspace", "cargo test -p {package}"]
         );
-        assert_eq!(
-            changelog_config.comparison_links_template.as_deref(),
-            Some("https://example.com/{repository}/compare/{base}...{target}")
+        assert!(
+            RootChangesetConfig::default()
+                .release_pre_checks()
+                .is_empty()
         );
 
         Ok(())
     }
 
     #[test]
-    fn parse_changelog_config_defaults() -> anyhow::Result<()> {
+    fn parse_new_crate_template() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
+
+[workspace.metadata.changeset.new-crate]
+path = "libs/{name}"
+version = "0.0.1"
+package-metadata = { require-changeset = "always", tags = false }
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
-        let changelog_config = config.changelog_config();
+        let config = parse_workspace_root_config(dir.path(), &[])?;
+        let template = config.new_crate_template();
 
-        assert_eq!(changelog_config.changelog, ChangelogLocation::Root);
+        assert_eq!(template.path("parser"), PathBuf::from("libs/parser"));
+        assert_eq!(template.version(), &Version::new(0, 0, 1));
+        assert_eq!(template.edition(), "2024");
         assert_eq!(
-            changelog_config.comparison_links,
-            ComparisonLinksSetting::Auto
+            template.package_metadata().get("require-changeset"),
+            Some(&toml::Value::String("always".to_string()))
+        );
+        assert_eq!(
+            template.package_metadata().get("tags"),
+            Some(&toml::Value::Boolean(false))
         );
-        assert!(changelog_config.comparison_links_template.is_none());
 
         Ok(())
     }
 
     #[test]
-    fn parse_single_package_changelog_config() -> anyhow::Result<()> {
+    fn new_crate_template_rejects_invalid_version() -> anyhow::Result<()> {
         let toml = r#"
-[package]
-name = "my-crate"
-version = "0.1.0"
+[workspace]
+members = ["crates/*"]
 
-[package.metadata.changeset]
-changelog = "root"
-comparison-links = "disabled"
+[workspace.metadata.changeset.new-crate]
+version = "one"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_package_root_config(dir.path())?;
-        let changelog_config = config.changelog_config();
+        let result = parse_workspace_root_config(dir.path(), &[]);
+
+        assert!(matches!(
+            result,
+            Err(ProjectError::InvalidTemplateVersion { ref version, .. }) if version == "one"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_channels_extends_builtin_ones() -> anyhow::Result<()> {
+        let toml = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.metadata.changeset.channels]
+beta = { tag-namespace = "beta/" }
+canary = { prerelease = "canary", publish = false }
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert_eq!(changelog_config.changelog, ChangelogLocation::Root);
         assert_eq!(
-            changelog_config.comparison_links,
-            ComparisonLinksSetting::Disabled
+            config.channel("beta"),
+            Some(
+                &ReleaseChannel::new("beta")
+                    .with_prerelease("beta")
+                    .with_tag_namespace("beta/")
+            )
+        );
+        assert_eq!(
+            config.channel("canary"),
+            Some(
+                &ReleaseChannel::new("canary")
+                    .with_prerelease("canary")
+                    .with_publish(false)
+            )
         );
+        let channel_of = |version: &str| -> anyhow::Result<Option<String>> {
+            Ok(config
+                .channel_of_version(&version.parse()?)
+                .map(|channel| channel.name().to_string()))
+        };
+        assert_eq!(channel_of("1.2.0")?.as_deref(), Some("stable"));
+        assert_eq!(channel_of("1.2.0-nightly.3")?.as_deref(), Some("nightly"));
+        assert_eq!(channel_of("1.2.0-rc.1")?, None);
+
+        let dir = setup_with_config(
+            "[workspace]\n[workspace.metadata.changeset.channels]\nnext = { prerelease = \"beta\" }\n",
+        )?;
+        assert!(matches!(
+            parse_workspace_root_config(dir.path(), &[]),
+            Err(ProjectError::InvalidChannel { ref name, .. }) if name == "next"
+        ));
 
         Ok(())
     }
 
     #[test]
-    fn parse_git_config_defaults() -> anyhow::Result<()> {
+    fn parse_version_stamps() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
+
+[[workspace.metadata.changeset.version-stamps]]
+files = "README.md"
+pattern = '{package} = "{version}"'
+
+[[workspace.metadata.changeset.version-stamps]]
+files = "charts/*/Chart.yaml"
+pattern = "appVersion: {version}"
+package = "server"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
-        let git_config = config.git_config();
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
-        assert!(git_config.commit());
-        assert!(git_config.tags());
-        assert!(!git_config.keep_changesets());
-        assert_eq!(git_config.tag_format(), TagFormat::VersionOnly);
-        assert_eq!(git_config.commit_title_template(), "{new-version}");
-        assert!(git_config.changes_in_body());
+        assert_eq!(
+            config.version_stamps(),
+            [
+                VersionStamp::new("README.md", r#"{package} = "{version}""#),
+                VersionStamp::new("charts/*/Chart.yaml", "appVersion: {version}")
+                    .with_package("server"),
+            ]
+        );
 
         Ok(())
     }
 
     #[test]
-    fn parse_git_config_all_options() -> anyhow::Result<()> {
+    fn parse_readme_badges() -> anyhow::Result<()> {
+        let toml = r#"
+[package]
+name = "my-crate"
+version = "0.1.0"
+
+[package.metadata.changeset]
+readme-badges = false
+readme-badge-patterns = ["my-crate@{version}"]
+"#;
+        let dir = setup_with_config(toml)?;
+
+        let config = parse_package_config(dir.path())?;
+
+        assert_eq!(
+            config.readme_badges(),
+            &ReadmeBadges::new(false).with_patterns(vec!["my-crate@{version}".to_string()])
+        );
+        assert_eq!(
+            parse_package_root_config(dir.path(), &[])?
+                .readme_badges()
+                .enabled(),
+            Some(false)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_duplicate_categories() -> anyhow::Result<()> {
         let toml = r#"
 [workspace]
 members = ["crates/*"]
 
 [workspace.metadata.changeset]
-commit = false
-tags = false
-keep-changesets = true
-tag-format = "crate-prefixed"
-commit-title-template = "chore(release): {new-version}"
-changes-in-body = false
+categories = [{ id = "perf" }, { id = "Perf" }]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
-        let git_config = config.git_config();
+        let result = parse_workspace_root_config(dir.path(), &[]);
 
-        assert!(!git_config.commit());
-        assert!(!git_config.tags());
-        assert!(git_config.keep_changesets());
-        assert_eq!(git_config.tag_format(), TagFormat::CratePrefixed);
-        assert_eq!(
-            git_config.commit_title_template(),
-            "chore(release): {new-version}"
-        );
-        assert!(!git_config.changes_in_body());
+        assert!(matches!(
+            result,
+            Err(ProjectError::InvalidCategory { ref id, .. }) if id == "Perf"
+        ));
 
         Ok(())
     }
@@ -677,7 +2986,7 @@ tag-format = "version-only"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
         let git_config = config.git_config();
 
         assert_eq!(git_config.tag_format(), TagFormat::VersionOnly);
@@ -699,7 +3008,7 @@ keep-changesets = true
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_package_root_config(dir.path())?;
+        let config = parse_package_root_config(dir.path(), &[])?;
         let git_config = config.git_config();
 
         assert!(!git_config.commit());
@@ -717,7 +3026,7 @@ members = ["crates/*"]
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
         assert_eq!(
             config.zero_version_behavior(),
@@ -738,7 +3047,7 @@ zero-version-behavior = "effective-minor"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
         assert_eq!(
             config.zero_version_behavior(),
@@ -759,7 +3068,7 @@ zero-version-behavior = "auto-promote-on-major"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_workspace_root_config(dir.path())?;
+        let config = parse_workspace_root_config(dir.path(), &[])?;
 
         assert_eq!(
             config.zero_version_behavior(),
@@ -781,7 +3090,7 @@ zero-version-behavior = "auto-promote-on-major"
 "#;
         let dir = setup_with_config(toml)?;
 
-        let config = parse_package_root_config(dir.path())?;
+        let config = parse_package_root_config(dir.path(), &[])?;
 
         assert_eq!(
             config.zero_version_behavior(),
@@ -0,0 +1,310 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::CargoProject;
+use crate::error::ProjectError;
+
+/// The dependency tables of a package manifest that matter when the package
+/// is built by others: `[dependencies]`, `[build-dependencies]` and their
+/// `[target.*]` variants. Dev-dependencies are left out.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct DependencyTables {
+    #[serde(default)]
+    dependencies: BTreeMap<String, toml::Value>,
+    #[serde(default, rename = "build-dependencies")]
+    build_dependencies: BTreeMap<String, toml::Value>,
+    #[serde(default)]
+    target: BTreeMap<String, TargetDependencyTables>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TargetDependencyTables {
+    #[serde(default)]
+    dependencies: BTreeMap<String, toml::Value>,
+    #[serde(default, rename = "build-dependencies")]
+    build_dependencies: BTreeMap<String, toml::Value>,
+}
+
+impl DependencyTables {
+    /// Every declared dependency as `(package name, spec)`, honoring
+    /// `package = "..."` renames.
+    pub(crate) fn specs(&self) -> impl Iterator<Item = (&str, &toml::Value)> {
+        [&self.dependencies, &self.build_dependencies]
+            .into_iter()
+            .chain(
+                self.target
+                    .values()
+                    .flat_map(|target| [&target.dependencies, &target.build_dependencies]),
+            )
+            .flat_map(|table| table.iter())
+            .map(|(key, value)| {
+                let name = value
+                    .get("package")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or(key);
+                (name, value)
+            })
+    }
+}
+
+#[derive(Deserialize)]
+struct RootManifest {
+    workspace: Option<WorkspaceTable>,
+}
+
+#[derive(Deserialize)]
+struct WorkspaceTable {
+    #[serde(default)]
+    dependencies: BTreeMap<String, toml::Value>,
+}
+
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+#[derive(Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(path: &Path, content: &str) -> Result<T, ProjectError> {
+    toml::from_str(content).map_err(|source| ProjectError::ManifestParse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The dependencies a package manifest declares, keyed by package name.
+///
+/// When a package is declared in several tables, the specs are kept in one
+/// array so that a change to any of them is noticed.
+///
+/// # Errors
+///
+/// Returns `ProjectError::ManifestParse` if `content` is not valid TOML.
+pub fn dependency_specs(
+    path: &Path,
+    content: &str,
+) -> Result<BTreeMap<String, toml::Value>, ProjectError> {
+    let tables: DependencyTables = parse(path, content)?;
+    let mut specs: BTreeMap<String, Vec<toml::Value>> = BTreeMap::new();
+    for (name, spec) in tables.specs() {
+        specs
+            .entry(name.to_string())
+            .or_default()
+            .push(spec.clone());
+    }
+    Ok(specs
+        .into_iter()
+        .map(|(name, mut declared)| {
+            let spec = if declared.len() == 1 {
+                declared.remove(0)
+            } else {
+                toml::Value::Array(declared)
+            };
+            (name, spec)
+        })
+        .collect())
+}
+
+/// Whether a dependency spec inherits from `[workspace.dependencies]`.
+#[must_use]
+pub fn is_workspace_inherited(spec: &toml::Value) -> bool {
+    let inherits =
+        |spec: &toml::Value| spec.get("workspace").and_then(toml::Value::as_bool) == Some(true);
+    match spec {
+        toml::Value::Array(declared) => declared.iter().any(inherits),
+        spec => inherits(spec),
+    }
+}
+
+/// The `[workspace.dependencies]` table of a root manifest.
+///
+/// # Errors
+///
+/// Returns `ProjectError::ManifestParse` if `content` is not valid TOML.
+pub fn workspace_dependency_specs(
+    path: &Path,
+    content: &str,
+) -> Result<BTreeMap<String, toml::Value>, ProjectError> {
+    let manifest: RootManifest = parse(path, content)?;
+    Ok(manifest
+        .workspace
+        .map(|workspace| workspace.dependencies)
+        .unwrap_or_default())
+}
+
+/// The versions of each package a `Cargo.lock` pins.
+///
+/// # Errors
+///
+/// Returns `ProjectError::ManifestParse` if `content` is not valid TOML.
+pub fn locked_versions(
+    path: &Path,
+    content: &str,
+) -> Result<BTreeMap<String, BTreeSet<String>>, ProjectError> {
+    let lockfile: Lockfile = parse(path, content)?;
+    let mut versions: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for package in lockfile.package {
+        versions
+            .entry(package.name)
+            .or_default()
+            .insert(package.version);
+    }
+    Ok(versions)
+}
+
+/// Names whose entry differs between `base` and `current`, including names
+/// only one of them has.
+#[must_use]
+pub fn changed_keys<V: PartialEq>(
+    base: &BTreeMap<String, V>,
+    current: &BTreeMap<String, V>,
+) -> BTreeSet<String> {
+    base.keys()
+        .chain(current.keys())
+        .filter(|name| base.get(*name) != current.get(*name))
+        .cloned()
+        .collect()
+}
+
+/// The workspace packages that depend on each workspace package through the
+/// tables of [`dependency_specs`].
+///
+/// # Errors
+///
+/// Returns an error if a package manifest cannot be read or parsed.
+pub fn workspace_dependents(
+    project: &CargoProject,
+) -> Result<BTreeMap<String, BTreeSet<String>>, ProjectError> {
+    let names: BTreeSet<&str> = project.packages.iter().map(|p| p.name.as_str()).collect();
+    let mut dependents: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for package in &project.cargo_packages() {
+        let path = package.path.join("Cargo.toml");
+        let content =
+            std::fs::read_to_string(&path).map_err(|source| ProjectError::ManifestRead {
+                path: path.clone(),
+                source,
+            })?;
+        let tables: DependencyTables = parse(&path, &content)?;
+        for (name, _) in tables.specs() {
+            if name != package.name && names.contains(name) {
+                dependents
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(package.name.clone());
+            }
+        }
+    }
+    Ok(dependents)
+}
+
+/// Packages up to `depth` dependency levels above `changed`, each with the
+/// changed package it was reached from. Packages in `changed` are left out.
+#[must_use]
+pub fn transitive_dependents(
+    dependents: &BTreeMap<String, BTreeSet<String>>,
+    changed: &[&str],
+    depth: usize,
+) -> BTreeMap<String, String> {
+    let mut reached: BTreeMap<String, String> = BTreeMap::new();
+    let mut frontier: Vec<(&str, &str)> = changed.iter().map(|name| (*name, *name)).collect();
+
+    for _ in 0..depth {
+        let mut next = Vec::new();
+        for (package, origin) in frontier {
+            for dependent in dependents.get(package).into_iter().flatten() {
+                if changed.contains(&dependent.as_str()) || reached.contains_key(dependent) {
+                    continue;
+                }
+                reached.insert(dependent.clone(), origin.to_string());
+                next.push((dependent.as_str(), origin));
+            }
+        }
+        frontier = next;
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specs_are_keyed_by_package_name_without_dev_dependencies() -> anyhow::Result<()> {
+        let specs = dependency_specs(
+            Path::new("Cargo.toml"),
+            "[package]\nname = \"app\"\n\
+             [dependencies]\njson = { package = \"serde_json\", version = \"1\" }\n\
+             serde = { workspace = true }\n\
+             [target.'cfg(unix)'.dependencies]\nserde = \"1\"\n\
+             [dev-dependencies]\ninsta = \"1\"\n",
+        )?;
+
+        assert_eq!(
+            specs.keys().map(String::as_str).collect::<Vec<_>>(),
+            ["serde", "serde_json"]
+        );
+        assert!(is_workspace_inherited(&specs["serde"]));
+        assert!(!is_workspace_inherited(&specs["serde_json"]));
+        Ok(())
+    }
+
+    #[test]
+    fn lockfile_changes_are_detected_per_package() -> anyhow::Result<()> {
+        let path = Path::new("Cargo.lock");
+        let base = locked_versions(
+            path,
+            "[[package]]\nname = \"log\"\nversion = \"0.4.20\"\n\n\
+             [[package]]\nname = \"rand\"\nversion = \"0.8.5\"\n",
+        )?;
+        let current = locked_versions(
+            path,
+            "[[package]]\nname = \"log\"\nversion = \"0.4.21\"\n\n\
+             [[package]]\nname = \"rand\"\nversion = \"0.8.5\"\n",
+        )?;
+
+        assert_eq!(
+            changed_keys(&base, &current)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            ["log"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dependents_are_followed_up_to_depth() {
+        let dependents = BTreeMap::from([
+            (
+                "core".to_string(),
+                BTreeSet::from(["sdk".to_string(), "macros".to_string()]),
+            ),
+            ("sdk".to_string(), BTreeSet::from(["cli".to_string()])),
+            ("cli".to_string(), BTreeSet::from(["app".to_string()])),
+        ]);
+
+        assert!(transitive_dependents(&dependents, &["core"], 0).is_empty());
+        assert_eq!(
+            transitive_dependents(&dependents, &["core"], 2),
+            BTreeMap::from([
+                ("cli".to_string(), "core".to_string()),
+                ("macros".to_string(), "core".to_string()),
+                ("sdk".to_string(), "core".to_string()),
+            ])
+        );
+        assert_eq!(
+            transitive_dependents(&dependents, &["core", "sdk"], 1),
+            BTreeMap::from([
+                ("cli".to_string(), "sdk".to_string()),
+                ("macros".to_string(), "core".to_string()),
+            ])
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::Path;
+
+use crate::CargoProject;
+
+const CHANGELOG_FILENAME: &str = "CHANGELOG.md";
+
+/// Where a project already keeps changelogs and how they are written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogLayout {
+    /// Whether the project root has a `CHANGELOG.md`.
+    pub root: bool,
+    /// Packages outside the project root with their own `CHANGELOG.md`.
+    pub packages: Vec<String>,
+    /// Whether an existing changelog follows the Keep a Changelog layout.
+    pub keep_a_changelog: bool,
+}
+
+/// Looks for existing `CHANGELOG.md` files at the project root and in each
+/// package directory.
+///
+/// Returns `None` when the project has no changelog yet.
+#[must_use]
+pub fn detect_changelog_layout(project: &CargoProject) -> Option<ChangelogLayout> {
+    let root_changelog = project.root.join(CHANGELOG_FILENAME);
+    let mut contents = Vec::new();
+
+    let root = read_changelog(&root_changelog).map(|content| contents.push(content));
+    let packages: Vec<String> = project
+        .packages
+        .iter()
+        .filter(|package| package.path != project.root)
+        .filter_map(|package| {
+            let content = read_changelog(&package.path.join(CHANGELOG_FILENAME))?;
+            contents.push(content);
+            Some(package.name.clone())
+        })
+        .collect();
+
+    if root.is_none() && packages.is_empty() {
+        return None;
+    }
+
+    Some(ChangelogLayout {
+        root: root.is_some(),
+        packages,
+        keep_a_changelog: contents.iter().any(|content| is_keep_a_changelog(content)),
+    })
+}
+
+fn read_changelog(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
+
+fn is_keep_a_changelog(content: &str) -> bool {
+    content.contains("keepachangelog.com")
+        || content
+            .lines()
+            .any(|line| line.trim().eq_ignore_ascii_case("## [unreleased]"))
+}
+
+#[cfg(test)]
+mod tests {
+    use changeset_core::PackageInfo;
+    use semver::Version;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::ProjectKind;
+
+    fn workspace(dir: &TempDir, names: &[&str]) -> CargoProject {
+        CargoProject {
+            root: dir.path().to_path_buf(),
+            kind: ProjectKind::VirtualWorkspace,
+            packages: names
+                .iter()
+                .map(|name| {
+                    let path = dir.path().join("crates").join(name);
+                    fs::create_dir_all(&path).expect("create package dir");
+                    PackageInfo {
+                        name: (*name).to_string(),
+                        version: Version::new(1, 0, 0),
+                        path,
+                    }
+                })
+                .collect(),
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn no_changelogs_yields_none() {
+        let dir = TempDir::new().expect("create temp dir");
+        let project = workspace(&dir, &["foo"]);
+
+        assert_eq!(detect_changelog_layout(&project), None);
+    }
+
+    #[test]
+    fn detects_per_package_changelogs() {
+        let dir = TempDir::new().expect("create temp dir");
+        let project = workspace(&dir, &["foo", "bar"]);
+        fs::write(
+            dir.path().join("crates/foo/CHANGELOG.md"),
+            "# Changelog\n\n## 1.0.0\n",
+        )
+        .expect("write changelog");
+
+        let layout = detect_changelog_layout(&project).expect("layout");
+
+        assert!(!layout.root);
+        assert_eq!(layout.packages, vec!["foo".to_string()]);
+        assert!(!layout.keep_a_changelog);
+    }
+
+    #[test]
+    fn detects_keep_a_changelog_root() {
+        let dir = TempDir::new().expect("create temp dir");
+        let project = workspace(&dir, &["foo"]);
+        fs::write(
+            dir.path().join("CHANGELOG.md"),
+            "# Changelog\n\n## [Unreleased]\n\n## [1.0.0] - 2025-01-31\n",
+        )
+        .expect("write changelog");
+
+        let layout = detect_changelog_layout(&project).expect("layout");
+
+        assert!(layout.root);
+        assert!(layout.packages.is_empty());
+        assert!(layout.keep_a_changelog);
+    }
+}
@@ -38,6 +38,9 @@ pub enum ProjectError {
         source: semver::Error,
     },
 
+    #[error("malformed package manifest at '{path}': {reason}")]
+    PackageManifest { path: PathBuf, reason: String },
+
     #[error("invalid glob pattern '{pattern}'")]
     GlobPattern {
         pattern: String,
@@ -45,10 +48,72 @@ pub enum ProjectError {
         source: globset::Error,
     },
 
+    #[error("invalid version '{version}' in the new-crate template")]
+    InvalidTemplateVersion {
+        version: String,
+        #[source]
+        source: semver::Error,
+    },
+
+    #[error("invalid change category '{id}': {reason}")]
+    InvalidCategory { id: String, reason: &'static str },
+
+    #[error("invalid release channel '{name}': {reason}")]
+    InvalidChannel { name: String, reason: &'static str },
+
+    #[error("invalid config override '{input}': {reason}")]
+    InvalidConfigOverride { input: String, reason: String },
+
+    #[error("invalid git identity '{value}': expected 'Name <email>'")]
+    InvalidGitIdentity { value: String },
+
+    #[error("failed to read '{path}'")]
+    MultiRepoRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse '{path}'")]
+    MultiRepoParse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("invalid changeset-workspaces.toml: {reason}")]
+    InvalidMultiRepo { reason: String },
+
+    #[error("'{path}' is listed in workspace default-members but is not a member")]
+    DefaultMemberNotMember { path: PathBuf },
+
     #[error("failed to create directory '{path}'")]
     DirectoryCreate {
         path: PathBuf,
         #[source]
         source: std::io::Error,
     },
+
+    #[error("failed to write cache file '{path}'")]
+    CacheWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(
+        "{file} uses config_version {version}, but this release supports up to {supported}; upgrade cargo-changeset"
+    )]
+    UnsupportedConfigVersion {
+        file: &'static str,
+        version: u32,
+        supported: u32,
+    },
+
+    #[error("unexpected layout in {file}")]
+    StateLayout {
+        file: &'static str,
+        #[source]
+        source: toml::de::Error,
+    },
 }
@@ -1,9 +1,19 @@
+mod adapter;
+mod codeowners;
 mod config;
+mod dependencies;
+mod detect;
 mod error;
 mod manifest;
 mod mapping;
+mod mapping_cache;
+mod multi_repo;
+mod overrides;
 mod project;
+mod publish;
 mod release_state;
+mod state_migration;
+mod submodules;
 
 pub const DEFAULT_CHANGESET_DIR: &str = ".changeset";
 
@@ -11,13 +21,40 @@ pub const DEFAULT_CHANGESET_DIR: &str = ".changeset";
 /// Full path: `<project_root>/<changeset_dir>/changesets/`
 pub const CHANGESETS_SUBDIR: &str = "changesets";
 
+/// File within the changeset directory that records consumed changesets when
+/// [`ConsumedStateStorage::File`] is configured.
+pub const CONSUMED_STATE_FILENAME: &str = "consumed.toml";
+
+pub use adapter::{CargoAdapter, NpmAdapter, PackageAdapter, PackageAdapters, PythonAdapter};
+pub use codeowners::{CodeOwners, package_owners};
 pub use config::{
-    GitConfig, PackageChangesetConfig, RootChangesetConfig, TagFormat, load_changeset_configs,
-    parse_package_config, parse_root_config,
+    ChangesetNaming, CommitBody, CommitStrategy, ConsumedStateStorage, GitConfig, GitIdentity,
+    LintConfig, NewCrateTemplate, NotificationConfig, OfflineBehavior, OwnedChangesetDir,
+    PackageChangesetConfig, ReadmeBadges, ReleaseChannel, RequireChangeset, RootChangesetConfig,
+    ScopeConfig, TagBody, TagFormat, VersionStamp, WebhookFormat, load_changeset_configs,
+    load_changeset_configs_with_overrides, parse_package_config, parse_root_config,
+    parse_root_config_with_overrides,
+};
+pub use dependencies::{
+    changed_keys, dependency_specs, is_workspace_inherited, locked_versions, transitive_dependents,
+    workspace_dependency_specs, workspace_dependents,
 };
+pub use detect::{ChangelogLayout, detect_changelog_layout};
 pub use error::ProjectError;
 pub use mapping::{FileMapping, PackageFiles, map_files_to_packages};
-pub use project::{CargoProject, ProjectKind, discover_project, ensure_changeset_dir};
-pub use release_state::{GraduationState, PrereleaseState};
+pub use mapping_cache::{CACHE_SUBDIR, MappingCache, map_files_to_packages_cached};
+pub use multi_repo::{
+    MULTI_REPO_CONFIG_FILENAME, MultiRepoConfig, RepositoryEntry, find_multi_repo_config,
+};
+pub use overrides::ConfigOverride;
+pub use project::{
+    CargoProject, ProjectKind, discover_project, discover_project_with_adapters,
+    ensure_changeset_dir, ensure_owned_changeset_dir,
+};
+pub use publish::publish_order;
+pub use release_state::{GraduationPolicy, GraduationState, PrereleaseState, StateMerge};
+pub use state_migration::{
+    CONFIG_VERSION_KEY, CURRENT_CONFIG_VERSION, Migrated, StateFile, migrate_state, serialize_state,
+};
 
 pub type Result<T> = std::result::Result<T, ProjectError>;
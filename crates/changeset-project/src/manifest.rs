@@ -1,10 +1,15 @@
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
 use std::path::Path;
 
-use changeset_changelog::{ChangelogLocation, ComparisonLinksSetting};
+use changeset_changelog::{
+    ChangelogFormat, ChangelogLocation, ChangelogOutput, ComparisonLinksSetting, EntryOrder,
+};
 use changeset_core::ZeroVersionBehavior;
 use serde::Deserialize;
 
 use crate::error::ProjectError;
+use crate::overrides::ConfigOverride;
 
 pub(crate) fn read_manifest(path: &Path) -> Result<CargoManifest, ProjectError> {
     let content = std::fs::read_to_string(path).map_err(|source| ProjectError::ManifestRead {
@@ -18,10 +23,50 @@ pub(crate) fn read_manifest(path: &Path) -> Result<CargoManifest, ProjectError>
     })
 }
 
+/// Reads a manifest with `overrides` applied to the changeset metadata table
+/// found under `section` (`workspace` or `package`).
+pub(crate) fn read_manifest_with_overrides(
+    path: &Path,
+    section: &str,
+    overrides: &[ConfigOverride],
+) -> Result<CargoManifest, ProjectError> {
+    if overrides.is_empty() {
+        return read_manifest(path);
+    }
+
+    let content = std::fs::read_to_string(path).map_err(|source| ProjectError::ManifestRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let parse_error = |source| ProjectError::ManifestParse {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let mut manifest: toml::Table = toml::from_str(&content).map_err(parse_error)?;
+    let mut table = &mut manifest;
+    for key in [section, "metadata", "changeset"] {
+        let entry = table
+            .entry(key)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        let toml::Value::Table(next) = entry else {
+            // Leave a malformed manifest for the typed parse to report.
+            return toml::from_str(&content).map_err(parse_error);
+        };
+        table = next;
+    }
+    for config_override in overrides {
+        config_override.apply(table);
+    }
+
+    toml::Value::Table(manifest).try_into().map_err(parse_error)
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct CargoManifest {
     pub(crate) package: Option<Package>,
     pub(crate) workspace: Option<WorkspaceSection>,
+    pub(crate) lib: Option<toml::Table>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -47,6 +92,8 @@ pub(crate) struct InheritedVersion {
 pub(crate) struct WorkspaceSection {
     pub(crate) members: Option<Vec<String>>,
     pub(crate) exclude: Option<Vec<String>>,
+    #[serde(rename = "default-members")]
+    pub(crate) default_members: Option<Vec<String>>,
     pub(crate) package: Option<WorkspacePackage>,
     pub(crate) metadata: Option<WorkspaceMetadata>,
 }
@@ -74,12 +121,26 @@ pub(crate) struct ChangesetMetadata {
     #[serde(default)]
     pub(crate) changeset_dir: Option<String>,
     #[serde(default)]
+    pub(crate) changeset_dirs: BTreeMap<String, String>,
+    #[serde(default)]
     pub(crate) changelog: Option<ChangelogLocation>,
     #[serde(default)]
     pub(crate) comparison_links: Option<ComparisonLinksSetting>,
     #[serde(default)]
     pub(crate) comparison_links_template: Option<String>,
     #[serde(default)]
+    pub(crate) changelog_entry_order: Option<EntryOrder>,
+    #[serde(default)]
+    pub(crate) changelog_format: Option<ChangelogFormat>,
+    #[serde(default)]
+    pub(crate) changelog_preamble: Option<String>,
+    #[serde(default)]
+    pub(crate) changelog_output: Vec<ChangelogOutput>,
+    #[serde(default)]
+    pub(crate) changelog_max_releases: Option<NonZeroUsize>,
+    #[serde(default)]
+    pub(crate) changelog_archive: Option<String>,
+    #[serde(default)]
     pub(crate) commit: Option<bool>,
     #[serde(default)]
     pub(crate) tags: Option<bool>,
@@ -88,11 +149,131 @@ pub(crate) struct ChangesetMetadata {
     #[serde(default)]
     pub(crate) tag_format: Option<TagFormatValue>,
     #[serde(default)]
+    pub(crate) tag_body: Option<TagBodyValue>,
+    #[serde(default)]
+    pub(crate) commit_strategy: Option<CommitStrategyValue>,
+    #[serde(default)]
     pub(crate) commit_title_template: Option<String>,
     #[serde(default)]
     pub(crate) changes_in_body: Option<bool>,
     #[serde(default)]
+    pub(crate) commit_body: Option<CommitBodyValue>,
+    #[serde(default)]
+    pub(crate) commit_body_max_length: Option<usize>,
+    #[serde(default)]
+    pub(crate) commit_body_truncation: Option<String>,
+    #[serde(default)]
+    pub(crate) release_branch: Option<String>,
+    #[serde(default)]
+    pub(crate) ignore_submodule_changes: Option<bool>,
+    #[serde(default)]
+    pub(crate) remote: Option<String>,
+    #[serde(default)]
+    pub(crate) commit_author: Option<String>,
+    #[serde(default)]
+    pub(crate) commit_committer: Option<String>,
+    #[serde(default)]
     pub(crate) zero_version_behavior: Option<ZeroVersionBehavior>,
+    #[serde(default)]
+    pub(crate) webhook_url: Option<String>,
+    #[serde(default)]
+    pub(crate) webhook_format: Option<WebhookFormatValue>,
+    #[serde(default)]
+    pub(crate) webhook_template: Option<String>,
+    #[serde(default)]
+    pub(crate) lint_summaries: Option<bool>,
+    #[serde(default)]
+    pub(crate) lint_denied_words: Vec<String>,
+    #[serde(default)]
+    pub(crate) display_names: HashMap<String, String>,
+    #[serde(default)]
+    pub(crate) owners: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub(crate) ignore_optional_dependencies: Option<bool>,
+    #[serde(default)]
+    pub(crate) categories: Option<Vec<CategoryValue>>,
+    #[serde(default)]
+    pub(crate) scopes: Vec<String>,
+    #[serde(default)]
+    pub(crate) require_scope: Option<bool>,
+    #[serde(default)]
+    pub(crate) release_pre_checks: Vec<String>,
+    #[serde(default)]
+    pub(crate) version_stamps: Vec<VersionStampValue>,
+    #[serde(default)]
+    pub(crate) readme_badges: Option<bool>,
+    #[serde(default)]
+    pub(crate) readme_badge_patterns: Vec<String>,
+    #[serde(default)]
+    pub(crate) offline_behavior: Option<OfflineBehaviorValue>,
+    #[serde(default)]
+    pub(crate) stale_changeset_releases: Option<usize>,
+    #[serde(default)]
+    pub(crate) consumed_state: Option<ConsumedStateValue>,
+    #[serde(default)]
+    pub(crate) require_changeset: Option<RequireChangesetValue>,
+    #[serde(default)]
+    pub(crate) transitive_coverage_depth: Option<usize>,
+    #[serde(default)]
+    pub(crate) changeset_names: Option<ChangesetNamesValue>,
+    #[serde(default)]
+    pub(crate) new_crate: Option<NewCrateValue>,
+    #[serde(default)]
+    pub(crate) extra_packages: Vec<String>,
+    #[serde(default)]
+    pub(crate) channels: BTreeMap<String, ChannelValue>,
+    #[serde(default)]
+    pub(crate) channel: Option<String>,
+}
+
+/// One entry of the `categories` list; omitted fields fall back to defaults.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct CategoryValue {
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+    #[serde(default)]
+    pub(crate) section: Option<String>,
+    #[serde(default)]
+    pub(crate) order: Option<i64>,
+}
+
+/// One entry of the `version-stamps` list.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct VersionStampValue {
+    pub(crate) files: String,
+    pub(crate) pattern: String,
+    #[serde(default)]
+    pub(crate) package: Option<String>,
+}
+
+/// One entry of the `channels` table; omitted fields fall back to the built-in
+/// channel of the same name, if any.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct ChannelValue {
+    #[serde(default)]
+    pub(crate) prerelease: Option<String>,
+    #[serde(default)]
+    pub(crate) tag_namespace: Option<String>,
+    #[serde(default)]
+    pub(crate) publish: Option<bool>,
+}
+
+/// The `new-crate` table; omitted fields fall back to defaults.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct NewCrateValue {
+    #[serde(default)]
+    pub(crate) path: Option<String>,
+    #[serde(default)]
+    pub(crate) version: Option<String>,
+    #[serde(default)]
+    pub(crate) edition: Option<String>,
+    #[serde(default)]
+    pub(crate) package_metadata: toml::Table,
 }
 
 #[derive(Debug, Deserialize, Clone, Copy)]
@@ -101,3 +282,61 @@ pub(crate) enum TagFormatValue {
     VersionOnly,
     CratePrefixed,
 }
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum TagBodyValue {
+    Summary,
+    Changelog,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum CommitBodyValue {
+    List,
+    Grouped,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum CommitStrategyValue {
+    Single,
+    PerPackage,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum WebhookFormatValue {
+    Generic,
+    Slack,
+    Discord,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum OfflineBehaviorValue {
+    Skip,
+    Error,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum RequireChangesetValue {
+    Always,
+    LibraryOnly,
+    Never,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ChangesetNamesValue {
+    Random,
+    Branch,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ConsumedStateValue {
+    Frontmatter,
+    File,
+}
@@ -3,9 +3,11 @@ use std::hash::BuildHasher;
 use std::path::{Path, PathBuf};
 
 use changeset_core::PackageInfo;
+use serde::{Deserialize, Serialize};
 
 use crate::config::{PackageChangesetConfig, RootChangesetConfig};
 use crate::project::CargoProject;
+use crate::submodules::read_submodule_paths;
 
 /// Mapping of files to a single package.
 ///
@@ -37,8 +39,17 @@ impl FileMapping {
     }
 }
 
-struct PackageWithDepth {
-    package: PackageInfo,
+/// Where a single file belongs; the unit stored in the mapping cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", tag = "kind", content = "name")]
+pub(crate) enum FileOwner {
+    Package(String),
+    Project,
+    Ignored,
+}
+
+struct PackageWithDepth<'a> {
+    package: &'a PackageInfo,
     depth: usize,
 }
 
@@ -46,70 +57,105 @@ fn calculate_path_depth(path: &Path) -> usize {
     path.components().count()
 }
 
-#[must_use]
-pub fn map_files_to_packages<S: BuildHasher>(
-    project: &CargoProject,
-    changed_files: &[PathBuf],
-    root_config: &RootChangesetConfig,
-    package_configs: &HashMap<String, PackageChangesetConfig, S>,
-) -> FileMapping {
-    let mut packages_with_depth: Vec<PackageWithDepth> = project
-        .packages
-        .iter()
-        .map(|p| {
-            // Fallback to full path if strip_prefix fails (shouldn't happen in practice)
-            let relative_path = p.path.strip_prefix(&project.root).unwrap_or(&p.path);
-            PackageWithDepth {
-                package: p.clone(),
-                depth: calculate_path_depth(relative_path),
-            }
-        })
-        .collect();
-
-    packages_with_depth.sort_by(|a, b| b.depth.cmp(&a.depth));
+/// Classifies files against a project's packages, deepest package first.
+pub(crate) struct FileClassifier<'a, S: BuildHasher> {
+    project: &'a CargoProject,
+    packages_with_depth: Vec<PackageWithDepth<'a>>,
+    root_config: &'a RootChangesetConfig,
+    package_configs: &'a HashMap<String, PackageChangesetConfig, S>,
+    /// Submodule checkouts, as absolute paths; their content belongs to no package.
+    submodules: Vec<PathBuf>,
+}
 
-    let mut package_files_map: HashMap<String, Vec<PathBuf>> = HashMap::new();
-    let mut project_files = Vec::new();
-    let mut ignored_files = Vec::new();
+impl<'a, S: BuildHasher> FileClassifier<'a, S> {
+    pub(crate) fn new(
+        project: &'a CargoProject,
+        root_config: &'a RootChangesetConfig,
+        package_configs: &'a HashMap<String, PackageChangesetConfig, S>,
+    ) -> Self {
+        let mut packages_with_depth: Vec<PackageWithDepth<'a>> = project
+            .packages
+            .iter()
+            .map(|p| {
+                // Fallback to full path if strip_prefix fails (shouldn't happen in practice)
+                let relative_path = p.path.strip_prefix(&project.root).unwrap_or(&p.path);
+                PackageWithDepth {
+                    package: p,
+                    depth: calculate_path_depth(relative_path),
+                }
+            })
+            .collect();
+
+        packages_with_depth.sort_by_key(|b| std::cmp::Reverse(b.depth));
+
+        let submodules = read_submodule_paths(&project.root)
+            .into_iter()
+            .map(|path| project.root.join(path))
+            .collect();
+
+        Self {
+            project,
+            packages_with_depth,
+            root_config,
+            package_configs,
+            submodules,
+        }
+    }
 
-    for file in changed_files {
-        if root_config.is_ignored(file) {
-            ignored_files.push(file.clone());
-            continue;
+    pub(crate) fn classify(&self, file: &Path) -> FileOwner {
+        if self.root_config.is_ignored(file) {
+            return FileOwner::Ignored;
         }
 
         let abs_file = if file.is_absolute() {
-            file.clone()
+            file.to_path_buf()
         } else {
-            project.root.join(file)
+            self.project.root.join(file)
         };
 
-        let mut matched = false;
-        for pwd in &packages_with_depth {
+        if self
+            .submodules
+            .iter()
+            .chain(&self.project.missing_members)
+            .any(|dir| abs_file.starts_with(dir))
+        {
+            return FileOwner::Ignored;
+        }
+
+        for pwd in &self.packages_with_depth {
             if abs_file.starts_with(&pwd.package.path) {
-                if let Some(pkg_config) = package_configs.get(&pwd.package.name) {
+                if let Some(pkg_config) = self.package_configs.get(&pwd.package.name) {
                     // Fallback to full path if strip_prefix fails (shouldn't happen in practice)
                     let relative_to_pkg = abs_file
                         .strip_prefix(&pwd.package.path)
                         .unwrap_or(&abs_file);
                     if pkg_config.is_ignored(relative_to_pkg) {
-                        ignored_files.push(file.clone());
-                        matched = true;
-                        break;
+                        return FileOwner::Ignored;
                     }
                 }
 
-                package_files_map
-                    .entry(pwd.package.name.clone())
-                    .or_default()
-                    .push(file.clone());
-                matched = true;
-                break;
+                return FileOwner::Package(pwd.package.name.clone());
             }
         }
 
-        if !matched {
-            project_files.push(file.clone());
+        FileOwner::Project
+    }
+}
+
+/// Groups classified files into a [`FileMapping`], preserving the input order.
+pub(crate) fn build_file_mapping(
+    project: &CargoProject,
+    classified: impl IntoIterator<Item = (PathBuf, FileOwner)>,
+) -> FileMapping {
+    let mut package_files_map: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    let mut project_files = Vec::new();
+    let mut ignored_files = Vec::new();
+
+    for (file, owner) in classified {
+        match owner {
+            FileOwner::Package(name) => package_files_map.entry(name).or_default().push(file),
+            FileOwner::Project => project_files.push(file),
+            FileOwner::Ignored => ignored_files.push(file),
         }
     }
 
@@ -129,6 +175,22 @@ pub fn map_files_to_packages<S: BuildHasher>(
     }
 }
 
+#[must_use]
+pub fn map_files_to_packages<S: BuildHasher>(
+    project: &CargoProject,
+    changed_files: &[PathBuf],
+    root_config: &RootChangesetConfig,
+    package_configs: &HashMap<String, PackageChangesetConfig, S>,
+) -> FileMapping {
+    let classifier = FileClassifier::new(project, root_config, package_configs);
+    build_file_mapping(
+        project,
+        changed_files
+            .iter()
+            .map(|file| (file.clone(), classifier.classify(file))),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,6 +210,8 @@ mod tests {
             root,
             kind: ProjectKind::VirtualWorkspace,
             packages,
+            missing_members: Vec::new(),
+            package_manifests: std::collections::BTreeMap::new(),
         }
     }
 
@@ -268,4 +332,64 @@ mod tests {
         assert!(mapping.package_files.is_empty());
         assert_eq!(mapping.project_files.len(), 1);
     }
+
+    #[test]
+    fn files_inside_submodules_are_ignored() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(
+            dir.path().join(".gitmodules"),
+            "[submodule \"vendor\"]\n\tpath = crates/crate-a/vendor\n\turl = ../vendor.git\n",
+        )?;
+        let root = dir.path().to_path_buf();
+        let project = make_project(
+            root.clone(),
+            vec![make_package("crate-a", root.join("crates/crate-a"))],
+        );
+
+        let changed_files = vec![
+            PathBuf::from("crates/crate-a/vendor"),
+            PathBuf::from("crates/crate-a/vendor/src/lib.rs"),
+            PathBuf::from("crates/crate-a/src/lib.rs"),
+        ];
+        let mapping = map_files_to_packages(
+            &project,
+            &changed_files,
+            &RootChangesetConfig::default(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            mapping.package_files[0].files,
+            vec![PathBuf::from("crates/crate-a/src/lib.rs")]
+        );
+        assert_eq!(mapping.ignored_files, changed_files[..2].to_vec());
+        Ok(())
+    }
+
+    #[test]
+    fn files_inside_missing_members_are_ignored() {
+        let root = PathBuf::from("/workspace");
+        let mut project = make_project(root.clone(), vec![make_package("my-crate", root.clone())]);
+        project.missing_members = vec![root.join("crates/sparse")];
+
+        let changed_files = vec![
+            PathBuf::from("crates/sparse/src/lib.rs"),
+            PathBuf::from("src/lib.rs"),
+        ];
+        let mapping = map_files_to_packages(
+            &project,
+            &changed_files,
+            &RootChangesetConfig::default(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            mapping.ignored_files,
+            vec![PathBuf::from("crates/sparse/src/lib.rs")]
+        );
+        assert_eq!(
+            mapping.package_files[0].files,
+            vec![PathBuf::from("src/lib.rs")]
+        );
+    }
 }
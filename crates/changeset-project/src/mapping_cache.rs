@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hash::BuildHasher;
+use std::path::{Path, PathBuf};
+
+use changeset_core::atomic_write;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{PackageChangesetConfig, RootChangesetConfig};
+use crate::error::ProjectError;
+use crate::mapping::{FileClassifier, FileMapping, FileOwner, build_file_mapping};
+use crate::project::CargoProject;
+use crate::submodules::GITMODULES_FILE;
+
+/// Subdirectory within the changeset directory holding regenerable caches.
+/// Full path: `<project_root>/<changeset_dir>/.cache/`
+pub const CACHE_SUBDIR: &str = ".cache";
+
+const MAPPING_CACHE_FILE: &str = "file-map.json";
+const CACHE_FORMAT_VERSION: u32 = 1;
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Persistent file-to-package assignments, keyed by a hash of every manifest
+/// in the project and its `.gitmodules` file.
+///
+/// Package layout and ignore patterns all live in `Cargo.toml` files, so any
+/// manifest change produces a new key and discards the cached entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingCache {
+    version: u32,
+    key: String,
+    entries: HashMap<PathBuf, FileOwner>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl MappingCache {
+    /// Loads the cache for `project` from `cache_dir`.
+    ///
+    /// A missing, unreadable, or stale cache file yields an empty cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProjectError::ManifestRead` if a project manifest cannot be read.
+    pub fn load(project: &CargoProject, cache_dir: &Path) -> Result<Self, ProjectError> {
+        let key = manifest_fingerprint(project)?;
+
+        let cached = fs::read(cache_dir.join(MAPPING_CACHE_FILE))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<Self>(&bytes).ok())
+            .filter(|cache| cache.version == CACHE_FORMAT_VERSION && cache.key == key);
+
+        Ok(cached.unwrap_or_else(|| Self {
+            version: CACHE_FORMAT_VERSION,
+            key,
+            entries: HashMap::new(),
+            dirty: true,
+        }))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the cache to `cache_dir` if anything changed since it was loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProjectError::CacheWrite` if the cache directory or file cannot be written.
+    pub fn save(&mut self, cache_dir: &Path) -> Result<(), ProjectError> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        fs::create_dir_all(cache_dir).map_err(|source| ProjectError::DirectoryCreate {
+            path: cache_dir.to_path_buf(),
+            source,
+        })?;
+
+        let gitignore = cache_dir.join(".gitignore");
+        if !gitignore.exists() {
+            fs::write(&gitignore, "*\n").map_err(|source| ProjectError::CacheWrite {
+                path: gitignore.clone(),
+                source,
+            })?;
+        }
+
+        let path = cache_dir.join(MAPPING_CACHE_FILE);
+        let contents = serde_json::to_vec(self).map_err(|source| ProjectError::CacheWrite {
+            path: path.clone(),
+            source: source.into(),
+        })?;
+        atomic_write(&path, contents)
+            .map_err(|source| ProjectError::CacheWrite { path, source })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// Like [`crate::map_files_to_packages`], but reuses and records assignments in `cache`.
+#[must_use]
+pub fn map_files_to_packages_cached<S: BuildHasher>(
+    project: &CargoProject,
+    changed_files: &[PathBuf],
+    root_config: &RootChangesetConfig,
+    package_configs: &HashMap<String, PackageChangesetConfig, S>,
+    cache: &mut MappingCache,
+) -> FileMapping {
+    let classifier = FileClassifier::new(project, root_config, package_configs);
+
+    let owners: Vec<(PathBuf, FileOwner)> = changed_files
+        .iter()
+        .map(|file| {
+            let owner = if let Some(owner) = cache.entries.get(file) {
+                owner.clone()
+            } else {
+                let owner = classifier.classify(file);
+                cache.entries.insert(file.clone(), owner.clone());
+                cache.dirty = true;
+                owner
+            };
+            (file.clone(), owner)
+        })
+        .collect();
+
+    build_file_mapping(project, owners)
+}
+
+fn manifest_fingerprint(project: &CargoProject) -> Result<String, ProjectError> {
+    let mut hash = fnv1a(FNV_OFFSET_BASIS, &CACHE_FORMAT_VERSION.to_le_bytes());
+    hash = hash_manifest(hash, project.root.join("Cargo.toml"))?;
+    // Submodule paths decide ownership too; a missing file hashes like an empty one.
+    let gitmodules = fs::read(project.root.join(GITMODULES_FILE)).unwrap_or_default();
+    hash = fnv1a(hash, &(gitmodules.len() as u64).to_le_bytes());
+    hash = fnv1a(hash, &gitmodules);
+
+    for missing in &project.missing_members {
+        let relative = missing.strip_prefix(&project.root).unwrap_or(missing);
+        hash = fnv1a(hash, relative.to_string_lossy().as_bytes());
+    }
+
+    let mut packages: Vec<_> = project.packages.iter().collect();
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+    for package in packages {
+        let relative = package
+            .path
+            .strip_prefix(&project.root)
+            .unwrap_or(&package.path);
+        hash = fnv1a(hash, package.name.as_bytes());
+        hash = fnv1a(hash, relative.to_string_lossy().as_bytes());
+        hash = hash_manifest(hash, project.manifest_path(package))?;
+    }
+
+    Ok(format!("{hash:016x}"))
+}
+
+fn hash_manifest(hash: u64, path: PathBuf) -> Result<u64, ProjectError> {
+    let contents = fs::read(&path).map_err(|source| ProjectError::ManifestRead { path, source })?;
+    // Length prefix keeps adjacent manifests from hashing the same when bytes shift between them.
+    let hash = fnv1a(hash, &(contents.len() as u64).to_le_bytes());
+    Ok(fnv1a(hash, &contents))
+}
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{discover_project, load_changeset_configs};
+    use tempfile::TempDir;
+
+    fn setup_workspace() -> anyhow::Result<TempDir> {
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )?;
+        for name in ["crate-a", "crate-b"] {
+            let crate_dir = dir.path().join("crates").join(name);
+            fs::create_dir_all(crate_dir.join("src"))?;
+            fs::write(
+                crate_dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+            )?;
+        }
+        Ok(dir)
+    }
+
+    fn cached_mapping(dir: &Path, files: &[PathBuf]) -> anyhow::Result<(FileMapping, usize)> {
+        let project = discover_project(dir)?;
+        let (root_config, package_configs) = load_changeset_configs(&project)?;
+        let cache_dir = dir.join(".changeset").join(CACHE_SUBDIR);
+
+        let mut cache = MappingCache::load(&project, &cache_dir)?;
+        let loaded_entries = cache.len();
+        let mapping = map_files_to_packages_cached(
+            &project,
+            files,
+            &root_config,
+            &package_configs,
+            &mut cache,
+        );
+        cache.save(&cache_dir)?;
+
+        Ok((mapping, loaded_entries))
+    }
+
+    #[test]
+    fn reuses_saved_entries() -> anyhow::Result<()> {
+        let dir = setup_workspace()?;
+        let files = vec![
+            PathBuf::from("crates/crate-a/src/lib.rs"),
+            PathBuf::from("README.md"),
+        ];
+
+        let (first, loaded) = cached_mapping(dir.path(), &files)?;
+        assert_eq!(loaded, 0);
+        assert!(dir.path().join(".changeset/.cache/file-map.json").exists());
+        assert!(dir.path().join(".changeset/.cache/.gitignore").exists());
+
+        let (second, loaded) = cached_mapping(dir.path(), &files)?;
+        assert_eq!(loaded, 2);
+        assert_eq!(first.affected_packages(), second.affected_packages());
+        assert_eq!(second.project_files, vec![PathBuf::from("README.md")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn manifest_change_invalidates_entries() -> anyhow::Result<()> {
+        let dir = setup_workspace()?;
+        let files = vec![PathBuf::from("crates/crate-a/src/lib.rs")];
+
+        cached_mapping(dir.path(), &files)?;
+
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n\
+             [workspace.metadata.changeset]\nignored-files = [\"crates/crate-a/src/*.rs\"]\n",
+        )?;
+
+        let (mapping, loaded) = cached_mapping(dir.path(), &files)?;
+        assert_eq!(loaded, 0);
+        assert!(mapping.affected_packages().is_empty());
+        assert_eq!(mapping.ignored_files, files);
+
+        Ok(())
+    }
+}
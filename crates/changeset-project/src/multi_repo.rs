@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::ProjectError;
+
+/// File listing the repositories driven by `cargo changeset multi`.
+pub const MULTI_REPO_CONFIG_FILENAME: &str = "changeset-workspaces.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MultiRepoFile {
+    #[serde(default, rename = "repository")]
+    repositories: Vec<RepositoryValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RepositoryValue {
+    path: PathBuf,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// One repository of a multi-repository setup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositoryEntry {
+    /// Label used in output; defaults to the directory name.
+    pub name: String,
+    /// Project root, resolved against the directory of the orchestration file.
+    pub path: PathBuf,
+}
+
+/// The repositories listed in a `changeset-workspaces.toml`, in file order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiRepoConfig {
+    repositories: Vec<RepositoryEntry>,
+}
+
+impl MultiRepoConfig {
+    /// Reads and validates an orchestration file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, lists no
+    /// repositories, or uses a repository name twice.
+    pub fn load(path: &Path) -> Result<Self, ProjectError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|source| ProjectError::MultiRepoRead {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        Self::parse(&content, base_dir).map_err(|error| match error {
+            ProjectError::TomlParse(source) => ProjectError::MultiRepoParse {
+                path: path.to_path_buf(),
+                source,
+            },
+            other => other,
+        })
+    }
+
+    fn parse(content: &str, base_dir: &Path) -> Result<Self, ProjectError> {
+        let file: MultiRepoFile = toml::from_str(content)?;
+        if file.repositories.is_empty() {
+            return Err(ProjectError::InvalidMultiRepo {
+                reason: "no [[repository]] entries".to_string(),
+            });
+        }
+
+        let mut names = HashSet::new();
+        let mut repositories = Vec::with_capacity(file.repositories.len());
+        for value in file.repositories {
+            let path = base_dir.join(&value.path);
+            let name = value.name.unwrap_or_else(|| {
+                path.file_name().map_or_else(
+                    || value.path.display().to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                )
+            });
+            if !names.insert(name.clone()) {
+                return Err(ProjectError::InvalidMultiRepo {
+                    reason: format!("repository name '{name}' is used more than once"),
+                });
+            }
+            repositories.push(RepositoryEntry { name, path });
+        }
+
+        Ok(Self { repositories })
+    }
+
+    #[must_use]
+    pub fn repositories(&self) -> &[RepositoryEntry] {
+        &self.repositories
+    }
+}
+
+/// Looks for a `changeset-workspaces.toml` in `start_dir` and its ancestors.
+#[must_use]
+pub fn find_multi_repo_config(start_dir: &Path) -> Option<PathBuf> {
+    start_dir
+        .ancestors()
+        .map(|dir| dir.join(MULTI_REPO_CONFIG_FILENAME))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_paths_and_default_names() -> anyhow::Result<()> {
+        let config = MultiRepoConfig::parse(
+            r#"
+[[repository]]
+path = "../core"
+
+[[repository]]
+path = "repos/utils"
+name = "shared-utils"
+"#,
+            Path::new("/work/platform"),
+        )?;
+
+        assert_eq!(
+            config.repositories(),
+            [
+                RepositoryEntry {
+                    name: "core".to_string(),
+                    path: PathBuf::from("/work/platform/../core"),
+                },
+                RepositoryEntry {
+                    name: "shared-utils".to_string(),
+                    path: PathBuf::from("/work/platform/repos/utils"),
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_empty_and_duplicate_entries() {
+        let empty = MultiRepoConfig::parse("", Path::new("/work"));
+        assert!(matches!(empty, Err(ProjectError::InvalidMultiRepo { .. })));
+
+        let duplicate = MultiRepoConfig::parse(
+            "[[repository]]\npath = \"a/core\"\n\n[[repository]]\npath = \"b/core\"\n",
+            Path::new("/work"),
+        );
+        assert!(
+            matches!(duplicate, Err(ProjectError::InvalidMultiRepo { reason }) if reason.contains("'core'"))
+        );
+    }
+
+    #[test]
+    fn finds_config_in_ancestors() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let nested = dir.path().join("core/src");
+        std::fs::create_dir_all(&nested)?;
+        std::fs::write(
+            dir.path().join(MULTI_REPO_CONFIG_FILENAME),
+            "[[repository]]\npath = \"core\"\n",
+        )?;
+
+        assert_eq!(
+            find_multi_repo_config(&nested),
+            Some(dir.path().join(MULTI_REPO_CONFIG_FILENAME))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::de::{self, Deserializer, Visitor};
+
+use crate::error::ProjectError;
+use crate::manifest::ChangesetMetadata;
+
+/// A single `key=value` override of the root changeset configuration, as
+/// given on the command line.
+///
+/// The key is a changeset metadata key such as `tags` or `changelog-format`;
+/// dotted keys address entries of table-valued keys, e.g.
+/// `display-names.crate-a`. The value is parsed as a TOML value and falls back
+/// to a plain string, so `false`, `3` and `["a", "b"]` keep their types while
+/// `per-package` needs no quoting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigOverride {
+    key: Vec<String>,
+    value: toml::Value,
+}
+
+impl ConfigOverride {
+    /// The dotted key this override sets.
+    #[must_use]
+    pub fn key(&self) -> String {
+        self.key.join(".")
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &toml::Value {
+        &self.value
+    }
+
+    /// Writes the override into a changeset metadata table, creating
+    /// intermediate tables and replacing non-table values along the way.
+    pub(crate) fn apply(&self, table: &mut toml::Table) {
+        let Some((last, parents)) = self.key.split_last() else {
+            return;
+        };
+        let mut current = table;
+        for segment in parents {
+            let entry = current
+                .entry(segment.clone())
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if !entry.is_table() {
+                *entry = toml::Value::Table(toml::Table::new());
+            }
+            let toml::Value::Table(next) = entry else {
+                unreachable!("entry was just made a table");
+            };
+            current = next;
+        }
+        current.insert(last.clone(), self.value.clone());
+    }
+}
+
+impl fmt::Display for ConfigOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key(), self.value)
+    }
+}
+
+impl FromStr for ConfigOverride {
+    type Err = ProjectError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| ProjectError::InvalidConfigOverride {
+            input: input.to_string(),
+            reason,
+        };
+
+        let (key, raw_value) = input
+            .split_once('=')
+            .ok_or_else(|| invalid("expected KEY=VALUE".to_string()))?;
+        let key: Vec<String> = key.trim().split('.').map(str::to_string).collect();
+        if key.iter().any(String::is_empty) {
+            return Err(invalid("key must not be empty".to_string()));
+        }
+        if !metadata_keys().contains(&key[0].as_str()) {
+            return Err(invalid(format!("unknown config key '{}'", key[0])));
+        }
+
+        let config_override = Self {
+            key,
+            value: parse_value(raw_value.trim()),
+        };
+        config_override.check_type().map_err(invalid)?;
+        Ok(config_override)
+    }
+}
+
+impl ConfigOverride {
+    /// Checks the value against the type of its key, so that a bad value is
+    /// reported against the override rather than the manifest it is merged
+    /// into.
+    fn check_type(&self) -> Result<(), String> {
+        let mut table = toml::Table::new();
+        self.apply(&mut table);
+        toml::Value::Table(table)
+            .try_into::<ChangesetMetadata>()
+            .map(drop)
+            .map_err(|e| format!("invalid value for '{}': {}", self.key(), e.message()))
+    }
+}
+
+fn parse_value(raw: &str) -> toml::Value {
+    format!("value = {raw}")
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()))
+}
+
+/// Keys accepted in `[workspace.metadata.changeset]`, read off the serde
+/// implementation of [`ChangesetMetadata`] so the list cannot drift.
+fn metadata_keys() -> &'static [&'static str] {
+    let mut recorder = FieldRecorder(&[]);
+    // The recorder fails on purpose once it has seen the field list.
+    let _ = ChangesetMetadata::deserialize(&mut recorder);
+    recorder.0
+}
+
+struct FieldRecorder(&'static [&'static str]);
+
+impl<'de> Deserializer<'de> for &mut FieldRecorder {
+    type Error = de::value::Error;
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0 = fields;
+        Err(de::Error::custom("field names recorded"))
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("expected a struct"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_typed_and_bare_values() -> anyhow::Result<()> {
+        let tags: ConfigOverride = "tags=false".parse()?;
+        assert_eq!(tags.key(), "tags");
+        assert_eq!(tags.value(), &toml::Value::Boolean(false));
+
+        let layout: ConfigOverride = "changelog = per-package".parse()?;
+        assert_eq!(layout.value().as_str(), Some("per-package"));
+
+        let scopes: ConfigOverride = r#"scopes=["cli", "core"]"#.parse()?;
+        assert_eq!(scopes.value().as_array().map(Vec::len), Some(2));
+
+        let name: ConfigOverride = "display-names.crate-a=Crate A".parse()?;
+        assert_eq!(name.key(), "display-names.crate-a");
+        assert_eq!(name.value().as_str(), Some("Crate A"));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_and_unknown_keys() {
+        for input in [
+            "tags",
+            "=false",
+            "display-names.=x",
+            "no-such-key=1",
+            "tags=maybe",
+            "tags=",
+        ] {
+            assert!(
+                matches!(
+                    input.parse::<ConfigOverride>(),
+                    Err(ProjectError::InvalidConfigOverride { .. })
+                ),
+                "{input} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn reports_the_expected_type_of_a_bad_value() {
+        let Err(ProjectError::InvalidConfigOverride { input, reason }) =
+            "tags=maybe".parse::<ConfigOverride>()
+        else {
+            panic!("tags=maybe should be rejected");
+        };
+
+        assert_eq!(input, "tags=maybe");
+        assert!(reason.contains("invalid value for 'tags'"), "{reason}");
+        assert!(reason.contains("expected a boolean"), "{reason}");
+    }
+
+    #[test]
+    fn apply_creates_nested_tables() -> anyhow::Result<()> {
+        let mut table = toml::Table::new();
+        table.insert("display-names".to_string(), toml::Value::Boolean(true));
+
+        "display-names.crate-a=A"
+            .parse::<ConfigOverride>()?
+            .apply(&mut table);
+        "tags=false".parse::<ConfigOverride>()?.apply(&mut table);
+
+        assert_eq!(
+            table
+                .get("display-names")
+                .and_then(|names| names.get("crate-a"))
+                .and_then(toml::Value::as_str),
+            Some("A")
+        );
+        assert_eq!(table.get("tags"), Some(&toml::Value::Boolean(false)));
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
-use std::path::{Path, PathBuf};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
 
 use changeset_core::PackageInfo;
 use globset::GlobBuilder;
 use semver::Version;
 
 use crate::CHANGESETS_SUBDIR;
-use crate::config::RootChangesetConfig;
+use crate::adapter::PackageAdapters;
+use crate::config::{OwnedChangesetDir, RootChangesetConfig};
 use crate::error::ProjectError;
 use crate::manifest::{CargoManifest, VersionField, read_manifest};
 
@@ -21,12 +23,64 @@ pub struct CargoProject {
     pub root: PathBuf,
     pub kind: ProjectKind,
     pub packages: Vec<PackageInfo>,
+    /// Workspace members listed by literal path that are not present on disk,
+    /// as in a sparse checkout. Their files belong to no package.
+    pub missing_members: Vec<PathBuf>,
+    /// Manifests of the packages found through `extra-packages`, by package
+    /// name. Packages not listed here are workspace members with a
+    /// `Cargo.toml` in their directory.
+    pub package_manifests: BTreeMap<String, PathBuf>,
+}
+
+impl CargoProject {
+    /// The manifest holding the version of `package`.
+    #[must_use]
+    pub fn manifest_path(&self, package: &PackageInfo) -> PathBuf {
+        self.package_manifests
+            .get(&package.name)
+            .cloned()
+            .unwrap_or_else(|| package.path.join("Cargo.toml"))
+    }
+
+    /// Whether `package` is a crate, as opposed to a package read by another
+    /// [`PackageAdapter`](crate::PackageAdapter).
+    #[must_use]
+    pub fn is_cargo_package(&self, package: &PackageInfo) -> bool {
+        self.manifest_path(package)
+            .file_name()
+            .is_some_and(|name| name == "Cargo.toml")
+    }
+
+    /// The packages that are crates, for the steps only Cargo packages have,
+    /// such as dependency updates and publishing.
+    #[must_use]
+    pub fn cargo_packages(&self) -> Vec<PackageInfo> {
+        self.packages
+            .iter()
+            .filter(|package| self.is_cargo_package(package))
+            .cloned()
+            .collect()
+    }
 }
 
 /// # Errors
 ///
 /// Returns `ProjectError` if no project root can be found or if manifest parsing fails.
 pub fn discover_project(start_dir: &Path) -> Result<CargoProject, ProjectError> {
+    discover_project_with_adapters(start_dir, &PackageAdapters::default())
+}
+
+/// Discovers the project like [`discover_project`], reading the packages in
+/// the `extra-packages` directories with `adapters`.
+///
+/// # Errors
+///
+/// Returns `ProjectError` if no project root can be found, if manifest parsing
+/// fails, or if an extra package has the name of another package.
+pub fn discover_project_with_adapters(
+    start_dir: &Path,
+    adapters: &PackageAdapters,
+) -> Result<CargoProject, ProjectError> {
     let start_dir = start_dir
         .canonicalize()
         .map_err(|source| ProjectError::ManifestRead {
@@ -36,15 +90,60 @@ pub fn discover_project(start_dir: &Path) -> Result<CargoProject, ProjectError>
 
     let (root, manifest) = find_project_root(&start_dir)?;
     let kind = determine_project_kind(&manifest);
-    let packages = collect_packages(&root, &manifest, &kind)?;
+    let mut packages = collect_packages(&root, &manifest, &kind)?;
+    let missing_members = find_missing_members(&root, &manifest);
+    let package_manifests = collect_extra_packages(&root, &manifest, adapters, &mut packages)?;
 
     Ok(CargoProject {
         root,
         kind,
         packages,
+        missing_members,
+        package_manifests,
     })
 }
 
+/// Reads the packages in the `extra-packages` directories of a workspace,
+/// appending them to `packages` and returning their manifests. Directories
+/// without a manifest any adapter reads are skipped, like members without a
+/// `Cargo.toml`.
+fn collect_extra_packages(
+    root: &Path,
+    manifest: &CargoManifest,
+    adapters: &PackageAdapters,
+    packages: &mut Vec<PackageInfo>,
+) -> Result<BTreeMap<String, PathBuf>, ProjectError> {
+    let patterns = manifest
+        .workspace
+        .as_ref()
+        .and_then(|ws| ws.metadata.as_ref())
+        .and_then(|metadata| metadata.changeset.as_ref())
+        .map_or(&[][..], |changeset| changeset.extra_packages.as_slice());
+
+    let mut manifests = BTreeMap::new();
+    for dir in expand_members(root, patterns)? {
+        if packages.iter().any(|package| package.path == dir) {
+            continue;
+        }
+        let Some((name, version, manifest_path)) = adapters.read_package(&dir)? else {
+            continue;
+        };
+        if packages.iter().any(|package| package.name == name) {
+            return Err(ProjectError::PackageManifest {
+                path: manifest_path,
+                reason: format!("package '{name}' is already in the workspace"),
+            });
+        }
+        manifests.insert(name.clone(), manifest_path);
+        packages.push(PackageInfo {
+            name,
+            version,
+            path: dir,
+        });
+    }
+    Ok(manifests)
+}
+
 /// # Errors
 ///
 /// Returns `ProjectError::DirectoryCreate` if directory creation fails.
@@ -52,7 +151,23 @@ pub fn ensure_changeset_dir(
     project: &CargoProject,
     config: &RootChangesetConfig,
 ) -> Result<PathBuf, ProjectError> {
-    let changeset_dir = project.root.join(config.changeset_dir());
+    create_changeset_dir(project.root.join(config.changeset_dir()))
+}
+
+/// Creates an owned changeset directory like [`ensure_changeset_dir`] does
+/// for the primary one.
+///
+/// # Errors
+///
+/// Returns `ProjectError::DirectoryCreate` if directory creation fails.
+pub fn ensure_owned_changeset_dir(
+    project: &CargoProject,
+    dir: &OwnedChangesetDir,
+) -> Result<PathBuf, ProjectError> {
+    create_changeset_dir(project.root.join(dir.path()))
+}
+
+fn create_changeset_dir(changeset_dir: PathBuf) -> Result<PathBuf, ProjectError> {
     let changesets_subdir = changeset_dir.join(CHANGESETS_SUBDIR);
     if !changesets_subdir.exists() {
         std::fs::create_dir_all(&changesets_subdir).map_err(|source| {
@@ -150,27 +265,44 @@ fn collect_packages(
         let members = workspace.members.as_deref().unwrap_or(&[]);
         let excludes = workspace.exclude.as_deref().unwrap_or(&[]);
 
-        for pattern in members {
-            let member_dirs = expand_glob_pattern(root, pattern, excludes)?;
+        let member_dirs = expand_members(root, members)?;
+        let member_dirs: Vec<PathBuf> = member_dirs
+            .into_iter()
+            .filter(|dir| dir != root && !is_excluded(root, dir, members, excludes))
+            .collect();
+
+        let mut discovered = Vec::new();
+        for member_dir in member_dirs {
+            let member_manifest_path = member_dir.join("Cargo.toml");
+            if !member_manifest_path.exists() {
+                continue;
+            }
 
-            for member_dir in member_dirs {
-                let member_manifest_path = member_dir.join("Cargo.toml");
-                if !member_manifest_path.exists() {
-                    continue;
-                }
+            let member_manifest = read_manifest(&member_manifest_path)?;
+            if let Some(pkg) = member_manifest.package {
+                let version = resolve_version(
+                    pkg.version.as_ref(),
+                    workspace_version,
+                    &member_manifest_path,
+                )?;
+                discovered.push(member_dir.clone());
+                packages.push(PackageInfo {
+                    name: pkg.name,
+                    version,
+                    path: member_dir,
+                });
+            }
+        }
 
-                let member_manifest = read_manifest(&member_manifest_path)?;
-                if let Some(pkg) = member_manifest.package {
-                    let version = resolve_version(
-                        pkg.version.as_ref(),
-                        workspace_version,
-                        &member_manifest_path,
-                    )?;
-                    packages.push(PackageInfo {
-                        name: pkg.name,
-                        version,
-                        path: member_dir,
-                    });
+        if let Some(default_members) = &workspace.default_members {
+            let missing = find_missing_members(root, manifest);
+            for default_dir in expand_members(root, default_members)? {
+                let is_root_package = default_dir == root && manifest.package.is_some();
+                if !is_root_package
+                    && !discovered.contains(&default_dir)
+                    && !missing.contains(&default_dir)
+                {
+                    return Err(ProjectError::DefaultMemberNotMember { path: default_dir });
                 }
             }
         }
@@ -179,6 +311,27 @@ fn collect_packages(
     Ok(packages)
 }
 
+/// Literal member paths whose directory or manifest is absent. Glob entries
+/// only ever match what is present, so they cannot be reported.
+fn find_missing_members(root: &Path, manifest: &CargoManifest) -> Vec<PathBuf> {
+    let Some(workspace) = &manifest.workspace else {
+        return Vec::new();
+    };
+    let members = workspace.members.as_deref().unwrap_or(&[]);
+
+    let mut missing: Vec<PathBuf> = Vec::new();
+    for member in members {
+        if member.contains(['*', '?', '[']) {
+            continue;
+        }
+        let dir = normalize_path(&root.join(member));
+        if dir != root && !dir.join("Cargo.toml").is_file() && !missing.contains(&dir) {
+            missing.push(dir);
+        }
+    }
+    missing
+}
+
 fn resolve_version(
     version_field: Option<&VersionField>,
     workspace_version: Option<&String>,
@@ -209,69 +362,136 @@ fn resolve_version(
         })
 }
 
-fn expand_glob_pattern(
-    root: &Path,
-    pattern: &str,
-    excludes: &[String],
-) -> Result<Vec<PathBuf>, ProjectError> {
-    let glob = GlobBuilder::new(pattern)
-        .literal_separator(true)
-        .build()
-        .map_err(|source| ProjectError::GlobPattern {
-            pattern: pattern.to_string(),
-            source,
-        })?
-        .compile_matcher();
+/// Expands `workspace.members` entries the way Cargo does: each entry is a
+/// path relative to the workspace root that may contain glob patterns, where
+/// `*`, `?`, and `[...]` match within one path component and `**` matches any
+/// number of directories.
+///
+/// Results are de-duplicated, keep the order of the entries, and are sorted
+/// within each entry. Only directories are returned.
+fn expand_members(root: &Path, members: &[String]) -> Result<Vec<PathBuf>, ProjectError> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+
+    for member in members {
+        let segments = parse_member_pattern(member)?;
+        let mut expanded = Vec::new();
+        collect_member_dirs(root, &segments, &mut expanded)?;
+        expanded.sort();
+
+        for dir in expanded {
+            let dir = normalize_path(&dir);
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+    }
+
+    Ok(dirs)
+}
 
-    let exclude_matchers: Vec<_> = excludes
-        .iter()
-        .filter_map(|ex| {
-            GlobBuilder::new(ex)
+/// Cargo treats `exclude` entries as plain path prefixes, not globs. A member
+/// listed by its literal path is kept even if an exclude entry covers it.
+fn is_excluded(root: &Path, member_dir: &Path, members: &[String], excludes: &[String]) -> bool {
+    let manifest_path = member_dir.join("Cargo.toml");
+    let covered_by = |entry: &String| manifest_path.starts_with(normalize_path(&root.join(entry)));
+
+    excludes.iter().any(covered_by) && !members.iter().any(covered_by)
+}
+
+enum MemberSegment {
+    Literal(String),
+    Pattern(globset::GlobMatcher),
+    AnyDepth,
+}
+
+fn parse_member_pattern(member: &str) -> Result<Vec<MemberSegment>, ProjectError> {
+    member
+        .split(['/', '\\'])
+        .filter(|component| !component.is_empty())
+        .map(|component| {
+            if component == "**" {
+                return Ok(MemberSegment::AnyDepth);
+            }
+            if !component.contains(['*', '?', '[']) {
+                return Ok(MemberSegment::Literal(component.to_string()));
+            }
+
+            let matcher = GlobBuilder::new(component)
                 .literal_separator(true)
                 .build()
-                .ok()
-                .map(|g| g.compile_matcher())
+                .map_err(|source| ProjectError::GlobPattern {
+                    pattern: member.to_string(),
+                    source,
+                })?
+                .compile_matcher();
+            Ok(MemberSegment::Pattern(matcher))
         })
-        .collect();
-
-    let mut dirs = Vec::new();
-    collect_matching_dirs(root, root, &glob, &exclude_matchers, &mut dirs)?;
-
-    Ok(dirs)
+        .collect()
 }
 
-fn collect_matching_dirs(
-    base: &Path,
+fn collect_member_dirs(
     current: &Path,
-    glob: &globset::GlobMatcher,
-    excludes: &[globset::GlobMatcher],
+    segments: &[MemberSegment],
     results: &mut Vec<PathBuf>,
 ) -> Result<(), ProjectError> {
-    let entries = std::fs::read_dir(current)?;
-
-    for entry in entries {
-        let entry = entry?;
-        let path = entry.path();
+    let Some((segment, rest)) = segments.split_first() else {
+        if current.is_dir() {
+            results.push(current.to_path_buf());
+        }
+        return Ok(());
+    };
 
-        if !path.is_dir() {
-            continue;
+    match segment {
+        MemberSegment::Literal(name) => {
+            let next = current.join(name);
+            if next.is_dir() {
+                collect_member_dirs(&next, rest, results)?;
+            }
         }
+        MemberSegment::Pattern(matcher) => {
+            for dir in subdirectories(current)? {
+                if dir.file_name().is_some_and(|name| matcher.is_match(name)) {
+                    collect_member_dirs(&dir, rest, results)?;
+                }
+            }
+        }
+        MemberSegment::AnyDepth => {
+            collect_member_dirs(current, rest, results)?;
+            for dir in subdirectories(current)? {
+                collect_member_dirs(&dir, segments, results)?;
+            }
+        }
+    }
 
-        // Fallback to full path if strip_prefix fails (shouldn't happen in practice)
-        let relative = path.strip_prefix(base).unwrap_or(&path);
+    Ok(())
+}
 
-        if excludes.iter().any(|ex| ex.is_match(relative)) {
-            continue;
+fn subdirectories(dir: &Path) -> Result<Vec<PathBuf>, ProjectError> {
+    let mut dirs = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
         }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
 
-        if glob.is_match(relative) {
-            results.push(path.clone());
+/// Resolves `.` and `..` components without touching the file system, as
+/// Cargo does for member paths.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
         }
-
-        collect_matching_dirs(base, &path, glob, excludes, results)?;
     }
-
-    Ok(())
+    normalized
 }
 
 #[cfg(test)]
@@ -285,9 +505,11 @@ mod tests {
             workspace: Some(crate::manifest::WorkspaceSection {
                 members: Some(vec!["crates/*".to_string()]),
                 exclude: None,
+                default_members: None,
                 package: None,
                 metadata: None,
             }),
+            lib: None,
         };
         assert_eq!(
             determine_project_kind(&manifest),
@@ -306,9 +528,11 @@ mod tests {
             workspace: Some(crate::manifest::WorkspaceSection {
                 members: Some(vec!["crates/*".to_string()]),
                 exclude: None,
+                default_members: None,
                 package: None,
                 metadata: None,
             }),
+            lib: None,
         };
         assert_eq!(
             determine_project_kind(&manifest),
@@ -325,6 +549,7 @@ mod tests {
                 metadata: None,
             }),
             workspace: None,
+            lib: None,
         };
         assert_eq!(
             determine_project_kind(&manifest),
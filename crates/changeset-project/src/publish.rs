@@ -0,0 +1,165 @@
+use std::path::Path;
+
+use changeset_core::PackageInfo;
+use serde::Deserialize;
+
+use crate::CargoProject;
+use crate::dependencies::DependencyTables;
+use crate::error::ProjectError;
+
+#[derive(Deserialize)]
+struct PublishManifest {
+    package: Option<PublishPackage>,
+    #[serde(flatten)]
+    dependencies: DependencyTables,
+}
+
+#[derive(Deserialize)]
+struct PublishPackage {
+    publish: Option<toml::Value>,
+}
+
+impl PublishManifest {
+    /// `publish = false` and `publish = []` both keep a package off every registry.
+    fn publishable(&self) -> bool {
+        match self
+            .package
+            .as_ref()
+            .and_then(|package| package.publish.as_ref())
+        {
+            Some(toml::Value::Boolean(publish)) => *publish,
+            Some(toml::Value::Array(registries)) => !registries.is_empty(),
+            _ => true,
+        }
+    }
+
+    /// Names of the packages this one needs at build time. Dev-dependencies
+    /// are left out because cargo strips path-only ones when publishing.
+    fn dependency_names(&self) -> Vec<String> {
+        self.dependencies
+            .specs()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+}
+
+fn read_publish_manifest(path: &Path) -> Result<PublishManifest, ProjectError> {
+    let content = std::fs::read_to_string(path).map_err(|source| ProjectError::ManifestRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    toml::from_str(&content).map_err(|source| ProjectError::ManifestParse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Publishable workspace packages, ordered so every package comes after the
+/// workspace packages it depends on.
+///
+/// Packages with `publish = false` and packages that are not crates are left
+/// out. Packages without ordering
+/// constraints keep their workspace order.
+///
+/// # Errors
+///
+/// Returns `ProjectError` if a package manifest cannot be read or parsed.
+pub fn publish_order(project: &CargoProject) -> Result<Vec<PackageInfo>, ProjectError> {
+    let mut pending: Vec<(&PackageInfo, Vec<String>)> = Vec::new();
+    for package in &project.packages {
+        if !project.is_cargo_package(package) {
+            continue;
+        }
+        let manifest = read_publish_manifest(&package.path.join("Cargo.toml"))?;
+        if manifest.publishable() {
+            pending.push((package, manifest.dependency_names()));
+        }
+    }
+
+    let mut ordered: Vec<PackageInfo> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready = pending
+            .iter()
+            .position(|(_, dependencies)| {
+                dependencies.iter().all(|dependency| {
+                    !pending
+                        .iter()
+                        .any(|(package, _)| &package.name == dependency)
+                })
+            })
+            // A dependency cycle cannot be published in any order; cargo
+            // reports it when publishing the first package of the cycle.
+            .unwrap_or(0);
+        let (package, _) = pending.remove(ready);
+        ordered.push(package.clone());
+    }
+
+    Ok(ordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use semver::Version;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::ProjectKind;
+
+    fn write_package(root: &Path, name: &str, extra: &str) -> anyhow::Result<PackageInfo> {
+        let path = root.join(name);
+        fs::create_dir_all(&path)?;
+        fs::write(
+            path.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n{extra}"),
+        )?;
+        Ok(PackageInfo {
+            name: name.to_string(),
+            version: Version::new(1, 0, 0),
+            path,
+        })
+    }
+
+    #[test]
+    fn orders_dependencies_first_and_skips_unpublished() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let root = dir.path();
+        let packages = vec![
+            write_package(
+                root,
+                "app",
+                "[dependencies]\ncore-lib = { path = \"../core\", package = \"core\" }\n\
+                 [target.'cfg(unix)'.build-dependencies]\nmacros = { path = \"../macros\" }\n",
+            )?,
+            write_package(
+                root,
+                "macros",
+                "[dependencies]\ncore = { path = \"../core\" }\n",
+            )?,
+            write_package(
+                root,
+                "core",
+                "[dev-dependencies]\napp = { path = \"../app\" }\n",
+            )?,
+            write_package(root, "xtask", "publish = false\n")?,
+        ];
+        let project = CargoProject {
+            root: root.to_path_buf(),
+            kind: ProjectKind::VirtualWorkspace,
+            packages,
+            missing_members: Vec::<PathBuf>::new(),
+            package_manifests: std::collections::BTreeMap::new(),
+        };
+
+        let order: Vec<String> = publish_order(&project)?
+            .into_iter()
+            .map(|package| package.name)
+            .collect();
+
+        assert_eq!(order, ["core", "macros", "app"]);
+        Ok(())
+    }
+}
@@ -1,18 +1,34 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::hash::Hash;
 
+use changeset_core::BumpType;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 /// Active prerelease configuration.
 /// File: `.changeset/pre-release.toml`
 /// Format:
 /// ```toml
+/// config_version = 2
+///
+/// [packages]
 /// crate-a = "alpha"
 /// crate-b = "beta"
+///
+/// [numbers]
+/// crate-a = 3
+/// crate-b = 1
 /// ```
+///
+/// `numbers` records the last pre-release number released for each crate, so
+/// numbering continues per crate instead of being re-derived from versions.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PrereleaseState {
-    #[serde(flatten)]
+    #[serde(default)]
     packages: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    numbers: HashMap<String, u64>,
 }
 
 impl PrereleaseState {
@@ -30,11 +46,23 @@ impl PrereleaseState {
         self.packages.insert(crate_name, tag);
     }
 
+    /// Removes the crate's tag and its recorded pre-release number.
     #[must_use]
     pub fn remove(&mut self, crate_name: &str) -> Option<String> {
+        self.numbers.remove(crate_name);
         self.packages.remove(crate_name)
     }
 
+    /// Returns the last pre-release number released for the crate.
+    #[must_use]
+    pub fn number(&self, crate_name: &str) -> Option<u64> {
+        self.numbers.get(crate_name).copied()
+    }
+
+    pub fn set_number(&mut self, crate_name: String, number: u64) {
+        self.numbers.insert(crate_name, number);
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
         self.packages.iter().map(|(k, v)| (k.as_str(), v.as_str()))
     }
@@ -44,6 +72,12 @@ impl PrereleaseState {
         self.packages.is_empty()
     }
 
+    /// Returns `true` when neither tags nor pre-release numbers are recorded.
+    #[must_use]
+    pub fn is_blank(&self) -> bool {
+        self.packages.is_empty() && self.numbers.is_empty()
+    }
+
     #[must_use]
     pub fn contains(&self, crate_name: &str) -> bool {
         self.packages.contains_key(crate_name)
@@ -53,18 +87,59 @@ impl PrereleaseState {
     pub fn len(&self) -> usize {
         self.packages.len()
     }
+
+    /// Three-way merges two edits of the same base state.
+    ///
+    /// Pre-release tags changed differently on both sides are conflicts and
+    /// keep `ours`; diverging pre-release numbers keep the higher one, since
+    /// numbers only ever grow.
+    #[must_use]
+    pub fn merge(base: &Self, ours: &Self, theirs: &Self) -> StateMerge<Self> {
+        let mut conflicts = Vec::new();
+        let packages = merge_maps(
+            &base.packages,
+            &ours.packages,
+            &theirs.packages,
+            |name, ours, _| {
+                conflicts.push(format!("pre-release tag of {name}"));
+                ours.cloned()
+            },
+        );
+        let numbers = merge_maps(
+            &base.numbers,
+            &ours.numbers,
+            &theirs.numbers,
+            |_, ours, theirs| ours.max(theirs).copied(),
+        );
+        StateMerge {
+            state: Self { packages, numbers },
+            conflicts,
+        }
+    }
 }
 
 /// Graduation queue for 0.x packages.
 /// File: `.changeset/graduation.toml`
 /// Format:
 /// ```toml
+/// config_version = 2
 /// graduation = ["crate-a", "crate-b"]
+///
+/// [policies.crate-c]
+/// graduate_after = "2025-01-01"
+///
+/// [policies.crate-d]
+/// graduate_on = "major"
 /// ```
+///
+/// Crates in `graduation` graduate at the next release; crates under
+/// `policies` graduate at the first release that satisfies their policy.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GraduationState {
     #[serde(default)]
     graduation: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    policies: BTreeMap<String, GraduationPolicy>,
 }
 
 impl GraduationState {
@@ -79,30 +154,248 @@ impl GraduationState {
         }
     }
 
+    /// Queues the crate to graduate once `policy` is satisfied.
+    pub fn set_policy(&mut self, crate_name: String, policy: GraduationPolicy) {
+        self.policies.insert(crate_name, policy);
+    }
+
+    #[must_use]
+    pub fn policy(&self, crate_name: &str) -> Option<&GraduationPolicy> {
+        self.policies.get(crate_name)
+    }
+
+    /// Removes the crate from the queue and drops its policy.
     #[must_use]
     pub fn remove(&mut self, crate_name: &str) -> bool {
         let len_before = self.graduation.len();
         self.graduation.retain(|x| x != crate_name);
-        self.graduation.len() != len_before
+        let had_policy = self.policies.remove(crate_name).is_some();
+        self.graduation.len() != len_before || had_policy
     }
 
+    /// Returns `true` when the crate is queued, with or without a policy.
     #[must_use]
     pub fn contains(&self, crate_name: &str) -> bool {
-        self.graduation.iter().any(|x| x == crate_name)
+        self.graduation.iter().any(|x| x == crate_name) || self.policies.contains_key(crate_name)
     }
 
+    /// Crates queued to graduate at the next release.
     pub fn iter(&self) -> impl Iterator<Item = &str> {
         self.graduation.iter().map(String::as_str)
     }
 
+    /// Crates queued to graduate once their policy is satisfied.
+    pub fn policies(&self) -> impl Iterator<Item = (&str, &GraduationPolicy)> {
+        self.policies
+            .iter()
+            .map(|(name, policy)| (name.as_str(), policy))
+    }
+
+    /// Every queued crate, with or without a policy.
+    pub fn crates(&self) -> impl Iterator<Item = &str> {
+        self.iter().chain(self.policies.keys().map(String::as_str))
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.graduation.is_empty()
+        self.graduation.is_empty() && self.policies.is_empty()
     }
 
     #[must_use]
     pub fn len(&self) -> usize {
-        self.graduation.len()
+        self.graduation.len() + self.policies.len()
+    }
+
+    /// Three-way merges two edits of the same base state.
+    ///
+    /// Crates queued or dequeued on either side stay that way; policies
+    /// changed differently on both sides are conflicts and keep `ours`.
+    #[must_use]
+    pub fn merge(base: &Self, ours: &Self, theirs: &Self) -> StateMerge<Self> {
+        let mut graduation = Vec::new();
+        for name in ours.graduation.iter().chain(&theirs.graduation) {
+            if graduation.contains(name) {
+                continue;
+            }
+            let queued = merge_entry(
+                base.graduation.contains(name),
+                ours.graduation.contains(name),
+                theirs.graduation.contains(name),
+            )
+            .unwrap_or(true);
+            if queued {
+                graduation.push(name.clone());
+            }
+        }
+
+        let mut conflicts = Vec::new();
+        let policies = merge_maps(
+            &base.policies,
+            &ours.policies,
+            &theirs.policies,
+            |name, ours, _| {
+                conflicts.push(format!("graduation policy of {name}"));
+                ours.cloned()
+            },
+        );
+        StateMerge {
+            state: Self {
+                graduation,
+                policies,
+            },
+            conflicts,
+        }
+    }
+}
+
+/// Result of merging two edits of a release state file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateMerge<T> {
+    pub state: T,
+    /// Entries both sides changed in different ways, described for humans.
+    pub conflicts: Vec<String>,
+}
+
+/// Picks the merged value of one entry, or `None` when both sides changed it
+/// in different ways.
+fn merge_entry<V: PartialEq>(base: V, ours: V, theirs: V) -> Option<V> {
+    if ours == theirs || theirs == base {
+        Some(ours)
+    } else if ours == base {
+        Some(theirs)
+    } else {
+        None
+    }
+}
+
+/// Merges every key of two maps against their common base; `on_conflict`
+/// resolves keys both sides changed in different ways, where `None` means the
+/// key was removed.
+fn merge_maps<K, V, M>(
+    base: &M,
+    ours: &M,
+    theirs: &M,
+    mut on_conflict: impl FnMut(&K, Option<&V>, Option<&V>) -> Option<V>,
+) -> M
+where
+    K: Ord + Clone,
+    V: PartialEq + Clone,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    M: MapLike<K, V> + Default,
+{
+    let keys: BTreeSet<&K> = ours.into_iter().chain(theirs).map(|(key, _)| key).collect();
+    let mut merged = M::default();
+    for key in keys {
+        let (ours_value, theirs_value) = (ours.lookup(key), theirs.lookup(key));
+        let value = merge_entry(base.lookup(key), ours_value, theirs_value).map_or_else(
+            || on_conflict(key, ours_value, theirs_value),
+            Option::<&V>::cloned,
+        );
+        if let Some(value) = value {
+            merged.put(key.clone(), value);
+        }
+    }
+    merged
+}
+
+/// The map operations [`merge_maps`] needs from `HashMap` and `BTreeMap`.
+trait MapLike<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V>;
+    fn put(&mut self, key: K, value: V);
+}
+
+impl<K: Hash + Eq, V> MapLike<K, V> for HashMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+impl<K: Ord, V> MapLike<K, V> for BTreeMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+/// Conditions under which a queued 0.x crate graduates to 1.0.0.
+///
+/// Every condition that is set must hold; a policy without conditions is
+/// satisfied at the next release.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraduationPolicy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    graduate_after: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    graduate_on: Option<BumpType>,
+}
+
+impl GraduationPolicy {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Graduates at the first release on or after `date`.
+    #[must_use]
+    pub fn with_graduate_after(mut self, date: NaiveDate) -> Self {
+        self.graduate_after = Some(date);
+        self
+    }
+
+    /// Graduates at the first release whose bump is at least `bump`.
+    #[must_use]
+    pub fn with_graduate_on(mut self, bump: BumpType) -> Self {
+        self.graduate_on = Some(bump);
+        self
+    }
+
+    #[must_use]
+    pub fn graduate_after(&self) -> Option<NaiveDate> {
+        self.graduate_after
+    }
+
+    #[must_use]
+    pub fn graduate_on(&self) -> Option<BumpType> {
+        self.graduate_on
+    }
+
+    /// Whether a release on `today` with the crate's combined `bump` satisfies the policy.
+    #[must_use]
+    pub fn is_satisfied(&self, today: NaiveDate, bump: Option<BumpType>) -> bool {
+        let date_reached = self.graduate_after.is_none_or(|date| today >= date);
+        let bump_reached = self
+            .graduate_on
+            .is_none_or(|required| bump.is_some_and(|bump| bump >= required));
+        date_reached && bump_reached
+    }
+}
+
+impl fmt::Display for GraduationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut conditions = Vec::new();
+        if let Some(date) = self.graduate_after {
+            conditions.push(format!("on or after {date}"));
+        }
+        if let Some(bump) = self.graduate_on {
+            let bump = match bump {
+                BumpType::Major => "major",
+                BumpType::Minor => "minor",
+                BumpType::Patch => "patch",
+            };
+            conditions.push(format!("on a {bump} release"));
+        }
+        if conditions.is_empty() {
+            write!(f, "at the next release")
+        } else {
+            write!(f, "{}", conditions.join(" and "))
+        }
     }
 }
 
@@ -208,6 +501,7 @@ mod tests {
         #[test]
         fn deserialize_from_toml() {
             let toml_content = r#"
+[packages]
 crate-a = "alpha"
 crate-b = "beta"
 "#;
@@ -229,6 +523,69 @@ crate-b = "beta"
 
             assert!(state.is_empty());
         }
+
+        #[test]
+        fn numbers_are_tracked_per_crate() {
+            let mut state = PrereleaseState::new();
+            state.insert("crate-a".to_string(), "alpha".to_string());
+            state.insert("crate-b".to_string(), "alpha".to_string());
+
+            state.set_number("crate-a".to_string(), 3);
+            state.set_number("crate-b".to_string(), 1);
+
+            assert_eq!(state.number("crate-a"), Some(3));
+            assert_eq!(state.number("crate-b"), Some(1));
+            assert_eq!(state.number("crate-c"), None);
+        }
+
+        #[test]
+        fn remove_drops_number() {
+            let mut state = PrereleaseState::new();
+            state.insert("crate-a".to_string(), "alpha".to_string());
+            state.set_number("crate-a".to_string(), 2);
+
+            let _ = state.remove("crate-a");
+
+            assert_eq!(state.number("crate-a"), None);
+            assert!(state.is_blank());
+        }
+
+        #[test]
+        fn numbers_without_tags_are_not_blank() {
+            let mut state = PrereleaseState::new();
+            state.set_number("crate-a".to_string(), 2);
+
+            assert!(state.is_empty());
+            assert!(!state.is_blank());
+        }
+
+        #[test]
+        fn numbers_roundtrip_through_toml() {
+            let toml_content = r#"
+[packages]
+crate-a = "alpha"
+
+[numbers]
+crate-a = 4
+"#;
+
+            let state: PrereleaseState =
+                toml::from_str(toml_content).expect("deserialization should succeed");
+            let serialized = toml::to_string(&state).expect("serialization should succeed");
+
+            assert_eq!(state.number("crate-a"), Some(4));
+            assert!(serialized.contains("[numbers]"));
+        }
+
+        #[test]
+        fn empty_numbers_are_not_serialized() {
+            let mut state = PrereleaseState::new();
+            state.insert("crate-a".to_string(), "alpha".to_string());
+
+            let serialized = toml::to_string(&state).expect("serialization should succeed");
+
+            assert!(!serialized.contains("numbers"));
+        }
     }
 
     mod graduation_state {
@@ -363,4 +720,192 @@ graduation = ["crate-a", "crate-b"]
             assert!(state.is_empty());
         }
     }
+
+    mod graduation_policy {
+        use super::*;
+
+        fn date(s: &str) -> NaiveDate {
+            s.parse().expect("valid date")
+        }
+
+        #[test]
+        fn deserializes_policies_from_toml() {
+            let toml_content = r#"
+graduation = ["crate-a"]
+
+[policies.crate-b]
+graduate_after = "2025-01-01"
+
+[policies.crate-c]
+graduate_on = "major"
+"#;
+
+            let state: GraduationState =
+                toml::from_str(toml_content).expect("deserialization should succeed");
+
+            assert_eq!(state.iter().collect::<Vec<_>>(), vec!["crate-a"]);
+            assert_eq!(
+                state.policy("crate-b"),
+                Some(&GraduationPolicy::new().with_graduate_after(date("2025-01-01")))
+            );
+            assert_eq!(
+                state.policy("crate-c"),
+                Some(&GraduationPolicy::new().with_graduate_on(BumpType::Major))
+            );
+            assert_eq!(state.len(), 3);
+        }
+
+        #[test]
+        fn serialize_deserialize_roundtrip() {
+            let mut state = GraduationState::new();
+            state.set_policy(
+                "crate-a".to_string(),
+                GraduationPolicy::new()
+                    .with_graduate_after(date("2025-01-01"))
+                    .with_graduate_on(BumpType::Minor),
+            );
+
+            let serialized = toml::to_string(&state).expect("serialization should succeed");
+            let deserialized: GraduationState =
+                toml::from_str(&serialized).expect("deserialization should succeed");
+
+            assert_eq!(state, deserialized);
+            assert!(serialized.contains("graduate_after = \"2025-01-01\""));
+        }
+
+        #[test]
+        fn remove_drops_policy() {
+            let mut state = GraduationState::new();
+            state.set_policy("crate-a".to_string(), GraduationPolicy::new());
+
+            assert!(state.contains("crate-a"));
+            assert!(state.remove("crate-a"));
+            assert!(state.is_empty());
+        }
+
+        #[test]
+        fn date_policy_is_satisfied_from_that_day() {
+            let policy = GraduationPolicy::new().with_graduate_after(date("2025-01-01"));
+
+            assert!(!policy.is_satisfied(date("2024-12-31"), Some(BumpType::Major)));
+            assert!(policy.is_satisfied(date("2025-01-01"), None));
+        }
+
+        #[test]
+        fn bump_policy_needs_at_least_that_bump() {
+            let policy = GraduationPolicy::new().with_graduate_on(BumpType::Major);
+            let today = date("2025-01-01");
+
+            assert!(!policy.is_satisfied(today, None));
+            assert!(!policy.is_satisfied(today, Some(BumpType::Minor)));
+            assert!(policy.is_satisfied(today, Some(BumpType::Major)));
+        }
+
+        #[test]
+        fn combined_policy_needs_every_condition() {
+            let policy = GraduationPolicy::new()
+                .with_graduate_after(date("2025-01-01"))
+                .with_graduate_on(BumpType::Major);
+
+            assert!(!policy.is_satisfied(date("2024-06-01"), Some(BumpType::Major)));
+            assert!(!policy.is_satisfied(date("2025-06-01"), Some(BumpType::Patch)));
+            assert!(policy.is_satisfied(date("2025-06-01"), Some(BumpType::Major)));
+        }
+
+        #[test]
+        fn display_describes_conditions() {
+            let policy = GraduationPolicy::new()
+                .with_graduate_after(date("2025-01-01"))
+                .with_graduate_on(BumpType::Major);
+
+            assert_eq!(
+                policy.to_string(),
+                "on or after 2025-01-01 and on a major release"
+            );
+            assert_eq!(GraduationPolicy::new().to_string(), "at the next release");
+        }
+    }
+
+    mod merge {
+        use super::*;
+
+        fn prerelease(packages: &[(&str, &str)], numbers: &[(&str, u64)]) -> PrereleaseState {
+            let mut state = PrereleaseState::new();
+            for (name, tag) in packages {
+                state.insert((*name).to_string(), (*tag).to_string());
+            }
+            for (name, number) in numbers {
+                state.set_number((*name).to_string(), *number);
+            }
+            state
+        }
+
+        fn graduation(queued: &[&str]) -> GraduationState {
+            let mut state = GraduationState::new();
+            for name in queued {
+                state.add((*name).to_string());
+            }
+            state
+        }
+
+        #[test]
+        fn prerelease_merge_unions_independent_edits() {
+            let base = prerelease(&[("a", "alpha"), ("b", "beta")], &[("a", 1)]);
+            let ours = prerelease(&[("a", "alpha"), ("b", "beta"), ("c", "rc")], &[("a", 2)]);
+            let theirs = prerelease(&[("a", "alpha")], &[("a", 3), ("d", 1)]);
+
+            let merged = PrereleaseState::merge(&base, &ours, &theirs);
+
+            assert!(merged.conflicts.is_empty());
+            assert_eq!(
+                merged.state,
+                prerelease(&[("a", "alpha"), ("c", "rc")], &[("a", 3), ("d", 1)])
+            );
+        }
+
+        #[test]
+        fn prerelease_merge_reports_diverging_tags() {
+            let base = prerelease(&[("a", "alpha")], &[]);
+            let ours = prerelease(&[("a", "beta")], &[]);
+            let theirs = prerelease(&[("a", "rc")], &[]);
+
+            let merged = PrereleaseState::merge(&base, &ours, &theirs);
+
+            assert_eq!(merged.conflicts, ["pre-release tag of a"]);
+            assert_eq!(merged.state.get("a"), Some("beta"));
+        }
+
+        #[test]
+        fn graduation_merge_keeps_additions_and_removals() {
+            let base = graduation(&["a", "b"]);
+            let ours = graduation(&["a", "b", "c"]);
+            let mut theirs = graduation(&["b", "d"]);
+            theirs.set_policy(
+                "e".to_string(),
+                GraduationPolicy::new().with_graduate_on(BumpType::Major),
+            );
+
+            let merged = GraduationState::merge(&base, &ours, &theirs);
+
+            assert!(merged.conflicts.is_empty());
+            assert_eq!(merged.state.iter().collect::<Vec<_>>(), ["b", "c", "d"]);
+            assert!(merged.state.policy("e").is_some());
+        }
+
+        #[test]
+        fn graduation_merge_reports_diverging_policies() {
+            let mut ours = GraduationState::new();
+            ours.set_policy("a".to_string(), GraduationPolicy::new());
+            let mut theirs = GraduationState::new();
+            theirs.set_policy(
+                "a".to_string(),
+                GraduationPolicy::new().with_graduate_on(BumpType::Minor),
+            );
+
+            let merged = GraduationState::merge(&GraduationState::new(), &ours, &theirs);
+
+            assert_eq!(merged.conflicts, ["graduation policy of a"]);
+            assert_eq!(merged.state.policy("a"), Some(&GraduationPolicy::new()));
+        }
+    }
 }
@@ -0,0 +1,206 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::ProjectError;
+
+/// Key holding the layout version at the top of every state file.
+pub const CONFIG_VERSION_KEY: &str = "config_version";
+
+/// Layout version written by this release.
+///
+/// Files without a `config_version` key predate versioning and are treated as
+/// version 1.
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+const UNVERSIONED: u32 = 1;
+
+/// A versioned file in the changeset directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateFile {
+    /// `pre-release.toml`
+    Prerelease,
+    /// `graduation.toml`
+    Graduation,
+}
+
+impl StateFile {
+    #[must_use]
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Self::Prerelease => "pre-release.toml",
+            Self::Graduation => "graduation.toml",
+        }
+    }
+
+    /// Upgrade steps, where the step at index `i` upgrades version `i + 1`.
+    fn migrations(self) -> &'static [fn(&mut toml::Table)] {
+        match self {
+            Self::Prerelease => &[nest_prerelease_packages],
+            Self::Graduation => &[no_layout_change],
+        }
+    }
+}
+
+impl std::fmt::Display for StateFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.file_name())
+    }
+}
+
+/// A state value loaded from disk together with the layout version it was stored in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migrated<T> {
+    pub state: T,
+    pub from_version: u32,
+}
+
+impl<T> Migrated<T> {
+    /// Whether the file on disk uses an older layout than [`CURRENT_CONFIG_VERSION`].
+    #[must_use]
+    pub fn is_outdated(&self) -> bool {
+        self.from_version < CURRENT_CONFIG_VERSION
+    }
+}
+
+/// Upgrades a parsed state file to the current layout and deserializes it.
+///
+/// The upgrade happens in memory only; callers decide whether to write the
+/// result back.
+///
+/// # Errors
+///
+/// Returns `ProjectError::UnsupportedConfigVersion` if the file was written by
+/// a newer release, or `ProjectError::StateLayout` if it does not match the
+/// expected layout.
+pub fn migrate_state<T: DeserializeOwned>(
+    file: StateFile,
+    mut table: toml::Table,
+) -> Result<Migrated<T>, ProjectError> {
+    let from_version = match table.remove(CONFIG_VERSION_KEY) {
+        None => UNVERSIONED,
+        Some(toml::Value::Integer(version)) => u32::try_from(version).unwrap_or(u32::MAX),
+        Some(_) => u32::MAX,
+    };
+
+    if from_version == 0 || from_version > CURRENT_CONFIG_VERSION {
+        return Err(ProjectError::UnsupportedConfigVersion {
+            file: file.file_name(),
+            version: from_version,
+            supported: CURRENT_CONFIG_VERSION,
+        });
+    }
+
+    for migration in file
+        .migrations()
+        .iter()
+        .skip((from_version - UNVERSIONED) as usize)
+    {
+        migration(&mut table);
+    }
+
+    let state = table
+        .try_into()
+        .map_err(|source| ProjectError::StateLayout {
+            file: file.file_name(),
+            source,
+        })?;
+
+    Ok(Migrated {
+        state,
+        from_version,
+    })
+}
+
+/// Serializes a state value in the current layout, including its version key.
+///
+/// # Errors
+///
+/// Returns an error if the state cannot be represented as TOML.
+pub fn serialize_state<T: Serialize>(state: &T) -> Result<String, toml::ser::Error> {
+    #[derive(Serialize)]
+    struct Versioned<'a, T> {
+        config_version: u32,
+        #[serde(flatten)]
+        state: &'a T,
+    }
+
+    toml::to_string_pretty(&Versioned {
+        config_version: CURRENT_CONFIG_VERSION,
+        state,
+    })
+}
+
+/// Version 1 kept crate names as top-level keys; version 2 moves them under
+/// `[packages]` so they cannot collide with file-level settings.
+fn nest_prerelease_packages(table: &mut toml::Table) {
+    let packages = std::mem::take(table);
+    table.insert("packages".to_string(), toml::Value::Table(packages));
+}
+
+fn no_layout_change(_table: &mut toml::Table) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GraduationState, PrereleaseState};
+
+    fn parse(content: &str) -> anyhow::Result<toml::Table> {
+        Ok(toml::from_str(content)?)
+    }
+
+    #[test]
+    fn migrates_unversioned_prerelease_file() -> anyhow::Result<()> {
+        let migrated: Migrated<PrereleaseState> = migrate_state(
+            StateFile::Prerelease,
+            parse("crate-a = \"alpha\"\ncrate-b = \"beta\"\n")?,
+        )?;
+
+        assert!(migrated.is_outdated());
+        assert_eq!(migrated.from_version, 1);
+        assert_eq!(migrated.state.get("crate-a"), Some("alpha"));
+        assert_eq!(migrated.state.get("crate-b"), Some("beta"));
+        Ok(())
+    }
+
+    #[test]
+    fn current_layout_roundtrips() -> anyhow::Result<()> {
+        let mut state = PrereleaseState::new();
+        state.insert("crate-a".to_string(), "rc".to_string());
+
+        let content = serialize_state(&state)?;
+        assert!(content.starts_with("config_version = 2"));
+        assert!(content.contains("[packages]"));
+
+        let migrated: Migrated<PrereleaseState> =
+            migrate_state(StateFile::Prerelease, parse(&content)?)?;
+        assert!(!migrated.is_outdated());
+        assert_eq!(migrated.state, state);
+        Ok(())
+    }
+
+    #[test]
+    fn unversioned_graduation_file_keeps_layout() -> anyhow::Result<()> {
+        let migrated: Migrated<GraduationState> = migrate_state(
+            StateFile::Graduation,
+            parse("graduation = [\"crate-a\"]\n")?,
+        )?;
+
+        assert!(migrated.is_outdated());
+        assert!(migrated.state.contains("crate-a"));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_newer_versions() -> anyhow::Result<()> {
+        let result = migrate_state::<GraduationState>(
+            StateFile::Graduation,
+            parse("config_version = 99\ngraduation = []\n")?,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ProjectError::UnsupportedConfigVersion { version: 99, .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) const GITMODULES_FILE: &str = ".gitmodules";
+
+/// Paths of the submodules declared in `<root>/.gitmodules`, relative to `root`.
+///
+/// A missing or unreadable file means the project has no submodules.
+pub(crate) fn read_submodule_paths(root: &Path) -> Vec<PathBuf> {
+    fs::read_to_string(root.join(GITMODULES_FILE))
+        .map(|content| parse_submodule_paths(&content))
+        .unwrap_or_default()
+}
+
+fn parse_submodule_paths(content: &str) -> Vec<PathBuf> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            key.trim().eq_ignore_ascii_case("path").then(|| {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .unwrap_or(value);
+                PathBuf::from(value.trim_end_matches('/'))
+            })
+        })
+        .filter(|path| !path.as_os_str().is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_submodule_paths() {
+        let content = r#"[submodule "vendor/lib"]
+	path = vendor/lib
+	url = https://example.com/lib.git
+[submodule "docs"]
+	Path = "docs theme/"
+	url = ../docs.git
+"#;
+
+        assert_eq!(
+            parse_submodule_paths(content),
+            vec![PathBuf::from("vendor/lib"), PathBuf::from("docs theme")]
+        );
+    }
+
+    #[test]
+    fn missing_file_has_no_submodules() {
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        assert!(read_submodule_paths(dir.path()).is_empty());
+    }
+}
@@ -246,3 +246,55 @@ fn discover_project_from_current_directory_works() {
     let result = discover_project(&cwd);
     assert!(result.is_ok());
 }
+
+#[test]
+fn extra_packages_are_read_by_adapters() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    let root = temp_dir.path();
+    let write = |path: &str, content: &str| {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().expect("has parent")).expect("create dir");
+        std::fs::write(path, content).expect("write file");
+    };
+    write(
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.metadata.changeset]\nextra-packages = [\"packages/*\"]\n",
+    );
+    write(
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\nversion = \"1.0.0\"\n",
+    );
+    write(
+        "packages/web/package.json",
+        "{ \"name\": \"@acme/web\", \"version\": \"2.1.0\" }",
+    );
+    write(
+        "packages/cli/pyproject.toml",
+        "[project]\nname = \"acme-cli\"\nversion = \"0.4.0\"\n",
+    );
+    write("packages/docs/README.md", "no manifest");
+
+    let project = discover_project(root).expect("should discover project");
+
+    let packages: Vec<_> = project
+        .packages
+        .iter()
+        .map(|p| (p.name.as_str(), p.version.to_string()))
+        .collect();
+    assert_eq!(
+        packages,
+        [
+            ("core", "1.0.0".to_string()),
+            ("acme-cli", "0.4.0".to_string()),
+            ("@acme/web", "2.1.0".to_string()),
+        ]
+    );
+    let web = &project.packages[2];
+    assert!(!project.is_cargo_package(web));
+    assert!(
+        project
+            .manifest_path(web)
+            .ends_with("packages/web/package.json")
+    );
+    assert_eq!(project.cargo_packages().len(), 1);
+}
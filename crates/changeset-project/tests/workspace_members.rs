@@ -0,0 +1,234 @@
+//! Member discovery compared against `cargo metadata` for the same workspace.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use changeset_project::{ProjectError, discover_project};
+use tempfile::TempDir;
+
+struct Case {
+    name: &'static str,
+    root_manifest: &'static str,
+    /// Package directories relative to the root; the last component is the package name.
+    packages: &'static [&'static str],
+    expected: &'static [&'static str],
+}
+
+const CASES: &[Case] = &[
+    Case {
+        name: "single-level glob",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\"]\n",
+        packages: &["crates/alpha", "crates/beta"],
+        expected: &["alpha", "beta"],
+    },
+    Case {
+        name: "glob with excluded directory",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/beta\"]\n",
+        packages: &["crates/alpha", "crates/beta"],
+        expected: &["alpha"],
+    },
+    Case {
+        name: "exclude is a path prefix",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\", \"tools/*\"]\nexclude = [\"tools\"]\n",
+        packages: &["crates/alpha", "tools/gen"],
+        expected: &["alpha"],
+    },
+    Case {
+        name: "exclude is not a glob",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/b*\"]\n",
+        packages: &["crates/alpha", "crates/beta"],
+        expected: &["alpha", "beta"],
+    },
+    Case {
+        name: "explicit member overrides exclude",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\", \"crates/beta\"]\nexclude = [\"crates/beta\"]\n",
+        packages: &["crates/alpha", "crates/beta"],
+        expected: &["alpha", "beta"],
+    },
+    Case {
+        name: "nested glob",
+        root_manifest: "[workspace]\nmembers = [\"crates/*/*\"]\n",
+        packages: &["crates/core/alpha", "crates/extra/beta"],
+        expected: &["alpha", "beta"],
+    },
+    Case {
+        name: "recursive glob",
+        root_manifest: "[workspace]\nmembers = [\"plugins/**/plugin-*\"]\n",
+        packages: &["plugins/plugin-a", "plugins/group/deep/plugin-b"],
+        expected: &["plugin-a", "plugin-b"],
+    },
+    Case {
+        name: "character class and single-character wildcard",
+        root_manifest: "[workspace]\nmembers = [\"crates/crate-[ab]\", \"tools/t?\"]\n",
+        packages: &["crates/crate-a", "crates/crate-b", "tools/t1"],
+        expected: &["crate-a", "crate-b", "t1"],
+    },
+    Case {
+        name: "overlapping patterns",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\", \"crates/alpha\", \"./crates/beta\"]\n",
+        packages: &["crates/alpha", "crates/beta"],
+        expected: &["alpha", "beta"],
+    },
+    Case {
+        name: "root package listed as member",
+        root_manifest: "[package]\nname = \"root\"\nversion = \"0.1.0\"\n\n[workspace]\nmembers = [\".\", \"crates/*\"]\n",
+        packages: &["crates/alpha"],
+        expected: &["alpha", "root"],
+    },
+    Case {
+        name: "default-members does not limit discovery",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\"]\ndefault-members = [\"crates/alpha\"]\n",
+        packages: &["crates/alpha", "crates/beta"],
+        expected: &["alpha", "beta"],
+    },
+    Case {
+        name: "glob default-members",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\", \"tools/*\"]\ndefault-members = [\"crates/*\"]\n",
+        packages: &["crates/alpha", "tools/gen"],
+        expected: &["alpha", "gen"],
+    },
+];
+
+fn create_workspace(case: &Case) -> anyhow::Result<TempDir> {
+    let dir = TempDir::new()?;
+    fs::write(dir.path().join("Cargo.toml"), case.root_manifest)?;
+    if case.root_manifest.contains("[package]") {
+        fs::create_dir_all(dir.path().join("src"))?;
+        fs::write(dir.path().join("src/lib.rs"), "")?;
+    }
+
+    for package in case.packages {
+        let package_dir = dir.path().join(package);
+        let name = Path::new(package)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow::anyhow!("invalid package path {package}"))?;
+        fs::create_dir_all(package_dir.join("src"))?;
+        fs::write(
+            package_dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n"),
+        )?;
+        fs::write(package_dir.join("src/lib.rs"), "")?;
+    }
+
+    Ok(dir)
+}
+
+/// Workspace member names as reported by `cargo metadata`, or `None` if cargo
+/// cannot be run in this environment.
+fn cargo_metadata_members(root: &Path) -> anyhow::Result<Option<BTreeSet<String>>> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let Ok(output) = Command::new(cargo)
+        .args([
+            "metadata",
+            "--no-deps",
+            "--offline",
+            "--format-version",
+            "1",
+        ])
+        .current_dir(root)
+        .output()
+    else {
+        return Ok(None);
+    };
+    anyhow::ensure!(
+        output.status.success(),
+        "cargo metadata failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let members: BTreeSet<&str> = metadata["workspace_members"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(serde_json::Value::as_str)
+        .collect();
+
+    Ok(Some(
+        metadata["packages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|package| {
+                package["id"]
+                    .as_str()
+                    .is_some_and(|id| members.contains(id))
+            })
+            .filter_map(|package| package["name"].as_str().map(str::to_string))
+            .collect(),
+    ))
+}
+
+#[test]
+fn discovery_matches_cargo_metadata() -> anyhow::Result<()> {
+    for case in CASES {
+        let dir = create_workspace(case)?;
+
+        let project = discover_project(dir.path())?;
+        let discovered: BTreeSet<String> = project
+            .packages
+            .iter()
+            .map(|package| package.name.clone())
+            .collect();
+        let expected: BTreeSet<String> = case.expected.iter().map(ToString::to_string).collect();
+
+        assert_eq!(discovered, expected, "case: {}", case.name);
+        assert_eq!(
+            project.packages.len(),
+            expected.len(),
+            "duplicate packages in case: {}",
+            case.name
+        );
+
+        if let Some(cargo_members) = cargo_metadata_members(dir.path())? {
+            assert_eq!(
+                discovered, cargo_members,
+                "disagrees with cargo metadata in case: {}",
+                case.name
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn default_member_outside_workspace_is_rejected() -> anyhow::Result<()> {
+    let case = Case {
+        name: "default member not a member",
+        root_manifest: "[workspace]\nmembers = [\"crates/*\"]\ndefault-members = [\"tools/gen\"]\n",
+        packages: &["crates/alpha", "tools/gen"],
+        expected: &[],
+    };
+    let dir = create_workspace(&case)?;
+
+    let result = discover_project(dir.path());
+
+    assert!(
+        matches!(result, Err(ProjectError::DefaultMemberNotMember { ref path }) if path.ends_with("tools/gen")),
+        "unexpected result: {result:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn members_missing_from_sparse_checkout_are_skipped() -> anyhow::Result<()> {
+    let case = Case {
+        name: "sparse checkout",
+        root_manifest: "[workspace]\nmembers = [\"crates/alpha\", \"crates/beta\", \"tools/*\"]\ndefault-members = [\"crates/beta\"]\n",
+        packages: &["crates/alpha"],
+        expected: &["alpha"],
+    };
+    let dir = create_workspace(&case)?;
+
+    let project = discover_project(dir.path())?;
+
+    let names: Vec<_> = project.packages.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, case.expected);
+    assert_eq!(project.missing_members.len(), 1);
+    assert!(project.missing_members[0].ends_with("crates/beta"));
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Status of a step in the audit log.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +12,10 @@ pub enum StepStatus {
     Compensated,
     /// Step compensation failed.
     CompensationFailed,
+    /// Step was skipped because its idempotency key was already completed.
+    AlreadyDone,
+    /// Step did not finish within its timeout.
+    TimedOut,
 }
 
 /// Record of a step's execution in the saga.
@@ -25,8 +29,12 @@ pub struct StepRecord {
     pub started_at: Instant,
     /// When the step completed (execution or compensation).
     pub completed_at: Option<Instant>,
+    /// How long the step's execution took, excluding any compensation.
+    pub duration: Option<Duration>,
     /// Description of compensation (if applicable).
     pub compensation_description: Option<String>,
+    /// Maximum time the step was allowed to run, if it had a timeout.
+    pub timeout: Option<Duration>,
 }
 
 /// Audit log tracking all step executions in a saga.
@@ -49,27 +57,60 @@ impl SagaAuditLog {
             status: StepStatus::Executed,
             started_at: Instant::now(),
             completed_at: None,
+            duration: None,
             compensation_description: None,
+            timeout: None,
         });
     }
 
+    /// Record the timeout the last step runs under.
+    pub(crate) fn record_timeout(&mut self, timeout: Duration) {
+        if let Some(record) = self.records.last_mut() {
+            record.timeout = Some(timeout);
+        }
+    }
+
     /// Mark the last step as failed.
     pub(crate) fn record_failure(&mut self) {
         if let Some(record) = self.records.last_mut() {
+            let now = Instant::now();
             record.status = StepStatus::Failed;
-            record.completed_at = Some(Instant::now());
+            record.completed_at = Some(now);
+            record.duration = Some(now.duration_since(record.started_at));
+        }
+    }
+
+    /// Mark the last step as abandoned after exceeding its timeout.
+    pub(crate) fn record_timed_out(&mut self) {
+        if let Some(record) = self.records.last_mut() {
+            let now = Instant::now();
+            record.status = StepStatus::TimedOut;
+            record.completed_at = Some(now);
+            record.duration = Some(now.duration_since(record.started_at));
         }
     }
 
     /// Mark the last step as completed successfully.
     pub(crate) fn record_success(&mut self, compensation_description: String) {
         if let Some(record) = self.records.last_mut() {
+            let now = Instant::now();
             record.status = StepStatus::Executed;
-            record.completed_at = Some(Instant::now());
+            record.completed_at = Some(now);
+            record.duration = Some(now.duration_since(record.started_at));
             record.compensation_description = Some(compensation_description);
         }
     }
 
+    /// Mark the last step as skipped because its effect was already done.
+    pub(crate) fn record_already_done(&mut self) {
+        if let Some(record) = self.records.last_mut() {
+            let now = Instant::now();
+            record.status = StepStatus::AlreadyDone;
+            record.completed_at = Some(now);
+            record.duration = Some(now.duration_since(record.started_at));
+        }
+    }
+
     /// Record that a step was compensated.
     pub(crate) fn record_compensated(&mut self, step_name: &str) {
         for record in &mut self.records {
@@ -96,6 +137,12 @@ impl SagaAuditLog {
         &self.records
     }
 
+    /// Total execution time of all recorded steps.
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.records.iter().filter_map(|r| r.duration).sum()
+    }
+
     /// Get a summary of the saga execution for display.
     #[must_use]
     pub fn summary(&self) -> String {
@@ -106,6 +153,8 @@ impl SagaAuditLog {
                 StepStatus::Failed => "✗",
                 StepStatus::Compensated => "↩",
                 StepStatus::CompensationFailed => "⚠",
+                StepStatus::AlreadyDone => "=",
+                StepStatus::TimedOut => "⏱",
             };
             lines.push(format!("{status} {}", record.name));
         }
@@ -142,6 +191,7 @@ mod tests {
 
         assert_eq!(log.records()[0].status, StepStatus::Failed);
         assert!(log.records()[0].completed_at.is_some());
+        assert!(log.records()[0].duration.is_some());
     }
 
     #[test]
@@ -181,6 +231,29 @@ mod tests {
         assert_eq!(log.records()[0].status, StepStatus::CompensationFailed);
     }
 
+    #[test]
+    fn compensation_keeps_execution_duration() {
+        let mut log = SagaAuditLog::new();
+        log.record_start("step_1");
+        log.record_success("undo".to_string());
+        let duration = log.records()[0].duration;
+        log.record_compensated("step_1");
+
+        assert!(duration.is_some());
+        assert_eq!(log.records()[0].duration, duration);
+    }
+
+    #[test]
+    fn total_duration_sums_step_durations() {
+        let mut log = SagaAuditLog::new();
+        log.record_start("step_1");
+        log.record_success("undo".to_string());
+        log.record_start("step_2");
+
+        let expected = log.records()[0].duration.expect("step_1 completed");
+        assert_eq!(log.total_duration(), expected);
+    }
+
     #[test]
     fn summary_formats_all_steps() {
         let mut log = SagaAuditLog::new();
@@ -1,9 +1,11 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use crate::erased::{ErasedStep, StepWrapper};
 use crate::saga::Saga;
 use crate::step::SagaStep;
+use crate::timeout::{StepTimeout, TimedStepWrapper};
 
 /// Marker type for a builder with no steps.
 pub struct Empty;
@@ -126,6 +128,37 @@ impl<Input, CurrentOutput, Ctx, Err>
         }
     }
 
+    /// Add another step that fails with [`StepTimeout`] if it runs longer than `timeout`.
+    ///
+    /// The step runs on its own thread with a clone of the context, so a step
+    /// that blocks fails the saga deterministically and triggers compensation
+    /// of the steps before it. The abandoned call is not interrupted and may
+    /// still finish in the background. A panic in the step is re-raised after
+    /// that compensation.
+    ///
+    /// Only use this for steps whose effect is harmless when it lands late,
+    /// such as read-only queries. Because the timed-out step is never
+    /// compensated, a step that writes files or publishes something may
+    /// complete after the saga has rolled back and leave that effect behind.
+    #[must_use]
+    pub fn then_with_timeout<S>(
+        self,
+        step: S,
+        timeout: Duration,
+    ) -> SagaBuilder<Input, S::Output, Ctx, Err, HasSteps<S::Output>>
+    where
+        S: SagaStep<Input = CurrentOutput, Context = Ctx, Error = Err> + 'static,
+        Ctx: Clone + Send + 'static,
+        Err: From<StepTimeout> + Send + 'static,
+    {
+        let mut steps = self.steps;
+        steps.push(Box::new(TimedStepWrapper::new(step, timeout)));
+        SagaBuilder {
+            steps,
+            _phantom: PhantomData,
+        }
+    }
+
     /// Build the saga from the accumulated steps.
     #[must_use]
     pub fn build(self) -> Saga<Input, CurrentOutput, Ctx, Err>
@@ -10,6 +10,9 @@ pub(crate) trait CloneableAny: Any + Send {
 
     /// Convert into a boxed `Any` for downcasting.
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
+
+    /// Borrow as `Any` for downcasting by reference.
+    fn as_any(&self) -> &dyn Any;
 }
 
 impl<T> CloneableAny for T
@@ -23,6 +26,10 @@ where
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
         self
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 #[cfg(test)]
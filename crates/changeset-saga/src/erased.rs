@@ -1,18 +1,43 @@
+use std::any::Any;
+use std::time::Duration;
+
 use crate::cloneable::CloneableAny;
 use crate::step::SagaStep;
 
+/// Why a type-erased step did not produce an output.
+#[derive(Debug)]
+pub(crate) enum StepFailure<E> {
+    Failed(E),
+    TimedOut(E),
+    /// The step panicked on another thread; the payload is resumed once the
+    /// completed steps are compensated.
+    Panicked(Box<dyn Any + Send>),
+}
+
 pub(crate) trait ErasedStep<Ctx, Err> {
     fn name(&self) -> &'static str;
 
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
     fn execute_erased(
         &self,
         ctx: &Ctx,
         input: Box<dyn CloneableAny>,
-    ) -> Result<Box<dyn CloneableAny>, Err>;
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<Err>>;
 
     fn compensate_erased(&self, ctx: &Ctx, input: Box<dyn CloneableAny>) -> Result<(), Err>;
 
     fn compensation_description(&self) -> String;
+
+    fn idempotency_key_erased(&self, input: &dyn CloneableAny) -> Option<String>;
+
+    fn resume_erased(
+        &self,
+        ctx: &Ctx,
+        input: Box<dyn CloneableAny>,
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<Err>>;
 }
 
 pub(crate) struct StepWrapper<S> {
@@ -37,12 +62,15 @@ where
         &self,
         ctx: &S::Context,
         input: Box<dyn CloneableAny>,
-    ) -> Result<Box<dyn CloneableAny>, S::Error> {
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<S::Error>> {
         let typed_input = input
             .into_any()
             .downcast::<S::Input>()
             .expect("type-state builder guarantees correct input type");
-        let output = self.step.execute(ctx, *typed_input)?;
+        let output = self
+            .step
+            .execute(ctx, *typed_input)
+            .map_err(StepFailure::Failed)?;
         Ok(Box::new(output))
     }
 
@@ -61,6 +89,30 @@ where
     fn compensation_description(&self) -> String {
         self.step.compensation_description()
     }
+
+    fn idempotency_key_erased(&self, input: &dyn CloneableAny) -> Option<String> {
+        let typed_input = input
+            .as_any()
+            .downcast_ref::<S::Input>()
+            .expect("type-state builder guarantees correct input type");
+        self.step.idempotency_key(typed_input)
+    }
+
+    fn resume_erased(
+        &self,
+        ctx: &S::Context,
+        input: Box<dyn CloneableAny>,
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<S::Error>> {
+        let typed_input = input
+            .into_any()
+            .downcast::<S::Input>()
+            .expect("type-state builder guarantees correct input type");
+        let output = self
+            .step
+            .resume(ctx, *typed_input)
+            .map_err(StepFailure::Failed)?;
+        Ok(Box::new(output))
+    }
 }
 
 #[cfg(test)]
@@ -165,6 +217,9 @@ mod tests {
 
         assert!(result.is_err());
         let err = result.err().expect("should have an error");
-        assert_eq!(err, TestError(String::from("test error")));
+        assert!(matches!(
+            err,
+            StepFailure::Failed(TestError(ref message)) if message == "test error"
+        ));
     }
 }
@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+/// Idempotency keys of saga steps whose externally visible effects are in place.
+///
+/// Pass the same set to successive runs of a saga (see
+/// [`Saga::execute_resumable`](crate::Saga::execute_resumable)) so that a
+/// resumed run skips effects that an earlier run already produced. Keys are
+/// added when a keyed step executes and removed when it is compensated, so
+/// after a failed run the set holds exactly the effects that could not be
+/// undone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdempotencyKeys {
+    keys: BTreeSet<String>,
+}
+
+impl IdempotencyKeys {
+    /// Create an empty set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the effect identified by `key` is already done.
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Record the effect identified by `key` as done.
+    pub fn insert(&mut self, key: impl Into<String>) {
+        self.keys.insert(key.into());
+    }
+
+    /// Forget the effect identified by `key`, returning whether it was recorded.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.keys.remove(key)
+    }
+
+    /// Whether no effect is recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Iterate over the recorded keys in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(String::as_str)
+    }
+}
+
+impl<K: Into<String>> FromIterator<K> for IdempotencyKeys {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        Self {
+            keys: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove_track_keys() {
+        let mut keys = IdempotencyKeys::new();
+        keys.insert("publish:crate-a@1.0.0");
+
+        assert!(keys.contains("publish:crate-a@1.0.0"));
+        assert!(keys.remove("publish:crate-a@1.0.0"));
+        assert!(!keys.remove("publish:crate-a@1.0.0"));
+        assert!(keys.is_empty());
+    }
+
+    #[test]
+    fn iterates_in_sorted_order() {
+        let keys: IdempotencyKeys = ["b", "a"].into_iter().collect();
+
+        assert_eq!(keys.iter().collect::<Vec<_>>(), ["a", "b"]);
+    }
+}
@@ -9,11 +9,15 @@ mod builder;
 mod cloneable;
 mod erased;
 mod error;
+mod idempotency;
 mod saga;
 mod step;
+mod timeout;
 
 pub use audit::{SagaAuditLog, StepRecord, StepStatus};
 pub use builder::SagaBuilder;
 pub use error::{CompensationError, SagaError};
+pub use idempotency::IdempotencyKeys;
 pub use saga::Saga;
 pub use step::SagaStep;
+pub use timeout::StepTimeout;
@@ -3,8 +3,16 @@ use std::marker::PhantomData;
 
 use crate::audit::SagaAuditLog;
 use crate::cloneable::CloneableAny;
-use crate::erased::ErasedStep;
+use crate::erased::{ErasedStep, StepFailure};
 use crate::error::{CompensationError, SagaError};
+use crate::idempotency::IdempotencyKeys;
+
+/// A completed step awaiting possible compensation.
+struct CompensationEntry {
+    index: usize,
+    input: Box<dyn CloneableAny>,
+    key: Option<String>,
+}
 
 /// A compiled saga ready for execution.
 ///
@@ -38,7 +46,7 @@ where
     /// Returns `SagaError::StepFailed` if a step fails and all compensations succeed.
     /// Returns `SagaError::CompensationFailed` if a step fails and some compensations also fail.
     pub fn execute(&self, ctx: &Ctx, input: Input) -> Result<Output, SagaError<Err>> {
-        let (result, _audit_log) = self.execute_internal(ctx, input);
+        let (result, _audit_log) = self.execute_internal(ctx, input, &mut IdempotencyKeys::new());
         result
     }
 
@@ -50,29 +58,66 @@ where
         ctx: &Ctx,
         input: Input,
     ) -> (Result<Output, SagaError<Err>>, SagaAuditLog) {
-        self.execute_internal(ctx, input)
+        self.execute_internal(ctx, input, &mut IdempotencyKeys::new())
+    }
+
+    /// Execute the saga, skipping steps whose effects are recorded in `completed`.
+    ///
+    /// A step whose idempotency key is in `completed` is resumed instead of
+    /// executed, and is not compensated if a later step fails, since this run
+    /// did not produce its effect. Keys of steps that execute are added to
+    /// `completed`; keys of steps that are compensated are removed again.
+    pub fn execute_resumable(
+        &self,
+        ctx: &Ctx,
+        input: Input,
+        completed: &mut IdempotencyKeys,
+    ) -> (Result<Output, SagaError<Err>>, SagaAuditLog) {
+        self.execute_internal(ctx, input, completed)
     }
 
     fn execute_internal(
         &self,
         ctx: &Ctx,
         input: Input,
+        completed: &mut IdempotencyKeys,
     ) -> (Result<Output, SagaError<Err>>, SagaAuditLog) {
         let mut audit_log = SagaAuditLog::new();
-        let mut compensation_stack: Vec<(usize, Box<dyn CloneableAny>)> = Vec::new();
+        let mut compensation_stack: Vec<CompensationEntry> = Vec::new();
 
         let mut current_input: Box<dyn CloneableAny> = Box::new(input);
 
         for (index, step) in self.steps.iter().enumerate() {
             audit_log.record_start(step.name());
+            if let Some(timeout) = step.timeout() {
+                audit_log.record_timeout(timeout);
+            }
 
             let input_clone = current_input.clone_box();
+            let key = step.idempotency_key_erased(input_clone.as_ref());
+            let already_done = key.as_deref().is_some_and(|key| completed.contains(key));
+
+            let result = if already_done {
+                step.resume_erased(ctx, current_input)
+            } else {
+                step.execute_erased(ctx, current_input)
+            };
 
-            match step.execute_erased(ctx, current_input) {
+            match result {
                 Ok(output) => {
-                    let description = step.compensation_description();
-                    audit_log.record_success(description);
-                    compensation_stack.push((index, input_clone));
+                    if already_done {
+                        audit_log.record_already_done();
+                    } else {
+                        audit_log.record_success(step.compensation_description());
+                        if let Some(key) = &key {
+                            completed.insert(key.clone());
+                        }
+                        compensation_stack.push(CompensationEntry {
+                            index,
+                            input: input_clone,
+                            key,
+                        });
+                    }
 
                     if index == self.steps.len() - 1 {
                         let typed_output = output
@@ -84,12 +129,32 @@ where
 
                     current_input = output;
                 }
-                Err(error) => {
-                    audit_log.record_failure();
+                Err(failure) => {
+                    let error = match failure {
+                        StepFailure::Failed(error) => {
+                            audit_log.record_failure();
+                            error
+                        }
+                        StepFailure::TimedOut(error) => {
+                            audit_log.record_timed_out();
+                            error
+                        }
+                        StepFailure::Panicked(payload) => {
+                            audit_log.record_failure();
+                            self.compensate_completed(
+                                ctx,
+                                &mut audit_log,
+                                compensation_stack,
+                                completed,
+                            );
+                            std::panic::resume_unwind(payload);
+                        }
+                    };
                     let saga_error = self.compensate(
                         ctx,
                         &mut audit_log,
                         compensation_stack,
+                        completed,
                         step.name(),
                         error,
                     );
@@ -105,20 +170,50 @@ where
         &self,
         ctx: &Ctx,
         audit_log: &mut SagaAuditLog,
-        mut compensation_stack: Vec<(usize, Box<dyn CloneableAny>)>,
+        compensation_stack: Vec<CompensationEntry>,
+        completed: &mut IdempotencyKeys,
         failed_step: &str,
         step_error: Err,
     ) -> SagaError<Err> {
+        let compensation_errors =
+            self.compensate_completed(ctx, audit_log, compensation_stack, completed);
+
+        if compensation_errors.is_empty() {
+            SagaError::StepFailed {
+                step: failed_step.to_string(),
+                source: step_error,
+            }
+        } else {
+            SagaError::CompensationFailed {
+                failed_step: failed_step.to_string(),
+                step_error,
+                compensation_errors,
+            }
+        }
+    }
+
+    /// Compensates the completed steps in reverse order, returning the
+    /// compensations that failed.
+    fn compensate_completed(
+        &self,
+        ctx: &Ctx,
+        audit_log: &mut SagaAuditLog,
+        mut compensation_stack: Vec<CompensationEntry>,
+        completed: &mut IdempotencyKeys,
+    ) -> Vec<CompensationError<Err>> {
         let mut compensation_errors = Vec::new();
 
-        while let Some((index, stored_input)) = compensation_stack.pop() {
-            let step = &self.steps[index];
+        while let Some(entry) = compensation_stack.pop() {
+            let step = &self.steps[entry.index];
             let step_name = step.name();
             let description = step.compensation_description();
 
-            match step.compensate_erased(ctx, stored_input) {
+            match step.compensate_erased(ctx, entry.input) {
                 Ok(()) => {
                     audit_log.record_compensated(step_name);
+                    if let Some(key) = &entry.key {
+                        completed.remove(key);
+                    }
                 }
                 Err(error) => {
                     audit_log.record_compensation_failed(step_name);
@@ -130,19 +225,7 @@ where
                 }
             }
         }
-
-        if compensation_errors.is_empty() {
-            SagaError::StepFailed {
-                step: failed_step.to_string(),
-                source: step_error,
-            }
-        } else {
-            SagaError::CompensationFailed {
-                failed_step: failed_step.to_string(),
-                step_error,
-                compensation_errors,
-            }
-        }
+        compensation_errors
     }
 }
 
@@ -52,4 +52,27 @@ pub trait SagaStep: Send + Sync {
     fn compensation_description(&self) -> String {
         format!("undo {}", self.name())
     }
+
+    /// Key identifying the externally visible effect `execute()` has for `input`.
+    ///
+    /// Steps that publish something outside the local repository should return
+    /// a key so that a resumed saga recognizes the effect as already done
+    /// instead of repeating it. The default is `None`: the step always runs.
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        let _ = input;
+        None
+    }
+
+    /// Produce the output of a step whose idempotency key is already completed.
+    ///
+    /// Called instead of `execute()` when resuming. Implementations must not
+    /// repeat the side effect. The default re-executes the step, so steps that
+    /// return an idempotency key should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output cannot be reconstructed.
+    fn resume(&self, ctx: &Self::Context, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        self.execute(ctx, input)
+    }
 }
@@ -0,0 +1,141 @@
+use std::sync::{Arc, mpsc};
+use std::thread;
+use std::time::Duration;
+
+use crate::cloneable::CloneableAny;
+use crate::erased::{ErasedStep, StepFailure};
+use crate::step::SagaStep;
+
+/// Error produced for a step that exceeded its timeout.
+///
+/// Steps added with
+/// [`SagaBuilder::then_with_timeout`](crate::SagaBuilder::then_with_timeout)
+/// must have an error type that converts from this one.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("step '{step}' timed out after {}s", timeout.as_secs_f64())]
+pub struct StepTimeout {
+    /// Name of the step that timed out.
+    pub step: &'static str,
+    /// The timeout the step exceeded.
+    pub timeout: Duration,
+}
+
+/// Runs a step on a separate thread and abandons it once `timeout` elapses.
+///
+/// The abandoned thread is detached rather than stopped, so a hung call may
+/// still complete later; its result is discarded. The timed-out step is not
+/// compensated because it never reported success, so any side effect of a
+/// late completion is left in place. A panic in the step is
+/// re-raised on the saga's thread after the earlier steps are compensated.
+pub(crate) struct TimedStepWrapper<S> {
+    step: Arc<S>,
+    timeout: Duration,
+}
+
+impl<S> TimedStepWrapper<S> {
+    pub(crate) fn new(step: S, timeout: Duration) -> Self {
+        Self {
+            step: Arc::new(step),
+            timeout,
+        }
+    }
+}
+
+impl<S> TimedStepWrapper<S>
+where
+    S: SagaStep + 'static,
+    S::Context: Clone + Send + 'static,
+    S::Error: From<StepTimeout> + Send + 'static,
+{
+    fn run_detached(
+        &self,
+        ctx: &S::Context,
+        input: Box<dyn CloneableAny>,
+        run: fn(&S, &S::Context, S::Input) -> Result<S::Output, S::Error>,
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<S::Error>> {
+        let typed_input = input
+            .into_any()
+            .downcast::<S::Input>()
+            .expect("type-state builder guarantees correct input type");
+        let step = Arc::clone(&self.step);
+        let ctx = ctx.clone();
+        let (sender, receiver) = mpsc::channel();
+
+        let worker = thread::spawn(move || {
+            let _ = sender.send(run(&step, &ctx, *typed_input));
+        });
+
+        match receiver.recv_timeout(self.timeout) {
+            Ok(result) => result
+                .map(|output| Box::new(output) as Box<dyn CloneableAny>)
+                .map_err(StepFailure::Failed),
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(StepFailure::TimedOut(
+                StepTimeout {
+                    step: self.step.name(),
+                    timeout: self.timeout,
+                }
+                .into(),
+            )),
+            // The worker only drops the sender without sending when the step panics.
+            Err(mpsc::RecvTimeoutError::Disconnected) => match worker.join() {
+                Err(payload) => Err(StepFailure::Panicked(payload)),
+                Ok(()) => unreachable!("worker exited without sending a result"),
+            },
+        }
+    }
+}
+
+impl<S> ErasedStep<S::Context, S::Error> for TimedStepWrapper<S>
+where
+    S: SagaStep + 'static,
+    S::Context: Clone + Send + 'static,
+    S::Error: From<StepTimeout> + Send + 'static,
+{
+    fn name(&self) -> &'static str {
+        self.step.name()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(self.timeout)
+    }
+
+    fn execute_erased(
+        &self,
+        ctx: &S::Context,
+        input: Box<dyn CloneableAny>,
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<S::Error>> {
+        self.run_detached(ctx, input, S::execute)
+    }
+
+    fn compensate_erased(
+        &self,
+        ctx: &S::Context,
+        input: Box<dyn CloneableAny>,
+    ) -> Result<(), S::Error> {
+        let typed_input = input
+            .into_any()
+            .downcast::<S::Input>()
+            .expect("type-state builder guarantees correct input type");
+        self.step.compensate(ctx, *typed_input)
+    }
+
+    fn compensation_description(&self) -> String {
+        self.step.compensation_description()
+    }
+
+    fn idempotency_key_erased(&self, input: &dyn CloneableAny) -> Option<String> {
+        let typed_input = input
+            .as_any()
+            .downcast_ref::<S::Input>()
+            .expect("type-state builder guarantees correct input type");
+        self.step.idempotency_key(typed_input)
+    }
+
+    fn resume_erased(
+        &self,
+        ctx: &S::Context,
+        input: Box<dyn CloneableAny>,
+    ) -> Result<Box<dyn CloneableAny>, StepFailure<S::Error>> {
+        self.run_detached(ctx, input, S::resume)
+    }
+}
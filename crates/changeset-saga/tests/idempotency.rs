@@ -0,0 +1,184 @@
+//! Integration tests for resuming sagas with idempotency keys.
+
+use std::cell::RefCell;
+
+use changeset_saga::{IdempotencyKeys, SagaBuilder, SagaStep, StepStatus};
+
+struct TestContext {
+    published: RefCell<Vec<i32>>,
+    log: RefCell<Vec<String>>,
+}
+
+impl TestContext {
+    fn new() -> Self {
+        Self {
+            published: RefCell::new(Vec::new()),
+            log: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+struct TestError(String);
+
+struct PublishStep;
+
+impl SagaStep for PublishStep {
+    type Input = i32;
+    type Output = i32;
+    type Context = TestContext;
+    type Error = TestError;
+
+    fn name(&self) -> &'static str {
+        "publish"
+    }
+
+    fn execute(
+        &self,
+        ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        ctx.published.borrow_mut().push(input);
+        Ok(input)
+    }
+
+    fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        ctx.log.borrow_mut().push(format!("unpublish {input}"));
+        Ok(())
+    }
+
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        Some(format!("publish:{input}"))
+    }
+
+    fn resume(
+        &self,
+        _ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(input)
+    }
+}
+
+struct StubbornPublishStep;
+
+impl SagaStep for StubbornPublishStep {
+    type Input = i32;
+    type Output = i32;
+    type Context = TestContext;
+    type Error = TestError;
+
+    fn name(&self) -> &'static str {
+        "stubborn_publish"
+    }
+
+    fn execute(
+        &self,
+        ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        ctx.published.borrow_mut().push(input);
+        Ok(input)
+    }
+
+    fn compensate(&self, _ctx: &Self::Context, _input: Self::Input) -> Result<(), Self::Error> {
+        Err(TestError("cannot unpublish".to_string()))
+    }
+
+    fn idempotency_key(&self, input: &Self::Input) -> Option<String> {
+        Some(format!("stubborn:{input}"))
+    }
+
+    fn resume(
+        &self,
+        _ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(input)
+    }
+}
+
+struct FailingStep;
+
+impl SagaStep for FailingStep {
+    type Input = i32;
+    type Output = i32;
+    type Context = TestContext;
+    type Error = TestError;
+
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+
+    fn execute(
+        &self,
+        _ctx: &Self::Context,
+        _input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        Err(TestError("boom".to_string()))
+    }
+}
+
+#[test]
+fn completed_step_is_resumed_instead_of_executed() {
+    let ctx = TestContext::new();
+    let saga = SagaBuilder::new().first_step(PublishStep).build();
+    let mut completed: IdempotencyKeys = ["publish:7"].into_iter().collect();
+
+    let (result, audit_log) = saga.execute_resumable(&ctx, 7, &mut completed);
+
+    assert_eq!(result.expect("saga should succeed"), 7);
+    assert!(ctx.published.borrow().is_empty());
+    assert_eq!(audit_log.records()[0].status, StepStatus::AlreadyDone);
+}
+
+#[test]
+fn executed_step_records_its_key() {
+    let ctx = TestContext::new();
+    let saga = SagaBuilder::new().first_step(PublishStep).build();
+    let mut completed = IdempotencyKeys::new();
+
+    let (result, _) = saga.execute_resumable(&ctx, 7, &mut completed);
+
+    assert!(result.is_ok());
+    assert_eq!(*ctx.published.borrow(), [7]);
+    assert!(completed.contains("publish:7"));
+}
+
+#[test]
+fn compensation_removes_key_and_skips_resumed_steps() {
+    let ctx = TestContext::new();
+    let saga = SagaBuilder::new()
+        .first_step(PublishStep)
+        .then(FailingStep)
+        .build();
+    let mut completed = IdempotencyKeys::new();
+
+    let (result, _) = saga.execute_resumable(&ctx, 7, &mut completed);
+    assert!(result.is_err());
+    assert!(completed.is_empty());
+    assert_eq!(*ctx.log.borrow(), ["unpublish 7"]);
+
+    ctx.log.borrow_mut().clear();
+    completed.insert("publish:7");
+    let (result, _) = saga.execute_resumable(&ctx, 7, &mut completed);
+    assert!(result.is_err());
+    assert!(ctx.log.borrow().is_empty());
+    assert!(completed.contains("publish:7"));
+}
+
+#[test]
+fn failed_compensation_keeps_key() {
+    let ctx = TestContext::new();
+    let saga = SagaBuilder::new()
+        .first_step(StubbornPublishStep)
+        .then(FailingStep)
+        .build();
+    let mut completed = IdempotencyKeys::new();
+
+    let (result, _) = saga.execute_resumable(&ctx, 3, &mut completed);
+
+    assert!(result.is_err());
+    assert_eq!(completed.iter().collect::<Vec<_>>(), ["stubborn:3"]);
+}
@@ -0,0 +1,172 @@
+//! Integration tests for per-step timeouts.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use changeset_saga::{SagaBuilder, SagaError, SagaStep, StepStatus, StepTimeout};
+
+#[derive(Clone, Default)]
+struct TestContext {
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TestError {
+    #[error(transparent)]
+    Timeout(#[from] StepTimeout),
+}
+
+struct RecordStep;
+
+impl SagaStep for RecordStep {
+    type Input = i32;
+    type Output = i32;
+    type Context = TestContext;
+    type Error = TestError;
+
+    fn name(&self) -> &'static str {
+        "record"
+    }
+
+    fn execute(
+        &self,
+        _ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        Ok(input + 1)
+    }
+
+    fn compensate(&self, ctx: &Self::Context, input: Self::Input) -> Result<(), Self::Error> {
+        ctx.log
+            .lock()
+            .expect("lock poisoned")
+            .push(format!("compensate record {input}"));
+        Ok(())
+    }
+}
+
+struct SleepStep {
+    duration: Duration,
+}
+
+impl SagaStep for SleepStep {
+    type Input = i32;
+    type Output = i32;
+    type Context = TestContext;
+    type Error = TestError;
+
+    fn name(&self) -> &'static str {
+        "sleep"
+    }
+
+    fn execute(
+        &self,
+        _ctx: &Self::Context,
+        input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        thread::sleep(self.duration);
+        Ok(input * 2)
+    }
+}
+
+#[test]
+fn step_within_timeout_succeeds() {
+    let ctx = TestContext::default();
+    let saga = SagaBuilder::new()
+        .first_step(RecordStep)
+        .then_with_timeout(
+            SleepStep {
+                duration: Duration::ZERO,
+            },
+            Duration::from_secs(10),
+        )
+        .build();
+
+    let (result, audit_log) = saga.execute_with_audit(&ctx, 1);
+
+    assert_eq!(result.expect("saga should succeed"), 4);
+    assert_eq!(audit_log.records()[1].status, StepStatus::Executed);
+    assert_eq!(
+        audit_log.records()[1].timeout,
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(audit_log.records()[0].timeout, None);
+}
+
+#[test]
+fn hung_step_times_out_and_triggers_compensation() {
+    let ctx = TestContext::default();
+    let saga = SagaBuilder::new()
+        .first_step(RecordStep)
+        .then_with_timeout(
+            SleepStep {
+                duration: Duration::from_secs(30),
+            },
+            Duration::from_millis(50),
+        )
+        .build();
+
+    let (result, audit_log) = saga.execute_with_audit(&ctx, 1);
+
+    match result {
+        Err(SagaError::StepFailed { step, source }) => {
+            assert_eq!(step, "sleep");
+            assert!(source.to_string().contains("timed out after 0.05s"));
+        }
+        other => panic!("expected a timed out step, got {other:?}"),
+    }
+    assert_eq!(audit_log.records()[1].status, StepStatus::TimedOut);
+    assert_eq!(
+        audit_log.records()[1].timeout,
+        Some(Duration::from_millis(50))
+    );
+    assert_eq!(
+        *ctx.log.lock().expect("lock poisoned"),
+        ["compensate record 1"]
+    );
+}
+
+struct PanicStep;
+
+impl SagaStep for PanicStep {
+    type Input = i32;
+    type Output = i32;
+    type Context = TestContext;
+    type Error = TestError;
+
+    fn name(&self) -> &'static str {
+        "panic"
+    }
+
+    fn execute(
+        &self,
+        _ctx: &Self::Context,
+        _input: Self::Input,
+    ) -> Result<Self::Output, Self::Error> {
+        panic!("registry client crashed");
+    }
+}
+
+#[test]
+fn panicking_step_compensates_and_resumes_its_panic() {
+    let ctx = TestContext::default();
+    let saga = SagaBuilder::new()
+        .first_step(RecordStep)
+        .then_with_timeout(PanicStep, Duration::from_secs(10))
+        .build();
+
+    let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        saga.execute_with_audit(&ctx, 1)
+    }))
+    .expect_err("saga should panic");
+
+    assert_eq!(
+        payload.downcast_ref::<&str>(),
+        Some(&"registry client crashed")
+    );
+    assert_eq!(
+        *ctx.log.lock().expect("lock poisoned"),
+        ["compensate record 1"]
+    );
+}
@@ -117,6 +117,27 @@ pub fn extract_prerelease_tag(version: &Version) -> Option<String> {
     parse_prerelease(&version.pre).map(|(tag, _)| tag)
 }
 
+#[must_use]
+pub fn extract_prerelease_number(version: &Version) -> Option<u64> {
+    parse_prerelease(&version.pre).map(|(_, num)| num)
+}
+
+/// Returns `version` with its prerelease number replaced by `number`.
+///
+/// Stable versions are returned unchanged.
+///
+/// # Errors
+///
+/// Returns `VersionError::InvalidPrerelease` if the resulting identifier
+/// is not a valid semver prerelease string.
+pub fn with_prerelease_number(version: &Version, number: u64) -> Result<Version, VersionError> {
+    let mut new_version = version.clone();
+    if let Some((tag, _)) = parse_prerelease(&version.pre) {
+        new_version.pre = make_prerelease(&tag, number)?;
+    }
+    Ok(new_version)
+}
+
 #[must_use]
 pub fn is_zero_version(version: &Version) -> bool {
     version.major == 0
@@ -498,6 +519,36 @@ mod tests {
         }
     }
 
+    mod prerelease_number_tests {
+        use super::*;
+
+        #[test]
+        fn extracts_number() {
+            let version = Version::parse("1.0.0-alpha.4").unwrap();
+            assert_eq!(extract_prerelease_number(&version), Some(4));
+        }
+
+        #[test]
+        fn stable_version_has_no_number() {
+            let version = Version::parse("1.0.0").unwrap();
+            assert_eq!(extract_prerelease_number(&version), None);
+        }
+
+        #[test]
+        fn replaces_number_keeping_tag() {
+            let version = Version::parse("1.0.0-beta.1").unwrap();
+            let renumbered = with_prerelease_number(&version, 3).unwrap();
+            assert_eq!(renumbered, Version::parse("1.0.0-beta.3").unwrap());
+        }
+
+        #[test]
+        fn stable_version_is_unchanged() {
+            let version = Version::parse("1.0.0").unwrap();
+            let renumbered = with_prerelease_number(&version, 3).unwrap();
+            assert_eq!(renumbered, version);
+        }
+    }
+
     mod is_zero_version_tests {
         use super::*;
 